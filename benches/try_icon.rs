@@ -0,0 +1,51 @@
+//! `try_icon` lookup timings for a small, a medium and the largest enabled pack.
+//!
+//! Run with `cargo bench --features pack-feather,pack-bootstrap` (add `all-packs` to include
+//! the largest pack, and `fast-lookup` to compare its hashed lookups).
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use iconflow::{Pack, Size, Style, list, list_in_variant, sizes, styles, try_icon};
+use std::hint::black_box;
+
+/// First `(style, size)` of `pack` with any icons, and the last name available in it.
+fn hit(pack: Pack) -> (Style, Size, &'static str) {
+    styles(pack)
+        .into_iter()
+        .flat_map(|style| sizes(pack).into_iter().map(move |size| (style, size)))
+        .find_map(|(style, size)| {
+            list_in_variant(pack, style, size)
+                .last()
+                .map(|&name| (style, size, name))
+        })
+        .expect("every enabled pack has at least one icon")
+}
+
+fn bench_pack(c: &mut Criterion, pack: Pack) {
+    let mut group = c.benchmark_group(format!("{} ({} icons)", pack.id(), list(pack).len()));
+    let (style, size, name) = hit(pack);
+    assert!(try_icon(pack, name, style, size).is_ok());
+    group.bench_function("hit", |b| {
+        b.iter(|| try_icon(black_box(pack), black_box(name), style, size))
+    });
+    group.bench_function("miss", |b| {
+        b.iter(|| try_icon(black_box(pack), black_box("no-such-icon"), style, size))
+    });
+    group.finish();
+}
+
+fn try_icon_benches(c: &mut Criterion) {
+    bench_pack(c, Pack::Feather);
+    bench_pack(c, Pack::Bootstrap);
+
+    let largest = Pack::ALL
+        .iter()
+        .copied()
+        .max_by_key(|&pack| list(pack).len())
+        .expect("at least one pack is enabled");
+    if largest != Pack::Feather && largest != Pack::Bootstrap {
+        bench_pack(c, largest);
+    }
+}
+
+criterion_group!(benches, try_icon_benches);
+criterion_main!(benches);
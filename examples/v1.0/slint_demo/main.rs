@@ -0,0 +1,53 @@
+use iconflow::slint::{glyph_string, register_fonts};
+use iconflow::{AsGlyph, Pack, Size, Style, try_icon};
+use slint::{ModelRc, VecModel};
+
+slint::slint! {
+    export struct Glyph {
+        family: string,
+        text: string,
+    }
+
+    export component IconDemo inherits Window {
+        in property <[Glyph]> glyphs;
+        title: "iconflow slint demo";
+        preferred-width: 480px;
+        preferred-height: 240px;
+        background: #202020;
+
+        HorizontalLayout {
+            alignment: center;
+            spacing: 16px;
+            for glyph in glyphs: Text {
+                text: glyph.text;
+                font-family: glyph.family;
+                font-size: 48px;
+                color: white;
+                vertical-alignment: center;
+            }
+        }
+    }
+}
+
+fn main() -> Result<(), slint::PlatformError> {
+    register_fonts();
+
+    let samples = [
+        (Pack::Bootstrap, "alarm", Style::Regular),
+        (Pack::Bootstrap, "alarm", Style::Filled),
+        (Pack::Heroicons, "academic-cap", Style::Filled),
+        (Pack::Lucide, "house", Style::Regular),
+    ];
+    let glyphs: Vec<Glyph> = samples
+        .into_iter()
+        .filter_map(|(pack, name, style)| try_icon(pack, name, style, Size::Regular).ok())
+        .map(|icon| Glyph {
+            family: icon.family().into(),
+            text: glyph_string(icon),
+        })
+        .collect();
+
+    let demo = IconDemo::new()?;
+    demo.set_glyphs(ModelRc::new(VecModel::from(glyphs)));
+    demo.run()
+}
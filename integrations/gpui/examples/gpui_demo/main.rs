@@ -0,0 +1,46 @@
+use gpui::{
+    App, Application, Bounds, Context, Window, WindowBounds, WindowOptions, div, prelude::*, px,
+    rgb, size,
+};
+use iconflow::{Pack, Size, Style};
+
+struct IconDemo;
+
+impl Render for IconDemo {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let samples = [
+            (Pack::Bootstrap, "alarm", Style::Regular),
+            (Pack::Bootstrap, "alarm", Style::Filled),
+            (Pack::Heroicons, "academic-cap", Style::Filled),
+            (Pack::Lucide, "not-an-icon", Style::Regular),
+        ];
+
+        div()
+            .flex()
+            .gap_4()
+            .size_full()
+            .justify_center()
+            .items_center()
+            .bg(rgb(0x202020))
+            .text_color(rgb(0xffffff))
+            .children(samples.into_iter().map(|(pack, name, style)| {
+                iconflow_gpui::icon(pack, name, style, Size::Regular).text_size(px(48.0))
+            }))
+    }
+}
+
+fn main() {
+    Application::new().run(|cx: &mut App| {
+        iconflow_gpui::register_fonts(cx).expect("failed to register icon fonts");
+        let bounds = Bounds::centered(None, size(px(480.0), px(240.0)), cx);
+        cx.open_window(
+            WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(bounds)),
+                ..Default::default()
+            },
+            |_, cx| cx.new(|_| IconDemo),
+        )
+        .expect("failed to open window");
+        cx.activate(true);
+    });
+}
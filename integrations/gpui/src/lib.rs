@@ -0,0 +1,51 @@
+//! Font registration and icon elements for gpui applications.
+//!
+//! Enable iconflow's pack features (or this crate's `all-packs`) to choose the embedded fonts.
+
+use gpui::{App, Div, ParentElement, Styled, div};
+use iconflow::{Pack, Size, Style, fonts, try_icon};
+use std::borrow::Cow;
+
+/// Glyph shown by [`icon`] when the icon or its variant is unavailable.
+pub const PLACEHOLDER: &str = "\u{FFFD}";
+
+/// Bytes of every embedded font, in the form `gpui::TextSystem::add_fonts` takes.
+pub fn font_sources() -> Vec<Cow<'static, [u8]>> {
+    fonts()
+        .iter()
+        .map(|asset| Cow::Borrowed(asset.bytes))
+        .collect()
+}
+
+/// Registers every embedded font with the app's text system.
+///
+/// Call it once at startup, before any window renders an [`icon`].
+pub fn register_fonts(cx: &App) -> gpui::Result<()> {
+    cx.text_system().add_fonts(font_sources())
+}
+
+/// A `div` showing the glyph in its icon font; style it further like any other element.
+///
+/// When the icon cannot be resolved the `div` shows [`PLACEHOLDER`] in the inherited font
+/// instead, so a typo in `name` never breaks layout.
+pub fn icon(pack: Pack, name: &str, style: Style, size: Size) -> Div {
+    match try_icon(pack, name, style, size) {
+        Ok(icon) => div().font_family(icon.family).child(icon.glyph_str()),
+        Err(_) => div().child(PLACEHOLDER),
+    }
+}
+
+#[cfg(all(test, feature = "pack-bootstrap"))]
+mod tests {
+    use super::font_sources;
+    use iconflow::fonts;
+
+    #[test]
+    fn font_sources_cover_every_font() {
+        let sources = font_sources();
+        assert_eq!(sources.len(), fonts().len());
+        for (source, asset) in sources.iter().zip(fonts()) {
+            assert_eq!(source.as_ptr(), asset.bytes.as_ptr());
+        }
+    }
+}
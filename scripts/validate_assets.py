@@ -30,7 +30,23 @@ def load_map(path: Path) -> dict:
     return json.loads(path.read_text(encoding="utf-8"))
 
 
-def assert_no_surrogates(codepoint: int, path: Path, context: str) -> None:
+def parse_codepoint(value: int | str) -> int:
+    """Accepts the same forms as `cargo xtask gen`: int, "U+E001", "0xe001", "\\ue001", or one char."""
+    if isinstance(value, int):
+        return value
+    if len(value) == 1:
+        return ord(value)
+    for prefix in ("U+", "u+", "0x", "0X", "\\u{", "\\u"):
+        if value.startswith(prefix):
+            digits = value[len(prefix):]
+            if prefix == "\\u{":
+                digits = digits.removesuffix("}")
+            return int(digits, 16)
+    raise ValueError(f"Invalid codepoint: {value!r}")
+
+
+def assert_no_surrogates(value: int | str, path: Path, context: str) -> None:
+    codepoint = parse_codepoint(value)
     if SURROGATE_MIN <= codepoint <= SURROGATE_MAX:
         raise ValueError(f"{path}: surrogate codepoint in {context}: {hex(codepoint)}")
 
@@ -0,0 +1,116 @@
+use iconflow::{IconError, Pack, Size, list, search_all, sizes, styles, try_icon};
+use std::env;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["search", query] => search(query),
+        ["list", pack] => list_pack(pack),
+        ["show", target] => show(target),
+        _ => {
+            print_usage();
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_usage() {
+    eprintln!("Usage:");
+    eprintln!("  iconflow search <query>");
+    eprintln!("  iconflow list <pack>");
+    eprintln!("  iconflow show <pack:name>");
+}
+
+fn parse_pack(id: &str) -> Result<Pack, String> {
+    Pack::ALL
+        .iter()
+        .copied()
+        .find(|pack| pack.id() == id)
+        .ok_or_else(|| {
+            let enabled: Vec<&str> = Pack::ALL.iter().map(|pack| pack.id()).collect();
+            format!("unknown or disabled pack '{id}' (enabled: {enabled:?})")
+        })
+}
+
+fn search(query: &str) -> Result<(), String> {
+    for (pack, name) in search_all(Pack::ALL, query) {
+        println!("{}:{name}", pack.id());
+    }
+    Ok(())
+}
+
+fn list_pack(id: &str) -> Result<(), String> {
+    for name in list(parse_pack(id)?) {
+        println!("{name}");
+    }
+    Ok(())
+}
+
+fn show(target: &str) -> Result<(), String> {
+    let (id, name) = target
+        .split_once(':')
+        .ok_or_else(|| format!("expected <pack:name>, got '{target}'"))?;
+    let pack = parse_pack(id)?;
+
+    for style in styles(pack) {
+        for size in sizes(pack) {
+            match try_icon(pack, name, style, size) {
+                Ok(icon) => {
+                    let glyph = char::from_u32(icon.codepoint).unwrap_or('?');
+                    println!(
+                        "{}/{}\tU+{:04X}\t{}\t{glyph}",
+                        style.name(),
+                        size_label(size),
+                        icon.codepoint,
+                        icon.family
+                    );
+                }
+                Err(IconError::VariantUnavailable { .. }) => {}
+                Err(err) => return Err(describe(&err)),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `Regular` for named sizes, `16px` for custom ones.
+fn size_label(size: Size) -> String {
+    match size {
+        Size::Custom(px) => format!("{px}px"),
+        named => format!("{named:?}"),
+    }
+}
+
+fn describe(err: &IconError) -> String {
+    match err {
+        IconError::PackDisabled { pack } => format!("pack '{pack}' is not enabled in this build"),
+        IconError::IconNotFound { pack, name } => format!("no icon '{name}' in pack '{pack}'"),
+        IconError::VariantUnavailable {
+            pack,
+            name,
+            requested: (style, size),
+            ..
+        } => format!(
+            "icon '{name}' in pack '{pack}' has no {}/{} variant",
+            style.name(),
+            size_label(*size)
+        ),
+        IconError::InvalidCodepoint {
+            pack,
+            name,
+            codepoint,
+        } => format!("icon '{name}' in pack '{pack}' maps invalid codepoint U+{codepoint:04X}"),
+        _ => "icon lookup failed".to_string(),
+    }
+}
@@ -1,22 +1,737 @@
-use crate::core::{FontAsset, IconError, IconRef, Size, Style};
+use crate::core::{
+    FontAsset, IconError, IconLookupError, IconRef, PackKind, Resolution, Size, Style, VariantKey,
+};
 use crate::generated::Pack;
+use std::borrow::Cow;
+use std::cmp::Ordering;
 
+/// Fonts of every enabled pack, ordered by pack id and then by family name.
+///
+/// The order is stable across builds, so registering fonts in this order is reproducible.
+///
+/// Entries are distinct `(family, bytes)` pairs, but two families may embed byte-identical files;
+/// use [`FontAsset::same_bytes`] to skip duplicates when building atlases.
 pub fn fonts() -> &'static [FontAsset] {
     crate::generated::fonts()
 }
 
+/// [`fonts`] with one asset per family, keeping the first in [`fonts`] order.
+///
+/// For renderers such as egui that must register each family exactly once; packs that declare
+/// the same family name would otherwise be registered twice.
+pub fn unique_fonts() -> Vec<FontAsset> {
+    unique_by_family(fonts())
+}
+
+fn unique_by_family(fonts: &[FontAsset]) -> Vec<FontAsset> {
+    let mut seen = std::collections::HashSet::new();
+    fonts
+        .iter()
+        .copied()
+        .filter(|font| seen.insert(font.family))
+        .collect()
+}
+
 pub fn list(pack: Pack) -> &'static [&'static str] {
     crate::generated::list(pack)
 }
 
+/// Iterator over the names of `pack`, in [`list`] order.
+pub fn list_iter(pack: Pack) -> impl ExactSizeIterator<Item = &'static str> + Clone {
+    list(pack).iter().copied()
+}
+
+/// Human-readable name of `pack` for UIs, e.g. `"Fluent UI"` for `Pack::Fluentui`.
+///
+/// Comes from the map's `display_name`, defaulting to the title-cased pack id.
+pub fn display_name(pack: Pack) -> &'static str {
+    pack.display_name()
+}
+
+/// Whether `pack` holds UI icons or brand logos, from the map's `kind` (default `ui`).
+pub fn pack_kind(pack: Pack) -> PackKind {
+    pack.kind()
+}
+
+/// Enabled packs of `kind`, in [`Pack::ALL`] order.
+pub fn packs_of_kind(kind: PackKind) -> Vec<Pack> {
+    Pack::ALL
+        .iter()
+        .copied()
+        .filter(|&pack| pack_kind(pack) == kind)
+        .collect()
+}
+
+/// The `(variant, font)` pairs of `pack`, so apps can register only the fonts they use.
+///
+/// Entries follow the map's variant order; a font shared by several variants appears once
+/// per variant.
+pub fn variant_fonts(pack: Pack) -> &'static [(VariantKey, FontAsset)] {
+    crate::generated::variant_fonts(pack)
+}
+
+/// Fingerprint of `pack`'s families, icon names and codepoints, computed by `xtask gen`.
+///
+/// Stable across builds and runs; it changes only when the pack's map does, so caches of
+/// derived artifacts (atlases, sprite sheets) can store it and rebuild on a mismatch.
+pub fn content_hash(pack: Pack) -> u64 {
+    crate::generated::content_hash(pack)
+}
+
+/// Lowest and highest codepoint `pack` maps across its enabled variants.
+///
+/// Useful for sizing PUA allocations in atlases and spotting overlaps between packs; `None`
+/// when the pack maps no codepoints in this build.
+pub fn codepoint_range(pack: Pack) -> Option<(u32, u32)> {
+    crate::generated::variant_codepoint_ranges(pack)
+        .iter()
+        .map(|&(_, min, max)| (min, max))
+        .reduce(|(min, max), (lo, hi)| (min.min(lo), max.max(hi)))
+}
+
+/// Name of the icon drawn by `codepoint` in the `(style, size)` variant of `pack`.
+///
+/// Binary-searches the generated `ICON_BY_CODEPOINT` table. When several names share a glyph,
+/// any one of them may be returned.
+pub fn name_for_codepoint(
+    pack: Pack,
+    style: Style,
+    size: Size,
+    codepoint: u32,
+) -> Option<&'static str> {
+    let key = VariantKey { style, size };
+    let table = crate::generated::icon_by_codepoint(pack);
+    table
+        .binary_search_by(|&(k, cp, _)| (k, cp).cmp(&(key, codepoint)))
+        .ok()
+        .map(|index| table[index].2)
+}
+
+/// `(name, codepoint)` of every icon `pack` maps in the `(style, size)` variant, by codepoint.
+///
+/// Icons without that variant are skipped. Meant for glyph browsers and font debugging; use
+/// [`list`] for alphabetical order.
+pub fn list_by_codepoint(pack: Pack, style: Style, size: Size) -> Vec<(&'static str, u32)> {
+    let key = VariantKey { style, size };
+    let table = crate::generated::icon_by_codepoint(pack);
+    let start = table.partition_point(|&(k, _, _)| k < key);
+    table[start..]
+        .iter()
+        .take_while(|&&(k, _, _)| k == key)
+        .map(|&(_, codepoint, name)| (name, codepoint))
+        .collect()
+}
+
+/// Styles `pack` provides in this build, in the map's `style_order` when it declares one.
+///
+/// Styles missing from `style_order` follow the listed ones in sorted order, so UI style
+/// toggles can simply iterate the result. The order is computed by `xtask gen`; see
+/// [`styles_static`] for the borrowed slice.
+pub fn styles(pack: Pack) -> Vec<Style> {
+    styles_static(pack).to_vec()
+}
+
+/// [`styles`] as a generated slice, for callers that cannot allocate.
+pub fn styles_static(pack: Pack) -> &'static [Style] {
+    crate::generated::styles(pack)
+}
+
+/// Sizes `pack` provides in this build, sorted.
+pub fn sizes(pack: Pack) -> Vec<Size> {
+    sizes_static(pack).to_vec()
+}
+
+/// [`sizes`] as a generated slice, for callers that cannot allocate.
+pub fn sizes_static(pack: Pack) -> &'static [Size] {
+    crate::generated::sizes(pack)
+}
+
+/// Next larger size `pack` provides in `style` after `current`, for a picker's "+" button.
+///
+/// Sizes step in [`Size`] order (`Tiny` < `Mini` < `Regular` < `Large` < custom sizes by
+/// pixels); `current` need not be supported itself. `None` at the largest size.
+pub fn next_size(pack: Pack, style: Style, current: Size) -> Option<Size> {
+    style_sizes(pack, style)
+        .into_iter()
+        .find(|&size| size > current)
+}
+
+/// Next smaller size `pack` provides in `style` before `current`; see [`next_size`].
+pub fn prev_size(pack: Pack, style: Style, current: Size) -> Option<Size> {
+    style_sizes(pack, style)
+        .into_iter()
+        .rev()
+        .find(|&size| size < current)
+}
+
+fn style_sizes(pack: Pack, style: Style) -> Vec<Size> {
+    let mut sizes: Vec<Size> = variant_fonts(pack)
+        .iter()
+        .filter(|(key, _)| key.style == style)
+        .map(|(key, _)| key.size)
+        .collect();
+    sizes.sort_unstable();
+    sizes.dedup();
+    sizes
+}
+
+/// Total size of the font files `pack` embeds with the current feature set.
+pub fn embedded_font_bytes(pack: Pack) -> usize {
+    crate::generated::pack_fonts(pack)
+        .iter()
+        .map(|asset| asset.bytes.len())
+        .sum()
+}
+
+/// Total size of the font files embedded by every enabled pack.
+pub fn total_embedded_font_bytes() -> usize {
+    fonts().iter().map(|asset| asset.bytes.len()).sum()
+}
+
+/// Names of `pack` that exist in the `(style, size)` variant, in sorted order.
+///
+/// Useful for pickers already scoped to one style and size; exact-size matching follows
+/// [`try_icon`].
+pub fn list_in_variant(pack: Pack, style: Style, size: Size) -> Vec<&'static str> {
+    list(pack)
+        .iter()
+        .copied()
+        .filter(|name| {
+            crate::generated::icon_available(pack, name)
+                .is_some_and(|available| available.contains(&(style, size)))
+        })
+        .collect()
+}
+
+/// [`IconRef`] of every icon `pack` resolves at `(style, size)`, in [`list`] order.
+///
+/// Icons without that variant are skipped rather than reported, so the result can pre-warm an
+/// atlas directly.
+pub fn list_resolved(pack: Pack, style: Style, size: Size) -> Vec<IconRef> {
+    list(pack)
+        .iter()
+        .filter_map(|&name| try_icon(pack, name, style, size).ok())
+        .collect()
+}
+
+/// Number of icons across every enabled pack; `0` when no pack feature is enabled.
+pub fn total_icon_count() -> usize {
+    Pack::ALL.iter().map(|&pack| list(pack).len()).sum()
+}
+
+/// Resolves `name` in `pack` at the requested variant.
+///
+/// Lookups are punctuation-insensitive: surrounding whitespace is ignored and `_` matches `-`,
+/// so `"arrow_left"` resolves the canonical `"arrow-left"`.
+///
+/// Sizes match exactly, including `Size::Custom(px)`; use [`IconError::suggested_size`] to find
+/// the nearest custom size a pack ships.
 pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<IconRef, IconError> {
-    crate::generated::try_icon(pack, name, style, size)
+    crate::generated::try_icon_ref(pack, &canonical_name(name), style, size)
+        .map_err(IconError::from)
+}
+
+/// [`try_icon`] that substitutes another variant when `(style, size)` is unavailable.
+///
+/// Candidates, in order: the [`suggested_size`](IconError::suggested_size) of a custom size,
+/// the same style in any size, `Style::Regular` in the requested size, then the first available
+/// variant. Other errors are returned unchanged.
+pub fn resolve_with_fallback(
+    pack: Pack,
+    name: &str,
+    style: Style,
+    size: Size,
+) -> Result<Resolution, IconError> {
+    let err = match try_icon(pack, name, style, size) {
+        Ok(icon) => {
+            return Ok(Resolution {
+                icon,
+                used_style: style,
+                used_size: size,
+                exact: true,
+            });
+        }
+        Err(err) => err,
+    };
+    let IconError::VariantUnavailable { available, .. } = &err else {
+        return Err(err);
+    };
+    let fallback = err
+        .suggested_size()
+        .map(|suggested| (style, suggested))
+        .or_else(|| available.iter().copied().find(|&(used, _)| used == style))
+        .or_else(|| {
+            available
+                .iter()
+                .copied()
+                .find(|&variant| variant == (Style::Regular, size))
+        })
+        .or_else(|| available.first().copied());
+    let Some((used_style, used_size)) = fallback else {
+        return Err(err);
+    };
+    let icon = try_icon(pack, name, used_style, used_size)?;
+    Ok(Resolution {
+        icon,
+        used_style,
+        used_size,
+        exact: false,
+    })
+}
+
+/// [`try_icon`] that never fails: any [`IconError`] yields `placeholder` instead.
+///
+/// The placeholder ref has an empty `family`, meaning "draw `placeholder` with the surrounding
+/// text font" rather than any pack font, so galleries always have something to render.
+pub fn icon_or_placeholder(
+    pack: Pack,
+    name: &str,
+    style: Style,
+    size: Size,
+    placeholder: char,
+) -> IconRef {
+    try_icon(pack, name, style, size).unwrap_or(IconRef {
+        family: "",
+        codepoint: u32::from(placeholder),
+    })
+}
+
+/// [`try_icon`] for hot probing loops: the error borrows `name` instead of copying it.
+///
+/// A miss allocates nothing unless `name` contains `_`, which needs a canonicalized copy for the
+/// lookup. The error then reports the trimmed input rather than the canonical name.
+pub fn try_icon_ref(
+    pack: Pack,
+    name: &str,
+    style: Style,
+    size: Size,
+) -> Result<IconRef, IconLookupError<'_>> {
+    match canonical_name(name) {
+        Cow::Borrowed(canonical) => crate::generated::try_icon_ref(pack, canonical, style, size),
+        Cow::Owned(canonical) => crate::generated::try_icon_ref(pack, &canonical, style, size)
+            .map_err(|err| err.with_name(name.trim())),
+    }
+}
+
+/// Shared tail of the generated per-pack lookups: checks availability, then the glyph data.
+///
+/// A variant listed as available but missing its family or codepoint is reported as unavailable
+/// rather than panicking; a codepoint that is not a `char` yields `InvalidCodepoint`.
+#[doc(hidden)]
+pub fn resolve_icon<'a>(
+    pack: &'static str,
+    name: &'a str,
+    style: Style,
+    size: Size,
+    available: Option<&'static [(Style, Size)]>,
+    family: Option<&'static str>,
+    codepoint: Option<u32>,
+) -> Result<IconRef, IconLookupError<'a>> {
+    let Some(available) = available else {
+        return Err(IconLookupError::IconNotFound { pack, name });
+    };
+    let unavailable = IconLookupError::VariantUnavailable {
+        pack,
+        name,
+        requested: (style, size),
+        available,
+    };
+    if !available.contains(&(style, size)) {
+        return Err(unavailable);
+    }
+    let (Some(family), Some(codepoint)) = (family, codepoint) else {
+        return Err(unavailable);
+    };
+    if char::from_u32(codepoint).is_none() {
+        return Err(IconLookupError::InvalidCodepoint {
+            pack,
+            name,
+            codepoint,
+        });
+    }
+    Ok(IconRef { family, codepoint })
+}
+
+/// Resolves `name` in `pack` ignoring ASCII and Unicode letter case.
+///
+/// Names are matched by their lowercase form. If two names in a pack differ only by case, the
+/// one that sorts first in canonical (byte) order wins.
+pub fn try_icon_ci(pack: Pack, name: &str, style: Style, size: Size) -> Result<IconRef, IconError> {
+    let lowercase = canonical_name(name).to_lowercase();
+    match crate::generated::canonical_name_ci(pack, &lowercase) {
+        Some(canonical) => try_icon(pack, canonical, style, size),
+        None => try_icon(pack, name, style, size),
+    }
+}
+
+/// Canonical RGBA brand color of `name`, for logo packs such as Devicon.
+///
+/// Returns `None` for unknown names and for icons without a brand color.
+pub fn brand_color(pack: Pack, name: &str) -> Option<[u8; 4]> {
+    crate::generated::brand_color(pack, &canonical_name(name))
+}
+
+/// Stable numeric id of `name` in `pack`, for compact persistence.
+///
+/// The id is the 32-bit FNV-1a hash of the canonical name, so it depends only on the name: it
+/// survives icons being added, removed or reordered, and the generator rejects packs where two
+/// names would share an id. Ids are pack-local, like names.
+pub fn id_of(pack: Pack, name: &str) -> Option<u32> {
+    let name = canonical_name(name);
+    list(pack)
+        .binary_search(&name.as_ref())
+        .ok()
+        .map(|_| icon_id(&name))
+}
+
+/// Name of the icon whose [`id_of`] is `id`, if `pack` has one.
+pub fn icon_by_id(pack: Pack, id: u32) -> Option<&'static str> {
+    crate::generated::icon_by_id(pack, id)
+}
+
+/// Must match `icon_id` in the xtask generator.
+fn icon_id(name: &str) -> u32 {
+    name.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Human-readable accessible name of `name`, e.g. `"Arrow Left"` for `"arrow-left"`.
+///
+/// Uses the map's `label` when present and the title-cased name otherwise. `None` for unknown names.
+pub fn aria_label(pack: Pack, name: &str) -> Option<&'static str> {
+    crate::generated::aria_label(pack, &canonical_name(name))
+}
+
+/// Whether `name` is directional and should be flipped horizontally in right-to-left layouts.
+///
+/// Unknown names report `false`.
+pub fn rtl_mirror(pack: Pack, name: &str) -> bool {
+    crate::generated::rtl_mirror(pack, &canonical_name(name))
+}
+
+fn canonical_name(name: &str) -> Cow<'_, str> {
+    let trimmed = name.trim();
+    if trimmed.contains('_') {
+        Cow::Owned(trimmed.replace('_', "-"))
+    } else {
+        Cow::Borrowed(trimmed)
+    }
+}
+
+/// Returns up to `limit` names of `pack` starting with `prefix`, in sorted order.
+///
+/// Names are kept sorted, so the matching range is found by binary search.
+pub fn autocomplete(pack: Pack, prefix: &str, limit: usize) -> Vec<&'static str> {
+    let names = list(pack);
+    let start = names.partition_point(|name| *name < prefix);
+    names[start..]
+        .iter()
+        .take_while(|name| name.starts_with(prefix))
+        .take(limit)
+        .copied()
+        .collect()
+}
+
+/// Returns the names of `pack` matching a glob `pattern`, in sorted order.
+///
+/// `*` matches any run of characters (including none) and `?` exactly one character; every
+/// other character matches itself, so `"arrow-*-circle"` finds `arrow-down-circle`. The whole
+/// name must match; there is no escaping or character classes.
+pub fn search_glob(pack: Pack, pattern: &str) -> Vec<&'static str> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    list(pack)
+        .iter()
+        .copied()
+        .filter(|name| glob_match(&pattern, name))
+        .collect()
+}
+
+/// Greedy wildcard matching that backtracks only to the most recent `*`.
+fn glob_match(pattern: &[char], name: &str) -> bool {
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns up to `len` names of `pack` starting at `offset`, clamped to the list bounds.
+pub fn list_page(pack: Pack, offset: usize, len: usize) -> &'static [&'static str] {
+    let names = list(pack);
+    let start = offset.min(names.len());
+    let end = start.saturating_add(len).min(names.len());
+    &names[start..end]
+}
+
+/// Splits the sorted names of `pack` into contiguous sections keyed by their first character.
+///
+/// Letters are uppercased (`'A'`) and every name starting with a digit falls under `'#'`.
+pub fn group_by_initial(pack: Pack) -> Vec<(char, &'static [&'static str])> {
+    let names = list(pack);
+    let mut groups = Vec::new();
+    let mut start = 0;
+    while start < names.len() {
+        let initial = name_initial(names[start]);
+        let len = names[start..]
+            .iter()
+            .take_while(|name| name_initial(name) == initial)
+            .count();
+        groups.push((initial, &names[start..start + len]));
+        start += len;
+    }
+    groups
+}
+
+fn name_initial(name: &str) -> char {
+    match name.chars().next() {
+        Some(ch) if ch.is_ascii_digit() => '#',
+        Some(ch) => ch.to_ascii_uppercase(),
+        None => '#',
+    }
+}
+
+/// Reusable `try_icon` arguments: a pack with default style and size.
+///
+/// The request is `Copy`, so a per-call override is just another builder call:
+/// `request.style(Style::Filled).resolve("alarm")`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct IconRequest {
+    pack: Pack,
+    style: Style,
+    size: Size,
+}
+
+impl IconRequest {
+    /// Starts a request for `pack` at `Style::Regular` / `Size::Regular`.
+    pub fn new(pack: Pack) -> Self {
+        Self {
+            pack,
+            style: Style::default(),
+            size: Size::default(),
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Resolves `name` with the captured pack, style and size.
+    pub fn resolve(&self, name: &str) -> Result<IconRef, IconError> {
+        try_icon(self.pack, name, self.style, self.size)
+    }
+}
+
+/// Per-pack lookup of the `(style, size)` pairs an icon name is available in.
+type IconAvailableFn = fn(&str) -> Option<&'static [(Style, Size)]>;
+
+/// One pack's lookup functions, captured once by [`Pack::resolver`].
+///
+/// [`try_icon`] dispatches on the pack for every call; a resolver skips that `match`, which
+/// helps loops that resolve many icons of the same pack. Results are identical to [`try_icon`].
+#[derive(Clone, Copy, Debug)]
+pub struct PackResolver {
+    pub(crate) pack: Pack,
+    pub(crate) pack_id: &'static str,
+    pub(crate) icon_available: IconAvailableFn,
+    pub(crate) variant_family: fn(Style, Size) -> Option<&'static str>,
+    pub(crate) icon_codepoint: fn(&str, VariantKey) -> Option<u32>,
+}
+
+impl PackResolver {
+    /// The pack this resolver was created for.
+    pub fn pack(&self) -> Pack {
+        self.pack
+    }
+
+    /// [`try_icon`] for the captured pack.
+    pub fn icon(&self, name: &str, style: Style, size: Size) -> Result<IconRef, IconError> {
+        let name = canonical_name(name);
+        resolve_icon(
+            self.pack_id,
+            &name,
+            style,
+            size,
+            (self.icon_available)(&name),
+            (self.variant_family)(style, size),
+            (self.icon_codepoint)(&name, VariantKey { style, size }),
+        )
+        .map_err(IconError::from)
+    }
+}
+
+/// Walks every icon of `pack`, resolving each name lazily at `style`/`size`.
+pub fn iter_icons(
+    pack: Pack,
+    style: Style,
+    size: Size,
+) -> impl Iterator<Item = (&'static str, Result<IconRef, IconError>)> {
+    list(pack)
+        .iter()
+        .map(move |&name| (name, try_icon(pack, name, style, size)))
+}
+
+/// Enabled packs that have an icon called `name`, in [`Pack::ALL`] order.
+///
+/// `name` is normalized like in [`try_icon`], so `"arrow_left"` finds `arrow-left`.
+pub fn packs_with_icon(name: &str) -> Vec<Pack> {
+    let name = canonical_name(name);
+    Pack::ALL
+        .iter()
+        .copied()
+        .filter(|&pack| crate::generated::icon_available(pack, &name).is_some())
+        .collect()
+}
+
+/// Returns the icon names present in every pack of `packs`, in sorted order.
+pub fn common_icons(packs: &[Pack]) -> Vec<&'static str> {
+    let Some((first, rest)) = packs.split_first() else {
+        return Vec::new();
+    };
+
+    let mut common = list(*first).to_vec();
+    for pack in rest {
+        let other = list(*pack);
+        let mut merged = Vec::with_capacity(common.len().min(other.len()));
+        let (mut left, mut right) = (0, 0);
+        while left < common.len() && right < other.len() {
+            match common[left].cmp(other[right]) {
+                Ordering::Less => left += 1,
+                Ordering::Greater => right += 1,
+                Ordering::Equal => {
+                    merged.push(common[left]);
+                    left += 1;
+                    right += 1;
+                }
+            }
+        }
+        common = merged;
+    }
+    common
+}
+
+/// Every icon of `packs` as `(pack, name)`, in the order of `packs` and then by name.
+///
+/// Meant for pickers whose enabled packs change at runtime; pass [`Pack::ALL`] for all of them.
+pub fn list_many(packs: &[Pack]) -> Vec<(Pack, &'static str)> {
+    packs
+        .iter()
+        .flat_map(|&pack| list(pack).iter().map(move |&name| (pack, name)))
+        .collect()
+}
+
+/// Icons of `packs` whose name contains `query`, ignoring case, ordered like [`list_many`].
+pub fn search_all(packs: &[Pack], query: &str) -> Vec<(Pack, &'static str)> {
+    let query = query.to_lowercase();
+    let mut found = list_many(packs);
+    found.retain(|(_, name)| name.contains(&query));
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_icon, unique_by_family};
+    use crate::core::{FontAsset, IconError, IconLookupError, IconRef, Size, Style};
+
+    const AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+
+    fn resolve(codepoint: Option<u32>) -> Result<IconRef, IconLookupError<'static>> {
+        resolve_icon(
+            "demo",
+            "alarm",
+            Style::Regular,
+            Size::Regular,
+            Some(AVAILABLE),
+            Some("Demo Regular"),
+            codepoint,
+        )
+    }
+
+    #[test]
+    fn resolve_icon_rejects_invalid_codepoints() {
+        assert_eq!(
+            resolve(Some(0xE001)),
+            Ok(IconRef {
+                family: "Demo Regular",
+                codepoint: 0xE001,
+            })
+        );
+        for codepoint in [0xD800, 0x11_0000] {
+            let err = resolve(Some(codepoint)).unwrap_err();
+            assert_eq!(
+                err,
+                IconLookupError::InvalidCodepoint {
+                    pack: "demo",
+                    name: "alarm",
+                    codepoint,
+                }
+            );
+            assert!(matches!(
+                IconError::from(err),
+                IconError::InvalidCodepoint { codepoint: c, .. } if c == codepoint
+            ));
+        }
+    }
+
+    #[test]
+    fn resolve_icon_reports_missing_glyph_data_as_unavailable() {
+        assert!(matches!(
+            resolve(None),
+            Err(IconLookupError::VariantUnavailable { .. })
+        ));
+    }
+
+    #[test]
+    fn unique_fonts_keep_one_asset_per_shared_family() {
+        let asset = |family, bytes| FontAsset { family, bytes };
+        let fonts = [
+            asset("Devicon Regular", b"devicon"),
+            asset("Shared Regular", b"first pack"),
+            asset("Shared Regular", b"second pack"),
+            asset("Lobe Regular", b"lobe"),
+        ];
+        let unique = unique_by_family(&fonts);
+        assert_eq!(unique.len(), fonts.len() - 1);
+        assert_eq!(unique[1], fonts[1]);
+        assert_eq!(unique[2], fonts[3]);
+    }
 }
 
 #[cfg(all(test, feature = "pack-bootstrap"))]
 mod tests_bootstrap {
-    use super::{list, try_icon};
-    use crate::core::{IconError, Size, Style};
+    use super::{
+        IconRequest, PackResolver, aria_label, autocomplete, codepoint_range, display_name, fonts,
+        group_by_initial, icon_by_id, icon_or_placeholder, id_of, iter_icons, list,
+        list_by_codepoint, list_in_variant, list_iter, list_page, list_resolved,
+        resolve_with_fallback, rtl_mirror, search_glob, styles, total_icon_count, try_icon,
+        try_icon_ci, variant_fonts,
+    };
+    use crate::core::{IconError, IconRef, Size, Style};
     use crate::generated::Pack;
 
     #[test]
@@ -25,12 +740,312 @@ mod tests_bootstrap {
         assert!(names.contains(&"alarm"));
     }
 
+    #[test]
+    fn typed_icon_try_icon_matches_name_lookup() {
+        use crate::generated::bootstrap::Icon;
+
+        assert_eq!(
+            Icon::Alarm.try_icon(Style::Regular, Size::Regular),
+            try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular)
+        );
+        assert!(Icon::Alarm.try_icon(Style::Regular, Size::Regular).is_ok());
+    }
+
+    #[test]
+    fn list_by_codepoint_is_sorted_ascending() {
+        let glyphs = list_by_codepoint(Pack::Bootstrap, Style::Regular, Size::Regular);
+        assert!(glyphs.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(glyphs.len() <= list(Pack::Bootstrap).len());
+        let alarm = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        assert!(glyphs.contains(&("alarm", alarm.codepoint)));
+        assert!(list_by_codepoint(Pack::Bootstrap, Style::Thin, Size::Regular).is_empty());
+    }
+
+    #[test]
+    fn list_resolved_skips_unavailable_icons() {
+        for (style, family) in [
+            (Style::Regular, "Bootstrap Regular"),
+            (Style::Filled, "Bootstrap Filled"),
+        ] {
+            let icons = list_resolved(Pack::Bootstrap, style, Size::Regular);
+            assert_eq!(
+                icons.len(),
+                list_in_variant(Pack::Bootstrap, style, Size::Regular).len()
+            );
+            assert!(icons.iter().all(|icon| icon.family == family));
+        }
+        assert!(list_resolved(Pack::Bootstrap, Style::Thin, Size::Regular).is_empty());
+    }
+
+    #[test]
+    fn typed_icon_converts_from_its_name() {
+        use crate::generated::bootstrap::Icon;
+
+        assert_eq!(Icon::try_from("alarm"), Ok(Icon::Alarm));
+        let name: &str = Icon::Alarm.into();
+        assert_eq!(name, "alarm");
+        let parse = |name: &str| -> Result<IconRef, IconError> {
+            Ok(Icon::try_from(name)?.icon(Style::Regular, Size::Regular))
+        };
+        assert_eq!(parse("alarm").unwrap().family, "Bootstrap Regular");
+        assert_eq!(
+            parse("no-such-icon"),
+            Err(IconError::IconNotFound {
+                pack: "bootstrap",
+                name: "no-such-icon".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn typed_icon_round_trips_through_serde_as_its_name() {
+        use crate::generated::bootstrap::Icon;
+
+        let json = serde_json::to_string(&Icon::ArrowLeft).unwrap();
+        assert_eq!(json, "\"arrow-left\"");
+        assert_eq!(
+            serde_json::from_str::<Icon>(&json).unwrap(),
+            Icon::ArrowLeft
+        );
+        let err = serde_json::from_str::<Icon>("\"no-such-icon\"").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown bootstrap icon 'no-such-icon'")
+        );
+    }
+
+    #[test]
+    fn typed_icon_try_icon_reports_unavailable_variants() {
+        use crate::generated::bootstrap::Icon;
+
+        let err = Icon::Alarm
+            .try_icon(Style::Outline, Size::Regular)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            IconError::VariantUnavailable {
+                requested: (Style::Outline, Size::Regular),
+                ..
+            }
+        ));
+        assert_eq!(
+            Icon::Alarm.icon(Style::Regular, Size::Regular),
+            Icon::Alarm.try_icon(Style::Regular, Size::Regular).unwrap()
+        );
+    }
+
+    #[test]
+    fn search_glob_matches_star_and_question_mark() {
+        let circles = search_glob(Pack::Bootstrap, "arrow-*-circle");
+        assert!(circles.contains(&"arrow-down-circle"));
+        assert!(circles.contains(&"arrow-up-left-circle"));
+        assert!(
+            circles
+                .iter()
+                .all(|name| name.starts_with("arrow-") && name.ends_with("-circle"))
+        );
+        assert!(circles.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let digits = search_glob(Pack::Bootstrap, "?-circle");
+        assert!(digits.contains(&"0-circle") && digits.contains(&"9-circle"));
+        assert!(digits.iter().all(|name| name.len() == "0-circle".len()));
+
+        assert_eq!(search_glob(Pack::Bootstrap, "alarm"), vec!["alarm"]);
+        assert_eq!(
+            search_glob(Pack::Bootstrap, "*").len(),
+            list(Pack::Bootstrap).len()
+        );
+        assert!(search_glob(Pack::Bootstrap, "alarm?").is_empty());
+        assert!(search_glob(Pack::Bootstrap, "**arm").contains(&"alarm"));
+    }
+
+    #[test]
+    fn pack_id_is_the_module_pack_id() {
+        assert_eq!(Pack::Bootstrap.id(), crate::generated::bootstrap::PACK_ID);
+        assert_eq!(Pack::Bootstrap.id(), "bootstrap");
+    }
+
+    #[test]
+    fn list_in_variant_is_a_subset_of_list() {
+        let names = list(Pack::Bootstrap);
+        let filled = list_in_variant(Pack::Bootstrap, Style::Filled, Size::Regular);
+        assert!(!filled.is_empty());
+        assert!(filled.len() < names.len());
+        assert!(filled.iter().all(|name| names.contains(name)));
+        assert!(filled.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(
+            filled.iter().all(|name| {
+                try_icon(Pack::Bootstrap, name, Style::Filled, Size::Regular).is_ok()
+            })
+        );
+        assert!(list_in_variant(Pack::Bootstrap, Style::Thin, Size::Regular).is_empty());
+    }
+
+    #[test]
+    fn total_icon_count_sums_enabled_packs() {
+        let per_pack: usize = Pack::ALL.iter().map(|&pack| list(pack).len()).sum();
+        assert_eq!(total_icon_count(), per_pack);
+        assert!(total_icon_count() >= list(Pack::Bootstrap).len());
+    }
+
+    #[test]
+    fn font_asset_ids_are_unique() {
+        let mut ids: Vec<u64> = fonts().iter().map(|asset| asset.id()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), fonts().len());
+    }
+
+    #[test]
+    fn icon_or_placeholder_substitutes_missing_icons() {
+        let icon = icon_or_placeholder(
+            Pack::Bootstrap,
+            "no-such-icon",
+            Style::Regular,
+            Size::Regular,
+            '?',
+        );
+        assert_eq!(icon.family, "");
+        assert_eq!(icon.codepoint, u32::from('?'));
+        assert_eq!(
+            icon_or_placeholder(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular, '?'),
+            try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_with_fallback_reports_substituted_variant() {
+        let exact =
+            resolve_with_fallback(Pack::Bootstrap, "alarm", Style::Filled, Size::Regular).unwrap();
+        assert!(exact.exact);
+        assert_eq!(exact.used_style, Style::Filled);
+
+        let fallback =
+            resolve_with_fallback(Pack::Bootstrap, "alarm", Style::Outline, Size::Regular).unwrap();
+        assert!(!fallback.exact);
+        assert_eq!(
+            (fallback.used_style, fallback.used_size),
+            (Style::Regular, Size::Regular)
+        );
+        assert_eq!(
+            fallback.icon,
+            try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap()
+        );
+
+        assert!(matches!(
+            resolve_with_fallback(
+                Pack::Bootstrap,
+                "no-such-icon",
+                Style::Regular,
+                Size::Regular
+            ),
+            Err(IconError::IconNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn codepoint_range_bounds_every_mapped_codepoint() {
+        let (min, max) = codepoint_range(Pack::Bootstrap).unwrap();
+        let mut seen = Vec::new();
+        for style in [Style::Regular, Style::Filled] {
+            for (_, icon) in iter_icons(Pack::Bootstrap, style, Size::Regular) {
+                if let Ok(icon) = icon {
+                    assert!((min..=max).contains(&icon.codepoint));
+                    seen.push(icon.codepoint);
+                }
+            }
+        }
+        assert_eq!(seen.iter().min(), Some(&min));
+        assert_eq!(seen.iter().max(), Some(&max));
+    }
+
+    #[test]
+    fn styles_fall_back_to_sorted_order() {
+        assert_eq!(styles(Pack::Bootstrap), vec![Style::Regular, Style::Filled]);
+    }
+
+    #[test]
+    fn list_iter_matches_list() {
+        assert!(list_iter(Pack::Bootstrap).eq(list(Pack::Bootstrap).iter().copied()));
+        assert_eq!(
+            list_iter(Pack::Bootstrap).len(),
+            list(Pack::Bootstrap).len()
+        );
+        let short: Vec<&str> = list_iter(Pack::Bootstrap)
+            .filter(|name| name.len() <= 4)
+            .take(3)
+            .collect();
+        assert_eq!(short.len(), 3);
+    }
+
+    #[test]
+    fn variant_fonts_are_pack_fonts() {
+        let pack_fonts = crate::generated::pack_fonts(Pack::Bootstrap);
+        assert!(!variant_fonts(Pack::Bootstrap).is_empty());
+        for (_, font) in variant_fonts(Pack::Bootstrap) {
+            assert!(pack_fonts.iter().any(|asset| asset.family == font.family));
+        }
+    }
+
+    #[test]
+    fn pack_resolver_matches_try_icon() {
+        let resolver: PackResolver = Pack::Bootstrap.resolver();
+        assert_eq!(resolver.pack(), Pack::Bootstrap);
+        for name in ["alarm", "arrow_left", " bell ", "no-such-icon"] {
+            for style in [Style::Regular, Style::Filled, Style::Thin] {
+                assert_eq!(
+                    resolver.icon(name, style, Size::Regular),
+                    try_icon(Pack::Bootstrap, name, style, Size::Regular),
+                    "{name} {style:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn display_name_defaults_to_title_cased_id() {
+        assert_eq!(display_name(Pack::Bootstrap), "Bootstrap");
+    }
+
     #[test]
     fn try_icon_resolves_regular_variant() {
         let icon = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
         assert_eq!(icon.family, "Bootstrap Regular");
     }
 
+    #[test]
+    fn try_icon_accepts_snake_case_and_whitespace() {
+        let canonical =
+            try_icon(Pack::Bootstrap, "arrow-left", Style::Regular, Size::Regular).unwrap();
+        let snake = try_icon(Pack::Bootstrap, "arrow_left", Style::Regular, Size::Regular).unwrap();
+        let padded = try_icon(
+            Pack::Bootstrap,
+            " arrow-left\n",
+            Style::Regular,
+            Size::Regular,
+        )
+        .unwrap();
+        assert_eq!(snake, canonical);
+        assert_eq!(padded, canonical);
+        assert!(!list(Pack::Bootstrap).contains(&"arrow_left"));
+    }
+
+    #[test]
+    fn try_icon_ci_resolves_title_case() {
+        let icon = try_icon_ci(Pack::Bootstrap, "Alarm", Style::Regular, Size::Regular).unwrap();
+        let canonical = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        assert_eq!(icon, canonical);
+        assert!(try_icon(Pack::Bootstrap, "Alarm", Style::Regular, Size::Regular).is_err());
+    }
+
+    #[test]
+    fn try_icon_ci_reports_missing_name() {
+        let err =
+            try_icon_ci(Pack::Bootstrap, "Missing", Style::Regular, Size::Regular).unwrap_err();
+        assert!(matches!(err, IconError::IconNotFound { .. }));
+    }
+
     #[test]
     fn try_icon_reports_missing_name() {
         let err = try_icon(Pack::Bootstrap, "missing", Style::Regular, Size::Regular).unwrap_err();
@@ -62,14 +1077,193 @@ mod tests_bootstrap {
             other => panic!("Expected VariantUnavailable, got {other:?}"),
         }
     }
+
+    #[test]
+    fn iter_icons_resolves_names_in_order() {
+        let pairs: Vec<_> = iter_icons(Pack::Bootstrap, Style::Regular, Size::Regular)
+            .take(3)
+            .collect();
+        assert_eq!(pairs.len(), 3);
+        for ((name, icon), expected) in pairs.iter().zip(list(Pack::Bootstrap)) {
+            assert_eq!(name, expected);
+            assert_eq!(icon.as_ref().unwrap().family, "Bootstrap Regular");
+        }
+    }
+
+    #[test]
+    fn name_constants_resolve() {
+        use crate::packs::bootstrap::names;
+
+        assert_eq!(names::ALARM, "alarm");
+        assert!(try_icon(Pack::Bootstrap, names::ALARM, Style::Regular, Size::Regular).is_ok());
+        assert_eq!(names::ICON_0_CIRCLE, "0-circle");
+    }
+
+    #[test]
+    fn icon_request_defaults_to_regular() {
+        let request = IconRequest::new(Pack::Bootstrap);
+        assert_eq!(
+            request.resolve("alarm").unwrap(),
+            try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap()
+        );
+    }
+
+    #[test]
+    fn icon_request_applies_overrides() {
+        let request = IconRequest::new(Pack::Bootstrap).style(Style::Filled);
+        assert_eq!(request.resolve("alarm").unwrap().family, "Bootstrap Filled");
+        assert_eq!(
+            request
+                .style(Style::Regular)
+                .resolve("alarm")
+                .unwrap()
+                .family,
+            "Bootstrap Regular"
+        );
+        assert!(matches!(
+            request.size(Size::Mini).resolve("alarm"),
+            Err(IconError::VariantUnavailable { .. })
+        ));
+    }
+
+    #[test]
+    fn autocomplete_returns_sorted_prefix_matches() {
+        let matches = autocomplete(Pack::Bootstrap, "arr", 5);
+        assert_eq!(matches.len(), 5);
+        assert!(matches.iter().all(|name| name.starts_with("arr")));
+        assert!(matches.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(matches[0], "arrow-90deg-down");
+
+        assert_eq!(
+            autocomplete(Pack::Bootstrap, "", 2),
+            &list(Pack::Bootstrap)[..2]
+        );
+        assert!(autocomplete(Pack::Bootstrap, "zzz", 5).is_empty());
+    }
+
+    #[test]
+    fn icon_ids_round_trip() {
+        for &name in list(Pack::Bootstrap) {
+            let id = id_of(Pack::Bootstrap, name).unwrap();
+            assert_eq!(icon_by_id(Pack::Bootstrap, id), Some(name));
+        }
+        assert_eq!(
+            id_of(Pack::Bootstrap, "arrow_left"),
+            id_of(Pack::Bootstrap, "arrow-left")
+        );
+        assert_eq!(id_of(Pack::Bootstrap, "no-such-icon"), None);
+        assert_eq!(icon_by_id(Pack::Bootstrap, 0), None);
+    }
+
+    #[test]
+    fn aria_label_defaults_to_title_cased_name() {
+        assert_eq!(
+            aria_label(Pack::Bootstrap, "arrow-left"),
+            Some("Arrow Left")
+        );
+        assert_eq!(aria_label(Pack::Bootstrap, "0_circle"), Some("0 Circle"));
+        assert_eq!(aria_label(Pack::Bootstrap, "no-such-icon"), None);
+    }
+
+    #[test]
+    fn rtl_mirror_flags_directional_icons() {
+        assert!(rtl_mirror(Pack::Bootstrap, "arrow-left"));
+        assert!(rtl_mirror(Pack::Bootstrap, "chevron_right"));
+        assert!(!rtl_mirror(Pack::Bootstrap, "arrow-up"));
+        assert!(!rtl_mirror(Pack::Bootstrap, "alarm"));
+        assert!(!rtl_mirror(Pack::Bootstrap, "no-such-icon"));
+    }
+
+    #[test]
+    fn list_page_clamps_to_bounds() {
+        let names = list(Pack::Bootstrap);
+        assert_eq!(list_page(Pack::Bootstrap, 0, 3), &names[..3]);
+        assert_eq!(list_page(Pack::Bootstrap, 10, 5), &names[10..15]);
+        assert!(list_page(Pack::Bootstrap, 10, 0).is_empty());
+        assert_eq!(
+            list_page(Pack::Bootstrap, names.len() - 2, 10),
+            &names[names.len() - 2..]
+        );
+        assert!(list_page(Pack::Bootstrap, names.len() + 5, 10).is_empty());
+        assert_eq!(list_page(Pack::Bootstrap, 0, usize::MAX), names);
+        assert!(list_page(Pack::Bootstrap, usize::MAX, usize::MAX).is_empty());
+    }
+
+    #[test]
+    fn group_by_initial_covers_all_names_contiguously() {
+        let groups = group_by_initial(Pack::Bootstrap);
+        assert_eq!(groups[0].0, '#');
+        assert!(groups[0].1.contains(&"0-circle"));
+        assert!(
+            groups
+                .iter()
+                .any(|(initial, names)| *initial == 'A' && names.contains(&"alarm"))
+        );
+
+        let flattened: Vec<&str> = groups
+            .iter()
+            .flat_map(|(_, names)| names.iter().copied())
+            .collect();
+        assert_eq!(flattened, list(Pack::Bootstrap));
+        for pair in groups.windows(2) {
+            assert_ne!(pair[0].0, pair[1].0);
+        }
+    }
+
+    #[test]
+    fn typed_icons_cover_every_name() {
+        let names: Vec<_> = crate::packs::bootstrap::icons()
+            .map(|icon| icon.name())
+            .collect();
+        assert_eq!(names, list(Pack::Bootstrap));
+    }
 }
 
 #[cfg(all(test, feature = "pack-heroicons"))]
 mod tests_heroicons {
-    use super::{list, try_icon};
+    use super::{list, sizes, sizes_static, styles, styles_static, try_icon};
     use crate::core::{IconError, Size, Style};
     use crate::generated::Pack;
 
+    #[test]
+    fn styles_follow_declared_style_order() {
+        assert_eq!(styles(Pack::Heroicons), vec![Style::Outline, Style::Filled]);
+    }
+
+    #[test]
+    fn static_styles_and_sizes_match_vec_versions() {
+        for &pack in Pack::ALL {
+            assert_eq!(styles_static(pack), styles(pack).as_slice());
+            assert_eq!(sizes_static(pack), sizes(pack).as_slice());
+        }
+        assert!(sizes_static(Pack::Heroicons).contains(&Size::Regular));
+        assert_eq!(
+            sizes_static(Pack::Heroicons).contains(&Size::Mini),
+            cfg!(feature = "heroicons-mini")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "heroicons-tiny", feature = "heroicons-mini"))]
+    fn size_steps_walk_the_sizes_of_a_style() {
+        use super::{next_size, prev_size};
+
+        let next = |size| next_size(Pack::Heroicons, Style::Filled, size);
+        let prev = |size| prev_size(Pack::Heroicons, Style::Filled, size);
+        assert_eq!(next(Size::Tiny), Some(Size::Mini));
+        assert_eq!(next(Size::Mini), Some(Size::Regular));
+        assert_eq!(next(Size::Regular), None);
+        assert_eq!(prev(Size::Regular), Some(Size::Mini));
+        assert_eq!(prev(Size::Mini), Some(Size::Tiny));
+        assert_eq!(prev(Size::Tiny), None);
+        assert_eq!(prev(Size::Large), Some(Size::Regular));
+        assert_eq!(
+            next_size(Pack::Heroicons, Style::Outline, Size::Tiny),
+            Some(Size::Regular)
+        );
+        assert_eq!(next_size(Pack::Heroicons, Style::Thin, Size::Tiny), None);
+    }
+
     #[test]
     fn list_exposes_icon_names() {
         let names = list(Pack::Heroicons);
@@ -113,3 +1307,263 @@ mod tests_heroicons {
         }
     }
 }
+
+#[cfg(all(test, feature = "pack-devicon"))]
+mod tests_devicon {
+    use super::brand_color;
+    use crate::generated::Pack;
+
+    #[test]
+    fn brand_color_parses_map_hex() {
+        assert_eq!(
+            brand_color(Pack::Devicon, "twitter-original"),
+            Some([0x1D, 0xA1, 0xF2, 0xFF])
+        );
+        assert_eq!(
+            brand_color(Pack::Devicon, "rust_original"),
+            Some([0, 0, 0, 0xFF])
+        );
+        assert_eq!(brand_color(Pack::Devicon, "aarch64-line"), None);
+        assert_eq!(brand_color(Pack::Devicon, "no-such-icon"), None);
+    }
+}
+
+#[cfg(all(test, feature = "pack-fluentui"))]
+mod tests_fluentui {
+    use super::display_name;
+    use crate::generated::Pack;
+
+    #[test]
+    fn display_name_uses_map_override() {
+        assert_eq!(display_name(Pack::Fluentui), "Fluent UI");
+        assert_eq!(Pack::Fluentui.id(), "fluentui");
+    }
+}
+
+#[cfg(all(test, feature = "pack-bootstrap", feature = "pack-heroicons"))]
+mod tests_common {
+    use super::{
+        common_icons, embedded_font_bytes, fonts, list, list_many, search_all,
+        total_embedded_font_bytes,
+    };
+    use crate::generated::{Pack, pack_fonts};
+
+    #[test]
+    fn common_icons_intersects_packs() {
+        let common = common_icons(&[Pack::Bootstrap, Pack::Heroicons]);
+        assert!(common.contains(&"arrow-left"));
+        assert!(
+            common
+                .iter()
+                .all(|name| list(Pack::Heroicons).contains(name))
+        );
+        assert!(common.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn list_many_orders_by_pack_then_name() {
+        let packs = [Pack::Heroicons, Pack::Bootstrap];
+        let all = list_many(&packs);
+        assert_eq!(
+            all.len(),
+            list(Pack::Heroicons).len() + list(Pack::Bootstrap).len()
+        );
+        let boundary = list(Pack::Heroicons).len();
+        assert!(
+            all[..boundary]
+                .iter()
+                .all(|(pack, _)| *pack == Pack::Heroicons)
+        );
+        assert!(
+            all[boundary..]
+                .iter()
+                .all(|(pack, _)| *pack == Pack::Bootstrap)
+        );
+        assert!(all[..boundary].windows(2).all(|pair| pair[0].1 < pair[1].1));
+
+        let found = search_all(&packs, "Arrow-Left");
+        assert!(found.contains(&(Pack::Heroicons, "arrow-left")));
+        assert!(found.contains(&(Pack::Bootstrap, "arrow-left")));
+        assert!(found.iter().all(|(_, name)| name.contains("arrow-left")));
+        assert_eq!(found[0].0, Pack::Heroicons);
+        assert!(list_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn common_icons_of_no_packs_is_empty() {
+        assert!(common_icons(&[]).is_empty());
+    }
+
+    #[test]
+    fn total_embedded_font_bytes_sums_enabled_packs() {
+        assert!(embedded_font_bytes(Pack::Bootstrap) > 0);
+        let per_pack: usize = Pack::ALL
+            .iter()
+            .map(|&pack| embedded_font_bytes(pack))
+            .sum();
+        assert_eq!(total_embedded_font_bytes(), per_pack);
+    }
+
+    #[test]
+    fn fonts_are_ordered_by_pack_then_family() {
+        let families = |fonts: &[crate::FontAsset]| -> Vec<&str> {
+            fonts.iter().map(|asset| asset.family).collect()
+        };
+        let all = families(fonts());
+        let bootstrap = families(pack_fonts(Pack::Bootstrap));
+        let heroicons = families(pack_fonts(Pack::Heroicons));
+        assert!(heroicons.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let start = |pack: &[&str]| all.windows(pack.len()).position(|window| window == pack);
+        assert!(start(&bootstrap).unwrap() < start(&heroicons).unwrap());
+
+        let mut packs = Pack::ALL.to_vec();
+        packs.sort_by_key(|pack| pack.id());
+        let expected: Vec<&str> = packs
+            .iter()
+            .flat_map(|&pack| families(pack_fonts(pack)))
+            .collect();
+        assert_eq!(all, expected);
+    }
+}
+
+#[cfg(all(test, feature = "index"))]
+mod tests_index {
+    use super::{list, total_icon_count};
+    use crate::generated::{ALL_ICONS, Pack};
+
+    #[test]
+    fn all_icons_lists_every_enabled_name() {
+        assert_eq!(ALL_ICONS.len(), total_icon_count());
+        for &pack in Pack::ALL {
+            let names: Vec<&str> = ALL_ICONS
+                .iter()
+                .filter(|(owner, _)| *owner == pack)
+                .map(|&(_, name)| name)
+                .collect();
+            assert_eq!(names, list(pack));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "all-packs"))]
+mod tests_all_packs {
+    use super::{
+        content_hash, fonts, list, name_for_codepoint, pack_kind, packs_of_kind, packs_with_icon,
+        sizes_static, styles_static, total_icon_count, try_icon, unique_fonts, variant_fonts,
+    };
+    use crate::core::{PackKind, Size, Style};
+    use crate::generated::{Pack, style_order};
+
+    #[test]
+    fn all_packs_enables_every_pack() {
+        assert_eq!(Pack::ALL.len(), 14);
+        for &pack in Pack::ALL {
+            assert!(!list(pack).is_empty(), "{} has no icons", pack.id());
+            assert!(
+                !variant_fonts(pack).is_empty(),
+                "{} has no fonts",
+                pack.id()
+            );
+        }
+        let listed: usize = Pack::ALL.iter().map(|&pack| list(pack).len()).sum();
+        assert_eq!(total_icon_count(), listed);
+        assert!(fonts().len() >= Pack::ALL.len());
+        assert!(unique_fonts().len() <= fonts().len());
+    }
+
+    #[test]
+    fn codepoint_index_reverses_forward_resolution() {
+        for &pack in Pack::ALL {
+            let table = crate::generated::icon_by_codepoint(pack);
+            assert!(
+                table
+                    .windows(2)
+                    .all(|pair| (pair[0].0, pair[0].1) <= (pair[1].0, pair[1].1)),
+                "{} index is not sorted",
+                pack.id()
+            );
+            for &(key, codepoint, name) in table {
+                let icon = try_icon(pack, name, key.style, key.size).unwrap();
+                assert_eq!(icon.codepoint, codepoint, "{}/{name}", pack.id());
+                let found = name_for_codepoint(pack, key.style, key.size, codepoint).unwrap();
+                let again = try_icon(pack, found, key.style, key.size).unwrap();
+                assert_eq!(again.codepoint, codepoint, "{}/{name}", pack.id());
+            }
+        }
+        assert_eq!(
+            name_for_codepoint(Pack::Bootstrap, Style::Regular, Size::Regular, 0x41),
+            None
+        );
+    }
+
+    #[test]
+    fn content_hashes_are_set_and_distinct() {
+        let mut hashes: Vec<u64> = Pack::ALL.iter().map(|&pack| content_hash(pack)).collect();
+        assert!(hashes.iter().all(|&hash| hash != 0));
+        assert_eq!(
+            content_hash(Pack::Lucide),
+            crate::generated::lucide::CONTENT_HASH
+        );
+        hashes.sort_unstable();
+        hashes.dedup();
+        assert_eq!(hashes.len(), Pack::ALL.len());
+    }
+
+    #[test]
+    fn packs_with_icon_finds_common_names_in_several_packs() {
+        let heart = packs_with_icon("heart");
+        assert!(heart.len() > 1);
+        assert!(heart.contains(&Pack::Bootstrap) && heart.contains(&Pack::Lucide));
+        assert!(!heart.contains(&Pack::Devicon));
+        assert!(heart.iter().all(|&pack| list(pack).contains(&"heart")));
+        assert!(packs_with_icon("no-such-icon").is_empty());
+    }
+
+    #[test]
+    fn pack_kind_separates_brand_logos_from_ui_icons() {
+        assert_eq!(pack_kind(Pack::Bootstrap), PackKind::Ui);
+        assert_eq!(pack_kind(Pack::Devicon), PackKind::Brand);
+        assert_eq!(packs_of_kind(PackKind::Brand), [Pack::Devicon, Pack::Lobe]);
+        assert_eq!(
+            packs_of_kind(PackKind::Ui).len() + 2,
+            Pack::ALL.len(),
+            "every pack has exactly one kind"
+        );
+    }
+
+    #[test]
+    fn generated_styles_and_sizes_are_sorted_and_unique() {
+        for &pack in Pack::ALL {
+            let order = style_order(pack);
+            let rank = |style: &Style| {
+                let position = order.iter().position(|preferred| preferred == style);
+                (position.unwrap_or(order.len()), *style)
+            };
+            let styles = styles_static(pack);
+            assert!(
+                styles
+                    .windows(2)
+                    .all(|pair| rank(&pair[0]) < rank(&pair[1])),
+                "{} styles out of order: {styles:?}",
+                pack.id()
+            );
+            let sizes = sizes_static(pack);
+            assert!(sizes.windows(2).all(|pair| pair[0] < pair[1]));
+
+            for (key, _) in variant_fonts(pack) {
+                assert!(styles.contains(&key.style) && sizes.contains(&key.size));
+            }
+            assert!(styles.iter().all(|style| {
+                variant_fonts(pack)
+                    .iter()
+                    .any(|(key, _)| key.style == *style)
+            }));
+            assert!(
+                sizes
+                    .iter()
+                    .all(|size| { variant_fonts(pack).iter().any(|(key, _)| key.size == *size) })
+            );
+        }
+    }
+}
@@ -1,6 +1,22 @@
 use crate::core::{Size, Style};
 
+/// Why an icon lookup failed.
+///
+/// New variants may be added in minor releases, so matches outside this crate need a wildcard
+/// arm:
+///
+/// ```compile_fail
+/// fn describe(err: &iconflow::IconError) -> &'static str {
+///     match err {
+///         iconflow::IconError::PackDisabled { .. } => "pack disabled",
+///         iconflow::IconError::IconNotFound { .. } => "no such icon",
+///         iconflow::IconError::VariantUnavailable { .. } => "variant unavailable",
+///         iconflow::IconError::InvalidCodepoint { .. } => "invalid codepoint",
+///     }
+/// }
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum IconError {
     PackDisabled {
         pack: &'static str,
@@ -15,4 +31,177 @@ pub enum IconError {
         requested: (Style, Size),
         available: &'static [(Style, Size)],
     },
+    /// The pack data maps the icon to a value that is not a Unicode scalar value.
+    InvalidCodepoint {
+        pack: &'static str,
+        name: String,
+        codepoint: u32,
+    },
+}
+
+/// [`IconError`] that borrows the looked-up name instead of owning it.
+///
+/// Returned by [`try_icon_ref`](crate::try_icon_ref) so failed probes do not allocate; convert
+/// with `IconError::from` when the error has to outlive the name. Like [`IconError`], it may
+/// gain variants in minor releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IconLookupError<'a> {
+    PackDisabled {
+        pack: &'static str,
+    },
+    IconNotFound {
+        pack: &'static str,
+        name: &'a str,
+    },
+    VariantUnavailable {
+        pack: &'static str,
+        name: &'a str,
+        requested: (Style, Size),
+        available: &'static [(Style, Size)],
+    },
+    InvalidCodepoint {
+        pack: &'static str,
+        name: &'a str,
+        codepoint: u32,
+    },
+}
+
+impl IconLookupError<'_> {
+    /// Same error reported against `name`.
+    pub(crate) fn with_name(self, name: &str) -> IconLookupError<'_> {
+        match self {
+            IconLookupError::PackDisabled { pack } => IconLookupError::PackDisabled { pack },
+            IconLookupError::IconNotFound { pack, .. } => {
+                IconLookupError::IconNotFound { pack, name }
+            }
+            IconLookupError::VariantUnavailable {
+                pack,
+                requested,
+                available,
+                ..
+            } => IconLookupError::VariantUnavailable {
+                pack,
+                name,
+                requested,
+                available,
+            },
+            IconLookupError::InvalidCodepoint {
+                pack, codepoint, ..
+            } => IconLookupError::InvalidCodepoint {
+                pack,
+                name,
+                codepoint,
+            },
+        }
+    }
+}
+
+impl From<IconLookupError<'_>> for IconError {
+    fn from(err: IconLookupError<'_>) -> Self {
+        match err {
+            IconLookupError::PackDisabled { pack } => IconError::PackDisabled { pack },
+            IconLookupError::IconNotFound { pack, name } => IconError::IconNotFound {
+                pack,
+                name: name.to_string(),
+            },
+            IconLookupError::VariantUnavailable {
+                pack,
+                name,
+                requested,
+                available,
+            } => IconError::VariantUnavailable {
+                pack,
+                name: name.to_string(),
+                requested,
+                available,
+            },
+            IconLookupError::InvalidCodepoint {
+                pack,
+                name,
+                codepoint,
+            } => IconError::InvalidCodepoint {
+                pack,
+                name: name.to_string(),
+                codepoint,
+            },
+        }
+    }
+}
+
+impl IconError {
+    /// For a [`IconError::VariantUnavailable`] that asked for `Size::Custom(px)`, the available
+    /// custom size of the same style closest to `px` (the smaller one on a tie).
+    ///
+    /// Custom sizes only match exactly, so this is the size to retry with.
+    pub fn suggested_size(&self) -> Option<Size> {
+        let IconError::VariantUnavailable {
+            requested: (style, Size::Custom(px)),
+            available,
+            ..
+        } = self
+        else {
+            return None;
+        };
+        available
+            .iter()
+            .filter_map(|&(available_style, size)| match size {
+                Size::Custom(candidate) if available_style == *style => Some(candidate),
+                _ => None,
+            })
+            .min_by_key(|&candidate| (candidate.abs_diff(*px), candidate))
+            .map(Size::Custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IconError;
+    use crate::core::{Size, Style};
+
+    fn unavailable(requested: Size, available: &'static [(Style, Size)]) -> IconError {
+        IconError::VariantUnavailable {
+            pack: "demo",
+            name: "alarm".to_string(),
+            requested: (Style::Regular, requested),
+            available,
+        }
+    }
+
+    #[test]
+    fn suggested_size_picks_nearest_custom_size_of_same_style() {
+        const AVAILABLE: &[(Style, Size)] = &[
+            (Style::Regular, Size::Custom(16)),
+            (Style::Regular, Size::Custom(24)),
+            (Style::Regular, Size::Regular),
+            (Style::Filled, Size::Custom(20)),
+        ];
+        assert_eq!(
+            unavailable(Size::Custom(21), AVAILABLE).suggested_size(),
+            Some(Size::Custom(24))
+        );
+        assert_eq!(
+            unavailable(Size::Custom(20), AVAILABLE).suggested_size(),
+            Some(Size::Custom(16))
+        );
+        assert_eq!(
+            unavailable(Size::Custom(48), AVAILABLE).suggested_size(),
+            Some(Size::Custom(24))
+        );
+    }
+
+    #[test]
+    fn suggested_size_is_none_without_custom_candidates() {
+        const NAMED_ONLY: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+        assert_eq!(
+            unavailable(Size::Custom(24), NAMED_ONLY).suggested_size(),
+            None
+        );
+        assert_eq!(unavailable(Size::Mini, NAMED_ONLY).suggested_size(), None);
+        let not_found = IconError::IconNotFound {
+            pack: "demo",
+            name: "alarm".to_string(),
+        };
+        assert_eq!(not_found.suggested_size(), None);
+    }
 }
@@ -2,6 +2,19 @@ mod api;
 mod error;
 mod types;
 
-pub use api::{fonts, list, try_icon};
-pub use error::IconError;
-pub use types::{FontAsset, IconRef, Size, Style, VariantKey};
+#[doc(hidden)]
+pub use api::resolve_icon;
+pub use api::{
+    IconRequest, PackResolver, aria_label, autocomplete, brand_color, codepoint_range,
+    common_icons, content_hash, display_name, embedded_font_bytes, fonts, group_by_initial,
+    icon_by_id, icon_or_placeholder, id_of, iter_icons, list, list_by_codepoint, list_in_variant,
+    list_iter, list_many, list_page, list_resolved, name_for_codepoint, next_size, pack_kind,
+    packs_of_kind, packs_with_icon, prev_size, resolve_with_fallback, rtl_mirror, search_all,
+    search_glob, sizes, sizes_static, styles, styles_static, total_embedded_font_bytes,
+    total_icon_count, try_icon, try_icon_ci, try_icon_ref, unique_fonts, variant_fonts,
+};
+pub use error::{IconError, IconLookupError};
+pub use types::{
+    AsGlyph, FontAsset, IconRef, OwnedIconRef, PackKind, ParseVariantError, Resolution, Size, Style,
+    VariantKey,
+};
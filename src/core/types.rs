@@ -1,11 +1,15 @@
+use std::fmt;
+use std::str::FromStr;
+
 /// Canonical size variants for icon packs.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum Size {
     /// Smallest available size (pack-specific).
     Tiny,
     /// Small size variant (pack-specific).
     Mini,
     /// Default size variant.
+    #[default]
     Regular,
     /// Larger size variant when a pack provides it.
     Large,
@@ -13,9 +17,36 @@ pub enum Size {
     Custom(u16),
 }
 
+impl Size {
+    /// Every named size, smallest first (excludes [`Size::Custom`]).
+    pub const NAMED: &[Size] = &[Size::Tiny, Size::Mini, Size::Regular, Size::Large];
+}
+
+/// Parses a named size (`"Regular"`) or a pixel size (`"24"`) as used in pack maps.
+impl FromStr for Size {
+    type Err = ParseVariantError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "Tiny" => Ok(Size::Tiny),
+            "Mini" => Ok(Size::Mini),
+            "Regular" => Ok(Size::Regular),
+            "Large" => Ok(Size::Large),
+            _ => match value.parse::<u16>() {
+                Ok(px) if px > 0 => Ok(Size::Custom(px)),
+                _ => Err(ParseVariantError {
+                    kind: "size",
+                    value: value.to_string(),
+                }),
+            },
+        }
+    }
+}
+
 /// Canonical style variants for icon packs.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum Style {
+    #[default]
     Regular,
     Filled,
     Outline,
@@ -28,6 +59,124 @@ pub enum Style {
     Rounded,
 }
 
+impl Style {
+    /// Every style, in declaration order.
+    pub const ALL: &[Style] = &[
+        Style::Regular,
+        Style::Filled,
+        Style::Outline,
+        Style::Light,
+        Style::Thin,
+        Style::Bold,
+        Style::Duotone,
+        Style::Glyph,
+        Style::Sharp,
+        Style::Rounded,
+    ];
+
+    /// Whether glyphs of this style are solid shapes.
+    ///
+    /// `Filled`, `Bold`, `Duotone` and `Sharp` (solid glyphs with square corners, as in Ionicons)
+    /// count as filled. `Regular`, `Glyph` and `Rounded` are neither filled nor outline: packs use
+    /// them for both kinds of drawing.
+    pub fn is_filled(self) -> bool {
+        matches!(
+            self,
+            Style::Filled | Style::Bold | Style::Duotone | Style::Sharp
+        )
+    }
+
+    /// Whether glyphs of this style are line drawings: `Outline`, `Light` and `Thin`.
+    ///
+    /// See [`Style::is_filled`] for the styles that are neither.
+    pub fn is_outline(self) -> bool {
+        matches!(self, Style::Outline | Style::Light | Style::Thin)
+    }
+
+    /// The style's name as written in pack maps, e.g. `"Filled"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Style::Regular => "Regular",
+            Style::Filled => "Filled",
+            Style::Outline => "Outline",
+            Style::Light => "Light",
+            Style::Thin => "Thin",
+            Style::Bold => "Bold",
+            Style::Duotone => "Duotone",
+            Style::Glyph => "Glyph",
+            Style::Sharp => "Sharp",
+            Style::Rounded => "Rounded",
+        }
+    }
+}
+
+/// Parses a style name as returned by [`Style::name`].
+impl FromStr for Style {
+    type Err = ParseVariantError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Style::ALL
+            .iter()
+            .copied()
+            .find(|style| style.name() == value)
+            .ok_or_else(|| ParseVariantError {
+                kind: "style",
+                value: value.to_string(),
+            })
+    }
+}
+
+/// What a pack's icons depict, so pickers can keep brand logos apart from UI icons.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PackKind {
+    /// Interface glyphs such as arrows, actions and objects.
+    #[default]
+    Ui,
+    /// Logos of products, companies and technologies.
+    Brand,
+}
+
+impl PackKind {
+    /// The kind's name as written in pack maps, e.g. `"brand"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            PackKind::Ui => "ui",
+            PackKind::Brand => "brand",
+        }
+    }
+}
+
+/// Parses a pack kind name as returned by [`PackKind::name`].
+impl FromStr for PackKind {
+    type Err = ParseVariantError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ui" => Ok(PackKind::Ui),
+            "brand" => Ok(PackKind::Brand),
+            _ => Err(ParseVariantError {
+                kind: "pack kind",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// Error from parsing a [`Style`], [`Size`] or [`PackKind`] that has no such name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseVariantError {
+    kind: &'static str,
+    value: String,
+}
+
+impl fmt::Display for ParseVariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} '{}'", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for ParseVariantError {}
+
 /// Font bytes and family name for a specific variant.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct FontAsset {
@@ -37,6 +186,37 @@ pub struct FontAsset {
     pub bytes: &'static [u8],
 }
 
+impl FontAsset {
+    /// Unique key for registering this font with a renderer.
+    ///
+    /// 64-bit FNV-1a of the family, the byte length and the first and last 64 bytes, so two
+    /// assets sharing a family but not a file get different ids. The value is stable across runs
+    /// of the same build and only changes when the embedded font does.
+    pub fn id(&self) -> u64 {
+        const EDGE: usize = 64;
+        let head = &self.bytes[..self.bytes.len().min(EDGE)];
+        let tail = &self.bytes[self.bytes.len().saturating_sub(EDGE)..];
+        self.family
+            .as_bytes()
+            .iter()
+            .chain(&(self.bytes.len() as u64).to_le_bytes())
+            .chain(head)
+            .chain(tail)
+            .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    /// Whether both assets embed the same font file, whatever their family names.
+    ///
+    /// Compares lengths and slice addresses before the content, so distinct fonts are rejected
+    /// cheaply. Useful for atlas builders that must not embed one file twice.
+    pub fn same_bytes(&self, other: &FontAsset) -> bool {
+        self.bytes.len() == other.bytes.len()
+            && (std::ptr::eq(self.bytes, other.bytes) || self.bytes == other.bytes)
+    }
+}
+
 /// Reference to a concrete glyph inside a font.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct IconRef {
@@ -46,6 +226,110 @@ pub struct IconRef {
     pub codepoint: u32,
 }
 
+impl IconRef {
+    /// The glyph as a one-character string, ready for text widgets.
+    ///
+    /// Falls back to U+FFFD (`'\u{FFFD}'`) if `codepoint` is not a valid `char`.
+    pub fn glyph_str(self) -> String {
+        char::from_u32(self.codepoint)
+            .unwrap_or(char::REPLACEMENT_CHARACTER)
+            .to_string()
+    }
+
+    /// Whether `other` draws the same codepoint, whatever its family.
+    ///
+    /// For change detection when two families share a codepoint layout, so toggling the style
+    /// does not count as a new glyph:
+    ///
+    /// ```
+    /// use iconflow::IconRef;
+    ///
+    /// let outline = IconRef { family: "Demo Outline", codepoint: 0xF101 };
+    /// let filled = IconRef { family: "Demo Filled", codepoint: 0xF101 };
+    /// assert!(outline.same_glyph(&filled));
+    /// assert_ne!(outline, filled);
+    /// ```
+    pub fn same_glyph(&self, other: &IconRef) -> bool {
+        self.codepoint == other.codepoint
+    }
+}
+
+/// Icon returned by [`resolve_with_fallback`](crate::resolve_with_fallback), with the
+/// variant that was actually used.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Resolution {
+    pub icon: IconRef,
+    pub used_style: Style,
+    pub used_size: Size,
+    /// `false` when another variant stood in for the requested one.
+    pub exact: bool,
+}
+
+/// [`IconRef`] with an owned family name, for fonts registered at runtime.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OwnedIconRef {
+    /// Font family name stored inside the TTF.
+    pub family: String,
+    /// Unicode codepoint of the glyph.
+    pub codepoint: u32,
+}
+
+impl From<IconRef> for OwnedIconRef {
+    fn from(icon: IconRef) -> Self {
+        Self {
+            family: icon.family.to_string(),
+            codepoint: icon.codepoint,
+        }
+    }
+}
+
+impl PartialEq<IconRef> for OwnedIconRef {
+    fn eq(&self, other: &IconRef) -> bool {
+        self.family == other.family && self.codepoint == other.codepoint
+    }
+}
+
+/// A glyph to draw: its font family and codepoint.
+///
+/// Implemented by [`IconRef`] and [`OwnedIconRef`] (and references to them), so the raster and
+/// `tiny-skia` helpers accept icons from embedded and runtime-registered fonts alike.
+pub trait AsGlyph {
+    /// Font family name stored inside the TTF.
+    fn family(&self) -> &str;
+    /// Unicode codepoint of the glyph.
+    fn codepoint(&self) -> u32;
+}
+
+impl AsGlyph for IconRef {
+    fn family(&self) -> &str {
+        self.family
+    }
+
+    fn codepoint(&self) -> u32 {
+        self.codepoint
+    }
+}
+
+impl AsGlyph for OwnedIconRef {
+    fn family(&self) -> &str {
+        &self.family
+    }
+
+    fn codepoint(&self) -> u32 {
+        self.codepoint
+    }
+}
+
+impl<T: AsGlyph + ?Sized> AsGlyph for &T {
+    fn family(&self) -> &str {
+        (**self).family()
+    }
+
+    fn codepoint(&self) -> u32 {
+        (**self).codepoint()
+    }
+}
+
 /// Variant key used to index font assets and codepoints.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct VariantKey {
@@ -55,7 +339,8 @@ pub struct VariantKey {
 
 #[cfg(test)]
 mod tests {
-    use super::{Size, Style, VariantKey};
+    use super::{FontAsset, IconRef, OwnedIconRef, PackKind, Size, Style, VariantKey};
+    use std::str::FromStr;
 
     #[test]
     fn variant_key_compares_by_fields() {
@@ -69,4 +354,148 @@ mod tests {
         };
         assert_eq!(left, right);
     }
+
+    #[test]
+    fn defaults_are_regular() {
+        assert_eq!(Style::default(), Style::Regular);
+        assert_eq!(Size::default(), Size::Regular);
+    }
+
+    #[test]
+    fn enumerators_list_every_variant() {
+        assert_eq!(Style::ALL.len(), 10);
+        assert!(Style::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(Size::NAMED.len(), 4);
+        assert!(
+            !Size::NAMED
+                .iter()
+                .any(|size| matches!(size, Size::Custom(_)))
+        );
+    }
+
+    #[test]
+    fn styles_classify_as_filled_or_outline() {
+        assert!(Style::Filled.is_filled() && !Style::Filled.is_outline());
+        assert!(Style::Sharp.is_filled());
+        assert!(Style::Thin.is_outline() && !Style::Thin.is_filled());
+        assert!(!Style::Regular.is_filled() && !Style::Regular.is_outline());
+        assert!(
+            Style::ALL
+                .iter()
+                .all(|style| !(style.is_filled() && style.is_outline()))
+        );
+    }
+
+    #[test]
+    fn glyph_str_is_the_codepoint_char() {
+        let icon = |codepoint| IconRef {
+            family: "Demo Regular",
+            codepoint,
+        };
+        assert_eq!(icon(0xF101).glyph_str(), "\u{F101}");
+        assert_eq!(icon(0xD800).glyph_str(), "\u{FFFD}");
+    }
+
+    #[test]
+    fn same_glyph_ignores_family() {
+        let outline = IconRef {
+            family: "Demo Outline",
+            codepoint: 0xF101,
+        };
+        let filled = IconRef {
+            family: "Demo Filled",
+            ..outline
+        };
+        assert!(outline.same_glyph(&filled));
+        assert!(!outline.same_glyph(&IconRef {
+            codepoint: 0xF102,
+            ..outline
+        }));
+    }
+
+    #[test]
+    fn same_bytes_ignores_family_and_compares_content() {
+        let regular = FontAsset {
+            family: "Demo Regular",
+            bytes: b"font bytes",
+        };
+        let copy = FontAsset {
+            family: "Demo Alias",
+            bytes: b"font bytes".as_slice().to_vec().leak(),
+        };
+        assert!(regular.same_bytes(&regular));
+        assert!(regular.same_bytes(&copy));
+        assert_ne!(regular, copy);
+        assert!(!regular.same_bytes(&FontAsset {
+            bytes: b"font bytez",
+            ..regular
+        }));
+        assert!(!regular.same_bytes(&FontAsset {
+            bytes: b"font bytes!",
+            ..regular
+        }));
+    }
+
+    #[test]
+    fn font_asset_ids_differ_by_content() {
+        let asset = |bytes| FontAsset {
+            family: "Demo Regular",
+            bytes,
+        };
+        let regular = asset(b"regular font bytes");
+        assert_eq!(regular.id(), asset(b"regular font bytes").id());
+        assert_ne!(regular.id(), asset(b"filled font bytes!").id());
+        assert_ne!(regular.id(), asset(b"regular font bytes ").id());
+        assert_ne!(
+            regular.id(),
+            FontAsset {
+                family: "Demo Filled",
+                ..regular
+            }
+            .id()
+        );
+    }
+
+    #[test]
+    fn owned_icon_ref_converts_from_icon_ref() {
+        let icon = IconRef {
+            family: "Demo Regular",
+            codepoint: 0xF101,
+        };
+        let owned = OwnedIconRef::from(icon);
+        assert_eq!(owned.family, "Demo Regular");
+        assert_eq!(owned.codepoint, 0xF101);
+        assert_eq!(owned, icon);
+
+        let runtime = OwnedIconRef {
+            family: format!("{} {}", "Demo", "Regular"),
+            codepoint: 0xF101,
+        };
+        assert_eq!(runtime, owned);
+        assert!(
+            runtime
+                != IconRef {
+                    codepoint: 0xF102,
+                    ..icon
+                }
+        );
+    }
+
+    #[test]
+    fn style_and_size_parse_their_map_names() {
+        for &style in Style::ALL {
+            assert_eq!(Style::from_str(style.name()), Ok(style));
+        }
+        assert_eq!("Large".parse::<Size>(), Ok(Size::Large));
+        assert_eq!("24".parse::<Size>(), Ok(Size::Custom(24)));
+
+        let err = "filled".parse::<Style>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown style 'filled'");
+        assert!("0".parse::<Size>().is_err());
+        assert!("Huge".parse::<Size>().is_err());
+
+        assert_eq!("brand".parse::<PackKind>(), Ok(PackKind::Brand));
+        assert_eq!(PackKind::Ui.name().parse::<PackKind>(), Ok(PackKind::Ui));
+        assert!("Brand".parse::<PackKind>().is_err());
+    }
 }
@@ -0,0 +1,114 @@
+//! Font registration for egui applications.
+
+use crate::core::{FontAsset, unique_fonts, variant_fonts};
+use crate::generated::Pack;
+use ::egui::{FontData, FontDefinitions, FontFamily};
+use std::sync::Arc;
+
+/// Adds every embedded font to `definitions` as its own `FontFamily::Name(family)`.
+///
+/// Fonts come from [`unique_fonts`](crate::unique_fonts), so a family shared by two packs is
+/// registered once, with the first pack's bytes.
+///
+/// Each icon family lists its own font first, followed by the fonts of
+/// `FontFamily::Proportional`, so text drawn with an icon family falls back to the default
+/// proportional font for glyphs the icon font lacks. Calling it twice leaves the
+/// definitions unchanged.
+pub fn register(definitions: &mut FontDefinitions) {
+    register_fonts(definitions, unique_fonts());
+}
+
+/// [`register`] limited to the fonts of `packs`, for apps that draw from a few packs only.
+///
+/// Fonts of other packs are neither copied nor loaded by egui.
+pub fn register_packs(definitions: &mut FontDefinitions, packs: &[Pack]) {
+    let mut fonts: Vec<FontAsset> = Vec::new();
+    for &pack in packs {
+        for &(_, font) in variant_fonts(pack) {
+            if !fonts.iter().any(|known| known.family == font.family) {
+                fonts.push(font);
+            }
+        }
+    }
+    register_fonts(definitions, fonts);
+}
+
+fn register_fonts(definitions: &mut FontDefinitions, fonts: Vec<FontAsset>) {
+    let fallbacks = definitions
+        .families
+        .get(&FontFamily::Proportional)
+        .cloned()
+        .unwrap_or_default();
+
+    for font in fonts {
+        definitions.font_data.insert(
+            font.family.to_string(),
+            Arc::new(FontData::from_static(font.bytes)),
+        );
+        let family = definitions
+            .families
+            .entry(FontFamily::Name(font.family.into()))
+            .or_default();
+        family.retain(|name| name != font.family);
+        family.insert(0, font.family.to_string());
+        for fallback in &fallbacks {
+            if !family.contains(fallback) {
+                family.push(fallback.clone());
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "pack-bootstrap"))]
+mod tests {
+    use super::{register, register_packs};
+    use crate::core::{fonts, variant_fonts};
+    use crate::generated::Pack;
+    use ::egui::{FontDefinitions, FontFamily};
+
+    #[test]
+    fn registers_every_family_with_proportional_fallback() {
+        let mut definitions = FontDefinitions::default();
+        let proportional = definitions.families[&FontFamily::Proportional].clone();
+        register(&mut definitions);
+
+        for font in fonts() {
+            assert!(definitions.font_data.contains_key(font.family));
+            let family = &definitions.families[&FontFamily::Name(font.family.into())];
+            assert_eq!(family[0], font.family);
+            assert_eq!(family[1..], proportional[..]);
+        }
+
+        let before = definitions.families.clone();
+        register(&mut definitions);
+        assert_eq!(definitions.families, before);
+    }
+
+    #[test]
+    fn register_packs_adds_only_the_selected_fonts() {
+        let mut definitions = FontDefinitions::default();
+        let builtin = definitions.font_data.len();
+        register_packs(&mut definitions, &[Pack::Bootstrap]);
+
+        let families: Vec<&str> = variant_fonts(Pack::Bootstrap)
+            .iter()
+            .map(|(_, font)| font.family)
+            .collect();
+        for family in &families {
+            assert!(definitions.font_data.contains_key(*family));
+            assert!(
+                definitions
+                    .families
+                    .contains_key(&FontFamily::Name((*family).into()))
+            );
+        }
+        let mut unique = families.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(definitions.font_data.len(), builtin + unique.len());
+
+        let mut empty = FontDefinitions::default();
+        register_packs(&mut empty, &[]);
+        assert_eq!(empty.font_data.len(), builtin);
+    }
+}
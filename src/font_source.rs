@@ -0,0 +1,68 @@
+//! Framework-neutral font loading for GUI toolkits that register fonts asynchronously.
+
+use crate::core::fonts;
+
+/// A GUI framework's way of loading one font and waiting for several loads.
+///
+/// Implement it once per framework, then build the load step for every embedded font
+/// with [`load_fonts_with`].
+pub trait AsyncFontSource {
+    /// The framework's unit of asynchronous work, e.g. iced's `Task`.
+    type Task;
+
+    /// Starts loading one font.
+    fn load_font(&self, family: &'static str, bytes: &'static [u8]) -> Self::Task;
+
+    /// Combines `tasks` into one that finishes after all of them.
+    fn join(&self, tasks: Vec<Self::Task>) -> Self::Task;
+}
+
+/// `(family, bytes)` of every font embedded by the enabled packs, in [`fonts`] order.
+pub fn async_fonts() -> impl ExactSizeIterator<Item = (&'static str, &'static [u8])> {
+    fonts().iter().map(|asset| (asset.family, asset.bytes))
+}
+
+/// Builds one task that loads every font from [`async_fonts`] through `source`.
+pub fn load_fonts_with<S: AsyncFontSource>(source: &S) -> S::Task {
+    let tasks = async_fonts()
+        .map(|(family, bytes)| source.load_font(family, bytes))
+        .collect();
+    source.join(tasks)
+}
+
+#[cfg(all(test, feature = "pack-bootstrap"))]
+mod tests {
+    use super::{AsyncFontSource, async_fonts, load_fonts_with};
+    use crate::core::fonts;
+
+    struct Recorder;
+
+    impl AsyncFontSource for Recorder {
+        type Task = Vec<&'static str>;
+
+        fn load_font(&self, family: &'static str, _bytes: &'static [u8]) -> Self::Task {
+            vec![family]
+        }
+
+        fn join(&self, tasks: Vec<Self::Task>) -> Self::Task {
+            tasks.concat()
+        }
+    }
+
+    #[test]
+    fn async_fonts_match_embedded_fonts() {
+        assert_eq!(async_fonts().len(), fonts().len());
+        for ((family, bytes), asset) in async_fonts().zip(fonts()) {
+            assert_eq!(family, asset.family);
+            assert_eq!(bytes.as_ptr(), asset.bytes.as_ptr());
+            assert!(!bytes.is_empty());
+        }
+        assert!(async_fonts().any(|(family, _)| family == "Bootstrap Regular"));
+    }
+
+    #[test]
+    fn load_fonts_with_loads_every_family_once() {
+        let families: Vec<&str> = fonts().iter().map(|asset| asset.family).collect();
+        assert_eq!(load_fonts_with(&Recorder), families);
+    }
+}
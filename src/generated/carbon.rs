@@ -1,7 +1,13 @@
 // @generated by xtask gen. DO NOT EDIT.
-use crate::core::{FontAsset, IconRef, Size, Style, VariantKey};
+use crate::core::{FontAsset, IconRef, PackKind, Size, Style, VariantKey};
 
+/// Canonical pack id, also returned by `Pack::Carbon.id()`.
 pub const PACK_ID: &str = "carbon";
+pub const DISPLAY_NAME: &str = "Carbon";
+pub const KIND: PackKind = PackKind::Ui;
+/// Fingerprint of the pack's families, names and codepoints; changes when the map does.
+pub const CONTENT_HASH: u64 = 0x2d24d962a4a1c064;
+pub const STYLE_ORDER: &[Style] = &[];
 
 pub(crate) const FONT_ASSET_CARBON_CARBON_FILLED: FontAsset = FontAsset {
     family: "Carbon Filled",
@@ -70,156 +76,494 @@ pub const VARIANT_ASSETS: &[(VariantKey, FontAsset)] = &[
     ),
 ];
 
+/// Styles of the enabled variants, in presentation order (`STYLE_ORDER` first).
+pub const STYLES: &[Style] = &[Style::Regular, Style::Filled, Style::Outline, Style::Glyph];
+
+/// Sizes of the enabled variants, sorted.
+pub const SIZES: &[Size] = &[Size::Regular];
+
+/// Lowest and highest codepoint each variant maps, for variants that map any.
+pub(crate) const VARIANT_CODEPOINT_RANGES: &[(VariantKey, u32, u32)] = &[
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE000,
+        0xE00C,
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE000,
+        0xE018,
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE000,
+        0xE00D,
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE000,
+        0xE074,
+    ),
+];
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Icon {
+    /// `add` — U+E000 (available in Glyph, Regular)
     Add,
+    /// `add-outline` — U+E000 (available in Outline)
     AddOutline,
+    /// `add-solid` — U+E000 (available in Filled)
     AddSolid,
+    /// `api` — U+E001 (available in Regular)
     Api,
+    /// `apis` — U+E002 (available in Regular)
     Apis,
+    /// `app-services` — U+E003 (available in Regular)
     AppServices,
+    /// `applications` — U+E004 (available in Regular)
     Applications,
+    /// `apps` — U+E005 (available in Regular)
     Apps,
+    /// `arrow-down` — U+E006 (available in Regular)
     ArrowDown,
+    /// `arrow-left` — U+E007 (available in Regular)
     ArrowLeft,
+    /// `arrow-right` — U+E008 (available in Regular)
     ArrowRight,
+    /// `arrow-up` — U+E009 (available in Regular)
     ArrowUp,
+    /// `arrows` — U+E00A (available in Regular)
     Arrows,
+    /// `asleep` — available in Glyph U+E001, Regular U+E00B
     Asleep,
+    /// `attachment` — U+E00C (available in Regular)
     Attachment,
+    /// `awake` — U+E00D (available in Regular)
     Awake,
+    /// `back-to-top` — U+E00E (available in Regular)
     BackToTop,
+    /// `block-chain` — U+E00F (available in Regular)
     BlockChain,
+    /// `calendar` — U+E010 (available in Regular)
     Calendar,
+    /// `caret-down` — U+E011 (available in Regular)
     CaretDown,
+    /// `caret-left` — U+E012 (available in Regular)
     CaretLeft,
+    /// `caret-right` — U+E013 (available in Regular)
     CaretRight,
+    /// `caret-up` — U+E014 (available in Regular)
     CaretUp,
+    /// `cf-apps` — U+E015 (available in Regular)
     CfApps,
+    /// `chat` — U+E016 (available in Regular)
     Chat,
+    /// `checkmark` — available in Glyph U+E002, Regular U+E017
     Checkmark,
+    /// `checkmark-outline` — U+E001 (available in Outline)
     CheckmarkOutline,
+    /// `checkmark-solid` — U+E001 (available in Filled)
     CheckmarkSolid,
+    /// `chevron-down` — U+E018 (available in Regular)
     ChevronDown,
+    /// `chevron-left` — U+E019 (available in Regular)
     ChevronLeft,
+    /// `chevron-right` — U+E01A (available in Regular)
     ChevronRight,
+    /// `chevron-up` — U+E01B (available in Regular)
     ChevronUp,
+    /// `close` — available in Glyph U+E003, Regular U+E01C
     Close,
+    /// `close-outline` — U+E002 (available in Outline)
     CloseOutline,
+    /// `close-solid` — U+E002 (available in Filled)
     CloseSolid,
+    /// `cloud-foundry` — U+E01D (available in Regular)
     CloudFoundry,
+    /// `code` — U+E01E (available in Regular)
     Code,
+    /// `console` — U+E01F (available in Regular)
     Console,
+    /// `containers` — U+E020 (available in Regular)
     Containers,
+    /// `copy` — available in Glyph U+E004, Regular U+E021
     Copy,
+    /// `crash` — U+E022 (available in Regular)
     Crash,
+    /// `dashboard` — U+E023 (available in Regular)
     Dashboard,
+    /// `data` — U+E024 (available in Regular)
     Data,
+    /// `delete` — available in Glyph U+E005, Regular U+E025
     Delete,
+    /// `devices` — U+E026 (available in Regular)
     Devices,
+    /// `devops` — U+E027 (available in Regular)
     Devops,
+    /// `document` — U+E028 (available in Regular)
     Document,
+    /// `dollars` — U+E029 (available in Regular)
     Dollars,
+    /// `download` — available in Glyph U+E006, Regular U+E02A
     Download,
+    /// `draggable` — U+E02B (available in Regular)
     Draggable,
+    /// `edit` — available in Glyph U+E007, Regular U+E02C
     Edit,
+    /// `email` — U+E02D (available in Regular)
     Email,
+    /// `error` — available in Glyph U+E008, Regular U+E02E
     Error,
+    /// `error-outline` — U+E003 (available in Outline)
     ErrorOutline,
+    /// `error-solid` — U+E003 (available in Filled)
     ErrorSolid,
+    /// `favorite` — U+E02F (available in Regular)
     Favorite,
+    /// `favorite-outline` — U+E004 (available in Outline)
     FavoriteOutline,
+    /// `favorite-solid` — U+E004 (available in Filled)
     FavoriteSolid,
+    /// `filter` — available in Glyph U+E009, Regular U+E030
     Filter,
+    /// `finance` — U+E031 (available in Regular)
     Finance,
+    /// `financial` — U+E032 (available in Regular)
     Financial,
+    /// `folder` — available in Glyph U+E00A, Regular U+E034
     Folder,
+    /// `folder-add` — U+E033 (available in Regular)
     FolderAdd,
+    /// `forum` — U+E035 (available in Regular)
     Forum,
+    /// `functions` — U+E036 (available in Regular)
     Functions,
+    /// `grid` — U+E037 (available in Regular)
     Grid,
+    /// `header-avatar` — U+E038 (available in Regular)
     HeaderAvatar,
+    /// `header-chevron` — U+E039 (available in Regular)
     HeaderChevron,
+    /// `header-close` — U+E03A (available in Regular)
     HeaderClose,
+    /// `header-contact` — U+E03B (available in Regular)
     HeaderContact,
+    /// `header-docs` — U+E03C (available in Regular)
     HeaderDocs,
+    /// `header-hamburger` — U+E03D (available in Regular)
     HeaderHamburger,
+    /// `header-help` — U+E03E (available in Regular)
     HeaderHelp,
+    /// `header-notification` — U+E03F (available in Regular)
     HeaderNotification,
+    /// `header-search` — U+E040 (available in Regular)
     HeaderSearch,
+    /// `header-ticket` — U+E041 (available in Regular)
     HeaderTicket,
+    /// `header-user` — U+E042 (available in Regular)
     HeaderUser,
+    /// `help` — available in Glyph U+E00B, Regular U+E043
     Help,
+    /// `help-outline` — U+E005 (available in Outline)
     HelpOutline,
+    /// `help-solid` — U+E005 (available in Filled)
     HelpSolid,
+    /// `hpa` — U+E045 (available in Regular)
     Hpa,
+    /// `hpa-stress` — U+E044 (available in Regular)
     HpaStress,
+    /// `info` — available in Glyph U+E00C, Regular U+E046
     Info,
+    /// `info-outline` — U+E006 (available in Outline)
     InfoOutline,
+    /// `info-solid` — U+E006 (available in Filled)
     InfoSolid,
+    /// `infrastructure` — U+E047 (available in Regular)
     Infrastructure,
+    /// `integration` — U+E048 (available in Regular)
     Integration,
+    /// `iot` — U+E049 (available in Regular)
     Iot,
+    /// `launch` — available in Glyph U+E00D, Regular U+E04A
     Launch,
+    /// `light` — available in Glyph U+E00E, Regular U+E04B
     Light,
+    /// `link` — U+E04C (available in Regular)
     Link,
+    /// `list` — U+E04D (available in Regular)
     List,
+    /// `locked` — U+E04E (available in Regular)
     Locked,
+    /// `maximize` — U+E04F (available in Regular)
     Maximize,
+    /// `menu` — U+E050 (available in Regular)
     Menu,
+    /// `minimize` — U+E051 (available in Regular)
     Minimize,
+    /// `mobile` — U+E052 (available in Regular)
     Mobile,
+    /// `network` — U+E053 (available in Regular)
     Network,
+    /// `new-tab` — U+E054 (available in Regular)
     NewTab,
+    /// `notification-off` — U+E055 (available in Regular)
     NotificationOff,
+    /// `notification-on` — U+E056 (available in Regular)
     NotificationOn,
+    /// `open-whisk` — U+E057 (available in Regular)
     OpenWhisk,
+    /// `overflow-menu` — U+E058 (available in Regular)
     OverflowMenu,
+    /// `pa` — U+E05A (available in Regular)
     Pa,
+    /// `pa-stress` — U+E059 (available in Regular)
     PaStress,
+    /// `pause` — available in Glyph U+E00F, Regular U+E05B
     Pause,
+    /// `pause-outline` — U+E007 (available in Outline)
     PauseOutline,
+    /// `pause-solid` — U+E007 (available in Filled)
     PauseSolid,
+    /// `play` — available in Glyph U+E010, Regular U+E05C
     Play,
+    /// `play-outline` — U+E008 (available in Outline)
     PlayOutline,
+    /// `play-solid` — U+E008 (available in Filled)
     PlaySolid,
+    /// `portfolio` — U+E05D (available in Regular)
     Portfolio,
+    /// `power` — available in Glyph U+E011, Regular U+E05E
     Power,
+    /// `predictive` — U+E05F (available in Regular)
     Predictive,
+    /// `purchase` — U+E060 (available in Regular)
     Purchase,
+    /// `restart` — available in Glyph U+E012, Regular U+E061
     Restart,
+    /// `save` — U+E062 (available in Regular)
     Save,
+    /// `schematics` — U+E063 (available in Regular)
     Schematics,
+    /// `search` — available in Glyph U+E013, Regular U+E064
     Search,
+    /// `security` — U+E065 (available in Regular)
     Security,
+    /// `services` — U+E066 (available in Regular)
     Services,
+    /// `settings` — available in Glyph U+E014, Regular U+E067
     Settings,
+    /// `star-outline` — U+E009 (available in Outline)
     StarOutline,
+    /// `star-solid` — U+E009 (available in Filled)
     StarSolid,
+    /// `start` — available in Glyph U+E015, Regular U+E068
     Start,
+    /// `start-outline` — U+E00A (available in Outline)
     StartOutline,
+    /// `stop` — available in Glyph U+E016, Regular U+E069
     Stop,
+    /// `stop-outline` — U+E00B (available in Outline)
     StopOutline,
+    /// `stop-solid` — U+E00A (available in Filled)
     StopSolid,
+    /// `storage` — U+E06A (available in Regular)
     Storage,
+    /// `subtract` — U+E017 (available in Glyph)
     Subtract,
+    /// `subtract-outline` — U+E00C (available in Outline)
     SubtractOutline,
+    /// `subtract-solid` — U+E00B (available in Filled)
     SubtractSolid,
+    /// `terminal` — U+E06B (available in Regular)
     Terminal,
+    /// `unlock` — U+E06C (available in Regular)
     Unlock,
+    /// `unlocked` — U+E06D (available in Regular)
     Unlocked,
+    /// `upload` — U+E06E (available in Regular)
     Upload,
+    /// `user` — U+E06F (available in Regular)
     User,
+    /// `visibility-off` — U+E070 (available in Regular)
     VisibilityOff,
+    /// `visibility-on` — U+E071 (available in Regular)
     VisibilityOn,
+    /// `warning` — available in Glyph U+E018, Regular U+E072
     Warning,
+    /// `warning-outline` — U+E00D (available in Outline)
     WarningOutline,
+    /// `warning-solid` — U+E00C (available in Filled)
     WarningSolid,
+    /// `watson` — U+E073 (available in Regular)
     Watson,
+    /// `whisk` — U+E074 (available in Regular)
     Whisk,
 }
 
+/// An icon's available `(style, size)` pairs and its codepoint in each variant.
+type IconVariantTables = (&'static [(Style, Size)], &'static [(VariantKey, u32)]);
+
 impl Icon {
+    pub const ALL: &[Icon] = &[
+        Icon::Add,
+        Icon::AddOutline,
+        Icon::AddSolid,
+        Icon::Api,
+        Icon::Apis,
+        Icon::AppServices,
+        Icon::Applications,
+        Icon::Apps,
+        Icon::ArrowDown,
+        Icon::ArrowLeft,
+        Icon::ArrowRight,
+        Icon::ArrowUp,
+        Icon::Arrows,
+        Icon::Asleep,
+        Icon::Attachment,
+        Icon::Awake,
+        Icon::BackToTop,
+        Icon::BlockChain,
+        Icon::Calendar,
+        Icon::CaretDown,
+        Icon::CaretLeft,
+        Icon::CaretRight,
+        Icon::CaretUp,
+        Icon::CfApps,
+        Icon::Chat,
+        Icon::Checkmark,
+        Icon::CheckmarkOutline,
+        Icon::CheckmarkSolid,
+        Icon::ChevronDown,
+        Icon::ChevronLeft,
+        Icon::ChevronRight,
+        Icon::ChevronUp,
+        Icon::Close,
+        Icon::CloseOutline,
+        Icon::CloseSolid,
+        Icon::CloudFoundry,
+        Icon::Code,
+        Icon::Console,
+        Icon::Containers,
+        Icon::Copy,
+        Icon::Crash,
+        Icon::Dashboard,
+        Icon::Data,
+        Icon::Delete,
+        Icon::Devices,
+        Icon::Devops,
+        Icon::Document,
+        Icon::Dollars,
+        Icon::Download,
+        Icon::Draggable,
+        Icon::Edit,
+        Icon::Email,
+        Icon::Error,
+        Icon::ErrorOutline,
+        Icon::ErrorSolid,
+        Icon::Favorite,
+        Icon::FavoriteOutline,
+        Icon::FavoriteSolid,
+        Icon::Filter,
+        Icon::Finance,
+        Icon::Financial,
+        Icon::Folder,
+        Icon::FolderAdd,
+        Icon::Forum,
+        Icon::Functions,
+        Icon::Grid,
+        Icon::HeaderAvatar,
+        Icon::HeaderChevron,
+        Icon::HeaderClose,
+        Icon::HeaderContact,
+        Icon::HeaderDocs,
+        Icon::HeaderHamburger,
+        Icon::HeaderHelp,
+        Icon::HeaderNotification,
+        Icon::HeaderSearch,
+        Icon::HeaderTicket,
+        Icon::HeaderUser,
+        Icon::Help,
+        Icon::HelpOutline,
+        Icon::HelpSolid,
+        Icon::Hpa,
+        Icon::HpaStress,
+        Icon::Info,
+        Icon::InfoOutline,
+        Icon::InfoSolid,
+        Icon::Infrastructure,
+        Icon::Integration,
+        Icon::Iot,
+        Icon::Launch,
+        Icon::Light,
+        Icon::Link,
+        Icon::List,
+        Icon::Locked,
+        Icon::Maximize,
+        Icon::Menu,
+        Icon::Minimize,
+        Icon::Mobile,
+        Icon::Network,
+        Icon::NewTab,
+        Icon::NotificationOff,
+        Icon::NotificationOn,
+        Icon::OpenWhisk,
+        Icon::OverflowMenu,
+        Icon::Pa,
+        Icon::PaStress,
+        Icon::Pause,
+        Icon::PauseOutline,
+        Icon::PauseSolid,
+        Icon::Play,
+        Icon::PlayOutline,
+        Icon::PlaySolid,
+        Icon::Portfolio,
+        Icon::Power,
+        Icon::Predictive,
+        Icon::Purchase,
+        Icon::Restart,
+        Icon::Save,
+        Icon::Schematics,
+        Icon::Search,
+        Icon::Security,
+        Icon::Services,
+        Icon::Settings,
+        Icon::StarOutline,
+        Icon::StarSolid,
+        Icon::Start,
+        Icon::StartOutline,
+        Icon::Stop,
+        Icon::StopOutline,
+        Icon::StopSolid,
+        Icon::Storage,
+        Icon::Subtract,
+        Icon::SubtractOutline,
+        Icon::SubtractSolid,
+        Icon::Terminal,
+        Icon::Unlock,
+        Icon::Unlocked,
+        Icon::Upload,
+        Icon::User,
+        Icon::VisibilityOff,
+        Icon::VisibilityOn,
+        Icon::Warning,
+        Icon::WarningOutline,
+        Icon::WarningSolid,
+        Icon::Watson,
+        Icon::Whisk,
+    ];
+
     pub fn name(self) -> &'static str {
         match self {
             Icon::Add => "add",
@@ -370,31 +714,286 @@ impl Icon {
         }
     }
 
+    /// Resolves this icon at a `(style, size)` known to exist, e.g. one listed in its docs.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the variant is unavailable; use [`Icon::try_icon`] to handle that case.
     pub fn icon(self, style: Style, size: Size) -> IconRef {
-        let name = self.name();
-        let available = icon_available(name).unwrap_or(&[]);
-        if !available.contains(&(style, size)) {
-            panic!(
-                "Icon '{}' is not available in {:?}/{:?}. Available: {:?}",
-                name, style, size, available
-            );
+        match self.try_icon(style, size) {
+            Ok(icon) => icon,
+            Err(err) => panic!(
+                "Icon '{}' is not available in {:?}/{:?}: {:?}",
+                self.name(),
+                style,
+                size,
+                err
+            ),
         }
-        let variant = variant_info(style, size).unwrap_or_else(|| {
-            panic!(
-                "Variant {:?}/{:?} is not available for pack {}",
-                style, size, PACK_ID
-            )
-        });
-        let codepoint = icon_codepoint(name, variant.key).unwrap_or_else(|| {
-            panic!("Icon '{}' is not available in {:?}/{:?}", name, style, size)
-        });
-        IconRef {
-            family: variant.family,
-            codepoint,
+    }
+
+    /// Resolves this icon at `(style, size)` without a name lookup, returning the same errors
+    /// as [`crate::try_icon`].
+    pub fn try_icon(self, style: Style, size: Size) -> Result<IconRef, crate::core::IconError> {
+        let (available, codepoints) = self.variant_tables();
+        let key = VariantKey { style, size };
+        crate::core::resolve_icon(
+            PACK_ID,
+            self.name(),
+            style,
+            size,
+            Some(available),
+            variant_info(style, size).map(|info| info.family),
+            codepoints
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|&(_, cp)| cp),
+        )
+        .map_err(crate::core::IconError::from)
+    }
+
+    fn variant_tables(self) -> IconVariantTables {
+        match self {
+            Icon::Add => (ICON_ADD_AVAILABLE, ICON_ADD_CODEPOINTS),
+            Icon::AddOutline => (ICON_ADD_OUTLINE_AVAILABLE, ICON_ADD_OUTLINE_CODEPOINTS),
+            Icon::AddSolid => (ICON_ADD_SOLID_AVAILABLE, ICON_ADD_SOLID_CODEPOINTS),
+            Icon::Api => (ICON_API_AVAILABLE, ICON_API_CODEPOINTS),
+            Icon::Apis => (ICON_APIS_AVAILABLE, ICON_APIS_CODEPOINTS),
+            Icon::AppServices => (ICON_APP_SERVICES_AVAILABLE, ICON_APP_SERVICES_CODEPOINTS),
+            Icon::Applications => (ICON_APPLICATIONS_AVAILABLE, ICON_APPLICATIONS_CODEPOINTS),
+            Icon::Apps => (ICON_APPS_AVAILABLE, ICON_APPS_CODEPOINTS),
+            Icon::ArrowDown => (ICON_ARROW_DOWN_AVAILABLE, ICON_ARROW_DOWN_CODEPOINTS),
+            Icon::ArrowLeft => (ICON_ARROW_LEFT_AVAILABLE, ICON_ARROW_LEFT_CODEPOINTS),
+            Icon::ArrowRight => (ICON_ARROW_RIGHT_AVAILABLE, ICON_ARROW_RIGHT_CODEPOINTS),
+            Icon::ArrowUp => (ICON_ARROW_UP_AVAILABLE, ICON_ARROW_UP_CODEPOINTS),
+            Icon::Arrows => (ICON_ARROWS_AVAILABLE, ICON_ARROWS_CODEPOINTS),
+            Icon::Asleep => (ICON_ASLEEP_AVAILABLE, ICON_ASLEEP_CODEPOINTS),
+            Icon::Attachment => (ICON_ATTACHMENT_AVAILABLE, ICON_ATTACHMENT_CODEPOINTS),
+            Icon::Awake => (ICON_AWAKE_AVAILABLE, ICON_AWAKE_CODEPOINTS),
+            Icon::BackToTop => (ICON_BACK_TO_TOP_AVAILABLE, ICON_BACK_TO_TOP_CODEPOINTS),
+            Icon::BlockChain => (ICON_BLOCK_CHAIN_AVAILABLE, ICON_BLOCK_CHAIN_CODEPOINTS),
+            Icon::Calendar => (ICON_CALENDAR_AVAILABLE, ICON_CALENDAR_CODEPOINTS),
+            Icon::CaretDown => (ICON_CARET_DOWN_AVAILABLE, ICON_CARET_DOWN_CODEPOINTS),
+            Icon::CaretLeft => (ICON_CARET_LEFT_AVAILABLE, ICON_CARET_LEFT_CODEPOINTS),
+            Icon::CaretRight => (ICON_CARET_RIGHT_AVAILABLE, ICON_CARET_RIGHT_CODEPOINTS),
+            Icon::CaretUp => (ICON_CARET_UP_AVAILABLE, ICON_CARET_UP_CODEPOINTS),
+            Icon::CfApps => (ICON_CF_APPS_AVAILABLE, ICON_CF_APPS_CODEPOINTS),
+            Icon::Chat => (ICON_CHAT_AVAILABLE, ICON_CHAT_CODEPOINTS),
+            Icon::Checkmark => (ICON_CHECKMARK_AVAILABLE, ICON_CHECKMARK_CODEPOINTS),
+            Icon::CheckmarkOutline => (
+                ICON_CHECKMARK_OUTLINE_AVAILABLE,
+                ICON_CHECKMARK_OUTLINE_CODEPOINTS,
+            ),
+            Icon::CheckmarkSolid => (
+                ICON_CHECKMARK_SOLID_AVAILABLE,
+                ICON_CHECKMARK_SOLID_CODEPOINTS,
+            ),
+            Icon::ChevronDown => (ICON_CHEVRON_DOWN_AVAILABLE, ICON_CHEVRON_DOWN_CODEPOINTS),
+            Icon::ChevronLeft => (ICON_CHEVRON_LEFT_AVAILABLE, ICON_CHEVRON_LEFT_CODEPOINTS),
+            Icon::ChevronRight => (ICON_CHEVRON_RIGHT_AVAILABLE, ICON_CHEVRON_RIGHT_CODEPOINTS),
+            Icon::ChevronUp => (ICON_CHEVRON_UP_AVAILABLE, ICON_CHEVRON_UP_CODEPOINTS),
+            Icon::Close => (ICON_CLOSE_AVAILABLE, ICON_CLOSE_CODEPOINTS),
+            Icon::CloseOutline => (ICON_CLOSE_OUTLINE_AVAILABLE, ICON_CLOSE_OUTLINE_CODEPOINTS),
+            Icon::CloseSolid => (ICON_CLOSE_SOLID_AVAILABLE, ICON_CLOSE_SOLID_CODEPOINTS),
+            Icon::CloudFoundry => (ICON_CLOUD_FOUNDRY_AVAILABLE, ICON_CLOUD_FOUNDRY_CODEPOINTS),
+            Icon::Code => (ICON_CODE_AVAILABLE, ICON_CODE_CODEPOINTS),
+            Icon::Console => (ICON_CONSOLE_AVAILABLE, ICON_CONSOLE_CODEPOINTS),
+            Icon::Containers => (ICON_CONTAINERS_AVAILABLE, ICON_CONTAINERS_CODEPOINTS),
+            Icon::Copy => (ICON_COPY_AVAILABLE, ICON_COPY_CODEPOINTS),
+            Icon::Crash => (ICON_CRASH_AVAILABLE, ICON_CRASH_CODEPOINTS),
+            Icon::Dashboard => (ICON_DASHBOARD_AVAILABLE, ICON_DASHBOARD_CODEPOINTS),
+            Icon::Data => (ICON_DATA_AVAILABLE, ICON_DATA_CODEPOINTS),
+            Icon::Delete => (ICON_DELETE_AVAILABLE, ICON_DELETE_CODEPOINTS),
+            Icon::Devices => (ICON_DEVICES_AVAILABLE, ICON_DEVICES_CODEPOINTS),
+            Icon::Devops => (ICON_DEVOPS_AVAILABLE, ICON_DEVOPS_CODEPOINTS),
+            Icon::Document => (ICON_DOCUMENT_AVAILABLE, ICON_DOCUMENT_CODEPOINTS),
+            Icon::Dollars => (ICON_DOLLARS_AVAILABLE, ICON_DOLLARS_CODEPOINTS),
+            Icon::Download => (ICON_DOWNLOAD_AVAILABLE, ICON_DOWNLOAD_CODEPOINTS),
+            Icon::Draggable => (ICON_DRAGGABLE_AVAILABLE, ICON_DRAGGABLE_CODEPOINTS),
+            Icon::Edit => (ICON_EDIT_AVAILABLE, ICON_EDIT_CODEPOINTS),
+            Icon::Email => (ICON_EMAIL_AVAILABLE, ICON_EMAIL_CODEPOINTS),
+            Icon::Error => (ICON_ERROR_AVAILABLE, ICON_ERROR_CODEPOINTS),
+            Icon::ErrorOutline => (ICON_ERROR_OUTLINE_AVAILABLE, ICON_ERROR_OUTLINE_CODEPOINTS),
+            Icon::ErrorSolid => (ICON_ERROR_SOLID_AVAILABLE, ICON_ERROR_SOLID_CODEPOINTS),
+            Icon::Favorite => (ICON_FAVORITE_AVAILABLE, ICON_FAVORITE_CODEPOINTS),
+            Icon::FavoriteOutline => (
+                ICON_FAVORITE_OUTLINE_AVAILABLE,
+                ICON_FAVORITE_OUTLINE_CODEPOINTS,
+            ),
+            Icon::FavoriteSolid => (
+                ICON_FAVORITE_SOLID_AVAILABLE,
+                ICON_FAVORITE_SOLID_CODEPOINTS,
+            ),
+            Icon::Filter => (ICON_FILTER_AVAILABLE, ICON_FILTER_CODEPOINTS),
+            Icon::Finance => (ICON_FINANCE_AVAILABLE, ICON_FINANCE_CODEPOINTS),
+            Icon::Financial => (ICON_FINANCIAL_AVAILABLE, ICON_FINANCIAL_CODEPOINTS),
+            Icon::Folder => (ICON_FOLDER_AVAILABLE, ICON_FOLDER_CODEPOINTS),
+            Icon::FolderAdd => (ICON_FOLDER_ADD_AVAILABLE, ICON_FOLDER_ADD_CODEPOINTS),
+            Icon::Forum => (ICON_FORUM_AVAILABLE, ICON_FORUM_CODEPOINTS),
+            Icon::Functions => (ICON_FUNCTIONS_AVAILABLE, ICON_FUNCTIONS_CODEPOINTS),
+            Icon::Grid => (ICON_GRID_AVAILABLE, ICON_GRID_CODEPOINTS),
+            Icon::HeaderAvatar => (ICON_HEADER_AVATAR_AVAILABLE, ICON_HEADER_AVATAR_CODEPOINTS),
+            Icon::HeaderChevron => (
+                ICON_HEADER_CHEVRON_AVAILABLE,
+                ICON_HEADER_CHEVRON_CODEPOINTS,
+            ),
+            Icon::HeaderClose => (ICON_HEADER_CLOSE_AVAILABLE, ICON_HEADER_CLOSE_CODEPOINTS),
+            Icon::HeaderContact => (
+                ICON_HEADER_CONTACT_AVAILABLE,
+                ICON_HEADER_CONTACT_CODEPOINTS,
+            ),
+            Icon::HeaderDocs => (ICON_HEADER_DOCS_AVAILABLE, ICON_HEADER_DOCS_CODEPOINTS),
+            Icon::HeaderHamburger => (
+                ICON_HEADER_HAMBURGER_AVAILABLE,
+                ICON_HEADER_HAMBURGER_CODEPOINTS,
+            ),
+            Icon::HeaderHelp => (ICON_HEADER_HELP_AVAILABLE, ICON_HEADER_HELP_CODEPOINTS),
+            Icon::HeaderNotification => (
+                ICON_HEADER_NOTIFICATION_AVAILABLE,
+                ICON_HEADER_NOTIFICATION_CODEPOINTS,
+            ),
+            Icon::HeaderSearch => (ICON_HEADER_SEARCH_AVAILABLE, ICON_HEADER_SEARCH_CODEPOINTS),
+            Icon::HeaderTicket => (ICON_HEADER_TICKET_AVAILABLE, ICON_HEADER_TICKET_CODEPOINTS),
+            Icon::HeaderUser => (ICON_HEADER_USER_AVAILABLE, ICON_HEADER_USER_CODEPOINTS),
+            Icon::Help => (ICON_HELP_AVAILABLE, ICON_HELP_CODEPOINTS),
+            Icon::HelpOutline => (ICON_HELP_OUTLINE_AVAILABLE, ICON_HELP_OUTLINE_CODEPOINTS),
+            Icon::HelpSolid => (ICON_HELP_SOLID_AVAILABLE, ICON_HELP_SOLID_CODEPOINTS),
+            Icon::Hpa => (ICON_HPA_AVAILABLE, ICON_HPA_CODEPOINTS),
+            Icon::HpaStress => (ICON_HPA_STRESS_AVAILABLE, ICON_HPA_STRESS_CODEPOINTS),
+            Icon::Info => (ICON_INFO_AVAILABLE, ICON_INFO_CODEPOINTS),
+            Icon::InfoOutline => (ICON_INFO_OUTLINE_AVAILABLE, ICON_INFO_OUTLINE_CODEPOINTS),
+            Icon::InfoSolid => (ICON_INFO_SOLID_AVAILABLE, ICON_INFO_SOLID_CODEPOINTS),
+            Icon::Infrastructure => (
+                ICON_INFRASTRUCTURE_AVAILABLE,
+                ICON_INFRASTRUCTURE_CODEPOINTS,
+            ),
+            Icon::Integration => (ICON_INTEGRATION_AVAILABLE, ICON_INTEGRATION_CODEPOINTS),
+            Icon::Iot => (ICON_IOT_AVAILABLE, ICON_IOT_CODEPOINTS),
+            Icon::Launch => (ICON_LAUNCH_AVAILABLE, ICON_LAUNCH_CODEPOINTS),
+            Icon::Light => (ICON_LIGHT_AVAILABLE, ICON_LIGHT_CODEPOINTS),
+            Icon::Link => (ICON_LINK_AVAILABLE, ICON_LINK_CODEPOINTS),
+            Icon::List => (ICON_LIST_AVAILABLE, ICON_LIST_CODEPOINTS),
+            Icon::Locked => (ICON_LOCKED_AVAILABLE, ICON_LOCKED_CODEPOINTS),
+            Icon::Maximize => (ICON_MAXIMIZE_AVAILABLE, ICON_MAXIMIZE_CODEPOINTS),
+            Icon::Menu => (ICON_MENU_AVAILABLE, ICON_MENU_CODEPOINTS),
+            Icon::Minimize => (ICON_MINIMIZE_AVAILABLE, ICON_MINIMIZE_CODEPOINTS),
+            Icon::Mobile => (ICON_MOBILE_AVAILABLE, ICON_MOBILE_CODEPOINTS),
+            Icon::Network => (ICON_NETWORK_AVAILABLE, ICON_NETWORK_CODEPOINTS),
+            Icon::NewTab => (ICON_NEW_TAB_AVAILABLE, ICON_NEW_TAB_CODEPOINTS),
+            Icon::NotificationOff => (
+                ICON_NOTIFICATION_OFF_AVAILABLE,
+                ICON_NOTIFICATION_OFF_CODEPOINTS,
+            ),
+            Icon::NotificationOn => (
+                ICON_NOTIFICATION_ON_AVAILABLE,
+                ICON_NOTIFICATION_ON_CODEPOINTS,
+            ),
+            Icon::OpenWhisk => (ICON_OPEN_WHISK_AVAILABLE, ICON_OPEN_WHISK_CODEPOINTS),
+            Icon::OverflowMenu => (ICON_OVERFLOW_MENU_AVAILABLE, ICON_OVERFLOW_MENU_CODEPOINTS),
+            Icon::Pa => (ICON_PA_AVAILABLE, ICON_PA_CODEPOINTS),
+            Icon::PaStress => (ICON_PA_STRESS_AVAILABLE, ICON_PA_STRESS_CODEPOINTS),
+            Icon::Pause => (ICON_PAUSE_AVAILABLE, ICON_PAUSE_CODEPOINTS),
+            Icon::PauseOutline => (ICON_PAUSE_OUTLINE_AVAILABLE, ICON_PAUSE_OUTLINE_CODEPOINTS),
+            Icon::PauseSolid => (ICON_PAUSE_SOLID_AVAILABLE, ICON_PAUSE_SOLID_CODEPOINTS),
+            Icon::Play => (ICON_PLAY_AVAILABLE, ICON_PLAY_CODEPOINTS),
+            Icon::PlayOutline => (ICON_PLAY_OUTLINE_AVAILABLE, ICON_PLAY_OUTLINE_CODEPOINTS),
+            Icon::PlaySolid => (ICON_PLAY_SOLID_AVAILABLE, ICON_PLAY_SOLID_CODEPOINTS),
+            Icon::Portfolio => (ICON_PORTFOLIO_AVAILABLE, ICON_PORTFOLIO_CODEPOINTS),
+            Icon::Power => (ICON_POWER_AVAILABLE, ICON_POWER_CODEPOINTS),
+            Icon::Predictive => (ICON_PREDICTIVE_AVAILABLE, ICON_PREDICTIVE_CODEPOINTS),
+            Icon::Purchase => (ICON_PURCHASE_AVAILABLE, ICON_PURCHASE_CODEPOINTS),
+            Icon::Restart => (ICON_RESTART_AVAILABLE, ICON_RESTART_CODEPOINTS),
+            Icon::Save => (ICON_SAVE_AVAILABLE, ICON_SAVE_CODEPOINTS),
+            Icon::Schematics => (ICON_SCHEMATICS_AVAILABLE, ICON_SCHEMATICS_CODEPOINTS),
+            Icon::Search => (ICON_SEARCH_AVAILABLE, ICON_SEARCH_CODEPOINTS),
+            Icon::Security => (ICON_SECURITY_AVAILABLE, ICON_SECURITY_CODEPOINTS),
+            Icon::Services => (ICON_SERVICES_AVAILABLE, ICON_SERVICES_CODEPOINTS),
+            Icon::Settings => (ICON_SETTINGS_AVAILABLE, ICON_SETTINGS_CODEPOINTS),
+            Icon::StarOutline => (ICON_STAR_OUTLINE_AVAILABLE, ICON_STAR_OUTLINE_CODEPOINTS),
+            Icon::StarSolid => (ICON_STAR_SOLID_AVAILABLE, ICON_STAR_SOLID_CODEPOINTS),
+            Icon::Start => (ICON_START_AVAILABLE, ICON_START_CODEPOINTS),
+            Icon::StartOutline => (ICON_START_OUTLINE_AVAILABLE, ICON_START_OUTLINE_CODEPOINTS),
+            Icon::Stop => (ICON_STOP_AVAILABLE, ICON_STOP_CODEPOINTS),
+            Icon::StopOutline => (ICON_STOP_OUTLINE_AVAILABLE, ICON_STOP_OUTLINE_CODEPOINTS),
+            Icon::StopSolid => (ICON_STOP_SOLID_AVAILABLE, ICON_STOP_SOLID_CODEPOINTS),
+            Icon::Storage => (ICON_STORAGE_AVAILABLE, ICON_STORAGE_CODEPOINTS),
+            Icon::Subtract => (ICON_SUBTRACT_AVAILABLE, ICON_SUBTRACT_CODEPOINTS),
+            Icon::SubtractOutline => (
+                ICON_SUBTRACT_OUTLINE_AVAILABLE,
+                ICON_SUBTRACT_OUTLINE_CODEPOINTS,
+            ),
+            Icon::SubtractSolid => (
+                ICON_SUBTRACT_SOLID_AVAILABLE,
+                ICON_SUBTRACT_SOLID_CODEPOINTS,
+            ),
+            Icon::Terminal => (ICON_TERMINAL_AVAILABLE, ICON_TERMINAL_CODEPOINTS),
+            Icon::Unlock => (ICON_UNLOCK_AVAILABLE, ICON_UNLOCK_CODEPOINTS),
+            Icon::Unlocked => (ICON_UNLOCKED_AVAILABLE, ICON_UNLOCKED_CODEPOINTS),
+            Icon::Upload => (ICON_UPLOAD_AVAILABLE, ICON_UPLOAD_CODEPOINTS),
+            Icon::User => (ICON_USER_AVAILABLE, ICON_USER_CODEPOINTS),
+            Icon::VisibilityOff => (
+                ICON_VISIBILITY_OFF_AVAILABLE,
+                ICON_VISIBILITY_OFF_CODEPOINTS,
+            ),
+            Icon::VisibilityOn => (ICON_VISIBILITY_ON_AVAILABLE, ICON_VISIBILITY_ON_CODEPOINTS),
+            Icon::Warning => (ICON_WARNING_AVAILABLE, ICON_WARNING_CODEPOINTS),
+            Icon::WarningOutline => (
+                ICON_WARNING_OUTLINE_AVAILABLE,
+                ICON_WARNING_OUTLINE_CODEPOINTS,
+            ),
+            Icon::WarningSolid => (ICON_WARNING_SOLID_AVAILABLE, ICON_WARNING_SOLID_CODEPOINTS),
+            Icon::Watson => (ICON_WATSON_AVAILABLE, ICON_WATSON_CODEPOINTS),
+            Icon::Whisk => (ICON_WHISK_AVAILABLE, ICON_WHISK_CODEPOINTS),
         }
     }
 }
 
+/// Looks an icon up by its exact map name; unknown names yield `IconError::IconNotFound`.
+///
+/// Binary-searches `ICON_NAMES`, which is sorted and index-aligned with `Icon::ALL`.
+impl TryFrom<&str> for Icon {
+    type Error = crate::core::IconError;
+
+    fn try_from(name: &str) -> Result<Self, crate::core::IconError> {
+        ICON_NAMES
+            .binary_search(&name)
+            .map(|index| Icon::ALL[index])
+            .map_err(|_| crate::core::IconError::IconNotFound {
+                pack: PACK_ID,
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Serializes as the icon's map name, e.g. `"alarm"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Icon {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes from the icon's map name through `TryFrom<&str>`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Icon {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Icon::try_from(name.as_str()).map_err(|_| {
+            <D::Error as serde::de::Error>::custom(format_args!("unknown {PACK_ID} icon '{name}'"))
+        })
+    }
+}
+
+impl From<Icon> for &'static str {
+    fn from(icon: Icon) -> Self {
+        icon.name()
+    }
+}
+
+pub fn icons() -> impl Iterator<Item = Icon> {
+    Icon::ALL.iter().copied()
+}
+
 pub const ICON_NAMES: &[&str] = &[
     "add",
     "add-outline",
@@ -543,6 +1142,1659 @@ pub const ICON_NAMES: &[&str] = &[
     "whisk",
 ];
 
+/// Icon names as constants, e.g. for `try_icon(pack, names::ALARM, ..)`.
+pub mod names {
+    pub const ADD: &str = "add";
+    pub const ADD_OUTLINE: &str = "add-outline";
+    pub const ADD_SOLID: &str = "add-solid";
+    pub const API: &str = "api";
+    pub const APIS: &str = "apis";
+    pub const APP_SERVICES: &str = "app-services";
+    pub const APPLICATIONS: &str = "applications";
+    pub const APPS: &str = "apps";
+    pub const ARROW_DOWN: &str = "arrow-down";
+    pub const ARROW_LEFT: &str = "arrow-left";
+    pub const ARROW_RIGHT: &str = "arrow-right";
+    pub const ARROW_UP: &str = "arrow-up";
+    pub const ARROWS: &str = "arrows";
+    pub const ASLEEP: &str = "asleep";
+    pub const ATTACHMENT: &str = "attachment";
+    pub const AWAKE: &str = "awake";
+    pub const BACK_TO_TOP: &str = "back-to-top";
+    pub const BLOCK_CHAIN: &str = "block-chain";
+    pub const CALENDAR: &str = "calendar";
+    pub const CARET_DOWN: &str = "caret-down";
+    pub const CARET_LEFT: &str = "caret-left";
+    pub const CARET_RIGHT: &str = "caret-right";
+    pub const CARET_UP: &str = "caret-up";
+    pub const CF_APPS: &str = "cf-apps";
+    pub const CHAT: &str = "chat";
+    pub const CHECKMARK: &str = "checkmark";
+    pub const CHECKMARK_OUTLINE: &str = "checkmark-outline";
+    pub const CHECKMARK_SOLID: &str = "checkmark-solid";
+    pub const CHEVRON_DOWN: &str = "chevron-down";
+    pub const CHEVRON_LEFT: &str = "chevron-left";
+    pub const CHEVRON_RIGHT: &str = "chevron-right";
+    pub const CHEVRON_UP: &str = "chevron-up";
+    pub const CLOSE: &str = "close";
+    pub const CLOSE_OUTLINE: &str = "close-outline";
+    pub const CLOSE_SOLID: &str = "close-solid";
+    pub const CLOUD_FOUNDRY: &str = "cloud-foundry";
+    pub const CODE: &str = "code";
+    pub const CONSOLE: &str = "console";
+    pub const CONTAINERS: &str = "containers";
+    pub const COPY: &str = "copy";
+    pub const CRASH: &str = "crash";
+    pub const DASHBOARD: &str = "dashboard";
+    pub const DATA: &str = "data";
+    pub const DELETE: &str = "delete";
+    pub const DEVICES: &str = "devices";
+    pub const DEVOPS: &str = "devops";
+    pub const DOCUMENT: &str = "document";
+    pub const DOLLARS: &str = "dollars";
+    pub const DOWNLOAD: &str = "download";
+    pub const DRAGGABLE: &str = "draggable";
+    pub const EDIT: &str = "edit";
+    pub const EMAIL: &str = "email";
+    pub const ERROR: &str = "error";
+    pub const ERROR_OUTLINE: &str = "error-outline";
+    pub const ERROR_SOLID: &str = "error-solid";
+    pub const FAVORITE: &str = "favorite";
+    pub const FAVORITE_OUTLINE: &str = "favorite-outline";
+    pub const FAVORITE_SOLID: &str = "favorite-solid";
+    pub const FILTER: &str = "filter";
+    pub const FINANCE: &str = "finance";
+    pub const FINANCIAL: &str = "financial";
+    pub const FOLDER: &str = "folder";
+    pub const FOLDER_ADD: &str = "folder-add";
+    pub const FORUM: &str = "forum";
+    pub const FUNCTIONS: &str = "functions";
+    pub const GRID: &str = "grid";
+    pub const HEADER_AVATAR: &str = "header-avatar";
+    pub const HEADER_CHEVRON: &str = "header-chevron";
+    pub const HEADER_CLOSE: &str = "header-close";
+    pub const HEADER_CONTACT: &str = "header-contact";
+    pub const HEADER_DOCS: &str = "header-docs";
+    pub const HEADER_HAMBURGER: &str = "header-hamburger";
+    pub const HEADER_HELP: &str = "header-help";
+    pub const HEADER_NOTIFICATION: &str = "header-notification";
+    pub const HEADER_SEARCH: &str = "header-search";
+    pub const HEADER_TICKET: &str = "header-ticket";
+    pub const HEADER_USER: &str = "header-user";
+    pub const HELP: &str = "help";
+    pub const HELP_OUTLINE: &str = "help-outline";
+    pub const HELP_SOLID: &str = "help-solid";
+    pub const HPA: &str = "hpa";
+    pub const HPA_STRESS: &str = "hpa-stress";
+    pub const INFO: &str = "info";
+    pub const INFO_OUTLINE: &str = "info-outline";
+    pub const INFO_SOLID: &str = "info-solid";
+    pub const INFRASTRUCTURE: &str = "infrastructure";
+    pub const INTEGRATION: &str = "integration";
+    pub const IOT: &str = "iot";
+    pub const LAUNCH: &str = "launch";
+    pub const LIGHT: &str = "light";
+    pub const LINK: &str = "link";
+    pub const LIST: &str = "list";
+    pub const LOCKED: &str = "locked";
+    pub const MAXIMIZE: &str = "maximize";
+    pub const MENU: &str = "menu";
+    pub const MINIMIZE: &str = "minimize";
+    pub const MOBILE: &str = "mobile";
+    pub const NETWORK: &str = "network";
+    pub const NEW_TAB: &str = "new-tab";
+    pub const NOTIFICATION_OFF: &str = "notification-off";
+    pub const NOTIFICATION_ON: &str = "notification-on";
+    pub const OPEN_WHISK: &str = "open-whisk";
+    pub const OVERFLOW_MENU: &str = "overflow-menu";
+    pub const PA: &str = "pa";
+    pub const PA_STRESS: &str = "pa-stress";
+    pub const PAUSE: &str = "pause";
+    pub const PAUSE_OUTLINE: &str = "pause-outline";
+    pub const PAUSE_SOLID: &str = "pause-solid";
+    pub const PLAY: &str = "play";
+    pub const PLAY_OUTLINE: &str = "play-outline";
+    pub const PLAY_SOLID: &str = "play-solid";
+    pub const PORTFOLIO: &str = "portfolio";
+    pub const POWER: &str = "power";
+    pub const PREDICTIVE: &str = "predictive";
+    pub const PURCHASE: &str = "purchase";
+    pub const RESTART: &str = "restart";
+    pub const SAVE: &str = "save";
+    pub const SCHEMATICS: &str = "schematics";
+    pub const SEARCH: &str = "search";
+    pub const SECURITY: &str = "security";
+    pub const SERVICES: &str = "services";
+    pub const SETTINGS: &str = "settings";
+    pub const STAR_OUTLINE: &str = "star-outline";
+    pub const STAR_SOLID: &str = "star-solid";
+    pub const START: &str = "start";
+    pub const START_OUTLINE: &str = "start-outline";
+    pub const STOP: &str = "stop";
+    pub const STOP_OUTLINE: &str = "stop-outline";
+    pub const STOP_SOLID: &str = "stop-solid";
+    pub const STORAGE: &str = "storage";
+    pub const SUBTRACT: &str = "subtract";
+    pub const SUBTRACT_OUTLINE: &str = "subtract-outline";
+    pub const SUBTRACT_SOLID: &str = "subtract-solid";
+    pub const TERMINAL: &str = "terminal";
+    pub const UNLOCK: &str = "unlock";
+    pub const UNLOCKED: &str = "unlocked";
+    pub const UPLOAD: &str = "upload";
+    pub const USER: &str = "user";
+    pub const VISIBILITY_OFF: &str = "visibility-off";
+    pub const VISIBILITY_ON: &str = "visibility-on";
+    pub const WARNING: &str = "warning";
+    pub const WARNING_OUTLINE: &str = "warning-outline";
+    pub const WARNING_SOLID: &str = "warning-solid";
+    pub const WATSON: &str = "watson";
+    pub const WHISK: &str = "whisk";
+}
+
+pub(crate) const ICON_NAMES_LOWERCASE: &[(&str, &str)] = &[
+    ("add", "add"),
+    ("add-outline", "add-outline"),
+    ("add-solid", "add-solid"),
+    ("api", "api"),
+    ("apis", "apis"),
+    ("app-services", "app-services"),
+    ("applications", "applications"),
+    ("apps", "apps"),
+    ("arrow-down", "arrow-down"),
+    ("arrow-left", "arrow-left"),
+    ("arrow-right", "arrow-right"),
+    ("arrow-up", "arrow-up"),
+    ("arrows", "arrows"),
+    ("asleep", "asleep"),
+    ("attachment", "attachment"),
+    ("awake", "awake"),
+    ("back-to-top", "back-to-top"),
+    ("block-chain", "block-chain"),
+    ("calendar", "calendar"),
+    ("caret-down", "caret-down"),
+    ("caret-left", "caret-left"),
+    ("caret-right", "caret-right"),
+    ("caret-up", "caret-up"),
+    ("cf-apps", "cf-apps"),
+    ("chat", "chat"),
+    ("checkmark", "checkmark"),
+    ("checkmark-outline", "checkmark-outline"),
+    ("checkmark-solid", "checkmark-solid"),
+    ("chevron-down", "chevron-down"),
+    ("chevron-left", "chevron-left"),
+    ("chevron-right", "chevron-right"),
+    ("chevron-up", "chevron-up"),
+    ("close", "close"),
+    ("close-outline", "close-outline"),
+    ("close-solid", "close-solid"),
+    ("cloud-foundry", "cloud-foundry"),
+    ("code", "code"),
+    ("console", "console"),
+    ("containers", "containers"),
+    ("copy", "copy"),
+    ("crash", "crash"),
+    ("dashboard", "dashboard"),
+    ("data", "data"),
+    ("delete", "delete"),
+    ("devices", "devices"),
+    ("devops", "devops"),
+    ("document", "document"),
+    ("dollars", "dollars"),
+    ("download", "download"),
+    ("draggable", "draggable"),
+    ("edit", "edit"),
+    ("email", "email"),
+    ("error", "error"),
+    ("error-outline", "error-outline"),
+    ("error-solid", "error-solid"),
+    ("favorite", "favorite"),
+    ("favorite-outline", "favorite-outline"),
+    ("favorite-solid", "favorite-solid"),
+    ("filter", "filter"),
+    ("finance", "finance"),
+    ("financial", "financial"),
+    ("folder", "folder"),
+    ("folder-add", "folder-add"),
+    ("forum", "forum"),
+    ("functions", "functions"),
+    ("grid", "grid"),
+    ("header-avatar", "header-avatar"),
+    ("header-chevron", "header-chevron"),
+    ("header-close", "header-close"),
+    ("header-contact", "header-contact"),
+    ("header-docs", "header-docs"),
+    ("header-hamburger", "header-hamburger"),
+    ("header-help", "header-help"),
+    ("header-notification", "header-notification"),
+    ("header-search", "header-search"),
+    ("header-ticket", "header-ticket"),
+    ("header-user", "header-user"),
+    ("help", "help"),
+    ("help-outline", "help-outline"),
+    ("help-solid", "help-solid"),
+    ("hpa", "hpa"),
+    ("hpa-stress", "hpa-stress"),
+    ("info", "info"),
+    ("info-outline", "info-outline"),
+    ("info-solid", "info-solid"),
+    ("infrastructure", "infrastructure"),
+    ("integration", "integration"),
+    ("iot", "iot"),
+    ("launch", "launch"),
+    ("light", "light"),
+    ("link", "link"),
+    ("list", "list"),
+    ("locked", "locked"),
+    ("maximize", "maximize"),
+    ("menu", "menu"),
+    ("minimize", "minimize"),
+    ("mobile", "mobile"),
+    ("network", "network"),
+    ("new-tab", "new-tab"),
+    ("notification-off", "notification-off"),
+    ("notification-on", "notification-on"),
+    ("open-whisk", "open-whisk"),
+    ("overflow-menu", "overflow-menu"),
+    ("pa", "pa"),
+    ("pa-stress", "pa-stress"),
+    ("pause", "pause"),
+    ("pause-outline", "pause-outline"),
+    ("pause-solid", "pause-solid"),
+    ("play", "play"),
+    ("play-outline", "play-outline"),
+    ("play-solid", "play-solid"),
+    ("portfolio", "portfolio"),
+    ("power", "power"),
+    ("predictive", "predictive"),
+    ("purchase", "purchase"),
+    ("restart", "restart"),
+    ("save", "save"),
+    ("schematics", "schematics"),
+    ("search", "search"),
+    ("security", "security"),
+    ("services", "services"),
+    ("settings", "settings"),
+    ("star-outline", "star-outline"),
+    ("star-solid", "star-solid"),
+    ("start", "start"),
+    ("start-outline", "start-outline"),
+    ("stop", "stop"),
+    ("stop-outline", "stop-outline"),
+    ("stop-solid", "stop-solid"),
+    ("storage", "storage"),
+    ("subtract", "subtract"),
+    ("subtract-outline", "subtract-outline"),
+    ("subtract-solid", "subtract-solid"),
+    ("terminal", "terminal"),
+    ("unlock", "unlock"),
+    ("unlocked", "unlocked"),
+    ("upload", "upload"),
+    ("user", "user"),
+    ("visibility-off", "visibility-off"),
+    ("visibility-on", "visibility-on"),
+    ("warning", "warning"),
+    ("warning-outline", "warning-outline"),
+    ("warning-solid", "warning-solid"),
+    ("watson", "watson"),
+    ("whisk", "whisk"),
+];
+
+/// `(variant, codepoint, name)` for every mapped glyph, sorted for `binary_search`.
+pub const ICON_BY_CODEPOINT: &[(VariantKey, u32, &str)] = &[
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE000,
+        "add",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE001,
+        "api",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE002,
+        "apis",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE003,
+        "app-services",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE004,
+        "applications",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE005,
+        "apps",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE006,
+        "arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE007,
+        "arrow-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE008,
+        "arrow-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE009,
+        "arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "arrows",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "asleep",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "attachment",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "awake",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00E,
+        "back-to-top",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00F,
+        "block-chain",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE010,
+        "calendar",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE011,
+        "caret-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE012,
+        "caret-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE013,
+        "caret-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE014,
+        "caret-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE015,
+        "cf-apps",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE016,
+        "chat",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE017,
+        "checkmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE018,
+        "chevron-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE019,
+        "chevron-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01A,
+        "chevron-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01B,
+        "chevron-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01C,
+        "close",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01D,
+        "cloud-foundry",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01E,
+        "code",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01F,
+        "console",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE020,
+        "containers",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE021,
+        "copy",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE022,
+        "crash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE023,
+        "dashboard",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE024,
+        "data",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE025,
+        "delete",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE026,
+        "devices",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE027,
+        "devops",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE028,
+        "document",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE029,
+        "dollars",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02A,
+        "download",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02B,
+        "draggable",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02C,
+        "edit",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02D,
+        "email",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02E,
+        "error",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02F,
+        "favorite",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE030,
+        "filter",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE031,
+        "finance",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE032,
+        "financial",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE033,
+        "folder-add",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE034,
+        "folder",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE035,
+        "forum",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE036,
+        "functions",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE037,
+        "grid",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE038,
+        "header-avatar",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE039,
+        "header-chevron",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03A,
+        "header-close",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03B,
+        "header-contact",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03C,
+        "header-docs",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03D,
+        "header-hamburger",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03E,
+        "header-help",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03F,
+        "header-notification",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE040,
+        "header-search",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE041,
+        "header-ticket",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE042,
+        "header-user",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE043,
+        "help",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE044,
+        "hpa-stress",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE045,
+        "hpa",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE046,
+        "info",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE047,
+        "infrastructure",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE048,
+        "integration",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE049,
+        "iot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04A,
+        "launch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04B,
+        "light",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04C,
+        "link",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04D,
+        "list",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04E,
+        "locked",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04F,
+        "maximize",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE050,
+        "menu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE051,
+        "minimize",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE052,
+        "mobile",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE053,
+        "network",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE054,
+        "new-tab",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE055,
+        "notification-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE056,
+        "notification-on",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE057,
+        "open-whisk",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE058,
+        "overflow-menu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE059,
+        "pa-stress",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05A,
+        "pa",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05B,
+        "pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05C,
+        "play",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05D,
+        "portfolio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05E,
+        "power",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05F,
+        "predictive",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE060,
+        "purchase",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE061,
+        "restart",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE062,
+        "save",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE063,
+        "schematics",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE064,
+        "search",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE065,
+        "security",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE066,
+        "services",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE067,
+        "settings",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE068,
+        "start",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE069,
+        "stop",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06A,
+        "storage",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06B,
+        "terminal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06C,
+        "unlock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06D,
+        "unlocked",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06E,
+        "upload",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06F,
+        "user",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE070,
+        "visibility-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE071,
+        "visibility-on",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE072,
+        "warning",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE073,
+        "watson",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE074,
+        "whisk",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE000,
+        "add-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE001,
+        "checkmark-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE002,
+        "close-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE003,
+        "error-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE004,
+        "favorite-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE005,
+        "help-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE006,
+        "info-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE007,
+        "pause-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE008,
+        "play-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE009,
+        "star-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "stop-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "subtract-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "warning-solid",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE000,
+        "add-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE001,
+        "checkmark-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE002,
+        "close-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE003,
+        "error-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE004,
+        "favorite-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE005,
+        "help-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE006,
+        "info-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE007,
+        "pause-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE008,
+        "play-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE009,
+        "star-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "start-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "stop-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "subtract-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "warning-outline",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE000,
+        "add",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE001,
+        "asleep",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE002,
+        "checkmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE003,
+        "close",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE004,
+        "copy",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE005,
+        "delete",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE006,
+        "download",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE007,
+        "edit",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE008,
+        "error",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE009,
+        "filter",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "folder",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "help",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "info",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "launch",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE00E,
+        "light",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE00F,
+        "pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE010,
+        "play",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE011,
+        "power",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE012,
+        "restart",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE013,
+        "search",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE014,
+        "settings",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE015,
+        "start",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE016,
+        "stop",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE017,
+        "subtract",
+    ),
+    (
+        VariantKey {
+            style: Style::Glyph,
+            size: Size::Regular,
+        },
+        0xE018,
+        "warning",
+    ),
+];
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct VariantInfo {
     pub key: VariantKey,
@@ -580,6 +2832,7 @@ pub(crate) const VARIANTS: &[VariantInfo] = &[
     },
 ];
 
+/// `add` — U+E000 (available in Glyph, Regular)
 const ICON_ADD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -597,6 +2850,7 @@ const ICON_ADD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `add-outline` — U+E000 (available in Outline)
 const ICON_ADD_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -605,6 +2859,7 @@ const ICON_ADD_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57344,
 )];
 
+/// `add-solid` — U+E000 (available in Filled)
 const ICON_ADD_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -613,6 +2868,7 @@ const ICON_ADD_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57344,
 )];
 
+/// `api` — U+E001 (available in Regular)
 const ICON_API_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -621,6 +2877,7 @@ const ICON_API_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57345,
 )];
 
+/// `apis` — U+E002 (available in Regular)
 const ICON_APIS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -629,6 +2886,7 @@ const ICON_APIS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57346,
 )];
 
+/// `app-services` — U+E003 (available in Regular)
 const ICON_APP_SERVICES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -637,6 +2895,7 @@ const ICON_APP_SERVICES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57347,
 )];
 
+/// `applications` — U+E004 (available in Regular)
 const ICON_APPLICATIONS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -645,6 +2904,7 @@ const ICON_APPLICATIONS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57348,
 )];
 
+/// `apps` — U+E005 (available in Regular)
 const ICON_APPS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -653,6 +2913,7 @@ const ICON_APPS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57349,
 )];
 
+/// `arrow-down` — U+E006 (available in Regular)
 const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -661,6 +2922,7 @@ const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57350,
 )];
 
+/// `arrow-left` — U+E007 (available in Regular)
 const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -669,6 +2931,7 @@ const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57351,
 )];
 
+/// `arrow-right` — U+E008 (available in Regular)
 const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -677,6 +2940,7 @@ const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57352,
 )];
 
+/// `arrow-up` — U+E009 (available in Regular)
 const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -685,6 +2949,7 @@ const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57353,
 )];
 
+/// `arrows` — U+E00A (available in Regular)
 const ICON_ARROWS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -693,6 +2958,7 @@ const ICON_ARROWS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57354,
 )];
 
+/// `asleep` — available in Glyph U+E001, Regular U+E00B
 const ICON_ASLEEP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -710,6 +2976,7 @@ const ICON_ASLEEP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `attachment` — U+E00C (available in Regular)
 const ICON_ATTACHMENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -718,6 +2985,7 @@ const ICON_ATTACHMENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57356,
 )];
 
+/// `awake` — U+E00D (available in Regular)
 const ICON_AWAKE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -726,6 +2994,7 @@ const ICON_AWAKE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57357,
 )];
 
+/// `back-to-top` — U+E00E (available in Regular)
 const ICON_BACK_TO_TOP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -734,6 +3003,7 @@ const ICON_BACK_TO_TOP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57358,
 )];
 
+/// `block-chain` — U+E00F (available in Regular)
 const ICON_BLOCK_CHAIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -742,6 +3012,7 @@ const ICON_BLOCK_CHAIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57359,
 )];
 
+/// `calendar` — U+E010 (available in Regular)
 const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -750,6 +3021,7 @@ const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57360,
 )];
 
+/// `caret-down` — U+E011 (available in Regular)
 const ICON_CARET_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -758,6 +3030,7 @@ const ICON_CARET_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57361,
 )];
 
+/// `caret-left` — U+E012 (available in Regular)
 const ICON_CARET_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -766,6 +3039,7 @@ const ICON_CARET_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57362,
 )];
 
+/// `caret-right` — U+E013 (available in Regular)
 const ICON_CARET_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -774,6 +3048,7 @@ const ICON_CARET_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57363,
 )];
 
+/// `caret-up` — U+E014 (available in Regular)
 const ICON_CARET_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -782,6 +3057,7 @@ const ICON_CARET_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57364,
 )];
 
+/// `cf-apps` — U+E015 (available in Regular)
 const ICON_CF_APPS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -790,6 +3066,7 @@ const ICON_CF_APPS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57365,
 )];
 
+/// `chat` — U+E016 (available in Regular)
 const ICON_CHAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -798,6 +3075,7 @@ const ICON_CHAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57366,
 )];
 
+/// `checkmark` — available in Glyph U+E002, Regular U+E017
 const ICON_CHECKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -815,6 +3093,7 @@ const ICON_CHECKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `checkmark-outline` — U+E001 (available in Outline)
 const ICON_CHECKMARK_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -823,6 +3102,7 @@ const ICON_CHECKMARK_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57345,
 )];
 
+/// `checkmark-solid` — U+E001 (available in Filled)
 const ICON_CHECKMARK_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -831,6 +3111,7 @@ const ICON_CHECKMARK_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57345,
 )];
 
+/// `chevron-down` — U+E018 (available in Regular)
 const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -839,6 +3120,7 @@ const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57368,
 )];
 
+/// `chevron-left` — U+E019 (available in Regular)
 const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -847,6 +3129,7 @@ const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57369,
 )];
 
+/// `chevron-right` — U+E01A (available in Regular)
 const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -855,6 +3138,7 @@ const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57370,
 )];
 
+/// `chevron-up` — U+E01B (available in Regular)
 const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -863,6 +3147,7 @@ const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57371,
 )];
 
+/// `close` — available in Glyph U+E003, Regular U+E01C
 const ICON_CLOSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -880,6 +3165,7 @@ const ICON_CLOSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `close-outline` — U+E002 (available in Outline)
 const ICON_CLOSE_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -888,6 +3174,7 @@ const ICON_CLOSE_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57346,
 )];
 
+/// `close-solid` — U+E002 (available in Filled)
 const ICON_CLOSE_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -896,6 +3183,7 @@ const ICON_CLOSE_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57346,
 )];
 
+/// `cloud-foundry` — U+E01D (available in Regular)
 const ICON_CLOUD_FOUNDRY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -904,6 +3192,7 @@ const ICON_CLOUD_FOUNDRY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57373,
 )];
 
+/// `code` — U+E01E (available in Regular)
 const ICON_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -912,6 +3201,7 @@ const ICON_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57374,
 )];
 
+/// `console` — U+E01F (available in Regular)
 const ICON_CONSOLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -920,6 +3210,7 @@ const ICON_CONSOLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57375,
 )];
 
+/// `containers` — U+E020 (available in Regular)
 const ICON_CONTAINERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -928,6 +3219,7 @@ const ICON_CONTAINERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57376,
 )];
 
+/// `copy` — available in Glyph U+E004, Regular U+E021
 const ICON_COPY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -945,6 +3237,7 @@ const ICON_COPY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `crash` — U+E022 (available in Regular)
 const ICON_CRASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -953,6 +3246,7 @@ const ICON_CRASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57378,
 )];
 
+/// `dashboard` — U+E023 (available in Regular)
 const ICON_DASHBOARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -961,6 +3255,7 @@ const ICON_DASHBOARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57379,
 )];
 
+/// `data` — U+E024 (available in Regular)
 const ICON_DATA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -969,6 +3264,7 @@ const ICON_DATA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57380,
 )];
 
+/// `delete` — available in Glyph U+E005, Regular U+E025
 const ICON_DELETE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -986,6 +3282,7 @@ const ICON_DELETE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `devices` — U+E026 (available in Regular)
 const ICON_DEVICES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -994,6 +3291,7 @@ const ICON_DEVICES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57382,
 )];
 
+/// `devops` — U+E027 (available in Regular)
 const ICON_DEVOPS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1002,6 +3300,7 @@ const ICON_DEVOPS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57383,
 )];
 
+/// `document` — U+E028 (available in Regular)
 const ICON_DOCUMENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1010,6 +3309,7 @@ const ICON_DOCUMENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57384,
 )];
 
+/// `dollars` — U+E029 (available in Regular)
 const ICON_DOLLARS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1018,6 +3318,7 @@ const ICON_DOLLARS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57385,
 )];
 
+/// `download` — available in Glyph U+E006, Regular U+E02A
 const ICON_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1035,6 +3336,7 @@ const ICON_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `draggable` — U+E02B (available in Regular)
 const ICON_DRAGGABLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1043,6 +3345,7 @@ const ICON_DRAGGABLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57387,
 )];
 
+/// `edit` — available in Glyph U+E007, Regular U+E02C
 const ICON_EDIT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1060,6 +3363,7 @@ const ICON_EDIT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `email` — U+E02D (available in Regular)
 const ICON_EMAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1068,6 +3372,7 @@ const ICON_EMAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57389,
 )];
 
+/// `error` — available in Glyph U+E008, Regular U+E02E
 const ICON_ERROR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1085,6 +3390,7 @@ const ICON_ERROR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `error-outline` — U+E003 (available in Outline)
 const ICON_ERROR_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1093,6 +3399,7 @@ const ICON_ERROR_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57347,
 )];
 
+/// `error-solid` — U+E003 (available in Filled)
 const ICON_ERROR_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1101,6 +3408,7 @@ const ICON_ERROR_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57347,
 )];
 
+/// `favorite` — U+E02F (available in Regular)
 const ICON_FAVORITE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1109,6 +3417,7 @@ const ICON_FAVORITE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57391,
 )];
 
+/// `favorite-outline` — U+E004 (available in Outline)
 const ICON_FAVORITE_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1117,6 +3426,7 @@ const ICON_FAVORITE_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57348,
 )];
 
+/// `favorite-solid` — U+E004 (available in Filled)
 const ICON_FAVORITE_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1125,6 +3435,7 @@ const ICON_FAVORITE_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57348,
 )];
 
+/// `filter` — available in Glyph U+E009, Regular U+E030
 const ICON_FILTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1142,6 +3453,7 @@ const ICON_FILTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `finance` — U+E031 (available in Regular)
 const ICON_FINANCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1150,6 +3462,7 @@ const ICON_FINANCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57393,
 )];
 
+/// `financial` — U+E032 (available in Regular)
 const ICON_FINANCIAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1158,6 +3471,7 @@ const ICON_FINANCIAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57394,
 )];
 
+/// `folder` — available in Glyph U+E00A, Regular U+E034
 const ICON_FOLDER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1175,6 +3489,7 @@ const ICON_FOLDER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `folder-add` — U+E033 (available in Regular)
 const ICON_FOLDER_ADD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1183,6 +3498,7 @@ const ICON_FOLDER_ADD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57395,
 )];
 
+/// `forum` — U+E035 (available in Regular)
 const ICON_FORUM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1191,6 +3507,7 @@ const ICON_FORUM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57397,
 )];
 
+/// `functions` — U+E036 (available in Regular)
 const ICON_FUNCTIONS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1199,6 +3516,7 @@ const ICON_FUNCTIONS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57398,
 )];
 
+/// `grid` — U+E037 (available in Regular)
 const ICON_GRID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1207,6 +3525,7 @@ const ICON_GRID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57399,
 )];
 
+/// `header-avatar` — U+E038 (available in Regular)
 const ICON_HEADER_AVATAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1215,6 +3534,7 @@ const ICON_HEADER_AVATAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57400,
 )];
 
+/// `header-chevron` — U+E039 (available in Regular)
 const ICON_HEADER_CHEVRON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1223,6 +3543,7 @@ const ICON_HEADER_CHEVRON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57401,
 )];
 
+/// `header-close` — U+E03A (available in Regular)
 const ICON_HEADER_CLOSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1231,6 +3552,7 @@ const ICON_HEADER_CLOSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57402,
 )];
 
+/// `header-contact` — U+E03B (available in Regular)
 const ICON_HEADER_CONTACT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1239,6 +3561,7 @@ const ICON_HEADER_CONTACT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57403,
 )];
 
+/// `header-docs` — U+E03C (available in Regular)
 const ICON_HEADER_DOCS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1247,6 +3570,7 @@ const ICON_HEADER_DOCS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57404,
 )];
 
+/// `header-hamburger` — U+E03D (available in Regular)
 const ICON_HEADER_HAMBURGER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1255,6 +3579,7 @@ const ICON_HEADER_HAMBURGER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57405,
 )];
 
+/// `header-help` — U+E03E (available in Regular)
 const ICON_HEADER_HELP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1263,6 +3588,7 @@ const ICON_HEADER_HELP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57406,
 )];
 
+/// `header-notification` — U+E03F (available in Regular)
 const ICON_HEADER_NOTIFICATION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1271,6 +3597,7 @@ const ICON_HEADER_NOTIFICATION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57407,
 )];
 
+/// `header-search` — U+E040 (available in Regular)
 const ICON_HEADER_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1279,6 +3606,7 @@ const ICON_HEADER_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57408,
 )];
 
+/// `header-ticket` — U+E041 (available in Regular)
 const ICON_HEADER_TICKET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1287,6 +3615,7 @@ const ICON_HEADER_TICKET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57409,
 )];
 
+/// `header-user` — U+E042 (available in Regular)
 const ICON_HEADER_USER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1295,6 +3624,7 @@ const ICON_HEADER_USER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57410,
 )];
 
+/// `help` — available in Glyph U+E00B, Regular U+E043
 const ICON_HELP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1312,6 +3642,7 @@ const ICON_HELP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `help-outline` — U+E005 (available in Outline)
 const ICON_HELP_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1320,6 +3651,7 @@ const ICON_HELP_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57349,
 )];
 
+/// `help-solid` — U+E005 (available in Filled)
 const ICON_HELP_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1328,6 +3660,7 @@ const ICON_HELP_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57349,
 )];
 
+/// `hpa` — U+E045 (available in Regular)
 const ICON_HPA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1336,6 +3669,7 @@ const ICON_HPA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57413,
 )];
 
+/// `hpa-stress` — U+E044 (available in Regular)
 const ICON_HPA_STRESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1344,6 +3678,7 @@ const ICON_HPA_STRESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57412,
 )];
 
+/// `info` — available in Glyph U+E00C, Regular U+E046
 const ICON_INFO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1361,6 +3696,7 @@ const ICON_INFO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `info-outline` — U+E006 (available in Outline)
 const ICON_INFO_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1369,6 +3705,7 @@ const ICON_INFO_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57350,
 )];
 
+/// `info-solid` — U+E006 (available in Filled)
 const ICON_INFO_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1377,6 +3714,7 @@ const ICON_INFO_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57350,
 )];
 
+/// `infrastructure` — U+E047 (available in Regular)
 const ICON_INFRASTRUCTURE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1385,6 +3723,7 @@ const ICON_INFRASTRUCTURE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57415,
 )];
 
+/// `integration` — U+E048 (available in Regular)
 const ICON_INTEGRATION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1393,6 +3732,7 @@ const ICON_INTEGRATION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57416,
 )];
 
+/// `iot` — U+E049 (available in Regular)
 const ICON_IOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1401,6 +3741,7 @@ const ICON_IOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57417,
 )];
 
+/// `launch` — available in Glyph U+E00D, Regular U+E04A
 const ICON_LAUNCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1418,6 +3759,7 @@ const ICON_LAUNCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `light` — available in Glyph U+E00E, Regular U+E04B
 const ICON_LIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1435,6 +3777,7 @@ const ICON_LIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `link` — U+E04C (available in Regular)
 const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1443,6 +3786,7 @@ const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57420,
 )];
 
+/// `list` — U+E04D (available in Regular)
 const ICON_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1451,6 +3795,7 @@ const ICON_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57421,
 )];
 
+/// `locked` — U+E04E (available in Regular)
 const ICON_LOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1459,6 +3804,7 @@ const ICON_LOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57422,
 )];
 
+/// `maximize` — U+E04F (available in Regular)
 const ICON_MAXIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1467,6 +3813,7 @@ const ICON_MAXIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57423,
 )];
 
+/// `menu` — U+E050 (available in Regular)
 const ICON_MENU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1475,6 +3822,7 @@ const ICON_MENU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57424,
 )];
 
+/// `minimize` — U+E051 (available in Regular)
 const ICON_MINIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1483,6 +3831,7 @@ const ICON_MINIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57425,
 )];
 
+/// `mobile` — U+E052 (available in Regular)
 const ICON_MOBILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1491,6 +3840,7 @@ const ICON_MOBILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57426,
 )];
 
+/// `network` — U+E053 (available in Regular)
 const ICON_NETWORK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1499,6 +3849,7 @@ const ICON_NETWORK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57427,
 )];
 
+/// `new-tab` — U+E054 (available in Regular)
 const ICON_NEW_TAB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1507,6 +3858,7 @@ const ICON_NEW_TAB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57428,
 )];
 
+/// `notification-off` — U+E055 (available in Regular)
 const ICON_NOTIFICATION_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1515,6 +3867,7 @@ const ICON_NOTIFICATION_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57429,
 )];
 
+/// `notification-on` — U+E056 (available in Regular)
 const ICON_NOTIFICATION_ON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1523,6 +3876,7 @@ const ICON_NOTIFICATION_ON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57430,
 )];
 
+/// `open-whisk` — U+E057 (available in Regular)
 const ICON_OPEN_WHISK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1531,6 +3885,7 @@ const ICON_OPEN_WHISK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57431,
 )];
 
+/// `overflow-menu` — U+E058 (available in Regular)
 const ICON_OVERFLOW_MENU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1539,6 +3894,7 @@ const ICON_OVERFLOW_MENU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57432,
 )];
 
+/// `pa` — U+E05A (available in Regular)
 const ICON_PA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1547,6 +3903,7 @@ const ICON_PA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57434,
 )];
 
+/// `pa-stress` — U+E059 (available in Regular)
 const ICON_PA_STRESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1555,6 +3912,7 @@ const ICON_PA_STRESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57433,
 )];
 
+/// `pause` — available in Glyph U+E00F, Regular U+E05B
 const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1572,6 +3930,7 @@ const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pause-outline` — U+E007 (available in Outline)
 const ICON_PAUSE_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1580,6 +3939,7 @@ const ICON_PAUSE_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57351,
 )];
 
+/// `pause-solid` — U+E007 (available in Filled)
 const ICON_PAUSE_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1588,6 +3948,7 @@ const ICON_PAUSE_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57351,
 )];
 
+/// `play` — available in Glyph U+E010, Regular U+E05C
 const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1605,6 +3966,7 @@ const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `play-outline` — U+E008 (available in Outline)
 const ICON_PLAY_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1613,6 +3975,7 @@ const ICON_PLAY_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57352,
 )];
 
+/// `play-solid` — U+E008 (available in Filled)
 const ICON_PLAY_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1621,6 +3984,7 @@ const ICON_PLAY_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57352,
 )];
 
+/// `portfolio` — U+E05D (available in Regular)
 const ICON_PORTFOLIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1629,6 +3993,7 @@ const ICON_PORTFOLIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57437,
 )];
 
+/// `power` — available in Glyph U+E011, Regular U+E05E
 const ICON_POWER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1646,6 +4011,7 @@ const ICON_POWER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `predictive` — U+E05F (available in Regular)
 const ICON_PREDICTIVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1654,6 +4020,7 @@ const ICON_PREDICTIVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57439,
 )];
 
+/// `purchase` — U+E060 (available in Regular)
 const ICON_PURCHASE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1662,6 +4029,7 @@ const ICON_PURCHASE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57440,
 )];
 
+/// `restart` — available in Glyph U+E012, Regular U+E061
 const ICON_RESTART_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1679,6 +4047,7 @@ const ICON_RESTART_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `save` — U+E062 (available in Regular)
 const ICON_SAVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1687,6 +4056,7 @@ const ICON_SAVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57442,
 )];
 
+/// `schematics` — U+E063 (available in Regular)
 const ICON_SCHEMATICS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1695,6 +4065,7 @@ const ICON_SCHEMATICS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57443,
 )];
 
+/// `search` — available in Glyph U+E013, Regular U+E064
 const ICON_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1712,6 +4083,7 @@ const ICON_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `security` — U+E065 (available in Regular)
 const ICON_SECURITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1720,6 +4092,7 @@ const ICON_SECURITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57445,
 )];
 
+/// `services` — U+E066 (available in Regular)
 const ICON_SERVICES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1728,6 +4101,7 @@ const ICON_SERVICES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57446,
 )];
 
+/// `settings` — available in Glyph U+E014, Regular U+E067
 const ICON_SETTINGS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1745,6 +4119,7 @@ const ICON_SETTINGS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `star-outline` — U+E009 (available in Outline)
 const ICON_STAR_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1753,6 +4128,7 @@ const ICON_STAR_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57353,
 )];
 
+/// `star-solid` — U+E009 (available in Filled)
 const ICON_STAR_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1761,6 +4137,7 @@ const ICON_STAR_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57353,
 )];
 
+/// `start` — available in Glyph U+E015, Regular U+E068
 const ICON_START_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1778,6 +4155,7 @@ const ICON_START_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `start-outline` — U+E00A (available in Outline)
 const ICON_START_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1786,6 +4164,7 @@ const ICON_START_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57354,
 )];
 
+/// `stop` — available in Glyph U+E016, Regular U+E069
 const ICON_STOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1803,6 +4182,7 @@ const ICON_STOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `stop-outline` — U+E00B (available in Outline)
 const ICON_STOP_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1811,6 +4191,7 @@ const ICON_STOP_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57355,
 )];
 
+/// `stop-solid` — U+E00A (available in Filled)
 const ICON_STOP_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1819,6 +4200,7 @@ const ICON_STOP_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57354,
 )];
 
+/// `storage` — U+E06A (available in Regular)
 const ICON_STORAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1827,6 +4209,7 @@ const ICON_STORAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57450,
 )];
 
+/// `subtract` — U+E017 (available in Glyph)
 const ICON_SUBTRACT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Glyph,
@@ -1835,6 +4218,7 @@ const ICON_SUBTRACT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57367,
 )];
 
+/// `subtract-outline` — U+E00C (available in Outline)
 const ICON_SUBTRACT_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1843,6 +4227,7 @@ const ICON_SUBTRACT_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57356,
 )];
 
+/// `subtract-solid` — U+E00B (available in Filled)
 const ICON_SUBTRACT_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1851,6 +4236,7 @@ const ICON_SUBTRACT_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57355,
 )];
 
+/// `terminal` — U+E06B (available in Regular)
 const ICON_TERMINAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1859,6 +4245,7 @@ const ICON_TERMINAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57451,
 )];
 
+/// `unlock` — U+E06C (available in Regular)
 const ICON_UNLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1867,6 +4254,7 @@ const ICON_UNLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57452,
 )];
 
+/// `unlocked` — U+E06D (available in Regular)
 const ICON_UNLOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1875,6 +4263,7 @@ const ICON_UNLOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57453,
 )];
 
+/// `upload` — U+E06E (available in Regular)
 const ICON_UPLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1883,6 +4272,7 @@ const ICON_UPLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57454,
 )];
 
+/// `user` — U+E06F (available in Regular)
 const ICON_USER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1891,6 +4281,7 @@ const ICON_USER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57455,
 )];
 
+/// `visibility-off` — U+E070 (available in Regular)
 const ICON_VISIBILITY_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1899,6 +4290,7 @@ const ICON_VISIBILITY_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57456,
 )];
 
+/// `visibility-on` — U+E071 (available in Regular)
 const ICON_VISIBILITY_ON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1907,6 +4299,7 @@ const ICON_VISIBILITY_ON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57457,
 )];
 
+/// `warning` — available in Glyph U+E018, Regular U+E072
 const ICON_WARNING_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1924,6 +4317,7 @@ const ICON_WARNING_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `warning-outline` — U+E00D (available in Outline)
 const ICON_WARNING_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Outline,
@@ -1932,6 +4326,7 @@ const ICON_WARNING_OUTLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57357,
 )];
 
+/// `warning-solid` — U+E00C (available in Filled)
 const ICON_WARNING_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Filled,
@@ -1940,6 +4335,7 @@ const ICON_WARNING_SOLID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57356,
 )];
 
+/// `watson` — U+E073 (available in Regular)
 const ICON_WATSON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1948,6 +4344,7 @@ const ICON_WATSON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57459,
 )];
 
+/// `whisk` — U+E074 (available in Regular)
 const ICON_WHISK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2318,12 +4715,14 @@ const ICON_WATSON_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular
 
 const ICON_WHISK_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconCodepoints {
     pub name: &'static str,
     pub codepoints: &'static [(VariantKey, u32)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     IconCodepoints {
         name: "add",
@@ -2907,12 +5306,14 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     },
 ];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconAvailability {
     pub name: &'static str,
     pub available: &'static [(Style, Size)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     IconAvailability {
         name: "add",
@@ -3496,6 +5897,315 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     },
 ];
 
+pub(crate) const ICON_COLORS: &[(&str, [u8; 4])] = &[];
+
+/// Accessible labels, index-aligned with `ICON_NAMES`.
+pub(crate) const ICON_LABELS: &[&str] = &[
+    "Add",
+    "Add Outline",
+    "Add Solid",
+    "Api",
+    "Apis",
+    "App Services",
+    "Applications",
+    "Apps",
+    "Arrow Down",
+    "Arrow Left",
+    "Arrow Right",
+    "Arrow Up",
+    "Arrows",
+    "Asleep",
+    "Attachment",
+    "Awake",
+    "Back To Top",
+    "Block Chain",
+    "Calendar",
+    "Caret Down",
+    "Caret Left",
+    "Caret Right",
+    "Caret Up",
+    "Cf Apps",
+    "Chat",
+    "Checkmark",
+    "Checkmark Outline",
+    "Checkmark Solid",
+    "Chevron Down",
+    "Chevron Left",
+    "Chevron Right",
+    "Chevron Up",
+    "Close",
+    "Close Outline",
+    "Close Solid",
+    "Cloud Foundry",
+    "Code",
+    "Console",
+    "Containers",
+    "Copy",
+    "Crash",
+    "Dashboard",
+    "Data",
+    "Delete",
+    "Devices",
+    "Devops",
+    "Document",
+    "Dollars",
+    "Download",
+    "Draggable",
+    "Edit",
+    "Email",
+    "Error",
+    "Error Outline",
+    "Error Solid",
+    "Favorite",
+    "Favorite Outline",
+    "Favorite Solid",
+    "Filter",
+    "Finance",
+    "Financial",
+    "Folder",
+    "Folder Add",
+    "Forum",
+    "Functions",
+    "Grid",
+    "Header Avatar",
+    "Header Chevron",
+    "Header Close",
+    "Header Contact",
+    "Header Docs",
+    "Header Hamburger",
+    "Header Help",
+    "Header Notification",
+    "Header Search",
+    "Header Ticket",
+    "Header User",
+    "Help",
+    "Help Outline",
+    "Help Solid",
+    "Hpa",
+    "Hpa Stress",
+    "Info",
+    "Info Outline",
+    "Info Solid",
+    "Infrastructure",
+    "Integration",
+    "Iot",
+    "Launch",
+    "Light",
+    "Link",
+    "List",
+    "Locked",
+    "Maximize",
+    "Menu",
+    "Minimize",
+    "Mobile",
+    "Network",
+    "New Tab",
+    "Notification Off",
+    "Notification On",
+    "Open Whisk",
+    "Overflow Menu",
+    "Pa",
+    "Pa Stress",
+    "Pause",
+    "Pause Outline",
+    "Pause Solid",
+    "Play",
+    "Play Outline",
+    "Play Solid",
+    "Portfolio",
+    "Power",
+    "Predictive",
+    "Purchase",
+    "Restart",
+    "Save",
+    "Schematics",
+    "Search",
+    "Security",
+    "Services",
+    "Settings",
+    "Star Outline",
+    "Star Solid",
+    "Start",
+    "Start Outline",
+    "Stop",
+    "Stop Outline",
+    "Stop Solid",
+    "Storage",
+    "Subtract",
+    "Subtract Outline",
+    "Subtract Solid",
+    "Terminal",
+    "Unlock",
+    "Unlocked",
+    "Upload",
+    "User",
+    "Visibility Off",
+    "Visibility On",
+    "Warning",
+    "Warning Outline",
+    "Warning Solid",
+    "Watson",
+    "Whisk",
+];
+
+/// Stable icon ids, sorted by id.
+pub(crate) const ICON_IDS: &[(u32, &str)] = &[
+    (0x018f4fde, "info-outline"),
+    (0x01ca9a66, "storage"),
+    (0x0577f9cf, "info-solid"),
+    (0x0582ab34, "arrow-up"),
+    (0x07024b16, "pause-outline"),
+    (0x07212014, "close-outline"),
+    (0x0b4675e2, "chevron-up"),
+    (0x0cfb5881, "list"),
+    (0x0ddb0669, "link"),
+    (0x0fb40705, "info"),
+    (0x111e5f8f, "maximize"),
+    (0x13c5be2a, "caret-up"),
+    (0x14e31103, "new-tab"),
+    (0x157170b8, "devops"),
+    (0x16f87451, "containers"),
+    (0x18b665ab, "star-solid"),
+    (0x19b8d1bc, "financial"),
+    (0x19e727d3, "chevron-right"),
+    (0x1a4d55d8, "crash"),
+    (0x1bac42e3, "portfolio"),
+    (0x1bee4f75, "checkmark-outline"),
+    (0x1c36a4e4, "help-solid"),
+    (0x1fc2906d, "header-close"),
+    (0x21918751, "error"),
+    (0x278928c1, "arrow-down"),
+    (0x279eb2a1, "visibility-on"),
+    (0x27cb3b23, "close"),
+    (0x2bc5cc4d, "help-outline"),
+    (0x2ca135cd, "favorite"),
+    (0x2e329b2a, "console"),
+    (0x3108b3f9, "download"),
+    (0x355f20e2, "error-outline"),
+    (0x35c000bd, "visibility-off"),
+    (0x36d0380a, "back-to-top"),
+    (0x383ebaa8, "warning-outline"),
+    (0x386aaa87, "api"),
+    (0x3871a3fa, "help"),
+    (0x3b391274, "add"),
+    (0x3c5c055c, "upload"),
+    (0x42c1a561, "subtract"),
+    (0x42fb9b8b, "schematics"),
+    (0x454a414e, "functions"),
+    (0x457953af, "arrows"),
+    (0x468e4bd8, "start-outline"),
+    (0x47223eb1, "header-contact"),
+    (0x4871f149, "watson"),
+    (0x4c37d88b, "caret-down"),
+    (0x504e588a, "pa"),
+    (0x521886c5, "network"),
+    (0x52392d3e, "stop-outline"),
+    (0x529f95b7, "pause-solid"),
+    (0x556d332b, "caret-right"),
+    (0x56661f55, "unlock"),
+    (0x5be7d591, "edit"),
+    (0x60785ef2, "user"),
+    (0x62f7be68, "predictive"),
+    (0x63b48835, "overflow-menu"),
+    (0x641fb434, "draggable"),
+    (0x652b04df, "start"),
+    (0x67c2444a, "delete"),
+    (0x68067b08, "settings"),
+    (0x69fb8c77, "mobile"),
+    (0x6f1f4144, "header-user"),
+    (0x7084d38d, "pause"),
+    (0x7395fea4, "dollars"),
+    (0x73bcba3b, "subtract-solid"),
+    (0x73c45b02, "applications"),
+    (0x75d2e95c, "checkmark-solid"),
+    (0x792112ef, "warning"),
+    (0x7a0f4bf6, "caret-left"),
+    (0x7b0c1a8b, "error-solid"),
+    (0x7c18b8c8, "header-notification"),
+    (0x7f9cc550, "header-docs"),
+    (0x801a44ed, "close-solid"),
+    (0x803328a9, "search"),
+    (0x8231383f, "cf-apps"),
+    (0x8340e2a3, "chevron-down"),
+    (0x83eba09f, "iot"),
+    (0x84597d02, "star-outline"),
+    (0x872d24fe, "chevron-left"),
+    (0x8a8753c7, "email"),
+    (0x8ab39237, "header-search"),
+    (0x8ac96cf3, "folder-add"),
+    (0x8b05e58c, "open-whisk"),
+    (0x8e192e3e, "header-hamburger"),
+    (0x91635e1b, "hpa-stress"),
+    (0x953445ed, "finance"),
+    (0x95858f56, "forum"),
+    (0x95d58f62, "purchase"),
+    (0x96234bd4, "document"),
+    (0x99e4dd3a, "menu"),
+    (0x99f40ab1, "terminal"),
+    (0x9a1c793c, "notification-on"),
+    (0x9f57303d, "minimize"),
+    (0x9fbda08d, "integration"),
+    (0xa10a8b80, "devices"),
+    (0xa24bf9ab, "chat"),
+    (0xa4810d1f, "calendar"),
+    (0xa5cb7c68, "attachment"),
+    (0xa825f777, "favorite-solid"),
+    (0xab3b3c2f, "stop-solid"),
+    (0xae8724a4, "header-help"),
+    (0xaf871a91, "grid"),
+    (0xb0fa09ee, "cloud-foundry"),
+    (0xb50eeeb7, "header-ticket"),
+    (0xbf221296, "add-solid"),
+    (0xbf3ec10e, "infrastructure"),
+    (0xc2cbd863, "play"),
+    (0xc3eb1e1c, "apis"),
+    (0xc64346ad, "play-solid"),
+    (0xc6b685d4, "play-outline"),
+    (0xc7e16877, "filter"),
+    (0xc8aae2fa, "header-chevron"),
+    (0xc8c02a7f, "app-services"),
+    (0xc92cc9bb, "security"),
+    (0xcb532ae5, "stop"),
+    (0xccff7e48, "save"),
+    (0xce164093, "locked"),
+    (0xd00fcd5f, "add-outline"),
+    (0xd1519ad2, "checkmark"),
+    (0xd16b73d6, "favorite-outline"),
+    (0xd3acce8d, "apps"),
+    (0xd71034dc, "awake"),
+    (0xd7d848a9, "arrow-right"),
+    (0xd872e2a5, "data"),
+    (0xd96a9e35, "services"),
+    (0xd96eb5dd, "folder"),
+    (0xdd20481c, "unlocked"),
+    (0xe0e43e2f, "asleep"),
+    (0xe29d1e2f, "light"),
+    (0xe2fdf11e, "header-avatar"),
+    (0xe2fe4c4e, "block-chain"),
+    (0xe3989c4c, "hpa"),
+    (0xe4c10bdb, "dashboard"),
+    (0xe4e67736, "notification-off"),
+    (0xe536575c, "arrow-left"),
+    (0xe562ea44, "copy"),
+    (0xe58ef285, "pa-stress"),
+    (0xe7f9c85f, "whisk"),
+    (0xf1b83866, "launch"),
+    (0xf1cd4429, "warning-solid"),
+    (0xf54f2346, "power"),
+    (0xf9316cf4, "code"),
+    (0xfd2fcdf2, "subtract-outline"),
+    (0xfe9c11ec, "restart"),
+];
+
+pub(crate) const ICON_RTL_MIRROR: &[&str] = &[
+    "arrow-left",
+    "arrow-right",
+    "caret-left",
+    "caret-right",
+    "chevron-left",
+    "chevron-right",
+];
+
 pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantInfo> {
     VARIANTS
         .iter()
@@ -3503,21 +6213,359 @@ pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantI
 }
 
 pub(crate) fn icon_codepoint(name: &str, key: VariantKey) -> Option<u32> {
-    ICON_CODEPOINTS
+    let codepoints: &[(VariantKey, u32)] = match name {
+        "add" => ICON_ADD_CODEPOINTS,
+        "add-outline" => ICON_ADD_OUTLINE_CODEPOINTS,
+        "add-solid" => ICON_ADD_SOLID_CODEPOINTS,
+        "api" => ICON_API_CODEPOINTS,
+        "apis" => ICON_APIS_CODEPOINTS,
+        "app-services" => ICON_APP_SERVICES_CODEPOINTS,
+        "applications" => ICON_APPLICATIONS_CODEPOINTS,
+        "apps" => ICON_APPS_CODEPOINTS,
+        "arrow-down" => ICON_ARROW_DOWN_CODEPOINTS,
+        "arrow-left" => ICON_ARROW_LEFT_CODEPOINTS,
+        "arrow-right" => ICON_ARROW_RIGHT_CODEPOINTS,
+        "arrow-up" => ICON_ARROW_UP_CODEPOINTS,
+        "arrows" => ICON_ARROWS_CODEPOINTS,
+        "asleep" => ICON_ASLEEP_CODEPOINTS,
+        "attachment" => ICON_ATTACHMENT_CODEPOINTS,
+        "awake" => ICON_AWAKE_CODEPOINTS,
+        "back-to-top" => ICON_BACK_TO_TOP_CODEPOINTS,
+        "block-chain" => ICON_BLOCK_CHAIN_CODEPOINTS,
+        "calendar" => ICON_CALENDAR_CODEPOINTS,
+        "caret-down" => ICON_CARET_DOWN_CODEPOINTS,
+        "caret-left" => ICON_CARET_LEFT_CODEPOINTS,
+        "caret-right" => ICON_CARET_RIGHT_CODEPOINTS,
+        "caret-up" => ICON_CARET_UP_CODEPOINTS,
+        "cf-apps" => ICON_CF_APPS_CODEPOINTS,
+        "chat" => ICON_CHAT_CODEPOINTS,
+        "checkmark" => ICON_CHECKMARK_CODEPOINTS,
+        "checkmark-outline" => ICON_CHECKMARK_OUTLINE_CODEPOINTS,
+        "checkmark-solid" => ICON_CHECKMARK_SOLID_CODEPOINTS,
+        "chevron-down" => ICON_CHEVRON_DOWN_CODEPOINTS,
+        "chevron-left" => ICON_CHEVRON_LEFT_CODEPOINTS,
+        "chevron-right" => ICON_CHEVRON_RIGHT_CODEPOINTS,
+        "chevron-up" => ICON_CHEVRON_UP_CODEPOINTS,
+        "close" => ICON_CLOSE_CODEPOINTS,
+        "close-outline" => ICON_CLOSE_OUTLINE_CODEPOINTS,
+        "close-solid" => ICON_CLOSE_SOLID_CODEPOINTS,
+        "cloud-foundry" => ICON_CLOUD_FOUNDRY_CODEPOINTS,
+        "code" => ICON_CODE_CODEPOINTS,
+        "console" => ICON_CONSOLE_CODEPOINTS,
+        "containers" => ICON_CONTAINERS_CODEPOINTS,
+        "copy" => ICON_COPY_CODEPOINTS,
+        "crash" => ICON_CRASH_CODEPOINTS,
+        "dashboard" => ICON_DASHBOARD_CODEPOINTS,
+        "data" => ICON_DATA_CODEPOINTS,
+        "delete" => ICON_DELETE_CODEPOINTS,
+        "devices" => ICON_DEVICES_CODEPOINTS,
+        "devops" => ICON_DEVOPS_CODEPOINTS,
+        "document" => ICON_DOCUMENT_CODEPOINTS,
+        "dollars" => ICON_DOLLARS_CODEPOINTS,
+        "download" => ICON_DOWNLOAD_CODEPOINTS,
+        "draggable" => ICON_DRAGGABLE_CODEPOINTS,
+        "edit" => ICON_EDIT_CODEPOINTS,
+        "email" => ICON_EMAIL_CODEPOINTS,
+        "error" => ICON_ERROR_CODEPOINTS,
+        "error-outline" => ICON_ERROR_OUTLINE_CODEPOINTS,
+        "error-solid" => ICON_ERROR_SOLID_CODEPOINTS,
+        "favorite" => ICON_FAVORITE_CODEPOINTS,
+        "favorite-outline" => ICON_FAVORITE_OUTLINE_CODEPOINTS,
+        "favorite-solid" => ICON_FAVORITE_SOLID_CODEPOINTS,
+        "filter" => ICON_FILTER_CODEPOINTS,
+        "finance" => ICON_FINANCE_CODEPOINTS,
+        "financial" => ICON_FINANCIAL_CODEPOINTS,
+        "folder" => ICON_FOLDER_CODEPOINTS,
+        "folder-add" => ICON_FOLDER_ADD_CODEPOINTS,
+        "forum" => ICON_FORUM_CODEPOINTS,
+        "functions" => ICON_FUNCTIONS_CODEPOINTS,
+        "grid" => ICON_GRID_CODEPOINTS,
+        "header-avatar" => ICON_HEADER_AVATAR_CODEPOINTS,
+        "header-chevron" => ICON_HEADER_CHEVRON_CODEPOINTS,
+        "header-close" => ICON_HEADER_CLOSE_CODEPOINTS,
+        "header-contact" => ICON_HEADER_CONTACT_CODEPOINTS,
+        "header-docs" => ICON_HEADER_DOCS_CODEPOINTS,
+        "header-hamburger" => ICON_HEADER_HAMBURGER_CODEPOINTS,
+        "header-help" => ICON_HEADER_HELP_CODEPOINTS,
+        "header-notification" => ICON_HEADER_NOTIFICATION_CODEPOINTS,
+        "header-search" => ICON_HEADER_SEARCH_CODEPOINTS,
+        "header-ticket" => ICON_HEADER_TICKET_CODEPOINTS,
+        "header-user" => ICON_HEADER_USER_CODEPOINTS,
+        "help" => ICON_HELP_CODEPOINTS,
+        "help-outline" => ICON_HELP_OUTLINE_CODEPOINTS,
+        "help-solid" => ICON_HELP_SOLID_CODEPOINTS,
+        "hpa" => ICON_HPA_CODEPOINTS,
+        "hpa-stress" => ICON_HPA_STRESS_CODEPOINTS,
+        "info" => ICON_INFO_CODEPOINTS,
+        "info-outline" => ICON_INFO_OUTLINE_CODEPOINTS,
+        "info-solid" => ICON_INFO_SOLID_CODEPOINTS,
+        "infrastructure" => ICON_INFRASTRUCTURE_CODEPOINTS,
+        "integration" => ICON_INTEGRATION_CODEPOINTS,
+        "iot" => ICON_IOT_CODEPOINTS,
+        "launch" => ICON_LAUNCH_CODEPOINTS,
+        "light" => ICON_LIGHT_CODEPOINTS,
+        "link" => ICON_LINK_CODEPOINTS,
+        "list" => ICON_LIST_CODEPOINTS,
+        "locked" => ICON_LOCKED_CODEPOINTS,
+        "maximize" => ICON_MAXIMIZE_CODEPOINTS,
+        "menu" => ICON_MENU_CODEPOINTS,
+        "minimize" => ICON_MINIMIZE_CODEPOINTS,
+        "mobile" => ICON_MOBILE_CODEPOINTS,
+        "network" => ICON_NETWORK_CODEPOINTS,
+        "new-tab" => ICON_NEW_TAB_CODEPOINTS,
+        "notification-off" => ICON_NOTIFICATION_OFF_CODEPOINTS,
+        "notification-on" => ICON_NOTIFICATION_ON_CODEPOINTS,
+        "open-whisk" => ICON_OPEN_WHISK_CODEPOINTS,
+        "overflow-menu" => ICON_OVERFLOW_MENU_CODEPOINTS,
+        "pa" => ICON_PA_CODEPOINTS,
+        "pa-stress" => ICON_PA_STRESS_CODEPOINTS,
+        "pause" => ICON_PAUSE_CODEPOINTS,
+        "pause-outline" => ICON_PAUSE_OUTLINE_CODEPOINTS,
+        "pause-solid" => ICON_PAUSE_SOLID_CODEPOINTS,
+        "play" => ICON_PLAY_CODEPOINTS,
+        "play-outline" => ICON_PLAY_OUTLINE_CODEPOINTS,
+        "play-solid" => ICON_PLAY_SOLID_CODEPOINTS,
+        "portfolio" => ICON_PORTFOLIO_CODEPOINTS,
+        "power" => ICON_POWER_CODEPOINTS,
+        "predictive" => ICON_PREDICTIVE_CODEPOINTS,
+        "purchase" => ICON_PURCHASE_CODEPOINTS,
+        "restart" => ICON_RESTART_CODEPOINTS,
+        "save" => ICON_SAVE_CODEPOINTS,
+        "schematics" => ICON_SCHEMATICS_CODEPOINTS,
+        "search" => ICON_SEARCH_CODEPOINTS,
+        "security" => ICON_SECURITY_CODEPOINTS,
+        "services" => ICON_SERVICES_CODEPOINTS,
+        "settings" => ICON_SETTINGS_CODEPOINTS,
+        "star-outline" => ICON_STAR_OUTLINE_CODEPOINTS,
+        "star-solid" => ICON_STAR_SOLID_CODEPOINTS,
+        "start" => ICON_START_CODEPOINTS,
+        "start-outline" => ICON_START_OUTLINE_CODEPOINTS,
+        "stop" => ICON_STOP_CODEPOINTS,
+        "stop-outline" => ICON_STOP_OUTLINE_CODEPOINTS,
+        "stop-solid" => ICON_STOP_SOLID_CODEPOINTS,
+        "storage" => ICON_STORAGE_CODEPOINTS,
+        "subtract" => ICON_SUBTRACT_CODEPOINTS,
+        "subtract-outline" => ICON_SUBTRACT_OUTLINE_CODEPOINTS,
+        "subtract-solid" => ICON_SUBTRACT_SOLID_CODEPOINTS,
+        "terminal" => ICON_TERMINAL_CODEPOINTS,
+        "unlock" => ICON_UNLOCK_CODEPOINTS,
+        "unlocked" => ICON_UNLOCKED_CODEPOINTS,
+        "upload" => ICON_UPLOAD_CODEPOINTS,
+        "user" => ICON_USER_CODEPOINTS,
+        "visibility-off" => ICON_VISIBILITY_OFF_CODEPOINTS,
+        "visibility-on" => ICON_VISIBILITY_ON_CODEPOINTS,
+        "warning" => ICON_WARNING_CODEPOINTS,
+        "warning-outline" => ICON_WARNING_OUTLINE_CODEPOINTS,
+        "warning-solid" => ICON_WARNING_SOLID_CODEPOINTS,
+        "watson" => ICON_WATSON_CODEPOINTS,
+        "whisk" => ICON_WHISK_CODEPOINTS,
+        _ => return None,
+    };
+    codepoints
         .iter()
-        .find(|entry| entry.name == name)
-        .and_then(|entry| {
-            entry
-                .codepoints
-                .iter()
-                .find(|(k, _)| *k == key)
-                .map(|(_, cp)| *cp)
-        })
+        .find(|(k, _)| *k == key)
+        .map(|(_, cp)| *cp)
+}
+
+pub(crate) fn canonical_name_ci(lowercase: &str) -> Option<&'static str> {
+    let index = ICON_NAMES_LOWERCASE.partition_point(|(lower, _)| *lower < lowercase);
+    ICON_NAMES_LOWERCASE
+        .get(index)
+        .filter(|(lower, _)| *lower == lowercase)
+        .map(|(_, name)| *name)
 }
 
 pub(crate) fn icon_available(name: &str) -> Option<&'static [(Style, Size)]> {
-    ICON_AVAILABILITY
-        .iter()
-        .find(|entry| entry.name == name)
-        .map(|entry| entry.available)
+    match name {
+        "add" => Some(ICON_ADD_AVAILABLE),
+        "add-outline" => Some(ICON_ADD_OUTLINE_AVAILABLE),
+        "add-solid" => Some(ICON_ADD_SOLID_AVAILABLE),
+        "api" => Some(ICON_API_AVAILABLE),
+        "apis" => Some(ICON_APIS_AVAILABLE),
+        "app-services" => Some(ICON_APP_SERVICES_AVAILABLE),
+        "applications" => Some(ICON_APPLICATIONS_AVAILABLE),
+        "apps" => Some(ICON_APPS_AVAILABLE),
+        "arrow-down" => Some(ICON_ARROW_DOWN_AVAILABLE),
+        "arrow-left" => Some(ICON_ARROW_LEFT_AVAILABLE),
+        "arrow-right" => Some(ICON_ARROW_RIGHT_AVAILABLE),
+        "arrow-up" => Some(ICON_ARROW_UP_AVAILABLE),
+        "arrows" => Some(ICON_ARROWS_AVAILABLE),
+        "asleep" => Some(ICON_ASLEEP_AVAILABLE),
+        "attachment" => Some(ICON_ATTACHMENT_AVAILABLE),
+        "awake" => Some(ICON_AWAKE_AVAILABLE),
+        "back-to-top" => Some(ICON_BACK_TO_TOP_AVAILABLE),
+        "block-chain" => Some(ICON_BLOCK_CHAIN_AVAILABLE),
+        "calendar" => Some(ICON_CALENDAR_AVAILABLE),
+        "caret-down" => Some(ICON_CARET_DOWN_AVAILABLE),
+        "caret-left" => Some(ICON_CARET_LEFT_AVAILABLE),
+        "caret-right" => Some(ICON_CARET_RIGHT_AVAILABLE),
+        "caret-up" => Some(ICON_CARET_UP_AVAILABLE),
+        "cf-apps" => Some(ICON_CF_APPS_AVAILABLE),
+        "chat" => Some(ICON_CHAT_AVAILABLE),
+        "checkmark" => Some(ICON_CHECKMARK_AVAILABLE),
+        "checkmark-outline" => Some(ICON_CHECKMARK_OUTLINE_AVAILABLE),
+        "checkmark-solid" => Some(ICON_CHECKMARK_SOLID_AVAILABLE),
+        "chevron-down" => Some(ICON_CHEVRON_DOWN_AVAILABLE),
+        "chevron-left" => Some(ICON_CHEVRON_LEFT_AVAILABLE),
+        "chevron-right" => Some(ICON_CHEVRON_RIGHT_AVAILABLE),
+        "chevron-up" => Some(ICON_CHEVRON_UP_AVAILABLE),
+        "close" => Some(ICON_CLOSE_AVAILABLE),
+        "close-outline" => Some(ICON_CLOSE_OUTLINE_AVAILABLE),
+        "close-solid" => Some(ICON_CLOSE_SOLID_AVAILABLE),
+        "cloud-foundry" => Some(ICON_CLOUD_FOUNDRY_AVAILABLE),
+        "code" => Some(ICON_CODE_AVAILABLE),
+        "console" => Some(ICON_CONSOLE_AVAILABLE),
+        "containers" => Some(ICON_CONTAINERS_AVAILABLE),
+        "copy" => Some(ICON_COPY_AVAILABLE),
+        "crash" => Some(ICON_CRASH_AVAILABLE),
+        "dashboard" => Some(ICON_DASHBOARD_AVAILABLE),
+        "data" => Some(ICON_DATA_AVAILABLE),
+        "delete" => Some(ICON_DELETE_AVAILABLE),
+        "devices" => Some(ICON_DEVICES_AVAILABLE),
+        "devops" => Some(ICON_DEVOPS_AVAILABLE),
+        "document" => Some(ICON_DOCUMENT_AVAILABLE),
+        "dollars" => Some(ICON_DOLLARS_AVAILABLE),
+        "download" => Some(ICON_DOWNLOAD_AVAILABLE),
+        "draggable" => Some(ICON_DRAGGABLE_AVAILABLE),
+        "edit" => Some(ICON_EDIT_AVAILABLE),
+        "email" => Some(ICON_EMAIL_AVAILABLE),
+        "error" => Some(ICON_ERROR_AVAILABLE),
+        "error-outline" => Some(ICON_ERROR_OUTLINE_AVAILABLE),
+        "error-solid" => Some(ICON_ERROR_SOLID_AVAILABLE),
+        "favorite" => Some(ICON_FAVORITE_AVAILABLE),
+        "favorite-outline" => Some(ICON_FAVORITE_OUTLINE_AVAILABLE),
+        "favorite-solid" => Some(ICON_FAVORITE_SOLID_AVAILABLE),
+        "filter" => Some(ICON_FILTER_AVAILABLE),
+        "finance" => Some(ICON_FINANCE_AVAILABLE),
+        "financial" => Some(ICON_FINANCIAL_AVAILABLE),
+        "folder" => Some(ICON_FOLDER_AVAILABLE),
+        "folder-add" => Some(ICON_FOLDER_ADD_AVAILABLE),
+        "forum" => Some(ICON_FORUM_AVAILABLE),
+        "functions" => Some(ICON_FUNCTIONS_AVAILABLE),
+        "grid" => Some(ICON_GRID_AVAILABLE),
+        "header-avatar" => Some(ICON_HEADER_AVATAR_AVAILABLE),
+        "header-chevron" => Some(ICON_HEADER_CHEVRON_AVAILABLE),
+        "header-close" => Some(ICON_HEADER_CLOSE_AVAILABLE),
+        "header-contact" => Some(ICON_HEADER_CONTACT_AVAILABLE),
+        "header-docs" => Some(ICON_HEADER_DOCS_AVAILABLE),
+        "header-hamburger" => Some(ICON_HEADER_HAMBURGER_AVAILABLE),
+        "header-help" => Some(ICON_HEADER_HELP_AVAILABLE),
+        "header-notification" => Some(ICON_HEADER_NOTIFICATION_AVAILABLE),
+        "header-search" => Some(ICON_HEADER_SEARCH_AVAILABLE),
+        "header-ticket" => Some(ICON_HEADER_TICKET_AVAILABLE),
+        "header-user" => Some(ICON_HEADER_USER_AVAILABLE),
+        "help" => Some(ICON_HELP_AVAILABLE),
+        "help-outline" => Some(ICON_HELP_OUTLINE_AVAILABLE),
+        "help-solid" => Some(ICON_HELP_SOLID_AVAILABLE),
+        "hpa" => Some(ICON_HPA_AVAILABLE),
+        "hpa-stress" => Some(ICON_HPA_STRESS_AVAILABLE),
+        "info" => Some(ICON_INFO_AVAILABLE),
+        "info-outline" => Some(ICON_INFO_OUTLINE_AVAILABLE),
+        "info-solid" => Some(ICON_INFO_SOLID_AVAILABLE),
+        "infrastructure" => Some(ICON_INFRASTRUCTURE_AVAILABLE),
+        "integration" => Some(ICON_INTEGRATION_AVAILABLE),
+        "iot" => Some(ICON_IOT_AVAILABLE),
+        "launch" => Some(ICON_LAUNCH_AVAILABLE),
+        "light" => Some(ICON_LIGHT_AVAILABLE),
+        "link" => Some(ICON_LINK_AVAILABLE),
+        "list" => Some(ICON_LIST_AVAILABLE),
+        "locked" => Some(ICON_LOCKED_AVAILABLE),
+        "maximize" => Some(ICON_MAXIMIZE_AVAILABLE),
+        "menu" => Some(ICON_MENU_AVAILABLE),
+        "minimize" => Some(ICON_MINIMIZE_AVAILABLE),
+        "mobile" => Some(ICON_MOBILE_AVAILABLE),
+        "network" => Some(ICON_NETWORK_AVAILABLE),
+        "new-tab" => Some(ICON_NEW_TAB_AVAILABLE),
+        "notification-off" => Some(ICON_NOTIFICATION_OFF_AVAILABLE),
+        "notification-on" => Some(ICON_NOTIFICATION_ON_AVAILABLE),
+        "open-whisk" => Some(ICON_OPEN_WHISK_AVAILABLE),
+        "overflow-menu" => Some(ICON_OVERFLOW_MENU_AVAILABLE),
+        "pa" => Some(ICON_PA_AVAILABLE),
+        "pa-stress" => Some(ICON_PA_STRESS_AVAILABLE),
+        "pause" => Some(ICON_PAUSE_AVAILABLE),
+        "pause-outline" => Some(ICON_PAUSE_OUTLINE_AVAILABLE),
+        "pause-solid" => Some(ICON_PAUSE_SOLID_AVAILABLE),
+        "play" => Some(ICON_PLAY_AVAILABLE),
+        "play-outline" => Some(ICON_PLAY_OUTLINE_AVAILABLE),
+        "play-solid" => Some(ICON_PLAY_SOLID_AVAILABLE),
+        "portfolio" => Some(ICON_PORTFOLIO_AVAILABLE),
+        "power" => Some(ICON_POWER_AVAILABLE),
+        "predictive" => Some(ICON_PREDICTIVE_AVAILABLE),
+        "purchase" => Some(ICON_PURCHASE_AVAILABLE),
+        "restart" => Some(ICON_RESTART_AVAILABLE),
+        "save" => Some(ICON_SAVE_AVAILABLE),
+        "schematics" => Some(ICON_SCHEMATICS_AVAILABLE),
+        "search" => Some(ICON_SEARCH_AVAILABLE),
+        "security" => Some(ICON_SECURITY_AVAILABLE),
+        "services" => Some(ICON_SERVICES_AVAILABLE),
+        "settings" => Some(ICON_SETTINGS_AVAILABLE),
+        "star-outline" => Some(ICON_STAR_OUTLINE_AVAILABLE),
+        "star-solid" => Some(ICON_STAR_SOLID_AVAILABLE),
+        "start" => Some(ICON_START_AVAILABLE),
+        "start-outline" => Some(ICON_START_OUTLINE_AVAILABLE),
+        "stop" => Some(ICON_STOP_AVAILABLE),
+        "stop-outline" => Some(ICON_STOP_OUTLINE_AVAILABLE),
+        "stop-solid" => Some(ICON_STOP_SOLID_AVAILABLE),
+        "storage" => Some(ICON_STORAGE_AVAILABLE),
+        "subtract" => Some(ICON_SUBTRACT_AVAILABLE),
+        "subtract-outline" => Some(ICON_SUBTRACT_OUTLINE_AVAILABLE),
+        "subtract-solid" => Some(ICON_SUBTRACT_SOLID_AVAILABLE),
+        "terminal" => Some(ICON_TERMINAL_AVAILABLE),
+        "unlock" => Some(ICON_UNLOCK_AVAILABLE),
+        "unlocked" => Some(ICON_UNLOCKED_AVAILABLE),
+        "upload" => Some(ICON_UPLOAD_AVAILABLE),
+        "user" => Some(ICON_USER_AVAILABLE),
+        "visibility-off" => Some(ICON_VISIBILITY_OFF_AVAILABLE),
+        "visibility-on" => Some(ICON_VISIBILITY_ON_AVAILABLE),
+        "warning" => Some(ICON_WARNING_AVAILABLE),
+        "warning-outline" => Some(ICON_WARNING_OUTLINE_AVAILABLE),
+        "warning-solid" => Some(ICON_WARNING_SOLID_AVAILABLE),
+        "watson" => Some(ICON_WATSON_AVAILABLE),
+        "whisk" => Some(ICON_WHISK_AVAILABLE),
+        _ => None,
+    }
+}
+
+pub(crate) fn brand_color(name: &str) -> Option<[u8; 4]> {
+    ICON_COLORS
+        .binary_search_by(|(entry, _)| (*entry).cmp(name))
+        .ok()
+        .map(|index| ICON_COLORS[index].1)
+}
+
+pub(crate) fn aria_label(name: &str) -> Option<&'static str> {
+    ICON_NAMES
+        .binary_search(&name)
+        .ok()
+        .map(|index| ICON_LABELS[index])
+}
+
+pub(crate) fn icon_by_id(id: u32) -> Option<&'static str> {
+    ICON_IDS
+        .binary_search_by_key(&id, |(entry, _)| *entry)
+        .ok()
+        .map(|index| ICON_IDS[index].1)
+}
+
+pub(crate) fn rtl_mirror(name: &str) -> bool {
+    ICON_RTL_MIRROR.binary_search(&name).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_lookup_agrees_with_scan_tables() {
+        for entry in ICON_CODEPOINTS {
+            for &(key, codepoint) in entry.codepoints {
+                assert_eq!(icon_codepoint(entry.name, key), Some(codepoint));
+            }
+        }
+        for entry in ICON_AVAILABILITY {
+            assert_eq!(icon_available(entry.name), Some(entry.available));
+        }
+        assert_eq!(icon_available(""), None);
+        assert_eq!(icon_codepoint("", VARIANTS[0].key), None);
+    }
 }
@@ -1,7 +1,13 @@
 // @generated by xtask gen. DO NOT EDIT.
-use crate::core::{FontAsset, IconRef, Size, Style, VariantKey};
+use crate::core::{FontAsset, IconRef, PackKind, Size, Style, VariantKey};
 
+/// Canonical pack id, also returned by `Pack::Feather.id()`.
 pub const PACK_ID: &str = "feather";
+pub const DISPLAY_NAME: &str = "Feather";
+pub const KIND: PackKind = PackKind::Ui;
+/// Fingerprint of the pack's families, names and codepoints; changes when the map does.
+pub const CONTENT_HASH: u64 = 0x8286a78d4cb0612a;
+pub const STYLE_ORDER: &[Style] = &[];
 
 pub(crate) const FONT_ASSET_FEATHER_FEATHER_REGULAR: FontAsset = FontAsset {
     family: "Feather Regular",
@@ -21,298 +27,894 @@ pub const VARIANT_ASSETS: &[(VariantKey, FontAsset)] = &[(
     FONT_ASSET_FEATHER_FEATHER_REGULAR,
 )];
 
+/// Styles of the enabled variants, in presentation order (`STYLE_ORDER` first).
+pub const STYLES: &[Style] = &[Style::Regular];
+
+/// Sizes of the enabled variants, sorted.
+pub const SIZES: &[Size] = &[Size::Regular];
+
+/// Lowest and highest codepoint each variant maps, for variants that map any.
+pub(crate) const VARIANT_CODEPOINT_RANGES: &[(VariantKey, u32, u32)] = &[(
+    VariantKey {
+        style: Style::Regular,
+        size: Size::Regular,
+    },
+    0xE000,
+    0xE11E,
+)];
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Icon {
+    /// `activity` — U+E000 (available in Regular)
     Activity,
+    /// `airplay` — U+E001 (available in Regular)
     Airplay,
+    /// `alert-circle` — U+E002 (available in Regular)
     AlertCircle,
+    /// `alert-octagon` — U+E003 (available in Regular)
     AlertOctagon,
+    /// `alert-triangle` — U+E004 (available in Regular)
     AlertTriangle,
+    /// `align-center` — U+E005 (available in Regular)
     AlignCenter,
+    /// `align-justify` — U+E006 (available in Regular)
     AlignJustify,
+    /// `align-left` — U+E007 (available in Regular)
     AlignLeft,
+    /// `align-right` — U+E008 (available in Regular)
     AlignRight,
+    /// `anchor` — U+E009 (available in Regular)
     Anchor,
+    /// `aperture` — U+E00A (available in Regular)
     Aperture,
+    /// `archive` — U+E00B (available in Regular)
     Archive,
+    /// `arrow-down` — U+E00F (available in Regular)
     ArrowDown,
+    /// `arrow-down-circle` — U+E00C (available in Regular)
     ArrowDownCircle,
+    /// `arrow-down-left` — U+E00D (available in Regular)
     ArrowDownLeft,
+    /// `arrow-down-right` — U+E00E (available in Regular)
     ArrowDownRight,
+    /// `arrow-left` — U+E011 (available in Regular)
     ArrowLeft,
+    /// `arrow-left-circle` — U+E010 (available in Regular)
     ArrowLeftCircle,
+    /// `arrow-right` — U+E013 (available in Regular)
     ArrowRight,
+    /// `arrow-right-circle` — U+E012 (available in Regular)
     ArrowRightCircle,
+    /// `arrow-up` — U+E017 (available in Regular)
     ArrowUp,
+    /// `arrow-up-circle` — U+E014 (available in Regular)
     ArrowUpCircle,
+    /// `arrow-up-left` — U+E015 (available in Regular)
     ArrowUpLeft,
+    /// `arrow-up-right` — U+E016 (available in Regular)
     ArrowUpRight,
+    /// `at-sign` — U+E018 (available in Regular)
     AtSign,
+    /// `award` — U+E019 (available in Regular)
     Award,
+    /// `bar-chart` — U+E01B (available in Regular)
     BarChart,
+    /// `bar-chart-2` — U+E01A (available in Regular)
     BarChart2,
+    /// `battery` — U+E01D (available in Regular)
     Battery,
+    /// `battery-charging` — U+E01C (available in Regular)
     BatteryCharging,
+    /// `bell` — U+E01F (available in Regular)
     Bell,
+    /// `bell-off` — U+E01E (available in Regular)
     BellOff,
+    /// `bluetooth` — U+E020 (available in Regular)
     Bluetooth,
+    /// `bold` — U+E021 (available in Regular)
     Bold,
+    /// `book` — U+E023 (available in Regular)
     Book,
+    /// `book-open` — U+E022 (available in Regular)
     BookOpen,
+    /// `bookmark` — U+E024 (available in Regular)
     Bookmark,
+    /// `box` — U+E025 (available in Regular)
     Box,
+    /// `briefcase` — U+E026 (available in Regular)
     Briefcase,
+    /// `calendar` — U+E027 (available in Regular)
     Calendar,
+    /// `camera` — U+E029 (available in Regular)
     Camera,
+    /// `camera-off` — U+E028 (available in Regular)
     CameraOff,
+    /// `cast` — U+E02A (available in Regular)
     Cast,
+    /// `check` — U+E02D (available in Regular)
     Check,
+    /// `check-circle` — U+E02B (available in Regular)
     CheckCircle,
+    /// `check-square` — U+E02C (available in Regular)
     CheckSquare,
+    /// `chevron-down` — U+E02E (available in Regular)
     ChevronDown,
+    /// `chevron-left` — U+E02F (available in Regular)
     ChevronLeft,
+    /// `chevron-right` — U+E030 (available in Regular)
     ChevronRight,
+    /// `chevron-up` — U+E031 (available in Regular)
     ChevronUp,
+    /// `chevrons-down` — U+E032 (available in Regular)
     ChevronsDown,
+    /// `chevrons-left` — U+E033 (available in Regular)
     ChevronsLeft,
+    /// `chevrons-right` — U+E034 (available in Regular)
     ChevronsRight,
+    /// `chevrons-up` — U+E035 (available in Regular)
     ChevronsUp,
+    /// `chrome` — U+E036 (available in Regular)
     Chrome,
+    /// `circle` — U+E037 (available in Regular)
     Circle,
+    /// `clipboard` — U+E038 (available in Regular)
     Clipboard,
+    /// `clock` — U+E039 (available in Regular)
     Clock,
+    /// `cloud` — U+E03F (available in Regular)
     Cloud,
+    /// `cloud-drizzle` — U+E03A (available in Regular)
     CloudDrizzle,
+    /// `cloud-lightning` — U+E03B (available in Regular)
     CloudLightning,
+    /// `cloud-off` — U+E03C (available in Regular)
     CloudOff,
+    /// `cloud-rain` — U+E03D (available in Regular)
     CloudRain,
+    /// `cloud-snow` — U+E03E (available in Regular)
     CloudSnow,
+    /// `code` — U+E040 (available in Regular)
     Code,
+    /// `codepen` — U+E041 (available in Regular)
     Codepen,
+    /// `codesandbox` — U+E042 (available in Regular)
     Codesandbox,
+    /// `coffee` — U+E043 (available in Regular)
     Coffee,
+    /// `columns` — U+E044 (available in Regular)
     Columns,
+    /// `command` — U+E045 (available in Regular)
     Command,
+    /// `compass` — U+E046 (available in Regular)
     Compass,
+    /// `copy` — U+E047 (available in Regular)
     Copy,
+    /// `corner-down-left` — U+E048 (available in Regular)
     CornerDownLeft,
+    /// `corner-down-right` — U+E049 (available in Regular)
     CornerDownRight,
+    /// `corner-left-down` — U+E04A (available in Regular)
     CornerLeftDown,
+    /// `corner-left-up` — U+E04B (available in Regular)
     CornerLeftUp,
+    /// `corner-right-down` — U+E04C (available in Regular)
     CornerRightDown,
+    /// `corner-right-up` — U+E04D (available in Regular)
     CornerRightUp,
+    /// `corner-up-left` — U+E04E (available in Regular)
     CornerUpLeft,
+    /// `corner-up-right` — U+E04F (available in Regular)
     CornerUpRight,
+    /// `cpu` — U+E050 (available in Regular)
     Cpu,
+    /// `credit-card` — U+E051 (available in Regular)
     CreditCard,
+    /// `crop` — U+E052 (available in Regular)
     Crop,
+    /// `crosshair` — U+E053 (available in Regular)
     Crosshair,
+    /// `database` — U+E054 (available in Regular)
     Database,
+    /// `delete` — U+E055 (available in Regular)
     Delete,
+    /// `disc` — U+E056 (available in Regular)
     Disc,
+    /// `divide` — U+E059 (available in Regular)
     Divide,
+    /// `divide-circle` — U+E057 (available in Regular)
     DivideCircle,
+    /// `divide-square` — U+E058 (available in Regular)
     DivideSquare,
+    /// `dollar-sign` — U+E05A (available in Regular)
     DollarSign,
+    /// `download` — U+E05C (available in Regular)
     Download,
+    /// `download-cloud` — U+E05B (available in Regular)
     DownloadCloud,
+    /// `dribbble` — U+E05D (available in Regular)
     Dribbble,
+    /// `droplet` — U+E05E (available in Regular)
     Droplet,
+    /// `edit` — U+E061 (available in Regular)
     Edit,
+    /// `edit-2` — U+E05F (available in Regular)
     Edit2,
+    /// `edit-3` — U+E060 (available in Regular)
     Edit3,
+    /// `external-link` — U+E062 (available in Regular)
     ExternalLink,
+    /// `eye` — U+E064 (available in Regular)
     Eye,
+    /// `eye-off` — U+E063 (available in Regular)
     EyeOff,
+    /// `facebook` — U+E065 (available in Regular)
     Facebook,
+    /// `fast-forward` — U+E066 (available in Regular)
     FastForward,
+    /// `feather` — U+E067 (available in Regular)
     Feather,
+    /// `figma` — U+E068 (available in Regular)
     Figma,
+    /// `file` — U+E06C (available in Regular)
     File,
+    /// `file-minus` — U+E069 (available in Regular)
     FileMinus,
+    /// `file-plus` — U+E06A (available in Regular)
     FilePlus,
+    /// `file-text` — U+E06B (available in Regular)
     FileText,
+    /// `film` — U+E06D (available in Regular)
     Film,
+    /// `filter` — U+E06E (available in Regular)
     Filter,
+    /// `flag` — U+E06F (available in Regular)
     Flag,
+    /// `folder` — U+E072 (available in Regular)
     Folder,
+    /// `folder-minus` — U+E070 (available in Regular)
     FolderMinus,
+    /// `folder-plus` — U+E071 (available in Regular)
     FolderPlus,
+    /// `framer` — U+E073 (available in Regular)
     Framer,
+    /// `frown` — U+E074 (available in Regular)
     Frown,
+    /// `gift` — U+E075 (available in Regular)
     Gift,
+    /// `git-branch` — U+E076 (available in Regular)
     GitBranch,
+    /// `git-commit` — U+E077 (available in Regular)
     GitCommit,
+    /// `git-merge` — U+E078 (available in Regular)
     GitMerge,
+    /// `git-pull-request` — U+E079 (available in Regular)
     GitPullRequest,
+    /// `github` — U+E07A (available in Regular)
     Github,
+    /// `gitlab` — U+E07B (available in Regular)
     Gitlab,
+    /// `globe` — U+E07C (available in Regular)
     Globe,
+    /// `grid` — U+E07D (available in Regular)
     Grid,
+    /// `hard-drive` — U+E07E (available in Regular)
     HardDrive,
+    /// `hash` — U+E07F (available in Regular)
     Hash,
+    /// `headphones` — U+E080 (available in Regular)
     Headphones,
+    /// `heart` — U+E081 (available in Regular)
     Heart,
+    /// `help-circle` — U+E082 (available in Regular)
     HelpCircle,
+    /// `hexagon` — U+E083 (available in Regular)
     Hexagon,
+    /// `home` — U+E084 (available in Regular)
     Home,
+    /// `image` — U+E085 (available in Regular)
     Image,
+    /// `inbox` — U+E086 (available in Regular)
     Inbox,
+    /// `info` — U+E087 (available in Regular)
     Info,
+    /// `instagram` — U+E088 (available in Regular)
     Instagram,
+    /// `italic` — U+E089 (available in Regular)
     Italic,
+    /// `key` — U+E08A (available in Regular)
     Key,
+    /// `layers` — U+E08B (available in Regular)
     Layers,
+    /// `layout` — U+E08C (available in Regular)
     Layout,
+    /// `life-buoy` — U+E08D (available in Regular)
     LifeBuoy,
+    /// `link` — U+E08F (available in Regular)
     Link,
+    /// `link-2` — U+E08E (available in Regular)
     Link2,
+    /// `linkedin` — U+E090 (available in Regular)
     Linkedin,
+    /// `list` — U+E091 (available in Regular)
     List,
+    /// `loader` — U+E092 (available in Regular)
     Loader,
+    /// `lock` — U+E093 (available in Regular)
     Lock,
+    /// `log-in` — U+E094 (available in Regular)
     LogIn,
+    /// `log-out` — U+E095 (available in Regular)
     LogOut,
+    /// `mail` — U+E096 (available in Regular)
     Mail,
+    /// `map` — U+E098 (available in Regular)
     Map,
+    /// `map-pin` — U+E097 (available in Regular)
     MapPin,
+    /// `maximize` — U+E09A (available in Regular)
     Maximize,
+    /// `maximize-2` — U+E099 (available in Regular)
     Maximize2,
+    /// `meh` — U+E09B (available in Regular)
     Meh,
+    /// `menu` — U+E09C (available in Regular)
     Menu,
+    /// `message-circle` — U+E09D (available in Regular)
     MessageCircle,
+    /// `message-square` — U+E09E (available in Regular)
     MessageSquare,
+    /// `mic` — U+E0A0 (available in Regular)
     Mic,
+    /// `mic-off` — U+E09F (available in Regular)
     MicOff,
+    /// `minimize` — U+E0A2 (available in Regular)
     Minimize,
+    /// `minimize-2` — U+E0A1 (available in Regular)
     Minimize2,
+    /// `minus` — U+E0A5 (available in Regular)
     Minus,
+    /// `minus-circle` — U+E0A3 (available in Regular)
     MinusCircle,
+    /// `minus-square` — U+E0A4 (available in Regular)
     MinusSquare,
+    /// `monitor` — U+E0A6 (available in Regular)
     Monitor,
+    /// `moon` — U+E0A7 (available in Regular)
     Moon,
+    /// `more-horizontal` — U+E0A8 (available in Regular)
     MoreHorizontal,
+    /// `more-vertical` — U+E0A9 (available in Regular)
     MoreVertical,
+    /// `mouse-pointer` — U+E0AA (available in Regular)
     MousePointer,
+    /// `move` — U+E0AB (available in Regular)
     Move_,
+    /// `music` — U+E0AC (available in Regular)
     Music,
+    /// `navigation` — U+E0AE (available in Regular)
     Navigation,
+    /// `navigation-2` — U+E0AD (available in Regular)
     Navigation2,
+    /// `octagon` — U+E0AF (available in Regular)
     Octagon,
+    /// `package` — U+E0B0 (available in Regular)
     Package,
+    /// `paperclip` — U+E0B1 (available in Regular)
     Paperclip,
+    /// `pause` — U+E0B3 (available in Regular)
     Pause,
+    /// `pause-circle` — U+E0B2 (available in Regular)
     PauseCircle,
+    /// `pen-tool` — U+E0B4 (available in Regular)
     PenTool,
+    /// `percent` — U+E0B5 (available in Regular)
     Percent,
+    /// `phone` — U+E0BC (available in Regular)
     Phone,
+    /// `phone-call` — U+E0B6 (available in Regular)
     PhoneCall,
+    /// `phone-forwarded` — U+E0B7 (available in Regular)
     PhoneForwarded,
+    /// `phone-incoming` — U+E0B8 (available in Regular)
     PhoneIncoming,
+    /// `phone-missed` — U+E0B9 (available in Regular)
     PhoneMissed,
+    /// `phone-off` — U+E0BA (available in Regular)
     PhoneOff,
+    /// `phone-outgoing` — U+E0BB (available in Regular)
     PhoneOutgoing,
+    /// `pie-chart` — U+E0BD (available in Regular)
     PieChart,
+    /// `play` — U+E0BF (available in Regular)
     Play,
+    /// `play-circle` — U+E0BE (available in Regular)
     PlayCircle,
+    /// `plus` — U+E0C2 (available in Regular)
     Plus,
+    /// `plus-circle` — U+E0C0 (available in Regular)
     PlusCircle,
+    /// `plus-square` — U+E0C1 (available in Regular)
     PlusSquare,
+    /// `pocket` — U+E0C3 (available in Regular)
     Pocket,
+    /// `power` — U+E0C4 (available in Regular)
     Power,
+    /// `printer` — U+E0C5 (available in Regular)
     Printer,
+    /// `radio` — U+E0C6 (available in Regular)
     Radio,
+    /// `refresh-ccw` — U+E0C7 (available in Regular)
     RefreshCcw,
+    /// `refresh-cw` — U+E0C8 (available in Regular)
     RefreshCw,
+    /// `repeat` — U+E0C9 (available in Regular)
     Repeat,
+    /// `rewind` — U+E0CA (available in Regular)
     Rewind,
+    /// `rotate-ccw` — U+E0CB (available in Regular)
     RotateCcw,
+    /// `rotate-cw` — U+E0CC (available in Regular)
     RotateCw,
+    /// `rss` — U+E0CD (available in Regular)
     Rss,
+    /// `save` — U+E0CE (available in Regular)
     Save,
+    /// `scissors` — U+E0CF (available in Regular)
     Scissors,
+    /// `search` — U+E0D0 (available in Regular)
     Search,
+    /// `send` — U+E0D1 (available in Regular)
     Send,
+    /// `server` — U+E0D2 (available in Regular)
     Server,
+    /// `settings` — U+E0D3 (available in Regular)
     Settings,
+    /// `share` — U+E0D5 (available in Regular)
     Share,
+    /// `share-2` — U+E0D4 (available in Regular)
     Share2,
+    /// `shield` — U+E0D7 (available in Regular)
     Shield,
+    /// `shield-off` — U+E0D6 (available in Regular)
     ShieldOff,
+    /// `shopping-bag` — U+E0D8 (available in Regular)
     ShoppingBag,
+    /// `shopping-cart` — U+E0D9 (available in Regular)
     ShoppingCart,
+    /// `shuffle` — U+E0DA (available in Regular)
     Shuffle,
+    /// `sidebar` — U+E0DB (available in Regular)
     Sidebar,
+    /// `skip-back` — U+E0DC (available in Regular)
     SkipBack,
+    /// `skip-forward` — U+E0DD (available in Regular)
     SkipForward,
+    /// `slack` — U+E0DE (available in Regular)
     Slack,
+    /// `slash` — U+E0DF (available in Regular)
     Slash,
+    /// `sliders` — U+E0E0 (available in Regular)
     Sliders,
+    /// `smartphone` — U+E0E1 (available in Regular)
     Smartphone,
+    /// `smile` — U+E0E2 (available in Regular)
     Smile,
+    /// `speaker` — U+E0E3 (available in Regular)
     Speaker,
+    /// `square` — U+E0E4 (available in Regular)
     Square,
+    /// `star` — U+E0E5 (available in Regular)
     Star,
+    /// `stop-circle` — U+E0E6 (available in Regular)
     StopCircle,
+    /// `sun` — U+E0E7 (available in Regular)
     Sun,
+    /// `sunrise` — U+E0E8 (available in Regular)
     Sunrise,
+    /// `sunset` — U+E0E9 (available in Regular)
     Sunset,
+    /// `table` — U+E0EA (available in Regular)
     Table,
+    /// `tablet` — U+E0EB (available in Regular)
     Tablet,
+    /// `tag` — U+E0EC (available in Regular)
     Tag,
+    /// `target` — U+E0ED (available in Regular)
     Target,
+    /// `terminal` — U+E0EE (available in Regular)
     Terminal,
+    /// `thermometer` — U+E0EF (available in Regular)
     Thermometer,
+    /// `thumbs-down` — U+E0F0 (available in Regular)
     ThumbsDown,
+    /// `thumbs-up` — U+E0F1 (available in Regular)
     ThumbsUp,
+    /// `toggle-left` — U+E0F2 (available in Regular)
     ToggleLeft,
+    /// `toggle-right` — U+E0F3 (available in Regular)
     ToggleRight,
+    /// `tool` — U+E0F4 (available in Regular)
     Tool,
+    /// `trash` — U+E0F6 (available in Regular)
     Trash,
+    /// `trash-2` — U+E0F5 (available in Regular)
     Trash2,
+    /// `trello` — U+E0F7 (available in Regular)
     Trello,
+    /// `trending-down` — U+E0F8 (available in Regular)
     TrendingDown,
+    /// `trending-up` — U+E0F9 (available in Regular)
     TrendingUp,
+    /// `triangle` — U+E0FA (available in Regular)
     Triangle,
+    /// `truck` — U+E0FB (available in Regular)
     Truck,
+    /// `tv` — U+E0FC (available in Regular)
     Tv,
+    /// `twitch` — U+E0FD (available in Regular)
     Twitch,
+    /// `twitter` — U+E0FE (available in Regular)
     Twitter,
+    /// `type` — U+E0FF (available in Regular)
     Type_,
+    /// `umbrella` — U+E100 (available in Regular)
     Umbrella,
+    /// `underline` — U+E101 (available in Regular)
     Underline,
+    /// `unlock` — U+E102 (available in Regular)
     Unlock,
+    /// `upload` — U+E104 (available in Regular)
     Upload,
+    /// `upload-cloud` — U+E103 (available in Regular)
     UploadCloud,
+    /// `user` — U+E109 (available in Regular)
     User,
+    /// `user-check` — U+E105 (available in Regular)
     UserCheck,
+    /// `user-minus` — U+E106 (available in Regular)
     UserMinus,
+    /// `user-plus` — U+E107 (available in Regular)
     UserPlus,
+    /// `user-x` — U+E108 (available in Regular)
     UserX,
+    /// `users` — U+E10A (available in Regular)
     Users,
+    /// `video` — U+E10C (available in Regular)
     Video,
+    /// `video-off` — U+E10B (available in Regular)
     VideoOff,
+    /// `voicemail` — U+E10D (available in Regular)
     Voicemail,
+    /// `volume` — U+E111 (available in Regular)
     Volume,
+    /// `volume-1` — U+E10E (available in Regular)
     Volume1,
+    /// `volume-2` — U+E10F (available in Regular)
     Volume2,
+    /// `volume-x` — U+E110 (available in Regular)
     VolumeX,
+    /// `watch` — U+E112 (available in Regular)
     Watch,
+    /// `wifi` — U+E114 (available in Regular)
     Wifi,
+    /// `wifi-off` — U+E113 (available in Regular)
     WifiOff,
+    /// `wind` — U+E115 (available in Regular)
     Wind,
+    /// `x` — U+E119 (available in Regular)
     X,
+    /// `x-circle` — U+E116 (available in Regular)
     XCircle,
+    /// `x-octagon` — U+E117 (available in Regular)
     XOctagon,
+    /// `x-square` — U+E118 (available in Regular)
     XSquare,
+    /// `youtube` — U+E11A (available in Regular)
     Youtube,
+    /// `zap` — U+E11C (available in Regular)
     Zap,
+    /// `zap-off` — U+E11B (available in Regular)
     ZapOff,
+    /// `zoom-in` — U+E11D (available in Regular)
     ZoomIn,
+    /// `zoom-out` — U+E11E (available in Regular)
     ZoomOut,
 }
 
+/// An icon's available `(style, size)` pairs and its codepoint in each variant.
+type IconVariantTables = (&'static [(Style, Size)], &'static [(VariantKey, u32)]);
+
 impl Icon {
+    pub const ALL: &[Icon] = &[
+        Icon::Activity,
+        Icon::Airplay,
+        Icon::AlertCircle,
+        Icon::AlertOctagon,
+        Icon::AlertTriangle,
+        Icon::AlignCenter,
+        Icon::AlignJustify,
+        Icon::AlignLeft,
+        Icon::AlignRight,
+        Icon::Anchor,
+        Icon::Aperture,
+        Icon::Archive,
+        Icon::ArrowDown,
+        Icon::ArrowDownCircle,
+        Icon::ArrowDownLeft,
+        Icon::ArrowDownRight,
+        Icon::ArrowLeft,
+        Icon::ArrowLeftCircle,
+        Icon::ArrowRight,
+        Icon::ArrowRightCircle,
+        Icon::ArrowUp,
+        Icon::ArrowUpCircle,
+        Icon::ArrowUpLeft,
+        Icon::ArrowUpRight,
+        Icon::AtSign,
+        Icon::Award,
+        Icon::BarChart,
+        Icon::BarChart2,
+        Icon::Battery,
+        Icon::BatteryCharging,
+        Icon::Bell,
+        Icon::BellOff,
+        Icon::Bluetooth,
+        Icon::Bold,
+        Icon::Book,
+        Icon::BookOpen,
+        Icon::Bookmark,
+        Icon::Box,
+        Icon::Briefcase,
+        Icon::Calendar,
+        Icon::Camera,
+        Icon::CameraOff,
+        Icon::Cast,
+        Icon::Check,
+        Icon::CheckCircle,
+        Icon::CheckSquare,
+        Icon::ChevronDown,
+        Icon::ChevronLeft,
+        Icon::ChevronRight,
+        Icon::ChevronUp,
+        Icon::ChevronsDown,
+        Icon::ChevronsLeft,
+        Icon::ChevronsRight,
+        Icon::ChevronsUp,
+        Icon::Chrome,
+        Icon::Circle,
+        Icon::Clipboard,
+        Icon::Clock,
+        Icon::Cloud,
+        Icon::CloudDrizzle,
+        Icon::CloudLightning,
+        Icon::CloudOff,
+        Icon::CloudRain,
+        Icon::CloudSnow,
+        Icon::Code,
+        Icon::Codepen,
+        Icon::Codesandbox,
+        Icon::Coffee,
+        Icon::Columns,
+        Icon::Command,
+        Icon::Compass,
+        Icon::Copy,
+        Icon::CornerDownLeft,
+        Icon::CornerDownRight,
+        Icon::CornerLeftDown,
+        Icon::CornerLeftUp,
+        Icon::CornerRightDown,
+        Icon::CornerRightUp,
+        Icon::CornerUpLeft,
+        Icon::CornerUpRight,
+        Icon::Cpu,
+        Icon::CreditCard,
+        Icon::Crop,
+        Icon::Crosshair,
+        Icon::Database,
+        Icon::Delete,
+        Icon::Disc,
+        Icon::Divide,
+        Icon::DivideCircle,
+        Icon::DivideSquare,
+        Icon::DollarSign,
+        Icon::Download,
+        Icon::DownloadCloud,
+        Icon::Dribbble,
+        Icon::Droplet,
+        Icon::Edit,
+        Icon::Edit2,
+        Icon::Edit3,
+        Icon::ExternalLink,
+        Icon::Eye,
+        Icon::EyeOff,
+        Icon::Facebook,
+        Icon::FastForward,
+        Icon::Feather,
+        Icon::Figma,
+        Icon::File,
+        Icon::FileMinus,
+        Icon::FilePlus,
+        Icon::FileText,
+        Icon::Film,
+        Icon::Filter,
+        Icon::Flag,
+        Icon::Folder,
+        Icon::FolderMinus,
+        Icon::FolderPlus,
+        Icon::Framer,
+        Icon::Frown,
+        Icon::Gift,
+        Icon::GitBranch,
+        Icon::GitCommit,
+        Icon::GitMerge,
+        Icon::GitPullRequest,
+        Icon::Github,
+        Icon::Gitlab,
+        Icon::Globe,
+        Icon::Grid,
+        Icon::HardDrive,
+        Icon::Hash,
+        Icon::Headphones,
+        Icon::Heart,
+        Icon::HelpCircle,
+        Icon::Hexagon,
+        Icon::Home,
+        Icon::Image,
+        Icon::Inbox,
+        Icon::Info,
+        Icon::Instagram,
+        Icon::Italic,
+        Icon::Key,
+        Icon::Layers,
+        Icon::Layout,
+        Icon::LifeBuoy,
+        Icon::Link,
+        Icon::Link2,
+        Icon::Linkedin,
+        Icon::List,
+        Icon::Loader,
+        Icon::Lock,
+        Icon::LogIn,
+        Icon::LogOut,
+        Icon::Mail,
+        Icon::Map,
+        Icon::MapPin,
+        Icon::Maximize,
+        Icon::Maximize2,
+        Icon::Meh,
+        Icon::Menu,
+        Icon::MessageCircle,
+        Icon::MessageSquare,
+        Icon::Mic,
+        Icon::MicOff,
+        Icon::Minimize,
+        Icon::Minimize2,
+        Icon::Minus,
+        Icon::MinusCircle,
+        Icon::MinusSquare,
+        Icon::Monitor,
+        Icon::Moon,
+        Icon::MoreHorizontal,
+        Icon::MoreVertical,
+        Icon::MousePointer,
+        Icon::Move_,
+        Icon::Music,
+        Icon::Navigation,
+        Icon::Navigation2,
+        Icon::Octagon,
+        Icon::Package,
+        Icon::Paperclip,
+        Icon::Pause,
+        Icon::PauseCircle,
+        Icon::PenTool,
+        Icon::Percent,
+        Icon::Phone,
+        Icon::PhoneCall,
+        Icon::PhoneForwarded,
+        Icon::PhoneIncoming,
+        Icon::PhoneMissed,
+        Icon::PhoneOff,
+        Icon::PhoneOutgoing,
+        Icon::PieChart,
+        Icon::Play,
+        Icon::PlayCircle,
+        Icon::Plus,
+        Icon::PlusCircle,
+        Icon::PlusSquare,
+        Icon::Pocket,
+        Icon::Power,
+        Icon::Printer,
+        Icon::Radio,
+        Icon::RefreshCcw,
+        Icon::RefreshCw,
+        Icon::Repeat,
+        Icon::Rewind,
+        Icon::RotateCcw,
+        Icon::RotateCw,
+        Icon::Rss,
+        Icon::Save,
+        Icon::Scissors,
+        Icon::Search,
+        Icon::Send,
+        Icon::Server,
+        Icon::Settings,
+        Icon::Share,
+        Icon::Share2,
+        Icon::Shield,
+        Icon::ShieldOff,
+        Icon::ShoppingBag,
+        Icon::ShoppingCart,
+        Icon::Shuffle,
+        Icon::Sidebar,
+        Icon::SkipBack,
+        Icon::SkipForward,
+        Icon::Slack,
+        Icon::Slash,
+        Icon::Sliders,
+        Icon::Smartphone,
+        Icon::Smile,
+        Icon::Speaker,
+        Icon::Square,
+        Icon::Star,
+        Icon::StopCircle,
+        Icon::Sun,
+        Icon::Sunrise,
+        Icon::Sunset,
+        Icon::Table,
+        Icon::Tablet,
+        Icon::Tag,
+        Icon::Target,
+        Icon::Terminal,
+        Icon::Thermometer,
+        Icon::ThumbsDown,
+        Icon::ThumbsUp,
+        Icon::ToggleLeft,
+        Icon::ToggleRight,
+        Icon::Tool,
+        Icon::Trash,
+        Icon::Trash2,
+        Icon::Trello,
+        Icon::TrendingDown,
+        Icon::TrendingUp,
+        Icon::Triangle,
+        Icon::Truck,
+        Icon::Tv,
+        Icon::Twitch,
+        Icon::Twitter,
+        Icon::Type_,
+        Icon::Umbrella,
+        Icon::Underline,
+        Icon::Unlock,
+        Icon::Upload,
+        Icon::UploadCloud,
+        Icon::User,
+        Icon::UserCheck,
+        Icon::UserMinus,
+        Icon::UserPlus,
+        Icon::UserX,
+        Icon::Users,
+        Icon::Video,
+        Icon::VideoOff,
+        Icon::Voicemail,
+        Icon::Volume,
+        Icon::Volume1,
+        Icon::Volume2,
+        Icon::VolumeX,
+        Icon::Watch,
+        Icon::Wifi,
+        Icon::WifiOff,
+        Icon::Wind,
+        Icon::X,
+        Icon::XCircle,
+        Icon::XOctagon,
+        Icon::XSquare,
+        Icon::Youtube,
+        Icon::Zap,
+        Icon::ZapOff,
+        Icon::ZoomIn,
+        Icon::ZoomOut,
+    ];
+
     pub fn name(self) -> &'static str {
         match self {
             Icon::Activity => "activity",
@@ -605,31 +1207,464 @@ impl Icon {
         }
     }
 
+    /// Resolves this icon at a `(style, size)` known to exist, e.g. one listed in its docs.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the variant is unavailable; use [`Icon::try_icon`] to handle that case.
     pub fn icon(self, style: Style, size: Size) -> IconRef {
-        let name = self.name();
-        let available = icon_available(name).unwrap_or(&[]);
-        if !available.contains(&(style, size)) {
-            panic!(
-                "Icon '{}' is not available in {:?}/{:?}. Available: {:?}",
-                name, style, size, available
-            );
+        match self.try_icon(style, size) {
+            Ok(icon) => icon,
+            Err(err) => panic!(
+                "Icon '{}' is not available in {:?}/{:?}: {:?}",
+                self.name(),
+                style,
+                size,
+                err
+            ),
         }
-        let variant = variant_info(style, size).unwrap_or_else(|| {
-            panic!(
-                "Variant {:?}/{:?} is not available for pack {}",
-                style, size, PACK_ID
-            )
-        });
-        let codepoint = icon_codepoint(name, variant.key).unwrap_or_else(|| {
-            panic!("Icon '{}' is not available in {:?}/{:?}", name, style, size)
-        });
-        IconRef {
-            family: variant.family,
-            codepoint,
+    }
+
+    /// Resolves this icon at `(style, size)` without a name lookup, returning the same errors
+    /// as [`crate::try_icon`].
+    pub fn try_icon(self, style: Style, size: Size) -> Result<IconRef, crate::core::IconError> {
+        let (available, codepoints) = self.variant_tables();
+        let key = VariantKey { style, size };
+        crate::core::resolve_icon(
+            PACK_ID,
+            self.name(),
+            style,
+            size,
+            Some(available),
+            variant_info(style, size).map(|info| info.family),
+            codepoints
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|&(_, cp)| cp),
+        )
+        .map_err(crate::core::IconError::from)
+    }
+
+    fn variant_tables(self) -> IconVariantTables {
+        match self {
+            Icon::Activity => (ICON_ACTIVITY_AVAILABLE, ICON_ACTIVITY_CODEPOINTS),
+            Icon::Airplay => (ICON_AIRPLAY_AVAILABLE, ICON_AIRPLAY_CODEPOINTS),
+            Icon::AlertCircle => (ICON_ALERT_CIRCLE_AVAILABLE, ICON_ALERT_CIRCLE_CODEPOINTS),
+            Icon::AlertOctagon => (ICON_ALERT_OCTAGON_AVAILABLE, ICON_ALERT_OCTAGON_CODEPOINTS),
+            Icon::AlertTriangle => (
+                ICON_ALERT_TRIANGLE_AVAILABLE,
+                ICON_ALERT_TRIANGLE_CODEPOINTS,
+            ),
+            Icon::AlignCenter => (ICON_ALIGN_CENTER_AVAILABLE, ICON_ALIGN_CENTER_CODEPOINTS),
+            Icon::AlignJustify => (ICON_ALIGN_JUSTIFY_AVAILABLE, ICON_ALIGN_JUSTIFY_CODEPOINTS),
+            Icon::AlignLeft => (ICON_ALIGN_LEFT_AVAILABLE, ICON_ALIGN_LEFT_CODEPOINTS),
+            Icon::AlignRight => (ICON_ALIGN_RIGHT_AVAILABLE, ICON_ALIGN_RIGHT_CODEPOINTS),
+            Icon::Anchor => (ICON_ANCHOR_AVAILABLE, ICON_ANCHOR_CODEPOINTS),
+            Icon::Aperture => (ICON_APERTURE_AVAILABLE, ICON_APERTURE_CODEPOINTS),
+            Icon::Archive => (ICON_ARCHIVE_AVAILABLE, ICON_ARCHIVE_CODEPOINTS),
+            Icon::ArrowDown => (ICON_ARROW_DOWN_AVAILABLE, ICON_ARROW_DOWN_CODEPOINTS),
+            Icon::ArrowDownCircle => (
+                ICON_ARROW_DOWN_CIRCLE_AVAILABLE,
+                ICON_ARROW_DOWN_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowDownLeft => (
+                ICON_ARROW_DOWN_LEFT_AVAILABLE,
+                ICON_ARROW_DOWN_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowDownRight => (
+                ICON_ARROW_DOWN_RIGHT_AVAILABLE,
+                ICON_ARROW_DOWN_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowLeft => (ICON_ARROW_LEFT_AVAILABLE, ICON_ARROW_LEFT_CODEPOINTS),
+            Icon::ArrowLeftCircle => (
+                ICON_ARROW_LEFT_CIRCLE_AVAILABLE,
+                ICON_ARROW_LEFT_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowRight => (ICON_ARROW_RIGHT_AVAILABLE, ICON_ARROW_RIGHT_CODEPOINTS),
+            Icon::ArrowRightCircle => (
+                ICON_ARROW_RIGHT_CIRCLE_AVAILABLE,
+                ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowUp => (ICON_ARROW_UP_AVAILABLE, ICON_ARROW_UP_CODEPOINTS),
+            Icon::ArrowUpCircle => (
+                ICON_ARROW_UP_CIRCLE_AVAILABLE,
+                ICON_ARROW_UP_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowUpLeft => (ICON_ARROW_UP_LEFT_AVAILABLE, ICON_ARROW_UP_LEFT_CODEPOINTS),
+            Icon::ArrowUpRight => (
+                ICON_ARROW_UP_RIGHT_AVAILABLE,
+                ICON_ARROW_UP_RIGHT_CODEPOINTS,
+            ),
+            Icon::AtSign => (ICON_AT_SIGN_AVAILABLE, ICON_AT_SIGN_CODEPOINTS),
+            Icon::Award => (ICON_AWARD_AVAILABLE, ICON_AWARD_CODEPOINTS),
+            Icon::BarChart => (ICON_BAR_CHART_AVAILABLE, ICON_BAR_CHART_CODEPOINTS),
+            Icon::BarChart2 => (ICON_BAR_CHART_2_AVAILABLE, ICON_BAR_CHART_2_CODEPOINTS),
+            Icon::Battery => (ICON_BATTERY_AVAILABLE, ICON_BATTERY_CODEPOINTS),
+            Icon::BatteryCharging => (
+                ICON_BATTERY_CHARGING_AVAILABLE,
+                ICON_BATTERY_CHARGING_CODEPOINTS,
+            ),
+            Icon::Bell => (ICON_BELL_AVAILABLE, ICON_BELL_CODEPOINTS),
+            Icon::BellOff => (ICON_BELL_OFF_AVAILABLE, ICON_BELL_OFF_CODEPOINTS),
+            Icon::Bluetooth => (ICON_BLUETOOTH_AVAILABLE, ICON_BLUETOOTH_CODEPOINTS),
+            Icon::Bold => (ICON_BOLD_AVAILABLE, ICON_BOLD_CODEPOINTS),
+            Icon::Book => (ICON_BOOK_AVAILABLE, ICON_BOOK_CODEPOINTS),
+            Icon::BookOpen => (ICON_BOOK_OPEN_AVAILABLE, ICON_BOOK_OPEN_CODEPOINTS),
+            Icon::Bookmark => (ICON_BOOKMARK_AVAILABLE, ICON_BOOKMARK_CODEPOINTS),
+            Icon::Box => (ICON_BOX_AVAILABLE, ICON_BOX_CODEPOINTS),
+            Icon::Briefcase => (ICON_BRIEFCASE_AVAILABLE, ICON_BRIEFCASE_CODEPOINTS),
+            Icon::Calendar => (ICON_CALENDAR_AVAILABLE, ICON_CALENDAR_CODEPOINTS),
+            Icon::Camera => (ICON_CAMERA_AVAILABLE, ICON_CAMERA_CODEPOINTS),
+            Icon::CameraOff => (ICON_CAMERA_OFF_AVAILABLE, ICON_CAMERA_OFF_CODEPOINTS),
+            Icon::Cast => (ICON_CAST_AVAILABLE, ICON_CAST_CODEPOINTS),
+            Icon::Check => (ICON_CHECK_AVAILABLE, ICON_CHECK_CODEPOINTS),
+            Icon::CheckCircle => (ICON_CHECK_CIRCLE_AVAILABLE, ICON_CHECK_CIRCLE_CODEPOINTS),
+            Icon::CheckSquare => (ICON_CHECK_SQUARE_AVAILABLE, ICON_CHECK_SQUARE_CODEPOINTS),
+            Icon::ChevronDown => (ICON_CHEVRON_DOWN_AVAILABLE, ICON_CHEVRON_DOWN_CODEPOINTS),
+            Icon::ChevronLeft => (ICON_CHEVRON_LEFT_AVAILABLE, ICON_CHEVRON_LEFT_CODEPOINTS),
+            Icon::ChevronRight => (ICON_CHEVRON_RIGHT_AVAILABLE, ICON_CHEVRON_RIGHT_CODEPOINTS),
+            Icon::ChevronUp => (ICON_CHEVRON_UP_AVAILABLE, ICON_CHEVRON_UP_CODEPOINTS),
+            Icon::ChevronsDown => (ICON_CHEVRONS_DOWN_AVAILABLE, ICON_CHEVRONS_DOWN_CODEPOINTS),
+            Icon::ChevronsLeft => (ICON_CHEVRONS_LEFT_AVAILABLE, ICON_CHEVRONS_LEFT_CODEPOINTS),
+            Icon::ChevronsRight => (
+                ICON_CHEVRONS_RIGHT_AVAILABLE,
+                ICON_CHEVRONS_RIGHT_CODEPOINTS,
+            ),
+            Icon::ChevronsUp => (ICON_CHEVRONS_UP_AVAILABLE, ICON_CHEVRONS_UP_CODEPOINTS),
+            Icon::Chrome => (ICON_CHROME_AVAILABLE, ICON_CHROME_CODEPOINTS),
+            Icon::Circle => (ICON_CIRCLE_AVAILABLE, ICON_CIRCLE_CODEPOINTS),
+            Icon::Clipboard => (ICON_CLIPBOARD_AVAILABLE, ICON_CLIPBOARD_CODEPOINTS),
+            Icon::Clock => (ICON_CLOCK_AVAILABLE, ICON_CLOCK_CODEPOINTS),
+            Icon::Cloud => (ICON_CLOUD_AVAILABLE, ICON_CLOUD_CODEPOINTS),
+            Icon::CloudDrizzle => (ICON_CLOUD_DRIZZLE_AVAILABLE, ICON_CLOUD_DRIZZLE_CODEPOINTS),
+            Icon::CloudLightning => (
+                ICON_CLOUD_LIGHTNING_AVAILABLE,
+                ICON_CLOUD_LIGHTNING_CODEPOINTS,
+            ),
+            Icon::CloudOff => (ICON_CLOUD_OFF_AVAILABLE, ICON_CLOUD_OFF_CODEPOINTS),
+            Icon::CloudRain => (ICON_CLOUD_RAIN_AVAILABLE, ICON_CLOUD_RAIN_CODEPOINTS),
+            Icon::CloudSnow => (ICON_CLOUD_SNOW_AVAILABLE, ICON_CLOUD_SNOW_CODEPOINTS),
+            Icon::Code => (ICON_CODE_AVAILABLE, ICON_CODE_CODEPOINTS),
+            Icon::Codepen => (ICON_CODEPEN_AVAILABLE, ICON_CODEPEN_CODEPOINTS),
+            Icon::Codesandbox => (ICON_CODESANDBOX_AVAILABLE, ICON_CODESANDBOX_CODEPOINTS),
+            Icon::Coffee => (ICON_COFFEE_AVAILABLE, ICON_COFFEE_CODEPOINTS),
+            Icon::Columns => (ICON_COLUMNS_AVAILABLE, ICON_COLUMNS_CODEPOINTS),
+            Icon::Command => (ICON_COMMAND_AVAILABLE, ICON_COMMAND_CODEPOINTS),
+            Icon::Compass => (ICON_COMPASS_AVAILABLE, ICON_COMPASS_CODEPOINTS),
+            Icon::Copy => (ICON_COPY_AVAILABLE, ICON_COPY_CODEPOINTS),
+            Icon::CornerDownLeft => (
+                ICON_CORNER_DOWN_LEFT_AVAILABLE,
+                ICON_CORNER_DOWN_LEFT_CODEPOINTS,
+            ),
+            Icon::CornerDownRight => (
+                ICON_CORNER_DOWN_RIGHT_AVAILABLE,
+                ICON_CORNER_DOWN_RIGHT_CODEPOINTS,
+            ),
+            Icon::CornerLeftDown => (
+                ICON_CORNER_LEFT_DOWN_AVAILABLE,
+                ICON_CORNER_LEFT_DOWN_CODEPOINTS,
+            ),
+            Icon::CornerLeftUp => (
+                ICON_CORNER_LEFT_UP_AVAILABLE,
+                ICON_CORNER_LEFT_UP_CODEPOINTS,
+            ),
+            Icon::CornerRightDown => (
+                ICON_CORNER_RIGHT_DOWN_AVAILABLE,
+                ICON_CORNER_RIGHT_DOWN_CODEPOINTS,
+            ),
+            Icon::CornerRightUp => (
+                ICON_CORNER_RIGHT_UP_AVAILABLE,
+                ICON_CORNER_RIGHT_UP_CODEPOINTS,
+            ),
+            Icon::CornerUpLeft => (
+                ICON_CORNER_UP_LEFT_AVAILABLE,
+                ICON_CORNER_UP_LEFT_CODEPOINTS,
+            ),
+            Icon::CornerUpRight => (
+                ICON_CORNER_UP_RIGHT_AVAILABLE,
+                ICON_CORNER_UP_RIGHT_CODEPOINTS,
+            ),
+            Icon::Cpu => (ICON_CPU_AVAILABLE, ICON_CPU_CODEPOINTS),
+            Icon::CreditCard => (ICON_CREDIT_CARD_AVAILABLE, ICON_CREDIT_CARD_CODEPOINTS),
+            Icon::Crop => (ICON_CROP_AVAILABLE, ICON_CROP_CODEPOINTS),
+            Icon::Crosshair => (ICON_CROSSHAIR_AVAILABLE, ICON_CROSSHAIR_CODEPOINTS),
+            Icon::Database => (ICON_DATABASE_AVAILABLE, ICON_DATABASE_CODEPOINTS),
+            Icon::Delete => (ICON_DELETE_AVAILABLE, ICON_DELETE_CODEPOINTS),
+            Icon::Disc => (ICON_DISC_AVAILABLE, ICON_DISC_CODEPOINTS),
+            Icon::Divide => (ICON_DIVIDE_AVAILABLE, ICON_DIVIDE_CODEPOINTS),
+            Icon::DivideCircle => (ICON_DIVIDE_CIRCLE_AVAILABLE, ICON_DIVIDE_CIRCLE_CODEPOINTS),
+            Icon::DivideSquare => (ICON_DIVIDE_SQUARE_AVAILABLE, ICON_DIVIDE_SQUARE_CODEPOINTS),
+            Icon::DollarSign => (ICON_DOLLAR_SIGN_AVAILABLE, ICON_DOLLAR_SIGN_CODEPOINTS),
+            Icon::Download => (ICON_DOWNLOAD_AVAILABLE, ICON_DOWNLOAD_CODEPOINTS),
+            Icon::DownloadCloud => (
+                ICON_DOWNLOAD_CLOUD_AVAILABLE,
+                ICON_DOWNLOAD_CLOUD_CODEPOINTS,
+            ),
+            Icon::Dribbble => (ICON_DRIBBBLE_AVAILABLE, ICON_DRIBBBLE_CODEPOINTS),
+            Icon::Droplet => (ICON_DROPLET_AVAILABLE, ICON_DROPLET_CODEPOINTS),
+            Icon::Edit => (ICON_EDIT_AVAILABLE, ICON_EDIT_CODEPOINTS),
+            Icon::Edit2 => (ICON_EDIT_2_AVAILABLE, ICON_EDIT_2_CODEPOINTS),
+            Icon::Edit3 => (ICON_EDIT_3_AVAILABLE, ICON_EDIT_3_CODEPOINTS),
+            Icon::ExternalLink => (ICON_EXTERNAL_LINK_AVAILABLE, ICON_EXTERNAL_LINK_CODEPOINTS),
+            Icon::Eye => (ICON_EYE_AVAILABLE, ICON_EYE_CODEPOINTS),
+            Icon::EyeOff => (ICON_EYE_OFF_AVAILABLE, ICON_EYE_OFF_CODEPOINTS),
+            Icon::Facebook => (ICON_FACEBOOK_AVAILABLE, ICON_FACEBOOK_CODEPOINTS),
+            Icon::FastForward => (ICON_FAST_FORWARD_AVAILABLE, ICON_FAST_FORWARD_CODEPOINTS),
+            Icon::Feather => (ICON_FEATHER_AVAILABLE, ICON_FEATHER_CODEPOINTS),
+            Icon::Figma => (ICON_FIGMA_AVAILABLE, ICON_FIGMA_CODEPOINTS),
+            Icon::File => (ICON_FILE_AVAILABLE, ICON_FILE_CODEPOINTS),
+            Icon::FileMinus => (ICON_FILE_MINUS_AVAILABLE, ICON_FILE_MINUS_CODEPOINTS),
+            Icon::FilePlus => (ICON_FILE_PLUS_AVAILABLE, ICON_FILE_PLUS_CODEPOINTS),
+            Icon::FileText => (ICON_FILE_TEXT_AVAILABLE, ICON_FILE_TEXT_CODEPOINTS),
+            Icon::Film => (ICON_FILM_AVAILABLE, ICON_FILM_CODEPOINTS),
+            Icon::Filter => (ICON_FILTER_AVAILABLE, ICON_FILTER_CODEPOINTS),
+            Icon::Flag => (ICON_FLAG_AVAILABLE, ICON_FLAG_CODEPOINTS),
+            Icon::Folder => (ICON_FOLDER_AVAILABLE, ICON_FOLDER_CODEPOINTS),
+            Icon::FolderMinus => (ICON_FOLDER_MINUS_AVAILABLE, ICON_FOLDER_MINUS_CODEPOINTS),
+            Icon::FolderPlus => (ICON_FOLDER_PLUS_AVAILABLE, ICON_FOLDER_PLUS_CODEPOINTS),
+            Icon::Framer => (ICON_FRAMER_AVAILABLE, ICON_FRAMER_CODEPOINTS),
+            Icon::Frown => (ICON_FROWN_AVAILABLE, ICON_FROWN_CODEPOINTS),
+            Icon::Gift => (ICON_GIFT_AVAILABLE, ICON_GIFT_CODEPOINTS),
+            Icon::GitBranch => (ICON_GIT_BRANCH_AVAILABLE, ICON_GIT_BRANCH_CODEPOINTS),
+            Icon::GitCommit => (ICON_GIT_COMMIT_AVAILABLE, ICON_GIT_COMMIT_CODEPOINTS),
+            Icon::GitMerge => (ICON_GIT_MERGE_AVAILABLE, ICON_GIT_MERGE_CODEPOINTS),
+            Icon::GitPullRequest => (
+                ICON_GIT_PULL_REQUEST_AVAILABLE,
+                ICON_GIT_PULL_REQUEST_CODEPOINTS,
+            ),
+            Icon::Github => (ICON_GITHUB_AVAILABLE, ICON_GITHUB_CODEPOINTS),
+            Icon::Gitlab => (ICON_GITLAB_AVAILABLE, ICON_GITLAB_CODEPOINTS),
+            Icon::Globe => (ICON_GLOBE_AVAILABLE, ICON_GLOBE_CODEPOINTS),
+            Icon::Grid => (ICON_GRID_AVAILABLE, ICON_GRID_CODEPOINTS),
+            Icon::HardDrive => (ICON_HARD_DRIVE_AVAILABLE, ICON_HARD_DRIVE_CODEPOINTS),
+            Icon::Hash => (ICON_HASH_AVAILABLE, ICON_HASH_CODEPOINTS),
+            Icon::Headphones => (ICON_HEADPHONES_AVAILABLE, ICON_HEADPHONES_CODEPOINTS),
+            Icon::Heart => (ICON_HEART_AVAILABLE, ICON_HEART_CODEPOINTS),
+            Icon::HelpCircle => (ICON_HELP_CIRCLE_AVAILABLE, ICON_HELP_CIRCLE_CODEPOINTS),
+            Icon::Hexagon => (ICON_HEXAGON_AVAILABLE, ICON_HEXAGON_CODEPOINTS),
+            Icon::Home => (ICON_HOME_AVAILABLE, ICON_HOME_CODEPOINTS),
+            Icon::Image => (ICON_IMAGE_AVAILABLE, ICON_IMAGE_CODEPOINTS),
+            Icon::Inbox => (ICON_INBOX_AVAILABLE, ICON_INBOX_CODEPOINTS),
+            Icon::Info => (ICON_INFO_AVAILABLE, ICON_INFO_CODEPOINTS),
+            Icon::Instagram => (ICON_INSTAGRAM_AVAILABLE, ICON_INSTAGRAM_CODEPOINTS),
+            Icon::Italic => (ICON_ITALIC_AVAILABLE, ICON_ITALIC_CODEPOINTS),
+            Icon::Key => (ICON_KEY_AVAILABLE, ICON_KEY_CODEPOINTS),
+            Icon::Layers => (ICON_LAYERS_AVAILABLE, ICON_LAYERS_CODEPOINTS),
+            Icon::Layout => (ICON_LAYOUT_AVAILABLE, ICON_LAYOUT_CODEPOINTS),
+            Icon::LifeBuoy => (ICON_LIFE_BUOY_AVAILABLE, ICON_LIFE_BUOY_CODEPOINTS),
+            Icon::Link => (ICON_LINK_AVAILABLE, ICON_LINK_CODEPOINTS),
+            Icon::Link2 => (ICON_LINK_2_AVAILABLE, ICON_LINK_2_CODEPOINTS),
+            Icon::Linkedin => (ICON_LINKEDIN_AVAILABLE, ICON_LINKEDIN_CODEPOINTS),
+            Icon::List => (ICON_LIST_AVAILABLE, ICON_LIST_CODEPOINTS),
+            Icon::Loader => (ICON_LOADER_AVAILABLE, ICON_LOADER_CODEPOINTS),
+            Icon::Lock => (ICON_LOCK_AVAILABLE, ICON_LOCK_CODEPOINTS),
+            Icon::LogIn => (ICON_LOG_IN_AVAILABLE, ICON_LOG_IN_CODEPOINTS),
+            Icon::LogOut => (ICON_LOG_OUT_AVAILABLE, ICON_LOG_OUT_CODEPOINTS),
+            Icon::Mail => (ICON_MAIL_AVAILABLE, ICON_MAIL_CODEPOINTS),
+            Icon::Map => (ICON_MAP_AVAILABLE, ICON_MAP_CODEPOINTS),
+            Icon::MapPin => (ICON_MAP_PIN_AVAILABLE, ICON_MAP_PIN_CODEPOINTS),
+            Icon::Maximize => (ICON_MAXIMIZE_AVAILABLE, ICON_MAXIMIZE_CODEPOINTS),
+            Icon::Maximize2 => (ICON_MAXIMIZE_2_AVAILABLE, ICON_MAXIMIZE_2_CODEPOINTS),
+            Icon::Meh => (ICON_MEH_AVAILABLE, ICON_MEH_CODEPOINTS),
+            Icon::Menu => (ICON_MENU_AVAILABLE, ICON_MENU_CODEPOINTS),
+            Icon::MessageCircle => (
+                ICON_MESSAGE_CIRCLE_AVAILABLE,
+                ICON_MESSAGE_CIRCLE_CODEPOINTS,
+            ),
+            Icon::MessageSquare => (
+                ICON_MESSAGE_SQUARE_AVAILABLE,
+                ICON_MESSAGE_SQUARE_CODEPOINTS,
+            ),
+            Icon::Mic => (ICON_MIC_AVAILABLE, ICON_MIC_CODEPOINTS),
+            Icon::MicOff => (ICON_MIC_OFF_AVAILABLE, ICON_MIC_OFF_CODEPOINTS),
+            Icon::Minimize => (ICON_MINIMIZE_AVAILABLE, ICON_MINIMIZE_CODEPOINTS),
+            Icon::Minimize2 => (ICON_MINIMIZE_2_AVAILABLE, ICON_MINIMIZE_2_CODEPOINTS),
+            Icon::Minus => (ICON_MINUS_AVAILABLE, ICON_MINUS_CODEPOINTS),
+            Icon::MinusCircle => (ICON_MINUS_CIRCLE_AVAILABLE, ICON_MINUS_CIRCLE_CODEPOINTS),
+            Icon::MinusSquare => (ICON_MINUS_SQUARE_AVAILABLE, ICON_MINUS_SQUARE_CODEPOINTS),
+            Icon::Monitor => (ICON_MONITOR_AVAILABLE, ICON_MONITOR_CODEPOINTS),
+            Icon::Moon => (ICON_MOON_AVAILABLE, ICON_MOON_CODEPOINTS),
+            Icon::MoreHorizontal => (
+                ICON_MORE_HORIZONTAL_AVAILABLE,
+                ICON_MORE_HORIZONTAL_CODEPOINTS,
+            ),
+            Icon::MoreVertical => (ICON_MORE_VERTICAL_AVAILABLE, ICON_MORE_VERTICAL_CODEPOINTS),
+            Icon::MousePointer => (ICON_MOUSE_POINTER_AVAILABLE, ICON_MOUSE_POINTER_CODEPOINTS),
+            Icon::Move_ => (ICON_MOVE__AVAILABLE, ICON_MOVE__CODEPOINTS),
+            Icon::Music => (ICON_MUSIC_AVAILABLE, ICON_MUSIC_CODEPOINTS),
+            Icon::Navigation => (ICON_NAVIGATION_AVAILABLE, ICON_NAVIGATION_CODEPOINTS),
+            Icon::Navigation2 => (ICON_NAVIGATION_2_AVAILABLE, ICON_NAVIGATION_2_CODEPOINTS),
+            Icon::Octagon => (ICON_OCTAGON_AVAILABLE, ICON_OCTAGON_CODEPOINTS),
+            Icon::Package => (ICON_PACKAGE_AVAILABLE, ICON_PACKAGE_CODEPOINTS),
+            Icon::Paperclip => (ICON_PAPERCLIP_AVAILABLE, ICON_PAPERCLIP_CODEPOINTS),
+            Icon::Pause => (ICON_PAUSE_AVAILABLE, ICON_PAUSE_CODEPOINTS),
+            Icon::PauseCircle => (ICON_PAUSE_CIRCLE_AVAILABLE, ICON_PAUSE_CIRCLE_CODEPOINTS),
+            Icon::PenTool => (ICON_PEN_TOOL_AVAILABLE, ICON_PEN_TOOL_CODEPOINTS),
+            Icon::Percent => (ICON_PERCENT_AVAILABLE, ICON_PERCENT_CODEPOINTS),
+            Icon::Phone => (ICON_PHONE_AVAILABLE, ICON_PHONE_CODEPOINTS),
+            Icon::PhoneCall => (ICON_PHONE_CALL_AVAILABLE, ICON_PHONE_CALL_CODEPOINTS),
+            Icon::PhoneForwarded => (
+                ICON_PHONE_FORWARDED_AVAILABLE,
+                ICON_PHONE_FORWARDED_CODEPOINTS,
+            ),
+            Icon::PhoneIncoming => (
+                ICON_PHONE_INCOMING_AVAILABLE,
+                ICON_PHONE_INCOMING_CODEPOINTS,
+            ),
+            Icon::PhoneMissed => (ICON_PHONE_MISSED_AVAILABLE, ICON_PHONE_MISSED_CODEPOINTS),
+            Icon::PhoneOff => (ICON_PHONE_OFF_AVAILABLE, ICON_PHONE_OFF_CODEPOINTS),
+            Icon::PhoneOutgoing => (
+                ICON_PHONE_OUTGOING_AVAILABLE,
+                ICON_PHONE_OUTGOING_CODEPOINTS,
+            ),
+            Icon::PieChart => (ICON_PIE_CHART_AVAILABLE, ICON_PIE_CHART_CODEPOINTS),
+            Icon::Play => (ICON_PLAY_AVAILABLE, ICON_PLAY_CODEPOINTS),
+            Icon::PlayCircle => (ICON_PLAY_CIRCLE_AVAILABLE, ICON_PLAY_CIRCLE_CODEPOINTS),
+            Icon::Plus => (ICON_PLUS_AVAILABLE, ICON_PLUS_CODEPOINTS),
+            Icon::PlusCircle => (ICON_PLUS_CIRCLE_AVAILABLE, ICON_PLUS_CIRCLE_CODEPOINTS),
+            Icon::PlusSquare => (ICON_PLUS_SQUARE_AVAILABLE, ICON_PLUS_SQUARE_CODEPOINTS),
+            Icon::Pocket => (ICON_POCKET_AVAILABLE, ICON_POCKET_CODEPOINTS),
+            Icon::Power => (ICON_POWER_AVAILABLE, ICON_POWER_CODEPOINTS),
+            Icon::Printer => (ICON_PRINTER_AVAILABLE, ICON_PRINTER_CODEPOINTS),
+            Icon::Radio => (ICON_RADIO_AVAILABLE, ICON_RADIO_CODEPOINTS),
+            Icon::RefreshCcw => (ICON_REFRESH_CCW_AVAILABLE, ICON_REFRESH_CCW_CODEPOINTS),
+            Icon::RefreshCw => (ICON_REFRESH_CW_AVAILABLE, ICON_REFRESH_CW_CODEPOINTS),
+            Icon::Repeat => (ICON_REPEAT_AVAILABLE, ICON_REPEAT_CODEPOINTS),
+            Icon::Rewind => (ICON_REWIND_AVAILABLE, ICON_REWIND_CODEPOINTS),
+            Icon::RotateCcw => (ICON_ROTATE_CCW_AVAILABLE, ICON_ROTATE_CCW_CODEPOINTS),
+            Icon::RotateCw => (ICON_ROTATE_CW_AVAILABLE, ICON_ROTATE_CW_CODEPOINTS),
+            Icon::Rss => (ICON_RSS_AVAILABLE, ICON_RSS_CODEPOINTS),
+            Icon::Save => (ICON_SAVE_AVAILABLE, ICON_SAVE_CODEPOINTS),
+            Icon::Scissors => (ICON_SCISSORS_AVAILABLE, ICON_SCISSORS_CODEPOINTS),
+            Icon::Search => (ICON_SEARCH_AVAILABLE, ICON_SEARCH_CODEPOINTS),
+            Icon::Send => (ICON_SEND_AVAILABLE, ICON_SEND_CODEPOINTS),
+            Icon::Server => (ICON_SERVER_AVAILABLE, ICON_SERVER_CODEPOINTS),
+            Icon::Settings => (ICON_SETTINGS_AVAILABLE, ICON_SETTINGS_CODEPOINTS),
+            Icon::Share => (ICON_SHARE_AVAILABLE, ICON_SHARE_CODEPOINTS),
+            Icon::Share2 => (ICON_SHARE_2_AVAILABLE, ICON_SHARE_2_CODEPOINTS),
+            Icon::Shield => (ICON_SHIELD_AVAILABLE, ICON_SHIELD_CODEPOINTS),
+            Icon::ShieldOff => (ICON_SHIELD_OFF_AVAILABLE, ICON_SHIELD_OFF_CODEPOINTS),
+            Icon::ShoppingBag => (ICON_SHOPPING_BAG_AVAILABLE, ICON_SHOPPING_BAG_CODEPOINTS),
+            Icon::ShoppingCart => (ICON_SHOPPING_CART_AVAILABLE, ICON_SHOPPING_CART_CODEPOINTS),
+            Icon::Shuffle => (ICON_SHUFFLE_AVAILABLE, ICON_SHUFFLE_CODEPOINTS),
+            Icon::Sidebar => (ICON_SIDEBAR_AVAILABLE, ICON_SIDEBAR_CODEPOINTS),
+            Icon::SkipBack => (ICON_SKIP_BACK_AVAILABLE, ICON_SKIP_BACK_CODEPOINTS),
+            Icon::SkipForward => (ICON_SKIP_FORWARD_AVAILABLE, ICON_SKIP_FORWARD_CODEPOINTS),
+            Icon::Slack => (ICON_SLACK_AVAILABLE, ICON_SLACK_CODEPOINTS),
+            Icon::Slash => (ICON_SLASH_AVAILABLE, ICON_SLASH_CODEPOINTS),
+            Icon::Sliders => (ICON_SLIDERS_AVAILABLE, ICON_SLIDERS_CODEPOINTS),
+            Icon::Smartphone => (ICON_SMARTPHONE_AVAILABLE, ICON_SMARTPHONE_CODEPOINTS),
+            Icon::Smile => (ICON_SMILE_AVAILABLE, ICON_SMILE_CODEPOINTS),
+            Icon::Speaker => (ICON_SPEAKER_AVAILABLE, ICON_SPEAKER_CODEPOINTS),
+            Icon::Square => (ICON_SQUARE_AVAILABLE, ICON_SQUARE_CODEPOINTS),
+            Icon::Star => (ICON_STAR_AVAILABLE, ICON_STAR_CODEPOINTS),
+            Icon::StopCircle => (ICON_STOP_CIRCLE_AVAILABLE, ICON_STOP_CIRCLE_CODEPOINTS),
+            Icon::Sun => (ICON_SUN_AVAILABLE, ICON_SUN_CODEPOINTS),
+            Icon::Sunrise => (ICON_SUNRISE_AVAILABLE, ICON_SUNRISE_CODEPOINTS),
+            Icon::Sunset => (ICON_SUNSET_AVAILABLE, ICON_SUNSET_CODEPOINTS),
+            Icon::Table => (ICON_TABLE_AVAILABLE, ICON_TABLE_CODEPOINTS),
+            Icon::Tablet => (ICON_TABLET_AVAILABLE, ICON_TABLET_CODEPOINTS),
+            Icon::Tag => (ICON_TAG_AVAILABLE, ICON_TAG_CODEPOINTS),
+            Icon::Target => (ICON_TARGET_AVAILABLE, ICON_TARGET_CODEPOINTS),
+            Icon::Terminal => (ICON_TERMINAL_AVAILABLE, ICON_TERMINAL_CODEPOINTS),
+            Icon::Thermometer => (ICON_THERMOMETER_AVAILABLE, ICON_THERMOMETER_CODEPOINTS),
+            Icon::ThumbsDown => (ICON_THUMBS_DOWN_AVAILABLE, ICON_THUMBS_DOWN_CODEPOINTS),
+            Icon::ThumbsUp => (ICON_THUMBS_UP_AVAILABLE, ICON_THUMBS_UP_CODEPOINTS),
+            Icon::ToggleLeft => (ICON_TOGGLE_LEFT_AVAILABLE, ICON_TOGGLE_LEFT_CODEPOINTS),
+            Icon::ToggleRight => (ICON_TOGGLE_RIGHT_AVAILABLE, ICON_TOGGLE_RIGHT_CODEPOINTS),
+            Icon::Tool => (ICON_TOOL_AVAILABLE, ICON_TOOL_CODEPOINTS),
+            Icon::Trash => (ICON_TRASH_AVAILABLE, ICON_TRASH_CODEPOINTS),
+            Icon::Trash2 => (ICON_TRASH_2_AVAILABLE, ICON_TRASH_2_CODEPOINTS),
+            Icon::Trello => (ICON_TRELLO_AVAILABLE, ICON_TRELLO_CODEPOINTS),
+            Icon::TrendingDown => (ICON_TRENDING_DOWN_AVAILABLE, ICON_TRENDING_DOWN_CODEPOINTS),
+            Icon::TrendingUp => (ICON_TRENDING_UP_AVAILABLE, ICON_TRENDING_UP_CODEPOINTS),
+            Icon::Triangle => (ICON_TRIANGLE_AVAILABLE, ICON_TRIANGLE_CODEPOINTS),
+            Icon::Truck => (ICON_TRUCK_AVAILABLE, ICON_TRUCK_CODEPOINTS),
+            Icon::Tv => (ICON_TV_AVAILABLE, ICON_TV_CODEPOINTS),
+            Icon::Twitch => (ICON_TWITCH_AVAILABLE, ICON_TWITCH_CODEPOINTS),
+            Icon::Twitter => (ICON_TWITTER_AVAILABLE, ICON_TWITTER_CODEPOINTS),
+            Icon::Type_ => (ICON_TYPE__AVAILABLE, ICON_TYPE__CODEPOINTS),
+            Icon::Umbrella => (ICON_UMBRELLA_AVAILABLE, ICON_UMBRELLA_CODEPOINTS),
+            Icon::Underline => (ICON_UNDERLINE_AVAILABLE, ICON_UNDERLINE_CODEPOINTS),
+            Icon::Unlock => (ICON_UNLOCK_AVAILABLE, ICON_UNLOCK_CODEPOINTS),
+            Icon::Upload => (ICON_UPLOAD_AVAILABLE, ICON_UPLOAD_CODEPOINTS),
+            Icon::UploadCloud => (ICON_UPLOAD_CLOUD_AVAILABLE, ICON_UPLOAD_CLOUD_CODEPOINTS),
+            Icon::User => (ICON_USER_AVAILABLE, ICON_USER_CODEPOINTS),
+            Icon::UserCheck => (ICON_USER_CHECK_AVAILABLE, ICON_USER_CHECK_CODEPOINTS),
+            Icon::UserMinus => (ICON_USER_MINUS_AVAILABLE, ICON_USER_MINUS_CODEPOINTS),
+            Icon::UserPlus => (ICON_USER_PLUS_AVAILABLE, ICON_USER_PLUS_CODEPOINTS),
+            Icon::UserX => (ICON_USER_X_AVAILABLE, ICON_USER_X_CODEPOINTS),
+            Icon::Users => (ICON_USERS_AVAILABLE, ICON_USERS_CODEPOINTS),
+            Icon::Video => (ICON_VIDEO_AVAILABLE, ICON_VIDEO_CODEPOINTS),
+            Icon::VideoOff => (ICON_VIDEO_OFF_AVAILABLE, ICON_VIDEO_OFF_CODEPOINTS),
+            Icon::Voicemail => (ICON_VOICEMAIL_AVAILABLE, ICON_VOICEMAIL_CODEPOINTS),
+            Icon::Volume => (ICON_VOLUME_AVAILABLE, ICON_VOLUME_CODEPOINTS),
+            Icon::Volume1 => (ICON_VOLUME_1_AVAILABLE, ICON_VOLUME_1_CODEPOINTS),
+            Icon::Volume2 => (ICON_VOLUME_2_AVAILABLE, ICON_VOLUME_2_CODEPOINTS),
+            Icon::VolumeX => (ICON_VOLUME_X_AVAILABLE, ICON_VOLUME_X_CODEPOINTS),
+            Icon::Watch => (ICON_WATCH_AVAILABLE, ICON_WATCH_CODEPOINTS),
+            Icon::Wifi => (ICON_WIFI_AVAILABLE, ICON_WIFI_CODEPOINTS),
+            Icon::WifiOff => (ICON_WIFI_OFF_AVAILABLE, ICON_WIFI_OFF_CODEPOINTS),
+            Icon::Wind => (ICON_WIND_AVAILABLE, ICON_WIND_CODEPOINTS),
+            Icon::X => (ICON_X_AVAILABLE, ICON_X_CODEPOINTS),
+            Icon::XCircle => (ICON_X_CIRCLE_AVAILABLE, ICON_X_CIRCLE_CODEPOINTS),
+            Icon::XOctagon => (ICON_X_OCTAGON_AVAILABLE, ICON_X_OCTAGON_CODEPOINTS),
+            Icon::XSquare => (ICON_X_SQUARE_AVAILABLE, ICON_X_SQUARE_CODEPOINTS),
+            Icon::Youtube => (ICON_YOUTUBE_AVAILABLE, ICON_YOUTUBE_CODEPOINTS),
+            Icon::Zap => (ICON_ZAP_AVAILABLE, ICON_ZAP_CODEPOINTS),
+            Icon::ZapOff => (ICON_ZAP_OFF_AVAILABLE, ICON_ZAP_OFF_CODEPOINTS),
+            Icon::ZoomIn => (ICON_ZOOM_IN_AVAILABLE, ICON_ZOOM_IN_CODEPOINTS),
+            Icon::ZoomOut => (ICON_ZOOM_OUT_AVAILABLE, ICON_ZOOM_OUT_CODEPOINTS),
         }
     }
 }
 
+/// Looks an icon up by its exact map name; unknown names yield `IconError::IconNotFound`.
+///
+/// Binary-searches `ICON_NAMES`, which is sorted and index-aligned with `Icon::ALL`.
+impl TryFrom<&str> for Icon {
+    type Error = crate::core::IconError;
+
+    fn try_from(name: &str) -> Result<Self, crate::core::IconError> {
+        ICON_NAMES
+            .binary_search(&name)
+            .map(|index| Icon::ALL[index])
+            .map_err(|_| crate::core::IconError::IconNotFound {
+                pack: PACK_ID,
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Serializes as the icon's map name, e.g. `"alarm"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Icon {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes from the icon's map name through `TryFrom<&str>`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Icon {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Icon::try_from(name.as_str()).map_err(|_| {
+            <D::Error as serde::de::Error>::custom(format_args!("unknown {PACK_ID} icon '{name}'"))
+        })
+    }
+}
+
+impl From<Icon> for &'static str {
+    fn from(icon: Icon) -> Self {
+        icon.name()
+    }
+}
+
+pub fn icons() -> impl Iterator<Item = Icon> {
+    Icon::ALL.iter().copied()
+}
+
 pub const ICON_NAMES: &[&str] = &[
     "activity",
     "airplay",
@@ -920,6 +1955,2887 @@ pub const ICON_NAMES: &[&str] = &[
     "zoom-out",
 ];
 
+/// Icon names as constants, e.g. for `try_icon(pack, names::ALARM, ..)`.
+pub mod names {
+    pub const ACTIVITY: &str = "activity";
+    pub const AIRPLAY: &str = "airplay";
+    pub const ALERT_CIRCLE: &str = "alert-circle";
+    pub const ALERT_OCTAGON: &str = "alert-octagon";
+    pub const ALERT_TRIANGLE: &str = "alert-triangle";
+    pub const ALIGN_CENTER: &str = "align-center";
+    pub const ALIGN_JUSTIFY: &str = "align-justify";
+    pub const ALIGN_LEFT: &str = "align-left";
+    pub const ALIGN_RIGHT: &str = "align-right";
+    pub const ANCHOR: &str = "anchor";
+    pub const APERTURE: &str = "aperture";
+    pub const ARCHIVE: &str = "archive";
+    pub const ARROW_DOWN: &str = "arrow-down";
+    pub const ARROW_DOWN_CIRCLE: &str = "arrow-down-circle";
+    pub const ARROW_DOWN_LEFT: &str = "arrow-down-left";
+    pub const ARROW_DOWN_RIGHT: &str = "arrow-down-right";
+    pub const ARROW_LEFT: &str = "arrow-left";
+    pub const ARROW_LEFT_CIRCLE: &str = "arrow-left-circle";
+    pub const ARROW_RIGHT: &str = "arrow-right";
+    pub const ARROW_RIGHT_CIRCLE: &str = "arrow-right-circle";
+    pub const ARROW_UP: &str = "arrow-up";
+    pub const ARROW_UP_CIRCLE: &str = "arrow-up-circle";
+    pub const ARROW_UP_LEFT: &str = "arrow-up-left";
+    pub const ARROW_UP_RIGHT: &str = "arrow-up-right";
+    pub const AT_SIGN: &str = "at-sign";
+    pub const AWARD: &str = "award";
+    pub const BAR_CHART: &str = "bar-chart";
+    pub const BAR_CHART_2: &str = "bar-chart-2";
+    pub const BATTERY: &str = "battery";
+    pub const BATTERY_CHARGING: &str = "battery-charging";
+    pub const BELL: &str = "bell";
+    pub const BELL_OFF: &str = "bell-off";
+    pub const BLUETOOTH: &str = "bluetooth";
+    pub const BOLD: &str = "bold";
+    pub const BOOK: &str = "book";
+    pub const BOOK_OPEN: &str = "book-open";
+    pub const BOOKMARK: &str = "bookmark";
+    pub const BOX: &str = "box";
+    pub const BRIEFCASE: &str = "briefcase";
+    pub const CALENDAR: &str = "calendar";
+    pub const CAMERA: &str = "camera";
+    pub const CAMERA_OFF: &str = "camera-off";
+    pub const CAST: &str = "cast";
+    pub const CHECK: &str = "check";
+    pub const CHECK_CIRCLE: &str = "check-circle";
+    pub const CHECK_SQUARE: &str = "check-square";
+    pub const CHEVRON_DOWN: &str = "chevron-down";
+    pub const CHEVRON_LEFT: &str = "chevron-left";
+    pub const CHEVRON_RIGHT: &str = "chevron-right";
+    pub const CHEVRON_UP: &str = "chevron-up";
+    pub const CHEVRONS_DOWN: &str = "chevrons-down";
+    pub const CHEVRONS_LEFT: &str = "chevrons-left";
+    pub const CHEVRONS_RIGHT: &str = "chevrons-right";
+    pub const CHEVRONS_UP: &str = "chevrons-up";
+    pub const CHROME: &str = "chrome";
+    pub const CIRCLE: &str = "circle";
+    pub const CLIPBOARD: &str = "clipboard";
+    pub const CLOCK: &str = "clock";
+    pub const CLOUD: &str = "cloud";
+    pub const CLOUD_DRIZZLE: &str = "cloud-drizzle";
+    pub const CLOUD_LIGHTNING: &str = "cloud-lightning";
+    pub const CLOUD_OFF: &str = "cloud-off";
+    pub const CLOUD_RAIN: &str = "cloud-rain";
+    pub const CLOUD_SNOW: &str = "cloud-snow";
+    pub const CODE: &str = "code";
+    pub const CODEPEN: &str = "codepen";
+    pub const CODESANDBOX: &str = "codesandbox";
+    pub const COFFEE: &str = "coffee";
+    pub const COLUMNS: &str = "columns";
+    pub const COMMAND: &str = "command";
+    pub const COMPASS: &str = "compass";
+    pub const COPY: &str = "copy";
+    pub const CORNER_DOWN_LEFT: &str = "corner-down-left";
+    pub const CORNER_DOWN_RIGHT: &str = "corner-down-right";
+    pub const CORNER_LEFT_DOWN: &str = "corner-left-down";
+    pub const CORNER_LEFT_UP: &str = "corner-left-up";
+    pub const CORNER_RIGHT_DOWN: &str = "corner-right-down";
+    pub const CORNER_RIGHT_UP: &str = "corner-right-up";
+    pub const CORNER_UP_LEFT: &str = "corner-up-left";
+    pub const CORNER_UP_RIGHT: &str = "corner-up-right";
+    pub const CPU: &str = "cpu";
+    pub const CREDIT_CARD: &str = "credit-card";
+    pub const CROP: &str = "crop";
+    pub const CROSSHAIR: &str = "crosshair";
+    pub const DATABASE: &str = "database";
+    pub const DELETE: &str = "delete";
+    pub const DISC: &str = "disc";
+    pub const DIVIDE: &str = "divide";
+    pub const DIVIDE_CIRCLE: &str = "divide-circle";
+    pub const DIVIDE_SQUARE: &str = "divide-square";
+    pub const DOLLAR_SIGN: &str = "dollar-sign";
+    pub const DOWNLOAD: &str = "download";
+    pub const DOWNLOAD_CLOUD: &str = "download-cloud";
+    pub const DRIBBBLE: &str = "dribbble";
+    pub const DROPLET: &str = "droplet";
+    pub const EDIT: &str = "edit";
+    pub const EDIT_2: &str = "edit-2";
+    pub const EDIT_3: &str = "edit-3";
+    pub const EXTERNAL_LINK: &str = "external-link";
+    pub const EYE: &str = "eye";
+    pub const EYE_OFF: &str = "eye-off";
+    pub const FACEBOOK: &str = "facebook";
+    pub const FAST_FORWARD: &str = "fast-forward";
+    pub const FEATHER: &str = "feather";
+    pub const FIGMA: &str = "figma";
+    pub const FILE: &str = "file";
+    pub const FILE_MINUS: &str = "file-minus";
+    pub const FILE_PLUS: &str = "file-plus";
+    pub const FILE_TEXT: &str = "file-text";
+    pub const FILM: &str = "film";
+    pub const FILTER: &str = "filter";
+    pub const FLAG: &str = "flag";
+    pub const FOLDER: &str = "folder";
+    pub const FOLDER_MINUS: &str = "folder-minus";
+    pub const FOLDER_PLUS: &str = "folder-plus";
+    pub const FRAMER: &str = "framer";
+    pub const FROWN: &str = "frown";
+    pub const GIFT: &str = "gift";
+    pub const GIT_BRANCH: &str = "git-branch";
+    pub const GIT_COMMIT: &str = "git-commit";
+    pub const GIT_MERGE: &str = "git-merge";
+    pub const GIT_PULL_REQUEST: &str = "git-pull-request";
+    pub const GITHUB: &str = "github";
+    pub const GITLAB: &str = "gitlab";
+    pub const GLOBE: &str = "globe";
+    pub const GRID: &str = "grid";
+    pub const HARD_DRIVE: &str = "hard-drive";
+    pub const HASH: &str = "hash";
+    pub const HEADPHONES: &str = "headphones";
+    pub const HEART: &str = "heart";
+    pub const HELP_CIRCLE: &str = "help-circle";
+    pub const HEXAGON: &str = "hexagon";
+    pub const HOME: &str = "home";
+    pub const IMAGE: &str = "image";
+    pub const INBOX: &str = "inbox";
+    pub const INFO: &str = "info";
+    pub const INSTAGRAM: &str = "instagram";
+    pub const ITALIC: &str = "italic";
+    pub const KEY: &str = "key";
+    pub const LAYERS: &str = "layers";
+    pub const LAYOUT: &str = "layout";
+    pub const LIFE_BUOY: &str = "life-buoy";
+    pub const LINK: &str = "link";
+    pub const LINK_2: &str = "link-2";
+    pub const LINKEDIN: &str = "linkedin";
+    pub const LIST: &str = "list";
+    pub const LOADER: &str = "loader";
+    pub const LOCK: &str = "lock";
+    pub const LOG_IN: &str = "log-in";
+    pub const LOG_OUT: &str = "log-out";
+    pub const MAIL: &str = "mail";
+    pub const MAP: &str = "map";
+    pub const MAP_PIN: &str = "map-pin";
+    pub const MAXIMIZE: &str = "maximize";
+    pub const MAXIMIZE_2: &str = "maximize-2";
+    pub const MEH: &str = "meh";
+    pub const MENU: &str = "menu";
+    pub const MESSAGE_CIRCLE: &str = "message-circle";
+    pub const MESSAGE_SQUARE: &str = "message-square";
+    pub const MIC: &str = "mic";
+    pub const MIC_OFF: &str = "mic-off";
+    pub const MINIMIZE: &str = "minimize";
+    pub const MINIMIZE_2: &str = "minimize-2";
+    pub const MINUS: &str = "minus";
+    pub const MINUS_CIRCLE: &str = "minus-circle";
+    pub const MINUS_SQUARE: &str = "minus-square";
+    pub const MONITOR: &str = "monitor";
+    pub const MOON: &str = "moon";
+    pub const MORE_HORIZONTAL: &str = "more-horizontal";
+    pub const MORE_VERTICAL: &str = "more-vertical";
+    pub const MOUSE_POINTER: &str = "mouse-pointer";
+    pub const MOVE_: &str = "move";
+    pub const MUSIC: &str = "music";
+    pub const NAVIGATION: &str = "navigation";
+    pub const NAVIGATION_2: &str = "navigation-2";
+    pub const OCTAGON: &str = "octagon";
+    pub const PACKAGE: &str = "package";
+    pub const PAPERCLIP: &str = "paperclip";
+    pub const PAUSE: &str = "pause";
+    pub const PAUSE_CIRCLE: &str = "pause-circle";
+    pub const PEN_TOOL: &str = "pen-tool";
+    pub const PERCENT: &str = "percent";
+    pub const PHONE: &str = "phone";
+    pub const PHONE_CALL: &str = "phone-call";
+    pub const PHONE_FORWARDED: &str = "phone-forwarded";
+    pub const PHONE_INCOMING: &str = "phone-incoming";
+    pub const PHONE_MISSED: &str = "phone-missed";
+    pub const PHONE_OFF: &str = "phone-off";
+    pub const PHONE_OUTGOING: &str = "phone-outgoing";
+    pub const PIE_CHART: &str = "pie-chart";
+    pub const PLAY: &str = "play";
+    pub const PLAY_CIRCLE: &str = "play-circle";
+    pub const PLUS: &str = "plus";
+    pub const PLUS_CIRCLE: &str = "plus-circle";
+    pub const PLUS_SQUARE: &str = "plus-square";
+    pub const POCKET: &str = "pocket";
+    pub const POWER: &str = "power";
+    pub const PRINTER: &str = "printer";
+    pub const RADIO: &str = "radio";
+    pub const REFRESH_CCW: &str = "refresh-ccw";
+    pub const REFRESH_CW: &str = "refresh-cw";
+    pub const REPEAT: &str = "repeat";
+    pub const REWIND: &str = "rewind";
+    pub const ROTATE_CCW: &str = "rotate-ccw";
+    pub const ROTATE_CW: &str = "rotate-cw";
+    pub const RSS: &str = "rss";
+    pub const SAVE: &str = "save";
+    pub const SCISSORS: &str = "scissors";
+    pub const SEARCH: &str = "search";
+    pub const SEND: &str = "send";
+    pub const SERVER: &str = "server";
+    pub const SETTINGS: &str = "settings";
+    pub const SHARE: &str = "share";
+    pub const SHARE_2: &str = "share-2";
+    pub const SHIELD: &str = "shield";
+    pub const SHIELD_OFF: &str = "shield-off";
+    pub const SHOPPING_BAG: &str = "shopping-bag";
+    pub const SHOPPING_CART: &str = "shopping-cart";
+    pub const SHUFFLE: &str = "shuffle";
+    pub const SIDEBAR: &str = "sidebar";
+    pub const SKIP_BACK: &str = "skip-back";
+    pub const SKIP_FORWARD: &str = "skip-forward";
+    pub const SLACK: &str = "slack";
+    pub const SLASH: &str = "slash";
+    pub const SLIDERS: &str = "sliders";
+    pub const SMARTPHONE: &str = "smartphone";
+    pub const SMILE: &str = "smile";
+    pub const SPEAKER: &str = "speaker";
+    pub const SQUARE: &str = "square";
+    pub const STAR: &str = "star";
+    pub const STOP_CIRCLE: &str = "stop-circle";
+    pub const SUN: &str = "sun";
+    pub const SUNRISE: &str = "sunrise";
+    pub const SUNSET: &str = "sunset";
+    pub const TABLE: &str = "table";
+    pub const TABLET: &str = "tablet";
+    pub const TAG: &str = "tag";
+    pub const TARGET: &str = "target";
+    pub const TERMINAL: &str = "terminal";
+    pub const THERMOMETER: &str = "thermometer";
+    pub const THUMBS_DOWN: &str = "thumbs-down";
+    pub const THUMBS_UP: &str = "thumbs-up";
+    pub const TOGGLE_LEFT: &str = "toggle-left";
+    pub const TOGGLE_RIGHT: &str = "toggle-right";
+    pub const TOOL: &str = "tool";
+    pub const TRASH: &str = "trash";
+    pub const TRASH_2: &str = "trash-2";
+    pub const TRELLO: &str = "trello";
+    pub const TRENDING_DOWN: &str = "trending-down";
+    pub const TRENDING_UP: &str = "trending-up";
+    pub const TRIANGLE: &str = "triangle";
+    pub const TRUCK: &str = "truck";
+    pub const TV: &str = "tv";
+    pub const TWITCH: &str = "twitch";
+    pub const TWITTER: &str = "twitter";
+    pub const TYPE_: &str = "type";
+    pub const UMBRELLA: &str = "umbrella";
+    pub const UNDERLINE: &str = "underline";
+    pub const UNLOCK: &str = "unlock";
+    pub const UPLOAD: &str = "upload";
+    pub const UPLOAD_CLOUD: &str = "upload-cloud";
+    pub const USER: &str = "user";
+    pub const USER_CHECK: &str = "user-check";
+    pub const USER_MINUS: &str = "user-minus";
+    pub const USER_PLUS: &str = "user-plus";
+    pub const USER_X: &str = "user-x";
+    pub const USERS: &str = "users";
+    pub const VIDEO: &str = "video";
+    pub const VIDEO_OFF: &str = "video-off";
+    pub const VOICEMAIL: &str = "voicemail";
+    pub const VOLUME: &str = "volume";
+    pub const VOLUME_1: &str = "volume-1";
+    pub const VOLUME_2: &str = "volume-2";
+    pub const VOLUME_X: &str = "volume-x";
+    pub const WATCH: &str = "watch";
+    pub const WIFI: &str = "wifi";
+    pub const WIFI_OFF: &str = "wifi-off";
+    pub const WIND: &str = "wind";
+    pub const X: &str = "x";
+    pub const X_CIRCLE: &str = "x-circle";
+    pub const X_OCTAGON: &str = "x-octagon";
+    pub const X_SQUARE: &str = "x-square";
+    pub const YOUTUBE: &str = "youtube";
+    pub const ZAP: &str = "zap";
+    pub const ZAP_OFF: &str = "zap-off";
+    pub const ZOOM_IN: &str = "zoom-in";
+    pub const ZOOM_OUT: &str = "zoom-out";
+}
+
+pub(crate) const ICON_NAMES_LOWERCASE: &[(&str, &str)] = &[
+    ("activity", "activity"),
+    ("airplay", "airplay"),
+    ("alert-circle", "alert-circle"),
+    ("alert-octagon", "alert-octagon"),
+    ("alert-triangle", "alert-triangle"),
+    ("align-center", "align-center"),
+    ("align-justify", "align-justify"),
+    ("align-left", "align-left"),
+    ("align-right", "align-right"),
+    ("anchor", "anchor"),
+    ("aperture", "aperture"),
+    ("archive", "archive"),
+    ("arrow-down", "arrow-down"),
+    ("arrow-down-circle", "arrow-down-circle"),
+    ("arrow-down-left", "arrow-down-left"),
+    ("arrow-down-right", "arrow-down-right"),
+    ("arrow-left", "arrow-left"),
+    ("arrow-left-circle", "arrow-left-circle"),
+    ("arrow-right", "arrow-right"),
+    ("arrow-right-circle", "arrow-right-circle"),
+    ("arrow-up", "arrow-up"),
+    ("arrow-up-circle", "arrow-up-circle"),
+    ("arrow-up-left", "arrow-up-left"),
+    ("arrow-up-right", "arrow-up-right"),
+    ("at-sign", "at-sign"),
+    ("award", "award"),
+    ("bar-chart", "bar-chart"),
+    ("bar-chart-2", "bar-chart-2"),
+    ("battery", "battery"),
+    ("battery-charging", "battery-charging"),
+    ("bell", "bell"),
+    ("bell-off", "bell-off"),
+    ("bluetooth", "bluetooth"),
+    ("bold", "bold"),
+    ("book", "book"),
+    ("book-open", "book-open"),
+    ("bookmark", "bookmark"),
+    ("box", "box"),
+    ("briefcase", "briefcase"),
+    ("calendar", "calendar"),
+    ("camera", "camera"),
+    ("camera-off", "camera-off"),
+    ("cast", "cast"),
+    ("check", "check"),
+    ("check-circle", "check-circle"),
+    ("check-square", "check-square"),
+    ("chevron-down", "chevron-down"),
+    ("chevron-left", "chevron-left"),
+    ("chevron-right", "chevron-right"),
+    ("chevron-up", "chevron-up"),
+    ("chevrons-down", "chevrons-down"),
+    ("chevrons-left", "chevrons-left"),
+    ("chevrons-right", "chevrons-right"),
+    ("chevrons-up", "chevrons-up"),
+    ("chrome", "chrome"),
+    ("circle", "circle"),
+    ("clipboard", "clipboard"),
+    ("clock", "clock"),
+    ("cloud", "cloud"),
+    ("cloud-drizzle", "cloud-drizzle"),
+    ("cloud-lightning", "cloud-lightning"),
+    ("cloud-off", "cloud-off"),
+    ("cloud-rain", "cloud-rain"),
+    ("cloud-snow", "cloud-snow"),
+    ("code", "code"),
+    ("codepen", "codepen"),
+    ("codesandbox", "codesandbox"),
+    ("coffee", "coffee"),
+    ("columns", "columns"),
+    ("command", "command"),
+    ("compass", "compass"),
+    ("copy", "copy"),
+    ("corner-down-left", "corner-down-left"),
+    ("corner-down-right", "corner-down-right"),
+    ("corner-left-down", "corner-left-down"),
+    ("corner-left-up", "corner-left-up"),
+    ("corner-right-down", "corner-right-down"),
+    ("corner-right-up", "corner-right-up"),
+    ("corner-up-left", "corner-up-left"),
+    ("corner-up-right", "corner-up-right"),
+    ("cpu", "cpu"),
+    ("credit-card", "credit-card"),
+    ("crop", "crop"),
+    ("crosshair", "crosshair"),
+    ("database", "database"),
+    ("delete", "delete"),
+    ("disc", "disc"),
+    ("divide", "divide"),
+    ("divide-circle", "divide-circle"),
+    ("divide-square", "divide-square"),
+    ("dollar-sign", "dollar-sign"),
+    ("download", "download"),
+    ("download-cloud", "download-cloud"),
+    ("dribbble", "dribbble"),
+    ("droplet", "droplet"),
+    ("edit", "edit"),
+    ("edit-2", "edit-2"),
+    ("edit-3", "edit-3"),
+    ("external-link", "external-link"),
+    ("eye", "eye"),
+    ("eye-off", "eye-off"),
+    ("facebook", "facebook"),
+    ("fast-forward", "fast-forward"),
+    ("feather", "feather"),
+    ("figma", "figma"),
+    ("file", "file"),
+    ("file-minus", "file-minus"),
+    ("file-plus", "file-plus"),
+    ("file-text", "file-text"),
+    ("film", "film"),
+    ("filter", "filter"),
+    ("flag", "flag"),
+    ("folder", "folder"),
+    ("folder-minus", "folder-minus"),
+    ("folder-plus", "folder-plus"),
+    ("framer", "framer"),
+    ("frown", "frown"),
+    ("gift", "gift"),
+    ("git-branch", "git-branch"),
+    ("git-commit", "git-commit"),
+    ("git-merge", "git-merge"),
+    ("git-pull-request", "git-pull-request"),
+    ("github", "github"),
+    ("gitlab", "gitlab"),
+    ("globe", "globe"),
+    ("grid", "grid"),
+    ("hard-drive", "hard-drive"),
+    ("hash", "hash"),
+    ("headphones", "headphones"),
+    ("heart", "heart"),
+    ("help-circle", "help-circle"),
+    ("hexagon", "hexagon"),
+    ("home", "home"),
+    ("image", "image"),
+    ("inbox", "inbox"),
+    ("info", "info"),
+    ("instagram", "instagram"),
+    ("italic", "italic"),
+    ("key", "key"),
+    ("layers", "layers"),
+    ("layout", "layout"),
+    ("life-buoy", "life-buoy"),
+    ("link", "link"),
+    ("link-2", "link-2"),
+    ("linkedin", "linkedin"),
+    ("list", "list"),
+    ("loader", "loader"),
+    ("lock", "lock"),
+    ("log-in", "log-in"),
+    ("log-out", "log-out"),
+    ("mail", "mail"),
+    ("map", "map"),
+    ("map-pin", "map-pin"),
+    ("maximize", "maximize"),
+    ("maximize-2", "maximize-2"),
+    ("meh", "meh"),
+    ("menu", "menu"),
+    ("message-circle", "message-circle"),
+    ("message-square", "message-square"),
+    ("mic", "mic"),
+    ("mic-off", "mic-off"),
+    ("minimize", "minimize"),
+    ("minimize-2", "minimize-2"),
+    ("minus", "minus"),
+    ("minus-circle", "minus-circle"),
+    ("minus-square", "minus-square"),
+    ("monitor", "monitor"),
+    ("moon", "moon"),
+    ("more-horizontal", "more-horizontal"),
+    ("more-vertical", "more-vertical"),
+    ("mouse-pointer", "mouse-pointer"),
+    ("move", "move"),
+    ("music", "music"),
+    ("navigation", "navigation"),
+    ("navigation-2", "navigation-2"),
+    ("octagon", "octagon"),
+    ("package", "package"),
+    ("paperclip", "paperclip"),
+    ("pause", "pause"),
+    ("pause-circle", "pause-circle"),
+    ("pen-tool", "pen-tool"),
+    ("percent", "percent"),
+    ("phone", "phone"),
+    ("phone-call", "phone-call"),
+    ("phone-forwarded", "phone-forwarded"),
+    ("phone-incoming", "phone-incoming"),
+    ("phone-missed", "phone-missed"),
+    ("phone-off", "phone-off"),
+    ("phone-outgoing", "phone-outgoing"),
+    ("pie-chart", "pie-chart"),
+    ("play", "play"),
+    ("play-circle", "play-circle"),
+    ("plus", "plus"),
+    ("plus-circle", "plus-circle"),
+    ("plus-square", "plus-square"),
+    ("pocket", "pocket"),
+    ("power", "power"),
+    ("printer", "printer"),
+    ("radio", "radio"),
+    ("refresh-ccw", "refresh-ccw"),
+    ("refresh-cw", "refresh-cw"),
+    ("repeat", "repeat"),
+    ("rewind", "rewind"),
+    ("rotate-ccw", "rotate-ccw"),
+    ("rotate-cw", "rotate-cw"),
+    ("rss", "rss"),
+    ("save", "save"),
+    ("scissors", "scissors"),
+    ("search", "search"),
+    ("send", "send"),
+    ("server", "server"),
+    ("settings", "settings"),
+    ("share", "share"),
+    ("share-2", "share-2"),
+    ("shield", "shield"),
+    ("shield-off", "shield-off"),
+    ("shopping-bag", "shopping-bag"),
+    ("shopping-cart", "shopping-cart"),
+    ("shuffle", "shuffle"),
+    ("sidebar", "sidebar"),
+    ("skip-back", "skip-back"),
+    ("skip-forward", "skip-forward"),
+    ("slack", "slack"),
+    ("slash", "slash"),
+    ("sliders", "sliders"),
+    ("smartphone", "smartphone"),
+    ("smile", "smile"),
+    ("speaker", "speaker"),
+    ("square", "square"),
+    ("star", "star"),
+    ("stop-circle", "stop-circle"),
+    ("sun", "sun"),
+    ("sunrise", "sunrise"),
+    ("sunset", "sunset"),
+    ("table", "table"),
+    ("tablet", "tablet"),
+    ("tag", "tag"),
+    ("target", "target"),
+    ("terminal", "terminal"),
+    ("thermometer", "thermometer"),
+    ("thumbs-down", "thumbs-down"),
+    ("thumbs-up", "thumbs-up"),
+    ("toggle-left", "toggle-left"),
+    ("toggle-right", "toggle-right"),
+    ("tool", "tool"),
+    ("trash", "trash"),
+    ("trash-2", "trash-2"),
+    ("trello", "trello"),
+    ("trending-down", "trending-down"),
+    ("trending-up", "trending-up"),
+    ("triangle", "triangle"),
+    ("truck", "truck"),
+    ("tv", "tv"),
+    ("twitch", "twitch"),
+    ("twitter", "twitter"),
+    ("type", "type"),
+    ("umbrella", "umbrella"),
+    ("underline", "underline"),
+    ("unlock", "unlock"),
+    ("upload", "upload"),
+    ("upload-cloud", "upload-cloud"),
+    ("user", "user"),
+    ("user-check", "user-check"),
+    ("user-minus", "user-minus"),
+    ("user-plus", "user-plus"),
+    ("user-x", "user-x"),
+    ("users", "users"),
+    ("video", "video"),
+    ("video-off", "video-off"),
+    ("voicemail", "voicemail"),
+    ("volume", "volume"),
+    ("volume-1", "volume-1"),
+    ("volume-2", "volume-2"),
+    ("volume-x", "volume-x"),
+    ("watch", "watch"),
+    ("wifi", "wifi"),
+    ("wifi-off", "wifi-off"),
+    ("wind", "wind"),
+    ("x", "x"),
+    ("x-circle", "x-circle"),
+    ("x-octagon", "x-octagon"),
+    ("x-square", "x-square"),
+    ("youtube", "youtube"),
+    ("zap", "zap"),
+    ("zap-off", "zap-off"),
+    ("zoom-in", "zoom-in"),
+    ("zoom-out", "zoom-out"),
+];
+
+/// `(variant, codepoint, name)` for every mapped glyph, sorted for `binary_search`.
+pub const ICON_BY_CODEPOINT: &[(VariantKey, u32, &str)] = &[
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE000,
+        "activity",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE001,
+        "airplay",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE002,
+        "alert-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE003,
+        "alert-octagon",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE004,
+        "alert-triangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE005,
+        "align-center",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE006,
+        "align-justify",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE007,
+        "align-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE008,
+        "align-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE009,
+        "anchor",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "aperture",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "archive",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "arrow-down-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "arrow-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00E,
+        "arrow-down-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00F,
+        "arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE010,
+        "arrow-left-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE011,
+        "arrow-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE012,
+        "arrow-right-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE013,
+        "arrow-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE014,
+        "arrow-up-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE015,
+        "arrow-up-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE016,
+        "arrow-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE017,
+        "arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE018,
+        "at-sign",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE019,
+        "award",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01A,
+        "bar-chart-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01B,
+        "bar-chart",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01C,
+        "battery-charging",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01D,
+        "battery",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01E,
+        "bell-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01F,
+        "bell",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE020,
+        "bluetooth",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE021,
+        "bold",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE022,
+        "book-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE023,
+        "book",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE024,
+        "bookmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE025,
+        "box",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE026,
+        "briefcase",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE027,
+        "calendar",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE028,
+        "camera-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE029,
+        "camera",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02A,
+        "cast",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02B,
+        "check-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02C,
+        "check-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02D,
+        "check",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02E,
+        "chevron-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02F,
+        "chevron-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE030,
+        "chevron-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE031,
+        "chevron-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE032,
+        "chevrons-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE033,
+        "chevrons-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE034,
+        "chevrons-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE035,
+        "chevrons-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE036,
+        "chrome",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE037,
+        "circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE038,
+        "clipboard",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE039,
+        "clock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03A,
+        "cloud-drizzle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03B,
+        "cloud-lightning",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03C,
+        "cloud-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03D,
+        "cloud-rain",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03E,
+        "cloud-snow",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03F,
+        "cloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE040,
+        "code",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE041,
+        "codepen",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE042,
+        "codesandbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE043,
+        "coffee",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE044,
+        "columns",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE045,
+        "command",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE046,
+        "compass",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE047,
+        "copy",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE048,
+        "corner-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE049,
+        "corner-down-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04A,
+        "corner-left-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04B,
+        "corner-left-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04C,
+        "corner-right-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04D,
+        "corner-right-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04E,
+        "corner-up-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04F,
+        "corner-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE050,
+        "cpu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE051,
+        "credit-card",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE052,
+        "crop",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE053,
+        "crosshair",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE054,
+        "database",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE055,
+        "delete",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE056,
+        "disc",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE057,
+        "divide-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE058,
+        "divide-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE059,
+        "divide",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05A,
+        "dollar-sign",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05B,
+        "download-cloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05C,
+        "download",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05D,
+        "dribbble",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05E,
+        "droplet",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05F,
+        "edit-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE060,
+        "edit-3",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE061,
+        "edit",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE062,
+        "external-link",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE063,
+        "eye-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE064,
+        "eye",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE065,
+        "facebook",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE066,
+        "fast-forward",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE067,
+        "feather",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE068,
+        "figma",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE069,
+        "file-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06A,
+        "file-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06B,
+        "file-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06C,
+        "file",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06D,
+        "film",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06E,
+        "filter",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06F,
+        "flag",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE070,
+        "folder-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE071,
+        "folder-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE072,
+        "folder",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE073,
+        "framer",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE074,
+        "frown",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE075,
+        "gift",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE076,
+        "git-branch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE077,
+        "git-commit",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE078,
+        "git-merge",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE079,
+        "git-pull-request",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07A,
+        "github",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07B,
+        "gitlab",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07C,
+        "globe",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07D,
+        "grid",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07E,
+        "hard-drive",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07F,
+        "hash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE080,
+        "headphones",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE081,
+        "heart",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE082,
+        "help-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE083,
+        "hexagon",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE084,
+        "home",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE085,
+        "image",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE086,
+        "inbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE087,
+        "info",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE088,
+        "instagram",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE089,
+        "italic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08A,
+        "key",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08B,
+        "layers",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08C,
+        "layout",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08D,
+        "life-buoy",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08E,
+        "link-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08F,
+        "link",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE090,
+        "linkedin",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE091,
+        "list",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE092,
+        "loader",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE093,
+        "lock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE094,
+        "log-in",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE095,
+        "log-out",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE096,
+        "mail",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE097,
+        "map-pin",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE098,
+        "map",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE099,
+        "maximize-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09A,
+        "maximize",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09B,
+        "meh",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09C,
+        "menu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09D,
+        "message-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09E,
+        "message-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09F,
+        "mic-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A0,
+        "mic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A1,
+        "minimize-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A2,
+        "minimize",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A3,
+        "minus-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A4,
+        "minus-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A5,
+        "minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A6,
+        "monitor",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A7,
+        "moon",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A8,
+        "more-horizontal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A9,
+        "more-vertical",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AA,
+        "mouse-pointer",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AB,
+        "move",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AC,
+        "music",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AD,
+        "navigation-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AE,
+        "navigation",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AF,
+        "octagon",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B0,
+        "package",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B1,
+        "paperclip",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B2,
+        "pause-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B3,
+        "pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B4,
+        "pen-tool",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B5,
+        "percent",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B6,
+        "phone-call",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B7,
+        "phone-forwarded",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B8,
+        "phone-incoming",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B9,
+        "phone-missed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BA,
+        "phone-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BB,
+        "phone-outgoing",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BC,
+        "phone",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BD,
+        "pie-chart",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BE,
+        "play-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BF,
+        "play",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C0,
+        "plus-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C1,
+        "plus-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C2,
+        "plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C3,
+        "pocket",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C4,
+        "power",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C5,
+        "printer",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C6,
+        "radio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C7,
+        "refresh-ccw",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C8,
+        "refresh-cw",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C9,
+        "repeat",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CA,
+        "rewind",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CB,
+        "rotate-ccw",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CC,
+        "rotate-cw",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CD,
+        "rss",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CE,
+        "save",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CF,
+        "scissors",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D0,
+        "search",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D1,
+        "send",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D2,
+        "server",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D3,
+        "settings",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D4,
+        "share-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D5,
+        "share",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D6,
+        "shield-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D7,
+        "shield",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D8,
+        "shopping-bag",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D9,
+        "shopping-cart",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DA,
+        "shuffle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DB,
+        "sidebar",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DC,
+        "skip-back",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DD,
+        "skip-forward",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DE,
+        "slack",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DF,
+        "slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E0,
+        "sliders",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E1,
+        "smartphone",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E2,
+        "smile",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E3,
+        "speaker",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E4,
+        "square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E5,
+        "star",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E6,
+        "stop-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E7,
+        "sun",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E8,
+        "sunrise",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E9,
+        "sunset",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EA,
+        "table",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EB,
+        "tablet",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EC,
+        "tag",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0ED,
+        "target",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EE,
+        "terminal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EF,
+        "thermometer",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F0,
+        "thumbs-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F1,
+        "thumbs-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F2,
+        "toggle-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F3,
+        "toggle-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F4,
+        "tool",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F5,
+        "trash-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F6,
+        "trash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F7,
+        "trello",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F8,
+        "trending-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F9,
+        "trending-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FA,
+        "triangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FB,
+        "truck",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FC,
+        "tv",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FD,
+        "twitch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FE,
+        "twitter",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FF,
+        "type",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE100,
+        "umbrella",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE101,
+        "underline",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE102,
+        "unlock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE103,
+        "upload-cloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE104,
+        "upload",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE105,
+        "user-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE106,
+        "user-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE107,
+        "user-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE108,
+        "user-x",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE109,
+        "user",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10A,
+        "users",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10B,
+        "video-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10C,
+        "video",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10D,
+        "voicemail",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10E,
+        "volume-1",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10F,
+        "volume-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE110,
+        "volume-x",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE111,
+        "volume",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE112,
+        "watch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE113,
+        "wifi-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE114,
+        "wifi",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE115,
+        "wind",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE116,
+        "x-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE117,
+        "x-octagon",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE118,
+        "x-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE119,
+        "x",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11A,
+        "youtube",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11B,
+        "zap-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11C,
+        "zap",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11D,
+        "zoom-in",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11E,
+        "zoom-out",
+    ),
+];
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct VariantInfo {
     pub key: VariantKey,
@@ -934,6 +4850,7 @@ pub(crate) const VARIANTS: &[VariantInfo] = &[VariantInfo {
     family: "Feather Regular",
 }];
 
+/// `activity` — U+E000 (available in Regular)
 const ICON_ACTIVITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -942,6 +4859,7 @@ const ICON_ACTIVITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57344,
 )];
 
+/// `airplay` — U+E001 (available in Regular)
 const ICON_AIRPLAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -950,6 +4868,7 @@ const ICON_AIRPLAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57345,
 )];
 
+/// `alert-circle` — U+E002 (available in Regular)
 const ICON_ALERT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -958,6 +4877,7 @@ const ICON_ALERT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57346,
 )];
 
+/// `alert-octagon` — U+E003 (available in Regular)
 const ICON_ALERT_OCTAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -966,6 +4886,7 @@ const ICON_ALERT_OCTAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57347,
 )];
 
+/// `alert-triangle` — U+E004 (available in Regular)
 const ICON_ALERT_TRIANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -974,6 +4895,7 @@ const ICON_ALERT_TRIANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57348,
 )];
 
+/// `align-center` — U+E005 (available in Regular)
 const ICON_ALIGN_CENTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -982,6 +4904,7 @@ const ICON_ALIGN_CENTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57349,
 )];
 
+/// `align-justify` — U+E006 (available in Regular)
 const ICON_ALIGN_JUSTIFY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -990,6 +4913,7 @@ const ICON_ALIGN_JUSTIFY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57350,
 )];
 
+/// `align-left` — U+E007 (available in Regular)
 const ICON_ALIGN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -998,6 +4922,7 @@ const ICON_ALIGN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57351,
 )];
 
+/// `align-right` — U+E008 (available in Regular)
 const ICON_ALIGN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1006,6 +4931,7 @@ const ICON_ALIGN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57352,
 )];
 
+/// `anchor` — U+E009 (available in Regular)
 const ICON_ANCHOR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1014,6 +4940,7 @@ const ICON_ANCHOR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57353,
 )];
 
+/// `aperture` — U+E00A (available in Regular)
 const ICON_APERTURE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1022,6 +4949,7 @@ const ICON_APERTURE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57354,
 )];
 
+/// `archive` — U+E00B (available in Regular)
 const ICON_ARCHIVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1030,6 +4958,7 @@ const ICON_ARCHIVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57355,
 )];
 
+/// `arrow-down` — U+E00F (available in Regular)
 const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1038,6 +4967,7 @@ const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57359,
 )];
 
+/// `arrow-down-circle` — U+E00C (available in Regular)
 const ICON_ARROW_DOWN_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1046,6 +4976,7 @@ const ICON_ARROW_DOWN_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57356,
 )];
 
+/// `arrow-down-left` — U+E00D (available in Regular)
 const ICON_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1054,6 +4985,7 @@ const ICON_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57357,
 )];
 
+/// `arrow-down-right` — U+E00E (available in Regular)
 const ICON_ARROW_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1062,6 +4994,7 @@ const ICON_ARROW_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57358,
 )];
 
+/// `arrow-left` — U+E011 (available in Regular)
 const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1070,6 +5003,7 @@ const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57361,
 )];
 
+/// `arrow-left-circle` — U+E010 (available in Regular)
 const ICON_ARROW_LEFT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1078,6 +5012,7 @@ const ICON_ARROW_LEFT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57360,
 )];
 
+/// `arrow-right` — U+E013 (available in Regular)
 const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1086,6 +5021,7 @@ const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57363,
 )];
 
+/// `arrow-right-circle` — U+E012 (available in Regular)
 const ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1094,6 +5030,7 @@ const ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57362,
 )];
 
+/// `arrow-up` — U+E017 (available in Regular)
 const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1102,6 +5039,7 @@ const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57367,
 )];
 
+/// `arrow-up-circle` — U+E014 (available in Regular)
 const ICON_ARROW_UP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1110,6 +5048,7 @@ const ICON_ARROW_UP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57364,
 )];
 
+/// `arrow-up-left` — U+E015 (available in Regular)
 const ICON_ARROW_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1118,6 +5057,7 @@ const ICON_ARROW_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57365,
 )];
 
+/// `arrow-up-right` — U+E016 (available in Regular)
 const ICON_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1126,6 +5066,7 @@ const ICON_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57366,
 )];
 
+/// `at-sign` — U+E018 (available in Regular)
 const ICON_AT_SIGN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1134,6 +5075,7 @@ const ICON_AT_SIGN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57368,
 )];
 
+/// `award` — U+E019 (available in Regular)
 const ICON_AWARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1142,6 +5084,7 @@ const ICON_AWARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57369,
 )];
 
+/// `bar-chart` — U+E01B (available in Regular)
 const ICON_BAR_CHART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1150,6 +5093,7 @@ const ICON_BAR_CHART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57371,
 )];
 
+/// `bar-chart-2` — U+E01A (available in Regular)
 const ICON_BAR_CHART_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1158,6 +5102,7 @@ const ICON_BAR_CHART_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57370,
 )];
 
+/// `battery` — U+E01D (available in Regular)
 const ICON_BATTERY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1166,6 +5111,7 @@ const ICON_BATTERY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57373,
 )];
 
+/// `battery-charging` — U+E01C (available in Regular)
 const ICON_BATTERY_CHARGING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1174,6 +5120,7 @@ const ICON_BATTERY_CHARGING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57372,
 )];
 
+/// `bell` — U+E01F (available in Regular)
 const ICON_BELL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1182,6 +5129,7 @@ const ICON_BELL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57375,
 )];
 
+/// `bell-off` — U+E01E (available in Regular)
 const ICON_BELL_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1190,6 +5138,7 @@ const ICON_BELL_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57374,
 )];
 
+/// `bluetooth` — U+E020 (available in Regular)
 const ICON_BLUETOOTH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1198,6 +5147,7 @@ const ICON_BLUETOOTH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57376,
 )];
 
+/// `bold` — U+E021 (available in Regular)
 const ICON_BOLD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1206,6 +5156,7 @@ const ICON_BOLD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57377,
 )];
 
+/// `book` — U+E023 (available in Regular)
 const ICON_BOOK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1214,6 +5165,7 @@ const ICON_BOOK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57379,
 )];
 
+/// `book-open` — U+E022 (available in Regular)
 const ICON_BOOK_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1222,6 +5174,7 @@ const ICON_BOOK_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57378,
 )];
 
+/// `bookmark` — U+E024 (available in Regular)
 const ICON_BOOKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1230,6 +5183,7 @@ const ICON_BOOKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57380,
 )];
 
+/// `box` — U+E025 (available in Regular)
 const ICON_BOX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1238,6 +5192,7 @@ const ICON_BOX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57381,
 )];
 
+/// `briefcase` — U+E026 (available in Regular)
 const ICON_BRIEFCASE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1246,6 +5201,7 @@ const ICON_BRIEFCASE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57382,
 )];
 
+/// `calendar` — U+E027 (available in Regular)
 const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1254,6 +5210,7 @@ const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57383,
 )];
 
+/// `camera` — U+E029 (available in Regular)
 const ICON_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1262,6 +5219,7 @@ const ICON_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57385,
 )];
 
+/// `camera-off` — U+E028 (available in Regular)
 const ICON_CAMERA_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1270,6 +5228,7 @@ const ICON_CAMERA_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57384,
 )];
 
+/// `cast` — U+E02A (available in Regular)
 const ICON_CAST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1278,6 +5237,7 @@ const ICON_CAST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57386,
 )];
 
+/// `check` — U+E02D (available in Regular)
 const ICON_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1286,6 +5246,7 @@ const ICON_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57389,
 )];
 
+/// `check-circle` — U+E02B (available in Regular)
 const ICON_CHECK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1294,6 +5255,7 @@ const ICON_CHECK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57387,
 )];
 
+/// `check-square` — U+E02C (available in Regular)
 const ICON_CHECK_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1302,6 +5264,7 @@ const ICON_CHECK_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57388,
 )];
 
+/// `chevron-down` — U+E02E (available in Regular)
 const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1310,6 +5273,7 @@ const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57390,
 )];
 
+/// `chevron-left` — U+E02F (available in Regular)
 const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1318,6 +5282,7 @@ const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57391,
 )];
 
+/// `chevron-right` — U+E030 (available in Regular)
 const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1326,6 +5291,7 @@ const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57392,
 )];
 
+/// `chevron-up` — U+E031 (available in Regular)
 const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1334,6 +5300,7 @@ const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57393,
 )];
 
+/// `chevrons-down` — U+E032 (available in Regular)
 const ICON_CHEVRONS_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1342,6 +5309,7 @@ const ICON_CHEVRONS_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57394,
 )];
 
+/// `chevrons-left` — U+E033 (available in Regular)
 const ICON_CHEVRONS_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1350,6 +5318,7 @@ const ICON_CHEVRONS_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57395,
 )];
 
+/// `chevrons-right` — U+E034 (available in Regular)
 const ICON_CHEVRONS_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1358,6 +5327,7 @@ const ICON_CHEVRONS_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57396,
 )];
 
+/// `chevrons-up` — U+E035 (available in Regular)
 const ICON_CHEVRONS_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1366,6 +5336,7 @@ const ICON_CHEVRONS_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57397,
 )];
 
+/// `chrome` — U+E036 (available in Regular)
 const ICON_CHROME_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1374,6 +5345,7 @@ const ICON_CHROME_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57398,
 )];
 
+/// `circle` — U+E037 (available in Regular)
 const ICON_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1382,6 +5354,7 @@ const ICON_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57399,
 )];
 
+/// `clipboard` — U+E038 (available in Regular)
 const ICON_CLIPBOARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1390,6 +5363,7 @@ const ICON_CLIPBOARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57400,
 )];
 
+/// `clock` — U+E039 (available in Regular)
 const ICON_CLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1398,6 +5372,7 @@ const ICON_CLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57401,
 )];
 
+/// `cloud` — U+E03F (available in Regular)
 const ICON_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1406,6 +5381,7 @@ const ICON_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57407,
 )];
 
+/// `cloud-drizzle` — U+E03A (available in Regular)
 const ICON_CLOUD_DRIZZLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1414,6 +5390,7 @@ const ICON_CLOUD_DRIZZLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57402,
 )];
 
+/// `cloud-lightning` — U+E03B (available in Regular)
 const ICON_CLOUD_LIGHTNING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1422,6 +5399,7 @@ const ICON_CLOUD_LIGHTNING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57403,
 )];
 
+/// `cloud-off` — U+E03C (available in Regular)
 const ICON_CLOUD_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1430,6 +5408,7 @@ const ICON_CLOUD_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57404,
 )];
 
+/// `cloud-rain` — U+E03D (available in Regular)
 const ICON_CLOUD_RAIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1438,6 +5417,7 @@ const ICON_CLOUD_RAIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57405,
 )];
 
+/// `cloud-snow` — U+E03E (available in Regular)
 const ICON_CLOUD_SNOW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1446,6 +5426,7 @@ const ICON_CLOUD_SNOW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57406,
 )];
 
+/// `code` — U+E040 (available in Regular)
 const ICON_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1454,6 +5435,7 @@ const ICON_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57408,
 )];
 
+/// `codepen` — U+E041 (available in Regular)
 const ICON_CODEPEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1462,6 +5444,7 @@ const ICON_CODEPEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57409,
 )];
 
+/// `codesandbox` — U+E042 (available in Regular)
 const ICON_CODESANDBOX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1470,6 +5453,7 @@ const ICON_CODESANDBOX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57410,
 )];
 
+/// `coffee` — U+E043 (available in Regular)
 const ICON_COFFEE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1478,6 +5462,7 @@ const ICON_COFFEE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57411,
 )];
 
+/// `columns` — U+E044 (available in Regular)
 const ICON_COLUMNS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1486,6 +5471,7 @@ const ICON_COLUMNS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57412,
 )];
 
+/// `command` — U+E045 (available in Regular)
 const ICON_COMMAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1494,6 +5480,7 @@ const ICON_COMMAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57413,
 )];
 
+/// `compass` — U+E046 (available in Regular)
 const ICON_COMPASS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1502,6 +5489,7 @@ const ICON_COMPASS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57414,
 )];
 
+/// `copy` — U+E047 (available in Regular)
 const ICON_COPY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1510,6 +5498,7 @@ const ICON_COPY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57415,
 )];
 
+/// `corner-down-left` — U+E048 (available in Regular)
 const ICON_CORNER_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1518,6 +5507,7 @@ const ICON_CORNER_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57416,
 )];
 
+/// `corner-down-right` — U+E049 (available in Regular)
 const ICON_CORNER_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1526,6 +5516,7 @@ const ICON_CORNER_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57417,
 )];
 
+/// `corner-left-down` — U+E04A (available in Regular)
 const ICON_CORNER_LEFT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1534,6 +5525,7 @@ const ICON_CORNER_LEFT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57418,
 )];
 
+/// `corner-left-up` — U+E04B (available in Regular)
 const ICON_CORNER_LEFT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1542,6 +5534,7 @@ const ICON_CORNER_LEFT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57419,
 )];
 
+/// `corner-right-down` — U+E04C (available in Regular)
 const ICON_CORNER_RIGHT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1550,6 +5543,7 @@ const ICON_CORNER_RIGHT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57420,
 )];
 
+/// `corner-right-up` — U+E04D (available in Regular)
 const ICON_CORNER_RIGHT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1558,6 +5552,7 @@ const ICON_CORNER_RIGHT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57421,
 )];
 
+/// `corner-up-left` — U+E04E (available in Regular)
 const ICON_CORNER_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1566,6 +5561,7 @@ const ICON_CORNER_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57422,
 )];
 
+/// `corner-up-right` — U+E04F (available in Regular)
 const ICON_CORNER_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1574,6 +5570,7 @@ const ICON_CORNER_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57423,
 )];
 
+/// `cpu` — U+E050 (available in Regular)
 const ICON_CPU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1582,6 +5579,7 @@ const ICON_CPU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57424,
 )];
 
+/// `credit-card` — U+E051 (available in Regular)
 const ICON_CREDIT_CARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1590,6 +5588,7 @@ const ICON_CREDIT_CARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57425,
 )];
 
+/// `crop` — U+E052 (available in Regular)
 const ICON_CROP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1598,6 +5597,7 @@ const ICON_CROP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57426,
 )];
 
+/// `crosshair` — U+E053 (available in Regular)
 const ICON_CROSSHAIR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1606,6 +5606,7 @@ const ICON_CROSSHAIR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57427,
 )];
 
+/// `database` — U+E054 (available in Regular)
 const ICON_DATABASE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1614,6 +5615,7 @@ const ICON_DATABASE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57428,
 )];
 
+/// `delete` — U+E055 (available in Regular)
 const ICON_DELETE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1622,6 +5624,7 @@ const ICON_DELETE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57429,
 )];
 
+/// `disc` — U+E056 (available in Regular)
 const ICON_DISC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1630,6 +5633,7 @@ const ICON_DISC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57430,
 )];
 
+/// `divide` — U+E059 (available in Regular)
 const ICON_DIVIDE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1638,6 +5642,7 @@ const ICON_DIVIDE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57433,
 )];
 
+/// `divide-circle` — U+E057 (available in Regular)
 const ICON_DIVIDE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1646,6 +5651,7 @@ const ICON_DIVIDE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57431,
 )];
 
+/// `divide-square` — U+E058 (available in Regular)
 const ICON_DIVIDE_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1654,6 +5660,7 @@ const ICON_DIVIDE_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57432,
 )];
 
+/// `dollar-sign` — U+E05A (available in Regular)
 const ICON_DOLLAR_SIGN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1662,6 +5669,7 @@ const ICON_DOLLAR_SIGN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57434,
 )];
 
+/// `download` — U+E05C (available in Regular)
 const ICON_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1670,6 +5678,7 @@ const ICON_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57436,
 )];
 
+/// `download-cloud` — U+E05B (available in Regular)
 const ICON_DOWNLOAD_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1678,6 +5687,7 @@ const ICON_DOWNLOAD_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57435,
 )];
 
+/// `dribbble` — U+E05D (available in Regular)
 const ICON_DRIBBBLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1686,6 +5696,7 @@ const ICON_DRIBBBLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57437,
 )];
 
+/// `droplet` — U+E05E (available in Regular)
 const ICON_DROPLET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1694,6 +5705,7 @@ const ICON_DROPLET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57438,
 )];
 
+/// `edit` — U+E061 (available in Regular)
 const ICON_EDIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1702,6 +5714,7 @@ const ICON_EDIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57441,
 )];
 
+/// `edit-2` — U+E05F (available in Regular)
 const ICON_EDIT_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1710,6 +5723,7 @@ const ICON_EDIT_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57439,
 )];
 
+/// `edit-3` — U+E060 (available in Regular)
 const ICON_EDIT_3_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1718,6 +5732,7 @@ const ICON_EDIT_3_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57440,
 )];
 
+/// `external-link` — U+E062 (available in Regular)
 const ICON_EXTERNAL_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1726,6 +5741,7 @@ const ICON_EXTERNAL_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57442,
 )];
 
+/// `eye` — U+E064 (available in Regular)
 const ICON_EYE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1734,6 +5750,7 @@ const ICON_EYE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57444,
 )];
 
+/// `eye-off` — U+E063 (available in Regular)
 const ICON_EYE_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1742,6 +5759,7 @@ const ICON_EYE_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57443,
 )];
 
+/// `facebook` — U+E065 (available in Regular)
 const ICON_FACEBOOK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1750,6 +5768,7 @@ const ICON_FACEBOOK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57445,
 )];
 
+/// `fast-forward` — U+E066 (available in Regular)
 const ICON_FAST_FORWARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1758,6 +5777,7 @@ const ICON_FAST_FORWARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57446,
 )];
 
+/// `feather` — U+E067 (available in Regular)
 const ICON_FEATHER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1766,6 +5786,7 @@ const ICON_FEATHER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57447,
 )];
 
+/// `figma` — U+E068 (available in Regular)
 const ICON_FIGMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1774,6 +5795,7 @@ const ICON_FIGMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57448,
 )];
 
+/// `file` — U+E06C (available in Regular)
 const ICON_FILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1782,6 +5804,7 @@ const ICON_FILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57452,
 )];
 
+/// `file-minus` — U+E069 (available in Regular)
 const ICON_FILE_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1790,6 +5813,7 @@ const ICON_FILE_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57449,
 )];
 
+/// `file-plus` — U+E06A (available in Regular)
 const ICON_FILE_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1798,6 +5822,7 @@ const ICON_FILE_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57450,
 )];
 
+/// `file-text` — U+E06B (available in Regular)
 const ICON_FILE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1806,6 +5831,7 @@ const ICON_FILE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57451,
 )];
 
+/// `film` — U+E06D (available in Regular)
 const ICON_FILM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1814,6 +5840,7 @@ const ICON_FILM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57453,
 )];
 
+/// `filter` — U+E06E (available in Regular)
 const ICON_FILTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1822,6 +5849,7 @@ const ICON_FILTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57454,
 )];
 
+/// `flag` — U+E06F (available in Regular)
 const ICON_FLAG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1830,6 +5858,7 @@ const ICON_FLAG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57455,
 )];
 
+/// `folder` — U+E072 (available in Regular)
 const ICON_FOLDER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1838,6 +5867,7 @@ const ICON_FOLDER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57458,
 )];
 
+/// `folder-minus` — U+E070 (available in Regular)
 const ICON_FOLDER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1846,6 +5876,7 @@ const ICON_FOLDER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57456,
 )];
 
+/// `folder-plus` — U+E071 (available in Regular)
 const ICON_FOLDER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1854,6 +5885,7 @@ const ICON_FOLDER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57457,
 )];
 
+/// `framer` — U+E073 (available in Regular)
 const ICON_FRAMER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1862,6 +5894,7 @@ const ICON_FRAMER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57459,
 )];
 
+/// `frown` — U+E074 (available in Regular)
 const ICON_FROWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1870,6 +5903,7 @@ const ICON_FROWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57460,
 )];
 
+/// `gift` — U+E075 (available in Regular)
 const ICON_GIFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1878,6 +5912,7 @@ const ICON_GIFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57461,
 )];
 
+/// `git-branch` — U+E076 (available in Regular)
 const ICON_GIT_BRANCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1886,6 +5921,7 @@ const ICON_GIT_BRANCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57462,
 )];
 
+/// `git-commit` — U+E077 (available in Regular)
 const ICON_GIT_COMMIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1894,6 +5930,7 @@ const ICON_GIT_COMMIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57463,
 )];
 
+/// `git-merge` — U+E078 (available in Regular)
 const ICON_GIT_MERGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1902,6 +5939,7 @@ const ICON_GIT_MERGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57464,
 )];
 
+/// `git-pull-request` — U+E079 (available in Regular)
 const ICON_GIT_PULL_REQUEST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1910,6 +5948,7 @@ const ICON_GIT_PULL_REQUEST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57465,
 )];
 
+/// `github` — U+E07A (available in Regular)
 const ICON_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1918,6 +5957,7 @@ const ICON_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57466,
 )];
 
+/// `gitlab` — U+E07B (available in Regular)
 const ICON_GITLAB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1926,6 +5966,7 @@ const ICON_GITLAB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57467,
 )];
 
+/// `globe` — U+E07C (available in Regular)
 const ICON_GLOBE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1934,6 +5975,7 @@ const ICON_GLOBE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57468,
 )];
 
+/// `grid` — U+E07D (available in Regular)
 const ICON_GRID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1942,6 +5984,7 @@ const ICON_GRID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57469,
 )];
 
+/// `hard-drive` — U+E07E (available in Regular)
 const ICON_HARD_DRIVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1950,6 +5993,7 @@ const ICON_HARD_DRIVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57470,
 )];
 
+/// `hash` — U+E07F (available in Regular)
 const ICON_HASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1958,6 +6002,7 @@ const ICON_HASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57471,
 )];
 
+/// `headphones` — U+E080 (available in Regular)
 const ICON_HEADPHONES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1966,6 +6011,7 @@ const ICON_HEADPHONES_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57472,
 )];
 
+/// `heart` — U+E081 (available in Regular)
 const ICON_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1974,6 +6020,7 @@ const ICON_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57473,
 )];
 
+/// `help-circle` — U+E082 (available in Regular)
 const ICON_HELP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1982,6 +6029,7 @@ const ICON_HELP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57474,
 )];
 
+/// `hexagon` — U+E083 (available in Regular)
 const ICON_HEXAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1990,6 +6038,7 @@ const ICON_HEXAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57475,
 )];
 
+/// `home` — U+E084 (available in Regular)
 const ICON_HOME_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1998,6 +6047,7 @@ const ICON_HOME_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57476,
 )];
 
+/// `image` — U+E085 (available in Regular)
 const ICON_IMAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2006,6 +6056,7 @@ const ICON_IMAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57477,
 )];
 
+/// `inbox` — U+E086 (available in Regular)
 const ICON_INBOX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2014,6 +6065,7 @@ const ICON_INBOX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57478,
 )];
 
+/// `info` — U+E087 (available in Regular)
 const ICON_INFO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2022,6 +6074,7 @@ const ICON_INFO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57479,
 )];
 
+/// `instagram` — U+E088 (available in Regular)
 const ICON_INSTAGRAM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2030,6 +6083,7 @@ const ICON_INSTAGRAM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57480,
 )];
 
+/// `italic` — U+E089 (available in Regular)
 const ICON_ITALIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2038,6 +6092,7 @@ const ICON_ITALIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57481,
 )];
 
+/// `key` — U+E08A (available in Regular)
 const ICON_KEY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2046,6 +6101,7 @@ const ICON_KEY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57482,
 )];
 
+/// `layers` — U+E08B (available in Regular)
 const ICON_LAYERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2054,6 +6110,7 @@ const ICON_LAYERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57483,
 )];
 
+/// `layout` — U+E08C (available in Regular)
 const ICON_LAYOUT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2062,6 +6119,7 @@ const ICON_LAYOUT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57484,
 )];
 
+/// `life-buoy` — U+E08D (available in Regular)
 const ICON_LIFE_BUOY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2070,6 +6128,7 @@ const ICON_LIFE_BUOY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57485,
 )];
 
+/// `link` — U+E08F (available in Regular)
 const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2078,6 +6137,7 @@ const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57487,
 )];
 
+/// `link-2` — U+E08E (available in Regular)
 const ICON_LINK_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2086,6 +6146,7 @@ const ICON_LINK_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57486,
 )];
 
+/// `linkedin` — U+E090 (available in Regular)
 const ICON_LINKEDIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2094,6 +6155,7 @@ const ICON_LINKEDIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57488,
 )];
 
+/// `list` — U+E091 (available in Regular)
 const ICON_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2102,6 +6164,7 @@ const ICON_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57489,
 )];
 
+/// `loader` — U+E092 (available in Regular)
 const ICON_LOADER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2110,6 +6173,7 @@ const ICON_LOADER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57490,
 )];
 
+/// `lock` — U+E093 (available in Regular)
 const ICON_LOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2118,6 +6182,7 @@ const ICON_LOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57491,
 )];
 
+/// `log-in` — U+E094 (available in Regular)
 const ICON_LOG_IN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2126,6 +6191,7 @@ const ICON_LOG_IN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57492,
 )];
 
+/// `log-out` — U+E095 (available in Regular)
 const ICON_LOG_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2134,6 +6200,7 @@ const ICON_LOG_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57493,
 )];
 
+/// `mail` — U+E096 (available in Regular)
 const ICON_MAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2142,6 +6209,7 @@ const ICON_MAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57494,
 )];
 
+/// `map` — U+E098 (available in Regular)
 const ICON_MAP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2150,6 +6218,7 @@ const ICON_MAP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57496,
 )];
 
+/// `map-pin` — U+E097 (available in Regular)
 const ICON_MAP_PIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2158,6 +6227,7 @@ const ICON_MAP_PIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57495,
 )];
 
+/// `maximize` — U+E09A (available in Regular)
 const ICON_MAXIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2166,6 +6236,7 @@ const ICON_MAXIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57498,
 )];
 
+/// `maximize-2` — U+E099 (available in Regular)
 const ICON_MAXIMIZE_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2174,6 +6245,7 @@ const ICON_MAXIMIZE_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57497,
 )];
 
+/// `meh` — U+E09B (available in Regular)
 const ICON_MEH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2182,6 +6254,7 @@ const ICON_MEH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57499,
 )];
 
+/// `menu` — U+E09C (available in Regular)
 const ICON_MENU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2190,6 +6263,7 @@ const ICON_MENU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57500,
 )];
 
+/// `message-circle` — U+E09D (available in Regular)
 const ICON_MESSAGE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2198,6 +6272,7 @@ const ICON_MESSAGE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57501,
 )];
 
+/// `message-square` — U+E09E (available in Regular)
 const ICON_MESSAGE_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2206,6 +6281,7 @@ const ICON_MESSAGE_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57502,
 )];
 
+/// `mic` — U+E0A0 (available in Regular)
 const ICON_MIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2214,6 +6290,7 @@ const ICON_MIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57504,
 )];
 
+/// `mic-off` — U+E09F (available in Regular)
 const ICON_MIC_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2222,6 +6299,7 @@ const ICON_MIC_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57503,
 )];
 
+/// `minimize` — U+E0A2 (available in Regular)
 const ICON_MINIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2230,6 +6308,7 @@ const ICON_MINIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57506,
 )];
 
+/// `minimize-2` — U+E0A1 (available in Regular)
 const ICON_MINIMIZE_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2238,6 +6317,7 @@ const ICON_MINIMIZE_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57505,
 )];
 
+/// `minus` — U+E0A5 (available in Regular)
 const ICON_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2246,6 +6326,7 @@ const ICON_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57509,
 )];
 
+/// `minus-circle` — U+E0A3 (available in Regular)
 const ICON_MINUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2254,6 +6335,7 @@ const ICON_MINUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57507,
 )];
 
+/// `minus-square` — U+E0A4 (available in Regular)
 const ICON_MINUS_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2262,6 +6344,7 @@ const ICON_MINUS_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57508,
 )];
 
+/// `monitor` — U+E0A6 (available in Regular)
 const ICON_MONITOR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2270,6 +6353,7 @@ const ICON_MONITOR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57510,
 )];
 
+/// `moon` — U+E0A7 (available in Regular)
 const ICON_MOON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2278,6 +6362,7 @@ const ICON_MOON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57511,
 )];
 
+/// `more-horizontal` — U+E0A8 (available in Regular)
 const ICON_MORE_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2286,6 +6371,7 @@ const ICON_MORE_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57512,
 )];
 
+/// `more-vertical` — U+E0A9 (available in Regular)
 const ICON_MORE_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2294,6 +6380,7 @@ const ICON_MORE_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57513,
 )];
 
+/// `mouse-pointer` — U+E0AA (available in Regular)
 const ICON_MOUSE_POINTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2302,6 +6389,7 @@ const ICON_MOUSE_POINTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57514,
 )];
 
+/// `move` — U+E0AB (available in Regular)
 const ICON_MOVE__CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2310,6 +6398,7 @@ const ICON_MOVE__CODEPOINTS: &[(VariantKey, u32)] = &[(
     57515,
 )];
 
+/// `music` — U+E0AC (available in Regular)
 const ICON_MUSIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2318,6 +6407,7 @@ const ICON_MUSIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57516,
 )];
 
+/// `navigation` — U+E0AE (available in Regular)
 const ICON_NAVIGATION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2326,6 +6416,7 @@ const ICON_NAVIGATION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57518,
 )];
 
+/// `navigation-2` — U+E0AD (available in Regular)
 const ICON_NAVIGATION_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2334,6 +6425,7 @@ const ICON_NAVIGATION_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57517,
 )];
 
+/// `octagon` — U+E0AF (available in Regular)
 const ICON_OCTAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2342,6 +6434,7 @@ const ICON_OCTAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57519,
 )];
 
+/// `package` — U+E0B0 (available in Regular)
 const ICON_PACKAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2350,6 +6443,7 @@ const ICON_PACKAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57520,
 )];
 
+/// `paperclip` — U+E0B1 (available in Regular)
 const ICON_PAPERCLIP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2358,6 +6452,7 @@ const ICON_PAPERCLIP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57521,
 )];
 
+/// `pause` — U+E0B3 (available in Regular)
 const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2366,6 +6461,7 @@ const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57523,
 )];
 
+/// `pause-circle` — U+E0B2 (available in Regular)
 const ICON_PAUSE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2374,6 +6470,7 @@ const ICON_PAUSE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57522,
 )];
 
+/// `pen-tool` — U+E0B4 (available in Regular)
 const ICON_PEN_TOOL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2382,6 +6479,7 @@ const ICON_PEN_TOOL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57524,
 )];
 
+/// `percent` — U+E0B5 (available in Regular)
 const ICON_PERCENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2390,6 +6488,7 @@ const ICON_PERCENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57525,
 )];
 
+/// `phone` — U+E0BC (available in Regular)
 const ICON_PHONE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2398,6 +6497,7 @@ const ICON_PHONE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57532,
 )];
 
+/// `phone-call` — U+E0B6 (available in Regular)
 const ICON_PHONE_CALL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2406,6 +6506,7 @@ const ICON_PHONE_CALL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57526,
 )];
 
+/// `phone-forwarded` — U+E0B7 (available in Regular)
 const ICON_PHONE_FORWARDED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2414,6 +6515,7 @@ const ICON_PHONE_FORWARDED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57527,
 )];
 
+/// `phone-incoming` — U+E0B8 (available in Regular)
 const ICON_PHONE_INCOMING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2422,6 +6524,7 @@ const ICON_PHONE_INCOMING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57528,
 )];
 
+/// `phone-missed` — U+E0B9 (available in Regular)
 const ICON_PHONE_MISSED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2430,6 +6533,7 @@ const ICON_PHONE_MISSED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57529,
 )];
 
+/// `phone-off` — U+E0BA (available in Regular)
 const ICON_PHONE_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2438,6 +6542,7 @@ const ICON_PHONE_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57530,
 )];
 
+/// `phone-outgoing` — U+E0BB (available in Regular)
 const ICON_PHONE_OUTGOING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2446,6 +6551,7 @@ const ICON_PHONE_OUTGOING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57531,
 )];
 
+/// `pie-chart` — U+E0BD (available in Regular)
 const ICON_PIE_CHART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2454,6 +6560,7 @@ const ICON_PIE_CHART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57533,
 )];
 
+/// `play` — U+E0BF (available in Regular)
 const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2462,6 +6569,7 @@ const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57535,
 )];
 
+/// `play-circle` — U+E0BE (available in Regular)
 const ICON_PLAY_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2470,6 +6578,7 @@ const ICON_PLAY_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57534,
 )];
 
+/// `plus` — U+E0C2 (available in Regular)
 const ICON_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2478,6 +6587,7 @@ const ICON_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57538,
 )];
 
+/// `plus-circle` — U+E0C0 (available in Regular)
 const ICON_PLUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2486,6 +6596,7 @@ const ICON_PLUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57536,
 )];
 
+/// `plus-square` — U+E0C1 (available in Regular)
 const ICON_PLUS_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2494,6 +6605,7 @@ const ICON_PLUS_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57537,
 )];
 
+/// `pocket` — U+E0C3 (available in Regular)
 const ICON_POCKET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2502,6 +6614,7 @@ const ICON_POCKET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57539,
 )];
 
+/// `power` — U+E0C4 (available in Regular)
 const ICON_POWER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2510,6 +6623,7 @@ const ICON_POWER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57540,
 )];
 
+/// `printer` — U+E0C5 (available in Regular)
 const ICON_PRINTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2518,6 +6632,7 @@ const ICON_PRINTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57541,
 )];
 
+/// `radio` — U+E0C6 (available in Regular)
 const ICON_RADIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2526,6 +6641,7 @@ const ICON_RADIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57542,
 )];
 
+/// `refresh-ccw` — U+E0C7 (available in Regular)
 const ICON_REFRESH_CCW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2534,6 +6650,7 @@ const ICON_REFRESH_CCW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57543,
 )];
 
+/// `refresh-cw` — U+E0C8 (available in Regular)
 const ICON_REFRESH_CW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2542,6 +6659,7 @@ const ICON_REFRESH_CW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57544,
 )];
 
+/// `repeat` — U+E0C9 (available in Regular)
 const ICON_REPEAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2550,6 +6668,7 @@ const ICON_REPEAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57545,
 )];
 
+/// `rewind` — U+E0CA (available in Regular)
 const ICON_REWIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2558,6 +6677,7 @@ const ICON_REWIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57546,
 )];
 
+/// `rotate-ccw` — U+E0CB (available in Regular)
 const ICON_ROTATE_CCW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2566,6 +6686,7 @@ const ICON_ROTATE_CCW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57547,
 )];
 
+/// `rotate-cw` — U+E0CC (available in Regular)
 const ICON_ROTATE_CW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2574,6 +6695,7 @@ const ICON_ROTATE_CW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57548,
 )];
 
+/// `rss` — U+E0CD (available in Regular)
 const ICON_RSS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2582,6 +6704,7 @@ const ICON_RSS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57549,
 )];
 
+/// `save` — U+E0CE (available in Regular)
 const ICON_SAVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2590,6 +6713,7 @@ const ICON_SAVE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57550,
 )];
 
+/// `scissors` — U+E0CF (available in Regular)
 const ICON_SCISSORS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2598,6 +6722,7 @@ const ICON_SCISSORS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57551,
 )];
 
+/// `search` — U+E0D0 (available in Regular)
 const ICON_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2606,6 +6731,7 @@ const ICON_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57552,
 )];
 
+/// `send` — U+E0D1 (available in Regular)
 const ICON_SEND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2614,6 +6740,7 @@ const ICON_SEND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57553,
 )];
 
+/// `server` — U+E0D2 (available in Regular)
 const ICON_SERVER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2622,6 +6749,7 @@ const ICON_SERVER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57554,
 )];
 
+/// `settings` — U+E0D3 (available in Regular)
 const ICON_SETTINGS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2630,6 +6758,7 @@ const ICON_SETTINGS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57555,
 )];
 
+/// `share` — U+E0D5 (available in Regular)
 const ICON_SHARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2638,6 +6767,7 @@ const ICON_SHARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57557,
 )];
 
+/// `share-2` — U+E0D4 (available in Regular)
 const ICON_SHARE_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2646,6 +6776,7 @@ const ICON_SHARE_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57556,
 )];
 
+/// `shield` — U+E0D7 (available in Regular)
 const ICON_SHIELD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2654,6 +6785,7 @@ const ICON_SHIELD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57559,
 )];
 
+/// `shield-off` — U+E0D6 (available in Regular)
 const ICON_SHIELD_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2662,6 +6794,7 @@ const ICON_SHIELD_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57558,
 )];
 
+/// `shopping-bag` — U+E0D8 (available in Regular)
 const ICON_SHOPPING_BAG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2670,6 +6803,7 @@ const ICON_SHOPPING_BAG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57560,
 )];
 
+/// `shopping-cart` — U+E0D9 (available in Regular)
 const ICON_SHOPPING_CART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2678,6 +6812,7 @@ const ICON_SHOPPING_CART_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57561,
 )];
 
+/// `shuffle` — U+E0DA (available in Regular)
 const ICON_SHUFFLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2686,6 +6821,7 @@ const ICON_SHUFFLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57562,
 )];
 
+/// `sidebar` — U+E0DB (available in Regular)
 const ICON_SIDEBAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2694,6 +6830,7 @@ const ICON_SIDEBAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57563,
 )];
 
+/// `skip-back` — U+E0DC (available in Regular)
 const ICON_SKIP_BACK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2702,6 +6839,7 @@ const ICON_SKIP_BACK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57564,
 )];
 
+/// `skip-forward` — U+E0DD (available in Regular)
 const ICON_SKIP_FORWARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2710,6 +6848,7 @@ const ICON_SKIP_FORWARD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57565,
 )];
 
+/// `slack` — U+E0DE (available in Regular)
 const ICON_SLACK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2718,6 +6857,7 @@ const ICON_SLACK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57566,
 )];
 
+/// `slash` — U+E0DF (available in Regular)
 const ICON_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2726,6 +6866,7 @@ const ICON_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57567,
 )];
 
+/// `sliders` — U+E0E0 (available in Regular)
 const ICON_SLIDERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2734,6 +6875,7 @@ const ICON_SLIDERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57568,
 )];
 
+/// `smartphone` — U+E0E1 (available in Regular)
 const ICON_SMARTPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2742,6 +6884,7 @@ const ICON_SMARTPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57569,
 )];
 
+/// `smile` — U+E0E2 (available in Regular)
 const ICON_SMILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2750,6 +6893,7 @@ const ICON_SMILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57570,
 )];
 
+/// `speaker` — U+E0E3 (available in Regular)
 const ICON_SPEAKER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2758,6 +6902,7 @@ const ICON_SPEAKER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57571,
 )];
 
+/// `square` — U+E0E4 (available in Regular)
 const ICON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2766,6 +6911,7 @@ const ICON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57572,
 )];
 
+/// `star` — U+E0E5 (available in Regular)
 const ICON_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2774,6 +6920,7 @@ const ICON_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57573,
 )];
 
+/// `stop-circle` — U+E0E6 (available in Regular)
 const ICON_STOP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2782,6 +6929,7 @@ const ICON_STOP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57574,
 )];
 
+/// `sun` — U+E0E7 (available in Regular)
 const ICON_SUN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2790,6 +6938,7 @@ const ICON_SUN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57575,
 )];
 
+/// `sunrise` — U+E0E8 (available in Regular)
 const ICON_SUNRISE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2798,6 +6947,7 @@ const ICON_SUNRISE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57576,
 )];
 
+/// `sunset` — U+E0E9 (available in Regular)
 const ICON_SUNSET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2806,6 +6956,7 @@ const ICON_SUNSET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57577,
 )];
 
+/// `table` — U+E0EA (available in Regular)
 const ICON_TABLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2814,6 +6965,7 @@ const ICON_TABLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57578,
 )];
 
+/// `tablet` — U+E0EB (available in Regular)
 const ICON_TABLET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2822,6 +6974,7 @@ const ICON_TABLET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57579,
 )];
 
+/// `tag` — U+E0EC (available in Regular)
 const ICON_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2830,6 +6983,7 @@ const ICON_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57580,
 )];
 
+/// `target` — U+E0ED (available in Regular)
 const ICON_TARGET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2838,6 +6992,7 @@ const ICON_TARGET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57581,
 )];
 
+/// `terminal` — U+E0EE (available in Regular)
 const ICON_TERMINAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2846,6 +7001,7 @@ const ICON_TERMINAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57582,
 )];
 
+/// `thermometer` — U+E0EF (available in Regular)
 const ICON_THERMOMETER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2854,6 +7010,7 @@ const ICON_THERMOMETER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57583,
 )];
 
+/// `thumbs-down` — U+E0F0 (available in Regular)
 const ICON_THUMBS_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2862,6 +7019,7 @@ const ICON_THUMBS_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57584,
 )];
 
+/// `thumbs-up` — U+E0F1 (available in Regular)
 const ICON_THUMBS_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2870,6 +7028,7 @@ const ICON_THUMBS_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57585,
 )];
 
+/// `toggle-left` — U+E0F2 (available in Regular)
 const ICON_TOGGLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2878,6 +7037,7 @@ const ICON_TOGGLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57586,
 )];
 
+/// `toggle-right` — U+E0F3 (available in Regular)
 const ICON_TOGGLE_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2886,6 +7046,7 @@ const ICON_TOGGLE_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57587,
 )];
 
+/// `tool` — U+E0F4 (available in Regular)
 const ICON_TOOL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2894,6 +7055,7 @@ const ICON_TOOL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57588,
 )];
 
+/// `trash` — U+E0F6 (available in Regular)
 const ICON_TRASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2902,6 +7064,7 @@ const ICON_TRASH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57590,
 )];
 
+/// `trash-2` — U+E0F5 (available in Regular)
 const ICON_TRASH_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2910,6 +7073,7 @@ const ICON_TRASH_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57589,
 )];
 
+/// `trello` — U+E0F7 (available in Regular)
 const ICON_TRELLO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2918,6 +7082,7 @@ const ICON_TRELLO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57591,
 )];
 
+/// `trending-down` — U+E0F8 (available in Regular)
 const ICON_TRENDING_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2926,6 +7091,7 @@ const ICON_TRENDING_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57592,
 )];
 
+/// `trending-up` — U+E0F9 (available in Regular)
 const ICON_TRENDING_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2934,6 +7100,7 @@ const ICON_TRENDING_UP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57593,
 )];
 
+/// `triangle` — U+E0FA (available in Regular)
 const ICON_TRIANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2942,6 +7109,7 @@ const ICON_TRIANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57594,
 )];
 
+/// `truck` — U+E0FB (available in Regular)
 const ICON_TRUCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2950,6 +7118,7 @@ const ICON_TRUCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57595,
 )];
 
+/// `tv` — U+E0FC (available in Regular)
 const ICON_TV_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2958,6 +7127,7 @@ const ICON_TV_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57596,
 )];
 
+/// `twitch` — U+E0FD (available in Regular)
 const ICON_TWITCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2966,6 +7136,7 @@ const ICON_TWITCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57597,
 )];
 
+/// `twitter` — U+E0FE (available in Regular)
 const ICON_TWITTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2974,6 +7145,7 @@ const ICON_TWITTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57598,
 )];
 
+/// `type` — U+E0FF (available in Regular)
 const ICON_TYPE__CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2982,6 +7154,7 @@ const ICON_TYPE__CODEPOINTS: &[(VariantKey, u32)] = &[(
     57599,
 )];
 
+/// `umbrella` — U+E100 (available in Regular)
 const ICON_UMBRELLA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2990,6 +7163,7 @@ const ICON_UMBRELLA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57600,
 )];
 
+/// `underline` — U+E101 (available in Regular)
 const ICON_UNDERLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2998,6 +7172,7 @@ const ICON_UNDERLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57601,
 )];
 
+/// `unlock` — U+E102 (available in Regular)
 const ICON_UNLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3006,6 +7181,7 @@ const ICON_UNLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57602,
 )];
 
+/// `upload` — U+E104 (available in Regular)
 const ICON_UPLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3014,6 +7190,7 @@ const ICON_UPLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57604,
 )];
 
+/// `upload-cloud` — U+E103 (available in Regular)
 const ICON_UPLOAD_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3022,6 +7199,7 @@ const ICON_UPLOAD_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57603,
 )];
 
+/// `user` — U+E109 (available in Regular)
 const ICON_USER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3030,6 +7208,7 @@ const ICON_USER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57609,
 )];
 
+/// `user-check` — U+E105 (available in Regular)
 const ICON_USER_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3038,6 +7217,7 @@ const ICON_USER_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57605,
 )];
 
+/// `user-minus` — U+E106 (available in Regular)
 const ICON_USER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3046,6 +7226,7 @@ const ICON_USER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57606,
 )];
 
+/// `user-plus` — U+E107 (available in Regular)
 const ICON_USER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3054,6 +7235,7 @@ const ICON_USER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57607,
 )];
 
+/// `user-x` — U+E108 (available in Regular)
 const ICON_USER_X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3062,6 +7244,7 @@ const ICON_USER_X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57608,
 )];
 
+/// `users` — U+E10A (available in Regular)
 const ICON_USERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3070,6 +7253,7 @@ const ICON_USERS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57610,
 )];
 
+/// `video` — U+E10C (available in Regular)
 const ICON_VIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3078,6 +7262,7 @@ const ICON_VIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57612,
 )];
 
+/// `video-off` — U+E10B (available in Regular)
 const ICON_VIDEO_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3086,6 +7271,7 @@ const ICON_VIDEO_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57611,
 )];
 
+/// `voicemail` — U+E10D (available in Regular)
 const ICON_VOICEMAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3094,6 +7280,7 @@ const ICON_VOICEMAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57613,
 )];
 
+/// `volume` — U+E111 (available in Regular)
 const ICON_VOLUME_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3102,6 +7289,7 @@ const ICON_VOLUME_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57617,
 )];
 
+/// `volume-1` — U+E10E (available in Regular)
 const ICON_VOLUME_1_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3110,6 +7298,7 @@ const ICON_VOLUME_1_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57614,
 )];
 
+/// `volume-2` — U+E10F (available in Regular)
 const ICON_VOLUME_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3118,6 +7307,7 @@ const ICON_VOLUME_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57615,
 )];
 
+/// `volume-x` — U+E110 (available in Regular)
 const ICON_VOLUME_X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3126,6 +7316,7 @@ const ICON_VOLUME_X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57616,
 )];
 
+/// `watch` — U+E112 (available in Regular)
 const ICON_WATCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3134,6 +7325,7 @@ const ICON_WATCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57618,
 )];
 
+/// `wifi` — U+E114 (available in Regular)
 const ICON_WIFI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3142,6 +7334,7 @@ const ICON_WIFI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57620,
 )];
 
+/// `wifi-off` — U+E113 (available in Regular)
 const ICON_WIFI_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3150,6 +7343,7 @@ const ICON_WIFI_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57619,
 )];
 
+/// `wind` — U+E115 (available in Regular)
 const ICON_WIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3158,6 +7352,7 @@ const ICON_WIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57621,
 )];
 
+/// `x` — U+E119 (available in Regular)
 const ICON_X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3166,6 +7361,7 @@ const ICON_X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57625,
 )];
 
+/// `x-circle` — U+E116 (available in Regular)
 const ICON_X_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3174,6 +7370,7 @@ const ICON_X_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57622,
 )];
 
+/// `x-octagon` — U+E117 (available in Regular)
 const ICON_X_OCTAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3182,6 +7379,7 @@ const ICON_X_OCTAGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57623,
 )];
 
+/// `x-square` — U+E118 (available in Regular)
 const ICON_X_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3190,6 +7388,7 @@ const ICON_X_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57624,
 )];
 
+/// `youtube` — U+E11A (available in Regular)
 const ICON_YOUTUBE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3198,6 +7397,7 @@ const ICON_YOUTUBE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57626,
 )];
 
+/// `zap` — U+E11C (available in Regular)
 const ICON_ZAP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3206,6 +7406,7 @@ const ICON_ZAP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57628,
 )];
 
+/// `zap-off` — U+E11B (available in Regular)
 const ICON_ZAP_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3214,6 +7415,7 @@ const ICON_ZAP_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57627,
 )];
 
+/// `zoom-in` — U+E11D (available in Regular)
 const ICON_ZOOM_IN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3222,6 +7424,7 @@ const ICON_ZOOM_IN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57629,
 )];
 
+/// `zoom-out` — U+E11E (available in Regular)
 const ICON_ZOOM_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3804,12 +8007,14 @@ const ICON_ZOOM_IN_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regula
 
 const ICON_ZOOM_OUT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconCodepoints {
     pub name: &'static str,
     pub codepoints: &'static [(VariantKey, u32)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     IconCodepoints {
         name: "activity",
@@ -4961,12 +9166,14 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     },
 ];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconAvailability {
     pub name: &'static str,
     pub available: &'static [(Style, Size)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     IconAvailability {
         name: "activity",
@@ -6118,6 +10325,605 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     },
 ];
 
+pub(crate) const ICON_COLORS: &[(&str, [u8; 4])] = &[];
+
+/// Accessible labels, index-aligned with `ICON_NAMES`.
+pub(crate) const ICON_LABELS: &[&str] = &[
+    "Activity",
+    "Airplay",
+    "Alert Circle",
+    "Alert Octagon",
+    "Alert Triangle",
+    "Align Center",
+    "Align Justify",
+    "Align Left",
+    "Align Right",
+    "Anchor",
+    "Aperture",
+    "Archive",
+    "Arrow Down",
+    "Arrow Down Circle",
+    "Arrow Down Left",
+    "Arrow Down Right",
+    "Arrow Left",
+    "Arrow Left Circle",
+    "Arrow Right",
+    "Arrow Right Circle",
+    "Arrow Up",
+    "Arrow Up Circle",
+    "Arrow Up Left",
+    "Arrow Up Right",
+    "At Sign",
+    "Award",
+    "Bar Chart",
+    "Bar Chart 2",
+    "Battery",
+    "Battery Charging",
+    "Bell",
+    "Bell Off",
+    "Bluetooth",
+    "Bold",
+    "Book",
+    "Book Open",
+    "Bookmark",
+    "Box",
+    "Briefcase",
+    "Calendar",
+    "Camera",
+    "Camera Off",
+    "Cast",
+    "Check",
+    "Check Circle",
+    "Check Square",
+    "Chevron Down",
+    "Chevron Left",
+    "Chevron Right",
+    "Chevron Up",
+    "Chevrons Down",
+    "Chevrons Left",
+    "Chevrons Right",
+    "Chevrons Up",
+    "Chrome",
+    "Circle",
+    "Clipboard",
+    "Clock",
+    "Cloud",
+    "Cloud Drizzle",
+    "Cloud Lightning",
+    "Cloud Off",
+    "Cloud Rain",
+    "Cloud Snow",
+    "Code",
+    "Codepen",
+    "Codesandbox",
+    "Coffee",
+    "Columns",
+    "Command",
+    "Compass",
+    "Copy",
+    "Corner Down Left",
+    "Corner Down Right",
+    "Corner Left Down",
+    "Corner Left Up",
+    "Corner Right Down",
+    "Corner Right Up",
+    "Corner Up Left",
+    "Corner Up Right",
+    "Cpu",
+    "Credit Card",
+    "Crop",
+    "Crosshair",
+    "Database",
+    "Delete",
+    "Disc",
+    "Divide",
+    "Divide Circle",
+    "Divide Square",
+    "Dollar Sign",
+    "Download",
+    "Download Cloud",
+    "Dribbble",
+    "Droplet",
+    "Edit",
+    "Edit 2",
+    "Edit 3",
+    "External Link",
+    "Eye",
+    "Eye Off",
+    "Facebook",
+    "Fast Forward",
+    "Feather",
+    "Figma",
+    "File",
+    "File Minus",
+    "File Plus",
+    "File Text",
+    "Film",
+    "Filter",
+    "Flag",
+    "Folder",
+    "Folder Minus",
+    "Folder Plus",
+    "Framer",
+    "Frown",
+    "Gift",
+    "Git Branch",
+    "Git Commit",
+    "Git Merge",
+    "Git Pull Request",
+    "Github",
+    "Gitlab",
+    "Globe",
+    "Grid",
+    "Hard Drive",
+    "Hash",
+    "Headphones",
+    "Heart",
+    "Help Circle",
+    "Hexagon",
+    "Home",
+    "Image",
+    "Inbox",
+    "Info",
+    "Instagram",
+    "Italic",
+    "Key",
+    "Layers",
+    "Layout",
+    "Life Buoy",
+    "Link",
+    "Link 2",
+    "Linkedin",
+    "List",
+    "Loader",
+    "Lock",
+    "Log In",
+    "Log Out",
+    "Mail",
+    "Map",
+    "Map Pin",
+    "Maximize",
+    "Maximize 2",
+    "Meh",
+    "Menu",
+    "Message Circle",
+    "Message Square",
+    "Mic",
+    "Mic Off",
+    "Minimize",
+    "Minimize 2",
+    "Minus",
+    "Minus Circle",
+    "Minus Square",
+    "Monitor",
+    "Moon",
+    "More Horizontal",
+    "More Vertical",
+    "Mouse Pointer",
+    "Move",
+    "Music",
+    "Navigation",
+    "Navigation 2",
+    "Octagon",
+    "Package",
+    "Paperclip",
+    "Pause",
+    "Pause Circle",
+    "Pen Tool",
+    "Percent",
+    "Phone",
+    "Phone Call",
+    "Phone Forwarded",
+    "Phone Incoming",
+    "Phone Missed",
+    "Phone Off",
+    "Phone Outgoing",
+    "Pie Chart",
+    "Play",
+    "Play Circle",
+    "Plus",
+    "Plus Circle",
+    "Plus Square",
+    "Pocket",
+    "Power",
+    "Printer",
+    "Radio",
+    "Refresh Ccw",
+    "Refresh Cw",
+    "Repeat",
+    "Rewind",
+    "Rotate Ccw",
+    "Rotate Cw",
+    "Rss",
+    "Save",
+    "Scissors",
+    "Search",
+    "Send",
+    "Server",
+    "Settings",
+    "Share",
+    "Share 2",
+    "Shield",
+    "Shield Off",
+    "Shopping Bag",
+    "Shopping Cart",
+    "Shuffle",
+    "Sidebar",
+    "Skip Back",
+    "Skip Forward",
+    "Slack",
+    "Slash",
+    "Sliders",
+    "Smartphone",
+    "Smile",
+    "Speaker",
+    "Square",
+    "Star",
+    "Stop Circle",
+    "Sun",
+    "Sunrise",
+    "Sunset",
+    "Table",
+    "Tablet",
+    "Tag",
+    "Target",
+    "Terminal",
+    "Thermometer",
+    "Thumbs Down",
+    "Thumbs Up",
+    "Toggle Left",
+    "Toggle Right",
+    "Tool",
+    "Trash",
+    "Trash 2",
+    "Trello",
+    "Trending Down",
+    "Trending Up",
+    "Triangle",
+    "Truck",
+    "Tv",
+    "Twitch",
+    "Twitter",
+    "Type",
+    "Umbrella",
+    "Underline",
+    "Unlock",
+    "Upload",
+    "Upload Cloud",
+    "User",
+    "User Check",
+    "User Minus",
+    "User Plus",
+    "User X",
+    "Users",
+    "Video",
+    "Video Off",
+    "Voicemail",
+    "Volume",
+    "Volume 1",
+    "Volume 2",
+    "Volume X",
+    "Watch",
+    "Wifi",
+    "Wifi Off",
+    "Wind",
+    "X",
+    "X Circle",
+    "X Octagon",
+    "X Square",
+    "Youtube",
+    "Zap",
+    "Zap Off",
+    "Zoom In",
+    "Zoom Out",
+];
+
+/// Stable icon ids, sorted by id.
+pub(crate) const ICON_IDS: &[(u32, &str)] = &[
+    (0x03374982, "globe"),
+    (0x0400a189, "x-octagon"),
+    (0x0427b352, "voicemail"),
+    (0x05025085, "triangle"),
+    (0x0503f878, "upload-cloud"),
+    (0x0582ab34, "arrow-up"),
+    (0x05c2dd77, "phone-missed"),
+    (0x05cf2d67, "pocket"),
+    (0x0642b47e, "arrow-down-right"),
+    (0x0689854c, "plus-circle"),
+    (0x06c7f7a2, "loader"),
+    (0x07286448, "wifi"),
+    (0x076554c2, "corner-right-down"),
+    (0x09746220, "zap-off"),
+    (0x09f0bcf6, "scissors"),
+    (0x0b4675e2, "chevron-up"),
+    (0x0cfb5881, "list"),
+    (0x0d89fdf1, "cloud-drizzle"),
+    (0x0ddb0669, "link"),
+    (0x0eaa25e3, "heart"),
+    (0x0fb40705, "info"),
+    (0x100e0423, "arrow-up-circle"),
+    (0x1018be33, "chrome"),
+    (0x10b1cc23, "map-pin"),
+    (0x111e5f8f, "maximize"),
+    (0x12a395c3, "dribbble"),
+    (0x139850da, "git-branch"),
+    (0x1414f107, "wind"),
+    (0x1551ad92, "refresh-ccw"),
+    (0x15a40f5d, "clock"),
+    (0x17e68617, "trello"),
+    (0x184b0014, "move"),
+    (0x19c83218, "folder-minus"),
+    (0x19df17c3, "phone-off"),
+    (0x19e727d3, "chevron-right"),
+    (0x1aa63a89, "external-link"),
+    (0x1ac6a97e, "cloud"),
+    (0x1c8c8297, "user-minus"),
+    (0x1cdd20c8, "align-center"),
+    (0x1d87ea26, "headphones"),
+    (0x1db36879, "hard-drive"),
+    (0x1e4ff5ab, "figma"),
+    (0x1f890e31, "tablet"),
+    (0x21f04afa, "eye-off"),
+    (0x22dc7932, "github"),
+    (0x24943a3e, "camera-off"),
+    (0x25cad490, "alert-circle"),
+    (0x26d767ff, "printer"),
+    (0x278928c1, "arrow-down"),
+    (0x28217089, "circle"),
+    (0x283babf1, "align-right"),
+    (0x2a24af07, "share-2"),
+    (0x2b2b8d49, "aperture"),
+    (0x2c0a5c17, "paperclip"),
+    (0x2cd52ea3, "navigation"),
+    (0x2e53d32f, "user-x"),
+    (0x2e9ce7ff, "codepen"),
+    (0x2ee0698f, "volume"),
+    (0x2fdc6133, "rss"),
+    (0x3108b3f9, "download"),
+    (0x323fe0f6, "shopping-bag"),
+    (0x32608848, "target"),
+    (0x32c8e2ed, "user-plus"),
+    (0x337519b0, "shield"),
+    (0x34b8665c, "rotate-ccw"),
+    (0x34db4107, "sunset"),
+    (0x35869cf3, "thumbs-down"),
+    (0x363c182a, "toggle-right"),
+    (0x381ba427, "thermometer"),
+    (0x39a6cfdc, "volume-2"),
+    (0x3adb2cc5, "airplay"),
+    (0x3c155fcc, "wifi-off"),
+    (0x3c5c055c, "upload"),
+    (0x3ca6d495, "volume-1"),
+    (0x3d60340c, "disc"),
+    (0x408b7292, "thumbs-up"),
+    (0x40ac3dd2, "server"),
+    (0x41375928, "feather"),
+    (0x42edcab4, "anchor"),
+    (0x4625dd4a, "twitch"),
+    (0x46e656df, "rotate-cw"),
+    (0x4a9c9bdf, "table"),
+    (0x4b45564f, "tv"),
+    (0x4fae4a87, "corner-right-up"),
+    (0x50cc08c3, "briefcase"),
+    (0x5127f14d, "type"),
+    (0x51750ec7, "tool"),
+    (0x51fb8e19, "bookmark"),
+    (0x5325949e, "shopping-cart"),
+    (0x5376e891, "chevrons-up"),
+    (0x54413081, "file-text"),
+    (0x548d9eb4, "shield-off"),
+    (0x5505928f, "smile"),
+    (0x56661f55, "unlock"),
+    (0x5b04cc8f, "divide-circle"),
+    (0x5be7d591, "edit"),
+    (0x5c383268, "corner-up-left"),
+    (0x5c6e7131, "facebook"),
+    (0x5e644e05, "trash"),
+    (0x5e7cc513, "users"),
+    (0x5e999a2b, "log-in"),
+    (0x5fb7b228, "watch"),
+    (0x60785ef2, "user"),
+    (0x60e9fb6d, "italic"),
+    (0x61526270, "divide"),
+    (0x617dc31f, "cloud-rain"),
+    (0x644e121d, "bluetooth"),
+    (0x648c2a98, "arrow-down-circle"),
+    (0x64f33304, "align-left"),
+    (0x665a489a, "link-2"),
+    (0x67c2444a, "delete"),
+    (0x68067b08, "settings"),
+    (0x6815c86c, "key"),
+    (0x6849a642, "speaker"),
+    (0x688023ab, "arrow-left-circle"),
+    (0x69dfb66e, "minus-circle"),
+    (0x6bc8b478, "log-out"),
+    (0x70529dba, "at-sign"),
+    (0x70849133, "book-open"),
+    (0x7084d38d, "pause"),
+    (0x70c111a6, "file-minus"),
+    (0x70c67e32, "box"),
+    (0x70f3d6be, "cloud-snow"),
+    (0x7261c8af, "send"),
+    (0x73bbb767, "plus-square"),
+    (0x75f83840, "pie-chart"),
+    (0x75f9fa5a, "percent"),
+    (0x763e0219, "monitor"),
+    (0x773279b1, "cloud-lightning"),
+    (0x773611af, "phone"),
+    (0x77576608, "alert-triangle"),
+    (0x784cb5dc, "maximize-2"),
+    (0x790c294e, "shuffle"),
+    (0x79ca0e26, "minimize-2"),
+    (0x7a7130bc, "framer"),
+    (0x7a98650a, "phone-forwarded"),
+    (0x7ae5403a, "trending-up"),
+    (0x7afe4abf, "linkedin"),
+    (0x7d362c82, "play-circle"),
+    (0x803328a9, "search"),
+    (0x80d2a573, "align-justify"),
+    (0x826e1638, "bell"),
+    (0x83307891, "pen-tool"),
+    (0x8340e2a3, "chevron-down"),
+    (0x83a7445a, "volume-x"),
+    (0x86f2be04, "pause-circle"),
+    (0x872d24fe, "chevron-left"),
+    (0x89ba8972, "smartphone"),
+    (0x8a7324ef, "gift"),
+    (0x8ab31a3c, "columns"),
+    (0x8ba8ed1a, "eye"),
+    (0x8e2c7aa1, "coffee"),
+    (0x8e9f585d, "chevrons-left"),
+    (0x8fb7915f, "layers"),
+    (0x9067f33c, "corner-left-up"),
+    (0x90d46842, "arrow-up-left"),
+    (0x913b2bfb, "package"),
+    (0x918db010, "folder-plus"),
+    (0x93594ab2, "command"),
+    (0x9363d8ad, "x-square"),
+    (0x9369f9b2, "edit-2"),
+    (0x9469fb45, "edit-3"),
+    (0x95f72993, "tag"),
+    (0x96631017, "crop"),
+    (0x9779ec84, "sunrise"),
+    (0x9833b9b5, "download-cloud"),
+    (0x987ac8a4, "mic-off"),
+    (0x98a6adaf, "arrow-up-right"),
+    (0x98fe03c5, "archive"),
+    (0x99e4dd3a, "menu"),
+    (0x99f40ab1, "terminal"),
+    (0x9abc0ca8, "navigation-2"),
+    (0x9b95e23c, "zap"),
+    (0x9c05c36e, "file-plus"),
+    (0x9d1e8111, "clipboard"),
+    (0x9e095cc7, "zoom-out"),
+    (0x9ebe984e, "camera"),
+    (0x9f57303d, "minimize"),
+    (0x9f9c4fd4, "music"),
+    (0xa165ddb8, "database"),
+    (0xa229d2ec, "activity"),
+    (0xa40c4014, "message-square"),
+    (0xa41b7c2f, "compass"),
+    (0xa4614d20, "phone-outgoing"),
+    (0xa4810d1f, "calendar"),
+    (0xa50d3589, "toggle-left"),
+    (0xa770a6bb, "bar-chart"),
+    (0xa9c9fc38, "share"),
+    (0xaa25504e, "cast"),
+    (0xaaea5743, "file"),
+    (0xabacac38, "octagon"),
+    (0xac9e0b76, "phone-incoming"),
+    (0xad86fe8d, "refresh-cw"),
+    (0xae401eb4, "radio"),
+    (0xaf871a91, "grid"),
+    (0xb09a304a, "skip-back"),
+    (0xb274c7ee, "battery-charging"),
+    (0xb2ea63db, "film"),
+    (0xb35135fa, "image"),
+    (0xb3711c47, "check"),
+    (0xb4b61246, "square"),
+    (0xb716cc98, "chevrons-down"),
+    (0xb7c5ca9e, "youtube"),
+    (0xba0ee5b5, "git-pull-request"),
+    (0xbc80edb7, "alert-octagon"),
+    (0xbc8f30e0, "mic"),
+    (0xbd4f0272, "skip-forward"),
+    (0xbdeaa8d7, "flag"),
+    (0xbedb26ed, "check-square"),
+    (0xbfd6d49a, "git-merge"),
+    (0xc1948a38, "book"),
+    (0xc2cbd863, "play"),
+    (0xc338be44, "twitter"),
+    (0xc3ef2129, "corner-left-down"),
+    (0xc4464cfb, "trending-down"),
+    (0xc4adc675, "plus"),
+    (0xc7e16877, "filter"),
+    (0xc867061d, "sliders"),
+    (0xc87acfc7, "sidebar"),
+    (0xc9b7be3c, "bell-off"),
+    (0xca956993, "message-circle"),
+    (0xcac17cff, "layout"),
+    (0xcb73e8ea, "slash"),
+    (0xccff7e48, "save"),
+    (0xcd07eb37, "corner-down-left"),
+    (0xcec577d1, "hash"),
+    (0xcef90b6c, "video"),
+    (0xcfad63dd, "meh"),
+    (0xd0099f63, "crosshair"),
+    (0xd00d54b3, "codesandbox"),
+    (0xd0e28766, "check-circle"),
+    (0xd13b2f71, "star"),
+    (0xd1b46919, "more-vertical"),
+    (0xd2636659, "help-circle"),
+    (0xd2c8c28e, "home"),
+    (0xd2d55228, "corner-down-right"),
+    (0xd47a4695, "minus-square"),
+    (0xd4e306af, "life-buoy"),
+    (0xd5d89d00, "arrow-right-circle"),
+    (0xd7d848a9, "arrow-right"),
+    (0xd96eb5dd, "folder"),
+    (0xd99ba82a, "repeat"),
+    (0xda112eae, "rewind"),
+    (0xda2182b6, "award"),
+    (0xdbc3b330, "bar-chart-2"),
+    (0xdd9873e6, "zoom-in"),
+    (0xde96f676, "bold"),
+    (0xdfa2efb1, "map"),
+    (0xdffb8150, "divide-square"),
+    (0xe04e3f61, "sun"),
+    (0xe252d53b, "inbox"),
+    (0xe2b2d5fe, "trash-2"),
+    (0xe4210837, "more-horizontal"),
+    (0xe536575c, "arrow-left"),
+    (0xe562ea44, "copy"),
+    (0xe578032a, "phone-call"),
+    (0xe582347f, "underline"),
+    (0xe731117d, "corner-up-right"),
+    (0xe732d40a, "gitlab"),
+    (0xe88db17b, "instagram"),
+    (0xea35704b, "fast-forward"),
+    (0xea454ba3, "dollar-sign"),
+    (0xea94b5db, "hexagon"),
+    (0xea9b2927, "slack"),
+    (0xeb311a26, "x-circle"),
+    (0xec90e52e, "mail"),
+    (0xed68a59b, "frown"),
+    (0xedcd8a90, "video-off"),
+    (0xef0d7842, "lock"),
+    (0xf04b201f, "user-check"),
+    (0xf184ae40, "moon"),
+    (0xf3db9a56, "chevrons-right"),
+    (0xf542bdc8, "truck"),
+    (0xf54f2346, "power"),
+    (0xf590e1e5, "umbrella"),
+    (0xf7505971, "droplet"),
+    (0xf7fa3daf, "minus"),
+    (0xf8dbf57d, "credit-card"),
+    (0xf9316cf4, "code"),
+    (0xf94af989, "cpu"),
+    (0xf96beaa5, "arrow-down-left"),
+    (0xf9b6b78f, "git-commit"),
+    (0xfad41d7c, "stop-circle"),
+    (0xfaf37fb0, "mouse-pointer"),
+    (0xfd0c5087, "x"),
+    (0xfd6a0c8e, "battery"),
+    (0xff976c2e, "cloud-off"),
+];
+
+pub(crate) const ICON_RTL_MIRROR: &[&str] = &[
+    "arrow-down-left",
+    "arrow-down-right",
+    "arrow-left",
+    "arrow-left-circle",
+    "arrow-right",
+    "arrow-right-circle",
+    "arrow-up-left",
+    "arrow-up-right",
+    "chevron-left",
+    "chevron-right",
+    "chevrons-left",
+    "chevrons-right",
+];
+
 pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantInfo> {
     VARIANTS
         .iter()
@@ -6125,21 +10931,643 @@ pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantI
 }
 
 pub(crate) fn icon_codepoint(name: &str, key: VariantKey) -> Option<u32> {
-    ICON_CODEPOINTS
+    let codepoints: &[(VariantKey, u32)] = match name {
+        "activity" => ICON_ACTIVITY_CODEPOINTS,
+        "airplay" => ICON_AIRPLAY_CODEPOINTS,
+        "alert-circle" => ICON_ALERT_CIRCLE_CODEPOINTS,
+        "alert-octagon" => ICON_ALERT_OCTAGON_CODEPOINTS,
+        "alert-triangle" => ICON_ALERT_TRIANGLE_CODEPOINTS,
+        "align-center" => ICON_ALIGN_CENTER_CODEPOINTS,
+        "align-justify" => ICON_ALIGN_JUSTIFY_CODEPOINTS,
+        "align-left" => ICON_ALIGN_LEFT_CODEPOINTS,
+        "align-right" => ICON_ALIGN_RIGHT_CODEPOINTS,
+        "anchor" => ICON_ANCHOR_CODEPOINTS,
+        "aperture" => ICON_APERTURE_CODEPOINTS,
+        "archive" => ICON_ARCHIVE_CODEPOINTS,
+        "arrow-down" => ICON_ARROW_DOWN_CODEPOINTS,
+        "arrow-down-circle" => ICON_ARROW_DOWN_CIRCLE_CODEPOINTS,
+        "arrow-down-left" => ICON_ARROW_DOWN_LEFT_CODEPOINTS,
+        "arrow-down-right" => ICON_ARROW_DOWN_RIGHT_CODEPOINTS,
+        "arrow-left" => ICON_ARROW_LEFT_CODEPOINTS,
+        "arrow-left-circle" => ICON_ARROW_LEFT_CIRCLE_CODEPOINTS,
+        "arrow-right" => ICON_ARROW_RIGHT_CODEPOINTS,
+        "arrow-right-circle" => ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS,
+        "arrow-up" => ICON_ARROW_UP_CODEPOINTS,
+        "arrow-up-circle" => ICON_ARROW_UP_CIRCLE_CODEPOINTS,
+        "arrow-up-left" => ICON_ARROW_UP_LEFT_CODEPOINTS,
+        "arrow-up-right" => ICON_ARROW_UP_RIGHT_CODEPOINTS,
+        "at-sign" => ICON_AT_SIGN_CODEPOINTS,
+        "award" => ICON_AWARD_CODEPOINTS,
+        "bar-chart" => ICON_BAR_CHART_CODEPOINTS,
+        "bar-chart-2" => ICON_BAR_CHART_2_CODEPOINTS,
+        "battery" => ICON_BATTERY_CODEPOINTS,
+        "battery-charging" => ICON_BATTERY_CHARGING_CODEPOINTS,
+        "bell" => ICON_BELL_CODEPOINTS,
+        "bell-off" => ICON_BELL_OFF_CODEPOINTS,
+        "bluetooth" => ICON_BLUETOOTH_CODEPOINTS,
+        "bold" => ICON_BOLD_CODEPOINTS,
+        "book" => ICON_BOOK_CODEPOINTS,
+        "book-open" => ICON_BOOK_OPEN_CODEPOINTS,
+        "bookmark" => ICON_BOOKMARK_CODEPOINTS,
+        "box" => ICON_BOX_CODEPOINTS,
+        "briefcase" => ICON_BRIEFCASE_CODEPOINTS,
+        "calendar" => ICON_CALENDAR_CODEPOINTS,
+        "camera" => ICON_CAMERA_CODEPOINTS,
+        "camera-off" => ICON_CAMERA_OFF_CODEPOINTS,
+        "cast" => ICON_CAST_CODEPOINTS,
+        "check" => ICON_CHECK_CODEPOINTS,
+        "check-circle" => ICON_CHECK_CIRCLE_CODEPOINTS,
+        "check-square" => ICON_CHECK_SQUARE_CODEPOINTS,
+        "chevron-down" => ICON_CHEVRON_DOWN_CODEPOINTS,
+        "chevron-left" => ICON_CHEVRON_LEFT_CODEPOINTS,
+        "chevron-right" => ICON_CHEVRON_RIGHT_CODEPOINTS,
+        "chevron-up" => ICON_CHEVRON_UP_CODEPOINTS,
+        "chevrons-down" => ICON_CHEVRONS_DOWN_CODEPOINTS,
+        "chevrons-left" => ICON_CHEVRONS_LEFT_CODEPOINTS,
+        "chevrons-right" => ICON_CHEVRONS_RIGHT_CODEPOINTS,
+        "chevrons-up" => ICON_CHEVRONS_UP_CODEPOINTS,
+        "chrome" => ICON_CHROME_CODEPOINTS,
+        "circle" => ICON_CIRCLE_CODEPOINTS,
+        "clipboard" => ICON_CLIPBOARD_CODEPOINTS,
+        "clock" => ICON_CLOCK_CODEPOINTS,
+        "cloud" => ICON_CLOUD_CODEPOINTS,
+        "cloud-drizzle" => ICON_CLOUD_DRIZZLE_CODEPOINTS,
+        "cloud-lightning" => ICON_CLOUD_LIGHTNING_CODEPOINTS,
+        "cloud-off" => ICON_CLOUD_OFF_CODEPOINTS,
+        "cloud-rain" => ICON_CLOUD_RAIN_CODEPOINTS,
+        "cloud-snow" => ICON_CLOUD_SNOW_CODEPOINTS,
+        "code" => ICON_CODE_CODEPOINTS,
+        "codepen" => ICON_CODEPEN_CODEPOINTS,
+        "codesandbox" => ICON_CODESANDBOX_CODEPOINTS,
+        "coffee" => ICON_COFFEE_CODEPOINTS,
+        "columns" => ICON_COLUMNS_CODEPOINTS,
+        "command" => ICON_COMMAND_CODEPOINTS,
+        "compass" => ICON_COMPASS_CODEPOINTS,
+        "copy" => ICON_COPY_CODEPOINTS,
+        "corner-down-left" => ICON_CORNER_DOWN_LEFT_CODEPOINTS,
+        "corner-down-right" => ICON_CORNER_DOWN_RIGHT_CODEPOINTS,
+        "corner-left-down" => ICON_CORNER_LEFT_DOWN_CODEPOINTS,
+        "corner-left-up" => ICON_CORNER_LEFT_UP_CODEPOINTS,
+        "corner-right-down" => ICON_CORNER_RIGHT_DOWN_CODEPOINTS,
+        "corner-right-up" => ICON_CORNER_RIGHT_UP_CODEPOINTS,
+        "corner-up-left" => ICON_CORNER_UP_LEFT_CODEPOINTS,
+        "corner-up-right" => ICON_CORNER_UP_RIGHT_CODEPOINTS,
+        "cpu" => ICON_CPU_CODEPOINTS,
+        "credit-card" => ICON_CREDIT_CARD_CODEPOINTS,
+        "crop" => ICON_CROP_CODEPOINTS,
+        "crosshair" => ICON_CROSSHAIR_CODEPOINTS,
+        "database" => ICON_DATABASE_CODEPOINTS,
+        "delete" => ICON_DELETE_CODEPOINTS,
+        "disc" => ICON_DISC_CODEPOINTS,
+        "divide" => ICON_DIVIDE_CODEPOINTS,
+        "divide-circle" => ICON_DIVIDE_CIRCLE_CODEPOINTS,
+        "divide-square" => ICON_DIVIDE_SQUARE_CODEPOINTS,
+        "dollar-sign" => ICON_DOLLAR_SIGN_CODEPOINTS,
+        "download" => ICON_DOWNLOAD_CODEPOINTS,
+        "download-cloud" => ICON_DOWNLOAD_CLOUD_CODEPOINTS,
+        "dribbble" => ICON_DRIBBBLE_CODEPOINTS,
+        "droplet" => ICON_DROPLET_CODEPOINTS,
+        "edit" => ICON_EDIT_CODEPOINTS,
+        "edit-2" => ICON_EDIT_2_CODEPOINTS,
+        "edit-3" => ICON_EDIT_3_CODEPOINTS,
+        "external-link" => ICON_EXTERNAL_LINK_CODEPOINTS,
+        "eye" => ICON_EYE_CODEPOINTS,
+        "eye-off" => ICON_EYE_OFF_CODEPOINTS,
+        "facebook" => ICON_FACEBOOK_CODEPOINTS,
+        "fast-forward" => ICON_FAST_FORWARD_CODEPOINTS,
+        "feather" => ICON_FEATHER_CODEPOINTS,
+        "figma" => ICON_FIGMA_CODEPOINTS,
+        "file" => ICON_FILE_CODEPOINTS,
+        "file-minus" => ICON_FILE_MINUS_CODEPOINTS,
+        "file-plus" => ICON_FILE_PLUS_CODEPOINTS,
+        "file-text" => ICON_FILE_TEXT_CODEPOINTS,
+        "film" => ICON_FILM_CODEPOINTS,
+        "filter" => ICON_FILTER_CODEPOINTS,
+        "flag" => ICON_FLAG_CODEPOINTS,
+        "folder" => ICON_FOLDER_CODEPOINTS,
+        "folder-minus" => ICON_FOLDER_MINUS_CODEPOINTS,
+        "folder-plus" => ICON_FOLDER_PLUS_CODEPOINTS,
+        "framer" => ICON_FRAMER_CODEPOINTS,
+        "frown" => ICON_FROWN_CODEPOINTS,
+        "gift" => ICON_GIFT_CODEPOINTS,
+        "git-branch" => ICON_GIT_BRANCH_CODEPOINTS,
+        "git-commit" => ICON_GIT_COMMIT_CODEPOINTS,
+        "git-merge" => ICON_GIT_MERGE_CODEPOINTS,
+        "git-pull-request" => ICON_GIT_PULL_REQUEST_CODEPOINTS,
+        "github" => ICON_GITHUB_CODEPOINTS,
+        "gitlab" => ICON_GITLAB_CODEPOINTS,
+        "globe" => ICON_GLOBE_CODEPOINTS,
+        "grid" => ICON_GRID_CODEPOINTS,
+        "hard-drive" => ICON_HARD_DRIVE_CODEPOINTS,
+        "hash" => ICON_HASH_CODEPOINTS,
+        "headphones" => ICON_HEADPHONES_CODEPOINTS,
+        "heart" => ICON_HEART_CODEPOINTS,
+        "help-circle" => ICON_HELP_CIRCLE_CODEPOINTS,
+        "hexagon" => ICON_HEXAGON_CODEPOINTS,
+        "home" => ICON_HOME_CODEPOINTS,
+        "image" => ICON_IMAGE_CODEPOINTS,
+        "inbox" => ICON_INBOX_CODEPOINTS,
+        "info" => ICON_INFO_CODEPOINTS,
+        "instagram" => ICON_INSTAGRAM_CODEPOINTS,
+        "italic" => ICON_ITALIC_CODEPOINTS,
+        "key" => ICON_KEY_CODEPOINTS,
+        "layers" => ICON_LAYERS_CODEPOINTS,
+        "layout" => ICON_LAYOUT_CODEPOINTS,
+        "life-buoy" => ICON_LIFE_BUOY_CODEPOINTS,
+        "link" => ICON_LINK_CODEPOINTS,
+        "link-2" => ICON_LINK_2_CODEPOINTS,
+        "linkedin" => ICON_LINKEDIN_CODEPOINTS,
+        "list" => ICON_LIST_CODEPOINTS,
+        "loader" => ICON_LOADER_CODEPOINTS,
+        "lock" => ICON_LOCK_CODEPOINTS,
+        "log-in" => ICON_LOG_IN_CODEPOINTS,
+        "log-out" => ICON_LOG_OUT_CODEPOINTS,
+        "mail" => ICON_MAIL_CODEPOINTS,
+        "map" => ICON_MAP_CODEPOINTS,
+        "map-pin" => ICON_MAP_PIN_CODEPOINTS,
+        "maximize" => ICON_MAXIMIZE_CODEPOINTS,
+        "maximize-2" => ICON_MAXIMIZE_2_CODEPOINTS,
+        "meh" => ICON_MEH_CODEPOINTS,
+        "menu" => ICON_MENU_CODEPOINTS,
+        "message-circle" => ICON_MESSAGE_CIRCLE_CODEPOINTS,
+        "message-square" => ICON_MESSAGE_SQUARE_CODEPOINTS,
+        "mic" => ICON_MIC_CODEPOINTS,
+        "mic-off" => ICON_MIC_OFF_CODEPOINTS,
+        "minimize" => ICON_MINIMIZE_CODEPOINTS,
+        "minimize-2" => ICON_MINIMIZE_2_CODEPOINTS,
+        "minus" => ICON_MINUS_CODEPOINTS,
+        "minus-circle" => ICON_MINUS_CIRCLE_CODEPOINTS,
+        "minus-square" => ICON_MINUS_SQUARE_CODEPOINTS,
+        "monitor" => ICON_MONITOR_CODEPOINTS,
+        "moon" => ICON_MOON_CODEPOINTS,
+        "more-horizontal" => ICON_MORE_HORIZONTAL_CODEPOINTS,
+        "more-vertical" => ICON_MORE_VERTICAL_CODEPOINTS,
+        "mouse-pointer" => ICON_MOUSE_POINTER_CODEPOINTS,
+        "move" => ICON_MOVE__CODEPOINTS,
+        "music" => ICON_MUSIC_CODEPOINTS,
+        "navigation" => ICON_NAVIGATION_CODEPOINTS,
+        "navigation-2" => ICON_NAVIGATION_2_CODEPOINTS,
+        "octagon" => ICON_OCTAGON_CODEPOINTS,
+        "package" => ICON_PACKAGE_CODEPOINTS,
+        "paperclip" => ICON_PAPERCLIP_CODEPOINTS,
+        "pause" => ICON_PAUSE_CODEPOINTS,
+        "pause-circle" => ICON_PAUSE_CIRCLE_CODEPOINTS,
+        "pen-tool" => ICON_PEN_TOOL_CODEPOINTS,
+        "percent" => ICON_PERCENT_CODEPOINTS,
+        "phone" => ICON_PHONE_CODEPOINTS,
+        "phone-call" => ICON_PHONE_CALL_CODEPOINTS,
+        "phone-forwarded" => ICON_PHONE_FORWARDED_CODEPOINTS,
+        "phone-incoming" => ICON_PHONE_INCOMING_CODEPOINTS,
+        "phone-missed" => ICON_PHONE_MISSED_CODEPOINTS,
+        "phone-off" => ICON_PHONE_OFF_CODEPOINTS,
+        "phone-outgoing" => ICON_PHONE_OUTGOING_CODEPOINTS,
+        "pie-chart" => ICON_PIE_CHART_CODEPOINTS,
+        "play" => ICON_PLAY_CODEPOINTS,
+        "play-circle" => ICON_PLAY_CIRCLE_CODEPOINTS,
+        "plus" => ICON_PLUS_CODEPOINTS,
+        "plus-circle" => ICON_PLUS_CIRCLE_CODEPOINTS,
+        "plus-square" => ICON_PLUS_SQUARE_CODEPOINTS,
+        "pocket" => ICON_POCKET_CODEPOINTS,
+        "power" => ICON_POWER_CODEPOINTS,
+        "printer" => ICON_PRINTER_CODEPOINTS,
+        "radio" => ICON_RADIO_CODEPOINTS,
+        "refresh-ccw" => ICON_REFRESH_CCW_CODEPOINTS,
+        "refresh-cw" => ICON_REFRESH_CW_CODEPOINTS,
+        "repeat" => ICON_REPEAT_CODEPOINTS,
+        "rewind" => ICON_REWIND_CODEPOINTS,
+        "rotate-ccw" => ICON_ROTATE_CCW_CODEPOINTS,
+        "rotate-cw" => ICON_ROTATE_CW_CODEPOINTS,
+        "rss" => ICON_RSS_CODEPOINTS,
+        "save" => ICON_SAVE_CODEPOINTS,
+        "scissors" => ICON_SCISSORS_CODEPOINTS,
+        "search" => ICON_SEARCH_CODEPOINTS,
+        "send" => ICON_SEND_CODEPOINTS,
+        "server" => ICON_SERVER_CODEPOINTS,
+        "settings" => ICON_SETTINGS_CODEPOINTS,
+        "share" => ICON_SHARE_CODEPOINTS,
+        "share-2" => ICON_SHARE_2_CODEPOINTS,
+        "shield" => ICON_SHIELD_CODEPOINTS,
+        "shield-off" => ICON_SHIELD_OFF_CODEPOINTS,
+        "shopping-bag" => ICON_SHOPPING_BAG_CODEPOINTS,
+        "shopping-cart" => ICON_SHOPPING_CART_CODEPOINTS,
+        "shuffle" => ICON_SHUFFLE_CODEPOINTS,
+        "sidebar" => ICON_SIDEBAR_CODEPOINTS,
+        "skip-back" => ICON_SKIP_BACK_CODEPOINTS,
+        "skip-forward" => ICON_SKIP_FORWARD_CODEPOINTS,
+        "slack" => ICON_SLACK_CODEPOINTS,
+        "slash" => ICON_SLASH_CODEPOINTS,
+        "sliders" => ICON_SLIDERS_CODEPOINTS,
+        "smartphone" => ICON_SMARTPHONE_CODEPOINTS,
+        "smile" => ICON_SMILE_CODEPOINTS,
+        "speaker" => ICON_SPEAKER_CODEPOINTS,
+        "square" => ICON_SQUARE_CODEPOINTS,
+        "star" => ICON_STAR_CODEPOINTS,
+        "stop-circle" => ICON_STOP_CIRCLE_CODEPOINTS,
+        "sun" => ICON_SUN_CODEPOINTS,
+        "sunrise" => ICON_SUNRISE_CODEPOINTS,
+        "sunset" => ICON_SUNSET_CODEPOINTS,
+        "table" => ICON_TABLE_CODEPOINTS,
+        "tablet" => ICON_TABLET_CODEPOINTS,
+        "tag" => ICON_TAG_CODEPOINTS,
+        "target" => ICON_TARGET_CODEPOINTS,
+        "terminal" => ICON_TERMINAL_CODEPOINTS,
+        "thermometer" => ICON_THERMOMETER_CODEPOINTS,
+        "thumbs-down" => ICON_THUMBS_DOWN_CODEPOINTS,
+        "thumbs-up" => ICON_THUMBS_UP_CODEPOINTS,
+        "toggle-left" => ICON_TOGGLE_LEFT_CODEPOINTS,
+        "toggle-right" => ICON_TOGGLE_RIGHT_CODEPOINTS,
+        "tool" => ICON_TOOL_CODEPOINTS,
+        "trash" => ICON_TRASH_CODEPOINTS,
+        "trash-2" => ICON_TRASH_2_CODEPOINTS,
+        "trello" => ICON_TRELLO_CODEPOINTS,
+        "trending-down" => ICON_TRENDING_DOWN_CODEPOINTS,
+        "trending-up" => ICON_TRENDING_UP_CODEPOINTS,
+        "triangle" => ICON_TRIANGLE_CODEPOINTS,
+        "truck" => ICON_TRUCK_CODEPOINTS,
+        "tv" => ICON_TV_CODEPOINTS,
+        "twitch" => ICON_TWITCH_CODEPOINTS,
+        "twitter" => ICON_TWITTER_CODEPOINTS,
+        "type" => ICON_TYPE__CODEPOINTS,
+        "umbrella" => ICON_UMBRELLA_CODEPOINTS,
+        "underline" => ICON_UNDERLINE_CODEPOINTS,
+        "unlock" => ICON_UNLOCK_CODEPOINTS,
+        "upload" => ICON_UPLOAD_CODEPOINTS,
+        "upload-cloud" => ICON_UPLOAD_CLOUD_CODEPOINTS,
+        "user" => ICON_USER_CODEPOINTS,
+        "user-check" => ICON_USER_CHECK_CODEPOINTS,
+        "user-minus" => ICON_USER_MINUS_CODEPOINTS,
+        "user-plus" => ICON_USER_PLUS_CODEPOINTS,
+        "user-x" => ICON_USER_X_CODEPOINTS,
+        "users" => ICON_USERS_CODEPOINTS,
+        "video" => ICON_VIDEO_CODEPOINTS,
+        "video-off" => ICON_VIDEO_OFF_CODEPOINTS,
+        "voicemail" => ICON_VOICEMAIL_CODEPOINTS,
+        "volume" => ICON_VOLUME_CODEPOINTS,
+        "volume-1" => ICON_VOLUME_1_CODEPOINTS,
+        "volume-2" => ICON_VOLUME_2_CODEPOINTS,
+        "volume-x" => ICON_VOLUME_X_CODEPOINTS,
+        "watch" => ICON_WATCH_CODEPOINTS,
+        "wifi" => ICON_WIFI_CODEPOINTS,
+        "wifi-off" => ICON_WIFI_OFF_CODEPOINTS,
+        "wind" => ICON_WIND_CODEPOINTS,
+        "x" => ICON_X_CODEPOINTS,
+        "x-circle" => ICON_X_CIRCLE_CODEPOINTS,
+        "x-octagon" => ICON_X_OCTAGON_CODEPOINTS,
+        "x-square" => ICON_X_SQUARE_CODEPOINTS,
+        "youtube" => ICON_YOUTUBE_CODEPOINTS,
+        "zap" => ICON_ZAP_CODEPOINTS,
+        "zap-off" => ICON_ZAP_OFF_CODEPOINTS,
+        "zoom-in" => ICON_ZOOM_IN_CODEPOINTS,
+        "zoom-out" => ICON_ZOOM_OUT_CODEPOINTS,
+        _ => return None,
+    };
+    codepoints
         .iter()
-        .find(|entry| entry.name == name)
-        .and_then(|entry| {
-            entry
-                .codepoints
-                .iter()
-                .find(|(k, _)| *k == key)
-                .map(|(_, cp)| *cp)
-        })
+        .find(|(k, _)| *k == key)
+        .map(|(_, cp)| *cp)
+}
+
+pub(crate) fn canonical_name_ci(lowercase: &str) -> Option<&'static str> {
+    let index = ICON_NAMES_LOWERCASE.partition_point(|(lower, _)| *lower < lowercase);
+    ICON_NAMES_LOWERCASE
+        .get(index)
+        .filter(|(lower, _)| *lower == lowercase)
+        .map(|(_, name)| *name)
 }
 
 pub(crate) fn icon_available(name: &str) -> Option<&'static [(Style, Size)]> {
-    ICON_AVAILABILITY
-        .iter()
-        .find(|entry| entry.name == name)
-        .map(|entry| entry.available)
+    match name {
+        "activity" => Some(ICON_ACTIVITY_AVAILABLE),
+        "airplay" => Some(ICON_AIRPLAY_AVAILABLE),
+        "alert-circle" => Some(ICON_ALERT_CIRCLE_AVAILABLE),
+        "alert-octagon" => Some(ICON_ALERT_OCTAGON_AVAILABLE),
+        "alert-triangle" => Some(ICON_ALERT_TRIANGLE_AVAILABLE),
+        "align-center" => Some(ICON_ALIGN_CENTER_AVAILABLE),
+        "align-justify" => Some(ICON_ALIGN_JUSTIFY_AVAILABLE),
+        "align-left" => Some(ICON_ALIGN_LEFT_AVAILABLE),
+        "align-right" => Some(ICON_ALIGN_RIGHT_AVAILABLE),
+        "anchor" => Some(ICON_ANCHOR_AVAILABLE),
+        "aperture" => Some(ICON_APERTURE_AVAILABLE),
+        "archive" => Some(ICON_ARCHIVE_AVAILABLE),
+        "arrow-down" => Some(ICON_ARROW_DOWN_AVAILABLE),
+        "arrow-down-circle" => Some(ICON_ARROW_DOWN_CIRCLE_AVAILABLE),
+        "arrow-down-left" => Some(ICON_ARROW_DOWN_LEFT_AVAILABLE),
+        "arrow-down-right" => Some(ICON_ARROW_DOWN_RIGHT_AVAILABLE),
+        "arrow-left" => Some(ICON_ARROW_LEFT_AVAILABLE),
+        "arrow-left-circle" => Some(ICON_ARROW_LEFT_CIRCLE_AVAILABLE),
+        "arrow-right" => Some(ICON_ARROW_RIGHT_AVAILABLE),
+        "arrow-right-circle" => Some(ICON_ARROW_RIGHT_CIRCLE_AVAILABLE),
+        "arrow-up" => Some(ICON_ARROW_UP_AVAILABLE),
+        "arrow-up-circle" => Some(ICON_ARROW_UP_CIRCLE_AVAILABLE),
+        "arrow-up-left" => Some(ICON_ARROW_UP_LEFT_AVAILABLE),
+        "arrow-up-right" => Some(ICON_ARROW_UP_RIGHT_AVAILABLE),
+        "at-sign" => Some(ICON_AT_SIGN_AVAILABLE),
+        "award" => Some(ICON_AWARD_AVAILABLE),
+        "bar-chart" => Some(ICON_BAR_CHART_AVAILABLE),
+        "bar-chart-2" => Some(ICON_BAR_CHART_2_AVAILABLE),
+        "battery" => Some(ICON_BATTERY_AVAILABLE),
+        "battery-charging" => Some(ICON_BATTERY_CHARGING_AVAILABLE),
+        "bell" => Some(ICON_BELL_AVAILABLE),
+        "bell-off" => Some(ICON_BELL_OFF_AVAILABLE),
+        "bluetooth" => Some(ICON_BLUETOOTH_AVAILABLE),
+        "bold" => Some(ICON_BOLD_AVAILABLE),
+        "book" => Some(ICON_BOOK_AVAILABLE),
+        "book-open" => Some(ICON_BOOK_OPEN_AVAILABLE),
+        "bookmark" => Some(ICON_BOOKMARK_AVAILABLE),
+        "box" => Some(ICON_BOX_AVAILABLE),
+        "briefcase" => Some(ICON_BRIEFCASE_AVAILABLE),
+        "calendar" => Some(ICON_CALENDAR_AVAILABLE),
+        "camera" => Some(ICON_CAMERA_AVAILABLE),
+        "camera-off" => Some(ICON_CAMERA_OFF_AVAILABLE),
+        "cast" => Some(ICON_CAST_AVAILABLE),
+        "check" => Some(ICON_CHECK_AVAILABLE),
+        "check-circle" => Some(ICON_CHECK_CIRCLE_AVAILABLE),
+        "check-square" => Some(ICON_CHECK_SQUARE_AVAILABLE),
+        "chevron-down" => Some(ICON_CHEVRON_DOWN_AVAILABLE),
+        "chevron-left" => Some(ICON_CHEVRON_LEFT_AVAILABLE),
+        "chevron-right" => Some(ICON_CHEVRON_RIGHT_AVAILABLE),
+        "chevron-up" => Some(ICON_CHEVRON_UP_AVAILABLE),
+        "chevrons-down" => Some(ICON_CHEVRONS_DOWN_AVAILABLE),
+        "chevrons-left" => Some(ICON_CHEVRONS_LEFT_AVAILABLE),
+        "chevrons-right" => Some(ICON_CHEVRONS_RIGHT_AVAILABLE),
+        "chevrons-up" => Some(ICON_CHEVRONS_UP_AVAILABLE),
+        "chrome" => Some(ICON_CHROME_AVAILABLE),
+        "circle" => Some(ICON_CIRCLE_AVAILABLE),
+        "clipboard" => Some(ICON_CLIPBOARD_AVAILABLE),
+        "clock" => Some(ICON_CLOCK_AVAILABLE),
+        "cloud" => Some(ICON_CLOUD_AVAILABLE),
+        "cloud-drizzle" => Some(ICON_CLOUD_DRIZZLE_AVAILABLE),
+        "cloud-lightning" => Some(ICON_CLOUD_LIGHTNING_AVAILABLE),
+        "cloud-off" => Some(ICON_CLOUD_OFF_AVAILABLE),
+        "cloud-rain" => Some(ICON_CLOUD_RAIN_AVAILABLE),
+        "cloud-snow" => Some(ICON_CLOUD_SNOW_AVAILABLE),
+        "code" => Some(ICON_CODE_AVAILABLE),
+        "codepen" => Some(ICON_CODEPEN_AVAILABLE),
+        "codesandbox" => Some(ICON_CODESANDBOX_AVAILABLE),
+        "coffee" => Some(ICON_COFFEE_AVAILABLE),
+        "columns" => Some(ICON_COLUMNS_AVAILABLE),
+        "command" => Some(ICON_COMMAND_AVAILABLE),
+        "compass" => Some(ICON_COMPASS_AVAILABLE),
+        "copy" => Some(ICON_COPY_AVAILABLE),
+        "corner-down-left" => Some(ICON_CORNER_DOWN_LEFT_AVAILABLE),
+        "corner-down-right" => Some(ICON_CORNER_DOWN_RIGHT_AVAILABLE),
+        "corner-left-down" => Some(ICON_CORNER_LEFT_DOWN_AVAILABLE),
+        "corner-left-up" => Some(ICON_CORNER_LEFT_UP_AVAILABLE),
+        "corner-right-down" => Some(ICON_CORNER_RIGHT_DOWN_AVAILABLE),
+        "corner-right-up" => Some(ICON_CORNER_RIGHT_UP_AVAILABLE),
+        "corner-up-left" => Some(ICON_CORNER_UP_LEFT_AVAILABLE),
+        "corner-up-right" => Some(ICON_CORNER_UP_RIGHT_AVAILABLE),
+        "cpu" => Some(ICON_CPU_AVAILABLE),
+        "credit-card" => Some(ICON_CREDIT_CARD_AVAILABLE),
+        "crop" => Some(ICON_CROP_AVAILABLE),
+        "crosshair" => Some(ICON_CROSSHAIR_AVAILABLE),
+        "database" => Some(ICON_DATABASE_AVAILABLE),
+        "delete" => Some(ICON_DELETE_AVAILABLE),
+        "disc" => Some(ICON_DISC_AVAILABLE),
+        "divide" => Some(ICON_DIVIDE_AVAILABLE),
+        "divide-circle" => Some(ICON_DIVIDE_CIRCLE_AVAILABLE),
+        "divide-square" => Some(ICON_DIVIDE_SQUARE_AVAILABLE),
+        "dollar-sign" => Some(ICON_DOLLAR_SIGN_AVAILABLE),
+        "download" => Some(ICON_DOWNLOAD_AVAILABLE),
+        "download-cloud" => Some(ICON_DOWNLOAD_CLOUD_AVAILABLE),
+        "dribbble" => Some(ICON_DRIBBBLE_AVAILABLE),
+        "droplet" => Some(ICON_DROPLET_AVAILABLE),
+        "edit" => Some(ICON_EDIT_AVAILABLE),
+        "edit-2" => Some(ICON_EDIT_2_AVAILABLE),
+        "edit-3" => Some(ICON_EDIT_3_AVAILABLE),
+        "external-link" => Some(ICON_EXTERNAL_LINK_AVAILABLE),
+        "eye" => Some(ICON_EYE_AVAILABLE),
+        "eye-off" => Some(ICON_EYE_OFF_AVAILABLE),
+        "facebook" => Some(ICON_FACEBOOK_AVAILABLE),
+        "fast-forward" => Some(ICON_FAST_FORWARD_AVAILABLE),
+        "feather" => Some(ICON_FEATHER_AVAILABLE),
+        "figma" => Some(ICON_FIGMA_AVAILABLE),
+        "file" => Some(ICON_FILE_AVAILABLE),
+        "file-minus" => Some(ICON_FILE_MINUS_AVAILABLE),
+        "file-plus" => Some(ICON_FILE_PLUS_AVAILABLE),
+        "file-text" => Some(ICON_FILE_TEXT_AVAILABLE),
+        "film" => Some(ICON_FILM_AVAILABLE),
+        "filter" => Some(ICON_FILTER_AVAILABLE),
+        "flag" => Some(ICON_FLAG_AVAILABLE),
+        "folder" => Some(ICON_FOLDER_AVAILABLE),
+        "folder-minus" => Some(ICON_FOLDER_MINUS_AVAILABLE),
+        "folder-plus" => Some(ICON_FOLDER_PLUS_AVAILABLE),
+        "framer" => Some(ICON_FRAMER_AVAILABLE),
+        "frown" => Some(ICON_FROWN_AVAILABLE),
+        "gift" => Some(ICON_GIFT_AVAILABLE),
+        "git-branch" => Some(ICON_GIT_BRANCH_AVAILABLE),
+        "git-commit" => Some(ICON_GIT_COMMIT_AVAILABLE),
+        "git-merge" => Some(ICON_GIT_MERGE_AVAILABLE),
+        "git-pull-request" => Some(ICON_GIT_PULL_REQUEST_AVAILABLE),
+        "github" => Some(ICON_GITHUB_AVAILABLE),
+        "gitlab" => Some(ICON_GITLAB_AVAILABLE),
+        "globe" => Some(ICON_GLOBE_AVAILABLE),
+        "grid" => Some(ICON_GRID_AVAILABLE),
+        "hard-drive" => Some(ICON_HARD_DRIVE_AVAILABLE),
+        "hash" => Some(ICON_HASH_AVAILABLE),
+        "headphones" => Some(ICON_HEADPHONES_AVAILABLE),
+        "heart" => Some(ICON_HEART_AVAILABLE),
+        "help-circle" => Some(ICON_HELP_CIRCLE_AVAILABLE),
+        "hexagon" => Some(ICON_HEXAGON_AVAILABLE),
+        "home" => Some(ICON_HOME_AVAILABLE),
+        "image" => Some(ICON_IMAGE_AVAILABLE),
+        "inbox" => Some(ICON_INBOX_AVAILABLE),
+        "info" => Some(ICON_INFO_AVAILABLE),
+        "instagram" => Some(ICON_INSTAGRAM_AVAILABLE),
+        "italic" => Some(ICON_ITALIC_AVAILABLE),
+        "key" => Some(ICON_KEY_AVAILABLE),
+        "layers" => Some(ICON_LAYERS_AVAILABLE),
+        "layout" => Some(ICON_LAYOUT_AVAILABLE),
+        "life-buoy" => Some(ICON_LIFE_BUOY_AVAILABLE),
+        "link" => Some(ICON_LINK_AVAILABLE),
+        "link-2" => Some(ICON_LINK_2_AVAILABLE),
+        "linkedin" => Some(ICON_LINKEDIN_AVAILABLE),
+        "list" => Some(ICON_LIST_AVAILABLE),
+        "loader" => Some(ICON_LOADER_AVAILABLE),
+        "lock" => Some(ICON_LOCK_AVAILABLE),
+        "log-in" => Some(ICON_LOG_IN_AVAILABLE),
+        "log-out" => Some(ICON_LOG_OUT_AVAILABLE),
+        "mail" => Some(ICON_MAIL_AVAILABLE),
+        "map" => Some(ICON_MAP_AVAILABLE),
+        "map-pin" => Some(ICON_MAP_PIN_AVAILABLE),
+        "maximize" => Some(ICON_MAXIMIZE_AVAILABLE),
+        "maximize-2" => Some(ICON_MAXIMIZE_2_AVAILABLE),
+        "meh" => Some(ICON_MEH_AVAILABLE),
+        "menu" => Some(ICON_MENU_AVAILABLE),
+        "message-circle" => Some(ICON_MESSAGE_CIRCLE_AVAILABLE),
+        "message-square" => Some(ICON_MESSAGE_SQUARE_AVAILABLE),
+        "mic" => Some(ICON_MIC_AVAILABLE),
+        "mic-off" => Some(ICON_MIC_OFF_AVAILABLE),
+        "minimize" => Some(ICON_MINIMIZE_AVAILABLE),
+        "minimize-2" => Some(ICON_MINIMIZE_2_AVAILABLE),
+        "minus" => Some(ICON_MINUS_AVAILABLE),
+        "minus-circle" => Some(ICON_MINUS_CIRCLE_AVAILABLE),
+        "minus-square" => Some(ICON_MINUS_SQUARE_AVAILABLE),
+        "monitor" => Some(ICON_MONITOR_AVAILABLE),
+        "moon" => Some(ICON_MOON_AVAILABLE),
+        "more-horizontal" => Some(ICON_MORE_HORIZONTAL_AVAILABLE),
+        "more-vertical" => Some(ICON_MORE_VERTICAL_AVAILABLE),
+        "mouse-pointer" => Some(ICON_MOUSE_POINTER_AVAILABLE),
+        "move" => Some(ICON_MOVE__AVAILABLE),
+        "music" => Some(ICON_MUSIC_AVAILABLE),
+        "navigation" => Some(ICON_NAVIGATION_AVAILABLE),
+        "navigation-2" => Some(ICON_NAVIGATION_2_AVAILABLE),
+        "octagon" => Some(ICON_OCTAGON_AVAILABLE),
+        "package" => Some(ICON_PACKAGE_AVAILABLE),
+        "paperclip" => Some(ICON_PAPERCLIP_AVAILABLE),
+        "pause" => Some(ICON_PAUSE_AVAILABLE),
+        "pause-circle" => Some(ICON_PAUSE_CIRCLE_AVAILABLE),
+        "pen-tool" => Some(ICON_PEN_TOOL_AVAILABLE),
+        "percent" => Some(ICON_PERCENT_AVAILABLE),
+        "phone" => Some(ICON_PHONE_AVAILABLE),
+        "phone-call" => Some(ICON_PHONE_CALL_AVAILABLE),
+        "phone-forwarded" => Some(ICON_PHONE_FORWARDED_AVAILABLE),
+        "phone-incoming" => Some(ICON_PHONE_INCOMING_AVAILABLE),
+        "phone-missed" => Some(ICON_PHONE_MISSED_AVAILABLE),
+        "phone-off" => Some(ICON_PHONE_OFF_AVAILABLE),
+        "phone-outgoing" => Some(ICON_PHONE_OUTGOING_AVAILABLE),
+        "pie-chart" => Some(ICON_PIE_CHART_AVAILABLE),
+        "play" => Some(ICON_PLAY_AVAILABLE),
+        "play-circle" => Some(ICON_PLAY_CIRCLE_AVAILABLE),
+        "plus" => Some(ICON_PLUS_AVAILABLE),
+        "plus-circle" => Some(ICON_PLUS_CIRCLE_AVAILABLE),
+        "plus-square" => Some(ICON_PLUS_SQUARE_AVAILABLE),
+        "pocket" => Some(ICON_POCKET_AVAILABLE),
+        "power" => Some(ICON_POWER_AVAILABLE),
+        "printer" => Some(ICON_PRINTER_AVAILABLE),
+        "radio" => Some(ICON_RADIO_AVAILABLE),
+        "refresh-ccw" => Some(ICON_REFRESH_CCW_AVAILABLE),
+        "refresh-cw" => Some(ICON_REFRESH_CW_AVAILABLE),
+        "repeat" => Some(ICON_REPEAT_AVAILABLE),
+        "rewind" => Some(ICON_REWIND_AVAILABLE),
+        "rotate-ccw" => Some(ICON_ROTATE_CCW_AVAILABLE),
+        "rotate-cw" => Some(ICON_ROTATE_CW_AVAILABLE),
+        "rss" => Some(ICON_RSS_AVAILABLE),
+        "save" => Some(ICON_SAVE_AVAILABLE),
+        "scissors" => Some(ICON_SCISSORS_AVAILABLE),
+        "search" => Some(ICON_SEARCH_AVAILABLE),
+        "send" => Some(ICON_SEND_AVAILABLE),
+        "server" => Some(ICON_SERVER_AVAILABLE),
+        "settings" => Some(ICON_SETTINGS_AVAILABLE),
+        "share" => Some(ICON_SHARE_AVAILABLE),
+        "share-2" => Some(ICON_SHARE_2_AVAILABLE),
+        "shield" => Some(ICON_SHIELD_AVAILABLE),
+        "shield-off" => Some(ICON_SHIELD_OFF_AVAILABLE),
+        "shopping-bag" => Some(ICON_SHOPPING_BAG_AVAILABLE),
+        "shopping-cart" => Some(ICON_SHOPPING_CART_AVAILABLE),
+        "shuffle" => Some(ICON_SHUFFLE_AVAILABLE),
+        "sidebar" => Some(ICON_SIDEBAR_AVAILABLE),
+        "skip-back" => Some(ICON_SKIP_BACK_AVAILABLE),
+        "skip-forward" => Some(ICON_SKIP_FORWARD_AVAILABLE),
+        "slack" => Some(ICON_SLACK_AVAILABLE),
+        "slash" => Some(ICON_SLASH_AVAILABLE),
+        "sliders" => Some(ICON_SLIDERS_AVAILABLE),
+        "smartphone" => Some(ICON_SMARTPHONE_AVAILABLE),
+        "smile" => Some(ICON_SMILE_AVAILABLE),
+        "speaker" => Some(ICON_SPEAKER_AVAILABLE),
+        "square" => Some(ICON_SQUARE_AVAILABLE),
+        "star" => Some(ICON_STAR_AVAILABLE),
+        "stop-circle" => Some(ICON_STOP_CIRCLE_AVAILABLE),
+        "sun" => Some(ICON_SUN_AVAILABLE),
+        "sunrise" => Some(ICON_SUNRISE_AVAILABLE),
+        "sunset" => Some(ICON_SUNSET_AVAILABLE),
+        "table" => Some(ICON_TABLE_AVAILABLE),
+        "tablet" => Some(ICON_TABLET_AVAILABLE),
+        "tag" => Some(ICON_TAG_AVAILABLE),
+        "target" => Some(ICON_TARGET_AVAILABLE),
+        "terminal" => Some(ICON_TERMINAL_AVAILABLE),
+        "thermometer" => Some(ICON_THERMOMETER_AVAILABLE),
+        "thumbs-down" => Some(ICON_THUMBS_DOWN_AVAILABLE),
+        "thumbs-up" => Some(ICON_THUMBS_UP_AVAILABLE),
+        "toggle-left" => Some(ICON_TOGGLE_LEFT_AVAILABLE),
+        "toggle-right" => Some(ICON_TOGGLE_RIGHT_AVAILABLE),
+        "tool" => Some(ICON_TOOL_AVAILABLE),
+        "trash" => Some(ICON_TRASH_AVAILABLE),
+        "trash-2" => Some(ICON_TRASH_2_AVAILABLE),
+        "trello" => Some(ICON_TRELLO_AVAILABLE),
+        "trending-down" => Some(ICON_TRENDING_DOWN_AVAILABLE),
+        "trending-up" => Some(ICON_TRENDING_UP_AVAILABLE),
+        "triangle" => Some(ICON_TRIANGLE_AVAILABLE),
+        "truck" => Some(ICON_TRUCK_AVAILABLE),
+        "tv" => Some(ICON_TV_AVAILABLE),
+        "twitch" => Some(ICON_TWITCH_AVAILABLE),
+        "twitter" => Some(ICON_TWITTER_AVAILABLE),
+        "type" => Some(ICON_TYPE__AVAILABLE),
+        "umbrella" => Some(ICON_UMBRELLA_AVAILABLE),
+        "underline" => Some(ICON_UNDERLINE_AVAILABLE),
+        "unlock" => Some(ICON_UNLOCK_AVAILABLE),
+        "upload" => Some(ICON_UPLOAD_AVAILABLE),
+        "upload-cloud" => Some(ICON_UPLOAD_CLOUD_AVAILABLE),
+        "user" => Some(ICON_USER_AVAILABLE),
+        "user-check" => Some(ICON_USER_CHECK_AVAILABLE),
+        "user-minus" => Some(ICON_USER_MINUS_AVAILABLE),
+        "user-plus" => Some(ICON_USER_PLUS_AVAILABLE),
+        "user-x" => Some(ICON_USER_X_AVAILABLE),
+        "users" => Some(ICON_USERS_AVAILABLE),
+        "video" => Some(ICON_VIDEO_AVAILABLE),
+        "video-off" => Some(ICON_VIDEO_OFF_AVAILABLE),
+        "voicemail" => Some(ICON_VOICEMAIL_AVAILABLE),
+        "volume" => Some(ICON_VOLUME_AVAILABLE),
+        "volume-1" => Some(ICON_VOLUME_1_AVAILABLE),
+        "volume-2" => Some(ICON_VOLUME_2_AVAILABLE),
+        "volume-x" => Some(ICON_VOLUME_X_AVAILABLE),
+        "watch" => Some(ICON_WATCH_AVAILABLE),
+        "wifi" => Some(ICON_WIFI_AVAILABLE),
+        "wifi-off" => Some(ICON_WIFI_OFF_AVAILABLE),
+        "wind" => Some(ICON_WIND_AVAILABLE),
+        "x" => Some(ICON_X_AVAILABLE),
+        "x-circle" => Some(ICON_X_CIRCLE_AVAILABLE),
+        "x-octagon" => Some(ICON_X_OCTAGON_AVAILABLE),
+        "x-square" => Some(ICON_X_SQUARE_AVAILABLE),
+        "youtube" => Some(ICON_YOUTUBE_AVAILABLE),
+        "zap" => Some(ICON_ZAP_AVAILABLE),
+        "zap-off" => Some(ICON_ZAP_OFF_AVAILABLE),
+        "zoom-in" => Some(ICON_ZOOM_IN_AVAILABLE),
+        "zoom-out" => Some(ICON_ZOOM_OUT_AVAILABLE),
+        _ => None,
+    }
+}
+
+pub(crate) fn brand_color(name: &str) -> Option<[u8; 4]> {
+    ICON_COLORS
+        .binary_search_by(|(entry, _)| (*entry).cmp(name))
+        .ok()
+        .map(|index| ICON_COLORS[index].1)
+}
+
+pub(crate) fn aria_label(name: &str) -> Option<&'static str> {
+    ICON_NAMES
+        .binary_search(&name)
+        .ok()
+        .map(|index| ICON_LABELS[index])
+}
+
+pub(crate) fn icon_by_id(id: u32) -> Option<&'static str> {
+    ICON_IDS
+        .binary_search_by_key(&id, |(entry, _)| *entry)
+        .ok()
+        .map(|index| ICON_IDS[index].1)
+}
+
+pub(crate) fn rtl_mirror(name: &str) -> bool {
+    ICON_RTL_MIRROR.binary_search(&name).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_lookup_agrees_with_scan_tables() {
+        for entry in ICON_CODEPOINTS {
+            for &(key, codepoint) in entry.codepoints {
+                assert_eq!(icon_codepoint(entry.name, key), Some(codepoint));
+            }
+        }
+        for entry in ICON_AVAILABILITY {
+            assert_eq!(icon_available(entry.name), Some(entry.available));
+        }
+        assert_eq!(icon_available(""), None);
+        assert_eq!(icon_codepoint("", VARIANTS[0].key), None);
+    }
 }
@@ -1,7 +1,13 @@
 // @generated by xtask gen. DO NOT EDIT.
-use crate::core::{FontAsset, IconRef, Size, Style, VariantKey};
+use crate::core::{FontAsset, IconRef, PackKind, Size, Style, VariantKey};
 
+/// Canonical pack id, also returned by `Pack::Heroicons.id()`.
 pub const PACK_ID: &str = "heroicons";
+pub const DISPLAY_NAME: &str = "Heroicons";
+pub const KIND: PackKind = PackKind::Ui;
+/// Fingerprint of the pack's families, names and codepoints; changes when the map does.
+pub const CONTENT_HASH: u64 = 0x5ec58b941a429854;
+pub const STYLE_ORDER: &[Style] = &[Style::Outline, Style::Filled];
 
 pub(crate) const FONT_ASSET_HEROICONS_HEROICONS_FILLED: FontAsset = FontAsset {
     family: "Heroicons Filled",
@@ -18,13 +24,6 @@ pub(crate) const FONT_ASSET_HEROICONS_HEROICONS_MINI: FontAsset = FontAsset {
         "/assets/fonts/heroicons/heroicons-mini.ttf"
     )),
 };
-pub(crate) const FONT_ASSET_HEROICONS_HEROICONS_OUTLINE: FontAsset = FontAsset {
-    family: "Heroicons Outline",
-    bytes: include_bytes!(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/assets/fonts/heroicons/heroicons-outline.ttf"
-    )),
-};
 #[cfg(feature = "heroicons-tiny")]
 pub(crate) const FONT_ASSET_HEROICONS_HEROICONS_TINY: FontAsset = FontAsset {
     family: "Heroicons Filled Tiny",
@@ -33,14 +32,21 @@ pub(crate) const FONT_ASSET_HEROICONS_HEROICONS_TINY: FontAsset = FontAsset {
         "/assets/fonts/heroicons/heroicons-tiny.ttf"
     )),
 };
+pub(crate) const FONT_ASSET_HEROICONS_HEROICONS_OUTLINE: FontAsset = FontAsset {
+    family: "Heroicons Outline",
+    bytes: include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/assets/fonts/heroicons/heroicons-outline.ttf"
+    )),
+};
 
 pub const FONT_ASSETS: &[FontAsset] = &[
     FONT_ASSET_HEROICONS_HEROICONS_FILLED,
     #[cfg(feature = "heroicons-mini")]
     FONT_ASSET_HEROICONS_HEROICONS_MINI,
-    FONT_ASSET_HEROICONS_HEROICONS_OUTLINE,
     #[cfg(feature = "heroicons-tiny")]
     FONT_ASSET_HEROICONS_HEROICONS_TINY,
+    FONT_ASSET_HEROICONS_HEROICONS_OUTLINE,
 ];
 
 pub const VARIANT_ASSETS: &[(VariantKey, FontAsset)] = &[
@@ -76,335 +82,1039 @@ pub const VARIANT_ASSETS: &[(VariantKey, FontAsset)] = &[
     ),
 ];
 
+/// Styles of the enabled variants, in presentation order (`STYLE_ORDER` first).
+pub const STYLES: &[Style] = &[Style::Outline, Style::Filled];
+
+/// Sizes of the enabled variants, sorted.
+pub const SIZES: &[Size] = &[
+    #[cfg(feature = "heroicons-tiny")]
+    Size::Tiny,
+    #[cfg(feature = "heroicons-mini")]
+    Size::Mini,
+    Size::Regular,
+];
+
+/// Lowest and highest codepoint each variant maps, for variants that map any.
+pub(crate) const VARIANT_CODEPOINT_RANGES: &[(VariantKey, u32, u32)] = &[
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE000,
+        0xE143,
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE000,
+        0xE143,
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE000,
+        0xE143,
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE000,
+        0xE13B,
+    ),
+];
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Icon {
+    /// `academic-cap` — U+E000 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     AcademicCap,
+    /// `adjustments-horizontal` — U+E001 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     AdjustmentsHorizontal,
+    /// `adjustments-vertical` — U+E002 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     AdjustmentsVertical,
+    /// `archive-box` — U+E005 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArchiveBox,
+    /// `archive-box-arrow-down` — U+E003 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArchiveBoxArrowDown,
+    /// `archive-box-x-mark` — U+E004 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArchiveBoxXMark,
+    /// `arrow-down` — U+E00C (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowDown,
+    /// `arrow-down-circle` — U+E006 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowDownCircle,
+    /// `arrow-down-left` — U+E007 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowDownLeft,
+    /// `arrow-down-on-square` — U+E009 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowDownOnSquare,
+    /// `arrow-down-on-square-stack` — U+E008 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowDownOnSquareStack,
+    /// `arrow-down-right` — U+E00A (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowDownRight,
+    /// `arrow-down-tray` — U+E00B (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowDownTray,
+    /// `arrow-left` — available in Filled U+E011, Filled/Mini U+E011, Outline U+E011, Filled/Tiny U+E010
     ArrowLeft,
+    /// `arrow-left-circle` — U+E00D (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowLeftCircle,
+    /// `arrow-left-end-on-rectangle` — U+E00E (available in Filled, Filled/Mini, Outline, Filled/Tiny)
     ArrowLeftEndOnRectangle,
+    /// `arrow-left-on-rectangle` — U+E00F (available in Filled, Filled/Mini, Outline)
     ArrowLeftOnRectangle,
+    /// `arrow-left-start-on-rectangle` — available in Filled U+E010, Filled/Mini U+E010, Outline U+E010, Filled/Tiny U+E00F
     ArrowLeftStartOnRectangle,
+    /// `arrow-long-down` — available in Filled U+E012, Filled/Mini U+E012, Outline U+E012, Filled/Tiny U+E011
     ArrowLongDown,
+    /// `arrow-long-left` — available in Filled U+E013, Filled/Mini U+E013, Outline U+E013, Filled/Tiny U+E012
     ArrowLongLeft,
+    /// `arrow-long-right` — available in Filled U+E014, Filled/Mini U+E014, Outline U+E014, Filled/Tiny U+E013
     ArrowLongRight,
+    /// `arrow-long-up` — available in Filled U+E015, Filled/Mini U+E015, Outline U+E015, Filled/Tiny U+E014
     ArrowLongUp,
+    /// `arrow-path` — available in Filled U+E017, Filled/Mini U+E017, Outline U+E017, Filled/Tiny U+E016
     ArrowPath,
+    /// `arrow-path-rounded-square` — available in Filled U+E016, Filled/Mini U+E016, Outline U+E016, Filled/Tiny U+E015
     ArrowPathRoundedSquare,
+    /// `arrow-right` — available in Filled U+E01C, Filled/Mini U+E01C, Outline U+E01C, Filled/Tiny U+E01A
     ArrowRight,
+    /// `arrow-right-circle` — available in Filled U+E018, Filled/Mini U+E018, Outline U+E018, Filled/Tiny U+E017
     ArrowRightCircle,
+    /// `arrow-right-end-on-rectangle` — available in Filled U+E019, Filled/Mini U+E019, Outline U+E019, Filled/Tiny U+E018
     ArrowRightEndOnRectangle,
+    /// `arrow-right-on-rectangle` — U+E01A (available in Filled, Filled/Mini, Outline)
     ArrowRightOnRectangle,
+    /// `arrow-right-start-on-rectangle` — available in Filled U+E01B, Filled/Mini U+E01B, Outline U+E01B, Filled/Tiny U+E019
     ArrowRightStartOnRectangle,
+    /// `arrow-small-down` — U+E01D (available in Filled, Filled/Mini, Outline)
     ArrowSmallDown,
+    /// `arrow-small-left` — U+E01E (available in Filled, Filled/Mini, Outline)
     ArrowSmallLeft,
+    /// `arrow-small-right` — U+E01F (available in Filled, Filled/Mini, Outline)
     ArrowSmallRight,
+    /// `arrow-small-up` — U+E020 (available in Filled, Filled/Mini, Outline)
     ArrowSmallUp,
+    /// `arrow-top-right-on-square` — available in Filled U+E021, Filled/Mini U+E021, Outline U+E021, Filled/Tiny U+E01B
     ArrowTopRightOnSquare,
+    /// `arrow-trending-down` — available in Filled U+E022, Filled/Mini U+E022, Outline U+E022, Filled/Tiny U+E01C
     ArrowTrendingDown,
+    /// `arrow-trending-up` — available in Filled U+E023, Filled/Mini U+E023, Outline U+E023, Filled/Tiny U+E01D
     ArrowTrendingUp,
+    /// `arrow-turn-down-left` — available in Filled U+E024, Filled/Mini U+E024, Outline U+E024, Filled/Tiny U+E01E
     ArrowTurnDownLeft,
+    /// `arrow-turn-down-right` — available in Filled U+E025, Filled/Mini U+E025, Outline U+E025, Filled/Tiny U+E01F
     ArrowTurnDownRight,
+    /// `arrow-turn-left-down` — available in Filled U+E026, Filled/Mini U+E026, Outline U+E026, Filled/Tiny U+E020
     ArrowTurnLeftDown,
+    /// `arrow-turn-left-up` — available in Filled U+E027, Filled/Mini U+E027, Outline U+E027, Filled/Tiny U+E021
     ArrowTurnLeftUp,
+    /// `arrow-turn-right-down` — available in Filled U+E028, Filled/Mini U+E028, Outline U+E028, Filled/Tiny U+E022
     ArrowTurnRightDown,
+    /// `arrow-turn-right-up` — available in Filled U+E029, Filled/Mini U+E029, Outline U+E029, Filled/Tiny U+E023
     ArrowTurnRightUp,
+    /// `arrow-turn-up-left` — available in Filled U+E02A, Filled/Mini U+E02A, Outline U+E02A, Filled/Tiny U+E024
     ArrowTurnUpLeft,
+    /// `arrow-turn-up-right` — available in Filled U+E02B, Filled/Mini U+E02B, Outline U+E02B, Filled/Tiny U+E025
     ArrowTurnUpRight,
+    /// `arrow-up` — available in Filled U+E032, Filled/Mini U+E032, Outline U+E032, Filled/Tiny U+E02C
     ArrowUp,
+    /// `arrow-up-circle` — available in Filled U+E02C, Filled/Mini U+E02C, Outline U+E02C, Filled/Tiny U+E026
     ArrowUpCircle,
+    /// `arrow-up-left` — available in Filled U+E02D, Filled/Mini U+E02D, Outline U+E02D, Filled/Tiny U+E027
     ArrowUpLeft,
+    /// `arrow-up-on-square` — available in Filled U+E02F, Filled/Mini U+E02F, Outline U+E02F, Filled/Tiny U+E029
     ArrowUpOnSquare,
+    /// `arrow-up-on-square-stack` — available in Filled U+E02E, Filled/Mini U+E02E, Outline U+E02E, Filled/Tiny U+E028
     ArrowUpOnSquareStack,
+    /// `arrow-up-right` — available in Filled U+E030, Filled/Mini U+E030, Outline U+E030, Filled/Tiny U+E02A
     ArrowUpRight,
+    /// `arrow-up-tray` — available in Filled U+E031, Filled/Mini U+E031, Outline U+E031, Filled/Tiny U+E02B
     ArrowUpTray,
+    /// `arrow-uturn-down` — available in Filled U+E033, Filled/Mini U+E033, Outline U+E033, Filled/Tiny U+E02D
     ArrowUturnDown,
+    /// `arrow-uturn-left` — available in Filled U+E034, Filled/Mini U+E034, Outline U+E034, Filled/Tiny U+E02E
     ArrowUturnLeft,
+    /// `arrow-uturn-right` — available in Filled U+E035, Filled/Mini U+E035, Outline U+E035, Filled/Tiny U+E02F
     ArrowUturnRight,
+    /// `arrow-uturn-up` — available in Filled U+E036, Filled/Mini U+E036, Outline U+E036, Filled/Tiny U+E030
     ArrowUturnUp,
+    /// `arrows-pointing-in` — available in Filled U+E037, Filled/Mini U+E037, Outline U+E037, Filled/Tiny U+E031
     ArrowsPointingIn,
+    /// `arrows-pointing-out` — available in Filled U+E038, Filled/Mini U+E038, Outline U+E038, Filled/Tiny U+E032
     ArrowsPointingOut,
+    /// `arrows-right-left` — available in Filled U+E039, Filled/Mini U+E039, Outline U+E039, Filled/Tiny U+E033
     ArrowsRightLeft,
+    /// `arrows-up-down` — available in Filled U+E03A, Filled/Mini U+E03A, Outline U+E03A, Filled/Tiny U+E034
     ArrowsUpDown,
+    /// `at-symbol` — available in Filled U+E03B, Filled/Mini U+E03B, Outline U+E03B, Filled/Tiny U+E035
     AtSymbol,
+    /// `backspace` — available in Filled U+E03C, Filled/Mini U+E03C, Outline U+E03C, Filled/Tiny U+E036
     Backspace,
+    /// `backward` — available in Filled U+E03D, Filled/Mini U+E03D, Outline U+E03D, Filled/Tiny U+E037
     Backward,
+    /// `banknotes` — available in Filled U+E03E, Filled/Mini U+E03E, Outline U+E03E, Filled/Tiny U+E038
     Banknotes,
+    /// `bars-2` — available in Filled U+E03F, Filled/Mini U+E03F, Outline U+E03F, Filled/Tiny U+E039
     Bars2,
+    /// `bars-3` — available in Filled U+E043, Filled/Mini U+E043, Outline U+E043, Filled/Tiny U+E03D
     Bars3,
+    /// `bars-3-bottom-left` — available in Filled U+E040, Filled/Mini U+E040, Outline U+E040, Filled/Tiny U+E03A
     Bars3BottomLeft,
+    /// `bars-3-bottom-right` — available in Filled U+E041, Filled/Mini U+E041, Outline U+E041, Filled/Tiny U+E03B
     Bars3BottomRight,
+    /// `bars-3-center-left` — available in Filled U+E042, Filled/Mini U+E042, Outline U+E042, Filled/Tiny U+E03C
     Bars3CenterLeft,
+    /// `bars-4` — available in Filled U+E044, Filled/Mini U+E044, Outline U+E044, Filled/Tiny U+E03E
     Bars4,
+    /// `bars-arrow-down` — available in Filled U+E045, Filled/Mini U+E045, Outline U+E045, Filled/Tiny U+E03F
     BarsArrowDown,
+    /// `bars-arrow-up` — available in Filled U+E046, Filled/Mini U+E046, Outline U+E046, Filled/Tiny U+E040
     BarsArrowUp,
+    /// `battery-0` — available in Filled U+E047, Filled/Mini U+E047, Outline U+E047, Filled/Tiny U+E041
     Battery0,
+    /// `battery-100` — available in Filled U+E048, Filled/Mini U+E048, Outline U+E048, Filled/Tiny U+E042
     Battery100,
+    /// `battery-50` — available in Filled U+E049, Filled/Mini U+E049, Outline U+E049, Filled/Tiny U+E043
     Battery50,
+    /// `beaker` — available in Filled U+E04A, Filled/Mini U+E04A, Outline U+E04A, Filled/Tiny U+E044
     Beaker,
+    /// `bell` — available in Filled U+E04E, Filled/Mini U+E04E, Outline U+E04E, Filled/Tiny U+E048
     Bell,
+    /// `bell-alert` — available in Filled U+E04B, Filled/Mini U+E04B, Outline U+E04B, Filled/Tiny U+E045
     BellAlert,
+    /// `bell-slash` — available in Filled U+E04C, Filled/Mini U+E04C, Outline U+E04C, Filled/Tiny U+E046
     BellSlash,
+    /// `bell-snooze` — available in Filled U+E04D, Filled/Mini U+E04D, Outline U+E04D, Filled/Tiny U+E047
     BellSnooze,
+    /// `bold` — available in Filled U+E04F, Filled/Mini U+E04F, Outline U+E04F, Filled/Tiny U+E049
     Bold,
+    /// `bolt` — available in Filled U+E051, Filled/Mini U+E051, Outline U+E051, Filled/Tiny U+E04B
     Bolt,
+    /// `bolt-slash` — available in Filled U+E050, Filled/Mini U+E050, Outline U+E050, Filled/Tiny U+E04A
     BoltSlash,
+    /// `book-open` — available in Filled U+E052, Filled/Mini U+E052, Outline U+E052, Filled/Tiny U+E04C
     BookOpen,
+    /// `bookmark` — available in Filled U+E055, Filled/Mini U+E055, Outline U+E055, Filled/Tiny U+E04F
     Bookmark,
+    /// `bookmark-slash` — available in Filled U+E053, Filled/Mini U+E053, Outline U+E053, Filled/Tiny U+E04D
     BookmarkSlash,
+    /// `bookmark-square` — available in Filled U+E054, Filled/Mini U+E054, Outline U+E054, Filled/Tiny U+E04E
     BookmarkSquare,
+    /// `briefcase` — available in Filled U+E056, Filled/Mini U+E056, Outline U+E056, Filled/Tiny U+E050
     Briefcase,
+    /// `bug-ant` — available in Filled U+E057, Filled/Mini U+E057, Outline U+E057, Filled/Tiny U+E051
     BugAnt,
+    /// `building-library` — available in Filled U+E058, Filled/Mini U+E058, Outline U+E058, Filled/Tiny U+E052
     BuildingLibrary,
+    /// `building-office` — available in Filled U+E05A, Filled/Mini U+E05A, Outline U+E05A, Filled/Tiny U+E054
     BuildingOffice,
+    /// `building-office-2` — available in Filled U+E059, Filled/Mini U+E059, Outline U+E059, Filled/Tiny U+E053
     BuildingOffice2,
+    /// `building-storefront` — available in Filled U+E05B, Filled/Mini U+E05B, Outline U+E05B, Filled/Tiny U+E055
     BuildingStorefront,
+    /// `cake` — available in Filled U+E05C, Filled/Mini U+E05C, Outline U+E05C, Filled/Tiny U+E056
     Cake,
+    /// `calculator` — available in Filled U+E05D, Filled/Mini U+E05D, Outline U+E05D, Filled/Tiny U+E057
     Calculator,
+    /// `calendar` — available in Filled U+E060, Filled/Mini U+E060, Outline U+E060, Filled/Tiny U+E05A
     Calendar,
+    /// `calendar-date-range` — available in Filled U+E05E, Filled/Mini U+E05E, Outline U+E05E, Filled/Tiny U+E058
     CalendarDateRange,
+    /// `calendar-days` — available in Filled U+E05F, Filled/Mini U+E05F, Outline U+E05F, Filled/Tiny U+E059
     CalendarDays,
+    /// `camera` — available in Filled U+E061, Filled/Mini U+E061, Outline U+E061, Filled/Tiny U+E05B
     Camera,
+    /// `chart-bar` — available in Filled U+E063, Filled/Mini U+E063, Outline U+E063, Filled/Tiny U+E05D
     ChartBar,
+    /// `chart-bar-square` — available in Filled U+E062, Filled/Mini U+E062, Outline U+E062, Filled/Tiny U+E05C
     ChartBarSquare,
+    /// `chart-pie` — available in Filled U+E064, Filled/Mini U+E064, Outline U+E064, Filled/Tiny U+E05E
     ChartPie,
+    /// `chat-bubble-bottom-center` — available in Filled U+E066, Filled/Mini U+E066, Outline U+E066, Filled/Tiny U+E060
     ChatBubbleBottomCenter,
+    /// `chat-bubble-bottom-center-text` — available in Filled U+E065, Filled/Mini U+E065, Outline U+E065, Filled/Tiny U+E05F
     ChatBubbleBottomCenterText,
+    /// `chat-bubble-left` — available in Filled U+E069, Filled/Mini U+E069, Outline U+E069, Filled/Tiny U+E063
     ChatBubbleLeft,
+    /// `chat-bubble-left-ellipsis` — available in Filled U+E067, Filled/Mini U+E067, Outline U+E067, Filled/Tiny U+E061
     ChatBubbleLeftEllipsis,
+    /// `chat-bubble-left-right` — available in Filled U+E068, Filled/Mini U+E068, Outline U+E068, Filled/Tiny U+E062
     ChatBubbleLeftRight,
+    /// `chat-bubble-oval-left` — available in Filled U+E06B, Filled/Mini U+E06B, Outline U+E06B, Filled/Tiny U+E065
     ChatBubbleOvalLeft,
+    /// `chat-bubble-oval-left-ellipsis` — available in Filled U+E06A, Filled/Mini U+E06A, Outline U+E06A, Filled/Tiny U+E064
     ChatBubbleOvalLeftEllipsis,
+    /// `check` — available in Filled U+E06E, Filled/Mini U+E06E, Outline U+E06E, Filled/Tiny U+E068
     Check,
+    /// `check-badge` — available in Filled U+E06C, Filled/Mini U+E06C, Outline U+E06C, Filled/Tiny U+E066
     CheckBadge,
+    /// `check-circle` — available in Filled U+E06D, Filled/Mini U+E06D, Outline U+E06D, Filled/Tiny U+E067
     CheckCircle,
+    /// `chevron-double-down` — available in Filled U+E06F, Filled/Mini U+E06F, Outline U+E06F, Filled/Tiny U+E069
     ChevronDoubleDown,
+    /// `chevron-double-left` — available in Filled U+E070, Filled/Mini U+E070, Outline U+E070, Filled/Tiny U+E06A
     ChevronDoubleLeft,
+    /// `chevron-double-right` — available in Filled U+E071, Filled/Mini U+E071, Outline U+E071, Filled/Tiny U+E06B
     ChevronDoubleRight,
+    /// `chevron-double-up` — available in Filled U+E072, Filled/Mini U+E072, Outline U+E072, Filled/Tiny U+E06C
     ChevronDoubleUp,
+    /// `chevron-down` — available in Filled U+E073, Filled/Mini U+E073, Outline U+E073, Filled/Tiny U+E06D
     ChevronDown,
+    /// `chevron-left` — available in Filled U+E074, Filled/Mini U+E074, Outline U+E074, Filled/Tiny U+E06E
     ChevronLeft,
+    /// `chevron-right` — available in Filled U+E075, Filled/Mini U+E075, Outline U+E075, Filled/Tiny U+E06F
     ChevronRight,
+    /// `chevron-up` — available in Filled U+E077, Filled/Mini U+E077, Outline U+E077, Filled/Tiny U+E071
     ChevronUp,
+    /// `chevron-up-down` — available in Filled U+E076, Filled/Mini U+E076, Outline U+E076, Filled/Tiny U+E070
     ChevronUpDown,
+    /// `circle-stack` — available in Filled U+E078, Filled/Mini U+E078, Outline U+E078, Filled/Tiny U+E072
     CircleStack,
+    /// `clipboard` — available in Filled U+E07C, Filled/Mini U+E07C, Outline U+E07C, Filled/Tiny U+E076
     Clipboard,
+    /// `clipboard-document` — available in Filled U+E07B, Filled/Mini U+E07B, Outline U+E07B, Filled/Tiny U+E075
     ClipboardDocument,
+    /// `clipboard-document-check` — available in Filled U+E079, Filled/Mini U+E079, Outline U+E079, Filled/Tiny U+E073
     ClipboardDocumentCheck,
+    /// `clipboard-document-list` — available in Filled U+E07A, Filled/Mini U+E07A, Outline U+E07A, Filled/Tiny U+E074
     ClipboardDocumentList,
+    /// `clock` — available in Filled U+E07D, Filled/Mini U+E07D, Outline U+E07D, Filled/Tiny U+E077
     Clock,
+    /// `cloud` — available in Filled U+E080, Filled/Mini U+E080, Outline U+E080, Filled/Tiny U+E07A
     Cloud,
+    /// `cloud-arrow-down` — available in Filled U+E07E, Filled/Mini U+E07E, Outline U+E07E, Filled/Tiny U+E078
     CloudArrowDown,
+    /// `cloud-arrow-up` — available in Filled U+E07F, Filled/Mini U+E07F, Outline U+E07F, Filled/Tiny U+E079
     CloudArrowUp,
+    /// `code-bracket` — available in Filled U+E082, Filled/Mini U+E082, Outline U+E082, Filled/Tiny U+E07C
     CodeBracket,
+    /// `code-bracket-square` — available in Filled U+E081, Filled/Mini U+E081, Outline U+E081, Filled/Tiny U+E07B
     CodeBracketSquare,
+    /// `cog` — available in Filled U+E085, Filled/Mini U+E085, Outline U+E085, Filled/Tiny U+E07F
     Cog,
+    /// `cog-6-tooth` — available in Filled U+E083, Filled/Mini U+E083, Outline U+E083, Filled/Tiny U+E07D
     Cog6Tooth,
+    /// `cog-8-tooth` — available in Filled U+E084, Filled/Mini U+E084, Outline U+E084, Filled/Tiny U+E07E
     Cog8Tooth,
+    /// `command-line` — available in Filled U+E086, Filled/Mini U+E086, Outline U+E086, Filled/Tiny U+E080
     CommandLine,
+    /// `computer-desktop` — available in Filled U+E087, Filled/Mini U+E087, Outline U+E087, Filled/Tiny U+E081
     ComputerDesktop,
+    /// `cpu-chip` — available in Filled U+E088, Filled/Mini U+E088, Outline U+E088, Filled/Tiny U+E082
     CpuChip,
+    /// `credit-card` — available in Filled U+E089, Filled/Mini U+E089, Outline U+E089, Filled/Tiny U+E083
     CreditCard,
+    /// `cube` — available in Filled U+E08B, Filled/Mini U+E08B, Outline U+E08B, Filled/Tiny U+E085
     Cube,
+    /// `cube-transparent` — available in Filled U+E08A, Filled/Mini U+E08A, Outline U+E08A, Filled/Tiny U+E084
     CubeTransparent,
+    /// `currency-bangladeshi` — available in Filled U+E08C, Filled/Mini U+E08C, Outline U+E08C, Filled/Tiny U+E086
     CurrencyBangladeshi,
+    /// `currency-dollar` — available in Filled U+E08D, Filled/Mini U+E08D, Outline U+E08D, Filled/Tiny U+E087
     CurrencyDollar,
+    /// `currency-euro` — available in Filled U+E08E, Filled/Mini U+E08E, Outline U+E08E, Filled/Tiny U+E088
     CurrencyEuro,
+    /// `currency-pound` — available in Filled U+E08F, Filled/Mini U+E08F, Outline U+E08F, Filled/Tiny U+E089
     CurrencyPound,
+    /// `currency-rupee` — available in Filled U+E090, Filled/Mini U+E090, Outline U+E090, Filled/Tiny U+E08A
     CurrencyRupee,
+    /// `currency-yen` — available in Filled U+E091, Filled/Mini U+E091, Outline U+E091, Filled/Tiny U+E08B
     CurrencyYen,
+    /// `cursor-arrow-rays` — available in Filled U+E092, Filled/Mini U+E092, Outline U+E092, Filled/Tiny U+E08C
     CursorArrowRays,
+    /// `cursor-arrow-ripple` — available in Filled U+E093, Filled/Mini U+E093, Outline U+E093, Filled/Tiny U+E08D
     CursorArrowRipple,
+    /// `device-phone-mobile` — available in Filled U+E094, Filled/Mini U+E094, Outline U+E094, Filled/Tiny U+E08E
     DevicePhoneMobile,
+    /// `device-tablet` — available in Filled U+E095, Filled/Mini U+E095, Outline U+E095, Filled/Tiny U+E08F
     DeviceTablet,
+    /// `divide` — available in Filled U+E096, Filled/Mini U+E096, Outline U+E096, Filled/Tiny U+E090
     Divide,
+    /// `document` — available in Filled U+E0A6, Filled/Mini U+E0A6, Outline U+E0A6, Filled/Tiny U+E0A0
     Document,
+    /// `document-arrow-down` — available in Filled U+E097, Filled/Mini U+E097, Outline U+E097, Filled/Tiny U+E091
     DocumentArrowDown,
+    /// `document-arrow-up` — available in Filled U+E098, Filled/Mini U+E098, Outline U+E098, Filled/Tiny U+E092
     DocumentArrowUp,
+    /// `document-chart-bar` — available in Filled U+E099, Filled/Mini U+E099, Outline U+E099, Filled/Tiny U+E093
     DocumentChartBar,
+    /// `document-check` — available in Filled U+E09A, Filled/Mini U+E09A, Outline U+E09A, Filled/Tiny U+E094
     DocumentCheck,
+    /// `document-currency-bangladeshi` — available in Filled U+E09B, Filled/Mini U+E09B, Outline U+E09B, Filled/Tiny U+E095
     DocumentCurrencyBangladeshi,
+    /// `document-currency-dollar` — available in Filled U+E09C, Filled/Mini U+E09C, Outline U+E09C, Filled/Tiny U+E096
     DocumentCurrencyDollar,
+    /// `document-currency-euro` — available in Filled U+E09D, Filled/Mini U+E09D, Outline U+E09D, Filled/Tiny U+E097
     DocumentCurrencyEuro,
+    /// `document-currency-pound` — available in Filled U+E09E, Filled/Mini U+E09E, Outline U+E09E, Filled/Tiny U+E098
     DocumentCurrencyPound,
+    /// `document-currency-rupee` — available in Filled U+E09F, Filled/Mini U+E09F, Outline U+E09F, Filled/Tiny U+E099
     DocumentCurrencyRupee,
+    /// `document-currency-yen` — available in Filled U+E0A0, Filled/Mini U+E0A0, Outline U+E0A0, Filled/Tiny U+E09A
     DocumentCurrencyYen,
+    /// `document-duplicate` — available in Filled U+E0A1, Filled/Mini U+E0A1, Outline U+E0A1, Filled/Tiny U+E09B
     DocumentDuplicate,
+    /// `document-magnifying-glass` — available in Filled U+E0A2, Filled/Mini U+E0A2, Outline U+E0A2, Filled/Tiny U+E09C
     DocumentMagnifyingGlass,
+    /// `document-minus` — available in Filled U+E0A3, Filled/Mini U+E0A3, Outline U+E0A3, Filled/Tiny U+E09D
     DocumentMinus,
+    /// `document-plus` — available in Filled U+E0A4, Filled/Mini U+E0A4, Outline U+E0A4, Filled/Tiny U+E09E
     DocumentPlus,
+    /// `document-text` — available in Filled U+E0A5, Filled/Mini U+E0A5, Outline U+E0A5, Filled/Tiny U+E09F
     DocumentText,
+    /// `ellipsis-horizontal` — available in Filled U+E0A8, Filled/Mini U+E0A8, Outline U+E0A8, Filled/Tiny U+E0A2
     EllipsisHorizontal,
+    /// `ellipsis-horizontal-circle` — available in Filled U+E0A7, Filled/Mini U+E0A7, Outline U+E0A7, Filled/Tiny U+E0A1
     EllipsisHorizontalCircle,
+    /// `ellipsis-vertical` — available in Filled U+E0A9, Filled/Mini U+E0A9, Outline U+E0A9, Filled/Tiny U+E0A3
     EllipsisVertical,
+    /// `envelope` — available in Filled U+E0AB, Filled/Mini U+E0AB, Outline U+E0AB, Filled/Tiny U+E0A5
     Envelope,
+    /// `envelope-open` — available in Filled U+E0AA, Filled/Mini U+E0AA, Outline U+E0AA, Filled/Tiny U+E0A4
     EnvelopeOpen,
+    /// `equals` — available in Filled U+E0AC, Filled/Mini U+E0AC, Outline U+E0AC, Filled/Tiny U+E0A6
     Equals,
+    /// `exclamation-circle` — available in Filled U+E0AD, Filled/Mini U+E0AD, Outline U+E0AD, Filled/Tiny U+E0A7
     ExclamationCircle,
+    /// `exclamation-triangle` — available in Filled U+E0AE, Filled/Mini U+E0AE, Outline U+E0AE, Filled/Tiny U+E0A8
     ExclamationTriangle,
+    /// `eye` — available in Filled U+E0B1, Filled/Mini U+E0B1, Outline U+E0B1, Filled/Tiny U+E0AB
     Eye,
+    /// `eye-dropper` — available in Filled U+E0AF, Filled/Mini U+E0AF, Outline U+E0AF, Filled/Tiny U+E0A9
     EyeDropper,
+    /// `eye-slash` — available in Filled U+E0B0, Filled/Mini U+E0B0, Outline U+E0B0, Filled/Tiny U+E0AA
     EyeSlash,
+    /// `face-frown` — available in Filled U+E0B2, Filled/Mini U+E0B2, Outline U+E0B2, Filled/Tiny U+E0AC
     FaceFrown,
+    /// `face-smile` — available in Filled U+E0B3, Filled/Mini U+E0B3, Outline U+E0B3, Filled/Tiny U+E0AD
     FaceSmile,
+    /// `film` — available in Filled U+E0B4, Filled/Mini U+E0B4, Outline U+E0B4, Filled/Tiny U+E0AE
     Film,
+    /// `finger-print` — available in Filled U+E0B5, Filled/Mini U+E0B5, Outline U+E0B5, Filled/Tiny U+E0AF
     FingerPrint,
+    /// `fire` — available in Filled U+E0B6, Filled/Mini U+E0B6, Outline U+E0B6, Filled/Tiny U+E0B0
     Fire,
+    /// `flag` — available in Filled U+E0B7, Filled/Mini U+E0B7, Outline U+E0B7, Filled/Tiny U+E0B1
     Flag,
+    /// `folder` — available in Filled U+E0BC, Filled/Mini U+E0BC, Outline U+E0BC, Filled/Tiny U+E0B6
     Folder,
+    /// `folder-arrow-down` — available in Filled U+E0B8, Filled/Mini U+E0B8, Outline U+E0B8, Filled/Tiny U+E0B2
     FolderArrowDown,
+    /// `folder-minus` — available in Filled U+E0B9, Filled/Mini U+E0B9, Outline U+E0B9, Filled/Tiny U+E0B3
     FolderMinus,
+    /// `folder-open` — available in Filled U+E0BA, Filled/Mini U+E0BA, Outline U+E0BA, Filled/Tiny U+E0B4
     FolderOpen,
+    /// `folder-plus` — available in Filled U+E0BB, Filled/Mini U+E0BB, Outline U+E0BB, Filled/Tiny U+E0B5
     FolderPlus,
+    /// `forward` — available in Filled U+E0BD, Filled/Mini U+E0BD, Outline U+E0BD, Filled/Tiny U+E0B7
     Forward,
+    /// `funnel` — available in Filled U+E0BE, Filled/Mini U+E0BE, Outline U+E0BE, Filled/Tiny U+E0B8
     Funnel,
+    /// `gif` — available in Filled U+E0BF, Filled/Mini U+E0BF, Outline U+E0BF, Filled/Tiny U+E0B9
     Gif,
+    /// `gift` — available in Filled U+E0C1, Filled/Mini U+E0C1, Outline U+E0C1, Filled/Tiny U+E0BB
     Gift,
+    /// `gift-top` — available in Filled U+E0C0, Filled/Mini U+E0C0, Outline U+E0C0, Filled/Tiny U+E0BA
     GiftTop,
+    /// `globe-alt` — available in Filled U+E0C2, Filled/Mini U+E0C2, Outline U+E0C2, Filled/Tiny U+E0BC
     GlobeAlt,
+    /// `globe-americas` — available in Filled U+E0C3, Filled/Mini U+E0C3, Outline U+E0C3, Filled/Tiny U+E0BD
     GlobeAmericas,
+    /// `globe-asia-australia` — available in Filled U+E0C4, Filled/Mini U+E0C4, Outline U+E0C4, Filled/Tiny U+E0BE
     GlobeAsiaAustralia,
+    /// `globe-europe-africa` — available in Filled U+E0C5, Filled/Mini U+E0C5, Outline U+E0C5, Filled/Tiny U+E0BF
     GlobeEuropeAfrica,
+    /// `h1` — available in Filled U+E0C6, Filled/Mini U+E0C6, Outline U+E0C6, Filled/Tiny U+E0C0
     H1,
+    /// `h2` — available in Filled U+E0C7, Filled/Mini U+E0C7, Outline U+E0C7, Filled/Tiny U+E0C1
     H2,
+    /// `h3` — available in Filled U+E0C8, Filled/Mini U+E0C8, Outline U+E0C8, Filled/Tiny U+E0C2
     H3,
+    /// `hand-raised` — available in Filled U+E0C9, Filled/Mini U+E0C9, Outline U+E0C9, Filled/Tiny U+E0C3
     HandRaised,
+    /// `hand-thumb-down` — available in Filled U+E0CA, Filled/Mini U+E0CA, Outline U+E0CA, Filled/Tiny U+E0C4
     HandThumbDown,
+    /// `hand-thumb-up` — available in Filled U+E0CB, Filled/Mini U+E0CB, Outline U+E0CB, Filled/Tiny U+E0C5
     HandThumbUp,
+    /// `hashtag` — available in Filled U+E0CC, Filled/Mini U+E0CC, Outline U+E0CC, Filled/Tiny U+E0C6
     Hashtag,
+    /// `heart` — available in Filled U+E0CD, Filled/Mini U+E0CD, Outline U+E0CD, Filled/Tiny U+E0C7
     Heart,
+    /// `home` — available in Filled U+E0CF, Filled/Mini U+E0CF, Outline U+E0CF, Filled/Tiny U+E0C9
     Home,
+    /// `home-modern` — available in Filled U+E0CE, Filled/Mini U+E0CE, Outline U+E0CE, Filled/Tiny U+E0C8
     HomeModern,
+    /// `identification` — available in Filled U+E0D0, Filled/Mini U+E0D0, Outline U+E0D0, Filled/Tiny U+E0CA
     Identification,
+    /// `inbox` — available in Filled U+E0D3, Filled/Mini U+E0D3, Outline U+E0D3, Filled/Tiny U+E0CD
     Inbox,
+    /// `inbox-arrow-down` — available in Filled U+E0D1, Filled/Mini U+E0D1, Outline U+E0D1, Filled/Tiny U+E0CB
     InboxArrowDown,
+    /// `inbox-stack` — available in Filled U+E0D2, Filled/Mini U+E0D2, Outline U+E0D2, Filled/Tiny U+E0CC
     InboxStack,
+    /// `information-circle` — available in Filled U+E0D4, Filled/Mini U+E0D4, Outline U+E0D4, Filled/Tiny U+E0CE
     InformationCircle,
+    /// `italic` — available in Filled U+E0D5, Filled/Mini U+E0D5, Outline U+E0D5, Filled/Tiny U+E0CF
     Italic,
+    /// `key` — available in Filled U+E0D6, Filled/Mini U+E0D6, Outline U+E0D6, Filled/Tiny U+E0D0
     Key,
+    /// `language` — available in Filled U+E0D7, Filled/Mini U+E0D7, Outline U+E0D7, Filled/Tiny U+E0D1
     Language,
+    /// `lifebuoy` — available in Filled U+E0D8, Filled/Mini U+E0D8, Outline U+E0D8, Filled/Tiny U+E0D2
     Lifebuoy,
+    /// `light-bulb` — available in Filled U+E0D9, Filled/Mini U+E0D9, Outline U+E0D9, Filled/Tiny U+E0D3
     LightBulb,
+    /// `link` — available in Filled U+E0DB, Filled/Mini U+E0DB, Outline U+E0DB, Filled/Tiny U+E0D5
     Link,
+    /// `link-slash` — available in Filled U+E0DA, Filled/Mini U+E0DA, Outline U+E0DA, Filled/Tiny U+E0D4
     LinkSlash,
+    /// `list-bullet` — available in Filled U+E0DC, Filled/Mini U+E0DC, Outline U+E0DC, Filled/Tiny U+E0D6
     ListBullet,
+    /// `lock-closed` — available in Filled U+E0DD, Filled/Mini U+E0DD, Outline U+E0DD, Filled/Tiny U+E0D7
     LockClosed,
+    /// `lock-open` — available in Filled U+E0DE, Filled/Mini U+E0DE, Outline U+E0DE, Filled/Tiny U+E0D8
     LockOpen,
+    /// `magnifying-glass` — available in Filled U+E0E2, Filled/Mini U+E0E2, Outline U+E0E2, Filled/Tiny U+E0DC
     MagnifyingGlass,
+    /// `magnifying-glass-circle` — available in Filled U+E0DF, Filled/Mini U+E0DF, Outline U+E0DF, Filled/Tiny U+E0D9
     MagnifyingGlassCircle,
+    /// `magnifying-glass-minus` — available in Filled U+E0E0, Filled/Mini U+E0E0, Outline U+E0E0, Filled/Tiny U+E0DA
     MagnifyingGlassMinus,
+    /// `magnifying-glass-plus` — available in Filled U+E0E1, Filled/Mini U+E0E1, Outline U+E0E1, Filled/Tiny U+E0DB
     MagnifyingGlassPlus,
+    /// `map` — available in Filled U+E0E4, Filled/Mini U+E0E4, Outline U+E0E4, Filled/Tiny U+E0DE
     Map,
+    /// `map-pin` — available in Filled U+E0E3, Filled/Mini U+E0E3, Outline U+E0E3, Filled/Tiny U+E0DD
     MapPin,
+    /// `megaphone` — available in Filled U+E0E5, Filled/Mini U+E0E5, Outline U+E0E5, Filled/Tiny U+E0DF
     Megaphone,
+    /// `microphone` — available in Filled U+E0E6, Filled/Mini U+E0E6, Outline U+E0E6, Filled/Tiny U+E0E0
     Microphone,
+    /// `minus` — available in Filled U+E0E9, Filled/Mini U+E0E9, Outline U+E0E9, Filled/Tiny U+E0E2
     Minus,
+    /// `minus-circle` — available in Filled U+E0E7, Filled/Mini U+E0E7, Outline U+E0E7, Filled/Tiny U+E0E1
     MinusCircle,
+    /// `minus-small` — U+E0E8 (available in Filled, Filled/Mini, Outline)
     MinusSmall,
+    /// `moon` — available in Filled U+E0EA, Filled/Mini U+E0EA, Outline U+E0EA, Filled/Tiny U+E0E3
     Moon,
+    /// `musical-note` — available in Filled U+E0EB, Filled/Mini U+E0EB, Outline U+E0EB, Filled/Tiny U+E0E4
     MusicalNote,
+    /// `newspaper` — available in Filled U+E0EC, Filled/Mini U+E0EC, Outline U+E0EC, Filled/Tiny U+E0E5
     Newspaper,
+    /// `no-symbol` — available in Filled U+E0ED, Filled/Mini U+E0ED, Outline U+E0ED, Filled/Tiny U+E0E6
     NoSymbol,
+    /// `numbered-list` — available in Filled U+E0EE, Filled/Mini U+E0EE, Outline U+E0EE, Filled/Tiny U+E0E7
     NumberedList,
+    /// `paint-brush` — available in Filled U+E0EF, Filled/Mini U+E0EF, Outline U+E0EF, Filled/Tiny U+E0E8
     PaintBrush,
+    /// `paper-airplane` — available in Filled U+E0F0, Filled/Mini U+E0F0, Outline U+E0F0, Filled/Tiny U+E0E9
     PaperAirplane,
+    /// `paper-clip` — available in Filled U+E0F1, Filled/Mini U+E0F1, Outline U+E0F1, Filled/Tiny U+E0EA
     PaperClip,
+    /// `pause` — available in Filled U+E0F3, Filled/Mini U+E0F3, Outline U+E0F3, Filled/Tiny U+E0EC
     Pause,
+    /// `pause-circle` — available in Filled U+E0F2, Filled/Mini U+E0F2, Outline U+E0F2, Filled/Tiny U+E0EB
     PauseCircle,
+    /// `pencil` — available in Filled U+E0F5, Filled/Mini U+E0F5, Outline U+E0F5, Filled/Tiny U+E0EE
     Pencil,
+    /// `pencil-square` — available in Filled U+E0F4, Filled/Mini U+E0F4, Outline U+E0F4, Filled/Tiny U+E0ED
     PencilSquare,
+    /// `percent-badge` — available in Filled U+E0F6, Filled/Mini U+E0F6, Outline U+E0F6, Filled/Tiny U+E0EF
     PercentBadge,
+    /// `phone` — available in Filled U+E0FA, Filled/Mini U+E0FA, Outline U+E0FA, Filled/Tiny U+E0F3
     Phone,
+    /// `phone-arrow-down-left` — available in Filled U+E0F7, Filled/Mini U+E0F7, Outline U+E0F7, Filled/Tiny U+E0F0
     PhoneArrowDownLeft,
+    /// `phone-arrow-up-right` — available in Filled U+E0F8, Filled/Mini U+E0F8, Outline U+E0F8, Filled/Tiny U+E0F1
     PhoneArrowUpRight,
+    /// `phone-x-mark` — available in Filled U+E0F9, Filled/Mini U+E0F9, Outline U+E0F9, Filled/Tiny U+E0F2
     PhoneXMark,
+    /// `photo` — available in Filled U+E0FB, Filled/Mini U+E0FB, Outline U+E0FB, Filled/Tiny U+E0F4
     Photo,
+    /// `play` — available in Filled U+E0FE, Filled/Mini U+E0FE, Outline U+E0FE, Filled/Tiny U+E0F7
     Play,
+    /// `play-circle` — available in Filled U+E0FC, Filled/Mini U+E0FC, Outline U+E0FC, Filled/Tiny U+E0F5
     PlayCircle,
+    /// `play-pause` — available in Filled U+E0FD, Filled/Mini U+E0FD, Outline U+E0FD, Filled/Tiny U+E0F6
     PlayPause,
+    /// `plus` — available in Filled U+E101, Filled/Mini U+E101, Outline U+E101, Filled/Tiny U+E0F9
     Plus,
+    /// `plus-circle` — available in Filled U+E0FF, Filled/Mini U+E0FF, Outline U+E0FF, Filled/Tiny U+E0F8
     PlusCircle,
+    /// `plus-small` — U+E100 (available in Filled, Filled/Mini, Outline)
     PlusSmall,
+    /// `power` — available in Filled U+E102, Filled/Mini U+E102, Outline U+E102, Filled/Tiny U+E0FA
     Power,
+    /// `presentation-chart-bar` — available in Filled U+E103, Filled/Mini U+E103, Outline U+E103, Filled/Tiny U+E0FB
     PresentationChartBar,
+    /// `presentation-chart-line` — available in Filled U+E104, Filled/Mini U+E104, Outline U+E104, Filled/Tiny U+E0FC
     PresentationChartLine,
+    /// `printer` — available in Filled U+E105, Filled/Mini U+E105, Outline U+E105, Filled/Tiny U+E0FD
     Printer,
+    /// `puzzle-piece` — available in Filled U+E106, Filled/Mini U+E106, Outline U+E106, Filled/Tiny U+E0FE
     PuzzlePiece,
+    /// `qr-code` — available in Filled U+E107, Filled/Mini U+E107, Outline U+E107, Filled/Tiny U+E0FF
     QrCode,
+    /// `question-mark-circle` — available in Filled U+E108, Filled/Mini U+E108, Outline U+E108, Filled/Tiny U+E100
     QuestionMarkCircle,
+    /// `queue-list` — available in Filled U+E109, Filled/Mini U+E109, Outline U+E109, Filled/Tiny U+E101
     QueueList,
+    /// `radio` — available in Filled U+E10A, Filled/Mini U+E10A, Outline U+E10A, Filled/Tiny U+E102
     Radio,
+    /// `receipt-percent` — available in Filled U+E10B, Filled/Mini U+E10B, Outline U+E10B, Filled/Tiny U+E103
     ReceiptPercent,
+    /// `receipt-refund` — available in Filled U+E10C, Filled/Mini U+E10C, Outline U+E10C, Filled/Tiny U+E104
     ReceiptRefund,
+    /// `rectangle-group` — available in Filled U+E10D, Filled/Mini U+E10D, Outline U+E10D, Filled/Tiny U+E105
     RectangleGroup,
+    /// `rectangle-stack` — available in Filled U+E10E, Filled/Mini U+E10E, Outline U+E10E, Filled/Tiny U+E106
     RectangleStack,
+    /// `rocket-launch` — available in Filled U+E10F, Filled/Mini U+E10F, Outline U+E10F, Filled/Tiny U+E107
     RocketLaunch,
+    /// `rss` — available in Filled U+E110, Filled/Mini U+E110, Outline U+E110, Filled/Tiny U+E108
     Rss,
+    /// `scale` — available in Filled U+E111, Filled/Mini U+E111, Outline U+E111, Filled/Tiny U+E109
     Scale,
+    /// `scissors` — available in Filled U+E112, Filled/Mini U+E112, Outline U+E112, Filled/Tiny U+E10A
     Scissors,
+    /// `server` — available in Filled U+E114, Filled/Mini U+E114, Outline U+E114, Filled/Tiny U+E10C
     Server,
+    /// `server-stack` — available in Filled U+E113, Filled/Mini U+E113, Outline U+E113, Filled/Tiny U+E10B
     ServerStack,
+    /// `share` — available in Filled U+E115, Filled/Mini U+E115, Outline U+E115, Filled/Tiny U+E10D
     Share,
+    /// `shield-check` — available in Filled U+E116, Filled/Mini U+E116, Outline U+E116, Filled/Tiny U+E10E
     ShieldCheck,
+    /// `shield-exclamation` — available in Filled U+E117, Filled/Mini U+E117, Outline U+E117, Filled/Tiny U+E10F
     ShieldExclamation,
+    /// `shopping-bag` — available in Filled U+E118, Filled/Mini U+E118, Outline U+E118, Filled/Tiny U+E110
     ShoppingBag,
+    /// `shopping-cart` — available in Filled U+E119, Filled/Mini U+E119, Outline U+E119, Filled/Tiny U+E111
     ShoppingCart,
+    /// `signal` — available in Filled U+E11B, Filled/Mini U+E11B, Outline U+E11B, Filled/Tiny U+E113
     Signal,
+    /// `signal-slash` — available in Filled U+E11A, Filled/Mini U+E11A, Outline U+E11A, Filled/Tiny U+E112
     SignalSlash,
+    /// `slash` — available in Filled U+E11C, Filled/Mini U+E11C, Outline U+E11C, Filled/Tiny U+E114
     Slash,
+    /// `sparkles` — available in Filled U+E11D, Filled/Mini U+E11D, Outline U+E11D, Filled/Tiny U+E115
     Sparkles,
+    /// `speaker-wave` — available in Filled U+E11E, Filled/Mini U+E11E, Outline U+E11E, Filled/Tiny U+E116
     SpeakerWave,
+    /// `speaker-x-mark` — available in Filled U+E11F, Filled/Mini U+E11F, Outline U+E11F, Filled/Tiny U+E117
     SpeakerXMark,
+    /// `square-2-stack` — available in Filled U+E120, Filled/Mini U+E120, Outline U+E120, Filled/Tiny U+E118
     Square2Stack,
+    /// `square-3-stack-3d` — available in Filled U+E121, Filled/Mini U+E121, Outline U+E121, Filled/Tiny U+E119
     Square3Stack3d,
+    /// `squares-2x2` — available in Filled U+E122, Filled/Mini U+E122, Outline U+E122, Filled/Tiny U+E11A
     Squares2x2,
+    /// `squares-plus` — available in Filled U+E123, Filled/Mini U+E123, Outline U+E123, Filled/Tiny U+E11B
     SquaresPlus,
+    /// `star` — available in Filled U+E124, Filled/Mini U+E124, Outline U+E124, Filled/Tiny U+E11C
     Star,
+    /// `stop` — available in Filled U+E126, Filled/Mini U+E126, Outline U+E126, Filled/Tiny U+E11E
     Stop,
+    /// `stop-circle` — available in Filled U+E125, Filled/Mini U+E125, Outline U+E125, Filled/Tiny U+E11D
     StopCircle,
+    /// `strikethrough` — available in Filled U+E127, Filled/Mini U+E127, Outline U+E127, Filled/Tiny U+E11F
     Strikethrough,
+    /// `sun` — available in Filled U+E128, Filled/Mini U+E128, Outline U+E128, Filled/Tiny U+E120
     Sun,
+    /// `swatch` — available in Filled U+E129, Filled/Mini U+E129, Outline U+E129, Filled/Tiny U+E121
     Swatch,
+    /// `table-cells` — available in Filled U+E12A, Filled/Mini U+E12A, Outline U+E12A, Filled/Tiny U+E122
     TableCells,
+    /// `tag` — available in Filled U+E12B, Filled/Mini U+E12B, Outline U+E12B, Filled/Tiny U+E123
     Tag,
+    /// `ticket` — available in Filled U+E12C, Filled/Mini U+E12C, Outline U+E12C, Filled/Tiny U+E124
     Ticket,
+    /// `trash` — available in Filled U+E12D, Filled/Mini U+E12D, Outline U+E12D, Filled/Tiny U+E125
     Trash,
+    /// `trophy` — available in Filled U+E12E, Filled/Mini U+E12E, Outline U+E12E, Filled/Tiny U+E126
     Trophy,
+    /// `truck` — available in Filled U+E12F, Filled/Mini U+E12F, Outline U+E12F, Filled/Tiny U+E127
     Truck,
+    /// `tv` — available in Filled U+E130, Filled/Mini U+E130, Outline U+E130, Filled/Tiny U+E128
     Tv,
+    /// `underline` — available in Filled U+E131, Filled/Mini U+E131, Outline U+E131, Filled/Tiny U+E129
     Underline,
+    /// `user` — available in Filled U+E136, Filled/Mini U+E136, Outline U+E136, Filled/Tiny U+E12E
     User,
+    /// `user-circle` — available in Filled U+E132, Filled/Mini U+E132, Outline U+E132, Filled/Tiny U+E12A
     UserCircle,
+    /// `user-group` — available in Filled U+E133, Filled/Mini U+E133, Outline U+E133, Filled/Tiny U+E12B
     UserGroup,
+    /// `user-minus` — available in Filled U+E134, Filled/Mini U+E134, Outline U+E134, Filled/Tiny U+E12C
     UserMinus,
+    /// `user-plus` — available in Filled U+E135, Filled/Mini U+E135, Outline U+E135, Filled/Tiny U+E12D
     UserPlus,
+    /// `users` — available in Filled U+E137, Filled/Mini U+E137, Outline U+E137, Filled/Tiny U+E12F
     Users,
+    /// `variable` — available in Filled U+E138, Filled/Mini U+E138, Outline U+E138, Filled/Tiny U+E130
     Variable,
+    /// `video-camera` — available in Filled U+E13A, Filled/Mini U+E13A, Outline U+E13A, Filled/Tiny U+E132
     VideoCamera,
+    /// `video-camera-slash` — available in Filled U+E139, Filled/Mini U+E139, Outline U+E139, Filled/Tiny U+E131
     VideoCameraSlash,
+    /// `view-columns` — available in Filled U+E13B, Filled/Mini U+E13B, Outline U+E13B, Filled/Tiny U+E133
     ViewColumns,
+    /// `viewfinder-circle` — available in Filled U+E13C, Filled/Mini U+E13C, Outline U+E13C, Filled/Tiny U+E134
     ViewfinderCircle,
+    /// `wallet` — available in Filled U+E13D, Filled/Mini U+E13D, Outline U+E13D, Filled/Tiny U+E135
     Wallet,
+    /// `wifi` — available in Filled U+E13E, Filled/Mini U+E13E, Outline U+E13E, Filled/Tiny U+E136
     Wifi,
+    /// `window` — available in Filled U+E13F, Filled/Mini U+E13F, Outline U+E13F, Filled/Tiny U+E137
     Window,
+    /// `wrench` — available in Filled U+E141, Filled/Mini U+E141, Outline U+E141, Filled/Tiny U+E139
     Wrench,
+    /// `wrench-screwdriver` — available in Filled U+E140, Filled/Mini U+E140, Outline U+E140, Filled/Tiny U+E138
     WrenchScrewdriver,
+    /// `x-circle` — available in Filled U+E142, Filled/Mini U+E142, Outline U+E142, Filled/Tiny U+E13A
     XCircle,
+    /// `x-mark` — available in Filled U+E143, Filled/Mini U+E143, Outline U+E143, Filled/Tiny U+E13B
     XMark,
 }
 
+/// An icon's available `(style, size)` pairs and its codepoint in each variant.
+type IconVariantTables = (&'static [(Style, Size)], &'static [(VariantKey, u32)]);
+
 impl Icon {
+    pub const ALL: &[Icon] = &[
+        Icon::AcademicCap,
+        Icon::AdjustmentsHorizontal,
+        Icon::AdjustmentsVertical,
+        Icon::ArchiveBox,
+        Icon::ArchiveBoxArrowDown,
+        Icon::ArchiveBoxXMark,
+        Icon::ArrowDown,
+        Icon::ArrowDownCircle,
+        Icon::ArrowDownLeft,
+        Icon::ArrowDownOnSquare,
+        Icon::ArrowDownOnSquareStack,
+        Icon::ArrowDownRight,
+        Icon::ArrowDownTray,
+        Icon::ArrowLeft,
+        Icon::ArrowLeftCircle,
+        Icon::ArrowLeftEndOnRectangle,
+        Icon::ArrowLeftOnRectangle,
+        Icon::ArrowLeftStartOnRectangle,
+        Icon::ArrowLongDown,
+        Icon::ArrowLongLeft,
+        Icon::ArrowLongRight,
+        Icon::ArrowLongUp,
+        Icon::ArrowPath,
+        Icon::ArrowPathRoundedSquare,
+        Icon::ArrowRight,
+        Icon::ArrowRightCircle,
+        Icon::ArrowRightEndOnRectangle,
+        Icon::ArrowRightOnRectangle,
+        Icon::ArrowRightStartOnRectangle,
+        Icon::ArrowSmallDown,
+        Icon::ArrowSmallLeft,
+        Icon::ArrowSmallRight,
+        Icon::ArrowSmallUp,
+        Icon::ArrowTopRightOnSquare,
+        Icon::ArrowTrendingDown,
+        Icon::ArrowTrendingUp,
+        Icon::ArrowTurnDownLeft,
+        Icon::ArrowTurnDownRight,
+        Icon::ArrowTurnLeftDown,
+        Icon::ArrowTurnLeftUp,
+        Icon::ArrowTurnRightDown,
+        Icon::ArrowTurnRightUp,
+        Icon::ArrowTurnUpLeft,
+        Icon::ArrowTurnUpRight,
+        Icon::ArrowUp,
+        Icon::ArrowUpCircle,
+        Icon::ArrowUpLeft,
+        Icon::ArrowUpOnSquare,
+        Icon::ArrowUpOnSquareStack,
+        Icon::ArrowUpRight,
+        Icon::ArrowUpTray,
+        Icon::ArrowUturnDown,
+        Icon::ArrowUturnLeft,
+        Icon::ArrowUturnRight,
+        Icon::ArrowUturnUp,
+        Icon::ArrowsPointingIn,
+        Icon::ArrowsPointingOut,
+        Icon::ArrowsRightLeft,
+        Icon::ArrowsUpDown,
+        Icon::AtSymbol,
+        Icon::Backspace,
+        Icon::Backward,
+        Icon::Banknotes,
+        Icon::Bars2,
+        Icon::Bars3,
+        Icon::Bars3BottomLeft,
+        Icon::Bars3BottomRight,
+        Icon::Bars3CenterLeft,
+        Icon::Bars4,
+        Icon::BarsArrowDown,
+        Icon::BarsArrowUp,
+        Icon::Battery0,
+        Icon::Battery100,
+        Icon::Battery50,
+        Icon::Beaker,
+        Icon::Bell,
+        Icon::BellAlert,
+        Icon::BellSlash,
+        Icon::BellSnooze,
+        Icon::Bold,
+        Icon::Bolt,
+        Icon::BoltSlash,
+        Icon::BookOpen,
+        Icon::Bookmark,
+        Icon::BookmarkSlash,
+        Icon::BookmarkSquare,
+        Icon::Briefcase,
+        Icon::BugAnt,
+        Icon::BuildingLibrary,
+        Icon::BuildingOffice,
+        Icon::BuildingOffice2,
+        Icon::BuildingStorefront,
+        Icon::Cake,
+        Icon::Calculator,
+        Icon::Calendar,
+        Icon::CalendarDateRange,
+        Icon::CalendarDays,
+        Icon::Camera,
+        Icon::ChartBar,
+        Icon::ChartBarSquare,
+        Icon::ChartPie,
+        Icon::ChatBubbleBottomCenter,
+        Icon::ChatBubbleBottomCenterText,
+        Icon::ChatBubbleLeft,
+        Icon::ChatBubbleLeftEllipsis,
+        Icon::ChatBubbleLeftRight,
+        Icon::ChatBubbleOvalLeft,
+        Icon::ChatBubbleOvalLeftEllipsis,
+        Icon::Check,
+        Icon::CheckBadge,
+        Icon::CheckCircle,
+        Icon::ChevronDoubleDown,
+        Icon::ChevronDoubleLeft,
+        Icon::ChevronDoubleRight,
+        Icon::ChevronDoubleUp,
+        Icon::ChevronDown,
+        Icon::ChevronLeft,
+        Icon::ChevronRight,
+        Icon::ChevronUp,
+        Icon::ChevronUpDown,
+        Icon::CircleStack,
+        Icon::Clipboard,
+        Icon::ClipboardDocument,
+        Icon::ClipboardDocumentCheck,
+        Icon::ClipboardDocumentList,
+        Icon::Clock,
+        Icon::Cloud,
+        Icon::CloudArrowDown,
+        Icon::CloudArrowUp,
+        Icon::CodeBracket,
+        Icon::CodeBracketSquare,
+        Icon::Cog,
+        Icon::Cog6Tooth,
+        Icon::Cog8Tooth,
+        Icon::CommandLine,
+        Icon::ComputerDesktop,
+        Icon::CpuChip,
+        Icon::CreditCard,
+        Icon::Cube,
+        Icon::CubeTransparent,
+        Icon::CurrencyBangladeshi,
+        Icon::CurrencyDollar,
+        Icon::CurrencyEuro,
+        Icon::CurrencyPound,
+        Icon::CurrencyRupee,
+        Icon::CurrencyYen,
+        Icon::CursorArrowRays,
+        Icon::CursorArrowRipple,
+        Icon::DevicePhoneMobile,
+        Icon::DeviceTablet,
+        Icon::Divide,
+        Icon::Document,
+        Icon::DocumentArrowDown,
+        Icon::DocumentArrowUp,
+        Icon::DocumentChartBar,
+        Icon::DocumentCheck,
+        Icon::DocumentCurrencyBangladeshi,
+        Icon::DocumentCurrencyDollar,
+        Icon::DocumentCurrencyEuro,
+        Icon::DocumentCurrencyPound,
+        Icon::DocumentCurrencyRupee,
+        Icon::DocumentCurrencyYen,
+        Icon::DocumentDuplicate,
+        Icon::DocumentMagnifyingGlass,
+        Icon::DocumentMinus,
+        Icon::DocumentPlus,
+        Icon::DocumentText,
+        Icon::EllipsisHorizontal,
+        Icon::EllipsisHorizontalCircle,
+        Icon::EllipsisVertical,
+        Icon::Envelope,
+        Icon::EnvelopeOpen,
+        Icon::Equals,
+        Icon::ExclamationCircle,
+        Icon::ExclamationTriangle,
+        Icon::Eye,
+        Icon::EyeDropper,
+        Icon::EyeSlash,
+        Icon::FaceFrown,
+        Icon::FaceSmile,
+        Icon::Film,
+        Icon::FingerPrint,
+        Icon::Fire,
+        Icon::Flag,
+        Icon::Folder,
+        Icon::FolderArrowDown,
+        Icon::FolderMinus,
+        Icon::FolderOpen,
+        Icon::FolderPlus,
+        Icon::Forward,
+        Icon::Funnel,
+        Icon::Gif,
+        Icon::Gift,
+        Icon::GiftTop,
+        Icon::GlobeAlt,
+        Icon::GlobeAmericas,
+        Icon::GlobeAsiaAustralia,
+        Icon::GlobeEuropeAfrica,
+        Icon::H1,
+        Icon::H2,
+        Icon::H3,
+        Icon::HandRaised,
+        Icon::HandThumbDown,
+        Icon::HandThumbUp,
+        Icon::Hashtag,
+        Icon::Heart,
+        Icon::Home,
+        Icon::HomeModern,
+        Icon::Identification,
+        Icon::Inbox,
+        Icon::InboxArrowDown,
+        Icon::InboxStack,
+        Icon::InformationCircle,
+        Icon::Italic,
+        Icon::Key,
+        Icon::Language,
+        Icon::Lifebuoy,
+        Icon::LightBulb,
+        Icon::Link,
+        Icon::LinkSlash,
+        Icon::ListBullet,
+        Icon::LockClosed,
+        Icon::LockOpen,
+        Icon::MagnifyingGlass,
+        Icon::MagnifyingGlassCircle,
+        Icon::MagnifyingGlassMinus,
+        Icon::MagnifyingGlassPlus,
+        Icon::Map,
+        Icon::MapPin,
+        Icon::Megaphone,
+        Icon::Microphone,
+        Icon::Minus,
+        Icon::MinusCircle,
+        Icon::MinusSmall,
+        Icon::Moon,
+        Icon::MusicalNote,
+        Icon::Newspaper,
+        Icon::NoSymbol,
+        Icon::NumberedList,
+        Icon::PaintBrush,
+        Icon::PaperAirplane,
+        Icon::PaperClip,
+        Icon::Pause,
+        Icon::PauseCircle,
+        Icon::Pencil,
+        Icon::PencilSquare,
+        Icon::PercentBadge,
+        Icon::Phone,
+        Icon::PhoneArrowDownLeft,
+        Icon::PhoneArrowUpRight,
+        Icon::PhoneXMark,
+        Icon::Photo,
+        Icon::Play,
+        Icon::PlayCircle,
+        Icon::PlayPause,
+        Icon::Plus,
+        Icon::PlusCircle,
+        Icon::PlusSmall,
+        Icon::Power,
+        Icon::PresentationChartBar,
+        Icon::PresentationChartLine,
+        Icon::Printer,
+        Icon::PuzzlePiece,
+        Icon::QrCode,
+        Icon::QuestionMarkCircle,
+        Icon::QueueList,
+        Icon::Radio,
+        Icon::ReceiptPercent,
+        Icon::ReceiptRefund,
+        Icon::RectangleGroup,
+        Icon::RectangleStack,
+        Icon::RocketLaunch,
+        Icon::Rss,
+        Icon::Scale,
+        Icon::Scissors,
+        Icon::Server,
+        Icon::ServerStack,
+        Icon::Share,
+        Icon::ShieldCheck,
+        Icon::ShieldExclamation,
+        Icon::ShoppingBag,
+        Icon::ShoppingCart,
+        Icon::Signal,
+        Icon::SignalSlash,
+        Icon::Slash,
+        Icon::Sparkles,
+        Icon::SpeakerWave,
+        Icon::SpeakerXMark,
+        Icon::Square2Stack,
+        Icon::Square3Stack3d,
+        Icon::Squares2x2,
+        Icon::SquaresPlus,
+        Icon::Star,
+        Icon::Stop,
+        Icon::StopCircle,
+        Icon::Strikethrough,
+        Icon::Sun,
+        Icon::Swatch,
+        Icon::TableCells,
+        Icon::Tag,
+        Icon::Ticket,
+        Icon::Trash,
+        Icon::Trophy,
+        Icon::Truck,
+        Icon::Tv,
+        Icon::Underline,
+        Icon::User,
+        Icon::UserCircle,
+        Icon::UserGroup,
+        Icon::UserMinus,
+        Icon::UserPlus,
+        Icon::Users,
+        Icon::Variable,
+        Icon::VideoCamera,
+        Icon::VideoCameraSlash,
+        Icon::ViewColumns,
+        Icon::ViewfinderCircle,
+        Icon::Wallet,
+        Icon::Wifi,
+        Icon::Window,
+        Icon::Wrench,
+        Icon::WrenchScrewdriver,
+        Icon::XCircle,
+        Icon::XMark,
+    ];
+
     pub fn name(self) -> &'static str {
         match self {
             Icon::AcademicCap => "academic-cap",
@@ -734,31 +1444,825 @@ impl Icon {
         }
     }
 
+    /// Resolves this icon at a `(style, size)` known to exist, e.g. one listed in its docs.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the variant is unavailable; use [`Icon::try_icon`] to handle that case.
     pub fn icon(self, style: Style, size: Size) -> IconRef {
-        let name = self.name();
-        let available = icon_available(name).unwrap_or(&[]);
-        if !available.contains(&(style, size)) {
-            panic!(
-                "Icon '{}' is not available in {:?}/{:?}. Available: {:?}",
-                name, style, size, available
-            );
+        match self.try_icon(style, size) {
+            Ok(icon) => icon,
+            Err(err) => panic!(
+                "Icon '{}' is not available in {:?}/{:?}: {:?}",
+                self.name(),
+                style,
+                size,
+                err
+            ),
         }
-        let variant = variant_info(style, size).unwrap_or_else(|| {
-            panic!(
-                "Variant {:?}/{:?} is not available for pack {}",
-                style, size, PACK_ID
-            )
-        });
-        let codepoint = icon_codepoint(name, variant.key).unwrap_or_else(|| {
-            panic!("Icon '{}' is not available in {:?}/{:?}", name, style, size)
-        });
-        IconRef {
-            family: variant.family,
-            codepoint,
+    }
+
+    /// Resolves this icon at `(style, size)` without a name lookup, returning the same errors
+    /// as [`crate::try_icon`].
+    pub fn try_icon(self, style: Style, size: Size) -> Result<IconRef, crate::core::IconError> {
+        let (available, codepoints) = self.variant_tables();
+        let key = VariantKey { style, size };
+        crate::core::resolve_icon(
+            PACK_ID,
+            self.name(),
+            style,
+            size,
+            Some(available),
+            variant_info(style, size).map(|info| info.family),
+            codepoints
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|&(_, cp)| cp),
+        )
+        .map_err(crate::core::IconError::from)
+    }
+
+    fn variant_tables(self) -> IconVariantTables {
+        match self {
+            Icon::AcademicCap => (ICON_ACADEMIC_CAP_AVAILABLE, ICON_ACADEMIC_CAP_CODEPOINTS),
+            Icon::AdjustmentsHorizontal => (
+                ICON_ADJUSTMENTS_HORIZONTAL_AVAILABLE,
+                ICON_ADJUSTMENTS_HORIZONTAL_CODEPOINTS,
+            ),
+            Icon::AdjustmentsVertical => (
+                ICON_ADJUSTMENTS_VERTICAL_AVAILABLE,
+                ICON_ADJUSTMENTS_VERTICAL_CODEPOINTS,
+            ),
+            Icon::ArchiveBox => (ICON_ARCHIVE_BOX_AVAILABLE, ICON_ARCHIVE_BOX_CODEPOINTS),
+            Icon::ArchiveBoxArrowDown => (
+                ICON_ARCHIVE_BOX_ARROW_DOWN_AVAILABLE,
+                ICON_ARCHIVE_BOX_ARROW_DOWN_CODEPOINTS,
+            ),
+            Icon::ArchiveBoxXMark => (
+                ICON_ARCHIVE_BOX_X_MARK_AVAILABLE,
+                ICON_ARCHIVE_BOX_X_MARK_CODEPOINTS,
+            ),
+            Icon::ArrowDown => (ICON_ARROW_DOWN_AVAILABLE, ICON_ARROW_DOWN_CODEPOINTS),
+            Icon::ArrowDownCircle => (
+                ICON_ARROW_DOWN_CIRCLE_AVAILABLE,
+                ICON_ARROW_DOWN_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowDownLeft => (
+                ICON_ARROW_DOWN_LEFT_AVAILABLE,
+                ICON_ARROW_DOWN_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowDownOnSquare => (
+                ICON_ARROW_DOWN_ON_SQUARE_AVAILABLE,
+                ICON_ARROW_DOWN_ON_SQUARE_CODEPOINTS,
+            ),
+            Icon::ArrowDownOnSquareStack => (
+                ICON_ARROW_DOWN_ON_SQUARE_STACK_AVAILABLE,
+                ICON_ARROW_DOWN_ON_SQUARE_STACK_CODEPOINTS,
+            ),
+            Icon::ArrowDownRight => (
+                ICON_ARROW_DOWN_RIGHT_AVAILABLE,
+                ICON_ARROW_DOWN_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowDownTray => (
+                ICON_ARROW_DOWN_TRAY_AVAILABLE,
+                ICON_ARROW_DOWN_TRAY_CODEPOINTS,
+            ),
+            Icon::ArrowLeft => (ICON_ARROW_LEFT_AVAILABLE, ICON_ARROW_LEFT_CODEPOINTS),
+            Icon::ArrowLeftCircle => (
+                ICON_ARROW_LEFT_CIRCLE_AVAILABLE,
+                ICON_ARROW_LEFT_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowLeftEndOnRectangle => (
+                ICON_ARROW_LEFT_END_ON_RECTANGLE_AVAILABLE,
+                ICON_ARROW_LEFT_END_ON_RECTANGLE_CODEPOINTS,
+            ),
+            Icon::ArrowLeftOnRectangle => (
+                ICON_ARROW_LEFT_ON_RECTANGLE_AVAILABLE,
+                ICON_ARROW_LEFT_ON_RECTANGLE_CODEPOINTS,
+            ),
+            Icon::ArrowLeftStartOnRectangle => (
+                ICON_ARROW_LEFT_START_ON_RECTANGLE_AVAILABLE,
+                ICON_ARROW_LEFT_START_ON_RECTANGLE_CODEPOINTS,
+            ),
+            Icon::ArrowLongDown => (
+                ICON_ARROW_LONG_DOWN_AVAILABLE,
+                ICON_ARROW_LONG_DOWN_CODEPOINTS,
+            ),
+            Icon::ArrowLongLeft => (
+                ICON_ARROW_LONG_LEFT_AVAILABLE,
+                ICON_ARROW_LONG_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowLongRight => (
+                ICON_ARROW_LONG_RIGHT_AVAILABLE,
+                ICON_ARROW_LONG_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowLongUp => (ICON_ARROW_LONG_UP_AVAILABLE, ICON_ARROW_LONG_UP_CODEPOINTS),
+            Icon::ArrowPath => (ICON_ARROW_PATH_AVAILABLE, ICON_ARROW_PATH_CODEPOINTS),
+            Icon::ArrowPathRoundedSquare => (
+                ICON_ARROW_PATH_ROUNDED_SQUARE_AVAILABLE,
+                ICON_ARROW_PATH_ROUNDED_SQUARE_CODEPOINTS,
+            ),
+            Icon::ArrowRight => (ICON_ARROW_RIGHT_AVAILABLE, ICON_ARROW_RIGHT_CODEPOINTS),
+            Icon::ArrowRightCircle => (
+                ICON_ARROW_RIGHT_CIRCLE_AVAILABLE,
+                ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowRightEndOnRectangle => (
+                ICON_ARROW_RIGHT_END_ON_RECTANGLE_AVAILABLE,
+                ICON_ARROW_RIGHT_END_ON_RECTANGLE_CODEPOINTS,
+            ),
+            Icon::ArrowRightOnRectangle => (
+                ICON_ARROW_RIGHT_ON_RECTANGLE_AVAILABLE,
+                ICON_ARROW_RIGHT_ON_RECTANGLE_CODEPOINTS,
+            ),
+            Icon::ArrowRightStartOnRectangle => (
+                ICON_ARROW_RIGHT_START_ON_RECTANGLE_AVAILABLE,
+                ICON_ARROW_RIGHT_START_ON_RECTANGLE_CODEPOINTS,
+            ),
+            Icon::ArrowSmallDown => (
+                ICON_ARROW_SMALL_DOWN_AVAILABLE,
+                ICON_ARROW_SMALL_DOWN_CODEPOINTS,
+            ),
+            Icon::ArrowSmallLeft => (
+                ICON_ARROW_SMALL_LEFT_AVAILABLE,
+                ICON_ARROW_SMALL_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowSmallRight => (
+                ICON_ARROW_SMALL_RIGHT_AVAILABLE,
+                ICON_ARROW_SMALL_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowSmallUp => (
+                ICON_ARROW_SMALL_UP_AVAILABLE,
+                ICON_ARROW_SMALL_UP_CODEPOINTS,
+            ),
+            Icon::ArrowTopRightOnSquare => (
+                ICON_ARROW_TOP_RIGHT_ON_SQUARE_AVAILABLE,
+                ICON_ARROW_TOP_RIGHT_ON_SQUARE_CODEPOINTS,
+            ),
+            Icon::ArrowTrendingDown => (
+                ICON_ARROW_TRENDING_DOWN_AVAILABLE,
+                ICON_ARROW_TRENDING_DOWN_CODEPOINTS,
+            ),
+            Icon::ArrowTrendingUp => (
+                ICON_ARROW_TRENDING_UP_AVAILABLE,
+                ICON_ARROW_TRENDING_UP_CODEPOINTS,
+            ),
+            Icon::ArrowTurnDownLeft => (
+                ICON_ARROW_TURN_DOWN_LEFT_AVAILABLE,
+                ICON_ARROW_TURN_DOWN_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowTurnDownRight => (
+                ICON_ARROW_TURN_DOWN_RIGHT_AVAILABLE,
+                ICON_ARROW_TURN_DOWN_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowTurnLeftDown => (
+                ICON_ARROW_TURN_LEFT_DOWN_AVAILABLE,
+                ICON_ARROW_TURN_LEFT_DOWN_CODEPOINTS,
+            ),
+            Icon::ArrowTurnLeftUp => (
+                ICON_ARROW_TURN_LEFT_UP_AVAILABLE,
+                ICON_ARROW_TURN_LEFT_UP_CODEPOINTS,
+            ),
+            Icon::ArrowTurnRightDown => (
+                ICON_ARROW_TURN_RIGHT_DOWN_AVAILABLE,
+                ICON_ARROW_TURN_RIGHT_DOWN_CODEPOINTS,
+            ),
+            Icon::ArrowTurnRightUp => (
+                ICON_ARROW_TURN_RIGHT_UP_AVAILABLE,
+                ICON_ARROW_TURN_RIGHT_UP_CODEPOINTS,
+            ),
+            Icon::ArrowTurnUpLeft => (
+                ICON_ARROW_TURN_UP_LEFT_AVAILABLE,
+                ICON_ARROW_TURN_UP_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowTurnUpRight => (
+                ICON_ARROW_TURN_UP_RIGHT_AVAILABLE,
+                ICON_ARROW_TURN_UP_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowUp => (ICON_ARROW_UP_AVAILABLE, ICON_ARROW_UP_CODEPOINTS),
+            Icon::ArrowUpCircle => (
+                ICON_ARROW_UP_CIRCLE_AVAILABLE,
+                ICON_ARROW_UP_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ArrowUpLeft => (ICON_ARROW_UP_LEFT_AVAILABLE, ICON_ARROW_UP_LEFT_CODEPOINTS),
+            Icon::ArrowUpOnSquare => (
+                ICON_ARROW_UP_ON_SQUARE_AVAILABLE,
+                ICON_ARROW_UP_ON_SQUARE_CODEPOINTS,
+            ),
+            Icon::ArrowUpOnSquareStack => (
+                ICON_ARROW_UP_ON_SQUARE_STACK_AVAILABLE,
+                ICON_ARROW_UP_ON_SQUARE_STACK_CODEPOINTS,
+            ),
+            Icon::ArrowUpRight => (
+                ICON_ARROW_UP_RIGHT_AVAILABLE,
+                ICON_ARROW_UP_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowUpTray => (ICON_ARROW_UP_TRAY_AVAILABLE, ICON_ARROW_UP_TRAY_CODEPOINTS),
+            Icon::ArrowUturnDown => (
+                ICON_ARROW_UTURN_DOWN_AVAILABLE,
+                ICON_ARROW_UTURN_DOWN_CODEPOINTS,
+            ),
+            Icon::ArrowUturnLeft => (
+                ICON_ARROW_UTURN_LEFT_AVAILABLE,
+                ICON_ARROW_UTURN_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowUturnRight => (
+                ICON_ARROW_UTURN_RIGHT_AVAILABLE,
+                ICON_ARROW_UTURN_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowUturnUp => (
+                ICON_ARROW_UTURN_UP_AVAILABLE,
+                ICON_ARROW_UTURN_UP_CODEPOINTS,
+            ),
+            Icon::ArrowsPointingIn => (
+                ICON_ARROWS_POINTING_IN_AVAILABLE,
+                ICON_ARROWS_POINTING_IN_CODEPOINTS,
+            ),
+            Icon::ArrowsPointingOut => (
+                ICON_ARROWS_POINTING_OUT_AVAILABLE,
+                ICON_ARROWS_POINTING_OUT_CODEPOINTS,
+            ),
+            Icon::ArrowsRightLeft => (
+                ICON_ARROWS_RIGHT_LEFT_AVAILABLE,
+                ICON_ARROWS_RIGHT_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowsUpDown => (
+                ICON_ARROWS_UP_DOWN_AVAILABLE,
+                ICON_ARROWS_UP_DOWN_CODEPOINTS,
+            ),
+            Icon::AtSymbol => (ICON_AT_SYMBOL_AVAILABLE, ICON_AT_SYMBOL_CODEPOINTS),
+            Icon::Backspace => (ICON_BACKSPACE_AVAILABLE, ICON_BACKSPACE_CODEPOINTS),
+            Icon::Backward => (ICON_BACKWARD_AVAILABLE, ICON_BACKWARD_CODEPOINTS),
+            Icon::Banknotes => (ICON_BANKNOTES_AVAILABLE, ICON_BANKNOTES_CODEPOINTS),
+            Icon::Bars2 => (ICON_BARS_2_AVAILABLE, ICON_BARS_2_CODEPOINTS),
+            Icon::Bars3 => (ICON_BARS_3_AVAILABLE, ICON_BARS_3_CODEPOINTS),
+            Icon::Bars3BottomLeft => (
+                ICON_BARS_3_BOTTOM_LEFT_AVAILABLE,
+                ICON_BARS_3_BOTTOM_LEFT_CODEPOINTS,
+            ),
+            Icon::Bars3BottomRight => (
+                ICON_BARS_3_BOTTOM_RIGHT_AVAILABLE,
+                ICON_BARS_3_BOTTOM_RIGHT_CODEPOINTS,
+            ),
+            Icon::Bars3CenterLeft => (
+                ICON_BARS_3_CENTER_LEFT_AVAILABLE,
+                ICON_BARS_3_CENTER_LEFT_CODEPOINTS,
+            ),
+            Icon::Bars4 => (ICON_BARS_4_AVAILABLE, ICON_BARS_4_CODEPOINTS),
+            Icon::BarsArrowDown => (
+                ICON_BARS_ARROW_DOWN_AVAILABLE,
+                ICON_BARS_ARROW_DOWN_CODEPOINTS,
+            ),
+            Icon::BarsArrowUp => (ICON_BARS_ARROW_UP_AVAILABLE, ICON_BARS_ARROW_UP_CODEPOINTS),
+            Icon::Battery0 => (ICON_BATTERY_0_AVAILABLE, ICON_BATTERY_0_CODEPOINTS),
+            Icon::Battery100 => (ICON_BATTERY_100_AVAILABLE, ICON_BATTERY_100_CODEPOINTS),
+            Icon::Battery50 => (ICON_BATTERY_50_AVAILABLE, ICON_BATTERY_50_CODEPOINTS),
+            Icon::Beaker => (ICON_BEAKER_AVAILABLE, ICON_BEAKER_CODEPOINTS),
+            Icon::Bell => (ICON_BELL_AVAILABLE, ICON_BELL_CODEPOINTS),
+            Icon::BellAlert => (ICON_BELL_ALERT_AVAILABLE, ICON_BELL_ALERT_CODEPOINTS),
+            Icon::BellSlash => (ICON_BELL_SLASH_AVAILABLE, ICON_BELL_SLASH_CODEPOINTS),
+            Icon::BellSnooze => (ICON_BELL_SNOOZE_AVAILABLE, ICON_BELL_SNOOZE_CODEPOINTS),
+            Icon::Bold => (ICON_BOLD_AVAILABLE, ICON_BOLD_CODEPOINTS),
+            Icon::Bolt => (ICON_BOLT_AVAILABLE, ICON_BOLT_CODEPOINTS),
+            Icon::BoltSlash => (ICON_BOLT_SLASH_AVAILABLE, ICON_BOLT_SLASH_CODEPOINTS),
+            Icon::BookOpen => (ICON_BOOK_OPEN_AVAILABLE, ICON_BOOK_OPEN_CODEPOINTS),
+            Icon::Bookmark => (ICON_BOOKMARK_AVAILABLE, ICON_BOOKMARK_CODEPOINTS),
+            Icon::BookmarkSlash => (
+                ICON_BOOKMARK_SLASH_AVAILABLE,
+                ICON_BOOKMARK_SLASH_CODEPOINTS,
+            ),
+            Icon::BookmarkSquare => (
+                ICON_BOOKMARK_SQUARE_AVAILABLE,
+                ICON_BOOKMARK_SQUARE_CODEPOINTS,
+            ),
+            Icon::Briefcase => (ICON_BRIEFCASE_AVAILABLE, ICON_BRIEFCASE_CODEPOINTS),
+            Icon::BugAnt => (ICON_BUG_ANT_AVAILABLE, ICON_BUG_ANT_CODEPOINTS),
+            Icon::BuildingLibrary => (
+                ICON_BUILDING_LIBRARY_AVAILABLE,
+                ICON_BUILDING_LIBRARY_CODEPOINTS,
+            ),
+            Icon::BuildingOffice => (
+                ICON_BUILDING_OFFICE_AVAILABLE,
+                ICON_BUILDING_OFFICE_CODEPOINTS,
+            ),
+            Icon::BuildingOffice2 => (
+                ICON_BUILDING_OFFICE_2_AVAILABLE,
+                ICON_BUILDING_OFFICE_2_CODEPOINTS,
+            ),
+            Icon::BuildingStorefront => (
+                ICON_BUILDING_STOREFRONT_AVAILABLE,
+                ICON_BUILDING_STOREFRONT_CODEPOINTS,
+            ),
+            Icon::Cake => (ICON_CAKE_AVAILABLE, ICON_CAKE_CODEPOINTS),
+            Icon::Calculator => (ICON_CALCULATOR_AVAILABLE, ICON_CALCULATOR_CODEPOINTS),
+            Icon::Calendar => (ICON_CALENDAR_AVAILABLE, ICON_CALENDAR_CODEPOINTS),
+            Icon::CalendarDateRange => (
+                ICON_CALENDAR_DATE_RANGE_AVAILABLE,
+                ICON_CALENDAR_DATE_RANGE_CODEPOINTS,
+            ),
+            Icon::CalendarDays => (ICON_CALENDAR_DAYS_AVAILABLE, ICON_CALENDAR_DAYS_CODEPOINTS),
+            Icon::Camera => (ICON_CAMERA_AVAILABLE, ICON_CAMERA_CODEPOINTS),
+            Icon::ChartBar => (ICON_CHART_BAR_AVAILABLE, ICON_CHART_BAR_CODEPOINTS),
+            Icon::ChartBarSquare => (
+                ICON_CHART_BAR_SQUARE_AVAILABLE,
+                ICON_CHART_BAR_SQUARE_CODEPOINTS,
+            ),
+            Icon::ChartPie => (ICON_CHART_PIE_AVAILABLE, ICON_CHART_PIE_CODEPOINTS),
+            Icon::ChatBubbleBottomCenter => (
+                ICON_CHAT_BUBBLE_BOTTOM_CENTER_AVAILABLE,
+                ICON_CHAT_BUBBLE_BOTTOM_CENTER_CODEPOINTS,
+            ),
+            Icon::ChatBubbleBottomCenterText => (
+                ICON_CHAT_BUBBLE_BOTTOM_CENTER_TEXT_AVAILABLE,
+                ICON_CHAT_BUBBLE_BOTTOM_CENTER_TEXT_CODEPOINTS,
+            ),
+            Icon::ChatBubbleLeft => (
+                ICON_CHAT_BUBBLE_LEFT_AVAILABLE,
+                ICON_CHAT_BUBBLE_LEFT_CODEPOINTS,
+            ),
+            Icon::ChatBubbleLeftEllipsis => (
+                ICON_CHAT_BUBBLE_LEFT_ELLIPSIS_AVAILABLE,
+                ICON_CHAT_BUBBLE_LEFT_ELLIPSIS_CODEPOINTS,
+            ),
+            Icon::ChatBubbleLeftRight => (
+                ICON_CHAT_BUBBLE_LEFT_RIGHT_AVAILABLE,
+                ICON_CHAT_BUBBLE_LEFT_RIGHT_CODEPOINTS,
+            ),
+            Icon::ChatBubbleOvalLeft => (
+                ICON_CHAT_BUBBLE_OVAL_LEFT_AVAILABLE,
+                ICON_CHAT_BUBBLE_OVAL_LEFT_CODEPOINTS,
+            ),
+            Icon::ChatBubbleOvalLeftEllipsis => (
+                ICON_CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS_AVAILABLE,
+                ICON_CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS_CODEPOINTS,
+            ),
+            Icon::Check => (ICON_CHECK_AVAILABLE, ICON_CHECK_CODEPOINTS),
+            Icon::CheckBadge => (ICON_CHECK_BADGE_AVAILABLE, ICON_CHECK_BADGE_CODEPOINTS),
+            Icon::CheckCircle => (ICON_CHECK_CIRCLE_AVAILABLE, ICON_CHECK_CIRCLE_CODEPOINTS),
+            Icon::ChevronDoubleDown => (
+                ICON_CHEVRON_DOUBLE_DOWN_AVAILABLE,
+                ICON_CHEVRON_DOUBLE_DOWN_CODEPOINTS,
+            ),
+            Icon::ChevronDoubleLeft => (
+                ICON_CHEVRON_DOUBLE_LEFT_AVAILABLE,
+                ICON_CHEVRON_DOUBLE_LEFT_CODEPOINTS,
+            ),
+            Icon::ChevronDoubleRight => (
+                ICON_CHEVRON_DOUBLE_RIGHT_AVAILABLE,
+                ICON_CHEVRON_DOUBLE_RIGHT_CODEPOINTS,
+            ),
+            Icon::ChevronDoubleUp => (
+                ICON_CHEVRON_DOUBLE_UP_AVAILABLE,
+                ICON_CHEVRON_DOUBLE_UP_CODEPOINTS,
+            ),
+            Icon::ChevronDown => (ICON_CHEVRON_DOWN_AVAILABLE, ICON_CHEVRON_DOWN_CODEPOINTS),
+            Icon::ChevronLeft => (ICON_CHEVRON_LEFT_AVAILABLE, ICON_CHEVRON_LEFT_CODEPOINTS),
+            Icon::ChevronRight => (ICON_CHEVRON_RIGHT_AVAILABLE, ICON_CHEVRON_RIGHT_CODEPOINTS),
+            Icon::ChevronUp => (ICON_CHEVRON_UP_AVAILABLE, ICON_CHEVRON_UP_CODEPOINTS),
+            Icon::ChevronUpDown => (
+                ICON_CHEVRON_UP_DOWN_AVAILABLE,
+                ICON_CHEVRON_UP_DOWN_CODEPOINTS,
+            ),
+            Icon::CircleStack => (ICON_CIRCLE_STACK_AVAILABLE, ICON_CIRCLE_STACK_CODEPOINTS),
+            Icon::Clipboard => (ICON_CLIPBOARD_AVAILABLE, ICON_CLIPBOARD_CODEPOINTS),
+            Icon::ClipboardDocument => (
+                ICON_CLIPBOARD_DOCUMENT_AVAILABLE,
+                ICON_CLIPBOARD_DOCUMENT_CODEPOINTS,
+            ),
+            Icon::ClipboardDocumentCheck => (
+                ICON_CLIPBOARD_DOCUMENT_CHECK_AVAILABLE,
+                ICON_CLIPBOARD_DOCUMENT_CHECK_CODEPOINTS,
+            ),
+            Icon::ClipboardDocumentList => (
+                ICON_CLIPBOARD_DOCUMENT_LIST_AVAILABLE,
+                ICON_CLIPBOARD_DOCUMENT_LIST_CODEPOINTS,
+            ),
+            Icon::Clock => (ICON_CLOCK_AVAILABLE, ICON_CLOCK_CODEPOINTS),
+            Icon::Cloud => (ICON_CLOUD_AVAILABLE, ICON_CLOUD_CODEPOINTS),
+            Icon::CloudArrowDown => (
+                ICON_CLOUD_ARROW_DOWN_AVAILABLE,
+                ICON_CLOUD_ARROW_DOWN_CODEPOINTS,
+            ),
+            Icon::CloudArrowUp => (
+                ICON_CLOUD_ARROW_UP_AVAILABLE,
+                ICON_CLOUD_ARROW_UP_CODEPOINTS,
+            ),
+            Icon::CodeBracket => (ICON_CODE_BRACKET_AVAILABLE, ICON_CODE_BRACKET_CODEPOINTS),
+            Icon::CodeBracketSquare => (
+                ICON_CODE_BRACKET_SQUARE_AVAILABLE,
+                ICON_CODE_BRACKET_SQUARE_CODEPOINTS,
+            ),
+            Icon::Cog => (ICON_COG_AVAILABLE, ICON_COG_CODEPOINTS),
+            Icon::Cog6Tooth => (ICON_COG_6_TOOTH_AVAILABLE, ICON_COG_6_TOOTH_CODEPOINTS),
+            Icon::Cog8Tooth => (ICON_COG_8_TOOTH_AVAILABLE, ICON_COG_8_TOOTH_CODEPOINTS),
+            Icon::CommandLine => (ICON_COMMAND_LINE_AVAILABLE, ICON_COMMAND_LINE_CODEPOINTS),
+            Icon::ComputerDesktop => (
+                ICON_COMPUTER_DESKTOP_AVAILABLE,
+                ICON_COMPUTER_DESKTOP_CODEPOINTS,
+            ),
+            Icon::CpuChip => (ICON_CPU_CHIP_AVAILABLE, ICON_CPU_CHIP_CODEPOINTS),
+            Icon::CreditCard => (ICON_CREDIT_CARD_AVAILABLE, ICON_CREDIT_CARD_CODEPOINTS),
+            Icon::Cube => (ICON_CUBE_AVAILABLE, ICON_CUBE_CODEPOINTS),
+            Icon::CubeTransparent => (
+                ICON_CUBE_TRANSPARENT_AVAILABLE,
+                ICON_CUBE_TRANSPARENT_CODEPOINTS,
+            ),
+            Icon::CurrencyBangladeshi => (
+                ICON_CURRENCY_BANGLADESHI_AVAILABLE,
+                ICON_CURRENCY_BANGLADESHI_CODEPOINTS,
+            ),
+            Icon::CurrencyDollar => (
+                ICON_CURRENCY_DOLLAR_AVAILABLE,
+                ICON_CURRENCY_DOLLAR_CODEPOINTS,
+            ),
+            Icon::CurrencyEuro => (ICON_CURRENCY_EURO_AVAILABLE, ICON_CURRENCY_EURO_CODEPOINTS),
+            Icon::CurrencyPound => (
+                ICON_CURRENCY_POUND_AVAILABLE,
+                ICON_CURRENCY_POUND_CODEPOINTS,
+            ),
+            Icon::CurrencyRupee => (
+                ICON_CURRENCY_RUPEE_AVAILABLE,
+                ICON_CURRENCY_RUPEE_CODEPOINTS,
+            ),
+            Icon::CurrencyYen => (ICON_CURRENCY_YEN_AVAILABLE, ICON_CURRENCY_YEN_CODEPOINTS),
+            Icon::CursorArrowRays => (
+                ICON_CURSOR_ARROW_RAYS_AVAILABLE,
+                ICON_CURSOR_ARROW_RAYS_CODEPOINTS,
+            ),
+            Icon::CursorArrowRipple => (
+                ICON_CURSOR_ARROW_RIPPLE_AVAILABLE,
+                ICON_CURSOR_ARROW_RIPPLE_CODEPOINTS,
+            ),
+            Icon::DevicePhoneMobile => (
+                ICON_DEVICE_PHONE_MOBILE_AVAILABLE,
+                ICON_DEVICE_PHONE_MOBILE_CODEPOINTS,
+            ),
+            Icon::DeviceTablet => (ICON_DEVICE_TABLET_AVAILABLE, ICON_DEVICE_TABLET_CODEPOINTS),
+            Icon::Divide => (ICON_DIVIDE_AVAILABLE, ICON_DIVIDE_CODEPOINTS),
+            Icon::Document => (ICON_DOCUMENT_AVAILABLE, ICON_DOCUMENT_CODEPOINTS),
+            Icon::DocumentArrowDown => (
+                ICON_DOCUMENT_ARROW_DOWN_AVAILABLE,
+                ICON_DOCUMENT_ARROW_DOWN_CODEPOINTS,
+            ),
+            Icon::DocumentArrowUp => (
+                ICON_DOCUMENT_ARROW_UP_AVAILABLE,
+                ICON_DOCUMENT_ARROW_UP_CODEPOINTS,
+            ),
+            Icon::DocumentChartBar => (
+                ICON_DOCUMENT_CHART_BAR_AVAILABLE,
+                ICON_DOCUMENT_CHART_BAR_CODEPOINTS,
+            ),
+            Icon::DocumentCheck => (
+                ICON_DOCUMENT_CHECK_AVAILABLE,
+                ICON_DOCUMENT_CHECK_CODEPOINTS,
+            ),
+            Icon::DocumentCurrencyBangladeshi => (
+                ICON_DOCUMENT_CURRENCY_BANGLADESHI_AVAILABLE,
+                ICON_DOCUMENT_CURRENCY_BANGLADESHI_CODEPOINTS,
+            ),
+            Icon::DocumentCurrencyDollar => (
+                ICON_DOCUMENT_CURRENCY_DOLLAR_AVAILABLE,
+                ICON_DOCUMENT_CURRENCY_DOLLAR_CODEPOINTS,
+            ),
+            Icon::DocumentCurrencyEuro => (
+                ICON_DOCUMENT_CURRENCY_EURO_AVAILABLE,
+                ICON_DOCUMENT_CURRENCY_EURO_CODEPOINTS,
+            ),
+            Icon::DocumentCurrencyPound => (
+                ICON_DOCUMENT_CURRENCY_POUND_AVAILABLE,
+                ICON_DOCUMENT_CURRENCY_POUND_CODEPOINTS,
+            ),
+            Icon::DocumentCurrencyRupee => (
+                ICON_DOCUMENT_CURRENCY_RUPEE_AVAILABLE,
+                ICON_DOCUMENT_CURRENCY_RUPEE_CODEPOINTS,
+            ),
+            Icon::DocumentCurrencyYen => (
+                ICON_DOCUMENT_CURRENCY_YEN_AVAILABLE,
+                ICON_DOCUMENT_CURRENCY_YEN_CODEPOINTS,
+            ),
+            Icon::DocumentDuplicate => (
+                ICON_DOCUMENT_DUPLICATE_AVAILABLE,
+                ICON_DOCUMENT_DUPLICATE_CODEPOINTS,
+            ),
+            Icon::DocumentMagnifyingGlass => (
+                ICON_DOCUMENT_MAGNIFYING_GLASS_AVAILABLE,
+                ICON_DOCUMENT_MAGNIFYING_GLASS_CODEPOINTS,
+            ),
+            Icon::DocumentMinus => (
+                ICON_DOCUMENT_MINUS_AVAILABLE,
+                ICON_DOCUMENT_MINUS_CODEPOINTS,
+            ),
+            Icon::DocumentPlus => (ICON_DOCUMENT_PLUS_AVAILABLE, ICON_DOCUMENT_PLUS_CODEPOINTS),
+            Icon::DocumentText => (ICON_DOCUMENT_TEXT_AVAILABLE, ICON_DOCUMENT_TEXT_CODEPOINTS),
+            Icon::EllipsisHorizontal => (
+                ICON_ELLIPSIS_HORIZONTAL_AVAILABLE,
+                ICON_ELLIPSIS_HORIZONTAL_CODEPOINTS,
+            ),
+            Icon::EllipsisHorizontalCircle => (
+                ICON_ELLIPSIS_HORIZONTAL_CIRCLE_AVAILABLE,
+                ICON_ELLIPSIS_HORIZONTAL_CIRCLE_CODEPOINTS,
+            ),
+            Icon::EllipsisVertical => (
+                ICON_ELLIPSIS_VERTICAL_AVAILABLE,
+                ICON_ELLIPSIS_VERTICAL_CODEPOINTS,
+            ),
+            Icon::Envelope => (ICON_ENVELOPE_AVAILABLE, ICON_ENVELOPE_CODEPOINTS),
+            Icon::EnvelopeOpen => (ICON_ENVELOPE_OPEN_AVAILABLE, ICON_ENVELOPE_OPEN_CODEPOINTS),
+            Icon::Equals => (ICON_EQUALS_AVAILABLE, ICON_EQUALS_CODEPOINTS),
+            Icon::ExclamationCircle => (
+                ICON_EXCLAMATION_CIRCLE_AVAILABLE,
+                ICON_EXCLAMATION_CIRCLE_CODEPOINTS,
+            ),
+            Icon::ExclamationTriangle => (
+                ICON_EXCLAMATION_TRIANGLE_AVAILABLE,
+                ICON_EXCLAMATION_TRIANGLE_CODEPOINTS,
+            ),
+            Icon::Eye => (ICON_EYE_AVAILABLE, ICON_EYE_CODEPOINTS),
+            Icon::EyeDropper => (ICON_EYE_DROPPER_AVAILABLE, ICON_EYE_DROPPER_CODEPOINTS),
+            Icon::EyeSlash => (ICON_EYE_SLASH_AVAILABLE, ICON_EYE_SLASH_CODEPOINTS),
+            Icon::FaceFrown => (ICON_FACE_FROWN_AVAILABLE, ICON_FACE_FROWN_CODEPOINTS),
+            Icon::FaceSmile => (ICON_FACE_SMILE_AVAILABLE, ICON_FACE_SMILE_CODEPOINTS),
+            Icon::Film => (ICON_FILM_AVAILABLE, ICON_FILM_CODEPOINTS),
+            Icon::FingerPrint => (ICON_FINGER_PRINT_AVAILABLE, ICON_FINGER_PRINT_CODEPOINTS),
+            Icon::Fire => (ICON_FIRE_AVAILABLE, ICON_FIRE_CODEPOINTS),
+            Icon::Flag => (ICON_FLAG_AVAILABLE, ICON_FLAG_CODEPOINTS),
+            Icon::Folder => (ICON_FOLDER_AVAILABLE, ICON_FOLDER_CODEPOINTS),
+            Icon::FolderArrowDown => (
+                ICON_FOLDER_ARROW_DOWN_AVAILABLE,
+                ICON_FOLDER_ARROW_DOWN_CODEPOINTS,
+            ),
+            Icon::FolderMinus => (ICON_FOLDER_MINUS_AVAILABLE, ICON_FOLDER_MINUS_CODEPOINTS),
+            Icon::FolderOpen => (ICON_FOLDER_OPEN_AVAILABLE, ICON_FOLDER_OPEN_CODEPOINTS),
+            Icon::FolderPlus => (ICON_FOLDER_PLUS_AVAILABLE, ICON_FOLDER_PLUS_CODEPOINTS),
+            Icon::Forward => (ICON_FORWARD_AVAILABLE, ICON_FORWARD_CODEPOINTS),
+            Icon::Funnel => (ICON_FUNNEL_AVAILABLE, ICON_FUNNEL_CODEPOINTS),
+            Icon::Gif => (ICON_GIF_AVAILABLE, ICON_GIF_CODEPOINTS),
+            Icon::Gift => (ICON_GIFT_AVAILABLE, ICON_GIFT_CODEPOINTS),
+            Icon::GiftTop => (ICON_GIFT_TOP_AVAILABLE, ICON_GIFT_TOP_CODEPOINTS),
+            Icon::GlobeAlt => (ICON_GLOBE_ALT_AVAILABLE, ICON_GLOBE_ALT_CODEPOINTS),
+            Icon::GlobeAmericas => (
+                ICON_GLOBE_AMERICAS_AVAILABLE,
+                ICON_GLOBE_AMERICAS_CODEPOINTS,
+            ),
+            Icon::GlobeAsiaAustralia => (
+                ICON_GLOBE_ASIA_AUSTRALIA_AVAILABLE,
+                ICON_GLOBE_ASIA_AUSTRALIA_CODEPOINTS,
+            ),
+            Icon::GlobeEuropeAfrica => (
+                ICON_GLOBE_EUROPE_AFRICA_AVAILABLE,
+                ICON_GLOBE_EUROPE_AFRICA_CODEPOINTS,
+            ),
+            Icon::H1 => (ICON_H_1_AVAILABLE, ICON_H_1_CODEPOINTS),
+            Icon::H2 => (ICON_H_2_AVAILABLE, ICON_H_2_CODEPOINTS),
+            Icon::H3 => (ICON_H_3_AVAILABLE, ICON_H_3_CODEPOINTS),
+            Icon::HandRaised => (ICON_HAND_RAISED_AVAILABLE, ICON_HAND_RAISED_CODEPOINTS),
+            Icon::HandThumbDown => (
+                ICON_HAND_THUMB_DOWN_AVAILABLE,
+                ICON_HAND_THUMB_DOWN_CODEPOINTS,
+            ),
+            Icon::HandThumbUp => (ICON_HAND_THUMB_UP_AVAILABLE, ICON_HAND_THUMB_UP_CODEPOINTS),
+            Icon::Hashtag => (ICON_HASHTAG_AVAILABLE, ICON_HASHTAG_CODEPOINTS),
+            Icon::Heart => (ICON_HEART_AVAILABLE, ICON_HEART_CODEPOINTS),
+            Icon::Home => (ICON_HOME_AVAILABLE, ICON_HOME_CODEPOINTS),
+            Icon::HomeModern => (ICON_HOME_MODERN_AVAILABLE, ICON_HOME_MODERN_CODEPOINTS),
+            Icon::Identification => (
+                ICON_IDENTIFICATION_AVAILABLE,
+                ICON_IDENTIFICATION_CODEPOINTS,
+            ),
+            Icon::Inbox => (ICON_INBOX_AVAILABLE, ICON_INBOX_CODEPOINTS),
+            Icon::InboxArrowDown => (
+                ICON_INBOX_ARROW_DOWN_AVAILABLE,
+                ICON_INBOX_ARROW_DOWN_CODEPOINTS,
+            ),
+            Icon::InboxStack => (ICON_INBOX_STACK_AVAILABLE, ICON_INBOX_STACK_CODEPOINTS),
+            Icon::InformationCircle => (
+                ICON_INFORMATION_CIRCLE_AVAILABLE,
+                ICON_INFORMATION_CIRCLE_CODEPOINTS,
+            ),
+            Icon::Italic => (ICON_ITALIC_AVAILABLE, ICON_ITALIC_CODEPOINTS),
+            Icon::Key => (ICON_KEY_AVAILABLE, ICON_KEY_CODEPOINTS),
+            Icon::Language => (ICON_LANGUAGE_AVAILABLE, ICON_LANGUAGE_CODEPOINTS),
+            Icon::Lifebuoy => (ICON_LIFEBUOY_AVAILABLE, ICON_LIFEBUOY_CODEPOINTS),
+            Icon::LightBulb => (ICON_LIGHT_BULB_AVAILABLE, ICON_LIGHT_BULB_CODEPOINTS),
+            Icon::Link => (ICON_LINK_AVAILABLE, ICON_LINK_CODEPOINTS),
+            Icon::LinkSlash => (ICON_LINK_SLASH_AVAILABLE, ICON_LINK_SLASH_CODEPOINTS),
+            Icon::ListBullet => (ICON_LIST_BULLET_AVAILABLE, ICON_LIST_BULLET_CODEPOINTS),
+            Icon::LockClosed => (ICON_LOCK_CLOSED_AVAILABLE, ICON_LOCK_CLOSED_CODEPOINTS),
+            Icon::LockOpen => (ICON_LOCK_OPEN_AVAILABLE, ICON_LOCK_OPEN_CODEPOINTS),
+            Icon::MagnifyingGlass => (
+                ICON_MAGNIFYING_GLASS_AVAILABLE,
+                ICON_MAGNIFYING_GLASS_CODEPOINTS,
+            ),
+            Icon::MagnifyingGlassCircle => (
+                ICON_MAGNIFYING_GLASS_CIRCLE_AVAILABLE,
+                ICON_MAGNIFYING_GLASS_CIRCLE_CODEPOINTS,
+            ),
+            Icon::MagnifyingGlassMinus => (
+                ICON_MAGNIFYING_GLASS_MINUS_AVAILABLE,
+                ICON_MAGNIFYING_GLASS_MINUS_CODEPOINTS,
+            ),
+            Icon::MagnifyingGlassPlus => (
+                ICON_MAGNIFYING_GLASS_PLUS_AVAILABLE,
+                ICON_MAGNIFYING_GLASS_PLUS_CODEPOINTS,
+            ),
+            Icon::Map => (ICON_MAP_AVAILABLE, ICON_MAP_CODEPOINTS),
+            Icon::MapPin => (ICON_MAP_PIN_AVAILABLE, ICON_MAP_PIN_CODEPOINTS),
+            Icon::Megaphone => (ICON_MEGAPHONE_AVAILABLE, ICON_MEGAPHONE_CODEPOINTS),
+            Icon::Microphone => (ICON_MICROPHONE_AVAILABLE, ICON_MICROPHONE_CODEPOINTS),
+            Icon::Minus => (ICON_MINUS_AVAILABLE, ICON_MINUS_CODEPOINTS),
+            Icon::MinusCircle => (ICON_MINUS_CIRCLE_AVAILABLE, ICON_MINUS_CIRCLE_CODEPOINTS),
+            Icon::MinusSmall => (ICON_MINUS_SMALL_AVAILABLE, ICON_MINUS_SMALL_CODEPOINTS),
+            Icon::Moon => (ICON_MOON_AVAILABLE, ICON_MOON_CODEPOINTS),
+            Icon::MusicalNote => (ICON_MUSICAL_NOTE_AVAILABLE, ICON_MUSICAL_NOTE_CODEPOINTS),
+            Icon::Newspaper => (ICON_NEWSPAPER_AVAILABLE, ICON_NEWSPAPER_CODEPOINTS),
+            Icon::NoSymbol => (ICON_NO_SYMBOL_AVAILABLE, ICON_NO_SYMBOL_CODEPOINTS),
+            Icon::NumberedList => (ICON_NUMBERED_LIST_AVAILABLE, ICON_NUMBERED_LIST_CODEPOINTS),
+            Icon::PaintBrush => (ICON_PAINT_BRUSH_AVAILABLE, ICON_PAINT_BRUSH_CODEPOINTS),
+            Icon::PaperAirplane => (
+                ICON_PAPER_AIRPLANE_AVAILABLE,
+                ICON_PAPER_AIRPLANE_CODEPOINTS,
+            ),
+            Icon::PaperClip => (ICON_PAPER_CLIP_AVAILABLE, ICON_PAPER_CLIP_CODEPOINTS),
+            Icon::Pause => (ICON_PAUSE_AVAILABLE, ICON_PAUSE_CODEPOINTS),
+            Icon::PauseCircle => (ICON_PAUSE_CIRCLE_AVAILABLE, ICON_PAUSE_CIRCLE_CODEPOINTS),
+            Icon::Pencil => (ICON_PENCIL_AVAILABLE, ICON_PENCIL_CODEPOINTS),
+            Icon::PencilSquare => (ICON_PENCIL_SQUARE_AVAILABLE, ICON_PENCIL_SQUARE_CODEPOINTS),
+            Icon::PercentBadge => (ICON_PERCENT_BADGE_AVAILABLE, ICON_PERCENT_BADGE_CODEPOINTS),
+            Icon::Phone => (ICON_PHONE_AVAILABLE, ICON_PHONE_CODEPOINTS),
+            Icon::PhoneArrowDownLeft => (
+                ICON_PHONE_ARROW_DOWN_LEFT_AVAILABLE,
+                ICON_PHONE_ARROW_DOWN_LEFT_CODEPOINTS,
+            ),
+            Icon::PhoneArrowUpRight => (
+                ICON_PHONE_ARROW_UP_RIGHT_AVAILABLE,
+                ICON_PHONE_ARROW_UP_RIGHT_CODEPOINTS,
+            ),
+            Icon::PhoneXMark => (ICON_PHONE_X_MARK_AVAILABLE, ICON_PHONE_X_MARK_CODEPOINTS),
+            Icon::Photo => (ICON_PHOTO_AVAILABLE, ICON_PHOTO_CODEPOINTS),
+            Icon::Play => (ICON_PLAY_AVAILABLE, ICON_PLAY_CODEPOINTS),
+            Icon::PlayCircle => (ICON_PLAY_CIRCLE_AVAILABLE, ICON_PLAY_CIRCLE_CODEPOINTS),
+            Icon::PlayPause => (ICON_PLAY_PAUSE_AVAILABLE, ICON_PLAY_PAUSE_CODEPOINTS),
+            Icon::Plus => (ICON_PLUS_AVAILABLE, ICON_PLUS_CODEPOINTS),
+            Icon::PlusCircle => (ICON_PLUS_CIRCLE_AVAILABLE, ICON_PLUS_CIRCLE_CODEPOINTS),
+            Icon::PlusSmall => (ICON_PLUS_SMALL_AVAILABLE, ICON_PLUS_SMALL_CODEPOINTS),
+            Icon::Power => (ICON_POWER_AVAILABLE, ICON_POWER_CODEPOINTS),
+            Icon::PresentationChartBar => (
+                ICON_PRESENTATION_CHART_BAR_AVAILABLE,
+                ICON_PRESENTATION_CHART_BAR_CODEPOINTS,
+            ),
+            Icon::PresentationChartLine => (
+                ICON_PRESENTATION_CHART_LINE_AVAILABLE,
+                ICON_PRESENTATION_CHART_LINE_CODEPOINTS,
+            ),
+            Icon::Printer => (ICON_PRINTER_AVAILABLE, ICON_PRINTER_CODEPOINTS),
+            Icon::PuzzlePiece => (ICON_PUZZLE_PIECE_AVAILABLE, ICON_PUZZLE_PIECE_CODEPOINTS),
+            Icon::QrCode => (ICON_QR_CODE_AVAILABLE, ICON_QR_CODE_CODEPOINTS),
+            Icon::QuestionMarkCircle => (
+                ICON_QUESTION_MARK_CIRCLE_AVAILABLE,
+                ICON_QUESTION_MARK_CIRCLE_CODEPOINTS,
+            ),
+            Icon::QueueList => (ICON_QUEUE_LIST_AVAILABLE, ICON_QUEUE_LIST_CODEPOINTS),
+            Icon::Radio => (ICON_RADIO_AVAILABLE, ICON_RADIO_CODEPOINTS),
+            Icon::ReceiptPercent => (
+                ICON_RECEIPT_PERCENT_AVAILABLE,
+                ICON_RECEIPT_PERCENT_CODEPOINTS,
+            ),
+            Icon::ReceiptRefund => (
+                ICON_RECEIPT_REFUND_AVAILABLE,
+                ICON_RECEIPT_REFUND_CODEPOINTS,
+            ),
+            Icon::RectangleGroup => (
+                ICON_RECTANGLE_GROUP_AVAILABLE,
+                ICON_RECTANGLE_GROUP_CODEPOINTS,
+            ),
+            Icon::RectangleStack => (
+                ICON_RECTANGLE_STACK_AVAILABLE,
+                ICON_RECTANGLE_STACK_CODEPOINTS,
+            ),
+            Icon::RocketLaunch => (ICON_ROCKET_LAUNCH_AVAILABLE, ICON_ROCKET_LAUNCH_CODEPOINTS),
+            Icon::Rss => (ICON_RSS_AVAILABLE, ICON_RSS_CODEPOINTS),
+            Icon::Scale => (ICON_SCALE_AVAILABLE, ICON_SCALE_CODEPOINTS),
+            Icon::Scissors => (ICON_SCISSORS_AVAILABLE, ICON_SCISSORS_CODEPOINTS),
+            Icon::Server => (ICON_SERVER_AVAILABLE, ICON_SERVER_CODEPOINTS),
+            Icon::ServerStack => (ICON_SERVER_STACK_AVAILABLE, ICON_SERVER_STACK_CODEPOINTS),
+            Icon::Share => (ICON_SHARE_AVAILABLE, ICON_SHARE_CODEPOINTS),
+            Icon::ShieldCheck => (ICON_SHIELD_CHECK_AVAILABLE, ICON_SHIELD_CHECK_CODEPOINTS),
+            Icon::ShieldExclamation => (
+                ICON_SHIELD_EXCLAMATION_AVAILABLE,
+                ICON_SHIELD_EXCLAMATION_CODEPOINTS,
+            ),
+            Icon::ShoppingBag => (ICON_SHOPPING_BAG_AVAILABLE, ICON_SHOPPING_BAG_CODEPOINTS),
+            Icon::ShoppingCart => (ICON_SHOPPING_CART_AVAILABLE, ICON_SHOPPING_CART_CODEPOINTS),
+            Icon::Signal => (ICON_SIGNAL_AVAILABLE, ICON_SIGNAL_CODEPOINTS),
+            Icon::SignalSlash => (ICON_SIGNAL_SLASH_AVAILABLE, ICON_SIGNAL_SLASH_CODEPOINTS),
+            Icon::Slash => (ICON_SLASH_AVAILABLE, ICON_SLASH_CODEPOINTS),
+            Icon::Sparkles => (ICON_SPARKLES_AVAILABLE, ICON_SPARKLES_CODEPOINTS),
+            Icon::SpeakerWave => (ICON_SPEAKER_WAVE_AVAILABLE, ICON_SPEAKER_WAVE_CODEPOINTS),
+            Icon::SpeakerXMark => (
+                ICON_SPEAKER_X_MARK_AVAILABLE,
+                ICON_SPEAKER_X_MARK_CODEPOINTS,
+            ),
+            Icon::Square2Stack => (
+                ICON_SQUARE_2_STACK_AVAILABLE,
+                ICON_SQUARE_2_STACK_CODEPOINTS,
+            ),
+            Icon::Square3Stack3d => (
+                ICON_SQUARE_3_STACK_3D_AVAILABLE,
+                ICON_SQUARE_3_STACK_3D_CODEPOINTS,
+            ),
+            Icon::Squares2x2 => (ICON_SQUARES_2X_2_AVAILABLE, ICON_SQUARES_2X_2_CODEPOINTS),
+            Icon::SquaresPlus => (ICON_SQUARES_PLUS_AVAILABLE, ICON_SQUARES_PLUS_CODEPOINTS),
+            Icon::Star => (ICON_STAR_AVAILABLE, ICON_STAR_CODEPOINTS),
+            Icon::Stop => (ICON_STOP_AVAILABLE, ICON_STOP_CODEPOINTS),
+            Icon::StopCircle => (ICON_STOP_CIRCLE_AVAILABLE, ICON_STOP_CIRCLE_CODEPOINTS),
+            Icon::Strikethrough => (ICON_STRIKETHROUGH_AVAILABLE, ICON_STRIKETHROUGH_CODEPOINTS),
+            Icon::Sun => (ICON_SUN_AVAILABLE, ICON_SUN_CODEPOINTS),
+            Icon::Swatch => (ICON_SWATCH_AVAILABLE, ICON_SWATCH_CODEPOINTS),
+            Icon::TableCells => (ICON_TABLE_CELLS_AVAILABLE, ICON_TABLE_CELLS_CODEPOINTS),
+            Icon::Tag => (ICON_TAG_AVAILABLE, ICON_TAG_CODEPOINTS),
+            Icon::Ticket => (ICON_TICKET_AVAILABLE, ICON_TICKET_CODEPOINTS),
+            Icon::Trash => (ICON_TRASH_AVAILABLE, ICON_TRASH_CODEPOINTS),
+            Icon::Trophy => (ICON_TROPHY_AVAILABLE, ICON_TROPHY_CODEPOINTS),
+            Icon::Truck => (ICON_TRUCK_AVAILABLE, ICON_TRUCK_CODEPOINTS),
+            Icon::Tv => (ICON_TV_AVAILABLE, ICON_TV_CODEPOINTS),
+            Icon::Underline => (ICON_UNDERLINE_AVAILABLE, ICON_UNDERLINE_CODEPOINTS),
+            Icon::User => (ICON_USER_AVAILABLE, ICON_USER_CODEPOINTS),
+            Icon::UserCircle => (ICON_USER_CIRCLE_AVAILABLE, ICON_USER_CIRCLE_CODEPOINTS),
+            Icon::UserGroup => (ICON_USER_GROUP_AVAILABLE, ICON_USER_GROUP_CODEPOINTS),
+            Icon::UserMinus => (ICON_USER_MINUS_AVAILABLE, ICON_USER_MINUS_CODEPOINTS),
+            Icon::UserPlus => (ICON_USER_PLUS_AVAILABLE, ICON_USER_PLUS_CODEPOINTS),
+            Icon::Users => (ICON_USERS_AVAILABLE, ICON_USERS_CODEPOINTS),
+            Icon::Variable => (ICON_VARIABLE_AVAILABLE, ICON_VARIABLE_CODEPOINTS),
+            Icon::VideoCamera => (ICON_VIDEO_CAMERA_AVAILABLE, ICON_VIDEO_CAMERA_CODEPOINTS),
+            Icon::VideoCameraSlash => (
+                ICON_VIDEO_CAMERA_SLASH_AVAILABLE,
+                ICON_VIDEO_CAMERA_SLASH_CODEPOINTS,
+            ),
+            Icon::ViewColumns => (ICON_VIEW_COLUMNS_AVAILABLE, ICON_VIEW_COLUMNS_CODEPOINTS),
+            Icon::ViewfinderCircle => (
+                ICON_VIEWFINDER_CIRCLE_AVAILABLE,
+                ICON_VIEWFINDER_CIRCLE_CODEPOINTS,
+            ),
+            Icon::Wallet => (ICON_WALLET_AVAILABLE, ICON_WALLET_CODEPOINTS),
+            Icon::Wifi => (ICON_WIFI_AVAILABLE, ICON_WIFI_CODEPOINTS),
+            Icon::Window => (ICON_WINDOW_AVAILABLE, ICON_WINDOW_CODEPOINTS),
+            Icon::Wrench => (ICON_WRENCH_AVAILABLE, ICON_WRENCH_CODEPOINTS),
+            Icon::WrenchScrewdriver => (
+                ICON_WRENCH_SCREWDRIVER_AVAILABLE,
+                ICON_WRENCH_SCREWDRIVER_CODEPOINTS,
+            ),
+            Icon::XCircle => (ICON_X_CIRCLE_AVAILABLE, ICON_X_CIRCLE_CODEPOINTS),
+            Icon::XMark => (ICON_X_MARK_AVAILABLE, ICON_X_MARK_CODEPOINTS),
         }
     }
 }
 
+/// Looks an icon up by its exact map name; unknown names yield `IconError::IconNotFound`.
+///
+/// Binary-searches `ICON_NAMES`, which is sorted and index-aligned with `Icon::ALL`.
+impl TryFrom<&str> for Icon {
+    type Error = crate::core::IconError;
+
+    fn try_from(name: &str) -> Result<Self, crate::core::IconError> {
+        ICON_NAMES
+            .binary_search(&name)
+            .map(|index| Icon::ALL[index])
+            .map_err(|_| crate::core::IconError::IconNotFound {
+                pack: PACK_ID,
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Serializes as the icon's map name, e.g. `"alarm"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Icon {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes from the icon's map name through `TryFrom<&str>`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Icon {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Icon::try_from(name.as_str()).map_err(|_| {
+            <D::Error as serde::de::Error>::custom(format_args!("unknown {PACK_ID} icon '{name}'"))
+        })
+    }
+}
+
+impl From<Icon> for &'static str {
+    fn from(icon: Icon) -> Self {
+        icon.name()
+    }
+}
+
+pub fn icons() -> impl Iterator<Item = Icon> {
+    Icon::ALL.iter().copied()
+}
+
 pub const ICON_NAMES: &[&str] = &[
     "academic-cap",
     "adjustments-horizontal",
@@ -1086,6 +2590,11627 @@ pub const ICON_NAMES: &[&str] = &[
     "x-mark",
 ];
 
+/// Icon names as constants, e.g. for `try_icon(pack, names::ALARM, ..)`.
+pub mod names {
+    pub const ACADEMIC_CAP: &str = "academic-cap";
+    pub const ADJUSTMENTS_HORIZONTAL: &str = "adjustments-horizontal";
+    pub const ADJUSTMENTS_VERTICAL: &str = "adjustments-vertical";
+    pub const ARCHIVE_BOX: &str = "archive-box";
+    pub const ARCHIVE_BOX_ARROW_DOWN: &str = "archive-box-arrow-down";
+    pub const ARCHIVE_BOX_X_MARK: &str = "archive-box-x-mark";
+    pub const ARROW_DOWN: &str = "arrow-down";
+    pub const ARROW_DOWN_CIRCLE: &str = "arrow-down-circle";
+    pub const ARROW_DOWN_LEFT: &str = "arrow-down-left";
+    pub const ARROW_DOWN_ON_SQUARE: &str = "arrow-down-on-square";
+    pub const ARROW_DOWN_ON_SQUARE_STACK: &str = "arrow-down-on-square-stack";
+    pub const ARROW_DOWN_RIGHT: &str = "arrow-down-right";
+    pub const ARROW_DOWN_TRAY: &str = "arrow-down-tray";
+    pub const ARROW_LEFT: &str = "arrow-left";
+    pub const ARROW_LEFT_CIRCLE: &str = "arrow-left-circle";
+    pub const ARROW_LEFT_END_ON_RECTANGLE: &str = "arrow-left-end-on-rectangle";
+    pub const ARROW_LEFT_ON_RECTANGLE: &str = "arrow-left-on-rectangle";
+    pub const ARROW_LEFT_START_ON_RECTANGLE: &str = "arrow-left-start-on-rectangle";
+    pub const ARROW_LONG_DOWN: &str = "arrow-long-down";
+    pub const ARROW_LONG_LEFT: &str = "arrow-long-left";
+    pub const ARROW_LONG_RIGHT: &str = "arrow-long-right";
+    pub const ARROW_LONG_UP: &str = "arrow-long-up";
+    pub const ARROW_PATH: &str = "arrow-path";
+    pub const ARROW_PATH_ROUNDED_SQUARE: &str = "arrow-path-rounded-square";
+    pub const ARROW_RIGHT: &str = "arrow-right";
+    pub const ARROW_RIGHT_CIRCLE: &str = "arrow-right-circle";
+    pub const ARROW_RIGHT_END_ON_RECTANGLE: &str = "arrow-right-end-on-rectangle";
+    pub const ARROW_RIGHT_ON_RECTANGLE: &str = "arrow-right-on-rectangle";
+    pub const ARROW_RIGHT_START_ON_RECTANGLE: &str = "arrow-right-start-on-rectangle";
+    pub const ARROW_SMALL_DOWN: &str = "arrow-small-down";
+    pub const ARROW_SMALL_LEFT: &str = "arrow-small-left";
+    pub const ARROW_SMALL_RIGHT: &str = "arrow-small-right";
+    pub const ARROW_SMALL_UP: &str = "arrow-small-up";
+    pub const ARROW_TOP_RIGHT_ON_SQUARE: &str = "arrow-top-right-on-square";
+    pub const ARROW_TRENDING_DOWN: &str = "arrow-trending-down";
+    pub const ARROW_TRENDING_UP: &str = "arrow-trending-up";
+    pub const ARROW_TURN_DOWN_LEFT: &str = "arrow-turn-down-left";
+    pub const ARROW_TURN_DOWN_RIGHT: &str = "arrow-turn-down-right";
+    pub const ARROW_TURN_LEFT_DOWN: &str = "arrow-turn-left-down";
+    pub const ARROW_TURN_LEFT_UP: &str = "arrow-turn-left-up";
+    pub const ARROW_TURN_RIGHT_DOWN: &str = "arrow-turn-right-down";
+    pub const ARROW_TURN_RIGHT_UP: &str = "arrow-turn-right-up";
+    pub const ARROW_TURN_UP_LEFT: &str = "arrow-turn-up-left";
+    pub const ARROW_TURN_UP_RIGHT: &str = "arrow-turn-up-right";
+    pub const ARROW_UP: &str = "arrow-up";
+    pub const ARROW_UP_CIRCLE: &str = "arrow-up-circle";
+    pub const ARROW_UP_LEFT: &str = "arrow-up-left";
+    pub const ARROW_UP_ON_SQUARE: &str = "arrow-up-on-square";
+    pub const ARROW_UP_ON_SQUARE_STACK: &str = "arrow-up-on-square-stack";
+    pub const ARROW_UP_RIGHT: &str = "arrow-up-right";
+    pub const ARROW_UP_TRAY: &str = "arrow-up-tray";
+    pub const ARROW_UTURN_DOWN: &str = "arrow-uturn-down";
+    pub const ARROW_UTURN_LEFT: &str = "arrow-uturn-left";
+    pub const ARROW_UTURN_RIGHT: &str = "arrow-uturn-right";
+    pub const ARROW_UTURN_UP: &str = "arrow-uturn-up";
+    pub const ARROWS_POINTING_IN: &str = "arrows-pointing-in";
+    pub const ARROWS_POINTING_OUT: &str = "arrows-pointing-out";
+    pub const ARROWS_RIGHT_LEFT: &str = "arrows-right-left";
+    pub const ARROWS_UP_DOWN: &str = "arrows-up-down";
+    pub const AT_SYMBOL: &str = "at-symbol";
+    pub const BACKSPACE: &str = "backspace";
+    pub const BACKWARD: &str = "backward";
+    pub const BANKNOTES: &str = "banknotes";
+    pub const BARS_2: &str = "bars-2";
+    pub const BARS_3: &str = "bars-3";
+    pub const BARS_3_BOTTOM_LEFT: &str = "bars-3-bottom-left";
+    pub const BARS_3_BOTTOM_RIGHT: &str = "bars-3-bottom-right";
+    pub const BARS_3_CENTER_LEFT: &str = "bars-3-center-left";
+    pub const BARS_4: &str = "bars-4";
+    pub const BARS_ARROW_DOWN: &str = "bars-arrow-down";
+    pub const BARS_ARROW_UP: &str = "bars-arrow-up";
+    pub const BATTERY_0: &str = "battery-0";
+    pub const BATTERY_100: &str = "battery-100";
+    pub const BATTERY_50: &str = "battery-50";
+    pub const BEAKER: &str = "beaker";
+    pub const BELL: &str = "bell";
+    pub const BELL_ALERT: &str = "bell-alert";
+    pub const BELL_SLASH: &str = "bell-slash";
+    pub const BELL_SNOOZE: &str = "bell-snooze";
+    pub const BOLD: &str = "bold";
+    pub const BOLT: &str = "bolt";
+    pub const BOLT_SLASH: &str = "bolt-slash";
+    pub const BOOK_OPEN: &str = "book-open";
+    pub const BOOKMARK: &str = "bookmark";
+    pub const BOOKMARK_SLASH: &str = "bookmark-slash";
+    pub const BOOKMARK_SQUARE: &str = "bookmark-square";
+    pub const BRIEFCASE: &str = "briefcase";
+    pub const BUG_ANT: &str = "bug-ant";
+    pub const BUILDING_LIBRARY: &str = "building-library";
+    pub const BUILDING_OFFICE: &str = "building-office";
+    pub const BUILDING_OFFICE_2: &str = "building-office-2";
+    pub const BUILDING_STOREFRONT: &str = "building-storefront";
+    pub const CAKE: &str = "cake";
+    pub const CALCULATOR: &str = "calculator";
+    pub const CALENDAR: &str = "calendar";
+    pub const CALENDAR_DATE_RANGE: &str = "calendar-date-range";
+    pub const CALENDAR_DAYS: &str = "calendar-days";
+    pub const CAMERA: &str = "camera";
+    pub const CHART_BAR: &str = "chart-bar";
+    pub const CHART_BAR_SQUARE: &str = "chart-bar-square";
+    pub const CHART_PIE: &str = "chart-pie";
+    pub const CHAT_BUBBLE_BOTTOM_CENTER: &str = "chat-bubble-bottom-center";
+    pub const CHAT_BUBBLE_BOTTOM_CENTER_TEXT: &str = "chat-bubble-bottom-center-text";
+    pub const CHAT_BUBBLE_LEFT: &str = "chat-bubble-left";
+    pub const CHAT_BUBBLE_LEFT_ELLIPSIS: &str = "chat-bubble-left-ellipsis";
+    pub const CHAT_BUBBLE_LEFT_RIGHT: &str = "chat-bubble-left-right";
+    pub const CHAT_BUBBLE_OVAL_LEFT: &str = "chat-bubble-oval-left";
+    pub const CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS: &str = "chat-bubble-oval-left-ellipsis";
+    pub const CHECK: &str = "check";
+    pub const CHECK_BADGE: &str = "check-badge";
+    pub const CHECK_CIRCLE: &str = "check-circle";
+    pub const CHEVRON_DOUBLE_DOWN: &str = "chevron-double-down";
+    pub const CHEVRON_DOUBLE_LEFT: &str = "chevron-double-left";
+    pub const CHEVRON_DOUBLE_RIGHT: &str = "chevron-double-right";
+    pub const CHEVRON_DOUBLE_UP: &str = "chevron-double-up";
+    pub const CHEVRON_DOWN: &str = "chevron-down";
+    pub const CHEVRON_LEFT: &str = "chevron-left";
+    pub const CHEVRON_RIGHT: &str = "chevron-right";
+    pub const CHEVRON_UP: &str = "chevron-up";
+    pub const CHEVRON_UP_DOWN: &str = "chevron-up-down";
+    pub const CIRCLE_STACK: &str = "circle-stack";
+    pub const CLIPBOARD: &str = "clipboard";
+    pub const CLIPBOARD_DOCUMENT: &str = "clipboard-document";
+    pub const CLIPBOARD_DOCUMENT_CHECK: &str = "clipboard-document-check";
+    pub const CLIPBOARD_DOCUMENT_LIST: &str = "clipboard-document-list";
+    pub const CLOCK: &str = "clock";
+    pub const CLOUD: &str = "cloud";
+    pub const CLOUD_ARROW_DOWN: &str = "cloud-arrow-down";
+    pub const CLOUD_ARROW_UP: &str = "cloud-arrow-up";
+    pub const CODE_BRACKET: &str = "code-bracket";
+    pub const CODE_BRACKET_SQUARE: &str = "code-bracket-square";
+    pub const COG: &str = "cog";
+    pub const COG_6_TOOTH: &str = "cog-6-tooth";
+    pub const COG_8_TOOTH: &str = "cog-8-tooth";
+    pub const COMMAND_LINE: &str = "command-line";
+    pub const COMPUTER_DESKTOP: &str = "computer-desktop";
+    pub const CPU_CHIP: &str = "cpu-chip";
+    pub const CREDIT_CARD: &str = "credit-card";
+    pub const CUBE: &str = "cube";
+    pub const CUBE_TRANSPARENT: &str = "cube-transparent";
+    pub const CURRENCY_BANGLADESHI: &str = "currency-bangladeshi";
+    pub const CURRENCY_DOLLAR: &str = "currency-dollar";
+    pub const CURRENCY_EURO: &str = "currency-euro";
+    pub const CURRENCY_POUND: &str = "currency-pound";
+    pub const CURRENCY_RUPEE: &str = "currency-rupee";
+    pub const CURRENCY_YEN: &str = "currency-yen";
+    pub const CURSOR_ARROW_RAYS: &str = "cursor-arrow-rays";
+    pub const CURSOR_ARROW_RIPPLE: &str = "cursor-arrow-ripple";
+    pub const DEVICE_PHONE_MOBILE: &str = "device-phone-mobile";
+    pub const DEVICE_TABLET: &str = "device-tablet";
+    pub const DIVIDE: &str = "divide";
+    pub const DOCUMENT: &str = "document";
+    pub const DOCUMENT_ARROW_DOWN: &str = "document-arrow-down";
+    pub const DOCUMENT_ARROW_UP: &str = "document-arrow-up";
+    pub const DOCUMENT_CHART_BAR: &str = "document-chart-bar";
+    pub const DOCUMENT_CHECK: &str = "document-check";
+    pub const DOCUMENT_CURRENCY_BANGLADESHI: &str = "document-currency-bangladeshi";
+    pub const DOCUMENT_CURRENCY_DOLLAR: &str = "document-currency-dollar";
+    pub const DOCUMENT_CURRENCY_EURO: &str = "document-currency-euro";
+    pub const DOCUMENT_CURRENCY_POUND: &str = "document-currency-pound";
+    pub const DOCUMENT_CURRENCY_RUPEE: &str = "document-currency-rupee";
+    pub const DOCUMENT_CURRENCY_YEN: &str = "document-currency-yen";
+    pub const DOCUMENT_DUPLICATE: &str = "document-duplicate";
+    pub const DOCUMENT_MAGNIFYING_GLASS: &str = "document-magnifying-glass";
+    pub const DOCUMENT_MINUS: &str = "document-minus";
+    pub const DOCUMENT_PLUS: &str = "document-plus";
+    pub const DOCUMENT_TEXT: &str = "document-text";
+    pub const ELLIPSIS_HORIZONTAL: &str = "ellipsis-horizontal";
+    pub const ELLIPSIS_HORIZONTAL_CIRCLE: &str = "ellipsis-horizontal-circle";
+    pub const ELLIPSIS_VERTICAL: &str = "ellipsis-vertical";
+    pub const ENVELOPE: &str = "envelope";
+    pub const ENVELOPE_OPEN: &str = "envelope-open";
+    pub const EQUALS: &str = "equals";
+    pub const EXCLAMATION_CIRCLE: &str = "exclamation-circle";
+    pub const EXCLAMATION_TRIANGLE: &str = "exclamation-triangle";
+    pub const EYE: &str = "eye";
+    pub const EYE_DROPPER: &str = "eye-dropper";
+    pub const EYE_SLASH: &str = "eye-slash";
+    pub const FACE_FROWN: &str = "face-frown";
+    pub const FACE_SMILE: &str = "face-smile";
+    pub const FILM: &str = "film";
+    pub const FINGER_PRINT: &str = "finger-print";
+    pub const FIRE: &str = "fire";
+    pub const FLAG: &str = "flag";
+    pub const FOLDER: &str = "folder";
+    pub const FOLDER_ARROW_DOWN: &str = "folder-arrow-down";
+    pub const FOLDER_MINUS: &str = "folder-minus";
+    pub const FOLDER_OPEN: &str = "folder-open";
+    pub const FOLDER_PLUS: &str = "folder-plus";
+    pub const FORWARD: &str = "forward";
+    pub const FUNNEL: &str = "funnel";
+    pub const GIF: &str = "gif";
+    pub const GIFT: &str = "gift";
+    pub const GIFT_TOP: &str = "gift-top";
+    pub const GLOBE_ALT: &str = "globe-alt";
+    pub const GLOBE_AMERICAS: &str = "globe-americas";
+    pub const GLOBE_ASIA_AUSTRALIA: &str = "globe-asia-australia";
+    pub const GLOBE_EUROPE_AFRICA: &str = "globe-europe-africa";
+    pub const H_1: &str = "h1";
+    pub const H_2: &str = "h2";
+    pub const H_3: &str = "h3";
+    pub const HAND_RAISED: &str = "hand-raised";
+    pub const HAND_THUMB_DOWN: &str = "hand-thumb-down";
+    pub const HAND_THUMB_UP: &str = "hand-thumb-up";
+    pub const HASHTAG: &str = "hashtag";
+    pub const HEART: &str = "heart";
+    pub const HOME: &str = "home";
+    pub const HOME_MODERN: &str = "home-modern";
+    pub const IDENTIFICATION: &str = "identification";
+    pub const INBOX: &str = "inbox";
+    pub const INBOX_ARROW_DOWN: &str = "inbox-arrow-down";
+    pub const INBOX_STACK: &str = "inbox-stack";
+    pub const INFORMATION_CIRCLE: &str = "information-circle";
+    pub const ITALIC: &str = "italic";
+    pub const KEY: &str = "key";
+    pub const LANGUAGE: &str = "language";
+    pub const LIFEBUOY: &str = "lifebuoy";
+    pub const LIGHT_BULB: &str = "light-bulb";
+    pub const LINK: &str = "link";
+    pub const LINK_SLASH: &str = "link-slash";
+    pub const LIST_BULLET: &str = "list-bullet";
+    pub const LOCK_CLOSED: &str = "lock-closed";
+    pub const LOCK_OPEN: &str = "lock-open";
+    pub const MAGNIFYING_GLASS: &str = "magnifying-glass";
+    pub const MAGNIFYING_GLASS_CIRCLE: &str = "magnifying-glass-circle";
+    pub const MAGNIFYING_GLASS_MINUS: &str = "magnifying-glass-minus";
+    pub const MAGNIFYING_GLASS_PLUS: &str = "magnifying-glass-plus";
+    pub const MAP: &str = "map";
+    pub const MAP_PIN: &str = "map-pin";
+    pub const MEGAPHONE: &str = "megaphone";
+    pub const MICROPHONE: &str = "microphone";
+    pub const MINUS: &str = "minus";
+    pub const MINUS_CIRCLE: &str = "minus-circle";
+    pub const MINUS_SMALL: &str = "minus-small";
+    pub const MOON: &str = "moon";
+    pub const MUSICAL_NOTE: &str = "musical-note";
+    pub const NEWSPAPER: &str = "newspaper";
+    pub const NO_SYMBOL: &str = "no-symbol";
+    pub const NUMBERED_LIST: &str = "numbered-list";
+    pub const PAINT_BRUSH: &str = "paint-brush";
+    pub const PAPER_AIRPLANE: &str = "paper-airplane";
+    pub const PAPER_CLIP: &str = "paper-clip";
+    pub const PAUSE: &str = "pause";
+    pub const PAUSE_CIRCLE: &str = "pause-circle";
+    pub const PENCIL: &str = "pencil";
+    pub const PENCIL_SQUARE: &str = "pencil-square";
+    pub const PERCENT_BADGE: &str = "percent-badge";
+    pub const PHONE: &str = "phone";
+    pub const PHONE_ARROW_DOWN_LEFT: &str = "phone-arrow-down-left";
+    pub const PHONE_ARROW_UP_RIGHT: &str = "phone-arrow-up-right";
+    pub const PHONE_X_MARK: &str = "phone-x-mark";
+    pub const PHOTO: &str = "photo";
+    pub const PLAY: &str = "play";
+    pub const PLAY_CIRCLE: &str = "play-circle";
+    pub const PLAY_PAUSE: &str = "play-pause";
+    pub const PLUS: &str = "plus";
+    pub const PLUS_CIRCLE: &str = "plus-circle";
+    pub const PLUS_SMALL: &str = "plus-small";
+    pub const POWER: &str = "power";
+    pub const PRESENTATION_CHART_BAR: &str = "presentation-chart-bar";
+    pub const PRESENTATION_CHART_LINE: &str = "presentation-chart-line";
+    pub const PRINTER: &str = "printer";
+    pub const PUZZLE_PIECE: &str = "puzzle-piece";
+    pub const QR_CODE: &str = "qr-code";
+    pub const QUESTION_MARK_CIRCLE: &str = "question-mark-circle";
+    pub const QUEUE_LIST: &str = "queue-list";
+    pub const RADIO: &str = "radio";
+    pub const RECEIPT_PERCENT: &str = "receipt-percent";
+    pub const RECEIPT_REFUND: &str = "receipt-refund";
+    pub const RECTANGLE_GROUP: &str = "rectangle-group";
+    pub const RECTANGLE_STACK: &str = "rectangle-stack";
+    pub const ROCKET_LAUNCH: &str = "rocket-launch";
+    pub const RSS: &str = "rss";
+    pub const SCALE: &str = "scale";
+    pub const SCISSORS: &str = "scissors";
+    pub const SERVER: &str = "server";
+    pub const SERVER_STACK: &str = "server-stack";
+    pub const SHARE: &str = "share";
+    pub const SHIELD_CHECK: &str = "shield-check";
+    pub const SHIELD_EXCLAMATION: &str = "shield-exclamation";
+    pub const SHOPPING_BAG: &str = "shopping-bag";
+    pub const SHOPPING_CART: &str = "shopping-cart";
+    pub const SIGNAL: &str = "signal";
+    pub const SIGNAL_SLASH: &str = "signal-slash";
+    pub const SLASH: &str = "slash";
+    pub const SPARKLES: &str = "sparkles";
+    pub const SPEAKER_WAVE: &str = "speaker-wave";
+    pub const SPEAKER_X_MARK: &str = "speaker-x-mark";
+    pub const SQUARE_2_STACK: &str = "square-2-stack";
+    pub const SQUARE_3_STACK_3D: &str = "square-3-stack-3d";
+    pub const SQUARES_2X_2: &str = "squares-2x2";
+    pub const SQUARES_PLUS: &str = "squares-plus";
+    pub const STAR: &str = "star";
+    pub const STOP: &str = "stop";
+    pub const STOP_CIRCLE: &str = "stop-circle";
+    pub const STRIKETHROUGH: &str = "strikethrough";
+    pub const SUN: &str = "sun";
+    pub const SWATCH: &str = "swatch";
+    pub const TABLE_CELLS: &str = "table-cells";
+    pub const TAG: &str = "tag";
+    pub const TICKET: &str = "ticket";
+    pub const TRASH: &str = "trash";
+    pub const TROPHY: &str = "trophy";
+    pub const TRUCK: &str = "truck";
+    pub const TV: &str = "tv";
+    pub const UNDERLINE: &str = "underline";
+    pub const USER: &str = "user";
+    pub const USER_CIRCLE: &str = "user-circle";
+    pub const USER_GROUP: &str = "user-group";
+    pub const USER_MINUS: &str = "user-minus";
+    pub const USER_PLUS: &str = "user-plus";
+    pub const USERS: &str = "users";
+    pub const VARIABLE: &str = "variable";
+    pub const VIDEO_CAMERA: &str = "video-camera";
+    pub const VIDEO_CAMERA_SLASH: &str = "video-camera-slash";
+    pub const VIEW_COLUMNS: &str = "view-columns";
+    pub const VIEWFINDER_CIRCLE: &str = "viewfinder-circle";
+    pub const WALLET: &str = "wallet";
+    pub const WIFI: &str = "wifi";
+    pub const WINDOW: &str = "window";
+    pub const WRENCH: &str = "wrench";
+    pub const WRENCH_SCREWDRIVER: &str = "wrench-screwdriver";
+    pub const X_CIRCLE: &str = "x-circle";
+    pub const X_MARK: &str = "x-mark";
+}
+
+pub(crate) const ICON_NAMES_LOWERCASE: &[(&str, &str)] = &[
+    ("academic-cap", "academic-cap"),
+    ("adjustments-horizontal", "adjustments-horizontal"),
+    ("adjustments-vertical", "adjustments-vertical"),
+    ("archive-box", "archive-box"),
+    ("archive-box-arrow-down", "archive-box-arrow-down"),
+    ("archive-box-x-mark", "archive-box-x-mark"),
+    ("arrow-down", "arrow-down"),
+    ("arrow-down-circle", "arrow-down-circle"),
+    ("arrow-down-left", "arrow-down-left"),
+    ("arrow-down-on-square", "arrow-down-on-square"),
+    ("arrow-down-on-square-stack", "arrow-down-on-square-stack"),
+    ("arrow-down-right", "arrow-down-right"),
+    ("arrow-down-tray", "arrow-down-tray"),
+    ("arrow-left", "arrow-left"),
+    ("arrow-left-circle", "arrow-left-circle"),
+    ("arrow-left-end-on-rectangle", "arrow-left-end-on-rectangle"),
+    ("arrow-left-on-rectangle", "arrow-left-on-rectangle"),
+    (
+        "arrow-left-start-on-rectangle",
+        "arrow-left-start-on-rectangle",
+    ),
+    ("arrow-long-down", "arrow-long-down"),
+    ("arrow-long-left", "arrow-long-left"),
+    ("arrow-long-right", "arrow-long-right"),
+    ("arrow-long-up", "arrow-long-up"),
+    ("arrow-path", "arrow-path"),
+    ("arrow-path-rounded-square", "arrow-path-rounded-square"),
+    ("arrow-right", "arrow-right"),
+    ("arrow-right-circle", "arrow-right-circle"),
+    (
+        "arrow-right-end-on-rectangle",
+        "arrow-right-end-on-rectangle",
+    ),
+    ("arrow-right-on-rectangle", "arrow-right-on-rectangle"),
+    (
+        "arrow-right-start-on-rectangle",
+        "arrow-right-start-on-rectangle",
+    ),
+    ("arrow-small-down", "arrow-small-down"),
+    ("arrow-small-left", "arrow-small-left"),
+    ("arrow-small-right", "arrow-small-right"),
+    ("arrow-small-up", "arrow-small-up"),
+    ("arrow-top-right-on-square", "arrow-top-right-on-square"),
+    ("arrow-trending-down", "arrow-trending-down"),
+    ("arrow-trending-up", "arrow-trending-up"),
+    ("arrow-turn-down-left", "arrow-turn-down-left"),
+    ("arrow-turn-down-right", "arrow-turn-down-right"),
+    ("arrow-turn-left-down", "arrow-turn-left-down"),
+    ("arrow-turn-left-up", "arrow-turn-left-up"),
+    ("arrow-turn-right-down", "arrow-turn-right-down"),
+    ("arrow-turn-right-up", "arrow-turn-right-up"),
+    ("arrow-turn-up-left", "arrow-turn-up-left"),
+    ("arrow-turn-up-right", "arrow-turn-up-right"),
+    ("arrow-up", "arrow-up"),
+    ("arrow-up-circle", "arrow-up-circle"),
+    ("arrow-up-left", "arrow-up-left"),
+    ("arrow-up-on-square", "arrow-up-on-square"),
+    ("arrow-up-on-square-stack", "arrow-up-on-square-stack"),
+    ("arrow-up-right", "arrow-up-right"),
+    ("arrow-up-tray", "arrow-up-tray"),
+    ("arrow-uturn-down", "arrow-uturn-down"),
+    ("arrow-uturn-left", "arrow-uturn-left"),
+    ("arrow-uturn-right", "arrow-uturn-right"),
+    ("arrow-uturn-up", "arrow-uturn-up"),
+    ("arrows-pointing-in", "arrows-pointing-in"),
+    ("arrows-pointing-out", "arrows-pointing-out"),
+    ("arrows-right-left", "arrows-right-left"),
+    ("arrows-up-down", "arrows-up-down"),
+    ("at-symbol", "at-symbol"),
+    ("backspace", "backspace"),
+    ("backward", "backward"),
+    ("banknotes", "banknotes"),
+    ("bars-2", "bars-2"),
+    ("bars-3", "bars-3"),
+    ("bars-3-bottom-left", "bars-3-bottom-left"),
+    ("bars-3-bottom-right", "bars-3-bottom-right"),
+    ("bars-3-center-left", "bars-3-center-left"),
+    ("bars-4", "bars-4"),
+    ("bars-arrow-down", "bars-arrow-down"),
+    ("bars-arrow-up", "bars-arrow-up"),
+    ("battery-0", "battery-0"),
+    ("battery-100", "battery-100"),
+    ("battery-50", "battery-50"),
+    ("beaker", "beaker"),
+    ("bell", "bell"),
+    ("bell-alert", "bell-alert"),
+    ("bell-slash", "bell-slash"),
+    ("bell-snooze", "bell-snooze"),
+    ("bold", "bold"),
+    ("bolt", "bolt"),
+    ("bolt-slash", "bolt-slash"),
+    ("book-open", "book-open"),
+    ("bookmark", "bookmark"),
+    ("bookmark-slash", "bookmark-slash"),
+    ("bookmark-square", "bookmark-square"),
+    ("briefcase", "briefcase"),
+    ("bug-ant", "bug-ant"),
+    ("building-library", "building-library"),
+    ("building-office", "building-office"),
+    ("building-office-2", "building-office-2"),
+    ("building-storefront", "building-storefront"),
+    ("cake", "cake"),
+    ("calculator", "calculator"),
+    ("calendar", "calendar"),
+    ("calendar-date-range", "calendar-date-range"),
+    ("calendar-days", "calendar-days"),
+    ("camera", "camera"),
+    ("chart-bar", "chart-bar"),
+    ("chart-bar-square", "chart-bar-square"),
+    ("chart-pie", "chart-pie"),
+    ("chat-bubble-bottom-center", "chat-bubble-bottom-center"),
+    (
+        "chat-bubble-bottom-center-text",
+        "chat-bubble-bottom-center-text",
+    ),
+    ("chat-bubble-left", "chat-bubble-left"),
+    ("chat-bubble-left-ellipsis", "chat-bubble-left-ellipsis"),
+    ("chat-bubble-left-right", "chat-bubble-left-right"),
+    ("chat-bubble-oval-left", "chat-bubble-oval-left"),
+    (
+        "chat-bubble-oval-left-ellipsis",
+        "chat-bubble-oval-left-ellipsis",
+    ),
+    ("check", "check"),
+    ("check-badge", "check-badge"),
+    ("check-circle", "check-circle"),
+    ("chevron-double-down", "chevron-double-down"),
+    ("chevron-double-left", "chevron-double-left"),
+    ("chevron-double-right", "chevron-double-right"),
+    ("chevron-double-up", "chevron-double-up"),
+    ("chevron-down", "chevron-down"),
+    ("chevron-left", "chevron-left"),
+    ("chevron-right", "chevron-right"),
+    ("chevron-up", "chevron-up"),
+    ("chevron-up-down", "chevron-up-down"),
+    ("circle-stack", "circle-stack"),
+    ("clipboard", "clipboard"),
+    ("clipboard-document", "clipboard-document"),
+    ("clipboard-document-check", "clipboard-document-check"),
+    ("clipboard-document-list", "clipboard-document-list"),
+    ("clock", "clock"),
+    ("cloud", "cloud"),
+    ("cloud-arrow-down", "cloud-arrow-down"),
+    ("cloud-arrow-up", "cloud-arrow-up"),
+    ("code-bracket", "code-bracket"),
+    ("code-bracket-square", "code-bracket-square"),
+    ("cog", "cog"),
+    ("cog-6-tooth", "cog-6-tooth"),
+    ("cog-8-tooth", "cog-8-tooth"),
+    ("command-line", "command-line"),
+    ("computer-desktop", "computer-desktop"),
+    ("cpu-chip", "cpu-chip"),
+    ("credit-card", "credit-card"),
+    ("cube", "cube"),
+    ("cube-transparent", "cube-transparent"),
+    ("currency-bangladeshi", "currency-bangladeshi"),
+    ("currency-dollar", "currency-dollar"),
+    ("currency-euro", "currency-euro"),
+    ("currency-pound", "currency-pound"),
+    ("currency-rupee", "currency-rupee"),
+    ("currency-yen", "currency-yen"),
+    ("cursor-arrow-rays", "cursor-arrow-rays"),
+    ("cursor-arrow-ripple", "cursor-arrow-ripple"),
+    ("device-phone-mobile", "device-phone-mobile"),
+    ("device-tablet", "device-tablet"),
+    ("divide", "divide"),
+    ("document", "document"),
+    ("document-arrow-down", "document-arrow-down"),
+    ("document-arrow-up", "document-arrow-up"),
+    ("document-chart-bar", "document-chart-bar"),
+    ("document-check", "document-check"),
+    (
+        "document-currency-bangladeshi",
+        "document-currency-bangladeshi",
+    ),
+    ("document-currency-dollar", "document-currency-dollar"),
+    ("document-currency-euro", "document-currency-euro"),
+    ("document-currency-pound", "document-currency-pound"),
+    ("document-currency-rupee", "document-currency-rupee"),
+    ("document-currency-yen", "document-currency-yen"),
+    ("document-duplicate", "document-duplicate"),
+    ("document-magnifying-glass", "document-magnifying-glass"),
+    ("document-minus", "document-minus"),
+    ("document-plus", "document-plus"),
+    ("document-text", "document-text"),
+    ("ellipsis-horizontal", "ellipsis-horizontal"),
+    ("ellipsis-horizontal-circle", "ellipsis-horizontal-circle"),
+    ("ellipsis-vertical", "ellipsis-vertical"),
+    ("envelope", "envelope"),
+    ("envelope-open", "envelope-open"),
+    ("equals", "equals"),
+    ("exclamation-circle", "exclamation-circle"),
+    ("exclamation-triangle", "exclamation-triangle"),
+    ("eye", "eye"),
+    ("eye-dropper", "eye-dropper"),
+    ("eye-slash", "eye-slash"),
+    ("face-frown", "face-frown"),
+    ("face-smile", "face-smile"),
+    ("film", "film"),
+    ("finger-print", "finger-print"),
+    ("fire", "fire"),
+    ("flag", "flag"),
+    ("folder", "folder"),
+    ("folder-arrow-down", "folder-arrow-down"),
+    ("folder-minus", "folder-minus"),
+    ("folder-open", "folder-open"),
+    ("folder-plus", "folder-plus"),
+    ("forward", "forward"),
+    ("funnel", "funnel"),
+    ("gif", "gif"),
+    ("gift", "gift"),
+    ("gift-top", "gift-top"),
+    ("globe-alt", "globe-alt"),
+    ("globe-americas", "globe-americas"),
+    ("globe-asia-australia", "globe-asia-australia"),
+    ("globe-europe-africa", "globe-europe-africa"),
+    ("h1", "h1"),
+    ("h2", "h2"),
+    ("h3", "h3"),
+    ("hand-raised", "hand-raised"),
+    ("hand-thumb-down", "hand-thumb-down"),
+    ("hand-thumb-up", "hand-thumb-up"),
+    ("hashtag", "hashtag"),
+    ("heart", "heart"),
+    ("home", "home"),
+    ("home-modern", "home-modern"),
+    ("identification", "identification"),
+    ("inbox", "inbox"),
+    ("inbox-arrow-down", "inbox-arrow-down"),
+    ("inbox-stack", "inbox-stack"),
+    ("information-circle", "information-circle"),
+    ("italic", "italic"),
+    ("key", "key"),
+    ("language", "language"),
+    ("lifebuoy", "lifebuoy"),
+    ("light-bulb", "light-bulb"),
+    ("link", "link"),
+    ("link-slash", "link-slash"),
+    ("list-bullet", "list-bullet"),
+    ("lock-closed", "lock-closed"),
+    ("lock-open", "lock-open"),
+    ("magnifying-glass", "magnifying-glass"),
+    ("magnifying-glass-circle", "magnifying-glass-circle"),
+    ("magnifying-glass-minus", "magnifying-glass-minus"),
+    ("magnifying-glass-plus", "magnifying-glass-plus"),
+    ("map", "map"),
+    ("map-pin", "map-pin"),
+    ("megaphone", "megaphone"),
+    ("microphone", "microphone"),
+    ("minus", "minus"),
+    ("minus-circle", "minus-circle"),
+    ("minus-small", "minus-small"),
+    ("moon", "moon"),
+    ("musical-note", "musical-note"),
+    ("newspaper", "newspaper"),
+    ("no-symbol", "no-symbol"),
+    ("numbered-list", "numbered-list"),
+    ("paint-brush", "paint-brush"),
+    ("paper-airplane", "paper-airplane"),
+    ("paper-clip", "paper-clip"),
+    ("pause", "pause"),
+    ("pause-circle", "pause-circle"),
+    ("pencil", "pencil"),
+    ("pencil-square", "pencil-square"),
+    ("percent-badge", "percent-badge"),
+    ("phone", "phone"),
+    ("phone-arrow-down-left", "phone-arrow-down-left"),
+    ("phone-arrow-up-right", "phone-arrow-up-right"),
+    ("phone-x-mark", "phone-x-mark"),
+    ("photo", "photo"),
+    ("play", "play"),
+    ("play-circle", "play-circle"),
+    ("play-pause", "play-pause"),
+    ("plus", "plus"),
+    ("plus-circle", "plus-circle"),
+    ("plus-small", "plus-small"),
+    ("power", "power"),
+    ("presentation-chart-bar", "presentation-chart-bar"),
+    ("presentation-chart-line", "presentation-chart-line"),
+    ("printer", "printer"),
+    ("puzzle-piece", "puzzle-piece"),
+    ("qr-code", "qr-code"),
+    ("question-mark-circle", "question-mark-circle"),
+    ("queue-list", "queue-list"),
+    ("radio", "radio"),
+    ("receipt-percent", "receipt-percent"),
+    ("receipt-refund", "receipt-refund"),
+    ("rectangle-group", "rectangle-group"),
+    ("rectangle-stack", "rectangle-stack"),
+    ("rocket-launch", "rocket-launch"),
+    ("rss", "rss"),
+    ("scale", "scale"),
+    ("scissors", "scissors"),
+    ("server", "server"),
+    ("server-stack", "server-stack"),
+    ("share", "share"),
+    ("shield-check", "shield-check"),
+    ("shield-exclamation", "shield-exclamation"),
+    ("shopping-bag", "shopping-bag"),
+    ("shopping-cart", "shopping-cart"),
+    ("signal", "signal"),
+    ("signal-slash", "signal-slash"),
+    ("slash", "slash"),
+    ("sparkles", "sparkles"),
+    ("speaker-wave", "speaker-wave"),
+    ("speaker-x-mark", "speaker-x-mark"),
+    ("square-2-stack", "square-2-stack"),
+    ("square-3-stack-3d", "square-3-stack-3d"),
+    ("squares-2x2", "squares-2x2"),
+    ("squares-plus", "squares-plus"),
+    ("star", "star"),
+    ("stop", "stop"),
+    ("stop-circle", "stop-circle"),
+    ("strikethrough", "strikethrough"),
+    ("sun", "sun"),
+    ("swatch", "swatch"),
+    ("table-cells", "table-cells"),
+    ("tag", "tag"),
+    ("ticket", "ticket"),
+    ("trash", "trash"),
+    ("trophy", "trophy"),
+    ("truck", "truck"),
+    ("tv", "tv"),
+    ("underline", "underline"),
+    ("user", "user"),
+    ("user-circle", "user-circle"),
+    ("user-group", "user-group"),
+    ("user-minus", "user-minus"),
+    ("user-plus", "user-plus"),
+    ("users", "users"),
+    ("variable", "variable"),
+    ("video-camera", "video-camera"),
+    ("video-camera-slash", "video-camera-slash"),
+    ("view-columns", "view-columns"),
+    ("viewfinder-circle", "viewfinder-circle"),
+    ("wallet", "wallet"),
+    ("wifi", "wifi"),
+    ("window", "window"),
+    ("wrench", "wrench"),
+    ("wrench-screwdriver", "wrench-screwdriver"),
+    ("x-circle", "x-circle"),
+    ("x-mark", "x-mark"),
+];
+
+/// `(variant, codepoint, name)` for every mapped glyph, sorted for `binary_search`.
+pub const ICON_BY_CODEPOINT: &[(VariantKey, u32, &str)] = &[
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE000,
+        "academic-cap",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE001,
+        "adjustments-horizontal",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE002,
+        "adjustments-vertical",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE003,
+        "archive-box-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE004,
+        "archive-box-x-mark",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE005,
+        "archive-box",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE006,
+        "arrow-down-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE007,
+        "arrow-down-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE008,
+        "arrow-down-on-square-stack",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE009,
+        "arrow-down-on-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE00A,
+        "arrow-down-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE00B,
+        "arrow-down-tray",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE00C,
+        "arrow-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE00D,
+        "arrow-left-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE00E,
+        "arrow-left-end-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE00F,
+        "arrow-left-start-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE010,
+        "arrow-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE011,
+        "arrow-long-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE012,
+        "arrow-long-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE013,
+        "arrow-long-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE014,
+        "arrow-long-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE015,
+        "arrow-path-rounded-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE016,
+        "arrow-path",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE017,
+        "arrow-right-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE018,
+        "arrow-right-end-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE019,
+        "arrow-right-start-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE01A,
+        "arrow-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE01B,
+        "arrow-top-right-on-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE01C,
+        "arrow-trending-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE01D,
+        "arrow-trending-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE01E,
+        "arrow-turn-down-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE01F,
+        "arrow-turn-down-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE020,
+        "arrow-turn-left-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE021,
+        "arrow-turn-left-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE022,
+        "arrow-turn-right-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE023,
+        "arrow-turn-right-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE024,
+        "arrow-turn-up-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE025,
+        "arrow-turn-up-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE026,
+        "arrow-up-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE027,
+        "arrow-up-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE028,
+        "arrow-up-on-square-stack",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE029,
+        "arrow-up-on-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE02A,
+        "arrow-up-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE02B,
+        "arrow-up-tray",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE02C,
+        "arrow-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE02D,
+        "arrow-uturn-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE02E,
+        "arrow-uturn-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE02F,
+        "arrow-uturn-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE030,
+        "arrow-uturn-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE031,
+        "arrows-pointing-in",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE032,
+        "arrows-pointing-out",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE033,
+        "arrows-right-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE034,
+        "arrows-up-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE035,
+        "at-symbol",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE036,
+        "backspace",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE037,
+        "backward",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE038,
+        "banknotes",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE039,
+        "bars-2",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE03A,
+        "bars-3-bottom-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE03B,
+        "bars-3-bottom-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE03C,
+        "bars-3-center-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE03D,
+        "bars-3",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE03E,
+        "bars-4",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE03F,
+        "bars-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE040,
+        "bars-arrow-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE041,
+        "battery-0",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE042,
+        "battery-100",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE043,
+        "battery-50",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE044,
+        "beaker",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE045,
+        "bell-alert",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE046,
+        "bell-slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE047,
+        "bell-snooze",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE048,
+        "bell",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE049,
+        "bold",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE04A,
+        "bolt-slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE04B,
+        "bolt",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE04C,
+        "book-open",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE04D,
+        "bookmark-slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE04E,
+        "bookmark-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE04F,
+        "bookmark",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE050,
+        "briefcase",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE051,
+        "bug-ant",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE052,
+        "building-library",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE053,
+        "building-office-2",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE054,
+        "building-office",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE055,
+        "building-storefront",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE056,
+        "cake",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE057,
+        "calculator",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE058,
+        "calendar-date-range",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE059,
+        "calendar-days",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE05A,
+        "calendar",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE05B,
+        "camera",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE05C,
+        "chart-bar-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE05D,
+        "chart-bar",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE05E,
+        "chart-pie",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE05F,
+        "chat-bubble-bottom-center-text",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE060,
+        "chat-bubble-bottom-center",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE061,
+        "chat-bubble-left-ellipsis",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE062,
+        "chat-bubble-left-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE063,
+        "chat-bubble-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE064,
+        "chat-bubble-oval-left-ellipsis",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE065,
+        "chat-bubble-oval-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE066,
+        "check-badge",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE067,
+        "check-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE068,
+        "check",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE069,
+        "chevron-double-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE06A,
+        "chevron-double-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE06B,
+        "chevron-double-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE06C,
+        "chevron-double-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE06D,
+        "chevron-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE06E,
+        "chevron-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE06F,
+        "chevron-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE070,
+        "chevron-up-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE071,
+        "chevron-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE072,
+        "circle-stack",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE073,
+        "clipboard-document-check",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE074,
+        "clipboard-document-list",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE075,
+        "clipboard-document",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE076,
+        "clipboard",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE077,
+        "clock",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE078,
+        "cloud-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE079,
+        "cloud-arrow-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE07A,
+        "cloud",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE07B,
+        "code-bracket-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE07C,
+        "code-bracket",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE07D,
+        "cog-6-tooth",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE07E,
+        "cog-8-tooth",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE07F,
+        "cog",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE080,
+        "command-line",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE081,
+        "computer-desktop",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE082,
+        "cpu-chip",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE083,
+        "credit-card",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE084,
+        "cube-transparent",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE085,
+        "cube",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE086,
+        "currency-bangladeshi",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE087,
+        "currency-dollar",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE088,
+        "currency-euro",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE089,
+        "currency-pound",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE08A,
+        "currency-rupee",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE08B,
+        "currency-yen",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE08C,
+        "cursor-arrow-rays",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE08D,
+        "cursor-arrow-ripple",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE08E,
+        "device-phone-mobile",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE08F,
+        "device-tablet",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE090,
+        "divide",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE091,
+        "document-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE092,
+        "document-arrow-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE093,
+        "document-chart-bar",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE094,
+        "document-check",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE095,
+        "document-currency-bangladeshi",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE096,
+        "document-currency-dollar",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE097,
+        "document-currency-euro",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE098,
+        "document-currency-pound",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE099,
+        "document-currency-rupee",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE09A,
+        "document-currency-yen",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE09B,
+        "document-duplicate",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE09C,
+        "document-magnifying-glass",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE09D,
+        "document-minus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE09E,
+        "document-plus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE09F,
+        "document-text",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A0,
+        "document",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A1,
+        "ellipsis-horizontal-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A2,
+        "ellipsis-horizontal",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A3,
+        "ellipsis-vertical",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A4,
+        "envelope-open",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A5,
+        "envelope",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A6,
+        "equals",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A7,
+        "exclamation-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A8,
+        "exclamation-triangle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A9,
+        "eye-dropper",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0AA,
+        "eye-slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0AB,
+        "eye",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0AC,
+        "face-frown",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0AD,
+        "face-smile",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0AE,
+        "film",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0AF,
+        "finger-print",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B0,
+        "fire",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B1,
+        "flag",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B2,
+        "folder-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B3,
+        "folder-minus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B4,
+        "folder-open",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B5,
+        "folder-plus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B6,
+        "folder",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B7,
+        "forward",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B8,
+        "funnel",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B9,
+        "gif",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0BA,
+        "gift-top",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0BB,
+        "gift",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0BC,
+        "globe-alt",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0BD,
+        "globe-americas",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0BE,
+        "globe-asia-australia",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0BF,
+        "globe-europe-africa",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C0,
+        "h1",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C1,
+        "h2",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C2,
+        "h3",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C3,
+        "hand-raised",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C4,
+        "hand-thumb-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C5,
+        "hand-thumb-up",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C6,
+        "hashtag",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C7,
+        "heart",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C8,
+        "home-modern",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0C9,
+        "home",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0CA,
+        "identification",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0CB,
+        "inbox-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0CC,
+        "inbox-stack",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0CD,
+        "inbox",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0CE,
+        "information-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0CF,
+        "italic",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D0,
+        "key",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D1,
+        "language",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D2,
+        "lifebuoy",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D3,
+        "light-bulb",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D4,
+        "link-slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D5,
+        "link",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D6,
+        "list-bullet",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D7,
+        "lock-closed",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D8,
+        "lock-open",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0D9,
+        "magnifying-glass-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0DA,
+        "magnifying-glass-minus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0DB,
+        "magnifying-glass-plus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0DC,
+        "magnifying-glass",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0DD,
+        "map-pin",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0DE,
+        "map",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0DF,
+        "megaphone",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E0,
+        "microphone",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E1,
+        "minus-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E2,
+        "minus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E3,
+        "moon",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E4,
+        "musical-note",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E5,
+        "newspaper",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E6,
+        "no-symbol",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E7,
+        "numbered-list",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E8,
+        "paint-brush",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0E9,
+        "paper-airplane",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0EA,
+        "paper-clip",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0EB,
+        "pause-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0EC,
+        "pause",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0ED,
+        "pencil-square",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0EE,
+        "pencil",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0EF,
+        "percent-badge",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F0,
+        "phone-arrow-down-left",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F1,
+        "phone-arrow-up-right",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F2,
+        "phone-x-mark",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F3,
+        "phone",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F4,
+        "photo",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F5,
+        "play-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F6,
+        "play-pause",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F7,
+        "play",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F8,
+        "plus-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F9,
+        "plus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0FA,
+        "power",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0FB,
+        "presentation-chart-bar",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0FC,
+        "presentation-chart-line",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0FD,
+        "printer",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0FE,
+        "puzzle-piece",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0FF,
+        "qr-code",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE100,
+        "question-mark-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE101,
+        "queue-list",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE102,
+        "radio",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE103,
+        "receipt-percent",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE104,
+        "receipt-refund",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE105,
+        "rectangle-group",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE106,
+        "rectangle-stack",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE107,
+        "rocket-launch",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE108,
+        "rss",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE109,
+        "scale",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE10A,
+        "scissors",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE10B,
+        "server-stack",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE10C,
+        "server",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE10D,
+        "share",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE10E,
+        "shield-check",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE10F,
+        "shield-exclamation",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE110,
+        "shopping-bag",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE111,
+        "shopping-cart",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE112,
+        "signal-slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE113,
+        "signal",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE114,
+        "slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE115,
+        "sparkles",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE116,
+        "speaker-wave",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE117,
+        "speaker-x-mark",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE118,
+        "square-2-stack",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE119,
+        "square-3-stack-3d",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE11A,
+        "squares-2x2",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE11B,
+        "squares-plus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE11C,
+        "star",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE11D,
+        "stop-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE11E,
+        "stop",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE11F,
+        "strikethrough",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE120,
+        "sun",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE121,
+        "swatch",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE122,
+        "table-cells",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE123,
+        "tag",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE124,
+        "ticket",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE125,
+        "trash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE126,
+        "trophy",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE127,
+        "truck",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE128,
+        "tv",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE129,
+        "underline",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE12A,
+        "user-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE12B,
+        "user-group",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE12C,
+        "user-minus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE12D,
+        "user-plus",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE12E,
+        "user",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE12F,
+        "users",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE130,
+        "variable",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE131,
+        "video-camera-slash",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE132,
+        "video-camera",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE133,
+        "view-columns",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE134,
+        "viewfinder-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE135,
+        "wallet",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE136,
+        "wifi",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE137,
+        "window",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE138,
+        "wrench-screwdriver",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE139,
+        "wrench",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE13A,
+        "x-circle",
+    ),
+    #[cfg(feature = "heroicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE13B,
+        "x-mark",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE000,
+        "academic-cap",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE001,
+        "adjustments-horizontal",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE002,
+        "adjustments-vertical",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE003,
+        "archive-box-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE004,
+        "archive-box-x-mark",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE005,
+        "archive-box",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE006,
+        "arrow-down-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE007,
+        "arrow-down-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE008,
+        "arrow-down-on-square-stack",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE009,
+        "arrow-down-on-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE00A,
+        "arrow-down-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE00B,
+        "arrow-down-tray",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE00C,
+        "arrow-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE00D,
+        "arrow-left-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE00E,
+        "arrow-left-end-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE00F,
+        "arrow-left-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE010,
+        "arrow-left-start-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE011,
+        "arrow-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE012,
+        "arrow-long-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE013,
+        "arrow-long-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE014,
+        "arrow-long-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE015,
+        "arrow-long-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE016,
+        "arrow-path-rounded-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE017,
+        "arrow-path",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE018,
+        "arrow-right-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE019,
+        "arrow-right-end-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE01A,
+        "arrow-right-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE01B,
+        "arrow-right-start-on-rectangle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE01C,
+        "arrow-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE01D,
+        "arrow-small-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE01E,
+        "arrow-small-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE01F,
+        "arrow-small-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE020,
+        "arrow-small-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE021,
+        "arrow-top-right-on-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE022,
+        "arrow-trending-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE023,
+        "arrow-trending-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE024,
+        "arrow-turn-down-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE025,
+        "arrow-turn-down-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE026,
+        "arrow-turn-left-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE027,
+        "arrow-turn-left-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE028,
+        "arrow-turn-right-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE029,
+        "arrow-turn-right-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE02A,
+        "arrow-turn-up-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE02B,
+        "arrow-turn-up-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE02C,
+        "arrow-up-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE02D,
+        "arrow-up-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE02E,
+        "arrow-up-on-square-stack",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE02F,
+        "arrow-up-on-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE030,
+        "arrow-up-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE031,
+        "arrow-up-tray",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE032,
+        "arrow-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE033,
+        "arrow-uturn-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE034,
+        "arrow-uturn-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE035,
+        "arrow-uturn-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE036,
+        "arrow-uturn-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE037,
+        "arrows-pointing-in",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE038,
+        "arrows-pointing-out",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE039,
+        "arrows-right-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE03A,
+        "arrows-up-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE03B,
+        "at-symbol",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE03C,
+        "backspace",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE03D,
+        "backward",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE03E,
+        "banknotes",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE03F,
+        "bars-2",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE040,
+        "bars-3-bottom-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE041,
+        "bars-3-bottom-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE042,
+        "bars-3-center-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE043,
+        "bars-3",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE044,
+        "bars-4",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE045,
+        "bars-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE046,
+        "bars-arrow-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE047,
+        "battery-0",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE048,
+        "battery-100",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE049,
+        "battery-50",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE04A,
+        "beaker",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE04B,
+        "bell-alert",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE04C,
+        "bell-slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE04D,
+        "bell-snooze",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE04E,
+        "bell",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE04F,
+        "bold",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE050,
+        "bolt-slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE051,
+        "bolt",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE052,
+        "book-open",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE053,
+        "bookmark-slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE054,
+        "bookmark-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE055,
+        "bookmark",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE056,
+        "briefcase",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE057,
+        "bug-ant",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE058,
+        "building-library",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE059,
+        "building-office-2",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE05A,
+        "building-office",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE05B,
+        "building-storefront",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE05C,
+        "cake",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE05D,
+        "calculator",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE05E,
+        "calendar-date-range",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE05F,
+        "calendar-days",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE060,
+        "calendar",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE061,
+        "camera",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE062,
+        "chart-bar-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE063,
+        "chart-bar",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE064,
+        "chart-pie",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE065,
+        "chat-bubble-bottom-center-text",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE066,
+        "chat-bubble-bottom-center",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE067,
+        "chat-bubble-left-ellipsis",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE068,
+        "chat-bubble-left-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE069,
+        "chat-bubble-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE06A,
+        "chat-bubble-oval-left-ellipsis",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE06B,
+        "chat-bubble-oval-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE06C,
+        "check-badge",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE06D,
+        "check-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE06E,
+        "check",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE06F,
+        "chevron-double-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE070,
+        "chevron-double-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE071,
+        "chevron-double-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE072,
+        "chevron-double-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE073,
+        "chevron-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE074,
+        "chevron-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE075,
+        "chevron-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE076,
+        "chevron-up-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE077,
+        "chevron-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE078,
+        "circle-stack",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE079,
+        "clipboard-document-check",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE07A,
+        "clipboard-document-list",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE07B,
+        "clipboard-document",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE07C,
+        "clipboard",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE07D,
+        "clock",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE07E,
+        "cloud-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE07F,
+        "cloud-arrow-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE080,
+        "cloud",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE081,
+        "code-bracket-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE082,
+        "code-bracket",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE083,
+        "cog-6-tooth",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE084,
+        "cog-8-tooth",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE085,
+        "cog",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE086,
+        "command-line",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE087,
+        "computer-desktop",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE088,
+        "cpu-chip",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE089,
+        "credit-card",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE08A,
+        "cube-transparent",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE08B,
+        "cube",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE08C,
+        "currency-bangladeshi",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE08D,
+        "currency-dollar",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE08E,
+        "currency-euro",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE08F,
+        "currency-pound",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE090,
+        "currency-rupee",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE091,
+        "currency-yen",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE092,
+        "cursor-arrow-rays",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE093,
+        "cursor-arrow-ripple",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE094,
+        "device-phone-mobile",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE095,
+        "device-tablet",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE096,
+        "divide",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE097,
+        "document-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE098,
+        "document-arrow-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE099,
+        "document-chart-bar",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE09A,
+        "document-check",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE09B,
+        "document-currency-bangladeshi",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE09C,
+        "document-currency-dollar",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE09D,
+        "document-currency-euro",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE09E,
+        "document-currency-pound",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE09F,
+        "document-currency-rupee",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A0,
+        "document-currency-yen",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A1,
+        "document-duplicate",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A2,
+        "document-magnifying-glass",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A3,
+        "document-minus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A4,
+        "document-plus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A5,
+        "document-text",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A6,
+        "document",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A7,
+        "ellipsis-horizontal-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A8,
+        "ellipsis-horizontal",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0A9,
+        "ellipsis-vertical",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0AA,
+        "envelope-open",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0AB,
+        "envelope",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0AC,
+        "equals",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0AD,
+        "exclamation-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0AE,
+        "exclamation-triangle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0AF,
+        "eye-dropper",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B0,
+        "eye-slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B1,
+        "eye",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B2,
+        "face-frown",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B3,
+        "face-smile",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B4,
+        "film",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B5,
+        "finger-print",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B6,
+        "fire",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B7,
+        "flag",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B8,
+        "folder-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0B9,
+        "folder-minus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0BA,
+        "folder-open",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0BB,
+        "folder-plus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0BC,
+        "folder",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0BD,
+        "forward",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0BE,
+        "funnel",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0BF,
+        "gif",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C0,
+        "gift-top",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C1,
+        "gift",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C2,
+        "globe-alt",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C3,
+        "globe-americas",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C4,
+        "globe-asia-australia",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C5,
+        "globe-europe-africa",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C6,
+        "h1",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C7,
+        "h2",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C8,
+        "h3",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0C9,
+        "hand-raised",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0CA,
+        "hand-thumb-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0CB,
+        "hand-thumb-up",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0CC,
+        "hashtag",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0CD,
+        "heart",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0CE,
+        "home-modern",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0CF,
+        "home",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D0,
+        "identification",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D1,
+        "inbox-arrow-down",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D2,
+        "inbox-stack",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D3,
+        "inbox",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D4,
+        "information-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D5,
+        "italic",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D6,
+        "key",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D7,
+        "language",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D8,
+        "lifebuoy",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0D9,
+        "light-bulb",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0DA,
+        "link-slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0DB,
+        "link",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0DC,
+        "list-bullet",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0DD,
+        "lock-closed",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0DE,
+        "lock-open",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0DF,
+        "magnifying-glass-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E0,
+        "magnifying-glass-minus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E1,
+        "magnifying-glass-plus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E2,
+        "magnifying-glass",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E3,
+        "map-pin",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E4,
+        "map",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E5,
+        "megaphone",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E6,
+        "microphone",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E7,
+        "minus-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E8,
+        "minus-small",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0E9,
+        "minus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0EA,
+        "moon",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0EB,
+        "musical-note",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0EC,
+        "newspaper",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0ED,
+        "no-symbol",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0EE,
+        "numbered-list",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0EF,
+        "paint-brush",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F0,
+        "paper-airplane",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F1,
+        "paper-clip",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F2,
+        "pause-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F3,
+        "pause",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F4,
+        "pencil-square",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F5,
+        "pencil",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F6,
+        "percent-badge",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F7,
+        "phone-arrow-down-left",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F8,
+        "phone-arrow-up-right",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0F9,
+        "phone-x-mark",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0FA,
+        "phone",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0FB,
+        "photo",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0FC,
+        "play-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0FD,
+        "play-pause",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0FE,
+        "play",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE0FF,
+        "plus-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE100,
+        "plus-small",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE101,
+        "plus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE102,
+        "power",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE103,
+        "presentation-chart-bar",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE104,
+        "presentation-chart-line",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE105,
+        "printer",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE106,
+        "puzzle-piece",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE107,
+        "qr-code",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE108,
+        "question-mark-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE109,
+        "queue-list",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE10A,
+        "radio",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE10B,
+        "receipt-percent",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE10C,
+        "receipt-refund",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE10D,
+        "rectangle-group",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE10E,
+        "rectangle-stack",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE10F,
+        "rocket-launch",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE110,
+        "rss",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE111,
+        "scale",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE112,
+        "scissors",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE113,
+        "server-stack",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE114,
+        "server",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE115,
+        "share",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE116,
+        "shield-check",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE117,
+        "shield-exclamation",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE118,
+        "shopping-bag",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE119,
+        "shopping-cart",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE11A,
+        "signal-slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE11B,
+        "signal",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE11C,
+        "slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE11D,
+        "sparkles",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE11E,
+        "speaker-wave",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE11F,
+        "speaker-x-mark",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE120,
+        "square-2-stack",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE121,
+        "square-3-stack-3d",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE122,
+        "squares-2x2",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE123,
+        "squares-plus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE124,
+        "star",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE125,
+        "stop-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE126,
+        "stop",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE127,
+        "strikethrough",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE128,
+        "sun",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE129,
+        "swatch",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE12A,
+        "table-cells",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE12B,
+        "tag",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE12C,
+        "ticket",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE12D,
+        "trash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE12E,
+        "trophy",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE12F,
+        "truck",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE130,
+        "tv",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE131,
+        "underline",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE132,
+        "user-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE133,
+        "user-group",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE134,
+        "user-minus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE135,
+        "user-plus",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE136,
+        "user",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE137,
+        "users",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE138,
+        "variable",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE139,
+        "video-camera-slash",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE13A,
+        "video-camera",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE13B,
+        "view-columns",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE13C,
+        "viewfinder-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE13D,
+        "wallet",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE13E,
+        "wifi",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE13F,
+        "window",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE140,
+        "wrench-screwdriver",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE141,
+        "wrench",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE142,
+        "x-circle",
+    ),
+    #[cfg(feature = "heroicons-mini")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        },
+        0xE143,
+        "x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE000,
+        "academic-cap",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE001,
+        "adjustments-horizontal",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE002,
+        "adjustments-vertical",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE003,
+        "archive-box-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE004,
+        "archive-box-x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE005,
+        "archive-box",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE006,
+        "arrow-down-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE007,
+        "arrow-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE008,
+        "arrow-down-on-square-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE009,
+        "arrow-down-on-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "arrow-down-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "arrow-down-tray",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "arrow-left-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00E,
+        "arrow-left-end-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE00F,
+        "arrow-left-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE010,
+        "arrow-left-start-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE011,
+        "arrow-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE012,
+        "arrow-long-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE013,
+        "arrow-long-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE014,
+        "arrow-long-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE015,
+        "arrow-long-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE016,
+        "arrow-path-rounded-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE017,
+        "arrow-path",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE018,
+        "arrow-right-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE019,
+        "arrow-right-end-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01A,
+        "arrow-right-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01B,
+        "arrow-right-start-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01C,
+        "arrow-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01D,
+        "arrow-small-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01E,
+        "arrow-small-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01F,
+        "arrow-small-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE020,
+        "arrow-small-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE021,
+        "arrow-top-right-on-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE022,
+        "arrow-trending-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE023,
+        "arrow-trending-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE024,
+        "arrow-turn-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE025,
+        "arrow-turn-down-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE026,
+        "arrow-turn-left-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE027,
+        "arrow-turn-left-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE028,
+        "arrow-turn-right-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE029,
+        "arrow-turn-right-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE02A,
+        "arrow-turn-up-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE02B,
+        "arrow-turn-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE02C,
+        "arrow-up-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE02D,
+        "arrow-up-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE02E,
+        "arrow-up-on-square-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE02F,
+        "arrow-up-on-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE030,
+        "arrow-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE031,
+        "arrow-up-tray",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE032,
+        "arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE033,
+        "arrow-uturn-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE034,
+        "arrow-uturn-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE035,
+        "arrow-uturn-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE036,
+        "arrow-uturn-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE037,
+        "arrows-pointing-in",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE038,
+        "arrows-pointing-out",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE039,
+        "arrows-right-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE03A,
+        "arrows-up-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE03B,
+        "at-symbol",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE03C,
+        "backspace",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE03D,
+        "backward",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE03E,
+        "banknotes",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE03F,
+        "bars-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE040,
+        "bars-3-bottom-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE041,
+        "bars-3-bottom-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE042,
+        "bars-3-center-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE043,
+        "bars-3",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE044,
+        "bars-4",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE045,
+        "bars-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE046,
+        "bars-arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE047,
+        "battery-0",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE048,
+        "battery-100",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE049,
+        "battery-50",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE04A,
+        "beaker",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE04B,
+        "bell-alert",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE04C,
+        "bell-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE04D,
+        "bell-snooze",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE04E,
+        "bell",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE04F,
+        "bold",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE050,
+        "bolt-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE051,
+        "bolt",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE052,
+        "book-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE053,
+        "bookmark-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE054,
+        "bookmark-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE055,
+        "bookmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE056,
+        "briefcase",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE057,
+        "bug-ant",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE058,
+        "building-library",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE059,
+        "building-office-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE05A,
+        "building-office",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE05B,
+        "building-storefront",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE05C,
+        "cake",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE05D,
+        "calculator",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE05E,
+        "calendar-date-range",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE05F,
+        "calendar-days",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE060,
+        "calendar",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE061,
+        "camera",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE062,
+        "chart-bar-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE063,
+        "chart-bar",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE064,
+        "chart-pie",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE065,
+        "chat-bubble-bottom-center-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE066,
+        "chat-bubble-bottom-center",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE067,
+        "chat-bubble-left-ellipsis",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE068,
+        "chat-bubble-left-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE069,
+        "chat-bubble-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE06A,
+        "chat-bubble-oval-left-ellipsis",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE06B,
+        "chat-bubble-oval-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE06C,
+        "check-badge",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE06D,
+        "check-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE06E,
+        "check",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE06F,
+        "chevron-double-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE070,
+        "chevron-double-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE071,
+        "chevron-double-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE072,
+        "chevron-double-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE073,
+        "chevron-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE074,
+        "chevron-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE075,
+        "chevron-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE076,
+        "chevron-up-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE077,
+        "chevron-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE078,
+        "circle-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE079,
+        "clipboard-document-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE07A,
+        "clipboard-document-list",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE07B,
+        "clipboard-document",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE07C,
+        "clipboard",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE07D,
+        "clock",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE07E,
+        "cloud-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE07F,
+        "cloud-arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE080,
+        "cloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE081,
+        "code-bracket-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE082,
+        "code-bracket",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE083,
+        "cog-6-tooth",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE084,
+        "cog-8-tooth",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE085,
+        "cog",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE086,
+        "command-line",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE087,
+        "computer-desktop",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE088,
+        "cpu-chip",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE089,
+        "credit-card",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE08A,
+        "cube-transparent",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE08B,
+        "cube",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE08C,
+        "currency-bangladeshi",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE08D,
+        "currency-dollar",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE08E,
+        "currency-euro",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE08F,
+        "currency-pound",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE090,
+        "currency-rupee",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE091,
+        "currency-yen",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE092,
+        "cursor-arrow-rays",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE093,
+        "cursor-arrow-ripple",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE094,
+        "device-phone-mobile",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE095,
+        "device-tablet",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE096,
+        "divide",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE097,
+        "document-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE098,
+        "document-arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE099,
+        "document-chart-bar",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09A,
+        "document-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09B,
+        "document-currency-bangladeshi",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09C,
+        "document-currency-dollar",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09D,
+        "document-currency-euro",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09E,
+        "document-currency-pound",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09F,
+        "document-currency-rupee",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A0,
+        "document-currency-yen",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A1,
+        "document-duplicate",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A2,
+        "document-magnifying-glass",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A3,
+        "document-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A4,
+        "document-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A5,
+        "document-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A6,
+        "document",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A7,
+        "ellipsis-horizontal-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A8,
+        "ellipsis-horizontal",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A9,
+        "ellipsis-vertical",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0AA,
+        "envelope-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0AB,
+        "envelope",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0AC,
+        "equals",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0AD,
+        "exclamation-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0AE,
+        "exclamation-triangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0AF,
+        "eye-dropper",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B0,
+        "eye-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B1,
+        "eye",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B2,
+        "face-frown",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B3,
+        "face-smile",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B4,
+        "film",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B5,
+        "finger-print",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B6,
+        "fire",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B7,
+        "flag",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B8,
+        "folder-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0B9,
+        "folder-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0BA,
+        "folder-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0BB,
+        "folder-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0BC,
+        "folder",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0BD,
+        "forward",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0BE,
+        "funnel",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0BF,
+        "gif",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C0,
+        "gift-top",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C1,
+        "gift",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C2,
+        "globe-alt",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C3,
+        "globe-americas",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C4,
+        "globe-asia-australia",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C5,
+        "globe-europe-africa",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C6,
+        "h1",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C7,
+        "h2",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C8,
+        "h3",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0C9,
+        "hand-raised",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0CA,
+        "hand-thumb-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0CB,
+        "hand-thumb-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0CC,
+        "hashtag",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0CD,
+        "heart",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0CE,
+        "home-modern",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0CF,
+        "home",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D0,
+        "identification",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D1,
+        "inbox-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D2,
+        "inbox-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D3,
+        "inbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D4,
+        "information-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D5,
+        "italic",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D6,
+        "key",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D7,
+        "language",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D8,
+        "lifebuoy",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0D9,
+        "light-bulb",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0DA,
+        "link-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0DB,
+        "link",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0DC,
+        "list-bullet",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0DD,
+        "lock-closed",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0DE,
+        "lock-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0DF,
+        "magnifying-glass-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E0,
+        "magnifying-glass-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E1,
+        "magnifying-glass-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E2,
+        "magnifying-glass",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E3,
+        "map-pin",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E4,
+        "map",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E5,
+        "megaphone",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E6,
+        "microphone",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E7,
+        "minus-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E8,
+        "minus-small",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E9,
+        "minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0EA,
+        "moon",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0EB,
+        "musical-note",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0EC,
+        "newspaper",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0ED,
+        "no-symbol",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0EE,
+        "numbered-list",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0EF,
+        "paint-brush",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F0,
+        "paper-airplane",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F1,
+        "paper-clip",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F2,
+        "pause-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F3,
+        "pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F4,
+        "pencil-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F5,
+        "pencil",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F6,
+        "percent-badge",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F7,
+        "phone-arrow-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F8,
+        "phone-arrow-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0F9,
+        "phone-x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0FA,
+        "phone",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0FB,
+        "photo",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0FC,
+        "play-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0FD,
+        "play-pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0FE,
+        "play",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0FF,
+        "plus-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE100,
+        "plus-small",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE101,
+        "plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE102,
+        "power",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE103,
+        "presentation-chart-bar",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE104,
+        "presentation-chart-line",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE105,
+        "printer",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE106,
+        "puzzle-piece",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE107,
+        "qr-code",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE108,
+        "question-mark-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE109,
+        "queue-list",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE10A,
+        "radio",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE10B,
+        "receipt-percent",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE10C,
+        "receipt-refund",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE10D,
+        "rectangle-group",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE10E,
+        "rectangle-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE10F,
+        "rocket-launch",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE110,
+        "rss",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE111,
+        "scale",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE112,
+        "scissors",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE113,
+        "server-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE114,
+        "server",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE115,
+        "share",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE116,
+        "shield-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE117,
+        "shield-exclamation",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE118,
+        "shopping-bag",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE119,
+        "shopping-cart",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE11A,
+        "signal-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE11B,
+        "signal",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE11C,
+        "slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE11D,
+        "sparkles",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE11E,
+        "speaker-wave",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE11F,
+        "speaker-x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE120,
+        "square-2-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE121,
+        "square-3-stack-3d",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE122,
+        "squares-2x2",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE123,
+        "squares-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE124,
+        "star",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE125,
+        "stop-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE126,
+        "stop",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE127,
+        "strikethrough",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE128,
+        "sun",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE129,
+        "swatch",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE12A,
+        "table-cells",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE12B,
+        "tag",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE12C,
+        "ticket",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE12D,
+        "trash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE12E,
+        "trophy",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE12F,
+        "truck",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE130,
+        "tv",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE131,
+        "underline",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE132,
+        "user-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE133,
+        "user-group",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE134,
+        "user-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE135,
+        "user-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE136,
+        "user",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE137,
+        "users",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE138,
+        "variable",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE139,
+        "video-camera-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE13A,
+        "video-camera",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE13B,
+        "view-columns",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE13C,
+        "viewfinder-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE13D,
+        "wallet",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE13E,
+        "wifi",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE13F,
+        "window",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE140,
+        "wrench-screwdriver",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE141,
+        "wrench",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE142,
+        "x-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE143,
+        "x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE000,
+        "academic-cap",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE001,
+        "adjustments-horizontal",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE002,
+        "adjustments-vertical",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE003,
+        "archive-box-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE004,
+        "archive-box-x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE005,
+        "archive-box",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE006,
+        "arrow-down-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE007,
+        "arrow-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE008,
+        "arrow-down-on-square-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE009,
+        "arrow-down-on-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "arrow-down-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "arrow-down-tray",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "arrow-left-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00E,
+        "arrow-left-end-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE00F,
+        "arrow-left-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE010,
+        "arrow-left-start-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE011,
+        "arrow-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE012,
+        "arrow-long-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE013,
+        "arrow-long-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE014,
+        "arrow-long-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE015,
+        "arrow-long-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE016,
+        "arrow-path-rounded-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE017,
+        "arrow-path",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE018,
+        "arrow-right-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE019,
+        "arrow-right-end-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE01A,
+        "arrow-right-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE01B,
+        "arrow-right-start-on-rectangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE01C,
+        "arrow-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE01D,
+        "arrow-small-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE01E,
+        "arrow-small-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE01F,
+        "arrow-small-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE020,
+        "arrow-small-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE021,
+        "arrow-top-right-on-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE022,
+        "arrow-trending-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE023,
+        "arrow-trending-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE024,
+        "arrow-turn-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE025,
+        "arrow-turn-down-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE026,
+        "arrow-turn-left-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE027,
+        "arrow-turn-left-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE028,
+        "arrow-turn-right-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE029,
+        "arrow-turn-right-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE02A,
+        "arrow-turn-up-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE02B,
+        "arrow-turn-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE02C,
+        "arrow-up-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE02D,
+        "arrow-up-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE02E,
+        "arrow-up-on-square-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE02F,
+        "arrow-up-on-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE030,
+        "arrow-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE031,
+        "arrow-up-tray",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE032,
+        "arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE033,
+        "arrow-uturn-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE034,
+        "arrow-uturn-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE035,
+        "arrow-uturn-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE036,
+        "arrow-uturn-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE037,
+        "arrows-pointing-in",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE038,
+        "arrows-pointing-out",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE039,
+        "arrows-right-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE03A,
+        "arrows-up-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE03B,
+        "at-symbol",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE03C,
+        "backspace",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE03D,
+        "backward",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE03E,
+        "banknotes",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE03F,
+        "bars-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE040,
+        "bars-3-bottom-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE041,
+        "bars-3-bottom-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE042,
+        "bars-3-center-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE043,
+        "bars-3",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE044,
+        "bars-4",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE045,
+        "bars-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE046,
+        "bars-arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE047,
+        "battery-0",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE048,
+        "battery-100",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE049,
+        "battery-50",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE04A,
+        "beaker",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE04B,
+        "bell-alert",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE04C,
+        "bell-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE04D,
+        "bell-snooze",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE04E,
+        "bell",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE04F,
+        "bold",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE050,
+        "bolt-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE051,
+        "bolt",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE052,
+        "book-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE053,
+        "bookmark-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE054,
+        "bookmark-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE055,
+        "bookmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE056,
+        "briefcase",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE057,
+        "bug-ant",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE058,
+        "building-library",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE059,
+        "building-office-2",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE05A,
+        "building-office",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE05B,
+        "building-storefront",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE05C,
+        "cake",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE05D,
+        "calculator",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE05E,
+        "calendar-date-range",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE05F,
+        "calendar-days",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE060,
+        "calendar",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE061,
+        "camera",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE062,
+        "chart-bar-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE063,
+        "chart-bar",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE064,
+        "chart-pie",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE065,
+        "chat-bubble-bottom-center-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE066,
+        "chat-bubble-bottom-center",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE067,
+        "chat-bubble-left-ellipsis",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE068,
+        "chat-bubble-left-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE069,
+        "chat-bubble-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE06A,
+        "chat-bubble-oval-left-ellipsis",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE06B,
+        "chat-bubble-oval-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE06C,
+        "check-badge",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE06D,
+        "check-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE06E,
+        "check",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE06F,
+        "chevron-double-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE070,
+        "chevron-double-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE071,
+        "chevron-double-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE072,
+        "chevron-double-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE073,
+        "chevron-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE074,
+        "chevron-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE075,
+        "chevron-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE076,
+        "chevron-up-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE077,
+        "chevron-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE078,
+        "circle-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE079,
+        "clipboard-document-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE07A,
+        "clipboard-document-list",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE07B,
+        "clipboard-document",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE07C,
+        "clipboard",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE07D,
+        "clock",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE07E,
+        "cloud-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE07F,
+        "cloud-arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE080,
+        "cloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE081,
+        "code-bracket-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE082,
+        "code-bracket",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE083,
+        "cog-6-tooth",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE084,
+        "cog-8-tooth",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE085,
+        "cog",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE086,
+        "command-line",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE087,
+        "computer-desktop",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE088,
+        "cpu-chip",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE089,
+        "credit-card",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE08A,
+        "cube-transparent",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE08B,
+        "cube",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE08C,
+        "currency-bangladeshi",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE08D,
+        "currency-dollar",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE08E,
+        "currency-euro",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE08F,
+        "currency-pound",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE090,
+        "currency-rupee",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE091,
+        "currency-yen",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE092,
+        "cursor-arrow-rays",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE093,
+        "cursor-arrow-ripple",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE094,
+        "device-phone-mobile",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE095,
+        "device-tablet",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE096,
+        "divide",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE097,
+        "document-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE098,
+        "document-arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE099,
+        "document-chart-bar",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE09A,
+        "document-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE09B,
+        "document-currency-bangladeshi",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE09C,
+        "document-currency-dollar",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE09D,
+        "document-currency-euro",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE09E,
+        "document-currency-pound",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE09F,
+        "document-currency-rupee",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A0,
+        "document-currency-yen",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A1,
+        "document-duplicate",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A2,
+        "document-magnifying-glass",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A3,
+        "document-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A4,
+        "document-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A5,
+        "document-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A6,
+        "document",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A7,
+        "ellipsis-horizontal-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A8,
+        "ellipsis-horizontal",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0A9,
+        "ellipsis-vertical",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0AA,
+        "envelope-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0AB,
+        "envelope",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0AC,
+        "equals",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0AD,
+        "exclamation-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0AE,
+        "exclamation-triangle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0AF,
+        "eye-dropper",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B0,
+        "eye-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B1,
+        "eye",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B2,
+        "face-frown",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B3,
+        "face-smile",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B4,
+        "film",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B5,
+        "finger-print",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B6,
+        "fire",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B7,
+        "flag",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B8,
+        "folder-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0B9,
+        "folder-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0BA,
+        "folder-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0BB,
+        "folder-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0BC,
+        "folder",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0BD,
+        "forward",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0BE,
+        "funnel",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0BF,
+        "gif",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C0,
+        "gift-top",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C1,
+        "gift",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C2,
+        "globe-alt",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C3,
+        "globe-americas",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C4,
+        "globe-asia-australia",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C5,
+        "globe-europe-africa",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C6,
+        "h1",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C7,
+        "h2",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C8,
+        "h3",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0C9,
+        "hand-raised",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0CA,
+        "hand-thumb-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0CB,
+        "hand-thumb-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0CC,
+        "hashtag",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0CD,
+        "heart",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0CE,
+        "home-modern",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0CF,
+        "home",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D0,
+        "identification",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D1,
+        "inbox-arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D2,
+        "inbox-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D3,
+        "inbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D4,
+        "information-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D5,
+        "italic",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D6,
+        "key",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D7,
+        "language",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D8,
+        "lifebuoy",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0D9,
+        "light-bulb",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0DA,
+        "link-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0DB,
+        "link",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0DC,
+        "list-bullet",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0DD,
+        "lock-closed",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0DE,
+        "lock-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0DF,
+        "magnifying-glass-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E0,
+        "magnifying-glass-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E1,
+        "magnifying-glass-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E2,
+        "magnifying-glass",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E3,
+        "map-pin",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E4,
+        "map",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E5,
+        "megaphone",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E6,
+        "microphone",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E7,
+        "minus-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E8,
+        "minus-small",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0E9,
+        "minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0EA,
+        "moon",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0EB,
+        "musical-note",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0EC,
+        "newspaper",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0ED,
+        "no-symbol",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0EE,
+        "numbered-list",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0EF,
+        "paint-brush",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F0,
+        "paper-airplane",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F1,
+        "paper-clip",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F2,
+        "pause-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F3,
+        "pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F4,
+        "pencil-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F5,
+        "pencil",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F6,
+        "percent-badge",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F7,
+        "phone-arrow-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F8,
+        "phone-arrow-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0F9,
+        "phone-x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0FA,
+        "phone",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0FB,
+        "photo",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0FC,
+        "play-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0FD,
+        "play-pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0FE,
+        "play",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE0FF,
+        "plus-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE100,
+        "plus-small",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE101,
+        "plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE102,
+        "power",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE103,
+        "presentation-chart-bar",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE104,
+        "presentation-chart-line",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE105,
+        "printer",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE106,
+        "puzzle-piece",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE107,
+        "qr-code",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE108,
+        "question-mark-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE109,
+        "queue-list",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE10A,
+        "radio",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE10B,
+        "receipt-percent",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE10C,
+        "receipt-refund",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE10D,
+        "rectangle-group",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE10E,
+        "rectangle-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE10F,
+        "rocket-launch",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE110,
+        "rss",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE111,
+        "scale",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE112,
+        "scissors",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE113,
+        "server-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE114,
+        "server",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE115,
+        "share",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE116,
+        "shield-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE117,
+        "shield-exclamation",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE118,
+        "shopping-bag",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE119,
+        "shopping-cart",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE11A,
+        "signal-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE11B,
+        "signal",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE11C,
+        "slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE11D,
+        "sparkles",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE11E,
+        "speaker-wave",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE11F,
+        "speaker-x-mark",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE120,
+        "square-2-stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE121,
+        "square-3-stack-3d",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE122,
+        "squares-2x2",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE123,
+        "squares-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE124,
+        "star",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE125,
+        "stop-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE126,
+        "stop",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE127,
+        "strikethrough",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE128,
+        "sun",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE129,
+        "swatch",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE12A,
+        "table-cells",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE12B,
+        "tag",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE12C,
+        "ticket",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE12D,
+        "trash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE12E,
+        "trophy",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE12F,
+        "truck",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE130,
+        "tv",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE131,
+        "underline",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE132,
+        "user-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE133,
+        "user-group",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE134,
+        "user-minus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE135,
+        "user-plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE136,
+        "user",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE137,
+        "users",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE138,
+        "variable",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE139,
+        "video-camera-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE13A,
+        "video-camera",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE13B,
+        "view-columns",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE13C,
+        "viewfinder-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE13D,
+        "wallet",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE13E,
+        "wifi",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE13F,
+        "window",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE140,
+        "wrench-screwdriver",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE141,
+        "wrench",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE142,
+        "x-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Outline,
+            size: Size::Regular,
+        },
+        0xE143,
+        "x-mark",
+    ),
+];
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct VariantInfo {
     pub key: VariantKey,
@@ -1125,6 +14250,7 @@ pub(crate) const VARIANTS: &[VariantInfo] = &[
     },
 ];
 
+/// `academic-cap` — U+E000 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ACADEMIC_CAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1158,6 +14284,7 @@ const ICON_ACADEMIC_CAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `adjustments-horizontal` — U+E001 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ADJUSTMENTS_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1191,6 +14318,7 @@ const ICON_ADJUSTMENTS_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `adjustments-vertical` — U+E002 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ADJUSTMENTS_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1224,6 +14352,7 @@ const ICON_ADJUSTMENTS_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `archive-box` — U+E005 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARCHIVE_BOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1257,6 +14386,7 @@ const ICON_ARCHIVE_BOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `archive-box-arrow-down` — U+E003 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARCHIVE_BOX_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1290,6 +14420,7 @@ const ICON_ARCHIVE_BOX_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `archive-box-x-mark` — U+E004 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARCHIVE_BOX_X_MARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1323,6 +14454,7 @@ const ICON_ARCHIVE_BOX_X_MARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down` — U+E00C (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1356,6 +14488,7 @@ const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-circle` — U+E006 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_DOWN_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1389,6 +14522,7 @@ const ICON_ARROW_DOWN_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-left` — U+E007 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1422,6 +14556,7 @@ const ICON_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-on-square` — U+E009 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_DOWN_ON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1455,6 +14590,7 @@ const ICON_ARROW_DOWN_ON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-on-square-stack` — U+E008 (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_DOWN_ON_SQUARE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1488,6 +14624,7 @@ const ICON_ARROW_DOWN_ON_SQUARE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-right` — U+E00A (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1521,6 +14658,7 @@ const ICON_ARROW_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-tray` — U+E00B (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_DOWN_TRAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1554,6 +14692,7 @@ const ICON_ARROW_DOWN_TRAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-left` — available in Filled U+E011, Filled/Mini U+E011, Outline U+E011, Filled/Tiny U+E010
 const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1587,6 +14726,7 @@ const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-left-circle` — U+E00D (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_LEFT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1620,6 +14760,7 @@ const ICON_ARROW_LEFT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-left-end-on-rectangle` — U+E00E (available in Filled, Filled/Mini, Outline, Filled/Tiny)
 const ICON_ARROW_LEFT_END_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1653,6 +14794,7 @@ const ICON_ARROW_LEFT_END_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-left-on-rectangle` — U+E00F (available in Filled, Filled/Mini, Outline)
 const ICON_ARROW_LEFT_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1678,6 +14820,7 @@ const ICON_ARROW_LEFT_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-left-start-on-rectangle` — available in Filled U+E010, Filled/Mini U+E010, Outline U+E010, Filled/Tiny U+E00F
 const ICON_ARROW_LEFT_START_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1711,6 +14854,7 @@ const ICON_ARROW_LEFT_START_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-long-down` — available in Filled U+E012, Filled/Mini U+E012, Outline U+E012, Filled/Tiny U+E011
 const ICON_ARROW_LONG_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1744,6 +14888,7 @@ const ICON_ARROW_LONG_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-long-left` — available in Filled U+E013, Filled/Mini U+E013, Outline U+E013, Filled/Tiny U+E012
 const ICON_ARROW_LONG_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1777,6 +14922,7 @@ const ICON_ARROW_LONG_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-long-right` — available in Filled U+E014, Filled/Mini U+E014, Outline U+E014, Filled/Tiny U+E013
 const ICON_ARROW_LONG_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1810,6 +14956,7 @@ const ICON_ARROW_LONG_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-long-up` — available in Filled U+E015, Filled/Mini U+E015, Outline U+E015, Filled/Tiny U+E014
 const ICON_ARROW_LONG_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1843,6 +14990,7 @@ const ICON_ARROW_LONG_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-path` — available in Filled U+E017, Filled/Mini U+E017, Outline U+E017, Filled/Tiny U+E016
 const ICON_ARROW_PATH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1876,6 +15024,7 @@ const ICON_ARROW_PATH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-path-rounded-square` — available in Filled U+E016, Filled/Mini U+E016, Outline U+E016, Filled/Tiny U+E015
 const ICON_ARROW_PATH_ROUNDED_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1909,6 +15058,7 @@ const ICON_ARROW_PATH_ROUNDED_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-right` — available in Filled U+E01C, Filled/Mini U+E01C, Outline U+E01C, Filled/Tiny U+E01A
 const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1942,6 +15092,7 @@ const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-right-circle` — available in Filled U+E018, Filled/Mini U+E018, Outline U+E018, Filled/Tiny U+E017
 const ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1975,6 +15126,7 @@ const ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-right-end-on-rectangle` — available in Filled U+E019, Filled/Mini U+E019, Outline U+E019, Filled/Tiny U+E018
 const ICON_ARROW_RIGHT_END_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2008,6 +15160,7 @@ const ICON_ARROW_RIGHT_END_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-right-on-rectangle` — U+E01A (available in Filled, Filled/Mini, Outline)
 const ICON_ARROW_RIGHT_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2033,6 +15186,7 @@ const ICON_ARROW_RIGHT_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-right-start-on-rectangle` — available in Filled U+E01B, Filled/Mini U+E01B, Outline U+E01B, Filled/Tiny U+E019
 const ICON_ARROW_RIGHT_START_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2066,6 +15220,7 @@ const ICON_ARROW_RIGHT_START_ON_RECTANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-small-down` — U+E01D (available in Filled, Filled/Mini, Outline)
 const ICON_ARROW_SMALL_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2091,6 +15246,7 @@ const ICON_ARROW_SMALL_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-small-left` — U+E01E (available in Filled, Filled/Mini, Outline)
 const ICON_ARROW_SMALL_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2116,6 +15272,7 @@ const ICON_ARROW_SMALL_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-small-right` — U+E01F (available in Filled, Filled/Mini, Outline)
 const ICON_ARROW_SMALL_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2141,6 +15298,7 @@ const ICON_ARROW_SMALL_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-small-up` — U+E020 (available in Filled, Filled/Mini, Outline)
 const ICON_ARROW_SMALL_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2166,6 +15324,7 @@ const ICON_ARROW_SMALL_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-top-right-on-square` — available in Filled U+E021, Filled/Mini U+E021, Outline U+E021, Filled/Tiny U+E01B
 const ICON_ARROW_TOP_RIGHT_ON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2199,6 +15358,7 @@ const ICON_ARROW_TOP_RIGHT_ON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-trending-down` — available in Filled U+E022, Filled/Mini U+E022, Outline U+E022, Filled/Tiny U+E01C
 const ICON_ARROW_TRENDING_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2232,6 +15392,7 @@ const ICON_ARROW_TRENDING_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-trending-up` — available in Filled U+E023, Filled/Mini U+E023, Outline U+E023, Filled/Tiny U+E01D
 const ICON_ARROW_TRENDING_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2265,6 +15426,7 @@ const ICON_ARROW_TRENDING_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-down-left` — available in Filled U+E024, Filled/Mini U+E024, Outline U+E024, Filled/Tiny U+E01E
 const ICON_ARROW_TURN_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2298,6 +15460,7 @@ const ICON_ARROW_TURN_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-down-right` — available in Filled U+E025, Filled/Mini U+E025, Outline U+E025, Filled/Tiny U+E01F
 const ICON_ARROW_TURN_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2331,6 +15494,7 @@ const ICON_ARROW_TURN_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-left-down` — available in Filled U+E026, Filled/Mini U+E026, Outline U+E026, Filled/Tiny U+E020
 const ICON_ARROW_TURN_LEFT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2364,6 +15528,7 @@ const ICON_ARROW_TURN_LEFT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-left-up` — available in Filled U+E027, Filled/Mini U+E027, Outline U+E027, Filled/Tiny U+E021
 const ICON_ARROW_TURN_LEFT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2397,6 +15562,7 @@ const ICON_ARROW_TURN_LEFT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-right-down` — available in Filled U+E028, Filled/Mini U+E028, Outline U+E028, Filled/Tiny U+E022
 const ICON_ARROW_TURN_RIGHT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2430,6 +15596,7 @@ const ICON_ARROW_TURN_RIGHT_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-right-up` — available in Filled U+E029, Filled/Mini U+E029, Outline U+E029, Filled/Tiny U+E023
 const ICON_ARROW_TURN_RIGHT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2463,6 +15630,7 @@ const ICON_ARROW_TURN_RIGHT_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-up-left` — available in Filled U+E02A, Filled/Mini U+E02A, Outline U+E02A, Filled/Tiny U+E024
 const ICON_ARROW_TURN_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2496,6 +15664,7 @@ const ICON_ARROW_TURN_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-turn-up-right` — available in Filled U+E02B, Filled/Mini U+E02B, Outline U+E02B, Filled/Tiny U+E025
 const ICON_ARROW_TURN_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2529,6 +15698,7 @@ const ICON_ARROW_TURN_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up` — available in Filled U+E032, Filled/Mini U+E032, Outline U+E032, Filled/Tiny U+E02C
 const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2562,6 +15732,7 @@ const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-circle` — available in Filled U+E02C, Filled/Mini U+E02C, Outline U+E02C, Filled/Tiny U+E026
 const ICON_ARROW_UP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2595,6 +15766,7 @@ const ICON_ARROW_UP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-left` — available in Filled U+E02D, Filled/Mini U+E02D, Outline U+E02D, Filled/Tiny U+E027
 const ICON_ARROW_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2628,6 +15800,7 @@ const ICON_ARROW_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-on-square` — available in Filled U+E02F, Filled/Mini U+E02F, Outline U+E02F, Filled/Tiny U+E029
 const ICON_ARROW_UP_ON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2661,6 +15834,7 @@ const ICON_ARROW_UP_ON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-on-square-stack` — available in Filled U+E02E, Filled/Mini U+E02E, Outline U+E02E, Filled/Tiny U+E028
 const ICON_ARROW_UP_ON_SQUARE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2694,6 +15868,7 @@ const ICON_ARROW_UP_ON_SQUARE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-right` — available in Filled U+E030, Filled/Mini U+E030, Outline U+E030, Filled/Tiny U+E02A
 const ICON_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2727,6 +15902,7 @@ const ICON_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-tray` — available in Filled U+E031, Filled/Mini U+E031, Outline U+E031, Filled/Tiny U+E02B
 const ICON_ARROW_UP_TRAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2760,6 +15936,7 @@ const ICON_ARROW_UP_TRAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-uturn-down` — available in Filled U+E033, Filled/Mini U+E033, Outline U+E033, Filled/Tiny U+E02D
 const ICON_ARROW_UTURN_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2793,6 +15970,7 @@ const ICON_ARROW_UTURN_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-uturn-left` — available in Filled U+E034, Filled/Mini U+E034, Outline U+E034, Filled/Tiny U+E02E
 const ICON_ARROW_UTURN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2826,6 +16004,7 @@ const ICON_ARROW_UTURN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-uturn-right` — available in Filled U+E035, Filled/Mini U+E035, Outline U+E035, Filled/Tiny U+E02F
 const ICON_ARROW_UTURN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2859,6 +16038,7 @@ const ICON_ARROW_UTURN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-uturn-up` — available in Filled U+E036, Filled/Mini U+E036, Outline U+E036, Filled/Tiny U+E030
 const ICON_ARROW_UTURN_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2892,6 +16072,7 @@ const ICON_ARROW_UTURN_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrows-pointing-in` — available in Filled U+E037, Filled/Mini U+E037, Outline U+E037, Filled/Tiny U+E031
 const ICON_ARROWS_POINTING_IN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2925,6 +16106,7 @@ const ICON_ARROWS_POINTING_IN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrows-pointing-out` — available in Filled U+E038, Filled/Mini U+E038, Outline U+E038, Filled/Tiny U+E032
 const ICON_ARROWS_POINTING_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2958,6 +16140,7 @@ const ICON_ARROWS_POINTING_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrows-right-left` — available in Filled U+E039, Filled/Mini U+E039, Outline U+E039, Filled/Tiny U+E033
 const ICON_ARROWS_RIGHT_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2991,6 +16174,7 @@ const ICON_ARROWS_RIGHT_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrows-up-down` — available in Filled U+E03A, Filled/Mini U+E03A, Outline U+E03A, Filled/Tiny U+E034
 const ICON_ARROWS_UP_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3024,6 +16208,7 @@ const ICON_ARROWS_UP_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `at-symbol` — available in Filled U+E03B, Filled/Mini U+E03B, Outline U+E03B, Filled/Tiny U+E035
 const ICON_AT_SYMBOL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3057,6 +16242,7 @@ const ICON_AT_SYMBOL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `backspace` — available in Filled U+E03C, Filled/Mini U+E03C, Outline U+E03C, Filled/Tiny U+E036
 const ICON_BACKSPACE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3090,6 +16276,7 @@ const ICON_BACKSPACE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `backward` — available in Filled U+E03D, Filled/Mini U+E03D, Outline U+E03D, Filled/Tiny U+E037
 const ICON_BACKWARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3123,6 +16310,7 @@ const ICON_BACKWARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `banknotes` — available in Filled U+E03E, Filled/Mini U+E03E, Outline U+E03E, Filled/Tiny U+E038
 const ICON_BANKNOTES_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3156,6 +16344,7 @@ const ICON_BANKNOTES_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-2` — available in Filled U+E03F, Filled/Mini U+E03F, Outline U+E03F, Filled/Tiny U+E039
 const ICON_BARS_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3189,6 +16378,7 @@ const ICON_BARS_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-3` — available in Filled U+E043, Filled/Mini U+E043, Outline U+E043, Filled/Tiny U+E03D
 const ICON_BARS_3_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3222,6 +16412,7 @@ const ICON_BARS_3_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-3-bottom-left` — available in Filled U+E040, Filled/Mini U+E040, Outline U+E040, Filled/Tiny U+E03A
 const ICON_BARS_3_BOTTOM_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3255,6 +16446,7 @@ const ICON_BARS_3_BOTTOM_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-3-bottom-right` — available in Filled U+E041, Filled/Mini U+E041, Outline U+E041, Filled/Tiny U+E03B
 const ICON_BARS_3_BOTTOM_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3288,6 +16480,7 @@ const ICON_BARS_3_BOTTOM_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-3-center-left` — available in Filled U+E042, Filled/Mini U+E042, Outline U+E042, Filled/Tiny U+E03C
 const ICON_BARS_3_CENTER_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3321,6 +16514,7 @@ const ICON_BARS_3_CENTER_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-4` — available in Filled U+E044, Filled/Mini U+E044, Outline U+E044, Filled/Tiny U+E03E
 const ICON_BARS_4_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3354,6 +16548,7 @@ const ICON_BARS_4_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-arrow-down` — available in Filled U+E045, Filled/Mini U+E045, Outline U+E045, Filled/Tiny U+E03F
 const ICON_BARS_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3387,6 +16582,7 @@ const ICON_BARS_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bars-arrow-up` — available in Filled U+E046, Filled/Mini U+E046, Outline U+E046, Filled/Tiny U+E040
 const ICON_BARS_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3420,6 +16616,7 @@ const ICON_BARS_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `battery-0` — available in Filled U+E047, Filled/Mini U+E047, Outline U+E047, Filled/Tiny U+E041
 const ICON_BATTERY_0_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3453,7 +16650,8 @@ const ICON_BATTERY_0_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
-const ICON_BATTERY_1_0_0_CODEPOINTS: &[(VariantKey, u32)] = &[
+/// `battery-100` — available in Filled U+E048, Filled/Mini U+E048, Outline U+E048, Filled/Tiny U+E042
+const ICON_BATTERY_100_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
             style: Style::Filled,
@@ -3486,7 +16684,8 @@ const ICON_BATTERY_1_0_0_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
-const ICON_BATTERY_5_0_CODEPOINTS: &[(VariantKey, u32)] = &[
+/// `battery-50` — available in Filled U+E049, Filled/Mini U+E049, Outline U+E049, Filled/Tiny U+E043
+const ICON_BATTERY_50_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
             style: Style::Filled,
@@ -3519,6 +16718,7 @@ const ICON_BATTERY_5_0_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `beaker` — available in Filled U+E04A, Filled/Mini U+E04A, Outline U+E04A, Filled/Tiny U+E044
 const ICON_BEAKER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3552,6 +16752,7 @@ const ICON_BEAKER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bell` — available in Filled U+E04E, Filled/Mini U+E04E, Outline U+E04E, Filled/Tiny U+E048
 const ICON_BELL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3585,6 +16786,7 @@ const ICON_BELL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bell-alert` — available in Filled U+E04B, Filled/Mini U+E04B, Outline U+E04B, Filled/Tiny U+E045
 const ICON_BELL_ALERT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3618,6 +16820,7 @@ const ICON_BELL_ALERT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bell-slash` — available in Filled U+E04C, Filled/Mini U+E04C, Outline U+E04C, Filled/Tiny U+E046
 const ICON_BELL_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3651,6 +16854,7 @@ const ICON_BELL_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bell-snooze` — available in Filled U+E04D, Filled/Mini U+E04D, Outline U+E04D, Filled/Tiny U+E047
 const ICON_BELL_SNOOZE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3684,6 +16888,7 @@ const ICON_BELL_SNOOZE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bold` — available in Filled U+E04F, Filled/Mini U+E04F, Outline U+E04F, Filled/Tiny U+E049
 const ICON_BOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3717,6 +16922,7 @@ const ICON_BOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bolt` — available in Filled U+E051, Filled/Mini U+E051, Outline U+E051, Filled/Tiny U+E04B
 const ICON_BOLT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3750,6 +16956,7 @@ const ICON_BOLT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bolt-slash` — available in Filled U+E050, Filled/Mini U+E050, Outline U+E050, Filled/Tiny U+E04A
 const ICON_BOLT_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3783,6 +16990,7 @@ const ICON_BOLT_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `book-open` — available in Filled U+E052, Filled/Mini U+E052, Outline U+E052, Filled/Tiny U+E04C
 const ICON_BOOK_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3816,6 +17024,7 @@ const ICON_BOOK_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bookmark` — available in Filled U+E055, Filled/Mini U+E055, Outline U+E055, Filled/Tiny U+E04F
 const ICON_BOOKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3849,6 +17058,7 @@ const ICON_BOOKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bookmark-slash` — available in Filled U+E053, Filled/Mini U+E053, Outline U+E053, Filled/Tiny U+E04D
 const ICON_BOOKMARK_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3882,6 +17092,7 @@ const ICON_BOOKMARK_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bookmark-square` — available in Filled U+E054, Filled/Mini U+E054, Outline U+E054, Filled/Tiny U+E04E
 const ICON_BOOKMARK_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3915,6 +17126,7 @@ const ICON_BOOKMARK_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `briefcase` — available in Filled U+E056, Filled/Mini U+E056, Outline U+E056, Filled/Tiny U+E050
 const ICON_BRIEFCASE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3948,6 +17160,7 @@ const ICON_BRIEFCASE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bug-ant` — available in Filled U+E057, Filled/Mini U+E057, Outline U+E057, Filled/Tiny U+E051
 const ICON_BUG_ANT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3981,6 +17194,7 @@ const ICON_BUG_ANT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `building-library` — available in Filled U+E058, Filled/Mini U+E058, Outline U+E058, Filled/Tiny U+E052
 const ICON_BUILDING_LIBRARY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4014,6 +17228,7 @@ const ICON_BUILDING_LIBRARY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `building-office` — available in Filled U+E05A, Filled/Mini U+E05A, Outline U+E05A, Filled/Tiny U+E054
 const ICON_BUILDING_OFFICE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4047,6 +17262,7 @@ const ICON_BUILDING_OFFICE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `building-office-2` — available in Filled U+E059, Filled/Mini U+E059, Outline U+E059, Filled/Tiny U+E053
 const ICON_BUILDING_OFFICE_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4080,6 +17296,7 @@ const ICON_BUILDING_OFFICE_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `building-storefront` — available in Filled U+E05B, Filled/Mini U+E05B, Outline U+E05B, Filled/Tiny U+E055
 const ICON_BUILDING_STOREFRONT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4113,6 +17330,7 @@ const ICON_BUILDING_STOREFRONT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cake` — available in Filled U+E05C, Filled/Mini U+E05C, Outline U+E05C, Filled/Tiny U+E056
 const ICON_CAKE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4146,6 +17364,7 @@ const ICON_CAKE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `calculator` — available in Filled U+E05D, Filled/Mini U+E05D, Outline U+E05D, Filled/Tiny U+E057
 const ICON_CALCULATOR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4179,6 +17398,7 @@ const ICON_CALCULATOR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `calendar` — available in Filled U+E060, Filled/Mini U+E060, Outline U+E060, Filled/Tiny U+E05A
 const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4212,6 +17432,7 @@ const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `calendar-date-range` — available in Filled U+E05E, Filled/Mini U+E05E, Outline U+E05E, Filled/Tiny U+E058
 const ICON_CALENDAR_DATE_RANGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4245,6 +17466,7 @@ const ICON_CALENDAR_DATE_RANGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `calendar-days` — available in Filled U+E05F, Filled/Mini U+E05F, Outline U+E05F, Filled/Tiny U+E059
 const ICON_CALENDAR_DAYS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4278,6 +17500,7 @@ const ICON_CALENDAR_DAYS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `camera` — available in Filled U+E061, Filled/Mini U+E061, Outline U+E061, Filled/Tiny U+E05B
 const ICON_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4311,6 +17534,7 @@ const ICON_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chart-bar` — available in Filled U+E063, Filled/Mini U+E063, Outline U+E063, Filled/Tiny U+E05D
 const ICON_CHART_BAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4344,6 +17568,7 @@ const ICON_CHART_BAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chart-bar-square` — available in Filled U+E062, Filled/Mini U+E062, Outline U+E062, Filled/Tiny U+E05C
 const ICON_CHART_BAR_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4377,6 +17602,7 @@ const ICON_CHART_BAR_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chart-pie` — available in Filled U+E064, Filled/Mini U+E064, Outline U+E064, Filled/Tiny U+E05E
 const ICON_CHART_PIE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4410,6 +17636,7 @@ const ICON_CHART_PIE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chat-bubble-bottom-center` — available in Filled U+E066, Filled/Mini U+E066, Outline U+E066, Filled/Tiny U+E060
 const ICON_CHAT_BUBBLE_BOTTOM_CENTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4443,6 +17670,7 @@ const ICON_CHAT_BUBBLE_BOTTOM_CENTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chat-bubble-bottom-center-text` — available in Filled U+E065, Filled/Mini U+E065, Outline U+E065, Filled/Tiny U+E05F
 const ICON_CHAT_BUBBLE_BOTTOM_CENTER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4476,6 +17704,7 @@ const ICON_CHAT_BUBBLE_BOTTOM_CENTER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chat-bubble-left` — available in Filled U+E069, Filled/Mini U+E069, Outline U+E069, Filled/Tiny U+E063
 const ICON_CHAT_BUBBLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4509,6 +17738,7 @@ const ICON_CHAT_BUBBLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chat-bubble-left-ellipsis` — available in Filled U+E067, Filled/Mini U+E067, Outline U+E067, Filled/Tiny U+E061
 const ICON_CHAT_BUBBLE_LEFT_ELLIPSIS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4542,6 +17772,7 @@ const ICON_CHAT_BUBBLE_LEFT_ELLIPSIS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chat-bubble-left-right` — available in Filled U+E068, Filled/Mini U+E068, Outline U+E068, Filled/Tiny U+E062
 const ICON_CHAT_BUBBLE_LEFT_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4575,6 +17806,7 @@ const ICON_CHAT_BUBBLE_LEFT_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chat-bubble-oval-left` — available in Filled U+E06B, Filled/Mini U+E06B, Outline U+E06B, Filled/Tiny U+E065
 const ICON_CHAT_BUBBLE_OVAL_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4608,6 +17840,7 @@ const ICON_CHAT_BUBBLE_OVAL_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chat-bubble-oval-left-ellipsis` — available in Filled U+E06A, Filled/Mini U+E06A, Outline U+E06A, Filled/Tiny U+E064
 const ICON_CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4641,6 +17874,7 @@ const ICON_CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `check` — available in Filled U+E06E, Filled/Mini U+E06E, Outline U+E06E, Filled/Tiny U+E068
 const ICON_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4674,6 +17908,7 @@ const ICON_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `check-badge` — available in Filled U+E06C, Filled/Mini U+E06C, Outline U+E06C, Filled/Tiny U+E066
 const ICON_CHECK_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4707,6 +17942,7 @@ const ICON_CHECK_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `check-circle` — available in Filled U+E06D, Filled/Mini U+E06D, Outline U+E06D, Filled/Tiny U+E067
 const ICON_CHECK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4740,6 +17976,7 @@ const ICON_CHECK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-double-down` — available in Filled U+E06F, Filled/Mini U+E06F, Outline U+E06F, Filled/Tiny U+E069
 const ICON_CHEVRON_DOUBLE_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4773,6 +18010,7 @@ const ICON_CHEVRON_DOUBLE_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-double-left` — available in Filled U+E070, Filled/Mini U+E070, Outline U+E070, Filled/Tiny U+E06A
 const ICON_CHEVRON_DOUBLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4806,6 +18044,7 @@ const ICON_CHEVRON_DOUBLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-double-right` — available in Filled U+E071, Filled/Mini U+E071, Outline U+E071, Filled/Tiny U+E06B
 const ICON_CHEVRON_DOUBLE_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4839,6 +18078,7 @@ const ICON_CHEVRON_DOUBLE_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-double-up` — available in Filled U+E072, Filled/Mini U+E072, Outline U+E072, Filled/Tiny U+E06C
 const ICON_CHEVRON_DOUBLE_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4872,6 +18112,7 @@ const ICON_CHEVRON_DOUBLE_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-down` — available in Filled U+E073, Filled/Mini U+E073, Outline U+E073, Filled/Tiny U+E06D
 const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4905,6 +18146,7 @@ const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-left` — available in Filled U+E074, Filled/Mini U+E074, Outline U+E074, Filled/Tiny U+E06E
 const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4938,6 +18180,7 @@ const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-right` — available in Filled U+E075, Filled/Mini U+E075, Outline U+E075, Filled/Tiny U+E06F
 const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4971,6 +18214,7 @@ const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-up` — available in Filled U+E077, Filled/Mini U+E077, Outline U+E077, Filled/Tiny U+E071
 const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5004,6 +18248,7 @@ const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-up-down` — available in Filled U+E076, Filled/Mini U+E076, Outline U+E076, Filled/Tiny U+E070
 const ICON_CHEVRON_UP_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5037,6 +18282,7 @@ const ICON_CHEVRON_UP_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `circle-stack` — available in Filled U+E078, Filled/Mini U+E078, Outline U+E078, Filled/Tiny U+E072
 const ICON_CIRCLE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5070,6 +18316,7 @@ const ICON_CIRCLE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `clipboard` — available in Filled U+E07C, Filled/Mini U+E07C, Outline U+E07C, Filled/Tiny U+E076
 const ICON_CLIPBOARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5103,6 +18350,7 @@ const ICON_CLIPBOARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `clipboard-document` — available in Filled U+E07B, Filled/Mini U+E07B, Outline U+E07B, Filled/Tiny U+E075
 const ICON_CLIPBOARD_DOCUMENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5136,6 +18384,7 @@ const ICON_CLIPBOARD_DOCUMENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `clipboard-document-check` — available in Filled U+E079, Filled/Mini U+E079, Outline U+E079, Filled/Tiny U+E073
 const ICON_CLIPBOARD_DOCUMENT_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5169,6 +18418,7 @@ const ICON_CLIPBOARD_DOCUMENT_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `clipboard-document-list` — available in Filled U+E07A, Filled/Mini U+E07A, Outline U+E07A, Filled/Tiny U+E074
 const ICON_CLIPBOARD_DOCUMENT_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5202,6 +18452,7 @@ const ICON_CLIPBOARD_DOCUMENT_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `clock` — available in Filled U+E07D, Filled/Mini U+E07D, Outline U+E07D, Filled/Tiny U+E077
 const ICON_CLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5235,6 +18486,7 @@ const ICON_CLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cloud` — available in Filled U+E080, Filled/Mini U+E080, Outline U+E080, Filled/Tiny U+E07A
 const ICON_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5268,6 +18520,7 @@ const ICON_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cloud-arrow-down` — available in Filled U+E07E, Filled/Mini U+E07E, Outline U+E07E, Filled/Tiny U+E078
 const ICON_CLOUD_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5301,6 +18554,7 @@ const ICON_CLOUD_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cloud-arrow-up` — available in Filled U+E07F, Filled/Mini U+E07F, Outline U+E07F, Filled/Tiny U+E079
 const ICON_CLOUD_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5334,6 +18588,7 @@ const ICON_CLOUD_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `code-bracket` — available in Filled U+E082, Filled/Mini U+E082, Outline U+E082, Filled/Tiny U+E07C
 const ICON_CODE_BRACKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5367,6 +18622,7 @@ const ICON_CODE_BRACKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `code-bracket-square` — available in Filled U+E081, Filled/Mini U+E081, Outline U+E081, Filled/Tiny U+E07B
 const ICON_CODE_BRACKET_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5400,6 +18656,7 @@ const ICON_CODE_BRACKET_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cog` — available in Filled U+E085, Filled/Mini U+E085, Outline U+E085, Filled/Tiny U+E07F
 const ICON_COG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5433,6 +18690,7 @@ const ICON_COG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cog-6-tooth` — available in Filled U+E083, Filled/Mini U+E083, Outline U+E083, Filled/Tiny U+E07D
 const ICON_COG_6_TOOTH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5466,6 +18724,7 @@ const ICON_COG_6_TOOTH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cog-8-tooth` — available in Filled U+E084, Filled/Mini U+E084, Outline U+E084, Filled/Tiny U+E07E
 const ICON_COG_8_TOOTH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5499,6 +18758,7 @@ const ICON_COG_8_TOOTH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `command-line` — available in Filled U+E086, Filled/Mini U+E086, Outline U+E086, Filled/Tiny U+E080
 const ICON_COMMAND_LINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5532,6 +18792,7 @@ const ICON_COMMAND_LINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `computer-desktop` — available in Filled U+E087, Filled/Mini U+E087, Outline U+E087, Filled/Tiny U+E081
 const ICON_COMPUTER_DESKTOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5565,6 +18826,7 @@ const ICON_COMPUTER_DESKTOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cpu-chip` — available in Filled U+E088, Filled/Mini U+E088, Outline U+E088, Filled/Tiny U+E082
 const ICON_CPU_CHIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5598,6 +18860,7 @@ const ICON_CPU_CHIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `credit-card` — available in Filled U+E089, Filled/Mini U+E089, Outline U+E089, Filled/Tiny U+E083
 const ICON_CREDIT_CARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5631,6 +18894,7 @@ const ICON_CREDIT_CARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cube` — available in Filled U+E08B, Filled/Mini U+E08B, Outline U+E08B, Filled/Tiny U+E085
 const ICON_CUBE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5664,6 +18928,7 @@ const ICON_CUBE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cube-transparent` — available in Filled U+E08A, Filled/Mini U+E08A, Outline U+E08A, Filled/Tiny U+E084
 const ICON_CUBE_TRANSPARENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5697,6 +18962,7 @@ const ICON_CUBE_TRANSPARENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `currency-bangladeshi` — available in Filled U+E08C, Filled/Mini U+E08C, Outline U+E08C, Filled/Tiny U+E086
 const ICON_CURRENCY_BANGLADESHI_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5730,6 +18996,7 @@ const ICON_CURRENCY_BANGLADESHI_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `currency-dollar` — available in Filled U+E08D, Filled/Mini U+E08D, Outline U+E08D, Filled/Tiny U+E087
 const ICON_CURRENCY_DOLLAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5763,6 +19030,7 @@ const ICON_CURRENCY_DOLLAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `currency-euro` — available in Filled U+E08E, Filled/Mini U+E08E, Outline U+E08E, Filled/Tiny U+E088
 const ICON_CURRENCY_EURO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5796,6 +19064,7 @@ const ICON_CURRENCY_EURO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `currency-pound` — available in Filled U+E08F, Filled/Mini U+E08F, Outline U+E08F, Filled/Tiny U+E089
 const ICON_CURRENCY_POUND_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5829,6 +19098,7 @@ const ICON_CURRENCY_POUND_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `currency-rupee` — available in Filled U+E090, Filled/Mini U+E090, Outline U+E090, Filled/Tiny U+E08A
 const ICON_CURRENCY_RUPEE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5862,6 +19132,7 @@ const ICON_CURRENCY_RUPEE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `currency-yen` — available in Filled U+E091, Filled/Mini U+E091, Outline U+E091, Filled/Tiny U+E08B
 const ICON_CURRENCY_YEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5895,6 +19166,7 @@ const ICON_CURRENCY_YEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cursor-arrow-rays` — available in Filled U+E092, Filled/Mini U+E092, Outline U+E092, Filled/Tiny U+E08C
 const ICON_CURSOR_ARROW_RAYS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5928,6 +19200,7 @@ const ICON_CURSOR_ARROW_RAYS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cursor-arrow-ripple` — available in Filled U+E093, Filled/Mini U+E093, Outline U+E093, Filled/Tiny U+E08D
 const ICON_CURSOR_ARROW_RIPPLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5961,6 +19234,7 @@ const ICON_CURSOR_ARROW_RIPPLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `device-phone-mobile` — available in Filled U+E094, Filled/Mini U+E094, Outline U+E094, Filled/Tiny U+E08E
 const ICON_DEVICE_PHONE_MOBILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5994,6 +19268,7 @@ const ICON_DEVICE_PHONE_MOBILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `device-tablet` — available in Filled U+E095, Filled/Mini U+E095, Outline U+E095, Filled/Tiny U+E08F
 const ICON_DEVICE_TABLET_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6027,6 +19302,7 @@ const ICON_DEVICE_TABLET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `divide` — available in Filled U+E096, Filled/Mini U+E096, Outline U+E096, Filled/Tiny U+E090
 const ICON_DIVIDE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6060,6 +19336,7 @@ const ICON_DIVIDE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document` — available in Filled U+E0A6, Filled/Mini U+E0A6, Outline U+E0A6, Filled/Tiny U+E0A0
 const ICON_DOCUMENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6093,6 +19370,7 @@ const ICON_DOCUMENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-arrow-down` — available in Filled U+E097, Filled/Mini U+E097, Outline U+E097, Filled/Tiny U+E091
 const ICON_DOCUMENT_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6126,6 +19404,7 @@ const ICON_DOCUMENT_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-arrow-up` — available in Filled U+E098, Filled/Mini U+E098, Outline U+E098, Filled/Tiny U+E092
 const ICON_DOCUMENT_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6159,6 +19438,7 @@ const ICON_DOCUMENT_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-chart-bar` — available in Filled U+E099, Filled/Mini U+E099, Outline U+E099, Filled/Tiny U+E093
 const ICON_DOCUMENT_CHART_BAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6192,6 +19472,7 @@ const ICON_DOCUMENT_CHART_BAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-check` — available in Filled U+E09A, Filled/Mini U+E09A, Outline U+E09A, Filled/Tiny U+E094
 const ICON_DOCUMENT_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6225,6 +19506,7 @@ const ICON_DOCUMENT_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-currency-bangladeshi` — available in Filled U+E09B, Filled/Mini U+E09B, Outline U+E09B, Filled/Tiny U+E095
 const ICON_DOCUMENT_CURRENCY_BANGLADESHI_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6258,6 +19540,7 @@ const ICON_DOCUMENT_CURRENCY_BANGLADESHI_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-currency-dollar` — available in Filled U+E09C, Filled/Mini U+E09C, Outline U+E09C, Filled/Tiny U+E096
 const ICON_DOCUMENT_CURRENCY_DOLLAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6291,6 +19574,7 @@ const ICON_DOCUMENT_CURRENCY_DOLLAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-currency-euro` — available in Filled U+E09D, Filled/Mini U+E09D, Outline U+E09D, Filled/Tiny U+E097
 const ICON_DOCUMENT_CURRENCY_EURO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6324,6 +19608,7 @@ const ICON_DOCUMENT_CURRENCY_EURO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-currency-pound` — available in Filled U+E09E, Filled/Mini U+E09E, Outline U+E09E, Filled/Tiny U+E098
 const ICON_DOCUMENT_CURRENCY_POUND_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6357,6 +19642,7 @@ const ICON_DOCUMENT_CURRENCY_POUND_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-currency-rupee` — available in Filled U+E09F, Filled/Mini U+E09F, Outline U+E09F, Filled/Tiny U+E099
 const ICON_DOCUMENT_CURRENCY_RUPEE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6390,6 +19676,7 @@ const ICON_DOCUMENT_CURRENCY_RUPEE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-currency-yen` — available in Filled U+E0A0, Filled/Mini U+E0A0, Outline U+E0A0, Filled/Tiny U+E09A
 const ICON_DOCUMENT_CURRENCY_YEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6423,6 +19710,7 @@ const ICON_DOCUMENT_CURRENCY_YEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-duplicate` — available in Filled U+E0A1, Filled/Mini U+E0A1, Outline U+E0A1, Filled/Tiny U+E09B
 const ICON_DOCUMENT_DUPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6456,6 +19744,7 @@ const ICON_DOCUMENT_DUPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-magnifying-glass` — available in Filled U+E0A2, Filled/Mini U+E0A2, Outline U+E0A2, Filled/Tiny U+E09C
 const ICON_DOCUMENT_MAGNIFYING_GLASS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6489,6 +19778,7 @@ const ICON_DOCUMENT_MAGNIFYING_GLASS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-minus` — available in Filled U+E0A3, Filled/Mini U+E0A3, Outline U+E0A3, Filled/Tiny U+E09D
 const ICON_DOCUMENT_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6522,6 +19812,7 @@ const ICON_DOCUMENT_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-plus` — available in Filled U+E0A4, Filled/Mini U+E0A4, Outline U+E0A4, Filled/Tiny U+E09E
 const ICON_DOCUMENT_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6555,6 +19846,7 @@ const ICON_DOCUMENT_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `document-text` — available in Filled U+E0A5, Filled/Mini U+E0A5, Outline U+E0A5, Filled/Tiny U+E09F
 const ICON_DOCUMENT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6588,6 +19880,7 @@ const ICON_DOCUMENT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `ellipsis-horizontal` — available in Filled U+E0A8, Filled/Mini U+E0A8, Outline U+E0A8, Filled/Tiny U+E0A2
 const ICON_ELLIPSIS_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6621,6 +19914,7 @@ const ICON_ELLIPSIS_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `ellipsis-horizontal-circle` — available in Filled U+E0A7, Filled/Mini U+E0A7, Outline U+E0A7, Filled/Tiny U+E0A1
 const ICON_ELLIPSIS_HORIZONTAL_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6654,6 +19948,7 @@ const ICON_ELLIPSIS_HORIZONTAL_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `ellipsis-vertical` — available in Filled U+E0A9, Filled/Mini U+E0A9, Outline U+E0A9, Filled/Tiny U+E0A3
 const ICON_ELLIPSIS_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6687,6 +19982,7 @@ const ICON_ELLIPSIS_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `envelope` — available in Filled U+E0AB, Filled/Mini U+E0AB, Outline U+E0AB, Filled/Tiny U+E0A5
 const ICON_ENVELOPE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6720,6 +20016,7 @@ const ICON_ENVELOPE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `envelope-open` — available in Filled U+E0AA, Filled/Mini U+E0AA, Outline U+E0AA, Filled/Tiny U+E0A4
 const ICON_ENVELOPE_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6753,6 +20050,7 @@ const ICON_ENVELOPE_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `equals` — available in Filled U+E0AC, Filled/Mini U+E0AC, Outline U+E0AC, Filled/Tiny U+E0A6
 const ICON_EQUALS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6786,6 +20084,7 @@ const ICON_EQUALS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `exclamation-circle` — available in Filled U+E0AD, Filled/Mini U+E0AD, Outline U+E0AD, Filled/Tiny U+E0A7
 const ICON_EXCLAMATION_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6819,6 +20118,7 @@ const ICON_EXCLAMATION_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `exclamation-triangle` — available in Filled U+E0AE, Filled/Mini U+E0AE, Outline U+E0AE, Filled/Tiny U+E0A8
 const ICON_EXCLAMATION_TRIANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6852,6 +20152,7 @@ const ICON_EXCLAMATION_TRIANGLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `eye` — available in Filled U+E0B1, Filled/Mini U+E0B1, Outline U+E0B1, Filled/Tiny U+E0AB
 const ICON_EYE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6885,6 +20186,7 @@ const ICON_EYE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `eye-dropper` — available in Filled U+E0AF, Filled/Mini U+E0AF, Outline U+E0AF, Filled/Tiny U+E0A9
 const ICON_EYE_DROPPER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6918,6 +20220,7 @@ const ICON_EYE_DROPPER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `eye-slash` — available in Filled U+E0B0, Filled/Mini U+E0B0, Outline U+E0B0, Filled/Tiny U+E0AA
 const ICON_EYE_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6951,6 +20254,7 @@ const ICON_EYE_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `face-frown` — available in Filled U+E0B2, Filled/Mini U+E0B2, Outline U+E0B2, Filled/Tiny U+E0AC
 const ICON_FACE_FROWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6984,6 +20288,7 @@ const ICON_FACE_FROWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `face-smile` — available in Filled U+E0B3, Filled/Mini U+E0B3, Outline U+E0B3, Filled/Tiny U+E0AD
 const ICON_FACE_SMILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7017,6 +20322,7 @@ const ICON_FACE_SMILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `film` — available in Filled U+E0B4, Filled/Mini U+E0B4, Outline U+E0B4, Filled/Tiny U+E0AE
 const ICON_FILM_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7050,6 +20356,7 @@ const ICON_FILM_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `finger-print` — available in Filled U+E0B5, Filled/Mini U+E0B5, Outline U+E0B5, Filled/Tiny U+E0AF
 const ICON_FINGER_PRINT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7083,6 +20390,7 @@ const ICON_FINGER_PRINT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `fire` — available in Filled U+E0B6, Filled/Mini U+E0B6, Outline U+E0B6, Filled/Tiny U+E0B0
 const ICON_FIRE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7116,6 +20424,7 @@ const ICON_FIRE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `flag` — available in Filled U+E0B7, Filled/Mini U+E0B7, Outline U+E0B7, Filled/Tiny U+E0B1
 const ICON_FLAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7149,6 +20458,7 @@ const ICON_FLAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `folder` — available in Filled U+E0BC, Filled/Mini U+E0BC, Outline U+E0BC, Filled/Tiny U+E0B6
 const ICON_FOLDER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7182,6 +20492,7 @@ const ICON_FOLDER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `folder-arrow-down` — available in Filled U+E0B8, Filled/Mini U+E0B8, Outline U+E0B8, Filled/Tiny U+E0B2
 const ICON_FOLDER_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7215,6 +20526,7 @@ const ICON_FOLDER_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `folder-minus` — available in Filled U+E0B9, Filled/Mini U+E0B9, Outline U+E0B9, Filled/Tiny U+E0B3
 const ICON_FOLDER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7248,6 +20560,7 @@ const ICON_FOLDER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `folder-open` — available in Filled U+E0BA, Filled/Mini U+E0BA, Outline U+E0BA, Filled/Tiny U+E0B4
 const ICON_FOLDER_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7281,6 +20594,7 @@ const ICON_FOLDER_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `folder-plus` — available in Filled U+E0BB, Filled/Mini U+E0BB, Outline U+E0BB, Filled/Tiny U+E0B5
 const ICON_FOLDER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7314,6 +20628,7 @@ const ICON_FOLDER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `forward` — available in Filled U+E0BD, Filled/Mini U+E0BD, Outline U+E0BD, Filled/Tiny U+E0B7
 const ICON_FORWARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7347,6 +20662,7 @@ const ICON_FORWARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `funnel` — available in Filled U+E0BE, Filled/Mini U+E0BE, Outline U+E0BE, Filled/Tiny U+E0B8
 const ICON_FUNNEL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7380,6 +20696,7 @@ const ICON_FUNNEL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `gif` — available in Filled U+E0BF, Filled/Mini U+E0BF, Outline U+E0BF, Filled/Tiny U+E0B9
 const ICON_GIF_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7413,6 +20730,7 @@ const ICON_GIF_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `gift` — available in Filled U+E0C1, Filled/Mini U+E0C1, Outline U+E0C1, Filled/Tiny U+E0BB
 const ICON_GIFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7446,6 +20764,7 @@ const ICON_GIFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `gift-top` — available in Filled U+E0C0, Filled/Mini U+E0C0, Outline U+E0C0, Filled/Tiny U+E0BA
 const ICON_GIFT_TOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7479,6 +20798,7 @@ const ICON_GIFT_TOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `globe-alt` — available in Filled U+E0C2, Filled/Mini U+E0C2, Outline U+E0C2, Filled/Tiny U+E0BC
 const ICON_GLOBE_ALT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7512,6 +20832,7 @@ const ICON_GLOBE_ALT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `globe-americas` — available in Filled U+E0C3, Filled/Mini U+E0C3, Outline U+E0C3, Filled/Tiny U+E0BD
 const ICON_GLOBE_AMERICAS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7545,6 +20866,7 @@ const ICON_GLOBE_AMERICAS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `globe-asia-australia` — available in Filled U+E0C4, Filled/Mini U+E0C4, Outline U+E0C4, Filled/Tiny U+E0BE
 const ICON_GLOBE_ASIA_AUSTRALIA_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7578,6 +20900,7 @@ const ICON_GLOBE_ASIA_AUSTRALIA_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `globe-europe-africa` — available in Filled U+E0C5, Filled/Mini U+E0C5, Outline U+E0C5, Filled/Tiny U+E0BF
 const ICON_GLOBE_EUROPE_AFRICA_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7611,6 +20934,7 @@ const ICON_GLOBE_EUROPE_AFRICA_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `h1` — available in Filled U+E0C6, Filled/Mini U+E0C6, Outline U+E0C6, Filled/Tiny U+E0C0
 const ICON_H_1_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7644,6 +20968,7 @@ const ICON_H_1_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `h2` — available in Filled U+E0C7, Filled/Mini U+E0C7, Outline U+E0C7, Filled/Tiny U+E0C1
 const ICON_H_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7677,6 +21002,7 @@ const ICON_H_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `h3` — available in Filled U+E0C8, Filled/Mini U+E0C8, Outline U+E0C8, Filled/Tiny U+E0C2
 const ICON_H_3_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7710,6 +21036,7 @@ const ICON_H_3_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `hand-raised` — available in Filled U+E0C9, Filled/Mini U+E0C9, Outline U+E0C9, Filled/Tiny U+E0C3
 const ICON_HAND_RAISED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7743,6 +21070,7 @@ const ICON_HAND_RAISED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `hand-thumb-down` — available in Filled U+E0CA, Filled/Mini U+E0CA, Outline U+E0CA, Filled/Tiny U+E0C4
 const ICON_HAND_THUMB_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7776,6 +21104,7 @@ const ICON_HAND_THUMB_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `hand-thumb-up` — available in Filled U+E0CB, Filled/Mini U+E0CB, Outline U+E0CB, Filled/Tiny U+E0C5
 const ICON_HAND_THUMB_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7809,6 +21138,7 @@ const ICON_HAND_THUMB_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `hashtag` — available in Filled U+E0CC, Filled/Mini U+E0CC, Outline U+E0CC, Filled/Tiny U+E0C6
 const ICON_HASHTAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7842,6 +21172,7 @@ const ICON_HASHTAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `heart` — available in Filled U+E0CD, Filled/Mini U+E0CD, Outline U+E0CD, Filled/Tiny U+E0C7
 const ICON_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7875,6 +21206,7 @@ const ICON_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `home` — available in Filled U+E0CF, Filled/Mini U+E0CF, Outline U+E0CF, Filled/Tiny U+E0C9
 const ICON_HOME_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7908,6 +21240,7 @@ const ICON_HOME_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `home-modern` — available in Filled U+E0CE, Filled/Mini U+E0CE, Outline U+E0CE, Filled/Tiny U+E0C8
 const ICON_HOME_MODERN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7941,6 +21274,7 @@ const ICON_HOME_MODERN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `identification` — available in Filled U+E0D0, Filled/Mini U+E0D0, Outline U+E0D0, Filled/Tiny U+E0CA
 const ICON_IDENTIFICATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7974,6 +21308,7 @@ const ICON_IDENTIFICATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `inbox` — available in Filled U+E0D3, Filled/Mini U+E0D3, Outline U+E0D3, Filled/Tiny U+E0CD
 const ICON_INBOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8007,6 +21342,7 @@ const ICON_INBOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `inbox-arrow-down` — available in Filled U+E0D1, Filled/Mini U+E0D1, Outline U+E0D1, Filled/Tiny U+E0CB
 const ICON_INBOX_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8040,6 +21376,7 @@ const ICON_INBOX_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `inbox-stack` — available in Filled U+E0D2, Filled/Mini U+E0D2, Outline U+E0D2, Filled/Tiny U+E0CC
 const ICON_INBOX_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8073,6 +21410,7 @@ const ICON_INBOX_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `information-circle` — available in Filled U+E0D4, Filled/Mini U+E0D4, Outline U+E0D4, Filled/Tiny U+E0CE
 const ICON_INFORMATION_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8106,6 +21444,7 @@ const ICON_INFORMATION_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `italic` — available in Filled U+E0D5, Filled/Mini U+E0D5, Outline U+E0D5, Filled/Tiny U+E0CF
 const ICON_ITALIC_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8139,6 +21478,7 @@ const ICON_ITALIC_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `key` — available in Filled U+E0D6, Filled/Mini U+E0D6, Outline U+E0D6, Filled/Tiny U+E0D0
 const ICON_KEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8172,6 +21512,7 @@ const ICON_KEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `language` — available in Filled U+E0D7, Filled/Mini U+E0D7, Outline U+E0D7, Filled/Tiny U+E0D1
 const ICON_LANGUAGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8205,6 +21546,7 @@ const ICON_LANGUAGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `lifebuoy` — available in Filled U+E0D8, Filled/Mini U+E0D8, Outline U+E0D8, Filled/Tiny U+E0D2
 const ICON_LIFEBUOY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8238,6 +21580,7 @@ const ICON_LIFEBUOY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `light-bulb` — available in Filled U+E0D9, Filled/Mini U+E0D9, Outline U+E0D9, Filled/Tiny U+E0D3
 const ICON_LIGHT_BULB_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8271,6 +21614,7 @@ const ICON_LIGHT_BULB_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `link` — available in Filled U+E0DB, Filled/Mini U+E0DB, Outline U+E0DB, Filled/Tiny U+E0D5
 const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8304,6 +21648,7 @@ const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `link-slash` — available in Filled U+E0DA, Filled/Mini U+E0DA, Outline U+E0DA, Filled/Tiny U+E0D4
 const ICON_LINK_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8337,6 +21682,7 @@ const ICON_LINK_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `list-bullet` — available in Filled U+E0DC, Filled/Mini U+E0DC, Outline U+E0DC, Filled/Tiny U+E0D6
 const ICON_LIST_BULLET_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8370,6 +21716,7 @@ const ICON_LIST_BULLET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `lock-closed` — available in Filled U+E0DD, Filled/Mini U+E0DD, Outline U+E0DD, Filled/Tiny U+E0D7
 const ICON_LOCK_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8403,6 +21750,7 @@ const ICON_LOCK_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `lock-open` — available in Filled U+E0DE, Filled/Mini U+E0DE, Outline U+E0DE, Filled/Tiny U+E0D8
 const ICON_LOCK_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8436,6 +21784,7 @@ const ICON_LOCK_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `magnifying-glass` — available in Filled U+E0E2, Filled/Mini U+E0E2, Outline U+E0E2, Filled/Tiny U+E0DC
 const ICON_MAGNIFYING_GLASS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8469,6 +21818,7 @@ const ICON_MAGNIFYING_GLASS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `magnifying-glass-circle` — available in Filled U+E0DF, Filled/Mini U+E0DF, Outline U+E0DF, Filled/Tiny U+E0D9
 const ICON_MAGNIFYING_GLASS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8502,6 +21852,7 @@ const ICON_MAGNIFYING_GLASS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `magnifying-glass-minus` — available in Filled U+E0E0, Filled/Mini U+E0E0, Outline U+E0E0, Filled/Tiny U+E0DA
 const ICON_MAGNIFYING_GLASS_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8535,6 +21886,7 @@ const ICON_MAGNIFYING_GLASS_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `magnifying-glass-plus` — available in Filled U+E0E1, Filled/Mini U+E0E1, Outline U+E0E1, Filled/Tiny U+E0DB
 const ICON_MAGNIFYING_GLASS_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8568,6 +21920,7 @@ const ICON_MAGNIFYING_GLASS_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `map` — available in Filled U+E0E4, Filled/Mini U+E0E4, Outline U+E0E4, Filled/Tiny U+E0DE
 const ICON_MAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8601,6 +21954,7 @@ const ICON_MAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `map-pin` — available in Filled U+E0E3, Filled/Mini U+E0E3, Outline U+E0E3, Filled/Tiny U+E0DD
 const ICON_MAP_PIN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8634,6 +21988,7 @@ const ICON_MAP_PIN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `megaphone` — available in Filled U+E0E5, Filled/Mini U+E0E5, Outline U+E0E5, Filled/Tiny U+E0DF
 const ICON_MEGAPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8667,6 +22022,7 @@ const ICON_MEGAPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `microphone` — available in Filled U+E0E6, Filled/Mini U+E0E6, Outline U+E0E6, Filled/Tiny U+E0E0
 const ICON_MICROPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8700,6 +22056,7 @@ const ICON_MICROPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `minus` — available in Filled U+E0E9, Filled/Mini U+E0E9, Outline U+E0E9, Filled/Tiny U+E0E2
 const ICON_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8733,6 +22090,7 @@ const ICON_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `minus-circle` — available in Filled U+E0E7, Filled/Mini U+E0E7, Outline U+E0E7, Filled/Tiny U+E0E1
 const ICON_MINUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8766,6 +22124,7 @@ const ICON_MINUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `minus-small` — U+E0E8 (available in Filled, Filled/Mini, Outline)
 const ICON_MINUS_SMALL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8791,6 +22150,7 @@ const ICON_MINUS_SMALL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `moon` — available in Filled U+E0EA, Filled/Mini U+E0EA, Outline U+E0EA, Filled/Tiny U+E0E3
 const ICON_MOON_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8824,6 +22184,7 @@ const ICON_MOON_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `musical-note` — available in Filled U+E0EB, Filled/Mini U+E0EB, Outline U+E0EB, Filled/Tiny U+E0E4
 const ICON_MUSICAL_NOTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8857,6 +22218,7 @@ const ICON_MUSICAL_NOTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `newspaper` — available in Filled U+E0EC, Filled/Mini U+E0EC, Outline U+E0EC, Filled/Tiny U+E0E5
 const ICON_NEWSPAPER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8890,6 +22252,7 @@ const ICON_NEWSPAPER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `no-symbol` — available in Filled U+E0ED, Filled/Mini U+E0ED, Outline U+E0ED, Filled/Tiny U+E0E6
 const ICON_NO_SYMBOL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8923,6 +22286,7 @@ const ICON_NO_SYMBOL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `numbered-list` — available in Filled U+E0EE, Filled/Mini U+E0EE, Outline U+E0EE, Filled/Tiny U+E0E7
 const ICON_NUMBERED_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8956,6 +22320,7 @@ const ICON_NUMBERED_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `paint-brush` — available in Filled U+E0EF, Filled/Mini U+E0EF, Outline U+E0EF, Filled/Tiny U+E0E8
 const ICON_PAINT_BRUSH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -8989,6 +22354,7 @@ const ICON_PAINT_BRUSH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `paper-airplane` — available in Filled U+E0F0, Filled/Mini U+E0F0, Outline U+E0F0, Filled/Tiny U+E0E9
 const ICON_PAPER_AIRPLANE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9022,6 +22388,7 @@ const ICON_PAPER_AIRPLANE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `paper-clip` — available in Filled U+E0F1, Filled/Mini U+E0F1, Outline U+E0F1, Filled/Tiny U+E0EA
 const ICON_PAPER_CLIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9055,6 +22422,7 @@ const ICON_PAPER_CLIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pause` — available in Filled U+E0F3, Filled/Mini U+E0F3, Outline U+E0F3, Filled/Tiny U+E0EC
 const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9088,6 +22456,7 @@ const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pause-circle` — available in Filled U+E0F2, Filled/Mini U+E0F2, Outline U+E0F2, Filled/Tiny U+E0EB
 const ICON_PAUSE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9121,6 +22490,7 @@ const ICON_PAUSE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pencil` — available in Filled U+E0F5, Filled/Mini U+E0F5, Outline U+E0F5, Filled/Tiny U+E0EE
 const ICON_PENCIL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9154,6 +22524,7 @@ const ICON_PENCIL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pencil-square` — available in Filled U+E0F4, Filled/Mini U+E0F4, Outline U+E0F4, Filled/Tiny U+E0ED
 const ICON_PENCIL_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9187,6 +22558,7 @@ const ICON_PENCIL_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `percent-badge` — available in Filled U+E0F6, Filled/Mini U+E0F6, Outline U+E0F6, Filled/Tiny U+E0EF
 const ICON_PERCENT_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9220,6 +22592,7 @@ const ICON_PERCENT_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `phone` — available in Filled U+E0FA, Filled/Mini U+E0FA, Outline U+E0FA, Filled/Tiny U+E0F3
 const ICON_PHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9253,6 +22626,7 @@ const ICON_PHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `phone-arrow-down-left` — available in Filled U+E0F7, Filled/Mini U+E0F7, Outline U+E0F7, Filled/Tiny U+E0F0
 const ICON_PHONE_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9286,6 +22660,7 @@ const ICON_PHONE_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `phone-arrow-up-right` — available in Filled U+E0F8, Filled/Mini U+E0F8, Outline U+E0F8, Filled/Tiny U+E0F1
 const ICON_PHONE_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9319,6 +22694,7 @@ const ICON_PHONE_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `phone-x-mark` — available in Filled U+E0F9, Filled/Mini U+E0F9, Outline U+E0F9, Filled/Tiny U+E0F2
 const ICON_PHONE_X_MARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9352,6 +22728,7 @@ const ICON_PHONE_X_MARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `photo` — available in Filled U+E0FB, Filled/Mini U+E0FB, Outline U+E0FB, Filled/Tiny U+E0F4
 const ICON_PHOTO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9385,6 +22762,7 @@ const ICON_PHOTO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `play` — available in Filled U+E0FE, Filled/Mini U+E0FE, Outline U+E0FE, Filled/Tiny U+E0F7
 const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9418,6 +22796,7 @@ const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `play-circle` — available in Filled U+E0FC, Filled/Mini U+E0FC, Outline U+E0FC, Filled/Tiny U+E0F5
 const ICON_PLAY_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9451,6 +22830,7 @@ const ICON_PLAY_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `play-pause` — available in Filled U+E0FD, Filled/Mini U+E0FD, Outline U+E0FD, Filled/Tiny U+E0F6
 const ICON_PLAY_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9484,6 +22864,7 @@ const ICON_PLAY_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `plus` — available in Filled U+E101, Filled/Mini U+E101, Outline U+E101, Filled/Tiny U+E0F9
 const ICON_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9517,6 +22898,7 @@ const ICON_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `plus-circle` — available in Filled U+E0FF, Filled/Mini U+E0FF, Outline U+E0FF, Filled/Tiny U+E0F8
 const ICON_PLUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9550,6 +22932,7 @@ const ICON_PLUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `plus-small` — U+E100 (available in Filled, Filled/Mini, Outline)
 const ICON_PLUS_SMALL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9575,6 +22958,7 @@ const ICON_PLUS_SMALL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `power` — available in Filled U+E102, Filled/Mini U+E102, Outline U+E102, Filled/Tiny U+E0FA
 const ICON_POWER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9608,6 +22992,7 @@ const ICON_POWER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `presentation-chart-bar` — available in Filled U+E103, Filled/Mini U+E103, Outline U+E103, Filled/Tiny U+E0FB
 const ICON_PRESENTATION_CHART_BAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9641,6 +23026,7 @@ const ICON_PRESENTATION_CHART_BAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `presentation-chart-line` — available in Filled U+E104, Filled/Mini U+E104, Outline U+E104, Filled/Tiny U+E0FC
 const ICON_PRESENTATION_CHART_LINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9674,6 +23060,7 @@ const ICON_PRESENTATION_CHART_LINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `printer` — available in Filled U+E105, Filled/Mini U+E105, Outline U+E105, Filled/Tiny U+E0FD
 const ICON_PRINTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9707,6 +23094,7 @@ const ICON_PRINTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `puzzle-piece` — available in Filled U+E106, Filled/Mini U+E106, Outline U+E106, Filled/Tiny U+E0FE
 const ICON_PUZZLE_PIECE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9740,6 +23128,7 @@ const ICON_PUZZLE_PIECE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `qr-code` — available in Filled U+E107, Filled/Mini U+E107, Outline U+E107, Filled/Tiny U+E0FF
 const ICON_QR_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9773,6 +23162,7 @@ const ICON_QR_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `question-mark-circle` — available in Filled U+E108, Filled/Mini U+E108, Outline U+E108, Filled/Tiny U+E100
 const ICON_QUESTION_MARK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9806,6 +23196,7 @@ const ICON_QUESTION_MARK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `queue-list` — available in Filled U+E109, Filled/Mini U+E109, Outline U+E109, Filled/Tiny U+E101
 const ICON_QUEUE_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9839,6 +23230,7 @@ const ICON_QUEUE_LIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `radio` — available in Filled U+E10A, Filled/Mini U+E10A, Outline U+E10A, Filled/Tiny U+E102
 const ICON_RADIO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9872,6 +23264,7 @@ const ICON_RADIO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `receipt-percent` — available in Filled U+E10B, Filled/Mini U+E10B, Outline U+E10B, Filled/Tiny U+E103
 const ICON_RECEIPT_PERCENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9905,6 +23298,7 @@ const ICON_RECEIPT_PERCENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `receipt-refund` — available in Filled U+E10C, Filled/Mini U+E10C, Outline U+E10C, Filled/Tiny U+E104
 const ICON_RECEIPT_REFUND_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9938,6 +23332,7 @@ const ICON_RECEIPT_REFUND_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rectangle-group` — available in Filled U+E10D, Filled/Mini U+E10D, Outline U+E10D, Filled/Tiny U+E105
 const ICON_RECTANGLE_GROUP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -9971,6 +23366,7 @@ const ICON_RECTANGLE_GROUP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rectangle-stack` — available in Filled U+E10E, Filled/Mini U+E10E, Outline U+E10E, Filled/Tiny U+E106
 const ICON_RECTANGLE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10004,6 +23400,7 @@ const ICON_RECTANGLE_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rocket-launch` — available in Filled U+E10F, Filled/Mini U+E10F, Outline U+E10F, Filled/Tiny U+E107
 const ICON_ROCKET_LAUNCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10037,6 +23434,7 @@ const ICON_ROCKET_LAUNCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rss` — available in Filled U+E110, Filled/Mini U+E110, Outline U+E110, Filled/Tiny U+E108
 const ICON_RSS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10070,6 +23468,7 @@ const ICON_RSS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `scale` — available in Filled U+E111, Filled/Mini U+E111, Outline U+E111, Filled/Tiny U+E109
 const ICON_SCALE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10103,6 +23502,7 @@ const ICON_SCALE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `scissors` — available in Filled U+E112, Filled/Mini U+E112, Outline U+E112, Filled/Tiny U+E10A
 const ICON_SCISSORS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10136,6 +23536,7 @@ const ICON_SCISSORS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `server` — available in Filled U+E114, Filled/Mini U+E114, Outline U+E114, Filled/Tiny U+E10C
 const ICON_SERVER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10169,6 +23570,7 @@ const ICON_SERVER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `server-stack` — available in Filled U+E113, Filled/Mini U+E113, Outline U+E113, Filled/Tiny U+E10B
 const ICON_SERVER_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10202,6 +23604,7 @@ const ICON_SERVER_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `share` — available in Filled U+E115, Filled/Mini U+E115, Outline U+E115, Filled/Tiny U+E10D
 const ICON_SHARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10235,6 +23638,7 @@ const ICON_SHARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shield-check` — available in Filled U+E116, Filled/Mini U+E116, Outline U+E116, Filled/Tiny U+E10E
 const ICON_SHIELD_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10268,6 +23672,7 @@ const ICON_SHIELD_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shield-exclamation` — available in Filled U+E117, Filled/Mini U+E117, Outline U+E117, Filled/Tiny U+E10F
 const ICON_SHIELD_EXCLAMATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10301,6 +23706,7 @@ const ICON_SHIELD_EXCLAMATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shopping-bag` — available in Filled U+E118, Filled/Mini U+E118, Outline U+E118, Filled/Tiny U+E110
 const ICON_SHOPPING_BAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10334,6 +23740,7 @@ const ICON_SHOPPING_BAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shopping-cart` — available in Filled U+E119, Filled/Mini U+E119, Outline U+E119, Filled/Tiny U+E111
 const ICON_SHOPPING_CART_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10367,6 +23774,7 @@ const ICON_SHOPPING_CART_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `signal` — available in Filled U+E11B, Filled/Mini U+E11B, Outline U+E11B, Filled/Tiny U+E113
 const ICON_SIGNAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10400,6 +23808,7 @@ const ICON_SIGNAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `signal-slash` — available in Filled U+E11A, Filled/Mini U+E11A, Outline U+E11A, Filled/Tiny U+E112
 const ICON_SIGNAL_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10433,6 +23842,7 @@ const ICON_SIGNAL_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `slash` — available in Filled U+E11C, Filled/Mini U+E11C, Outline U+E11C, Filled/Tiny U+E114
 const ICON_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10466,6 +23876,7 @@ const ICON_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sparkles` — available in Filled U+E11D, Filled/Mini U+E11D, Outline U+E11D, Filled/Tiny U+E115
 const ICON_SPARKLES_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10499,6 +23910,7 @@ const ICON_SPARKLES_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `speaker-wave` — available in Filled U+E11E, Filled/Mini U+E11E, Outline U+E11E, Filled/Tiny U+E116
 const ICON_SPEAKER_WAVE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10532,6 +23944,7 @@ const ICON_SPEAKER_WAVE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `speaker-x-mark` — available in Filled U+E11F, Filled/Mini U+E11F, Outline U+E11F, Filled/Tiny U+E117
 const ICON_SPEAKER_X_MARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10565,6 +23978,7 @@ const ICON_SPEAKER_X_MARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `square-2-stack` — available in Filled U+E120, Filled/Mini U+E120, Outline U+E120, Filled/Tiny U+E118
 const ICON_SQUARE_2_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10598,6 +24012,7 @@ const ICON_SQUARE_2_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `square-3-stack-3d` — available in Filled U+E121, Filled/Mini U+E121, Outline U+E121, Filled/Tiny U+E119
 const ICON_SQUARE_3_STACK_3D_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10631,6 +24046,7 @@ const ICON_SQUARE_3_STACK_3D_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `squares-2x2` — available in Filled U+E122, Filled/Mini U+E122, Outline U+E122, Filled/Tiny U+E11A
 const ICON_SQUARES_2X_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10664,6 +24080,7 @@ const ICON_SQUARES_2X_2_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `squares-plus` — available in Filled U+E123, Filled/Mini U+E123, Outline U+E123, Filled/Tiny U+E11B
 const ICON_SQUARES_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10697,6 +24114,7 @@ const ICON_SQUARES_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `star` — available in Filled U+E124, Filled/Mini U+E124, Outline U+E124, Filled/Tiny U+E11C
 const ICON_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10730,6 +24148,7 @@ const ICON_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `stop` — available in Filled U+E126, Filled/Mini U+E126, Outline U+E126, Filled/Tiny U+E11E
 const ICON_STOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10763,6 +24182,7 @@ const ICON_STOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `stop-circle` — available in Filled U+E125, Filled/Mini U+E125, Outline U+E125, Filled/Tiny U+E11D
 const ICON_STOP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10796,6 +24216,7 @@ const ICON_STOP_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `strikethrough` — available in Filled U+E127, Filled/Mini U+E127, Outline U+E127, Filled/Tiny U+E11F
 const ICON_STRIKETHROUGH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10829,6 +24250,7 @@ const ICON_STRIKETHROUGH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sun` — available in Filled U+E128, Filled/Mini U+E128, Outline U+E128, Filled/Tiny U+E120
 const ICON_SUN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10862,6 +24284,7 @@ const ICON_SUN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `swatch` — available in Filled U+E129, Filled/Mini U+E129, Outline U+E129, Filled/Tiny U+E121
 const ICON_SWATCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10895,6 +24318,7 @@ const ICON_SWATCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `table-cells` — available in Filled U+E12A, Filled/Mini U+E12A, Outline U+E12A, Filled/Tiny U+E122
 const ICON_TABLE_CELLS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10928,6 +24352,7 @@ const ICON_TABLE_CELLS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tag` — available in Filled U+E12B, Filled/Mini U+E12B, Outline U+E12B, Filled/Tiny U+E123
 const ICON_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10961,6 +24386,7 @@ const ICON_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `ticket` — available in Filled U+E12C, Filled/Mini U+E12C, Outline U+E12C, Filled/Tiny U+E124
 const ICON_TICKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -10994,6 +24420,7 @@ const ICON_TICKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `trash` — available in Filled U+E12D, Filled/Mini U+E12D, Outline U+E12D, Filled/Tiny U+E125
 const ICON_TRASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11027,6 +24454,7 @@ const ICON_TRASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `trophy` — available in Filled U+E12E, Filled/Mini U+E12E, Outline U+E12E, Filled/Tiny U+E126
 const ICON_TROPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11060,6 +24488,7 @@ const ICON_TROPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `truck` — available in Filled U+E12F, Filled/Mini U+E12F, Outline U+E12F, Filled/Tiny U+E127
 const ICON_TRUCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11093,6 +24522,7 @@ const ICON_TRUCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tv` — available in Filled U+E130, Filled/Mini U+E130, Outline U+E130, Filled/Tiny U+E128
 const ICON_TV_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11126,6 +24556,7 @@ const ICON_TV_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `underline` — available in Filled U+E131, Filled/Mini U+E131, Outline U+E131, Filled/Tiny U+E129
 const ICON_UNDERLINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11159,6 +24590,7 @@ const ICON_UNDERLINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `user` — available in Filled U+E136, Filled/Mini U+E136, Outline U+E136, Filled/Tiny U+E12E
 const ICON_USER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11192,6 +24624,7 @@ const ICON_USER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `user-circle` — available in Filled U+E132, Filled/Mini U+E132, Outline U+E132, Filled/Tiny U+E12A
 const ICON_USER_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11225,6 +24658,7 @@ const ICON_USER_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `user-group` — available in Filled U+E133, Filled/Mini U+E133, Outline U+E133, Filled/Tiny U+E12B
 const ICON_USER_GROUP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11258,6 +24692,7 @@ const ICON_USER_GROUP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `user-minus` — available in Filled U+E134, Filled/Mini U+E134, Outline U+E134, Filled/Tiny U+E12C
 const ICON_USER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11291,6 +24726,7 @@ const ICON_USER_MINUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `user-plus` — available in Filled U+E135, Filled/Mini U+E135, Outline U+E135, Filled/Tiny U+E12D
 const ICON_USER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11324,6 +24760,7 @@ const ICON_USER_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `users` — available in Filled U+E137, Filled/Mini U+E137, Outline U+E137, Filled/Tiny U+E12F
 const ICON_USERS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11357,6 +24794,7 @@ const ICON_USERS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `variable` — available in Filled U+E138, Filled/Mini U+E138, Outline U+E138, Filled/Tiny U+E130
 const ICON_VARIABLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11390,6 +24828,7 @@ const ICON_VARIABLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `video-camera` — available in Filled U+E13A, Filled/Mini U+E13A, Outline U+E13A, Filled/Tiny U+E132
 const ICON_VIDEO_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11423,6 +24862,7 @@ const ICON_VIDEO_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `video-camera-slash` — available in Filled U+E139, Filled/Mini U+E139, Outline U+E139, Filled/Tiny U+E131
 const ICON_VIDEO_CAMERA_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11456,6 +24896,7 @@ const ICON_VIDEO_CAMERA_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `view-columns` — available in Filled U+E13B, Filled/Mini U+E13B, Outline U+E13B, Filled/Tiny U+E133
 const ICON_VIEW_COLUMNS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11489,6 +24930,7 @@ const ICON_VIEW_COLUMNS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `viewfinder-circle` — available in Filled U+E13C, Filled/Mini U+E13C, Outline U+E13C, Filled/Tiny U+E134
 const ICON_VIEWFINDER_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11522,6 +24964,7 @@ const ICON_VIEWFINDER_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `wallet` — available in Filled U+E13D, Filled/Mini U+E13D, Outline U+E13D, Filled/Tiny U+E135
 const ICON_WALLET_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11555,6 +24998,7 @@ const ICON_WALLET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `wifi` — available in Filled U+E13E, Filled/Mini U+E13E, Outline U+E13E, Filled/Tiny U+E136
 const ICON_WIFI_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11588,6 +25032,7 @@ const ICON_WIFI_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `window` — available in Filled U+E13F, Filled/Mini U+E13F, Outline U+E13F, Filled/Tiny U+E137
 const ICON_WINDOW_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11621,6 +25066,7 @@ const ICON_WINDOW_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `wrench` — available in Filled U+E141, Filled/Mini U+E141, Outline U+E141, Filled/Tiny U+E139
 const ICON_WRENCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11654,6 +25100,7 @@ const ICON_WRENCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `wrench-screwdriver` — available in Filled U+E140, Filled/Mini U+E140, Outline U+E140, Filled/Tiny U+E138
 const ICON_WRENCH_SCREWDRIVER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11687,6 +25134,7 @@ const ICON_WRENCH_SCREWDRIVER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `x-circle` — available in Filled U+E142, Filled/Mini U+E142, Outline U+E142, Filled/Tiny U+E13A
 const ICON_X_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -11720,6 +25168,7 @@ const ICON_X_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `x-mark` — available in Filled U+E143, Filled/Mini U+E143, Outline U+E143, Filled/Tiny U+E13B
 const ICON_X_MARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -12389,7 +25838,7 @@ const ICON_BATTERY_0_AVAILABLE: &[(Style, Size)] = &[
     (Style::Filled, Size::Tiny),
 ];
 
-const ICON_BATTERY_1_0_0_AVAILABLE: &[(Style, Size)] = &[
+const ICON_BATTERY_100_AVAILABLE: &[(Style, Size)] = &[
     (Style::Filled, Size::Regular),
     #[cfg(feature = "heroicons-mini")]
     (Style::Filled, Size::Mini),
@@ -12398,7 +25847,7 @@ const ICON_BATTERY_1_0_0_AVAILABLE: &[(Style, Size)] = &[
     (Style::Filled, Size::Tiny),
 ];
 
-const ICON_BATTERY_5_0_AVAILABLE: &[(Style, Size)] = &[
+const ICON_BATTERY_50_AVAILABLE: &[(Style, Size)] = &[
     (Style::Filled, Size::Regular),
     #[cfg(feature = "heroicons-mini")]
     (Style::Filled, Size::Mini),
@@ -14653,12 +28102,14 @@ const ICON_X_MARK_AVAILABLE: &[(Style, Size)] = &[
     (Style::Filled, Size::Tiny),
 ];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconCodepoints {
     pub name: &'static str,
     pub codepoints: &'static [(VariantKey, u32)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     IconCodepoints {
         name: "academic-cap",
@@ -14950,11 +28401,11 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     },
     IconCodepoints {
         name: "battery-100",
-        codepoints: ICON_BATTERY_1_0_0_CODEPOINTS,
+        codepoints: ICON_BATTERY_100_CODEPOINTS,
     },
     IconCodepoints {
         name: "battery-50",
-        codepoints: ICON_BATTERY_5_0_CODEPOINTS,
+        codepoints: ICON_BATTERY_50_CODEPOINTS,
     },
     IconCodepoints {
         name: "beaker",
@@ -15958,12 +29409,14 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     },
 ];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconAvailability {
     pub name: &'static str,
     pub available: &'static [(Style, Size)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     IconAvailability {
         name: "academic-cap",
@@ -16255,11 +29708,11 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     },
     IconAvailability {
         name: "battery-100",
-        available: ICON_BATTERY_1_0_0_AVAILABLE,
+        available: ICON_BATTERY_100_AVAILABLE,
     },
     IconAvailability {
         name: "battery-50",
-        available: ICON_BATTERY_5_0_AVAILABLE,
+        available: ICON_BATTERY_50_AVAILABLE,
     },
     IconAvailability {
         name: "beaker",
@@ -17263,6 +30716,703 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     },
 ];
 
+pub(crate) const ICON_COLORS: &[(&str, [u8; 4])] = &[];
+
+/// Accessible labels, index-aligned with `ICON_NAMES`.
+pub(crate) const ICON_LABELS: &[&str] = &[
+    "Academic Cap",
+    "Adjustments Horizontal",
+    "Adjustments Vertical",
+    "Archive Box",
+    "Archive Box Arrow Down",
+    "Archive Box X Mark",
+    "Arrow Down",
+    "Arrow Down Circle",
+    "Arrow Down Left",
+    "Arrow Down On Square",
+    "Arrow Down On Square Stack",
+    "Arrow Down Right",
+    "Arrow Down Tray",
+    "Arrow Left",
+    "Arrow Left Circle",
+    "Arrow Left End On Rectangle",
+    "Arrow Left On Rectangle",
+    "Arrow Left Start On Rectangle",
+    "Arrow Long Down",
+    "Arrow Long Left",
+    "Arrow Long Right",
+    "Arrow Long Up",
+    "Arrow Path",
+    "Arrow Path Rounded Square",
+    "Arrow Right",
+    "Arrow Right Circle",
+    "Arrow Right End On Rectangle",
+    "Arrow Right On Rectangle",
+    "Arrow Right Start On Rectangle",
+    "Arrow Small Down",
+    "Arrow Small Left",
+    "Arrow Small Right",
+    "Arrow Small Up",
+    "Arrow Top Right On Square",
+    "Arrow Trending Down",
+    "Arrow Trending Up",
+    "Arrow Turn Down Left",
+    "Arrow Turn Down Right",
+    "Arrow Turn Left Down",
+    "Arrow Turn Left Up",
+    "Arrow Turn Right Down",
+    "Arrow Turn Right Up",
+    "Arrow Turn Up Left",
+    "Arrow Turn Up Right",
+    "Arrow Up",
+    "Arrow Up Circle",
+    "Arrow Up Left",
+    "Arrow Up On Square",
+    "Arrow Up On Square Stack",
+    "Arrow Up Right",
+    "Arrow Up Tray",
+    "Arrow Uturn Down",
+    "Arrow Uturn Left",
+    "Arrow Uturn Right",
+    "Arrow Uturn Up",
+    "Arrows Pointing In",
+    "Arrows Pointing Out",
+    "Arrows Right Left",
+    "Arrows Up Down",
+    "At Symbol",
+    "Backspace",
+    "Backward",
+    "Banknotes",
+    "Bars 2",
+    "Bars 3",
+    "Bars 3 Bottom Left",
+    "Bars 3 Bottom Right",
+    "Bars 3 Center Left",
+    "Bars 4",
+    "Bars Arrow Down",
+    "Bars Arrow Up",
+    "Battery 0",
+    "Battery 100",
+    "Battery 50",
+    "Beaker",
+    "Bell",
+    "Bell Alert",
+    "Bell Slash",
+    "Bell Snooze",
+    "Bold",
+    "Bolt",
+    "Bolt Slash",
+    "Book Open",
+    "Bookmark",
+    "Bookmark Slash",
+    "Bookmark Square",
+    "Briefcase",
+    "Bug Ant",
+    "Building Library",
+    "Building Office",
+    "Building Office 2",
+    "Building Storefront",
+    "Cake",
+    "Calculator",
+    "Calendar",
+    "Calendar Date Range",
+    "Calendar Days",
+    "Camera",
+    "Chart Bar",
+    "Chart Bar Square",
+    "Chart Pie",
+    "Chat Bubble Bottom Center",
+    "Chat Bubble Bottom Center Text",
+    "Chat Bubble Left",
+    "Chat Bubble Left Ellipsis",
+    "Chat Bubble Left Right",
+    "Chat Bubble Oval Left",
+    "Chat Bubble Oval Left Ellipsis",
+    "Check",
+    "Check Badge",
+    "Check Circle",
+    "Chevron Double Down",
+    "Chevron Double Left",
+    "Chevron Double Right",
+    "Chevron Double Up",
+    "Chevron Down",
+    "Chevron Left",
+    "Chevron Right",
+    "Chevron Up",
+    "Chevron Up Down",
+    "Circle Stack",
+    "Clipboard",
+    "Clipboard Document",
+    "Clipboard Document Check",
+    "Clipboard Document List",
+    "Clock",
+    "Cloud",
+    "Cloud Arrow Down",
+    "Cloud Arrow Up",
+    "Code Bracket",
+    "Code Bracket Square",
+    "Cog",
+    "Cog 6 Tooth",
+    "Cog 8 Tooth",
+    "Command Line",
+    "Computer Desktop",
+    "Cpu Chip",
+    "Credit Card",
+    "Cube",
+    "Cube Transparent",
+    "Currency Bangladeshi",
+    "Currency Dollar",
+    "Currency Euro",
+    "Currency Pound",
+    "Currency Rupee",
+    "Currency Yen",
+    "Cursor Arrow Rays",
+    "Cursor Arrow Ripple",
+    "Device Phone Mobile",
+    "Device Tablet",
+    "Divide",
+    "Document",
+    "Document Arrow Down",
+    "Document Arrow Up",
+    "Document Chart Bar",
+    "Document Check",
+    "Document Currency Bangladeshi",
+    "Document Currency Dollar",
+    "Document Currency Euro",
+    "Document Currency Pound",
+    "Document Currency Rupee",
+    "Document Currency Yen",
+    "Document Duplicate",
+    "Document Magnifying Glass",
+    "Document Minus",
+    "Document Plus",
+    "Document Text",
+    "Ellipsis Horizontal",
+    "Ellipsis Horizontal Circle",
+    "Ellipsis Vertical",
+    "Envelope",
+    "Envelope Open",
+    "Equals",
+    "Exclamation Circle",
+    "Exclamation Triangle",
+    "Eye",
+    "Eye Dropper",
+    "Eye Slash",
+    "Face Frown",
+    "Face Smile",
+    "Film",
+    "Finger Print",
+    "Fire",
+    "Flag",
+    "Folder",
+    "Folder Arrow Down",
+    "Folder Minus",
+    "Folder Open",
+    "Folder Plus",
+    "Forward",
+    "Funnel",
+    "Gif",
+    "Gift",
+    "Gift Top",
+    "Globe Alt",
+    "Globe Americas",
+    "Globe Asia Australia",
+    "Globe Europe Africa",
+    "H1",
+    "H2",
+    "H3",
+    "Hand Raised",
+    "Hand Thumb Down",
+    "Hand Thumb Up",
+    "Hashtag",
+    "Heart",
+    "Home",
+    "Home Modern",
+    "Identification",
+    "Inbox",
+    "Inbox Arrow Down",
+    "Inbox Stack",
+    "Information Circle",
+    "Italic",
+    "Key",
+    "Language",
+    "Lifebuoy",
+    "Light Bulb",
+    "Link",
+    "Link Slash",
+    "List Bullet",
+    "Lock Closed",
+    "Lock Open",
+    "Magnifying Glass",
+    "Magnifying Glass Circle",
+    "Magnifying Glass Minus",
+    "Magnifying Glass Plus",
+    "Map",
+    "Map Pin",
+    "Megaphone",
+    "Microphone",
+    "Minus",
+    "Minus Circle",
+    "Minus Small",
+    "Moon",
+    "Musical Note",
+    "Newspaper",
+    "No Symbol",
+    "Numbered List",
+    "Paint Brush",
+    "Paper Airplane",
+    "Paper Clip",
+    "Pause",
+    "Pause Circle",
+    "Pencil",
+    "Pencil Square",
+    "Percent Badge",
+    "Phone",
+    "Phone Arrow Down Left",
+    "Phone Arrow Up Right",
+    "Phone X Mark",
+    "Photo",
+    "Play",
+    "Play Circle",
+    "Play Pause",
+    "Plus",
+    "Plus Circle",
+    "Plus Small",
+    "Power",
+    "Presentation Chart Bar",
+    "Presentation Chart Line",
+    "Printer",
+    "Puzzle Piece",
+    "Qr Code",
+    "Question Mark Circle",
+    "Queue List",
+    "Radio",
+    "Receipt Percent",
+    "Receipt Refund",
+    "Rectangle Group",
+    "Rectangle Stack",
+    "Rocket Launch",
+    "Rss",
+    "Scale",
+    "Scissors",
+    "Server",
+    "Server Stack",
+    "Share",
+    "Shield Check",
+    "Shield Exclamation",
+    "Shopping Bag",
+    "Shopping Cart",
+    "Signal",
+    "Signal Slash",
+    "Slash",
+    "Sparkles",
+    "Speaker Wave",
+    "Speaker X Mark",
+    "Square 2 Stack",
+    "Square 3 Stack 3d",
+    "Squares 2x2",
+    "Squares Plus",
+    "Star",
+    "Stop",
+    "Stop Circle",
+    "Strikethrough",
+    "Sun",
+    "Swatch",
+    "Table Cells",
+    "Tag",
+    "Ticket",
+    "Trash",
+    "Trophy",
+    "Truck",
+    "Tv",
+    "Underline",
+    "User",
+    "User Circle",
+    "User Group",
+    "User Minus",
+    "User Plus",
+    "Users",
+    "Variable",
+    "Video Camera",
+    "Video Camera Slash",
+    "View Columns",
+    "Viewfinder Circle",
+    "Wallet",
+    "Wifi",
+    "Window",
+    "Wrench",
+    "Wrench Screwdriver",
+    "X Circle",
+    "X Mark",
+];
+
+/// Stable icon ids, sorted by id.
+pub(crate) const ICON_IDS: &[(u32, &str)] = &[
+    (0x01929972, "chat-bubble-left"),
+    (0x02127979, "arrow-turn-right-up"),
+    (0x025c2870, "clipboard-document-check"),
+    (0x036fc297, "building-office-2"),
+    (0x04fcb833, "rectangle-stack"),
+    (0x057aefdf, "arrow-down-on-square"),
+    (0x0582ab34, "arrow-up"),
+    (0x06257c2d, "hand-thumb-up"),
+    (0x0642b47e, "arrow-down-right"),
+    (0x0689854c, "plus-circle"),
+    (0x07286448, "wifi"),
+    (0x07c93e45, "exclamation-circle"),
+    (0x085194c3, "document-currency-pound"),
+    (0x08a6a70b, "wrench-screwdriver"),
+    (0x09f0bcf6, "scissors"),
+    (0x0b4675e2, "chevron-up"),
+    (0x0c20e67d, "lock-closed"),
+    (0x0cd2bba0, "document-currency-rupee"),
+    (0x0ddb0669, "link"),
+    (0x0eaa25e3, "heart"),
+    (0x100e0423, "arrow-up-circle"),
+    (0x108149d5, "face-smile"),
+    (0x10b1cc23, "map-pin"),
+    (0x1237f034, "pencil-square"),
+    (0x1305db8f, "viewfinder-circle"),
+    (0x136fe7c8, "ellipsis-horizontal-circle"),
+    (0x146960c5, "bookmark-slash"),
+    (0x1516e54e, "device-tablet"),
+    (0x15a40f5d, "clock"),
+    (0x15ef45ea, "currency-rupee"),
+    (0x1650eec8, "magnifying-glass-minus"),
+    (0x16894085, "arrow-right-end-on-rectangle"),
+    (0x17a37264, "presentation-chart-bar"),
+    (0x17f22a0b, "arrow-small-right"),
+    (0x19385305, "variable"),
+    (0x19c83218, "folder-minus"),
+    (0x19e727d3, "chevron-right"),
+    (0x1a632414, "archive-box-arrow-down"),
+    (0x1a994e90, "chevron-double-left"),
+    (0x1ac6a97e, "cloud"),
+    (0x1bd5a2ed, "chevron-up-down"),
+    (0x1c2d51d7, "queue-list"),
+    (0x1c30ccf0, "phone-arrow-down-left"),
+    (0x1c717434, "user-group"),
+    (0x1c8c8297, "user-minus"),
+    (0x1d0b478a, "arrow-small-up"),
+    (0x1d65ff40, "chevron-double-up"),
+    (0x1e2f1667, "table-cells"),
+    (0x1eb28c44, "strikethrough"),
+    (0x1ec0d800, "globe-americas"),
+    (0x20093d88, "newspaper"),
+    (0x213f275a, "paint-brush"),
+    (0x233c1c7d, "arrow-right-on-rectangle"),
+    (0x24687632, "sparkles"),
+    (0x2475ce12, "document-currency-euro"),
+    (0x24921453, "document-arrow-down"),
+    (0x24a79715, "link-slash"),
+    (0x24da59d0, "clipboard-document-list"),
+    (0x25fec055, "square-3-stack-3d"),
+    (0x26d767ff, "printer"),
+    (0x271b753d, "chat-bubble-left-right"),
+    (0x278928c1, "arrow-down"),
+    (0x2a09e0fe, "archive-box-x-mark"),
+    (0x2ae2bc27, "arrow-turn-left-down"),
+    (0x2c43664c, "cog-6-tooth"),
+    (0x2cbb6635, "chevron-double-down"),
+    (0x2d398b46, "arrow-turn-down-right"),
+    (0x2fdc6133, "rss"),
+    (0x31af715b, "currency-dollar"),
+    (0x323fe0f6, "shopping-bag"),
+    (0x32c8e2ed, "user-plus"),
+    (0x340a1ad8, "folder-arrow-down"),
+    (0x34ccd1eb, "arrow-small-down"),
+    (0x357764a8, "cursor-arrow-ripple"),
+    (0x367b2996, "arrow-left-end-on-rectangle"),
+    (0x38aa1006, "lifebuoy"),
+    (0x3940b7f7, "at-symbol"),
+    (0x395dfebe, "bars-arrow-down"),
+    (0x3b890e1c, "battery-50"),
+    (0x3bbde2ff, "trophy"),
+    (0x3c5e234c, "hand-thumb-down"),
+    (0x3ca16331, "ellipsis-horizontal"),
+    (0x3e6f9f35, "signal-slash"),
+    (0x3e74a2b0, "phone-x-mark"),
+    (0x3eb17dd8, "folder-open"),
+    (0x3ef98189, "exclamation-triangle"),
+    (0x3f0b27e0, "bars-2"),
+    (0x400b2973, "bars-3"),
+    (0x408d3f63, "bell-alert"),
+    (0x40ac3dd2, "server"),
+    (0x410acc5f, "document-plus"),
+    (0x444a8a8d, "magnifying-glass"),
+    (0x44ee3fc1, "document-currency-yen"),
+    (0x450b3152, "bars-4"),
+    (0x451acd5b, "currency-yen"),
+    (0x46366cfc, "list-bullet"),
+    (0x4722e191, "bug-ant"),
+    (0x472cc697, "arrow-uturn-up"),
+    (0x487af637, "arrow-up-tray"),
+    (0x48e5b40c, "information-circle"),
+    (0x4b45564f, "tv"),
+    (0x4eafdc57, "cursor-arrow-rays"),
+    (0x50cc08c3, "briefcase"),
+    (0x513c8d94, "equals"),
+    (0x51fb8e19, "bookmark"),
+    (0x5325949e, "shopping-cart"),
+    (0x54b7c777, "code-bracket-square"),
+    (0x54b8b7ca, "arrows-right-left"),
+    (0x55b47a57, "arrow-turn-up-right"),
+    (0x562b1149, "microphone"),
+    (0x576baa03, "chart-bar"),
+    (0x580caca7, "hashtag"),
+    (0x58473848, "cloud-arrow-up"),
+    (0x58cda68d, "document-currency-bangladeshi"),
+    (0x58e1198c, "document-text"),
+    (0x5adee78d, "arrow-turn-down-left"),
+    (0x5baa2cd9, "arrows-pointing-out"),
+    (0x5e644e05, "trash"),
+    (0x5e7cc513, "users"),
+    (0x60785ef2, "user"),
+    (0x60e9fb6d, "italic"),
+    (0x61526270, "divide"),
+    (0x61efe1f4, "video-camera"),
+    (0x6264021a, "squares-2x2"),
+    (0x648c2a98, "arrow-down-circle"),
+    (0x6517b3e5, "ticket"),
+    (0x651d1b56, "arrow-small-left"),
+    (0x662ad8c1, "gif"),
+    (0x67514817, "arrow-uturn-left"),
+    (0x675d2bc4, "backward"),
+    (0x6815c86c, "key"),
+    (0x688023ab, "arrow-left-circle"),
+    (0x69dfb66e, "minus-circle"),
+    (0x6b91d120, "cube"),
+    (0x6c474185, "chevron-double-right"),
+    (0x6e81d77b, "receipt-percent"),
+    (0x6f148035, "clipboard-document"),
+    (0x6f24aada, "arrows-up-down"),
+    (0x70849133, "book-open"),
+    (0x7084d38d, "pause"),
+    (0x714bd214, "circle-stack"),
+    (0x723569b2, "inbox-stack"),
+    (0x728cc1a9, "arrow-top-right-on-square"),
+    (0x74d73939, "chart-bar-square"),
+    (0x74e1b387, "speaker-x-mark"),
+    (0x7583e80a, "arrow-path"),
+    (0x762ddce8, "percent-badge"),
+    (0x773611af, "phone"),
+    (0x7856791a, "eye-slash"),
+    (0x78627a04, "pencil"),
+    (0x7995e85e, "arrow-long-down"),
+    (0x79c968f4, "qr-code"),
+    (0x7a87e80e, "inbox-arrow-down"),
+    (0x7a9e9cf7, "arrow-trending-down"),
+    (0x7b210ccc, "speaker-wave"),
+    (0x7b4642db, "document-magnifying-glass"),
+    (0x7bf68149, "shield-check"),
+    (0x7c505a26, "cpu-chip"),
+    (0x7d362c82, "play-circle"),
+    (0x7dac8d46, "bars-3-bottom-right"),
+    (0x7e6b4974, "building-storefront"),
+    (0x7f20070f, "x-mark"),
+    (0x812716e3, "photo"),
+    (0x826e1638, "bell"),
+    (0x82971c71, "scale"),
+    (0x8340e2a3, "chevron-down"),
+    (0x83dcb20c, "numbered-list"),
+    (0x83e0c7c8, "chart-pie"),
+    (0x8462a926, "arrow-right-start-on-rectangle"),
+    (0x846eb18c, "document-duplicate"),
+    (0x84d9da3d, "bell-snooze"),
+    (0x84eebbd4, "arrow-down-tray"),
+    (0x853c682c, "backspace"),
+    (0x86f2be04, "pause-circle"),
+    (0x872d24fe, "chevron-left"),
+    (0x893f328b, "rocket-launch"),
+    (0x8a7324ef, "gift"),
+    (0x8a7eebab, "bars-arrow-up"),
+    (0x8aa6541a, "paper-airplane"),
+    (0x8aea8859, "cake"),
+    (0x8b2add5e, "chat-bubble-bottom-center"),
+    (0x8b76f393, "arrow-long-left"),
+    (0x8b77b511, "user-circle"),
+    (0x8ba8ed1a, "eye"),
+    (0x8c082940, "bell-slash"),
+    (0x8d051fc5, "check-badge"),
+    (0x8d92617a, "squares-plus"),
+    (0x8e3b326c, "h3"),
+    (0x8eab16d9, "fire"),
+    (0x8f2fbdac, "wallet"),
+    (0x8f3b33ff, "h2"),
+    (0x8f4cd840, "globe-asia-australia"),
+    (0x8f67c77d, "cloud-arrow-down"),
+    (0x903b3592, "h1"),
+    (0x90b6e08c, "phone-arrow-up-right"),
+    (0x90d46842, "arrow-up-left"),
+    (0x918db010, "folder-plus"),
+    (0x928fc9ff, "command-line"),
+    (0x92ed48fa, "puzzle-piece"),
+    (0x95e9d387, "device-phone-mobile"),
+    (0x95f72993, "tag"),
+    (0x96234bd4, "document"),
+    (0x970e9591, "gift-top"),
+    (0x97be7fff, "plus-small"),
+    (0x98a6adaf, "arrow-up-right"),
+    (0x99ee13e1, "presentation-chart-line"),
+    (0x9a171e67, "chat-bubble-oval-left"),
+    (0x9a6af4a8, "building-office"),
+    (0x9b54391c, "arrow-long-right"),
+    (0x9b7030f9, "funnel"),
+    (0x9bedfb80, "magnifying-glass-plus"),
+    (0x9d0b757b, "bookmark-square"),
+    (0x9d1e8111, "clipboard"),
+    (0x9ebe984e, "camera"),
+    (0xa172b7dd, "window"),
+    (0xa1ce6d04, "square-2-stack"),
+    (0xa221ae41, "chat-bubble-oval-left-ellipsis"),
+    (0xa25810f4, "wrench"),
+    (0xa2dad840, "receipt-refund"),
+    (0xa4810d1f, "calendar"),
+    (0xa6bc665c, "banknotes"),
+    (0xa8389ab8, "battery-100"),
+    (0xa8863433, "ellipsis-vertical"),
+    (0xa93a790a, "arrow-turn-up-left"),
+    (0xa9c9fc38, "share"),
+    (0xaa349df4, "adjustments-vertical"),
+    (0xaa922e07, "arrow-up-on-square-stack"),
+    (0xab0a190f, "hand-raised"),
+    (0xad734dc1, "no-symbol"),
+    (0xadc114f5, "arrow-left-start-on-rectangle"),
+    (0xae401eb4, "radio"),
+    (0xaef4596a, "chat-bubble-bottom-center-text"),
+    (0xaf246ede, "arrow-turn-left-up"),
+    (0xaf5b8b4d, "document-check"),
+    (0xb131afba, "arrow-left-on-rectangle"),
+    (0xb22c7e5b, "identification"),
+    (0xb274e792, "arrow-up-on-square"),
+    (0xb299e105, "minus-small"),
+    (0xb2ea63db, "film"),
+    (0xb3711c47, "check"),
+    (0xb3b6ba23, "currency-bangladeshi"),
+    (0xb45e4f7c, "video-camera-slash"),
+    (0xb45fa81a, "forward"),
+    (0xb8285b05, "computer-desktop"),
+    (0xb885a909, "document-currency-dollar"),
+    (0xb95f896c, "currency-euro"),
+    (0xb9ef387b, "language"),
+    (0xba88c198, "shield-exclamation"),
+    (0xbc4b1fa8, "musical-note"),
+    (0xbd648813, "eye-dropper"),
+    (0xbd7bba09, "signal"),
+    (0xbdeaa8d7, "flag"),
+    (0xbeb43ee1, "document-minus"),
+    (0xbf2229be, "cog-8-tooth"),
+    (0xc1d3917d, "document-chart-bar"),
+    (0xc2200fe3, "cube-transparent"),
+    (0xc2cbd863, "play"),
+    (0xc2d63076, "bolt-slash"),
+    (0xc4a4ffa6, "arrow-down-on-square-stack"),
+    (0xc4adc675, "plus"),
+    (0xc55adf4d, "calculator"),
+    (0xc6c088f5, "light-bulb"),
+    (0xc6faa803, "building-library"),
+    (0xc7298f0e, "arrow-trending-up"),
+    (0xc98a7fe1, "megaphone"),
+    (0xca2993e5, "code-bracket"),
+    (0xcb532ae5, "stop"),
+    (0xcb73e8ea, "slash"),
+    (0xcc65a8aa, "calendar-date-range"),
+    (0xccf4542b, "academic-cap"),
+    (0xcd24e630, "question-mark-circle"),
+    (0xce96dd46, "bolt"),
+    (0xd0e28766, "check-circle"),
+    (0xd13b2f71, "star"),
+    (0xd27bbdff, "beaker"),
+    (0xd2c8c28e, "home"),
+    (0xd5091d8d, "bars-3-bottom-left"),
+    (0xd5990010, "rectangle-group"),
+    (0xd5caf60b, "battery-0"),
+    (0xd5d89d00, "arrow-right-circle"),
+    (0xd63cd1c7, "server-stack"),
+    (0xd66bb2fc, "play-pause"),
+    (0xd76f1115, "calendar-days"),
+    (0xd7d848a9, "arrow-right"),
+    (0xd86294c9, "currency-pound"),
+    (0xd8808c06, "home-modern"),
+    (0xd96eb5dd, "folder"),
+    (0xdc870131, "archive-box"),
+    (0xde3db16c, "arrows-pointing-in"),
+    (0xde96f676, "bold"),
+    (0xdec5dbe1, "lock-open"),
+    (0xdf2652ba, "paper-clip"),
+    (0xdfa2efb1, "map"),
+    (0xdfcb2d32, "arrow-uturn-down"),
+    (0xe04e3f61, "sun"),
+    (0xe0d69fb0, "envelope-open"),
+    (0xe252d53b, "inbox"),
+    (0xe35ce2f0, "globe-europe-africa"),
+    (0xe36f5f94, "globe-alt"),
+    (0xe536575c, "arrow-left"),
+    (0xe582347f, "underline"),
+    (0xe78dc320, "cog"),
+    (0xe7b3b2c0, "arrow-turn-right-down"),
+    (0xe8b272aa, "view-columns"),
+    (0xe9094169, "swatch"),
+    (0xeb311a26, "x-circle"),
+    (0xed9fa088, "arrow-uturn-right"),
+    (0xf07f1a62, "finger-print"),
+    (0xf184ae40, "moon"),
+    (0xf51ce752, "adjustments-horizontal"),
+    (0xf520324b, "arrow-long-up"),
+    (0xf542bdc8, "truck"),
+    (0xf54f2346, "power"),
+    (0xf757c8f2, "document-arrow-up"),
+    (0xf7fa3daf, "minus"),
+    (0xf8dbf57d, "credit-card"),
+    (0xf96beaa5, "arrow-down-left"),
+    (0xf9cea359, "face-frown"),
+    (0xfa10a755, "envelope"),
+    (0xfa912b04, "magnifying-glass-circle"),
+    (0xfad41d7c, "stop-circle"),
+    (0xfbcb0d63, "bars-3-center-left"),
+    (0xfce6c82a, "chat-bubble-left-ellipsis"),
+    (0xfd6746d6, "arrow-path-rounded-square"),
+];
+
+pub(crate) const ICON_RTL_MIRROR: &[&str] = &[
+    "arrow-down-left",
+    "arrow-down-right",
+    "arrow-left",
+    "arrow-left-circle",
+    "arrow-left-end-on-rectangle",
+    "arrow-left-on-rectangle",
+    "arrow-left-start-on-rectangle",
+    "arrow-long-left",
+    "arrow-long-right",
+    "arrow-right",
+    "arrow-right-circle",
+    "arrow-right-end-on-rectangle",
+    "arrow-right-on-rectangle",
+    "arrow-right-start-on-rectangle",
+    "arrow-small-left",
+    "arrow-small-right",
+    "arrow-top-right-on-square",
+    "arrow-turn-down-left",
+    "arrow-turn-down-right",
+    "arrow-turn-left-down",
+    "arrow-turn-left-up",
+    "arrow-turn-right-down",
+    "arrow-turn-right-up",
+    "arrow-turn-up-left",
+    "arrow-turn-up-right",
+    "arrow-up-left",
+    "arrow-up-right",
+    "arrow-uturn-left",
+    "arrow-uturn-right",
+    "arrows-right-left",
+    "chevron-double-left",
+    "chevron-double-right",
+    "chevron-left",
+    "chevron-right",
+    "phone-arrow-down-left",
+    "phone-arrow-up-right",
+];
+
 pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantInfo> {
     VARIANTS
         .iter()
@@ -17270,21 +31420,717 @@ pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantI
 }
 
 pub(crate) fn icon_codepoint(name: &str, key: VariantKey) -> Option<u32> {
-    ICON_CODEPOINTS
+    let codepoints: &[(VariantKey, u32)] = match name {
+        "academic-cap" => ICON_ACADEMIC_CAP_CODEPOINTS,
+        "adjustments-horizontal" => ICON_ADJUSTMENTS_HORIZONTAL_CODEPOINTS,
+        "adjustments-vertical" => ICON_ADJUSTMENTS_VERTICAL_CODEPOINTS,
+        "archive-box" => ICON_ARCHIVE_BOX_CODEPOINTS,
+        "archive-box-arrow-down" => ICON_ARCHIVE_BOX_ARROW_DOWN_CODEPOINTS,
+        "archive-box-x-mark" => ICON_ARCHIVE_BOX_X_MARK_CODEPOINTS,
+        "arrow-down" => ICON_ARROW_DOWN_CODEPOINTS,
+        "arrow-down-circle" => ICON_ARROW_DOWN_CIRCLE_CODEPOINTS,
+        "arrow-down-left" => ICON_ARROW_DOWN_LEFT_CODEPOINTS,
+        "arrow-down-on-square" => ICON_ARROW_DOWN_ON_SQUARE_CODEPOINTS,
+        "arrow-down-on-square-stack" => ICON_ARROW_DOWN_ON_SQUARE_STACK_CODEPOINTS,
+        "arrow-down-right" => ICON_ARROW_DOWN_RIGHT_CODEPOINTS,
+        "arrow-down-tray" => ICON_ARROW_DOWN_TRAY_CODEPOINTS,
+        "arrow-left" => ICON_ARROW_LEFT_CODEPOINTS,
+        "arrow-left-circle" => ICON_ARROW_LEFT_CIRCLE_CODEPOINTS,
+        "arrow-left-end-on-rectangle" => ICON_ARROW_LEFT_END_ON_RECTANGLE_CODEPOINTS,
+        "arrow-left-on-rectangle" => ICON_ARROW_LEFT_ON_RECTANGLE_CODEPOINTS,
+        "arrow-left-start-on-rectangle" => ICON_ARROW_LEFT_START_ON_RECTANGLE_CODEPOINTS,
+        "arrow-long-down" => ICON_ARROW_LONG_DOWN_CODEPOINTS,
+        "arrow-long-left" => ICON_ARROW_LONG_LEFT_CODEPOINTS,
+        "arrow-long-right" => ICON_ARROW_LONG_RIGHT_CODEPOINTS,
+        "arrow-long-up" => ICON_ARROW_LONG_UP_CODEPOINTS,
+        "arrow-path" => ICON_ARROW_PATH_CODEPOINTS,
+        "arrow-path-rounded-square" => ICON_ARROW_PATH_ROUNDED_SQUARE_CODEPOINTS,
+        "arrow-right" => ICON_ARROW_RIGHT_CODEPOINTS,
+        "arrow-right-circle" => ICON_ARROW_RIGHT_CIRCLE_CODEPOINTS,
+        "arrow-right-end-on-rectangle" => ICON_ARROW_RIGHT_END_ON_RECTANGLE_CODEPOINTS,
+        "arrow-right-on-rectangle" => ICON_ARROW_RIGHT_ON_RECTANGLE_CODEPOINTS,
+        "arrow-right-start-on-rectangle" => ICON_ARROW_RIGHT_START_ON_RECTANGLE_CODEPOINTS,
+        "arrow-small-down" => ICON_ARROW_SMALL_DOWN_CODEPOINTS,
+        "arrow-small-left" => ICON_ARROW_SMALL_LEFT_CODEPOINTS,
+        "arrow-small-right" => ICON_ARROW_SMALL_RIGHT_CODEPOINTS,
+        "arrow-small-up" => ICON_ARROW_SMALL_UP_CODEPOINTS,
+        "arrow-top-right-on-square" => ICON_ARROW_TOP_RIGHT_ON_SQUARE_CODEPOINTS,
+        "arrow-trending-down" => ICON_ARROW_TRENDING_DOWN_CODEPOINTS,
+        "arrow-trending-up" => ICON_ARROW_TRENDING_UP_CODEPOINTS,
+        "arrow-turn-down-left" => ICON_ARROW_TURN_DOWN_LEFT_CODEPOINTS,
+        "arrow-turn-down-right" => ICON_ARROW_TURN_DOWN_RIGHT_CODEPOINTS,
+        "arrow-turn-left-down" => ICON_ARROW_TURN_LEFT_DOWN_CODEPOINTS,
+        "arrow-turn-left-up" => ICON_ARROW_TURN_LEFT_UP_CODEPOINTS,
+        "arrow-turn-right-down" => ICON_ARROW_TURN_RIGHT_DOWN_CODEPOINTS,
+        "arrow-turn-right-up" => ICON_ARROW_TURN_RIGHT_UP_CODEPOINTS,
+        "arrow-turn-up-left" => ICON_ARROW_TURN_UP_LEFT_CODEPOINTS,
+        "arrow-turn-up-right" => ICON_ARROW_TURN_UP_RIGHT_CODEPOINTS,
+        "arrow-up" => ICON_ARROW_UP_CODEPOINTS,
+        "arrow-up-circle" => ICON_ARROW_UP_CIRCLE_CODEPOINTS,
+        "arrow-up-left" => ICON_ARROW_UP_LEFT_CODEPOINTS,
+        "arrow-up-on-square" => ICON_ARROW_UP_ON_SQUARE_CODEPOINTS,
+        "arrow-up-on-square-stack" => ICON_ARROW_UP_ON_SQUARE_STACK_CODEPOINTS,
+        "arrow-up-right" => ICON_ARROW_UP_RIGHT_CODEPOINTS,
+        "arrow-up-tray" => ICON_ARROW_UP_TRAY_CODEPOINTS,
+        "arrow-uturn-down" => ICON_ARROW_UTURN_DOWN_CODEPOINTS,
+        "arrow-uturn-left" => ICON_ARROW_UTURN_LEFT_CODEPOINTS,
+        "arrow-uturn-right" => ICON_ARROW_UTURN_RIGHT_CODEPOINTS,
+        "arrow-uturn-up" => ICON_ARROW_UTURN_UP_CODEPOINTS,
+        "arrows-pointing-in" => ICON_ARROWS_POINTING_IN_CODEPOINTS,
+        "arrows-pointing-out" => ICON_ARROWS_POINTING_OUT_CODEPOINTS,
+        "arrows-right-left" => ICON_ARROWS_RIGHT_LEFT_CODEPOINTS,
+        "arrows-up-down" => ICON_ARROWS_UP_DOWN_CODEPOINTS,
+        "at-symbol" => ICON_AT_SYMBOL_CODEPOINTS,
+        "backspace" => ICON_BACKSPACE_CODEPOINTS,
+        "backward" => ICON_BACKWARD_CODEPOINTS,
+        "banknotes" => ICON_BANKNOTES_CODEPOINTS,
+        "bars-2" => ICON_BARS_2_CODEPOINTS,
+        "bars-3" => ICON_BARS_3_CODEPOINTS,
+        "bars-3-bottom-left" => ICON_BARS_3_BOTTOM_LEFT_CODEPOINTS,
+        "bars-3-bottom-right" => ICON_BARS_3_BOTTOM_RIGHT_CODEPOINTS,
+        "bars-3-center-left" => ICON_BARS_3_CENTER_LEFT_CODEPOINTS,
+        "bars-4" => ICON_BARS_4_CODEPOINTS,
+        "bars-arrow-down" => ICON_BARS_ARROW_DOWN_CODEPOINTS,
+        "bars-arrow-up" => ICON_BARS_ARROW_UP_CODEPOINTS,
+        "battery-0" => ICON_BATTERY_0_CODEPOINTS,
+        "battery-100" => ICON_BATTERY_100_CODEPOINTS,
+        "battery-50" => ICON_BATTERY_50_CODEPOINTS,
+        "beaker" => ICON_BEAKER_CODEPOINTS,
+        "bell" => ICON_BELL_CODEPOINTS,
+        "bell-alert" => ICON_BELL_ALERT_CODEPOINTS,
+        "bell-slash" => ICON_BELL_SLASH_CODEPOINTS,
+        "bell-snooze" => ICON_BELL_SNOOZE_CODEPOINTS,
+        "bold" => ICON_BOLD_CODEPOINTS,
+        "bolt" => ICON_BOLT_CODEPOINTS,
+        "bolt-slash" => ICON_BOLT_SLASH_CODEPOINTS,
+        "book-open" => ICON_BOOK_OPEN_CODEPOINTS,
+        "bookmark" => ICON_BOOKMARK_CODEPOINTS,
+        "bookmark-slash" => ICON_BOOKMARK_SLASH_CODEPOINTS,
+        "bookmark-square" => ICON_BOOKMARK_SQUARE_CODEPOINTS,
+        "briefcase" => ICON_BRIEFCASE_CODEPOINTS,
+        "bug-ant" => ICON_BUG_ANT_CODEPOINTS,
+        "building-library" => ICON_BUILDING_LIBRARY_CODEPOINTS,
+        "building-office" => ICON_BUILDING_OFFICE_CODEPOINTS,
+        "building-office-2" => ICON_BUILDING_OFFICE_2_CODEPOINTS,
+        "building-storefront" => ICON_BUILDING_STOREFRONT_CODEPOINTS,
+        "cake" => ICON_CAKE_CODEPOINTS,
+        "calculator" => ICON_CALCULATOR_CODEPOINTS,
+        "calendar" => ICON_CALENDAR_CODEPOINTS,
+        "calendar-date-range" => ICON_CALENDAR_DATE_RANGE_CODEPOINTS,
+        "calendar-days" => ICON_CALENDAR_DAYS_CODEPOINTS,
+        "camera" => ICON_CAMERA_CODEPOINTS,
+        "chart-bar" => ICON_CHART_BAR_CODEPOINTS,
+        "chart-bar-square" => ICON_CHART_BAR_SQUARE_CODEPOINTS,
+        "chart-pie" => ICON_CHART_PIE_CODEPOINTS,
+        "chat-bubble-bottom-center" => ICON_CHAT_BUBBLE_BOTTOM_CENTER_CODEPOINTS,
+        "chat-bubble-bottom-center-text" => ICON_CHAT_BUBBLE_BOTTOM_CENTER_TEXT_CODEPOINTS,
+        "chat-bubble-left" => ICON_CHAT_BUBBLE_LEFT_CODEPOINTS,
+        "chat-bubble-left-ellipsis" => ICON_CHAT_BUBBLE_LEFT_ELLIPSIS_CODEPOINTS,
+        "chat-bubble-left-right" => ICON_CHAT_BUBBLE_LEFT_RIGHT_CODEPOINTS,
+        "chat-bubble-oval-left" => ICON_CHAT_BUBBLE_OVAL_LEFT_CODEPOINTS,
+        "chat-bubble-oval-left-ellipsis" => ICON_CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS_CODEPOINTS,
+        "check" => ICON_CHECK_CODEPOINTS,
+        "check-badge" => ICON_CHECK_BADGE_CODEPOINTS,
+        "check-circle" => ICON_CHECK_CIRCLE_CODEPOINTS,
+        "chevron-double-down" => ICON_CHEVRON_DOUBLE_DOWN_CODEPOINTS,
+        "chevron-double-left" => ICON_CHEVRON_DOUBLE_LEFT_CODEPOINTS,
+        "chevron-double-right" => ICON_CHEVRON_DOUBLE_RIGHT_CODEPOINTS,
+        "chevron-double-up" => ICON_CHEVRON_DOUBLE_UP_CODEPOINTS,
+        "chevron-down" => ICON_CHEVRON_DOWN_CODEPOINTS,
+        "chevron-left" => ICON_CHEVRON_LEFT_CODEPOINTS,
+        "chevron-right" => ICON_CHEVRON_RIGHT_CODEPOINTS,
+        "chevron-up" => ICON_CHEVRON_UP_CODEPOINTS,
+        "chevron-up-down" => ICON_CHEVRON_UP_DOWN_CODEPOINTS,
+        "circle-stack" => ICON_CIRCLE_STACK_CODEPOINTS,
+        "clipboard" => ICON_CLIPBOARD_CODEPOINTS,
+        "clipboard-document" => ICON_CLIPBOARD_DOCUMENT_CODEPOINTS,
+        "clipboard-document-check" => ICON_CLIPBOARD_DOCUMENT_CHECK_CODEPOINTS,
+        "clipboard-document-list" => ICON_CLIPBOARD_DOCUMENT_LIST_CODEPOINTS,
+        "clock" => ICON_CLOCK_CODEPOINTS,
+        "cloud" => ICON_CLOUD_CODEPOINTS,
+        "cloud-arrow-down" => ICON_CLOUD_ARROW_DOWN_CODEPOINTS,
+        "cloud-arrow-up" => ICON_CLOUD_ARROW_UP_CODEPOINTS,
+        "code-bracket" => ICON_CODE_BRACKET_CODEPOINTS,
+        "code-bracket-square" => ICON_CODE_BRACKET_SQUARE_CODEPOINTS,
+        "cog" => ICON_COG_CODEPOINTS,
+        "cog-6-tooth" => ICON_COG_6_TOOTH_CODEPOINTS,
+        "cog-8-tooth" => ICON_COG_8_TOOTH_CODEPOINTS,
+        "command-line" => ICON_COMMAND_LINE_CODEPOINTS,
+        "computer-desktop" => ICON_COMPUTER_DESKTOP_CODEPOINTS,
+        "cpu-chip" => ICON_CPU_CHIP_CODEPOINTS,
+        "credit-card" => ICON_CREDIT_CARD_CODEPOINTS,
+        "cube" => ICON_CUBE_CODEPOINTS,
+        "cube-transparent" => ICON_CUBE_TRANSPARENT_CODEPOINTS,
+        "currency-bangladeshi" => ICON_CURRENCY_BANGLADESHI_CODEPOINTS,
+        "currency-dollar" => ICON_CURRENCY_DOLLAR_CODEPOINTS,
+        "currency-euro" => ICON_CURRENCY_EURO_CODEPOINTS,
+        "currency-pound" => ICON_CURRENCY_POUND_CODEPOINTS,
+        "currency-rupee" => ICON_CURRENCY_RUPEE_CODEPOINTS,
+        "currency-yen" => ICON_CURRENCY_YEN_CODEPOINTS,
+        "cursor-arrow-rays" => ICON_CURSOR_ARROW_RAYS_CODEPOINTS,
+        "cursor-arrow-ripple" => ICON_CURSOR_ARROW_RIPPLE_CODEPOINTS,
+        "device-phone-mobile" => ICON_DEVICE_PHONE_MOBILE_CODEPOINTS,
+        "device-tablet" => ICON_DEVICE_TABLET_CODEPOINTS,
+        "divide" => ICON_DIVIDE_CODEPOINTS,
+        "document" => ICON_DOCUMENT_CODEPOINTS,
+        "document-arrow-down" => ICON_DOCUMENT_ARROW_DOWN_CODEPOINTS,
+        "document-arrow-up" => ICON_DOCUMENT_ARROW_UP_CODEPOINTS,
+        "document-chart-bar" => ICON_DOCUMENT_CHART_BAR_CODEPOINTS,
+        "document-check" => ICON_DOCUMENT_CHECK_CODEPOINTS,
+        "document-currency-bangladeshi" => ICON_DOCUMENT_CURRENCY_BANGLADESHI_CODEPOINTS,
+        "document-currency-dollar" => ICON_DOCUMENT_CURRENCY_DOLLAR_CODEPOINTS,
+        "document-currency-euro" => ICON_DOCUMENT_CURRENCY_EURO_CODEPOINTS,
+        "document-currency-pound" => ICON_DOCUMENT_CURRENCY_POUND_CODEPOINTS,
+        "document-currency-rupee" => ICON_DOCUMENT_CURRENCY_RUPEE_CODEPOINTS,
+        "document-currency-yen" => ICON_DOCUMENT_CURRENCY_YEN_CODEPOINTS,
+        "document-duplicate" => ICON_DOCUMENT_DUPLICATE_CODEPOINTS,
+        "document-magnifying-glass" => ICON_DOCUMENT_MAGNIFYING_GLASS_CODEPOINTS,
+        "document-minus" => ICON_DOCUMENT_MINUS_CODEPOINTS,
+        "document-plus" => ICON_DOCUMENT_PLUS_CODEPOINTS,
+        "document-text" => ICON_DOCUMENT_TEXT_CODEPOINTS,
+        "ellipsis-horizontal" => ICON_ELLIPSIS_HORIZONTAL_CODEPOINTS,
+        "ellipsis-horizontal-circle" => ICON_ELLIPSIS_HORIZONTAL_CIRCLE_CODEPOINTS,
+        "ellipsis-vertical" => ICON_ELLIPSIS_VERTICAL_CODEPOINTS,
+        "envelope" => ICON_ENVELOPE_CODEPOINTS,
+        "envelope-open" => ICON_ENVELOPE_OPEN_CODEPOINTS,
+        "equals" => ICON_EQUALS_CODEPOINTS,
+        "exclamation-circle" => ICON_EXCLAMATION_CIRCLE_CODEPOINTS,
+        "exclamation-triangle" => ICON_EXCLAMATION_TRIANGLE_CODEPOINTS,
+        "eye" => ICON_EYE_CODEPOINTS,
+        "eye-dropper" => ICON_EYE_DROPPER_CODEPOINTS,
+        "eye-slash" => ICON_EYE_SLASH_CODEPOINTS,
+        "face-frown" => ICON_FACE_FROWN_CODEPOINTS,
+        "face-smile" => ICON_FACE_SMILE_CODEPOINTS,
+        "film" => ICON_FILM_CODEPOINTS,
+        "finger-print" => ICON_FINGER_PRINT_CODEPOINTS,
+        "fire" => ICON_FIRE_CODEPOINTS,
+        "flag" => ICON_FLAG_CODEPOINTS,
+        "folder" => ICON_FOLDER_CODEPOINTS,
+        "folder-arrow-down" => ICON_FOLDER_ARROW_DOWN_CODEPOINTS,
+        "folder-minus" => ICON_FOLDER_MINUS_CODEPOINTS,
+        "folder-open" => ICON_FOLDER_OPEN_CODEPOINTS,
+        "folder-plus" => ICON_FOLDER_PLUS_CODEPOINTS,
+        "forward" => ICON_FORWARD_CODEPOINTS,
+        "funnel" => ICON_FUNNEL_CODEPOINTS,
+        "gif" => ICON_GIF_CODEPOINTS,
+        "gift" => ICON_GIFT_CODEPOINTS,
+        "gift-top" => ICON_GIFT_TOP_CODEPOINTS,
+        "globe-alt" => ICON_GLOBE_ALT_CODEPOINTS,
+        "globe-americas" => ICON_GLOBE_AMERICAS_CODEPOINTS,
+        "globe-asia-australia" => ICON_GLOBE_ASIA_AUSTRALIA_CODEPOINTS,
+        "globe-europe-africa" => ICON_GLOBE_EUROPE_AFRICA_CODEPOINTS,
+        "h1" => ICON_H_1_CODEPOINTS,
+        "h2" => ICON_H_2_CODEPOINTS,
+        "h3" => ICON_H_3_CODEPOINTS,
+        "hand-raised" => ICON_HAND_RAISED_CODEPOINTS,
+        "hand-thumb-down" => ICON_HAND_THUMB_DOWN_CODEPOINTS,
+        "hand-thumb-up" => ICON_HAND_THUMB_UP_CODEPOINTS,
+        "hashtag" => ICON_HASHTAG_CODEPOINTS,
+        "heart" => ICON_HEART_CODEPOINTS,
+        "home" => ICON_HOME_CODEPOINTS,
+        "home-modern" => ICON_HOME_MODERN_CODEPOINTS,
+        "identification" => ICON_IDENTIFICATION_CODEPOINTS,
+        "inbox" => ICON_INBOX_CODEPOINTS,
+        "inbox-arrow-down" => ICON_INBOX_ARROW_DOWN_CODEPOINTS,
+        "inbox-stack" => ICON_INBOX_STACK_CODEPOINTS,
+        "information-circle" => ICON_INFORMATION_CIRCLE_CODEPOINTS,
+        "italic" => ICON_ITALIC_CODEPOINTS,
+        "key" => ICON_KEY_CODEPOINTS,
+        "language" => ICON_LANGUAGE_CODEPOINTS,
+        "lifebuoy" => ICON_LIFEBUOY_CODEPOINTS,
+        "light-bulb" => ICON_LIGHT_BULB_CODEPOINTS,
+        "link" => ICON_LINK_CODEPOINTS,
+        "link-slash" => ICON_LINK_SLASH_CODEPOINTS,
+        "list-bullet" => ICON_LIST_BULLET_CODEPOINTS,
+        "lock-closed" => ICON_LOCK_CLOSED_CODEPOINTS,
+        "lock-open" => ICON_LOCK_OPEN_CODEPOINTS,
+        "magnifying-glass" => ICON_MAGNIFYING_GLASS_CODEPOINTS,
+        "magnifying-glass-circle" => ICON_MAGNIFYING_GLASS_CIRCLE_CODEPOINTS,
+        "magnifying-glass-minus" => ICON_MAGNIFYING_GLASS_MINUS_CODEPOINTS,
+        "magnifying-glass-plus" => ICON_MAGNIFYING_GLASS_PLUS_CODEPOINTS,
+        "map" => ICON_MAP_CODEPOINTS,
+        "map-pin" => ICON_MAP_PIN_CODEPOINTS,
+        "megaphone" => ICON_MEGAPHONE_CODEPOINTS,
+        "microphone" => ICON_MICROPHONE_CODEPOINTS,
+        "minus" => ICON_MINUS_CODEPOINTS,
+        "minus-circle" => ICON_MINUS_CIRCLE_CODEPOINTS,
+        "minus-small" => ICON_MINUS_SMALL_CODEPOINTS,
+        "moon" => ICON_MOON_CODEPOINTS,
+        "musical-note" => ICON_MUSICAL_NOTE_CODEPOINTS,
+        "newspaper" => ICON_NEWSPAPER_CODEPOINTS,
+        "no-symbol" => ICON_NO_SYMBOL_CODEPOINTS,
+        "numbered-list" => ICON_NUMBERED_LIST_CODEPOINTS,
+        "paint-brush" => ICON_PAINT_BRUSH_CODEPOINTS,
+        "paper-airplane" => ICON_PAPER_AIRPLANE_CODEPOINTS,
+        "paper-clip" => ICON_PAPER_CLIP_CODEPOINTS,
+        "pause" => ICON_PAUSE_CODEPOINTS,
+        "pause-circle" => ICON_PAUSE_CIRCLE_CODEPOINTS,
+        "pencil" => ICON_PENCIL_CODEPOINTS,
+        "pencil-square" => ICON_PENCIL_SQUARE_CODEPOINTS,
+        "percent-badge" => ICON_PERCENT_BADGE_CODEPOINTS,
+        "phone" => ICON_PHONE_CODEPOINTS,
+        "phone-arrow-down-left" => ICON_PHONE_ARROW_DOWN_LEFT_CODEPOINTS,
+        "phone-arrow-up-right" => ICON_PHONE_ARROW_UP_RIGHT_CODEPOINTS,
+        "phone-x-mark" => ICON_PHONE_X_MARK_CODEPOINTS,
+        "photo" => ICON_PHOTO_CODEPOINTS,
+        "play" => ICON_PLAY_CODEPOINTS,
+        "play-circle" => ICON_PLAY_CIRCLE_CODEPOINTS,
+        "play-pause" => ICON_PLAY_PAUSE_CODEPOINTS,
+        "plus" => ICON_PLUS_CODEPOINTS,
+        "plus-circle" => ICON_PLUS_CIRCLE_CODEPOINTS,
+        "plus-small" => ICON_PLUS_SMALL_CODEPOINTS,
+        "power" => ICON_POWER_CODEPOINTS,
+        "presentation-chart-bar" => ICON_PRESENTATION_CHART_BAR_CODEPOINTS,
+        "presentation-chart-line" => ICON_PRESENTATION_CHART_LINE_CODEPOINTS,
+        "printer" => ICON_PRINTER_CODEPOINTS,
+        "puzzle-piece" => ICON_PUZZLE_PIECE_CODEPOINTS,
+        "qr-code" => ICON_QR_CODE_CODEPOINTS,
+        "question-mark-circle" => ICON_QUESTION_MARK_CIRCLE_CODEPOINTS,
+        "queue-list" => ICON_QUEUE_LIST_CODEPOINTS,
+        "radio" => ICON_RADIO_CODEPOINTS,
+        "receipt-percent" => ICON_RECEIPT_PERCENT_CODEPOINTS,
+        "receipt-refund" => ICON_RECEIPT_REFUND_CODEPOINTS,
+        "rectangle-group" => ICON_RECTANGLE_GROUP_CODEPOINTS,
+        "rectangle-stack" => ICON_RECTANGLE_STACK_CODEPOINTS,
+        "rocket-launch" => ICON_ROCKET_LAUNCH_CODEPOINTS,
+        "rss" => ICON_RSS_CODEPOINTS,
+        "scale" => ICON_SCALE_CODEPOINTS,
+        "scissors" => ICON_SCISSORS_CODEPOINTS,
+        "server" => ICON_SERVER_CODEPOINTS,
+        "server-stack" => ICON_SERVER_STACK_CODEPOINTS,
+        "share" => ICON_SHARE_CODEPOINTS,
+        "shield-check" => ICON_SHIELD_CHECK_CODEPOINTS,
+        "shield-exclamation" => ICON_SHIELD_EXCLAMATION_CODEPOINTS,
+        "shopping-bag" => ICON_SHOPPING_BAG_CODEPOINTS,
+        "shopping-cart" => ICON_SHOPPING_CART_CODEPOINTS,
+        "signal" => ICON_SIGNAL_CODEPOINTS,
+        "signal-slash" => ICON_SIGNAL_SLASH_CODEPOINTS,
+        "slash" => ICON_SLASH_CODEPOINTS,
+        "sparkles" => ICON_SPARKLES_CODEPOINTS,
+        "speaker-wave" => ICON_SPEAKER_WAVE_CODEPOINTS,
+        "speaker-x-mark" => ICON_SPEAKER_X_MARK_CODEPOINTS,
+        "square-2-stack" => ICON_SQUARE_2_STACK_CODEPOINTS,
+        "square-3-stack-3d" => ICON_SQUARE_3_STACK_3D_CODEPOINTS,
+        "squares-2x2" => ICON_SQUARES_2X_2_CODEPOINTS,
+        "squares-plus" => ICON_SQUARES_PLUS_CODEPOINTS,
+        "star" => ICON_STAR_CODEPOINTS,
+        "stop" => ICON_STOP_CODEPOINTS,
+        "stop-circle" => ICON_STOP_CIRCLE_CODEPOINTS,
+        "strikethrough" => ICON_STRIKETHROUGH_CODEPOINTS,
+        "sun" => ICON_SUN_CODEPOINTS,
+        "swatch" => ICON_SWATCH_CODEPOINTS,
+        "table-cells" => ICON_TABLE_CELLS_CODEPOINTS,
+        "tag" => ICON_TAG_CODEPOINTS,
+        "ticket" => ICON_TICKET_CODEPOINTS,
+        "trash" => ICON_TRASH_CODEPOINTS,
+        "trophy" => ICON_TROPHY_CODEPOINTS,
+        "truck" => ICON_TRUCK_CODEPOINTS,
+        "tv" => ICON_TV_CODEPOINTS,
+        "underline" => ICON_UNDERLINE_CODEPOINTS,
+        "user" => ICON_USER_CODEPOINTS,
+        "user-circle" => ICON_USER_CIRCLE_CODEPOINTS,
+        "user-group" => ICON_USER_GROUP_CODEPOINTS,
+        "user-minus" => ICON_USER_MINUS_CODEPOINTS,
+        "user-plus" => ICON_USER_PLUS_CODEPOINTS,
+        "users" => ICON_USERS_CODEPOINTS,
+        "variable" => ICON_VARIABLE_CODEPOINTS,
+        "video-camera" => ICON_VIDEO_CAMERA_CODEPOINTS,
+        "video-camera-slash" => ICON_VIDEO_CAMERA_SLASH_CODEPOINTS,
+        "view-columns" => ICON_VIEW_COLUMNS_CODEPOINTS,
+        "viewfinder-circle" => ICON_VIEWFINDER_CIRCLE_CODEPOINTS,
+        "wallet" => ICON_WALLET_CODEPOINTS,
+        "wifi" => ICON_WIFI_CODEPOINTS,
+        "window" => ICON_WINDOW_CODEPOINTS,
+        "wrench" => ICON_WRENCH_CODEPOINTS,
+        "wrench-screwdriver" => ICON_WRENCH_SCREWDRIVER_CODEPOINTS,
+        "x-circle" => ICON_X_CIRCLE_CODEPOINTS,
+        "x-mark" => ICON_X_MARK_CODEPOINTS,
+        _ => return None,
+    };
+    codepoints
         .iter()
-        .find(|entry| entry.name == name)
-        .and_then(|entry| {
-            entry
-                .codepoints
-                .iter()
-                .find(|(k, _)| *k == key)
-                .map(|(_, cp)| *cp)
-        })
+        .find(|(k, _)| *k == key)
+        .map(|(_, cp)| *cp)
+}
+
+pub(crate) fn canonical_name_ci(lowercase: &str) -> Option<&'static str> {
+    let index = ICON_NAMES_LOWERCASE.partition_point(|(lower, _)| *lower < lowercase);
+    ICON_NAMES_LOWERCASE
+        .get(index)
+        .filter(|(lower, _)| *lower == lowercase)
+        .map(|(_, name)| *name)
 }
 
 pub(crate) fn icon_available(name: &str) -> Option<&'static [(Style, Size)]> {
-    ICON_AVAILABILITY
-        .iter()
-        .find(|entry| entry.name == name)
-        .map(|entry| entry.available)
+    match name {
+        "academic-cap" => Some(ICON_ACADEMIC_CAP_AVAILABLE),
+        "adjustments-horizontal" => Some(ICON_ADJUSTMENTS_HORIZONTAL_AVAILABLE),
+        "adjustments-vertical" => Some(ICON_ADJUSTMENTS_VERTICAL_AVAILABLE),
+        "archive-box" => Some(ICON_ARCHIVE_BOX_AVAILABLE),
+        "archive-box-arrow-down" => Some(ICON_ARCHIVE_BOX_ARROW_DOWN_AVAILABLE),
+        "archive-box-x-mark" => Some(ICON_ARCHIVE_BOX_X_MARK_AVAILABLE),
+        "arrow-down" => Some(ICON_ARROW_DOWN_AVAILABLE),
+        "arrow-down-circle" => Some(ICON_ARROW_DOWN_CIRCLE_AVAILABLE),
+        "arrow-down-left" => Some(ICON_ARROW_DOWN_LEFT_AVAILABLE),
+        "arrow-down-on-square" => Some(ICON_ARROW_DOWN_ON_SQUARE_AVAILABLE),
+        "arrow-down-on-square-stack" => Some(ICON_ARROW_DOWN_ON_SQUARE_STACK_AVAILABLE),
+        "arrow-down-right" => Some(ICON_ARROW_DOWN_RIGHT_AVAILABLE),
+        "arrow-down-tray" => Some(ICON_ARROW_DOWN_TRAY_AVAILABLE),
+        "arrow-left" => Some(ICON_ARROW_LEFT_AVAILABLE),
+        "arrow-left-circle" => Some(ICON_ARROW_LEFT_CIRCLE_AVAILABLE),
+        "arrow-left-end-on-rectangle" => Some(ICON_ARROW_LEFT_END_ON_RECTANGLE_AVAILABLE),
+        "arrow-left-on-rectangle" => Some(ICON_ARROW_LEFT_ON_RECTANGLE_AVAILABLE),
+        "arrow-left-start-on-rectangle" => Some(ICON_ARROW_LEFT_START_ON_RECTANGLE_AVAILABLE),
+        "arrow-long-down" => Some(ICON_ARROW_LONG_DOWN_AVAILABLE),
+        "arrow-long-left" => Some(ICON_ARROW_LONG_LEFT_AVAILABLE),
+        "arrow-long-right" => Some(ICON_ARROW_LONG_RIGHT_AVAILABLE),
+        "arrow-long-up" => Some(ICON_ARROW_LONG_UP_AVAILABLE),
+        "arrow-path" => Some(ICON_ARROW_PATH_AVAILABLE),
+        "arrow-path-rounded-square" => Some(ICON_ARROW_PATH_ROUNDED_SQUARE_AVAILABLE),
+        "arrow-right" => Some(ICON_ARROW_RIGHT_AVAILABLE),
+        "arrow-right-circle" => Some(ICON_ARROW_RIGHT_CIRCLE_AVAILABLE),
+        "arrow-right-end-on-rectangle" => Some(ICON_ARROW_RIGHT_END_ON_RECTANGLE_AVAILABLE),
+        "arrow-right-on-rectangle" => Some(ICON_ARROW_RIGHT_ON_RECTANGLE_AVAILABLE),
+        "arrow-right-start-on-rectangle" => Some(ICON_ARROW_RIGHT_START_ON_RECTANGLE_AVAILABLE),
+        "arrow-small-down" => Some(ICON_ARROW_SMALL_DOWN_AVAILABLE),
+        "arrow-small-left" => Some(ICON_ARROW_SMALL_LEFT_AVAILABLE),
+        "arrow-small-right" => Some(ICON_ARROW_SMALL_RIGHT_AVAILABLE),
+        "arrow-small-up" => Some(ICON_ARROW_SMALL_UP_AVAILABLE),
+        "arrow-top-right-on-square" => Some(ICON_ARROW_TOP_RIGHT_ON_SQUARE_AVAILABLE),
+        "arrow-trending-down" => Some(ICON_ARROW_TRENDING_DOWN_AVAILABLE),
+        "arrow-trending-up" => Some(ICON_ARROW_TRENDING_UP_AVAILABLE),
+        "arrow-turn-down-left" => Some(ICON_ARROW_TURN_DOWN_LEFT_AVAILABLE),
+        "arrow-turn-down-right" => Some(ICON_ARROW_TURN_DOWN_RIGHT_AVAILABLE),
+        "arrow-turn-left-down" => Some(ICON_ARROW_TURN_LEFT_DOWN_AVAILABLE),
+        "arrow-turn-left-up" => Some(ICON_ARROW_TURN_LEFT_UP_AVAILABLE),
+        "arrow-turn-right-down" => Some(ICON_ARROW_TURN_RIGHT_DOWN_AVAILABLE),
+        "arrow-turn-right-up" => Some(ICON_ARROW_TURN_RIGHT_UP_AVAILABLE),
+        "arrow-turn-up-left" => Some(ICON_ARROW_TURN_UP_LEFT_AVAILABLE),
+        "arrow-turn-up-right" => Some(ICON_ARROW_TURN_UP_RIGHT_AVAILABLE),
+        "arrow-up" => Some(ICON_ARROW_UP_AVAILABLE),
+        "arrow-up-circle" => Some(ICON_ARROW_UP_CIRCLE_AVAILABLE),
+        "arrow-up-left" => Some(ICON_ARROW_UP_LEFT_AVAILABLE),
+        "arrow-up-on-square" => Some(ICON_ARROW_UP_ON_SQUARE_AVAILABLE),
+        "arrow-up-on-square-stack" => Some(ICON_ARROW_UP_ON_SQUARE_STACK_AVAILABLE),
+        "arrow-up-right" => Some(ICON_ARROW_UP_RIGHT_AVAILABLE),
+        "arrow-up-tray" => Some(ICON_ARROW_UP_TRAY_AVAILABLE),
+        "arrow-uturn-down" => Some(ICON_ARROW_UTURN_DOWN_AVAILABLE),
+        "arrow-uturn-left" => Some(ICON_ARROW_UTURN_LEFT_AVAILABLE),
+        "arrow-uturn-right" => Some(ICON_ARROW_UTURN_RIGHT_AVAILABLE),
+        "arrow-uturn-up" => Some(ICON_ARROW_UTURN_UP_AVAILABLE),
+        "arrows-pointing-in" => Some(ICON_ARROWS_POINTING_IN_AVAILABLE),
+        "arrows-pointing-out" => Some(ICON_ARROWS_POINTING_OUT_AVAILABLE),
+        "arrows-right-left" => Some(ICON_ARROWS_RIGHT_LEFT_AVAILABLE),
+        "arrows-up-down" => Some(ICON_ARROWS_UP_DOWN_AVAILABLE),
+        "at-symbol" => Some(ICON_AT_SYMBOL_AVAILABLE),
+        "backspace" => Some(ICON_BACKSPACE_AVAILABLE),
+        "backward" => Some(ICON_BACKWARD_AVAILABLE),
+        "banknotes" => Some(ICON_BANKNOTES_AVAILABLE),
+        "bars-2" => Some(ICON_BARS_2_AVAILABLE),
+        "bars-3" => Some(ICON_BARS_3_AVAILABLE),
+        "bars-3-bottom-left" => Some(ICON_BARS_3_BOTTOM_LEFT_AVAILABLE),
+        "bars-3-bottom-right" => Some(ICON_BARS_3_BOTTOM_RIGHT_AVAILABLE),
+        "bars-3-center-left" => Some(ICON_BARS_3_CENTER_LEFT_AVAILABLE),
+        "bars-4" => Some(ICON_BARS_4_AVAILABLE),
+        "bars-arrow-down" => Some(ICON_BARS_ARROW_DOWN_AVAILABLE),
+        "bars-arrow-up" => Some(ICON_BARS_ARROW_UP_AVAILABLE),
+        "battery-0" => Some(ICON_BATTERY_0_AVAILABLE),
+        "battery-100" => Some(ICON_BATTERY_100_AVAILABLE),
+        "battery-50" => Some(ICON_BATTERY_50_AVAILABLE),
+        "beaker" => Some(ICON_BEAKER_AVAILABLE),
+        "bell" => Some(ICON_BELL_AVAILABLE),
+        "bell-alert" => Some(ICON_BELL_ALERT_AVAILABLE),
+        "bell-slash" => Some(ICON_BELL_SLASH_AVAILABLE),
+        "bell-snooze" => Some(ICON_BELL_SNOOZE_AVAILABLE),
+        "bold" => Some(ICON_BOLD_AVAILABLE),
+        "bolt" => Some(ICON_BOLT_AVAILABLE),
+        "bolt-slash" => Some(ICON_BOLT_SLASH_AVAILABLE),
+        "book-open" => Some(ICON_BOOK_OPEN_AVAILABLE),
+        "bookmark" => Some(ICON_BOOKMARK_AVAILABLE),
+        "bookmark-slash" => Some(ICON_BOOKMARK_SLASH_AVAILABLE),
+        "bookmark-square" => Some(ICON_BOOKMARK_SQUARE_AVAILABLE),
+        "briefcase" => Some(ICON_BRIEFCASE_AVAILABLE),
+        "bug-ant" => Some(ICON_BUG_ANT_AVAILABLE),
+        "building-library" => Some(ICON_BUILDING_LIBRARY_AVAILABLE),
+        "building-office" => Some(ICON_BUILDING_OFFICE_AVAILABLE),
+        "building-office-2" => Some(ICON_BUILDING_OFFICE_2_AVAILABLE),
+        "building-storefront" => Some(ICON_BUILDING_STOREFRONT_AVAILABLE),
+        "cake" => Some(ICON_CAKE_AVAILABLE),
+        "calculator" => Some(ICON_CALCULATOR_AVAILABLE),
+        "calendar" => Some(ICON_CALENDAR_AVAILABLE),
+        "calendar-date-range" => Some(ICON_CALENDAR_DATE_RANGE_AVAILABLE),
+        "calendar-days" => Some(ICON_CALENDAR_DAYS_AVAILABLE),
+        "camera" => Some(ICON_CAMERA_AVAILABLE),
+        "chart-bar" => Some(ICON_CHART_BAR_AVAILABLE),
+        "chart-bar-square" => Some(ICON_CHART_BAR_SQUARE_AVAILABLE),
+        "chart-pie" => Some(ICON_CHART_PIE_AVAILABLE),
+        "chat-bubble-bottom-center" => Some(ICON_CHAT_BUBBLE_BOTTOM_CENTER_AVAILABLE),
+        "chat-bubble-bottom-center-text" => Some(ICON_CHAT_BUBBLE_BOTTOM_CENTER_TEXT_AVAILABLE),
+        "chat-bubble-left" => Some(ICON_CHAT_BUBBLE_LEFT_AVAILABLE),
+        "chat-bubble-left-ellipsis" => Some(ICON_CHAT_BUBBLE_LEFT_ELLIPSIS_AVAILABLE),
+        "chat-bubble-left-right" => Some(ICON_CHAT_BUBBLE_LEFT_RIGHT_AVAILABLE),
+        "chat-bubble-oval-left" => Some(ICON_CHAT_BUBBLE_OVAL_LEFT_AVAILABLE),
+        "chat-bubble-oval-left-ellipsis" => Some(ICON_CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS_AVAILABLE),
+        "check" => Some(ICON_CHECK_AVAILABLE),
+        "check-badge" => Some(ICON_CHECK_BADGE_AVAILABLE),
+        "check-circle" => Some(ICON_CHECK_CIRCLE_AVAILABLE),
+        "chevron-double-down" => Some(ICON_CHEVRON_DOUBLE_DOWN_AVAILABLE),
+        "chevron-double-left" => Some(ICON_CHEVRON_DOUBLE_LEFT_AVAILABLE),
+        "chevron-double-right" => Some(ICON_CHEVRON_DOUBLE_RIGHT_AVAILABLE),
+        "chevron-double-up" => Some(ICON_CHEVRON_DOUBLE_UP_AVAILABLE),
+        "chevron-down" => Some(ICON_CHEVRON_DOWN_AVAILABLE),
+        "chevron-left" => Some(ICON_CHEVRON_LEFT_AVAILABLE),
+        "chevron-right" => Some(ICON_CHEVRON_RIGHT_AVAILABLE),
+        "chevron-up" => Some(ICON_CHEVRON_UP_AVAILABLE),
+        "chevron-up-down" => Some(ICON_CHEVRON_UP_DOWN_AVAILABLE),
+        "circle-stack" => Some(ICON_CIRCLE_STACK_AVAILABLE),
+        "clipboard" => Some(ICON_CLIPBOARD_AVAILABLE),
+        "clipboard-document" => Some(ICON_CLIPBOARD_DOCUMENT_AVAILABLE),
+        "clipboard-document-check" => Some(ICON_CLIPBOARD_DOCUMENT_CHECK_AVAILABLE),
+        "clipboard-document-list" => Some(ICON_CLIPBOARD_DOCUMENT_LIST_AVAILABLE),
+        "clock" => Some(ICON_CLOCK_AVAILABLE),
+        "cloud" => Some(ICON_CLOUD_AVAILABLE),
+        "cloud-arrow-down" => Some(ICON_CLOUD_ARROW_DOWN_AVAILABLE),
+        "cloud-arrow-up" => Some(ICON_CLOUD_ARROW_UP_AVAILABLE),
+        "code-bracket" => Some(ICON_CODE_BRACKET_AVAILABLE),
+        "code-bracket-square" => Some(ICON_CODE_BRACKET_SQUARE_AVAILABLE),
+        "cog" => Some(ICON_COG_AVAILABLE),
+        "cog-6-tooth" => Some(ICON_COG_6_TOOTH_AVAILABLE),
+        "cog-8-tooth" => Some(ICON_COG_8_TOOTH_AVAILABLE),
+        "command-line" => Some(ICON_COMMAND_LINE_AVAILABLE),
+        "computer-desktop" => Some(ICON_COMPUTER_DESKTOP_AVAILABLE),
+        "cpu-chip" => Some(ICON_CPU_CHIP_AVAILABLE),
+        "credit-card" => Some(ICON_CREDIT_CARD_AVAILABLE),
+        "cube" => Some(ICON_CUBE_AVAILABLE),
+        "cube-transparent" => Some(ICON_CUBE_TRANSPARENT_AVAILABLE),
+        "currency-bangladeshi" => Some(ICON_CURRENCY_BANGLADESHI_AVAILABLE),
+        "currency-dollar" => Some(ICON_CURRENCY_DOLLAR_AVAILABLE),
+        "currency-euro" => Some(ICON_CURRENCY_EURO_AVAILABLE),
+        "currency-pound" => Some(ICON_CURRENCY_POUND_AVAILABLE),
+        "currency-rupee" => Some(ICON_CURRENCY_RUPEE_AVAILABLE),
+        "currency-yen" => Some(ICON_CURRENCY_YEN_AVAILABLE),
+        "cursor-arrow-rays" => Some(ICON_CURSOR_ARROW_RAYS_AVAILABLE),
+        "cursor-arrow-ripple" => Some(ICON_CURSOR_ARROW_RIPPLE_AVAILABLE),
+        "device-phone-mobile" => Some(ICON_DEVICE_PHONE_MOBILE_AVAILABLE),
+        "device-tablet" => Some(ICON_DEVICE_TABLET_AVAILABLE),
+        "divide" => Some(ICON_DIVIDE_AVAILABLE),
+        "document" => Some(ICON_DOCUMENT_AVAILABLE),
+        "document-arrow-down" => Some(ICON_DOCUMENT_ARROW_DOWN_AVAILABLE),
+        "document-arrow-up" => Some(ICON_DOCUMENT_ARROW_UP_AVAILABLE),
+        "document-chart-bar" => Some(ICON_DOCUMENT_CHART_BAR_AVAILABLE),
+        "document-check" => Some(ICON_DOCUMENT_CHECK_AVAILABLE),
+        "document-currency-bangladeshi" => Some(ICON_DOCUMENT_CURRENCY_BANGLADESHI_AVAILABLE),
+        "document-currency-dollar" => Some(ICON_DOCUMENT_CURRENCY_DOLLAR_AVAILABLE),
+        "document-currency-euro" => Some(ICON_DOCUMENT_CURRENCY_EURO_AVAILABLE),
+        "document-currency-pound" => Some(ICON_DOCUMENT_CURRENCY_POUND_AVAILABLE),
+        "document-currency-rupee" => Some(ICON_DOCUMENT_CURRENCY_RUPEE_AVAILABLE),
+        "document-currency-yen" => Some(ICON_DOCUMENT_CURRENCY_YEN_AVAILABLE),
+        "document-duplicate" => Some(ICON_DOCUMENT_DUPLICATE_AVAILABLE),
+        "document-magnifying-glass" => Some(ICON_DOCUMENT_MAGNIFYING_GLASS_AVAILABLE),
+        "document-minus" => Some(ICON_DOCUMENT_MINUS_AVAILABLE),
+        "document-plus" => Some(ICON_DOCUMENT_PLUS_AVAILABLE),
+        "document-text" => Some(ICON_DOCUMENT_TEXT_AVAILABLE),
+        "ellipsis-horizontal" => Some(ICON_ELLIPSIS_HORIZONTAL_AVAILABLE),
+        "ellipsis-horizontal-circle" => Some(ICON_ELLIPSIS_HORIZONTAL_CIRCLE_AVAILABLE),
+        "ellipsis-vertical" => Some(ICON_ELLIPSIS_VERTICAL_AVAILABLE),
+        "envelope" => Some(ICON_ENVELOPE_AVAILABLE),
+        "envelope-open" => Some(ICON_ENVELOPE_OPEN_AVAILABLE),
+        "equals" => Some(ICON_EQUALS_AVAILABLE),
+        "exclamation-circle" => Some(ICON_EXCLAMATION_CIRCLE_AVAILABLE),
+        "exclamation-triangle" => Some(ICON_EXCLAMATION_TRIANGLE_AVAILABLE),
+        "eye" => Some(ICON_EYE_AVAILABLE),
+        "eye-dropper" => Some(ICON_EYE_DROPPER_AVAILABLE),
+        "eye-slash" => Some(ICON_EYE_SLASH_AVAILABLE),
+        "face-frown" => Some(ICON_FACE_FROWN_AVAILABLE),
+        "face-smile" => Some(ICON_FACE_SMILE_AVAILABLE),
+        "film" => Some(ICON_FILM_AVAILABLE),
+        "finger-print" => Some(ICON_FINGER_PRINT_AVAILABLE),
+        "fire" => Some(ICON_FIRE_AVAILABLE),
+        "flag" => Some(ICON_FLAG_AVAILABLE),
+        "folder" => Some(ICON_FOLDER_AVAILABLE),
+        "folder-arrow-down" => Some(ICON_FOLDER_ARROW_DOWN_AVAILABLE),
+        "folder-minus" => Some(ICON_FOLDER_MINUS_AVAILABLE),
+        "folder-open" => Some(ICON_FOLDER_OPEN_AVAILABLE),
+        "folder-plus" => Some(ICON_FOLDER_PLUS_AVAILABLE),
+        "forward" => Some(ICON_FORWARD_AVAILABLE),
+        "funnel" => Some(ICON_FUNNEL_AVAILABLE),
+        "gif" => Some(ICON_GIF_AVAILABLE),
+        "gift" => Some(ICON_GIFT_AVAILABLE),
+        "gift-top" => Some(ICON_GIFT_TOP_AVAILABLE),
+        "globe-alt" => Some(ICON_GLOBE_ALT_AVAILABLE),
+        "globe-americas" => Some(ICON_GLOBE_AMERICAS_AVAILABLE),
+        "globe-asia-australia" => Some(ICON_GLOBE_ASIA_AUSTRALIA_AVAILABLE),
+        "globe-europe-africa" => Some(ICON_GLOBE_EUROPE_AFRICA_AVAILABLE),
+        "h1" => Some(ICON_H_1_AVAILABLE),
+        "h2" => Some(ICON_H_2_AVAILABLE),
+        "h3" => Some(ICON_H_3_AVAILABLE),
+        "hand-raised" => Some(ICON_HAND_RAISED_AVAILABLE),
+        "hand-thumb-down" => Some(ICON_HAND_THUMB_DOWN_AVAILABLE),
+        "hand-thumb-up" => Some(ICON_HAND_THUMB_UP_AVAILABLE),
+        "hashtag" => Some(ICON_HASHTAG_AVAILABLE),
+        "heart" => Some(ICON_HEART_AVAILABLE),
+        "home" => Some(ICON_HOME_AVAILABLE),
+        "home-modern" => Some(ICON_HOME_MODERN_AVAILABLE),
+        "identification" => Some(ICON_IDENTIFICATION_AVAILABLE),
+        "inbox" => Some(ICON_INBOX_AVAILABLE),
+        "inbox-arrow-down" => Some(ICON_INBOX_ARROW_DOWN_AVAILABLE),
+        "inbox-stack" => Some(ICON_INBOX_STACK_AVAILABLE),
+        "information-circle" => Some(ICON_INFORMATION_CIRCLE_AVAILABLE),
+        "italic" => Some(ICON_ITALIC_AVAILABLE),
+        "key" => Some(ICON_KEY_AVAILABLE),
+        "language" => Some(ICON_LANGUAGE_AVAILABLE),
+        "lifebuoy" => Some(ICON_LIFEBUOY_AVAILABLE),
+        "light-bulb" => Some(ICON_LIGHT_BULB_AVAILABLE),
+        "link" => Some(ICON_LINK_AVAILABLE),
+        "link-slash" => Some(ICON_LINK_SLASH_AVAILABLE),
+        "list-bullet" => Some(ICON_LIST_BULLET_AVAILABLE),
+        "lock-closed" => Some(ICON_LOCK_CLOSED_AVAILABLE),
+        "lock-open" => Some(ICON_LOCK_OPEN_AVAILABLE),
+        "magnifying-glass" => Some(ICON_MAGNIFYING_GLASS_AVAILABLE),
+        "magnifying-glass-circle" => Some(ICON_MAGNIFYING_GLASS_CIRCLE_AVAILABLE),
+        "magnifying-glass-minus" => Some(ICON_MAGNIFYING_GLASS_MINUS_AVAILABLE),
+        "magnifying-glass-plus" => Some(ICON_MAGNIFYING_GLASS_PLUS_AVAILABLE),
+        "map" => Some(ICON_MAP_AVAILABLE),
+        "map-pin" => Some(ICON_MAP_PIN_AVAILABLE),
+        "megaphone" => Some(ICON_MEGAPHONE_AVAILABLE),
+        "microphone" => Some(ICON_MICROPHONE_AVAILABLE),
+        "minus" => Some(ICON_MINUS_AVAILABLE),
+        "minus-circle" => Some(ICON_MINUS_CIRCLE_AVAILABLE),
+        "minus-small" => Some(ICON_MINUS_SMALL_AVAILABLE),
+        "moon" => Some(ICON_MOON_AVAILABLE),
+        "musical-note" => Some(ICON_MUSICAL_NOTE_AVAILABLE),
+        "newspaper" => Some(ICON_NEWSPAPER_AVAILABLE),
+        "no-symbol" => Some(ICON_NO_SYMBOL_AVAILABLE),
+        "numbered-list" => Some(ICON_NUMBERED_LIST_AVAILABLE),
+        "paint-brush" => Some(ICON_PAINT_BRUSH_AVAILABLE),
+        "paper-airplane" => Some(ICON_PAPER_AIRPLANE_AVAILABLE),
+        "paper-clip" => Some(ICON_PAPER_CLIP_AVAILABLE),
+        "pause" => Some(ICON_PAUSE_AVAILABLE),
+        "pause-circle" => Some(ICON_PAUSE_CIRCLE_AVAILABLE),
+        "pencil" => Some(ICON_PENCIL_AVAILABLE),
+        "pencil-square" => Some(ICON_PENCIL_SQUARE_AVAILABLE),
+        "percent-badge" => Some(ICON_PERCENT_BADGE_AVAILABLE),
+        "phone" => Some(ICON_PHONE_AVAILABLE),
+        "phone-arrow-down-left" => Some(ICON_PHONE_ARROW_DOWN_LEFT_AVAILABLE),
+        "phone-arrow-up-right" => Some(ICON_PHONE_ARROW_UP_RIGHT_AVAILABLE),
+        "phone-x-mark" => Some(ICON_PHONE_X_MARK_AVAILABLE),
+        "photo" => Some(ICON_PHOTO_AVAILABLE),
+        "play" => Some(ICON_PLAY_AVAILABLE),
+        "play-circle" => Some(ICON_PLAY_CIRCLE_AVAILABLE),
+        "play-pause" => Some(ICON_PLAY_PAUSE_AVAILABLE),
+        "plus" => Some(ICON_PLUS_AVAILABLE),
+        "plus-circle" => Some(ICON_PLUS_CIRCLE_AVAILABLE),
+        "plus-small" => Some(ICON_PLUS_SMALL_AVAILABLE),
+        "power" => Some(ICON_POWER_AVAILABLE),
+        "presentation-chart-bar" => Some(ICON_PRESENTATION_CHART_BAR_AVAILABLE),
+        "presentation-chart-line" => Some(ICON_PRESENTATION_CHART_LINE_AVAILABLE),
+        "printer" => Some(ICON_PRINTER_AVAILABLE),
+        "puzzle-piece" => Some(ICON_PUZZLE_PIECE_AVAILABLE),
+        "qr-code" => Some(ICON_QR_CODE_AVAILABLE),
+        "question-mark-circle" => Some(ICON_QUESTION_MARK_CIRCLE_AVAILABLE),
+        "queue-list" => Some(ICON_QUEUE_LIST_AVAILABLE),
+        "radio" => Some(ICON_RADIO_AVAILABLE),
+        "receipt-percent" => Some(ICON_RECEIPT_PERCENT_AVAILABLE),
+        "receipt-refund" => Some(ICON_RECEIPT_REFUND_AVAILABLE),
+        "rectangle-group" => Some(ICON_RECTANGLE_GROUP_AVAILABLE),
+        "rectangle-stack" => Some(ICON_RECTANGLE_STACK_AVAILABLE),
+        "rocket-launch" => Some(ICON_ROCKET_LAUNCH_AVAILABLE),
+        "rss" => Some(ICON_RSS_AVAILABLE),
+        "scale" => Some(ICON_SCALE_AVAILABLE),
+        "scissors" => Some(ICON_SCISSORS_AVAILABLE),
+        "server" => Some(ICON_SERVER_AVAILABLE),
+        "server-stack" => Some(ICON_SERVER_STACK_AVAILABLE),
+        "share" => Some(ICON_SHARE_AVAILABLE),
+        "shield-check" => Some(ICON_SHIELD_CHECK_AVAILABLE),
+        "shield-exclamation" => Some(ICON_SHIELD_EXCLAMATION_AVAILABLE),
+        "shopping-bag" => Some(ICON_SHOPPING_BAG_AVAILABLE),
+        "shopping-cart" => Some(ICON_SHOPPING_CART_AVAILABLE),
+        "signal" => Some(ICON_SIGNAL_AVAILABLE),
+        "signal-slash" => Some(ICON_SIGNAL_SLASH_AVAILABLE),
+        "slash" => Some(ICON_SLASH_AVAILABLE),
+        "sparkles" => Some(ICON_SPARKLES_AVAILABLE),
+        "speaker-wave" => Some(ICON_SPEAKER_WAVE_AVAILABLE),
+        "speaker-x-mark" => Some(ICON_SPEAKER_X_MARK_AVAILABLE),
+        "square-2-stack" => Some(ICON_SQUARE_2_STACK_AVAILABLE),
+        "square-3-stack-3d" => Some(ICON_SQUARE_3_STACK_3D_AVAILABLE),
+        "squares-2x2" => Some(ICON_SQUARES_2X_2_AVAILABLE),
+        "squares-plus" => Some(ICON_SQUARES_PLUS_AVAILABLE),
+        "star" => Some(ICON_STAR_AVAILABLE),
+        "stop" => Some(ICON_STOP_AVAILABLE),
+        "stop-circle" => Some(ICON_STOP_CIRCLE_AVAILABLE),
+        "strikethrough" => Some(ICON_STRIKETHROUGH_AVAILABLE),
+        "sun" => Some(ICON_SUN_AVAILABLE),
+        "swatch" => Some(ICON_SWATCH_AVAILABLE),
+        "table-cells" => Some(ICON_TABLE_CELLS_AVAILABLE),
+        "tag" => Some(ICON_TAG_AVAILABLE),
+        "ticket" => Some(ICON_TICKET_AVAILABLE),
+        "trash" => Some(ICON_TRASH_AVAILABLE),
+        "trophy" => Some(ICON_TROPHY_AVAILABLE),
+        "truck" => Some(ICON_TRUCK_AVAILABLE),
+        "tv" => Some(ICON_TV_AVAILABLE),
+        "underline" => Some(ICON_UNDERLINE_AVAILABLE),
+        "user" => Some(ICON_USER_AVAILABLE),
+        "user-circle" => Some(ICON_USER_CIRCLE_AVAILABLE),
+        "user-group" => Some(ICON_USER_GROUP_AVAILABLE),
+        "user-minus" => Some(ICON_USER_MINUS_AVAILABLE),
+        "user-plus" => Some(ICON_USER_PLUS_AVAILABLE),
+        "users" => Some(ICON_USERS_AVAILABLE),
+        "variable" => Some(ICON_VARIABLE_AVAILABLE),
+        "video-camera" => Some(ICON_VIDEO_CAMERA_AVAILABLE),
+        "video-camera-slash" => Some(ICON_VIDEO_CAMERA_SLASH_AVAILABLE),
+        "view-columns" => Some(ICON_VIEW_COLUMNS_AVAILABLE),
+        "viewfinder-circle" => Some(ICON_VIEWFINDER_CIRCLE_AVAILABLE),
+        "wallet" => Some(ICON_WALLET_AVAILABLE),
+        "wifi" => Some(ICON_WIFI_AVAILABLE),
+        "window" => Some(ICON_WINDOW_AVAILABLE),
+        "wrench" => Some(ICON_WRENCH_AVAILABLE),
+        "wrench-screwdriver" => Some(ICON_WRENCH_SCREWDRIVER_AVAILABLE),
+        "x-circle" => Some(ICON_X_CIRCLE_AVAILABLE),
+        "x-mark" => Some(ICON_X_MARK_AVAILABLE),
+        _ => None,
+    }
+}
+
+pub(crate) fn brand_color(name: &str) -> Option<[u8; 4]> {
+    ICON_COLORS
+        .binary_search_by(|(entry, _)| (*entry).cmp(name))
+        .ok()
+        .map(|index| ICON_COLORS[index].1)
+}
+
+pub(crate) fn aria_label(name: &str) -> Option<&'static str> {
+    ICON_NAMES
+        .binary_search(&name)
+        .ok()
+        .map(|index| ICON_LABELS[index])
+}
+
+pub(crate) fn icon_by_id(id: u32) -> Option<&'static str> {
+    ICON_IDS
+        .binary_search_by_key(&id, |(entry, _)| *entry)
+        .ok()
+        .map(|index| ICON_IDS[index].1)
+}
+
+pub(crate) fn rtl_mirror(name: &str) -> bool {
+    ICON_RTL_MIRROR.binary_search(&name).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_lookup_agrees_with_scan_tables() {
+        for entry in ICON_CODEPOINTS {
+            for &(key, codepoint) in entry.codepoints {
+                assert_eq!(icon_codepoint(entry.name, key), Some(codepoint));
+            }
+        }
+        for entry in ICON_AVAILABILITY {
+            assert_eq!(icon_available(entry.name), Some(entry.available));
+        }
+        assert_eq!(icon_available(""), None);
+        assert_eq!(icon_codepoint("", VARIANTS[0].key), None);
+    }
 }
@@ -1,7 +1,13 @@
 // @generated by xtask gen. DO NOT EDIT.
-use crate::core::{FontAsset, IconRef, Size, Style, VariantKey};
+use crate::core::{FontAsset, IconRef, PackKind, Size, Style, VariantKey};
 
+/// Canonical pack id, also returned by `Pack::Lobe.id()`.
 pub const PACK_ID: &str = "lobe";
+pub const DISPLAY_NAME: &str = "Lobe";
+pub const KIND: PackKind = PackKind::Brand;
+/// Fingerprint of the pack's families, names and codepoints; changes when the map does.
+pub const CONTENT_HASH: u64 = 0x4364f968d80ee9a1;
+pub const STYLE_ORDER: &[Style] = &[];
 
 pub(crate) const FONT_ASSET_LOBE_LOBE_REGULAR: FontAsset = FontAsset {
     family: "Lobe Regular",
@@ -21,549 +27,1647 @@ pub const VARIANT_ASSETS: &[(VariantKey, FontAsset)] = &[(
     FONT_ASSET_LOBE_LOBE_REGULAR,
 )];
 
+/// Styles of the enabled variants, in presentation order (`STYLE_ORDER` first).
+pub const STYLES: &[Style] = &[Style::Regular];
+
+/// Sizes of the enabled variants, sorted.
+pub const SIZES: &[Size] = &[Size::Regular];
+
+/// Lowest and highest codepoint each variant maps, for variants that map any.
+pub(crate) const VARIANT_CODEPOINT_RANGES: &[(VariantKey, u32, u32)] = &[(
+    VariantKey {
+        style: Style::Regular,
+        size: Size::Regular,
+    },
+    0xE000,
+    0xE219,
+)];
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Icon {
+    /// `adobe` — U+E001 (available in Regular)
     Adobe,
+    /// `adobe-text` — U+E000 (available in Regular)
     AdobeText,
+    /// `adobefirefly` — U+E003 (available in Regular)
     Adobefirefly,
+    /// `adobefirefly-text` — U+E002 (available in Regular)
     AdobefireflyText,
+    /// `agui` — U+E005 (available in Regular)
     Agui,
+    /// `agui-text` — U+E004 (available in Regular)
     AguiText,
+    /// `ai21` — U+E008 (available in Regular)
     Ai21,
+    /// `ai21-brand` — U+E006 (available in Regular)
     Ai21Brand,
+    /// `ai21-text` — U+E007 (available in Regular)
     Ai21Text,
+    /// `ai302` — U+E00A (available in Regular)
     Ai302,
+    /// `ai302-text` — U+E009 (available in Regular)
     Ai302Text,
+    /// `ai360` — U+E00C (available in Regular)
     Ai360,
+    /// `ai360-text` — U+E00B (available in Regular)
     Ai360Text,
+    /// `aihubmix` — U+E00E (available in Regular)
     Aihubmix,
+    /// `aihubmix-text` — U+E00D (available in Regular)
     AihubmixText,
+    /// `aimass` — U+E010 (available in Regular)
     Aimass,
+    /// `aimass-text` — U+E00F (available in Regular)
     AimassText,
+    /// `aionlabs` — U+E012 (available in Regular)
     Aionlabs,
+    /// `aionlabs-text` — U+E011 (available in Regular)
     AionlabsText,
+    /// `aistudio` — U+E014 (available in Regular)
     Aistudio,
+    /// `aistudio-text` — U+E013 (available in Regular)
     AistudioText,
+    /// `akashchat` — U+E016 (available in Regular)
     Akashchat,
+    /// `akashchat-text` — U+E015 (available in Regular)
     AkashchatText,
+    /// `alephalpha` — U+E018 (available in Regular)
     Alephalpha,
+    /// `alephalpha-text` — U+E017 (available in Regular)
     AlephalphaText,
+    /// `alibaba` — U+E01C (available in Regular)
     Alibaba,
+    /// `alibaba-brand` — U+E019 (available in Regular)
     AlibabaBrand,
+    /// `alibaba-text` — U+E01B (available in Regular)
     AlibabaText,
+    /// `alibaba-text-cn` — U+E01A (available in Regular)
     AlibabaTextCn,
+    /// `alibabacloud` — U+E01F (available in Regular)
     Alibabacloud,
+    /// `alibabacloud-text` — U+E01E (available in Regular)
     AlibabacloudText,
+    /// `alibabacloud-text-cn` — U+E01D (available in Regular)
     AlibabacloudTextCn,
+    /// `antgroup` — U+E023 (available in Regular)
     Antgroup,
+    /// `antgroup-brand` — U+E020 (available in Regular)
     AntgroupBrand,
+    /// `antgroup-text` — U+E022 (available in Regular)
     AntgroupText,
+    /// `antgroup-text-cn` — U+E021 (available in Regular)
     AntgroupTextCn,
+    /// `anthropic` — U+E025 (available in Regular)
     Anthropic,
+    /// `anthropic-text` — U+E024 (available in Regular)
     AnthropicText,
+    /// `anyscale` — U+E027 (available in Regular)
     Anyscale,
+    /// `anyscale-text` — U+E026 (available in Regular)
     AnyscaleText,
+    /// `apple` — U+E029 (available in Regular)
     Apple,
+    /// `apple-text` — U+E028 (available in Regular)
     AppleText,
+    /// `assemblyai` — U+E02B (available in Regular)
     Assemblyai,
+    /// `assemblyai-text` — U+E02A (available in Regular)
     AssemblyaiText,
+    /// `automatic` — U+E02D (available in Regular)
     Automatic,
+    /// `automatic-text` — U+E02C (available in Regular)
     AutomaticText,
+    /// `aws` — U+E030 (available in Regular)
     Aws,
+    /// `aws-brand` — U+E02E (available in Regular)
     AwsBrand,
+    /// `aws-text` — U+E02F (available in Regular)
     AwsText,
+    /// `aya` — U+E032 (available in Regular)
     Aya,
+    /// `aya-text` — U+E031 (available in Regular)
     AyaText,
+    /// `azure` — U+E034 (available in Regular)
     Azure,
+    /// `azure-text` — U+E033 (available in Regular)
     AzureText,
+    /// `azureai` — U+E036 (available in Regular)
     Azureai,
+    /// `azureai-text` — U+E035 (available in Regular)
     AzureaiText,
+    /// `baai` — U+E038 (available in Regular)
     Baai,
+    /// `baai-text` — U+E037 (available in Regular)
     BaaiText,
+    /// `baichuan` — U+E03A (available in Regular)
     Baichuan,
+    /// `baichuan-text` — U+E039 (available in Regular)
     BaichuanText,
+    /// `baidu` — U+E03E (available in Regular)
     Baidu,
+    /// `baidu-brand` — U+E03B (available in Regular)
     BaiduBrand,
+    /// `baidu-text` — U+E03D (available in Regular)
     BaiduText,
+    /// `baidu-text-cn` — U+E03C (available in Regular)
     BaiduTextCn,
+    /// `baiducloud` — U+E040 (available in Regular)
     Baiducloud,
+    /// `baiducloud-text` — U+E03F (available in Regular)
     BaiducloudText,
+    /// `bailian` — U+E042 (available in Regular)
     Bailian,
+    /// `bailian-text` — U+E041 (available in Regular)
     BailianText,
+    /// `baseten` — U+E044 (available in Regular)
     Baseten,
+    /// `baseten-text` — U+E043 (available in Regular)
     BasetenText,
+    /// `bedrock` — U+E046 (available in Regular)
     Bedrock,
+    /// `bedrock-text` — U+E045 (available in Regular)
     BedrockText,
+    /// `bfl` — U+E048 (available in Regular)
     Bfl,
+    /// `bfl-text` — U+E047 (available in Regular)
     BflText,
+    /// `bilibili` — U+E04A (available in Regular)
     Bilibili,
+    /// `bilibili-text` — U+E049 (available in Regular)
     BilibiliText,
+    /// `bilibiliindex` — U+E04C (available in Regular)
     Bilibiliindex,
+    /// `bilibiliindex-text` — U+E04B (available in Regular)
     BilibiliindexText,
+    /// `bing` — U+E04E (available in Regular)
     Bing,
+    /// `bing-text` — U+E04D (available in Regular)
     BingText,
+    /// `burncloud` — U+E050 (available in Regular)
     Burncloud,
+    /// `burncloud-text` — U+E04F (available in Regular)
     BurncloudText,
+    /// `bytedance` — U+E054 (available in Regular)
     Bytedance,
+    /// `bytedance-brand` — U+E051 (available in Regular)
     BytedanceBrand,
+    /// `bytedance-text` — U+E053 (available in Regular)
     BytedanceText,
+    /// `bytedance-text-cn` — U+E052 (available in Regular)
     BytedanceTextCn,
+    /// `capcut` — U+E056 (available in Regular)
     Capcut,
+    /// `capcut-text` — U+E055 (available in Regular)
     CapcutText,
+    /// `centml` — U+E059 (available in Regular)
     Centml,
+    /// `centml-brand` — U+E057 (available in Regular)
     CentmlBrand,
+    /// `centml-text` — U+E058 (available in Regular)
     CentmlText,
+    /// `cerebras` — U+E05C (available in Regular)
     Cerebras,
+    /// `cerebras-brand` — U+E05A (available in Regular)
     CerebrasBrand,
+    /// `cerebras-text` — U+E05B (available in Regular)
     CerebrasText,
+    /// `chatglm` — U+E05E (available in Regular)
     Chatglm,
+    /// `chatglm-text` — U+E05D (available in Regular)
     ChatglmText,
+    /// `civitai` — U+E060 (available in Regular)
     Civitai,
+    /// `civitai-text` — U+E05F (available in Regular)
     CivitaiText,
+    /// `claude` — U+E062 (available in Regular)
     Claude,
+    /// `claude-text` — U+E061 (available in Regular)
     ClaudeText,
+    /// `cline` — U+E064 (available in Regular)
     Cline,
+    /// `cline-text` — U+E063 (available in Regular)
     ClineText,
+    /// `clipdrop` — U+E066 (available in Regular)
     Clipdrop,
+    /// `clipdrop-text` — U+E065 (available in Regular)
     ClipdropText,
+    /// `cloudflare` — U+E068 (available in Regular)
     Cloudflare,
+    /// `cloudflare-text` — U+E067 (available in Regular)
     CloudflareText,
+    /// `codegeex` — U+E06A (available in Regular)
     Codegeex,
+    /// `codegeex-text` — U+E069 (available in Regular)
     CodegeexText,
+    /// `cogvideo` — U+E06C (available in Regular)
     Cogvideo,
+    /// `cogvideo-text` — U+E06B (available in Regular)
     CogvideoText,
+    /// `cogview` — U+E06E (available in Regular)
     Cogview,
+    /// `cogview-text` — U+E06D (available in Regular)
     CogviewText,
+    /// `cohere` — U+E070 (available in Regular)
     Cohere,
+    /// `cohere-text` — U+E06F (available in Regular)
     CohereText,
+    /// `colab` — U+E072 (available in Regular)
     Colab,
+    /// `colab-text` — U+E071 (available in Regular)
     ColabText,
+    /// `cometapi` — U+E074 (available in Regular)
     Cometapi,
+    /// `cometapi-text` — U+E073 (available in Regular)
     CometapiText,
+    /// `comfyui` — U+E076 (available in Regular)
     Comfyui,
+    /// `comfyui-text` — U+E075 (available in Regular)
     ComfyuiText,
+    /// `commanda` — U+E078 (available in Regular)
     Commanda,
+    /// `commanda-text` — U+E077 (available in Regular)
     CommandaText,
+    /// `copilot` — U+E07A (available in Regular)
     Copilot,
+    /// `copilot-text` — U+E079 (available in Regular)
     CopilotText,
+    /// `copilotkit` — U+E07C (available in Regular)
     Copilotkit,
+    /// `copilotkit-text` — U+E07B (available in Regular)
     CopilotkitText,
+    /// `coqui` — U+E07E (available in Regular)
     Coqui,
+    /// `coqui-text` — U+E07D (available in Regular)
     CoquiText,
+    /// `coze` — U+E080 (available in Regular)
     Coze,
+    /// `coze-text` — U+E07F (available in Regular)
     CozeText,
+    /// `crewai` — U+E083 (available in Regular)
     Crewai,
+    /// `crewai-brand` — U+E081 (available in Regular)
     CrewaiBrand,
+    /// `crewai-text` — U+E082 (available in Regular)
     CrewaiText,
+    /// `crusoe` — U+E085 (available in Regular)
     Crusoe,
+    /// `crusoe-text` — U+E084 (available in Regular)
     CrusoeText,
+    /// `cursor` — U+E087 (available in Regular)
     Cursor,
+    /// `cursor-text` — U+E086 (available in Regular)
     CursorText,
+    /// `dalle` — U+E089 (available in Regular)
     Dalle,
+    /// `dalle-text` — U+E088 (available in Regular)
     DalleText,
+    /// `dbrx` — U+E08C (available in Regular)
     Dbrx,
+    /// `dbrx-brand` — U+E08A (available in Regular)
     DbrxBrand,
+    /// `dbrx-text` — U+E08B (available in Regular)
     DbrxText,
+    /// `deepai` — U+E08E (available in Regular)
     Deepai,
+    /// `deepai-text` — U+E08D (available in Regular)
     DeepaiText,
+    /// `deepinfra` — U+E090 (available in Regular)
     Deepinfra,
+    /// `deepinfra-text` — U+E08F (available in Regular)
     DeepinfraText,
+    /// `deepl` — U+E092 (available in Regular)
     Deepl,
+    /// `deepl-text` — U+E091 (available in Regular)
     DeeplText,
+    /// `deepmind` — U+E094 (available in Regular)
     Deepmind,
+    /// `deepmind-text` — U+E093 (available in Regular)
     DeepmindText,
+    /// `deepseek` — U+E096 (available in Regular)
     Deepseek,
+    /// `deepseek-text` — U+E095 (available in Regular)
     DeepseekText,
+    /// `dify` — U+E098 (available in Regular)
     Dify,
+    /// `dify-text` — U+E097 (available in Regular)
     DifyText,
+    /// `doc2x` — U+E09A (available in Regular)
     Doc2x,
+    /// `doc2x-text` — U+E099 (available in Regular)
     Doc2xText,
+    /// `docsearch` — U+E09C (available in Regular)
     Docsearch,
+    /// `docsearch-text` — U+E09B (available in Regular)
     DocsearchText,
+    /// `dolphin` — U+E09E (available in Regular)
     Dolphin,
+    /// `dolphin-text` — U+E09D (available in Regular)
     DolphinText,
+    /// `doubao` — U+E0A0 (available in Regular)
     Doubao,
+    /// `doubao-text` — U+E09F (available in Regular)
     DoubaoText,
+    /// `dreammachine` — U+E0A2 (available in Regular)
     Dreammachine,
+    /// `dreammachine-text` — U+E0A1 (available in Regular)
     DreammachineText,
+    /// `elevenlabs` — U+E0A4 (available in Regular)
     Elevenlabs,
+    /// `elevenlabs-text` — U+E0A3 (available in Regular)
     ElevenlabsText,
+    /// `elevenx` — U+E0A6 (available in Regular)
     Elevenx,
+    /// `elevenx-text` — U+E0A5 (available in Regular)
     ElevenxText,
+    /// `exa` — U+E0A8 (available in Regular)
     Exa,
+    /// `exa-text` — U+E0A7 (available in Regular)
     ExaText,
+    /// `fal` — U+E0AA (available in Regular)
     Fal,
+    /// `fal-text` — U+E0A9 (available in Regular)
     FalText,
+    /// `fastgpt` — U+E0AC (available in Regular)
     Fastgpt,
+    /// `fastgpt-text` — U+E0AB (available in Regular)
     FastgptText,
+    /// `featherless` — U+E0AE (available in Regular)
     Featherless,
+    /// `featherless-text` — U+E0AD (available in Regular)
     FeatherlessText,
+    /// `figma` — U+E0B0 (available in Regular)
     Figma,
+    /// `figma-text` — U+E0AF (available in Regular)
     FigmaText,
+    /// `fireworks` — U+E0B2 (available in Regular)
     Fireworks,
+    /// `fireworks-text` — U+E0B1 (available in Regular)
     FireworksText,
+    /// `fishaudio` — U+E0B4 (available in Regular)
     Fishaudio,
+    /// `fishaudio-text` — U+E0B3 (available in Regular)
     FishaudioText,
+    /// `flora` — U+E0B6 (available in Regular)
     Flora,
+    /// `flora-text` — U+E0B5 (available in Regular)
     FloraText,
+    /// `flowith` — U+E0B8 (available in Regular)
     Flowith,
+    /// `flowith-text` — U+E0B7 (available in Regular)
     FlowithText,
+    /// `flux` — U+E0BA (available in Regular)
     Flux,
+    /// `flux-text` — U+E0B9 (available in Regular)
     FluxText,
+    /// `friendli` — U+E0BC (available in Regular)
     Friendli,
+    /// `friendli-text` — U+E0BB (available in Regular)
     FriendliText,
+    /// `gemini` — U+E0BE (available in Regular)
     Gemini,
+    /// `gemini-text` — U+E0BD (available in Regular)
     GeminiText,
+    /// `gemma` — U+E0C0 (available in Regular)
     Gemma,
+    /// `gemma-text` — U+E0BF (available in Regular)
     GemmaText,
+    /// `giteeai` — U+E0C2 (available in Regular)
     Giteeai,
+    /// `giteeai-text` — U+E0C1 (available in Regular)
     GiteeaiText,
+    /// `github` — U+E0C4 (available in Regular)
     Github,
+    /// `github-text` — U+E0C3 (available in Regular)
     GithubText,
+    /// `githubcopilot` — U+E0C6 (available in Regular)
     Githubcopilot,
+    /// `githubcopilot-text` — U+E0C5 (available in Regular)
     GithubcopilotText,
+    /// `glama` — U+E0C8 (available in Regular)
     Glama,
+    /// `glama-text` — U+E0C7 (available in Regular)
     GlamaText,
+    /// `glif` — U+E0CA (available in Regular)
     Glif,
+    /// `glif-text` — U+E0C9 (available in Regular)
     GlifText,
+    /// `glmv` — U+E0CC (available in Regular)
     Glmv,
+    /// `glmv-text` — U+E0CB (available in Regular)
     GlmvText,
+    /// `google` — U+E0CE (available in Regular)
     Google,
+    /// `google-brand` — U+E0CD (available in Regular)
     GoogleBrand,
+    /// `googlecloud` — U+E0D0 (available in Regular)
     Googlecloud,
+    /// `googlecloud-brand` — U+E0CF (available in Regular)
     GooglecloudBrand,
+    /// `goose` — U+E0D2 (available in Regular)
     Goose,
+    /// `goose-text` — U+E0D1 (available in Regular)
     GooseText,
+    /// `gradio` — U+E0D4 (available in Regular)
     Gradio,
+    /// `gradio-text` — U+E0D3 (available in Regular)
     GradioText,
+    /// `greptile` — U+E0D6 (available in Regular)
     Greptile,
+    /// `greptile-text` — U+E0D5 (available in Regular)
     GreptileText,
+    /// `grok` — U+E0D8 (available in Regular)
     Grok,
+    /// `grok-text` — U+E0D7 (available in Regular)
     GrokText,
+    /// `groq` — U+E0DA (available in Regular)
     Groq,
+    /// `groq-text` — U+E0D9 (available in Regular)
     GroqText,
+    /// `hailuo` — U+E0DC (available in Regular)
     Hailuo,
+    /// `hailuo-text` — U+E0DB (available in Regular)
     HailuoText,
+    /// `haiper` — U+E0DE (available in Regular)
     Haiper,
+    /// `haiper-text` — U+E0DD (available in Regular)
     HaiperText,
+    /// `hedra` — U+E0E0 (available in Regular)
     Hedra,
+    /// `hedra-text` — U+E0DF (available in Regular)
     HedraText,
+    /// `higress` — U+E0E2 (available in Regular)
     Higress,
+    /// `higress-text` — U+E0E1 (available in Regular)
     HigressText,
+    /// `huawei` — U+E0E5 (available in Regular)
     Huawei,
+    /// `huawei-text` — U+E0E4 (available in Regular)
     HuaweiText,
+    /// `huawei-text-cn` — U+E0E3 (available in Regular)
     HuaweiTextCn,
+    /// `huaweicloud` — U+E0E8 (available in Regular)
     Huaweicloud,
+    /// `huaweicloud-text` — U+E0E7 (available in Regular)
     HuaweicloudText,
+    /// `huaweicloud-text-cn` — U+E0E6 (available in Regular)
     HuaweicloudTextCn,
+    /// `huggingface` — U+E0EA (available in Regular)
     Huggingface,
+    /// `huggingface-text` — U+E0E9 (available in Regular)
     HuggingfaceText,
+    /// `hunyuan` — U+E0EC (available in Regular)
     Hunyuan,
+    /// `hunyuan-text` — U+E0EB (available in Regular)
     HunyuanText,
+    /// `hyperbolic` — U+E0EE (available in Regular)
     Hyperbolic,
+    /// `hyperbolic-text` — U+E0ED (available in Regular)
     HyperbolicText,
+    /// `ibm` — U+E0F0 (available in Regular)
     Ibm,
+    /// `ibm-text` — U+E0EF (available in Regular)
     IbmText,
+    /// `ideogram` — U+E0F2 (available in Regular)
     Ideogram,
+    /// `ideogram-text` — U+E0F1 (available in Regular)
     IdeogramText,
+    /// `iflytekcloud` — U+E0F4 (available in Regular)
     Iflytekcloud,
+    /// `iflytekcloud-text` — U+E0F3 (available in Regular)
     IflytekcloudText,
+    /// `inference` — U+E0F6 (available in Regular)
     Inference,
+    /// `inference-text` — U+E0F5 (available in Regular)
     InferenceText,
+    /// `infermatic` — U+E0F8 (available in Regular)
     Infermatic,
+    /// `infermatic-text` — U+E0F7 (available in Regular)
     InfermaticText,
+    /// `infinigence` — U+E0FB (available in Regular)
     Infinigence,
+    /// `infinigence-text` — U+E0FA (available in Regular)
     InfinigenceText,
+    /// `infinigence-text-cn` — U+E0F9 (available in Regular)
     InfinigenceTextCn,
+    /// `inflection` — U+E0FD (available in Regular)
     Inflection,
+    /// `inflection-text` — U+E0FC (available in Regular)
     InflectionText,
+    /// `internlm` — U+E0FF (available in Regular)
     Internlm,
+    /// `internlm-text` — U+E0FE (available in Regular)
     InternlmText,
+    /// `jimeng` — U+E101 (available in Regular)
     Jimeng,
+    /// `jimeng-text` — U+E100 (available in Regular)
     JimengText,
+    /// `jina` — U+E103 (available in Regular)
     Jina,
+    /// `jina-text` — U+E102 (available in Regular)
     JinaText,
+    /// `kimi` — U+E105 (available in Regular)
     Kimi,
+    /// `kimi-text` — U+E104 (available in Regular)
     KimiText,
+    /// `kling` — U+E107 (available in Regular)
     Kling,
+    /// `kling-text` — U+E106 (available in Regular)
     KlingText,
+    /// `kluster` — U+E109 (available in Regular)
     Kluster,
+    /// `kluster-text` — U+E108 (available in Regular)
     KlusterText,
+    /// `kolors` — U+E10B (available in Regular)
     Kolors,
+    /// `kolors-text` — U+E10A (available in Regular)
     KolorsText,
+    /// `krea` — U+E10D (available in Regular)
     Krea,
+    /// `krea-text` — U+E10C (available in Regular)
     KreaText,
+    /// `kwaipilot` — U+E10F (available in Regular)
     Kwaipilot,
+    /// `kwaipilot-text` — U+E10E (available in Regular)
     KwaipilotText,
+    /// `lambda` — U+E111 (available in Regular)
     Lambda,
+    /// `lambda-text` — U+E110 (available in Regular)
     LambdaText,
+    /// `langchain` — U+E113 (available in Regular)
     Langchain,
+    /// `langchain-text` — U+E112 (available in Regular)
     LangchainText,
+    /// `langfuse` — U+E115 (available in Regular)
     Langfuse,
+    /// `langfuse-text` — U+E114 (available in Regular)
     LangfuseText,
+    /// `langgraph` — U+E117 (available in Regular)
     Langgraph,
+    /// `langgraph-text` — U+E116 (available in Regular)
     LanggraphText,
+    /// `langsmith` — U+E119 (available in Regular)
     Langsmith,
+    /// `langsmith-text` — U+E118 (available in Regular)
     LangsmithText,
+    /// `leptonai` — U+E11B (available in Regular)
     Leptonai,
+    /// `leptonai-text` — U+E11A (available in Regular)
     LeptonaiText,
+    /// `lg` — U+E11D (available in Regular)
     Lg,
+    /// `lg-text` — U+E11C (available in Regular)
     LgText,
+    /// `lightricks` — U+E11F (available in Regular)
     Lightricks,
+    /// `lightricks-text` — U+E11E (available in Regular)
     LightricksText,
+    /// `liquid` — U+E121 (available in Regular)
     Liquid,
+    /// `liquid-text` — U+E120 (available in Regular)
     LiquidText,
+    /// `livekit` — U+E123 (available in Regular)
     Livekit,
+    /// `livekit-text` — U+E122 (available in Regular)
     LivekitText,
+    /// `llamaindex` — U+E125 (available in Regular)
     Llamaindex,
+    /// `llamaindex-text` — U+E124 (available in Regular)
     LlamaindexText,
+    /// `llava` — U+E127 (available in Regular)
     Llava,
+    /// `llava-text` — U+E126 (available in Regular)
     LlavaText,
+    /// `lmstudio` — U+E129 (available in Regular)
     Lmstudio,
+    /// `lmstudio-text` — U+E128 (available in Regular)
     LmstudioText,
+    /// `lobehub` — U+E12B (available in Regular)
     Lobehub,
+    /// `lobehub-text` — U+E12A (available in Regular)
     LobehubText,
+    /// `longcat` — U+E12D (available in Regular)
     Longcat,
+    /// `longcat-text` — U+E12C (available in Regular)
     LongcatText,
+    /// `lovable` — U+E12F (available in Regular)
     Lovable,
+    /// `lovable-text` — U+E12E (available in Regular)
     LovableText,
+    /// `luma` — U+E131 (available in Regular)
     Luma,
+    /// `luma-text` — U+E130 (available in Regular)
     LumaText,
+    /// `magic` — U+E133 (available in Regular)
     Magic,
+    /// `magic-text` — U+E132 (available in Regular)
     MagicText,
+    /// `make` — U+E135 (available in Regular)
     Make,
+    /// `make-text` — U+E134 (available in Regular)
     MakeText,
+    /// `manus` — U+E137 (available in Regular)
     Manus,
+    /// `manus-text` — U+E136 (available in Regular)
     ManusText,
+    /// `mastra` — U+E139 (available in Regular)
     Mastra,
+    /// `mastra-text` — U+E138 (available in Regular)
     MastraText,
+    /// `mcp` — U+E13B (available in Regular)
     Mcp,
+    /// `mcp-text` — U+E13A (available in Regular)
     McpText,
+    /// `mcpso` — U+E13D (available in Regular)
     Mcpso,
+    /// `mcpso-text` — U+E13C (available in Regular)
     McpsoText,
+    /// `menlo` — U+E13F (available in Regular)
     Menlo,
+    /// `menlo-text` — U+E13E (available in Regular)
     MenloText,
+    /// `meta` — U+E142 (available in Regular)
     Meta,
+    /// `meta-brand` — U+E140 (available in Regular)
     MetaBrand,
+    /// `meta-text` — U+E141 (available in Regular)
     MetaText,
+    /// `metaai` — U+E144 (available in Regular)
     Metaai,
+    /// `metaai-text` — U+E143 (available in Regular)
     MetaaiText,
+    /// `metagpt` — U+E146 (available in Regular)
     Metagpt,
+    /// `metagpt-text` — U+E145 (available in Regular)
     MetagptText,
+    /// `microsoft` — U+E148 (available in Regular)
     Microsoft,
+    /// `microsoft-text` — U+E147 (available in Regular)
     MicrosoftText,
+    /// `midjourney` — U+E14A (available in Regular)
     Midjourney,
+    /// `midjourney-text` — U+E149 (available in Regular)
     MidjourneyText,
+    /// `minimax` — U+E14C (available in Regular)
     Minimax,
+    /// `minimax-text` — U+E14B (available in Regular)
     MinimaxText,
+    /// `mistral` — U+E14E (available in Regular)
     Mistral,
+    /// `mistral-text` — U+E14D (available in Regular)
     MistralText,
+    /// `modelscope` — U+E150 (available in Regular)
     Modelscope,
+    /// `modelscope-text` — U+E14F (available in Regular)
     ModelscopeText,
+    /// `monica` — U+E152 (available in Regular)
     Monica,
+    /// `monica-text` — U+E151 (available in Regular)
     MonicaText,
+    /// `moonshot` — U+E154 (available in Regular)
     Moonshot,
+    /// `moonshot-text` — U+E153 (available in Regular)
     MoonshotText,
+    /// `myshell` — U+E156 (available in Regular)
     Myshell,
+    /// `myshell-text` — U+E155 (available in Regular)
     MyshellText,
+    /// `n8n` — U+E158 (available in Regular)
     N8n,
+    /// `n8n-text` — U+E157 (available in Regular)
     N8nText,
+    /// `nebius` — U+E15A (available in Regular)
     Nebius,
+    /// `nebius-text` — U+E159 (available in Regular)
     NebiusText,
+    /// `newapi` — U+E15C (available in Regular)
     Newapi,
+    /// `newapi-text` — U+E15B (available in Regular)
     NewapiText,
+    /// `notebooklm` — U+E15E (available in Regular)
     Notebooklm,
+    /// `notebooklm-text` — U+E15D (available in Regular)
     NotebooklmText,
+    /// `notion` — U+E160 (available in Regular)
     Notion,
+    /// `notion-text` — U+E15F (available in Regular)
     NotionText,
+    /// `nousresearch` — U+E162 (available in Regular)
     Nousresearch,
+    /// `nousresearch-text` — U+E161 (available in Regular)
     NousresearchText,
+    /// `nova` — U+E164 (available in Regular)
     Nova,
+    /// `nova-text` — U+E163 (available in Regular)
     NovaText,
+    /// `novelai` — U+E166 (available in Regular)
     Novelai,
+    /// `novelai-text` — U+E165 (available in Regular)
     NovelaiText,
+    /// `novita` — U+E168 (available in Regular)
     Novita,
+    /// `novita-text` — U+E167 (available in Regular)
     NovitaText,
+    /// `nplcloud` — U+E16A (available in Regular)
     Nplcloud,
+    /// `nplcloud-text` — U+E169 (available in Regular)
     NplcloudText,
+    /// `nvidia` — U+E16C (available in Regular)
     Nvidia,
+    /// `nvidia-text` — U+E16B (available in Regular)
     NvidiaText,
+    /// `ollama` — U+E16E (available in Regular)
     Ollama,
+    /// `ollama-text` — U+E16D (available in Regular)
     OllamaText,
+    /// `openai` — U+E170 (available in Regular)
     Openai,
+    /// `openai-text` — U+E16F (available in Regular)
     OpenaiText,
+    /// `openchat` — U+E172 (available in Regular)
     Openchat,
+    /// `openchat-text` — U+E171 (available in Regular)
     OpenchatText,
+    /// `openrouter` — U+E174 (available in Regular)
     Openrouter,
+    /// `openrouter-text` — U+E173 (available in Regular)
     OpenrouterText,
+    /// `openwebui` — U+E176 (available in Regular)
     Openwebui,
+    /// `openwebui-text` — U+E175 (available in Regular)
     OpenwebuiText,
+    /// `palm` — U+E178 (available in Regular)
     Palm,
+    /// `palm-text` — U+E177 (available in Regular)
     PalmText,
+    /// `parasail` — U+E17A (available in Regular)
     Parasail,
+    /// `parasail-text` — U+E179 (available in Regular)
     ParasailText,
+    /// `perplexity` — U+E17C (available in Regular)
     Perplexity,
+    /// `perplexity-text` — U+E17B (available in Regular)
     PerplexityText,
+    /// `phidata` — U+E17E (available in Regular)
     Phidata,
+    /// `phidata-text` — U+E17D (available in Regular)
     PhidataText,
+    /// `phind` — U+E180 (available in Regular)
     Phind,
+    /// `phind-text` — U+E17F (available in Regular)
     PhindText,
+    /// `pika` — U+E182 (available in Regular)
     Pika,
+    /// `pika-text` — U+E181 (available in Regular)
     PikaText,
+    /// `pixverse` — U+E184 (available in Regular)
     Pixverse,
+    /// `pixverse-text` — U+E183 (available in Regular)
     PixverseText,
+    /// `player2` — U+E186 (available in Regular)
     Player2,
+    /// `player2-text` — U+E185 (available in Regular)
     Player2Text,
+    /// `poe` — U+E188 (available in Regular)
     Poe,
+    /// `poe-text` — U+E187 (available in Regular)
     PoeText,
+    /// `pollinations` — U+E18A (available in Regular)
     Pollinations,
+    /// `pollinations-text` — U+E189 (available in Regular)
     PollinationsText,
+    /// `ppio` — U+E18D (available in Regular)
     Ppio,
+    /// `ppio-text` — U+E18C (available in Regular)
     PpioText,
+    /// `ppio-text-cn` — U+E18B (available in Regular)
     PpioTextCn,
+    /// `pydanticai` — U+E18F (available in Regular)
     Pydanticai,
+    /// `pydanticai-text` — U+E18E (available in Regular)
     PydanticaiText,
+    /// `qingyan` — U+E191 (available in Regular)
     Qingyan,
+    /// `qingyan-text` — U+E190 (available in Regular)
     QingyanText,
+    /// `qiniu` — U+E193 (available in Regular)
     Qiniu,
+    /// `qiniu-text` — U+E192 (available in Regular)
     QiniuText,
+    /// `qwen` — U+E195 (available in Regular)
     Qwen,
+    /// `qwen-text` — U+E194 (available in Regular)
     QwenText,
+    /// `railway` — U+E197 (available in Regular)
     Railway,
+    /// `railway-text` — U+E196 (available in Regular)
     RailwayText,
+    /// `recraft` — U+E199 (available in Regular)
     Recraft,
+    /// `recraft-text` — U+E198 (available in Regular)
     RecraftText,
+    /// `replicate` — U+E19B (available in Regular)
     Replicate,
+    /// `replicate-brand` — U+E19A (available in Regular)
     ReplicateBrand,
+    /// `replit` — U+E19D (available in Regular)
     Replit,
+    /// `replit-text` — U+E19C (available in Regular)
     ReplitText,
+    /// `rsshub` — U+E19F (available in Regular)
     Rsshub,
+    /// `rsshub-text` — U+E19E (available in Regular)
     RsshubText,
+    /// `runway` — U+E1A1 (available in Regular)
     Runway,
+    /// `runway-text` — U+E1A0 (available in Regular)
     RunwayText,
+    /// `rwkv` — U+E1A3 (available in Regular)
     Rwkv,
+    /// `rwkv-text` — U+E1A2 (available in Regular)
     RwkvText,
+    /// `sambanova` — U+E1A5 (available in Regular)
     Sambanova,
+    /// `sambanova-text` — U+E1A4 (available in Regular)
     SambanovaText,
+    /// `search1api` — U+E1A7 (available in Regular)
     Search1api,
+    /// `search1api-text` — U+E1A6 (available in Regular)
     Search1apiText,
+    /// `searchapi` — U+E1A9 (available in Regular)
     Searchapi,
+    /// `searchapi-text` — U+E1A8 (available in Regular)
     SearchapiText,
+    /// `sensenova` — U+E1AC (available in Regular)
     Sensenova,
+    /// `sensenova-brand` — U+E1AA (available in Regular)
     SensenovaBrand,
+    /// `sensenova-text` — U+E1AB (available in Regular)
     SensenovaText,
+    /// `siliconcloud` — U+E1AE (available in Regular)
     Siliconcloud,
+    /// `siliconcloud-text` — U+E1AD (available in Regular)
     SiliconcloudText,
+    /// `skywork` — U+E1B0 (available in Regular)
     Skywork,
+    /// `skywork-text` — U+E1AF (available in Regular)
     SkyworkText,
+    /// `smithery` — U+E1B2 (available in Regular)
     Smithery,
+    /// `smithery-text` — U+E1B1 (available in Regular)
     SmitheryText,
+    /// `snowflake` — U+E1B4 (available in Regular)
     Snowflake,
+    /// `snowflake-text` — U+E1B3 (available in Regular)
     SnowflakeText,
+    /// `sophnet` — U+E1B6 (available in Regular)
     Sophnet,
+    /// `sophnet-text` — U+E1B5 (available in Regular)
     SophnetText,
+    /// `sora` — U+E1B8 (available in Regular)
     Sora,
+    /// `sora-text` — U+E1B7 (available in Regular)
     SoraText,
+    /// `spark` — U+E1BA (available in Regular)
     Spark,
+    /// `spark-text` — U+E1B9 (available in Regular)
     SparkText,
+    /// `stability` — U+E1BD (available in Regular)
     Stability,
+    /// `stability-brand` — U+E1BB (available in Regular)
     StabilityBrand,
+    /// `stability-text` — U+E1BC (available in Regular)
     StabilityText,
+    /// `statecloud` — U+E1BF (available in Regular)
     Statecloud,
+    /// `statecloud-text` — U+E1BE (available in Regular)
     StatecloudText,
+    /// `stepfun` — U+E1C1 (available in Regular)
     Stepfun,
+    /// `stepfun-text` — U+E1C0 (available in Regular)
     StepfunText,
+    /// `straico` — U+E1C3 (available in Regular)
     Straico,
+    /// `straico-text` — U+E1C2 (available in Regular)
     StraicoText,
+    /// `submodel` — U+E1C5 (available in Regular)
     Submodel,
+    /// `submodel-text` — U+E1C4 (available in Regular)
     SubmodelText,
+    /// `suno` — U+E1C7 (available in Regular)
     Suno,
+    /// `suno-text` — U+E1C6 (available in Regular)
     SunoText,
+    /// `sync` — U+E1C9 (available in Regular)
     Sync,
+    /// `sync-text` — U+E1C8 (available in Regular)
     SyncText,
+    /// `targon` — U+E1CB (available in Regular)
     Targon,
+    /// `targon-text` — U+E1CA (available in Regular)
     TargonText,
+    /// `tavily` — U+E1CD (available in Regular)
     Tavily,
+    /// `tavily-text` — U+E1CC (available in Regular)
     TavilyText,
+    /// `tencent` — U+E1D1 (available in Regular)
     Tencent,
+    /// `tencent-brand` — U+E1CE (available in Regular)
     TencentBrand,
+    /// `tencent-text` — U+E1D0 (available in Regular)
     TencentText,
+    /// `tencent-text-cn` — U+E1CF (available in Regular)
     TencentTextCn,
+    /// `tencentcloud` — U+E1D3 (available in Regular)
     Tencentcloud,
+    /// `tencentcloud-text` — U+E1D2 (available in Regular)
     TencentcloudText,
+    /// `tiangong` — U+E1D5 (available in Regular)
     Tiangong,
+    /// `tiangong-text` — U+E1D4 (available in Regular)
     TiangongText,
+    /// `tii` — U+E1D7 (available in Regular)
     Tii,
+    /// `tii-text` — U+E1D6 (available in Regular)
     TiiText,
+    /// `together` — U+E1DA (available in Regular)
     Together,
+    /// `together-brand` — U+E1D8 (available in Regular)
     TogetherBrand,
+    /// `together-text` — U+E1D9 (available in Regular)
     TogetherText,
+    /// `topazlabs` — U+E1DC (available in Regular)
     Topazlabs,
+    /// `topazlabs-text` — U+E1DB (available in Regular)
     TopazlabsText,
+    /// `trae` — U+E1DE (available in Regular)
     Trae,
+    /// `trae-text` — U+E1DD (available in Regular)
     TraeText,
+    /// `tripo` — U+E1E0 (available in Regular)
     Tripo,
+    /// `tripo-text` — U+E1DF (available in Regular)
     TripoText,
+    /// `turix` — U+E1E2 (available in Regular)
     Turix,
+    /// `turix-text` — U+E1E1 (available in Regular)
     TurixText,
+    /// `udio` — U+E1E4 (available in Regular)
     Udio,
+    /// `udio-text` — U+E1E3 (available in Regular)
     UdioText,
+    /// `unstructured` — U+E1E6 (available in Regular)
     Unstructured,
+    /// `unstructured-text` — U+E1E5 (available in Regular)
     UnstructuredText,
+    /// `upstage` — U+E1E8 (available in Regular)
     Upstage,
+    /// `upstage-text` — U+E1E7 (available in Regular)
     UpstageText,
+    /// `v0` — U+E1E9 (available in Regular)
     V0,
+    /// `vectorizerai` — U+E1EB (available in Regular)
     Vectorizerai,
+    /// `vectorizerai-text` — U+E1EA (available in Regular)
     VectorizeraiText,
+    /// `vercel` — U+E1ED (available in Regular)
     Vercel,
+    /// `vercel-text` — U+E1EC (available in Regular)
     VercelText,
+    /// `vertexai` — U+E1EF (available in Regular)
     Vertexai,
+    /// `vertexai-text` — U+E1EE (available in Regular)
     VertexaiText,
+    /// `vidu` — U+E1F1 (available in Regular)
     Vidu,
+    /// `vidu-text` — U+E1F0 (available in Regular)
     ViduText,
+    /// `viggle` — U+E1F3 (available in Regular)
     Viggle,
+    /// `viggle-text` — U+E1F2 (available in Regular)
     ViggleText,
+    /// `vllm` — U+E1F5 (available in Regular)
     Vllm,
+    /// `vllm-text` — U+E1F4 (available in Regular)
     VllmText,
+    /// `volcengine` — U+E1F7 (available in Regular)
     Volcengine,
+    /// `volcengine-text` — U+E1F6 (available in Regular)
     VolcengineText,
+    /// `voyage` — U+E1F9 (available in Regular)
     Voyage,
+    /// `voyage-text` — U+E1F8 (available in Regular)
     VoyageText,
+    /// `wenxin` — U+E1FB (available in Regular)
     Wenxin,
+    /// `wenxin-text` — U+E1FA (available in Regular)
     WenxinText,
+    /// `windsurf` — U+E1FD (available in Regular)
     Windsurf,
+    /// `windsurf-text` — U+E1FC (available in Regular)
     WindsurfText,
+    /// `workersai` — U+E1FF (available in Regular)
     Workersai,
+    /// `workersai-text` — U+E1FE (available in Regular)
     WorkersaiText,
+    /// `xai` — U+E201 (available in Regular)
     Xai,
+    /// `xai-text` — U+E200 (available in Regular)
     XaiText,
+    /// `xinference` — U+E203 (available in Regular)
     Xinference,
+    /// `xinference-text` — U+E202 (available in Regular)
     XinferenceText,
+    /// `xuanyuan` — U+E205 (available in Regular)
     Xuanyuan,
+    /// `xuanyuan-text` — U+E204 (available in Regular)
     XuanyuanText,
+    /// `yandex` — U+E207 (available in Regular)
     Yandex,
+    /// `yandex-text` — U+E206 (available in Regular)
     YandexText,
+    /// `yi` — U+E209 (available in Regular)
     Yi,
+    /// `yi-text` — U+E208 (available in Regular)
     YiText,
+    /// `youmind` — U+E20B (available in Regular)
     Youmind,
+    /// `youmind-text` — U+E20A (available in Regular)
     YoumindText,
+    /// `yuanbao` — U+E20D (available in Regular)
     Yuanbao,
+    /// `yuanbao-text` — U+E20C (available in Regular)
     YuanbaoText,
+    /// `zai` — U+E20F (available in Regular)
     Zai,
+    /// `zai-text` — U+E20E (available in Regular)
     ZaiText,
+    /// `zapier` — U+E211 (available in Regular)
     Zapier,
+    /// `zapier-text` — U+E210 (available in Regular)
     ZapierText,
+    /// `zeabur` — U+E213 (available in Regular)
     Zeabur,
+    /// `zeabur-text` — U+E212 (available in Regular)
     ZeaburText,
+    /// `zenmux` — U+E215 (available in Regular)
     Zenmux,
+    /// `zenmux-text` — U+E214 (available in Regular)
     ZenmuxText,
+    /// `zeroone` — U+E217 (available in Regular)
     Zeroone,
+    /// `zeroone-text` — U+E216 (available in Regular)
     ZerooneText,
+    /// `zhipu` — U+E219 (available in Regular)
     Zhipu,
+    /// `zhipu-text` — U+E218 (available in Regular)
     ZhipuText,
 }
 
+/// An icon's available `(style, size)` pairs and its codepoint in each variant.
+type IconVariantTables = (&'static [(Style, Size)], &'static [(VariantKey, u32)]);
+
 impl Icon {
+    pub const ALL: &[Icon] = &[
+        Icon::Adobe,
+        Icon::AdobeText,
+        Icon::Adobefirefly,
+        Icon::AdobefireflyText,
+        Icon::Agui,
+        Icon::AguiText,
+        Icon::Ai21,
+        Icon::Ai21Brand,
+        Icon::Ai21Text,
+        Icon::Ai302,
+        Icon::Ai302Text,
+        Icon::Ai360,
+        Icon::Ai360Text,
+        Icon::Aihubmix,
+        Icon::AihubmixText,
+        Icon::Aimass,
+        Icon::AimassText,
+        Icon::Aionlabs,
+        Icon::AionlabsText,
+        Icon::Aistudio,
+        Icon::AistudioText,
+        Icon::Akashchat,
+        Icon::AkashchatText,
+        Icon::Alephalpha,
+        Icon::AlephalphaText,
+        Icon::Alibaba,
+        Icon::AlibabaBrand,
+        Icon::AlibabaText,
+        Icon::AlibabaTextCn,
+        Icon::Alibabacloud,
+        Icon::AlibabacloudText,
+        Icon::AlibabacloudTextCn,
+        Icon::Antgroup,
+        Icon::AntgroupBrand,
+        Icon::AntgroupText,
+        Icon::AntgroupTextCn,
+        Icon::Anthropic,
+        Icon::AnthropicText,
+        Icon::Anyscale,
+        Icon::AnyscaleText,
+        Icon::Apple,
+        Icon::AppleText,
+        Icon::Assemblyai,
+        Icon::AssemblyaiText,
+        Icon::Automatic,
+        Icon::AutomaticText,
+        Icon::Aws,
+        Icon::AwsBrand,
+        Icon::AwsText,
+        Icon::Aya,
+        Icon::AyaText,
+        Icon::Azure,
+        Icon::AzureText,
+        Icon::Azureai,
+        Icon::AzureaiText,
+        Icon::Baai,
+        Icon::BaaiText,
+        Icon::Baichuan,
+        Icon::BaichuanText,
+        Icon::Baidu,
+        Icon::BaiduBrand,
+        Icon::BaiduText,
+        Icon::BaiduTextCn,
+        Icon::Baiducloud,
+        Icon::BaiducloudText,
+        Icon::Bailian,
+        Icon::BailianText,
+        Icon::Baseten,
+        Icon::BasetenText,
+        Icon::Bedrock,
+        Icon::BedrockText,
+        Icon::Bfl,
+        Icon::BflText,
+        Icon::Bilibili,
+        Icon::BilibiliText,
+        Icon::Bilibiliindex,
+        Icon::BilibiliindexText,
+        Icon::Bing,
+        Icon::BingText,
+        Icon::Burncloud,
+        Icon::BurncloudText,
+        Icon::Bytedance,
+        Icon::BytedanceBrand,
+        Icon::BytedanceText,
+        Icon::BytedanceTextCn,
+        Icon::Capcut,
+        Icon::CapcutText,
+        Icon::Centml,
+        Icon::CentmlBrand,
+        Icon::CentmlText,
+        Icon::Cerebras,
+        Icon::CerebrasBrand,
+        Icon::CerebrasText,
+        Icon::Chatglm,
+        Icon::ChatglmText,
+        Icon::Civitai,
+        Icon::CivitaiText,
+        Icon::Claude,
+        Icon::ClaudeText,
+        Icon::Cline,
+        Icon::ClineText,
+        Icon::Clipdrop,
+        Icon::ClipdropText,
+        Icon::Cloudflare,
+        Icon::CloudflareText,
+        Icon::Codegeex,
+        Icon::CodegeexText,
+        Icon::Cogvideo,
+        Icon::CogvideoText,
+        Icon::Cogview,
+        Icon::CogviewText,
+        Icon::Cohere,
+        Icon::CohereText,
+        Icon::Colab,
+        Icon::ColabText,
+        Icon::Cometapi,
+        Icon::CometapiText,
+        Icon::Comfyui,
+        Icon::ComfyuiText,
+        Icon::Commanda,
+        Icon::CommandaText,
+        Icon::Copilot,
+        Icon::CopilotText,
+        Icon::Copilotkit,
+        Icon::CopilotkitText,
+        Icon::Coqui,
+        Icon::CoquiText,
+        Icon::Coze,
+        Icon::CozeText,
+        Icon::Crewai,
+        Icon::CrewaiBrand,
+        Icon::CrewaiText,
+        Icon::Crusoe,
+        Icon::CrusoeText,
+        Icon::Cursor,
+        Icon::CursorText,
+        Icon::Dalle,
+        Icon::DalleText,
+        Icon::Dbrx,
+        Icon::DbrxBrand,
+        Icon::DbrxText,
+        Icon::Deepai,
+        Icon::DeepaiText,
+        Icon::Deepinfra,
+        Icon::DeepinfraText,
+        Icon::Deepl,
+        Icon::DeeplText,
+        Icon::Deepmind,
+        Icon::DeepmindText,
+        Icon::Deepseek,
+        Icon::DeepseekText,
+        Icon::Dify,
+        Icon::DifyText,
+        Icon::Doc2x,
+        Icon::Doc2xText,
+        Icon::Docsearch,
+        Icon::DocsearchText,
+        Icon::Dolphin,
+        Icon::DolphinText,
+        Icon::Doubao,
+        Icon::DoubaoText,
+        Icon::Dreammachine,
+        Icon::DreammachineText,
+        Icon::Elevenlabs,
+        Icon::ElevenlabsText,
+        Icon::Elevenx,
+        Icon::ElevenxText,
+        Icon::Exa,
+        Icon::ExaText,
+        Icon::Fal,
+        Icon::FalText,
+        Icon::Fastgpt,
+        Icon::FastgptText,
+        Icon::Featherless,
+        Icon::FeatherlessText,
+        Icon::Figma,
+        Icon::FigmaText,
+        Icon::Fireworks,
+        Icon::FireworksText,
+        Icon::Fishaudio,
+        Icon::FishaudioText,
+        Icon::Flora,
+        Icon::FloraText,
+        Icon::Flowith,
+        Icon::FlowithText,
+        Icon::Flux,
+        Icon::FluxText,
+        Icon::Friendli,
+        Icon::FriendliText,
+        Icon::Gemini,
+        Icon::GeminiText,
+        Icon::Gemma,
+        Icon::GemmaText,
+        Icon::Giteeai,
+        Icon::GiteeaiText,
+        Icon::Github,
+        Icon::GithubText,
+        Icon::Githubcopilot,
+        Icon::GithubcopilotText,
+        Icon::Glama,
+        Icon::GlamaText,
+        Icon::Glif,
+        Icon::GlifText,
+        Icon::Glmv,
+        Icon::GlmvText,
+        Icon::Google,
+        Icon::GoogleBrand,
+        Icon::Googlecloud,
+        Icon::GooglecloudBrand,
+        Icon::Goose,
+        Icon::GooseText,
+        Icon::Gradio,
+        Icon::GradioText,
+        Icon::Greptile,
+        Icon::GreptileText,
+        Icon::Grok,
+        Icon::GrokText,
+        Icon::Groq,
+        Icon::GroqText,
+        Icon::Hailuo,
+        Icon::HailuoText,
+        Icon::Haiper,
+        Icon::HaiperText,
+        Icon::Hedra,
+        Icon::HedraText,
+        Icon::Higress,
+        Icon::HigressText,
+        Icon::Huawei,
+        Icon::HuaweiText,
+        Icon::HuaweiTextCn,
+        Icon::Huaweicloud,
+        Icon::HuaweicloudText,
+        Icon::HuaweicloudTextCn,
+        Icon::Huggingface,
+        Icon::HuggingfaceText,
+        Icon::Hunyuan,
+        Icon::HunyuanText,
+        Icon::Hyperbolic,
+        Icon::HyperbolicText,
+        Icon::Ibm,
+        Icon::IbmText,
+        Icon::Ideogram,
+        Icon::IdeogramText,
+        Icon::Iflytekcloud,
+        Icon::IflytekcloudText,
+        Icon::Inference,
+        Icon::InferenceText,
+        Icon::Infermatic,
+        Icon::InfermaticText,
+        Icon::Infinigence,
+        Icon::InfinigenceText,
+        Icon::InfinigenceTextCn,
+        Icon::Inflection,
+        Icon::InflectionText,
+        Icon::Internlm,
+        Icon::InternlmText,
+        Icon::Jimeng,
+        Icon::JimengText,
+        Icon::Jina,
+        Icon::JinaText,
+        Icon::Kimi,
+        Icon::KimiText,
+        Icon::Kling,
+        Icon::KlingText,
+        Icon::Kluster,
+        Icon::KlusterText,
+        Icon::Kolors,
+        Icon::KolorsText,
+        Icon::Krea,
+        Icon::KreaText,
+        Icon::Kwaipilot,
+        Icon::KwaipilotText,
+        Icon::Lambda,
+        Icon::LambdaText,
+        Icon::Langchain,
+        Icon::LangchainText,
+        Icon::Langfuse,
+        Icon::LangfuseText,
+        Icon::Langgraph,
+        Icon::LanggraphText,
+        Icon::Langsmith,
+        Icon::LangsmithText,
+        Icon::Leptonai,
+        Icon::LeptonaiText,
+        Icon::Lg,
+        Icon::LgText,
+        Icon::Lightricks,
+        Icon::LightricksText,
+        Icon::Liquid,
+        Icon::LiquidText,
+        Icon::Livekit,
+        Icon::LivekitText,
+        Icon::Llamaindex,
+        Icon::LlamaindexText,
+        Icon::Llava,
+        Icon::LlavaText,
+        Icon::Lmstudio,
+        Icon::LmstudioText,
+        Icon::Lobehub,
+        Icon::LobehubText,
+        Icon::Longcat,
+        Icon::LongcatText,
+        Icon::Lovable,
+        Icon::LovableText,
+        Icon::Luma,
+        Icon::LumaText,
+        Icon::Magic,
+        Icon::MagicText,
+        Icon::Make,
+        Icon::MakeText,
+        Icon::Manus,
+        Icon::ManusText,
+        Icon::Mastra,
+        Icon::MastraText,
+        Icon::Mcp,
+        Icon::McpText,
+        Icon::Mcpso,
+        Icon::McpsoText,
+        Icon::Menlo,
+        Icon::MenloText,
+        Icon::Meta,
+        Icon::MetaBrand,
+        Icon::MetaText,
+        Icon::Metaai,
+        Icon::MetaaiText,
+        Icon::Metagpt,
+        Icon::MetagptText,
+        Icon::Microsoft,
+        Icon::MicrosoftText,
+        Icon::Midjourney,
+        Icon::MidjourneyText,
+        Icon::Minimax,
+        Icon::MinimaxText,
+        Icon::Mistral,
+        Icon::MistralText,
+        Icon::Modelscope,
+        Icon::ModelscopeText,
+        Icon::Monica,
+        Icon::MonicaText,
+        Icon::Moonshot,
+        Icon::MoonshotText,
+        Icon::Myshell,
+        Icon::MyshellText,
+        Icon::N8n,
+        Icon::N8nText,
+        Icon::Nebius,
+        Icon::NebiusText,
+        Icon::Newapi,
+        Icon::NewapiText,
+        Icon::Notebooklm,
+        Icon::NotebooklmText,
+        Icon::Notion,
+        Icon::NotionText,
+        Icon::Nousresearch,
+        Icon::NousresearchText,
+        Icon::Nova,
+        Icon::NovaText,
+        Icon::Novelai,
+        Icon::NovelaiText,
+        Icon::Novita,
+        Icon::NovitaText,
+        Icon::Nplcloud,
+        Icon::NplcloudText,
+        Icon::Nvidia,
+        Icon::NvidiaText,
+        Icon::Ollama,
+        Icon::OllamaText,
+        Icon::Openai,
+        Icon::OpenaiText,
+        Icon::Openchat,
+        Icon::OpenchatText,
+        Icon::Openrouter,
+        Icon::OpenrouterText,
+        Icon::Openwebui,
+        Icon::OpenwebuiText,
+        Icon::Palm,
+        Icon::PalmText,
+        Icon::Parasail,
+        Icon::ParasailText,
+        Icon::Perplexity,
+        Icon::PerplexityText,
+        Icon::Phidata,
+        Icon::PhidataText,
+        Icon::Phind,
+        Icon::PhindText,
+        Icon::Pika,
+        Icon::PikaText,
+        Icon::Pixverse,
+        Icon::PixverseText,
+        Icon::Player2,
+        Icon::Player2Text,
+        Icon::Poe,
+        Icon::PoeText,
+        Icon::Pollinations,
+        Icon::PollinationsText,
+        Icon::Ppio,
+        Icon::PpioText,
+        Icon::PpioTextCn,
+        Icon::Pydanticai,
+        Icon::PydanticaiText,
+        Icon::Qingyan,
+        Icon::QingyanText,
+        Icon::Qiniu,
+        Icon::QiniuText,
+        Icon::Qwen,
+        Icon::QwenText,
+        Icon::Railway,
+        Icon::RailwayText,
+        Icon::Recraft,
+        Icon::RecraftText,
+        Icon::Replicate,
+        Icon::ReplicateBrand,
+        Icon::Replit,
+        Icon::ReplitText,
+        Icon::Rsshub,
+        Icon::RsshubText,
+        Icon::Runway,
+        Icon::RunwayText,
+        Icon::Rwkv,
+        Icon::RwkvText,
+        Icon::Sambanova,
+        Icon::SambanovaText,
+        Icon::Search1api,
+        Icon::Search1apiText,
+        Icon::Searchapi,
+        Icon::SearchapiText,
+        Icon::Sensenova,
+        Icon::SensenovaBrand,
+        Icon::SensenovaText,
+        Icon::Siliconcloud,
+        Icon::SiliconcloudText,
+        Icon::Skywork,
+        Icon::SkyworkText,
+        Icon::Smithery,
+        Icon::SmitheryText,
+        Icon::Snowflake,
+        Icon::SnowflakeText,
+        Icon::Sophnet,
+        Icon::SophnetText,
+        Icon::Sora,
+        Icon::SoraText,
+        Icon::Spark,
+        Icon::SparkText,
+        Icon::Stability,
+        Icon::StabilityBrand,
+        Icon::StabilityText,
+        Icon::Statecloud,
+        Icon::StatecloudText,
+        Icon::Stepfun,
+        Icon::StepfunText,
+        Icon::Straico,
+        Icon::StraicoText,
+        Icon::Submodel,
+        Icon::SubmodelText,
+        Icon::Suno,
+        Icon::SunoText,
+        Icon::Sync,
+        Icon::SyncText,
+        Icon::Targon,
+        Icon::TargonText,
+        Icon::Tavily,
+        Icon::TavilyText,
+        Icon::Tencent,
+        Icon::TencentBrand,
+        Icon::TencentText,
+        Icon::TencentTextCn,
+        Icon::Tencentcloud,
+        Icon::TencentcloudText,
+        Icon::Tiangong,
+        Icon::TiangongText,
+        Icon::Tii,
+        Icon::TiiText,
+        Icon::Together,
+        Icon::TogetherBrand,
+        Icon::TogetherText,
+        Icon::Topazlabs,
+        Icon::TopazlabsText,
+        Icon::Trae,
+        Icon::TraeText,
+        Icon::Tripo,
+        Icon::TripoText,
+        Icon::Turix,
+        Icon::TurixText,
+        Icon::Udio,
+        Icon::UdioText,
+        Icon::Unstructured,
+        Icon::UnstructuredText,
+        Icon::Upstage,
+        Icon::UpstageText,
+        Icon::V0,
+        Icon::Vectorizerai,
+        Icon::VectorizeraiText,
+        Icon::Vercel,
+        Icon::VercelText,
+        Icon::Vertexai,
+        Icon::VertexaiText,
+        Icon::Vidu,
+        Icon::ViduText,
+        Icon::Viggle,
+        Icon::ViggleText,
+        Icon::Vllm,
+        Icon::VllmText,
+        Icon::Volcengine,
+        Icon::VolcengineText,
+        Icon::Voyage,
+        Icon::VoyageText,
+        Icon::Wenxin,
+        Icon::WenxinText,
+        Icon::Windsurf,
+        Icon::WindsurfText,
+        Icon::Workersai,
+        Icon::WorkersaiText,
+        Icon::Xai,
+        Icon::XaiText,
+        Icon::Xinference,
+        Icon::XinferenceText,
+        Icon::Xuanyuan,
+        Icon::XuanyuanText,
+        Icon::Yandex,
+        Icon::YandexText,
+        Icon::Yi,
+        Icon::YiText,
+        Icon::Youmind,
+        Icon::YoumindText,
+        Icon::Yuanbao,
+        Icon::YuanbaoText,
+        Icon::Zai,
+        Icon::ZaiText,
+        Icon::Zapier,
+        Icon::ZapierText,
+        Icon::Zeabur,
+        Icon::ZeaburText,
+        Icon::Zenmux,
+        Icon::ZenmuxText,
+        Icon::Zeroone,
+        Icon::ZerooneText,
+        Icon::Zhipu,
+        Icon::ZhipuText,
+    ];
+
     pub fn name(self) -> &'static str {
         match self {
             Icon::Adobe => "adobe",
@@ -1107,31 +2211,862 @@ impl Icon {
         }
     }
 
+    /// Resolves this icon at a `(style, size)` known to exist, e.g. one listed in its docs.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the variant is unavailable; use [`Icon::try_icon`] to handle that case.
     pub fn icon(self, style: Style, size: Size) -> IconRef {
-        let name = self.name();
-        let available = icon_available(name).unwrap_or(&[]);
-        if !available.contains(&(style, size)) {
-            panic!(
-                "Icon '{}' is not available in {:?}/{:?}. Available: {:?}",
-                name, style, size, available
-            );
+        match self.try_icon(style, size) {
+            Ok(icon) => icon,
+            Err(err) => panic!(
+                "Icon '{}' is not available in {:?}/{:?}: {:?}",
+                self.name(),
+                style,
+                size,
+                err
+            ),
         }
-        let variant = variant_info(style, size).unwrap_or_else(|| {
-            panic!(
-                "Variant {:?}/{:?} is not available for pack {}",
-                style, size, PACK_ID
-            )
-        });
-        let codepoint = icon_codepoint(name, variant.key).unwrap_or_else(|| {
-            panic!("Icon '{}' is not available in {:?}/{:?}", name, style, size)
-        });
-        IconRef {
-            family: variant.family,
-            codepoint,
+    }
+
+    /// Resolves this icon at `(style, size)` without a name lookup, returning the same errors
+    /// as [`crate::try_icon`].
+    pub fn try_icon(self, style: Style, size: Size) -> Result<IconRef, crate::core::IconError> {
+        let (available, codepoints) = self.variant_tables();
+        let key = VariantKey { style, size };
+        crate::core::resolve_icon(
+            PACK_ID,
+            self.name(),
+            style,
+            size,
+            Some(available),
+            variant_info(style, size).map(|info| info.family),
+            codepoints
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|&(_, cp)| cp),
+        )
+        .map_err(crate::core::IconError::from)
+    }
+
+    fn variant_tables(self) -> IconVariantTables {
+        match self {
+            Icon::Adobe => (ICON_ADOBE_AVAILABLE, ICON_ADOBE_CODEPOINTS),
+            Icon::AdobeText => (ICON_ADOBE_TEXT_AVAILABLE, ICON_ADOBE_TEXT_CODEPOINTS),
+            Icon::Adobefirefly => (ICON_ADOBEFIREFLY_AVAILABLE, ICON_ADOBEFIREFLY_CODEPOINTS),
+            Icon::AdobefireflyText => (
+                ICON_ADOBEFIREFLY_TEXT_AVAILABLE,
+                ICON_ADOBEFIREFLY_TEXT_CODEPOINTS,
+            ),
+            Icon::Agui => (ICON_AGUI_AVAILABLE, ICON_AGUI_CODEPOINTS),
+            Icon::AguiText => (ICON_AGUI_TEXT_AVAILABLE, ICON_AGUI_TEXT_CODEPOINTS),
+            Icon::Ai21 => (ICON_AI_21_AVAILABLE, ICON_AI_21_CODEPOINTS),
+            Icon::Ai21Brand => (ICON_AI_21_BRAND_AVAILABLE, ICON_AI_21_BRAND_CODEPOINTS),
+            Icon::Ai21Text => (ICON_AI_21_TEXT_AVAILABLE, ICON_AI_21_TEXT_CODEPOINTS),
+            Icon::Ai302 => (ICON_AI_302_AVAILABLE, ICON_AI_302_CODEPOINTS),
+            Icon::Ai302Text => (ICON_AI_302_TEXT_AVAILABLE, ICON_AI_302_TEXT_CODEPOINTS),
+            Icon::Ai360 => (ICON_AI_360_AVAILABLE, ICON_AI_360_CODEPOINTS),
+            Icon::Ai360Text => (ICON_AI_360_TEXT_AVAILABLE, ICON_AI_360_TEXT_CODEPOINTS),
+            Icon::Aihubmix => (ICON_AIHUBMIX_AVAILABLE, ICON_AIHUBMIX_CODEPOINTS),
+            Icon::AihubmixText => (ICON_AIHUBMIX_TEXT_AVAILABLE, ICON_AIHUBMIX_TEXT_CODEPOINTS),
+            Icon::Aimass => (ICON_AIMASS_AVAILABLE, ICON_AIMASS_CODEPOINTS),
+            Icon::AimassText => (ICON_AIMASS_TEXT_AVAILABLE, ICON_AIMASS_TEXT_CODEPOINTS),
+            Icon::Aionlabs => (ICON_AIONLABS_AVAILABLE, ICON_AIONLABS_CODEPOINTS),
+            Icon::AionlabsText => (ICON_AIONLABS_TEXT_AVAILABLE, ICON_AIONLABS_TEXT_CODEPOINTS),
+            Icon::Aistudio => (ICON_AISTUDIO_AVAILABLE, ICON_AISTUDIO_CODEPOINTS),
+            Icon::AistudioText => (ICON_AISTUDIO_TEXT_AVAILABLE, ICON_AISTUDIO_TEXT_CODEPOINTS),
+            Icon::Akashchat => (ICON_AKASHCHAT_AVAILABLE, ICON_AKASHCHAT_CODEPOINTS),
+            Icon::AkashchatText => (
+                ICON_AKASHCHAT_TEXT_AVAILABLE,
+                ICON_AKASHCHAT_TEXT_CODEPOINTS,
+            ),
+            Icon::Alephalpha => (ICON_ALEPHALPHA_AVAILABLE, ICON_ALEPHALPHA_CODEPOINTS),
+            Icon::AlephalphaText => (
+                ICON_ALEPHALPHA_TEXT_AVAILABLE,
+                ICON_ALEPHALPHA_TEXT_CODEPOINTS,
+            ),
+            Icon::Alibaba => (ICON_ALIBABA_AVAILABLE, ICON_ALIBABA_CODEPOINTS),
+            Icon::AlibabaBrand => (ICON_ALIBABA_BRAND_AVAILABLE, ICON_ALIBABA_BRAND_CODEPOINTS),
+            Icon::AlibabaText => (ICON_ALIBABA_TEXT_AVAILABLE, ICON_ALIBABA_TEXT_CODEPOINTS),
+            Icon::AlibabaTextCn => (
+                ICON_ALIBABA_TEXT_CN_AVAILABLE,
+                ICON_ALIBABA_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Alibabacloud => (ICON_ALIBABACLOUD_AVAILABLE, ICON_ALIBABACLOUD_CODEPOINTS),
+            Icon::AlibabacloudText => (
+                ICON_ALIBABACLOUD_TEXT_AVAILABLE,
+                ICON_ALIBABACLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::AlibabacloudTextCn => (
+                ICON_ALIBABACLOUD_TEXT_CN_AVAILABLE,
+                ICON_ALIBABACLOUD_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Antgroup => (ICON_ANTGROUP_AVAILABLE, ICON_ANTGROUP_CODEPOINTS),
+            Icon::AntgroupBrand => (
+                ICON_ANTGROUP_BRAND_AVAILABLE,
+                ICON_ANTGROUP_BRAND_CODEPOINTS,
+            ),
+            Icon::AntgroupText => (ICON_ANTGROUP_TEXT_AVAILABLE, ICON_ANTGROUP_TEXT_CODEPOINTS),
+            Icon::AntgroupTextCn => (
+                ICON_ANTGROUP_TEXT_CN_AVAILABLE,
+                ICON_ANTGROUP_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Anthropic => (ICON_ANTHROPIC_AVAILABLE, ICON_ANTHROPIC_CODEPOINTS),
+            Icon::AnthropicText => (
+                ICON_ANTHROPIC_TEXT_AVAILABLE,
+                ICON_ANTHROPIC_TEXT_CODEPOINTS,
+            ),
+            Icon::Anyscale => (ICON_ANYSCALE_AVAILABLE, ICON_ANYSCALE_CODEPOINTS),
+            Icon::AnyscaleText => (ICON_ANYSCALE_TEXT_AVAILABLE, ICON_ANYSCALE_TEXT_CODEPOINTS),
+            Icon::Apple => (ICON_APPLE_AVAILABLE, ICON_APPLE_CODEPOINTS),
+            Icon::AppleText => (ICON_APPLE_TEXT_AVAILABLE, ICON_APPLE_TEXT_CODEPOINTS),
+            Icon::Assemblyai => (ICON_ASSEMBLYAI_AVAILABLE, ICON_ASSEMBLYAI_CODEPOINTS),
+            Icon::AssemblyaiText => (
+                ICON_ASSEMBLYAI_TEXT_AVAILABLE,
+                ICON_ASSEMBLYAI_TEXT_CODEPOINTS,
+            ),
+            Icon::Automatic => (ICON_AUTOMATIC_AVAILABLE, ICON_AUTOMATIC_CODEPOINTS),
+            Icon::AutomaticText => (
+                ICON_AUTOMATIC_TEXT_AVAILABLE,
+                ICON_AUTOMATIC_TEXT_CODEPOINTS,
+            ),
+            Icon::Aws => (ICON_AWS_AVAILABLE, ICON_AWS_CODEPOINTS),
+            Icon::AwsBrand => (ICON_AWS_BRAND_AVAILABLE, ICON_AWS_BRAND_CODEPOINTS),
+            Icon::AwsText => (ICON_AWS_TEXT_AVAILABLE, ICON_AWS_TEXT_CODEPOINTS),
+            Icon::Aya => (ICON_AYA_AVAILABLE, ICON_AYA_CODEPOINTS),
+            Icon::AyaText => (ICON_AYA_TEXT_AVAILABLE, ICON_AYA_TEXT_CODEPOINTS),
+            Icon::Azure => (ICON_AZURE_AVAILABLE, ICON_AZURE_CODEPOINTS),
+            Icon::AzureText => (ICON_AZURE_TEXT_AVAILABLE, ICON_AZURE_TEXT_CODEPOINTS),
+            Icon::Azureai => (ICON_AZUREAI_AVAILABLE, ICON_AZUREAI_CODEPOINTS),
+            Icon::AzureaiText => (ICON_AZUREAI_TEXT_AVAILABLE, ICON_AZUREAI_TEXT_CODEPOINTS),
+            Icon::Baai => (ICON_BAAI_AVAILABLE, ICON_BAAI_CODEPOINTS),
+            Icon::BaaiText => (ICON_BAAI_TEXT_AVAILABLE, ICON_BAAI_TEXT_CODEPOINTS),
+            Icon::Baichuan => (ICON_BAICHUAN_AVAILABLE, ICON_BAICHUAN_CODEPOINTS),
+            Icon::BaichuanText => (ICON_BAICHUAN_TEXT_AVAILABLE, ICON_BAICHUAN_TEXT_CODEPOINTS),
+            Icon::Baidu => (ICON_BAIDU_AVAILABLE, ICON_BAIDU_CODEPOINTS),
+            Icon::BaiduBrand => (ICON_BAIDU_BRAND_AVAILABLE, ICON_BAIDU_BRAND_CODEPOINTS),
+            Icon::BaiduText => (ICON_BAIDU_TEXT_AVAILABLE, ICON_BAIDU_TEXT_CODEPOINTS),
+            Icon::BaiduTextCn => (ICON_BAIDU_TEXT_CN_AVAILABLE, ICON_BAIDU_TEXT_CN_CODEPOINTS),
+            Icon::Baiducloud => (ICON_BAIDUCLOUD_AVAILABLE, ICON_BAIDUCLOUD_CODEPOINTS),
+            Icon::BaiducloudText => (
+                ICON_BAIDUCLOUD_TEXT_AVAILABLE,
+                ICON_BAIDUCLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::Bailian => (ICON_BAILIAN_AVAILABLE, ICON_BAILIAN_CODEPOINTS),
+            Icon::BailianText => (ICON_BAILIAN_TEXT_AVAILABLE, ICON_BAILIAN_TEXT_CODEPOINTS),
+            Icon::Baseten => (ICON_BASETEN_AVAILABLE, ICON_BASETEN_CODEPOINTS),
+            Icon::BasetenText => (ICON_BASETEN_TEXT_AVAILABLE, ICON_BASETEN_TEXT_CODEPOINTS),
+            Icon::Bedrock => (ICON_BEDROCK_AVAILABLE, ICON_BEDROCK_CODEPOINTS),
+            Icon::BedrockText => (ICON_BEDROCK_TEXT_AVAILABLE, ICON_BEDROCK_TEXT_CODEPOINTS),
+            Icon::Bfl => (ICON_BFL_AVAILABLE, ICON_BFL_CODEPOINTS),
+            Icon::BflText => (ICON_BFL_TEXT_AVAILABLE, ICON_BFL_TEXT_CODEPOINTS),
+            Icon::Bilibili => (ICON_BILIBILI_AVAILABLE, ICON_BILIBILI_CODEPOINTS),
+            Icon::BilibiliText => (ICON_BILIBILI_TEXT_AVAILABLE, ICON_BILIBILI_TEXT_CODEPOINTS),
+            Icon::Bilibiliindex => (ICON_BILIBILIINDEX_AVAILABLE, ICON_BILIBILIINDEX_CODEPOINTS),
+            Icon::BilibiliindexText => (
+                ICON_BILIBILIINDEX_TEXT_AVAILABLE,
+                ICON_BILIBILIINDEX_TEXT_CODEPOINTS,
+            ),
+            Icon::Bing => (ICON_BING_AVAILABLE, ICON_BING_CODEPOINTS),
+            Icon::BingText => (ICON_BING_TEXT_AVAILABLE, ICON_BING_TEXT_CODEPOINTS),
+            Icon::Burncloud => (ICON_BURNCLOUD_AVAILABLE, ICON_BURNCLOUD_CODEPOINTS),
+            Icon::BurncloudText => (
+                ICON_BURNCLOUD_TEXT_AVAILABLE,
+                ICON_BURNCLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::Bytedance => (ICON_BYTEDANCE_AVAILABLE, ICON_BYTEDANCE_CODEPOINTS),
+            Icon::BytedanceBrand => (
+                ICON_BYTEDANCE_BRAND_AVAILABLE,
+                ICON_BYTEDANCE_BRAND_CODEPOINTS,
+            ),
+            Icon::BytedanceText => (
+                ICON_BYTEDANCE_TEXT_AVAILABLE,
+                ICON_BYTEDANCE_TEXT_CODEPOINTS,
+            ),
+            Icon::BytedanceTextCn => (
+                ICON_BYTEDANCE_TEXT_CN_AVAILABLE,
+                ICON_BYTEDANCE_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Capcut => (ICON_CAPCUT_AVAILABLE, ICON_CAPCUT_CODEPOINTS),
+            Icon::CapcutText => (ICON_CAPCUT_TEXT_AVAILABLE, ICON_CAPCUT_TEXT_CODEPOINTS),
+            Icon::Centml => (ICON_CENTML_AVAILABLE, ICON_CENTML_CODEPOINTS),
+            Icon::CentmlBrand => (ICON_CENTML_BRAND_AVAILABLE, ICON_CENTML_BRAND_CODEPOINTS),
+            Icon::CentmlText => (ICON_CENTML_TEXT_AVAILABLE, ICON_CENTML_TEXT_CODEPOINTS),
+            Icon::Cerebras => (ICON_CEREBRAS_AVAILABLE, ICON_CEREBRAS_CODEPOINTS),
+            Icon::CerebrasBrand => (
+                ICON_CEREBRAS_BRAND_AVAILABLE,
+                ICON_CEREBRAS_BRAND_CODEPOINTS,
+            ),
+            Icon::CerebrasText => (ICON_CEREBRAS_TEXT_AVAILABLE, ICON_CEREBRAS_TEXT_CODEPOINTS),
+            Icon::Chatglm => (ICON_CHATGLM_AVAILABLE, ICON_CHATGLM_CODEPOINTS),
+            Icon::ChatglmText => (ICON_CHATGLM_TEXT_AVAILABLE, ICON_CHATGLM_TEXT_CODEPOINTS),
+            Icon::Civitai => (ICON_CIVITAI_AVAILABLE, ICON_CIVITAI_CODEPOINTS),
+            Icon::CivitaiText => (ICON_CIVITAI_TEXT_AVAILABLE, ICON_CIVITAI_TEXT_CODEPOINTS),
+            Icon::Claude => (ICON_CLAUDE_AVAILABLE, ICON_CLAUDE_CODEPOINTS),
+            Icon::ClaudeText => (ICON_CLAUDE_TEXT_AVAILABLE, ICON_CLAUDE_TEXT_CODEPOINTS),
+            Icon::Cline => (ICON_CLINE_AVAILABLE, ICON_CLINE_CODEPOINTS),
+            Icon::ClineText => (ICON_CLINE_TEXT_AVAILABLE, ICON_CLINE_TEXT_CODEPOINTS),
+            Icon::Clipdrop => (ICON_CLIPDROP_AVAILABLE, ICON_CLIPDROP_CODEPOINTS),
+            Icon::ClipdropText => (ICON_CLIPDROP_TEXT_AVAILABLE, ICON_CLIPDROP_TEXT_CODEPOINTS),
+            Icon::Cloudflare => (ICON_CLOUDFLARE_AVAILABLE, ICON_CLOUDFLARE_CODEPOINTS),
+            Icon::CloudflareText => (
+                ICON_CLOUDFLARE_TEXT_AVAILABLE,
+                ICON_CLOUDFLARE_TEXT_CODEPOINTS,
+            ),
+            Icon::Codegeex => (ICON_CODEGEEX_AVAILABLE, ICON_CODEGEEX_CODEPOINTS),
+            Icon::CodegeexText => (ICON_CODEGEEX_TEXT_AVAILABLE, ICON_CODEGEEX_TEXT_CODEPOINTS),
+            Icon::Cogvideo => (ICON_COGVIDEO_AVAILABLE, ICON_COGVIDEO_CODEPOINTS),
+            Icon::CogvideoText => (ICON_COGVIDEO_TEXT_AVAILABLE, ICON_COGVIDEO_TEXT_CODEPOINTS),
+            Icon::Cogview => (ICON_COGVIEW_AVAILABLE, ICON_COGVIEW_CODEPOINTS),
+            Icon::CogviewText => (ICON_COGVIEW_TEXT_AVAILABLE, ICON_COGVIEW_TEXT_CODEPOINTS),
+            Icon::Cohere => (ICON_COHERE_AVAILABLE, ICON_COHERE_CODEPOINTS),
+            Icon::CohereText => (ICON_COHERE_TEXT_AVAILABLE, ICON_COHERE_TEXT_CODEPOINTS),
+            Icon::Colab => (ICON_COLAB_AVAILABLE, ICON_COLAB_CODEPOINTS),
+            Icon::ColabText => (ICON_COLAB_TEXT_AVAILABLE, ICON_COLAB_TEXT_CODEPOINTS),
+            Icon::Cometapi => (ICON_COMETAPI_AVAILABLE, ICON_COMETAPI_CODEPOINTS),
+            Icon::CometapiText => (ICON_COMETAPI_TEXT_AVAILABLE, ICON_COMETAPI_TEXT_CODEPOINTS),
+            Icon::Comfyui => (ICON_COMFYUI_AVAILABLE, ICON_COMFYUI_CODEPOINTS),
+            Icon::ComfyuiText => (ICON_COMFYUI_TEXT_AVAILABLE, ICON_COMFYUI_TEXT_CODEPOINTS),
+            Icon::Commanda => (ICON_COMMANDA_AVAILABLE, ICON_COMMANDA_CODEPOINTS),
+            Icon::CommandaText => (ICON_COMMANDA_TEXT_AVAILABLE, ICON_COMMANDA_TEXT_CODEPOINTS),
+            Icon::Copilot => (ICON_COPILOT_AVAILABLE, ICON_COPILOT_CODEPOINTS),
+            Icon::CopilotText => (ICON_COPILOT_TEXT_AVAILABLE, ICON_COPILOT_TEXT_CODEPOINTS),
+            Icon::Copilotkit => (ICON_COPILOTKIT_AVAILABLE, ICON_COPILOTKIT_CODEPOINTS),
+            Icon::CopilotkitText => (
+                ICON_COPILOTKIT_TEXT_AVAILABLE,
+                ICON_COPILOTKIT_TEXT_CODEPOINTS,
+            ),
+            Icon::Coqui => (ICON_COQUI_AVAILABLE, ICON_COQUI_CODEPOINTS),
+            Icon::CoquiText => (ICON_COQUI_TEXT_AVAILABLE, ICON_COQUI_TEXT_CODEPOINTS),
+            Icon::Coze => (ICON_COZE_AVAILABLE, ICON_COZE_CODEPOINTS),
+            Icon::CozeText => (ICON_COZE_TEXT_AVAILABLE, ICON_COZE_TEXT_CODEPOINTS),
+            Icon::Crewai => (ICON_CREWAI_AVAILABLE, ICON_CREWAI_CODEPOINTS),
+            Icon::CrewaiBrand => (ICON_CREWAI_BRAND_AVAILABLE, ICON_CREWAI_BRAND_CODEPOINTS),
+            Icon::CrewaiText => (ICON_CREWAI_TEXT_AVAILABLE, ICON_CREWAI_TEXT_CODEPOINTS),
+            Icon::Crusoe => (ICON_CRUSOE_AVAILABLE, ICON_CRUSOE_CODEPOINTS),
+            Icon::CrusoeText => (ICON_CRUSOE_TEXT_AVAILABLE, ICON_CRUSOE_TEXT_CODEPOINTS),
+            Icon::Cursor => (ICON_CURSOR_AVAILABLE, ICON_CURSOR_CODEPOINTS),
+            Icon::CursorText => (ICON_CURSOR_TEXT_AVAILABLE, ICON_CURSOR_TEXT_CODEPOINTS),
+            Icon::Dalle => (ICON_DALLE_AVAILABLE, ICON_DALLE_CODEPOINTS),
+            Icon::DalleText => (ICON_DALLE_TEXT_AVAILABLE, ICON_DALLE_TEXT_CODEPOINTS),
+            Icon::Dbrx => (ICON_DBRX_AVAILABLE, ICON_DBRX_CODEPOINTS),
+            Icon::DbrxBrand => (ICON_DBRX_BRAND_AVAILABLE, ICON_DBRX_BRAND_CODEPOINTS),
+            Icon::DbrxText => (ICON_DBRX_TEXT_AVAILABLE, ICON_DBRX_TEXT_CODEPOINTS),
+            Icon::Deepai => (ICON_DEEPAI_AVAILABLE, ICON_DEEPAI_CODEPOINTS),
+            Icon::DeepaiText => (ICON_DEEPAI_TEXT_AVAILABLE, ICON_DEEPAI_TEXT_CODEPOINTS),
+            Icon::Deepinfra => (ICON_DEEPINFRA_AVAILABLE, ICON_DEEPINFRA_CODEPOINTS),
+            Icon::DeepinfraText => (
+                ICON_DEEPINFRA_TEXT_AVAILABLE,
+                ICON_DEEPINFRA_TEXT_CODEPOINTS,
+            ),
+            Icon::Deepl => (ICON_DEEPL_AVAILABLE, ICON_DEEPL_CODEPOINTS),
+            Icon::DeeplText => (ICON_DEEPL_TEXT_AVAILABLE, ICON_DEEPL_TEXT_CODEPOINTS),
+            Icon::Deepmind => (ICON_DEEPMIND_AVAILABLE, ICON_DEEPMIND_CODEPOINTS),
+            Icon::DeepmindText => (ICON_DEEPMIND_TEXT_AVAILABLE, ICON_DEEPMIND_TEXT_CODEPOINTS),
+            Icon::Deepseek => (ICON_DEEPSEEK_AVAILABLE, ICON_DEEPSEEK_CODEPOINTS),
+            Icon::DeepseekText => (ICON_DEEPSEEK_TEXT_AVAILABLE, ICON_DEEPSEEK_TEXT_CODEPOINTS),
+            Icon::Dify => (ICON_DIFY_AVAILABLE, ICON_DIFY_CODEPOINTS),
+            Icon::DifyText => (ICON_DIFY_TEXT_AVAILABLE, ICON_DIFY_TEXT_CODEPOINTS),
+            Icon::Doc2x => (ICON_DOC_2X_AVAILABLE, ICON_DOC_2X_CODEPOINTS),
+            Icon::Doc2xText => (ICON_DOC_2X_TEXT_AVAILABLE, ICON_DOC_2X_TEXT_CODEPOINTS),
+            Icon::Docsearch => (ICON_DOCSEARCH_AVAILABLE, ICON_DOCSEARCH_CODEPOINTS),
+            Icon::DocsearchText => (
+                ICON_DOCSEARCH_TEXT_AVAILABLE,
+                ICON_DOCSEARCH_TEXT_CODEPOINTS,
+            ),
+            Icon::Dolphin => (ICON_DOLPHIN_AVAILABLE, ICON_DOLPHIN_CODEPOINTS),
+            Icon::DolphinText => (ICON_DOLPHIN_TEXT_AVAILABLE, ICON_DOLPHIN_TEXT_CODEPOINTS),
+            Icon::Doubao => (ICON_DOUBAO_AVAILABLE, ICON_DOUBAO_CODEPOINTS),
+            Icon::DoubaoText => (ICON_DOUBAO_TEXT_AVAILABLE, ICON_DOUBAO_TEXT_CODEPOINTS),
+            Icon::Dreammachine => (ICON_DREAMMACHINE_AVAILABLE, ICON_DREAMMACHINE_CODEPOINTS),
+            Icon::DreammachineText => (
+                ICON_DREAMMACHINE_TEXT_AVAILABLE,
+                ICON_DREAMMACHINE_TEXT_CODEPOINTS,
+            ),
+            Icon::Elevenlabs => (ICON_ELEVENLABS_AVAILABLE, ICON_ELEVENLABS_CODEPOINTS),
+            Icon::ElevenlabsText => (
+                ICON_ELEVENLABS_TEXT_AVAILABLE,
+                ICON_ELEVENLABS_TEXT_CODEPOINTS,
+            ),
+            Icon::Elevenx => (ICON_ELEVENX_AVAILABLE, ICON_ELEVENX_CODEPOINTS),
+            Icon::ElevenxText => (ICON_ELEVENX_TEXT_AVAILABLE, ICON_ELEVENX_TEXT_CODEPOINTS),
+            Icon::Exa => (ICON_EXA_AVAILABLE, ICON_EXA_CODEPOINTS),
+            Icon::ExaText => (ICON_EXA_TEXT_AVAILABLE, ICON_EXA_TEXT_CODEPOINTS),
+            Icon::Fal => (ICON_FAL_AVAILABLE, ICON_FAL_CODEPOINTS),
+            Icon::FalText => (ICON_FAL_TEXT_AVAILABLE, ICON_FAL_TEXT_CODEPOINTS),
+            Icon::Fastgpt => (ICON_FASTGPT_AVAILABLE, ICON_FASTGPT_CODEPOINTS),
+            Icon::FastgptText => (ICON_FASTGPT_TEXT_AVAILABLE, ICON_FASTGPT_TEXT_CODEPOINTS),
+            Icon::Featherless => (ICON_FEATHERLESS_AVAILABLE, ICON_FEATHERLESS_CODEPOINTS),
+            Icon::FeatherlessText => (
+                ICON_FEATHERLESS_TEXT_AVAILABLE,
+                ICON_FEATHERLESS_TEXT_CODEPOINTS,
+            ),
+            Icon::Figma => (ICON_FIGMA_AVAILABLE, ICON_FIGMA_CODEPOINTS),
+            Icon::FigmaText => (ICON_FIGMA_TEXT_AVAILABLE, ICON_FIGMA_TEXT_CODEPOINTS),
+            Icon::Fireworks => (ICON_FIREWORKS_AVAILABLE, ICON_FIREWORKS_CODEPOINTS),
+            Icon::FireworksText => (
+                ICON_FIREWORKS_TEXT_AVAILABLE,
+                ICON_FIREWORKS_TEXT_CODEPOINTS,
+            ),
+            Icon::Fishaudio => (ICON_FISHAUDIO_AVAILABLE, ICON_FISHAUDIO_CODEPOINTS),
+            Icon::FishaudioText => (
+                ICON_FISHAUDIO_TEXT_AVAILABLE,
+                ICON_FISHAUDIO_TEXT_CODEPOINTS,
+            ),
+            Icon::Flora => (ICON_FLORA_AVAILABLE, ICON_FLORA_CODEPOINTS),
+            Icon::FloraText => (ICON_FLORA_TEXT_AVAILABLE, ICON_FLORA_TEXT_CODEPOINTS),
+            Icon::Flowith => (ICON_FLOWITH_AVAILABLE, ICON_FLOWITH_CODEPOINTS),
+            Icon::FlowithText => (ICON_FLOWITH_TEXT_AVAILABLE, ICON_FLOWITH_TEXT_CODEPOINTS),
+            Icon::Flux => (ICON_FLUX_AVAILABLE, ICON_FLUX_CODEPOINTS),
+            Icon::FluxText => (ICON_FLUX_TEXT_AVAILABLE, ICON_FLUX_TEXT_CODEPOINTS),
+            Icon::Friendli => (ICON_FRIENDLI_AVAILABLE, ICON_FRIENDLI_CODEPOINTS),
+            Icon::FriendliText => (ICON_FRIENDLI_TEXT_AVAILABLE, ICON_FRIENDLI_TEXT_CODEPOINTS),
+            Icon::Gemini => (ICON_GEMINI_AVAILABLE, ICON_GEMINI_CODEPOINTS),
+            Icon::GeminiText => (ICON_GEMINI_TEXT_AVAILABLE, ICON_GEMINI_TEXT_CODEPOINTS),
+            Icon::Gemma => (ICON_GEMMA_AVAILABLE, ICON_GEMMA_CODEPOINTS),
+            Icon::GemmaText => (ICON_GEMMA_TEXT_AVAILABLE, ICON_GEMMA_TEXT_CODEPOINTS),
+            Icon::Giteeai => (ICON_GITEEAI_AVAILABLE, ICON_GITEEAI_CODEPOINTS),
+            Icon::GiteeaiText => (ICON_GITEEAI_TEXT_AVAILABLE, ICON_GITEEAI_TEXT_CODEPOINTS),
+            Icon::Github => (ICON_GITHUB_AVAILABLE, ICON_GITHUB_CODEPOINTS),
+            Icon::GithubText => (ICON_GITHUB_TEXT_AVAILABLE, ICON_GITHUB_TEXT_CODEPOINTS),
+            Icon::Githubcopilot => (ICON_GITHUBCOPILOT_AVAILABLE, ICON_GITHUBCOPILOT_CODEPOINTS),
+            Icon::GithubcopilotText => (
+                ICON_GITHUBCOPILOT_TEXT_AVAILABLE,
+                ICON_GITHUBCOPILOT_TEXT_CODEPOINTS,
+            ),
+            Icon::Glama => (ICON_GLAMA_AVAILABLE, ICON_GLAMA_CODEPOINTS),
+            Icon::GlamaText => (ICON_GLAMA_TEXT_AVAILABLE, ICON_GLAMA_TEXT_CODEPOINTS),
+            Icon::Glif => (ICON_GLIF_AVAILABLE, ICON_GLIF_CODEPOINTS),
+            Icon::GlifText => (ICON_GLIF_TEXT_AVAILABLE, ICON_GLIF_TEXT_CODEPOINTS),
+            Icon::Glmv => (ICON_GLMV_AVAILABLE, ICON_GLMV_CODEPOINTS),
+            Icon::GlmvText => (ICON_GLMV_TEXT_AVAILABLE, ICON_GLMV_TEXT_CODEPOINTS),
+            Icon::Google => (ICON_GOOGLE_AVAILABLE, ICON_GOOGLE_CODEPOINTS),
+            Icon::GoogleBrand => (ICON_GOOGLE_BRAND_AVAILABLE, ICON_GOOGLE_BRAND_CODEPOINTS),
+            Icon::Googlecloud => (ICON_GOOGLECLOUD_AVAILABLE, ICON_GOOGLECLOUD_CODEPOINTS),
+            Icon::GooglecloudBrand => (
+                ICON_GOOGLECLOUD_BRAND_AVAILABLE,
+                ICON_GOOGLECLOUD_BRAND_CODEPOINTS,
+            ),
+            Icon::Goose => (ICON_GOOSE_AVAILABLE, ICON_GOOSE_CODEPOINTS),
+            Icon::GooseText => (ICON_GOOSE_TEXT_AVAILABLE, ICON_GOOSE_TEXT_CODEPOINTS),
+            Icon::Gradio => (ICON_GRADIO_AVAILABLE, ICON_GRADIO_CODEPOINTS),
+            Icon::GradioText => (ICON_GRADIO_TEXT_AVAILABLE, ICON_GRADIO_TEXT_CODEPOINTS),
+            Icon::Greptile => (ICON_GREPTILE_AVAILABLE, ICON_GREPTILE_CODEPOINTS),
+            Icon::GreptileText => (ICON_GREPTILE_TEXT_AVAILABLE, ICON_GREPTILE_TEXT_CODEPOINTS),
+            Icon::Grok => (ICON_GROK_AVAILABLE, ICON_GROK_CODEPOINTS),
+            Icon::GrokText => (ICON_GROK_TEXT_AVAILABLE, ICON_GROK_TEXT_CODEPOINTS),
+            Icon::Groq => (ICON_GROQ_AVAILABLE, ICON_GROQ_CODEPOINTS),
+            Icon::GroqText => (ICON_GROQ_TEXT_AVAILABLE, ICON_GROQ_TEXT_CODEPOINTS),
+            Icon::Hailuo => (ICON_HAILUO_AVAILABLE, ICON_HAILUO_CODEPOINTS),
+            Icon::HailuoText => (ICON_HAILUO_TEXT_AVAILABLE, ICON_HAILUO_TEXT_CODEPOINTS),
+            Icon::Haiper => (ICON_HAIPER_AVAILABLE, ICON_HAIPER_CODEPOINTS),
+            Icon::HaiperText => (ICON_HAIPER_TEXT_AVAILABLE, ICON_HAIPER_TEXT_CODEPOINTS),
+            Icon::Hedra => (ICON_HEDRA_AVAILABLE, ICON_HEDRA_CODEPOINTS),
+            Icon::HedraText => (ICON_HEDRA_TEXT_AVAILABLE, ICON_HEDRA_TEXT_CODEPOINTS),
+            Icon::Higress => (ICON_HIGRESS_AVAILABLE, ICON_HIGRESS_CODEPOINTS),
+            Icon::HigressText => (ICON_HIGRESS_TEXT_AVAILABLE, ICON_HIGRESS_TEXT_CODEPOINTS),
+            Icon::Huawei => (ICON_HUAWEI_AVAILABLE, ICON_HUAWEI_CODEPOINTS),
+            Icon::HuaweiText => (ICON_HUAWEI_TEXT_AVAILABLE, ICON_HUAWEI_TEXT_CODEPOINTS),
+            Icon::HuaweiTextCn => (
+                ICON_HUAWEI_TEXT_CN_AVAILABLE,
+                ICON_HUAWEI_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Huaweicloud => (ICON_HUAWEICLOUD_AVAILABLE, ICON_HUAWEICLOUD_CODEPOINTS),
+            Icon::HuaweicloudText => (
+                ICON_HUAWEICLOUD_TEXT_AVAILABLE,
+                ICON_HUAWEICLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::HuaweicloudTextCn => (
+                ICON_HUAWEICLOUD_TEXT_CN_AVAILABLE,
+                ICON_HUAWEICLOUD_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Huggingface => (ICON_HUGGINGFACE_AVAILABLE, ICON_HUGGINGFACE_CODEPOINTS),
+            Icon::HuggingfaceText => (
+                ICON_HUGGINGFACE_TEXT_AVAILABLE,
+                ICON_HUGGINGFACE_TEXT_CODEPOINTS,
+            ),
+            Icon::Hunyuan => (ICON_HUNYUAN_AVAILABLE, ICON_HUNYUAN_CODEPOINTS),
+            Icon::HunyuanText => (ICON_HUNYUAN_TEXT_AVAILABLE, ICON_HUNYUAN_TEXT_CODEPOINTS),
+            Icon::Hyperbolic => (ICON_HYPERBOLIC_AVAILABLE, ICON_HYPERBOLIC_CODEPOINTS),
+            Icon::HyperbolicText => (
+                ICON_HYPERBOLIC_TEXT_AVAILABLE,
+                ICON_HYPERBOLIC_TEXT_CODEPOINTS,
+            ),
+            Icon::Ibm => (ICON_IBM_AVAILABLE, ICON_IBM_CODEPOINTS),
+            Icon::IbmText => (ICON_IBM_TEXT_AVAILABLE, ICON_IBM_TEXT_CODEPOINTS),
+            Icon::Ideogram => (ICON_IDEOGRAM_AVAILABLE, ICON_IDEOGRAM_CODEPOINTS),
+            Icon::IdeogramText => (ICON_IDEOGRAM_TEXT_AVAILABLE, ICON_IDEOGRAM_TEXT_CODEPOINTS),
+            Icon::Iflytekcloud => (ICON_IFLYTEKCLOUD_AVAILABLE, ICON_IFLYTEKCLOUD_CODEPOINTS),
+            Icon::IflytekcloudText => (
+                ICON_IFLYTEKCLOUD_TEXT_AVAILABLE,
+                ICON_IFLYTEKCLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::Inference => (ICON_INFERENCE_AVAILABLE, ICON_INFERENCE_CODEPOINTS),
+            Icon::InferenceText => (
+                ICON_INFERENCE_TEXT_AVAILABLE,
+                ICON_INFERENCE_TEXT_CODEPOINTS,
+            ),
+            Icon::Infermatic => (ICON_INFERMATIC_AVAILABLE, ICON_INFERMATIC_CODEPOINTS),
+            Icon::InfermaticText => (
+                ICON_INFERMATIC_TEXT_AVAILABLE,
+                ICON_INFERMATIC_TEXT_CODEPOINTS,
+            ),
+            Icon::Infinigence => (ICON_INFINIGENCE_AVAILABLE, ICON_INFINIGENCE_CODEPOINTS),
+            Icon::InfinigenceText => (
+                ICON_INFINIGENCE_TEXT_AVAILABLE,
+                ICON_INFINIGENCE_TEXT_CODEPOINTS,
+            ),
+            Icon::InfinigenceTextCn => (
+                ICON_INFINIGENCE_TEXT_CN_AVAILABLE,
+                ICON_INFINIGENCE_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Inflection => (ICON_INFLECTION_AVAILABLE, ICON_INFLECTION_CODEPOINTS),
+            Icon::InflectionText => (
+                ICON_INFLECTION_TEXT_AVAILABLE,
+                ICON_INFLECTION_TEXT_CODEPOINTS,
+            ),
+            Icon::Internlm => (ICON_INTERNLM_AVAILABLE, ICON_INTERNLM_CODEPOINTS),
+            Icon::InternlmText => (ICON_INTERNLM_TEXT_AVAILABLE, ICON_INTERNLM_TEXT_CODEPOINTS),
+            Icon::Jimeng => (ICON_JIMENG_AVAILABLE, ICON_JIMENG_CODEPOINTS),
+            Icon::JimengText => (ICON_JIMENG_TEXT_AVAILABLE, ICON_JIMENG_TEXT_CODEPOINTS),
+            Icon::Jina => (ICON_JINA_AVAILABLE, ICON_JINA_CODEPOINTS),
+            Icon::JinaText => (ICON_JINA_TEXT_AVAILABLE, ICON_JINA_TEXT_CODEPOINTS),
+            Icon::Kimi => (ICON_KIMI_AVAILABLE, ICON_KIMI_CODEPOINTS),
+            Icon::KimiText => (ICON_KIMI_TEXT_AVAILABLE, ICON_KIMI_TEXT_CODEPOINTS),
+            Icon::Kling => (ICON_KLING_AVAILABLE, ICON_KLING_CODEPOINTS),
+            Icon::KlingText => (ICON_KLING_TEXT_AVAILABLE, ICON_KLING_TEXT_CODEPOINTS),
+            Icon::Kluster => (ICON_KLUSTER_AVAILABLE, ICON_KLUSTER_CODEPOINTS),
+            Icon::KlusterText => (ICON_KLUSTER_TEXT_AVAILABLE, ICON_KLUSTER_TEXT_CODEPOINTS),
+            Icon::Kolors => (ICON_KOLORS_AVAILABLE, ICON_KOLORS_CODEPOINTS),
+            Icon::KolorsText => (ICON_KOLORS_TEXT_AVAILABLE, ICON_KOLORS_TEXT_CODEPOINTS),
+            Icon::Krea => (ICON_KREA_AVAILABLE, ICON_KREA_CODEPOINTS),
+            Icon::KreaText => (ICON_KREA_TEXT_AVAILABLE, ICON_KREA_TEXT_CODEPOINTS),
+            Icon::Kwaipilot => (ICON_KWAIPILOT_AVAILABLE, ICON_KWAIPILOT_CODEPOINTS),
+            Icon::KwaipilotText => (
+                ICON_KWAIPILOT_TEXT_AVAILABLE,
+                ICON_KWAIPILOT_TEXT_CODEPOINTS,
+            ),
+            Icon::Lambda => (ICON_LAMBDA_AVAILABLE, ICON_LAMBDA_CODEPOINTS),
+            Icon::LambdaText => (ICON_LAMBDA_TEXT_AVAILABLE, ICON_LAMBDA_TEXT_CODEPOINTS),
+            Icon::Langchain => (ICON_LANGCHAIN_AVAILABLE, ICON_LANGCHAIN_CODEPOINTS),
+            Icon::LangchainText => (
+                ICON_LANGCHAIN_TEXT_AVAILABLE,
+                ICON_LANGCHAIN_TEXT_CODEPOINTS,
+            ),
+            Icon::Langfuse => (ICON_LANGFUSE_AVAILABLE, ICON_LANGFUSE_CODEPOINTS),
+            Icon::LangfuseText => (ICON_LANGFUSE_TEXT_AVAILABLE, ICON_LANGFUSE_TEXT_CODEPOINTS),
+            Icon::Langgraph => (ICON_LANGGRAPH_AVAILABLE, ICON_LANGGRAPH_CODEPOINTS),
+            Icon::LanggraphText => (
+                ICON_LANGGRAPH_TEXT_AVAILABLE,
+                ICON_LANGGRAPH_TEXT_CODEPOINTS,
+            ),
+            Icon::Langsmith => (ICON_LANGSMITH_AVAILABLE, ICON_LANGSMITH_CODEPOINTS),
+            Icon::LangsmithText => (
+                ICON_LANGSMITH_TEXT_AVAILABLE,
+                ICON_LANGSMITH_TEXT_CODEPOINTS,
+            ),
+            Icon::Leptonai => (ICON_LEPTONAI_AVAILABLE, ICON_LEPTONAI_CODEPOINTS),
+            Icon::LeptonaiText => (ICON_LEPTONAI_TEXT_AVAILABLE, ICON_LEPTONAI_TEXT_CODEPOINTS),
+            Icon::Lg => (ICON_LG_AVAILABLE, ICON_LG_CODEPOINTS),
+            Icon::LgText => (ICON_LG_TEXT_AVAILABLE, ICON_LG_TEXT_CODEPOINTS),
+            Icon::Lightricks => (ICON_LIGHTRICKS_AVAILABLE, ICON_LIGHTRICKS_CODEPOINTS),
+            Icon::LightricksText => (
+                ICON_LIGHTRICKS_TEXT_AVAILABLE,
+                ICON_LIGHTRICKS_TEXT_CODEPOINTS,
+            ),
+            Icon::Liquid => (ICON_LIQUID_AVAILABLE, ICON_LIQUID_CODEPOINTS),
+            Icon::LiquidText => (ICON_LIQUID_TEXT_AVAILABLE, ICON_LIQUID_TEXT_CODEPOINTS),
+            Icon::Livekit => (ICON_LIVEKIT_AVAILABLE, ICON_LIVEKIT_CODEPOINTS),
+            Icon::LivekitText => (ICON_LIVEKIT_TEXT_AVAILABLE, ICON_LIVEKIT_TEXT_CODEPOINTS),
+            Icon::Llamaindex => (ICON_LLAMAINDEX_AVAILABLE, ICON_LLAMAINDEX_CODEPOINTS),
+            Icon::LlamaindexText => (
+                ICON_LLAMAINDEX_TEXT_AVAILABLE,
+                ICON_LLAMAINDEX_TEXT_CODEPOINTS,
+            ),
+            Icon::Llava => (ICON_LLAVA_AVAILABLE, ICON_LLAVA_CODEPOINTS),
+            Icon::LlavaText => (ICON_LLAVA_TEXT_AVAILABLE, ICON_LLAVA_TEXT_CODEPOINTS),
+            Icon::Lmstudio => (ICON_LMSTUDIO_AVAILABLE, ICON_LMSTUDIO_CODEPOINTS),
+            Icon::LmstudioText => (ICON_LMSTUDIO_TEXT_AVAILABLE, ICON_LMSTUDIO_TEXT_CODEPOINTS),
+            Icon::Lobehub => (ICON_LOBEHUB_AVAILABLE, ICON_LOBEHUB_CODEPOINTS),
+            Icon::LobehubText => (ICON_LOBEHUB_TEXT_AVAILABLE, ICON_LOBEHUB_TEXT_CODEPOINTS),
+            Icon::Longcat => (ICON_LONGCAT_AVAILABLE, ICON_LONGCAT_CODEPOINTS),
+            Icon::LongcatText => (ICON_LONGCAT_TEXT_AVAILABLE, ICON_LONGCAT_TEXT_CODEPOINTS),
+            Icon::Lovable => (ICON_LOVABLE_AVAILABLE, ICON_LOVABLE_CODEPOINTS),
+            Icon::LovableText => (ICON_LOVABLE_TEXT_AVAILABLE, ICON_LOVABLE_TEXT_CODEPOINTS),
+            Icon::Luma => (ICON_LUMA_AVAILABLE, ICON_LUMA_CODEPOINTS),
+            Icon::LumaText => (ICON_LUMA_TEXT_AVAILABLE, ICON_LUMA_TEXT_CODEPOINTS),
+            Icon::Magic => (ICON_MAGIC_AVAILABLE, ICON_MAGIC_CODEPOINTS),
+            Icon::MagicText => (ICON_MAGIC_TEXT_AVAILABLE, ICON_MAGIC_TEXT_CODEPOINTS),
+            Icon::Make => (ICON_MAKE_AVAILABLE, ICON_MAKE_CODEPOINTS),
+            Icon::MakeText => (ICON_MAKE_TEXT_AVAILABLE, ICON_MAKE_TEXT_CODEPOINTS),
+            Icon::Manus => (ICON_MANUS_AVAILABLE, ICON_MANUS_CODEPOINTS),
+            Icon::ManusText => (ICON_MANUS_TEXT_AVAILABLE, ICON_MANUS_TEXT_CODEPOINTS),
+            Icon::Mastra => (ICON_MASTRA_AVAILABLE, ICON_MASTRA_CODEPOINTS),
+            Icon::MastraText => (ICON_MASTRA_TEXT_AVAILABLE, ICON_MASTRA_TEXT_CODEPOINTS),
+            Icon::Mcp => (ICON_MCP_AVAILABLE, ICON_MCP_CODEPOINTS),
+            Icon::McpText => (ICON_MCP_TEXT_AVAILABLE, ICON_MCP_TEXT_CODEPOINTS),
+            Icon::Mcpso => (ICON_MCPSO_AVAILABLE, ICON_MCPSO_CODEPOINTS),
+            Icon::McpsoText => (ICON_MCPSO_TEXT_AVAILABLE, ICON_MCPSO_TEXT_CODEPOINTS),
+            Icon::Menlo => (ICON_MENLO_AVAILABLE, ICON_MENLO_CODEPOINTS),
+            Icon::MenloText => (ICON_MENLO_TEXT_AVAILABLE, ICON_MENLO_TEXT_CODEPOINTS),
+            Icon::Meta => (ICON_META_AVAILABLE, ICON_META_CODEPOINTS),
+            Icon::MetaBrand => (ICON_META_BRAND_AVAILABLE, ICON_META_BRAND_CODEPOINTS),
+            Icon::MetaText => (ICON_META_TEXT_AVAILABLE, ICON_META_TEXT_CODEPOINTS),
+            Icon::Metaai => (ICON_METAAI_AVAILABLE, ICON_METAAI_CODEPOINTS),
+            Icon::MetaaiText => (ICON_METAAI_TEXT_AVAILABLE, ICON_METAAI_TEXT_CODEPOINTS),
+            Icon::Metagpt => (ICON_METAGPT_AVAILABLE, ICON_METAGPT_CODEPOINTS),
+            Icon::MetagptText => (ICON_METAGPT_TEXT_AVAILABLE, ICON_METAGPT_TEXT_CODEPOINTS),
+            Icon::Microsoft => (ICON_MICROSOFT_AVAILABLE, ICON_MICROSOFT_CODEPOINTS),
+            Icon::MicrosoftText => (
+                ICON_MICROSOFT_TEXT_AVAILABLE,
+                ICON_MICROSOFT_TEXT_CODEPOINTS,
+            ),
+            Icon::Midjourney => (ICON_MIDJOURNEY_AVAILABLE, ICON_MIDJOURNEY_CODEPOINTS),
+            Icon::MidjourneyText => (
+                ICON_MIDJOURNEY_TEXT_AVAILABLE,
+                ICON_MIDJOURNEY_TEXT_CODEPOINTS,
+            ),
+            Icon::Minimax => (ICON_MINIMAX_AVAILABLE, ICON_MINIMAX_CODEPOINTS),
+            Icon::MinimaxText => (ICON_MINIMAX_TEXT_AVAILABLE, ICON_MINIMAX_TEXT_CODEPOINTS),
+            Icon::Mistral => (ICON_MISTRAL_AVAILABLE, ICON_MISTRAL_CODEPOINTS),
+            Icon::MistralText => (ICON_MISTRAL_TEXT_AVAILABLE, ICON_MISTRAL_TEXT_CODEPOINTS),
+            Icon::Modelscope => (ICON_MODELSCOPE_AVAILABLE, ICON_MODELSCOPE_CODEPOINTS),
+            Icon::ModelscopeText => (
+                ICON_MODELSCOPE_TEXT_AVAILABLE,
+                ICON_MODELSCOPE_TEXT_CODEPOINTS,
+            ),
+            Icon::Monica => (ICON_MONICA_AVAILABLE, ICON_MONICA_CODEPOINTS),
+            Icon::MonicaText => (ICON_MONICA_TEXT_AVAILABLE, ICON_MONICA_TEXT_CODEPOINTS),
+            Icon::Moonshot => (ICON_MOONSHOT_AVAILABLE, ICON_MOONSHOT_CODEPOINTS),
+            Icon::MoonshotText => (ICON_MOONSHOT_TEXT_AVAILABLE, ICON_MOONSHOT_TEXT_CODEPOINTS),
+            Icon::Myshell => (ICON_MYSHELL_AVAILABLE, ICON_MYSHELL_CODEPOINTS),
+            Icon::MyshellText => (ICON_MYSHELL_TEXT_AVAILABLE, ICON_MYSHELL_TEXT_CODEPOINTS),
+            Icon::N8n => (ICON_N_8N_AVAILABLE, ICON_N_8N_CODEPOINTS),
+            Icon::N8nText => (ICON_N_8N_TEXT_AVAILABLE, ICON_N_8N_TEXT_CODEPOINTS),
+            Icon::Nebius => (ICON_NEBIUS_AVAILABLE, ICON_NEBIUS_CODEPOINTS),
+            Icon::NebiusText => (ICON_NEBIUS_TEXT_AVAILABLE, ICON_NEBIUS_TEXT_CODEPOINTS),
+            Icon::Newapi => (ICON_NEWAPI_AVAILABLE, ICON_NEWAPI_CODEPOINTS),
+            Icon::NewapiText => (ICON_NEWAPI_TEXT_AVAILABLE, ICON_NEWAPI_TEXT_CODEPOINTS),
+            Icon::Notebooklm => (ICON_NOTEBOOKLM_AVAILABLE, ICON_NOTEBOOKLM_CODEPOINTS),
+            Icon::NotebooklmText => (
+                ICON_NOTEBOOKLM_TEXT_AVAILABLE,
+                ICON_NOTEBOOKLM_TEXT_CODEPOINTS,
+            ),
+            Icon::Notion => (ICON_NOTION_AVAILABLE, ICON_NOTION_CODEPOINTS),
+            Icon::NotionText => (ICON_NOTION_TEXT_AVAILABLE, ICON_NOTION_TEXT_CODEPOINTS),
+            Icon::Nousresearch => (ICON_NOUSRESEARCH_AVAILABLE, ICON_NOUSRESEARCH_CODEPOINTS),
+            Icon::NousresearchText => (
+                ICON_NOUSRESEARCH_TEXT_AVAILABLE,
+                ICON_NOUSRESEARCH_TEXT_CODEPOINTS,
+            ),
+            Icon::Nova => (ICON_NOVA_AVAILABLE, ICON_NOVA_CODEPOINTS),
+            Icon::NovaText => (ICON_NOVA_TEXT_AVAILABLE, ICON_NOVA_TEXT_CODEPOINTS),
+            Icon::Novelai => (ICON_NOVELAI_AVAILABLE, ICON_NOVELAI_CODEPOINTS),
+            Icon::NovelaiText => (ICON_NOVELAI_TEXT_AVAILABLE, ICON_NOVELAI_TEXT_CODEPOINTS),
+            Icon::Novita => (ICON_NOVITA_AVAILABLE, ICON_NOVITA_CODEPOINTS),
+            Icon::NovitaText => (ICON_NOVITA_TEXT_AVAILABLE, ICON_NOVITA_TEXT_CODEPOINTS),
+            Icon::Nplcloud => (ICON_NPLCLOUD_AVAILABLE, ICON_NPLCLOUD_CODEPOINTS),
+            Icon::NplcloudText => (ICON_NPLCLOUD_TEXT_AVAILABLE, ICON_NPLCLOUD_TEXT_CODEPOINTS),
+            Icon::Nvidia => (ICON_NVIDIA_AVAILABLE, ICON_NVIDIA_CODEPOINTS),
+            Icon::NvidiaText => (ICON_NVIDIA_TEXT_AVAILABLE, ICON_NVIDIA_TEXT_CODEPOINTS),
+            Icon::Ollama => (ICON_OLLAMA_AVAILABLE, ICON_OLLAMA_CODEPOINTS),
+            Icon::OllamaText => (ICON_OLLAMA_TEXT_AVAILABLE, ICON_OLLAMA_TEXT_CODEPOINTS),
+            Icon::Openai => (ICON_OPENAI_AVAILABLE, ICON_OPENAI_CODEPOINTS),
+            Icon::OpenaiText => (ICON_OPENAI_TEXT_AVAILABLE, ICON_OPENAI_TEXT_CODEPOINTS),
+            Icon::Openchat => (ICON_OPENCHAT_AVAILABLE, ICON_OPENCHAT_CODEPOINTS),
+            Icon::OpenchatText => (ICON_OPENCHAT_TEXT_AVAILABLE, ICON_OPENCHAT_TEXT_CODEPOINTS),
+            Icon::Openrouter => (ICON_OPENROUTER_AVAILABLE, ICON_OPENROUTER_CODEPOINTS),
+            Icon::OpenrouterText => (
+                ICON_OPENROUTER_TEXT_AVAILABLE,
+                ICON_OPENROUTER_TEXT_CODEPOINTS,
+            ),
+            Icon::Openwebui => (ICON_OPENWEBUI_AVAILABLE, ICON_OPENWEBUI_CODEPOINTS),
+            Icon::OpenwebuiText => (
+                ICON_OPENWEBUI_TEXT_AVAILABLE,
+                ICON_OPENWEBUI_TEXT_CODEPOINTS,
+            ),
+            Icon::Palm => (ICON_PALM_AVAILABLE, ICON_PALM_CODEPOINTS),
+            Icon::PalmText => (ICON_PALM_TEXT_AVAILABLE, ICON_PALM_TEXT_CODEPOINTS),
+            Icon::Parasail => (ICON_PARASAIL_AVAILABLE, ICON_PARASAIL_CODEPOINTS),
+            Icon::ParasailText => (ICON_PARASAIL_TEXT_AVAILABLE, ICON_PARASAIL_TEXT_CODEPOINTS),
+            Icon::Perplexity => (ICON_PERPLEXITY_AVAILABLE, ICON_PERPLEXITY_CODEPOINTS),
+            Icon::PerplexityText => (
+                ICON_PERPLEXITY_TEXT_AVAILABLE,
+                ICON_PERPLEXITY_TEXT_CODEPOINTS,
+            ),
+            Icon::Phidata => (ICON_PHIDATA_AVAILABLE, ICON_PHIDATA_CODEPOINTS),
+            Icon::PhidataText => (ICON_PHIDATA_TEXT_AVAILABLE, ICON_PHIDATA_TEXT_CODEPOINTS),
+            Icon::Phind => (ICON_PHIND_AVAILABLE, ICON_PHIND_CODEPOINTS),
+            Icon::PhindText => (ICON_PHIND_TEXT_AVAILABLE, ICON_PHIND_TEXT_CODEPOINTS),
+            Icon::Pika => (ICON_PIKA_AVAILABLE, ICON_PIKA_CODEPOINTS),
+            Icon::PikaText => (ICON_PIKA_TEXT_AVAILABLE, ICON_PIKA_TEXT_CODEPOINTS),
+            Icon::Pixverse => (ICON_PIXVERSE_AVAILABLE, ICON_PIXVERSE_CODEPOINTS),
+            Icon::PixverseText => (ICON_PIXVERSE_TEXT_AVAILABLE, ICON_PIXVERSE_TEXT_CODEPOINTS),
+            Icon::Player2 => (ICON_PLAYER_2_AVAILABLE, ICON_PLAYER_2_CODEPOINTS),
+            Icon::Player2Text => (ICON_PLAYER_2_TEXT_AVAILABLE, ICON_PLAYER_2_TEXT_CODEPOINTS),
+            Icon::Poe => (ICON_POE_AVAILABLE, ICON_POE_CODEPOINTS),
+            Icon::PoeText => (ICON_POE_TEXT_AVAILABLE, ICON_POE_TEXT_CODEPOINTS),
+            Icon::Pollinations => (ICON_POLLINATIONS_AVAILABLE, ICON_POLLINATIONS_CODEPOINTS),
+            Icon::PollinationsText => (
+                ICON_POLLINATIONS_TEXT_AVAILABLE,
+                ICON_POLLINATIONS_TEXT_CODEPOINTS,
+            ),
+            Icon::Ppio => (ICON_PPIO_AVAILABLE, ICON_PPIO_CODEPOINTS),
+            Icon::PpioText => (ICON_PPIO_TEXT_AVAILABLE, ICON_PPIO_TEXT_CODEPOINTS),
+            Icon::PpioTextCn => (ICON_PPIO_TEXT_CN_AVAILABLE, ICON_PPIO_TEXT_CN_CODEPOINTS),
+            Icon::Pydanticai => (ICON_PYDANTICAI_AVAILABLE, ICON_PYDANTICAI_CODEPOINTS),
+            Icon::PydanticaiText => (
+                ICON_PYDANTICAI_TEXT_AVAILABLE,
+                ICON_PYDANTICAI_TEXT_CODEPOINTS,
+            ),
+            Icon::Qingyan => (ICON_QINGYAN_AVAILABLE, ICON_QINGYAN_CODEPOINTS),
+            Icon::QingyanText => (ICON_QINGYAN_TEXT_AVAILABLE, ICON_QINGYAN_TEXT_CODEPOINTS),
+            Icon::Qiniu => (ICON_QINIU_AVAILABLE, ICON_QINIU_CODEPOINTS),
+            Icon::QiniuText => (ICON_QINIU_TEXT_AVAILABLE, ICON_QINIU_TEXT_CODEPOINTS),
+            Icon::Qwen => (ICON_QWEN_AVAILABLE, ICON_QWEN_CODEPOINTS),
+            Icon::QwenText => (ICON_QWEN_TEXT_AVAILABLE, ICON_QWEN_TEXT_CODEPOINTS),
+            Icon::Railway => (ICON_RAILWAY_AVAILABLE, ICON_RAILWAY_CODEPOINTS),
+            Icon::RailwayText => (ICON_RAILWAY_TEXT_AVAILABLE, ICON_RAILWAY_TEXT_CODEPOINTS),
+            Icon::Recraft => (ICON_RECRAFT_AVAILABLE, ICON_RECRAFT_CODEPOINTS),
+            Icon::RecraftText => (ICON_RECRAFT_TEXT_AVAILABLE, ICON_RECRAFT_TEXT_CODEPOINTS),
+            Icon::Replicate => (ICON_REPLICATE_AVAILABLE, ICON_REPLICATE_CODEPOINTS),
+            Icon::ReplicateBrand => (
+                ICON_REPLICATE_BRAND_AVAILABLE,
+                ICON_REPLICATE_BRAND_CODEPOINTS,
+            ),
+            Icon::Replit => (ICON_REPLIT_AVAILABLE, ICON_REPLIT_CODEPOINTS),
+            Icon::ReplitText => (ICON_REPLIT_TEXT_AVAILABLE, ICON_REPLIT_TEXT_CODEPOINTS),
+            Icon::Rsshub => (ICON_RSSHUB_AVAILABLE, ICON_RSSHUB_CODEPOINTS),
+            Icon::RsshubText => (ICON_RSSHUB_TEXT_AVAILABLE, ICON_RSSHUB_TEXT_CODEPOINTS),
+            Icon::Runway => (ICON_RUNWAY_AVAILABLE, ICON_RUNWAY_CODEPOINTS),
+            Icon::RunwayText => (ICON_RUNWAY_TEXT_AVAILABLE, ICON_RUNWAY_TEXT_CODEPOINTS),
+            Icon::Rwkv => (ICON_RWKV_AVAILABLE, ICON_RWKV_CODEPOINTS),
+            Icon::RwkvText => (ICON_RWKV_TEXT_AVAILABLE, ICON_RWKV_TEXT_CODEPOINTS),
+            Icon::Sambanova => (ICON_SAMBANOVA_AVAILABLE, ICON_SAMBANOVA_CODEPOINTS),
+            Icon::SambanovaText => (
+                ICON_SAMBANOVA_TEXT_AVAILABLE,
+                ICON_SAMBANOVA_TEXT_CODEPOINTS,
+            ),
+            Icon::Search1api => (ICON_SEARCH_1API_AVAILABLE, ICON_SEARCH_1API_CODEPOINTS),
+            Icon::Search1apiText => (
+                ICON_SEARCH_1API_TEXT_AVAILABLE,
+                ICON_SEARCH_1API_TEXT_CODEPOINTS,
+            ),
+            Icon::Searchapi => (ICON_SEARCHAPI_AVAILABLE, ICON_SEARCHAPI_CODEPOINTS),
+            Icon::SearchapiText => (
+                ICON_SEARCHAPI_TEXT_AVAILABLE,
+                ICON_SEARCHAPI_TEXT_CODEPOINTS,
+            ),
+            Icon::Sensenova => (ICON_SENSENOVA_AVAILABLE, ICON_SENSENOVA_CODEPOINTS),
+            Icon::SensenovaBrand => (
+                ICON_SENSENOVA_BRAND_AVAILABLE,
+                ICON_SENSENOVA_BRAND_CODEPOINTS,
+            ),
+            Icon::SensenovaText => (
+                ICON_SENSENOVA_TEXT_AVAILABLE,
+                ICON_SENSENOVA_TEXT_CODEPOINTS,
+            ),
+            Icon::Siliconcloud => (ICON_SILICONCLOUD_AVAILABLE, ICON_SILICONCLOUD_CODEPOINTS),
+            Icon::SiliconcloudText => (
+                ICON_SILICONCLOUD_TEXT_AVAILABLE,
+                ICON_SILICONCLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::Skywork => (ICON_SKYWORK_AVAILABLE, ICON_SKYWORK_CODEPOINTS),
+            Icon::SkyworkText => (ICON_SKYWORK_TEXT_AVAILABLE, ICON_SKYWORK_TEXT_CODEPOINTS),
+            Icon::Smithery => (ICON_SMITHERY_AVAILABLE, ICON_SMITHERY_CODEPOINTS),
+            Icon::SmitheryText => (ICON_SMITHERY_TEXT_AVAILABLE, ICON_SMITHERY_TEXT_CODEPOINTS),
+            Icon::Snowflake => (ICON_SNOWFLAKE_AVAILABLE, ICON_SNOWFLAKE_CODEPOINTS),
+            Icon::SnowflakeText => (
+                ICON_SNOWFLAKE_TEXT_AVAILABLE,
+                ICON_SNOWFLAKE_TEXT_CODEPOINTS,
+            ),
+            Icon::Sophnet => (ICON_SOPHNET_AVAILABLE, ICON_SOPHNET_CODEPOINTS),
+            Icon::SophnetText => (ICON_SOPHNET_TEXT_AVAILABLE, ICON_SOPHNET_TEXT_CODEPOINTS),
+            Icon::Sora => (ICON_SORA_AVAILABLE, ICON_SORA_CODEPOINTS),
+            Icon::SoraText => (ICON_SORA_TEXT_AVAILABLE, ICON_SORA_TEXT_CODEPOINTS),
+            Icon::Spark => (ICON_SPARK_AVAILABLE, ICON_SPARK_CODEPOINTS),
+            Icon::SparkText => (ICON_SPARK_TEXT_AVAILABLE, ICON_SPARK_TEXT_CODEPOINTS),
+            Icon::Stability => (ICON_STABILITY_AVAILABLE, ICON_STABILITY_CODEPOINTS),
+            Icon::StabilityBrand => (
+                ICON_STABILITY_BRAND_AVAILABLE,
+                ICON_STABILITY_BRAND_CODEPOINTS,
+            ),
+            Icon::StabilityText => (
+                ICON_STABILITY_TEXT_AVAILABLE,
+                ICON_STABILITY_TEXT_CODEPOINTS,
+            ),
+            Icon::Statecloud => (ICON_STATECLOUD_AVAILABLE, ICON_STATECLOUD_CODEPOINTS),
+            Icon::StatecloudText => (
+                ICON_STATECLOUD_TEXT_AVAILABLE,
+                ICON_STATECLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::Stepfun => (ICON_STEPFUN_AVAILABLE, ICON_STEPFUN_CODEPOINTS),
+            Icon::StepfunText => (ICON_STEPFUN_TEXT_AVAILABLE, ICON_STEPFUN_TEXT_CODEPOINTS),
+            Icon::Straico => (ICON_STRAICO_AVAILABLE, ICON_STRAICO_CODEPOINTS),
+            Icon::StraicoText => (ICON_STRAICO_TEXT_AVAILABLE, ICON_STRAICO_TEXT_CODEPOINTS),
+            Icon::Submodel => (ICON_SUBMODEL_AVAILABLE, ICON_SUBMODEL_CODEPOINTS),
+            Icon::SubmodelText => (ICON_SUBMODEL_TEXT_AVAILABLE, ICON_SUBMODEL_TEXT_CODEPOINTS),
+            Icon::Suno => (ICON_SUNO_AVAILABLE, ICON_SUNO_CODEPOINTS),
+            Icon::SunoText => (ICON_SUNO_TEXT_AVAILABLE, ICON_SUNO_TEXT_CODEPOINTS),
+            Icon::Sync => (ICON_SYNC_AVAILABLE, ICON_SYNC_CODEPOINTS),
+            Icon::SyncText => (ICON_SYNC_TEXT_AVAILABLE, ICON_SYNC_TEXT_CODEPOINTS),
+            Icon::Targon => (ICON_TARGON_AVAILABLE, ICON_TARGON_CODEPOINTS),
+            Icon::TargonText => (ICON_TARGON_TEXT_AVAILABLE, ICON_TARGON_TEXT_CODEPOINTS),
+            Icon::Tavily => (ICON_TAVILY_AVAILABLE, ICON_TAVILY_CODEPOINTS),
+            Icon::TavilyText => (ICON_TAVILY_TEXT_AVAILABLE, ICON_TAVILY_TEXT_CODEPOINTS),
+            Icon::Tencent => (ICON_TENCENT_AVAILABLE, ICON_TENCENT_CODEPOINTS),
+            Icon::TencentBrand => (ICON_TENCENT_BRAND_AVAILABLE, ICON_TENCENT_BRAND_CODEPOINTS),
+            Icon::TencentText => (ICON_TENCENT_TEXT_AVAILABLE, ICON_TENCENT_TEXT_CODEPOINTS),
+            Icon::TencentTextCn => (
+                ICON_TENCENT_TEXT_CN_AVAILABLE,
+                ICON_TENCENT_TEXT_CN_CODEPOINTS,
+            ),
+            Icon::Tencentcloud => (ICON_TENCENTCLOUD_AVAILABLE, ICON_TENCENTCLOUD_CODEPOINTS),
+            Icon::TencentcloudText => (
+                ICON_TENCENTCLOUD_TEXT_AVAILABLE,
+                ICON_TENCENTCLOUD_TEXT_CODEPOINTS,
+            ),
+            Icon::Tiangong => (ICON_TIANGONG_AVAILABLE, ICON_TIANGONG_CODEPOINTS),
+            Icon::TiangongText => (ICON_TIANGONG_TEXT_AVAILABLE, ICON_TIANGONG_TEXT_CODEPOINTS),
+            Icon::Tii => (ICON_TII_AVAILABLE, ICON_TII_CODEPOINTS),
+            Icon::TiiText => (ICON_TII_TEXT_AVAILABLE, ICON_TII_TEXT_CODEPOINTS),
+            Icon::Together => (ICON_TOGETHER_AVAILABLE, ICON_TOGETHER_CODEPOINTS),
+            Icon::TogetherBrand => (
+                ICON_TOGETHER_BRAND_AVAILABLE,
+                ICON_TOGETHER_BRAND_CODEPOINTS,
+            ),
+            Icon::TogetherText => (ICON_TOGETHER_TEXT_AVAILABLE, ICON_TOGETHER_TEXT_CODEPOINTS),
+            Icon::Topazlabs => (ICON_TOPAZLABS_AVAILABLE, ICON_TOPAZLABS_CODEPOINTS),
+            Icon::TopazlabsText => (
+                ICON_TOPAZLABS_TEXT_AVAILABLE,
+                ICON_TOPAZLABS_TEXT_CODEPOINTS,
+            ),
+            Icon::Trae => (ICON_TRAE_AVAILABLE, ICON_TRAE_CODEPOINTS),
+            Icon::TraeText => (ICON_TRAE_TEXT_AVAILABLE, ICON_TRAE_TEXT_CODEPOINTS),
+            Icon::Tripo => (ICON_TRIPO_AVAILABLE, ICON_TRIPO_CODEPOINTS),
+            Icon::TripoText => (ICON_TRIPO_TEXT_AVAILABLE, ICON_TRIPO_TEXT_CODEPOINTS),
+            Icon::Turix => (ICON_TURIX_AVAILABLE, ICON_TURIX_CODEPOINTS),
+            Icon::TurixText => (ICON_TURIX_TEXT_AVAILABLE, ICON_TURIX_TEXT_CODEPOINTS),
+            Icon::Udio => (ICON_UDIO_AVAILABLE, ICON_UDIO_CODEPOINTS),
+            Icon::UdioText => (ICON_UDIO_TEXT_AVAILABLE, ICON_UDIO_TEXT_CODEPOINTS),
+            Icon::Unstructured => (ICON_UNSTRUCTURED_AVAILABLE, ICON_UNSTRUCTURED_CODEPOINTS),
+            Icon::UnstructuredText => (
+                ICON_UNSTRUCTURED_TEXT_AVAILABLE,
+                ICON_UNSTRUCTURED_TEXT_CODEPOINTS,
+            ),
+            Icon::Upstage => (ICON_UPSTAGE_AVAILABLE, ICON_UPSTAGE_CODEPOINTS),
+            Icon::UpstageText => (ICON_UPSTAGE_TEXT_AVAILABLE, ICON_UPSTAGE_TEXT_CODEPOINTS),
+            Icon::V0 => (ICON_V_0_AVAILABLE, ICON_V_0_CODEPOINTS),
+            Icon::Vectorizerai => (ICON_VECTORIZERAI_AVAILABLE, ICON_VECTORIZERAI_CODEPOINTS),
+            Icon::VectorizeraiText => (
+                ICON_VECTORIZERAI_TEXT_AVAILABLE,
+                ICON_VECTORIZERAI_TEXT_CODEPOINTS,
+            ),
+            Icon::Vercel => (ICON_VERCEL_AVAILABLE, ICON_VERCEL_CODEPOINTS),
+            Icon::VercelText => (ICON_VERCEL_TEXT_AVAILABLE, ICON_VERCEL_TEXT_CODEPOINTS),
+            Icon::Vertexai => (ICON_VERTEXAI_AVAILABLE, ICON_VERTEXAI_CODEPOINTS),
+            Icon::VertexaiText => (ICON_VERTEXAI_TEXT_AVAILABLE, ICON_VERTEXAI_TEXT_CODEPOINTS),
+            Icon::Vidu => (ICON_VIDU_AVAILABLE, ICON_VIDU_CODEPOINTS),
+            Icon::ViduText => (ICON_VIDU_TEXT_AVAILABLE, ICON_VIDU_TEXT_CODEPOINTS),
+            Icon::Viggle => (ICON_VIGGLE_AVAILABLE, ICON_VIGGLE_CODEPOINTS),
+            Icon::ViggleText => (ICON_VIGGLE_TEXT_AVAILABLE, ICON_VIGGLE_TEXT_CODEPOINTS),
+            Icon::Vllm => (ICON_VLLM_AVAILABLE, ICON_VLLM_CODEPOINTS),
+            Icon::VllmText => (ICON_VLLM_TEXT_AVAILABLE, ICON_VLLM_TEXT_CODEPOINTS),
+            Icon::Volcengine => (ICON_VOLCENGINE_AVAILABLE, ICON_VOLCENGINE_CODEPOINTS),
+            Icon::VolcengineText => (
+                ICON_VOLCENGINE_TEXT_AVAILABLE,
+                ICON_VOLCENGINE_TEXT_CODEPOINTS,
+            ),
+            Icon::Voyage => (ICON_VOYAGE_AVAILABLE, ICON_VOYAGE_CODEPOINTS),
+            Icon::VoyageText => (ICON_VOYAGE_TEXT_AVAILABLE, ICON_VOYAGE_TEXT_CODEPOINTS),
+            Icon::Wenxin => (ICON_WENXIN_AVAILABLE, ICON_WENXIN_CODEPOINTS),
+            Icon::WenxinText => (ICON_WENXIN_TEXT_AVAILABLE, ICON_WENXIN_TEXT_CODEPOINTS),
+            Icon::Windsurf => (ICON_WINDSURF_AVAILABLE, ICON_WINDSURF_CODEPOINTS),
+            Icon::WindsurfText => (ICON_WINDSURF_TEXT_AVAILABLE, ICON_WINDSURF_TEXT_CODEPOINTS),
+            Icon::Workersai => (ICON_WORKERSAI_AVAILABLE, ICON_WORKERSAI_CODEPOINTS),
+            Icon::WorkersaiText => (
+                ICON_WORKERSAI_TEXT_AVAILABLE,
+                ICON_WORKERSAI_TEXT_CODEPOINTS,
+            ),
+            Icon::Xai => (ICON_XAI_AVAILABLE, ICON_XAI_CODEPOINTS),
+            Icon::XaiText => (ICON_XAI_TEXT_AVAILABLE, ICON_XAI_TEXT_CODEPOINTS),
+            Icon::Xinference => (ICON_XINFERENCE_AVAILABLE, ICON_XINFERENCE_CODEPOINTS),
+            Icon::XinferenceText => (
+                ICON_XINFERENCE_TEXT_AVAILABLE,
+                ICON_XINFERENCE_TEXT_CODEPOINTS,
+            ),
+            Icon::Xuanyuan => (ICON_XUANYUAN_AVAILABLE, ICON_XUANYUAN_CODEPOINTS),
+            Icon::XuanyuanText => (ICON_XUANYUAN_TEXT_AVAILABLE, ICON_XUANYUAN_TEXT_CODEPOINTS),
+            Icon::Yandex => (ICON_YANDEX_AVAILABLE, ICON_YANDEX_CODEPOINTS),
+            Icon::YandexText => (ICON_YANDEX_TEXT_AVAILABLE, ICON_YANDEX_TEXT_CODEPOINTS),
+            Icon::Yi => (ICON_YI_AVAILABLE, ICON_YI_CODEPOINTS),
+            Icon::YiText => (ICON_YI_TEXT_AVAILABLE, ICON_YI_TEXT_CODEPOINTS),
+            Icon::Youmind => (ICON_YOUMIND_AVAILABLE, ICON_YOUMIND_CODEPOINTS),
+            Icon::YoumindText => (ICON_YOUMIND_TEXT_AVAILABLE, ICON_YOUMIND_TEXT_CODEPOINTS),
+            Icon::Yuanbao => (ICON_YUANBAO_AVAILABLE, ICON_YUANBAO_CODEPOINTS),
+            Icon::YuanbaoText => (ICON_YUANBAO_TEXT_AVAILABLE, ICON_YUANBAO_TEXT_CODEPOINTS),
+            Icon::Zai => (ICON_ZAI_AVAILABLE, ICON_ZAI_CODEPOINTS),
+            Icon::ZaiText => (ICON_ZAI_TEXT_AVAILABLE, ICON_ZAI_TEXT_CODEPOINTS),
+            Icon::Zapier => (ICON_ZAPIER_AVAILABLE, ICON_ZAPIER_CODEPOINTS),
+            Icon::ZapierText => (ICON_ZAPIER_TEXT_AVAILABLE, ICON_ZAPIER_TEXT_CODEPOINTS),
+            Icon::Zeabur => (ICON_ZEABUR_AVAILABLE, ICON_ZEABUR_CODEPOINTS),
+            Icon::ZeaburText => (ICON_ZEABUR_TEXT_AVAILABLE, ICON_ZEABUR_TEXT_CODEPOINTS),
+            Icon::Zenmux => (ICON_ZENMUX_AVAILABLE, ICON_ZENMUX_CODEPOINTS),
+            Icon::ZenmuxText => (ICON_ZENMUX_TEXT_AVAILABLE, ICON_ZENMUX_TEXT_CODEPOINTS),
+            Icon::Zeroone => (ICON_ZEROONE_AVAILABLE, ICON_ZEROONE_CODEPOINTS),
+            Icon::ZerooneText => (ICON_ZEROONE_TEXT_AVAILABLE, ICON_ZEROONE_TEXT_CODEPOINTS),
+            Icon::Zhipu => (ICON_ZHIPU_AVAILABLE, ICON_ZHIPU_CODEPOINTS),
+            Icon::ZhipuText => (ICON_ZHIPU_TEXT_AVAILABLE, ICON_ZHIPU_TEXT_CODEPOINTS),
         }
     }
 }
 
+/// Looks an icon up by its exact map name; unknown names yield `IconError::IconNotFound`.
+///
+/// Binary-searches `ICON_NAMES`, which is sorted and index-aligned with `Icon::ALL`.
+impl TryFrom<&str> for Icon {
+    type Error = crate::core::IconError;
+
+    fn try_from(name: &str) -> Result<Self, crate::core::IconError> {
+        ICON_NAMES
+            .binary_search(&name)
+            .map(|index| Icon::ALL[index])
+            .map_err(|_| crate::core::IconError::IconNotFound {
+                pack: PACK_ID,
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Serializes as the icon's map name, e.g. `"alarm"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Icon {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes from the icon's map name through `TryFrom<&str>`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Icon {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Icon::try_from(name.as_str()).map_err(|_| {
+            <D::Error as serde::de::Error>::custom(format_args!("unknown {PACK_ID} icon '{name}'"))
+        })
+    }
+}
+
+impl From<Icon> for &'static str {
+    fn from(icon: Icon) -> Self {
+        icon.name()
+    }
+}
+
+pub fn icons() -> impl Iterator<Item = Icon> {
+    Icon::ALL.iter().copied()
+}
+
 pub const ICON_NAMES: &[&str] = &[
     "adobe",
     "adobe-text",
@@ -1673,6 +3608,5397 @@ pub const ICON_NAMES: &[&str] = &[
     "zhipu-text",
 ];
 
+/// Icon names as constants, e.g. for `try_icon(pack, names::ALARM, ..)`.
+pub mod names {
+    pub const ADOBE: &str = "adobe";
+    pub const ADOBE_TEXT: &str = "adobe-text";
+    pub const ADOBEFIREFLY: &str = "adobefirefly";
+    pub const ADOBEFIREFLY_TEXT: &str = "adobefirefly-text";
+    pub const AGUI: &str = "agui";
+    pub const AGUI_TEXT: &str = "agui-text";
+    pub const AI_21: &str = "ai21";
+    pub const AI_21_BRAND: &str = "ai21-brand";
+    pub const AI_21_TEXT: &str = "ai21-text";
+    pub const AI_302: &str = "ai302";
+    pub const AI_302_TEXT: &str = "ai302-text";
+    pub const AI_360: &str = "ai360";
+    pub const AI_360_TEXT: &str = "ai360-text";
+    pub const AIHUBMIX: &str = "aihubmix";
+    pub const AIHUBMIX_TEXT: &str = "aihubmix-text";
+    pub const AIMASS: &str = "aimass";
+    pub const AIMASS_TEXT: &str = "aimass-text";
+    pub const AIONLABS: &str = "aionlabs";
+    pub const AIONLABS_TEXT: &str = "aionlabs-text";
+    pub const AISTUDIO: &str = "aistudio";
+    pub const AISTUDIO_TEXT: &str = "aistudio-text";
+    pub const AKASHCHAT: &str = "akashchat";
+    pub const AKASHCHAT_TEXT: &str = "akashchat-text";
+    pub const ALEPHALPHA: &str = "alephalpha";
+    pub const ALEPHALPHA_TEXT: &str = "alephalpha-text";
+    pub const ALIBABA: &str = "alibaba";
+    pub const ALIBABA_BRAND: &str = "alibaba-brand";
+    pub const ALIBABA_TEXT: &str = "alibaba-text";
+    pub const ALIBABA_TEXT_CN: &str = "alibaba-text-cn";
+    pub const ALIBABACLOUD: &str = "alibabacloud";
+    pub const ALIBABACLOUD_TEXT: &str = "alibabacloud-text";
+    pub const ALIBABACLOUD_TEXT_CN: &str = "alibabacloud-text-cn";
+    pub const ANTGROUP: &str = "antgroup";
+    pub const ANTGROUP_BRAND: &str = "antgroup-brand";
+    pub const ANTGROUP_TEXT: &str = "antgroup-text";
+    pub const ANTGROUP_TEXT_CN: &str = "antgroup-text-cn";
+    pub const ANTHROPIC: &str = "anthropic";
+    pub const ANTHROPIC_TEXT: &str = "anthropic-text";
+    pub const ANYSCALE: &str = "anyscale";
+    pub const ANYSCALE_TEXT: &str = "anyscale-text";
+    pub const APPLE: &str = "apple";
+    pub const APPLE_TEXT: &str = "apple-text";
+    pub const ASSEMBLYAI: &str = "assemblyai";
+    pub const ASSEMBLYAI_TEXT: &str = "assemblyai-text";
+    pub const AUTOMATIC: &str = "automatic";
+    pub const AUTOMATIC_TEXT: &str = "automatic-text";
+    pub const AWS: &str = "aws";
+    pub const AWS_BRAND: &str = "aws-brand";
+    pub const AWS_TEXT: &str = "aws-text";
+    pub const AYA: &str = "aya";
+    pub const AYA_TEXT: &str = "aya-text";
+    pub const AZURE: &str = "azure";
+    pub const AZURE_TEXT: &str = "azure-text";
+    pub const AZUREAI: &str = "azureai";
+    pub const AZUREAI_TEXT: &str = "azureai-text";
+    pub const BAAI: &str = "baai";
+    pub const BAAI_TEXT: &str = "baai-text";
+    pub const BAICHUAN: &str = "baichuan";
+    pub const BAICHUAN_TEXT: &str = "baichuan-text";
+    pub const BAIDU: &str = "baidu";
+    pub const BAIDU_BRAND: &str = "baidu-brand";
+    pub const BAIDU_TEXT: &str = "baidu-text";
+    pub const BAIDU_TEXT_CN: &str = "baidu-text-cn";
+    pub const BAIDUCLOUD: &str = "baiducloud";
+    pub const BAIDUCLOUD_TEXT: &str = "baiducloud-text";
+    pub const BAILIAN: &str = "bailian";
+    pub const BAILIAN_TEXT: &str = "bailian-text";
+    pub const BASETEN: &str = "baseten";
+    pub const BASETEN_TEXT: &str = "baseten-text";
+    pub const BEDROCK: &str = "bedrock";
+    pub const BEDROCK_TEXT: &str = "bedrock-text";
+    pub const BFL: &str = "bfl";
+    pub const BFL_TEXT: &str = "bfl-text";
+    pub const BILIBILI: &str = "bilibili";
+    pub const BILIBILI_TEXT: &str = "bilibili-text";
+    pub const BILIBILIINDEX: &str = "bilibiliindex";
+    pub const BILIBILIINDEX_TEXT: &str = "bilibiliindex-text";
+    pub const BING: &str = "bing";
+    pub const BING_TEXT: &str = "bing-text";
+    pub const BURNCLOUD: &str = "burncloud";
+    pub const BURNCLOUD_TEXT: &str = "burncloud-text";
+    pub const BYTEDANCE: &str = "bytedance";
+    pub const BYTEDANCE_BRAND: &str = "bytedance-brand";
+    pub const BYTEDANCE_TEXT: &str = "bytedance-text";
+    pub const BYTEDANCE_TEXT_CN: &str = "bytedance-text-cn";
+    pub const CAPCUT: &str = "capcut";
+    pub const CAPCUT_TEXT: &str = "capcut-text";
+    pub const CENTML: &str = "centml";
+    pub const CENTML_BRAND: &str = "centml-brand";
+    pub const CENTML_TEXT: &str = "centml-text";
+    pub const CEREBRAS: &str = "cerebras";
+    pub const CEREBRAS_BRAND: &str = "cerebras-brand";
+    pub const CEREBRAS_TEXT: &str = "cerebras-text";
+    pub const CHATGLM: &str = "chatglm";
+    pub const CHATGLM_TEXT: &str = "chatglm-text";
+    pub const CIVITAI: &str = "civitai";
+    pub const CIVITAI_TEXT: &str = "civitai-text";
+    pub const CLAUDE: &str = "claude";
+    pub const CLAUDE_TEXT: &str = "claude-text";
+    pub const CLINE: &str = "cline";
+    pub const CLINE_TEXT: &str = "cline-text";
+    pub const CLIPDROP: &str = "clipdrop";
+    pub const CLIPDROP_TEXT: &str = "clipdrop-text";
+    pub const CLOUDFLARE: &str = "cloudflare";
+    pub const CLOUDFLARE_TEXT: &str = "cloudflare-text";
+    pub const CODEGEEX: &str = "codegeex";
+    pub const CODEGEEX_TEXT: &str = "codegeex-text";
+    pub const COGVIDEO: &str = "cogvideo";
+    pub const COGVIDEO_TEXT: &str = "cogvideo-text";
+    pub const COGVIEW: &str = "cogview";
+    pub const COGVIEW_TEXT: &str = "cogview-text";
+    pub const COHERE: &str = "cohere";
+    pub const COHERE_TEXT: &str = "cohere-text";
+    pub const COLAB: &str = "colab";
+    pub const COLAB_TEXT: &str = "colab-text";
+    pub const COMETAPI: &str = "cometapi";
+    pub const COMETAPI_TEXT: &str = "cometapi-text";
+    pub const COMFYUI: &str = "comfyui";
+    pub const COMFYUI_TEXT: &str = "comfyui-text";
+    pub const COMMANDA: &str = "commanda";
+    pub const COMMANDA_TEXT: &str = "commanda-text";
+    pub const COPILOT: &str = "copilot";
+    pub const COPILOT_TEXT: &str = "copilot-text";
+    pub const COPILOTKIT: &str = "copilotkit";
+    pub const COPILOTKIT_TEXT: &str = "copilotkit-text";
+    pub const COQUI: &str = "coqui";
+    pub const COQUI_TEXT: &str = "coqui-text";
+    pub const COZE: &str = "coze";
+    pub const COZE_TEXT: &str = "coze-text";
+    pub const CREWAI: &str = "crewai";
+    pub const CREWAI_BRAND: &str = "crewai-brand";
+    pub const CREWAI_TEXT: &str = "crewai-text";
+    pub const CRUSOE: &str = "crusoe";
+    pub const CRUSOE_TEXT: &str = "crusoe-text";
+    pub const CURSOR: &str = "cursor";
+    pub const CURSOR_TEXT: &str = "cursor-text";
+    pub const DALLE: &str = "dalle";
+    pub const DALLE_TEXT: &str = "dalle-text";
+    pub const DBRX: &str = "dbrx";
+    pub const DBRX_BRAND: &str = "dbrx-brand";
+    pub const DBRX_TEXT: &str = "dbrx-text";
+    pub const DEEPAI: &str = "deepai";
+    pub const DEEPAI_TEXT: &str = "deepai-text";
+    pub const DEEPINFRA: &str = "deepinfra";
+    pub const DEEPINFRA_TEXT: &str = "deepinfra-text";
+    pub const DEEPL: &str = "deepl";
+    pub const DEEPL_TEXT: &str = "deepl-text";
+    pub const DEEPMIND: &str = "deepmind";
+    pub const DEEPMIND_TEXT: &str = "deepmind-text";
+    pub const DEEPSEEK: &str = "deepseek";
+    pub const DEEPSEEK_TEXT: &str = "deepseek-text";
+    pub const DIFY: &str = "dify";
+    pub const DIFY_TEXT: &str = "dify-text";
+    pub const DOC_2X: &str = "doc2x";
+    pub const DOC_2X_TEXT: &str = "doc2x-text";
+    pub const DOCSEARCH: &str = "docsearch";
+    pub const DOCSEARCH_TEXT: &str = "docsearch-text";
+    pub const DOLPHIN: &str = "dolphin";
+    pub const DOLPHIN_TEXT: &str = "dolphin-text";
+    pub const DOUBAO: &str = "doubao";
+    pub const DOUBAO_TEXT: &str = "doubao-text";
+    pub const DREAMMACHINE: &str = "dreammachine";
+    pub const DREAMMACHINE_TEXT: &str = "dreammachine-text";
+    pub const ELEVENLABS: &str = "elevenlabs";
+    pub const ELEVENLABS_TEXT: &str = "elevenlabs-text";
+    pub const ELEVENX: &str = "elevenx";
+    pub const ELEVENX_TEXT: &str = "elevenx-text";
+    pub const EXA: &str = "exa";
+    pub const EXA_TEXT: &str = "exa-text";
+    pub const FAL: &str = "fal";
+    pub const FAL_TEXT: &str = "fal-text";
+    pub const FASTGPT: &str = "fastgpt";
+    pub const FASTGPT_TEXT: &str = "fastgpt-text";
+    pub const FEATHERLESS: &str = "featherless";
+    pub const FEATHERLESS_TEXT: &str = "featherless-text";
+    pub const FIGMA: &str = "figma";
+    pub const FIGMA_TEXT: &str = "figma-text";
+    pub const FIREWORKS: &str = "fireworks";
+    pub const FIREWORKS_TEXT: &str = "fireworks-text";
+    pub const FISHAUDIO: &str = "fishaudio";
+    pub const FISHAUDIO_TEXT: &str = "fishaudio-text";
+    pub const FLORA: &str = "flora";
+    pub const FLORA_TEXT: &str = "flora-text";
+    pub const FLOWITH: &str = "flowith";
+    pub const FLOWITH_TEXT: &str = "flowith-text";
+    pub const FLUX: &str = "flux";
+    pub const FLUX_TEXT: &str = "flux-text";
+    pub const FRIENDLI: &str = "friendli";
+    pub const FRIENDLI_TEXT: &str = "friendli-text";
+    pub const GEMINI: &str = "gemini";
+    pub const GEMINI_TEXT: &str = "gemini-text";
+    pub const GEMMA: &str = "gemma";
+    pub const GEMMA_TEXT: &str = "gemma-text";
+    pub const GITEEAI: &str = "giteeai";
+    pub const GITEEAI_TEXT: &str = "giteeai-text";
+    pub const GITHUB: &str = "github";
+    pub const GITHUB_TEXT: &str = "github-text";
+    pub const GITHUBCOPILOT: &str = "githubcopilot";
+    pub const GITHUBCOPILOT_TEXT: &str = "githubcopilot-text";
+    pub const GLAMA: &str = "glama";
+    pub const GLAMA_TEXT: &str = "glama-text";
+    pub const GLIF: &str = "glif";
+    pub const GLIF_TEXT: &str = "glif-text";
+    pub const GLMV: &str = "glmv";
+    pub const GLMV_TEXT: &str = "glmv-text";
+    pub const GOOGLE: &str = "google";
+    pub const GOOGLE_BRAND: &str = "google-brand";
+    pub const GOOGLECLOUD: &str = "googlecloud";
+    pub const GOOGLECLOUD_BRAND: &str = "googlecloud-brand";
+    pub const GOOSE: &str = "goose";
+    pub const GOOSE_TEXT: &str = "goose-text";
+    pub const GRADIO: &str = "gradio";
+    pub const GRADIO_TEXT: &str = "gradio-text";
+    pub const GREPTILE: &str = "greptile";
+    pub const GREPTILE_TEXT: &str = "greptile-text";
+    pub const GROK: &str = "grok";
+    pub const GROK_TEXT: &str = "grok-text";
+    pub const GROQ: &str = "groq";
+    pub const GROQ_TEXT: &str = "groq-text";
+    pub const HAILUO: &str = "hailuo";
+    pub const HAILUO_TEXT: &str = "hailuo-text";
+    pub const HAIPER: &str = "haiper";
+    pub const HAIPER_TEXT: &str = "haiper-text";
+    pub const HEDRA: &str = "hedra";
+    pub const HEDRA_TEXT: &str = "hedra-text";
+    pub const HIGRESS: &str = "higress";
+    pub const HIGRESS_TEXT: &str = "higress-text";
+    pub const HUAWEI: &str = "huawei";
+    pub const HUAWEI_TEXT: &str = "huawei-text";
+    pub const HUAWEI_TEXT_CN: &str = "huawei-text-cn";
+    pub const HUAWEICLOUD: &str = "huaweicloud";
+    pub const HUAWEICLOUD_TEXT: &str = "huaweicloud-text";
+    pub const HUAWEICLOUD_TEXT_CN: &str = "huaweicloud-text-cn";
+    pub const HUGGINGFACE: &str = "huggingface";
+    pub const HUGGINGFACE_TEXT: &str = "huggingface-text";
+    pub const HUNYUAN: &str = "hunyuan";
+    pub const HUNYUAN_TEXT: &str = "hunyuan-text";
+    pub const HYPERBOLIC: &str = "hyperbolic";
+    pub const HYPERBOLIC_TEXT: &str = "hyperbolic-text";
+    pub const IBM: &str = "ibm";
+    pub const IBM_TEXT: &str = "ibm-text";
+    pub const IDEOGRAM: &str = "ideogram";
+    pub const IDEOGRAM_TEXT: &str = "ideogram-text";
+    pub const IFLYTEKCLOUD: &str = "iflytekcloud";
+    pub const IFLYTEKCLOUD_TEXT: &str = "iflytekcloud-text";
+    pub const INFERENCE: &str = "inference";
+    pub const INFERENCE_TEXT: &str = "inference-text";
+    pub const INFERMATIC: &str = "infermatic";
+    pub const INFERMATIC_TEXT: &str = "infermatic-text";
+    pub const INFINIGENCE: &str = "infinigence";
+    pub const INFINIGENCE_TEXT: &str = "infinigence-text";
+    pub const INFINIGENCE_TEXT_CN: &str = "infinigence-text-cn";
+    pub const INFLECTION: &str = "inflection";
+    pub const INFLECTION_TEXT: &str = "inflection-text";
+    pub const INTERNLM: &str = "internlm";
+    pub const INTERNLM_TEXT: &str = "internlm-text";
+    pub const JIMENG: &str = "jimeng";
+    pub const JIMENG_TEXT: &str = "jimeng-text";
+    pub const JINA: &str = "jina";
+    pub const JINA_TEXT: &str = "jina-text";
+    pub const KIMI: &str = "kimi";
+    pub const KIMI_TEXT: &str = "kimi-text";
+    pub const KLING: &str = "kling";
+    pub const KLING_TEXT: &str = "kling-text";
+    pub const KLUSTER: &str = "kluster";
+    pub const KLUSTER_TEXT: &str = "kluster-text";
+    pub const KOLORS: &str = "kolors";
+    pub const KOLORS_TEXT: &str = "kolors-text";
+    pub const KREA: &str = "krea";
+    pub const KREA_TEXT: &str = "krea-text";
+    pub const KWAIPILOT: &str = "kwaipilot";
+    pub const KWAIPILOT_TEXT: &str = "kwaipilot-text";
+    pub const LAMBDA: &str = "lambda";
+    pub const LAMBDA_TEXT: &str = "lambda-text";
+    pub const LANGCHAIN: &str = "langchain";
+    pub const LANGCHAIN_TEXT: &str = "langchain-text";
+    pub const LANGFUSE: &str = "langfuse";
+    pub const LANGFUSE_TEXT: &str = "langfuse-text";
+    pub const LANGGRAPH: &str = "langgraph";
+    pub const LANGGRAPH_TEXT: &str = "langgraph-text";
+    pub const LANGSMITH: &str = "langsmith";
+    pub const LANGSMITH_TEXT: &str = "langsmith-text";
+    pub const LEPTONAI: &str = "leptonai";
+    pub const LEPTONAI_TEXT: &str = "leptonai-text";
+    pub const LG: &str = "lg";
+    pub const LG_TEXT: &str = "lg-text";
+    pub const LIGHTRICKS: &str = "lightricks";
+    pub const LIGHTRICKS_TEXT: &str = "lightricks-text";
+    pub const LIQUID: &str = "liquid";
+    pub const LIQUID_TEXT: &str = "liquid-text";
+    pub const LIVEKIT: &str = "livekit";
+    pub const LIVEKIT_TEXT: &str = "livekit-text";
+    pub const LLAMAINDEX: &str = "llamaindex";
+    pub const LLAMAINDEX_TEXT: &str = "llamaindex-text";
+    pub const LLAVA: &str = "llava";
+    pub const LLAVA_TEXT: &str = "llava-text";
+    pub const LMSTUDIO: &str = "lmstudio";
+    pub const LMSTUDIO_TEXT: &str = "lmstudio-text";
+    pub const LOBEHUB: &str = "lobehub";
+    pub const LOBEHUB_TEXT: &str = "lobehub-text";
+    pub const LONGCAT: &str = "longcat";
+    pub const LONGCAT_TEXT: &str = "longcat-text";
+    pub const LOVABLE: &str = "lovable";
+    pub const LOVABLE_TEXT: &str = "lovable-text";
+    pub const LUMA: &str = "luma";
+    pub const LUMA_TEXT: &str = "luma-text";
+    pub const MAGIC: &str = "magic";
+    pub const MAGIC_TEXT: &str = "magic-text";
+    pub const MAKE: &str = "make";
+    pub const MAKE_TEXT: &str = "make-text";
+    pub const MANUS: &str = "manus";
+    pub const MANUS_TEXT: &str = "manus-text";
+    pub const MASTRA: &str = "mastra";
+    pub const MASTRA_TEXT: &str = "mastra-text";
+    pub const MCP: &str = "mcp";
+    pub const MCP_TEXT: &str = "mcp-text";
+    pub const MCPSO: &str = "mcpso";
+    pub const MCPSO_TEXT: &str = "mcpso-text";
+    pub const MENLO: &str = "menlo";
+    pub const MENLO_TEXT: &str = "menlo-text";
+    pub const META: &str = "meta";
+    pub const META_BRAND: &str = "meta-brand";
+    pub const META_TEXT: &str = "meta-text";
+    pub const METAAI: &str = "metaai";
+    pub const METAAI_TEXT: &str = "metaai-text";
+    pub const METAGPT: &str = "metagpt";
+    pub const METAGPT_TEXT: &str = "metagpt-text";
+    pub const MICROSOFT: &str = "microsoft";
+    pub const MICROSOFT_TEXT: &str = "microsoft-text";
+    pub const MIDJOURNEY: &str = "midjourney";
+    pub const MIDJOURNEY_TEXT: &str = "midjourney-text";
+    pub const MINIMAX: &str = "minimax";
+    pub const MINIMAX_TEXT: &str = "minimax-text";
+    pub const MISTRAL: &str = "mistral";
+    pub const MISTRAL_TEXT: &str = "mistral-text";
+    pub const MODELSCOPE: &str = "modelscope";
+    pub const MODELSCOPE_TEXT: &str = "modelscope-text";
+    pub const MONICA: &str = "monica";
+    pub const MONICA_TEXT: &str = "monica-text";
+    pub const MOONSHOT: &str = "moonshot";
+    pub const MOONSHOT_TEXT: &str = "moonshot-text";
+    pub const MYSHELL: &str = "myshell";
+    pub const MYSHELL_TEXT: &str = "myshell-text";
+    pub const N_8N: &str = "n8n";
+    pub const N_8N_TEXT: &str = "n8n-text";
+    pub const NEBIUS: &str = "nebius";
+    pub const NEBIUS_TEXT: &str = "nebius-text";
+    pub const NEWAPI: &str = "newapi";
+    pub const NEWAPI_TEXT: &str = "newapi-text";
+    pub const NOTEBOOKLM: &str = "notebooklm";
+    pub const NOTEBOOKLM_TEXT: &str = "notebooklm-text";
+    pub const NOTION: &str = "notion";
+    pub const NOTION_TEXT: &str = "notion-text";
+    pub const NOUSRESEARCH: &str = "nousresearch";
+    pub const NOUSRESEARCH_TEXT: &str = "nousresearch-text";
+    pub const NOVA: &str = "nova";
+    pub const NOVA_TEXT: &str = "nova-text";
+    pub const NOVELAI: &str = "novelai";
+    pub const NOVELAI_TEXT: &str = "novelai-text";
+    pub const NOVITA: &str = "novita";
+    pub const NOVITA_TEXT: &str = "novita-text";
+    pub const NPLCLOUD: &str = "nplcloud";
+    pub const NPLCLOUD_TEXT: &str = "nplcloud-text";
+    pub const NVIDIA: &str = "nvidia";
+    pub const NVIDIA_TEXT: &str = "nvidia-text";
+    pub const OLLAMA: &str = "ollama";
+    pub const OLLAMA_TEXT: &str = "ollama-text";
+    pub const OPENAI: &str = "openai";
+    pub const OPENAI_TEXT: &str = "openai-text";
+    pub const OPENCHAT: &str = "openchat";
+    pub const OPENCHAT_TEXT: &str = "openchat-text";
+    pub const OPENROUTER: &str = "openrouter";
+    pub const OPENROUTER_TEXT: &str = "openrouter-text";
+    pub const OPENWEBUI: &str = "openwebui";
+    pub const OPENWEBUI_TEXT: &str = "openwebui-text";
+    pub const PALM: &str = "palm";
+    pub const PALM_TEXT: &str = "palm-text";
+    pub const PARASAIL: &str = "parasail";
+    pub const PARASAIL_TEXT: &str = "parasail-text";
+    pub const PERPLEXITY: &str = "perplexity";
+    pub const PERPLEXITY_TEXT: &str = "perplexity-text";
+    pub const PHIDATA: &str = "phidata";
+    pub const PHIDATA_TEXT: &str = "phidata-text";
+    pub const PHIND: &str = "phind";
+    pub const PHIND_TEXT: &str = "phind-text";
+    pub const PIKA: &str = "pika";
+    pub const PIKA_TEXT: &str = "pika-text";
+    pub const PIXVERSE: &str = "pixverse";
+    pub const PIXVERSE_TEXT: &str = "pixverse-text";
+    pub const PLAYER_2: &str = "player2";
+    pub const PLAYER_2_TEXT: &str = "player2-text";
+    pub const POE: &str = "poe";
+    pub const POE_TEXT: &str = "poe-text";
+    pub const POLLINATIONS: &str = "pollinations";
+    pub const POLLINATIONS_TEXT: &str = "pollinations-text";
+    pub const PPIO: &str = "ppio";
+    pub const PPIO_TEXT: &str = "ppio-text";
+    pub const PPIO_TEXT_CN: &str = "ppio-text-cn";
+    pub const PYDANTICAI: &str = "pydanticai";
+    pub const PYDANTICAI_TEXT: &str = "pydanticai-text";
+    pub const QINGYAN: &str = "qingyan";
+    pub const QINGYAN_TEXT: &str = "qingyan-text";
+    pub const QINIU: &str = "qiniu";
+    pub const QINIU_TEXT: &str = "qiniu-text";
+    pub const QWEN: &str = "qwen";
+    pub const QWEN_TEXT: &str = "qwen-text";
+    pub const RAILWAY: &str = "railway";
+    pub const RAILWAY_TEXT: &str = "railway-text";
+    pub const RECRAFT: &str = "recraft";
+    pub const RECRAFT_TEXT: &str = "recraft-text";
+    pub const REPLICATE: &str = "replicate";
+    pub const REPLICATE_BRAND: &str = "replicate-brand";
+    pub const REPLIT: &str = "replit";
+    pub const REPLIT_TEXT: &str = "replit-text";
+    pub const RSSHUB: &str = "rsshub";
+    pub const RSSHUB_TEXT: &str = "rsshub-text";
+    pub const RUNWAY: &str = "runway";
+    pub const RUNWAY_TEXT: &str = "runway-text";
+    pub const RWKV: &str = "rwkv";
+    pub const RWKV_TEXT: &str = "rwkv-text";
+    pub const SAMBANOVA: &str = "sambanova";
+    pub const SAMBANOVA_TEXT: &str = "sambanova-text";
+    pub const SEARCH_1API: &str = "search1api";
+    pub const SEARCH_1API_TEXT: &str = "search1api-text";
+    pub const SEARCHAPI: &str = "searchapi";
+    pub const SEARCHAPI_TEXT: &str = "searchapi-text";
+    pub const SENSENOVA: &str = "sensenova";
+    pub const SENSENOVA_BRAND: &str = "sensenova-brand";
+    pub const SENSENOVA_TEXT: &str = "sensenova-text";
+    pub const SILICONCLOUD: &str = "siliconcloud";
+    pub const SILICONCLOUD_TEXT: &str = "siliconcloud-text";
+    pub const SKYWORK: &str = "skywork";
+    pub const SKYWORK_TEXT: &str = "skywork-text";
+    pub const SMITHERY: &str = "smithery";
+    pub const SMITHERY_TEXT: &str = "smithery-text";
+    pub const SNOWFLAKE: &str = "snowflake";
+    pub const SNOWFLAKE_TEXT: &str = "snowflake-text";
+    pub const SOPHNET: &str = "sophnet";
+    pub const SOPHNET_TEXT: &str = "sophnet-text";
+    pub const SORA: &str = "sora";
+    pub const SORA_TEXT: &str = "sora-text";
+    pub const SPARK: &str = "spark";
+    pub const SPARK_TEXT: &str = "spark-text";
+    pub const STABILITY: &str = "stability";
+    pub const STABILITY_BRAND: &str = "stability-brand";
+    pub const STABILITY_TEXT: &str = "stability-text";
+    pub const STATECLOUD: &str = "statecloud";
+    pub const STATECLOUD_TEXT: &str = "statecloud-text";
+    pub const STEPFUN: &str = "stepfun";
+    pub const STEPFUN_TEXT: &str = "stepfun-text";
+    pub const STRAICO: &str = "straico";
+    pub const STRAICO_TEXT: &str = "straico-text";
+    pub const SUBMODEL: &str = "submodel";
+    pub const SUBMODEL_TEXT: &str = "submodel-text";
+    pub const SUNO: &str = "suno";
+    pub const SUNO_TEXT: &str = "suno-text";
+    pub const SYNC: &str = "sync";
+    pub const SYNC_TEXT: &str = "sync-text";
+    pub const TARGON: &str = "targon";
+    pub const TARGON_TEXT: &str = "targon-text";
+    pub const TAVILY: &str = "tavily";
+    pub const TAVILY_TEXT: &str = "tavily-text";
+    pub const TENCENT: &str = "tencent";
+    pub const TENCENT_BRAND: &str = "tencent-brand";
+    pub const TENCENT_TEXT: &str = "tencent-text";
+    pub const TENCENT_TEXT_CN: &str = "tencent-text-cn";
+    pub const TENCENTCLOUD: &str = "tencentcloud";
+    pub const TENCENTCLOUD_TEXT: &str = "tencentcloud-text";
+    pub const TIANGONG: &str = "tiangong";
+    pub const TIANGONG_TEXT: &str = "tiangong-text";
+    pub const TII: &str = "tii";
+    pub const TII_TEXT: &str = "tii-text";
+    pub const TOGETHER: &str = "together";
+    pub const TOGETHER_BRAND: &str = "together-brand";
+    pub const TOGETHER_TEXT: &str = "together-text";
+    pub const TOPAZLABS: &str = "topazlabs";
+    pub const TOPAZLABS_TEXT: &str = "topazlabs-text";
+    pub const TRAE: &str = "trae";
+    pub const TRAE_TEXT: &str = "trae-text";
+    pub const TRIPO: &str = "tripo";
+    pub const TRIPO_TEXT: &str = "tripo-text";
+    pub const TURIX: &str = "turix";
+    pub const TURIX_TEXT: &str = "turix-text";
+    pub const UDIO: &str = "udio";
+    pub const UDIO_TEXT: &str = "udio-text";
+    pub const UNSTRUCTURED: &str = "unstructured";
+    pub const UNSTRUCTURED_TEXT: &str = "unstructured-text";
+    pub const UPSTAGE: &str = "upstage";
+    pub const UPSTAGE_TEXT: &str = "upstage-text";
+    pub const V_0: &str = "v0";
+    pub const VECTORIZERAI: &str = "vectorizerai";
+    pub const VECTORIZERAI_TEXT: &str = "vectorizerai-text";
+    pub const VERCEL: &str = "vercel";
+    pub const VERCEL_TEXT: &str = "vercel-text";
+    pub const VERTEXAI: &str = "vertexai";
+    pub const VERTEXAI_TEXT: &str = "vertexai-text";
+    pub const VIDU: &str = "vidu";
+    pub const VIDU_TEXT: &str = "vidu-text";
+    pub const VIGGLE: &str = "viggle";
+    pub const VIGGLE_TEXT: &str = "viggle-text";
+    pub const VLLM: &str = "vllm";
+    pub const VLLM_TEXT: &str = "vllm-text";
+    pub const VOLCENGINE: &str = "volcengine";
+    pub const VOLCENGINE_TEXT: &str = "volcengine-text";
+    pub const VOYAGE: &str = "voyage";
+    pub const VOYAGE_TEXT: &str = "voyage-text";
+    pub const WENXIN: &str = "wenxin";
+    pub const WENXIN_TEXT: &str = "wenxin-text";
+    pub const WINDSURF: &str = "windsurf";
+    pub const WINDSURF_TEXT: &str = "windsurf-text";
+    pub const WORKERSAI: &str = "workersai";
+    pub const WORKERSAI_TEXT: &str = "workersai-text";
+    pub const XAI: &str = "xai";
+    pub const XAI_TEXT: &str = "xai-text";
+    pub const XINFERENCE: &str = "xinference";
+    pub const XINFERENCE_TEXT: &str = "xinference-text";
+    pub const XUANYUAN: &str = "xuanyuan";
+    pub const XUANYUAN_TEXT: &str = "xuanyuan-text";
+    pub const YANDEX: &str = "yandex";
+    pub const YANDEX_TEXT: &str = "yandex-text";
+    pub const YI: &str = "yi";
+    pub const YI_TEXT: &str = "yi-text";
+    pub const YOUMIND: &str = "youmind";
+    pub const YOUMIND_TEXT: &str = "youmind-text";
+    pub const YUANBAO: &str = "yuanbao";
+    pub const YUANBAO_TEXT: &str = "yuanbao-text";
+    pub const ZAI: &str = "zai";
+    pub const ZAI_TEXT: &str = "zai-text";
+    pub const ZAPIER: &str = "zapier";
+    pub const ZAPIER_TEXT: &str = "zapier-text";
+    pub const ZEABUR: &str = "zeabur";
+    pub const ZEABUR_TEXT: &str = "zeabur-text";
+    pub const ZENMUX: &str = "zenmux";
+    pub const ZENMUX_TEXT: &str = "zenmux-text";
+    pub const ZEROONE: &str = "zeroone";
+    pub const ZEROONE_TEXT: &str = "zeroone-text";
+    pub const ZHIPU: &str = "zhipu";
+    pub const ZHIPU_TEXT: &str = "zhipu-text";
+}
+
+pub(crate) const ICON_NAMES_LOWERCASE: &[(&str, &str)] = &[
+    ("adobe", "adobe"),
+    ("adobe-text", "adobe-text"),
+    ("adobefirefly", "adobefirefly"),
+    ("adobefirefly-text", "adobefirefly-text"),
+    ("agui", "agui"),
+    ("agui-text", "agui-text"),
+    ("ai21", "ai21"),
+    ("ai21-brand", "ai21-brand"),
+    ("ai21-text", "ai21-text"),
+    ("ai302", "ai302"),
+    ("ai302-text", "ai302-text"),
+    ("ai360", "ai360"),
+    ("ai360-text", "ai360-text"),
+    ("aihubmix", "aihubmix"),
+    ("aihubmix-text", "aihubmix-text"),
+    ("aimass", "aimass"),
+    ("aimass-text", "aimass-text"),
+    ("aionlabs", "aionlabs"),
+    ("aionlabs-text", "aionlabs-text"),
+    ("aistudio", "aistudio"),
+    ("aistudio-text", "aistudio-text"),
+    ("akashchat", "akashchat"),
+    ("akashchat-text", "akashchat-text"),
+    ("alephalpha", "alephalpha"),
+    ("alephalpha-text", "alephalpha-text"),
+    ("alibaba", "alibaba"),
+    ("alibaba-brand", "alibaba-brand"),
+    ("alibaba-text", "alibaba-text"),
+    ("alibaba-text-cn", "alibaba-text-cn"),
+    ("alibabacloud", "alibabacloud"),
+    ("alibabacloud-text", "alibabacloud-text"),
+    ("alibabacloud-text-cn", "alibabacloud-text-cn"),
+    ("antgroup", "antgroup"),
+    ("antgroup-brand", "antgroup-brand"),
+    ("antgroup-text", "antgroup-text"),
+    ("antgroup-text-cn", "antgroup-text-cn"),
+    ("anthropic", "anthropic"),
+    ("anthropic-text", "anthropic-text"),
+    ("anyscale", "anyscale"),
+    ("anyscale-text", "anyscale-text"),
+    ("apple", "apple"),
+    ("apple-text", "apple-text"),
+    ("assemblyai", "assemblyai"),
+    ("assemblyai-text", "assemblyai-text"),
+    ("automatic", "automatic"),
+    ("automatic-text", "automatic-text"),
+    ("aws", "aws"),
+    ("aws-brand", "aws-brand"),
+    ("aws-text", "aws-text"),
+    ("aya", "aya"),
+    ("aya-text", "aya-text"),
+    ("azure", "azure"),
+    ("azure-text", "azure-text"),
+    ("azureai", "azureai"),
+    ("azureai-text", "azureai-text"),
+    ("baai", "baai"),
+    ("baai-text", "baai-text"),
+    ("baichuan", "baichuan"),
+    ("baichuan-text", "baichuan-text"),
+    ("baidu", "baidu"),
+    ("baidu-brand", "baidu-brand"),
+    ("baidu-text", "baidu-text"),
+    ("baidu-text-cn", "baidu-text-cn"),
+    ("baiducloud", "baiducloud"),
+    ("baiducloud-text", "baiducloud-text"),
+    ("bailian", "bailian"),
+    ("bailian-text", "bailian-text"),
+    ("baseten", "baseten"),
+    ("baseten-text", "baseten-text"),
+    ("bedrock", "bedrock"),
+    ("bedrock-text", "bedrock-text"),
+    ("bfl", "bfl"),
+    ("bfl-text", "bfl-text"),
+    ("bilibili", "bilibili"),
+    ("bilibili-text", "bilibili-text"),
+    ("bilibiliindex", "bilibiliindex"),
+    ("bilibiliindex-text", "bilibiliindex-text"),
+    ("bing", "bing"),
+    ("bing-text", "bing-text"),
+    ("burncloud", "burncloud"),
+    ("burncloud-text", "burncloud-text"),
+    ("bytedance", "bytedance"),
+    ("bytedance-brand", "bytedance-brand"),
+    ("bytedance-text", "bytedance-text"),
+    ("bytedance-text-cn", "bytedance-text-cn"),
+    ("capcut", "capcut"),
+    ("capcut-text", "capcut-text"),
+    ("centml", "centml"),
+    ("centml-brand", "centml-brand"),
+    ("centml-text", "centml-text"),
+    ("cerebras", "cerebras"),
+    ("cerebras-brand", "cerebras-brand"),
+    ("cerebras-text", "cerebras-text"),
+    ("chatglm", "chatglm"),
+    ("chatglm-text", "chatglm-text"),
+    ("civitai", "civitai"),
+    ("civitai-text", "civitai-text"),
+    ("claude", "claude"),
+    ("claude-text", "claude-text"),
+    ("cline", "cline"),
+    ("cline-text", "cline-text"),
+    ("clipdrop", "clipdrop"),
+    ("clipdrop-text", "clipdrop-text"),
+    ("cloudflare", "cloudflare"),
+    ("cloudflare-text", "cloudflare-text"),
+    ("codegeex", "codegeex"),
+    ("codegeex-text", "codegeex-text"),
+    ("cogvideo", "cogvideo"),
+    ("cogvideo-text", "cogvideo-text"),
+    ("cogview", "cogview"),
+    ("cogview-text", "cogview-text"),
+    ("cohere", "cohere"),
+    ("cohere-text", "cohere-text"),
+    ("colab", "colab"),
+    ("colab-text", "colab-text"),
+    ("cometapi", "cometapi"),
+    ("cometapi-text", "cometapi-text"),
+    ("comfyui", "comfyui"),
+    ("comfyui-text", "comfyui-text"),
+    ("commanda", "commanda"),
+    ("commanda-text", "commanda-text"),
+    ("copilot", "copilot"),
+    ("copilot-text", "copilot-text"),
+    ("copilotkit", "copilotkit"),
+    ("copilotkit-text", "copilotkit-text"),
+    ("coqui", "coqui"),
+    ("coqui-text", "coqui-text"),
+    ("coze", "coze"),
+    ("coze-text", "coze-text"),
+    ("crewai", "crewai"),
+    ("crewai-brand", "crewai-brand"),
+    ("crewai-text", "crewai-text"),
+    ("crusoe", "crusoe"),
+    ("crusoe-text", "crusoe-text"),
+    ("cursor", "cursor"),
+    ("cursor-text", "cursor-text"),
+    ("dalle", "dalle"),
+    ("dalle-text", "dalle-text"),
+    ("dbrx", "dbrx"),
+    ("dbrx-brand", "dbrx-brand"),
+    ("dbrx-text", "dbrx-text"),
+    ("deepai", "deepai"),
+    ("deepai-text", "deepai-text"),
+    ("deepinfra", "deepinfra"),
+    ("deepinfra-text", "deepinfra-text"),
+    ("deepl", "deepl"),
+    ("deepl-text", "deepl-text"),
+    ("deepmind", "deepmind"),
+    ("deepmind-text", "deepmind-text"),
+    ("deepseek", "deepseek"),
+    ("deepseek-text", "deepseek-text"),
+    ("dify", "dify"),
+    ("dify-text", "dify-text"),
+    ("doc2x", "doc2x"),
+    ("doc2x-text", "doc2x-text"),
+    ("docsearch", "docsearch"),
+    ("docsearch-text", "docsearch-text"),
+    ("dolphin", "dolphin"),
+    ("dolphin-text", "dolphin-text"),
+    ("doubao", "doubao"),
+    ("doubao-text", "doubao-text"),
+    ("dreammachine", "dreammachine"),
+    ("dreammachine-text", "dreammachine-text"),
+    ("elevenlabs", "elevenlabs"),
+    ("elevenlabs-text", "elevenlabs-text"),
+    ("elevenx", "elevenx"),
+    ("elevenx-text", "elevenx-text"),
+    ("exa", "exa"),
+    ("exa-text", "exa-text"),
+    ("fal", "fal"),
+    ("fal-text", "fal-text"),
+    ("fastgpt", "fastgpt"),
+    ("fastgpt-text", "fastgpt-text"),
+    ("featherless", "featherless"),
+    ("featherless-text", "featherless-text"),
+    ("figma", "figma"),
+    ("figma-text", "figma-text"),
+    ("fireworks", "fireworks"),
+    ("fireworks-text", "fireworks-text"),
+    ("fishaudio", "fishaudio"),
+    ("fishaudio-text", "fishaudio-text"),
+    ("flora", "flora"),
+    ("flora-text", "flora-text"),
+    ("flowith", "flowith"),
+    ("flowith-text", "flowith-text"),
+    ("flux", "flux"),
+    ("flux-text", "flux-text"),
+    ("friendli", "friendli"),
+    ("friendli-text", "friendli-text"),
+    ("gemini", "gemini"),
+    ("gemini-text", "gemini-text"),
+    ("gemma", "gemma"),
+    ("gemma-text", "gemma-text"),
+    ("giteeai", "giteeai"),
+    ("giteeai-text", "giteeai-text"),
+    ("github", "github"),
+    ("github-text", "github-text"),
+    ("githubcopilot", "githubcopilot"),
+    ("githubcopilot-text", "githubcopilot-text"),
+    ("glama", "glama"),
+    ("glama-text", "glama-text"),
+    ("glif", "glif"),
+    ("glif-text", "glif-text"),
+    ("glmv", "glmv"),
+    ("glmv-text", "glmv-text"),
+    ("google", "google"),
+    ("google-brand", "google-brand"),
+    ("googlecloud", "googlecloud"),
+    ("googlecloud-brand", "googlecloud-brand"),
+    ("goose", "goose"),
+    ("goose-text", "goose-text"),
+    ("gradio", "gradio"),
+    ("gradio-text", "gradio-text"),
+    ("greptile", "greptile"),
+    ("greptile-text", "greptile-text"),
+    ("grok", "grok"),
+    ("grok-text", "grok-text"),
+    ("groq", "groq"),
+    ("groq-text", "groq-text"),
+    ("hailuo", "hailuo"),
+    ("hailuo-text", "hailuo-text"),
+    ("haiper", "haiper"),
+    ("haiper-text", "haiper-text"),
+    ("hedra", "hedra"),
+    ("hedra-text", "hedra-text"),
+    ("higress", "higress"),
+    ("higress-text", "higress-text"),
+    ("huawei", "huawei"),
+    ("huawei-text", "huawei-text"),
+    ("huawei-text-cn", "huawei-text-cn"),
+    ("huaweicloud", "huaweicloud"),
+    ("huaweicloud-text", "huaweicloud-text"),
+    ("huaweicloud-text-cn", "huaweicloud-text-cn"),
+    ("huggingface", "huggingface"),
+    ("huggingface-text", "huggingface-text"),
+    ("hunyuan", "hunyuan"),
+    ("hunyuan-text", "hunyuan-text"),
+    ("hyperbolic", "hyperbolic"),
+    ("hyperbolic-text", "hyperbolic-text"),
+    ("ibm", "ibm"),
+    ("ibm-text", "ibm-text"),
+    ("ideogram", "ideogram"),
+    ("ideogram-text", "ideogram-text"),
+    ("iflytekcloud", "iflytekcloud"),
+    ("iflytekcloud-text", "iflytekcloud-text"),
+    ("inference", "inference"),
+    ("inference-text", "inference-text"),
+    ("infermatic", "infermatic"),
+    ("infermatic-text", "infermatic-text"),
+    ("infinigence", "infinigence"),
+    ("infinigence-text", "infinigence-text"),
+    ("infinigence-text-cn", "infinigence-text-cn"),
+    ("inflection", "inflection"),
+    ("inflection-text", "inflection-text"),
+    ("internlm", "internlm"),
+    ("internlm-text", "internlm-text"),
+    ("jimeng", "jimeng"),
+    ("jimeng-text", "jimeng-text"),
+    ("jina", "jina"),
+    ("jina-text", "jina-text"),
+    ("kimi", "kimi"),
+    ("kimi-text", "kimi-text"),
+    ("kling", "kling"),
+    ("kling-text", "kling-text"),
+    ("kluster", "kluster"),
+    ("kluster-text", "kluster-text"),
+    ("kolors", "kolors"),
+    ("kolors-text", "kolors-text"),
+    ("krea", "krea"),
+    ("krea-text", "krea-text"),
+    ("kwaipilot", "kwaipilot"),
+    ("kwaipilot-text", "kwaipilot-text"),
+    ("lambda", "lambda"),
+    ("lambda-text", "lambda-text"),
+    ("langchain", "langchain"),
+    ("langchain-text", "langchain-text"),
+    ("langfuse", "langfuse"),
+    ("langfuse-text", "langfuse-text"),
+    ("langgraph", "langgraph"),
+    ("langgraph-text", "langgraph-text"),
+    ("langsmith", "langsmith"),
+    ("langsmith-text", "langsmith-text"),
+    ("leptonai", "leptonai"),
+    ("leptonai-text", "leptonai-text"),
+    ("lg", "lg"),
+    ("lg-text", "lg-text"),
+    ("lightricks", "lightricks"),
+    ("lightricks-text", "lightricks-text"),
+    ("liquid", "liquid"),
+    ("liquid-text", "liquid-text"),
+    ("livekit", "livekit"),
+    ("livekit-text", "livekit-text"),
+    ("llamaindex", "llamaindex"),
+    ("llamaindex-text", "llamaindex-text"),
+    ("llava", "llava"),
+    ("llava-text", "llava-text"),
+    ("lmstudio", "lmstudio"),
+    ("lmstudio-text", "lmstudio-text"),
+    ("lobehub", "lobehub"),
+    ("lobehub-text", "lobehub-text"),
+    ("longcat", "longcat"),
+    ("longcat-text", "longcat-text"),
+    ("lovable", "lovable"),
+    ("lovable-text", "lovable-text"),
+    ("luma", "luma"),
+    ("luma-text", "luma-text"),
+    ("magic", "magic"),
+    ("magic-text", "magic-text"),
+    ("make", "make"),
+    ("make-text", "make-text"),
+    ("manus", "manus"),
+    ("manus-text", "manus-text"),
+    ("mastra", "mastra"),
+    ("mastra-text", "mastra-text"),
+    ("mcp", "mcp"),
+    ("mcp-text", "mcp-text"),
+    ("mcpso", "mcpso"),
+    ("mcpso-text", "mcpso-text"),
+    ("menlo", "menlo"),
+    ("menlo-text", "menlo-text"),
+    ("meta", "meta"),
+    ("meta-brand", "meta-brand"),
+    ("meta-text", "meta-text"),
+    ("metaai", "metaai"),
+    ("metaai-text", "metaai-text"),
+    ("metagpt", "metagpt"),
+    ("metagpt-text", "metagpt-text"),
+    ("microsoft", "microsoft"),
+    ("microsoft-text", "microsoft-text"),
+    ("midjourney", "midjourney"),
+    ("midjourney-text", "midjourney-text"),
+    ("minimax", "minimax"),
+    ("minimax-text", "minimax-text"),
+    ("mistral", "mistral"),
+    ("mistral-text", "mistral-text"),
+    ("modelscope", "modelscope"),
+    ("modelscope-text", "modelscope-text"),
+    ("monica", "monica"),
+    ("monica-text", "monica-text"),
+    ("moonshot", "moonshot"),
+    ("moonshot-text", "moonshot-text"),
+    ("myshell", "myshell"),
+    ("myshell-text", "myshell-text"),
+    ("n8n", "n8n"),
+    ("n8n-text", "n8n-text"),
+    ("nebius", "nebius"),
+    ("nebius-text", "nebius-text"),
+    ("newapi", "newapi"),
+    ("newapi-text", "newapi-text"),
+    ("notebooklm", "notebooklm"),
+    ("notebooklm-text", "notebooklm-text"),
+    ("notion", "notion"),
+    ("notion-text", "notion-text"),
+    ("nousresearch", "nousresearch"),
+    ("nousresearch-text", "nousresearch-text"),
+    ("nova", "nova"),
+    ("nova-text", "nova-text"),
+    ("novelai", "novelai"),
+    ("novelai-text", "novelai-text"),
+    ("novita", "novita"),
+    ("novita-text", "novita-text"),
+    ("nplcloud", "nplcloud"),
+    ("nplcloud-text", "nplcloud-text"),
+    ("nvidia", "nvidia"),
+    ("nvidia-text", "nvidia-text"),
+    ("ollama", "ollama"),
+    ("ollama-text", "ollama-text"),
+    ("openai", "openai"),
+    ("openai-text", "openai-text"),
+    ("openchat", "openchat"),
+    ("openchat-text", "openchat-text"),
+    ("openrouter", "openrouter"),
+    ("openrouter-text", "openrouter-text"),
+    ("openwebui", "openwebui"),
+    ("openwebui-text", "openwebui-text"),
+    ("palm", "palm"),
+    ("palm-text", "palm-text"),
+    ("parasail", "parasail"),
+    ("parasail-text", "parasail-text"),
+    ("perplexity", "perplexity"),
+    ("perplexity-text", "perplexity-text"),
+    ("phidata", "phidata"),
+    ("phidata-text", "phidata-text"),
+    ("phind", "phind"),
+    ("phind-text", "phind-text"),
+    ("pika", "pika"),
+    ("pika-text", "pika-text"),
+    ("pixverse", "pixverse"),
+    ("pixverse-text", "pixverse-text"),
+    ("player2", "player2"),
+    ("player2-text", "player2-text"),
+    ("poe", "poe"),
+    ("poe-text", "poe-text"),
+    ("pollinations", "pollinations"),
+    ("pollinations-text", "pollinations-text"),
+    ("ppio", "ppio"),
+    ("ppio-text", "ppio-text"),
+    ("ppio-text-cn", "ppio-text-cn"),
+    ("pydanticai", "pydanticai"),
+    ("pydanticai-text", "pydanticai-text"),
+    ("qingyan", "qingyan"),
+    ("qingyan-text", "qingyan-text"),
+    ("qiniu", "qiniu"),
+    ("qiniu-text", "qiniu-text"),
+    ("qwen", "qwen"),
+    ("qwen-text", "qwen-text"),
+    ("railway", "railway"),
+    ("railway-text", "railway-text"),
+    ("recraft", "recraft"),
+    ("recraft-text", "recraft-text"),
+    ("replicate", "replicate"),
+    ("replicate-brand", "replicate-brand"),
+    ("replit", "replit"),
+    ("replit-text", "replit-text"),
+    ("rsshub", "rsshub"),
+    ("rsshub-text", "rsshub-text"),
+    ("runway", "runway"),
+    ("runway-text", "runway-text"),
+    ("rwkv", "rwkv"),
+    ("rwkv-text", "rwkv-text"),
+    ("sambanova", "sambanova"),
+    ("sambanova-text", "sambanova-text"),
+    ("search1api", "search1api"),
+    ("search1api-text", "search1api-text"),
+    ("searchapi", "searchapi"),
+    ("searchapi-text", "searchapi-text"),
+    ("sensenova", "sensenova"),
+    ("sensenova-brand", "sensenova-brand"),
+    ("sensenova-text", "sensenova-text"),
+    ("siliconcloud", "siliconcloud"),
+    ("siliconcloud-text", "siliconcloud-text"),
+    ("skywork", "skywork"),
+    ("skywork-text", "skywork-text"),
+    ("smithery", "smithery"),
+    ("smithery-text", "smithery-text"),
+    ("snowflake", "snowflake"),
+    ("snowflake-text", "snowflake-text"),
+    ("sophnet", "sophnet"),
+    ("sophnet-text", "sophnet-text"),
+    ("sora", "sora"),
+    ("sora-text", "sora-text"),
+    ("spark", "spark"),
+    ("spark-text", "spark-text"),
+    ("stability", "stability"),
+    ("stability-brand", "stability-brand"),
+    ("stability-text", "stability-text"),
+    ("statecloud", "statecloud"),
+    ("statecloud-text", "statecloud-text"),
+    ("stepfun", "stepfun"),
+    ("stepfun-text", "stepfun-text"),
+    ("straico", "straico"),
+    ("straico-text", "straico-text"),
+    ("submodel", "submodel"),
+    ("submodel-text", "submodel-text"),
+    ("suno", "suno"),
+    ("suno-text", "suno-text"),
+    ("sync", "sync"),
+    ("sync-text", "sync-text"),
+    ("targon", "targon"),
+    ("targon-text", "targon-text"),
+    ("tavily", "tavily"),
+    ("tavily-text", "tavily-text"),
+    ("tencent", "tencent"),
+    ("tencent-brand", "tencent-brand"),
+    ("tencent-text", "tencent-text"),
+    ("tencent-text-cn", "tencent-text-cn"),
+    ("tencentcloud", "tencentcloud"),
+    ("tencentcloud-text", "tencentcloud-text"),
+    ("tiangong", "tiangong"),
+    ("tiangong-text", "tiangong-text"),
+    ("tii", "tii"),
+    ("tii-text", "tii-text"),
+    ("together", "together"),
+    ("together-brand", "together-brand"),
+    ("together-text", "together-text"),
+    ("topazlabs", "topazlabs"),
+    ("topazlabs-text", "topazlabs-text"),
+    ("trae", "trae"),
+    ("trae-text", "trae-text"),
+    ("tripo", "tripo"),
+    ("tripo-text", "tripo-text"),
+    ("turix", "turix"),
+    ("turix-text", "turix-text"),
+    ("udio", "udio"),
+    ("udio-text", "udio-text"),
+    ("unstructured", "unstructured"),
+    ("unstructured-text", "unstructured-text"),
+    ("upstage", "upstage"),
+    ("upstage-text", "upstage-text"),
+    ("v0", "v0"),
+    ("vectorizerai", "vectorizerai"),
+    ("vectorizerai-text", "vectorizerai-text"),
+    ("vercel", "vercel"),
+    ("vercel-text", "vercel-text"),
+    ("vertexai", "vertexai"),
+    ("vertexai-text", "vertexai-text"),
+    ("vidu", "vidu"),
+    ("vidu-text", "vidu-text"),
+    ("viggle", "viggle"),
+    ("viggle-text", "viggle-text"),
+    ("vllm", "vllm"),
+    ("vllm-text", "vllm-text"),
+    ("volcengine", "volcengine"),
+    ("volcengine-text", "volcengine-text"),
+    ("voyage", "voyage"),
+    ("voyage-text", "voyage-text"),
+    ("wenxin", "wenxin"),
+    ("wenxin-text", "wenxin-text"),
+    ("windsurf", "windsurf"),
+    ("windsurf-text", "windsurf-text"),
+    ("workersai", "workersai"),
+    ("workersai-text", "workersai-text"),
+    ("xai", "xai"),
+    ("xai-text", "xai-text"),
+    ("xinference", "xinference"),
+    ("xinference-text", "xinference-text"),
+    ("xuanyuan", "xuanyuan"),
+    ("xuanyuan-text", "xuanyuan-text"),
+    ("yandex", "yandex"),
+    ("yandex-text", "yandex-text"),
+    ("yi", "yi"),
+    ("yi-text", "yi-text"),
+    ("youmind", "youmind"),
+    ("youmind-text", "youmind-text"),
+    ("yuanbao", "yuanbao"),
+    ("yuanbao-text", "yuanbao-text"),
+    ("zai", "zai"),
+    ("zai-text", "zai-text"),
+    ("zapier", "zapier"),
+    ("zapier-text", "zapier-text"),
+    ("zeabur", "zeabur"),
+    ("zeabur-text", "zeabur-text"),
+    ("zenmux", "zenmux"),
+    ("zenmux-text", "zenmux-text"),
+    ("zeroone", "zeroone"),
+    ("zeroone-text", "zeroone-text"),
+    ("zhipu", "zhipu"),
+    ("zhipu-text", "zhipu-text"),
+];
+
+/// `(variant, codepoint, name)` for every mapped glyph, sorted for `binary_search`.
+pub const ICON_BY_CODEPOINT: &[(VariantKey, u32, &str)] = &[
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE000,
+        "adobe-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE001,
+        "adobe",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE002,
+        "adobefirefly-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE003,
+        "adobefirefly",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE004,
+        "agui-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE005,
+        "agui",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE006,
+        "ai21-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE007,
+        "ai21-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE008,
+        "ai21",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE009,
+        "ai302-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "ai302",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "ai360-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "ai360",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "aihubmix-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00E,
+        "aihubmix",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00F,
+        "aimass-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE010,
+        "aimass",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE011,
+        "aionlabs-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE012,
+        "aionlabs",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE013,
+        "aistudio-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE014,
+        "aistudio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE015,
+        "akashchat-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE016,
+        "akashchat",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE017,
+        "alephalpha-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE018,
+        "alephalpha",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE019,
+        "alibaba-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01A,
+        "alibaba-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01B,
+        "alibaba-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01C,
+        "alibaba",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01D,
+        "alibabacloud-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01E,
+        "alibabacloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01F,
+        "alibabacloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE020,
+        "antgroup-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE021,
+        "antgroup-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE022,
+        "antgroup-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE023,
+        "antgroup",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE024,
+        "anthropic-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE025,
+        "anthropic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE026,
+        "anyscale-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE027,
+        "anyscale",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE028,
+        "apple-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE029,
+        "apple",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02A,
+        "assemblyai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02B,
+        "assemblyai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02C,
+        "automatic-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02D,
+        "automatic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02E,
+        "aws-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02F,
+        "aws-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE030,
+        "aws",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE031,
+        "aya-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE032,
+        "aya",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE033,
+        "azure-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE034,
+        "azure",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE035,
+        "azureai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE036,
+        "azureai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE037,
+        "baai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE038,
+        "baai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE039,
+        "baichuan-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03A,
+        "baichuan",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03B,
+        "baidu-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03C,
+        "baidu-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03D,
+        "baidu-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03E,
+        "baidu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03F,
+        "baiducloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE040,
+        "baiducloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE041,
+        "bailian-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE042,
+        "bailian",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE043,
+        "baseten-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE044,
+        "baseten",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE045,
+        "bedrock-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE046,
+        "bedrock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE047,
+        "bfl-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE048,
+        "bfl",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE049,
+        "bilibili-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04A,
+        "bilibili",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04B,
+        "bilibiliindex-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04C,
+        "bilibiliindex",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04D,
+        "bing-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04E,
+        "bing",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04F,
+        "burncloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE050,
+        "burncloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE051,
+        "bytedance-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE052,
+        "bytedance-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE053,
+        "bytedance-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE054,
+        "bytedance",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE055,
+        "capcut-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE056,
+        "capcut",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE057,
+        "centml-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE058,
+        "centml-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE059,
+        "centml",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05A,
+        "cerebras-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05B,
+        "cerebras-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05C,
+        "cerebras",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05D,
+        "chatglm-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05E,
+        "chatglm",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05F,
+        "civitai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE060,
+        "civitai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE061,
+        "claude-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE062,
+        "claude",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE063,
+        "cline-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE064,
+        "cline",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE065,
+        "clipdrop-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE066,
+        "clipdrop",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE067,
+        "cloudflare-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE068,
+        "cloudflare",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE069,
+        "codegeex-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06A,
+        "codegeex",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06B,
+        "cogvideo-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06C,
+        "cogvideo",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06D,
+        "cogview-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06E,
+        "cogview",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06F,
+        "cohere-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE070,
+        "cohere",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE071,
+        "colab-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE072,
+        "colab",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE073,
+        "cometapi-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE074,
+        "cometapi",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE075,
+        "comfyui-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE076,
+        "comfyui",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE077,
+        "commanda-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE078,
+        "commanda",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE079,
+        "copilot-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07A,
+        "copilot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07B,
+        "copilotkit-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07C,
+        "copilotkit",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07D,
+        "coqui-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07E,
+        "coqui",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07F,
+        "coze-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE080,
+        "coze",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE081,
+        "crewai-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE082,
+        "crewai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE083,
+        "crewai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE084,
+        "crusoe-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE085,
+        "crusoe",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE086,
+        "cursor-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE087,
+        "cursor",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE088,
+        "dalle-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE089,
+        "dalle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08A,
+        "dbrx-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08B,
+        "dbrx-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08C,
+        "dbrx",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08D,
+        "deepai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08E,
+        "deepai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08F,
+        "deepinfra-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE090,
+        "deepinfra",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE091,
+        "deepl-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE092,
+        "deepl",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE093,
+        "deepmind-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE094,
+        "deepmind",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE095,
+        "deepseek-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE096,
+        "deepseek",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE097,
+        "dify-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE098,
+        "dify",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE099,
+        "doc2x-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09A,
+        "doc2x",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09B,
+        "docsearch-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09C,
+        "docsearch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09D,
+        "dolphin-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09E,
+        "dolphin",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09F,
+        "doubao-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A0,
+        "doubao",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A1,
+        "dreammachine-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A2,
+        "dreammachine",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A3,
+        "elevenlabs-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A4,
+        "elevenlabs",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A5,
+        "elevenx-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A6,
+        "elevenx",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A7,
+        "exa-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A8,
+        "exa",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A9,
+        "fal-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AA,
+        "fal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AB,
+        "fastgpt-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AC,
+        "fastgpt",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AD,
+        "featherless-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AE,
+        "featherless",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AF,
+        "figma-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B0,
+        "figma",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B1,
+        "fireworks-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B2,
+        "fireworks",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B3,
+        "fishaudio-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B4,
+        "fishaudio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B5,
+        "flora-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B6,
+        "flora",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B7,
+        "flowith-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B8,
+        "flowith",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B9,
+        "flux-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BA,
+        "flux",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BB,
+        "friendli-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BC,
+        "friendli",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BD,
+        "gemini-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BE,
+        "gemini",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BF,
+        "gemma-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C0,
+        "gemma",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C1,
+        "giteeai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C2,
+        "giteeai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C3,
+        "github-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C4,
+        "github",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C5,
+        "githubcopilot-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C6,
+        "githubcopilot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C7,
+        "glama-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C8,
+        "glama",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C9,
+        "glif-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CA,
+        "glif",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CB,
+        "glmv-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CC,
+        "glmv",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CD,
+        "google-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CE,
+        "google",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CF,
+        "googlecloud-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D0,
+        "googlecloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D1,
+        "goose-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D2,
+        "goose",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D3,
+        "gradio-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D4,
+        "gradio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D5,
+        "greptile-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D6,
+        "greptile",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D7,
+        "grok-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D8,
+        "grok",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D9,
+        "groq-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DA,
+        "groq",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DB,
+        "hailuo-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DC,
+        "hailuo",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DD,
+        "haiper-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DE,
+        "haiper",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DF,
+        "hedra-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E0,
+        "hedra",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E1,
+        "higress-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E2,
+        "higress",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E3,
+        "huawei-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E4,
+        "huawei-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E5,
+        "huawei",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E6,
+        "huaweicloud-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E7,
+        "huaweicloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E8,
+        "huaweicloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E9,
+        "huggingface-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EA,
+        "huggingface",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EB,
+        "hunyuan-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EC,
+        "hunyuan",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0ED,
+        "hyperbolic-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EE,
+        "hyperbolic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EF,
+        "ibm-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F0,
+        "ibm",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F1,
+        "ideogram-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F2,
+        "ideogram",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F3,
+        "iflytekcloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F4,
+        "iflytekcloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F5,
+        "inference-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F6,
+        "inference",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F7,
+        "infermatic-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F8,
+        "infermatic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F9,
+        "infinigence-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FA,
+        "infinigence-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FB,
+        "infinigence",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FC,
+        "inflection-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FD,
+        "inflection",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FE,
+        "internlm-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FF,
+        "internlm",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE100,
+        "jimeng-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE101,
+        "jimeng",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE102,
+        "jina-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE103,
+        "jina",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE104,
+        "kimi-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE105,
+        "kimi",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE106,
+        "kling-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE107,
+        "kling",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE108,
+        "kluster-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE109,
+        "kluster",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10A,
+        "kolors-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10B,
+        "kolors",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10C,
+        "krea-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10D,
+        "krea",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10E,
+        "kwaipilot-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10F,
+        "kwaipilot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE110,
+        "lambda-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE111,
+        "lambda",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE112,
+        "langchain-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE113,
+        "langchain",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE114,
+        "langfuse-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE115,
+        "langfuse",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE116,
+        "langgraph-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE117,
+        "langgraph",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE118,
+        "langsmith-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE119,
+        "langsmith",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11A,
+        "leptonai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11B,
+        "leptonai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11C,
+        "lg-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11D,
+        "lg",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11E,
+        "lightricks-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11F,
+        "lightricks",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE120,
+        "liquid-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE121,
+        "liquid",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE122,
+        "livekit-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE123,
+        "livekit",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE124,
+        "llamaindex-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE125,
+        "llamaindex",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE126,
+        "llava-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE127,
+        "llava",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE128,
+        "lmstudio-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE129,
+        "lmstudio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12A,
+        "lobehub-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12B,
+        "lobehub",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12C,
+        "longcat-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12D,
+        "longcat",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12E,
+        "lovable-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12F,
+        "lovable",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE130,
+        "luma-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE131,
+        "luma",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE132,
+        "magic-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE133,
+        "magic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE134,
+        "make-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE135,
+        "make",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE136,
+        "manus-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE137,
+        "manus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE138,
+        "mastra-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE139,
+        "mastra",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13A,
+        "mcp-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13B,
+        "mcp",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13C,
+        "mcpso-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13D,
+        "mcpso",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13E,
+        "menlo-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13F,
+        "menlo",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE140,
+        "meta-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE141,
+        "meta-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE142,
+        "meta",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE143,
+        "metaai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE144,
+        "metaai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE145,
+        "metagpt-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE146,
+        "metagpt",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE147,
+        "microsoft-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE148,
+        "microsoft",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE149,
+        "midjourney-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14A,
+        "midjourney",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14B,
+        "minimax-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14C,
+        "minimax",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14D,
+        "mistral-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14E,
+        "mistral",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14F,
+        "modelscope-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE150,
+        "modelscope",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE151,
+        "monica-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE152,
+        "monica",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE153,
+        "moonshot-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE154,
+        "moonshot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE155,
+        "myshell-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE156,
+        "myshell",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE157,
+        "n8n-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE158,
+        "n8n",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE159,
+        "nebius-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15A,
+        "nebius",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15B,
+        "newapi-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15C,
+        "newapi",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15D,
+        "notebooklm-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15E,
+        "notebooklm",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15F,
+        "notion-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE160,
+        "notion",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE161,
+        "nousresearch-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE162,
+        "nousresearch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE163,
+        "nova-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE164,
+        "nova",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE165,
+        "novelai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE166,
+        "novelai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE167,
+        "novita-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE168,
+        "novita",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE169,
+        "nplcloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE16A,
+        "nplcloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE16B,
+        "nvidia-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE16C,
+        "nvidia",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE16D,
+        "ollama-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE16E,
+        "ollama",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE16F,
+        "openai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE170,
+        "openai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE171,
+        "openchat-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE172,
+        "openchat",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE173,
+        "openrouter-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE174,
+        "openrouter",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE175,
+        "openwebui-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE176,
+        "openwebui",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE177,
+        "palm-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE178,
+        "palm",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE179,
+        "parasail-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE17A,
+        "parasail",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE17B,
+        "perplexity-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE17C,
+        "perplexity",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE17D,
+        "phidata-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE17E,
+        "phidata",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE17F,
+        "phind-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE180,
+        "phind",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE181,
+        "pika-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE182,
+        "pika",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE183,
+        "pixverse-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE184,
+        "pixverse",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE185,
+        "player2-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE186,
+        "player2",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE187,
+        "poe-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE188,
+        "poe",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE189,
+        "pollinations-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE18A,
+        "pollinations",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE18B,
+        "ppio-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE18C,
+        "ppio-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE18D,
+        "ppio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE18E,
+        "pydanticai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE18F,
+        "pydanticai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE190,
+        "qingyan-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE191,
+        "qingyan",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE192,
+        "qiniu-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE193,
+        "qiniu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE194,
+        "qwen-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE195,
+        "qwen",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE196,
+        "railway-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE197,
+        "railway",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE198,
+        "recraft-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE199,
+        "recraft",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE19A,
+        "replicate-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE19B,
+        "replicate",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE19C,
+        "replit-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE19D,
+        "replit",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE19E,
+        "rsshub-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE19F,
+        "rsshub",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A0,
+        "runway-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A1,
+        "runway",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A2,
+        "rwkv-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A3,
+        "rwkv",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A4,
+        "sambanova-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A5,
+        "sambanova",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A6,
+        "search1api-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A7,
+        "search1api",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A8,
+        "searchapi-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1A9,
+        "searchapi",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1AA,
+        "sensenova-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1AB,
+        "sensenova-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1AC,
+        "sensenova",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1AD,
+        "siliconcloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1AE,
+        "siliconcloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1AF,
+        "skywork-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B0,
+        "skywork",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B1,
+        "smithery-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B2,
+        "smithery",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B3,
+        "snowflake-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B4,
+        "snowflake",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B5,
+        "sophnet-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B6,
+        "sophnet",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B7,
+        "sora-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B8,
+        "sora",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1B9,
+        "spark-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1BA,
+        "spark",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1BB,
+        "stability-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1BC,
+        "stability-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1BD,
+        "stability",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1BE,
+        "statecloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1BF,
+        "statecloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C0,
+        "stepfun-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C1,
+        "stepfun",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C2,
+        "straico-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C3,
+        "straico",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C4,
+        "submodel-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C5,
+        "submodel",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C6,
+        "suno-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C7,
+        "suno",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C8,
+        "sync-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1C9,
+        "sync",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1CA,
+        "targon-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1CB,
+        "targon",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1CC,
+        "tavily-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1CD,
+        "tavily",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1CE,
+        "tencent-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1CF,
+        "tencent-text-cn",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D0,
+        "tencent-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D1,
+        "tencent",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D2,
+        "tencentcloud-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D3,
+        "tencentcloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D4,
+        "tiangong-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D5,
+        "tiangong",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D6,
+        "tii-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D7,
+        "tii",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D8,
+        "together-brand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1D9,
+        "together-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1DA,
+        "together",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1DB,
+        "topazlabs-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1DC,
+        "topazlabs",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1DD,
+        "trae-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1DE,
+        "trae",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1DF,
+        "tripo-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E0,
+        "tripo",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E1,
+        "turix-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E2,
+        "turix",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E3,
+        "udio-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E4,
+        "udio",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E5,
+        "unstructured-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E6,
+        "unstructured",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E7,
+        "upstage-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E8,
+        "upstage",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1E9,
+        "v0",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1EA,
+        "vectorizerai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1EB,
+        "vectorizerai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1EC,
+        "vercel-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1ED,
+        "vercel",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1EE,
+        "vertexai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1EF,
+        "vertexai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F0,
+        "vidu-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F1,
+        "vidu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F2,
+        "viggle-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F3,
+        "viggle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F4,
+        "vllm-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F5,
+        "vllm",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F6,
+        "volcengine-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F7,
+        "volcengine",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F8,
+        "voyage-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1F9,
+        "voyage",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1FA,
+        "wenxin-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1FB,
+        "wenxin",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1FC,
+        "windsurf-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1FD,
+        "windsurf",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1FE,
+        "workersai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE1FF,
+        "workersai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE200,
+        "xai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE201,
+        "xai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE202,
+        "xinference-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE203,
+        "xinference",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE204,
+        "xuanyuan-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE205,
+        "xuanyuan",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE206,
+        "yandex-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE207,
+        "yandex",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE208,
+        "yi-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE209,
+        "yi",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE20A,
+        "youmind-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE20B,
+        "youmind",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE20C,
+        "yuanbao-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE20D,
+        "yuanbao",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE20E,
+        "zai-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE20F,
+        "zai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE210,
+        "zapier-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE211,
+        "zapier",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE212,
+        "zeabur-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE213,
+        "zeabur",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE214,
+        "zenmux-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE215,
+        "zenmux",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE216,
+        "zeroone-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE217,
+        "zeroone",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE218,
+        "zhipu-text",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE219,
+        "zhipu",
+    ),
+];
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct VariantInfo {
     pub key: VariantKey,
@@ -1687,6 +9013,7 @@ pub(crate) const VARIANTS: &[VariantInfo] = &[VariantInfo {
     family: "Lobe Regular",
 }];
 
+/// `adobe` — U+E001 (available in Regular)
 const ICON_ADOBE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1695,6 +9022,7 @@ const ICON_ADOBE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57345,
 )];
 
+/// `adobe-text` — U+E000 (available in Regular)
 const ICON_ADOBE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1703,6 +9031,7 @@ const ICON_ADOBE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57344,
 )];
 
+/// `adobefirefly` — U+E003 (available in Regular)
 const ICON_ADOBEFIREFLY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1711,6 +9040,7 @@ const ICON_ADOBEFIREFLY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57347,
 )];
 
+/// `adobefirefly-text` — U+E002 (available in Regular)
 const ICON_ADOBEFIREFLY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1719,6 +9049,7 @@ const ICON_ADOBEFIREFLY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57346,
 )];
 
+/// `agui` — U+E005 (available in Regular)
 const ICON_AGUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1727,6 +9058,7 @@ const ICON_AGUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57349,
 )];
 
+/// `agui-text` — U+E004 (available in Regular)
 const ICON_AGUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1735,7 +9067,8 @@ const ICON_AGUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57348,
 )];
 
-const ICON_AI_2_1_CODEPOINTS: &[(VariantKey, u32)] = &[(
+/// `ai21` — U+E008 (available in Regular)
+const ICON_AI_21_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
         size: Size::Regular,
@@ -1743,7 +9076,8 @@ const ICON_AI_2_1_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57352,
 )];
 
-const ICON_AI_2_1_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
+/// `ai21-brand` — U+E006 (available in Regular)
+const ICON_AI_21_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
         size: Size::Regular,
@@ -1751,7 +9085,8 @@ const ICON_AI_2_1_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57350,
 )];
 
-const ICON_AI_2_1_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
+/// `ai21-text` — U+E007 (available in Regular)
+const ICON_AI_21_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
         size: Size::Regular,
@@ -1759,7 +9094,8 @@ const ICON_AI_2_1_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57351,
 )];
 
-const ICON_AI_3_0_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
+/// `ai302` — U+E00A (available in Regular)
+const ICON_AI_302_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
         size: Size::Regular,
@@ -1767,7 +9103,8 @@ const ICON_AI_3_0_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57354,
 )];
 
-const ICON_AI_3_0_2_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
+/// `ai302-text` — U+E009 (available in Regular)
+const ICON_AI_302_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
         size: Size::Regular,
@@ -1775,7 +9112,8 @@ const ICON_AI_3_0_2_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57353,
 )];
 
-const ICON_AI_3_6_0_CODEPOINTS: &[(VariantKey, u32)] = &[(
+/// `ai360` — U+E00C (available in Regular)
+const ICON_AI_360_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
         size: Size::Regular,
@@ -1783,7 +9121,8 @@ const ICON_AI_3_6_0_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57356,
 )];
 
-const ICON_AI_3_6_0_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
+/// `ai360-text` — U+E00B (available in Regular)
+const ICON_AI_360_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
         size: Size::Regular,
@@ -1791,6 +9130,7 @@ const ICON_AI_3_6_0_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57355,
 )];
 
+/// `aihubmix` — U+E00E (available in Regular)
 const ICON_AIHUBMIX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1799,6 +9139,7 @@ const ICON_AIHUBMIX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57358,
 )];
 
+/// `aihubmix-text` — U+E00D (available in Regular)
 const ICON_AIHUBMIX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1807,6 +9148,7 @@ const ICON_AIHUBMIX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57357,
 )];
 
+/// `aimass` — U+E010 (available in Regular)
 const ICON_AIMASS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1815,6 +9157,7 @@ const ICON_AIMASS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57360,
 )];
 
+/// `aimass-text` — U+E00F (available in Regular)
 const ICON_AIMASS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1823,6 +9166,7 @@ const ICON_AIMASS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57359,
 )];
 
+/// `aionlabs` — U+E012 (available in Regular)
 const ICON_AIONLABS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1831,6 +9175,7 @@ const ICON_AIONLABS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57362,
 )];
 
+/// `aionlabs-text` — U+E011 (available in Regular)
 const ICON_AIONLABS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1839,6 +9184,7 @@ const ICON_AIONLABS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57361,
 )];
 
+/// `aistudio` — U+E014 (available in Regular)
 const ICON_AISTUDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1847,6 +9193,7 @@ const ICON_AISTUDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57364,
 )];
 
+/// `aistudio-text` — U+E013 (available in Regular)
 const ICON_AISTUDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1855,6 +9202,7 @@ const ICON_AISTUDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57363,
 )];
 
+/// `akashchat` — U+E016 (available in Regular)
 const ICON_AKASHCHAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1863,6 +9211,7 @@ const ICON_AKASHCHAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57366,
 )];
 
+/// `akashchat-text` — U+E015 (available in Regular)
 const ICON_AKASHCHAT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1871,6 +9220,7 @@ const ICON_AKASHCHAT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57365,
 )];
 
+/// `alephalpha` — U+E018 (available in Regular)
 const ICON_ALEPHALPHA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1879,6 +9229,7 @@ const ICON_ALEPHALPHA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57368,
 )];
 
+/// `alephalpha-text` — U+E017 (available in Regular)
 const ICON_ALEPHALPHA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1887,6 +9238,7 @@ const ICON_ALEPHALPHA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57367,
 )];
 
+/// `alibaba` — U+E01C (available in Regular)
 const ICON_ALIBABA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1895,6 +9247,7 @@ const ICON_ALIBABA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57372,
 )];
 
+/// `alibaba-brand` — U+E019 (available in Regular)
 const ICON_ALIBABA_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1903,6 +9256,7 @@ const ICON_ALIBABA_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57369,
 )];
 
+/// `alibaba-text` — U+E01B (available in Regular)
 const ICON_ALIBABA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1911,6 +9265,7 @@ const ICON_ALIBABA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57371,
 )];
 
+/// `alibaba-text-cn` — U+E01A (available in Regular)
 const ICON_ALIBABA_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1919,6 +9274,7 @@ const ICON_ALIBABA_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57370,
 )];
 
+/// `alibabacloud` — U+E01F (available in Regular)
 const ICON_ALIBABACLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1927,6 +9283,7 @@ const ICON_ALIBABACLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57375,
 )];
 
+/// `alibabacloud-text` — U+E01E (available in Regular)
 const ICON_ALIBABACLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1935,6 +9292,7 @@ const ICON_ALIBABACLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57374,
 )];
 
+/// `alibabacloud-text-cn` — U+E01D (available in Regular)
 const ICON_ALIBABACLOUD_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1943,6 +9301,7 @@ const ICON_ALIBABACLOUD_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57373,
 )];
 
+/// `antgroup` — U+E023 (available in Regular)
 const ICON_ANTGROUP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1951,6 +9310,7 @@ const ICON_ANTGROUP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57379,
 )];
 
+/// `antgroup-brand` — U+E020 (available in Regular)
 const ICON_ANTGROUP_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1959,6 +9319,7 @@ const ICON_ANTGROUP_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57376,
 )];
 
+/// `antgroup-text` — U+E022 (available in Regular)
 const ICON_ANTGROUP_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1967,6 +9328,7 @@ const ICON_ANTGROUP_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57378,
 )];
 
+/// `antgroup-text-cn` — U+E021 (available in Regular)
 const ICON_ANTGROUP_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1975,6 +9337,7 @@ const ICON_ANTGROUP_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57377,
 )];
 
+/// `anthropic` — U+E025 (available in Regular)
 const ICON_ANTHROPIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1983,6 +9346,7 @@ const ICON_ANTHROPIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57381,
 )];
 
+/// `anthropic-text` — U+E024 (available in Regular)
 const ICON_ANTHROPIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1991,6 +9355,7 @@ const ICON_ANTHROPIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57380,
 )];
 
+/// `anyscale` — U+E027 (available in Regular)
 const ICON_ANYSCALE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -1999,6 +9364,7 @@ const ICON_ANYSCALE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57383,
 )];
 
+/// `anyscale-text` — U+E026 (available in Regular)
 const ICON_ANYSCALE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2007,6 +9373,7 @@ const ICON_ANYSCALE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57382,
 )];
 
+/// `apple` — U+E029 (available in Regular)
 const ICON_APPLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2015,6 +9382,7 @@ const ICON_APPLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57385,
 )];
 
+/// `apple-text` — U+E028 (available in Regular)
 const ICON_APPLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2023,6 +9391,7 @@ const ICON_APPLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57384,
 )];
 
+/// `assemblyai` — U+E02B (available in Regular)
 const ICON_ASSEMBLYAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2031,6 +9400,7 @@ const ICON_ASSEMBLYAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57387,
 )];
 
+/// `assemblyai-text` — U+E02A (available in Regular)
 const ICON_ASSEMBLYAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2039,6 +9409,7 @@ const ICON_ASSEMBLYAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57386,
 )];
 
+/// `automatic` — U+E02D (available in Regular)
 const ICON_AUTOMATIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2047,6 +9418,7 @@ const ICON_AUTOMATIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57389,
 )];
 
+/// `automatic-text` — U+E02C (available in Regular)
 const ICON_AUTOMATIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2055,6 +9427,7 @@ const ICON_AUTOMATIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57388,
 )];
 
+/// `aws` — U+E030 (available in Regular)
 const ICON_AWS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2063,6 +9436,7 @@ const ICON_AWS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57392,
 )];
 
+/// `aws-brand` — U+E02E (available in Regular)
 const ICON_AWS_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2071,6 +9445,7 @@ const ICON_AWS_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57390,
 )];
 
+/// `aws-text` — U+E02F (available in Regular)
 const ICON_AWS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2079,6 +9454,7 @@ const ICON_AWS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57391,
 )];
 
+/// `aya` — U+E032 (available in Regular)
 const ICON_AYA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2087,6 +9463,7 @@ const ICON_AYA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57394,
 )];
 
+/// `aya-text` — U+E031 (available in Regular)
 const ICON_AYA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2095,6 +9472,7 @@ const ICON_AYA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57393,
 )];
 
+/// `azure` — U+E034 (available in Regular)
 const ICON_AZURE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2103,6 +9481,7 @@ const ICON_AZURE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57396,
 )];
 
+/// `azure-text` — U+E033 (available in Regular)
 const ICON_AZURE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2111,6 +9490,7 @@ const ICON_AZURE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57395,
 )];
 
+/// `azureai` — U+E036 (available in Regular)
 const ICON_AZUREAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2119,6 +9499,7 @@ const ICON_AZUREAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57398,
 )];
 
+/// `azureai-text` — U+E035 (available in Regular)
 const ICON_AZUREAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2127,6 +9508,7 @@ const ICON_AZUREAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57397,
 )];
 
+/// `baai` — U+E038 (available in Regular)
 const ICON_BAAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2135,6 +9517,7 @@ const ICON_BAAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57400,
 )];
 
+/// `baai-text` — U+E037 (available in Regular)
 const ICON_BAAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2143,6 +9526,7 @@ const ICON_BAAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57399,
 )];
 
+/// `baichuan` — U+E03A (available in Regular)
 const ICON_BAICHUAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2151,6 +9535,7 @@ const ICON_BAICHUAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57402,
 )];
 
+/// `baichuan-text` — U+E039 (available in Regular)
 const ICON_BAICHUAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2159,6 +9544,7 @@ const ICON_BAICHUAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57401,
 )];
 
+/// `baidu` — U+E03E (available in Regular)
 const ICON_BAIDU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2167,6 +9553,7 @@ const ICON_BAIDU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57406,
 )];
 
+/// `baidu-brand` — U+E03B (available in Regular)
 const ICON_BAIDU_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2175,6 +9562,7 @@ const ICON_BAIDU_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57403,
 )];
 
+/// `baidu-text` — U+E03D (available in Regular)
 const ICON_BAIDU_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2183,6 +9571,7 @@ const ICON_BAIDU_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57405,
 )];
 
+/// `baidu-text-cn` — U+E03C (available in Regular)
 const ICON_BAIDU_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2191,6 +9580,7 @@ const ICON_BAIDU_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57404,
 )];
 
+/// `baiducloud` — U+E040 (available in Regular)
 const ICON_BAIDUCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2199,6 +9589,7 @@ const ICON_BAIDUCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57408,
 )];
 
+/// `baiducloud-text` — U+E03F (available in Regular)
 const ICON_BAIDUCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2207,6 +9598,7 @@ const ICON_BAIDUCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57407,
 )];
 
+/// `bailian` — U+E042 (available in Regular)
 const ICON_BAILIAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2215,6 +9607,7 @@ const ICON_BAILIAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57410,
 )];
 
+/// `bailian-text` — U+E041 (available in Regular)
 const ICON_BAILIAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2223,6 +9616,7 @@ const ICON_BAILIAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57409,
 )];
 
+/// `baseten` — U+E044 (available in Regular)
 const ICON_BASETEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2231,6 +9625,7 @@ const ICON_BASETEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57412,
 )];
 
+/// `baseten-text` — U+E043 (available in Regular)
 const ICON_BASETEN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2239,6 +9634,7 @@ const ICON_BASETEN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57411,
 )];
 
+/// `bedrock` — U+E046 (available in Regular)
 const ICON_BEDROCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2247,6 +9643,7 @@ const ICON_BEDROCK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57414,
 )];
 
+/// `bedrock-text` — U+E045 (available in Regular)
 const ICON_BEDROCK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2255,6 +9652,7 @@ const ICON_BEDROCK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57413,
 )];
 
+/// `bfl` — U+E048 (available in Regular)
 const ICON_BFL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2263,6 +9661,7 @@ const ICON_BFL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57416,
 )];
 
+/// `bfl-text` — U+E047 (available in Regular)
 const ICON_BFL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2271,6 +9670,7 @@ const ICON_BFL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57415,
 )];
 
+/// `bilibili` — U+E04A (available in Regular)
 const ICON_BILIBILI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2279,6 +9679,7 @@ const ICON_BILIBILI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57418,
 )];
 
+/// `bilibili-text` — U+E049 (available in Regular)
 const ICON_BILIBILI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2287,6 +9688,7 @@ const ICON_BILIBILI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57417,
 )];
 
+/// `bilibiliindex` — U+E04C (available in Regular)
 const ICON_BILIBILIINDEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2295,6 +9697,7 @@ const ICON_BILIBILIINDEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57420,
 )];
 
+/// `bilibiliindex-text` — U+E04B (available in Regular)
 const ICON_BILIBILIINDEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2303,6 +9706,7 @@ const ICON_BILIBILIINDEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57419,
 )];
 
+/// `bing` — U+E04E (available in Regular)
 const ICON_BING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2311,6 +9715,7 @@ const ICON_BING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57422,
 )];
 
+/// `bing-text` — U+E04D (available in Regular)
 const ICON_BING_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2319,6 +9724,7 @@ const ICON_BING_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57421,
 )];
 
+/// `burncloud` — U+E050 (available in Regular)
 const ICON_BURNCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2327,6 +9733,7 @@ const ICON_BURNCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57424,
 )];
 
+/// `burncloud-text` — U+E04F (available in Regular)
 const ICON_BURNCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2335,6 +9742,7 @@ const ICON_BURNCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57423,
 )];
 
+/// `bytedance` — U+E054 (available in Regular)
 const ICON_BYTEDANCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2343,6 +9751,7 @@ const ICON_BYTEDANCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57428,
 )];
 
+/// `bytedance-brand` — U+E051 (available in Regular)
 const ICON_BYTEDANCE_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2351,6 +9760,7 @@ const ICON_BYTEDANCE_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57425,
 )];
 
+/// `bytedance-text` — U+E053 (available in Regular)
 const ICON_BYTEDANCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2359,6 +9769,7 @@ const ICON_BYTEDANCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57427,
 )];
 
+/// `bytedance-text-cn` — U+E052 (available in Regular)
 const ICON_BYTEDANCE_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2367,6 +9778,7 @@ const ICON_BYTEDANCE_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57426,
 )];
 
+/// `capcut` — U+E056 (available in Regular)
 const ICON_CAPCUT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2375,6 +9787,7 @@ const ICON_CAPCUT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57430,
 )];
 
+/// `capcut-text` — U+E055 (available in Regular)
 const ICON_CAPCUT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2383,6 +9796,7 @@ const ICON_CAPCUT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57429,
 )];
 
+/// `centml` — U+E059 (available in Regular)
 const ICON_CENTML_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2391,6 +9805,7 @@ const ICON_CENTML_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57433,
 )];
 
+/// `centml-brand` — U+E057 (available in Regular)
 const ICON_CENTML_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2399,6 +9814,7 @@ const ICON_CENTML_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57431,
 )];
 
+/// `centml-text` — U+E058 (available in Regular)
 const ICON_CENTML_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2407,6 +9823,7 @@ const ICON_CENTML_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57432,
 )];
 
+/// `cerebras` — U+E05C (available in Regular)
 const ICON_CEREBRAS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2415,6 +9832,7 @@ const ICON_CEREBRAS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57436,
 )];
 
+/// `cerebras-brand` — U+E05A (available in Regular)
 const ICON_CEREBRAS_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2423,6 +9841,7 @@ const ICON_CEREBRAS_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57434,
 )];
 
+/// `cerebras-text` — U+E05B (available in Regular)
 const ICON_CEREBRAS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2431,6 +9850,7 @@ const ICON_CEREBRAS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57435,
 )];
 
+/// `chatglm` — U+E05E (available in Regular)
 const ICON_CHATGLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2439,6 +9859,7 @@ const ICON_CHATGLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57438,
 )];
 
+/// `chatglm-text` — U+E05D (available in Regular)
 const ICON_CHATGLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2447,6 +9868,7 @@ const ICON_CHATGLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57437,
 )];
 
+/// `civitai` — U+E060 (available in Regular)
 const ICON_CIVITAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2455,6 +9877,7 @@ const ICON_CIVITAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57440,
 )];
 
+/// `civitai-text` — U+E05F (available in Regular)
 const ICON_CIVITAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2463,6 +9886,7 @@ const ICON_CIVITAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57439,
 )];
 
+/// `claude` — U+E062 (available in Regular)
 const ICON_CLAUDE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2471,6 +9895,7 @@ const ICON_CLAUDE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57442,
 )];
 
+/// `claude-text` — U+E061 (available in Regular)
 const ICON_CLAUDE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2479,6 +9904,7 @@ const ICON_CLAUDE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57441,
 )];
 
+/// `cline` — U+E064 (available in Regular)
 const ICON_CLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2487,6 +9913,7 @@ const ICON_CLINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57444,
 )];
 
+/// `cline-text` — U+E063 (available in Regular)
 const ICON_CLINE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2495,6 +9922,7 @@ const ICON_CLINE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57443,
 )];
 
+/// `clipdrop` — U+E066 (available in Regular)
 const ICON_CLIPDROP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2503,6 +9931,7 @@ const ICON_CLIPDROP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57446,
 )];
 
+/// `clipdrop-text` — U+E065 (available in Regular)
 const ICON_CLIPDROP_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2511,6 +9940,7 @@ const ICON_CLIPDROP_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57445,
 )];
 
+/// `cloudflare` — U+E068 (available in Regular)
 const ICON_CLOUDFLARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2519,6 +9949,7 @@ const ICON_CLOUDFLARE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57448,
 )];
 
+/// `cloudflare-text` — U+E067 (available in Regular)
 const ICON_CLOUDFLARE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2527,6 +9958,7 @@ const ICON_CLOUDFLARE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57447,
 )];
 
+/// `codegeex` — U+E06A (available in Regular)
 const ICON_CODEGEEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2535,6 +9967,7 @@ const ICON_CODEGEEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57450,
 )];
 
+/// `codegeex-text` — U+E069 (available in Regular)
 const ICON_CODEGEEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2543,6 +9976,7 @@ const ICON_CODEGEEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57449,
 )];
 
+/// `cogvideo` — U+E06C (available in Regular)
 const ICON_COGVIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2551,6 +9985,7 @@ const ICON_COGVIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57452,
 )];
 
+/// `cogvideo-text` — U+E06B (available in Regular)
 const ICON_COGVIDEO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2559,6 +9994,7 @@ const ICON_COGVIDEO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57451,
 )];
 
+/// `cogview` — U+E06E (available in Regular)
 const ICON_COGVIEW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2567,6 +10003,7 @@ const ICON_COGVIEW_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57454,
 )];
 
+/// `cogview-text` — U+E06D (available in Regular)
 const ICON_COGVIEW_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2575,6 +10012,7 @@ const ICON_COGVIEW_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57453,
 )];
 
+/// `cohere` — U+E070 (available in Regular)
 const ICON_COHERE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2583,6 +10021,7 @@ const ICON_COHERE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57456,
 )];
 
+/// `cohere-text` — U+E06F (available in Regular)
 const ICON_COHERE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2591,6 +10030,7 @@ const ICON_COHERE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57455,
 )];
 
+/// `colab` — U+E072 (available in Regular)
 const ICON_COLAB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2599,6 +10039,7 @@ const ICON_COLAB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57458,
 )];
 
+/// `colab-text` — U+E071 (available in Regular)
 const ICON_COLAB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2607,6 +10048,7 @@ const ICON_COLAB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57457,
 )];
 
+/// `cometapi` — U+E074 (available in Regular)
 const ICON_COMETAPI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2615,6 +10057,7 @@ const ICON_COMETAPI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57460,
 )];
 
+/// `cometapi-text` — U+E073 (available in Regular)
 const ICON_COMETAPI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2623,6 +10066,7 @@ const ICON_COMETAPI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57459,
 )];
 
+/// `comfyui` — U+E076 (available in Regular)
 const ICON_COMFYUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2631,6 +10075,7 @@ const ICON_COMFYUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57462,
 )];
 
+/// `comfyui-text` — U+E075 (available in Regular)
 const ICON_COMFYUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2639,6 +10084,7 @@ const ICON_COMFYUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57461,
 )];
 
+/// `commanda` — U+E078 (available in Regular)
 const ICON_COMMANDA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2647,6 +10093,7 @@ const ICON_COMMANDA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57464,
 )];
 
+/// `commanda-text` — U+E077 (available in Regular)
 const ICON_COMMANDA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2655,6 +10102,7 @@ const ICON_COMMANDA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57463,
 )];
 
+/// `copilot` — U+E07A (available in Regular)
 const ICON_COPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2663,6 +10111,7 @@ const ICON_COPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57466,
 )];
 
+/// `copilot-text` — U+E079 (available in Regular)
 const ICON_COPILOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2671,6 +10120,7 @@ const ICON_COPILOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57465,
 )];
 
+/// `copilotkit` — U+E07C (available in Regular)
 const ICON_COPILOTKIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2679,6 +10129,7 @@ const ICON_COPILOTKIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57468,
 )];
 
+/// `copilotkit-text` — U+E07B (available in Regular)
 const ICON_COPILOTKIT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2687,6 +10138,7 @@ const ICON_COPILOTKIT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57467,
 )];
 
+/// `coqui` — U+E07E (available in Regular)
 const ICON_COQUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2695,6 +10147,7 @@ const ICON_COQUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57470,
 )];
 
+/// `coqui-text` — U+E07D (available in Regular)
 const ICON_COQUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2703,6 +10156,7 @@ const ICON_COQUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57469,
 )];
 
+/// `coze` — U+E080 (available in Regular)
 const ICON_COZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2711,6 +10165,7 @@ const ICON_COZE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57472,
 )];
 
+/// `coze-text` — U+E07F (available in Regular)
 const ICON_COZE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2719,6 +10174,7 @@ const ICON_COZE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57471,
 )];
 
+/// `crewai` — U+E083 (available in Regular)
 const ICON_CREWAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2727,6 +10183,7 @@ const ICON_CREWAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57475,
 )];
 
+/// `crewai-brand` — U+E081 (available in Regular)
 const ICON_CREWAI_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2735,6 +10192,7 @@ const ICON_CREWAI_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57473,
 )];
 
+/// `crewai-text` — U+E082 (available in Regular)
 const ICON_CREWAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2743,6 +10201,7 @@ const ICON_CREWAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57474,
 )];
 
+/// `crusoe` — U+E085 (available in Regular)
 const ICON_CRUSOE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2751,6 +10210,7 @@ const ICON_CRUSOE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57477,
 )];
 
+/// `crusoe-text` — U+E084 (available in Regular)
 const ICON_CRUSOE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2759,6 +10219,7 @@ const ICON_CRUSOE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57476,
 )];
 
+/// `cursor` — U+E087 (available in Regular)
 const ICON_CURSOR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2767,6 +10228,7 @@ const ICON_CURSOR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57479,
 )];
 
+/// `cursor-text` — U+E086 (available in Regular)
 const ICON_CURSOR_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2775,6 +10237,7 @@ const ICON_CURSOR_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57478,
 )];
 
+/// `dalle` — U+E089 (available in Regular)
 const ICON_DALLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2783,6 +10246,7 @@ const ICON_DALLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57481,
 )];
 
+/// `dalle-text` — U+E088 (available in Regular)
 const ICON_DALLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2791,6 +10255,7 @@ const ICON_DALLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57480,
 )];
 
+/// `dbrx` — U+E08C (available in Regular)
 const ICON_DBRX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2799,6 +10264,7 @@ const ICON_DBRX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57484,
 )];
 
+/// `dbrx-brand` — U+E08A (available in Regular)
 const ICON_DBRX_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2807,6 +10273,7 @@ const ICON_DBRX_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57482,
 )];
 
+/// `dbrx-text` — U+E08B (available in Regular)
 const ICON_DBRX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2815,6 +10282,7 @@ const ICON_DBRX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57483,
 )];
 
+/// `deepai` — U+E08E (available in Regular)
 const ICON_DEEPAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2823,6 +10291,7 @@ const ICON_DEEPAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57486,
 )];
 
+/// `deepai-text` — U+E08D (available in Regular)
 const ICON_DEEPAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2831,6 +10300,7 @@ const ICON_DEEPAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57485,
 )];
 
+/// `deepinfra` — U+E090 (available in Regular)
 const ICON_DEEPINFRA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2839,6 +10309,7 @@ const ICON_DEEPINFRA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57488,
 )];
 
+/// `deepinfra-text` — U+E08F (available in Regular)
 const ICON_DEEPINFRA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2847,6 +10318,7 @@ const ICON_DEEPINFRA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57487,
 )];
 
+/// `deepl` — U+E092 (available in Regular)
 const ICON_DEEPL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2855,6 +10327,7 @@ const ICON_DEEPL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57490,
 )];
 
+/// `deepl-text` — U+E091 (available in Regular)
 const ICON_DEEPL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2863,6 +10336,7 @@ const ICON_DEEPL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57489,
 )];
 
+/// `deepmind` — U+E094 (available in Regular)
 const ICON_DEEPMIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2871,6 +10345,7 @@ const ICON_DEEPMIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57492,
 )];
 
+/// `deepmind-text` — U+E093 (available in Regular)
 const ICON_DEEPMIND_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2879,6 +10354,7 @@ const ICON_DEEPMIND_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57491,
 )];
 
+/// `deepseek` — U+E096 (available in Regular)
 const ICON_DEEPSEEK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2887,6 +10363,7 @@ const ICON_DEEPSEEK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57494,
 )];
 
+/// `deepseek-text` — U+E095 (available in Regular)
 const ICON_DEEPSEEK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2895,6 +10372,7 @@ const ICON_DEEPSEEK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57493,
 )];
 
+/// `dify` — U+E098 (available in Regular)
 const ICON_DIFY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2903,6 +10381,7 @@ const ICON_DIFY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57496,
 )];
 
+/// `dify-text` — U+E097 (available in Regular)
 const ICON_DIFY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2911,6 +10390,7 @@ const ICON_DIFY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57495,
 )];
 
+/// `doc2x` — U+E09A (available in Regular)
 const ICON_DOC_2X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2919,6 +10399,7 @@ const ICON_DOC_2X_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57498,
 )];
 
+/// `doc2x-text` — U+E099 (available in Regular)
 const ICON_DOC_2X_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2927,6 +10408,7 @@ const ICON_DOC_2X_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57497,
 )];
 
+/// `docsearch` — U+E09C (available in Regular)
 const ICON_DOCSEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2935,6 +10417,7 @@ const ICON_DOCSEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57500,
 )];
 
+/// `docsearch-text` — U+E09B (available in Regular)
 const ICON_DOCSEARCH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2943,6 +10426,7 @@ const ICON_DOCSEARCH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57499,
 )];
 
+/// `dolphin` — U+E09E (available in Regular)
 const ICON_DOLPHIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2951,6 +10435,7 @@ const ICON_DOLPHIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57502,
 )];
 
+/// `dolphin-text` — U+E09D (available in Regular)
 const ICON_DOLPHIN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2959,6 +10444,7 @@ const ICON_DOLPHIN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57501,
 )];
 
+/// `doubao` — U+E0A0 (available in Regular)
 const ICON_DOUBAO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2967,6 +10453,7 @@ const ICON_DOUBAO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57504,
 )];
 
+/// `doubao-text` — U+E09F (available in Regular)
 const ICON_DOUBAO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2975,6 +10462,7 @@ const ICON_DOUBAO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57503,
 )];
 
+/// `dreammachine` — U+E0A2 (available in Regular)
 const ICON_DREAMMACHINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2983,6 +10471,7 @@ const ICON_DREAMMACHINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57506,
 )];
 
+/// `dreammachine-text` — U+E0A1 (available in Regular)
 const ICON_DREAMMACHINE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2991,6 +10480,7 @@ const ICON_DREAMMACHINE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57505,
 )];
 
+/// `elevenlabs` — U+E0A4 (available in Regular)
 const ICON_ELEVENLABS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -2999,6 +10489,7 @@ const ICON_ELEVENLABS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57508,
 )];
 
+/// `elevenlabs-text` — U+E0A3 (available in Regular)
 const ICON_ELEVENLABS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3007,6 +10498,7 @@ const ICON_ELEVENLABS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57507,
 )];
 
+/// `elevenx` — U+E0A6 (available in Regular)
 const ICON_ELEVENX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3015,6 +10507,7 @@ const ICON_ELEVENX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57510,
 )];
 
+/// `elevenx-text` — U+E0A5 (available in Regular)
 const ICON_ELEVENX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3023,6 +10516,7 @@ const ICON_ELEVENX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57509,
 )];
 
+/// `exa` — U+E0A8 (available in Regular)
 const ICON_EXA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3031,6 +10525,7 @@ const ICON_EXA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57512,
 )];
 
+/// `exa-text` — U+E0A7 (available in Regular)
 const ICON_EXA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3039,6 +10534,7 @@ const ICON_EXA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57511,
 )];
 
+/// `fal` — U+E0AA (available in Regular)
 const ICON_FAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3047,6 +10543,7 @@ const ICON_FAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57514,
 )];
 
+/// `fal-text` — U+E0A9 (available in Regular)
 const ICON_FAL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3055,6 +10552,7 @@ const ICON_FAL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57513,
 )];
 
+/// `fastgpt` — U+E0AC (available in Regular)
 const ICON_FASTGPT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3063,6 +10561,7 @@ const ICON_FASTGPT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57516,
 )];
 
+/// `fastgpt-text` — U+E0AB (available in Regular)
 const ICON_FASTGPT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3071,6 +10570,7 @@ const ICON_FASTGPT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57515,
 )];
 
+/// `featherless` — U+E0AE (available in Regular)
 const ICON_FEATHERLESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3079,6 +10579,7 @@ const ICON_FEATHERLESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57518,
 )];
 
+/// `featherless-text` — U+E0AD (available in Regular)
 const ICON_FEATHERLESS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3087,6 +10588,7 @@ const ICON_FEATHERLESS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57517,
 )];
 
+/// `figma` — U+E0B0 (available in Regular)
 const ICON_FIGMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3095,6 +10597,7 @@ const ICON_FIGMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57520,
 )];
 
+/// `figma-text` — U+E0AF (available in Regular)
 const ICON_FIGMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3103,6 +10606,7 @@ const ICON_FIGMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57519,
 )];
 
+/// `fireworks` — U+E0B2 (available in Regular)
 const ICON_FIREWORKS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3111,6 +10615,7 @@ const ICON_FIREWORKS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57522,
 )];
 
+/// `fireworks-text` — U+E0B1 (available in Regular)
 const ICON_FIREWORKS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3119,6 +10624,7 @@ const ICON_FIREWORKS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57521,
 )];
 
+/// `fishaudio` — U+E0B4 (available in Regular)
 const ICON_FISHAUDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3127,6 +10633,7 @@ const ICON_FISHAUDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57524,
 )];
 
+/// `fishaudio-text` — U+E0B3 (available in Regular)
 const ICON_FISHAUDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3135,6 +10642,7 @@ const ICON_FISHAUDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57523,
 )];
 
+/// `flora` — U+E0B6 (available in Regular)
 const ICON_FLORA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3143,6 +10651,7 @@ const ICON_FLORA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57526,
 )];
 
+/// `flora-text` — U+E0B5 (available in Regular)
 const ICON_FLORA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3151,6 +10660,7 @@ const ICON_FLORA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57525,
 )];
 
+/// `flowith` — U+E0B8 (available in Regular)
 const ICON_FLOWITH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3159,6 +10669,7 @@ const ICON_FLOWITH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57528,
 )];
 
+/// `flowith-text` — U+E0B7 (available in Regular)
 const ICON_FLOWITH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3167,6 +10678,7 @@ const ICON_FLOWITH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57527,
 )];
 
+/// `flux` — U+E0BA (available in Regular)
 const ICON_FLUX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3175,6 +10687,7 @@ const ICON_FLUX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57530,
 )];
 
+/// `flux-text` — U+E0B9 (available in Regular)
 const ICON_FLUX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3183,6 +10696,7 @@ const ICON_FLUX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57529,
 )];
 
+/// `friendli` — U+E0BC (available in Regular)
 const ICON_FRIENDLI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3191,6 +10705,7 @@ const ICON_FRIENDLI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57532,
 )];
 
+/// `friendli-text` — U+E0BB (available in Regular)
 const ICON_FRIENDLI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3199,6 +10714,7 @@ const ICON_FRIENDLI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57531,
 )];
 
+/// `gemini` — U+E0BE (available in Regular)
 const ICON_GEMINI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3207,6 +10723,7 @@ const ICON_GEMINI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57534,
 )];
 
+/// `gemini-text` — U+E0BD (available in Regular)
 const ICON_GEMINI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3215,6 +10732,7 @@ const ICON_GEMINI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57533,
 )];
 
+/// `gemma` — U+E0C0 (available in Regular)
 const ICON_GEMMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3223,6 +10741,7 @@ const ICON_GEMMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57536,
 )];
 
+/// `gemma-text` — U+E0BF (available in Regular)
 const ICON_GEMMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3231,6 +10750,7 @@ const ICON_GEMMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57535,
 )];
 
+/// `giteeai` — U+E0C2 (available in Regular)
 const ICON_GITEEAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3239,6 +10759,7 @@ const ICON_GITEEAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57538,
 )];
 
+/// `giteeai-text` — U+E0C1 (available in Regular)
 const ICON_GITEEAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3247,6 +10768,7 @@ const ICON_GITEEAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57537,
 )];
 
+/// `github` — U+E0C4 (available in Regular)
 const ICON_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3255,6 +10777,7 @@ const ICON_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57540,
 )];
 
+/// `github-text` — U+E0C3 (available in Regular)
 const ICON_GITHUB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3263,6 +10786,7 @@ const ICON_GITHUB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57539,
 )];
 
+/// `githubcopilot` — U+E0C6 (available in Regular)
 const ICON_GITHUBCOPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3271,6 +10795,7 @@ const ICON_GITHUBCOPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57542,
 )];
 
+/// `githubcopilot-text` — U+E0C5 (available in Regular)
 const ICON_GITHUBCOPILOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3279,6 +10804,7 @@ const ICON_GITHUBCOPILOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57541,
 )];
 
+/// `glama` — U+E0C8 (available in Regular)
 const ICON_GLAMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3287,6 +10813,7 @@ const ICON_GLAMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57544,
 )];
 
+/// `glama-text` — U+E0C7 (available in Regular)
 const ICON_GLAMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3295,6 +10822,7 @@ const ICON_GLAMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57543,
 )];
 
+/// `glif` — U+E0CA (available in Regular)
 const ICON_GLIF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3303,6 +10831,7 @@ const ICON_GLIF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57546,
 )];
 
+/// `glif-text` — U+E0C9 (available in Regular)
 const ICON_GLIF_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3311,6 +10840,7 @@ const ICON_GLIF_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57545,
 )];
 
+/// `glmv` — U+E0CC (available in Regular)
 const ICON_GLMV_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3319,6 +10849,7 @@ const ICON_GLMV_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57548,
 )];
 
+/// `glmv-text` — U+E0CB (available in Regular)
 const ICON_GLMV_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3327,6 +10858,7 @@ const ICON_GLMV_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57547,
 )];
 
+/// `google` — U+E0CE (available in Regular)
 const ICON_GOOGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3335,6 +10867,7 @@ const ICON_GOOGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57550,
 )];
 
+/// `google-brand` — U+E0CD (available in Regular)
 const ICON_GOOGLE_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3343,6 +10876,7 @@ const ICON_GOOGLE_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57549,
 )];
 
+/// `googlecloud` — U+E0D0 (available in Regular)
 const ICON_GOOGLECLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3351,6 +10885,7 @@ const ICON_GOOGLECLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57552,
 )];
 
+/// `googlecloud-brand` — U+E0CF (available in Regular)
 const ICON_GOOGLECLOUD_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3359,6 +10894,7 @@ const ICON_GOOGLECLOUD_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57551,
 )];
 
+/// `goose` — U+E0D2 (available in Regular)
 const ICON_GOOSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3367,6 +10903,7 @@ const ICON_GOOSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57554,
 )];
 
+/// `goose-text` — U+E0D1 (available in Regular)
 const ICON_GOOSE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3375,6 +10912,7 @@ const ICON_GOOSE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57553,
 )];
 
+/// `gradio` — U+E0D4 (available in Regular)
 const ICON_GRADIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3383,6 +10921,7 @@ const ICON_GRADIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57556,
 )];
 
+/// `gradio-text` — U+E0D3 (available in Regular)
 const ICON_GRADIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3391,6 +10930,7 @@ const ICON_GRADIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57555,
 )];
 
+/// `greptile` — U+E0D6 (available in Regular)
 const ICON_GREPTILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3399,6 +10939,7 @@ const ICON_GREPTILE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57558,
 )];
 
+/// `greptile-text` — U+E0D5 (available in Regular)
 const ICON_GREPTILE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3407,6 +10948,7 @@ const ICON_GREPTILE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57557,
 )];
 
+/// `grok` — U+E0D8 (available in Regular)
 const ICON_GROK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3415,6 +10957,7 @@ const ICON_GROK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57560,
 )];
 
+/// `grok-text` — U+E0D7 (available in Regular)
 const ICON_GROK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3423,6 +10966,7 @@ const ICON_GROK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57559,
 )];
 
+/// `groq` — U+E0DA (available in Regular)
 const ICON_GROQ_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3431,6 +10975,7 @@ const ICON_GROQ_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57562,
 )];
 
+/// `groq-text` — U+E0D9 (available in Regular)
 const ICON_GROQ_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3439,6 +10984,7 @@ const ICON_GROQ_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57561,
 )];
 
+/// `hailuo` — U+E0DC (available in Regular)
 const ICON_HAILUO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3447,6 +10993,7 @@ const ICON_HAILUO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57564,
 )];
 
+/// `hailuo-text` — U+E0DB (available in Regular)
 const ICON_HAILUO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3455,6 +11002,7 @@ const ICON_HAILUO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57563,
 )];
 
+/// `haiper` — U+E0DE (available in Regular)
 const ICON_HAIPER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3463,6 +11011,7 @@ const ICON_HAIPER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57566,
 )];
 
+/// `haiper-text` — U+E0DD (available in Regular)
 const ICON_HAIPER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3471,6 +11020,7 @@ const ICON_HAIPER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57565,
 )];
 
+/// `hedra` — U+E0E0 (available in Regular)
 const ICON_HEDRA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3479,6 +11029,7 @@ const ICON_HEDRA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57568,
 )];
 
+/// `hedra-text` — U+E0DF (available in Regular)
 const ICON_HEDRA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3487,6 +11038,7 @@ const ICON_HEDRA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57567,
 )];
 
+/// `higress` — U+E0E2 (available in Regular)
 const ICON_HIGRESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3495,6 +11047,7 @@ const ICON_HIGRESS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57570,
 )];
 
+/// `higress-text` — U+E0E1 (available in Regular)
 const ICON_HIGRESS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3503,6 +11056,7 @@ const ICON_HIGRESS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57569,
 )];
 
+/// `huawei` — U+E0E5 (available in Regular)
 const ICON_HUAWEI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3511,6 +11065,7 @@ const ICON_HUAWEI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57573,
 )];
 
+/// `huawei-text` — U+E0E4 (available in Regular)
 const ICON_HUAWEI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3519,6 +11074,7 @@ const ICON_HUAWEI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57572,
 )];
 
+/// `huawei-text-cn` — U+E0E3 (available in Regular)
 const ICON_HUAWEI_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3527,6 +11083,7 @@ const ICON_HUAWEI_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57571,
 )];
 
+/// `huaweicloud` — U+E0E8 (available in Regular)
 const ICON_HUAWEICLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3535,6 +11092,7 @@ const ICON_HUAWEICLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57576,
 )];
 
+/// `huaweicloud-text` — U+E0E7 (available in Regular)
 const ICON_HUAWEICLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3543,6 +11101,7 @@ const ICON_HUAWEICLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57575,
 )];
 
+/// `huaweicloud-text-cn` — U+E0E6 (available in Regular)
 const ICON_HUAWEICLOUD_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3551,6 +11110,7 @@ const ICON_HUAWEICLOUD_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57574,
 )];
 
+/// `huggingface` — U+E0EA (available in Regular)
 const ICON_HUGGINGFACE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3559,6 +11119,7 @@ const ICON_HUGGINGFACE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57578,
 )];
 
+/// `huggingface-text` — U+E0E9 (available in Regular)
 const ICON_HUGGINGFACE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3567,6 +11128,7 @@ const ICON_HUGGINGFACE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57577,
 )];
 
+/// `hunyuan` — U+E0EC (available in Regular)
 const ICON_HUNYUAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3575,6 +11137,7 @@ const ICON_HUNYUAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57580,
 )];
 
+/// `hunyuan-text` — U+E0EB (available in Regular)
 const ICON_HUNYUAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3583,6 +11146,7 @@ const ICON_HUNYUAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57579,
 )];
 
+/// `hyperbolic` — U+E0EE (available in Regular)
 const ICON_HYPERBOLIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3591,6 +11155,7 @@ const ICON_HYPERBOLIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57582,
 )];
 
+/// `hyperbolic-text` — U+E0ED (available in Regular)
 const ICON_HYPERBOLIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3599,6 +11164,7 @@ const ICON_HYPERBOLIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57581,
 )];
 
+/// `ibm` — U+E0F0 (available in Regular)
 const ICON_IBM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3607,6 +11173,7 @@ const ICON_IBM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57584,
 )];
 
+/// `ibm-text` — U+E0EF (available in Regular)
 const ICON_IBM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3615,6 +11182,7 @@ const ICON_IBM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57583,
 )];
 
+/// `ideogram` — U+E0F2 (available in Regular)
 const ICON_IDEOGRAM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3623,6 +11191,7 @@ const ICON_IDEOGRAM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57586,
 )];
 
+/// `ideogram-text` — U+E0F1 (available in Regular)
 const ICON_IDEOGRAM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3631,6 +11200,7 @@ const ICON_IDEOGRAM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57585,
 )];
 
+/// `iflytekcloud` — U+E0F4 (available in Regular)
 const ICON_IFLYTEKCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3639,6 +11209,7 @@ const ICON_IFLYTEKCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57588,
 )];
 
+/// `iflytekcloud-text` — U+E0F3 (available in Regular)
 const ICON_IFLYTEKCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3647,6 +11218,7 @@ const ICON_IFLYTEKCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57587,
 )];
 
+/// `inference` — U+E0F6 (available in Regular)
 const ICON_INFERENCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3655,6 +11227,7 @@ const ICON_INFERENCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57590,
 )];
 
+/// `inference-text` — U+E0F5 (available in Regular)
 const ICON_INFERENCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3663,6 +11236,7 @@ const ICON_INFERENCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57589,
 )];
 
+/// `infermatic` — U+E0F8 (available in Regular)
 const ICON_INFERMATIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3671,6 +11245,7 @@ const ICON_INFERMATIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57592,
 )];
 
+/// `infermatic-text` — U+E0F7 (available in Regular)
 const ICON_INFERMATIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3679,6 +11254,7 @@ const ICON_INFERMATIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57591,
 )];
 
+/// `infinigence` — U+E0FB (available in Regular)
 const ICON_INFINIGENCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3687,6 +11263,7 @@ const ICON_INFINIGENCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57595,
 )];
 
+/// `infinigence-text` — U+E0FA (available in Regular)
 const ICON_INFINIGENCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3695,6 +11272,7 @@ const ICON_INFINIGENCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57594,
 )];
 
+/// `infinigence-text-cn` — U+E0F9 (available in Regular)
 const ICON_INFINIGENCE_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3703,6 +11281,7 @@ const ICON_INFINIGENCE_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57593,
 )];
 
+/// `inflection` — U+E0FD (available in Regular)
 const ICON_INFLECTION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3711,6 +11290,7 @@ const ICON_INFLECTION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57597,
 )];
 
+/// `inflection-text` — U+E0FC (available in Regular)
 const ICON_INFLECTION_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3719,6 +11299,7 @@ const ICON_INFLECTION_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57596,
 )];
 
+/// `internlm` — U+E0FF (available in Regular)
 const ICON_INTERNLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3727,6 +11308,7 @@ const ICON_INTERNLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57599,
 )];
 
+/// `internlm-text` — U+E0FE (available in Regular)
 const ICON_INTERNLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3735,6 +11317,7 @@ const ICON_INTERNLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57598,
 )];
 
+/// `jimeng` — U+E101 (available in Regular)
 const ICON_JIMENG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3743,6 +11326,7 @@ const ICON_JIMENG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57601,
 )];
 
+/// `jimeng-text` — U+E100 (available in Regular)
 const ICON_JIMENG_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3751,6 +11335,7 @@ const ICON_JIMENG_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57600,
 )];
 
+/// `jina` — U+E103 (available in Regular)
 const ICON_JINA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3759,6 +11344,7 @@ const ICON_JINA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57603,
 )];
 
+/// `jina-text` — U+E102 (available in Regular)
 const ICON_JINA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3767,6 +11353,7 @@ const ICON_JINA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57602,
 )];
 
+/// `kimi` — U+E105 (available in Regular)
 const ICON_KIMI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3775,6 +11362,7 @@ const ICON_KIMI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57605,
 )];
 
+/// `kimi-text` — U+E104 (available in Regular)
 const ICON_KIMI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3783,6 +11371,7 @@ const ICON_KIMI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57604,
 )];
 
+/// `kling` — U+E107 (available in Regular)
 const ICON_KLING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3791,6 +11380,7 @@ const ICON_KLING_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57607,
 )];
 
+/// `kling-text` — U+E106 (available in Regular)
 const ICON_KLING_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3799,6 +11389,7 @@ const ICON_KLING_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57606,
 )];
 
+/// `kluster` — U+E109 (available in Regular)
 const ICON_KLUSTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3807,6 +11398,7 @@ const ICON_KLUSTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57609,
 )];
 
+/// `kluster-text` — U+E108 (available in Regular)
 const ICON_KLUSTER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3815,6 +11407,7 @@ const ICON_KLUSTER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57608,
 )];
 
+/// `kolors` — U+E10B (available in Regular)
 const ICON_KOLORS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3823,6 +11416,7 @@ const ICON_KOLORS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57611,
 )];
 
+/// `kolors-text` — U+E10A (available in Regular)
 const ICON_KOLORS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3831,6 +11425,7 @@ const ICON_KOLORS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57610,
 )];
 
+/// `krea` — U+E10D (available in Regular)
 const ICON_KREA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3839,6 +11434,7 @@ const ICON_KREA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57613,
 )];
 
+/// `krea-text` — U+E10C (available in Regular)
 const ICON_KREA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3847,6 +11443,7 @@ const ICON_KREA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57612,
 )];
 
+/// `kwaipilot` — U+E10F (available in Regular)
 const ICON_KWAIPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3855,6 +11452,7 @@ const ICON_KWAIPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57615,
 )];
 
+/// `kwaipilot-text` — U+E10E (available in Regular)
 const ICON_KWAIPILOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3863,6 +11461,7 @@ const ICON_KWAIPILOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57614,
 )];
 
+/// `lambda` — U+E111 (available in Regular)
 const ICON_LAMBDA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3871,6 +11470,7 @@ const ICON_LAMBDA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57617,
 )];
 
+/// `lambda-text` — U+E110 (available in Regular)
 const ICON_LAMBDA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3879,6 +11479,7 @@ const ICON_LAMBDA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57616,
 )];
 
+/// `langchain` — U+E113 (available in Regular)
 const ICON_LANGCHAIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3887,6 +11488,7 @@ const ICON_LANGCHAIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57619,
 )];
 
+/// `langchain-text` — U+E112 (available in Regular)
 const ICON_LANGCHAIN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3895,6 +11497,7 @@ const ICON_LANGCHAIN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57618,
 )];
 
+/// `langfuse` — U+E115 (available in Regular)
 const ICON_LANGFUSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3903,6 +11506,7 @@ const ICON_LANGFUSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57621,
 )];
 
+/// `langfuse-text` — U+E114 (available in Regular)
 const ICON_LANGFUSE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3911,6 +11515,7 @@ const ICON_LANGFUSE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57620,
 )];
 
+/// `langgraph` — U+E117 (available in Regular)
 const ICON_LANGGRAPH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3919,6 +11524,7 @@ const ICON_LANGGRAPH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57623,
 )];
 
+/// `langgraph-text` — U+E116 (available in Regular)
 const ICON_LANGGRAPH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3927,6 +11533,7 @@ const ICON_LANGGRAPH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57622,
 )];
 
+/// `langsmith` — U+E119 (available in Regular)
 const ICON_LANGSMITH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3935,6 +11542,7 @@ const ICON_LANGSMITH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57625,
 )];
 
+/// `langsmith-text` — U+E118 (available in Regular)
 const ICON_LANGSMITH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3943,6 +11551,7 @@ const ICON_LANGSMITH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57624,
 )];
 
+/// `leptonai` — U+E11B (available in Regular)
 const ICON_LEPTONAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3951,6 +11560,7 @@ const ICON_LEPTONAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57627,
 )];
 
+/// `leptonai-text` — U+E11A (available in Regular)
 const ICON_LEPTONAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3959,6 +11569,7 @@ const ICON_LEPTONAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57626,
 )];
 
+/// `lg` — U+E11D (available in Regular)
 const ICON_LG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3967,6 +11578,7 @@ const ICON_LG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57629,
 )];
 
+/// `lg-text` — U+E11C (available in Regular)
 const ICON_LG_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3975,6 +11587,7 @@ const ICON_LG_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57628,
 )];
 
+/// `lightricks` — U+E11F (available in Regular)
 const ICON_LIGHTRICKS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3983,6 +11596,7 @@ const ICON_LIGHTRICKS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57631,
 )];
 
+/// `lightricks-text` — U+E11E (available in Regular)
 const ICON_LIGHTRICKS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3991,6 +11605,7 @@ const ICON_LIGHTRICKS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57630,
 )];
 
+/// `liquid` — U+E121 (available in Regular)
 const ICON_LIQUID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -3999,6 +11614,7 @@ const ICON_LIQUID_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57633,
 )];
 
+/// `liquid-text` — U+E120 (available in Regular)
 const ICON_LIQUID_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4007,6 +11623,7 @@ const ICON_LIQUID_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57632,
 )];
 
+/// `livekit` — U+E123 (available in Regular)
 const ICON_LIVEKIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4015,6 +11632,7 @@ const ICON_LIVEKIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57635,
 )];
 
+/// `livekit-text` — U+E122 (available in Regular)
 const ICON_LIVEKIT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4023,6 +11641,7 @@ const ICON_LIVEKIT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57634,
 )];
 
+/// `llamaindex` — U+E125 (available in Regular)
 const ICON_LLAMAINDEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4031,6 +11650,7 @@ const ICON_LLAMAINDEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57637,
 )];
 
+/// `llamaindex-text` — U+E124 (available in Regular)
 const ICON_LLAMAINDEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4039,6 +11659,7 @@ const ICON_LLAMAINDEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57636,
 )];
 
+/// `llava` — U+E127 (available in Regular)
 const ICON_LLAVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4047,6 +11668,7 @@ const ICON_LLAVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57639,
 )];
 
+/// `llava-text` — U+E126 (available in Regular)
 const ICON_LLAVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4055,6 +11677,7 @@ const ICON_LLAVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57638,
 )];
 
+/// `lmstudio` — U+E129 (available in Regular)
 const ICON_LMSTUDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4063,6 +11686,7 @@ const ICON_LMSTUDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57641,
 )];
 
+/// `lmstudio-text` — U+E128 (available in Regular)
 const ICON_LMSTUDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4071,6 +11695,7 @@ const ICON_LMSTUDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57640,
 )];
 
+/// `lobehub` — U+E12B (available in Regular)
 const ICON_LOBEHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4079,6 +11704,7 @@ const ICON_LOBEHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57643,
 )];
 
+/// `lobehub-text` — U+E12A (available in Regular)
 const ICON_LOBEHUB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4087,6 +11713,7 @@ const ICON_LOBEHUB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57642,
 )];
 
+/// `longcat` — U+E12D (available in Regular)
 const ICON_LONGCAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4095,6 +11722,7 @@ const ICON_LONGCAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57645,
 )];
 
+/// `longcat-text` — U+E12C (available in Regular)
 const ICON_LONGCAT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4103,6 +11731,7 @@ const ICON_LONGCAT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57644,
 )];
 
+/// `lovable` — U+E12F (available in Regular)
 const ICON_LOVABLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4111,6 +11740,7 @@ const ICON_LOVABLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57647,
 )];
 
+/// `lovable-text` — U+E12E (available in Regular)
 const ICON_LOVABLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4119,6 +11749,7 @@ const ICON_LOVABLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57646,
 )];
 
+/// `luma` — U+E131 (available in Regular)
 const ICON_LUMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4127,6 +11758,7 @@ const ICON_LUMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57649,
 )];
 
+/// `luma-text` — U+E130 (available in Regular)
 const ICON_LUMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4135,6 +11767,7 @@ const ICON_LUMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57648,
 )];
 
+/// `magic` — U+E133 (available in Regular)
 const ICON_MAGIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4143,6 +11776,7 @@ const ICON_MAGIC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57651,
 )];
 
+/// `magic-text` — U+E132 (available in Regular)
 const ICON_MAGIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4151,6 +11785,7 @@ const ICON_MAGIC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57650,
 )];
 
+/// `make` — U+E135 (available in Regular)
 const ICON_MAKE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4159,6 +11794,7 @@ const ICON_MAKE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57653,
 )];
 
+/// `make-text` — U+E134 (available in Regular)
 const ICON_MAKE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4167,6 +11803,7 @@ const ICON_MAKE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57652,
 )];
 
+/// `manus` — U+E137 (available in Regular)
 const ICON_MANUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4175,6 +11812,7 @@ const ICON_MANUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57655,
 )];
 
+/// `manus-text` — U+E136 (available in Regular)
 const ICON_MANUS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4183,6 +11821,7 @@ const ICON_MANUS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57654,
 )];
 
+/// `mastra` — U+E139 (available in Regular)
 const ICON_MASTRA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4191,6 +11830,7 @@ const ICON_MASTRA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57657,
 )];
 
+/// `mastra-text` — U+E138 (available in Regular)
 const ICON_MASTRA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4199,6 +11839,7 @@ const ICON_MASTRA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57656,
 )];
 
+/// `mcp` — U+E13B (available in Regular)
 const ICON_MCP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4207,6 +11848,7 @@ const ICON_MCP_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57659,
 )];
 
+/// `mcp-text` — U+E13A (available in Regular)
 const ICON_MCP_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4215,6 +11857,7 @@ const ICON_MCP_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57658,
 )];
 
+/// `mcpso` — U+E13D (available in Regular)
 const ICON_MCPSO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4223,6 +11866,7 @@ const ICON_MCPSO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57661,
 )];
 
+/// `mcpso-text` — U+E13C (available in Regular)
 const ICON_MCPSO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4231,6 +11875,7 @@ const ICON_MCPSO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57660,
 )];
 
+/// `menlo` — U+E13F (available in Regular)
 const ICON_MENLO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4239,6 +11884,7 @@ const ICON_MENLO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57663,
 )];
 
+/// `menlo-text` — U+E13E (available in Regular)
 const ICON_MENLO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4247,6 +11893,7 @@ const ICON_MENLO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57662,
 )];
 
+/// `meta` — U+E142 (available in Regular)
 const ICON_META_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4255,6 +11902,7 @@ const ICON_META_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57666,
 )];
 
+/// `meta-brand` — U+E140 (available in Regular)
 const ICON_META_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4263,6 +11911,7 @@ const ICON_META_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57664,
 )];
 
+/// `meta-text` — U+E141 (available in Regular)
 const ICON_META_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4271,6 +11920,7 @@ const ICON_META_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57665,
 )];
 
+/// `metaai` — U+E144 (available in Regular)
 const ICON_METAAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4279,6 +11929,7 @@ const ICON_METAAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57668,
 )];
 
+/// `metaai-text` — U+E143 (available in Regular)
 const ICON_METAAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4287,6 +11938,7 @@ const ICON_METAAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57667,
 )];
 
+/// `metagpt` — U+E146 (available in Regular)
 const ICON_METAGPT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4295,6 +11947,7 @@ const ICON_METAGPT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57670,
 )];
 
+/// `metagpt-text` — U+E145 (available in Regular)
 const ICON_METAGPT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4303,6 +11956,7 @@ const ICON_METAGPT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57669,
 )];
 
+/// `microsoft` — U+E148 (available in Regular)
 const ICON_MICROSOFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4311,6 +11965,7 @@ const ICON_MICROSOFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57672,
 )];
 
+/// `microsoft-text` — U+E147 (available in Regular)
 const ICON_MICROSOFT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4319,6 +11974,7 @@ const ICON_MICROSOFT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57671,
 )];
 
+/// `midjourney` — U+E14A (available in Regular)
 const ICON_MIDJOURNEY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4327,6 +11983,7 @@ const ICON_MIDJOURNEY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57674,
 )];
 
+/// `midjourney-text` — U+E149 (available in Regular)
 const ICON_MIDJOURNEY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4335,6 +11992,7 @@ const ICON_MIDJOURNEY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57673,
 )];
 
+/// `minimax` — U+E14C (available in Regular)
 const ICON_MINIMAX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4343,6 +12001,7 @@ const ICON_MINIMAX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57676,
 )];
 
+/// `minimax-text` — U+E14B (available in Regular)
 const ICON_MINIMAX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4351,6 +12010,7 @@ const ICON_MINIMAX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57675,
 )];
 
+/// `mistral` — U+E14E (available in Regular)
 const ICON_MISTRAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4359,6 +12019,7 @@ const ICON_MISTRAL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57678,
 )];
 
+/// `mistral-text` — U+E14D (available in Regular)
 const ICON_MISTRAL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4367,6 +12028,7 @@ const ICON_MISTRAL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57677,
 )];
 
+/// `modelscope` — U+E150 (available in Regular)
 const ICON_MODELSCOPE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4375,6 +12037,7 @@ const ICON_MODELSCOPE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57680,
 )];
 
+/// `modelscope-text` — U+E14F (available in Regular)
 const ICON_MODELSCOPE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4383,6 +12046,7 @@ const ICON_MODELSCOPE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57679,
 )];
 
+/// `monica` — U+E152 (available in Regular)
 const ICON_MONICA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4391,6 +12055,7 @@ const ICON_MONICA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57682,
 )];
 
+/// `monica-text` — U+E151 (available in Regular)
 const ICON_MONICA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4399,6 +12064,7 @@ const ICON_MONICA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57681,
 )];
 
+/// `moonshot` — U+E154 (available in Regular)
 const ICON_MOONSHOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4407,6 +12073,7 @@ const ICON_MOONSHOT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57684,
 )];
 
+/// `moonshot-text` — U+E153 (available in Regular)
 const ICON_MOONSHOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4415,6 +12082,7 @@ const ICON_MOONSHOT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57683,
 )];
 
+/// `myshell` — U+E156 (available in Regular)
 const ICON_MYSHELL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4423,6 +12091,7 @@ const ICON_MYSHELL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57686,
 )];
 
+/// `myshell-text` — U+E155 (available in Regular)
 const ICON_MYSHELL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4431,6 +12100,7 @@ const ICON_MYSHELL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57685,
 )];
 
+/// `n8n` — U+E158 (available in Regular)
 const ICON_N_8N_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4439,6 +12109,7 @@ const ICON_N_8N_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57688,
 )];
 
+/// `n8n-text` — U+E157 (available in Regular)
 const ICON_N_8N_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4447,6 +12118,7 @@ const ICON_N_8N_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57687,
 )];
 
+/// `nebius` — U+E15A (available in Regular)
 const ICON_NEBIUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4455,6 +12127,7 @@ const ICON_NEBIUS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57690,
 )];
 
+/// `nebius-text` — U+E159 (available in Regular)
 const ICON_NEBIUS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4463,6 +12136,7 @@ const ICON_NEBIUS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57689,
 )];
 
+/// `newapi` — U+E15C (available in Regular)
 const ICON_NEWAPI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4471,6 +12145,7 @@ const ICON_NEWAPI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57692,
 )];
 
+/// `newapi-text` — U+E15B (available in Regular)
 const ICON_NEWAPI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4479,6 +12154,7 @@ const ICON_NEWAPI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57691,
 )];
 
+/// `notebooklm` — U+E15E (available in Regular)
 const ICON_NOTEBOOKLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4487,6 +12163,7 @@ const ICON_NOTEBOOKLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57694,
 )];
 
+/// `notebooklm-text` — U+E15D (available in Regular)
 const ICON_NOTEBOOKLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4495,6 +12172,7 @@ const ICON_NOTEBOOKLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57693,
 )];
 
+/// `notion` — U+E160 (available in Regular)
 const ICON_NOTION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4503,6 +12181,7 @@ const ICON_NOTION_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57696,
 )];
 
+/// `notion-text` — U+E15F (available in Regular)
 const ICON_NOTION_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4511,6 +12190,7 @@ const ICON_NOTION_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57695,
 )];
 
+/// `nousresearch` — U+E162 (available in Regular)
 const ICON_NOUSRESEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4519,6 +12199,7 @@ const ICON_NOUSRESEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57698,
 )];
 
+/// `nousresearch-text` — U+E161 (available in Regular)
 const ICON_NOUSRESEARCH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4527,6 +12208,7 @@ const ICON_NOUSRESEARCH_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57697,
 )];
 
+/// `nova` — U+E164 (available in Regular)
 const ICON_NOVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4535,6 +12217,7 @@ const ICON_NOVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57700,
 )];
 
+/// `nova-text` — U+E163 (available in Regular)
 const ICON_NOVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4543,6 +12226,7 @@ const ICON_NOVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57699,
 )];
 
+/// `novelai` — U+E166 (available in Regular)
 const ICON_NOVELAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4551,6 +12235,7 @@ const ICON_NOVELAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57702,
 )];
 
+/// `novelai-text` — U+E165 (available in Regular)
 const ICON_NOVELAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4559,6 +12244,7 @@ const ICON_NOVELAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57701,
 )];
 
+/// `novita` — U+E168 (available in Regular)
 const ICON_NOVITA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4567,6 +12253,7 @@ const ICON_NOVITA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57704,
 )];
 
+/// `novita-text` — U+E167 (available in Regular)
 const ICON_NOVITA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4575,6 +12262,7 @@ const ICON_NOVITA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57703,
 )];
 
+/// `nplcloud` — U+E16A (available in Regular)
 const ICON_NPLCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4583,6 +12271,7 @@ const ICON_NPLCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57706,
 )];
 
+/// `nplcloud-text` — U+E169 (available in Regular)
 const ICON_NPLCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4591,6 +12280,7 @@ const ICON_NPLCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57705,
 )];
 
+/// `nvidia` — U+E16C (available in Regular)
 const ICON_NVIDIA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4599,6 +12289,7 @@ const ICON_NVIDIA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57708,
 )];
 
+/// `nvidia-text` — U+E16B (available in Regular)
 const ICON_NVIDIA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4607,6 +12298,7 @@ const ICON_NVIDIA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57707,
 )];
 
+/// `ollama` — U+E16E (available in Regular)
 const ICON_OLLAMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4615,6 +12307,7 @@ const ICON_OLLAMA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57710,
 )];
 
+/// `ollama-text` — U+E16D (available in Regular)
 const ICON_OLLAMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4623,6 +12316,7 @@ const ICON_OLLAMA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57709,
 )];
 
+/// `openai` — U+E170 (available in Regular)
 const ICON_OPENAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4631,6 +12325,7 @@ const ICON_OPENAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57712,
 )];
 
+/// `openai-text` — U+E16F (available in Regular)
 const ICON_OPENAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4639,6 +12334,7 @@ const ICON_OPENAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57711,
 )];
 
+/// `openchat` — U+E172 (available in Regular)
 const ICON_OPENCHAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4647,6 +12343,7 @@ const ICON_OPENCHAT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57714,
 )];
 
+/// `openchat-text` — U+E171 (available in Regular)
 const ICON_OPENCHAT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4655,6 +12352,7 @@ const ICON_OPENCHAT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57713,
 )];
 
+/// `openrouter` — U+E174 (available in Regular)
 const ICON_OPENROUTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4663,6 +12361,7 @@ const ICON_OPENROUTER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57716,
 )];
 
+/// `openrouter-text` — U+E173 (available in Regular)
 const ICON_OPENROUTER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4671,6 +12370,7 @@ const ICON_OPENROUTER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57715,
 )];
 
+/// `openwebui` — U+E176 (available in Regular)
 const ICON_OPENWEBUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4679,6 +12379,7 @@ const ICON_OPENWEBUI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57718,
 )];
 
+/// `openwebui-text` — U+E175 (available in Regular)
 const ICON_OPENWEBUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4687,6 +12388,7 @@ const ICON_OPENWEBUI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57717,
 )];
 
+/// `palm` — U+E178 (available in Regular)
 const ICON_PALM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4695,6 +12397,7 @@ const ICON_PALM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57720,
 )];
 
+/// `palm-text` — U+E177 (available in Regular)
 const ICON_PALM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4703,6 +12406,7 @@ const ICON_PALM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57719,
 )];
 
+/// `parasail` — U+E17A (available in Regular)
 const ICON_PARASAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4711,6 +12415,7 @@ const ICON_PARASAIL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57722,
 )];
 
+/// `parasail-text` — U+E179 (available in Regular)
 const ICON_PARASAIL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4719,6 +12424,7 @@ const ICON_PARASAIL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57721,
 )];
 
+/// `perplexity` — U+E17C (available in Regular)
 const ICON_PERPLEXITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4727,6 +12433,7 @@ const ICON_PERPLEXITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57724,
 )];
 
+/// `perplexity-text` — U+E17B (available in Regular)
 const ICON_PERPLEXITY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4735,6 +12442,7 @@ const ICON_PERPLEXITY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57723,
 )];
 
+/// `phidata` — U+E17E (available in Regular)
 const ICON_PHIDATA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4743,6 +12451,7 @@ const ICON_PHIDATA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57726,
 )];
 
+/// `phidata-text` — U+E17D (available in Regular)
 const ICON_PHIDATA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4751,6 +12460,7 @@ const ICON_PHIDATA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57725,
 )];
 
+/// `phind` — U+E180 (available in Regular)
 const ICON_PHIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4759,6 +12469,7 @@ const ICON_PHIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57728,
 )];
 
+/// `phind-text` — U+E17F (available in Regular)
 const ICON_PHIND_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4767,6 +12478,7 @@ const ICON_PHIND_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57727,
 )];
 
+/// `pika` — U+E182 (available in Regular)
 const ICON_PIKA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4775,6 +12487,7 @@ const ICON_PIKA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57730,
 )];
 
+/// `pika-text` — U+E181 (available in Regular)
 const ICON_PIKA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4783,6 +12496,7 @@ const ICON_PIKA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57729,
 )];
 
+/// `pixverse` — U+E184 (available in Regular)
 const ICON_PIXVERSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4791,6 +12505,7 @@ const ICON_PIXVERSE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57732,
 )];
 
+/// `pixverse-text` — U+E183 (available in Regular)
 const ICON_PIXVERSE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4799,6 +12514,7 @@ const ICON_PIXVERSE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57731,
 )];
 
+/// `player2` — U+E186 (available in Regular)
 const ICON_PLAYER_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4807,6 +12523,7 @@ const ICON_PLAYER_2_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57734,
 )];
 
+/// `player2-text` — U+E185 (available in Regular)
 const ICON_PLAYER_2_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4815,6 +12532,7 @@ const ICON_PLAYER_2_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57733,
 )];
 
+/// `poe` — U+E188 (available in Regular)
 const ICON_POE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4823,6 +12541,7 @@ const ICON_POE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57736,
 )];
 
+/// `poe-text` — U+E187 (available in Regular)
 const ICON_POE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4831,6 +12550,7 @@ const ICON_POE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57735,
 )];
 
+/// `pollinations` — U+E18A (available in Regular)
 const ICON_POLLINATIONS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4839,6 +12559,7 @@ const ICON_POLLINATIONS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57738,
 )];
 
+/// `pollinations-text` — U+E189 (available in Regular)
 const ICON_POLLINATIONS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4847,6 +12568,7 @@ const ICON_POLLINATIONS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57737,
 )];
 
+/// `ppio` — U+E18D (available in Regular)
 const ICON_PPIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4855,6 +12577,7 @@ const ICON_PPIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57741,
 )];
 
+/// `ppio-text` — U+E18C (available in Regular)
 const ICON_PPIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4863,6 +12586,7 @@ const ICON_PPIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57740,
 )];
 
+/// `ppio-text-cn` — U+E18B (available in Regular)
 const ICON_PPIO_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4871,6 +12595,7 @@ const ICON_PPIO_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57739,
 )];
 
+/// `pydanticai` — U+E18F (available in Regular)
 const ICON_PYDANTICAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4879,6 +12604,7 @@ const ICON_PYDANTICAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57743,
 )];
 
+/// `pydanticai-text` — U+E18E (available in Regular)
 const ICON_PYDANTICAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4887,6 +12613,7 @@ const ICON_PYDANTICAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57742,
 )];
 
+/// `qingyan` — U+E191 (available in Regular)
 const ICON_QINGYAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4895,6 +12622,7 @@ const ICON_QINGYAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57745,
 )];
 
+/// `qingyan-text` — U+E190 (available in Regular)
 const ICON_QINGYAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4903,6 +12631,7 @@ const ICON_QINGYAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57744,
 )];
 
+/// `qiniu` — U+E193 (available in Regular)
 const ICON_QINIU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4911,6 +12640,7 @@ const ICON_QINIU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57747,
 )];
 
+/// `qiniu-text` — U+E192 (available in Regular)
 const ICON_QINIU_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4919,6 +12649,7 @@ const ICON_QINIU_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57746,
 )];
 
+/// `qwen` — U+E195 (available in Regular)
 const ICON_QWEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4927,6 +12658,7 @@ const ICON_QWEN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57749,
 )];
 
+/// `qwen-text` — U+E194 (available in Regular)
 const ICON_QWEN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4935,6 +12667,7 @@ const ICON_QWEN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57748,
 )];
 
+/// `railway` — U+E197 (available in Regular)
 const ICON_RAILWAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4943,6 +12676,7 @@ const ICON_RAILWAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57751,
 )];
 
+/// `railway-text` — U+E196 (available in Regular)
 const ICON_RAILWAY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4951,6 +12685,7 @@ const ICON_RAILWAY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57750,
 )];
 
+/// `recraft` — U+E199 (available in Regular)
 const ICON_RECRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4959,6 +12694,7 @@ const ICON_RECRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57753,
 )];
 
+/// `recraft-text` — U+E198 (available in Regular)
 const ICON_RECRAFT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4967,6 +12703,7 @@ const ICON_RECRAFT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57752,
 )];
 
+/// `replicate` — U+E19B (available in Regular)
 const ICON_REPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4975,6 +12712,7 @@ const ICON_REPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57755,
 )];
 
+/// `replicate-brand` — U+E19A (available in Regular)
 const ICON_REPLICATE_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4983,6 +12721,7 @@ const ICON_REPLICATE_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57754,
 )];
 
+/// `replit` — U+E19D (available in Regular)
 const ICON_REPLIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4991,6 +12730,7 @@ const ICON_REPLIT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57757,
 )];
 
+/// `replit-text` — U+E19C (available in Regular)
 const ICON_REPLIT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -4999,6 +12739,7 @@ const ICON_REPLIT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57756,
 )];
 
+/// `rsshub` — U+E19F (available in Regular)
 const ICON_RSSHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5007,6 +12748,7 @@ const ICON_RSSHUB_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57759,
 )];
 
+/// `rsshub-text` — U+E19E (available in Regular)
 const ICON_RSSHUB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5015,6 +12757,7 @@ const ICON_RSSHUB_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57758,
 )];
 
+/// `runway` — U+E1A1 (available in Regular)
 const ICON_RUNWAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5023,6 +12766,7 @@ const ICON_RUNWAY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57761,
 )];
 
+/// `runway-text` — U+E1A0 (available in Regular)
 const ICON_RUNWAY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5031,6 +12775,7 @@ const ICON_RUNWAY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57760,
 )];
 
+/// `rwkv` — U+E1A3 (available in Regular)
 const ICON_RWKV_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5039,6 +12784,7 @@ const ICON_RWKV_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57763,
 )];
 
+/// `rwkv-text` — U+E1A2 (available in Regular)
 const ICON_RWKV_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5047,6 +12793,7 @@ const ICON_RWKV_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57762,
 )];
 
+/// `sambanova` — U+E1A5 (available in Regular)
 const ICON_SAMBANOVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5055,6 +12802,7 @@ const ICON_SAMBANOVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57765,
 )];
 
+/// `sambanova-text` — U+E1A4 (available in Regular)
 const ICON_SAMBANOVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5063,6 +12811,7 @@ const ICON_SAMBANOVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57764,
 )];
 
+/// `search1api` — U+E1A7 (available in Regular)
 const ICON_SEARCH_1API_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5071,6 +12820,7 @@ const ICON_SEARCH_1API_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57767,
 )];
 
+/// `search1api-text` — U+E1A6 (available in Regular)
 const ICON_SEARCH_1API_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5079,6 +12829,7 @@ const ICON_SEARCH_1API_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57766,
 )];
 
+/// `searchapi` — U+E1A9 (available in Regular)
 const ICON_SEARCHAPI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5087,6 +12838,7 @@ const ICON_SEARCHAPI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57769,
 )];
 
+/// `searchapi-text` — U+E1A8 (available in Regular)
 const ICON_SEARCHAPI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5095,6 +12847,7 @@ const ICON_SEARCHAPI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57768,
 )];
 
+/// `sensenova` — U+E1AC (available in Regular)
 const ICON_SENSENOVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5103,6 +12856,7 @@ const ICON_SENSENOVA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57772,
 )];
 
+/// `sensenova-brand` — U+E1AA (available in Regular)
 const ICON_SENSENOVA_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5111,6 +12865,7 @@ const ICON_SENSENOVA_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57770,
 )];
 
+/// `sensenova-text` — U+E1AB (available in Regular)
 const ICON_SENSENOVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5119,6 +12874,7 @@ const ICON_SENSENOVA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57771,
 )];
 
+/// `siliconcloud` — U+E1AE (available in Regular)
 const ICON_SILICONCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5127,6 +12883,7 @@ const ICON_SILICONCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57774,
 )];
 
+/// `siliconcloud-text` — U+E1AD (available in Regular)
 const ICON_SILICONCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5135,6 +12892,7 @@ const ICON_SILICONCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57773,
 )];
 
+/// `skywork` — U+E1B0 (available in Regular)
 const ICON_SKYWORK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5143,6 +12901,7 @@ const ICON_SKYWORK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57776,
 )];
 
+/// `skywork-text` — U+E1AF (available in Regular)
 const ICON_SKYWORK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5151,6 +12910,7 @@ const ICON_SKYWORK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57775,
 )];
 
+/// `smithery` — U+E1B2 (available in Regular)
 const ICON_SMITHERY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5159,6 +12919,7 @@ const ICON_SMITHERY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57778,
 )];
 
+/// `smithery-text` — U+E1B1 (available in Regular)
 const ICON_SMITHERY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5167,6 +12928,7 @@ const ICON_SMITHERY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57777,
 )];
 
+/// `snowflake` — U+E1B4 (available in Regular)
 const ICON_SNOWFLAKE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5175,6 +12937,7 @@ const ICON_SNOWFLAKE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57780,
 )];
 
+/// `snowflake-text` — U+E1B3 (available in Regular)
 const ICON_SNOWFLAKE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5183,6 +12946,7 @@ const ICON_SNOWFLAKE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57779,
 )];
 
+/// `sophnet` — U+E1B6 (available in Regular)
 const ICON_SOPHNET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5191,6 +12955,7 @@ const ICON_SOPHNET_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57782,
 )];
 
+/// `sophnet-text` — U+E1B5 (available in Regular)
 const ICON_SOPHNET_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5199,6 +12964,7 @@ const ICON_SOPHNET_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57781,
 )];
 
+/// `sora` — U+E1B8 (available in Regular)
 const ICON_SORA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5207,6 +12973,7 @@ const ICON_SORA_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57784,
 )];
 
+/// `sora-text` — U+E1B7 (available in Regular)
 const ICON_SORA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5215,6 +12982,7 @@ const ICON_SORA_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57783,
 )];
 
+/// `spark` — U+E1BA (available in Regular)
 const ICON_SPARK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5223,6 +12991,7 @@ const ICON_SPARK_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57786,
 )];
 
+/// `spark-text` — U+E1B9 (available in Regular)
 const ICON_SPARK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5231,6 +13000,7 @@ const ICON_SPARK_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57785,
 )];
 
+/// `stability` — U+E1BD (available in Regular)
 const ICON_STABILITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5239,6 +13009,7 @@ const ICON_STABILITY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57789,
 )];
 
+/// `stability-brand` — U+E1BB (available in Regular)
 const ICON_STABILITY_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5247,6 +13018,7 @@ const ICON_STABILITY_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57787,
 )];
 
+/// `stability-text` — U+E1BC (available in Regular)
 const ICON_STABILITY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5255,6 +13027,7 @@ const ICON_STABILITY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57788,
 )];
 
+/// `statecloud` — U+E1BF (available in Regular)
 const ICON_STATECLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5263,6 +13036,7 @@ const ICON_STATECLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57791,
 )];
 
+/// `statecloud-text` — U+E1BE (available in Regular)
 const ICON_STATECLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5271,6 +13045,7 @@ const ICON_STATECLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57790,
 )];
 
+/// `stepfun` — U+E1C1 (available in Regular)
 const ICON_STEPFUN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5279,6 +13054,7 @@ const ICON_STEPFUN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57793,
 )];
 
+/// `stepfun-text` — U+E1C0 (available in Regular)
 const ICON_STEPFUN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5287,6 +13063,7 @@ const ICON_STEPFUN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57792,
 )];
 
+/// `straico` — U+E1C3 (available in Regular)
 const ICON_STRAICO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5295,6 +13072,7 @@ const ICON_STRAICO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57795,
 )];
 
+/// `straico-text` — U+E1C2 (available in Regular)
 const ICON_STRAICO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5303,6 +13081,7 @@ const ICON_STRAICO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57794,
 )];
 
+/// `submodel` — U+E1C5 (available in Regular)
 const ICON_SUBMODEL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5311,6 +13090,7 @@ const ICON_SUBMODEL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57797,
 )];
 
+/// `submodel-text` — U+E1C4 (available in Regular)
 const ICON_SUBMODEL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5319,6 +13099,7 @@ const ICON_SUBMODEL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57796,
 )];
 
+/// `suno` — U+E1C7 (available in Regular)
 const ICON_SUNO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5327,6 +13108,7 @@ const ICON_SUNO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57799,
 )];
 
+/// `suno-text` — U+E1C6 (available in Regular)
 const ICON_SUNO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5335,6 +13117,7 @@ const ICON_SUNO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57798,
 )];
 
+/// `sync` — U+E1C9 (available in Regular)
 const ICON_SYNC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5343,6 +13126,7 @@ const ICON_SYNC_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57801,
 )];
 
+/// `sync-text` — U+E1C8 (available in Regular)
 const ICON_SYNC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5351,6 +13135,7 @@ const ICON_SYNC_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57800,
 )];
 
+/// `targon` — U+E1CB (available in Regular)
 const ICON_TARGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5359,6 +13144,7 @@ const ICON_TARGON_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57803,
 )];
 
+/// `targon-text` — U+E1CA (available in Regular)
 const ICON_TARGON_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5367,6 +13153,7 @@ const ICON_TARGON_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57802,
 )];
 
+/// `tavily` — U+E1CD (available in Regular)
 const ICON_TAVILY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5375,6 +13162,7 @@ const ICON_TAVILY_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57805,
 )];
 
+/// `tavily-text` — U+E1CC (available in Regular)
 const ICON_TAVILY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5383,6 +13171,7 @@ const ICON_TAVILY_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57804,
 )];
 
+/// `tencent` — U+E1D1 (available in Regular)
 const ICON_TENCENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5391,6 +13180,7 @@ const ICON_TENCENT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57809,
 )];
 
+/// `tencent-brand` — U+E1CE (available in Regular)
 const ICON_TENCENT_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5399,6 +13189,7 @@ const ICON_TENCENT_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57806,
 )];
 
+/// `tencent-text` — U+E1D0 (available in Regular)
 const ICON_TENCENT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5407,6 +13198,7 @@ const ICON_TENCENT_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57808,
 )];
 
+/// `tencent-text-cn` — U+E1CF (available in Regular)
 const ICON_TENCENT_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5415,6 +13207,7 @@ const ICON_TENCENT_TEXT_CN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57807,
 )];
 
+/// `tencentcloud` — U+E1D3 (available in Regular)
 const ICON_TENCENTCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5423,6 +13216,7 @@ const ICON_TENCENTCLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57811,
 )];
 
+/// `tencentcloud-text` — U+E1D2 (available in Regular)
 const ICON_TENCENTCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5431,6 +13225,7 @@ const ICON_TENCENTCLOUD_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57810,
 )];
 
+/// `tiangong` — U+E1D5 (available in Regular)
 const ICON_TIANGONG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5439,6 +13234,7 @@ const ICON_TIANGONG_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57813,
 )];
 
+/// `tiangong-text` — U+E1D4 (available in Regular)
 const ICON_TIANGONG_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5447,6 +13243,7 @@ const ICON_TIANGONG_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57812,
 )];
 
+/// `tii` — U+E1D7 (available in Regular)
 const ICON_TII_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5455,6 +13252,7 @@ const ICON_TII_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57815,
 )];
 
+/// `tii-text` — U+E1D6 (available in Regular)
 const ICON_TII_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5463,6 +13261,7 @@ const ICON_TII_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57814,
 )];
 
+/// `together` — U+E1DA (available in Regular)
 const ICON_TOGETHER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5471,6 +13270,7 @@ const ICON_TOGETHER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57818,
 )];
 
+/// `together-brand` — U+E1D8 (available in Regular)
 const ICON_TOGETHER_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5479,6 +13279,7 @@ const ICON_TOGETHER_BRAND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57816,
 )];
 
+/// `together-text` — U+E1D9 (available in Regular)
 const ICON_TOGETHER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5487,6 +13288,7 @@ const ICON_TOGETHER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57817,
 )];
 
+/// `topazlabs` — U+E1DC (available in Regular)
 const ICON_TOPAZLABS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5495,6 +13297,7 @@ const ICON_TOPAZLABS_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57820,
 )];
 
+/// `topazlabs-text` — U+E1DB (available in Regular)
 const ICON_TOPAZLABS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5503,6 +13306,7 @@ const ICON_TOPAZLABS_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57819,
 )];
 
+/// `trae` — U+E1DE (available in Regular)
 const ICON_TRAE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5511,6 +13315,7 @@ const ICON_TRAE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57822,
 )];
 
+/// `trae-text` — U+E1DD (available in Regular)
 const ICON_TRAE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5519,6 +13324,7 @@ const ICON_TRAE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57821,
 )];
 
+/// `tripo` — U+E1E0 (available in Regular)
 const ICON_TRIPO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5527,6 +13333,7 @@ const ICON_TRIPO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57824,
 )];
 
+/// `tripo-text` — U+E1DF (available in Regular)
 const ICON_TRIPO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5535,6 +13342,7 @@ const ICON_TRIPO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57823,
 )];
 
+/// `turix` — U+E1E2 (available in Regular)
 const ICON_TURIX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5543,6 +13351,7 @@ const ICON_TURIX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57826,
 )];
 
+/// `turix-text` — U+E1E1 (available in Regular)
 const ICON_TURIX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5551,6 +13360,7 @@ const ICON_TURIX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57825,
 )];
 
+/// `udio` — U+E1E4 (available in Regular)
 const ICON_UDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5559,6 +13369,7 @@ const ICON_UDIO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57828,
 )];
 
+/// `udio-text` — U+E1E3 (available in Regular)
 const ICON_UDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5567,6 +13378,7 @@ const ICON_UDIO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57827,
 )];
 
+/// `unstructured` — U+E1E6 (available in Regular)
 const ICON_UNSTRUCTURED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5575,6 +13387,7 @@ const ICON_UNSTRUCTURED_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57830,
 )];
 
+/// `unstructured-text` — U+E1E5 (available in Regular)
 const ICON_UNSTRUCTURED_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5583,6 +13396,7 @@ const ICON_UNSTRUCTURED_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57829,
 )];
 
+/// `upstage` — U+E1E8 (available in Regular)
 const ICON_UPSTAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5591,6 +13405,7 @@ const ICON_UPSTAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57832,
 )];
 
+/// `upstage-text` — U+E1E7 (available in Regular)
 const ICON_UPSTAGE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5599,6 +13414,7 @@ const ICON_UPSTAGE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57831,
 )];
 
+/// `v0` — U+E1E9 (available in Regular)
 const ICON_V_0_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5607,6 +13423,7 @@ const ICON_V_0_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57833,
 )];
 
+/// `vectorizerai` — U+E1EB (available in Regular)
 const ICON_VECTORIZERAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5615,6 +13432,7 @@ const ICON_VECTORIZERAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57835,
 )];
 
+/// `vectorizerai-text` — U+E1EA (available in Regular)
 const ICON_VECTORIZERAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5623,6 +13441,7 @@ const ICON_VECTORIZERAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57834,
 )];
 
+/// `vercel` — U+E1ED (available in Regular)
 const ICON_VERCEL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5631,6 +13450,7 @@ const ICON_VERCEL_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57837,
 )];
 
+/// `vercel-text` — U+E1EC (available in Regular)
 const ICON_VERCEL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5639,6 +13459,7 @@ const ICON_VERCEL_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57836,
 )];
 
+/// `vertexai` — U+E1EF (available in Regular)
 const ICON_VERTEXAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5647,6 +13468,7 @@ const ICON_VERTEXAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57839,
 )];
 
+/// `vertexai-text` — U+E1EE (available in Regular)
 const ICON_VERTEXAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5655,6 +13477,7 @@ const ICON_VERTEXAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57838,
 )];
 
+/// `vidu` — U+E1F1 (available in Regular)
 const ICON_VIDU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5663,6 +13486,7 @@ const ICON_VIDU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57841,
 )];
 
+/// `vidu-text` — U+E1F0 (available in Regular)
 const ICON_VIDU_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5671,6 +13495,7 @@ const ICON_VIDU_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57840,
 )];
 
+/// `viggle` — U+E1F3 (available in Regular)
 const ICON_VIGGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5679,6 +13504,7 @@ const ICON_VIGGLE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57843,
 )];
 
+/// `viggle-text` — U+E1F2 (available in Regular)
 const ICON_VIGGLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5687,6 +13513,7 @@ const ICON_VIGGLE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57842,
 )];
 
+/// `vllm` — U+E1F5 (available in Regular)
 const ICON_VLLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5695,6 +13522,7 @@ const ICON_VLLM_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57845,
 )];
 
+/// `vllm-text` — U+E1F4 (available in Regular)
 const ICON_VLLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5703,6 +13531,7 @@ const ICON_VLLM_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57844,
 )];
 
+/// `volcengine` — U+E1F7 (available in Regular)
 const ICON_VOLCENGINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5711,6 +13540,7 @@ const ICON_VOLCENGINE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57847,
 )];
 
+/// `volcengine-text` — U+E1F6 (available in Regular)
 const ICON_VOLCENGINE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5719,6 +13549,7 @@ const ICON_VOLCENGINE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57846,
 )];
 
+/// `voyage` — U+E1F9 (available in Regular)
 const ICON_VOYAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5727,6 +13558,7 @@ const ICON_VOYAGE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57849,
 )];
 
+/// `voyage-text` — U+E1F8 (available in Regular)
 const ICON_VOYAGE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5735,6 +13567,7 @@ const ICON_VOYAGE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57848,
 )];
 
+/// `wenxin` — U+E1FB (available in Regular)
 const ICON_WENXIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5743,6 +13576,7 @@ const ICON_WENXIN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57851,
 )];
 
+/// `wenxin-text` — U+E1FA (available in Regular)
 const ICON_WENXIN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5751,6 +13585,7 @@ const ICON_WENXIN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57850,
 )];
 
+/// `windsurf` — U+E1FD (available in Regular)
 const ICON_WINDSURF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5759,6 +13594,7 @@ const ICON_WINDSURF_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57853,
 )];
 
+/// `windsurf-text` — U+E1FC (available in Regular)
 const ICON_WINDSURF_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5767,6 +13603,7 @@ const ICON_WINDSURF_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57852,
 )];
 
+/// `workersai` — U+E1FF (available in Regular)
 const ICON_WORKERSAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5775,6 +13612,7 @@ const ICON_WORKERSAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57855,
 )];
 
+/// `workersai-text` — U+E1FE (available in Regular)
 const ICON_WORKERSAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5783,6 +13621,7 @@ const ICON_WORKERSAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57854,
 )];
 
+/// `xai` — U+E201 (available in Regular)
 const ICON_XAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5791,6 +13630,7 @@ const ICON_XAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57857,
 )];
 
+/// `xai-text` — U+E200 (available in Regular)
 const ICON_XAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5799,6 +13639,7 @@ const ICON_XAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57856,
 )];
 
+/// `xinference` — U+E203 (available in Regular)
 const ICON_XINFERENCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5807,6 +13648,7 @@ const ICON_XINFERENCE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57859,
 )];
 
+/// `xinference-text` — U+E202 (available in Regular)
 const ICON_XINFERENCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5815,6 +13657,7 @@ const ICON_XINFERENCE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57858,
 )];
 
+/// `xuanyuan` — U+E205 (available in Regular)
 const ICON_XUANYUAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5823,6 +13666,7 @@ const ICON_XUANYUAN_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57861,
 )];
 
+/// `xuanyuan-text` — U+E204 (available in Regular)
 const ICON_XUANYUAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5831,6 +13675,7 @@ const ICON_XUANYUAN_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57860,
 )];
 
+/// `yandex` — U+E207 (available in Regular)
 const ICON_YANDEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5839,6 +13684,7 @@ const ICON_YANDEX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57863,
 )];
 
+/// `yandex-text` — U+E206 (available in Regular)
 const ICON_YANDEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5847,6 +13693,7 @@ const ICON_YANDEX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57862,
 )];
 
+/// `yi` — U+E209 (available in Regular)
 const ICON_YI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5855,6 +13702,7 @@ const ICON_YI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57865,
 )];
 
+/// `yi-text` — U+E208 (available in Regular)
 const ICON_YI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5863,6 +13711,7 @@ const ICON_YI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57864,
 )];
 
+/// `youmind` — U+E20B (available in Regular)
 const ICON_YOUMIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5871,6 +13720,7 @@ const ICON_YOUMIND_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57867,
 )];
 
+/// `youmind-text` — U+E20A (available in Regular)
 const ICON_YOUMIND_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5879,6 +13729,7 @@ const ICON_YOUMIND_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57866,
 )];
 
+/// `yuanbao` — U+E20D (available in Regular)
 const ICON_YUANBAO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5887,6 +13738,7 @@ const ICON_YUANBAO_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57869,
 )];
 
+/// `yuanbao-text` — U+E20C (available in Regular)
 const ICON_YUANBAO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5895,6 +13747,7 @@ const ICON_YUANBAO_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57868,
 )];
 
+/// `zai` — U+E20F (available in Regular)
 const ICON_ZAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5903,6 +13756,7 @@ const ICON_ZAI_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57871,
 )];
 
+/// `zai-text` — U+E20E (available in Regular)
 const ICON_ZAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5911,6 +13765,7 @@ const ICON_ZAI_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57870,
 )];
 
+/// `zapier` — U+E211 (available in Regular)
 const ICON_ZAPIER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5919,6 +13774,7 @@ const ICON_ZAPIER_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57873,
 )];
 
+/// `zapier-text` — U+E210 (available in Regular)
 const ICON_ZAPIER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5927,6 +13783,7 @@ const ICON_ZAPIER_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57872,
 )];
 
+/// `zeabur` — U+E213 (available in Regular)
 const ICON_ZEABUR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5935,6 +13792,7 @@ const ICON_ZEABUR_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57875,
 )];
 
+/// `zeabur-text` — U+E212 (available in Regular)
 const ICON_ZEABUR_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5943,6 +13801,7 @@ const ICON_ZEABUR_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57874,
 )];
 
+/// `zenmux` — U+E215 (available in Regular)
 const ICON_ZENMUX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5951,6 +13810,7 @@ const ICON_ZENMUX_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57877,
 )];
 
+/// `zenmux-text` — U+E214 (available in Regular)
 const ICON_ZENMUX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5959,6 +13819,7 @@ const ICON_ZENMUX_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57876,
 )];
 
+/// `zeroone` — U+E217 (available in Regular)
 const ICON_ZEROONE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5967,6 +13828,7 @@ const ICON_ZEROONE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57879,
 )];
 
+/// `zeroone-text` — U+E216 (available in Regular)
 const ICON_ZEROONE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5975,6 +13837,7 @@ const ICON_ZEROONE_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57878,
 )];
 
+/// `zhipu` — U+E219 (available in Regular)
 const ICON_ZHIPU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5983,6 +13846,7 @@ const ICON_ZHIPU_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57881,
 )];
 
+/// `zhipu-text` — U+E218 (available in Regular)
 const ICON_ZHIPU_TEXT_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -6003,19 +13867,19 @@ const ICON_AGUI_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)]
 
 const ICON_AGUI_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
-const ICON_AI_2_1_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+const ICON_AI_21_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
-const ICON_AI_2_1_BRAND_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+const ICON_AI_21_BRAND_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
-const ICON_AI_2_1_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+const ICON_AI_21_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
-const ICON_AI_3_0_2_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+const ICON_AI_302_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
-const ICON_AI_3_0_2_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+const ICON_AI_302_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
-const ICON_AI_3_6_0_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+const ICON_AI_360_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
-const ICON_AI_3_6_0_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
+const ICON_AI_360_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
 const ICON_AIHUBMIX_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
@@ -7069,6 +14933,7 @@ const ICON_ZHIPU_TEXT_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Reg
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconCodepoints {
+    #[cfg_attr(feature = "fast-lookup", allow(dead_code))]
     pub name: &'static str,
     pub codepoints: &'static [(VariantKey, u32)],
 }
@@ -7100,31 +14965,31 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     },
     IconCodepoints {
         name: "ai21",
-        codepoints: ICON_AI_2_1_CODEPOINTS,
+        codepoints: ICON_AI_21_CODEPOINTS,
     },
     IconCodepoints {
         name: "ai21-brand",
-        codepoints: ICON_AI_2_1_BRAND_CODEPOINTS,
+        codepoints: ICON_AI_21_BRAND_CODEPOINTS,
     },
     IconCodepoints {
         name: "ai21-text",
-        codepoints: ICON_AI_2_1_TEXT_CODEPOINTS,
+        codepoints: ICON_AI_21_TEXT_CODEPOINTS,
     },
     IconCodepoints {
         name: "ai302",
-        codepoints: ICON_AI_3_0_2_CODEPOINTS,
+        codepoints: ICON_AI_302_CODEPOINTS,
     },
     IconCodepoints {
         name: "ai302-text",
-        codepoints: ICON_AI_3_0_2_TEXT_CODEPOINTS,
+        codepoints: ICON_AI_302_TEXT_CODEPOINTS,
     },
     IconCodepoints {
         name: "ai360",
-        codepoints: ICON_AI_3_6_0_CODEPOINTS,
+        codepoints: ICON_AI_360_CODEPOINTS,
     },
     IconCodepoints {
         name: "ai360-text",
-        codepoints: ICON_AI_3_6_0_TEXT_CODEPOINTS,
+        codepoints: ICON_AI_360_TEXT_CODEPOINTS,
     },
     IconCodepoints {
         name: "aihubmix",
@@ -9230,6 +17095,7 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconAvailability {
+    #[cfg_attr(feature = "fast-lookup", allow(dead_code))]
     pub name: &'static str,
     pub available: &'static [(Style, Size)],
 }
@@ -9261,31 +17127,31 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     },
     IconAvailability {
         name: "ai21",
-        available: ICON_AI_2_1_AVAILABLE,
+        available: ICON_AI_21_AVAILABLE,
     },
     IconAvailability {
         name: "ai21-brand",
-        available: ICON_AI_2_1_BRAND_AVAILABLE,
+        available: ICON_AI_21_BRAND_AVAILABLE,
     },
     IconAvailability {
         name: "ai21-text",
-        available: ICON_AI_2_1_TEXT_AVAILABLE,
+        available: ICON_AI_21_TEXT_AVAILABLE,
     },
     IconAvailability {
         name: "ai302",
-        available: ICON_AI_3_0_2_AVAILABLE,
+        available: ICON_AI_302_AVAILABLE,
     },
     IconAvailability {
         name: "ai302-text",
-        available: ICON_AI_3_0_2_TEXT_AVAILABLE,
+        available: ICON_AI_302_TEXT_AVAILABLE,
     },
     IconAvailability {
         name: "ai360",
-        available: ICON_AI_3_6_0_AVAILABLE,
+        available: ICON_AI_360_AVAILABLE,
     },
     IconAvailability {
         name: "ai360-text",
-        available: ICON_AI_3_6_0_TEXT_AVAILABLE,
+        available: ICON_AI_360_TEXT_AVAILABLE,
     },
     IconAvailability {
         name: "aihubmix",
@@ -11389,6 +19255,3164 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     },
 ];
 
+/// Open-addressing index into `ICON_CODEPOINTS` and `ICON_AVAILABILITY`, probed by `icon_slot`.
+#[cfg(feature = "fast-lookup")]
+const ICON_SLOTS: &[Option<(&str, usize)>] = &[
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("clipdrop-text", 102)),
+    None,
+    Some(("baichuan-text", 58)),
+    Some(("perplexity-text", 380)),
+    Some(("submodel", 452)),
+    None,
+    None,
+    None,
+    None,
+    Some(("adobefirefly-text", 3)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("fishaudio-text", 180)),
+    Some(("colab", 113)),
+    Some(("mcpso-text", 317)),
+    Some(("railway", 406)),
+    Some(("straico", 450)),
+    Some(("rsshub", 414)),
+    None,
+    None,
+    Some(("bailian-text", 66)),
+    None,
+    None,
+    None,
+    None,
+    Some(("clipdrop", 101)),
+    Some(("livekit-text", 291)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("capcut-text", 86)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("anthropic", 36)),
+    Some(("coqui", 125)),
+    None,
+    Some(("internlm-text", 255)),
+    Some(("gradio-text", 212)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("bedrock-text", 70)),
+    Some(("novita-text", 360)),
+    None,
+    None,
+    Some(("kolors-text", 267)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("colab-text", 114)),
+    None,
+    None,
+    Some(("kimi-text", 261)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("baidu", 59)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("mcp", 314)),
+    None,
+    Some(("n8n-text", 344)),
+    None,
+    None,
+    Some(("elevenx", 165)),
+    Some(("groq", 217)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("copilotkit-text", 124)),
+    None,
+    None,
+    Some(("aws-text", 48)),
+    None,
+    None,
+    None,
+    Some(("inflection-text", 253)),
+    Some(("recraft-text", 409)),
+    None,
+    None,
+    Some(("workersai", 510)),
+    Some(("together-brand", 473)),
+    Some(("trae-text", 478)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("assemblyai-text", 43)),
+    None,
+    Some(("llava-text", 295)),
+    None,
+    Some(("hailuo-text", 220)),
+    None,
+    None,
+    Some(("inference-text", 246)),
+    Some(("mcpso", 316)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("openchat", 369)),
+    None,
+    None,
+    None,
+    None,
+    Some(("fastgpt-text", 172)),
+    None,
+    None,
+    None,
+    Some(("moonshot", 339)),
+    None,
+    Some(("hyperbolic-text", 238)),
+    Some(("ollama", 365)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("googlecloud-brand", 208)),
+    None,
+    Some(("deepl", 145)),
+    None,
+    None,
+    None,
+    Some(("llava", 294)),
+    None,
+    None,
+    None,
+    Some(("dbrx", 138)),
+    Some(("langchain-text", 275)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("capcut", 85)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("internlm", 254)),
+    Some(("dbrx-text", 140)),
+    Some(("inference", 245)),
+    Some(("tencentcloud-text", 467)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("pixverse-text", 388)),
+    None,
+    None,
+    None,
+    None,
+    Some(("gemma", 191)),
+    Some(("snowflake", 435)),
+    None,
+    None,
+    Some(("parasail-text", 378)),
+    Some(("glif-text", 202)),
+    Some(("coqui-text", 126)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("fal-text", 170)),
+    None,
+    None,
+    Some(("novelai", 357)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("searchapi-text", 425)),
+    None,
+    Some(("comfyui-text", 118)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("tii-text", 471)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("burncloud", 79)),
+    None,
+    None,
+    Some(("tiangong", 468)),
+    None,
+    None,
+    Some(("huaweicloud-text-cn", 232)),
+    Some(("langfuse", 276)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("yi", 520)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("cogview", 109)),
+    Some(("github", 195)),
+    Some(("qiniu-text", 403)),
+    None,
+    None,
+    None,
+    Some(("ibm-text", 240)),
+    None,
+    Some(("notebooklm-text", 350)),
+    None,
+    None,
+    None,
+    None,
+    Some(("ai302-text", 10)),
+    Some(("assemblyai", 42)),
+    Some(("hunyuan-text", 236)),
+    Some(("zeroone", 534)),
+    None,
+    None,
+    None,
+    Some(("poe-text", 392)),
+    None,
+    None,
+    None,
+    Some(("vidu-text", 497)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("sensenova-brand", 427)),
+    None,
+    Some(("alephalpha", 23)),
+    Some(("dreammachine", 161)),
+    Some(("featherless-text", 174)),
+    Some(("minimax-text", 332)),
+    None,
+    Some(("nplcloud", 361)),
+    None,
+    None,
+    Some(("doubao-text", 160)),
+    Some(("exa-text", 168)),
+    Some(("gemini-text", 190)),
+    Some(("skywork", 431)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("skywork-text", 432)),
+    Some(("spark", 441)),
+    None,
+    None,
+    None,
+    None,
+    Some(("jina-text", 259)),
+    Some(("unstructured-text", 486)),
+    Some(("runway", 416)),
+    Some(("player2-text", 390)),
+    None,
+    None,
+    Some(("chatglm-text", 94)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("zeabur", 530)),
+    None,
+    None,
+    Some(("commanda-text", 120)),
+    None,
+    None,
+    Some(("civitai", 95)),
+    Some(("smithery-text", 434)),
+    Some(("monica-text", 338)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("dolphin", 157)),
+    Some(("topazlabs", 475)),
+    None,
+    None,
+    None,
+    None,
+    Some(("tripo-text", 480)),
+    Some(("lovable-text", 303)),
+    Some(("bailian", 65)),
+    Some(("pollinations-text", 394)),
+    None,
+    None,
+    None,
+    Some(("crusoe", 132)),
+    Some(("hedra-text", 224)),
+    None,
+    None,
+    Some(("iflytekcloud-text", 244)),
+    Some(("udio-text", 484)),
+    None,
+    Some(("xai-text", 513)),
+    None,
+    Some(("tii", 470)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("antgroup-brand", 33)),
+    Some(("centml-brand", 88)),
+    Some(("cogvideo-text", 108)),
+    Some(("cohere-text", 112)),
+    None,
+    Some(("flux-text", 186)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("xuanyuan-text", 517)),
+    None,
+    Some(("llamaindex-text", 293)),
+    None,
+    None,
+    None,
+    Some(("alibabacloud-text-cn", 31)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("dbrx-brand", 139)),
+    Some(("azureai-text", 54)),
+    Some(("deepai", 141)),
+    Some(("zhipu-text", 537)),
+    None,
+    Some(("menlo", 318)),
+    None,
+    None,
+    Some(("ollama-text", 366)),
+    None,
+    None,
+    None,
+    Some(("hunyuan", 235)),
+    None,
+    Some(("liquid-text", 289)),
+    None,
+    None,
+    Some(("aya", 49)),
+    Some(("lambda", 272)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("ideogram-text", 242)),
+    None,
+    Some(("glama", 199)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("nebius-text", 346)),
+    None,
+    None,
+    None,
+    Some(("cogview-text", 110)),
+    Some(("notion", 351)),
+    None,
+    None,
+    Some(("giteeai", 193)),
+    None,
+    None,
+    None,
+    None,
+    Some(("vllm", 500)),
+    Some(("mistral-text", 334)),
+    None,
+    Some(("cursor", 134)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("aionlabs", 17)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("qiniu", 402)),
+    None,
+    Some(("statecloud", 446)),
+    Some(("replicate", 410)),
+    Some(("agui-text", 5)),
+    Some(("yuanbao", 524)),
+    None,
+    None,
+    Some(("commanda", 119)),
+    None,
+    None,
+    Some(("alibabacloud", 29)),
+    None,
+    None,
+    None,
+    None,
+    Some(("anthropic-text", 37)),
+    None,
+    None,
+    Some(("wenxin-text", 507)),
+    None,
+    None,
+    None,
+    None,
+    Some(("replit", 412)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("livekit", 290)),
+    None,
+    Some(("zeroone-text", 535)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("windsurf-text", 509)),
+    None,
+    Some(("tripo", 479)),
+    Some(("zenmux", 532)),
+    None,
+    Some(("flux", 185)),
+    Some(("gemma-text", 192)),
+    None,
+    None,
+    None,
+    None,
+    Some(("stability", 443)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("chatglm", 93)),
+    None,
+    Some(("cohere", 111)),
+    Some(("glama-text", 200)),
+    None,
+    None,
+    None,
+    None,
+    Some(("palm-text", 376)),
+    None,
+    Some(("anyscale", 38)),
+    None,
+    None,
+    None,
+    Some(("bilibili", 73)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("bilibiliindex", 75)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("higress", 225)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("inflection", 252)),
+    Some(("recraft", 408)),
+    None,
+    None,
+    Some(("qwen", 404)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("liquid", 288)),
+    None,
+    Some(("n8n", 343)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("kwaipilot-text", 271)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("friendli", 187)),
+    None,
+    Some(("lmstudio-text", 297)),
+    None,
+    None,
+    None,
+    None,
+    Some(("together-text", 474)),
+    None,
+    Some(("glmv-text", 204)),
+    None,
+    None,
+    None,
+    None,
+    Some(("notion-text", 352)),
+    None,
+    None,
+    Some(("apple", 40)),
+    None,
+    Some(("openwebui-text", 374)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("jimeng-text", 257)),
+    Some(("phidata", 381)),
+    Some(("claude-text", 98)),
+    Some(("replicate-brand", 411)),
+    Some(("workersai-text", 511)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("baiducloud", 63)),
+    None,
+    None,
+    Some(("yandex", 518)),
+    None,
+    None,
+    Some(("anyscale-text", 39)),
+    None,
+    Some(("pollinations", 393)),
+    None,
+    Some(("nova", 355)),
+    Some(("azure", 51)),
+    None,
+    Some(("githubcopilot", 197)),
+    None,
+    Some(("elevenlabs-text", 164)),
+    Some(("fastgpt", 171)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("sambanova-text", 421)),
+    None,
+    Some(("akashchat-text", 22)),
+    None,
+    None,
+    None,
+    Some(("hedra", 223)),
+    None,
+    Some(("mastra", 312)),
+    Some(("friendli-text", 188)),
+    None,
+    None,
+    None,
+    Some(("nvidia", 363)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("suno", 454)),
+    None,
+    None,
+    None,
+    Some(("azureai", 53)),
+    None,
+    Some(("adobe", 0)),
+    Some(("huawei-text", 228)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("luma", 304)),
+    None,
+    None,
+    None,
+    None,
+    Some(("leptonai", 282)),
+    Some(("mcp-text", 315)),
+    None,
+    None,
+    Some(("nova-text", 356)),
+    Some(("huawei-text-cn", 229)),
+    Some(("myshell", 341)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("kling", 262)),
+    None,
+    None,
+    Some(("midjourney-text", 330)),
+    None,
+    Some(("midjourney", 329)),
+    None,
+    None,
+    None,
+    Some(("huggingface-text", 234)),
+    None,
+    None,
+    None,
+    Some(("turix-text", 482)),
+    Some(("vidu", 496)),
+    Some(("zapier-text", 529)),
+    Some(("spark-text", 442)),
+    None,
+    Some(("iflytekcloud", 243)),
+    Some(("udio", 483)),
+    None,
+    None,
+    None,
+    Some(("volcengine", 502)),
+    Some(("githubcopilot-text", 198)),
+    Some(("microsoft", 327)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("bytedance-text-cn", 84)),
+    Some(("siliconcloud-text", 430)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("turix", 481)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("akashchat", 21)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("doc2x", 153)),
+    None,
+    None,
+    Some(("ai302", 9)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("phidata-text", 382)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("lobehub-text", 299)),
+    Some(("tavily-text", 461)),
+    None,
+    None,
+    None,
+    None,
+    Some(("coze-text", 128)),
+    None,
+    Some(("cerebras-text", 92)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("nvidia-text", 364)),
+    None,
+    None,
+    None,
+    None,
+    Some(("longcat-text", 301)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("infermatic", 247)),
+    None,
+    None,
+    Some(("modelscope-text", 336)),
+    Some(("docsearch-text", 156)),
+    None,
+    None,
+    None,
+    None,
+    Some(("voyage-text", 505)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("stability-text", 445)),
+    None,
+    None,
+    None,
+    None,
+    Some(("novelai-text", 358)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("elevenlabs", 163)),
+    Some(("lg-text", 285)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("langchain", 274)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("aimass-text", 16)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("mistral", 333)),
+    Some(("meta-text", 322)),
+    Some(("search1api-text", 423)),
+    None,
+    None,
+    None,
+    None,
+    Some(("ai21-brand", 7)),
+    Some(("centml", 87)),
+    Some(("vercel-text", 493)),
+    None,
+    None,
+    None,
+    Some(("cerebras-brand", 91)),
+    None,
+    Some(("krea-text", 269)),
+    None,
+    None,
+    Some(("metagpt", 325)),
+    None,
+    None,
+    None,
+    None,
+    Some(("baai-text", 56)),
+    Some(("sync-text", 457)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("flowith-text", 184)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("cogvideo", 107)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("wenxin", 506)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("cursor-text", 135)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("replit-text", 413)),
+    None,
+    None,
+    None,
+    Some(("volcengine-text", 503)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("meta-brand", 321)),
+    None,
+    None,
+    None,
+    None,
+    Some(("gradio", 211)),
+    None,
+    None,
+    Some(("aihubmix", 13)),
+    None,
+    None,
+    Some(("leptonai-text", 283)),
+    Some(("vllm-text", 501)),
+    None,
+    None,
+    None,
+    Some(("goose-text", 210)),
+    Some(("openrouter-text", 372)),
+    Some(("sensenova-text", 428)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("cline", 99)),
+    None,
+    None,
+    Some(("copilot", 121)),
+    Some(("infinigence-text", 250)),
+    Some(("cometapi", 115)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("zenmux-text", 533)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("mastra-text", 313)),
+    None,
+    None,
+    Some(("upstage", 487)),
+    None,
+    Some(("aihubmix-text", 14)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("windsurf", 508)),
+    None,
+    Some(("kolors", 266)),
+    None,
+    Some(("notebooklm", 349)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("codegeex", 105)),
+    None,
+    Some(("ideogram", 241)),
+    None,
+    Some(("flora", 181)),
+    None,
+    None,
+    None,
+    None,
+    Some(("aws-brand", 47)),
+    Some(("burncloud-text", 80)),
+    Some(("kling-text", 263)),
+    Some(("youmind", 522)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("cloudflare", 103)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("xuanyuan", 516)),
+    Some(("copilotkit", 123)),
+    Some(("metaai", 323)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("automatic-text", 45)),
+    Some(("myshell-text", 342)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("ppio-text-cn", 397)),
+    Some(("vectorizerai", 490)),
+    None,
+    Some(("google-brand", 206)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("cline-text", 100)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("aws", 46)),
+    None,
+    None,
+    Some(("docsearch", 155)),
+    None,
+    None,
+    None,
+    None,
+    Some(("aya-text", 50)),
+    Some(("lambda-text", 273)),
+    None,
+    Some(("vertexai", 494)),
+    Some(("lovable", 302)),
+    Some(("searchapi", 424)),
+    Some(("luma-text", 305)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("bfl", 71)),
+    Some(("kluster-text", 265)),
+    None,
+    None,
+    Some(("aimass", 15)),
+    Some(("vertexai-text", 495)),
+    None,
+    None,
+    Some(("deepinfra", 143)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("submodel-text", 453)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("baidu-brand", 60)),
+    None,
+    None,
+    None,
+    None,
+    Some(("deepseek-text", 150)),
+    Some(("centml-text", 89)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("langsmith-text", 281)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("figma-text", 176)),
+    None,
+    Some(("hailuo", 219)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("lg", 284)),
+    Some(("apple-text", 41)),
+    None,
+    None,
+    None,
+    Some(("glif", 201)),
+    Some(("vercel", 492)),
+    None,
+    None,
+    Some(("deepinfra-text", 144)),
+    None,
+    None,
+    None,
+    None,
+    Some(("nplcloud-text", 362)),
+    None,
+    None,
+    None,
+    None,
+    Some(("claude", 97)),
+    Some(("railway-text", 407)),
+    Some(("straico-text", 451)),
+    Some(("pika-text", 386)),
+    Some(("trae", 477)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("alephalpha-text", 24)),
+    Some(("dreammachine-text", 162)),
+    None,
+    Some(("xinference", 514)),
+    None,
+    None,
+    None,
+    Some(("tencent", 462)),
+    None,
+    None,
+    Some(("huggingface", 233)),
+    None,
+    Some(("baiducloud-text", 64)),
+    Some(("together", 472)),
+    None,
+    None,
+    Some(("viggle-text", 499)),
+    None,
+    Some(("longcat", 300)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("greptile", 213)),
+    Some(("sophnet-text", 438)),
+    None,
+    Some(("haiper-text", 222)),
+    Some(("bilibiliindex-text", 76)),
+    None,
+    None,
+    Some(("lobehub", 298)),
+    Some(("tavily", 460)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("openchat-text", 370)),
+    Some(("phind", 383)),
+    Some(("flora-text", 182)),
+    Some(("tencentcloud", 466)),
+    None,
+    None,
+    None,
+    Some(("crusoe-text", 133)),
+    Some(("alibaba-text", 27)),
+    None,
+    None,
+    None,
+    None,
+    Some(("civitai-text", 96)),
+    Some(("ppio-text", 396)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("dolphin-text", 158)),
+    Some(("topazlabs-text", 476)),
+    None,
+    Some(("vectorizerai-text", 491)),
+    None,
+    None,
+    Some(("sensenova", 426)),
+    None,
+    Some(("langgraph-text", 279)),
+    Some(("rsshub-text", 415)),
+    Some(("zai-text", 527)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("llamaindex", 292)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("fireworks", 177)),
+    Some(("google", 205)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("magic", 306)),
+    Some(("targon", 458)),
+    Some(("gemini", 189)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("github-text", 196)),
+    None,
+    None,
+    None,
+    Some(("phind-text", 384)),
+    Some(("alibaba-brand", 26)),
+    Some(("bing", 77)),
+    Some(("infinigence", 249)),
+    Some(("manus-text", 311)),
+    None,
+    None,
+    None,
+    None,
+    Some(("pixverse", 387)),
+    None,
+    None,
+    Some(("hyperbolic", 237)),
+    None,
+    Some(("elevenx-text", 166)),
+    Some(("groq-text", 218)),
+    None,
+    Some(("figma", 175)),
+    Some(("minimax", 331)),
+    Some(("alibaba-text-cn", 28)),
+    None,
+    None,
+    Some(("tencent-text-cn", 465)),
+    Some(("dify-text", 152)),
+    Some(("huaweicloud", 230)),
+    None,
+    Some(("higress-text", 226)),
+    None,
+    None,
+    None,
+    Some(("goose", 209)),
+    Some(("openrouter", 371)),
+    None,
+    None,
+    None,
+    Some(("microsoft-text", 328)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("stepfun-text", 449)),
+    None,
+    Some(("grok", 215)),
+    Some(("bilibili-text", 74)),
+    None,
+    None,
+    Some(("bytedance", 81)),
+    None,
+    None,
+    Some(("nousresearch-text", 354)),
+    None,
+    None,
+    Some(("azure-text", 52)),
+    None,
+    Some(("metaai-text", 324)),
+    Some(("pydanticai-text", 399)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("kwaipilot", 270)),
+    Some(("suno-text", 455)),
+    None,
+    None,
+    None,
+    Some(("baidu-text-cn", 62)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("palm", 375)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("ppio", 395)),
+    None,
+    None,
+    None,
+    Some(("bedrock", 69)),
+    None,
+    None,
+    Some(("langsmith", 280)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("greptile-text", 214)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("deepmind-text", 148)),
+    None,
+    Some(("fishaudio", 179)),
+    Some(("moonshot-text", 340)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("ai360-text", 12)),
+    Some(("nousresearch", 353)),
+    None,
+    None,
+    None,
+    None,
+    Some(("stepfun", 448)),
+    None,
+    None,
+    None,
+    None,
+    Some(("viggle", 498)),
+    Some(("yuanbao-text", 525)),
+    None,
+    Some(("youmind-text", 523)),
+    Some(("nebius", 345)),
+    None,
+    Some(("manus", 310)),
+    None,
+    Some(("bing-text", 78)),
+    None,
+    Some(("newapi", 347)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("comfyui", 117)),
+    None,
+    Some(("googlecloud", 207)),
+    None,
+    Some(("aistudio", 19)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("lmstudio", 296)),
+    Some(("featherless", 173)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("antgroup-text", 34)),
+    None,
+    Some(("infermatic-text", 248)),
+    None,
+    None,
+    None,
+    None,
+    Some(("aionlabs-text", 18)),
+    None,
+    None,
+    None,
+    Some(("bytedance-brand", 82)),
+    None,
+    Some(("yandex-text", 519)),
+    None,
+    Some(("cloudflare-text", 104)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("statecloud-text", 447)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("antgroup", 32)),
+    Some(("upstage-text", 488)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("crewai-text", 131)),
+    Some(("rwkv", 418)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("newapi-text", 348)),
+    Some(("crewai-brand", 130)),
+    None,
+    None,
+    None,
+    Some(("tencent-text", 464)),
+    Some(("poe", 391)),
+    None,
+    None,
+    None,
+    None,
+    Some(("baidu-text", 61)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("sora-text", 440)),
+    Some(("aistudio-text", 20)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("stability-brand", 444)),
+    None,
+    None,
+    None,
+    None,
+    Some(("yi-text", 521)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("baseten", 67)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("make", 308)),
+    None,
+    None,
+    Some(("ai360", 11)),
+    Some(("dify", 151)),
+    Some(("magic-text", 307)),
+    Some(("adobefirefly", 2)),
+    Some(("crewai", 129)),
+    Some(("targon-text", 459)),
+    None,
+    None,
+    Some(("sora", 439)),
+    Some(("deepai-text", 142)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("grok-text", 216)),
+    Some(("openai", 367)),
+    Some(("runway-text", 417)),
+    Some(("tiangong-text", 469)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("metagpt-text", 326)),
+    None,
+    None,
+    None,
+    Some(("alibaba", 25)),
+    None,
+    None,
+    None,
+    None,
+    Some(("alibabacloud-text", 30)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("automatic", 44)),
+    Some(("qingyan-text", 401)),
+    None,
+    None,
+    None,
+    Some(("lightricks", 286)),
+    Some(("menlo-text", 319)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("voyage", 504)),
+    None,
+    None,
+    None,
+    None,
+    Some(("kluster", 264)),
+    None,
+    None,
+    Some(("flowith", 183)),
+    None,
+    None,
+    None,
+    None,
+    Some(("giteeai-text", 194)),
+    None,
+    None,
+    None,
+    Some(("openai-text", 368)),
+    None,
+    Some(("openwebui", 373)),
+    None,
+    None,
+    Some(("coze", 127)),
+    None,
+    Some(("novita", 359)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("baseten-text", 68)),
+    Some(("bytedance-text", 83)),
+    Some(("cometapi-text", 116)),
+    Some(("monica", 337)),
+    None,
+    Some(("infinigence-text-cn", 251)),
+    Some(("xinference-text", 515)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("ibm", 239)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("make-text", 309)),
+    Some(("tencent-brand", 463)),
+    Some(("langgraph", 278)),
+    Some(("zai", 526)),
+    None,
+    None,
+    Some(("dalle-text", 137)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("baichuan", 57)),
+    None,
+    None,
+    None,
+    Some(("fal", 169)),
+    None,
+    Some(("modelscope", 335)),
+    None,
+    None,
+    None,
+    None,
+    Some(("siliconcloud", 429)),
+    None,
+    Some(("bfl-text", 72)),
+    None,
+    None,
+    Some(("meta", 320)),
+    Some(("dalle", 136)),
+    Some(("search1api", 422)),
+    None,
+    Some(("cerebras", 90)),
+    Some(("doc2x-text", 154)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("sophnet", 437)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("sambanova", 420)),
+    None,
+    None,
+    Some(("ai21-text", 8)),
+    Some(("antgroup-text-cn", 35)),
+    None,
+    Some(("zhipu", 536)),
+    None,
+    Some(("perplexity", 379)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("copilot-text", 122)),
+    Some(("krea", 268)),
+    Some(("huaweicloud-text", 231)),
+    Some(("zapier", 528)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("doubao", 159)),
+    Some(("exa", 167)),
+    None,
+    None,
+    None,
+    Some(("haiper", 221)),
+    Some(("langfuse-text", 277)),
+    Some(("ai21", 6)),
+    None,
+    None,
+    Some(("jina", 258)),
+    Some(("unstructured", 485)),
+    None,
+    None,
+    None,
+    Some(("baai", 55)),
+    Some(("deepl-text", 146)),
+    Some(("sync", 456)),
+    None,
+    None,
+    None,
+    Some(("huawei", 227)),
+    Some(("xai", 512)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("glmv", 203)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("player2", 389)),
+    None,
+    None,
+    None,
+    Some(("smithery", 433)),
+    None,
+    Some(("qingyan", 400)),
+    None,
+    None,
+    None,
+    Some(("adobe-text", 1)),
+    Some(("pydanticai", 398)),
+    None,
+    Some(("codegeex-text", 106)),
+    None,
+    None,
+    None,
+    None,
+    Some(("zeabur-text", 531)),
+    None,
+    None,
+    Some(("lightricks-text", 287)),
+    None,
+    None,
+    Some(("pika", 385)),
+    Some(("kimi", 260)),
+    None,
+    None,
+    Some(("qwen-text", 405)),
+    None,
+    None,
+    None,
+    None,
+    Some(("deepmind", 147)),
+    None,
+    None,
+    None,
+    Some(("deepseek", 149)),
+    None,
+    Some(("snowflake-text", 436)),
+    Some(("parasail", 377)),
+    Some(("agui", 4)),
+    Some(("fireworks-text", 178)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("jimeng", 256)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("rwkv-text", 419)),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(("v0", 489)),
+    None,
+    None,
+    None,
+    None,
+];
+
+/// Linear probe from the name's FNV-1a hash; the table is at most half full, so it stops.
+#[cfg(feature = "fast-lookup")]
+fn icon_slot(name: &str) -> Option<usize> {
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    let mask = ICON_SLOTS.len() - 1;
+    let mut slot = hash as usize & mask;
+    while let Some((entry, index)) = ICON_SLOTS[slot] {
+        if entry == name {
+            return Some(index);
+        }
+        slot = (slot + 1) & mask;
+    }
+    None
+}
+
+pub(crate) const ICON_COLORS: &[(&str, [u8; 4])] = &[];
+
+/// Accessible labels, index-aligned with `ICON_NAMES`.
+pub(crate) const ICON_LABELS: &[&str] = &[
+    "Adobe",
+    "Adobe Text",
+    "Adobefirefly",
+    "Adobefirefly Text",
+    "Agui",
+    "Agui Text",
+    "Ai21",
+    "Ai21 Brand",
+    "Ai21 Text",
+    "Ai302",
+    "Ai302 Text",
+    "Ai360",
+    "Ai360 Text",
+    "Aihubmix",
+    "Aihubmix Text",
+    "Aimass",
+    "Aimass Text",
+    "Aionlabs",
+    "Aionlabs Text",
+    "Aistudio",
+    "Aistudio Text",
+    "Akashchat",
+    "Akashchat Text",
+    "Alephalpha",
+    "Alephalpha Text",
+    "Alibaba",
+    "Alibaba Brand",
+    "Alibaba Text",
+    "Alibaba Text Cn",
+    "Alibabacloud",
+    "Alibabacloud Text",
+    "Alibabacloud Text Cn",
+    "Antgroup",
+    "Antgroup Brand",
+    "Antgroup Text",
+    "Antgroup Text Cn",
+    "Anthropic",
+    "Anthropic Text",
+    "Anyscale",
+    "Anyscale Text",
+    "Apple",
+    "Apple Text",
+    "Assemblyai",
+    "Assemblyai Text",
+    "Automatic",
+    "Automatic Text",
+    "Aws",
+    "Aws Brand",
+    "Aws Text",
+    "Aya",
+    "Aya Text",
+    "Azure",
+    "Azure Text",
+    "Azureai",
+    "Azureai Text",
+    "Baai",
+    "Baai Text",
+    "Baichuan",
+    "Baichuan Text",
+    "Baidu",
+    "Baidu Brand",
+    "Baidu Text",
+    "Baidu Text Cn",
+    "Baiducloud",
+    "Baiducloud Text",
+    "Bailian",
+    "Bailian Text",
+    "Baseten",
+    "Baseten Text",
+    "Bedrock",
+    "Bedrock Text",
+    "Bfl",
+    "Bfl Text",
+    "Bilibili",
+    "Bilibili Text",
+    "Bilibiliindex",
+    "Bilibiliindex Text",
+    "Bing",
+    "Bing Text",
+    "Burncloud",
+    "Burncloud Text",
+    "Bytedance",
+    "Bytedance Brand",
+    "Bytedance Text",
+    "Bytedance Text Cn",
+    "Capcut",
+    "Capcut Text",
+    "Centml",
+    "Centml Brand",
+    "Centml Text",
+    "Cerebras",
+    "Cerebras Brand",
+    "Cerebras Text",
+    "Chatglm",
+    "Chatglm Text",
+    "Civitai",
+    "Civitai Text",
+    "Claude",
+    "Claude Text",
+    "Cline",
+    "Cline Text",
+    "Clipdrop",
+    "Clipdrop Text",
+    "Cloudflare",
+    "Cloudflare Text",
+    "Codegeex",
+    "Codegeex Text",
+    "Cogvideo",
+    "Cogvideo Text",
+    "Cogview",
+    "Cogview Text",
+    "Cohere",
+    "Cohere Text",
+    "Colab",
+    "Colab Text",
+    "Cometapi",
+    "Cometapi Text",
+    "Comfyui",
+    "Comfyui Text",
+    "Commanda",
+    "Commanda Text",
+    "Copilot",
+    "Copilot Text",
+    "Copilotkit",
+    "Copilotkit Text",
+    "Coqui",
+    "Coqui Text",
+    "Coze",
+    "Coze Text",
+    "Crewai",
+    "Crewai Brand",
+    "Crewai Text",
+    "Crusoe",
+    "Crusoe Text",
+    "Cursor",
+    "Cursor Text",
+    "Dalle",
+    "Dalle Text",
+    "Dbrx",
+    "Dbrx Brand",
+    "Dbrx Text",
+    "Deepai",
+    "Deepai Text",
+    "Deepinfra",
+    "Deepinfra Text",
+    "Deepl",
+    "Deepl Text",
+    "Deepmind",
+    "Deepmind Text",
+    "Deepseek",
+    "Deepseek Text",
+    "Dify",
+    "Dify Text",
+    "Doc2x",
+    "Doc2x Text",
+    "Docsearch",
+    "Docsearch Text",
+    "Dolphin",
+    "Dolphin Text",
+    "Doubao",
+    "Doubao Text",
+    "Dreammachine",
+    "Dreammachine Text",
+    "Elevenlabs",
+    "Elevenlabs Text",
+    "Elevenx",
+    "Elevenx Text",
+    "Exa",
+    "Exa Text",
+    "Fal",
+    "Fal Text",
+    "Fastgpt",
+    "Fastgpt Text",
+    "Featherless",
+    "Featherless Text",
+    "Figma",
+    "Figma Text",
+    "Fireworks",
+    "Fireworks Text",
+    "Fishaudio",
+    "Fishaudio Text",
+    "Flora",
+    "Flora Text",
+    "Flowith",
+    "Flowith Text",
+    "Flux",
+    "Flux Text",
+    "Friendli",
+    "Friendli Text",
+    "Gemini",
+    "Gemini Text",
+    "Gemma",
+    "Gemma Text",
+    "Giteeai",
+    "Giteeai Text",
+    "Github",
+    "Github Text",
+    "Githubcopilot",
+    "Githubcopilot Text",
+    "Glama",
+    "Glama Text",
+    "Glif",
+    "Glif Text",
+    "Glmv",
+    "Glmv Text",
+    "Google",
+    "Google Brand",
+    "Googlecloud",
+    "Googlecloud Brand",
+    "Goose",
+    "Goose Text",
+    "Gradio",
+    "Gradio Text",
+    "Greptile",
+    "Greptile Text",
+    "Grok",
+    "Grok Text",
+    "Groq",
+    "Groq Text",
+    "Hailuo",
+    "Hailuo Text",
+    "Haiper",
+    "Haiper Text",
+    "Hedra",
+    "Hedra Text",
+    "Higress",
+    "Higress Text",
+    "Huawei",
+    "Huawei Text",
+    "Huawei Text Cn",
+    "Huaweicloud",
+    "Huaweicloud Text",
+    "Huaweicloud Text Cn",
+    "Huggingface",
+    "Huggingface Text",
+    "Hunyuan",
+    "Hunyuan Text",
+    "Hyperbolic",
+    "Hyperbolic Text",
+    "Ibm",
+    "Ibm Text",
+    "Ideogram",
+    "Ideogram Text",
+    "Iflytekcloud",
+    "Iflytekcloud Text",
+    "Inference",
+    "Inference Text",
+    "Infermatic",
+    "Infermatic Text",
+    "Infinigence",
+    "Infinigence Text",
+    "Infinigence Text Cn",
+    "Inflection",
+    "Inflection Text",
+    "Internlm",
+    "Internlm Text",
+    "Jimeng",
+    "Jimeng Text",
+    "Jina",
+    "Jina Text",
+    "Kimi",
+    "Kimi Text",
+    "Kling",
+    "Kling Text",
+    "Kluster",
+    "Kluster Text",
+    "Kolors",
+    "Kolors Text",
+    "Krea",
+    "Krea Text",
+    "Kwaipilot",
+    "Kwaipilot Text",
+    "Lambda",
+    "Lambda Text",
+    "Langchain",
+    "Langchain Text",
+    "Langfuse",
+    "Langfuse Text",
+    "Langgraph",
+    "Langgraph Text",
+    "Langsmith",
+    "Langsmith Text",
+    "Leptonai",
+    "Leptonai Text",
+    "Lg",
+    "Lg Text",
+    "Lightricks",
+    "Lightricks Text",
+    "Liquid",
+    "Liquid Text",
+    "Livekit",
+    "Livekit Text",
+    "Llamaindex",
+    "Llamaindex Text",
+    "Llava",
+    "Llava Text",
+    "Lmstudio",
+    "Lmstudio Text",
+    "Lobehub",
+    "Lobehub Text",
+    "Longcat",
+    "Longcat Text",
+    "Lovable",
+    "Lovable Text",
+    "Luma",
+    "Luma Text",
+    "Magic",
+    "Magic Text",
+    "Make",
+    "Make Text",
+    "Manus",
+    "Manus Text",
+    "Mastra",
+    "Mastra Text",
+    "Mcp",
+    "Mcp Text",
+    "Mcpso",
+    "Mcpso Text",
+    "Menlo",
+    "Menlo Text",
+    "Meta",
+    "Meta Brand",
+    "Meta Text",
+    "Metaai",
+    "Metaai Text",
+    "Metagpt",
+    "Metagpt Text",
+    "Microsoft",
+    "Microsoft Text",
+    "Midjourney",
+    "Midjourney Text",
+    "Minimax",
+    "Minimax Text",
+    "Mistral",
+    "Mistral Text",
+    "Modelscope",
+    "Modelscope Text",
+    "Monica",
+    "Monica Text",
+    "Moonshot",
+    "Moonshot Text",
+    "Myshell",
+    "Myshell Text",
+    "N8n",
+    "N8n Text",
+    "Nebius",
+    "Nebius Text",
+    "Newapi",
+    "Newapi Text",
+    "Notebooklm",
+    "Notebooklm Text",
+    "Notion",
+    "Notion Text",
+    "Nousresearch",
+    "Nousresearch Text",
+    "Nova",
+    "Nova Text",
+    "Novelai",
+    "Novelai Text",
+    "Novita",
+    "Novita Text",
+    "Nplcloud",
+    "Nplcloud Text",
+    "Nvidia",
+    "Nvidia Text",
+    "Ollama",
+    "Ollama Text",
+    "Openai",
+    "Openai Text",
+    "Openchat",
+    "Openchat Text",
+    "Openrouter",
+    "Openrouter Text",
+    "Openwebui",
+    "Openwebui Text",
+    "Palm",
+    "Palm Text",
+    "Parasail",
+    "Parasail Text",
+    "Perplexity",
+    "Perplexity Text",
+    "Phidata",
+    "Phidata Text",
+    "Phind",
+    "Phind Text",
+    "Pika",
+    "Pika Text",
+    "Pixverse",
+    "Pixverse Text",
+    "Player2",
+    "Player2 Text",
+    "Poe",
+    "Poe Text",
+    "Pollinations",
+    "Pollinations Text",
+    "Ppio",
+    "Ppio Text",
+    "Ppio Text Cn",
+    "Pydanticai",
+    "Pydanticai Text",
+    "Qingyan",
+    "Qingyan Text",
+    "Qiniu",
+    "Qiniu Text",
+    "Qwen",
+    "Qwen Text",
+    "Railway",
+    "Railway Text",
+    "Recraft",
+    "Recraft Text",
+    "Replicate",
+    "Replicate Brand",
+    "Replit",
+    "Replit Text",
+    "Rsshub",
+    "Rsshub Text",
+    "Runway",
+    "Runway Text",
+    "Rwkv",
+    "Rwkv Text",
+    "Sambanova",
+    "Sambanova Text",
+    "Search1api",
+    "Search1api Text",
+    "Searchapi",
+    "Searchapi Text",
+    "Sensenova",
+    "Sensenova Brand",
+    "Sensenova Text",
+    "Siliconcloud",
+    "Siliconcloud Text",
+    "Skywork",
+    "Skywork Text",
+    "Smithery",
+    "Smithery Text",
+    "Snowflake",
+    "Snowflake Text",
+    "Sophnet",
+    "Sophnet Text",
+    "Sora",
+    "Sora Text",
+    "Spark",
+    "Spark Text",
+    "Stability",
+    "Stability Brand",
+    "Stability Text",
+    "Statecloud",
+    "Statecloud Text",
+    "Stepfun",
+    "Stepfun Text",
+    "Straico",
+    "Straico Text",
+    "Submodel",
+    "Submodel Text",
+    "Suno",
+    "Suno Text",
+    "Sync",
+    "Sync Text",
+    "Targon",
+    "Targon Text",
+    "Tavily",
+    "Tavily Text",
+    "Tencent",
+    "Tencent Brand",
+    "Tencent Text",
+    "Tencent Text Cn",
+    "Tencentcloud",
+    "Tencentcloud Text",
+    "Tiangong",
+    "Tiangong Text",
+    "Tii",
+    "Tii Text",
+    "Together",
+    "Together Brand",
+    "Together Text",
+    "Topazlabs",
+    "Topazlabs Text",
+    "Trae",
+    "Trae Text",
+    "Tripo",
+    "Tripo Text",
+    "Turix",
+    "Turix Text",
+    "Udio",
+    "Udio Text",
+    "Unstructured",
+    "Unstructured Text",
+    "Upstage",
+    "Upstage Text",
+    "V0",
+    "Vectorizerai",
+    "Vectorizerai Text",
+    "Vercel",
+    "Vercel Text",
+    "Vertexai",
+    "Vertexai Text",
+    "Vidu",
+    "Vidu Text",
+    "Viggle",
+    "Viggle Text",
+    "Vllm",
+    "Vllm Text",
+    "Volcengine",
+    "Volcengine Text",
+    "Voyage",
+    "Voyage Text",
+    "Wenxin",
+    "Wenxin Text",
+    "Windsurf",
+    "Windsurf Text",
+    "Workersai",
+    "Workersai Text",
+    "Xai",
+    "Xai Text",
+    "Xinference",
+    "Xinference Text",
+    "Xuanyuan",
+    "Xuanyuan Text",
+    "Yandex",
+    "Yandex Text",
+    "Yi",
+    "Yi Text",
+    "Youmind",
+    "Youmind Text",
+    "Yuanbao",
+    "Yuanbao Text",
+    "Zai",
+    "Zai Text",
+    "Zapier",
+    "Zapier Text",
+    "Zeabur",
+    "Zeabur Text",
+    "Zenmux",
+    "Zenmux Text",
+    "Zeroone",
+    "Zeroone Text",
+    "Zhipu",
+    "Zhipu Text",
+];
+
+/// Stable icon ids, sorted by id.
+pub(crate) const ICON_IDS: &[(u32, &str)] = &[
+    (0x0012d66a, "upstage-text"),
+    (0x00ab21d9, "dbrx-brand"),
+    (0x0158c568, "vectorizerai-text"),
+    (0x02c013e6, "krea-text"),
+    (0x02cf316f, "jina-text"),
+    (0x03227fbc, "qingyan"),
+    (0x0352cacb, "jimeng-text"),
+    (0x03eeb3d8, "meta-text"),
+    (0x044f4876, "inflection-text"),
+    (0x0526075c, "cerebras"),
+    (0x05447887, "llava-text"),
+    (0x0747c848, "novita-text"),
+    (0x078cfb10, "adobe"),
+    (0x07d559c1, "cogvideo-text"),
+    (0x080172d8, "yandex"),
+    (0x08d2db21, "mcp-text"),
+    (0x08fb8333, "midjourney"),
+    (0x097acf9a, "sync"),
+    (0x0a10f972, "player2-text"),
+    (0x0aa08627, "manus"),
+    (0x0c581975, "chatglm-text"),
+    (0x0ca29d6d, "zai-text"),
+    (0x0dae8836, "internlm-text"),
+    (0x0dc48ea7, "baseten"),
+    (0x0e88f56e, "rsshub-text"),
+    (0x0e9fe6c2, "grok-text"),
+    (0x0ecbbf73, "sambanova"),
+    (0x0f5bcec4, "tiangong-text"),
+    (0x0f93019f, "dolphin"),
+    (0x0fca5ee4, "qingyan-text"),
+    (0x0fee3171, "runway"),
+    (0x10bc2abf, "apple"),
+    (0x10dfca9f, "n8n"),
+    (0x11013ace, "workersai-text"),
+    (0x112b6722, "xinference-text"),
+    (0x115483c6, "langchain"),
+    (0x121be30e, "azureai"),
+    (0x125ba1a5, "tripo-text"),
+    (0x1287a0a2, "hyperbolic-text"),
+    (0x134f5e61, "statecloud-text"),
+    (0x1448f88c, "inference-text"),
+    (0x1594d2b7, "glmv-text"),
+    (0x16fc293f, "hunyuan-text"),
+    (0x172be6ba, "sora"),
+    (0x18303698, "stability-brand"),
+    (0x18a08f01, "openai-text"),
+    (0x1a57e62b, "newapi"),
+    (0x1a683cb6, "aws"),
+    (0x1a807471, "codegeex"),
+    (0x1a9932c1, "openwebui-text"),
+    (0x1a9eb4d3, "aimass"),
+    (0x1ab14ef5, "kluster"),
+    (0x1ae59367, "doc2x"),
+    (0x1b45fd18, "railway-text"),
+    (0x1b6eb5c7, "bilibili-text"),
+    (0x1bd4ed55, "alibaba-text"),
+    (0x1c6344d7, "deepinfra"),
+    (0x1c79fd8c, "targon"),
+    (0x1c9c2450, "zenmux-text"),
+    (0x1ca23e24, "youmind-text"),
+    (0x1cd93fd7, "deepmind"),
+    (0x1d06d1ac, "crusoe"),
+    (0x1d199dfd, "bedrock"),
+    (0x1d6e7c34, "vllm-text"),
+    (0x1d9438a3, "ollama"),
+    (0x1e4ff5ab, "figma"),
+    (0x1e8ef8a0, "moonshot"),
+    (0x1ee54006, "clipdrop-text"),
+    (0x1fa8ad1a, "pika-text"),
+    (0x1fab3e58, "yandex-text"),
+    (0x202d8784, "zapier"),
+    (0x20e4620d, "mistral-text"),
+    (0x21d75a07, "giteeai"),
+    (0x2234ec9d, "myshell-text"),
+    (0x22dc7932, "github"),
+    (0x22e7ee69, "antgroup"),
+    (0x237e0a03, "cogview-text"),
+    (0x23f562bc, "notion-text"),
+    (0x2455b7ce, "pika"),
+    (0x245941ea, "aya"),
+    (0x24952d18, "straico-text"),
+    (0x2556d6fd, "giteeai-text"),
+    (0x25570d85, "fireworks"),
+    (0x267e2b21, "leptonai"),
+    (0x27c972b0, "lmstudio-text"),
+    (0x27fab3e9, "metagpt"),
+    (0x29048397, "infermatic"),
+    (0x292d430a, "suno"),
+    (0x29e1d76a, "sophnet"),
+    (0x2a00bf5d, "doc2x-text"),
+    (0x2a24ee7a, "crewai-brand"),
+    (0x2a55e6cb, "metagpt-text"),
+    (0x2b1684aa, "google-brand"),
+    (0x2c3339c6, "flux-text"),
+    (0x2c57293e, "ai302-text"),
+    (0x2dbca3a0, "voyage-text"),
+    (0x2e77a440, "cline"),
+    (0x2e82a0e6, "gemma"),
+    (0x2e8e5c7a, "aws-brand"),
+    (0x2ec26901, "searchapi-text"),
+    (0x2f00e36a, "ai302"),
+    (0x2f962945, "poe-text"),
+    (0x2fc7eb74, "phidata-text"),
+    (0x305eb10f, "tii-text"),
+    (0x31711d9c, "infinigence"),
+    (0x31775433, "leptonai-text"),
+    (0x31eca2f3, "akashchat-text"),
+    (0x327da151, "alephalpha"),
+    (0x32a4991f, "huaweicloud-text-cn"),
+    (0x32ac1c39, "sensenova-text"),
+    (0x331a91e5, "hunyuan"),
+    (0x3393a188, "zeabur"),
+    (0x33de071e, "bytedance-text"),
+    (0x34153e0f, "fishaudio"),
+    (0x348343ee, "sync-text"),
+    (0x349774f0, "langsmith-text"),
+    (0x3584652a, "tencent"),
+    (0x365ca4be, "aya-text"),
+    (0x36947ee9, "lightricks"),
+    (0x36b2a55b, "ppio-text"),
+    (0x36d45296, "qwen"),
+    (0x3758c2e0, "azure"),
+    (0x37d937c1, "pydanticai"),
+    (0x38434460, "aihubmix-text"),
+    (0x387e0231, "anthropic-text"),
+    (0x38ac8db0, "tencent-text-cn"),
+    (0x38b54fdb, "deepseek"),
+    (0x38cff736, "tencent-brand"),
+    (0x39f9173b, "dalle-text"),
+    (0x3a60ebf8, "flowith-text"),
+    (0x3aa09808, "baichuan-text"),
+    (0x3b1ed120, "langfuse"),
+    (0x3bd59cc4, "luma-text"),
+    (0x3c5b1a5d, "windsurf-text"),
+    (0x3ca287ba, "smithery"),
+    (0x3cdb7ee4, "automatic"),
+    (0x3cf2e159, "exa-text"),
+    (0x3d5bd016, "colab"),
+    (0x3d8c1f92, "ai21"),
+    (0x3de817c8, "zeabur-text"),
+    (0x3e61db25, "nova-text"),
+    (0x3e80f052, "colab-text"),
+    (0x3ec7887b, "together-brand"),
+    (0x3f0f8753, "siliconcloud"),
+    (0x3f2f216a, "spark"),
+    (0x4047cb3c, "zapier-text"),
+    (0x41c2f64b, "antgroup-text"),
+    (0x41d16512, "nplcloud-text"),
+    (0x429601c2, "centml-brand"),
+    (0x42a38c1f, "volcengine-text"),
+    (0x42be0d33, "viggle-text"),
+    (0x433d5e29, "bing-text"),
+    (0x439a46c3, "runway-text"),
+    (0x442ec3de, "ai21-brand"),
+    (0x4483a345, "volcengine"),
+    (0x44b78541, "bilibiliindex-text"),
+    (0x44bbf065, "n8n-text"),
+    (0x46334e7f, "poe"),
+    (0x4715799f, "topazlabs"),
+    (0x47190600, "langsmith"),
+    (0x4764cba8, "stability-text"),
+    (0x47af4b51, "siliconcloud-text"),
+    (0x47f5d1ff, "nebius-text"),
+    (0x4888a8ec, "coqui-text"),
+    (0x48ac9023, "livekit-text"),
+    (0x4a2ed8f4, "fal-text"),
+    (0x4a31cd04, "lg"),
+    (0x4ac11d6b, "sensenova"),
+    (0x4b2c6eb3, "dify"),
+    (0x4c6bb889, "hailuo-text"),
+    (0x4d7ccdd0, "azure-text"),
+    (0x4d7f4085, "assemblyai-text"),
+    (0x4d807519, "trae"),
+    (0x4da768b9, "deepl"),
+    (0x4ddb1159, "doubao-text"),
+    (0x4e2d76b4, "targon-text"),
+    (0x4ea24a68, "stability"),
+    (0x4f194337, "huggingface-text"),
+    (0x4f449ecf, "alibaba"),
+    (0x4f9a9954, "minimax-text"),
+    (0x507d3a34, "wenxin-text"),
+    (0x50814dde, "suno-text"),
+    (0x50b8bfdd, "snowflake-text"),
+    (0x522bd08d, "mcpso"),
+    (0x52372818, "straico"),
+    (0x527709d2, "alibabacloud-text-cn"),
+    (0x52fcbcc2, "lovable"),
+    (0x53a5ad40, "haiper-text"),
+    (0x54a601b3, "xai-text"),
+    (0x552b0722, "infinigence-text-cn"),
+    (0x553f3941, "zeroone"),
+    (0x585e22cd, "claude-text"),
+    (0x58a43919, "burncloud"),
+    (0x58d534be, "lambda-text"),
+    (0x59bfd27d, "bilibili"),
+    (0x59ec471f, "cometapi-text"),
+    (0x5a2b9f03, "openwebui"),
+    (0x5b64886f, "copilotkit-text"),
+    (0x5c435292, "recraft"),
+    (0x5c9aa708, "novita"),
+    (0x5d0bc5bd, "microsoft-text"),
+    (0x5d1ccf48, "baichuan"),
+    (0x5dafa79a, "baai"),
+    (0x5dc887cb, "lightricks-text"),
+    (0x5e00052d, "huggingface"),
+    (0x5e1c1076, "recraft-text"),
+    (0x5e2d65a6, "hyperbolic"),
+    (0x5e4889b0, "iflytekcloud-text"),
+    (0x5e4daa9d, "liquid"),
+    (0x5e931e16, "ai360-text"),
+    (0x610c7b8f, "longcat-text"),
+    (0x611c37de, "parasail"),
+    (0x617fbb9a, "modelscope-text"),
+    (0x6247ba92, "inflection"),
+    (0x632f05a8, "elevenx-text"),
+    (0x63a2587a, "workersai"),
+    (0x63a66f8b, "exa"),
+    (0x63c0dacc, "replicate-brand"),
+    (0x63e2fef0, "voyage"),
+    (0x64153103, "comfyui-text"),
+    (0x645fc127, "yi"),
+    (0x652e0933, "qiniu-text"),
+    (0x6571654e, "phind"),
+    (0x6571b7f1, "rwkv-text"),
+    (0x65ad1c30, "aihubmix"),
+    (0x672d5822, "clipdrop"),
+    (0x680e47b6, "player2"),
+    (0x6817d949, "vidu-text"),
+    (0x6900368e, "sora-text"),
+    (0x6977a0b7, "googlecloud-brand"),
+    (0x6af2e438, "goose-text"),
+    (0x6c783990, "monica-text"),
+    (0x6dd3987b, "trae-text"),
+    (0x6e422607, "greptile-text"),
+    (0x6edfc4a8, "vectorizerai"),
+    (0x6ef66777, "antgroup-text-cn"),
+    (0x6f84b4e4, "baidu-brand"),
+    (0x6fe7afc0, "adobe-text"),
+    (0x7018d656, "bytedance-brand"),
+    (0x722525e2, "baidu-text-cn"),
+    (0x728d5e52, "aionlabs-text"),
+    (0x7363a5b1, "huaweicloud"),
+    (0x746390e1, "pixverse-text"),
+    (0x74a7cd05, "apple-text"),
+    (0x7649e00f, "adobefirefly-text"),
+    (0x7676eeb4, "magic-text"),
+    (0x767c3720, "monica"),
+    (0x76be61ce, "llamaindex-text"),
+    (0x76df2684, "baidu-text"),
+    (0x780fe1de, "menlo"),
+    (0x7881bafe, "nvidia"),
+    (0x78d8fc95, "copilotkit"),
+    (0x79470226, "yuanbao"),
+    (0x796a932e, "kling"),
+    (0x79b7f817, "mcpso-text"),
+    (0x79ce668f, "aistudio-text"),
+    (0x7a2d8ccf, "kluster-text"),
+    (0x7ab544cf, "bfl"),
+    (0x7aba8939, "notebooklm-text"),
+    (0x7aca7f8b, "doubao"),
+    (0x7b1d2f35, "make-text"),
+    (0x7b3cbfad, "glmv"),
+    (0x7b474d09, "glif"),
+    (0x7bac88bd, "llava"),
+    (0x7c4736bb, "deepai-text"),
+    (0x7c696443, "copilot"),
+    (0x7cc1484b, "kolors-text"),
+    (0x7ce030c1, "dbrx"),
+    (0x7cf6bc15, "cursor-text"),
+    (0x7cfbfb10, "huawei-text"),
+    (0x7ddb45dd, "kwaipilot"),
+    (0x7e04ca16, "aionlabs"),
+    (0x7ef15a83, "bilibiliindex"),
+    (0x7f0571ea, "lambda"),
+    (0x7f9e97df, "agui"),
+    (0x7ff964f9, "figma-text"),
+    (0x8104269d, "yi-text"),
+    (0x8116db46, "githubcopilot-text"),
+    (0x811a1ae4, "elevenlabs-text"),
+    (0x8193e2df, "nova"),
+    (0x81fe4f58, "meta"),
+    (0x82c9d3de, "vercel-text"),
+    (0x830fd737, "langgraph"),
+    (0x8314e47c, "youmind"),
+    (0x84d0db9b, "docsearch-text"),
+    (0x84f49d26, "xinference"),
+    (0x85171db8, "openrouter"),
+    (0x8657db61, "akashchat"),
+    (0x86f112dd, "pollinations"),
+    (0x87295cd3, "vertexai-text"),
+    (0x881719c1, "antgroup-brand"),
+    (0x894cbded, "palm"),
+    (0x89909d6d, "langgraph-text"),
+    (0x8a0993d7, "mistral"),
+    (0x8c2d91f1, "ideogram-text"),
+    (0x8c4b2f1d, "baseten-text"),
+    (0x8ca731cc, "xuanyuan-text"),
+    (0x8cd5a4b0, "cline-text"),
+    (0x8cf2516f, "unstructured-text"),
+    (0x8d579a04, "notion"),
+    (0x8d9fffe9, "jimeng"),
+    (0x8daa78eb, "glif-text"),
+    (0x8dd413bc, "elevenlabs"),
+    (0x8e8e65b1, "dify-text"),
+    (0x8eba65b4, "higress-text"),
+    (0x8eca7a77, "palm-text"),
+    (0x8fb65d4d, "openchat-text"),
+    (0x90c53847, "bedrock-text"),
+    (0x90e14d8e, "gemini"),
+    (0x91391679, "newapi-text"),
+    (0x91ef7b40, "udio"),
+    (0x91fe08e7, "snowflake"),
+    (0x9255e5ad, "alibaba-text-cn"),
+    (0x9344c01a, "rsshub"),
+    (0x935c559a, "phind-text"),
+    (0x93a17e1c, "stepfun"),
+    (0x93b6998e, "civitai"),
+    (0x9400450a, "vercel"),
+    (0x9473a1ad, "hedra-text"),
+    (0x94861818, "railway"),
+    (0x9495d737, "zai"),
+    (0x952646ea, "menlo-text"),
+    (0x9542f65a, "cloudflare-text"),
+    (0x954a47fb, "v0"),
+    (0x958d2a60, "zenmux"),
+    (0x95cd554f, "tencentcloud"),
+    (0x96222c28, "meta-brand"),
+    (0x96984fc3, "codegeex-text"),
+    (0x97036c03, "cogvideo"),
+    (0x97058868, "elevenx"),
+    (0x980403e4, "cerebras-brand"),
+    (0x9902740c, "wenxin"),
+    (0x9a13852f, "baiducloud-text"),
+    (0x9c1ee9a7, "pollinations-text"),
+    (0x9c293d65, "topazlabs-text"),
+    (0x9c2c9d44, "lobehub"),
+    (0x9ce25c5b, "mastra-text"),
+    (0x9da4e586, "google"),
+    (0x9df71c67, "windsurf"),
+    (0x9dfd359d, "manus-text"),
+    (0x9edeff2d, "ibm"),
+    (0x9ee5257a, "llamaindex"),
+    (0x9f65c8ea, "parasail-text"),
+    (0x9f8640d2, "internlm"),
+    (0x9fa31781, "copilot-text"),
+    (0x9fc9fe21, "viggle"),
+    (0xa08285c6, "grok"),
+    (0xa0fb86b2, "ai360"),
+    (0xa1056253, "zeroone-text"),
+    (0xa15485f9, "ppio"),
+    (0xa187f48e, "cloudflare"),
+    (0xa18da55a, "civitai-text"),
+    (0xa1ea3897, "openchat"),
+    (0xa25b8953, "featherless-text"),
+    (0xa2825d2f, "together"),
+    (0xa2870ae4, "fastgpt"),
+    (0xa3bf3a71, "cohere"),
+    (0xa3fb591c, "tiangong"),
+    (0xa3ff8adb, "anyscale-text"),
+    (0xa4d7049c, "automatic-text"),
+    (0xa543dcea, "centml-text"),
+    (0xa647b137, "ibm-text"),
+    (0xa6b2ddac, "minimax"),
+    (0xa6f7f74c, "fal"),
+    (0xa90ffc44, "infinigence-text"),
+    (0xa985be40, "lmstudio"),
+    (0xaa070523, "dreammachine-text"),
+    (0xaa899872, "aws-text"),
+    (0xabae6037, "gradio-text"),
+    (0xac4a1da3, "pixverse"),
+    (0xacafddca, "bytedance"),
+    (0xae81cc96, "metaai"),
+    (0xaef7c5d2, "metaai-text"),
+    (0xaf2bfa62, "gemma-text"),
+    (0xb08a7e4d, "infermatic-text"),
+    (0xb0d11833, "anthropic"),
+    (0xb16de47b, "burncloud-text"),
+    (0xb2321326, "huawei-text-cn"),
+    (0xb2674c45, "cometapi"),
+    (0xb3c9038a, "nvidia-text"),
+    (0xb45321c3, "cohere-text"),
+    (0xb51ebf59, "dalle"),
+    (0xb5a69635, "aistudio"),
+    (0xb5f9e009, "perplexity-text"),
+    (0xb6658327, "myshell"),
+    (0xb682a868, "groq"),
+    (0xb68cd26f, "chatglm"),
+    (0xb7f5d631, "comfyui"),
+    (0xb9607f95, "unstructured"),
+    (0xb9d61633, "googlecloud"),
+    (0xb9ef5c75, "flora"),
+    (0xba04af90, "haiper"),
+    (0xba1d00d4, "inference"),
+    (0xbaad5f55, "bfl-text"),
+    (0xbbc182f9, "mastra"),
+    (0xbd00c15a, "gemini-text"),
+    (0xbdc1a42d, "gradio"),
+    (0xbdf64f9b, "deepl-text"),
+    (0xbdfc61a7, "bailian"),
+    (0xbe3753d8, "search1api-text"),
+    (0xbe4d1a5f, "tripo"),
+    (0xbe506359, "turix"),
+    (0xbe98b4de, "submodel-text"),
+    (0xbea92f58, "search1api"),
+    (0xbf0120f7, "novelai"),
+    (0xbf221f95, "jina"),
+    (0xbfb63cfb, "hailuo"),
+    (0xbfd03c1b, "replit-text"),
+    (0xc0cfdb40, "iflytekcloud"),
+    (0xc1c8660d, "deepmind-text"),
+    (0xc2a805b8, "goose"),
+    (0xc2eb1625, "nebius"),
+    (0xc3098790, "langfuse-text"),
+    (0xc40af9b5, "tii"),
+    (0xc4c2633e, "spark-text"),
+    (0xc4c5a3ad, "novelai-text"),
+    (0xc5638239, "replit"),
+    (0xc5f50641, "featherless"),
+    (0xc615bb47, "microsoft"),
+    (0xc6535d9b, "bing"),
+    (0xc66d7617, "nousresearch"),
+    (0xc730e015, "fishaudio-text"),
+    (0xc74f3bbc, "lg-text"),
+    (0xc8047f7b, "perplexity"),
+    (0xc890ca29, "commanda"),
+    (0xc8e8da51, "livekit"),
+    (0xc9312523, "alephalpha-text"),
+    (0xcab0554f, "flora-text"),
+    (0xcad24151, "dreammachine"),
+    (0xcb08d262, "flux"),
+    (0xcb4d00c9, "capcut"),
+    (0xcba8e063, "mcp"),
+    (0xcbbcba25, "agui-text"),
+    (0xcbfed7a0, "huawei"),
+    (0xcc0642f1, "sambanova-text"),
+    (0xcc8f1eb5, "adobefirefly"),
+    (0xcd71cf06, "coze"),
+    (0xce1570d5, "tencentcloud-text"),
+    (0xce1809a6, "lovable-text"),
+    (0xce65782b, "capcut-text"),
+    (0xce73bf82, "krea"),
+    (0xcebf69b0, "udio-text"),
+    (0xcfb9d1f3, "glama"),
+    (0xd0df2c6b, "notebooklm"),
+    (0xd22cfdc4, "stepfun-text"),
+    (0xd2fdd7d2, "qwen-text"),
+    (0xd55d053e, "sophnet-text"),
+    (0xd6950931, "cogview"),
+    (0xd7676fa1, "xai"),
+    (0xd8814473, "ideogram"),
+    (0xd88e12e2, "githubcopilot"),
+    (0xd91d3469, "kolors"),
+    (0xd9a2b33b, "vidu"),
+    (0xd9ffda71, "glama-text"),
+    (0xda0f7156, "nplcloud"),
+    (0xdb165221, "qiniu"),
+    (0xdc2bea24, "replicate"),
+    (0xdc7fd565, "dolphin-text"),
+    (0xdd2bdeb6, "crewai"),
+    (0xdd4756d4, "alibabacloud-text"),
+    (0xddd3313f, "assemblyai"),
+    (0xddd50aae, "friendli"),
+    (0xdf54d22c, "alibabacloud"),
+    (0xdf79628c, "higress"),
+    (0xdfa09ad5, "baiducloud"),
+    (0xdfcfc223, "statecloud"),
+    (0xe003d98e, "smithery-text"),
+    (0xe03686f8, "flowith"),
+    (0xe0b5f494, "xuanyuan"),
+    (0xe0b934c3, "searchapi"),
+    (0xe0ec1e10, "moonshot-text"),
+    (0xe148b67e, "tencent-text"),
+    (0xe166394f, "sensenova-brand"),
+    (0xe18c56af, "make"),
+    (0xe19acd3d, "greptile"),
+    (0xe1ad5596, "github-text"),
+    (0xe1fc8517, "claude"),
+    (0xe2107855, "kimi-text"),
+    (0xe336320f, "cursor"),
+    (0xe33a0a0c, "vllm"),
+    (0xe40d37cf, "kimi"),
+    (0xe43cb5cd, "nousresearch-text"),
+    (0xe46b3776, "ai21-text"),
+    (0xe4eb258c, "magic"),
+    (0xe6397554, "crusoe-text"),
+    (0xe748d00a, "submodel"),
+    (0xe74a92fa, "friendli-text"),
+    (0xe755e81d, "bailian-text"),
+    (0xe7f59d9b, "alibaba-brand"),
+    (0xe8502673, "rwkv"),
+    (0xe8abcbee, "baai-text"),
+    (0xe8f15622, "yuanbao-text"),
+    (0xe9079c5e, "upstage"),
+    (0xe9a8c2a7, "kwaipilot-text"),
+    (0xe9c668d3, "dbrx-text"),
+    (0xe9dd73d1, "aimass-text"),
+    (0xea5321e1, "ollama-text"),
+    (0xeb7893de, "centml"),
+    (0xecc5ecb9, "docsearch"),
+    (0xed6bf2cc, "phidata"),
+    (0xeda877df, "fireworks-text"),
+    (0xede616c3, "openai"),
+    (0xee0660c2, "langchain-text"),
+    (0xee8d0c7a, "kling-text"),
+    (0xefb9aca7, "ppio-text-cn"),
+    (0xefd1a2b5, "together-text"),
+    (0xefd2805c, "baidu"),
+    (0xf0effb84, "cerebras-text"),
+    (0xf0fb3279, "anyscale"),
+    (0xf17a8331, "midjourney-text"),
+    (0xf2362cc1, "vertexai"),
+    (0xf24d350d, "deepinfra-text"),
+    (0xf286415b, "skywork"),
+    (0xf29721da, "azureai-text"),
+    (0xf2a5a9d9, "deepai"),
+    (0xf2f94af7, "hedra"),
+    (0xf35e65a8, "groq-text"),
+    (0xf43f2ce9, "deepseek-text"),
+    (0xf50b731c, "luma"),
+    (0xf57bfe72, "crewai-text"),
+    (0xf630074e, "modelscope"),
+    (0xf767bdd3, "pydanticai-text"),
+    (0xf800a1e7, "liquid-text"),
+    (0xf9ab0b82, "coze-text"),
+    (0xf9c6bf83, "huaweicloud-text"),
+    (0xf9c7e9db, "zhipu-text"),
+    (0xfa9a7350, "bytedance-text-cn"),
+    (0xfafb1d44, "tavily"),
+    (0xfb1b1034, "coqui"),
+    (0xfb45033b, "turix-text"),
+    (0xfb83dd35, "longcat"),
+    (0xfb8a6779, "zhipu"),
+    (0xfba53b7c, "tavily-text"),
+    (0xfbe1a169, "skywork-text"),
+    (0xfd3a2c38, "openrouter-text"),
+    (0xfe56a89c, "fastgpt-text"),
+    (0xfed9bb7c, "lobehub-text"),
+    (0xff0b218b, "commanda-text"),
+];
+
+pub(crate) const ICON_RTL_MIRROR: &[&str] = &[];
+
 pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantInfo> {
     VARIANTS
         .iter()
@@ -11396,21 +22420,78 @@ pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantI
 }
 
 pub(crate) fn icon_codepoint(name: &str, key: VariantKey) -> Option<u32> {
-    ICON_CODEPOINTS
-        .iter()
-        .find(|entry| entry.name == name)
-        .and_then(|entry| {
-            entry
-                .codepoints
-                .iter()
-                .find(|(k, _)| *k == key)
-                .map(|(_, cp)| *cp)
-        })
+    #[cfg(feature = "fast-lookup")]
+    let entry = icon_slot(name).map(|index| &ICON_CODEPOINTS[index]);
+    #[cfg(not(feature = "fast-lookup"))]
+    let entry = ICON_CODEPOINTS.iter().find(|entry| entry.name == name);
+    entry.and_then(|entry| {
+        entry
+            .codepoints
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, cp)| *cp)
+    })
+}
+
+pub(crate) fn canonical_name_ci(lowercase: &str) -> Option<&'static str> {
+    let index = ICON_NAMES_LOWERCASE.partition_point(|(lower, _)| *lower < lowercase);
+    ICON_NAMES_LOWERCASE
+        .get(index)
+        .filter(|(lower, _)| *lower == lowercase)
+        .map(|(_, name)| *name)
 }
 
 pub(crate) fn icon_available(name: &str) -> Option<&'static [(Style, Size)]> {
-    ICON_AVAILABILITY
-        .iter()
-        .find(|entry| entry.name == name)
-        .map(|entry| entry.available)
+    #[cfg(feature = "fast-lookup")]
+    let entry = icon_slot(name).map(|index| &ICON_AVAILABILITY[index]);
+    #[cfg(not(feature = "fast-lookup"))]
+    let entry = ICON_AVAILABILITY.iter().find(|entry| entry.name == name);
+    entry.map(|entry| entry.available)
+}
+
+pub(crate) fn brand_color(name: &str) -> Option<[u8; 4]> {
+    ICON_COLORS
+        .binary_search_by(|(entry, _)| (*entry).cmp(name))
+        .ok()
+        .map(|index| ICON_COLORS[index].1)
+}
+
+pub(crate) fn aria_label(name: &str) -> Option<&'static str> {
+    ICON_NAMES
+        .binary_search(&name)
+        .ok()
+        .map(|index| ICON_LABELS[index])
+}
+
+pub(crate) fn icon_by_id(id: u32) -> Option<&'static str> {
+    ICON_IDS
+        .binary_search_by_key(&id, |(entry, _)| *entry)
+        .ok()
+        .map(|index| ICON_IDS[index].1)
+}
+
+pub(crate) fn rtl_mirror(name: &str) -> bool {
+    ICON_RTL_MIRROR.binary_search(&name).is_ok()
+}
+
+#[cfg(all(test, feature = "fast-lookup"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fast_lookup_agrees_with_scan_tables() {
+        for (index, entry) in ICON_CODEPOINTS.iter().enumerate() {
+            assert_eq!(icon_slot(entry.name), Some(index));
+            for &(key, codepoint) in entry.codepoints {
+                assert_eq!(icon_codepoint(entry.name, key), Some(codepoint));
+            }
+            assert_eq!(
+                icon_available(entry.name),
+                Some(ICON_AVAILABILITY[index].available)
+            );
+        }
+        assert_eq!(ICON_SLOTS.iter().flatten().count(), ICON_CODEPOINTS.len());
+        assert_eq!(icon_slot(""), None);
+        assert_eq!(icon_available(""), None);
+    }
 }
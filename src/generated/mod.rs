@@ -1,6 +1,6 @@
 // @generated by xtask gen. DO NOT EDIT.
 
-use crate::core::{FontAsset, IconError, IconRef, Size, Style};
+use crate::core::{FontAsset, IconLookupError, IconRef, PackKind, Size, Style, VariantKey};
 
 #[cfg(feature = "pack-bootstrap")]
 pub mod bootstrap;
@@ -44,7 +44,18 @@ pub mod remixicon;
 #[cfg(feature = "pack-tabler")]
 pub mod tabler;
 
+/// Icon packs enabled by `pack-*` features.
+///
+/// New packs may be added in minor releases, so matches outside this crate need a wildcard
+/// arm. Even with no packs enabled, an empty match does not compile:
+///
+/// ```compile_fail
+/// fn id(pack: iconflow::Pack) -> &'static str {
+///     match pack {}
+/// }
+/// ```
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum Pack {
     #[cfg(feature = "pack-bootstrap")]
     Bootstrap,
@@ -76,77 +87,1510 @@ pub enum Pack {
     Tabler,
 }
 
+impl Pack {
+    /// Every pack enabled in this build.
+    pub const ALL: &[Pack] = &[
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler,
+    ];
+
+    /// Canonical pack id, matching the `pack-<id>` feature suffix.
+    ///
+    /// This is exactly the pack module's `PACK_ID`, e.g. `bootstrap::PACK_ID`.
+    pub fn id(self) -> &'static str {
+        match self {
+            #[cfg(feature = "pack-bootstrap")]
+            Pack::Bootstrap => bootstrap::PACK_ID,
+            #[cfg(feature = "pack-carbon")]
+            Pack::Carbon => carbon::PACK_ID,
+            #[cfg(feature = "pack-devicon")]
+            Pack::Devicon => devicon::PACK_ID,
+            #[cfg(feature = "pack-feather")]
+            Pack::Feather => feather::PACK_ID,
+            #[cfg(feature = "pack-fluentui")]
+            Pack::Fluentui => fluentui::PACK_ID,
+            #[cfg(feature = "pack-heroicons")]
+            Pack::Heroicons => heroicons::PACK_ID,
+            #[cfg(feature = "pack-iconoir")]
+            Pack::Iconoir => iconoir::PACK_ID,
+            #[cfg(feature = "pack-ionicons")]
+            Pack::Ionicons => ionicons::PACK_ID,
+            #[cfg(feature = "pack-lobe")]
+            Pack::Lobe => lobe::PACK_ID,
+            #[cfg(feature = "pack-lucide")]
+            Pack::Lucide => lucide::PACK_ID,
+            #[cfg(feature = "pack-octicons")]
+            Pack::Octicons => octicons::PACK_ID,
+            #[cfg(feature = "pack-phosphor")]
+            Pack::Phosphor => phosphor::PACK_ID,
+            #[cfg(feature = "pack-remixicon")]
+            Pack::Remixicon => remixicon::PACK_ID,
+            #[cfg(feature = "pack-tabler")]
+            Pack::Tabler => tabler::PACK_ID,
+        }
+    }
+
+    /// Human-readable pack name for UIs, e.g. `"Fluent UI"`.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "pack-bootstrap")]
+            Pack::Bootstrap => bootstrap::DISPLAY_NAME,
+            #[cfg(feature = "pack-carbon")]
+            Pack::Carbon => carbon::DISPLAY_NAME,
+            #[cfg(feature = "pack-devicon")]
+            Pack::Devicon => devicon::DISPLAY_NAME,
+            #[cfg(feature = "pack-feather")]
+            Pack::Feather => feather::DISPLAY_NAME,
+            #[cfg(feature = "pack-fluentui")]
+            Pack::Fluentui => fluentui::DISPLAY_NAME,
+            #[cfg(feature = "pack-heroicons")]
+            Pack::Heroicons => heroicons::DISPLAY_NAME,
+            #[cfg(feature = "pack-iconoir")]
+            Pack::Iconoir => iconoir::DISPLAY_NAME,
+            #[cfg(feature = "pack-ionicons")]
+            Pack::Ionicons => ionicons::DISPLAY_NAME,
+            #[cfg(feature = "pack-lobe")]
+            Pack::Lobe => lobe::DISPLAY_NAME,
+            #[cfg(feature = "pack-lucide")]
+            Pack::Lucide => lucide::DISPLAY_NAME,
+            #[cfg(feature = "pack-octicons")]
+            Pack::Octicons => octicons::DISPLAY_NAME,
+            #[cfg(feature = "pack-phosphor")]
+            Pack::Phosphor => phosphor::DISPLAY_NAME,
+            #[cfg(feature = "pack-remixicon")]
+            Pack::Remixicon => remixicon::DISPLAY_NAME,
+            #[cfg(feature = "pack-tabler")]
+            Pack::Tabler => tabler::DISPLAY_NAME,
+        }
+    }
+
+    /// Whether the pack holds UI icons or brand logos, from the map's `kind`.
+    pub fn kind(self) -> PackKind {
+        match self {
+            #[cfg(feature = "pack-bootstrap")]
+            Pack::Bootstrap => bootstrap::KIND,
+            #[cfg(feature = "pack-carbon")]
+            Pack::Carbon => carbon::KIND,
+            #[cfg(feature = "pack-devicon")]
+            Pack::Devicon => devicon::KIND,
+            #[cfg(feature = "pack-feather")]
+            Pack::Feather => feather::KIND,
+            #[cfg(feature = "pack-fluentui")]
+            Pack::Fluentui => fluentui::KIND,
+            #[cfg(feature = "pack-heroicons")]
+            Pack::Heroicons => heroicons::KIND,
+            #[cfg(feature = "pack-iconoir")]
+            Pack::Iconoir => iconoir::KIND,
+            #[cfg(feature = "pack-ionicons")]
+            Pack::Ionicons => ionicons::KIND,
+            #[cfg(feature = "pack-lobe")]
+            Pack::Lobe => lobe::KIND,
+            #[cfg(feature = "pack-lucide")]
+            Pack::Lucide => lucide::KIND,
+            #[cfg(feature = "pack-octicons")]
+            Pack::Octicons => octicons::KIND,
+            #[cfg(feature = "pack-phosphor")]
+            Pack::Phosphor => phosphor::KIND,
+            #[cfg(feature = "pack-remixicon")]
+            Pack::Remixicon => remixicon::KIND,
+            #[cfg(feature = "pack-tabler")]
+            Pack::Tabler => tabler::KIND,
+        }
+    }
+
+    /// Captures the pack's lookup functions once, for resolving many icons of it.
+    pub fn resolver(self) -> crate::core::PackResolver {
+        match self {
+            #[cfg(feature = "pack-bootstrap")]
+            Pack::Bootstrap => crate::core::PackResolver {
+                pack: self,
+                pack_id: bootstrap::PACK_ID,
+                icon_available: bootstrap::icon_available,
+                variant_family: |style, size| {
+                    bootstrap::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: bootstrap::icon_codepoint,
+            },
+            #[cfg(feature = "pack-carbon")]
+            Pack::Carbon => crate::core::PackResolver {
+                pack: self,
+                pack_id: carbon::PACK_ID,
+                icon_available: carbon::icon_available,
+                variant_family: |style, size| {
+                    carbon::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: carbon::icon_codepoint,
+            },
+            #[cfg(feature = "pack-devicon")]
+            Pack::Devicon => crate::core::PackResolver {
+                pack: self,
+                pack_id: devicon::PACK_ID,
+                icon_available: devicon::icon_available,
+                variant_family: |style, size| {
+                    devicon::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: devicon::icon_codepoint,
+            },
+            #[cfg(feature = "pack-feather")]
+            Pack::Feather => crate::core::PackResolver {
+                pack: self,
+                pack_id: feather::PACK_ID,
+                icon_available: feather::icon_available,
+                variant_family: |style, size| {
+                    feather::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: feather::icon_codepoint,
+            },
+            #[cfg(feature = "pack-fluentui")]
+            Pack::Fluentui => crate::core::PackResolver {
+                pack: self,
+                pack_id: fluentui::PACK_ID,
+                icon_available: fluentui::icon_available,
+                variant_family: |style, size| {
+                    fluentui::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: fluentui::icon_codepoint,
+            },
+            #[cfg(feature = "pack-heroicons")]
+            Pack::Heroicons => crate::core::PackResolver {
+                pack: self,
+                pack_id: heroicons::PACK_ID,
+                icon_available: heroicons::icon_available,
+                variant_family: |style, size| {
+                    heroicons::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: heroicons::icon_codepoint,
+            },
+            #[cfg(feature = "pack-iconoir")]
+            Pack::Iconoir => crate::core::PackResolver {
+                pack: self,
+                pack_id: iconoir::PACK_ID,
+                icon_available: iconoir::icon_available,
+                variant_family: |style, size| {
+                    iconoir::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: iconoir::icon_codepoint,
+            },
+            #[cfg(feature = "pack-ionicons")]
+            Pack::Ionicons => crate::core::PackResolver {
+                pack: self,
+                pack_id: ionicons::PACK_ID,
+                icon_available: ionicons::icon_available,
+                variant_family: |style, size| {
+                    ionicons::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: ionicons::icon_codepoint,
+            },
+            #[cfg(feature = "pack-lobe")]
+            Pack::Lobe => crate::core::PackResolver {
+                pack: self,
+                pack_id: lobe::PACK_ID,
+                icon_available: lobe::icon_available,
+                variant_family: |style, size| {
+                    lobe::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: lobe::icon_codepoint,
+            },
+            #[cfg(feature = "pack-lucide")]
+            Pack::Lucide => crate::core::PackResolver {
+                pack: self,
+                pack_id: lucide::PACK_ID,
+                icon_available: lucide::icon_available,
+                variant_family: |style, size| {
+                    lucide::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: lucide::icon_codepoint,
+            },
+            #[cfg(feature = "pack-octicons")]
+            Pack::Octicons => crate::core::PackResolver {
+                pack: self,
+                pack_id: octicons::PACK_ID,
+                icon_available: octicons::icon_available,
+                variant_family: |style, size| {
+                    octicons::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: octicons::icon_codepoint,
+            },
+            #[cfg(feature = "pack-phosphor")]
+            Pack::Phosphor => crate::core::PackResolver {
+                pack: self,
+                pack_id: phosphor::PACK_ID,
+                icon_available: phosphor::icon_available,
+                variant_family: |style, size| {
+                    phosphor::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: phosphor::icon_codepoint,
+            },
+            #[cfg(feature = "pack-remixicon")]
+            Pack::Remixicon => crate::core::PackResolver {
+                pack: self,
+                pack_id: remixicon::PACK_ID,
+                icon_available: remixicon::icon_available,
+                variant_family: |style, size| {
+                    remixicon::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: remixicon::icon_codepoint,
+            },
+            #[cfg(feature = "pack-tabler")]
+            Pack::Tabler => crate::core::PackResolver {
+                pack: self,
+                pack_id: tabler::PACK_ID,
+                icon_available: tabler::icon_available,
+                variant_family: |style, size| {
+                    tabler::variant_info(style, size).map(|info| info.family)
+                },
+                icon_codepoint: tabler::icon_codepoint,
+            },
+        }
+    }
+}
+
 pub fn fonts() -> &'static [FontAsset] {
     &[
         #[cfg(feature = "pack-bootstrap")]
         bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_FILLED,
         #[cfg(feature = "pack-bootstrap")]
-        bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR,
+        bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR,
+        #[cfg(feature = "pack-carbon")]
+        carbon::FONT_ASSET_CARBON_CARBON_FILLED,
+        #[cfg(feature = "pack-carbon")]
+        carbon::FONT_ASSET_CARBON_CARBON_GLYPH,
+        #[cfg(feature = "pack-carbon")]
+        carbon::FONT_ASSET_CARBON_CARBON_OUTLINE,
+        #[cfg(feature = "pack-carbon")]
+        carbon::FONT_ASSET_CARBON_CARBON_REGULAR,
+        #[cfg(feature = "pack-devicon")]
+        devicon::FONT_ASSET_DEVICON_DEVICON_REGULAR,
+        #[cfg(feature = "pack-feather")]
+        feather::FONT_ASSET_FEATHER_FEATHER_REGULAR,
+        #[cfg(feature = "pack-fluentui")]
+        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_FILLED,
+        #[cfg(feature = "pack-fluentui")]
+        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_LIGHT,
+        #[cfg(feature = "pack-fluentui")]
+        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_REGULAR,
+        #[cfg(feature = "pack-fluentui")]
+        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_RESIZABLE,
+        #[cfg(feature = "pack-heroicons")]
+        heroicons::FONT_ASSET_HEROICONS_HEROICONS_FILLED,
+        #[cfg(all(feature = "pack-heroicons", feature = "heroicons-mini"))]
+        heroicons::FONT_ASSET_HEROICONS_HEROICONS_MINI,
+        #[cfg(all(feature = "pack-heroicons", feature = "heroicons-tiny"))]
+        heroicons::FONT_ASSET_HEROICONS_HEROICONS_TINY,
+        #[cfg(feature = "pack-heroicons")]
+        heroicons::FONT_ASSET_HEROICONS_HEROICONS_OUTLINE,
+        #[cfg(feature = "pack-iconoir")]
+        iconoir::FONT_ASSET_ICONOIR_ICONOIR_FILLED,
+        #[cfg(feature = "pack-iconoir")]
+        iconoir::FONT_ASSET_ICONOIR_ICONOIR_REGULAR,
+        #[cfg(feature = "pack-ionicons")]
+        ionicons::FONT_ASSET_IONICONS_IONICONS_OUTLINE,
+        #[cfg(feature = "pack-ionicons")]
+        ionicons::FONT_ASSET_IONICONS_IONICONS_REGULAR,
+        #[cfg(feature = "pack-ionicons")]
+        ionicons::FONT_ASSET_IONICONS_IONICONS_SHARP,
+        #[cfg(feature = "pack-lobe")]
+        lobe::FONT_ASSET_LOBE_LOBE_REGULAR,
+        #[cfg(feature = "pack-lucide")]
+        lucide::FONT_ASSET_LUCIDE_LUCIDE_REGULAR,
+        #[cfg(feature = "pack-octicons")]
+        octicons::FONT_ASSET_OCTICONS_OCTICONS_REGULAR,
+        #[cfg(all(feature = "pack-octicons", feature = "octicons-tiny"))]
+        octicons::FONT_ASSET_OCTICONS_OCTICONS_TINY,
+        #[cfg(feature = "pack-phosphor")]
+        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_BOLD,
+        #[cfg(feature = "pack-phosphor")]
+        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_DUOTONE,
+        #[cfg(feature = "pack-phosphor")]
+        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_FILLED,
+        #[cfg(feature = "pack-phosphor")]
+        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_LIGHT,
+        #[cfg(feature = "pack-phosphor")]
+        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_REGULAR,
+        #[cfg(feature = "pack-phosphor")]
+        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_THIN,
+        #[cfg(feature = "pack-remixicon")]
+        remixicon::FONT_ASSET_REMIXICON_REMIXICON_REGULAR,
+        #[cfg(feature = "pack-tabler")]
+        tabler::FONT_ASSET_TABLER_TABLER_FILLED,
+        #[cfg(feature = "pack-tabler")]
+        tabler::FONT_ASSET_TABLER_TABLER_REGULAR,
+    ]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn list(pack: Pack) -> &'static [&'static str] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::ICON_NAMES,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::ICON_NAMES,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::ICON_NAMES,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::ICON_NAMES,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::ICON_NAMES,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::ICON_NAMES,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::ICON_NAMES,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::ICON_NAMES,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::ICON_NAMES,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::ICON_NAMES,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::ICON_NAMES,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::ICON_NAMES,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::ICON_NAMES,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::ICON_NAMES,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn list(_pack: Pack) -> &'static [&'static str] {
+    &[]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn pack_fonts(pack: Pack) -> &'static [FontAsset] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::FONT_ASSETS,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::FONT_ASSETS,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::FONT_ASSETS,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::FONT_ASSETS,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::FONT_ASSETS,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::FONT_ASSETS,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::FONT_ASSETS,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::FONT_ASSETS,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::FONT_ASSETS,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::FONT_ASSETS,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::FONT_ASSETS,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::FONT_ASSETS,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::FONT_ASSETS,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::FONT_ASSETS,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn pack_fonts(_pack: Pack) -> &'static [FontAsset] {
+    &[]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn variant_fonts(pack: Pack) -> &'static [(VariantKey, FontAsset)] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::VARIANT_ASSETS,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::VARIANT_ASSETS,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::VARIANT_ASSETS,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::VARIANT_ASSETS,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::VARIANT_ASSETS,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::VARIANT_ASSETS,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::VARIANT_ASSETS,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::VARIANT_ASSETS,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::VARIANT_ASSETS,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::VARIANT_ASSETS,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::VARIANT_ASSETS,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::VARIANT_ASSETS,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::VARIANT_ASSETS,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::VARIANT_ASSETS,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn variant_fonts(_pack: Pack) -> &'static [(VariantKey, FontAsset)] {
+    &[]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn variant_codepoint_ranges(pack: Pack) -> &'static [(VariantKey, u32, u32)] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::VARIANT_CODEPOINT_RANGES,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::VARIANT_CODEPOINT_RANGES,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn variant_codepoint_ranges(_pack: Pack) -> &'static [(VariantKey, u32, u32)] {
+    &[]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn styles(pack: Pack) -> &'static [Style] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::STYLES,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::STYLES,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::STYLES,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::STYLES,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::STYLES,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::STYLES,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::STYLES,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::STYLES,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::STYLES,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::STYLES,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::STYLES,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::STYLES,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::STYLES,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::STYLES,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn styles(_pack: Pack) -> &'static [Style] {
+    &[]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn sizes(pack: Pack) -> &'static [Size] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::SIZES,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::SIZES,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::SIZES,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::SIZES,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::SIZES,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::SIZES,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::SIZES,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::SIZES,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::SIZES,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::SIZES,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::SIZES,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::SIZES,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::SIZES,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::SIZES,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn sizes(_pack: Pack) -> &'static [Size] {
+    &[]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn icon_by_codepoint(pack: Pack) -> &'static [(VariantKey, u32, &'static str)] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::ICON_BY_CODEPOINT,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::ICON_BY_CODEPOINT,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn icon_by_codepoint(_pack: Pack) -> &'static [(VariantKey, u32, &'static str)] {
+    &[]
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn style_order(pack: Pack) -> &'static [Style] {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::STYLE_ORDER,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::STYLE_ORDER,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::STYLE_ORDER,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::STYLE_ORDER,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::STYLE_ORDER,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::STYLE_ORDER,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::STYLE_ORDER,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::STYLE_ORDER,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::STYLE_ORDER,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::STYLE_ORDER,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::STYLE_ORDER,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::STYLE_ORDER,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::STYLE_ORDER,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::STYLE_ORDER,
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn style_order(_pack: Pack) -> &'static [Style] {
+    &[]
+}
+
+pub fn content_hash(pack: Pack) -> u64 {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::CONTENT_HASH,
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::CONTENT_HASH,
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::CONTENT_HASH,
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::CONTENT_HASH,
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::CONTENT_HASH,
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::CONTENT_HASH,
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::CONTENT_HASH,
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::CONTENT_HASH,
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::CONTENT_HASH,
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::CONTENT_HASH,
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::CONTENT_HASH,
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::CONTENT_HASH,
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::CONTENT_HASH,
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::CONTENT_HASH,
+    }
+}
+
+#[cfg(all(
+    feature = "index",
+    any(
+        feature = "pack-bootstrap",
+        feature = "pack-carbon",
+        feature = "pack-devicon",
+        feature = "pack-feather",
+        feature = "pack-fluentui",
+        feature = "pack-heroicons",
+        feature = "pack-iconoir",
+        feature = "pack-ionicons",
+        feature = "pack-lobe",
+        feature = "pack-lucide",
+        feature = "pack-octicons",
+        feature = "pack-phosphor",
+        feature = "pack-remixicon",
+        feature = "pack-tabler"
+    )
+))]
+const fn append_icons(
+    out: &mut [(Pack, &'static str)],
+    mut at: usize,
+    pack: Pack,
+    names: &[&'static str],
+) -> usize {
+    let mut index = 0;
+    while index < names.len() {
+        out[at] = (pack, names[index]);
+        at += 1;
+        index += 1;
+    }
+    at
+}
+
+#[cfg(all(
+    feature = "index",
+    any(
+        feature = "pack-bootstrap",
+        feature = "pack-carbon",
+        feature = "pack-devicon",
+        feature = "pack-feather",
+        feature = "pack-fluentui",
+        feature = "pack-heroicons",
+        feature = "pack-iconoir",
+        feature = "pack-ionicons",
+        feature = "pack-lobe",
+        feature = "pack-lucide",
+        feature = "pack-octicons",
+        feature = "pack-phosphor",
+        feature = "pack-remixicon",
+        feature = "pack-tabler"
+    )
+))]
+const ALL_ICONS_LEN: usize = {
+    let mut len = 0;
+    #[cfg(feature = "pack-bootstrap")]
+    {
+        len += bootstrap::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-carbon")]
+    {
+        len += carbon::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-devicon")]
+    {
+        len += devicon::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-feather")]
+    {
+        len += feather::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-fluentui")]
+    {
+        len += fluentui::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-heroicons")]
+    {
+        len += heroicons::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-iconoir")]
+    {
+        len += iconoir::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-ionicons")]
+    {
+        len += ionicons::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-lobe")]
+    {
+        len += lobe::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-lucide")]
+    {
+        len += lucide::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-octicons")]
+    {
+        len += octicons::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-phosphor")]
+    {
+        len += phosphor::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-remixicon")]
+    {
+        len += remixicon::ICON_NAMES.len();
+    }
+    #[cfg(feature = "pack-tabler")]
+    {
+        len += tabler::ICON_NAMES.len();
+    }
+    len
+};
+
+/// Every `(pack, name)` pair across the enabled packs, in `Pack::ALL` order.
+#[cfg(all(
+    feature = "index",
+    any(
+        feature = "pack-bootstrap",
+        feature = "pack-carbon",
+        feature = "pack-devicon",
+        feature = "pack-feather",
+        feature = "pack-fluentui",
+        feature = "pack-heroicons",
+        feature = "pack-iconoir",
+        feature = "pack-ionicons",
+        feature = "pack-lobe",
+        feature = "pack-lucide",
+        feature = "pack-octicons",
+        feature = "pack-phosphor",
+        feature = "pack-remixicon",
+        feature = "pack-tabler"
+    )
+))]
+pub const ALL_ICONS: &[(Pack, &str)] = &{
+    let mut out = [(Pack::ALL[0], ""); ALL_ICONS_LEN];
+    let mut at = 0;
+    #[cfg(feature = "pack-bootstrap")]
+    {
+        at = append_icons(&mut out, at, Pack::Bootstrap, bootstrap::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-carbon")]
+    {
+        at = append_icons(&mut out, at, Pack::Carbon, carbon::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-devicon")]
+    {
+        at = append_icons(&mut out, at, Pack::Devicon, devicon::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-feather")]
+    {
+        at = append_icons(&mut out, at, Pack::Feather, feather::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-fluentui")]
+    {
+        at = append_icons(&mut out, at, Pack::Fluentui, fluentui::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-heroicons")]
+    {
+        at = append_icons(&mut out, at, Pack::Heroicons, heroicons::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-iconoir")]
+    {
+        at = append_icons(&mut out, at, Pack::Iconoir, iconoir::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-ionicons")]
+    {
+        at = append_icons(&mut out, at, Pack::Ionicons, ionicons::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-lobe")]
+    {
+        at = append_icons(&mut out, at, Pack::Lobe, lobe::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-lucide")]
+    {
+        at = append_icons(&mut out, at, Pack::Lucide, lucide::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-octicons")]
+    {
+        at = append_icons(&mut out, at, Pack::Octicons, octicons::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-phosphor")]
+    {
+        at = append_icons(&mut out, at, Pack::Phosphor, phosphor::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-remixicon")]
+    {
+        at = append_icons(&mut out, at, Pack::Remixicon, remixicon::ICON_NAMES);
+    }
+    #[cfg(feature = "pack-tabler")]
+    {
+        at = append_icons(&mut out, at, Pack::Tabler, tabler::ICON_NAMES);
+    }
+    let _ = at;
+    out
+};
+
+/// Every `(pack, name)` pair across the enabled packs, in `Pack::ALL` order.
+#[cfg(all(
+    feature = "index",
+    not(any(
+        feature = "pack-bootstrap",
+        feature = "pack-carbon",
+        feature = "pack-devicon",
+        feature = "pack-feather",
+        feature = "pack-fluentui",
+        feature = "pack-heroicons",
+        feature = "pack-iconoir",
+        feature = "pack-ionicons",
+        feature = "pack-lobe",
+        feature = "pack-lucide",
+        feature = "pack-octicons",
+        feature = "pack-phosphor",
+        feature = "pack-remixicon",
+        feature = "pack-tabler"
+    ))
+))]
+pub const ALL_ICONS: &[(Pack, &str)] = &[];
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn canonical_name_ci(pack: Pack, lowercase: &str) -> Option<&'static str> {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::canonical_name_ci(lowercase),
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::canonical_name_ci(lowercase),
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn canonical_name_ci(_pack: Pack, _lowercase: &str) -> Option<&'static str> {
+    None
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn brand_color(pack: Pack, name: &str) -> Option<[u8; 4]> {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::brand_color(name),
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::brand_color(name),
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::brand_color(name),
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::brand_color(name),
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::brand_color(name),
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::brand_color(name),
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::brand_color(name),
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::brand_color(name),
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::brand_color(name),
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::brand_color(name),
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::brand_color(name),
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::brand_color(name),
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::brand_color(name),
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::brand_color(name),
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn brand_color(_pack: Pack, _name: &str) -> Option<[u8; 4]> {
+    None
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn icon_by_id(pack: Pack, id: u32) -> Option<&'static str> {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::icon_by_id(id),
         #[cfg(feature = "pack-carbon")]
-        carbon::FONT_ASSET_CARBON_CARBON_FILLED,
-        #[cfg(feature = "pack-carbon")]
-        carbon::FONT_ASSET_CARBON_CARBON_GLYPH,
-        #[cfg(feature = "pack-carbon")]
-        carbon::FONT_ASSET_CARBON_CARBON_OUTLINE,
-        #[cfg(feature = "pack-carbon")]
-        carbon::FONT_ASSET_CARBON_CARBON_REGULAR,
+        Pack::Carbon => carbon::icon_by_id(id),
         #[cfg(feature = "pack-devicon")]
-        devicon::FONT_ASSET_DEVICON_DEVICON_REGULAR,
+        Pack::Devicon => devicon::icon_by_id(id),
         #[cfg(feature = "pack-feather")]
-        feather::FONT_ASSET_FEATHER_FEATHER_REGULAR,
-        #[cfg(feature = "pack-fluentui")]
-        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_FILLED,
-        #[cfg(feature = "pack-fluentui")]
-        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_LIGHT,
-        #[cfg(feature = "pack-fluentui")]
-        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_REGULAR,
+        Pack::Feather => feather::icon_by_id(id),
         #[cfg(feature = "pack-fluentui")]
-        fluentui::FONT_ASSET_FLUENTUI_FLUENTUI_RESIZABLE,
+        Pack::Fluentui => fluentui::icon_by_id(id),
         #[cfg(feature = "pack-heroicons")]
-        heroicons::FONT_ASSET_HEROICONS_HEROICONS_FILLED,
-        #[cfg(all(feature = "pack-heroicons", feature = "heroicons-mini"))]
-        heroicons::FONT_ASSET_HEROICONS_HEROICONS_MINI,
-        #[cfg(feature = "pack-heroicons")]
-        heroicons::FONT_ASSET_HEROICONS_HEROICONS_OUTLINE,
-        #[cfg(all(feature = "pack-heroicons", feature = "heroicons-tiny"))]
-        heroicons::FONT_ASSET_HEROICONS_HEROICONS_TINY,
-        #[cfg(feature = "pack-iconoir")]
-        iconoir::FONT_ASSET_ICONOIR_ICONOIR_FILLED,
+        Pack::Heroicons => heroicons::icon_by_id(id),
         #[cfg(feature = "pack-iconoir")]
-        iconoir::FONT_ASSET_ICONOIR_ICONOIR_REGULAR,
-        #[cfg(feature = "pack-ionicons")]
-        ionicons::FONT_ASSET_IONICONS_IONICONS_OUTLINE,
+        Pack::Iconoir => iconoir::icon_by_id(id),
         #[cfg(feature = "pack-ionicons")]
-        ionicons::FONT_ASSET_IONICONS_IONICONS_REGULAR,
-        #[cfg(feature = "pack-ionicons")]
-        ionicons::FONT_ASSET_IONICONS_IONICONS_SHARP,
+        Pack::Ionicons => ionicons::icon_by_id(id),
         #[cfg(feature = "pack-lobe")]
-        lobe::FONT_ASSET_LOBE_LOBE_REGULAR,
+        Pack::Lobe => lobe::icon_by_id(id),
         #[cfg(feature = "pack-lucide")]
-        lucide::FONT_ASSET_LUCIDE_LUCIDE_REGULAR,
+        Pack::Lucide => lucide::icon_by_id(id),
         #[cfg(feature = "pack-octicons")]
-        octicons::FONT_ASSET_OCTICONS_OCTICONS_REGULAR,
-        #[cfg(all(feature = "pack-octicons", feature = "octicons-tiny"))]
-        octicons::FONT_ASSET_OCTICONS_OCTICONS_TINY,
-        #[cfg(feature = "pack-phosphor")]
-        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_BOLD,
-        #[cfg(feature = "pack-phosphor")]
-        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_DUOTONE,
+        Pack::Octicons => octicons::icon_by_id(id),
         #[cfg(feature = "pack-phosphor")]
-        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_FILLED,
-        #[cfg(feature = "pack-phosphor")]
-        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_LIGHT,
-        #[cfg(feature = "pack-phosphor")]
-        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_REGULAR,
+        Pack::Phosphor => phosphor::icon_by_id(id),
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::icon_by_id(id),
+        #[cfg(feature = "pack-tabler")]
+        Pack::Tabler => tabler::icon_by_id(id),
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn icon_by_id(_pack: Pack, _id: u32) -> Option<&'static str> {
+    None
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn aria_label(pack: Pack, name: &str) -> Option<&'static str> {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::aria_label(name),
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::aria_label(name),
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::aria_label(name),
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::aria_label(name),
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::aria_label(name),
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::aria_label(name),
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::aria_label(name),
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::aria_label(name),
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::aria_label(name),
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::aria_label(name),
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::aria_label(name),
         #[cfg(feature = "pack-phosphor")]
-        phosphor::FONT_ASSET_PHOSPHOR_PHOSPHOR_THIN,
+        Pack::Phosphor => phosphor::aria_label(name),
         #[cfg(feature = "pack-remixicon")]
-        remixicon::FONT_ASSET_REMIXICON_REMIXICON_REGULAR,
+        Pack::Remixicon => remixicon::aria_label(name),
         #[cfg(feature = "pack-tabler")]
-        tabler::FONT_ASSET_TABLER_TABLER_FILLED,
+        Pack::Tabler => tabler::aria_label(name),
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn aria_label(_pack: Pack, _name: &str) -> Option<&'static str> {
+    None
+}
+
+#[cfg(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+))]
+pub fn rtl_mirror(pack: Pack, name: &str) -> bool {
+    match pack {
+        #[cfg(feature = "pack-bootstrap")]
+        Pack::Bootstrap => bootstrap::rtl_mirror(name),
+        #[cfg(feature = "pack-carbon")]
+        Pack::Carbon => carbon::rtl_mirror(name),
+        #[cfg(feature = "pack-devicon")]
+        Pack::Devicon => devicon::rtl_mirror(name),
+        #[cfg(feature = "pack-feather")]
+        Pack::Feather => feather::rtl_mirror(name),
+        #[cfg(feature = "pack-fluentui")]
+        Pack::Fluentui => fluentui::rtl_mirror(name),
+        #[cfg(feature = "pack-heroicons")]
+        Pack::Heroicons => heroicons::rtl_mirror(name),
+        #[cfg(feature = "pack-iconoir")]
+        Pack::Iconoir => iconoir::rtl_mirror(name),
+        #[cfg(feature = "pack-ionicons")]
+        Pack::Ionicons => ionicons::rtl_mirror(name),
+        #[cfg(feature = "pack-lobe")]
+        Pack::Lobe => lobe::rtl_mirror(name),
+        #[cfg(feature = "pack-lucide")]
+        Pack::Lucide => lucide::rtl_mirror(name),
+        #[cfg(feature = "pack-octicons")]
+        Pack::Octicons => octicons::rtl_mirror(name),
+        #[cfg(feature = "pack-phosphor")]
+        Pack::Phosphor => phosphor::rtl_mirror(name),
+        #[cfg(feature = "pack-remixicon")]
+        Pack::Remixicon => remixicon::rtl_mirror(name),
         #[cfg(feature = "pack-tabler")]
-        tabler::FONT_ASSET_TABLER_TABLER_REGULAR,
-    ]
+        Pack::Tabler => tabler::rtl_mirror(name),
+    }
+}
+
+#[cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler"
+)))]
+pub fn rtl_mirror(_pack: Pack, _name: &str) -> bool {
+    false
 }
 
 #[cfg(any(
@@ -165,36 +1609,36 @@ pub fn fonts() -> &'static [FontAsset] {
     feature = "pack-remixicon",
     feature = "pack-tabler"
 ))]
-pub fn list(pack: Pack) -> &'static [&'static str] {
+pub fn icon_available(pack: Pack, name: &str) -> Option<&'static [(Style, Size)]> {
     match pack {
         #[cfg(feature = "pack-bootstrap")]
-        Pack::Bootstrap => bootstrap::ICON_NAMES,
+        Pack::Bootstrap => bootstrap::icon_available(name),
         #[cfg(feature = "pack-carbon")]
-        Pack::Carbon => carbon::ICON_NAMES,
+        Pack::Carbon => carbon::icon_available(name),
         #[cfg(feature = "pack-devicon")]
-        Pack::Devicon => devicon::ICON_NAMES,
+        Pack::Devicon => devicon::icon_available(name),
         #[cfg(feature = "pack-feather")]
-        Pack::Feather => feather::ICON_NAMES,
+        Pack::Feather => feather::icon_available(name),
         #[cfg(feature = "pack-fluentui")]
-        Pack::Fluentui => fluentui::ICON_NAMES,
+        Pack::Fluentui => fluentui::icon_available(name),
         #[cfg(feature = "pack-heroicons")]
-        Pack::Heroicons => heroicons::ICON_NAMES,
+        Pack::Heroicons => heroicons::icon_available(name),
         #[cfg(feature = "pack-iconoir")]
-        Pack::Iconoir => iconoir::ICON_NAMES,
+        Pack::Iconoir => iconoir::icon_available(name),
         #[cfg(feature = "pack-ionicons")]
-        Pack::Ionicons => ionicons::ICON_NAMES,
+        Pack::Ionicons => ionicons::icon_available(name),
         #[cfg(feature = "pack-lobe")]
-        Pack::Lobe => lobe::ICON_NAMES,
+        Pack::Lobe => lobe::icon_available(name),
         #[cfg(feature = "pack-lucide")]
-        Pack::Lucide => lucide::ICON_NAMES,
+        Pack::Lucide => lucide::icon_available(name),
         #[cfg(feature = "pack-octicons")]
-        Pack::Octicons => octicons::ICON_NAMES,
+        Pack::Octicons => octicons::icon_available(name),
         #[cfg(feature = "pack-phosphor")]
-        Pack::Phosphor => phosphor::ICON_NAMES,
+        Pack::Phosphor => phosphor::icon_available(name),
         #[cfg(feature = "pack-remixicon")]
-        Pack::Remixicon => remixicon::ICON_NAMES,
+        Pack::Remixicon => remixicon::icon_available(name),
         #[cfg(feature = "pack-tabler")]
-        Pack::Tabler => tabler::ICON_NAMES,
+        Pack::Tabler => tabler::icon_available(name),
     }
 }
 
@@ -214,8 +1658,8 @@ pub fn list(pack: Pack) -> &'static [&'static str] {
     feature = "pack-remixicon",
     feature = "pack-tabler"
 )))]
-pub fn list(_pack: Pack) -> &'static [&'static str] {
-    &[]
+pub fn icon_available(_pack: Pack, _name: &str) -> Option<&'static [(Style, Size)]> {
+    None
 }
 
 #[cfg(any(
@@ -234,10 +1678,15 @@ pub fn list(_pack: Pack) -> &'static [&'static str] {
     feature = "pack-remixicon",
     feature = "pack-tabler"
 ))]
-pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<IconRef, IconError> {
+pub fn try_icon_ref(
+    pack: Pack,
+    name: &str,
+    style: Style,
+    size: Size,
+) -> Result<IconRef, IconLookupError<'_>> {
     match pack {
         #[cfg(feature = "pack-bootstrap")]
-        Pack::Bootstrap => resolve_icon(
+        Pack::Bootstrap => crate::core::resolve_icon(
             bootstrap::PACK_ID,
             name,
             style,
@@ -247,7 +1696,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             bootstrap::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-carbon")]
-        Pack::Carbon => resolve_icon(
+        Pack::Carbon => crate::core::resolve_icon(
             carbon::PACK_ID,
             name,
             style,
@@ -257,7 +1706,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             carbon::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-devicon")]
-        Pack::Devicon => resolve_icon(
+        Pack::Devicon => crate::core::resolve_icon(
             devicon::PACK_ID,
             name,
             style,
@@ -267,7 +1716,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             devicon::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-feather")]
-        Pack::Feather => resolve_icon(
+        Pack::Feather => crate::core::resolve_icon(
             feather::PACK_ID,
             name,
             style,
@@ -277,7 +1726,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             feather::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-fluentui")]
-        Pack::Fluentui => resolve_icon(
+        Pack::Fluentui => crate::core::resolve_icon(
             fluentui::PACK_ID,
             name,
             style,
@@ -287,7 +1736,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             fluentui::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-heroicons")]
-        Pack::Heroicons => resolve_icon(
+        Pack::Heroicons => crate::core::resolve_icon(
             heroicons::PACK_ID,
             name,
             style,
@@ -297,7 +1746,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             heroicons::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-iconoir")]
-        Pack::Iconoir => resolve_icon(
+        Pack::Iconoir => crate::core::resolve_icon(
             iconoir::PACK_ID,
             name,
             style,
@@ -307,7 +1756,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             iconoir::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-ionicons")]
-        Pack::Ionicons => resolve_icon(
+        Pack::Ionicons => crate::core::resolve_icon(
             ionicons::PACK_ID,
             name,
             style,
@@ -317,7 +1766,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             ionicons::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-lobe")]
-        Pack::Lobe => resolve_icon(
+        Pack::Lobe => crate::core::resolve_icon(
             lobe::PACK_ID,
             name,
             style,
@@ -327,7 +1776,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             lobe::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-lucide")]
-        Pack::Lucide => resolve_icon(
+        Pack::Lucide => crate::core::resolve_icon(
             lucide::PACK_ID,
             name,
             style,
@@ -337,7 +1786,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             lucide::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-octicons")]
-        Pack::Octicons => resolve_icon(
+        Pack::Octicons => crate::core::resolve_icon(
             octicons::PACK_ID,
             name,
             style,
@@ -347,7 +1796,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             octicons::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-phosphor")]
-        Pack::Phosphor => resolve_icon(
+        Pack::Phosphor => crate::core::resolve_icon(
             phosphor::PACK_ID,
             name,
             style,
@@ -357,7 +1806,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             phosphor::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-remixicon")]
-        Pack::Remixicon => resolve_icon(
+        Pack::Remixicon => crate::core::resolve_icon(
             remixicon::PACK_ID,
             name,
             style,
@@ -367,7 +1816,7 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
             remixicon::icon_codepoint(name, crate::core::VariantKey { style, size }),
         ),
         #[cfg(feature = "pack-tabler")]
-        Pack::Tabler => resolve_icon(
+        Pack::Tabler => crate::core::resolve_icon(
             tabler::PACK_ID,
             name,
             style,
@@ -395,60 +1844,11 @@ pub fn try_icon(pack: Pack, name: &str, style: Style, size: Size) -> Result<Icon
     feature = "pack-remixicon",
     feature = "pack-tabler"
 )))]
-pub fn try_icon(
+pub fn try_icon_ref(
     _pack: Pack,
     _name: &str,
     _style: Style,
     _size: Size,
-) -> Result<IconRef, IconError> {
-    Err(IconError::PackDisabled { pack: "none" })
-}
-
-#[cfg(any(
-    feature = "pack-bootstrap",
-    feature = "pack-carbon",
-    feature = "pack-devicon",
-    feature = "pack-feather",
-    feature = "pack-fluentui",
-    feature = "pack-heroicons",
-    feature = "pack-iconoir",
-    feature = "pack-ionicons",
-    feature = "pack-lobe",
-    feature = "pack-lucide",
-    feature = "pack-octicons",
-    feature = "pack-phosphor",
-    feature = "pack-remixicon",
-    feature = "pack-tabler"
-))]
-fn resolve_icon(
-    pack: &'static str,
-    name: &str,
-    style: Style,
-    size: Size,
-    available: Option<&'static [(Style, Size)]>,
-    family: Option<&'static str>,
-    codepoint: Option<u32>,
-) -> Result<IconRef, IconError> {
-    let available = match available {
-        Some(available) => available,
-        None => {
-            return Err(IconError::IconNotFound {
-                pack,
-                name: name.to_string(),
-            });
-        }
-    };
-
-    if !available.contains(&(style, size)) {
-        return Err(IconError::VariantUnavailable {
-            pack,
-            name: name.to_string(),
-            requested: (style, size),
-            available,
-        });
-    }
-
-    let family = family.expect("Icon variant should have a font family");
-    let codepoint = codepoint.expect("Icon variant should have a codepoint");
-    Ok(IconRef { family, codepoint })
+) -> Result<IconRef, IconLookupError<'_>> {
+    Err(IconLookupError::PackDisabled { pack: "none" })
 }
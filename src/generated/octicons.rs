@@ -1,7 +1,13 @@
 // @generated by xtask gen. DO NOT EDIT.
-use crate::core::{FontAsset, IconRef, Size, Style, VariantKey};
+use crate::core::{FontAsset, IconRef, PackKind, Size, Style, VariantKey};
 
+/// Canonical pack id, also returned by `Pack::Octicons.id()`.
 pub const PACK_ID: &str = "octicons";
+pub const DISPLAY_NAME: &str = "Octicons";
+pub const KIND: PackKind = PackKind::Ui;
+/// Fingerprint of the pack's families, names and codepoints; changes when the map does.
+pub const CONTENT_HASH: u64 = 0x915556b4e9b31d61;
+pub const STYLE_ORDER: &[Style] = &[];
 
 pub(crate) const FONT_ASSET_OCTICONS_OCTICONS_REGULAR: FontAsset = FontAsset {
     family: "Octicons Regular",
@@ -58,359 +64,1159 @@ pub const VARIANT_ASSETS: &[(VariantKey, FontAsset)] = &[
     ),
 ];
 
+/// Styles of the enabled variants, in presentation order (`STYLE_ORDER` first).
+pub const STYLES: &[Style] = &[Style::Regular, Style::Filled];
+
+/// Sizes of the enabled variants, sorted.
+pub const SIZES: &[Size] = &[
+    #[cfg(feature = "octicons-tiny")]
+    Size::Tiny,
+    Size::Regular,
+];
+
+/// Lowest and highest codepoint each variant maps, for variants that map any.
+pub(crate) const VARIANT_CODEPOINT_RANGES: &[(VariantKey, u32, u32)] = &[
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE006,
+        0xE15F,
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE000,
+        0xE162,
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE000,
+        0xE16C,
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE005,
+        0xE169,
+    ),
+];
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Icon {
+    /// `accessibility` — U+E000 (available in Regular, Regular/Tiny)
     Accessibility,
+    /// `accessibility-inset` — U+E001 (available in Regular, Regular/Tiny)
     AccessibilityInset,
+    /// `agent` — U+E002 (available in Regular, Regular/Tiny)
     Agent,
+    /// `ai-model` — U+E003 (available in Regular, Regular/Tiny)
     AiModel,
+    /// `alert` — available in Filled U+E006, Regular U+E004, Regular/Tiny U+E004, Filled/Tiny U+E005
     Alert,
+    /// `apps` — available in Regular U+E007, Regular/Tiny U+E006
     Apps,
+    /// `archive` — available in Regular U+E008, Regular/Tiny U+E007
     Archive,
+    /// `arrow-both` — available in Regular U+E009, Regular/Tiny U+E008
     ArrowBoth,
+    /// `arrow-down` — available in Regular U+E00A, Regular/Tiny U+E009
     ArrowDown,
+    /// `arrow-down-left` — available in Regular U+E00B, Regular/Tiny U+E00A
     ArrowDownLeft,
+    /// `arrow-down-right` — available in Regular U+E00C, Regular/Tiny U+E00B
     ArrowDownRight,
+    /// `arrow-left` — available in Regular U+E00D, Regular/Tiny U+E00C
     ArrowLeft,
+    /// `arrow-right` — available in Regular U+E00E, Regular/Tiny U+E00D
     ArrowRight,
+    /// `arrow-switch` — available in Regular U+E00F, Regular/Tiny U+E00E
     ArrowSwitch,
+    /// `arrow-up` — available in Regular U+E010, Regular/Tiny U+E00F
     ArrowUp,
+    /// `arrow-up-left` — available in Regular U+E011, Regular/Tiny U+E010
     ArrowUpLeft,
+    /// `arrow-up-right` — available in Regular U+E012, Regular/Tiny U+E011
     ArrowUpRight,
+    /// `beaker` — available in Regular U+E013, Regular/Tiny U+E012
     Beaker,
+    /// `bell` — available in Filled U+E015, Regular U+E014, Regular/Tiny U+E013, Filled/Tiny U+E014
     Bell,
+    /// `bell-slash` — available in Regular U+E016, Regular/Tiny U+E015
     BellSlash,
+    /// `blocked` — available in Regular U+E017, Regular/Tiny U+E016
     Blocked,
+    /// `bold` — available in Regular U+E018, Regular/Tiny U+E017
     Bold,
+    /// `book` — available in Regular U+E019, Regular/Tiny U+E018
     Book,
+    /// `bookmark` — available in Filled U+E01B, Regular U+E01A, Regular/Tiny U+E019
     Bookmark,
+    /// `bookmark-filled` — U+E01A (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     BookmarkFilled,
+    /// `bookmark-slash` — available in Filled U+E01D, Regular U+E01C, Regular/Tiny U+E01B, Filled/Tiny U+E01C
     BookmarkSlash,
+    /// `boolean-off` — available in Regular U+E01E, Regular/Tiny U+E01D
     BooleanOff,
+    /// `boolean-on` — available in Regular U+E01F, Regular/Tiny U+E01E
     BooleanOn,
+    /// `briefcase` — available in Regular U+E020, Regular/Tiny U+E01F
     Briefcase,
+    /// `broadcast` — available in Regular U+E021, Regular/Tiny U+E020
     Broadcast,
+    /// `browser` — available in Regular U+E022, Regular/Tiny U+E021
     Browser,
+    /// `bug` — available in Regular U+E023, Regular/Tiny U+E022
     Bug,
+    /// `cache` — available in Regular U+E024, Regular/Tiny U+E023
     Cache,
+    /// `calendar` — available in Regular U+E025, Regular/Tiny U+E024
     Calendar,
+    /// `check` — available in Regular U+E026, Regular/Tiny U+E025
     Check,
+    /// `check-circle` — available in Filled U+E029, Regular U+E027, Regular/Tiny U+E026, Filled/Tiny U+E027
     CheckCircle,
+    /// `checkbox` — available in Filled U+E02B, Regular U+E02A, Regular/Tiny U+E028, Filled/Tiny U+E029
     Checkbox,
+    /// `checklist` — available in Regular U+E02C, Regular/Tiny U+E02A
     Checklist,
+    /// `chevron-down` — available in Regular U+E02E, Regular/Tiny U+E02B
     ChevronDown,
+    /// `chevron-left` — available in Regular U+E030, Regular/Tiny U+E02C
     ChevronLeft,
+    /// `chevron-right` — available in Regular U+E032, Regular/Tiny U+E02D
     ChevronRight,
+    /// `chevron-up` — available in Regular U+E034, Regular/Tiny U+E02E
     ChevronUp,
+    /// `circle` — available in Regular U+E035, Regular/Tiny U+E02F
     Circle,
+    /// `circle-slash` — available in Regular U+E036, Regular/Tiny U+E030
     CircleSlash,
+    /// `clock` — available in Filled U+E038, Regular U+E037, Regular/Tiny U+E031, Filled/Tiny U+E032
     Clock,
+    /// `cloud` — available in Regular U+E039, Regular/Tiny U+E033
     Cloud,
+    /// `cloud-offline` — available in Regular U+E03A, Regular/Tiny U+E034
     CloudOffline,
+    /// `code` — available in Regular U+E03B, Regular/Tiny U+E035
     Code,
+    /// `code-of-conduct` — available in Regular U+E03C, Regular/Tiny U+E036
     CodeOfConduct,
+    /// `code-review` — available in Regular U+E03D, Regular/Tiny U+E037
     CodeReview,
+    /// `code-square` — available in Regular U+E03E, Regular/Tiny U+E038
     CodeSquare,
+    /// `codescan` — available in Regular U+E03F, Regular/Tiny U+E039
     Codescan,
+    /// `codescan-checkmark` — available in Regular U+E040, Regular/Tiny U+E03A
     CodescanCheckmark,
+    /// `codespaces` — available in Regular U+E041, Regular/Tiny U+E03B
     Codespaces,
+    /// `columns` — available in Regular U+E042, Regular/Tiny U+E03C
     Columns,
+    /// `command-palette` — available in Regular U+E043, Regular/Tiny U+E03D
     CommandPalette,
+    /// `comment` — available in Regular U+E044, Regular/Tiny U+E03E
     Comment,
+    /// `comment-ai` — available in Regular U+E045, Regular/Tiny U+E03F
     CommentAi,
+    /// `comment-discussion` — available in Regular U+E046, Regular/Tiny U+E040
     CommentDiscussion,
+    /// `compose` — available in Regular U+E047, Regular/Tiny U+E041
     Compose,
+    /// `container` — available in Regular U+E048, Regular/Tiny U+E042
     Container,
+    /// `copilot` — available in Regular U+E049, Regular/Tiny U+E043
     Copilot,
+    /// `copilot-error` — U+E044 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     CopilotError,
+    /// `copilot-warning` — U+E045 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     CopilotWarning,
+    /// `copy` — available in Regular U+E04C, Regular/Tiny U+E046
     Copy,
+    /// `cpu` — available in Regular U+E04D, Regular/Tiny U+E047
     Cpu,
+    /// `credit-card` — available in Regular U+E04E, Regular/Tiny U+E048
     CreditCard,
+    /// `cross-reference` — available in Regular U+E04F, Regular/Tiny U+E049
     CrossReference,
+    /// `crosshairs` — available in Regular U+E050, Regular/Tiny U+E04A
     Crosshairs,
+    /// `dash` — available in Regular U+E051, Regular/Tiny U+E04B
     Dash,
+    /// `database` — available in Regular U+E052, Regular/Tiny U+E04C
     Database,
+    /// `dependabot` — available in Regular U+E053, Regular/Tiny U+E04D
     Dependabot,
+    /// `desktop-download` — available in Regular U+E054, Regular/Tiny U+E04E
     DesktopDownload,
+    /// `device-camera` — available in Regular U+E055, Regular/Tiny U+E04F
     DeviceCamera,
+    /// `device-camera-video` — available in Regular U+E056, Regular/Tiny U+E050
     DeviceCameraVideo,
+    /// `device-desktop` — available in Regular U+E057, Regular/Tiny U+E051
     DeviceDesktop,
+    /// `device-mobile` — available in Regular U+E058, Regular/Tiny U+E052
     DeviceMobile,
+    /// `devices` — available in Regular U+E059, Regular/Tiny U+E053
     Devices,
+    /// `diamond` — available in Regular U+E05A, Regular/Tiny U+E054
     Diamond,
+    /// `dice` — available in Regular U+E05B, Regular/Tiny U+E055
     Dice,
+    /// `diff` — available in Regular U+E05C, Regular/Tiny U+E056
     Diff,
+    /// `diff-added` — available in Regular U+E05D, Regular/Tiny U+E057
     DiffAdded,
+    /// `diff-ignored` — available in Regular U+E05E, Regular/Tiny U+E058
     DiffIgnored,
+    /// `diff-modified` — available in Regular U+E05F, Regular/Tiny U+E059
     DiffModified,
+    /// `diff-removed` — available in Regular U+E060, Regular/Tiny U+E05A
     DiffRemoved,
+    /// `diff-renamed` — available in Regular U+E061, Regular/Tiny U+E05B
     DiffRenamed,
+    /// `discussion-closed` — available in Regular U+E062, Regular/Tiny U+E05C
     DiscussionClosed,
+    /// `discussion-duplicate` — available in Regular U+E063, Regular/Tiny U+E05D
     DiscussionDuplicate,
+    /// `discussion-outdated` — available in Regular U+E064, Regular/Tiny U+E05E
     DiscussionOutdated,
+    /// `dot` — available in Filled U+E066, Regular U+E065, Regular/Tiny U+E05F, Filled/Tiny U+E060
     Dot,
+    /// `download` — available in Regular U+E067, Regular/Tiny U+E061
     Download,
+    /// `duplicate` — available in Regular U+E068, Regular/Tiny U+E062
     Duplicate,
+    /// `ellipsis` — available in Regular U+E069, Regular/Tiny U+E063
     Ellipsis,
+    /// `exclamation` — available in Regular U+E06A, Regular/Tiny U+E064
     Exclamation,
+    /// `eye` — available in Regular U+E06B, Regular/Tiny U+E065
     Eye,
+    /// `eye-closed` — available in Regular U+E06C, Regular/Tiny U+E066
     EyeClosed,
+    /// `feed-discussion` — U+E067 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedDiscussion,
+    /// `feed-forked` — U+E068 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedForked,
+    /// `feed-heart` — U+E069 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedHeart,
+    /// `feed-issue-closed` — U+E06A (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedIssueClosed,
+    /// `feed-issue-draft` — U+E06B (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedIssueDraft,
+    /// `feed-issue-open` — U+E06C (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedIssueOpen,
+    /// `feed-issue-reopen` — U+E06D (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedIssueReopen,
+    /// `feed-merged` — U+E06E (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedMerged,
+    /// `feed-person` — U+E06F (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedPerson,
+    /// `feed-plus` — U+E070 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedPlus,
+    /// `feed-public` — U+E071 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedPublic,
+    /// `feed-pull-request-closed` — U+E072 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedPullRequestClosed,
+    /// `feed-pull-request-draft` — U+E073 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedPullRequestDraft,
+    /// `feed-pull-request-open` — U+E074 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedPullRequestOpen,
+    /// `feed-repo` — U+E075 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedRepo,
+    /// `feed-rocket` — U+E076 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedRocket,
+    /// `feed-star` — U+E077 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedStar,
+    /// `feed-tag` — U+E078 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedTag,
+    /// `feed-trophy` — U+E079 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     FeedTrophy,
+    /// `file` — available in Regular U+E06D, Regular/Tiny U+E07A
     File,
+    /// `file-added` — available in Regular U+E06E, Regular/Tiny U+E07B
     FileAdded,
+    /// `file-badge` — available in Regular U+E06F, Regular/Tiny U+E07C
     FileBadge,
+    /// `file-binary` — available in Regular U+E070, Regular/Tiny U+E07D
     FileBinary,
+    /// `file-check` — available in Regular U+E071, Regular/Tiny U+E07E
     FileCheck,
+    /// `file-code` — available in Regular U+E072, Regular/Tiny U+E07F
     FileCode,
+    /// `file-diff` — available in Regular U+E073, Regular/Tiny U+E080
     FileDiff,
+    /// `file-directory` — available in Filled U+E075, Regular U+E074, Regular/Tiny U+E081, Filled/Tiny U+E082
     FileDirectory,
+    /// `file-directory-open` — available in Filled U+E076, Filled/Tiny U+E083
     FileDirectoryOpen,
+    /// `file-directory-symlink` — available in Regular U+E077, Regular/Tiny U+E084
     FileDirectorySymlink,
+    /// `file-media` — available in Regular U+E078, Regular/Tiny U+E085
     FileMedia,
+    /// `file-moved` — available in Regular U+E079, Regular/Tiny U+E086
     FileMoved,
+    /// `file-removed` — available in Regular U+E07A, Regular/Tiny U+E087
     FileRemoved,
+    /// `file-submodule` — available in Regular U+E07B, Regular/Tiny U+E088
     FileSubmodule,
+    /// `file-symlink-file` — available in Regular U+E07C, Regular/Tiny U+E089
     FileSymlinkFile,
+    /// `file-zip` — available in Regular U+E07D, Regular/Tiny U+E08A
     FileZip,
+    /// `filter` — available in Regular U+E07E, Regular/Tiny U+E08B
     Filter,
+    /// `filter-remove` — available in Regular U+E07F, Regular/Tiny U+E08C
     FilterRemove,
+    /// `fiscal-host` — available in Regular U+E080, Regular/Tiny U+E08D
     FiscalHost,
+    /// `flame` — available in Regular U+E081, Regular/Tiny U+E08E
     Flame,
+    /// `flowchart` — available in Regular U+E082, Regular/Tiny U+E08F
     Flowchart,
+    /// `focus-center` — available in Regular U+E083, Regular/Tiny U+E090
     FocusCenter,
+    /// `fold` — available in Regular U+E084, Regular/Tiny U+E091
     Fold,
+    /// `fold-down` — available in Regular U+E085, Regular/Tiny U+E092
     FoldDown,
+    /// `fold-up` — available in Regular U+E086, Regular/Tiny U+E093
     FoldUp,
+    /// `gear` — available in Regular U+E087, Regular/Tiny U+E094
     Gear,
+    /// `gift` — available in Regular U+E088, Regular/Tiny U+E095
     Gift,
+    /// `git-branch` — available in Regular U+E089, Regular/Tiny U+E096
     GitBranch,
+    /// `git-branch-check` — available in Regular U+E08A, Regular/Tiny U+E097
     GitBranchCheck,
+    /// `git-commit` — available in Regular U+E08B, Regular/Tiny U+E098
     GitCommit,
+    /// `git-compare` — available in Regular U+E08C, Regular/Tiny U+E099
     GitCompare,
+    /// `git-merge` — available in Regular U+E08D, Regular/Tiny U+E09A
     GitMerge,
+    /// `git-merge-queue` — available in Regular U+E08E, Regular/Tiny U+E09B
     GitMergeQueue,
+    /// `git-pull-request` — available in Regular U+E08F, Regular/Tiny U+E09C
     GitPullRequest,
+    /// `git-pull-request-closed` — available in Regular U+E090, Regular/Tiny U+E09D
     GitPullRequestClosed,
+    /// `git-pull-request-draft` — available in Regular U+E091, Regular/Tiny U+E09E
     GitPullRequestDraft,
+    /// `globe` — available in Regular U+E092, Regular/Tiny U+E09F
     Globe,
+    /// `goal` — available in Regular U+E093, Regular/Tiny U+E0A0
     Goal,
+    /// `grabber` — available in Regular U+E094, Regular/Tiny U+E0A1
     Grabber,
+    /// `graph` — available in Regular U+E095, Regular/Tiny U+E0A2
     Graph,
+    /// `graph-bar-horizontal` — available in Regular U+E096, Regular/Tiny U+E0A3
     GraphBarHorizontal,
+    /// `graph-bar-vertical` — available in Regular U+E097, Regular/Tiny U+E0A4
     GraphBarVertical,
+    /// `hash` — available in Regular U+E098, Regular/Tiny U+E0A5
     Hash,
+    /// `heading` — available in Regular U+E099, Regular/Tiny U+E0A6
     Heading,
+    /// `heart` — available in Filled U+E09B, Regular U+E09A, Regular/Tiny U+E0A7, Filled/Tiny U+E0A8
     Heart,
+    /// `history` — available in Regular U+E09C, Regular/Tiny U+E0A9
     History,
+    /// `home` — available in Filled U+E09E, Regular U+E09D, Regular/Tiny U+E0AA, Filled/Tiny U+E0AB
     Home,
+    /// `horizontal-rule` — available in Regular U+E09F, Regular/Tiny U+E0AC
     HorizontalRule,
+    /// `hourglass` — available in Regular U+E0A0, Regular/Tiny U+E0AD
     Hourglass,
+    /// `hubot` — available in Regular U+E0A1, Regular/Tiny U+E0AE
     Hubot,
+    /// `id-badge` — available in Regular U+E0A2, Regular/Tiny U+E0AF
     IdBadge,
+    /// `image` — available in Regular U+E0A3, Regular/Tiny U+E0B0
     Image,
+    /// `inbox` — available in Filled U+E0A5, Regular U+E0A4, Regular/Tiny U+E0B1, Filled/Tiny U+E0B2
     Inbox,
+    /// `infinity` — available in Regular U+E0A6, Regular/Tiny U+E0B3
     Infinity,
+    /// `info` — available in Regular U+E0A7, Regular/Tiny U+E0B4
     Info,
+    /// `issue-closed` — available in Regular U+E0A8, Regular/Tiny U+E0B5
     IssueClosed,
+    /// `issue-draft` — available in Regular U+E0A9, Regular/Tiny U+E0B6
     IssueDraft,
+    /// `issue-opened` — available in Regular U+E0AA, Regular/Tiny U+E0B7
     IssueOpened,
+    /// `issue-reopened` — available in Regular U+E0AB, Regular/Tiny U+E0B8
     IssueReopened,
+    /// `issue-tracked-by` — available in Regular U+E0AC, Regular/Tiny U+E0B9
     IssueTrackedBy,
+    /// `issue-tracks` — available in Regular U+E0AD, Regular/Tiny U+E0BA
     IssueTracks,
+    /// `italic` — available in Regular U+E0AE, Regular/Tiny U+E0BB
     Italic,
+    /// `iterations` — available in Regular U+E0AF, Regular/Tiny U+E0BC
     Iterations,
+    /// `kebab-horizontal` — available in Regular U+E0B0, Regular/Tiny U+E0BD
     KebabHorizontal,
+    /// `key` — available in Regular U+E0B1, Regular/Tiny U+E0BE
     Key,
+    /// `key-asterisk` — available in Regular U+E0B2, Regular/Tiny U+E0BF
     KeyAsterisk,
+    /// `law` — available in Regular U+E0B3, Regular/Tiny U+E0C0
     Law,
+    /// `light-bulb` — available in Regular U+E0B4, Regular/Tiny U+E0C1
     LightBulb,
+    /// `link` — available in Regular U+E0B5, Regular/Tiny U+E0C2
     Link,
+    /// `link-external` — available in Regular U+E0B6, Regular/Tiny U+E0C3
     LinkExternal,
+    /// `list-ordered` — available in Regular U+E0B7, Regular/Tiny U+E0C4
     ListOrdered,
+    /// `list-unordered` — available in Regular U+E0B8, Regular/Tiny U+E0C5
     ListUnordered,
+    /// `location` — available in Regular U+E0B9, Regular/Tiny U+E0C6
     Location,
+    /// `lock` — available in Regular U+E0BA, Regular/Tiny U+E0C7
     Lock,
+    /// `log` — available in Regular U+E0BB, Regular/Tiny U+E0C8
     Log,
+    /// `logo-gist` — available in Regular U+E0BC, Regular/Tiny U+E0C9
     LogoGist,
+    /// `logo-github` — available in Regular U+E0BD, Regular/Tiny U+E0CA
     LogoGithub,
+    /// `loop` — available in Regular U+E0BE, Regular/Tiny U+E0CB
     Loop_,
+    /// `mail` — available in Regular U+E0BF, Regular/Tiny U+E0CC
     Mail,
+    /// `mark-github` — available in Regular U+E0C0, Regular/Tiny U+E0CD
     MarkGithub,
+    /// `markdown` — available in Regular U+E0C1, Regular/Tiny U+E0CE
     Markdown,
+    /// `maximize` — available in Regular U+E0C2, Regular/Tiny U+E0CF
     Maximize,
+    /// `mcp` — available in Regular U+E0C3, Regular/Tiny U+E0D0
     Mcp,
+    /// `megaphone` — available in Regular U+E0C4, Regular/Tiny U+E0D1
     Megaphone,
+    /// `mention` — available in Regular U+E0C5, Regular/Tiny U+E0D2
     Mention,
+    /// `meter` — available in Regular U+E0C6, Regular/Tiny U+E0D3
     Meter,
+    /// `milestone` — available in Regular U+E0C7, Regular/Tiny U+E0D4
     Milestone,
+    /// `minimize` — available in Regular U+E0C8, Regular/Tiny U+E0D5
     Minimize,
+    /// `mirror` — available in Regular U+E0C9, Regular/Tiny U+E0D6
     Mirror,
+    /// `moon` — available in Regular U+E0CA, Regular/Tiny U+E0D7
     Moon,
+    /// `mortar-board` — available in Regular U+E0CB, Regular/Tiny U+E0D8
     MortarBoard,
+    /// `move-to-bottom` — available in Regular U+E0CC, Regular/Tiny U+E0D9
     MoveToBottom,
+    /// `move-to-end` — available in Regular U+E0CD, Regular/Tiny U+E0DA
     MoveToEnd,
+    /// `move-to-start` — available in Regular U+E0CE, Regular/Tiny U+E0DB
     MoveToStart,
+    /// `move-to-top` — available in Regular U+E0CF, Regular/Tiny U+E0DC
     MoveToTop,
+    /// `multi-select` — available in Regular U+E0D0, Regular/Tiny U+E0DD
     MultiSelect,
+    /// `mute` — available in Regular U+E0D1, Regular/Tiny U+E0DE
     Mute,
+    /// `no-entry` — available in Regular U+E0D2, Regular/Tiny U+E0DF
     NoEntry,
+    /// `node` — available in Regular U+E0D4, Regular/Tiny U+E0E0
     Node,
+    /// `north-star` — available in Regular U+E0D5, Regular/Tiny U+E0E1
     NorthStar,
+    /// `note` — available in Regular U+E0D6, Regular/Tiny U+E0E2
     Note,
+    /// `number` — available in Regular U+E0D7, Regular/Tiny U+E0E3
     Number,
+    /// `organization` — available in Regular U+E0D8, Regular/Tiny U+E0E4
     Organization,
+    /// `package` — available in Regular U+E0D9, Regular/Tiny U+E0E5
     Package,
+    /// `package-dependencies` — available in Regular U+E0DA, Regular/Tiny U+E0E6
     PackageDependencies,
+    /// `package-dependents` — available in Regular U+E0DB, Regular/Tiny U+E0E7
     PackageDependents,
+    /// `paintbrush` — available in Regular U+E0DC, Regular/Tiny U+E0E8
     Paintbrush,
+    /// `paper-airplane` — available in Regular U+E0DD, Regular/Tiny U+E0E9
     PaperAirplane,
+    /// `paperclip` — available in Regular U+E0DE, Regular/Tiny U+E0EA
     Paperclip,
+    /// `passkey` — available in Filled U+E0DF, Filled/Tiny U+E0EB
     Passkey,
+    /// `paste` — available in Regular U+E0E0, Regular/Tiny U+E0EC
     Paste,
+    /// `pause` — available in Regular U+E0E1, Regular/Tiny U+E0ED
     Pause,
+    /// `pencil` — available in Regular U+E0E2, Regular/Tiny U+E0EE
     Pencil,
+    /// `pencil-ai` — available in Regular U+E0E3, Regular/Tiny U+E0EF
     PencilAi,
+    /// `people` — available in Regular U+E0E4, Regular/Tiny U+E0F0
     People,
+    /// `person` — available in Filled U+E0E7, Regular U+E0E5, Regular/Tiny U+E0F1, Filled/Tiny U+E0F3
     Person,
+    /// `person-add` — available in Regular U+E0E6, Regular/Tiny U+E0F2
     PersonAdd,
+    /// `pin` — available in Regular U+E0E8, Regular/Tiny U+E0F4
     Pin,
+    /// `pin-slash` — available in Regular U+E0E9, Regular/Tiny U+E0F5
     PinSlash,
+    /// `pivot-column` — available in Regular U+E0EA, Regular/Tiny U+E0F6
     PivotColumn,
+    /// `play` — available in Regular U+E0EB, Regular/Tiny U+E0F7
     Play,
+    /// `plug` — available in Regular U+E0EC, Regular/Tiny U+E0F8
     Plug,
+    /// `plus` — available in Regular U+E0ED, Regular/Tiny U+E0F9
     Plus,
+    /// `plus-circle` — available in Regular U+E0EE, Regular/Tiny U+E0FA
     PlusCircle,
+    /// `project` — available in Regular U+E0EF, Regular/Tiny U+E0FB
     Project,
+    /// `project-roadmap` — available in Regular U+E0F0, Regular/Tiny U+E0FC
     ProjectRoadmap,
+    /// `project-symlink` — available in Regular U+E0F1, Regular/Tiny U+E0FD
     ProjectSymlink,
+    /// `project-template` — available in Regular U+E0F2, Regular/Tiny U+E0FE
     ProjectTemplate,
+    /// `pulse` — available in Regular U+E0F3, Regular/Tiny U+E0FF
     Pulse,
+    /// `question` — available in Regular U+E0F4, Regular/Tiny U+E100
     Question,
+    /// `quote` — available in Regular U+E0F5, Regular/Tiny U+E101
     Quote,
+    /// `read` — available in Regular U+E0F6, Regular/Tiny U+E102
     Read,
+    /// `redo` — available in Regular U+E0F7, Regular/Tiny U+E103
     Redo,
+    /// `rel-file-path` — available in Regular U+E0F8, Regular/Tiny U+E104
     RelFilePath,
+    /// `reply` — available in Regular U+E0F9, Regular/Tiny U+E105
     Reply,
+    /// `repo` — available in Regular U+E0FA, Regular/Tiny U+E106
     Repo,
+    /// `repo-clone` — available in Regular U+E0FB, Regular/Tiny U+E107
     RepoClone,
+    /// `repo-delete` — U+E0FC (available in Regular)
     RepoDelete,
+    /// `repo-deleted` — U+E108 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     RepoDeleted,
+    /// `repo-forked` — available in Regular U+E0FD, Regular/Tiny U+E109
     RepoForked,
+    /// `repo-locked` — available in Regular U+E0FE, Regular/Tiny U+E10A
     RepoLocked,
+    /// `repo-pull` — available in Regular U+E0FF, Regular/Tiny U+E10B
     RepoPull,
+    /// `repo-push` — available in Regular U+E100, Regular/Tiny U+E10C
     RepoPush,
+    /// `repo-template` — available in Regular U+E101, Regular/Tiny U+E10D
     RepoTemplate,
+    /// `report` — available in Regular U+E102, Regular/Tiny U+E10E
     Report,
+    /// `rocket` — available in Regular U+E103, Regular/Tiny U+E10F
     Rocket,
+    /// `rows` — available in Regular U+E104, Regular/Tiny U+E110
     Rows,
+    /// `rss` — available in Regular U+E105, Regular/Tiny U+E111
     Rss,
+    /// `ruby` — available in Regular U+E106, Regular/Tiny U+E112
     Ruby,
+    /// `screen-full` — available in Regular U+E107, Regular/Tiny U+E113
     ScreenFull,
+    /// `screen-normal` — available in Regular U+E108, Regular/Tiny U+E114
     ScreenNormal,
+    /// `search` — available in Regular U+E109, Regular/Tiny U+E115
     Search,
+    /// `server` — available in Regular U+E10A, Regular/Tiny U+E116
     Server,
+    /// `share` — available in Regular U+E10B, Regular/Tiny U+E117
     Share,
+    /// `share-android` — available in Regular U+E10C, Regular/Tiny U+E118
     ShareAndroid,
+    /// `shield` — available in Regular U+E10D, Regular/Tiny U+E119
     Shield,
+    /// `shield-check` — available in Regular U+E10E, Regular/Tiny U+E11A
     ShieldCheck,
+    /// `shield-lock` — available in Regular U+E10F, Regular/Tiny U+E11B
     ShieldLock,
+    /// `shield-slash` — available in Regular U+E110, Regular/Tiny U+E11C
     ShieldSlash,
+    /// `shield-x` — available in Regular U+E111, Regular/Tiny U+E11D
     ShieldX,
+    /// `sidebar-collapse` — available in Regular U+E112, Regular/Tiny U+E11E
     SidebarCollapse,
+    /// `sidebar-expand` — available in Regular U+E113, Regular/Tiny U+E11F
     SidebarExpand,
+    /// `sign-in` — available in Regular U+E114, Regular/Tiny U+E120
     SignIn,
+    /// `sign-out` — available in Regular U+E115, Regular/Tiny U+E121
     SignOut,
+    /// `single-select` — available in Regular U+E116, Regular/Tiny U+E122
     SingleSelect,
+    /// `skip` — available in Filled U+E118, Regular U+E117, Regular/Tiny U+E123, Filled/Tiny U+E124
     Skip,
+    /// `sliders` — available in Regular U+E119, Regular/Tiny U+E125
     Sliders,
+    /// `smiley` — available in Regular U+E11A, Regular/Tiny U+E126
     Smiley,
+    /// `smiley-frown` — available in Regular U+E11B, Regular/Tiny U+E127
     SmileyFrown,
+    /// `smiley-frustrated` — available in Regular U+E11C, Regular/Tiny U+E128
     SmileyFrustrated,
+    /// `smiley-grin` — available in Regular U+E11D, Regular/Tiny U+E129
     SmileyGrin,
+    /// `smiley-neutral` — available in Regular U+E11E, Regular/Tiny U+E12A
     SmileyNeutral,
+    /// `sort-asc` — available in Regular U+E11F, Regular/Tiny U+E12B
     SortAsc,
+    /// `sort-desc` — available in Regular U+E120, Regular/Tiny U+E12C
     SortDesc,
+    /// `space` — available in Regular U+E121, Regular/Tiny U+E12D
     Space,
+    /// `spacing-large` — available in Regular U+E122, Regular/Tiny U+E12E
     SpacingLarge,
+    /// `spacing-medium` — available in Regular U+E123, Regular/Tiny U+E12F
     SpacingMedium,
+    /// `spacing-small` — available in Regular U+E124, Regular/Tiny U+E130
     SpacingSmall,
+    /// `sparkle` — available in Filled U+E126, Regular U+E125, Regular/Tiny U+E131, Filled/Tiny U+E132
     Sparkle,
+    /// `sparkles` — available in Filled U+E127, Filled/Tiny U+E133
     Sparkles,
+    /// `split-view` — available in Regular U+E128, Regular/Tiny U+E134
     SplitView,
+    /// `sponsor-tiers` — available in Regular U+E129, Regular/Tiny U+E135
     SponsorTiers,
+    /// `square` — available in Filled U+E12C, Regular U+E12A, Regular/Tiny U+E136, Filled/Tiny U+E138
     Square,
+    /// `square-circle` — available in Regular U+E12B, Regular/Tiny U+E137
     SquareCircle,
+    /// `squirrel` — available in Regular U+E12D, Regular/Tiny U+E139
     Squirrel,
+    /// `stack` — available in Regular U+E12E, Regular/Tiny U+E13A
     Stack,
+    /// `star` — available in Filled U+E130, Regular U+E12F, Regular/Tiny U+E13B, Filled/Tiny U+E13C
     Star,
+    /// `stop` — available in Regular U+E131, Regular/Tiny U+E13D
     Stop,
+    /// `stopwatch` — available in Regular U+E132, Regular/Tiny U+E13E
     Stopwatch,
+    /// `strikethrough` — available in Regular U+E133, Regular/Tiny U+E13F
     Strikethrough,
+    /// `sun` — available in Regular U+E134, Regular/Tiny U+E140
     Sun,
+    /// `sync` — available in Regular U+E135, Regular/Tiny U+E141
     Sync,
+    /// `tab` — available in Regular U+E136, Regular/Tiny U+E142
     Tab,
+    /// `tab-external` — available in Regular U+E137, Regular/Tiny U+E143
     TabExternal,
+    /// `table` — available in Regular U+E138, Regular/Tiny U+E144
     Table,
+    /// `tag` — available in Regular U+E139, Regular/Tiny U+E145
     Tag,
+    /// `tasklist` — available in Regular U+E13A, Regular/Tiny U+E146
     Tasklist,
+    /// `telescope` — available in Filled U+E13C, Regular U+E13B, Regular/Tiny U+E147, Filled/Tiny U+E148
     Telescope,
+    /// `terminal` — available in Regular U+E13D, Regular/Tiny U+E149
     Terminal,
+    /// `three-bars` — available in Regular U+E13E, Regular/Tiny U+E14A
     ThreeBars,
+    /// `thumbsdown` — available in Regular U+E13F, Regular/Tiny U+E14B
     Thumbsdown,
+    /// `thumbsup` — available in Regular U+E140, Regular/Tiny U+E14C
     Thumbsup,
+    /// `tools` — available in Regular U+E141, Regular/Tiny U+E14D
     Tools,
+    /// `tracked-by-closed-completed` — available in Regular U+E142, Regular/Tiny U+E14E
     TrackedByClosedCompleted,
+    /// `tracked-by-closed-not-planned` — available in Regular U+E143, Regular/Tiny U+E14F
     TrackedByClosedNotPlanned,
+    /// `trash` — available in Regular U+E144, Regular/Tiny U+E150
     Trash,
+    /// `triangle-down` — available in Regular U+E145, Regular/Tiny U+E151
     TriangleDown,
+    /// `triangle-left` — available in Regular U+E146, Regular/Tiny U+E152
     TriangleLeft,
+    /// `triangle-right` — available in Regular U+E147, Regular/Tiny U+E153
     TriangleRight,
+    /// `triangle-up` — available in Regular U+E148, Regular/Tiny U+E154
     TriangleUp,
+    /// `trophy` — available in Regular U+E149, Regular/Tiny U+E155
     Trophy,
+    /// `typography` — available in Regular U+E14A, Regular/Tiny U+E156
     Typography,
+    /// `undo` — available in Regular U+E14B, Regular/Tiny U+E157
     Undo,
+    /// `unfold` — available in Regular U+E14C, Regular/Tiny U+E158
     Unfold,
+    /// `unlink` — available in Regular U+E14D, Regular/Tiny U+E159
     Unlink,
+    /// `unlock` — available in Regular U+E14E, Regular/Tiny U+E15A
     Unlock,
+    /// `unmute` — available in Regular U+E14F, Regular/Tiny U+E15B
     Unmute,
+    /// `unread` — available in Regular U+E150, Regular/Tiny U+E15C
     Unread,
+    /// `unverified` — available in Regular U+E151, Regular/Tiny U+E15D
     Unverified,
+    /// `unwrap` — available in Regular U+E152, Regular/Tiny U+E15E
     Unwrap,
+    /// `upload` — available in Regular U+E153, Regular/Tiny U+E15F
     Upload,
+    /// `verified` — available in Regular U+E154, Regular/Tiny U+E160
     Verified,
+    /// `versions` — available in Regular U+E155, Regular/Tiny U+E161
     Versions,
+    /// `video` — available in Regular U+E156, Regular/Tiny U+E162
     Video,
+    /// `vscode` — U+E163 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     Vscode,
+    /// `webhook` — U+E164 (available in Regular/Tiny)
+    #[cfg(feature = "octicons-tiny")]
     Webhook,
+    /// `workflow` — available in Regular U+E159, Regular/Tiny U+E165
     Workflow,
+    /// `wrap` — available in Regular U+E15A, Regular/Tiny U+E166
     Wrap,
+    /// `x` — available in Regular U+E15C, Regular/Tiny U+E167
     X,
+    /// `x-circle` — available in Filled U+E15F, Regular U+E15D, Regular/Tiny U+E168, Filled/Tiny U+E169
     XCircle,
+    /// `zap` — available in Regular U+E160, Regular/Tiny U+E16A
     Zap,
+    /// `zoom-in` — available in Regular U+E161, Regular/Tiny U+E16B
     ZoomIn,
+    /// `zoom-out` — available in Regular U+E162, Regular/Tiny U+E16C
     ZoomOut,
 }
 
+/// An icon's available `(style, size)` pairs and its codepoint in each variant.
+type IconVariantTables = (&'static [(Style, Size)], &'static [(VariantKey, u32)]);
+
 impl Icon {
+    pub const ALL: &[Icon] = &[
+        Icon::Accessibility,
+        Icon::AccessibilityInset,
+        Icon::Agent,
+        Icon::AiModel,
+        Icon::Alert,
+        Icon::Apps,
+        Icon::Archive,
+        Icon::ArrowBoth,
+        Icon::ArrowDown,
+        Icon::ArrowDownLeft,
+        Icon::ArrowDownRight,
+        Icon::ArrowLeft,
+        Icon::ArrowRight,
+        Icon::ArrowSwitch,
+        Icon::ArrowUp,
+        Icon::ArrowUpLeft,
+        Icon::ArrowUpRight,
+        Icon::Beaker,
+        Icon::Bell,
+        Icon::BellSlash,
+        Icon::Blocked,
+        Icon::Bold,
+        Icon::Book,
+        Icon::Bookmark,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::BookmarkFilled,
+        Icon::BookmarkSlash,
+        Icon::BooleanOff,
+        Icon::BooleanOn,
+        Icon::Briefcase,
+        Icon::Broadcast,
+        Icon::Browser,
+        Icon::Bug,
+        Icon::Cache,
+        Icon::Calendar,
+        Icon::Check,
+        Icon::CheckCircle,
+        Icon::Checkbox,
+        Icon::Checklist,
+        Icon::ChevronDown,
+        Icon::ChevronLeft,
+        Icon::ChevronRight,
+        Icon::ChevronUp,
+        Icon::Circle,
+        Icon::CircleSlash,
+        Icon::Clock,
+        Icon::Cloud,
+        Icon::CloudOffline,
+        Icon::Code,
+        Icon::CodeOfConduct,
+        Icon::CodeReview,
+        Icon::CodeSquare,
+        Icon::Codescan,
+        Icon::CodescanCheckmark,
+        Icon::Codespaces,
+        Icon::Columns,
+        Icon::CommandPalette,
+        Icon::Comment,
+        Icon::CommentAi,
+        Icon::CommentDiscussion,
+        Icon::Compose,
+        Icon::Container,
+        Icon::Copilot,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::CopilotError,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::CopilotWarning,
+        Icon::Copy,
+        Icon::Cpu,
+        Icon::CreditCard,
+        Icon::CrossReference,
+        Icon::Crosshairs,
+        Icon::Dash,
+        Icon::Database,
+        Icon::Dependabot,
+        Icon::DesktopDownload,
+        Icon::DeviceCamera,
+        Icon::DeviceCameraVideo,
+        Icon::DeviceDesktop,
+        Icon::DeviceMobile,
+        Icon::Devices,
+        Icon::Diamond,
+        Icon::Dice,
+        Icon::Diff,
+        Icon::DiffAdded,
+        Icon::DiffIgnored,
+        Icon::DiffModified,
+        Icon::DiffRemoved,
+        Icon::DiffRenamed,
+        Icon::DiscussionClosed,
+        Icon::DiscussionDuplicate,
+        Icon::DiscussionOutdated,
+        Icon::Dot,
+        Icon::Download,
+        Icon::Duplicate,
+        Icon::Ellipsis,
+        Icon::Exclamation,
+        Icon::Eye,
+        Icon::EyeClosed,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedDiscussion,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedForked,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedHeart,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedIssueClosed,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedIssueDraft,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedIssueOpen,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedIssueReopen,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedMerged,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedPerson,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedPlus,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedPublic,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedPullRequestClosed,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedPullRequestDraft,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedPullRequestOpen,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedRepo,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedRocket,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedStar,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedTag,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::FeedTrophy,
+        Icon::File,
+        Icon::FileAdded,
+        Icon::FileBadge,
+        Icon::FileBinary,
+        Icon::FileCheck,
+        Icon::FileCode,
+        Icon::FileDiff,
+        Icon::FileDirectory,
+        Icon::FileDirectoryOpen,
+        Icon::FileDirectorySymlink,
+        Icon::FileMedia,
+        Icon::FileMoved,
+        Icon::FileRemoved,
+        Icon::FileSubmodule,
+        Icon::FileSymlinkFile,
+        Icon::FileZip,
+        Icon::Filter,
+        Icon::FilterRemove,
+        Icon::FiscalHost,
+        Icon::Flame,
+        Icon::Flowchart,
+        Icon::FocusCenter,
+        Icon::Fold,
+        Icon::FoldDown,
+        Icon::FoldUp,
+        Icon::Gear,
+        Icon::Gift,
+        Icon::GitBranch,
+        Icon::GitBranchCheck,
+        Icon::GitCommit,
+        Icon::GitCompare,
+        Icon::GitMerge,
+        Icon::GitMergeQueue,
+        Icon::GitPullRequest,
+        Icon::GitPullRequestClosed,
+        Icon::GitPullRequestDraft,
+        Icon::Globe,
+        Icon::Goal,
+        Icon::Grabber,
+        Icon::Graph,
+        Icon::GraphBarHorizontal,
+        Icon::GraphBarVertical,
+        Icon::Hash,
+        Icon::Heading,
+        Icon::Heart,
+        Icon::History,
+        Icon::Home,
+        Icon::HorizontalRule,
+        Icon::Hourglass,
+        Icon::Hubot,
+        Icon::IdBadge,
+        Icon::Image,
+        Icon::Inbox,
+        Icon::Infinity,
+        Icon::Info,
+        Icon::IssueClosed,
+        Icon::IssueDraft,
+        Icon::IssueOpened,
+        Icon::IssueReopened,
+        Icon::IssueTrackedBy,
+        Icon::IssueTracks,
+        Icon::Italic,
+        Icon::Iterations,
+        Icon::KebabHorizontal,
+        Icon::Key,
+        Icon::KeyAsterisk,
+        Icon::Law,
+        Icon::LightBulb,
+        Icon::Link,
+        Icon::LinkExternal,
+        Icon::ListOrdered,
+        Icon::ListUnordered,
+        Icon::Location,
+        Icon::Lock,
+        Icon::Log,
+        Icon::LogoGist,
+        Icon::LogoGithub,
+        Icon::Loop_,
+        Icon::Mail,
+        Icon::MarkGithub,
+        Icon::Markdown,
+        Icon::Maximize,
+        Icon::Mcp,
+        Icon::Megaphone,
+        Icon::Mention,
+        Icon::Meter,
+        Icon::Milestone,
+        Icon::Minimize,
+        Icon::Mirror,
+        Icon::Moon,
+        Icon::MortarBoard,
+        Icon::MoveToBottom,
+        Icon::MoveToEnd,
+        Icon::MoveToStart,
+        Icon::MoveToTop,
+        Icon::MultiSelect,
+        Icon::Mute,
+        Icon::NoEntry,
+        Icon::Node,
+        Icon::NorthStar,
+        Icon::Note,
+        Icon::Number,
+        Icon::Organization,
+        Icon::Package,
+        Icon::PackageDependencies,
+        Icon::PackageDependents,
+        Icon::Paintbrush,
+        Icon::PaperAirplane,
+        Icon::Paperclip,
+        Icon::Passkey,
+        Icon::Paste,
+        Icon::Pause,
+        Icon::Pencil,
+        Icon::PencilAi,
+        Icon::People,
+        Icon::Person,
+        Icon::PersonAdd,
+        Icon::Pin,
+        Icon::PinSlash,
+        Icon::PivotColumn,
+        Icon::Play,
+        Icon::Plug,
+        Icon::Plus,
+        Icon::PlusCircle,
+        Icon::Project,
+        Icon::ProjectRoadmap,
+        Icon::ProjectSymlink,
+        Icon::ProjectTemplate,
+        Icon::Pulse,
+        Icon::Question,
+        Icon::Quote,
+        Icon::Read,
+        Icon::Redo,
+        Icon::RelFilePath,
+        Icon::Reply,
+        Icon::Repo,
+        Icon::RepoClone,
+        Icon::RepoDelete,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::RepoDeleted,
+        Icon::RepoForked,
+        Icon::RepoLocked,
+        Icon::RepoPull,
+        Icon::RepoPush,
+        Icon::RepoTemplate,
+        Icon::Report,
+        Icon::Rocket,
+        Icon::Rows,
+        Icon::Rss,
+        Icon::Ruby,
+        Icon::ScreenFull,
+        Icon::ScreenNormal,
+        Icon::Search,
+        Icon::Server,
+        Icon::Share,
+        Icon::ShareAndroid,
+        Icon::Shield,
+        Icon::ShieldCheck,
+        Icon::ShieldLock,
+        Icon::ShieldSlash,
+        Icon::ShieldX,
+        Icon::SidebarCollapse,
+        Icon::SidebarExpand,
+        Icon::SignIn,
+        Icon::SignOut,
+        Icon::SingleSelect,
+        Icon::Skip,
+        Icon::Sliders,
+        Icon::Smiley,
+        Icon::SmileyFrown,
+        Icon::SmileyFrustrated,
+        Icon::SmileyGrin,
+        Icon::SmileyNeutral,
+        Icon::SortAsc,
+        Icon::SortDesc,
+        Icon::Space,
+        Icon::SpacingLarge,
+        Icon::SpacingMedium,
+        Icon::SpacingSmall,
+        Icon::Sparkle,
+        Icon::Sparkles,
+        Icon::SplitView,
+        Icon::SponsorTiers,
+        Icon::Square,
+        Icon::SquareCircle,
+        Icon::Squirrel,
+        Icon::Stack,
+        Icon::Star,
+        Icon::Stop,
+        Icon::Stopwatch,
+        Icon::Strikethrough,
+        Icon::Sun,
+        Icon::Sync,
+        Icon::Tab,
+        Icon::TabExternal,
+        Icon::Table,
+        Icon::Tag,
+        Icon::Tasklist,
+        Icon::Telescope,
+        Icon::Terminal,
+        Icon::ThreeBars,
+        Icon::Thumbsdown,
+        Icon::Thumbsup,
+        Icon::Tools,
+        Icon::TrackedByClosedCompleted,
+        Icon::TrackedByClosedNotPlanned,
+        Icon::Trash,
+        Icon::TriangleDown,
+        Icon::TriangleLeft,
+        Icon::TriangleRight,
+        Icon::TriangleUp,
+        Icon::Trophy,
+        Icon::Typography,
+        Icon::Undo,
+        Icon::Unfold,
+        Icon::Unlink,
+        Icon::Unlock,
+        Icon::Unmute,
+        Icon::Unread,
+        Icon::Unverified,
+        Icon::Unwrap,
+        Icon::Upload,
+        Icon::Verified,
+        Icon::Versions,
+        Icon::Video,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::Vscode,
+        #[cfg(feature = "octicons-tiny")]
+        Icon::Webhook,
+        Icon::Workflow,
+        Icon::Wrap,
+        Icon::X,
+        Icon::XCircle,
+        Icon::Zap,
+        Icon::ZoomIn,
+        Icon::ZoomOut,
+    ];
+
     pub fn name(self) -> &'static str {
         match self {
             Icon::Accessibility => "accessibility",
@@ -437,6 +1243,7 @@ impl Icon {
             Icon::Bold => "bold",
             Icon::Book => "book",
             Icon::Bookmark => "bookmark",
+            #[cfg(feature = "octicons-tiny")]
             Icon::BookmarkFilled => "bookmark-filled",
             Icon::BookmarkSlash => "bookmark-slash",
             Icon::BooleanOff => "boolean-off",
@@ -475,7 +1282,9 @@ impl Icon {
             Icon::Compose => "compose",
             Icon::Container => "container",
             Icon::Copilot => "copilot",
+            #[cfg(feature = "octicons-tiny")]
             Icon::CopilotError => "copilot-error",
+            #[cfg(feature = "octicons-tiny")]
             Icon::CopilotWarning => "copilot-warning",
             Icon::Copy => "copy",
             Icon::Cpu => "cpu",
@@ -509,24 +1318,43 @@ impl Icon {
             Icon::Exclamation => "exclamation",
             Icon::Eye => "eye",
             Icon::EyeClosed => "eye-closed",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedDiscussion => "feed-discussion",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedForked => "feed-forked",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedHeart => "feed-heart",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedIssueClosed => "feed-issue-closed",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedIssueDraft => "feed-issue-draft",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedIssueOpen => "feed-issue-open",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedIssueReopen => "feed-issue-reopen",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedMerged => "feed-merged",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedPerson => "feed-person",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedPlus => "feed-plus",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedPublic => "feed-public",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedPullRequestClosed => "feed-pull-request-closed",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedPullRequestDraft => "feed-pull-request-draft",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedPullRequestOpen => "feed-pull-request-open",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedRepo => "feed-repo",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedRocket => "feed-rocket",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedStar => "feed-star",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedTag => "feed-tag",
+            #[cfg(feature = "octicons-tiny")]
             Icon::FeedTrophy => "feed-trophy",
             Icon::File => "file",
             Icon::FileAdded => "file-added",
@@ -666,6 +1494,7 @@ impl Icon {
             Icon::Repo => "repo",
             Icon::RepoClone => "repo-clone",
             Icon::RepoDelete => "repo-delete",
+            #[cfg(feature = "octicons-tiny")]
             Icon::RepoDeleted => "repo-deleted",
             Icon::RepoForked => "repo-forked",
             Icon::RepoLocked => "repo-locked",
@@ -752,7 +1581,9 @@ impl Icon {
             Icon::Verified => "verified",
             Icon::Versions => "versions",
             Icon::Video => "video",
+            #[cfg(feature = "octicons-tiny")]
             Icon::Vscode => "vscode",
+            #[cfg(feature = "octicons-tiny")]
             Icon::Webhook => "webhook",
             Icon::Workflow => "workflow",
             Icon::Wrap => "wrap",
@@ -764,31 +1595,643 @@ impl Icon {
         }
     }
 
+    /// Resolves this icon at a `(style, size)` known to exist, e.g. one listed in its docs.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the variant is unavailable; use [`Icon::try_icon`] to handle that case.
     pub fn icon(self, style: Style, size: Size) -> IconRef {
-        let name = self.name();
-        let available = icon_available(name).unwrap_or(&[]);
-        if !available.contains(&(style, size)) {
-            panic!(
-                "Icon '{}' is not available in {:?}/{:?}. Available: {:?}",
-                name, style, size, available
-            );
+        match self.try_icon(style, size) {
+            Ok(icon) => icon,
+            Err(err) => panic!(
+                "Icon '{}' is not available in {:?}/{:?}: {:?}",
+                self.name(),
+                style,
+                size,
+                err
+            ),
         }
-        let variant = variant_info(style, size).unwrap_or_else(|| {
-            panic!(
-                "Variant {:?}/{:?} is not available for pack {}",
-                style, size, PACK_ID
-            )
-        });
-        let codepoint = icon_codepoint(name, variant.key).unwrap_or_else(|| {
-            panic!("Icon '{}' is not available in {:?}/{:?}", name, style, size)
-        });
-        IconRef {
-            family: variant.family,
-            codepoint,
+    }
+
+    /// Resolves this icon at `(style, size)` without a name lookup, returning the same errors
+    /// as [`crate::try_icon`].
+    pub fn try_icon(self, style: Style, size: Size) -> Result<IconRef, crate::core::IconError> {
+        let (available, codepoints) = self.variant_tables();
+        let key = VariantKey { style, size };
+        crate::core::resolve_icon(
+            PACK_ID,
+            self.name(),
+            style,
+            size,
+            Some(available),
+            variant_info(style, size).map(|info| info.family),
+            codepoints
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|&(_, cp)| cp),
+        )
+        .map_err(crate::core::IconError::from)
+    }
+
+    fn variant_tables(self) -> IconVariantTables {
+        match self {
+            Icon::Accessibility => (ICON_ACCESSIBILITY_AVAILABLE, ICON_ACCESSIBILITY_CODEPOINTS),
+            Icon::AccessibilityInset => (
+                ICON_ACCESSIBILITY_INSET_AVAILABLE,
+                ICON_ACCESSIBILITY_INSET_CODEPOINTS,
+            ),
+            Icon::Agent => (ICON_AGENT_AVAILABLE, ICON_AGENT_CODEPOINTS),
+            Icon::AiModel => (ICON_AI_MODEL_AVAILABLE, ICON_AI_MODEL_CODEPOINTS),
+            Icon::Alert => (ICON_ALERT_AVAILABLE, ICON_ALERT_CODEPOINTS),
+            Icon::Apps => (ICON_APPS_AVAILABLE, ICON_APPS_CODEPOINTS),
+            Icon::Archive => (ICON_ARCHIVE_AVAILABLE, ICON_ARCHIVE_CODEPOINTS),
+            Icon::ArrowBoth => (ICON_ARROW_BOTH_AVAILABLE, ICON_ARROW_BOTH_CODEPOINTS),
+            Icon::ArrowDown => (ICON_ARROW_DOWN_AVAILABLE, ICON_ARROW_DOWN_CODEPOINTS),
+            Icon::ArrowDownLeft => (
+                ICON_ARROW_DOWN_LEFT_AVAILABLE,
+                ICON_ARROW_DOWN_LEFT_CODEPOINTS,
+            ),
+            Icon::ArrowDownRight => (
+                ICON_ARROW_DOWN_RIGHT_AVAILABLE,
+                ICON_ARROW_DOWN_RIGHT_CODEPOINTS,
+            ),
+            Icon::ArrowLeft => (ICON_ARROW_LEFT_AVAILABLE, ICON_ARROW_LEFT_CODEPOINTS),
+            Icon::ArrowRight => (ICON_ARROW_RIGHT_AVAILABLE, ICON_ARROW_RIGHT_CODEPOINTS),
+            Icon::ArrowSwitch => (ICON_ARROW_SWITCH_AVAILABLE, ICON_ARROW_SWITCH_CODEPOINTS),
+            Icon::ArrowUp => (ICON_ARROW_UP_AVAILABLE, ICON_ARROW_UP_CODEPOINTS),
+            Icon::ArrowUpLeft => (ICON_ARROW_UP_LEFT_AVAILABLE, ICON_ARROW_UP_LEFT_CODEPOINTS),
+            Icon::ArrowUpRight => (
+                ICON_ARROW_UP_RIGHT_AVAILABLE,
+                ICON_ARROW_UP_RIGHT_CODEPOINTS,
+            ),
+            Icon::Beaker => (ICON_BEAKER_AVAILABLE, ICON_BEAKER_CODEPOINTS),
+            Icon::Bell => (ICON_BELL_AVAILABLE, ICON_BELL_CODEPOINTS),
+            Icon::BellSlash => (ICON_BELL_SLASH_AVAILABLE, ICON_BELL_SLASH_CODEPOINTS),
+            Icon::Blocked => (ICON_BLOCKED_AVAILABLE, ICON_BLOCKED_CODEPOINTS),
+            Icon::Bold => (ICON_BOLD_AVAILABLE, ICON_BOLD_CODEPOINTS),
+            Icon::Book => (ICON_BOOK_AVAILABLE, ICON_BOOK_CODEPOINTS),
+            Icon::Bookmark => (ICON_BOOKMARK_AVAILABLE, ICON_BOOKMARK_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::BookmarkFilled => (
+                ICON_BOOKMARK_FILLED_AVAILABLE,
+                ICON_BOOKMARK_FILLED_CODEPOINTS,
+            ),
+            Icon::BookmarkSlash => (
+                ICON_BOOKMARK_SLASH_AVAILABLE,
+                ICON_BOOKMARK_SLASH_CODEPOINTS,
+            ),
+            Icon::BooleanOff => (ICON_BOOLEAN_OFF_AVAILABLE, ICON_BOOLEAN_OFF_CODEPOINTS),
+            Icon::BooleanOn => (ICON_BOOLEAN_ON_AVAILABLE, ICON_BOOLEAN_ON_CODEPOINTS),
+            Icon::Briefcase => (ICON_BRIEFCASE_AVAILABLE, ICON_BRIEFCASE_CODEPOINTS),
+            Icon::Broadcast => (ICON_BROADCAST_AVAILABLE, ICON_BROADCAST_CODEPOINTS),
+            Icon::Browser => (ICON_BROWSER_AVAILABLE, ICON_BROWSER_CODEPOINTS),
+            Icon::Bug => (ICON_BUG_AVAILABLE, ICON_BUG_CODEPOINTS),
+            Icon::Cache => (ICON_CACHE_AVAILABLE, ICON_CACHE_CODEPOINTS),
+            Icon::Calendar => (ICON_CALENDAR_AVAILABLE, ICON_CALENDAR_CODEPOINTS),
+            Icon::Check => (ICON_CHECK_AVAILABLE, ICON_CHECK_CODEPOINTS),
+            Icon::CheckCircle => (ICON_CHECK_CIRCLE_AVAILABLE, ICON_CHECK_CIRCLE_CODEPOINTS),
+            Icon::Checkbox => (ICON_CHECKBOX_AVAILABLE, ICON_CHECKBOX_CODEPOINTS),
+            Icon::Checklist => (ICON_CHECKLIST_AVAILABLE, ICON_CHECKLIST_CODEPOINTS),
+            Icon::ChevronDown => (ICON_CHEVRON_DOWN_AVAILABLE, ICON_CHEVRON_DOWN_CODEPOINTS),
+            Icon::ChevronLeft => (ICON_CHEVRON_LEFT_AVAILABLE, ICON_CHEVRON_LEFT_CODEPOINTS),
+            Icon::ChevronRight => (ICON_CHEVRON_RIGHT_AVAILABLE, ICON_CHEVRON_RIGHT_CODEPOINTS),
+            Icon::ChevronUp => (ICON_CHEVRON_UP_AVAILABLE, ICON_CHEVRON_UP_CODEPOINTS),
+            Icon::Circle => (ICON_CIRCLE_AVAILABLE, ICON_CIRCLE_CODEPOINTS),
+            Icon::CircleSlash => (ICON_CIRCLE_SLASH_AVAILABLE, ICON_CIRCLE_SLASH_CODEPOINTS),
+            Icon::Clock => (ICON_CLOCK_AVAILABLE, ICON_CLOCK_CODEPOINTS),
+            Icon::Cloud => (ICON_CLOUD_AVAILABLE, ICON_CLOUD_CODEPOINTS),
+            Icon::CloudOffline => (ICON_CLOUD_OFFLINE_AVAILABLE, ICON_CLOUD_OFFLINE_CODEPOINTS),
+            Icon::Code => (ICON_CODE_AVAILABLE, ICON_CODE_CODEPOINTS),
+            Icon::CodeOfConduct => (
+                ICON_CODE_OF_CONDUCT_AVAILABLE,
+                ICON_CODE_OF_CONDUCT_CODEPOINTS,
+            ),
+            Icon::CodeReview => (ICON_CODE_REVIEW_AVAILABLE, ICON_CODE_REVIEW_CODEPOINTS),
+            Icon::CodeSquare => (ICON_CODE_SQUARE_AVAILABLE, ICON_CODE_SQUARE_CODEPOINTS),
+            Icon::Codescan => (ICON_CODESCAN_AVAILABLE, ICON_CODESCAN_CODEPOINTS),
+            Icon::CodescanCheckmark => (
+                ICON_CODESCAN_CHECKMARK_AVAILABLE,
+                ICON_CODESCAN_CHECKMARK_CODEPOINTS,
+            ),
+            Icon::Codespaces => (ICON_CODESPACES_AVAILABLE, ICON_CODESPACES_CODEPOINTS),
+            Icon::Columns => (ICON_COLUMNS_AVAILABLE, ICON_COLUMNS_CODEPOINTS),
+            Icon::CommandPalette => (
+                ICON_COMMAND_PALETTE_AVAILABLE,
+                ICON_COMMAND_PALETTE_CODEPOINTS,
+            ),
+            Icon::Comment => (ICON_COMMENT_AVAILABLE, ICON_COMMENT_CODEPOINTS),
+            Icon::CommentAi => (ICON_COMMENT_AI_AVAILABLE, ICON_COMMENT_AI_CODEPOINTS),
+            Icon::CommentDiscussion => (
+                ICON_COMMENT_DISCUSSION_AVAILABLE,
+                ICON_COMMENT_DISCUSSION_CODEPOINTS,
+            ),
+            Icon::Compose => (ICON_COMPOSE_AVAILABLE, ICON_COMPOSE_CODEPOINTS),
+            Icon::Container => (ICON_CONTAINER_AVAILABLE, ICON_CONTAINER_CODEPOINTS),
+            Icon::Copilot => (ICON_COPILOT_AVAILABLE, ICON_COPILOT_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::CopilotError => (ICON_COPILOT_ERROR_AVAILABLE, ICON_COPILOT_ERROR_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::CopilotWarning => (
+                ICON_COPILOT_WARNING_AVAILABLE,
+                ICON_COPILOT_WARNING_CODEPOINTS,
+            ),
+            Icon::Copy => (ICON_COPY_AVAILABLE, ICON_COPY_CODEPOINTS),
+            Icon::Cpu => (ICON_CPU_AVAILABLE, ICON_CPU_CODEPOINTS),
+            Icon::CreditCard => (ICON_CREDIT_CARD_AVAILABLE, ICON_CREDIT_CARD_CODEPOINTS),
+            Icon::CrossReference => (
+                ICON_CROSS_REFERENCE_AVAILABLE,
+                ICON_CROSS_REFERENCE_CODEPOINTS,
+            ),
+            Icon::Crosshairs => (ICON_CROSSHAIRS_AVAILABLE, ICON_CROSSHAIRS_CODEPOINTS),
+            Icon::Dash => (ICON_DASH_AVAILABLE, ICON_DASH_CODEPOINTS),
+            Icon::Database => (ICON_DATABASE_AVAILABLE, ICON_DATABASE_CODEPOINTS),
+            Icon::Dependabot => (ICON_DEPENDABOT_AVAILABLE, ICON_DEPENDABOT_CODEPOINTS),
+            Icon::DesktopDownload => (
+                ICON_DESKTOP_DOWNLOAD_AVAILABLE,
+                ICON_DESKTOP_DOWNLOAD_CODEPOINTS,
+            ),
+            Icon::DeviceCamera => (ICON_DEVICE_CAMERA_AVAILABLE, ICON_DEVICE_CAMERA_CODEPOINTS),
+            Icon::DeviceCameraVideo => (
+                ICON_DEVICE_CAMERA_VIDEO_AVAILABLE,
+                ICON_DEVICE_CAMERA_VIDEO_CODEPOINTS,
+            ),
+            Icon::DeviceDesktop => (
+                ICON_DEVICE_DESKTOP_AVAILABLE,
+                ICON_DEVICE_DESKTOP_CODEPOINTS,
+            ),
+            Icon::DeviceMobile => (ICON_DEVICE_MOBILE_AVAILABLE, ICON_DEVICE_MOBILE_CODEPOINTS),
+            Icon::Devices => (ICON_DEVICES_AVAILABLE, ICON_DEVICES_CODEPOINTS),
+            Icon::Diamond => (ICON_DIAMOND_AVAILABLE, ICON_DIAMOND_CODEPOINTS),
+            Icon::Dice => (ICON_DICE_AVAILABLE, ICON_DICE_CODEPOINTS),
+            Icon::Diff => (ICON_DIFF_AVAILABLE, ICON_DIFF_CODEPOINTS),
+            Icon::DiffAdded => (ICON_DIFF_ADDED_AVAILABLE, ICON_DIFF_ADDED_CODEPOINTS),
+            Icon::DiffIgnored => (ICON_DIFF_IGNORED_AVAILABLE, ICON_DIFF_IGNORED_CODEPOINTS),
+            Icon::DiffModified => (ICON_DIFF_MODIFIED_AVAILABLE, ICON_DIFF_MODIFIED_CODEPOINTS),
+            Icon::DiffRemoved => (ICON_DIFF_REMOVED_AVAILABLE, ICON_DIFF_REMOVED_CODEPOINTS),
+            Icon::DiffRenamed => (ICON_DIFF_RENAMED_AVAILABLE, ICON_DIFF_RENAMED_CODEPOINTS),
+            Icon::DiscussionClosed => (
+                ICON_DISCUSSION_CLOSED_AVAILABLE,
+                ICON_DISCUSSION_CLOSED_CODEPOINTS,
+            ),
+            Icon::DiscussionDuplicate => (
+                ICON_DISCUSSION_DUPLICATE_AVAILABLE,
+                ICON_DISCUSSION_DUPLICATE_CODEPOINTS,
+            ),
+            Icon::DiscussionOutdated => (
+                ICON_DISCUSSION_OUTDATED_AVAILABLE,
+                ICON_DISCUSSION_OUTDATED_CODEPOINTS,
+            ),
+            Icon::Dot => (ICON_DOT_AVAILABLE, ICON_DOT_CODEPOINTS),
+            Icon::Download => (ICON_DOWNLOAD_AVAILABLE, ICON_DOWNLOAD_CODEPOINTS),
+            Icon::Duplicate => (ICON_DUPLICATE_AVAILABLE, ICON_DUPLICATE_CODEPOINTS),
+            Icon::Ellipsis => (ICON_ELLIPSIS_AVAILABLE, ICON_ELLIPSIS_CODEPOINTS),
+            Icon::Exclamation => (ICON_EXCLAMATION_AVAILABLE, ICON_EXCLAMATION_CODEPOINTS),
+            Icon::Eye => (ICON_EYE_AVAILABLE, ICON_EYE_CODEPOINTS),
+            Icon::EyeClosed => (ICON_EYE_CLOSED_AVAILABLE, ICON_EYE_CLOSED_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedDiscussion => (
+                ICON_FEED_DISCUSSION_AVAILABLE,
+                ICON_FEED_DISCUSSION_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedForked => (ICON_FEED_FORKED_AVAILABLE, ICON_FEED_FORKED_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedHeart => (ICON_FEED_HEART_AVAILABLE, ICON_FEED_HEART_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedIssueClosed => (
+                ICON_FEED_ISSUE_CLOSED_AVAILABLE,
+                ICON_FEED_ISSUE_CLOSED_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedIssueDraft => (
+                ICON_FEED_ISSUE_DRAFT_AVAILABLE,
+                ICON_FEED_ISSUE_DRAFT_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedIssueOpen => (
+                ICON_FEED_ISSUE_OPEN_AVAILABLE,
+                ICON_FEED_ISSUE_OPEN_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedIssueReopen => (
+                ICON_FEED_ISSUE_REOPEN_AVAILABLE,
+                ICON_FEED_ISSUE_REOPEN_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedMerged => (ICON_FEED_MERGED_AVAILABLE, ICON_FEED_MERGED_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedPerson => (ICON_FEED_PERSON_AVAILABLE, ICON_FEED_PERSON_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedPlus => (ICON_FEED_PLUS_AVAILABLE, ICON_FEED_PLUS_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedPublic => (ICON_FEED_PUBLIC_AVAILABLE, ICON_FEED_PUBLIC_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedPullRequestClosed => (
+                ICON_FEED_PULL_REQUEST_CLOSED_AVAILABLE,
+                ICON_FEED_PULL_REQUEST_CLOSED_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedPullRequestDraft => (
+                ICON_FEED_PULL_REQUEST_DRAFT_AVAILABLE,
+                ICON_FEED_PULL_REQUEST_DRAFT_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedPullRequestOpen => (
+                ICON_FEED_PULL_REQUEST_OPEN_AVAILABLE,
+                ICON_FEED_PULL_REQUEST_OPEN_CODEPOINTS,
+            ),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedRepo => (ICON_FEED_REPO_AVAILABLE, ICON_FEED_REPO_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedRocket => (ICON_FEED_ROCKET_AVAILABLE, ICON_FEED_ROCKET_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedStar => (ICON_FEED_STAR_AVAILABLE, ICON_FEED_STAR_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedTag => (ICON_FEED_TAG_AVAILABLE, ICON_FEED_TAG_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::FeedTrophy => (ICON_FEED_TROPHY_AVAILABLE, ICON_FEED_TROPHY_CODEPOINTS),
+            Icon::File => (ICON_FILE_AVAILABLE, ICON_FILE_CODEPOINTS),
+            Icon::FileAdded => (ICON_FILE_ADDED_AVAILABLE, ICON_FILE_ADDED_CODEPOINTS),
+            Icon::FileBadge => (ICON_FILE_BADGE_AVAILABLE, ICON_FILE_BADGE_CODEPOINTS),
+            Icon::FileBinary => (ICON_FILE_BINARY_AVAILABLE, ICON_FILE_BINARY_CODEPOINTS),
+            Icon::FileCheck => (ICON_FILE_CHECK_AVAILABLE, ICON_FILE_CHECK_CODEPOINTS),
+            Icon::FileCode => (ICON_FILE_CODE_AVAILABLE, ICON_FILE_CODE_CODEPOINTS),
+            Icon::FileDiff => (ICON_FILE_DIFF_AVAILABLE, ICON_FILE_DIFF_CODEPOINTS),
+            Icon::FileDirectory => (
+                ICON_FILE_DIRECTORY_AVAILABLE,
+                ICON_FILE_DIRECTORY_CODEPOINTS,
+            ),
+            Icon::FileDirectoryOpen => (
+                ICON_FILE_DIRECTORY_OPEN_AVAILABLE,
+                ICON_FILE_DIRECTORY_OPEN_CODEPOINTS,
+            ),
+            Icon::FileDirectorySymlink => (
+                ICON_FILE_DIRECTORY_SYMLINK_AVAILABLE,
+                ICON_FILE_DIRECTORY_SYMLINK_CODEPOINTS,
+            ),
+            Icon::FileMedia => (ICON_FILE_MEDIA_AVAILABLE, ICON_FILE_MEDIA_CODEPOINTS),
+            Icon::FileMoved => (ICON_FILE_MOVED_AVAILABLE, ICON_FILE_MOVED_CODEPOINTS),
+            Icon::FileRemoved => (ICON_FILE_REMOVED_AVAILABLE, ICON_FILE_REMOVED_CODEPOINTS),
+            Icon::FileSubmodule => (
+                ICON_FILE_SUBMODULE_AVAILABLE,
+                ICON_FILE_SUBMODULE_CODEPOINTS,
+            ),
+            Icon::FileSymlinkFile => (
+                ICON_FILE_SYMLINK_FILE_AVAILABLE,
+                ICON_FILE_SYMLINK_FILE_CODEPOINTS,
+            ),
+            Icon::FileZip => (ICON_FILE_ZIP_AVAILABLE, ICON_FILE_ZIP_CODEPOINTS),
+            Icon::Filter => (ICON_FILTER_AVAILABLE, ICON_FILTER_CODEPOINTS),
+            Icon::FilterRemove => (ICON_FILTER_REMOVE_AVAILABLE, ICON_FILTER_REMOVE_CODEPOINTS),
+            Icon::FiscalHost => (ICON_FISCAL_HOST_AVAILABLE, ICON_FISCAL_HOST_CODEPOINTS),
+            Icon::Flame => (ICON_FLAME_AVAILABLE, ICON_FLAME_CODEPOINTS),
+            Icon::Flowchart => (ICON_FLOWCHART_AVAILABLE, ICON_FLOWCHART_CODEPOINTS),
+            Icon::FocusCenter => (ICON_FOCUS_CENTER_AVAILABLE, ICON_FOCUS_CENTER_CODEPOINTS),
+            Icon::Fold => (ICON_FOLD_AVAILABLE, ICON_FOLD_CODEPOINTS),
+            Icon::FoldDown => (ICON_FOLD_DOWN_AVAILABLE, ICON_FOLD_DOWN_CODEPOINTS),
+            Icon::FoldUp => (ICON_FOLD_UP_AVAILABLE, ICON_FOLD_UP_CODEPOINTS),
+            Icon::Gear => (ICON_GEAR_AVAILABLE, ICON_GEAR_CODEPOINTS),
+            Icon::Gift => (ICON_GIFT_AVAILABLE, ICON_GIFT_CODEPOINTS),
+            Icon::GitBranch => (ICON_GIT_BRANCH_AVAILABLE, ICON_GIT_BRANCH_CODEPOINTS),
+            Icon::GitBranchCheck => (
+                ICON_GIT_BRANCH_CHECK_AVAILABLE,
+                ICON_GIT_BRANCH_CHECK_CODEPOINTS,
+            ),
+            Icon::GitCommit => (ICON_GIT_COMMIT_AVAILABLE, ICON_GIT_COMMIT_CODEPOINTS),
+            Icon::GitCompare => (ICON_GIT_COMPARE_AVAILABLE, ICON_GIT_COMPARE_CODEPOINTS),
+            Icon::GitMerge => (ICON_GIT_MERGE_AVAILABLE, ICON_GIT_MERGE_CODEPOINTS),
+            Icon::GitMergeQueue => (
+                ICON_GIT_MERGE_QUEUE_AVAILABLE,
+                ICON_GIT_MERGE_QUEUE_CODEPOINTS,
+            ),
+            Icon::GitPullRequest => (
+                ICON_GIT_PULL_REQUEST_AVAILABLE,
+                ICON_GIT_PULL_REQUEST_CODEPOINTS,
+            ),
+            Icon::GitPullRequestClosed => (
+                ICON_GIT_PULL_REQUEST_CLOSED_AVAILABLE,
+                ICON_GIT_PULL_REQUEST_CLOSED_CODEPOINTS,
+            ),
+            Icon::GitPullRequestDraft => (
+                ICON_GIT_PULL_REQUEST_DRAFT_AVAILABLE,
+                ICON_GIT_PULL_REQUEST_DRAFT_CODEPOINTS,
+            ),
+            Icon::Globe => (ICON_GLOBE_AVAILABLE, ICON_GLOBE_CODEPOINTS),
+            Icon::Goal => (ICON_GOAL_AVAILABLE, ICON_GOAL_CODEPOINTS),
+            Icon::Grabber => (ICON_GRABBER_AVAILABLE, ICON_GRABBER_CODEPOINTS),
+            Icon::Graph => (ICON_GRAPH_AVAILABLE, ICON_GRAPH_CODEPOINTS),
+            Icon::GraphBarHorizontal => (
+                ICON_GRAPH_BAR_HORIZONTAL_AVAILABLE,
+                ICON_GRAPH_BAR_HORIZONTAL_CODEPOINTS,
+            ),
+            Icon::GraphBarVertical => (
+                ICON_GRAPH_BAR_VERTICAL_AVAILABLE,
+                ICON_GRAPH_BAR_VERTICAL_CODEPOINTS,
+            ),
+            Icon::Hash => (ICON_HASH_AVAILABLE, ICON_HASH_CODEPOINTS),
+            Icon::Heading => (ICON_HEADING_AVAILABLE, ICON_HEADING_CODEPOINTS),
+            Icon::Heart => (ICON_HEART_AVAILABLE, ICON_HEART_CODEPOINTS),
+            Icon::History => (ICON_HISTORY_AVAILABLE, ICON_HISTORY_CODEPOINTS),
+            Icon::Home => (ICON_HOME_AVAILABLE, ICON_HOME_CODEPOINTS),
+            Icon::HorizontalRule => (
+                ICON_HORIZONTAL_RULE_AVAILABLE,
+                ICON_HORIZONTAL_RULE_CODEPOINTS,
+            ),
+            Icon::Hourglass => (ICON_HOURGLASS_AVAILABLE, ICON_HOURGLASS_CODEPOINTS),
+            Icon::Hubot => (ICON_HUBOT_AVAILABLE, ICON_HUBOT_CODEPOINTS),
+            Icon::IdBadge => (ICON_ID_BADGE_AVAILABLE, ICON_ID_BADGE_CODEPOINTS),
+            Icon::Image => (ICON_IMAGE_AVAILABLE, ICON_IMAGE_CODEPOINTS),
+            Icon::Inbox => (ICON_INBOX_AVAILABLE, ICON_INBOX_CODEPOINTS),
+            Icon::Infinity => (ICON_INFINITY_AVAILABLE, ICON_INFINITY_CODEPOINTS),
+            Icon::Info => (ICON_INFO_AVAILABLE, ICON_INFO_CODEPOINTS),
+            Icon::IssueClosed => (ICON_ISSUE_CLOSED_AVAILABLE, ICON_ISSUE_CLOSED_CODEPOINTS),
+            Icon::IssueDraft => (ICON_ISSUE_DRAFT_AVAILABLE, ICON_ISSUE_DRAFT_CODEPOINTS),
+            Icon::IssueOpened => (ICON_ISSUE_OPENED_AVAILABLE, ICON_ISSUE_OPENED_CODEPOINTS),
+            Icon::IssueReopened => (
+                ICON_ISSUE_REOPENED_AVAILABLE,
+                ICON_ISSUE_REOPENED_CODEPOINTS,
+            ),
+            Icon::IssueTrackedBy => (
+                ICON_ISSUE_TRACKED_BY_AVAILABLE,
+                ICON_ISSUE_TRACKED_BY_CODEPOINTS,
+            ),
+            Icon::IssueTracks => (ICON_ISSUE_TRACKS_AVAILABLE, ICON_ISSUE_TRACKS_CODEPOINTS),
+            Icon::Italic => (ICON_ITALIC_AVAILABLE, ICON_ITALIC_CODEPOINTS),
+            Icon::Iterations => (ICON_ITERATIONS_AVAILABLE, ICON_ITERATIONS_CODEPOINTS),
+            Icon::KebabHorizontal => (
+                ICON_KEBAB_HORIZONTAL_AVAILABLE,
+                ICON_KEBAB_HORIZONTAL_CODEPOINTS,
+            ),
+            Icon::Key => (ICON_KEY_AVAILABLE, ICON_KEY_CODEPOINTS),
+            Icon::KeyAsterisk => (ICON_KEY_ASTERISK_AVAILABLE, ICON_KEY_ASTERISK_CODEPOINTS),
+            Icon::Law => (ICON_LAW_AVAILABLE, ICON_LAW_CODEPOINTS),
+            Icon::LightBulb => (ICON_LIGHT_BULB_AVAILABLE, ICON_LIGHT_BULB_CODEPOINTS),
+            Icon::Link => (ICON_LINK_AVAILABLE, ICON_LINK_CODEPOINTS),
+            Icon::LinkExternal => (ICON_LINK_EXTERNAL_AVAILABLE, ICON_LINK_EXTERNAL_CODEPOINTS),
+            Icon::ListOrdered => (ICON_LIST_ORDERED_AVAILABLE, ICON_LIST_ORDERED_CODEPOINTS),
+            Icon::ListUnordered => (
+                ICON_LIST_UNORDERED_AVAILABLE,
+                ICON_LIST_UNORDERED_CODEPOINTS,
+            ),
+            Icon::Location => (ICON_LOCATION_AVAILABLE, ICON_LOCATION_CODEPOINTS),
+            Icon::Lock => (ICON_LOCK_AVAILABLE, ICON_LOCK_CODEPOINTS),
+            Icon::Log => (ICON_LOG_AVAILABLE, ICON_LOG_CODEPOINTS),
+            Icon::LogoGist => (ICON_LOGO_GIST_AVAILABLE, ICON_LOGO_GIST_CODEPOINTS),
+            Icon::LogoGithub => (ICON_LOGO_GITHUB_AVAILABLE, ICON_LOGO_GITHUB_CODEPOINTS),
+            Icon::Loop_ => (ICON_LOOP__AVAILABLE, ICON_LOOP__CODEPOINTS),
+            Icon::Mail => (ICON_MAIL_AVAILABLE, ICON_MAIL_CODEPOINTS),
+            Icon::MarkGithub => (ICON_MARK_GITHUB_AVAILABLE, ICON_MARK_GITHUB_CODEPOINTS),
+            Icon::Markdown => (ICON_MARKDOWN_AVAILABLE, ICON_MARKDOWN_CODEPOINTS),
+            Icon::Maximize => (ICON_MAXIMIZE_AVAILABLE, ICON_MAXIMIZE_CODEPOINTS),
+            Icon::Mcp => (ICON_MCP_AVAILABLE, ICON_MCP_CODEPOINTS),
+            Icon::Megaphone => (ICON_MEGAPHONE_AVAILABLE, ICON_MEGAPHONE_CODEPOINTS),
+            Icon::Mention => (ICON_MENTION_AVAILABLE, ICON_MENTION_CODEPOINTS),
+            Icon::Meter => (ICON_METER_AVAILABLE, ICON_METER_CODEPOINTS),
+            Icon::Milestone => (ICON_MILESTONE_AVAILABLE, ICON_MILESTONE_CODEPOINTS),
+            Icon::Minimize => (ICON_MINIMIZE_AVAILABLE, ICON_MINIMIZE_CODEPOINTS),
+            Icon::Mirror => (ICON_MIRROR_AVAILABLE, ICON_MIRROR_CODEPOINTS),
+            Icon::Moon => (ICON_MOON_AVAILABLE, ICON_MOON_CODEPOINTS),
+            Icon::MortarBoard => (ICON_MORTAR_BOARD_AVAILABLE, ICON_MORTAR_BOARD_CODEPOINTS),
+            Icon::MoveToBottom => (
+                ICON_MOVE_TO_BOTTOM_AVAILABLE,
+                ICON_MOVE_TO_BOTTOM_CODEPOINTS,
+            ),
+            Icon::MoveToEnd => (ICON_MOVE_TO_END_AVAILABLE, ICON_MOVE_TO_END_CODEPOINTS),
+            Icon::MoveToStart => (ICON_MOVE_TO_START_AVAILABLE, ICON_MOVE_TO_START_CODEPOINTS),
+            Icon::MoveToTop => (ICON_MOVE_TO_TOP_AVAILABLE, ICON_MOVE_TO_TOP_CODEPOINTS),
+            Icon::MultiSelect => (ICON_MULTI_SELECT_AVAILABLE, ICON_MULTI_SELECT_CODEPOINTS),
+            Icon::Mute => (ICON_MUTE_AVAILABLE, ICON_MUTE_CODEPOINTS),
+            Icon::NoEntry => (ICON_NO_ENTRY_AVAILABLE, ICON_NO_ENTRY_CODEPOINTS),
+            Icon::Node => (ICON_NODE_AVAILABLE, ICON_NODE_CODEPOINTS),
+            Icon::NorthStar => (ICON_NORTH_STAR_AVAILABLE, ICON_NORTH_STAR_CODEPOINTS),
+            Icon::Note => (ICON_NOTE_AVAILABLE, ICON_NOTE_CODEPOINTS),
+            Icon::Number => (ICON_NUMBER_AVAILABLE, ICON_NUMBER_CODEPOINTS),
+            Icon::Organization => (ICON_ORGANIZATION_AVAILABLE, ICON_ORGANIZATION_CODEPOINTS),
+            Icon::Package => (ICON_PACKAGE_AVAILABLE, ICON_PACKAGE_CODEPOINTS),
+            Icon::PackageDependencies => (
+                ICON_PACKAGE_DEPENDENCIES_AVAILABLE,
+                ICON_PACKAGE_DEPENDENCIES_CODEPOINTS,
+            ),
+            Icon::PackageDependents => (
+                ICON_PACKAGE_DEPENDENTS_AVAILABLE,
+                ICON_PACKAGE_DEPENDENTS_CODEPOINTS,
+            ),
+            Icon::Paintbrush => (ICON_PAINTBRUSH_AVAILABLE, ICON_PAINTBRUSH_CODEPOINTS),
+            Icon::PaperAirplane => (
+                ICON_PAPER_AIRPLANE_AVAILABLE,
+                ICON_PAPER_AIRPLANE_CODEPOINTS,
+            ),
+            Icon::Paperclip => (ICON_PAPERCLIP_AVAILABLE, ICON_PAPERCLIP_CODEPOINTS),
+            Icon::Passkey => (ICON_PASSKEY_AVAILABLE, ICON_PASSKEY_CODEPOINTS),
+            Icon::Paste => (ICON_PASTE_AVAILABLE, ICON_PASTE_CODEPOINTS),
+            Icon::Pause => (ICON_PAUSE_AVAILABLE, ICON_PAUSE_CODEPOINTS),
+            Icon::Pencil => (ICON_PENCIL_AVAILABLE, ICON_PENCIL_CODEPOINTS),
+            Icon::PencilAi => (ICON_PENCIL_AI_AVAILABLE, ICON_PENCIL_AI_CODEPOINTS),
+            Icon::People => (ICON_PEOPLE_AVAILABLE, ICON_PEOPLE_CODEPOINTS),
+            Icon::Person => (ICON_PERSON_AVAILABLE, ICON_PERSON_CODEPOINTS),
+            Icon::PersonAdd => (ICON_PERSON_ADD_AVAILABLE, ICON_PERSON_ADD_CODEPOINTS),
+            Icon::Pin => (ICON_PIN_AVAILABLE, ICON_PIN_CODEPOINTS),
+            Icon::PinSlash => (ICON_PIN_SLASH_AVAILABLE, ICON_PIN_SLASH_CODEPOINTS),
+            Icon::PivotColumn => (ICON_PIVOT_COLUMN_AVAILABLE, ICON_PIVOT_COLUMN_CODEPOINTS),
+            Icon::Play => (ICON_PLAY_AVAILABLE, ICON_PLAY_CODEPOINTS),
+            Icon::Plug => (ICON_PLUG_AVAILABLE, ICON_PLUG_CODEPOINTS),
+            Icon::Plus => (ICON_PLUS_AVAILABLE, ICON_PLUS_CODEPOINTS),
+            Icon::PlusCircle => (ICON_PLUS_CIRCLE_AVAILABLE, ICON_PLUS_CIRCLE_CODEPOINTS),
+            Icon::Project => (ICON_PROJECT_AVAILABLE, ICON_PROJECT_CODEPOINTS),
+            Icon::ProjectRoadmap => (
+                ICON_PROJECT_ROADMAP_AVAILABLE,
+                ICON_PROJECT_ROADMAP_CODEPOINTS,
+            ),
+            Icon::ProjectSymlink => (
+                ICON_PROJECT_SYMLINK_AVAILABLE,
+                ICON_PROJECT_SYMLINK_CODEPOINTS,
+            ),
+            Icon::ProjectTemplate => (
+                ICON_PROJECT_TEMPLATE_AVAILABLE,
+                ICON_PROJECT_TEMPLATE_CODEPOINTS,
+            ),
+            Icon::Pulse => (ICON_PULSE_AVAILABLE, ICON_PULSE_CODEPOINTS),
+            Icon::Question => (ICON_QUESTION_AVAILABLE, ICON_QUESTION_CODEPOINTS),
+            Icon::Quote => (ICON_QUOTE_AVAILABLE, ICON_QUOTE_CODEPOINTS),
+            Icon::Read => (ICON_READ_AVAILABLE, ICON_READ_CODEPOINTS),
+            Icon::Redo => (ICON_REDO_AVAILABLE, ICON_REDO_CODEPOINTS),
+            Icon::RelFilePath => (ICON_REL_FILE_PATH_AVAILABLE, ICON_REL_FILE_PATH_CODEPOINTS),
+            Icon::Reply => (ICON_REPLY_AVAILABLE, ICON_REPLY_CODEPOINTS),
+            Icon::Repo => (ICON_REPO_AVAILABLE, ICON_REPO_CODEPOINTS),
+            Icon::RepoClone => (ICON_REPO_CLONE_AVAILABLE, ICON_REPO_CLONE_CODEPOINTS),
+            Icon::RepoDelete => (ICON_REPO_DELETE_AVAILABLE, ICON_REPO_DELETE_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::RepoDeleted => (ICON_REPO_DELETED_AVAILABLE, ICON_REPO_DELETED_CODEPOINTS),
+            Icon::RepoForked => (ICON_REPO_FORKED_AVAILABLE, ICON_REPO_FORKED_CODEPOINTS),
+            Icon::RepoLocked => (ICON_REPO_LOCKED_AVAILABLE, ICON_REPO_LOCKED_CODEPOINTS),
+            Icon::RepoPull => (ICON_REPO_PULL_AVAILABLE, ICON_REPO_PULL_CODEPOINTS),
+            Icon::RepoPush => (ICON_REPO_PUSH_AVAILABLE, ICON_REPO_PUSH_CODEPOINTS),
+            Icon::RepoTemplate => (ICON_REPO_TEMPLATE_AVAILABLE, ICON_REPO_TEMPLATE_CODEPOINTS),
+            Icon::Report => (ICON_REPORT_AVAILABLE, ICON_REPORT_CODEPOINTS),
+            Icon::Rocket => (ICON_ROCKET_AVAILABLE, ICON_ROCKET_CODEPOINTS),
+            Icon::Rows => (ICON_ROWS_AVAILABLE, ICON_ROWS_CODEPOINTS),
+            Icon::Rss => (ICON_RSS_AVAILABLE, ICON_RSS_CODEPOINTS),
+            Icon::Ruby => (ICON_RUBY_AVAILABLE, ICON_RUBY_CODEPOINTS),
+            Icon::ScreenFull => (ICON_SCREEN_FULL_AVAILABLE, ICON_SCREEN_FULL_CODEPOINTS),
+            Icon::ScreenNormal => (ICON_SCREEN_NORMAL_AVAILABLE, ICON_SCREEN_NORMAL_CODEPOINTS),
+            Icon::Search => (ICON_SEARCH_AVAILABLE, ICON_SEARCH_CODEPOINTS),
+            Icon::Server => (ICON_SERVER_AVAILABLE, ICON_SERVER_CODEPOINTS),
+            Icon::Share => (ICON_SHARE_AVAILABLE, ICON_SHARE_CODEPOINTS),
+            Icon::ShareAndroid => (ICON_SHARE_ANDROID_AVAILABLE, ICON_SHARE_ANDROID_CODEPOINTS),
+            Icon::Shield => (ICON_SHIELD_AVAILABLE, ICON_SHIELD_CODEPOINTS),
+            Icon::ShieldCheck => (ICON_SHIELD_CHECK_AVAILABLE, ICON_SHIELD_CHECK_CODEPOINTS),
+            Icon::ShieldLock => (ICON_SHIELD_LOCK_AVAILABLE, ICON_SHIELD_LOCK_CODEPOINTS),
+            Icon::ShieldSlash => (ICON_SHIELD_SLASH_AVAILABLE, ICON_SHIELD_SLASH_CODEPOINTS),
+            Icon::ShieldX => (ICON_SHIELD_X_AVAILABLE, ICON_SHIELD_X_CODEPOINTS),
+            Icon::SidebarCollapse => (
+                ICON_SIDEBAR_COLLAPSE_AVAILABLE,
+                ICON_SIDEBAR_COLLAPSE_CODEPOINTS,
+            ),
+            Icon::SidebarExpand => (
+                ICON_SIDEBAR_EXPAND_AVAILABLE,
+                ICON_SIDEBAR_EXPAND_CODEPOINTS,
+            ),
+            Icon::SignIn => (ICON_SIGN_IN_AVAILABLE, ICON_SIGN_IN_CODEPOINTS),
+            Icon::SignOut => (ICON_SIGN_OUT_AVAILABLE, ICON_SIGN_OUT_CODEPOINTS),
+            Icon::SingleSelect => (ICON_SINGLE_SELECT_AVAILABLE, ICON_SINGLE_SELECT_CODEPOINTS),
+            Icon::Skip => (ICON_SKIP_AVAILABLE, ICON_SKIP_CODEPOINTS),
+            Icon::Sliders => (ICON_SLIDERS_AVAILABLE, ICON_SLIDERS_CODEPOINTS),
+            Icon::Smiley => (ICON_SMILEY_AVAILABLE, ICON_SMILEY_CODEPOINTS),
+            Icon::SmileyFrown => (ICON_SMILEY_FROWN_AVAILABLE, ICON_SMILEY_FROWN_CODEPOINTS),
+            Icon::SmileyFrustrated => (
+                ICON_SMILEY_FRUSTRATED_AVAILABLE,
+                ICON_SMILEY_FRUSTRATED_CODEPOINTS,
+            ),
+            Icon::SmileyGrin => (ICON_SMILEY_GRIN_AVAILABLE, ICON_SMILEY_GRIN_CODEPOINTS),
+            Icon::SmileyNeutral => (
+                ICON_SMILEY_NEUTRAL_AVAILABLE,
+                ICON_SMILEY_NEUTRAL_CODEPOINTS,
+            ),
+            Icon::SortAsc => (ICON_SORT_ASC_AVAILABLE, ICON_SORT_ASC_CODEPOINTS),
+            Icon::SortDesc => (ICON_SORT_DESC_AVAILABLE, ICON_SORT_DESC_CODEPOINTS),
+            Icon::Space => (ICON_SPACE_AVAILABLE, ICON_SPACE_CODEPOINTS),
+            Icon::SpacingLarge => (ICON_SPACING_LARGE_AVAILABLE, ICON_SPACING_LARGE_CODEPOINTS),
+            Icon::SpacingMedium => (
+                ICON_SPACING_MEDIUM_AVAILABLE,
+                ICON_SPACING_MEDIUM_CODEPOINTS,
+            ),
+            Icon::SpacingSmall => (ICON_SPACING_SMALL_AVAILABLE, ICON_SPACING_SMALL_CODEPOINTS),
+            Icon::Sparkle => (ICON_SPARKLE_AVAILABLE, ICON_SPARKLE_CODEPOINTS),
+            Icon::Sparkles => (ICON_SPARKLES_AVAILABLE, ICON_SPARKLES_CODEPOINTS),
+            Icon::SplitView => (ICON_SPLIT_VIEW_AVAILABLE, ICON_SPLIT_VIEW_CODEPOINTS),
+            Icon::SponsorTiers => (ICON_SPONSOR_TIERS_AVAILABLE, ICON_SPONSOR_TIERS_CODEPOINTS),
+            Icon::Square => (ICON_SQUARE_AVAILABLE, ICON_SQUARE_CODEPOINTS),
+            Icon::SquareCircle => (ICON_SQUARE_CIRCLE_AVAILABLE, ICON_SQUARE_CIRCLE_CODEPOINTS),
+            Icon::Squirrel => (ICON_SQUIRREL_AVAILABLE, ICON_SQUIRREL_CODEPOINTS),
+            Icon::Stack => (ICON_STACK_AVAILABLE, ICON_STACK_CODEPOINTS),
+            Icon::Star => (ICON_STAR_AVAILABLE, ICON_STAR_CODEPOINTS),
+            Icon::Stop => (ICON_STOP_AVAILABLE, ICON_STOP_CODEPOINTS),
+            Icon::Stopwatch => (ICON_STOPWATCH_AVAILABLE, ICON_STOPWATCH_CODEPOINTS),
+            Icon::Strikethrough => (ICON_STRIKETHROUGH_AVAILABLE, ICON_STRIKETHROUGH_CODEPOINTS),
+            Icon::Sun => (ICON_SUN_AVAILABLE, ICON_SUN_CODEPOINTS),
+            Icon::Sync => (ICON_SYNC_AVAILABLE, ICON_SYNC_CODEPOINTS),
+            Icon::Tab => (ICON_TAB_AVAILABLE, ICON_TAB_CODEPOINTS),
+            Icon::TabExternal => (ICON_TAB_EXTERNAL_AVAILABLE, ICON_TAB_EXTERNAL_CODEPOINTS),
+            Icon::Table => (ICON_TABLE_AVAILABLE, ICON_TABLE_CODEPOINTS),
+            Icon::Tag => (ICON_TAG_AVAILABLE, ICON_TAG_CODEPOINTS),
+            Icon::Tasklist => (ICON_TASKLIST_AVAILABLE, ICON_TASKLIST_CODEPOINTS),
+            Icon::Telescope => (ICON_TELESCOPE_AVAILABLE, ICON_TELESCOPE_CODEPOINTS),
+            Icon::Terminal => (ICON_TERMINAL_AVAILABLE, ICON_TERMINAL_CODEPOINTS),
+            Icon::ThreeBars => (ICON_THREE_BARS_AVAILABLE, ICON_THREE_BARS_CODEPOINTS),
+            Icon::Thumbsdown => (ICON_THUMBSDOWN_AVAILABLE, ICON_THUMBSDOWN_CODEPOINTS),
+            Icon::Thumbsup => (ICON_THUMBSUP_AVAILABLE, ICON_THUMBSUP_CODEPOINTS),
+            Icon::Tools => (ICON_TOOLS_AVAILABLE, ICON_TOOLS_CODEPOINTS),
+            Icon::TrackedByClosedCompleted => (
+                ICON_TRACKED_BY_CLOSED_COMPLETED_AVAILABLE,
+                ICON_TRACKED_BY_CLOSED_COMPLETED_CODEPOINTS,
+            ),
+            Icon::TrackedByClosedNotPlanned => (
+                ICON_TRACKED_BY_CLOSED_NOT_PLANNED_AVAILABLE,
+                ICON_TRACKED_BY_CLOSED_NOT_PLANNED_CODEPOINTS,
+            ),
+            Icon::Trash => (ICON_TRASH_AVAILABLE, ICON_TRASH_CODEPOINTS),
+            Icon::TriangleDown => (ICON_TRIANGLE_DOWN_AVAILABLE, ICON_TRIANGLE_DOWN_CODEPOINTS),
+            Icon::TriangleLeft => (ICON_TRIANGLE_LEFT_AVAILABLE, ICON_TRIANGLE_LEFT_CODEPOINTS),
+            Icon::TriangleRight => (
+                ICON_TRIANGLE_RIGHT_AVAILABLE,
+                ICON_TRIANGLE_RIGHT_CODEPOINTS,
+            ),
+            Icon::TriangleUp => (ICON_TRIANGLE_UP_AVAILABLE, ICON_TRIANGLE_UP_CODEPOINTS),
+            Icon::Trophy => (ICON_TROPHY_AVAILABLE, ICON_TROPHY_CODEPOINTS),
+            Icon::Typography => (ICON_TYPOGRAPHY_AVAILABLE, ICON_TYPOGRAPHY_CODEPOINTS),
+            Icon::Undo => (ICON_UNDO_AVAILABLE, ICON_UNDO_CODEPOINTS),
+            Icon::Unfold => (ICON_UNFOLD_AVAILABLE, ICON_UNFOLD_CODEPOINTS),
+            Icon::Unlink => (ICON_UNLINK_AVAILABLE, ICON_UNLINK_CODEPOINTS),
+            Icon::Unlock => (ICON_UNLOCK_AVAILABLE, ICON_UNLOCK_CODEPOINTS),
+            Icon::Unmute => (ICON_UNMUTE_AVAILABLE, ICON_UNMUTE_CODEPOINTS),
+            Icon::Unread => (ICON_UNREAD_AVAILABLE, ICON_UNREAD_CODEPOINTS),
+            Icon::Unverified => (ICON_UNVERIFIED_AVAILABLE, ICON_UNVERIFIED_CODEPOINTS),
+            Icon::Unwrap => (ICON_UNWRAP_AVAILABLE, ICON_UNWRAP_CODEPOINTS),
+            Icon::Upload => (ICON_UPLOAD_AVAILABLE, ICON_UPLOAD_CODEPOINTS),
+            Icon::Verified => (ICON_VERIFIED_AVAILABLE, ICON_VERIFIED_CODEPOINTS),
+            Icon::Versions => (ICON_VERSIONS_AVAILABLE, ICON_VERSIONS_CODEPOINTS),
+            Icon::Video => (ICON_VIDEO_AVAILABLE, ICON_VIDEO_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::Vscode => (ICON_VSCODE_AVAILABLE, ICON_VSCODE_CODEPOINTS),
+            #[cfg(feature = "octicons-tiny")]
+            Icon::Webhook => (ICON_WEBHOOK_AVAILABLE, ICON_WEBHOOK_CODEPOINTS),
+            Icon::Workflow => (ICON_WORKFLOW_AVAILABLE, ICON_WORKFLOW_CODEPOINTS),
+            Icon::Wrap => (ICON_WRAP_AVAILABLE, ICON_WRAP_CODEPOINTS),
+            Icon::X => (ICON_X_AVAILABLE, ICON_X_CODEPOINTS),
+            Icon::XCircle => (ICON_X_CIRCLE_AVAILABLE, ICON_X_CIRCLE_CODEPOINTS),
+            Icon::Zap => (ICON_ZAP_AVAILABLE, ICON_ZAP_CODEPOINTS),
+            Icon::ZoomIn => (ICON_ZOOM_IN_AVAILABLE, ICON_ZOOM_IN_CODEPOINTS),
+            Icon::ZoomOut => (ICON_ZOOM_OUT_AVAILABLE, ICON_ZOOM_OUT_CODEPOINTS),
         }
     }
 }
 
+/// Looks an icon up by its exact map name; unknown names yield `IconError::IconNotFound`.
+///
+/// Binary-searches `ICON_NAMES`, which is sorted and index-aligned with `Icon::ALL`.
+impl TryFrom<&str> for Icon {
+    type Error = crate::core::IconError;
+
+    fn try_from(name: &str) -> Result<Self, crate::core::IconError> {
+        ICON_NAMES
+            .binary_search(&name)
+            .map(|index| Icon::ALL[index])
+            .map_err(|_| crate::core::IconError::IconNotFound {
+                pack: PACK_ID,
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Serializes as the icon's map name, e.g. `"alarm"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Icon {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes from the icon's map name through `TryFrom<&str>`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Icon {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Icon::try_from(name.as_str()).map_err(|_| {
+            <D::Error as serde::de::Error>::custom(format_args!("unknown {PACK_ID} icon '{name}'"))
+        })
+    }
+}
+
+impl From<Icon> for &'static str {
+    fn from(icon: Icon) -> Self {
+        icon.name()
+    }
+}
+
+pub fn icons() -> impl Iterator<Item = Icon> {
+    Icon::ALL.iter().copied()
+}
+
 pub const ICON_NAMES: &[&str] = &[
     "accessibility",
     "accessibility-inset",
@@ -814,6 +2257,7 @@ pub const ICON_NAMES: &[&str] = &[
     "bold",
     "book",
     "bookmark",
+    #[cfg(feature = "octicons-tiny")]
     "bookmark-filled",
     "bookmark-slash",
     "boolean-off",
@@ -852,7 +2296,9 @@ pub const ICON_NAMES: &[&str] = &[
     "compose",
     "container",
     "copilot",
+    #[cfg(feature = "octicons-tiny")]
     "copilot-error",
+    #[cfg(feature = "octicons-tiny")]
     "copilot-warning",
     "copy",
     "cpu",
@@ -886,24 +2332,43 @@ pub const ICON_NAMES: &[&str] = &[
     "exclamation",
     "eye",
     "eye-closed",
+    #[cfg(feature = "octicons-tiny")]
     "feed-discussion",
+    #[cfg(feature = "octicons-tiny")]
     "feed-forked",
+    #[cfg(feature = "octicons-tiny")]
     "feed-heart",
+    #[cfg(feature = "octicons-tiny")]
     "feed-issue-closed",
+    #[cfg(feature = "octicons-tiny")]
     "feed-issue-draft",
+    #[cfg(feature = "octicons-tiny")]
     "feed-issue-open",
+    #[cfg(feature = "octicons-tiny")]
     "feed-issue-reopen",
+    #[cfg(feature = "octicons-tiny")]
     "feed-merged",
+    #[cfg(feature = "octicons-tiny")]
     "feed-person",
+    #[cfg(feature = "octicons-tiny")]
     "feed-plus",
+    #[cfg(feature = "octicons-tiny")]
     "feed-public",
+    #[cfg(feature = "octicons-tiny")]
     "feed-pull-request-closed",
+    #[cfg(feature = "octicons-tiny")]
     "feed-pull-request-draft",
+    #[cfg(feature = "octicons-tiny")]
     "feed-pull-request-open",
+    #[cfg(feature = "octicons-tiny")]
     "feed-repo",
+    #[cfg(feature = "octicons-tiny")]
     "feed-rocket",
+    #[cfg(feature = "octicons-tiny")]
     "feed-star",
+    #[cfg(feature = "octicons-tiny")]
     "feed-tag",
+    #[cfg(feature = "octicons-tiny")]
     "feed-trophy",
     "file",
     "file-added",
@@ -1043,6 +2508,7 @@ pub const ICON_NAMES: &[&str] = &[
     "repo",
     "repo-clone",
     "repo-delete",
+    #[cfg(feature = "octicons-tiny")]
     "repo-deleted",
     "repo-forked",
     "repo-locked",
@@ -1129,7 +2595,9 @@ pub const ICON_NAMES: &[&str] = &[
     "verified",
     "versions",
     "video",
+    #[cfg(feature = "octicons-tiny")]
     "vscode",
+    #[cfg(feature = "octicons-tiny")]
     "webhook",
     "workflow",
     "wrap",
@@ -1140,6 +2608,6812 @@ pub const ICON_NAMES: &[&str] = &[
     "zoom-out",
 ];
 
+/// Icon names as constants, e.g. for `try_icon(pack, names::ALARM, ..)`.
+pub mod names {
+    pub const ACCESSIBILITY: &str = "accessibility";
+    pub const ACCESSIBILITY_INSET: &str = "accessibility-inset";
+    pub const AGENT: &str = "agent";
+    pub const AI_MODEL: &str = "ai-model";
+    pub const ALERT: &str = "alert";
+    pub const APPS: &str = "apps";
+    pub const ARCHIVE: &str = "archive";
+    pub const ARROW_BOTH: &str = "arrow-both";
+    pub const ARROW_DOWN: &str = "arrow-down";
+    pub const ARROW_DOWN_LEFT: &str = "arrow-down-left";
+    pub const ARROW_DOWN_RIGHT: &str = "arrow-down-right";
+    pub const ARROW_LEFT: &str = "arrow-left";
+    pub const ARROW_RIGHT: &str = "arrow-right";
+    pub const ARROW_SWITCH: &str = "arrow-switch";
+    pub const ARROW_UP: &str = "arrow-up";
+    pub const ARROW_UP_LEFT: &str = "arrow-up-left";
+    pub const ARROW_UP_RIGHT: &str = "arrow-up-right";
+    pub const BEAKER: &str = "beaker";
+    pub const BELL: &str = "bell";
+    pub const BELL_SLASH: &str = "bell-slash";
+    pub const BLOCKED: &str = "blocked";
+    pub const BOLD: &str = "bold";
+    pub const BOOK: &str = "book";
+    pub const BOOKMARK: &str = "bookmark";
+    #[cfg(feature = "octicons-tiny")]
+    pub const BOOKMARK_FILLED: &str = "bookmark-filled";
+    pub const BOOKMARK_SLASH: &str = "bookmark-slash";
+    pub const BOOLEAN_OFF: &str = "boolean-off";
+    pub const BOOLEAN_ON: &str = "boolean-on";
+    pub const BRIEFCASE: &str = "briefcase";
+    pub const BROADCAST: &str = "broadcast";
+    pub const BROWSER: &str = "browser";
+    pub const BUG: &str = "bug";
+    pub const CACHE: &str = "cache";
+    pub const CALENDAR: &str = "calendar";
+    pub const CHECK: &str = "check";
+    pub const CHECK_CIRCLE: &str = "check-circle";
+    pub const CHECKBOX: &str = "checkbox";
+    pub const CHECKLIST: &str = "checklist";
+    pub const CHEVRON_DOWN: &str = "chevron-down";
+    pub const CHEVRON_LEFT: &str = "chevron-left";
+    pub const CHEVRON_RIGHT: &str = "chevron-right";
+    pub const CHEVRON_UP: &str = "chevron-up";
+    pub const CIRCLE: &str = "circle";
+    pub const CIRCLE_SLASH: &str = "circle-slash";
+    pub const CLOCK: &str = "clock";
+    pub const CLOUD: &str = "cloud";
+    pub const CLOUD_OFFLINE: &str = "cloud-offline";
+    pub const CODE: &str = "code";
+    pub const CODE_OF_CONDUCT: &str = "code-of-conduct";
+    pub const CODE_REVIEW: &str = "code-review";
+    pub const CODE_SQUARE: &str = "code-square";
+    pub const CODESCAN: &str = "codescan";
+    pub const CODESCAN_CHECKMARK: &str = "codescan-checkmark";
+    pub const CODESPACES: &str = "codespaces";
+    pub const COLUMNS: &str = "columns";
+    pub const COMMAND_PALETTE: &str = "command-palette";
+    pub const COMMENT: &str = "comment";
+    pub const COMMENT_AI: &str = "comment-ai";
+    pub const COMMENT_DISCUSSION: &str = "comment-discussion";
+    pub const COMPOSE: &str = "compose";
+    pub const CONTAINER: &str = "container";
+    pub const COPILOT: &str = "copilot";
+    #[cfg(feature = "octicons-tiny")]
+    pub const COPILOT_ERROR: &str = "copilot-error";
+    #[cfg(feature = "octicons-tiny")]
+    pub const COPILOT_WARNING: &str = "copilot-warning";
+    pub const COPY: &str = "copy";
+    pub const CPU: &str = "cpu";
+    pub const CREDIT_CARD: &str = "credit-card";
+    pub const CROSS_REFERENCE: &str = "cross-reference";
+    pub const CROSSHAIRS: &str = "crosshairs";
+    pub const DASH: &str = "dash";
+    pub const DATABASE: &str = "database";
+    pub const DEPENDABOT: &str = "dependabot";
+    pub const DESKTOP_DOWNLOAD: &str = "desktop-download";
+    pub const DEVICE_CAMERA: &str = "device-camera";
+    pub const DEVICE_CAMERA_VIDEO: &str = "device-camera-video";
+    pub const DEVICE_DESKTOP: &str = "device-desktop";
+    pub const DEVICE_MOBILE: &str = "device-mobile";
+    pub const DEVICES: &str = "devices";
+    pub const DIAMOND: &str = "diamond";
+    pub const DICE: &str = "dice";
+    pub const DIFF: &str = "diff";
+    pub const DIFF_ADDED: &str = "diff-added";
+    pub const DIFF_IGNORED: &str = "diff-ignored";
+    pub const DIFF_MODIFIED: &str = "diff-modified";
+    pub const DIFF_REMOVED: &str = "diff-removed";
+    pub const DIFF_RENAMED: &str = "diff-renamed";
+    pub const DISCUSSION_CLOSED: &str = "discussion-closed";
+    pub const DISCUSSION_DUPLICATE: &str = "discussion-duplicate";
+    pub const DISCUSSION_OUTDATED: &str = "discussion-outdated";
+    pub const DOT: &str = "dot";
+    pub const DOWNLOAD: &str = "download";
+    pub const DUPLICATE: &str = "duplicate";
+    pub const ELLIPSIS: &str = "ellipsis";
+    pub const EXCLAMATION: &str = "exclamation";
+    pub const EYE: &str = "eye";
+    pub const EYE_CLOSED: &str = "eye-closed";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_DISCUSSION: &str = "feed-discussion";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_FORKED: &str = "feed-forked";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_HEART: &str = "feed-heart";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_ISSUE_CLOSED: &str = "feed-issue-closed";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_ISSUE_DRAFT: &str = "feed-issue-draft";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_ISSUE_OPEN: &str = "feed-issue-open";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_ISSUE_REOPEN: &str = "feed-issue-reopen";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_MERGED: &str = "feed-merged";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_PERSON: &str = "feed-person";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_PLUS: &str = "feed-plus";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_PUBLIC: &str = "feed-public";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_PULL_REQUEST_CLOSED: &str = "feed-pull-request-closed";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_PULL_REQUEST_DRAFT: &str = "feed-pull-request-draft";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_PULL_REQUEST_OPEN: &str = "feed-pull-request-open";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_REPO: &str = "feed-repo";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_ROCKET: &str = "feed-rocket";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_STAR: &str = "feed-star";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_TAG: &str = "feed-tag";
+    #[cfg(feature = "octicons-tiny")]
+    pub const FEED_TROPHY: &str = "feed-trophy";
+    pub const FILE: &str = "file";
+    pub const FILE_ADDED: &str = "file-added";
+    pub const FILE_BADGE: &str = "file-badge";
+    pub const FILE_BINARY: &str = "file-binary";
+    pub const FILE_CHECK: &str = "file-check";
+    pub const FILE_CODE: &str = "file-code";
+    pub const FILE_DIFF: &str = "file-diff";
+    pub const FILE_DIRECTORY: &str = "file-directory";
+    pub const FILE_DIRECTORY_OPEN: &str = "file-directory-open";
+    pub const FILE_DIRECTORY_SYMLINK: &str = "file-directory-symlink";
+    pub const FILE_MEDIA: &str = "file-media";
+    pub const FILE_MOVED: &str = "file-moved";
+    pub const FILE_REMOVED: &str = "file-removed";
+    pub const FILE_SUBMODULE: &str = "file-submodule";
+    pub const FILE_SYMLINK_FILE: &str = "file-symlink-file";
+    pub const FILE_ZIP: &str = "file-zip";
+    pub const FILTER: &str = "filter";
+    pub const FILTER_REMOVE: &str = "filter-remove";
+    pub const FISCAL_HOST: &str = "fiscal-host";
+    pub const FLAME: &str = "flame";
+    pub const FLOWCHART: &str = "flowchart";
+    pub const FOCUS_CENTER: &str = "focus-center";
+    pub const FOLD: &str = "fold";
+    pub const FOLD_DOWN: &str = "fold-down";
+    pub const FOLD_UP: &str = "fold-up";
+    pub const GEAR: &str = "gear";
+    pub const GIFT: &str = "gift";
+    pub const GIT_BRANCH: &str = "git-branch";
+    pub const GIT_BRANCH_CHECK: &str = "git-branch-check";
+    pub const GIT_COMMIT: &str = "git-commit";
+    pub const GIT_COMPARE: &str = "git-compare";
+    pub const GIT_MERGE: &str = "git-merge";
+    pub const GIT_MERGE_QUEUE: &str = "git-merge-queue";
+    pub const GIT_PULL_REQUEST: &str = "git-pull-request";
+    pub const GIT_PULL_REQUEST_CLOSED: &str = "git-pull-request-closed";
+    pub const GIT_PULL_REQUEST_DRAFT: &str = "git-pull-request-draft";
+    pub const GLOBE: &str = "globe";
+    pub const GOAL: &str = "goal";
+    pub const GRABBER: &str = "grabber";
+    pub const GRAPH: &str = "graph";
+    pub const GRAPH_BAR_HORIZONTAL: &str = "graph-bar-horizontal";
+    pub const GRAPH_BAR_VERTICAL: &str = "graph-bar-vertical";
+    pub const HASH: &str = "hash";
+    pub const HEADING: &str = "heading";
+    pub const HEART: &str = "heart";
+    pub const HISTORY: &str = "history";
+    pub const HOME: &str = "home";
+    pub const HORIZONTAL_RULE: &str = "horizontal-rule";
+    pub const HOURGLASS: &str = "hourglass";
+    pub const HUBOT: &str = "hubot";
+    pub const ID_BADGE: &str = "id-badge";
+    pub const IMAGE: &str = "image";
+    pub const INBOX: &str = "inbox";
+    pub const INFINITY: &str = "infinity";
+    pub const INFO: &str = "info";
+    pub const ISSUE_CLOSED: &str = "issue-closed";
+    pub const ISSUE_DRAFT: &str = "issue-draft";
+    pub const ISSUE_OPENED: &str = "issue-opened";
+    pub const ISSUE_REOPENED: &str = "issue-reopened";
+    pub const ISSUE_TRACKED_BY: &str = "issue-tracked-by";
+    pub const ISSUE_TRACKS: &str = "issue-tracks";
+    pub const ITALIC: &str = "italic";
+    pub const ITERATIONS: &str = "iterations";
+    pub const KEBAB_HORIZONTAL: &str = "kebab-horizontal";
+    pub const KEY: &str = "key";
+    pub const KEY_ASTERISK: &str = "key-asterisk";
+    pub const LAW: &str = "law";
+    pub const LIGHT_BULB: &str = "light-bulb";
+    pub const LINK: &str = "link";
+    pub const LINK_EXTERNAL: &str = "link-external";
+    pub const LIST_ORDERED: &str = "list-ordered";
+    pub const LIST_UNORDERED: &str = "list-unordered";
+    pub const LOCATION: &str = "location";
+    pub const LOCK: &str = "lock";
+    pub const LOG: &str = "log";
+    pub const LOGO_GIST: &str = "logo-gist";
+    pub const LOGO_GITHUB: &str = "logo-github";
+    pub const LOOP_: &str = "loop";
+    pub const MAIL: &str = "mail";
+    pub const MARK_GITHUB: &str = "mark-github";
+    pub const MARKDOWN: &str = "markdown";
+    pub const MAXIMIZE: &str = "maximize";
+    pub const MCP: &str = "mcp";
+    pub const MEGAPHONE: &str = "megaphone";
+    pub const MENTION: &str = "mention";
+    pub const METER: &str = "meter";
+    pub const MILESTONE: &str = "milestone";
+    pub const MINIMIZE: &str = "minimize";
+    pub const MIRROR: &str = "mirror";
+    pub const MOON: &str = "moon";
+    pub const MORTAR_BOARD: &str = "mortar-board";
+    pub const MOVE_TO_BOTTOM: &str = "move-to-bottom";
+    pub const MOVE_TO_END: &str = "move-to-end";
+    pub const MOVE_TO_START: &str = "move-to-start";
+    pub const MOVE_TO_TOP: &str = "move-to-top";
+    pub const MULTI_SELECT: &str = "multi-select";
+    pub const MUTE: &str = "mute";
+    pub const NO_ENTRY: &str = "no-entry";
+    pub const NODE: &str = "node";
+    pub const NORTH_STAR: &str = "north-star";
+    pub const NOTE: &str = "note";
+    pub const NUMBER: &str = "number";
+    pub const ORGANIZATION: &str = "organization";
+    pub const PACKAGE: &str = "package";
+    pub const PACKAGE_DEPENDENCIES: &str = "package-dependencies";
+    pub const PACKAGE_DEPENDENTS: &str = "package-dependents";
+    pub const PAINTBRUSH: &str = "paintbrush";
+    pub const PAPER_AIRPLANE: &str = "paper-airplane";
+    pub const PAPERCLIP: &str = "paperclip";
+    pub const PASSKEY: &str = "passkey";
+    pub const PASTE: &str = "paste";
+    pub const PAUSE: &str = "pause";
+    pub const PENCIL: &str = "pencil";
+    pub const PENCIL_AI: &str = "pencil-ai";
+    pub const PEOPLE: &str = "people";
+    pub const PERSON: &str = "person";
+    pub const PERSON_ADD: &str = "person-add";
+    pub const PIN: &str = "pin";
+    pub const PIN_SLASH: &str = "pin-slash";
+    pub const PIVOT_COLUMN: &str = "pivot-column";
+    pub const PLAY: &str = "play";
+    pub const PLUG: &str = "plug";
+    pub const PLUS: &str = "plus";
+    pub const PLUS_CIRCLE: &str = "plus-circle";
+    pub const PROJECT: &str = "project";
+    pub const PROJECT_ROADMAP: &str = "project-roadmap";
+    pub const PROJECT_SYMLINK: &str = "project-symlink";
+    pub const PROJECT_TEMPLATE: &str = "project-template";
+    pub const PULSE: &str = "pulse";
+    pub const QUESTION: &str = "question";
+    pub const QUOTE: &str = "quote";
+    pub const READ: &str = "read";
+    pub const REDO: &str = "redo";
+    pub const REL_FILE_PATH: &str = "rel-file-path";
+    pub const REPLY: &str = "reply";
+    pub const REPO: &str = "repo";
+    pub const REPO_CLONE: &str = "repo-clone";
+    pub const REPO_DELETE: &str = "repo-delete";
+    #[cfg(feature = "octicons-tiny")]
+    pub const REPO_DELETED: &str = "repo-deleted";
+    pub const REPO_FORKED: &str = "repo-forked";
+    pub const REPO_LOCKED: &str = "repo-locked";
+    pub const REPO_PULL: &str = "repo-pull";
+    pub const REPO_PUSH: &str = "repo-push";
+    pub const REPO_TEMPLATE: &str = "repo-template";
+    pub const REPORT: &str = "report";
+    pub const ROCKET: &str = "rocket";
+    pub const ROWS: &str = "rows";
+    pub const RSS: &str = "rss";
+    pub const RUBY: &str = "ruby";
+    pub const SCREEN_FULL: &str = "screen-full";
+    pub const SCREEN_NORMAL: &str = "screen-normal";
+    pub const SEARCH: &str = "search";
+    pub const SERVER: &str = "server";
+    pub const SHARE: &str = "share";
+    pub const SHARE_ANDROID: &str = "share-android";
+    pub const SHIELD: &str = "shield";
+    pub const SHIELD_CHECK: &str = "shield-check";
+    pub const SHIELD_LOCK: &str = "shield-lock";
+    pub const SHIELD_SLASH: &str = "shield-slash";
+    pub const SHIELD_X: &str = "shield-x";
+    pub const SIDEBAR_COLLAPSE: &str = "sidebar-collapse";
+    pub const SIDEBAR_EXPAND: &str = "sidebar-expand";
+    pub const SIGN_IN: &str = "sign-in";
+    pub const SIGN_OUT: &str = "sign-out";
+    pub const SINGLE_SELECT: &str = "single-select";
+    pub const SKIP: &str = "skip";
+    pub const SLIDERS: &str = "sliders";
+    pub const SMILEY: &str = "smiley";
+    pub const SMILEY_FROWN: &str = "smiley-frown";
+    pub const SMILEY_FRUSTRATED: &str = "smiley-frustrated";
+    pub const SMILEY_GRIN: &str = "smiley-grin";
+    pub const SMILEY_NEUTRAL: &str = "smiley-neutral";
+    pub const SORT_ASC: &str = "sort-asc";
+    pub const SORT_DESC: &str = "sort-desc";
+    pub const SPACE: &str = "space";
+    pub const SPACING_LARGE: &str = "spacing-large";
+    pub const SPACING_MEDIUM: &str = "spacing-medium";
+    pub const SPACING_SMALL: &str = "spacing-small";
+    pub const SPARKLE: &str = "sparkle";
+    pub const SPARKLES: &str = "sparkles";
+    pub const SPLIT_VIEW: &str = "split-view";
+    pub const SPONSOR_TIERS: &str = "sponsor-tiers";
+    pub const SQUARE: &str = "square";
+    pub const SQUARE_CIRCLE: &str = "square-circle";
+    pub const SQUIRREL: &str = "squirrel";
+    pub const STACK: &str = "stack";
+    pub const STAR: &str = "star";
+    pub const STOP: &str = "stop";
+    pub const STOPWATCH: &str = "stopwatch";
+    pub const STRIKETHROUGH: &str = "strikethrough";
+    pub const SUN: &str = "sun";
+    pub const SYNC: &str = "sync";
+    pub const TAB: &str = "tab";
+    pub const TAB_EXTERNAL: &str = "tab-external";
+    pub const TABLE: &str = "table";
+    pub const TAG: &str = "tag";
+    pub const TASKLIST: &str = "tasklist";
+    pub const TELESCOPE: &str = "telescope";
+    pub const TERMINAL: &str = "terminal";
+    pub const THREE_BARS: &str = "three-bars";
+    pub const THUMBSDOWN: &str = "thumbsdown";
+    pub const THUMBSUP: &str = "thumbsup";
+    pub const TOOLS: &str = "tools";
+    pub const TRACKED_BY_CLOSED_COMPLETED: &str = "tracked-by-closed-completed";
+    pub const TRACKED_BY_CLOSED_NOT_PLANNED: &str = "tracked-by-closed-not-planned";
+    pub const TRASH: &str = "trash";
+    pub const TRIANGLE_DOWN: &str = "triangle-down";
+    pub const TRIANGLE_LEFT: &str = "triangle-left";
+    pub const TRIANGLE_RIGHT: &str = "triangle-right";
+    pub const TRIANGLE_UP: &str = "triangle-up";
+    pub const TROPHY: &str = "trophy";
+    pub const TYPOGRAPHY: &str = "typography";
+    pub const UNDO: &str = "undo";
+    pub const UNFOLD: &str = "unfold";
+    pub const UNLINK: &str = "unlink";
+    pub const UNLOCK: &str = "unlock";
+    pub const UNMUTE: &str = "unmute";
+    pub const UNREAD: &str = "unread";
+    pub const UNVERIFIED: &str = "unverified";
+    pub const UNWRAP: &str = "unwrap";
+    pub const UPLOAD: &str = "upload";
+    pub const VERIFIED: &str = "verified";
+    pub const VERSIONS: &str = "versions";
+    pub const VIDEO: &str = "video";
+    #[cfg(feature = "octicons-tiny")]
+    pub const VSCODE: &str = "vscode";
+    #[cfg(feature = "octicons-tiny")]
+    pub const WEBHOOK: &str = "webhook";
+    pub const WORKFLOW: &str = "workflow";
+    pub const WRAP: &str = "wrap";
+    pub const X: &str = "x";
+    pub const X_CIRCLE: &str = "x-circle";
+    pub const ZAP: &str = "zap";
+    pub const ZOOM_IN: &str = "zoom-in";
+    pub const ZOOM_OUT: &str = "zoom-out";
+}
+
+pub(crate) const ICON_NAMES_LOWERCASE: &[(&str, &str)] = &[
+    ("accessibility", "accessibility"),
+    ("accessibility-inset", "accessibility-inset"),
+    ("agent", "agent"),
+    ("ai-model", "ai-model"),
+    ("alert", "alert"),
+    ("apps", "apps"),
+    ("archive", "archive"),
+    ("arrow-both", "arrow-both"),
+    ("arrow-down", "arrow-down"),
+    ("arrow-down-left", "arrow-down-left"),
+    ("arrow-down-right", "arrow-down-right"),
+    ("arrow-left", "arrow-left"),
+    ("arrow-right", "arrow-right"),
+    ("arrow-switch", "arrow-switch"),
+    ("arrow-up", "arrow-up"),
+    ("arrow-up-left", "arrow-up-left"),
+    ("arrow-up-right", "arrow-up-right"),
+    ("beaker", "beaker"),
+    ("bell", "bell"),
+    ("bell-slash", "bell-slash"),
+    ("blocked", "blocked"),
+    ("bold", "bold"),
+    ("book", "book"),
+    ("bookmark", "bookmark"),
+    #[cfg(feature = "octicons-tiny")]
+    ("bookmark-filled", "bookmark-filled"),
+    ("bookmark-slash", "bookmark-slash"),
+    ("boolean-off", "boolean-off"),
+    ("boolean-on", "boolean-on"),
+    ("briefcase", "briefcase"),
+    ("broadcast", "broadcast"),
+    ("browser", "browser"),
+    ("bug", "bug"),
+    ("cache", "cache"),
+    ("calendar", "calendar"),
+    ("check", "check"),
+    ("check-circle", "check-circle"),
+    ("checkbox", "checkbox"),
+    ("checklist", "checklist"),
+    ("chevron-down", "chevron-down"),
+    ("chevron-left", "chevron-left"),
+    ("chevron-right", "chevron-right"),
+    ("chevron-up", "chevron-up"),
+    ("circle", "circle"),
+    ("circle-slash", "circle-slash"),
+    ("clock", "clock"),
+    ("cloud", "cloud"),
+    ("cloud-offline", "cloud-offline"),
+    ("code", "code"),
+    ("code-of-conduct", "code-of-conduct"),
+    ("code-review", "code-review"),
+    ("code-square", "code-square"),
+    ("codescan", "codescan"),
+    ("codescan-checkmark", "codescan-checkmark"),
+    ("codespaces", "codespaces"),
+    ("columns", "columns"),
+    ("command-palette", "command-palette"),
+    ("comment", "comment"),
+    ("comment-ai", "comment-ai"),
+    ("comment-discussion", "comment-discussion"),
+    ("compose", "compose"),
+    ("container", "container"),
+    ("copilot", "copilot"),
+    #[cfg(feature = "octicons-tiny")]
+    ("copilot-error", "copilot-error"),
+    #[cfg(feature = "octicons-tiny")]
+    ("copilot-warning", "copilot-warning"),
+    ("copy", "copy"),
+    ("cpu", "cpu"),
+    ("credit-card", "credit-card"),
+    ("cross-reference", "cross-reference"),
+    ("crosshairs", "crosshairs"),
+    ("dash", "dash"),
+    ("database", "database"),
+    ("dependabot", "dependabot"),
+    ("desktop-download", "desktop-download"),
+    ("device-camera", "device-camera"),
+    ("device-camera-video", "device-camera-video"),
+    ("device-desktop", "device-desktop"),
+    ("device-mobile", "device-mobile"),
+    ("devices", "devices"),
+    ("diamond", "diamond"),
+    ("dice", "dice"),
+    ("diff", "diff"),
+    ("diff-added", "diff-added"),
+    ("diff-ignored", "diff-ignored"),
+    ("diff-modified", "diff-modified"),
+    ("diff-removed", "diff-removed"),
+    ("diff-renamed", "diff-renamed"),
+    ("discussion-closed", "discussion-closed"),
+    ("discussion-duplicate", "discussion-duplicate"),
+    ("discussion-outdated", "discussion-outdated"),
+    ("dot", "dot"),
+    ("download", "download"),
+    ("duplicate", "duplicate"),
+    ("ellipsis", "ellipsis"),
+    ("exclamation", "exclamation"),
+    ("eye", "eye"),
+    ("eye-closed", "eye-closed"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-discussion", "feed-discussion"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-forked", "feed-forked"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-heart", "feed-heart"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-issue-closed", "feed-issue-closed"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-issue-draft", "feed-issue-draft"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-issue-open", "feed-issue-open"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-issue-reopen", "feed-issue-reopen"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-merged", "feed-merged"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-person", "feed-person"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-plus", "feed-plus"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-public", "feed-public"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-pull-request-closed", "feed-pull-request-closed"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-pull-request-draft", "feed-pull-request-draft"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-pull-request-open", "feed-pull-request-open"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-repo", "feed-repo"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-rocket", "feed-rocket"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-star", "feed-star"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-tag", "feed-tag"),
+    #[cfg(feature = "octicons-tiny")]
+    ("feed-trophy", "feed-trophy"),
+    ("file", "file"),
+    ("file-added", "file-added"),
+    ("file-badge", "file-badge"),
+    ("file-binary", "file-binary"),
+    ("file-check", "file-check"),
+    ("file-code", "file-code"),
+    ("file-diff", "file-diff"),
+    ("file-directory", "file-directory"),
+    ("file-directory-open", "file-directory-open"),
+    ("file-directory-symlink", "file-directory-symlink"),
+    ("file-media", "file-media"),
+    ("file-moved", "file-moved"),
+    ("file-removed", "file-removed"),
+    ("file-submodule", "file-submodule"),
+    ("file-symlink-file", "file-symlink-file"),
+    ("file-zip", "file-zip"),
+    ("filter", "filter"),
+    ("filter-remove", "filter-remove"),
+    ("fiscal-host", "fiscal-host"),
+    ("flame", "flame"),
+    ("flowchart", "flowchart"),
+    ("focus-center", "focus-center"),
+    ("fold", "fold"),
+    ("fold-down", "fold-down"),
+    ("fold-up", "fold-up"),
+    ("gear", "gear"),
+    ("gift", "gift"),
+    ("git-branch", "git-branch"),
+    ("git-branch-check", "git-branch-check"),
+    ("git-commit", "git-commit"),
+    ("git-compare", "git-compare"),
+    ("git-merge", "git-merge"),
+    ("git-merge-queue", "git-merge-queue"),
+    ("git-pull-request", "git-pull-request"),
+    ("git-pull-request-closed", "git-pull-request-closed"),
+    ("git-pull-request-draft", "git-pull-request-draft"),
+    ("globe", "globe"),
+    ("goal", "goal"),
+    ("grabber", "grabber"),
+    ("graph", "graph"),
+    ("graph-bar-horizontal", "graph-bar-horizontal"),
+    ("graph-bar-vertical", "graph-bar-vertical"),
+    ("hash", "hash"),
+    ("heading", "heading"),
+    ("heart", "heart"),
+    ("history", "history"),
+    ("home", "home"),
+    ("horizontal-rule", "horizontal-rule"),
+    ("hourglass", "hourglass"),
+    ("hubot", "hubot"),
+    ("id-badge", "id-badge"),
+    ("image", "image"),
+    ("inbox", "inbox"),
+    ("infinity", "infinity"),
+    ("info", "info"),
+    ("issue-closed", "issue-closed"),
+    ("issue-draft", "issue-draft"),
+    ("issue-opened", "issue-opened"),
+    ("issue-reopened", "issue-reopened"),
+    ("issue-tracked-by", "issue-tracked-by"),
+    ("issue-tracks", "issue-tracks"),
+    ("italic", "italic"),
+    ("iterations", "iterations"),
+    ("kebab-horizontal", "kebab-horizontal"),
+    ("key", "key"),
+    ("key-asterisk", "key-asterisk"),
+    ("law", "law"),
+    ("light-bulb", "light-bulb"),
+    ("link", "link"),
+    ("link-external", "link-external"),
+    ("list-ordered", "list-ordered"),
+    ("list-unordered", "list-unordered"),
+    ("location", "location"),
+    ("lock", "lock"),
+    ("log", "log"),
+    ("logo-gist", "logo-gist"),
+    ("logo-github", "logo-github"),
+    ("loop", "loop"),
+    ("mail", "mail"),
+    ("mark-github", "mark-github"),
+    ("markdown", "markdown"),
+    ("maximize", "maximize"),
+    ("mcp", "mcp"),
+    ("megaphone", "megaphone"),
+    ("mention", "mention"),
+    ("meter", "meter"),
+    ("milestone", "milestone"),
+    ("minimize", "minimize"),
+    ("mirror", "mirror"),
+    ("moon", "moon"),
+    ("mortar-board", "mortar-board"),
+    ("move-to-bottom", "move-to-bottom"),
+    ("move-to-end", "move-to-end"),
+    ("move-to-start", "move-to-start"),
+    ("move-to-top", "move-to-top"),
+    ("multi-select", "multi-select"),
+    ("mute", "mute"),
+    ("no-entry", "no-entry"),
+    ("node", "node"),
+    ("north-star", "north-star"),
+    ("note", "note"),
+    ("number", "number"),
+    ("organization", "organization"),
+    ("package", "package"),
+    ("package-dependencies", "package-dependencies"),
+    ("package-dependents", "package-dependents"),
+    ("paintbrush", "paintbrush"),
+    ("paper-airplane", "paper-airplane"),
+    ("paperclip", "paperclip"),
+    ("passkey", "passkey"),
+    ("paste", "paste"),
+    ("pause", "pause"),
+    ("pencil", "pencil"),
+    ("pencil-ai", "pencil-ai"),
+    ("people", "people"),
+    ("person", "person"),
+    ("person-add", "person-add"),
+    ("pin", "pin"),
+    ("pin-slash", "pin-slash"),
+    ("pivot-column", "pivot-column"),
+    ("play", "play"),
+    ("plug", "plug"),
+    ("plus", "plus"),
+    ("plus-circle", "plus-circle"),
+    ("project", "project"),
+    ("project-roadmap", "project-roadmap"),
+    ("project-symlink", "project-symlink"),
+    ("project-template", "project-template"),
+    ("pulse", "pulse"),
+    ("question", "question"),
+    ("quote", "quote"),
+    ("read", "read"),
+    ("redo", "redo"),
+    ("rel-file-path", "rel-file-path"),
+    ("reply", "reply"),
+    ("repo", "repo"),
+    ("repo-clone", "repo-clone"),
+    ("repo-delete", "repo-delete"),
+    #[cfg(feature = "octicons-tiny")]
+    ("repo-deleted", "repo-deleted"),
+    ("repo-forked", "repo-forked"),
+    ("repo-locked", "repo-locked"),
+    ("repo-pull", "repo-pull"),
+    ("repo-push", "repo-push"),
+    ("repo-template", "repo-template"),
+    ("report", "report"),
+    ("rocket", "rocket"),
+    ("rows", "rows"),
+    ("rss", "rss"),
+    ("ruby", "ruby"),
+    ("screen-full", "screen-full"),
+    ("screen-normal", "screen-normal"),
+    ("search", "search"),
+    ("server", "server"),
+    ("share", "share"),
+    ("share-android", "share-android"),
+    ("shield", "shield"),
+    ("shield-check", "shield-check"),
+    ("shield-lock", "shield-lock"),
+    ("shield-slash", "shield-slash"),
+    ("shield-x", "shield-x"),
+    ("sidebar-collapse", "sidebar-collapse"),
+    ("sidebar-expand", "sidebar-expand"),
+    ("sign-in", "sign-in"),
+    ("sign-out", "sign-out"),
+    ("single-select", "single-select"),
+    ("skip", "skip"),
+    ("sliders", "sliders"),
+    ("smiley", "smiley"),
+    ("smiley-frown", "smiley-frown"),
+    ("smiley-frustrated", "smiley-frustrated"),
+    ("smiley-grin", "smiley-grin"),
+    ("smiley-neutral", "smiley-neutral"),
+    ("sort-asc", "sort-asc"),
+    ("sort-desc", "sort-desc"),
+    ("space", "space"),
+    ("spacing-large", "spacing-large"),
+    ("spacing-medium", "spacing-medium"),
+    ("spacing-small", "spacing-small"),
+    ("sparkle", "sparkle"),
+    ("sparkles", "sparkles"),
+    ("split-view", "split-view"),
+    ("sponsor-tiers", "sponsor-tiers"),
+    ("square", "square"),
+    ("square-circle", "square-circle"),
+    ("squirrel", "squirrel"),
+    ("stack", "stack"),
+    ("star", "star"),
+    ("stop", "stop"),
+    ("stopwatch", "stopwatch"),
+    ("strikethrough", "strikethrough"),
+    ("sun", "sun"),
+    ("sync", "sync"),
+    ("tab", "tab"),
+    ("tab-external", "tab-external"),
+    ("table", "table"),
+    ("tag", "tag"),
+    ("tasklist", "tasklist"),
+    ("telescope", "telescope"),
+    ("terminal", "terminal"),
+    ("three-bars", "three-bars"),
+    ("thumbsdown", "thumbsdown"),
+    ("thumbsup", "thumbsup"),
+    ("tools", "tools"),
+    ("tracked-by-closed-completed", "tracked-by-closed-completed"),
+    (
+        "tracked-by-closed-not-planned",
+        "tracked-by-closed-not-planned",
+    ),
+    ("trash", "trash"),
+    ("triangle-down", "triangle-down"),
+    ("triangle-left", "triangle-left"),
+    ("triangle-right", "triangle-right"),
+    ("triangle-up", "triangle-up"),
+    ("trophy", "trophy"),
+    ("typography", "typography"),
+    ("undo", "undo"),
+    ("unfold", "unfold"),
+    ("unlink", "unlink"),
+    ("unlock", "unlock"),
+    ("unmute", "unmute"),
+    ("unread", "unread"),
+    ("unverified", "unverified"),
+    ("unwrap", "unwrap"),
+    ("upload", "upload"),
+    ("verified", "verified"),
+    ("versions", "versions"),
+    ("video", "video"),
+    #[cfg(feature = "octicons-tiny")]
+    ("vscode", "vscode"),
+    #[cfg(feature = "octicons-tiny")]
+    ("webhook", "webhook"),
+    ("workflow", "workflow"),
+    ("wrap", "wrap"),
+    ("x", "x"),
+    ("x-circle", "x-circle"),
+    ("zap", "zap"),
+    ("zoom-in", "zoom-in"),
+    ("zoom-out", "zoom-out"),
+];
+
+/// `(variant, codepoint, name)` for every mapped glyph, sorted for `binary_search`.
+pub const ICON_BY_CODEPOINT: &[(VariantKey, u32, &str)] = &[
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE000,
+        "accessibility",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE001,
+        "accessibility-inset",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE002,
+        "agent",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE003,
+        "ai-model",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE004,
+        "alert",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE006,
+        "apps",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE007,
+        "archive",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE008,
+        "arrow-both",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE009,
+        "arrow-down",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE00A,
+        "arrow-down-left",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE00B,
+        "arrow-down-right",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE00C,
+        "arrow-left",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE00D,
+        "arrow-right",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE00E,
+        "arrow-switch",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE00F,
+        "arrow-up",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE010,
+        "arrow-up-left",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE011,
+        "arrow-up-right",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE012,
+        "beaker",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE013,
+        "bell",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE015,
+        "bell-slash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE016,
+        "blocked",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE017,
+        "bold",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE018,
+        "book",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE019,
+        "bookmark",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE01A,
+        "bookmark-filled",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE01B,
+        "bookmark-slash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE01D,
+        "boolean-off",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE01E,
+        "boolean-on",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE01F,
+        "briefcase",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE020,
+        "broadcast",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE021,
+        "browser",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE022,
+        "bug",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE023,
+        "cache",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE024,
+        "calendar",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE025,
+        "check",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE026,
+        "check-circle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE028,
+        "checkbox",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE02A,
+        "checklist",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE02B,
+        "chevron-down",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE02C,
+        "chevron-left",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE02D,
+        "chevron-right",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE02E,
+        "chevron-up",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE02F,
+        "circle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE030,
+        "circle-slash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE031,
+        "clock",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE033,
+        "cloud",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE034,
+        "cloud-offline",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE035,
+        "code",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE036,
+        "code-of-conduct",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE037,
+        "code-review",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE038,
+        "code-square",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE039,
+        "codescan",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE03A,
+        "codescan-checkmark",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE03B,
+        "codespaces",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE03C,
+        "columns",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE03D,
+        "command-palette",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE03E,
+        "comment",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE03F,
+        "comment-ai",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE040,
+        "comment-discussion",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE041,
+        "compose",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE042,
+        "container",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE043,
+        "copilot",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE044,
+        "copilot-error",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE045,
+        "copilot-warning",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE046,
+        "copy",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE047,
+        "cpu",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE048,
+        "credit-card",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE049,
+        "cross-reference",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE04A,
+        "crosshairs",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE04B,
+        "dash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE04C,
+        "database",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE04D,
+        "dependabot",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE04E,
+        "desktop-download",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE04F,
+        "device-camera",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE050,
+        "device-camera-video",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE051,
+        "device-desktop",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE052,
+        "device-mobile",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE053,
+        "devices",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE054,
+        "diamond",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE055,
+        "dice",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE056,
+        "diff",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE057,
+        "diff-added",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE058,
+        "diff-ignored",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE059,
+        "diff-modified",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE05A,
+        "diff-removed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE05B,
+        "diff-renamed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE05C,
+        "discussion-closed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE05D,
+        "discussion-duplicate",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE05E,
+        "discussion-outdated",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE05F,
+        "dot",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE061,
+        "download",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE062,
+        "duplicate",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE063,
+        "ellipsis",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE064,
+        "exclamation",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE065,
+        "eye",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE066,
+        "eye-closed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE067,
+        "feed-discussion",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE068,
+        "feed-forked",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE069,
+        "feed-heart",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE06A,
+        "feed-issue-closed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE06B,
+        "feed-issue-draft",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE06C,
+        "feed-issue-open",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE06D,
+        "feed-issue-reopen",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE06E,
+        "feed-merged",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE06F,
+        "feed-person",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE070,
+        "feed-plus",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE071,
+        "feed-public",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE072,
+        "feed-pull-request-closed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE073,
+        "feed-pull-request-draft",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE074,
+        "feed-pull-request-open",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE075,
+        "feed-repo",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE076,
+        "feed-rocket",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE077,
+        "feed-star",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE078,
+        "feed-tag",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE079,
+        "feed-trophy",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE07A,
+        "file",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE07B,
+        "file-added",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE07C,
+        "file-badge",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE07D,
+        "file-binary",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE07E,
+        "file-check",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE07F,
+        "file-code",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE080,
+        "file-diff",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE081,
+        "file-directory",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE084,
+        "file-directory-symlink",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE085,
+        "file-media",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE086,
+        "file-moved",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE087,
+        "file-removed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE088,
+        "file-submodule",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE089,
+        "file-symlink-file",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE08A,
+        "file-zip",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE08B,
+        "filter",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE08C,
+        "filter-remove",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE08D,
+        "fiscal-host",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE08E,
+        "flame",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE08F,
+        "flowchart",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE090,
+        "focus-center",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE091,
+        "fold",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE092,
+        "fold-down",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE093,
+        "fold-up",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE094,
+        "gear",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE095,
+        "gift",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE096,
+        "git-branch",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE097,
+        "git-branch-check",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE098,
+        "git-commit",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE099,
+        "git-compare",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE09A,
+        "git-merge",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE09B,
+        "git-merge-queue",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE09C,
+        "git-pull-request",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE09D,
+        "git-pull-request-closed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE09E,
+        "git-pull-request-draft",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE09F,
+        "globe",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A0,
+        "goal",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A1,
+        "grabber",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A2,
+        "graph",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A3,
+        "graph-bar-horizontal",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A4,
+        "graph-bar-vertical",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A5,
+        "hash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A6,
+        "heading",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A7,
+        "heart",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0A9,
+        "history",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0AA,
+        "home",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0AC,
+        "horizontal-rule",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0AD,
+        "hourglass",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0AE,
+        "hubot",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0AF,
+        "id-badge",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B0,
+        "image",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B1,
+        "inbox",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B3,
+        "infinity",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B4,
+        "info",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B5,
+        "issue-closed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B6,
+        "issue-draft",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B7,
+        "issue-opened",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B8,
+        "issue-reopened",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0B9,
+        "issue-tracked-by",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0BA,
+        "issue-tracks",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0BB,
+        "italic",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0BC,
+        "iterations",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0BD,
+        "kebab-horizontal",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0BE,
+        "key",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0BF,
+        "key-asterisk",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C0,
+        "law",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C1,
+        "light-bulb",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C2,
+        "link",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C3,
+        "link-external",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C4,
+        "list-ordered",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C5,
+        "list-unordered",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C6,
+        "location",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C7,
+        "lock",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C8,
+        "log",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0C9,
+        "logo-gist",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0CA,
+        "logo-github",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0CB,
+        "loop",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0CC,
+        "mail",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0CD,
+        "mark-github",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0CE,
+        "markdown",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0CF,
+        "maximize",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D0,
+        "mcp",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D1,
+        "megaphone",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D2,
+        "mention",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D3,
+        "meter",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D4,
+        "milestone",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D5,
+        "minimize",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D6,
+        "mirror",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D7,
+        "moon",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D8,
+        "mortar-board",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0D9,
+        "move-to-bottom",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0DA,
+        "move-to-end",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0DB,
+        "move-to-start",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0DC,
+        "move-to-top",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0DD,
+        "multi-select",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0DE,
+        "mute",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0DF,
+        "no-entry",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E0,
+        "node",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E1,
+        "north-star",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E2,
+        "note",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E3,
+        "number",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E4,
+        "organization",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E5,
+        "package",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E6,
+        "package-dependencies",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E7,
+        "package-dependents",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E8,
+        "paintbrush",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0E9,
+        "paper-airplane",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0EA,
+        "paperclip",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0EC,
+        "paste",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0ED,
+        "pause",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0EE,
+        "pencil",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0EF,
+        "pencil-ai",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F0,
+        "people",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F1,
+        "person",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F2,
+        "person-add",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F4,
+        "pin",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F5,
+        "pin-slash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F6,
+        "pivot-column",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F7,
+        "play",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F8,
+        "plug",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0F9,
+        "plus",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0FA,
+        "plus-circle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0FB,
+        "project",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0FC,
+        "project-roadmap",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0FD,
+        "project-symlink",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0FE,
+        "project-template",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE0FF,
+        "pulse",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE100,
+        "question",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE101,
+        "quote",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE102,
+        "read",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE103,
+        "redo",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE104,
+        "rel-file-path",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE105,
+        "reply",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE106,
+        "repo",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE107,
+        "repo-clone",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE108,
+        "repo-deleted",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE109,
+        "repo-forked",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE10A,
+        "repo-locked",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE10B,
+        "repo-pull",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE10C,
+        "repo-push",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE10D,
+        "repo-template",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE10E,
+        "report",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE10F,
+        "rocket",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE110,
+        "rows",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE111,
+        "rss",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE112,
+        "ruby",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE113,
+        "screen-full",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE114,
+        "screen-normal",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE115,
+        "search",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE116,
+        "server",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE117,
+        "share",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE118,
+        "share-android",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE119,
+        "shield",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE11A,
+        "shield-check",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE11B,
+        "shield-lock",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE11C,
+        "shield-slash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE11D,
+        "shield-x",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE11E,
+        "sidebar-collapse",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE11F,
+        "sidebar-expand",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE120,
+        "sign-in",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE121,
+        "sign-out",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE122,
+        "single-select",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE123,
+        "skip",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE125,
+        "sliders",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE126,
+        "smiley",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE127,
+        "smiley-frown",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE128,
+        "smiley-frustrated",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE129,
+        "smiley-grin",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE12A,
+        "smiley-neutral",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE12B,
+        "sort-asc",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE12C,
+        "sort-desc",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE12D,
+        "space",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE12E,
+        "spacing-large",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE12F,
+        "spacing-medium",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE130,
+        "spacing-small",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE131,
+        "sparkle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE134,
+        "split-view",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE135,
+        "sponsor-tiers",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE136,
+        "square",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE137,
+        "square-circle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE139,
+        "squirrel",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE13A,
+        "stack",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE13B,
+        "star",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE13D,
+        "stop",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE13E,
+        "stopwatch",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE13F,
+        "strikethrough",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE140,
+        "sun",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE141,
+        "sync",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE142,
+        "tab",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE143,
+        "tab-external",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE144,
+        "table",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE145,
+        "tag",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE146,
+        "tasklist",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE147,
+        "telescope",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE149,
+        "terminal",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE14A,
+        "three-bars",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE14B,
+        "thumbsdown",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE14C,
+        "thumbsup",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE14D,
+        "tools",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE14E,
+        "tracked-by-closed-completed",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE14F,
+        "tracked-by-closed-not-planned",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE150,
+        "trash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE151,
+        "triangle-down",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE152,
+        "triangle-left",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE153,
+        "triangle-right",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE154,
+        "triangle-up",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE155,
+        "trophy",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE156,
+        "typography",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE157,
+        "undo",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE158,
+        "unfold",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE159,
+        "unlink",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE15A,
+        "unlock",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE15B,
+        "unmute",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE15C,
+        "unread",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE15D,
+        "unverified",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE15E,
+        "unwrap",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE15F,
+        "upload",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE160,
+        "verified",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE161,
+        "versions",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE162,
+        "video",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE163,
+        "vscode",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE164,
+        "webhook",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE165,
+        "workflow",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE166,
+        "wrap",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE167,
+        "x",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE168,
+        "x-circle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE16A,
+        "zap",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE16B,
+        "zoom-in",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        },
+        0xE16C,
+        "zoom-out",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE000,
+        "accessibility",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE001,
+        "accessibility-inset",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE002,
+        "agent",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE003,
+        "ai-model",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE004,
+        "alert",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE007,
+        "apps",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE008,
+        "archive",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE009,
+        "arrow-both",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00A,
+        "arrow-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00B,
+        "arrow-down-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00C,
+        "arrow-down-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00D,
+        "arrow-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00E,
+        "arrow-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE00F,
+        "arrow-switch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE010,
+        "arrow-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE011,
+        "arrow-up-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE012,
+        "arrow-up-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE013,
+        "beaker",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE014,
+        "bell",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE016,
+        "bell-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE017,
+        "blocked",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE018,
+        "bold",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE019,
+        "book",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01A,
+        "bookmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01C,
+        "bookmark-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01E,
+        "boolean-off",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE01F,
+        "boolean-on",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE020,
+        "briefcase",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE021,
+        "broadcast",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE022,
+        "browser",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE023,
+        "bug",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE024,
+        "cache",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE025,
+        "calendar",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE026,
+        "check",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE027,
+        "check-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02A,
+        "checkbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02C,
+        "checklist",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE02E,
+        "chevron-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE030,
+        "chevron-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE032,
+        "chevron-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE034,
+        "chevron-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE035,
+        "circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE036,
+        "circle-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE037,
+        "clock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE039,
+        "cloud",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03A,
+        "cloud-offline",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03B,
+        "code",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03C,
+        "code-of-conduct",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03D,
+        "code-review",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03E,
+        "code-square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE03F,
+        "codescan",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE040,
+        "codescan-checkmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE041,
+        "codespaces",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE042,
+        "columns",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE043,
+        "command-palette",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE044,
+        "comment",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE045,
+        "comment-ai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE046,
+        "comment-discussion",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE047,
+        "compose",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE048,
+        "container",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE049,
+        "copilot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04C,
+        "copy",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04D,
+        "cpu",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04E,
+        "credit-card",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE04F,
+        "cross-reference",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE050,
+        "crosshairs",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE051,
+        "dash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE052,
+        "database",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE053,
+        "dependabot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE054,
+        "desktop-download",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE055,
+        "device-camera",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE056,
+        "device-camera-video",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE057,
+        "device-desktop",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE058,
+        "device-mobile",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE059,
+        "devices",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05A,
+        "diamond",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05B,
+        "dice",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05C,
+        "diff",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05D,
+        "diff-added",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05E,
+        "diff-ignored",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE05F,
+        "diff-modified",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE060,
+        "diff-removed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE061,
+        "diff-renamed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE062,
+        "discussion-closed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE063,
+        "discussion-duplicate",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE064,
+        "discussion-outdated",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE065,
+        "dot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE067,
+        "download",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE068,
+        "duplicate",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE069,
+        "ellipsis",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06A,
+        "exclamation",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06B,
+        "eye",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06C,
+        "eye-closed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06D,
+        "file",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06E,
+        "file-added",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE06F,
+        "file-badge",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE070,
+        "file-binary",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE071,
+        "file-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE072,
+        "file-code",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE073,
+        "file-diff",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE074,
+        "file-directory",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE077,
+        "file-directory-symlink",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE078,
+        "file-media",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE079,
+        "file-moved",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07A,
+        "file-removed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07B,
+        "file-submodule",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07C,
+        "file-symlink-file",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07D,
+        "file-zip",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07E,
+        "filter",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE07F,
+        "filter-remove",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE080,
+        "fiscal-host",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE081,
+        "flame",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE082,
+        "flowchart",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE083,
+        "focus-center",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE084,
+        "fold",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE085,
+        "fold-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE086,
+        "fold-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE087,
+        "gear",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE088,
+        "gift",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE089,
+        "git-branch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08A,
+        "git-branch-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08B,
+        "git-commit",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08C,
+        "git-compare",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08D,
+        "git-merge",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08E,
+        "git-merge-queue",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE08F,
+        "git-pull-request",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE090,
+        "git-pull-request-closed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE091,
+        "git-pull-request-draft",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE092,
+        "globe",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE093,
+        "goal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE094,
+        "grabber",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE095,
+        "graph",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE096,
+        "graph-bar-horizontal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE097,
+        "graph-bar-vertical",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE098,
+        "hash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE099,
+        "heading",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09A,
+        "heart",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09C,
+        "history",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09D,
+        "home",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE09F,
+        "horizontal-rule",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A0,
+        "hourglass",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A1,
+        "hubot",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A2,
+        "id-badge",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A3,
+        "image",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A4,
+        "inbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A6,
+        "infinity",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A7,
+        "info",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A8,
+        "issue-closed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0A9,
+        "issue-draft",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AA,
+        "issue-opened",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AB,
+        "issue-reopened",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AC,
+        "issue-tracked-by",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AD,
+        "issue-tracks",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AE,
+        "italic",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0AF,
+        "iterations",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B0,
+        "kebab-horizontal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B1,
+        "key",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B2,
+        "key-asterisk",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B3,
+        "law",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B4,
+        "light-bulb",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B5,
+        "link",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B6,
+        "link-external",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B7,
+        "list-ordered",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B8,
+        "list-unordered",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0B9,
+        "location",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BA,
+        "lock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BB,
+        "log",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BC,
+        "logo-gist",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BD,
+        "logo-github",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BE,
+        "loop",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0BF,
+        "mail",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C0,
+        "mark-github",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C1,
+        "markdown",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C2,
+        "maximize",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C3,
+        "mcp",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C4,
+        "megaphone",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C5,
+        "mention",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C6,
+        "meter",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C7,
+        "milestone",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C8,
+        "minimize",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0C9,
+        "mirror",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CA,
+        "moon",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CB,
+        "mortar-board",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CC,
+        "move-to-bottom",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CD,
+        "move-to-end",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CE,
+        "move-to-start",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0CF,
+        "move-to-top",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D0,
+        "multi-select",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D1,
+        "mute",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D2,
+        "no-entry",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D4,
+        "node",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D5,
+        "north-star",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D6,
+        "note",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D7,
+        "number",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D8,
+        "organization",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0D9,
+        "package",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DA,
+        "package-dependencies",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DB,
+        "package-dependents",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DC,
+        "paintbrush",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DD,
+        "paper-airplane",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0DE,
+        "paperclip",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E0,
+        "paste",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E1,
+        "pause",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E2,
+        "pencil",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E3,
+        "pencil-ai",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E4,
+        "people",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E5,
+        "person",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E6,
+        "person-add",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E8,
+        "pin",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0E9,
+        "pin-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EA,
+        "pivot-column",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EB,
+        "play",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EC,
+        "plug",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0ED,
+        "plus",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EE,
+        "plus-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0EF,
+        "project",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F0,
+        "project-roadmap",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F1,
+        "project-symlink",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F2,
+        "project-template",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F3,
+        "pulse",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F4,
+        "question",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F5,
+        "quote",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F6,
+        "read",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F7,
+        "redo",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F8,
+        "rel-file-path",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0F9,
+        "reply",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FA,
+        "repo",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FB,
+        "repo-clone",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FC,
+        "repo-delete",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FD,
+        "repo-forked",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FE,
+        "repo-locked",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE0FF,
+        "repo-pull",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE100,
+        "repo-push",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE101,
+        "repo-template",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE102,
+        "report",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE103,
+        "rocket",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE104,
+        "rows",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE105,
+        "rss",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE106,
+        "ruby",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE107,
+        "screen-full",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE108,
+        "screen-normal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE109,
+        "search",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10A,
+        "server",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10B,
+        "share",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10C,
+        "share-android",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10D,
+        "shield",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10E,
+        "shield-check",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE10F,
+        "shield-lock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE110,
+        "shield-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE111,
+        "shield-x",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE112,
+        "sidebar-collapse",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE113,
+        "sidebar-expand",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE114,
+        "sign-in",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE115,
+        "sign-out",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE116,
+        "single-select",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE117,
+        "skip",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE119,
+        "sliders",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11A,
+        "smiley",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11B,
+        "smiley-frown",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11C,
+        "smiley-frustrated",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11D,
+        "smiley-grin",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11E,
+        "smiley-neutral",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE11F,
+        "sort-asc",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE120,
+        "sort-desc",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE121,
+        "space",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE122,
+        "spacing-large",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE123,
+        "spacing-medium",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE124,
+        "spacing-small",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE125,
+        "sparkle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE128,
+        "split-view",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE129,
+        "sponsor-tiers",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12A,
+        "square",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12B,
+        "square-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12D,
+        "squirrel",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12E,
+        "stack",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE12F,
+        "star",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE131,
+        "stop",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE132,
+        "stopwatch",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE133,
+        "strikethrough",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE134,
+        "sun",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE135,
+        "sync",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE136,
+        "tab",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE137,
+        "tab-external",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE138,
+        "table",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE139,
+        "tag",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13A,
+        "tasklist",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13B,
+        "telescope",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13D,
+        "terminal",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13E,
+        "three-bars",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE13F,
+        "thumbsdown",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE140,
+        "thumbsup",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE141,
+        "tools",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE142,
+        "tracked-by-closed-completed",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE143,
+        "tracked-by-closed-not-planned",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE144,
+        "trash",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE145,
+        "triangle-down",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE146,
+        "triangle-left",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE147,
+        "triangle-right",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE148,
+        "triangle-up",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE149,
+        "trophy",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14A,
+        "typography",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14B,
+        "undo",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14C,
+        "unfold",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14D,
+        "unlink",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14E,
+        "unlock",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE14F,
+        "unmute",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE150,
+        "unread",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE151,
+        "unverified",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE152,
+        "unwrap",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE153,
+        "upload",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE154,
+        "verified",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE155,
+        "versions",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE156,
+        "video",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE159,
+        "workflow",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15A,
+        "wrap",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15C,
+        "x",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE15D,
+        "x-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE160,
+        "zap",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE161,
+        "zoom-in",
+    ),
+    (
+        VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        },
+        0xE162,
+        "zoom-out",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE005,
+        "alert",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE014,
+        "bell",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE01C,
+        "bookmark-slash",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE027,
+        "check-circle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE029,
+        "checkbox",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE032,
+        "clock",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE060,
+        "dot",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE082,
+        "file-directory",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE083,
+        "file-directory-open",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0A8,
+        "heart",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0AB,
+        "home",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0B2,
+        "inbox",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0EB,
+        "passkey",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE0F3,
+        "person",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE124,
+        "skip",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE132,
+        "sparkle",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE133,
+        "sparkles",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE138,
+        "square",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE13C,
+        "star",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE148,
+        "telescope",
+    ),
+    #[cfg(feature = "octicons-tiny")]
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Tiny,
+        },
+        0xE169,
+        "x-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE006,
+        "alert",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE015,
+        "bell",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01B,
+        "bookmark",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE01D,
+        "bookmark-slash",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE029,
+        "check-circle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE02B,
+        "checkbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE038,
+        "clock",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE066,
+        "dot",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE075,
+        "file-directory",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE076,
+        "file-directory-open",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09B,
+        "heart",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE09E,
+        "home",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0A5,
+        "inbox",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0DF,
+        "passkey",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE0E7,
+        "person",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE118,
+        "skip",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE126,
+        "sparkle",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE127,
+        "sparkles",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE12C,
+        "square",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE130,
+        "star",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE13C,
+        "telescope",
+    ),
+    (
+        VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        },
+        0xE15F,
+        "x-circle",
+    ),
+];
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct VariantInfo {
     pub key: VariantKey,
@@ -1179,6 +9453,7 @@ pub(crate) const VARIANTS: &[VariantInfo] = &[
     },
 ];
 
+/// `accessibility` — U+E000 (available in Regular, Regular/Tiny)
 const ICON_ACCESSIBILITY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1197,6 +9472,7 @@ const ICON_ACCESSIBILITY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `accessibility-inset` — U+E001 (available in Regular, Regular/Tiny)
 const ICON_ACCESSIBILITY_INSET_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1215,6 +9491,7 @@ const ICON_ACCESSIBILITY_INSET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `agent` — U+E002 (available in Regular, Regular/Tiny)
 const ICON_AGENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1233,6 +9510,7 @@ const ICON_AGENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `ai-model` — U+E003 (available in Regular, Regular/Tiny)
 const ICON_AI_MODEL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1251,6 +9529,7 @@ const ICON_AI_MODEL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `alert` — available in Filled U+E006, Regular U+E004, Regular/Tiny U+E004, Filled/Tiny U+E005
 const ICON_ALERT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1284,6 +9563,7 @@ const ICON_ALERT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `apps` — available in Regular U+E007, Regular/Tiny U+E006
 const ICON_APPS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1302,6 +9582,7 @@ const ICON_APPS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `archive` — available in Regular U+E008, Regular/Tiny U+E007
 const ICON_ARCHIVE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1320,6 +9601,7 @@ const ICON_ARCHIVE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-both` — available in Regular U+E009, Regular/Tiny U+E008
 const ICON_ARROW_BOTH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1338,6 +9620,7 @@ const ICON_ARROW_BOTH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down` — available in Regular U+E00A, Regular/Tiny U+E009
 const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1356,6 +9639,7 @@ const ICON_ARROW_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-left` — available in Regular U+E00B, Regular/Tiny U+E00A
 const ICON_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1374,6 +9658,7 @@ const ICON_ARROW_DOWN_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-down-right` — available in Regular U+E00C, Regular/Tiny U+E00B
 const ICON_ARROW_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1392,6 +9677,7 @@ const ICON_ARROW_DOWN_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-left` — available in Regular U+E00D, Regular/Tiny U+E00C
 const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1410,6 +9696,7 @@ const ICON_ARROW_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-right` — available in Regular U+E00E, Regular/Tiny U+E00D
 const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1428,6 +9715,7 @@ const ICON_ARROW_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-switch` — available in Regular U+E00F, Regular/Tiny U+E00E
 const ICON_ARROW_SWITCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1446,6 +9734,7 @@ const ICON_ARROW_SWITCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up` — available in Regular U+E010, Regular/Tiny U+E00F
 const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1464,6 +9753,7 @@ const ICON_ARROW_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-left` — available in Regular U+E011, Regular/Tiny U+E010
 const ICON_ARROW_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1482,6 +9772,7 @@ const ICON_ARROW_UP_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `arrow-up-right` — available in Regular U+E012, Regular/Tiny U+E011
 const ICON_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1500,6 +9791,7 @@ const ICON_ARROW_UP_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `beaker` — available in Regular U+E013, Regular/Tiny U+E012
 const ICON_BEAKER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1518,6 +9810,7 @@ const ICON_BEAKER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bell` — available in Filled U+E015, Regular U+E014, Regular/Tiny U+E013, Filled/Tiny U+E014
 const ICON_BELL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1551,6 +9844,7 @@ const ICON_BELL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bell-slash` — available in Regular U+E016, Regular/Tiny U+E015
 const ICON_BELL_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1569,6 +9863,7 @@ const ICON_BELL_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `blocked` — available in Regular U+E017, Regular/Tiny U+E016
 const ICON_BLOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1587,6 +9882,7 @@ const ICON_BLOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bold` — available in Regular U+E018, Regular/Tiny U+E017
 const ICON_BOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1605,6 +9901,7 @@ const ICON_BOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `book` — available in Regular U+E019, Regular/Tiny U+E018
 const ICON_BOOK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1623,6 +9920,7 @@ const ICON_BOOK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bookmark` — available in Filled U+E01B, Regular U+E01A, Regular/Tiny U+E019
 const ICON_BOOKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1648,6 +9946,8 @@ const ICON_BOOKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bookmark-filled` — U+E01A (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_BOOKMARK_FILLED_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -1659,6 +9959,7 @@ const ICON_BOOKMARK_FILLED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bookmark-slash` — available in Filled U+E01D, Regular U+E01C, Regular/Tiny U+E01B, Filled/Tiny U+E01C
 const ICON_BOOKMARK_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1692,6 +9993,7 @@ const ICON_BOOKMARK_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `boolean-off` — available in Regular U+E01E, Regular/Tiny U+E01D
 const ICON_BOOLEAN_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1710,6 +10012,7 @@ const ICON_BOOLEAN_OFF_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `boolean-on` — available in Regular U+E01F, Regular/Tiny U+E01E
 const ICON_BOOLEAN_ON_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1728,6 +10031,7 @@ const ICON_BOOLEAN_ON_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `briefcase` — available in Regular U+E020, Regular/Tiny U+E01F
 const ICON_BRIEFCASE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1746,6 +10050,7 @@ const ICON_BRIEFCASE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `broadcast` — available in Regular U+E021, Regular/Tiny U+E020
 const ICON_BROADCAST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1764,6 +10069,7 @@ const ICON_BROADCAST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `browser` — available in Regular U+E022, Regular/Tiny U+E021
 const ICON_BROWSER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1782,6 +10088,7 @@ const ICON_BROWSER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `bug` — available in Regular U+E023, Regular/Tiny U+E022
 const ICON_BUG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1800,6 +10107,7 @@ const ICON_BUG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cache` — available in Regular U+E024, Regular/Tiny U+E023
 const ICON_CACHE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1818,6 +10126,7 @@ const ICON_CACHE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `calendar` — available in Regular U+E025, Regular/Tiny U+E024
 const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1836,6 +10145,7 @@ const ICON_CALENDAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `check` — available in Regular U+E026, Regular/Tiny U+E025
 const ICON_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1854,6 +10164,7 @@ const ICON_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `check-circle` — available in Filled U+E029, Regular U+E027, Regular/Tiny U+E026, Filled/Tiny U+E027
 const ICON_CHECK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1887,6 +10198,7 @@ const ICON_CHECK_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `checkbox` — available in Filled U+E02B, Regular U+E02A, Regular/Tiny U+E028, Filled/Tiny U+E029
 const ICON_CHECKBOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1920,6 +10232,7 @@ const ICON_CHECKBOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `checklist` — available in Regular U+E02C, Regular/Tiny U+E02A
 const ICON_CHECKLIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1938,6 +10251,7 @@ const ICON_CHECKLIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-down` — available in Regular U+E02E, Regular/Tiny U+E02B
 const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1956,6 +10270,7 @@ const ICON_CHEVRON_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-left` — available in Regular U+E030, Regular/Tiny U+E02C
 const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1974,6 +10289,7 @@ const ICON_CHEVRON_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-right` — available in Regular U+E032, Regular/Tiny U+E02D
 const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -1992,6 +10308,7 @@ const ICON_CHEVRON_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `chevron-up` — available in Regular U+E034, Regular/Tiny U+E02E
 const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2010,6 +10327,7 @@ const ICON_CHEVRON_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `circle` — available in Regular U+E035, Regular/Tiny U+E02F
 const ICON_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2028,6 +10346,7 @@ const ICON_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `circle-slash` — available in Regular U+E036, Regular/Tiny U+E030
 const ICON_CIRCLE_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2046,6 +10365,7 @@ const ICON_CIRCLE_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `clock` — available in Filled U+E038, Regular U+E037, Regular/Tiny U+E031, Filled/Tiny U+E032
 const ICON_CLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2079,6 +10399,7 @@ const ICON_CLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cloud` — available in Regular U+E039, Regular/Tiny U+E033
 const ICON_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2097,6 +10418,7 @@ const ICON_CLOUD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cloud-offline` — available in Regular U+E03A, Regular/Tiny U+E034
 const ICON_CLOUD_OFFLINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2115,6 +10437,7 @@ const ICON_CLOUD_OFFLINE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `code` — available in Regular U+E03B, Regular/Tiny U+E035
 const ICON_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2133,6 +10456,7 @@ const ICON_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `code-of-conduct` — available in Regular U+E03C, Regular/Tiny U+E036
 const ICON_CODE_OF_CONDUCT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2151,6 +10475,7 @@ const ICON_CODE_OF_CONDUCT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `code-review` — available in Regular U+E03D, Regular/Tiny U+E037
 const ICON_CODE_REVIEW_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2169,6 +10494,7 @@ const ICON_CODE_REVIEW_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `code-square` — available in Regular U+E03E, Regular/Tiny U+E038
 const ICON_CODE_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2187,6 +10513,7 @@ const ICON_CODE_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `codescan` — available in Regular U+E03F, Regular/Tiny U+E039
 const ICON_CODESCAN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2205,6 +10532,7 @@ const ICON_CODESCAN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `codescan-checkmark` — available in Regular U+E040, Regular/Tiny U+E03A
 const ICON_CODESCAN_CHECKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2223,6 +10551,7 @@ const ICON_CODESCAN_CHECKMARK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `codespaces` — available in Regular U+E041, Regular/Tiny U+E03B
 const ICON_CODESPACES_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2241,6 +10570,7 @@ const ICON_CODESPACES_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `columns` — available in Regular U+E042, Regular/Tiny U+E03C
 const ICON_COLUMNS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2259,6 +10589,7 @@ const ICON_COLUMNS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `command-palette` — available in Regular U+E043, Regular/Tiny U+E03D
 const ICON_COMMAND_PALETTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2277,6 +10608,7 @@ const ICON_COMMAND_PALETTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `comment` — available in Regular U+E044, Regular/Tiny U+E03E
 const ICON_COMMENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2295,6 +10627,7 @@ const ICON_COMMENT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `comment-ai` — available in Regular U+E045, Regular/Tiny U+E03F
 const ICON_COMMENT_AI_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2313,6 +10646,7 @@ const ICON_COMMENT_AI_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `comment-discussion` — available in Regular U+E046, Regular/Tiny U+E040
 const ICON_COMMENT_DISCUSSION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2331,6 +10665,7 @@ const ICON_COMMENT_DISCUSSION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `compose` — available in Regular U+E047, Regular/Tiny U+E041
 const ICON_COMPOSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2349,6 +10684,7 @@ const ICON_COMPOSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `container` — available in Regular U+E048, Regular/Tiny U+E042
 const ICON_CONTAINER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2367,6 +10703,7 @@ const ICON_CONTAINER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `copilot` — available in Regular U+E049, Regular/Tiny U+E043
 const ICON_COPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2385,6 +10722,8 @@ const ICON_COPILOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `copilot-error` — U+E044 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_COPILOT_ERROR_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -2396,6 +10735,8 @@ const ICON_COPILOT_ERROR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `copilot-warning` — U+E045 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_COPILOT_WARNING_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -2407,6 +10748,7 @@ const ICON_COPILOT_WARNING_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `copy` — available in Regular U+E04C, Regular/Tiny U+E046
 const ICON_COPY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2425,6 +10767,7 @@ const ICON_COPY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cpu` — available in Regular U+E04D, Regular/Tiny U+E047
 const ICON_CPU_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2443,6 +10786,7 @@ const ICON_CPU_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `credit-card` — available in Regular U+E04E, Regular/Tiny U+E048
 const ICON_CREDIT_CARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2461,6 +10805,7 @@ const ICON_CREDIT_CARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `cross-reference` — available in Regular U+E04F, Regular/Tiny U+E049
 const ICON_CROSS_REFERENCE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2479,6 +10824,7 @@ const ICON_CROSS_REFERENCE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `crosshairs` — available in Regular U+E050, Regular/Tiny U+E04A
 const ICON_CROSSHAIRS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2497,6 +10843,7 @@ const ICON_CROSSHAIRS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `dash` — available in Regular U+E051, Regular/Tiny U+E04B
 const ICON_DASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2515,6 +10862,7 @@ const ICON_DASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `database` — available in Regular U+E052, Regular/Tiny U+E04C
 const ICON_DATABASE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2533,6 +10881,7 @@ const ICON_DATABASE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `dependabot` — available in Regular U+E053, Regular/Tiny U+E04D
 const ICON_DEPENDABOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2551,6 +10900,7 @@ const ICON_DEPENDABOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `desktop-download` — available in Regular U+E054, Regular/Tiny U+E04E
 const ICON_DESKTOP_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2569,6 +10919,7 @@ const ICON_DESKTOP_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `device-camera` — available in Regular U+E055, Regular/Tiny U+E04F
 const ICON_DEVICE_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2587,6 +10938,7 @@ const ICON_DEVICE_CAMERA_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `device-camera-video` — available in Regular U+E056, Regular/Tiny U+E050
 const ICON_DEVICE_CAMERA_VIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2605,6 +10957,7 @@ const ICON_DEVICE_CAMERA_VIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `device-desktop` — available in Regular U+E057, Regular/Tiny U+E051
 const ICON_DEVICE_DESKTOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2623,6 +10976,7 @@ const ICON_DEVICE_DESKTOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `device-mobile` — available in Regular U+E058, Regular/Tiny U+E052
 const ICON_DEVICE_MOBILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2641,6 +10995,7 @@ const ICON_DEVICE_MOBILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `devices` — available in Regular U+E059, Regular/Tiny U+E053
 const ICON_DEVICES_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2659,6 +11014,7 @@ const ICON_DEVICES_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `diamond` — available in Regular U+E05A, Regular/Tiny U+E054
 const ICON_DIAMOND_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2677,6 +11033,7 @@ const ICON_DIAMOND_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `dice` — available in Regular U+E05B, Regular/Tiny U+E055
 const ICON_DICE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2695,6 +11052,7 @@ const ICON_DICE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `diff` — available in Regular U+E05C, Regular/Tiny U+E056
 const ICON_DIFF_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2713,6 +11071,7 @@ const ICON_DIFF_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `diff-added` — available in Regular U+E05D, Regular/Tiny U+E057
 const ICON_DIFF_ADDED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2731,6 +11090,7 @@ const ICON_DIFF_ADDED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `diff-ignored` — available in Regular U+E05E, Regular/Tiny U+E058
 const ICON_DIFF_IGNORED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2749,6 +11109,7 @@ const ICON_DIFF_IGNORED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `diff-modified` — available in Regular U+E05F, Regular/Tiny U+E059
 const ICON_DIFF_MODIFIED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2767,6 +11128,7 @@ const ICON_DIFF_MODIFIED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `diff-removed` — available in Regular U+E060, Regular/Tiny U+E05A
 const ICON_DIFF_REMOVED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2785,6 +11147,7 @@ const ICON_DIFF_REMOVED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `diff-renamed` — available in Regular U+E061, Regular/Tiny U+E05B
 const ICON_DIFF_RENAMED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2803,6 +11166,7 @@ const ICON_DIFF_RENAMED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `discussion-closed` — available in Regular U+E062, Regular/Tiny U+E05C
 const ICON_DISCUSSION_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2821,6 +11185,7 @@ const ICON_DISCUSSION_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `discussion-duplicate` — available in Regular U+E063, Regular/Tiny U+E05D
 const ICON_DISCUSSION_DUPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2839,6 +11204,7 @@ const ICON_DISCUSSION_DUPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `discussion-outdated` — available in Regular U+E064, Regular/Tiny U+E05E
 const ICON_DISCUSSION_OUTDATED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2857,6 +11223,7 @@ const ICON_DISCUSSION_OUTDATED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `dot` — available in Filled U+E066, Regular U+E065, Regular/Tiny U+E05F, Filled/Tiny U+E060
 const ICON_DOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2890,6 +11257,7 @@ const ICON_DOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `download` — available in Regular U+E067, Regular/Tiny U+E061
 const ICON_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2908,6 +11276,7 @@ const ICON_DOWNLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `duplicate` — available in Regular U+E068, Regular/Tiny U+E062
 const ICON_DUPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2926,6 +11295,7 @@ const ICON_DUPLICATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `ellipsis` — available in Regular U+E069, Regular/Tiny U+E063
 const ICON_ELLIPSIS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2944,6 +11314,7 @@ const ICON_ELLIPSIS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `exclamation` — available in Regular U+E06A, Regular/Tiny U+E064
 const ICON_EXCLAMATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2962,6 +11333,7 @@ const ICON_EXCLAMATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `eye` — available in Regular U+E06B, Regular/Tiny U+E065
 const ICON_EYE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2980,6 +11352,7 @@ const ICON_EYE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `eye-closed` — available in Regular U+E06C, Regular/Tiny U+E066
 const ICON_EYE_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -2998,6 +11371,8 @@ const ICON_EYE_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-discussion` — U+E067 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_DISCUSSION_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3009,6 +11384,8 @@ const ICON_FEED_DISCUSSION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-forked` — U+E068 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_FORKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3020,6 +11397,8 @@ const ICON_FEED_FORKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-heart` — U+E069 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3031,6 +11410,8 @@ const ICON_FEED_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-issue-closed` — U+E06A (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3042,6 +11423,8 @@ const ICON_FEED_ISSUE_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-issue-draft` — U+E06B (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3053,6 +11436,8 @@ const ICON_FEED_ISSUE_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-issue-open` — U+E06C (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3064,6 +11449,8 @@ const ICON_FEED_ISSUE_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-issue-reopen` — U+E06D (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_REOPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3075,6 +11462,8 @@ const ICON_FEED_ISSUE_REOPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-merged` — U+E06E (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_MERGED_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3086,6 +11475,8 @@ const ICON_FEED_MERGED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-person` — U+E06F (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PERSON_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3097,6 +11488,8 @@ const ICON_FEED_PERSON_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-plus` — U+E070 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3108,6 +11501,8 @@ const ICON_FEED_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-public` — U+E071 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PUBLIC_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3119,6 +11514,8 @@ const ICON_FEED_PUBLIC_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-pull-request-closed` — U+E072 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PULL_REQUEST_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3130,6 +11527,8 @@ const ICON_FEED_PULL_REQUEST_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-pull-request-draft` — U+E073 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PULL_REQUEST_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3141,6 +11540,8 @@ const ICON_FEED_PULL_REQUEST_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-pull-request-open` — U+E074 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PULL_REQUEST_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3152,6 +11553,8 @@ const ICON_FEED_PULL_REQUEST_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-repo` — U+E075 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_REPO_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3163,6 +11566,8 @@ const ICON_FEED_REPO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-rocket` — U+E076 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ROCKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3174,6 +11579,8 @@ const ICON_FEED_ROCKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-star` — U+E077 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3185,6 +11592,8 @@ const ICON_FEED_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-tag` — U+E078 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3196,6 +11605,8 @@ const ICON_FEED_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `feed-trophy` — U+E079 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_TROPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -3207,6 +11618,7 @@ const ICON_FEED_TROPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file` — available in Regular U+E06D, Regular/Tiny U+E07A
 const ICON_FILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3225,6 +11637,7 @@ const ICON_FILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-added` — available in Regular U+E06E, Regular/Tiny U+E07B
 const ICON_FILE_ADDED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3243,6 +11656,7 @@ const ICON_FILE_ADDED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-badge` — available in Regular U+E06F, Regular/Tiny U+E07C
 const ICON_FILE_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3261,6 +11675,7 @@ const ICON_FILE_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-binary` — available in Regular U+E070, Regular/Tiny U+E07D
 const ICON_FILE_BINARY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3279,6 +11694,7 @@ const ICON_FILE_BINARY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-check` — available in Regular U+E071, Regular/Tiny U+E07E
 const ICON_FILE_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3297,6 +11713,7 @@ const ICON_FILE_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-code` — available in Regular U+E072, Regular/Tiny U+E07F
 const ICON_FILE_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3315,6 +11732,7 @@ const ICON_FILE_CODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-diff` — available in Regular U+E073, Regular/Tiny U+E080
 const ICON_FILE_DIFF_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3333,6 +11751,7 @@ const ICON_FILE_DIFF_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-directory` — available in Filled U+E075, Regular U+E074, Regular/Tiny U+E081, Filled/Tiny U+E082
 const ICON_FILE_DIRECTORY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3366,6 +11785,7 @@ const ICON_FILE_DIRECTORY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-directory-open` — available in Filled U+E076, Filled/Tiny U+E083
 const ICON_FILE_DIRECTORY_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3384,6 +11804,7 @@ const ICON_FILE_DIRECTORY_OPEN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-directory-symlink` — available in Regular U+E077, Regular/Tiny U+E084
 const ICON_FILE_DIRECTORY_SYMLINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3402,6 +11823,7 @@ const ICON_FILE_DIRECTORY_SYMLINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-media` — available in Regular U+E078, Regular/Tiny U+E085
 const ICON_FILE_MEDIA_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3420,6 +11842,7 @@ const ICON_FILE_MEDIA_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-moved` — available in Regular U+E079, Regular/Tiny U+E086
 const ICON_FILE_MOVED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3438,6 +11861,7 @@ const ICON_FILE_MOVED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-removed` — available in Regular U+E07A, Regular/Tiny U+E087
 const ICON_FILE_REMOVED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3456,6 +11880,7 @@ const ICON_FILE_REMOVED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-submodule` — available in Regular U+E07B, Regular/Tiny U+E088
 const ICON_FILE_SUBMODULE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3474,6 +11899,7 @@ const ICON_FILE_SUBMODULE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-symlink-file` — available in Regular U+E07C, Regular/Tiny U+E089
 const ICON_FILE_SYMLINK_FILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3492,6 +11918,7 @@ const ICON_FILE_SYMLINK_FILE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `file-zip` — available in Regular U+E07D, Regular/Tiny U+E08A
 const ICON_FILE_ZIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3510,6 +11937,7 @@ const ICON_FILE_ZIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `filter` — available in Regular U+E07E, Regular/Tiny U+E08B
 const ICON_FILTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3528,6 +11956,7 @@ const ICON_FILTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `filter-remove` — available in Regular U+E07F, Regular/Tiny U+E08C
 const ICON_FILTER_REMOVE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3546,6 +11975,7 @@ const ICON_FILTER_REMOVE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `fiscal-host` — available in Regular U+E080, Regular/Tiny U+E08D
 const ICON_FISCAL_HOST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3564,6 +11994,7 @@ const ICON_FISCAL_HOST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `flame` — available in Regular U+E081, Regular/Tiny U+E08E
 const ICON_FLAME_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3582,6 +12013,7 @@ const ICON_FLAME_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `flowchart` — available in Regular U+E082, Regular/Tiny U+E08F
 const ICON_FLOWCHART_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3600,6 +12032,7 @@ const ICON_FLOWCHART_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `focus-center` — available in Regular U+E083, Regular/Tiny U+E090
 const ICON_FOCUS_CENTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3618,6 +12051,7 @@ const ICON_FOCUS_CENTER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `fold` — available in Regular U+E084, Regular/Tiny U+E091
 const ICON_FOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3636,6 +12070,7 @@ const ICON_FOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `fold-down` — available in Regular U+E085, Regular/Tiny U+E092
 const ICON_FOLD_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3654,6 +12089,7 @@ const ICON_FOLD_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `fold-up` — available in Regular U+E086, Regular/Tiny U+E093
 const ICON_FOLD_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3672,6 +12108,7 @@ const ICON_FOLD_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `gear` — available in Regular U+E087, Regular/Tiny U+E094
 const ICON_GEAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3690,6 +12127,7 @@ const ICON_GEAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `gift` — available in Regular U+E088, Regular/Tiny U+E095
 const ICON_GIFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3708,6 +12146,7 @@ const ICON_GIFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-branch` — available in Regular U+E089, Regular/Tiny U+E096
 const ICON_GIT_BRANCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3726,6 +12165,7 @@ const ICON_GIT_BRANCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-branch-check` — available in Regular U+E08A, Regular/Tiny U+E097
 const ICON_GIT_BRANCH_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3744,6 +12184,7 @@ const ICON_GIT_BRANCH_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-commit` — available in Regular U+E08B, Regular/Tiny U+E098
 const ICON_GIT_COMMIT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3762,6 +12203,7 @@ const ICON_GIT_COMMIT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-compare` — available in Regular U+E08C, Regular/Tiny U+E099
 const ICON_GIT_COMPARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3780,6 +12222,7 @@ const ICON_GIT_COMPARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-merge` — available in Regular U+E08D, Regular/Tiny U+E09A
 const ICON_GIT_MERGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3798,6 +12241,7 @@ const ICON_GIT_MERGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-merge-queue` — available in Regular U+E08E, Regular/Tiny U+E09B
 const ICON_GIT_MERGE_QUEUE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3816,6 +12260,7 @@ const ICON_GIT_MERGE_QUEUE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-pull-request` — available in Regular U+E08F, Regular/Tiny U+E09C
 const ICON_GIT_PULL_REQUEST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3834,6 +12279,7 @@ const ICON_GIT_PULL_REQUEST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-pull-request-closed` — available in Regular U+E090, Regular/Tiny U+E09D
 const ICON_GIT_PULL_REQUEST_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3852,6 +12298,7 @@ const ICON_GIT_PULL_REQUEST_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `git-pull-request-draft` — available in Regular U+E091, Regular/Tiny U+E09E
 const ICON_GIT_PULL_REQUEST_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3870,6 +12317,7 @@ const ICON_GIT_PULL_REQUEST_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `globe` — available in Regular U+E092, Regular/Tiny U+E09F
 const ICON_GLOBE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3888,6 +12336,7 @@ const ICON_GLOBE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `goal` — available in Regular U+E093, Regular/Tiny U+E0A0
 const ICON_GOAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3906,6 +12355,7 @@ const ICON_GOAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `grabber` — available in Regular U+E094, Regular/Tiny U+E0A1
 const ICON_GRABBER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3924,6 +12374,7 @@ const ICON_GRABBER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `graph` — available in Regular U+E095, Regular/Tiny U+E0A2
 const ICON_GRAPH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3942,6 +12393,7 @@ const ICON_GRAPH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `graph-bar-horizontal` — available in Regular U+E096, Regular/Tiny U+E0A3
 const ICON_GRAPH_BAR_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3960,6 +12412,7 @@ const ICON_GRAPH_BAR_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `graph-bar-vertical` — available in Regular U+E097, Regular/Tiny U+E0A4
 const ICON_GRAPH_BAR_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3978,6 +12431,7 @@ const ICON_GRAPH_BAR_VERTICAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `hash` — available in Regular U+E098, Regular/Tiny U+E0A5
 const ICON_HASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -3996,6 +12450,7 @@ const ICON_HASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `heading` — available in Regular U+E099, Regular/Tiny U+E0A6
 const ICON_HEADING_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4014,6 +12469,7 @@ const ICON_HEADING_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `heart` — available in Filled U+E09B, Regular U+E09A, Regular/Tiny U+E0A7, Filled/Tiny U+E0A8
 const ICON_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4047,6 +12503,7 @@ const ICON_HEART_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `history` — available in Regular U+E09C, Regular/Tiny U+E0A9
 const ICON_HISTORY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4065,6 +12522,7 @@ const ICON_HISTORY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `home` — available in Filled U+E09E, Regular U+E09D, Regular/Tiny U+E0AA, Filled/Tiny U+E0AB
 const ICON_HOME_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4098,6 +12556,7 @@ const ICON_HOME_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `horizontal-rule` — available in Regular U+E09F, Regular/Tiny U+E0AC
 const ICON_HORIZONTAL_RULE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4116,6 +12575,7 @@ const ICON_HORIZONTAL_RULE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `hourglass` — available in Regular U+E0A0, Regular/Tiny U+E0AD
 const ICON_HOURGLASS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4134,6 +12594,7 @@ const ICON_HOURGLASS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `hubot` — available in Regular U+E0A1, Regular/Tiny U+E0AE
 const ICON_HUBOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4152,6 +12613,7 @@ const ICON_HUBOT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `id-badge` — available in Regular U+E0A2, Regular/Tiny U+E0AF
 const ICON_ID_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4170,6 +12632,7 @@ const ICON_ID_BADGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `image` — available in Regular U+E0A3, Regular/Tiny U+E0B0
 const ICON_IMAGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4188,6 +12651,7 @@ const ICON_IMAGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `inbox` — available in Filled U+E0A5, Regular U+E0A4, Regular/Tiny U+E0B1, Filled/Tiny U+E0B2
 const ICON_INBOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4221,6 +12685,7 @@ const ICON_INBOX_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `infinity` — available in Regular U+E0A6, Regular/Tiny U+E0B3
 const ICON_INFINITY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4239,6 +12704,7 @@ const ICON_INFINITY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `info` — available in Regular U+E0A7, Regular/Tiny U+E0B4
 const ICON_INFO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4257,6 +12723,7 @@ const ICON_INFO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `issue-closed` — available in Regular U+E0A8, Regular/Tiny U+E0B5
 const ICON_ISSUE_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4275,6 +12742,7 @@ const ICON_ISSUE_CLOSED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `issue-draft` — available in Regular U+E0A9, Regular/Tiny U+E0B6
 const ICON_ISSUE_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4293,6 +12761,7 @@ const ICON_ISSUE_DRAFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `issue-opened` — available in Regular U+E0AA, Regular/Tiny U+E0B7
 const ICON_ISSUE_OPENED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4311,6 +12780,7 @@ const ICON_ISSUE_OPENED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `issue-reopened` — available in Regular U+E0AB, Regular/Tiny U+E0B8
 const ICON_ISSUE_REOPENED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4329,6 +12799,7 @@ const ICON_ISSUE_REOPENED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `issue-tracked-by` — available in Regular U+E0AC, Regular/Tiny U+E0B9
 const ICON_ISSUE_TRACKED_BY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4347,6 +12818,7 @@ const ICON_ISSUE_TRACKED_BY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `issue-tracks` — available in Regular U+E0AD, Regular/Tiny U+E0BA
 const ICON_ISSUE_TRACKS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4365,6 +12837,7 @@ const ICON_ISSUE_TRACKS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `italic` — available in Regular U+E0AE, Regular/Tiny U+E0BB
 const ICON_ITALIC_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4383,6 +12856,7 @@ const ICON_ITALIC_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `iterations` — available in Regular U+E0AF, Regular/Tiny U+E0BC
 const ICON_ITERATIONS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4401,6 +12875,7 @@ const ICON_ITERATIONS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `kebab-horizontal` — available in Regular U+E0B0, Regular/Tiny U+E0BD
 const ICON_KEBAB_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4419,6 +12894,7 @@ const ICON_KEBAB_HORIZONTAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `key` — available in Regular U+E0B1, Regular/Tiny U+E0BE
 const ICON_KEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4437,6 +12913,7 @@ const ICON_KEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `key-asterisk` — available in Regular U+E0B2, Regular/Tiny U+E0BF
 const ICON_KEY_ASTERISK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4455,6 +12932,7 @@ const ICON_KEY_ASTERISK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `law` — available in Regular U+E0B3, Regular/Tiny U+E0C0
 const ICON_LAW_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4473,6 +12951,7 @@ const ICON_LAW_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `light-bulb` — available in Regular U+E0B4, Regular/Tiny U+E0C1
 const ICON_LIGHT_BULB_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4491,6 +12970,7 @@ const ICON_LIGHT_BULB_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `link` — available in Regular U+E0B5, Regular/Tiny U+E0C2
 const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4509,6 +12989,7 @@ const ICON_LINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `link-external` — available in Regular U+E0B6, Regular/Tiny U+E0C3
 const ICON_LINK_EXTERNAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4527,6 +13008,7 @@ const ICON_LINK_EXTERNAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `list-ordered` — available in Regular U+E0B7, Regular/Tiny U+E0C4
 const ICON_LIST_ORDERED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4545,6 +13027,7 @@ const ICON_LIST_ORDERED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `list-unordered` — available in Regular U+E0B8, Regular/Tiny U+E0C5
 const ICON_LIST_UNORDERED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4563,6 +13046,7 @@ const ICON_LIST_UNORDERED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `location` — available in Regular U+E0B9, Regular/Tiny U+E0C6
 const ICON_LOCATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4581,6 +13065,7 @@ const ICON_LOCATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `lock` — available in Regular U+E0BA, Regular/Tiny U+E0C7
 const ICON_LOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4599,6 +13084,7 @@ const ICON_LOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `log` — available in Regular U+E0BB, Regular/Tiny U+E0C8
 const ICON_LOG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4617,6 +13103,7 @@ const ICON_LOG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `logo-gist` — available in Regular U+E0BC, Regular/Tiny U+E0C9
 const ICON_LOGO_GIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4635,6 +13122,7 @@ const ICON_LOGO_GIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `logo-github` — available in Regular U+E0BD, Regular/Tiny U+E0CA
 const ICON_LOGO_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4653,6 +13141,7 @@ const ICON_LOGO_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `loop` — available in Regular U+E0BE, Regular/Tiny U+E0CB
 const ICON_LOOP__CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4671,6 +13160,7 @@ const ICON_LOOP__CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `mail` — available in Regular U+E0BF, Regular/Tiny U+E0CC
 const ICON_MAIL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4689,6 +13179,7 @@ const ICON_MAIL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `mark-github` — available in Regular U+E0C0, Regular/Tiny U+E0CD
 const ICON_MARK_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4707,6 +13198,7 @@ const ICON_MARK_GITHUB_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `markdown` — available in Regular U+E0C1, Regular/Tiny U+E0CE
 const ICON_MARKDOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4725,6 +13217,7 @@ const ICON_MARKDOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `maximize` — available in Regular U+E0C2, Regular/Tiny U+E0CF
 const ICON_MAXIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4743,6 +13236,7 @@ const ICON_MAXIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `mcp` — available in Regular U+E0C3, Regular/Tiny U+E0D0
 const ICON_MCP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4761,6 +13255,7 @@ const ICON_MCP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `megaphone` — available in Regular U+E0C4, Regular/Tiny U+E0D1
 const ICON_MEGAPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4779,6 +13274,7 @@ const ICON_MEGAPHONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `mention` — available in Regular U+E0C5, Regular/Tiny U+E0D2
 const ICON_MENTION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4797,6 +13293,7 @@ const ICON_MENTION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `meter` — available in Regular U+E0C6, Regular/Tiny U+E0D3
 const ICON_METER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4815,6 +13312,7 @@ const ICON_METER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `milestone` — available in Regular U+E0C7, Regular/Tiny U+E0D4
 const ICON_MILESTONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4833,6 +13331,7 @@ const ICON_MILESTONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `minimize` — available in Regular U+E0C8, Regular/Tiny U+E0D5
 const ICON_MINIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4851,6 +13350,7 @@ const ICON_MINIMIZE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `mirror` — available in Regular U+E0C9, Regular/Tiny U+E0D6
 const ICON_MIRROR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4869,6 +13369,7 @@ const ICON_MIRROR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `moon` — available in Regular U+E0CA, Regular/Tiny U+E0D7
 const ICON_MOON_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4887,6 +13388,7 @@ const ICON_MOON_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `mortar-board` — available in Regular U+E0CB, Regular/Tiny U+E0D8
 const ICON_MORTAR_BOARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4905,6 +13407,7 @@ const ICON_MORTAR_BOARD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `move-to-bottom` — available in Regular U+E0CC, Regular/Tiny U+E0D9
 const ICON_MOVE_TO_BOTTOM_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4923,6 +13426,7 @@ const ICON_MOVE_TO_BOTTOM_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `move-to-end` — available in Regular U+E0CD, Regular/Tiny U+E0DA
 const ICON_MOVE_TO_END_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4941,6 +13445,7 @@ const ICON_MOVE_TO_END_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `move-to-start` — available in Regular U+E0CE, Regular/Tiny U+E0DB
 const ICON_MOVE_TO_START_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4959,6 +13464,7 @@ const ICON_MOVE_TO_START_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `move-to-top` — available in Regular U+E0CF, Regular/Tiny U+E0DC
 const ICON_MOVE_TO_TOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4977,6 +13483,7 @@ const ICON_MOVE_TO_TOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `multi-select` — available in Regular U+E0D0, Regular/Tiny U+E0DD
 const ICON_MULTI_SELECT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -4995,6 +13502,7 @@ const ICON_MULTI_SELECT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `mute` — available in Regular U+E0D1, Regular/Tiny U+E0DE
 const ICON_MUTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5013,6 +13521,7 @@ const ICON_MUTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `no-entry` — available in Regular U+E0D2, Regular/Tiny U+E0DF
 const ICON_NO_ENTRY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5031,6 +13540,7 @@ const ICON_NO_ENTRY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `node` — available in Regular U+E0D4, Regular/Tiny U+E0E0
 const ICON_NODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5049,6 +13559,7 @@ const ICON_NODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `north-star` — available in Regular U+E0D5, Regular/Tiny U+E0E1
 const ICON_NORTH_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5067,6 +13578,7 @@ const ICON_NORTH_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `note` — available in Regular U+E0D6, Regular/Tiny U+E0E2
 const ICON_NOTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5085,6 +13597,7 @@ const ICON_NOTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `number` — available in Regular U+E0D7, Regular/Tiny U+E0E3
 const ICON_NUMBER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5103,6 +13616,7 @@ const ICON_NUMBER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `organization` — available in Regular U+E0D8, Regular/Tiny U+E0E4
 const ICON_ORGANIZATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5121,6 +13635,7 @@ const ICON_ORGANIZATION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `package` — available in Regular U+E0D9, Regular/Tiny U+E0E5
 const ICON_PACKAGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5139,6 +13654,7 @@ const ICON_PACKAGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `package-dependencies` — available in Regular U+E0DA, Regular/Tiny U+E0E6
 const ICON_PACKAGE_DEPENDENCIES_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5157,6 +13673,7 @@ const ICON_PACKAGE_DEPENDENCIES_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `package-dependents` — available in Regular U+E0DB, Regular/Tiny U+E0E7
 const ICON_PACKAGE_DEPENDENTS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5175,6 +13692,7 @@ const ICON_PACKAGE_DEPENDENTS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `paintbrush` — available in Regular U+E0DC, Regular/Tiny U+E0E8
 const ICON_PAINTBRUSH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5193,6 +13711,7 @@ const ICON_PAINTBRUSH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `paper-airplane` — available in Regular U+E0DD, Regular/Tiny U+E0E9
 const ICON_PAPER_AIRPLANE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5211,6 +13730,7 @@ const ICON_PAPER_AIRPLANE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `paperclip` — available in Regular U+E0DE, Regular/Tiny U+E0EA
 const ICON_PAPERCLIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5229,6 +13749,7 @@ const ICON_PAPERCLIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `passkey` — available in Filled U+E0DF, Filled/Tiny U+E0EB
 const ICON_PASSKEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5247,6 +13768,7 @@ const ICON_PASSKEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `paste` — available in Regular U+E0E0, Regular/Tiny U+E0EC
 const ICON_PASTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5265,6 +13787,7 @@ const ICON_PASTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pause` — available in Regular U+E0E1, Regular/Tiny U+E0ED
 const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5283,6 +13806,7 @@ const ICON_PAUSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pencil` — available in Regular U+E0E2, Regular/Tiny U+E0EE
 const ICON_PENCIL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5301,6 +13825,7 @@ const ICON_PENCIL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pencil-ai` — available in Regular U+E0E3, Regular/Tiny U+E0EF
 const ICON_PENCIL_AI_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5319,6 +13844,7 @@ const ICON_PENCIL_AI_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `people` — available in Regular U+E0E4, Regular/Tiny U+E0F0
 const ICON_PEOPLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5337,6 +13863,7 @@ const ICON_PEOPLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `person` — available in Filled U+E0E7, Regular U+E0E5, Regular/Tiny U+E0F1, Filled/Tiny U+E0F3
 const ICON_PERSON_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5370,6 +13897,7 @@ const ICON_PERSON_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `person-add` — available in Regular U+E0E6, Regular/Tiny U+E0F2
 const ICON_PERSON_ADD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5388,6 +13916,7 @@ const ICON_PERSON_ADD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pin` — available in Regular U+E0E8, Regular/Tiny U+E0F4
 const ICON_PIN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5406,6 +13935,7 @@ const ICON_PIN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pin-slash` — available in Regular U+E0E9, Regular/Tiny U+E0F5
 const ICON_PIN_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5424,6 +13954,7 @@ const ICON_PIN_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pivot-column` — available in Regular U+E0EA, Regular/Tiny U+E0F6
 const ICON_PIVOT_COLUMN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5442,6 +13973,7 @@ const ICON_PIVOT_COLUMN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `play` — available in Regular U+E0EB, Regular/Tiny U+E0F7
 const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5460,6 +13992,7 @@ const ICON_PLAY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `plug` — available in Regular U+E0EC, Regular/Tiny U+E0F8
 const ICON_PLUG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5478,6 +14011,7 @@ const ICON_PLUG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `plus` — available in Regular U+E0ED, Regular/Tiny U+E0F9
 const ICON_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5496,6 +14030,7 @@ const ICON_PLUS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `plus-circle` — available in Regular U+E0EE, Regular/Tiny U+E0FA
 const ICON_PLUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5514,6 +14049,7 @@ const ICON_PLUS_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `project` — available in Regular U+E0EF, Regular/Tiny U+E0FB
 const ICON_PROJECT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5532,6 +14068,7 @@ const ICON_PROJECT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `project-roadmap` — available in Regular U+E0F0, Regular/Tiny U+E0FC
 const ICON_PROJECT_ROADMAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5550,6 +14087,7 @@ const ICON_PROJECT_ROADMAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `project-symlink` — available in Regular U+E0F1, Regular/Tiny U+E0FD
 const ICON_PROJECT_SYMLINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5568,6 +14106,7 @@ const ICON_PROJECT_SYMLINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `project-template` — available in Regular U+E0F2, Regular/Tiny U+E0FE
 const ICON_PROJECT_TEMPLATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5586,6 +14125,7 @@ const ICON_PROJECT_TEMPLATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `pulse` — available in Regular U+E0F3, Regular/Tiny U+E0FF
 const ICON_PULSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5604,6 +14144,7 @@ const ICON_PULSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `question` — available in Regular U+E0F4, Regular/Tiny U+E100
 const ICON_QUESTION_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5622,6 +14163,7 @@ const ICON_QUESTION_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `quote` — available in Regular U+E0F5, Regular/Tiny U+E101
 const ICON_QUOTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5640,6 +14182,7 @@ const ICON_QUOTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `read` — available in Regular U+E0F6, Regular/Tiny U+E102
 const ICON_READ_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5658,6 +14201,7 @@ const ICON_READ_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `redo` — available in Regular U+E0F7, Regular/Tiny U+E103
 const ICON_REDO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5676,6 +14220,7 @@ const ICON_REDO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rel-file-path` — available in Regular U+E0F8, Regular/Tiny U+E104
 const ICON_REL_FILE_PATH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5694,6 +14239,7 @@ const ICON_REL_FILE_PATH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `reply` — available in Regular U+E0F9, Regular/Tiny U+E105
 const ICON_REPLY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5712,6 +14258,7 @@ const ICON_REPLY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo` — available in Regular U+E0FA, Regular/Tiny U+E106
 const ICON_REPO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5730,6 +14277,7 @@ const ICON_REPO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo-clone` — available in Regular U+E0FB, Regular/Tiny U+E107
 const ICON_REPO_CLONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5748,6 +14296,7 @@ const ICON_REPO_CLONE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo-delete` — U+E0FC (available in Regular)
 const ICON_REPO_DELETE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     VariantKey {
         style: Style::Regular,
@@ -5756,6 +14305,8 @@ const ICON_REPO_DELETE_CODEPOINTS: &[(VariantKey, u32)] = &[(
     57596,
 )];
 
+/// `repo-deleted` — U+E108 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_REPO_DELETED_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -5767,6 +14318,7 @@ const ICON_REPO_DELETED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo-forked` — available in Regular U+E0FD, Regular/Tiny U+E109
 const ICON_REPO_FORKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5785,6 +14337,7 @@ const ICON_REPO_FORKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo-locked` — available in Regular U+E0FE, Regular/Tiny U+E10A
 const ICON_REPO_LOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5803,6 +14356,7 @@ const ICON_REPO_LOCKED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo-pull` — available in Regular U+E0FF, Regular/Tiny U+E10B
 const ICON_REPO_PULL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5821,6 +14375,7 @@ const ICON_REPO_PULL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo-push` — available in Regular U+E100, Regular/Tiny U+E10C
 const ICON_REPO_PUSH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5839,6 +14394,7 @@ const ICON_REPO_PUSH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `repo-template` — available in Regular U+E101, Regular/Tiny U+E10D
 const ICON_REPO_TEMPLATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5857,6 +14413,7 @@ const ICON_REPO_TEMPLATE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `report` — available in Regular U+E102, Regular/Tiny U+E10E
 const ICON_REPORT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5875,6 +14432,7 @@ const ICON_REPORT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rocket` — available in Regular U+E103, Regular/Tiny U+E10F
 const ICON_ROCKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5893,6 +14451,7 @@ const ICON_ROCKET_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rows` — available in Regular U+E104, Regular/Tiny U+E110
 const ICON_ROWS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5911,6 +14470,7 @@ const ICON_ROWS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `rss` — available in Regular U+E105, Regular/Tiny U+E111
 const ICON_RSS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5929,6 +14489,7 @@ const ICON_RSS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `ruby` — available in Regular U+E106, Regular/Tiny U+E112
 const ICON_RUBY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5947,6 +14508,7 @@ const ICON_RUBY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `screen-full` — available in Regular U+E107, Regular/Tiny U+E113
 const ICON_SCREEN_FULL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5965,6 +14527,7 @@ const ICON_SCREEN_FULL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `screen-normal` — available in Regular U+E108, Regular/Tiny U+E114
 const ICON_SCREEN_NORMAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -5983,6 +14546,7 @@ const ICON_SCREEN_NORMAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `search` — available in Regular U+E109, Regular/Tiny U+E115
 const ICON_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6001,6 +14565,7 @@ const ICON_SEARCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `server` — available in Regular U+E10A, Regular/Tiny U+E116
 const ICON_SERVER_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6019,6 +14584,7 @@ const ICON_SERVER_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `share` — available in Regular U+E10B, Regular/Tiny U+E117
 const ICON_SHARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6037,6 +14603,7 @@ const ICON_SHARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `share-android` — available in Regular U+E10C, Regular/Tiny U+E118
 const ICON_SHARE_ANDROID_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6055,6 +14622,7 @@ const ICON_SHARE_ANDROID_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shield` — available in Regular U+E10D, Regular/Tiny U+E119
 const ICON_SHIELD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6073,6 +14641,7 @@ const ICON_SHIELD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shield-check` — available in Regular U+E10E, Regular/Tiny U+E11A
 const ICON_SHIELD_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6091,6 +14660,7 @@ const ICON_SHIELD_CHECK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shield-lock` — available in Regular U+E10F, Regular/Tiny U+E11B
 const ICON_SHIELD_LOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6109,6 +14679,7 @@ const ICON_SHIELD_LOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shield-slash` — available in Regular U+E110, Regular/Tiny U+E11C
 const ICON_SHIELD_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6127,6 +14698,7 @@ const ICON_SHIELD_SLASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `shield-x` — available in Regular U+E111, Regular/Tiny U+E11D
 const ICON_SHIELD_X_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6145,6 +14717,7 @@ const ICON_SHIELD_X_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sidebar-collapse` — available in Regular U+E112, Regular/Tiny U+E11E
 const ICON_SIDEBAR_COLLAPSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6163,6 +14736,7 @@ const ICON_SIDEBAR_COLLAPSE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sidebar-expand` — available in Regular U+E113, Regular/Tiny U+E11F
 const ICON_SIDEBAR_EXPAND_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6181,6 +14755,7 @@ const ICON_SIDEBAR_EXPAND_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sign-in` — available in Regular U+E114, Regular/Tiny U+E120
 const ICON_SIGN_IN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6199,6 +14774,7 @@ const ICON_SIGN_IN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sign-out` — available in Regular U+E115, Regular/Tiny U+E121
 const ICON_SIGN_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6217,6 +14793,7 @@ const ICON_SIGN_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `single-select` — available in Regular U+E116, Regular/Tiny U+E122
 const ICON_SINGLE_SELECT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6235,6 +14812,7 @@ const ICON_SINGLE_SELECT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `skip` — available in Filled U+E118, Regular U+E117, Regular/Tiny U+E123, Filled/Tiny U+E124
 const ICON_SKIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6268,6 +14846,7 @@ const ICON_SKIP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sliders` — available in Regular U+E119, Regular/Tiny U+E125
 const ICON_SLIDERS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6286,6 +14865,7 @@ const ICON_SLIDERS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `smiley` — available in Regular U+E11A, Regular/Tiny U+E126
 const ICON_SMILEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6304,6 +14884,7 @@ const ICON_SMILEY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `smiley-frown` — available in Regular U+E11B, Regular/Tiny U+E127
 const ICON_SMILEY_FROWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6322,6 +14903,7 @@ const ICON_SMILEY_FROWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `smiley-frustrated` — available in Regular U+E11C, Regular/Tiny U+E128
 const ICON_SMILEY_FRUSTRATED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6340,6 +14922,7 @@ const ICON_SMILEY_FRUSTRATED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `smiley-grin` — available in Regular U+E11D, Regular/Tiny U+E129
 const ICON_SMILEY_GRIN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6358,6 +14941,7 @@ const ICON_SMILEY_GRIN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `smiley-neutral` — available in Regular U+E11E, Regular/Tiny U+E12A
 const ICON_SMILEY_NEUTRAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6376,6 +14960,7 @@ const ICON_SMILEY_NEUTRAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sort-asc` — available in Regular U+E11F, Regular/Tiny U+E12B
 const ICON_SORT_ASC_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6394,6 +14979,7 @@ const ICON_SORT_ASC_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sort-desc` — available in Regular U+E120, Regular/Tiny U+E12C
 const ICON_SORT_DESC_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6412,6 +14998,7 @@ const ICON_SORT_DESC_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `space` — available in Regular U+E121, Regular/Tiny U+E12D
 const ICON_SPACE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6430,6 +15017,7 @@ const ICON_SPACE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `spacing-large` — available in Regular U+E122, Regular/Tiny U+E12E
 const ICON_SPACING_LARGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6448,6 +15036,7 @@ const ICON_SPACING_LARGE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `spacing-medium` — available in Regular U+E123, Regular/Tiny U+E12F
 const ICON_SPACING_MEDIUM_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6466,6 +15055,7 @@ const ICON_SPACING_MEDIUM_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `spacing-small` — available in Regular U+E124, Regular/Tiny U+E130
 const ICON_SPACING_SMALL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6484,6 +15074,7 @@ const ICON_SPACING_SMALL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sparkle` — available in Filled U+E126, Regular U+E125, Regular/Tiny U+E131, Filled/Tiny U+E132
 const ICON_SPARKLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6517,6 +15108,7 @@ const ICON_SPARKLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sparkles` — available in Filled U+E127, Filled/Tiny U+E133
 const ICON_SPARKLES_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6535,6 +15127,7 @@ const ICON_SPARKLES_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `split-view` — available in Regular U+E128, Regular/Tiny U+E134
 const ICON_SPLIT_VIEW_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6553,6 +15146,7 @@ const ICON_SPLIT_VIEW_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sponsor-tiers` — available in Regular U+E129, Regular/Tiny U+E135
 const ICON_SPONSOR_TIERS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6571,6 +15165,7 @@ const ICON_SPONSOR_TIERS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `square` — available in Filled U+E12C, Regular U+E12A, Regular/Tiny U+E136, Filled/Tiny U+E138
 const ICON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6604,6 +15199,7 @@ const ICON_SQUARE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `square-circle` — available in Regular U+E12B, Regular/Tiny U+E137
 const ICON_SQUARE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6622,6 +15218,7 @@ const ICON_SQUARE_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `squirrel` — available in Regular U+E12D, Regular/Tiny U+E139
 const ICON_SQUIRREL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6640,6 +15237,7 @@ const ICON_SQUIRREL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `stack` — available in Regular U+E12E, Regular/Tiny U+E13A
 const ICON_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6658,6 +15256,7 @@ const ICON_STACK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `star` — available in Filled U+E130, Regular U+E12F, Regular/Tiny U+E13B, Filled/Tiny U+E13C
 const ICON_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6691,6 +15290,7 @@ const ICON_STAR_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `stop` — available in Regular U+E131, Regular/Tiny U+E13D
 const ICON_STOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6709,6 +15309,7 @@ const ICON_STOP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `stopwatch` — available in Regular U+E132, Regular/Tiny U+E13E
 const ICON_STOPWATCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6727,6 +15328,7 @@ const ICON_STOPWATCH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `strikethrough` — available in Regular U+E133, Regular/Tiny U+E13F
 const ICON_STRIKETHROUGH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6745,6 +15347,7 @@ const ICON_STRIKETHROUGH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sun` — available in Regular U+E134, Regular/Tiny U+E140
 const ICON_SUN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6763,6 +15366,7 @@ const ICON_SUN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `sync` — available in Regular U+E135, Regular/Tiny U+E141
 const ICON_SYNC_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6781,6 +15385,7 @@ const ICON_SYNC_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tab` — available in Regular U+E136, Regular/Tiny U+E142
 const ICON_TAB_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6799,6 +15404,7 @@ const ICON_TAB_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tab-external` — available in Regular U+E137, Regular/Tiny U+E143
 const ICON_TAB_EXTERNAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6817,6 +15423,7 @@ const ICON_TAB_EXTERNAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `table` — available in Regular U+E138, Regular/Tiny U+E144
 const ICON_TABLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6835,6 +15442,7 @@ const ICON_TABLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tag` — available in Regular U+E139, Regular/Tiny U+E145
 const ICON_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6853,6 +15461,7 @@ const ICON_TAG_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tasklist` — available in Regular U+E13A, Regular/Tiny U+E146
 const ICON_TASKLIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6871,6 +15480,7 @@ const ICON_TASKLIST_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `telescope` — available in Filled U+E13C, Regular U+E13B, Regular/Tiny U+E147, Filled/Tiny U+E148
 const ICON_TELESCOPE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6904,6 +15514,7 @@ const ICON_TELESCOPE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `terminal` — available in Regular U+E13D, Regular/Tiny U+E149
 const ICON_TERMINAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6922,6 +15533,7 @@ const ICON_TERMINAL_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `three-bars` — available in Regular U+E13E, Regular/Tiny U+E14A
 const ICON_THREE_BARS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6940,6 +15552,7 @@ const ICON_THREE_BARS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `thumbsdown` — available in Regular U+E13F, Regular/Tiny U+E14B
 const ICON_THUMBSDOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6958,6 +15571,7 @@ const ICON_THUMBSDOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `thumbsup` — available in Regular U+E140, Regular/Tiny U+E14C
 const ICON_THUMBSUP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6976,6 +15590,7 @@ const ICON_THUMBSUP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tools` — available in Regular U+E141, Regular/Tiny U+E14D
 const ICON_TOOLS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -6994,6 +15609,7 @@ const ICON_TOOLS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tracked-by-closed-completed` — available in Regular U+E142, Regular/Tiny U+E14E
 const ICON_TRACKED_BY_CLOSED_COMPLETED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7012,6 +15628,7 @@ const ICON_TRACKED_BY_CLOSED_COMPLETED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `tracked-by-closed-not-planned` — available in Regular U+E143, Regular/Tiny U+E14F
 const ICON_TRACKED_BY_CLOSED_NOT_PLANNED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7030,6 +15647,7 @@ const ICON_TRACKED_BY_CLOSED_NOT_PLANNED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `trash` — available in Regular U+E144, Regular/Tiny U+E150
 const ICON_TRASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7048,6 +15666,7 @@ const ICON_TRASH_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `triangle-down` — available in Regular U+E145, Regular/Tiny U+E151
 const ICON_TRIANGLE_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7066,6 +15685,7 @@ const ICON_TRIANGLE_DOWN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `triangle-left` — available in Regular U+E146, Regular/Tiny U+E152
 const ICON_TRIANGLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7084,6 +15704,7 @@ const ICON_TRIANGLE_LEFT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `triangle-right` — available in Regular U+E147, Regular/Tiny U+E153
 const ICON_TRIANGLE_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7102,6 +15723,7 @@ const ICON_TRIANGLE_RIGHT_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `triangle-up` — available in Regular U+E148, Regular/Tiny U+E154
 const ICON_TRIANGLE_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7120,6 +15742,7 @@ const ICON_TRIANGLE_UP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `trophy` — available in Regular U+E149, Regular/Tiny U+E155
 const ICON_TROPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7138,6 +15761,7 @@ const ICON_TROPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `typography` — available in Regular U+E14A, Regular/Tiny U+E156
 const ICON_TYPOGRAPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7156,6 +15780,7 @@ const ICON_TYPOGRAPHY_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `undo` — available in Regular U+E14B, Regular/Tiny U+E157
 const ICON_UNDO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7174,6 +15799,7 @@ const ICON_UNDO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `unfold` — available in Regular U+E14C, Regular/Tiny U+E158
 const ICON_UNFOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7192,6 +15818,7 @@ const ICON_UNFOLD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `unlink` — available in Regular U+E14D, Regular/Tiny U+E159
 const ICON_UNLINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7210,6 +15837,7 @@ const ICON_UNLINK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `unlock` — available in Regular U+E14E, Regular/Tiny U+E15A
 const ICON_UNLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7228,6 +15856,7 @@ const ICON_UNLOCK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `unmute` — available in Regular U+E14F, Regular/Tiny U+E15B
 const ICON_UNMUTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7246,6 +15875,7 @@ const ICON_UNMUTE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `unread` — available in Regular U+E150, Regular/Tiny U+E15C
 const ICON_UNREAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7264,6 +15894,7 @@ const ICON_UNREAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `unverified` — available in Regular U+E151, Regular/Tiny U+E15D
 const ICON_UNVERIFIED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7282,6 +15913,7 @@ const ICON_UNVERIFIED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `unwrap` — available in Regular U+E152, Regular/Tiny U+E15E
 const ICON_UNWRAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7300,6 +15932,7 @@ const ICON_UNWRAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `upload` — available in Regular U+E153, Regular/Tiny U+E15F
 const ICON_UPLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7318,6 +15951,7 @@ const ICON_UPLOAD_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `verified` — available in Regular U+E154, Regular/Tiny U+E160
 const ICON_VERIFIED_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7336,6 +15970,7 @@ const ICON_VERIFIED_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `versions` — available in Regular U+E155, Regular/Tiny U+E161
 const ICON_VERSIONS_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7354,6 +15989,7 @@ const ICON_VERSIONS_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `video` — available in Regular U+E156, Regular/Tiny U+E162
 const ICON_VIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7372,6 +16008,8 @@ const ICON_VIDEO_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `vscode` — U+E163 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_VSCODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -7383,6 +16021,8 @@ const ICON_VSCODE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `webhook` — U+E164 (available in Regular/Tiny)
+#[cfg(feature = "octicons-tiny")]
 const ICON_WEBHOOK_CODEPOINTS: &[(VariantKey, u32)] = &[
     #[cfg(feature = "octicons-tiny")]
     (
@@ -7394,6 +16034,7 @@ const ICON_WEBHOOK_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `workflow` — available in Regular U+E159, Regular/Tiny U+E165
 const ICON_WORKFLOW_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7412,6 +16053,7 @@ const ICON_WORKFLOW_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `wrap` — available in Regular U+E15A, Regular/Tiny U+E166
 const ICON_WRAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7430,6 +16072,7 @@ const ICON_WRAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `x` — available in Regular U+E15C, Regular/Tiny U+E167
 const ICON_X_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7448,6 +16091,7 @@ const ICON_X_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `x-circle` — available in Filled U+E15F, Regular U+E15D, Regular/Tiny U+E168, Filled/Tiny U+E169
 const ICON_X_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7481,6 +16125,7 @@ const ICON_X_CIRCLE_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `zap` — available in Regular U+E160, Regular/Tiny U+E16A
 const ICON_ZAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7499,6 +16144,7 @@ const ICON_ZAP_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `zoom-in` — available in Regular U+E161, Regular/Tiny U+E16B
 const ICON_ZOOM_IN_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7517,6 +16163,7 @@ const ICON_ZOOM_IN_CODEPOINTS: &[(VariantKey, u32)] = &[
     ),
 ];
 
+/// `zoom-out` — available in Regular U+E162, Regular/Tiny U+E16C
 const ICON_ZOOM_OUT_CODEPOINTS: &[(VariantKey, u32)] = &[
     (
         VariantKey {
@@ -7686,6 +16333,7 @@ const ICON_BOOKMARK_AVAILABLE: &[(Style, Size)] = &[
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_BOOKMARK_FILLED_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
@@ -7925,11 +16573,13 @@ const ICON_COPILOT_AVAILABLE: &[(Style, Size)] = &[
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_COPILOT_ERROR_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_COPILOT_WARNING_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
@@ -8130,96 +16780,115 @@ const ICON_EYE_CLOSED_AVAILABLE: &[(Style, Size)] = &[
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_DISCUSSION_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_FORKED_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_HEART_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_CLOSED_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_DRAFT_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_OPEN_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ISSUE_REOPEN_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_MERGED_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PERSON_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PLUS_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PUBLIC_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PULL_REQUEST_CLOSED_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PULL_REQUEST_DRAFT_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_PULL_REQUEST_OPEN_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_REPO_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_ROCKET_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_STAR_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_TAG_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_FEED_TROPHY_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
@@ -9064,6 +17733,7 @@ const ICON_REPO_CLONE_AVAILABLE: &[(Style, Size)] = &[
 
 const ICON_REPO_DELETE_AVAILABLE: &[(Style, Size)] = &[(Style::Regular, Size::Regular)];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_REPO_DELETED_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
@@ -9594,11 +18264,13 @@ const ICON_VIDEO_AVAILABLE: &[(Style, Size)] = &[
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_VSCODE_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(feature = "octicons-tiny")]
 const ICON_WEBHOOK_AVAILABLE: &[(Style, Size)] = &[
     #[cfg(feature = "octicons-tiny")]
     (Style::Regular, Size::Tiny),
@@ -9649,12 +18321,14 @@ const ICON_ZOOM_OUT_AVAILABLE: &[(Style, Size)] = &[
     (Style::Regular, Size::Tiny),
 ];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconCodepoints {
     pub name: &'static str,
     pub codepoints: &'static [(VariantKey, u32)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     IconCodepoints {
         name: "accessibility",
@@ -9752,6 +18426,7 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
         name: "bookmark",
         codepoints: ICON_BOOKMARK_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "bookmark-filled",
         codepoints: ICON_BOOKMARK_FILLED_CODEPOINTS,
@@ -9904,10 +18579,12 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
         name: "copilot",
         codepoints: ICON_COPILOT_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "copilot-error",
         codepoints: ICON_COPILOT_ERROR_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "copilot-warning",
         codepoints: ICON_COPILOT_WARNING_CODEPOINTS,
@@ -10040,78 +18717,97 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
         name: "eye-closed",
         codepoints: ICON_EYE_CLOSED_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-discussion",
         codepoints: ICON_FEED_DISCUSSION_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-forked",
         codepoints: ICON_FEED_FORKED_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-heart",
         codepoints: ICON_FEED_HEART_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-issue-closed",
         codepoints: ICON_FEED_ISSUE_CLOSED_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-issue-draft",
         codepoints: ICON_FEED_ISSUE_DRAFT_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-issue-open",
         codepoints: ICON_FEED_ISSUE_OPEN_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-issue-reopen",
         codepoints: ICON_FEED_ISSUE_REOPEN_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-merged",
         codepoints: ICON_FEED_MERGED_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-person",
         codepoints: ICON_FEED_PERSON_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-plus",
         codepoints: ICON_FEED_PLUS_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-public",
         codepoints: ICON_FEED_PUBLIC_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-pull-request-closed",
         codepoints: ICON_FEED_PULL_REQUEST_CLOSED_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-pull-request-draft",
         codepoints: ICON_FEED_PULL_REQUEST_DRAFT_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-pull-request-open",
         codepoints: ICON_FEED_PULL_REQUEST_OPEN_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-repo",
         codepoints: ICON_FEED_REPO_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-rocket",
         codepoints: ICON_FEED_ROCKET_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-star",
         codepoints: ICON_FEED_STAR_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-tag",
         codepoints: ICON_FEED_TAG_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "feed-trophy",
         codepoints: ICON_FEED_TROPHY_CODEPOINTS,
@@ -10668,6 +19364,7 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
         name: "repo-delete",
         codepoints: ICON_REPO_DELETE_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "repo-deleted",
         codepoints: ICON_REPO_DELETED_CODEPOINTS,
@@ -11012,10 +19709,12 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
         name: "video",
         codepoints: ICON_VIDEO_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "vscode",
         codepoints: ICON_VSCODE_CODEPOINTS,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconCodepoints {
         name: "webhook",
         codepoints: ICON_WEBHOOK_CODEPOINTS,
@@ -11050,12 +19749,14 @@ pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[
     },
 ];
 
+#[cfg(test)]
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct IconAvailability {
     pub name: &'static str,
     pub available: &'static [(Style, Size)],
 }
 
+#[cfg(test)]
 pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     IconAvailability {
         name: "accessibility",
@@ -11153,6 +19854,7 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
         name: "bookmark",
         available: ICON_BOOKMARK_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "bookmark-filled",
         available: ICON_BOOKMARK_FILLED_AVAILABLE,
@@ -11305,10 +20007,12 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
         name: "copilot",
         available: ICON_COPILOT_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "copilot-error",
         available: ICON_COPILOT_ERROR_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "copilot-warning",
         available: ICON_COPILOT_WARNING_AVAILABLE,
@@ -11441,78 +20145,97 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
         name: "eye-closed",
         available: ICON_EYE_CLOSED_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-discussion",
         available: ICON_FEED_DISCUSSION_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-forked",
         available: ICON_FEED_FORKED_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-heart",
         available: ICON_FEED_HEART_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-issue-closed",
         available: ICON_FEED_ISSUE_CLOSED_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-issue-draft",
         available: ICON_FEED_ISSUE_DRAFT_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-issue-open",
         available: ICON_FEED_ISSUE_OPEN_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-issue-reopen",
         available: ICON_FEED_ISSUE_REOPEN_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-merged",
         available: ICON_FEED_MERGED_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-person",
         available: ICON_FEED_PERSON_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-plus",
         available: ICON_FEED_PLUS_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-public",
         available: ICON_FEED_PUBLIC_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-pull-request-closed",
         available: ICON_FEED_PULL_REQUEST_CLOSED_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-pull-request-draft",
         available: ICON_FEED_PULL_REQUEST_DRAFT_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-pull-request-open",
         available: ICON_FEED_PULL_REQUEST_OPEN_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-repo",
         available: ICON_FEED_REPO_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-rocket",
         available: ICON_FEED_ROCKET_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-star",
         available: ICON_FEED_STAR_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-tag",
         available: ICON_FEED_TAG_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "feed-trophy",
         available: ICON_FEED_TROPHY_AVAILABLE,
@@ -12069,6 +20792,7 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
         name: "repo-delete",
         available: ICON_REPO_DELETE_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "repo-deleted",
         available: ICON_REPO_DELETED_AVAILABLE,
@@ -12413,10 +21137,12 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
         name: "video",
         available: ICON_VIDEO_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "vscode",
         available: ICON_VSCODE_AVAILABLE,
     },
+    #[cfg(feature = "octicons-tiny")]
     IconAvailability {
         name: "webhook",
         available: ICON_WEBHOOK_AVAILABLE,
@@ -12451,6 +21177,773 @@ pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[
     },
 ];
 
+pub(crate) const ICON_COLORS: &[(&str, [u8; 4])] = &[];
+
+/// Accessible labels, index-aligned with `ICON_NAMES`.
+pub(crate) const ICON_LABELS: &[&str] = &[
+    "Accessibility",
+    "Accessibility Inset",
+    "Agent",
+    "Ai Model",
+    "Alert",
+    "Apps",
+    "Archive",
+    "Arrow Both",
+    "Arrow Down",
+    "Arrow Down Left",
+    "Arrow Down Right",
+    "Arrow Left",
+    "Arrow Right",
+    "Arrow Switch",
+    "Arrow Up",
+    "Arrow Up Left",
+    "Arrow Up Right",
+    "Beaker",
+    "Bell",
+    "Bell Slash",
+    "Blocked",
+    "Bold",
+    "Book",
+    "Bookmark",
+    #[cfg(feature = "octicons-tiny")]
+    "Bookmark Filled",
+    "Bookmark Slash",
+    "Boolean Off",
+    "Boolean On",
+    "Briefcase",
+    "Broadcast",
+    "Browser",
+    "Bug",
+    "Cache",
+    "Calendar",
+    "Check",
+    "Check Circle",
+    "Checkbox",
+    "Checklist",
+    "Chevron Down",
+    "Chevron Left",
+    "Chevron Right",
+    "Chevron Up",
+    "Circle",
+    "Circle Slash",
+    "Clock",
+    "Cloud",
+    "Cloud Offline",
+    "Code",
+    "Code Of Conduct",
+    "Code Review",
+    "Code Square",
+    "Codescan",
+    "Codescan Checkmark",
+    "Codespaces",
+    "Columns",
+    "Command Palette",
+    "Comment",
+    "Comment Ai",
+    "Comment Discussion",
+    "Compose",
+    "Container",
+    "Copilot",
+    #[cfg(feature = "octicons-tiny")]
+    "Copilot Error",
+    #[cfg(feature = "octicons-tiny")]
+    "Copilot Warning",
+    "Copy",
+    "Cpu",
+    "Credit Card",
+    "Cross Reference",
+    "Crosshairs",
+    "Dash",
+    "Database",
+    "Dependabot",
+    "Desktop Download",
+    "Device Camera",
+    "Device Camera Video",
+    "Device Desktop",
+    "Device Mobile",
+    "Devices",
+    "Diamond",
+    "Dice",
+    "Diff",
+    "Diff Added",
+    "Diff Ignored",
+    "Diff Modified",
+    "Diff Removed",
+    "Diff Renamed",
+    "Discussion Closed",
+    "Discussion Duplicate",
+    "Discussion Outdated",
+    "Dot",
+    "Download",
+    "Duplicate",
+    "Ellipsis",
+    "Exclamation",
+    "Eye",
+    "Eye Closed",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Discussion",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Forked",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Heart",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Issue Closed",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Issue Draft",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Issue Open",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Issue Reopen",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Merged",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Person",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Plus",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Public",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Pull Request Closed",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Pull Request Draft",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Pull Request Open",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Repo",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Rocket",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Star",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Tag",
+    #[cfg(feature = "octicons-tiny")]
+    "Feed Trophy",
+    "File",
+    "File Added",
+    "File Badge",
+    "File Binary",
+    "File Check",
+    "File Code",
+    "File Diff",
+    "File Directory",
+    "File Directory Open",
+    "File Directory Symlink",
+    "File Media",
+    "File Moved",
+    "File Removed",
+    "File Submodule",
+    "File Symlink File",
+    "File Zip",
+    "Filter",
+    "Filter Remove",
+    "Fiscal Host",
+    "Flame",
+    "Flowchart",
+    "Focus Center",
+    "Fold",
+    "Fold Down",
+    "Fold Up",
+    "Gear",
+    "Gift",
+    "Git Branch",
+    "Git Branch Check",
+    "Git Commit",
+    "Git Compare",
+    "Git Merge",
+    "Git Merge Queue",
+    "Git Pull Request",
+    "Git Pull Request Closed",
+    "Git Pull Request Draft",
+    "Globe",
+    "Goal",
+    "Grabber",
+    "Graph",
+    "Graph Bar Horizontal",
+    "Graph Bar Vertical",
+    "Hash",
+    "Heading",
+    "Heart",
+    "History",
+    "Home",
+    "Horizontal Rule",
+    "Hourglass",
+    "Hubot",
+    "Id Badge",
+    "Image",
+    "Inbox",
+    "Infinity",
+    "Info",
+    "Issue Closed",
+    "Issue Draft",
+    "Issue Opened",
+    "Issue Reopened",
+    "Issue Tracked By",
+    "Issue Tracks",
+    "Italic",
+    "Iterations",
+    "Kebab Horizontal",
+    "Key",
+    "Key Asterisk",
+    "Law",
+    "Light Bulb",
+    "Link",
+    "Link External",
+    "List Ordered",
+    "List Unordered",
+    "Location",
+    "Lock",
+    "Log",
+    "Logo Gist",
+    "Logo Github",
+    "Loop",
+    "Mail",
+    "Mark Github",
+    "Markdown",
+    "Maximize",
+    "Mcp",
+    "Megaphone",
+    "Mention",
+    "Meter",
+    "Milestone",
+    "Minimize",
+    "Mirror",
+    "Moon",
+    "Mortar Board",
+    "Move To Bottom",
+    "Move To End",
+    "Move To Start",
+    "Move To Top",
+    "Multi Select",
+    "Mute",
+    "No Entry",
+    "Node",
+    "North Star",
+    "Note",
+    "Number",
+    "Organization",
+    "Package",
+    "Package Dependencies",
+    "Package Dependents",
+    "Paintbrush",
+    "Paper Airplane",
+    "Paperclip",
+    "Passkey",
+    "Paste",
+    "Pause",
+    "Pencil",
+    "Pencil Ai",
+    "People",
+    "Person",
+    "Person Add",
+    "Pin",
+    "Pin Slash",
+    "Pivot Column",
+    "Play",
+    "Plug",
+    "Plus",
+    "Plus Circle",
+    "Project",
+    "Project Roadmap",
+    "Project Symlink",
+    "Project Template",
+    "Pulse",
+    "Question",
+    "Quote",
+    "Read",
+    "Redo",
+    "Rel File Path",
+    "Reply",
+    "Repo",
+    "Repo Clone",
+    "Repo Delete",
+    #[cfg(feature = "octicons-tiny")]
+    "Repo Deleted",
+    "Repo Forked",
+    "Repo Locked",
+    "Repo Pull",
+    "Repo Push",
+    "Repo Template",
+    "Report",
+    "Rocket",
+    "Rows",
+    "Rss",
+    "Ruby",
+    "Screen Full",
+    "Screen Normal",
+    "Search",
+    "Server",
+    "Share",
+    "Share Android",
+    "Shield",
+    "Shield Check",
+    "Shield Lock",
+    "Shield Slash",
+    "Shield X",
+    "Sidebar Collapse",
+    "Sidebar Expand",
+    "Sign In",
+    "Sign Out",
+    "Single Select",
+    "Skip",
+    "Sliders",
+    "Smiley",
+    "Smiley Frown",
+    "Smiley Frustrated",
+    "Smiley Grin",
+    "Smiley Neutral",
+    "Sort Asc",
+    "Sort Desc",
+    "Space",
+    "Spacing Large",
+    "Spacing Medium",
+    "Spacing Small",
+    "Sparkle",
+    "Sparkles",
+    "Split View",
+    "Sponsor Tiers",
+    "Square",
+    "Square Circle",
+    "Squirrel",
+    "Stack",
+    "Star",
+    "Stop",
+    "Stopwatch",
+    "Strikethrough",
+    "Sun",
+    "Sync",
+    "Tab",
+    "Tab External",
+    "Table",
+    "Tag",
+    "Tasklist",
+    "Telescope",
+    "Terminal",
+    "Three Bars",
+    "Thumbsdown",
+    "Thumbsup",
+    "Tools",
+    "Tracked By Closed Completed",
+    "Tracked By Closed Not Planned",
+    "Trash",
+    "Triangle Down",
+    "Triangle Left",
+    "Triangle Right",
+    "Triangle Up",
+    "Trophy",
+    "Typography",
+    "Undo",
+    "Unfold",
+    "Unlink",
+    "Unlock",
+    "Unmute",
+    "Unread",
+    "Unverified",
+    "Unwrap",
+    "Upload",
+    "Verified",
+    "Versions",
+    "Video",
+    #[cfg(feature = "octicons-tiny")]
+    "Vscode",
+    #[cfg(feature = "octicons-tiny")]
+    "Webhook",
+    "Workflow",
+    "Wrap",
+    "X",
+    "X Circle",
+    "Zap",
+    "Zoom In",
+    "Zoom Out",
+];
+
+/// Stable icon ids, sorted by id.
+pub(crate) const ICON_IDS: &[(u32, &str)] = &[
+    (0x00a878f3, "compose"),
+    (0x0179def5, "dash"),
+    (0x02cef468, "shield-slash"),
+    (0x03374982, "globe"),
+    (0x03a12517, "screen-normal"),
+    (0x0494602e, "arrow-both"),
+    (0x0496bbc4, "mark-github"),
+    (0x04da9268, "file-added"),
+    (0x0582ab34, "arrow-up"),
+    (0x06389b18, "checkbox"),
+    (0x0642b47e, "arrow-down-right"),
+    (0x0689854c, "plus-circle"),
+    (0x06d5a845, "question"),
+    (0x07531d8f, "browser"),
+    (0x097849f4, "single-select"),
+    (0x097acf9a, "sync"),
+    (0x09b3787d, "milestone"),
+    (0x0a285164, "triangle-down"),
+    (0x0b4675e2, "chevron-up"),
+    (0x0bf5a9a6, "location"),
+    (0x0ceba988, "git-merge-queue"),
+    (0x0ddb0669, "link"),
+    (0x0e59521d, "hourglass"),
+    (0x0eaa25e3, "heart"),
+    (0x0fb40705, "info"),
+    (0x10edb7af, "focus-center"),
+    (0x111e5f8f, "maximize"),
+    (0x115f7bf5, "sort-desc"),
+    (0x139850da, "git-branch"),
+    (0x13ba9793, "boolean-off"),
+    (0x146960c5, "bookmark-slash"),
+    (0x14bd5cfc, "thumbsdown"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x150c3b44, "feed-repo"),
+    (0x15a40f5d, "clock"),
+    (0x166f6876, "goal"),
+    (0x17264b0a, "filter-remove"),
+    (0x19bb34eb, "report"),
+    (0x19e1c999, "sponsor-tiers"),
+    (0x19e727d3, "chevron-right"),
+    (0x1a46f3a4, "pivot-column"),
+    (0x1ac6a97e, "cloud"),
+    (0x1bd670a0, "number"),
+    (0x1c14db4d, "ruby"),
+    (0x1eb28c44, "strikethrough"),
+    (0x1ecdca95, "diff-added"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x1f5470d3, "vscode"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x213cf958, "feed-rocket"),
+    (0x23679223, "code-of-conduct"),
+    (0x24025703, "ai-model"),
+    (0x24687632, "sparkles"),
+    (0x265dafe5, "cache"),
+    (0x26dd8f5e, "squirrel"),
+    (0x278928c1, "arrow-down"),
+    (0x278c5325, "codescan"),
+    (0x27e59a22, "diff-modified"),
+    (0x281c1798, "sign-in"),
+    (0x28217089, "circle"),
+    (0x29931627, "reply"),
+    (0x29d9ed3a, "issue-opened"),
+    (0x29e2080a, "desktop-download"),
+    (0x2a37b398, "rows"),
+    (0x2b1cd835, "sparkle"),
+    (0x2b472083, "smiley-frown"),
+    (0x2b52960a, "fold"),
+    (0x2bd992aa, "triangle-right"),
+    (0x2c0a5c17, "paperclip"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x2faa52c8, "feed-tag"),
+    (0x2fc941c2, "mirror"),
+    (0x2fdc6133, "rss"),
+    (0x304a4b35, "mention"),
+    (0x3108b3f9, "download"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x32c60f90, "feed-merged"),
+    (0x337519b0, "shield"),
+    (0x3532ef3d, "arrow-switch"),
+    (0x3553e285, "space"),
+    (0x356dedef, "package-dependencies"),
+    (0x35f6ef60, "smiley-neutral"),
+    (0x38f02ca5, "diff-renamed"),
+    (0x3900233c, "grabber"),
+    (0x3a66b6c0, "fold-up"),
+    (0x3bbde2ff, "trophy"),
+    (0x3bed729b, "git-pull-request-draft"),
+    (0x3c219e15, "discussion-duplicate"),
+    (0x3c5c055c, "upload"),
+    (0x3cda11cd, "repo-clone"),
+    (0x3f3880bd, "thumbsup"),
+    (0x3f515151, "log"),
+    (0x3f793b04, "cloud-offline"),
+    (0x40ac3dd2, "server"),
+    (0x41341eba, "device-desktop"),
+    (0x416b7bc2, "skip"),
+    (0x42a7fc0e, "file-symlink-file"),
+    (0x43ad5579, "infinity"),
+    (0x43b1b457, "device-camera-video"),
+    (0x447677e7, "dependabot"),
+    (0x44f2d2d8, "discussion-outdated"),
+    (0x450ee975, "square-circle"),
+    (0x453bd135, "sort-asc"),
+    (0x46629a88, "command-palette"),
+    (0x46d0c5cf, "boolean-on"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x46d9c24c, "copilot-error"),
+    (0x4902c242, "person-add"),
+    (0x4984aba9, "device-camera"),
+    (0x49f38209, "unfold"),
+    (0x4a7181df, "history"),
+    (0x4a9c9bdf, "table"),
+    (0x4e3eb663, "tracked-by-closed-not-planned"),
+    (0x4f60750b, "law"),
+    (0x4fe7f1a6, "agent"),
+    (0x50cc08c3, "briefcase"),
+    (0x51d6cb02, "logo-github"),
+    (0x51fb8e19, "bookmark"),
+    (0x5387fcd8, "project-symlink"),
+    (0x550bfe9e, "pulse"),
+    (0x55cfdd48, "three-bars"),
+    (0x55f06109, "triangle-left"),
+    (0x56661f55, "unlock"),
+    (0x585b885a, "file-check"),
+    (0x5a5d6eb3, "blocked"),
+    (0x5ae63e52, "unlink"),
+    (0x5b37c0d6, "dice"),
+    (0x5cffc60e, "move-to-bottom"),
+    (0x5e644e05, "trash"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x5ef4a296, "feed-pull-request-closed"),
+    (0x60e9fb6d, "italic"),
+    (0x625163ff, "stack"),
+    (0x625f5c9b, "codespaces"),
+    (0x64ee3779, "mortar-board"),
+    (0x67a6c45e, "comment"),
+    (0x6815c86c, "key"),
+    (0x682c9c5a, "diff"),
+    (0x68c24300, "list-unordered"),
+    (0x6c0a0cff, "accessibility"),
+    (0x6ec8c86f, "issue-reopened"),
+    (0x6eeac737, "hubot"),
+    (0x6f410694, "pin"),
+    (0x7084d38d, "pause"),
+    (0x71dfc27a, "sidebar-expand"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x71e94809, "feed-issue-draft"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x722773fd, "feed-person"),
+    (0x73d58d75, "bug"),
+    (0x7533a1b5, "circle-slash"),
+    (0x7555f89b, "unmute"),
+    (0x7756c167, "north-star"),
+    (0x78627a04, "pencil"),
+    (0x7886b02f, "accessibility-inset"),
+    (0x7a6111d7, "issue-tracked-by"),
+    (0x7b06ff2e, "gear"),
+    (0x7b62d2f7, "cross-reference"),
+    (0x7b953678, "spacing-large"),
+    (0x7ba86b63, "code-review"),
+    (0x7bf68149, "shield-check"),
+    (0x7c696443, "copilot"),
+    (0x7d238db9, "file-moved"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x7e34c015, "feed-forked"),
+    (0x7fdbf4a5, "wrap"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x8014ef4c, "bookmark-filled"),
+    (0x803328a9, "search"),
+    (0x810b6cc2, "ellipsis"),
+    (0x8178dd55, "comment-discussion"),
+    (0x818a4cb7, "git-branch-check"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x825ff648, "feed-plus"),
+    (0x826e1638, "bell"),
+    (0x82af3166, "move-to-end"),
+    (0x8340e2a3, "chevron-down"),
+    (0x848dc29c, "pin-slash"),
+    (0x850361b5, "diff-ignored"),
+    (0x85a1d367, "file-directory-symlink"),
+    (0x86584d00, "flame"),
+    (0x86758b03, "comment-ai"),
+    (0x872d24fe, "chevron-left"),
+    (0x87a1ec56, "repo-locked"),
+    (0x886553d2, "horizontal-rule"),
+    (0x88a2c3ed, "repo-forked"),
+    (0x89592c13, "telescope"),
+    (0x895fe98b, "issue-closed"),
+    (0x8a275685, "multi-select"),
+    (0x8a7324ef, "gift"),
+    (0x8aa6541a, "paper-airplane"),
+    (0x8ab31a3c, "columns"),
+    (0x8ae150c7, "passkey"),
+    (0x8ba8ed1a, "eye"),
+    (0x8c082940, "bell-slash"),
+    (0x8cd99c70, "shield-lock"),
+    (0x8daff178, "container"),
+    (0x8eb5bb46, "package-dependents"),
+    (0x8f256630, "crosshairs"),
+    (0x8fced5c8, "stopwatch"),
+    (0x90d46842, "arrow-up-left"),
+    (0x913b2bfb, "package"),
+    (0x919a0c3d, "note"),
+    (0x92ee8396, "duplicate"),
+    (0x95d107c3, "split-view"),
+    (0x95ef264f, "issue-tracks"),
+    (0x95f72993, "tag"),
+    (0x963335ee, "people"),
+    #[cfg(feature = "octicons-tiny")]
+    (0x98a449c9, "feed-public"),
+    (0x98a6adaf, "arrow-up-right"),
+    (0x98f72e4c, "tab"),
+    (0x98fe03c5, "archive"),
+    (0x99f40ab1, "terminal"),
+    (0x9b95e23c, "zap"),
+    (0x9e095cc7, "zoom-out"),
+    (0x9f481715, "heading"),
+    (0x9f57303d, "minimize"),
+    (0xa0367f44, "graph-bar-vertical"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xa064f192, "feed-pull-request-open"),
+    (0xa0b9cdd4, "tasklist"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xa0ebd926, "webhook"),
+    (0xa10a8b80, "devices"),
+    (0xa1176cd0, "file-submodule"),
+    (0xa165ddb8, "database"),
+    (0xa276ab8d, "shield-x"),
+    (0xa286b8c5, "verified"),
+    (0xa477ceba, "repo-template"),
+    (0xa4810d1f, "calendar"),
+    (0xa5aca540, "spacing-small"),
+    (0xa6ba83de, "file-removed"),
+    (0xa6e78eaf, "repo-pull"),
+    (0xa7212b4c, "markdown"),
+    (0xa74abf49, "file-directory"),
+    (0xa7629831, "file-zip"),
+    (0xa9246794, "repo-push"),
+    (0xa9c9fc38, "share"),
+    (0xaab361eb, "file-binary"),
+    (0xaaea5743, "file"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xab114d16, "copilot-warning"),
+    (0xac3b172f, "project-roadmap"),
+    (0xac4fabcb, "diff-removed"),
+    (0xad1bf1ac, "device-mobile"),
+    (0xad69036d, "iterations"),
+    (0xad89f690, "tab-external"),
+    (0xb0ada6f9, "plug"),
+    (0xb0bc69f4, "fiscal-host"),
+    (0xb16f10a7, "diamond"),
+    (0xb1c14e0d, "node"),
+    (0xb2887bd7, "quote"),
+    (0xb2e353c4, "code-square"),
+    (0xb35135fa, "image"),
+    (0xb3711c47, "check"),
+    (0xb48ff679, "screen-full"),
+    (0xb4b61246, "square"),
+    (0xb4df7ad2, "issue-draft"),
+    (0xb66efbea, "organization"),
+    (0xb7c358f9, "alert"),
+    (0xb9780647, "graph"),
+    (0xb9f6a871, "paintbrush"),
+    (0xba0ee5b5, "git-pull-request"),
+    (0xbdc42201, "codescan-checkmark"),
+    (0xbe666961, "file-badge"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xbe71b43d, "repo-deleted"),
+    (0xbf91d451, "file-diff"),
+    (0xbfd6d49a, "git-merge"),
+    (0xc1948a38, "book"),
+    (0xc234a5d6, "exclamation"),
+    (0xc2cbd863, "play"),
+    (0xc305a495, "pencil-ai"),
+    (0xc4adc675, "plus"),
+    (0xc4e1892d, "list-ordered"),
+    (0xc5611fe2, "meter"),
+    (0xc6c088f5, "light-bulb"),
+    (0xc731a490, "move-to-top"),
+    (0xc7e16877, "filter"),
+    (0xc867061d, "sliders"),
+    (0xc8f650ec, "versions"),
+    (0xc98a7fe1, "megaphone"),
+    (0xcb532ae5, "stop"),
+    (0xcba8e063, "mcp"),
+    (0xcbf06af6, "id-badge"),
+    (0xcbfc9b33, "move-to-start"),
+    (0xcc154b7a, "file-media"),
+    (0xcc8bdfcb, "smiley-grin"),
+    (0xccebc521, "kebab-horizontal"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xcd22fd2b, "feed-issue-reopen"),
+    (0xcdc65942, "smiley"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xce6cbae2, "feed-issue-open"),
+    (0xcec577d1, "hash"),
+    (0xcedfa3c5, "read"),
+    (0xcef90b6c, "video"),
+    (0xcfeeaac3, "no-entry"),
+    (0xd070423f, "rel-file-path"),
+    (0xd0e28766, "check-circle"),
+    (0xd13b2f71, "star"),
+    (0xd27bbdff, "beaker"),
+    (0xd2c8c28e, "home"),
+    (0xd3689f20, "dot"),
+    (0xd397b280, "person"),
+    (0xd3acce8d, "apps"),
+    (0xd3b4ca9d, "repo"),
+    (0xd3b7c80c, "typography"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xd4e47238, "feed-star"),
+    (0xd5523242, "unverified"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xd5862c14, "feed-heart"),
+    (0xd5e309db, "link-external"),
+    (0xd6069f54, "mute"),
+    (0xd7d848a9, "arrow-right"),
+    (0xd879a9b5, "fold-down"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xd8c4d3f8, "feed-discussion"),
+    (0xdd9873e6, "zoom-in"),
+    (0xddef486b, "loop"),
+    (0xde96f676, "bold"),
+    (0xded516e8, "paste"),
+    (0xdff7eb44, "discussion-closed"),
+    (0xe04e3f61, "sun"),
+    (0xe16a8ce9, "git-compare"),
+    (0xe1f20462, "unread"),
+    (0xe252d53b, "inbox"),
+    (0xe2d789d4, "broadcast"),
+    (0xe2fbd2f7, "smiley-frustrated"),
+    (0xe3129ec8, "git-pull-request-closed"),
+    (0xe416dcaf, "key-asterisk"),
+    (0xe418134f, "checklist"),
+    (0xe536575c, "arrow-left"),
+    (0xe562ea44, "copy"),
+    (0xe67c0055, "rocket"),
+    (0xe78b4b2a, "logo-gist"),
+    (0xe99a8e31, "sidebar-collapse"),
+    (0xe9f93f05, "triangle-up"),
+    (0xeb311a26, "x-circle"),
+    (0xec90e52e, "mail"),
+    (0xedb5790e, "share-android"),
+    (0xef0d7842, "lock"),
+    (0xef46255c, "tools"),
+    (0xf109cb30, "tracked-by-closed-completed"),
+    (0xf16d5ca8, "workflow"),
+    (0xf184ae40, "moon"),
+    (0xf1c10dbe, "unwrap"),
+    (0xf1d0feaf, "undo"),
+    (0xf1fcf21d, "sign-out"),
+    (0xf29ff16c, "file-directory-open"),
+    (0xf2e2cc35, "eye-closed"),
+    (0xf3e699c9, "redo"),
+    (0xf4750662, "graph-bar-horizontal"),
+    (0xf4bd82be, "project"),
+    (0xf4c451bb, "flowchart"),
+    (0xf5e14cb2, "spacing-medium"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xf71138e6, "feed-issue-closed"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xf76f4239, "feed-pull-request-draft"),
+    #[cfg(feature = "octicons-tiny")]
+    (0xf8a3b88a, "feed-trophy"),
+    (0xf8dbf57d, "credit-card"),
+    (0xf8e4548b, "repo-delete"),
+    (0xf9316cf4, "code"),
+    (0xf94af989, "cpu"),
+    (0xf96beaa5, "arrow-down-left"),
+    (0xf9b6b78f, "git-commit"),
+    (0xfae268af, "project-template"),
+    (0xfd0c5087, "x"),
+    (0xfeb1d4a7, "file-code"),
+];
+
+pub(crate) const ICON_RTL_MIRROR: &[&str] = &[
+    "arrow-down-left",
+    "arrow-down-right",
+    "arrow-left",
+    "arrow-right",
+    "arrow-up-left",
+    "arrow-up-right",
+    "chevron-left",
+    "chevron-right",
+];
+
 pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantInfo> {
     VARIANTS
         .iter()
@@ -12458,21 +21951,815 @@ pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantI
 }
 
 pub(crate) fn icon_codepoint(name: &str, key: VariantKey) -> Option<u32> {
-    ICON_CODEPOINTS
+    let codepoints: &[(VariantKey, u32)] = match name {
+        "accessibility" => ICON_ACCESSIBILITY_CODEPOINTS,
+        "accessibility-inset" => ICON_ACCESSIBILITY_INSET_CODEPOINTS,
+        "agent" => ICON_AGENT_CODEPOINTS,
+        "ai-model" => ICON_AI_MODEL_CODEPOINTS,
+        "alert" => ICON_ALERT_CODEPOINTS,
+        "apps" => ICON_APPS_CODEPOINTS,
+        "archive" => ICON_ARCHIVE_CODEPOINTS,
+        "arrow-both" => ICON_ARROW_BOTH_CODEPOINTS,
+        "arrow-down" => ICON_ARROW_DOWN_CODEPOINTS,
+        "arrow-down-left" => ICON_ARROW_DOWN_LEFT_CODEPOINTS,
+        "arrow-down-right" => ICON_ARROW_DOWN_RIGHT_CODEPOINTS,
+        "arrow-left" => ICON_ARROW_LEFT_CODEPOINTS,
+        "arrow-right" => ICON_ARROW_RIGHT_CODEPOINTS,
+        "arrow-switch" => ICON_ARROW_SWITCH_CODEPOINTS,
+        "arrow-up" => ICON_ARROW_UP_CODEPOINTS,
+        "arrow-up-left" => ICON_ARROW_UP_LEFT_CODEPOINTS,
+        "arrow-up-right" => ICON_ARROW_UP_RIGHT_CODEPOINTS,
+        "beaker" => ICON_BEAKER_CODEPOINTS,
+        "bell" => ICON_BELL_CODEPOINTS,
+        "bell-slash" => ICON_BELL_SLASH_CODEPOINTS,
+        "blocked" => ICON_BLOCKED_CODEPOINTS,
+        "bold" => ICON_BOLD_CODEPOINTS,
+        "book" => ICON_BOOK_CODEPOINTS,
+        "bookmark" => ICON_BOOKMARK_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "bookmark-filled" => ICON_BOOKMARK_FILLED_CODEPOINTS,
+        "bookmark-slash" => ICON_BOOKMARK_SLASH_CODEPOINTS,
+        "boolean-off" => ICON_BOOLEAN_OFF_CODEPOINTS,
+        "boolean-on" => ICON_BOOLEAN_ON_CODEPOINTS,
+        "briefcase" => ICON_BRIEFCASE_CODEPOINTS,
+        "broadcast" => ICON_BROADCAST_CODEPOINTS,
+        "browser" => ICON_BROWSER_CODEPOINTS,
+        "bug" => ICON_BUG_CODEPOINTS,
+        "cache" => ICON_CACHE_CODEPOINTS,
+        "calendar" => ICON_CALENDAR_CODEPOINTS,
+        "check" => ICON_CHECK_CODEPOINTS,
+        "check-circle" => ICON_CHECK_CIRCLE_CODEPOINTS,
+        "checkbox" => ICON_CHECKBOX_CODEPOINTS,
+        "checklist" => ICON_CHECKLIST_CODEPOINTS,
+        "chevron-down" => ICON_CHEVRON_DOWN_CODEPOINTS,
+        "chevron-left" => ICON_CHEVRON_LEFT_CODEPOINTS,
+        "chevron-right" => ICON_CHEVRON_RIGHT_CODEPOINTS,
+        "chevron-up" => ICON_CHEVRON_UP_CODEPOINTS,
+        "circle" => ICON_CIRCLE_CODEPOINTS,
+        "circle-slash" => ICON_CIRCLE_SLASH_CODEPOINTS,
+        "clock" => ICON_CLOCK_CODEPOINTS,
+        "cloud" => ICON_CLOUD_CODEPOINTS,
+        "cloud-offline" => ICON_CLOUD_OFFLINE_CODEPOINTS,
+        "code" => ICON_CODE_CODEPOINTS,
+        "code-of-conduct" => ICON_CODE_OF_CONDUCT_CODEPOINTS,
+        "code-review" => ICON_CODE_REVIEW_CODEPOINTS,
+        "code-square" => ICON_CODE_SQUARE_CODEPOINTS,
+        "codescan" => ICON_CODESCAN_CODEPOINTS,
+        "codescan-checkmark" => ICON_CODESCAN_CHECKMARK_CODEPOINTS,
+        "codespaces" => ICON_CODESPACES_CODEPOINTS,
+        "columns" => ICON_COLUMNS_CODEPOINTS,
+        "command-palette" => ICON_COMMAND_PALETTE_CODEPOINTS,
+        "comment" => ICON_COMMENT_CODEPOINTS,
+        "comment-ai" => ICON_COMMENT_AI_CODEPOINTS,
+        "comment-discussion" => ICON_COMMENT_DISCUSSION_CODEPOINTS,
+        "compose" => ICON_COMPOSE_CODEPOINTS,
+        "container" => ICON_CONTAINER_CODEPOINTS,
+        "copilot" => ICON_COPILOT_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "copilot-error" => ICON_COPILOT_ERROR_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "copilot-warning" => ICON_COPILOT_WARNING_CODEPOINTS,
+        "copy" => ICON_COPY_CODEPOINTS,
+        "cpu" => ICON_CPU_CODEPOINTS,
+        "credit-card" => ICON_CREDIT_CARD_CODEPOINTS,
+        "cross-reference" => ICON_CROSS_REFERENCE_CODEPOINTS,
+        "crosshairs" => ICON_CROSSHAIRS_CODEPOINTS,
+        "dash" => ICON_DASH_CODEPOINTS,
+        "database" => ICON_DATABASE_CODEPOINTS,
+        "dependabot" => ICON_DEPENDABOT_CODEPOINTS,
+        "desktop-download" => ICON_DESKTOP_DOWNLOAD_CODEPOINTS,
+        "device-camera" => ICON_DEVICE_CAMERA_CODEPOINTS,
+        "device-camera-video" => ICON_DEVICE_CAMERA_VIDEO_CODEPOINTS,
+        "device-desktop" => ICON_DEVICE_DESKTOP_CODEPOINTS,
+        "device-mobile" => ICON_DEVICE_MOBILE_CODEPOINTS,
+        "devices" => ICON_DEVICES_CODEPOINTS,
+        "diamond" => ICON_DIAMOND_CODEPOINTS,
+        "dice" => ICON_DICE_CODEPOINTS,
+        "diff" => ICON_DIFF_CODEPOINTS,
+        "diff-added" => ICON_DIFF_ADDED_CODEPOINTS,
+        "diff-ignored" => ICON_DIFF_IGNORED_CODEPOINTS,
+        "diff-modified" => ICON_DIFF_MODIFIED_CODEPOINTS,
+        "diff-removed" => ICON_DIFF_REMOVED_CODEPOINTS,
+        "diff-renamed" => ICON_DIFF_RENAMED_CODEPOINTS,
+        "discussion-closed" => ICON_DISCUSSION_CLOSED_CODEPOINTS,
+        "discussion-duplicate" => ICON_DISCUSSION_DUPLICATE_CODEPOINTS,
+        "discussion-outdated" => ICON_DISCUSSION_OUTDATED_CODEPOINTS,
+        "dot" => ICON_DOT_CODEPOINTS,
+        "download" => ICON_DOWNLOAD_CODEPOINTS,
+        "duplicate" => ICON_DUPLICATE_CODEPOINTS,
+        "ellipsis" => ICON_ELLIPSIS_CODEPOINTS,
+        "exclamation" => ICON_EXCLAMATION_CODEPOINTS,
+        "eye" => ICON_EYE_CODEPOINTS,
+        "eye-closed" => ICON_EYE_CLOSED_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-discussion" => ICON_FEED_DISCUSSION_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-forked" => ICON_FEED_FORKED_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-heart" => ICON_FEED_HEART_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-closed" => ICON_FEED_ISSUE_CLOSED_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-draft" => ICON_FEED_ISSUE_DRAFT_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-open" => ICON_FEED_ISSUE_OPEN_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-reopen" => ICON_FEED_ISSUE_REOPEN_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-merged" => ICON_FEED_MERGED_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-person" => ICON_FEED_PERSON_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-plus" => ICON_FEED_PLUS_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-public" => ICON_FEED_PUBLIC_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-pull-request-closed" => ICON_FEED_PULL_REQUEST_CLOSED_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-pull-request-draft" => ICON_FEED_PULL_REQUEST_DRAFT_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-pull-request-open" => ICON_FEED_PULL_REQUEST_OPEN_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-repo" => ICON_FEED_REPO_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-rocket" => ICON_FEED_ROCKET_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-star" => ICON_FEED_STAR_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-tag" => ICON_FEED_TAG_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "feed-trophy" => ICON_FEED_TROPHY_CODEPOINTS,
+        "file" => ICON_FILE_CODEPOINTS,
+        "file-added" => ICON_FILE_ADDED_CODEPOINTS,
+        "file-badge" => ICON_FILE_BADGE_CODEPOINTS,
+        "file-binary" => ICON_FILE_BINARY_CODEPOINTS,
+        "file-check" => ICON_FILE_CHECK_CODEPOINTS,
+        "file-code" => ICON_FILE_CODE_CODEPOINTS,
+        "file-diff" => ICON_FILE_DIFF_CODEPOINTS,
+        "file-directory" => ICON_FILE_DIRECTORY_CODEPOINTS,
+        "file-directory-open" => ICON_FILE_DIRECTORY_OPEN_CODEPOINTS,
+        "file-directory-symlink" => ICON_FILE_DIRECTORY_SYMLINK_CODEPOINTS,
+        "file-media" => ICON_FILE_MEDIA_CODEPOINTS,
+        "file-moved" => ICON_FILE_MOVED_CODEPOINTS,
+        "file-removed" => ICON_FILE_REMOVED_CODEPOINTS,
+        "file-submodule" => ICON_FILE_SUBMODULE_CODEPOINTS,
+        "file-symlink-file" => ICON_FILE_SYMLINK_FILE_CODEPOINTS,
+        "file-zip" => ICON_FILE_ZIP_CODEPOINTS,
+        "filter" => ICON_FILTER_CODEPOINTS,
+        "filter-remove" => ICON_FILTER_REMOVE_CODEPOINTS,
+        "fiscal-host" => ICON_FISCAL_HOST_CODEPOINTS,
+        "flame" => ICON_FLAME_CODEPOINTS,
+        "flowchart" => ICON_FLOWCHART_CODEPOINTS,
+        "focus-center" => ICON_FOCUS_CENTER_CODEPOINTS,
+        "fold" => ICON_FOLD_CODEPOINTS,
+        "fold-down" => ICON_FOLD_DOWN_CODEPOINTS,
+        "fold-up" => ICON_FOLD_UP_CODEPOINTS,
+        "gear" => ICON_GEAR_CODEPOINTS,
+        "gift" => ICON_GIFT_CODEPOINTS,
+        "git-branch" => ICON_GIT_BRANCH_CODEPOINTS,
+        "git-branch-check" => ICON_GIT_BRANCH_CHECK_CODEPOINTS,
+        "git-commit" => ICON_GIT_COMMIT_CODEPOINTS,
+        "git-compare" => ICON_GIT_COMPARE_CODEPOINTS,
+        "git-merge" => ICON_GIT_MERGE_CODEPOINTS,
+        "git-merge-queue" => ICON_GIT_MERGE_QUEUE_CODEPOINTS,
+        "git-pull-request" => ICON_GIT_PULL_REQUEST_CODEPOINTS,
+        "git-pull-request-closed" => ICON_GIT_PULL_REQUEST_CLOSED_CODEPOINTS,
+        "git-pull-request-draft" => ICON_GIT_PULL_REQUEST_DRAFT_CODEPOINTS,
+        "globe" => ICON_GLOBE_CODEPOINTS,
+        "goal" => ICON_GOAL_CODEPOINTS,
+        "grabber" => ICON_GRABBER_CODEPOINTS,
+        "graph" => ICON_GRAPH_CODEPOINTS,
+        "graph-bar-horizontal" => ICON_GRAPH_BAR_HORIZONTAL_CODEPOINTS,
+        "graph-bar-vertical" => ICON_GRAPH_BAR_VERTICAL_CODEPOINTS,
+        "hash" => ICON_HASH_CODEPOINTS,
+        "heading" => ICON_HEADING_CODEPOINTS,
+        "heart" => ICON_HEART_CODEPOINTS,
+        "history" => ICON_HISTORY_CODEPOINTS,
+        "home" => ICON_HOME_CODEPOINTS,
+        "horizontal-rule" => ICON_HORIZONTAL_RULE_CODEPOINTS,
+        "hourglass" => ICON_HOURGLASS_CODEPOINTS,
+        "hubot" => ICON_HUBOT_CODEPOINTS,
+        "id-badge" => ICON_ID_BADGE_CODEPOINTS,
+        "image" => ICON_IMAGE_CODEPOINTS,
+        "inbox" => ICON_INBOX_CODEPOINTS,
+        "infinity" => ICON_INFINITY_CODEPOINTS,
+        "info" => ICON_INFO_CODEPOINTS,
+        "issue-closed" => ICON_ISSUE_CLOSED_CODEPOINTS,
+        "issue-draft" => ICON_ISSUE_DRAFT_CODEPOINTS,
+        "issue-opened" => ICON_ISSUE_OPENED_CODEPOINTS,
+        "issue-reopened" => ICON_ISSUE_REOPENED_CODEPOINTS,
+        "issue-tracked-by" => ICON_ISSUE_TRACKED_BY_CODEPOINTS,
+        "issue-tracks" => ICON_ISSUE_TRACKS_CODEPOINTS,
+        "italic" => ICON_ITALIC_CODEPOINTS,
+        "iterations" => ICON_ITERATIONS_CODEPOINTS,
+        "kebab-horizontal" => ICON_KEBAB_HORIZONTAL_CODEPOINTS,
+        "key" => ICON_KEY_CODEPOINTS,
+        "key-asterisk" => ICON_KEY_ASTERISK_CODEPOINTS,
+        "law" => ICON_LAW_CODEPOINTS,
+        "light-bulb" => ICON_LIGHT_BULB_CODEPOINTS,
+        "link" => ICON_LINK_CODEPOINTS,
+        "link-external" => ICON_LINK_EXTERNAL_CODEPOINTS,
+        "list-ordered" => ICON_LIST_ORDERED_CODEPOINTS,
+        "list-unordered" => ICON_LIST_UNORDERED_CODEPOINTS,
+        "location" => ICON_LOCATION_CODEPOINTS,
+        "lock" => ICON_LOCK_CODEPOINTS,
+        "log" => ICON_LOG_CODEPOINTS,
+        "logo-gist" => ICON_LOGO_GIST_CODEPOINTS,
+        "logo-github" => ICON_LOGO_GITHUB_CODEPOINTS,
+        "loop" => ICON_LOOP__CODEPOINTS,
+        "mail" => ICON_MAIL_CODEPOINTS,
+        "mark-github" => ICON_MARK_GITHUB_CODEPOINTS,
+        "markdown" => ICON_MARKDOWN_CODEPOINTS,
+        "maximize" => ICON_MAXIMIZE_CODEPOINTS,
+        "mcp" => ICON_MCP_CODEPOINTS,
+        "megaphone" => ICON_MEGAPHONE_CODEPOINTS,
+        "mention" => ICON_MENTION_CODEPOINTS,
+        "meter" => ICON_METER_CODEPOINTS,
+        "milestone" => ICON_MILESTONE_CODEPOINTS,
+        "minimize" => ICON_MINIMIZE_CODEPOINTS,
+        "mirror" => ICON_MIRROR_CODEPOINTS,
+        "moon" => ICON_MOON_CODEPOINTS,
+        "mortar-board" => ICON_MORTAR_BOARD_CODEPOINTS,
+        "move-to-bottom" => ICON_MOVE_TO_BOTTOM_CODEPOINTS,
+        "move-to-end" => ICON_MOVE_TO_END_CODEPOINTS,
+        "move-to-start" => ICON_MOVE_TO_START_CODEPOINTS,
+        "move-to-top" => ICON_MOVE_TO_TOP_CODEPOINTS,
+        "multi-select" => ICON_MULTI_SELECT_CODEPOINTS,
+        "mute" => ICON_MUTE_CODEPOINTS,
+        "no-entry" => ICON_NO_ENTRY_CODEPOINTS,
+        "node" => ICON_NODE_CODEPOINTS,
+        "north-star" => ICON_NORTH_STAR_CODEPOINTS,
+        "note" => ICON_NOTE_CODEPOINTS,
+        "number" => ICON_NUMBER_CODEPOINTS,
+        "organization" => ICON_ORGANIZATION_CODEPOINTS,
+        "package" => ICON_PACKAGE_CODEPOINTS,
+        "package-dependencies" => ICON_PACKAGE_DEPENDENCIES_CODEPOINTS,
+        "package-dependents" => ICON_PACKAGE_DEPENDENTS_CODEPOINTS,
+        "paintbrush" => ICON_PAINTBRUSH_CODEPOINTS,
+        "paper-airplane" => ICON_PAPER_AIRPLANE_CODEPOINTS,
+        "paperclip" => ICON_PAPERCLIP_CODEPOINTS,
+        "passkey" => ICON_PASSKEY_CODEPOINTS,
+        "paste" => ICON_PASTE_CODEPOINTS,
+        "pause" => ICON_PAUSE_CODEPOINTS,
+        "pencil" => ICON_PENCIL_CODEPOINTS,
+        "pencil-ai" => ICON_PENCIL_AI_CODEPOINTS,
+        "people" => ICON_PEOPLE_CODEPOINTS,
+        "person" => ICON_PERSON_CODEPOINTS,
+        "person-add" => ICON_PERSON_ADD_CODEPOINTS,
+        "pin" => ICON_PIN_CODEPOINTS,
+        "pin-slash" => ICON_PIN_SLASH_CODEPOINTS,
+        "pivot-column" => ICON_PIVOT_COLUMN_CODEPOINTS,
+        "play" => ICON_PLAY_CODEPOINTS,
+        "plug" => ICON_PLUG_CODEPOINTS,
+        "plus" => ICON_PLUS_CODEPOINTS,
+        "plus-circle" => ICON_PLUS_CIRCLE_CODEPOINTS,
+        "project" => ICON_PROJECT_CODEPOINTS,
+        "project-roadmap" => ICON_PROJECT_ROADMAP_CODEPOINTS,
+        "project-symlink" => ICON_PROJECT_SYMLINK_CODEPOINTS,
+        "project-template" => ICON_PROJECT_TEMPLATE_CODEPOINTS,
+        "pulse" => ICON_PULSE_CODEPOINTS,
+        "question" => ICON_QUESTION_CODEPOINTS,
+        "quote" => ICON_QUOTE_CODEPOINTS,
+        "read" => ICON_READ_CODEPOINTS,
+        "redo" => ICON_REDO_CODEPOINTS,
+        "rel-file-path" => ICON_REL_FILE_PATH_CODEPOINTS,
+        "reply" => ICON_REPLY_CODEPOINTS,
+        "repo" => ICON_REPO_CODEPOINTS,
+        "repo-clone" => ICON_REPO_CLONE_CODEPOINTS,
+        "repo-delete" => ICON_REPO_DELETE_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "repo-deleted" => ICON_REPO_DELETED_CODEPOINTS,
+        "repo-forked" => ICON_REPO_FORKED_CODEPOINTS,
+        "repo-locked" => ICON_REPO_LOCKED_CODEPOINTS,
+        "repo-pull" => ICON_REPO_PULL_CODEPOINTS,
+        "repo-push" => ICON_REPO_PUSH_CODEPOINTS,
+        "repo-template" => ICON_REPO_TEMPLATE_CODEPOINTS,
+        "report" => ICON_REPORT_CODEPOINTS,
+        "rocket" => ICON_ROCKET_CODEPOINTS,
+        "rows" => ICON_ROWS_CODEPOINTS,
+        "rss" => ICON_RSS_CODEPOINTS,
+        "ruby" => ICON_RUBY_CODEPOINTS,
+        "screen-full" => ICON_SCREEN_FULL_CODEPOINTS,
+        "screen-normal" => ICON_SCREEN_NORMAL_CODEPOINTS,
+        "search" => ICON_SEARCH_CODEPOINTS,
+        "server" => ICON_SERVER_CODEPOINTS,
+        "share" => ICON_SHARE_CODEPOINTS,
+        "share-android" => ICON_SHARE_ANDROID_CODEPOINTS,
+        "shield" => ICON_SHIELD_CODEPOINTS,
+        "shield-check" => ICON_SHIELD_CHECK_CODEPOINTS,
+        "shield-lock" => ICON_SHIELD_LOCK_CODEPOINTS,
+        "shield-slash" => ICON_SHIELD_SLASH_CODEPOINTS,
+        "shield-x" => ICON_SHIELD_X_CODEPOINTS,
+        "sidebar-collapse" => ICON_SIDEBAR_COLLAPSE_CODEPOINTS,
+        "sidebar-expand" => ICON_SIDEBAR_EXPAND_CODEPOINTS,
+        "sign-in" => ICON_SIGN_IN_CODEPOINTS,
+        "sign-out" => ICON_SIGN_OUT_CODEPOINTS,
+        "single-select" => ICON_SINGLE_SELECT_CODEPOINTS,
+        "skip" => ICON_SKIP_CODEPOINTS,
+        "sliders" => ICON_SLIDERS_CODEPOINTS,
+        "smiley" => ICON_SMILEY_CODEPOINTS,
+        "smiley-frown" => ICON_SMILEY_FROWN_CODEPOINTS,
+        "smiley-frustrated" => ICON_SMILEY_FRUSTRATED_CODEPOINTS,
+        "smiley-grin" => ICON_SMILEY_GRIN_CODEPOINTS,
+        "smiley-neutral" => ICON_SMILEY_NEUTRAL_CODEPOINTS,
+        "sort-asc" => ICON_SORT_ASC_CODEPOINTS,
+        "sort-desc" => ICON_SORT_DESC_CODEPOINTS,
+        "space" => ICON_SPACE_CODEPOINTS,
+        "spacing-large" => ICON_SPACING_LARGE_CODEPOINTS,
+        "spacing-medium" => ICON_SPACING_MEDIUM_CODEPOINTS,
+        "spacing-small" => ICON_SPACING_SMALL_CODEPOINTS,
+        "sparkle" => ICON_SPARKLE_CODEPOINTS,
+        "sparkles" => ICON_SPARKLES_CODEPOINTS,
+        "split-view" => ICON_SPLIT_VIEW_CODEPOINTS,
+        "sponsor-tiers" => ICON_SPONSOR_TIERS_CODEPOINTS,
+        "square" => ICON_SQUARE_CODEPOINTS,
+        "square-circle" => ICON_SQUARE_CIRCLE_CODEPOINTS,
+        "squirrel" => ICON_SQUIRREL_CODEPOINTS,
+        "stack" => ICON_STACK_CODEPOINTS,
+        "star" => ICON_STAR_CODEPOINTS,
+        "stop" => ICON_STOP_CODEPOINTS,
+        "stopwatch" => ICON_STOPWATCH_CODEPOINTS,
+        "strikethrough" => ICON_STRIKETHROUGH_CODEPOINTS,
+        "sun" => ICON_SUN_CODEPOINTS,
+        "sync" => ICON_SYNC_CODEPOINTS,
+        "tab" => ICON_TAB_CODEPOINTS,
+        "tab-external" => ICON_TAB_EXTERNAL_CODEPOINTS,
+        "table" => ICON_TABLE_CODEPOINTS,
+        "tag" => ICON_TAG_CODEPOINTS,
+        "tasklist" => ICON_TASKLIST_CODEPOINTS,
+        "telescope" => ICON_TELESCOPE_CODEPOINTS,
+        "terminal" => ICON_TERMINAL_CODEPOINTS,
+        "three-bars" => ICON_THREE_BARS_CODEPOINTS,
+        "thumbsdown" => ICON_THUMBSDOWN_CODEPOINTS,
+        "thumbsup" => ICON_THUMBSUP_CODEPOINTS,
+        "tools" => ICON_TOOLS_CODEPOINTS,
+        "tracked-by-closed-completed" => ICON_TRACKED_BY_CLOSED_COMPLETED_CODEPOINTS,
+        "tracked-by-closed-not-planned" => ICON_TRACKED_BY_CLOSED_NOT_PLANNED_CODEPOINTS,
+        "trash" => ICON_TRASH_CODEPOINTS,
+        "triangle-down" => ICON_TRIANGLE_DOWN_CODEPOINTS,
+        "triangle-left" => ICON_TRIANGLE_LEFT_CODEPOINTS,
+        "triangle-right" => ICON_TRIANGLE_RIGHT_CODEPOINTS,
+        "triangle-up" => ICON_TRIANGLE_UP_CODEPOINTS,
+        "trophy" => ICON_TROPHY_CODEPOINTS,
+        "typography" => ICON_TYPOGRAPHY_CODEPOINTS,
+        "undo" => ICON_UNDO_CODEPOINTS,
+        "unfold" => ICON_UNFOLD_CODEPOINTS,
+        "unlink" => ICON_UNLINK_CODEPOINTS,
+        "unlock" => ICON_UNLOCK_CODEPOINTS,
+        "unmute" => ICON_UNMUTE_CODEPOINTS,
+        "unread" => ICON_UNREAD_CODEPOINTS,
+        "unverified" => ICON_UNVERIFIED_CODEPOINTS,
+        "unwrap" => ICON_UNWRAP_CODEPOINTS,
+        "upload" => ICON_UPLOAD_CODEPOINTS,
+        "verified" => ICON_VERIFIED_CODEPOINTS,
+        "versions" => ICON_VERSIONS_CODEPOINTS,
+        "video" => ICON_VIDEO_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "vscode" => ICON_VSCODE_CODEPOINTS,
+        #[cfg(feature = "octicons-tiny")]
+        "webhook" => ICON_WEBHOOK_CODEPOINTS,
+        "workflow" => ICON_WORKFLOW_CODEPOINTS,
+        "wrap" => ICON_WRAP_CODEPOINTS,
+        "x" => ICON_X_CODEPOINTS,
+        "x-circle" => ICON_X_CIRCLE_CODEPOINTS,
+        "zap" => ICON_ZAP_CODEPOINTS,
+        "zoom-in" => ICON_ZOOM_IN_CODEPOINTS,
+        "zoom-out" => ICON_ZOOM_OUT_CODEPOINTS,
+        _ => return None,
+    };
+    codepoints
         .iter()
-        .find(|entry| entry.name == name)
-        .and_then(|entry| {
-            entry
-                .codepoints
-                .iter()
-                .find(|(k, _)| *k == key)
-                .map(|(_, cp)| *cp)
-        })
+        .find(|(k, _)| *k == key)
+        .map(|(_, cp)| *cp)
+}
+
+pub(crate) fn canonical_name_ci(lowercase: &str) -> Option<&'static str> {
+    let index = ICON_NAMES_LOWERCASE.partition_point(|(lower, _)| *lower < lowercase);
+    ICON_NAMES_LOWERCASE
+        .get(index)
+        .filter(|(lower, _)| *lower == lowercase)
+        .map(|(_, name)| *name)
 }
 
 pub(crate) fn icon_available(name: &str) -> Option<&'static [(Style, Size)]> {
-    ICON_AVAILABILITY
-        .iter()
-        .find(|entry| entry.name == name)
-        .map(|entry| entry.available)
+    match name {
+        "accessibility" => Some(ICON_ACCESSIBILITY_AVAILABLE),
+        "accessibility-inset" => Some(ICON_ACCESSIBILITY_INSET_AVAILABLE),
+        "agent" => Some(ICON_AGENT_AVAILABLE),
+        "ai-model" => Some(ICON_AI_MODEL_AVAILABLE),
+        "alert" => Some(ICON_ALERT_AVAILABLE),
+        "apps" => Some(ICON_APPS_AVAILABLE),
+        "archive" => Some(ICON_ARCHIVE_AVAILABLE),
+        "arrow-both" => Some(ICON_ARROW_BOTH_AVAILABLE),
+        "arrow-down" => Some(ICON_ARROW_DOWN_AVAILABLE),
+        "arrow-down-left" => Some(ICON_ARROW_DOWN_LEFT_AVAILABLE),
+        "arrow-down-right" => Some(ICON_ARROW_DOWN_RIGHT_AVAILABLE),
+        "arrow-left" => Some(ICON_ARROW_LEFT_AVAILABLE),
+        "arrow-right" => Some(ICON_ARROW_RIGHT_AVAILABLE),
+        "arrow-switch" => Some(ICON_ARROW_SWITCH_AVAILABLE),
+        "arrow-up" => Some(ICON_ARROW_UP_AVAILABLE),
+        "arrow-up-left" => Some(ICON_ARROW_UP_LEFT_AVAILABLE),
+        "arrow-up-right" => Some(ICON_ARROW_UP_RIGHT_AVAILABLE),
+        "beaker" => Some(ICON_BEAKER_AVAILABLE),
+        "bell" => Some(ICON_BELL_AVAILABLE),
+        "bell-slash" => Some(ICON_BELL_SLASH_AVAILABLE),
+        "blocked" => Some(ICON_BLOCKED_AVAILABLE),
+        "bold" => Some(ICON_BOLD_AVAILABLE),
+        "book" => Some(ICON_BOOK_AVAILABLE),
+        "bookmark" => Some(ICON_BOOKMARK_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "bookmark-filled" => Some(ICON_BOOKMARK_FILLED_AVAILABLE),
+        "bookmark-slash" => Some(ICON_BOOKMARK_SLASH_AVAILABLE),
+        "boolean-off" => Some(ICON_BOOLEAN_OFF_AVAILABLE),
+        "boolean-on" => Some(ICON_BOOLEAN_ON_AVAILABLE),
+        "briefcase" => Some(ICON_BRIEFCASE_AVAILABLE),
+        "broadcast" => Some(ICON_BROADCAST_AVAILABLE),
+        "browser" => Some(ICON_BROWSER_AVAILABLE),
+        "bug" => Some(ICON_BUG_AVAILABLE),
+        "cache" => Some(ICON_CACHE_AVAILABLE),
+        "calendar" => Some(ICON_CALENDAR_AVAILABLE),
+        "check" => Some(ICON_CHECK_AVAILABLE),
+        "check-circle" => Some(ICON_CHECK_CIRCLE_AVAILABLE),
+        "checkbox" => Some(ICON_CHECKBOX_AVAILABLE),
+        "checklist" => Some(ICON_CHECKLIST_AVAILABLE),
+        "chevron-down" => Some(ICON_CHEVRON_DOWN_AVAILABLE),
+        "chevron-left" => Some(ICON_CHEVRON_LEFT_AVAILABLE),
+        "chevron-right" => Some(ICON_CHEVRON_RIGHT_AVAILABLE),
+        "chevron-up" => Some(ICON_CHEVRON_UP_AVAILABLE),
+        "circle" => Some(ICON_CIRCLE_AVAILABLE),
+        "circle-slash" => Some(ICON_CIRCLE_SLASH_AVAILABLE),
+        "clock" => Some(ICON_CLOCK_AVAILABLE),
+        "cloud" => Some(ICON_CLOUD_AVAILABLE),
+        "cloud-offline" => Some(ICON_CLOUD_OFFLINE_AVAILABLE),
+        "code" => Some(ICON_CODE_AVAILABLE),
+        "code-of-conduct" => Some(ICON_CODE_OF_CONDUCT_AVAILABLE),
+        "code-review" => Some(ICON_CODE_REVIEW_AVAILABLE),
+        "code-square" => Some(ICON_CODE_SQUARE_AVAILABLE),
+        "codescan" => Some(ICON_CODESCAN_AVAILABLE),
+        "codescan-checkmark" => Some(ICON_CODESCAN_CHECKMARK_AVAILABLE),
+        "codespaces" => Some(ICON_CODESPACES_AVAILABLE),
+        "columns" => Some(ICON_COLUMNS_AVAILABLE),
+        "command-palette" => Some(ICON_COMMAND_PALETTE_AVAILABLE),
+        "comment" => Some(ICON_COMMENT_AVAILABLE),
+        "comment-ai" => Some(ICON_COMMENT_AI_AVAILABLE),
+        "comment-discussion" => Some(ICON_COMMENT_DISCUSSION_AVAILABLE),
+        "compose" => Some(ICON_COMPOSE_AVAILABLE),
+        "container" => Some(ICON_CONTAINER_AVAILABLE),
+        "copilot" => Some(ICON_COPILOT_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "copilot-error" => Some(ICON_COPILOT_ERROR_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "copilot-warning" => Some(ICON_COPILOT_WARNING_AVAILABLE),
+        "copy" => Some(ICON_COPY_AVAILABLE),
+        "cpu" => Some(ICON_CPU_AVAILABLE),
+        "credit-card" => Some(ICON_CREDIT_CARD_AVAILABLE),
+        "cross-reference" => Some(ICON_CROSS_REFERENCE_AVAILABLE),
+        "crosshairs" => Some(ICON_CROSSHAIRS_AVAILABLE),
+        "dash" => Some(ICON_DASH_AVAILABLE),
+        "database" => Some(ICON_DATABASE_AVAILABLE),
+        "dependabot" => Some(ICON_DEPENDABOT_AVAILABLE),
+        "desktop-download" => Some(ICON_DESKTOP_DOWNLOAD_AVAILABLE),
+        "device-camera" => Some(ICON_DEVICE_CAMERA_AVAILABLE),
+        "device-camera-video" => Some(ICON_DEVICE_CAMERA_VIDEO_AVAILABLE),
+        "device-desktop" => Some(ICON_DEVICE_DESKTOP_AVAILABLE),
+        "device-mobile" => Some(ICON_DEVICE_MOBILE_AVAILABLE),
+        "devices" => Some(ICON_DEVICES_AVAILABLE),
+        "diamond" => Some(ICON_DIAMOND_AVAILABLE),
+        "dice" => Some(ICON_DICE_AVAILABLE),
+        "diff" => Some(ICON_DIFF_AVAILABLE),
+        "diff-added" => Some(ICON_DIFF_ADDED_AVAILABLE),
+        "diff-ignored" => Some(ICON_DIFF_IGNORED_AVAILABLE),
+        "diff-modified" => Some(ICON_DIFF_MODIFIED_AVAILABLE),
+        "diff-removed" => Some(ICON_DIFF_REMOVED_AVAILABLE),
+        "diff-renamed" => Some(ICON_DIFF_RENAMED_AVAILABLE),
+        "discussion-closed" => Some(ICON_DISCUSSION_CLOSED_AVAILABLE),
+        "discussion-duplicate" => Some(ICON_DISCUSSION_DUPLICATE_AVAILABLE),
+        "discussion-outdated" => Some(ICON_DISCUSSION_OUTDATED_AVAILABLE),
+        "dot" => Some(ICON_DOT_AVAILABLE),
+        "download" => Some(ICON_DOWNLOAD_AVAILABLE),
+        "duplicate" => Some(ICON_DUPLICATE_AVAILABLE),
+        "ellipsis" => Some(ICON_ELLIPSIS_AVAILABLE),
+        "exclamation" => Some(ICON_EXCLAMATION_AVAILABLE),
+        "eye" => Some(ICON_EYE_AVAILABLE),
+        "eye-closed" => Some(ICON_EYE_CLOSED_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-discussion" => Some(ICON_FEED_DISCUSSION_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-forked" => Some(ICON_FEED_FORKED_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-heart" => Some(ICON_FEED_HEART_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-closed" => Some(ICON_FEED_ISSUE_CLOSED_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-draft" => Some(ICON_FEED_ISSUE_DRAFT_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-open" => Some(ICON_FEED_ISSUE_OPEN_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-issue-reopen" => Some(ICON_FEED_ISSUE_REOPEN_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-merged" => Some(ICON_FEED_MERGED_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-person" => Some(ICON_FEED_PERSON_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-plus" => Some(ICON_FEED_PLUS_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-public" => Some(ICON_FEED_PUBLIC_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-pull-request-closed" => Some(ICON_FEED_PULL_REQUEST_CLOSED_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-pull-request-draft" => Some(ICON_FEED_PULL_REQUEST_DRAFT_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-pull-request-open" => Some(ICON_FEED_PULL_REQUEST_OPEN_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-repo" => Some(ICON_FEED_REPO_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-rocket" => Some(ICON_FEED_ROCKET_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-star" => Some(ICON_FEED_STAR_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-tag" => Some(ICON_FEED_TAG_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "feed-trophy" => Some(ICON_FEED_TROPHY_AVAILABLE),
+        "file" => Some(ICON_FILE_AVAILABLE),
+        "file-added" => Some(ICON_FILE_ADDED_AVAILABLE),
+        "file-badge" => Some(ICON_FILE_BADGE_AVAILABLE),
+        "file-binary" => Some(ICON_FILE_BINARY_AVAILABLE),
+        "file-check" => Some(ICON_FILE_CHECK_AVAILABLE),
+        "file-code" => Some(ICON_FILE_CODE_AVAILABLE),
+        "file-diff" => Some(ICON_FILE_DIFF_AVAILABLE),
+        "file-directory" => Some(ICON_FILE_DIRECTORY_AVAILABLE),
+        "file-directory-open" => Some(ICON_FILE_DIRECTORY_OPEN_AVAILABLE),
+        "file-directory-symlink" => Some(ICON_FILE_DIRECTORY_SYMLINK_AVAILABLE),
+        "file-media" => Some(ICON_FILE_MEDIA_AVAILABLE),
+        "file-moved" => Some(ICON_FILE_MOVED_AVAILABLE),
+        "file-removed" => Some(ICON_FILE_REMOVED_AVAILABLE),
+        "file-submodule" => Some(ICON_FILE_SUBMODULE_AVAILABLE),
+        "file-symlink-file" => Some(ICON_FILE_SYMLINK_FILE_AVAILABLE),
+        "file-zip" => Some(ICON_FILE_ZIP_AVAILABLE),
+        "filter" => Some(ICON_FILTER_AVAILABLE),
+        "filter-remove" => Some(ICON_FILTER_REMOVE_AVAILABLE),
+        "fiscal-host" => Some(ICON_FISCAL_HOST_AVAILABLE),
+        "flame" => Some(ICON_FLAME_AVAILABLE),
+        "flowchart" => Some(ICON_FLOWCHART_AVAILABLE),
+        "focus-center" => Some(ICON_FOCUS_CENTER_AVAILABLE),
+        "fold" => Some(ICON_FOLD_AVAILABLE),
+        "fold-down" => Some(ICON_FOLD_DOWN_AVAILABLE),
+        "fold-up" => Some(ICON_FOLD_UP_AVAILABLE),
+        "gear" => Some(ICON_GEAR_AVAILABLE),
+        "gift" => Some(ICON_GIFT_AVAILABLE),
+        "git-branch" => Some(ICON_GIT_BRANCH_AVAILABLE),
+        "git-branch-check" => Some(ICON_GIT_BRANCH_CHECK_AVAILABLE),
+        "git-commit" => Some(ICON_GIT_COMMIT_AVAILABLE),
+        "git-compare" => Some(ICON_GIT_COMPARE_AVAILABLE),
+        "git-merge" => Some(ICON_GIT_MERGE_AVAILABLE),
+        "git-merge-queue" => Some(ICON_GIT_MERGE_QUEUE_AVAILABLE),
+        "git-pull-request" => Some(ICON_GIT_PULL_REQUEST_AVAILABLE),
+        "git-pull-request-closed" => Some(ICON_GIT_PULL_REQUEST_CLOSED_AVAILABLE),
+        "git-pull-request-draft" => Some(ICON_GIT_PULL_REQUEST_DRAFT_AVAILABLE),
+        "globe" => Some(ICON_GLOBE_AVAILABLE),
+        "goal" => Some(ICON_GOAL_AVAILABLE),
+        "grabber" => Some(ICON_GRABBER_AVAILABLE),
+        "graph" => Some(ICON_GRAPH_AVAILABLE),
+        "graph-bar-horizontal" => Some(ICON_GRAPH_BAR_HORIZONTAL_AVAILABLE),
+        "graph-bar-vertical" => Some(ICON_GRAPH_BAR_VERTICAL_AVAILABLE),
+        "hash" => Some(ICON_HASH_AVAILABLE),
+        "heading" => Some(ICON_HEADING_AVAILABLE),
+        "heart" => Some(ICON_HEART_AVAILABLE),
+        "history" => Some(ICON_HISTORY_AVAILABLE),
+        "home" => Some(ICON_HOME_AVAILABLE),
+        "horizontal-rule" => Some(ICON_HORIZONTAL_RULE_AVAILABLE),
+        "hourglass" => Some(ICON_HOURGLASS_AVAILABLE),
+        "hubot" => Some(ICON_HUBOT_AVAILABLE),
+        "id-badge" => Some(ICON_ID_BADGE_AVAILABLE),
+        "image" => Some(ICON_IMAGE_AVAILABLE),
+        "inbox" => Some(ICON_INBOX_AVAILABLE),
+        "infinity" => Some(ICON_INFINITY_AVAILABLE),
+        "info" => Some(ICON_INFO_AVAILABLE),
+        "issue-closed" => Some(ICON_ISSUE_CLOSED_AVAILABLE),
+        "issue-draft" => Some(ICON_ISSUE_DRAFT_AVAILABLE),
+        "issue-opened" => Some(ICON_ISSUE_OPENED_AVAILABLE),
+        "issue-reopened" => Some(ICON_ISSUE_REOPENED_AVAILABLE),
+        "issue-tracked-by" => Some(ICON_ISSUE_TRACKED_BY_AVAILABLE),
+        "issue-tracks" => Some(ICON_ISSUE_TRACKS_AVAILABLE),
+        "italic" => Some(ICON_ITALIC_AVAILABLE),
+        "iterations" => Some(ICON_ITERATIONS_AVAILABLE),
+        "kebab-horizontal" => Some(ICON_KEBAB_HORIZONTAL_AVAILABLE),
+        "key" => Some(ICON_KEY_AVAILABLE),
+        "key-asterisk" => Some(ICON_KEY_ASTERISK_AVAILABLE),
+        "law" => Some(ICON_LAW_AVAILABLE),
+        "light-bulb" => Some(ICON_LIGHT_BULB_AVAILABLE),
+        "link" => Some(ICON_LINK_AVAILABLE),
+        "link-external" => Some(ICON_LINK_EXTERNAL_AVAILABLE),
+        "list-ordered" => Some(ICON_LIST_ORDERED_AVAILABLE),
+        "list-unordered" => Some(ICON_LIST_UNORDERED_AVAILABLE),
+        "location" => Some(ICON_LOCATION_AVAILABLE),
+        "lock" => Some(ICON_LOCK_AVAILABLE),
+        "log" => Some(ICON_LOG_AVAILABLE),
+        "logo-gist" => Some(ICON_LOGO_GIST_AVAILABLE),
+        "logo-github" => Some(ICON_LOGO_GITHUB_AVAILABLE),
+        "loop" => Some(ICON_LOOP__AVAILABLE),
+        "mail" => Some(ICON_MAIL_AVAILABLE),
+        "mark-github" => Some(ICON_MARK_GITHUB_AVAILABLE),
+        "markdown" => Some(ICON_MARKDOWN_AVAILABLE),
+        "maximize" => Some(ICON_MAXIMIZE_AVAILABLE),
+        "mcp" => Some(ICON_MCP_AVAILABLE),
+        "megaphone" => Some(ICON_MEGAPHONE_AVAILABLE),
+        "mention" => Some(ICON_MENTION_AVAILABLE),
+        "meter" => Some(ICON_METER_AVAILABLE),
+        "milestone" => Some(ICON_MILESTONE_AVAILABLE),
+        "minimize" => Some(ICON_MINIMIZE_AVAILABLE),
+        "mirror" => Some(ICON_MIRROR_AVAILABLE),
+        "moon" => Some(ICON_MOON_AVAILABLE),
+        "mortar-board" => Some(ICON_MORTAR_BOARD_AVAILABLE),
+        "move-to-bottom" => Some(ICON_MOVE_TO_BOTTOM_AVAILABLE),
+        "move-to-end" => Some(ICON_MOVE_TO_END_AVAILABLE),
+        "move-to-start" => Some(ICON_MOVE_TO_START_AVAILABLE),
+        "move-to-top" => Some(ICON_MOVE_TO_TOP_AVAILABLE),
+        "multi-select" => Some(ICON_MULTI_SELECT_AVAILABLE),
+        "mute" => Some(ICON_MUTE_AVAILABLE),
+        "no-entry" => Some(ICON_NO_ENTRY_AVAILABLE),
+        "node" => Some(ICON_NODE_AVAILABLE),
+        "north-star" => Some(ICON_NORTH_STAR_AVAILABLE),
+        "note" => Some(ICON_NOTE_AVAILABLE),
+        "number" => Some(ICON_NUMBER_AVAILABLE),
+        "organization" => Some(ICON_ORGANIZATION_AVAILABLE),
+        "package" => Some(ICON_PACKAGE_AVAILABLE),
+        "package-dependencies" => Some(ICON_PACKAGE_DEPENDENCIES_AVAILABLE),
+        "package-dependents" => Some(ICON_PACKAGE_DEPENDENTS_AVAILABLE),
+        "paintbrush" => Some(ICON_PAINTBRUSH_AVAILABLE),
+        "paper-airplane" => Some(ICON_PAPER_AIRPLANE_AVAILABLE),
+        "paperclip" => Some(ICON_PAPERCLIP_AVAILABLE),
+        "passkey" => Some(ICON_PASSKEY_AVAILABLE),
+        "paste" => Some(ICON_PASTE_AVAILABLE),
+        "pause" => Some(ICON_PAUSE_AVAILABLE),
+        "pencil" => Some(ICON_PENCIL_AVAILABLE),
+        "pencil-ai" => Some(ICON_PENCIL_AI_AVAILABLE),
+        "people" => Some(ICON_PEOPLE_AVAILABLE),
+        "person" => Some(ICON_PERSON_AVAILABLE),
+        "person-add" => Some(ICON_PERSON_ADD_AVAILABLE),
+        "pin" => Some(ICON_PIN_AVAILABLE),
+        "pin-slash" => Some(ICON_PIN_SLASH_AVAILABLE),
+        "pivot-column" => Some(ICON_PIVOT_COLUMN_AVAILABLE),
+        "play" => Some(ICON_PLAY_AVAILABLE),
+        "plug" => Some(ICON_PLUG_AVAILABLE),
+        "plus" => Some(ICON_PLUS_AVAILABLE),
+        "plus-circle" => Some(ICON_PLUS_CIRCLE_AVAILABLE),
+        "project" => Some(ICON_PROJECT_AVAILABLE),
+        "project-roadmap" => Some(ICON_PROJECT_ROADMAP_AVAILABLE),
+        "project-symlink" => Some(ICON_PROJECT_SYMLINK_AVAILABLE),
+        "project-template" => Some(ICON_PROJECT_TEMPLATE_AVAILABLE),
+        "pulse" => Some(ICON_PULSE_AVAILABLE),
+        "question" => Some(ICON_QUESTION_AVAILABLE),
+        "quote" => Some(ICON_QUOTE_AVAILABLE),
+        "read" => Some(ICON_READ_AVAILABLE),
+        "redo" => Some(ICON_REDO_AVAILABLE),
+        "rel-file-path" => Some(ICON_REL_FILE_PATH_AVAILABLE),
+        "reply" => Some(ICON_REPLY_AVAILABLE),
+        "repo" => Some(ICON_REPO_AVAILABLE),
+        "repo-clone" => Some(ICON_REPO_CLONE_AVAILABLE),
+        "repo-delete" => Some(ICON_REPO_DELETE_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "repo-deleted" => Some(ICON_REPO_DELETED_AVAILABLE),
+        "repo-forked" => Some(ICON_REPO_FORKED_AVAILABLE),
+        "repo-locked" => Some(ICON_REPO_LOCKED_AVAILABLE),
+        "repo-pull" => Some(ICON_REPO_PULL_AVAILABLE),
+        "repo-push" => Some(ICON_REPO_PUSH_AVAILABLE),
+        "repo-template" => Some(ICON_REPO_TEMPLATE_AVAILABLE),
+        "report" => Some(ICON_REPORT_AVAILABLE),
+        "rocket" => Some(ICON_ROCKET_AVAILABLE),
+        "rows" => Some(ICON_ROWS_AVAILABLE),
+        "rss" => Some(ICON_RSS_AVAILABLE),
+        "ruby" => Some(ICON_RUBY_AVAILABLE),
+        "screen-full" => Some(ICON_SCREEN_FULL_AVAILABLE),
+        "screen-normal" => Some(ICON_SCREEN_NORMAL_AVAILABLE),
+        "search" => Some(ICON_SEARCH_AVAILABLE),
+        "server" => Some(ICON_SERVER_AVAILABLE),
+        "share" => Some(ICON_SHARE_AVAILABLE),
+        "share-android" => Some(ICON_SHARE_ANDROID_AVAILABLE),
+        "shield" => Some(ICON_SHIELD_AVAILABLE),
+        "shield-check" => Some(ICON_SHIELD_CHECK_AVAILABLE),
+        "shield-lock" => Some(ICON_SHIELD_LOCK_AVAILABLE),
+        "shield-slash" => Some(ICON_SHIELD_SLASH_AVAILABLE),
+        "shield-x" => Some(ICON_SHIELD_X_AVAILABLE),
+        "sidebar-collapse" => Some(ICON_SIDEBAR_COLLAPSE_AVAILABLE),
+        "sidebar-expand" => Some(ICON_SIDEBAR_EXPAND_AVAILABLE),
+        "sign-in" => Some(ICON_SIGN_IN_AVAILABLE),
+        "sign-out" => Some(ICON_SIGN_OUT_AVAILABLE),
+        "single-select" => Some(ICON_SINGLE_SELECT_AVAILABLE),
+        "skip" => Some(ICON_SKIP_AVAILABLE),
+        "sliders" => Some(ICON_SLIDERS_AVAILABLE),
+        "smiley" => Some(ICON_SMILEY_AVAILABLE),
+        "smiley-frown" => Some(ICON_SMILEY_FROWN_AVAILABLE),
+        "smiley-frustrated" => Some(ICON_SMILEY_FRUSTRATED_AVAILABLE),
+        "smiley-grin" => Some(ICON_SMILEY_GRIN_AVAILABLE),
+        "smiley-neutral" => Some(ICON_SMILEY_NEUTRAL_AVAILABLE),
+        "sort-asc" => Some(ICON_SORT_ASC_AVAILABLE),
+        "sort-desc" => Some(ICON_SORT_DESC_AVAILABLE),
+        "space" => Some(ICON_SPACE_AVAILABLE),
+        "spacing-large" => Some(ICON_SPACING_LARGE_AVAILABLE),
+        "spacing-medium" => Some(ICON_SPACING_MEDIUM_AVAILABLE),
+        "spacing-small" => Some(ICON_SPACING_SMALL_AVAILABLE),
+        "sparkle" => Some(ICON_SPARKLE_AVAILABLE),
+        "sparkles" => Some(ICON_SPARKLES_AVAILABLE),
+        "split-view" => Some(ICON_SPLIT_VIEW_AVAILABLE),
+        "sponsor-tiers" => Some(ICON_SPONSOR_TIERS_AVAILABLE),
+        "square" => Some(ICON_SQUARE_AVAILABLE),
+        "square-circle" => Some(ICON_SQUARE_CIRCLE_AVAILABLE),
+        "squirrel" => Some(ICON_SQUIRREL_AVAILABLE),
+        "stack" => Some(ICON_STACK_AVAILABLE),
+        "star" => Some(ICON_STAR_AVAILABLE),
+        "stop" => Some(ICON_STOP_AVAILABLE),
+        "stopwatch" => Some(ICON_STOPWATCH_AVAILABLE),
+        "strikethrough" => Some(ICON_STRIKETHROUGH_AVAILABLE),
+        "sun" => Some(ICON_SUN_AVAILABLE),
+        "sync" => Some(ICON_SYNC_AVAILABLE),
+        "tab" => Some(ICON_TAB_AVAILABLE),
+        "tab-external" => Some(ICON_TAB_EXTERNAL_AVAILABLE),
+        "table" => Some(ICON_TABLE_AVAILABLE),
+        "tag" => Some(ICON_TAG_AVAILABLE),
+        "tasklist" => Some(ICON_TASKLIST_AVAILABLE),
+        "telescope" => Some(ICON_TELESCOPE_AVAILABLE),
+        "terminal" => Some(ICON_TERMINAL_AVAILABLE),
+        "three-bars" => Some(ICON_THREE_BARS_AVAILABLE),
+        "thumbsdown" => Some(ICON_THUMBSDOWN_AVAILABLE),
+        "thumbsup" => Some(ICON_THUMBSUP_AVAILABLE),
+        "tools" => Some(ICON_TOOLS_AVAILABLE),
+        "tracked-by-closed-completed" => Some(ICON_TRACKED_BY_CLOSED_COMPLETED_AVAILABLE),
+        "tracked-by-closed-not-planned" => Some(ICON_TRACKED_BY_CLOSED_NOT_PLANNED_AVAILABLE),
+        "trash" => Some(ICON_TRASH_AVAILABLE),
+        "triangle-down" => Some(ICON_TRIANGLE_DOWN_AVAILABLE),
+        "triangle-left" => Some(ICON_TRIANGLE_LEFT_AVAILABLE),
+        "triangle-right" => Some(ICON_TRIANGLE_RIGHT_AVAILABLE),
+        "triangle-up" => Some(ICON_TRIANGLE_UP_AVAILABLE),
+        "trophy" => Some(ICON_TROPHY_AVAILABLE),
+        "typography" => Some(ICON_TYPOGRAPHY_AVAILABLE),
+        "undo" => Some(ICON_UNDO_AVAILABLE),
+        "unfold" => Some(ICON_UNFOLD_AVAILABLE),
+        "unlink" => Some(ICON_UNLINK_AVAILABLE),
+        "unlock" => Some(ICON_UNLOCK_AVAILABLE),
+        "unmute" => Some(ICON_UNMUTE_AVAILABLE),
+        "unread" => Some(ICON_UNREAD_AVAILABLE),
+        "unverified" => Some(ICON_UNVERIFIED_AVAILABLE),
+        "unwrap" => Some(ICON_UNWRAP_AVAILABLE),
+        "upload" => Some(ICON_UPLOAD_AVAILABLE),
+        "verified" => Some(ICON_VERIFIED_AVAILABLE),
+        "versions" => Some(ICON_VERSIONS_AVAILABLE),
+        "video" => Some(ICON_VIDEO_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "vscode" => Some(ICON_VSCODE_AVAILABLE),
+        #[cfg(feature = "octicons-tiny")]
+        "webhook" => Some(ICON_WEBHOOK_AVAILABLE),
+        "workflow" => Some(ICON_WORKFLOW_AVAILABLE),
+        "wrap" => Some(ICON_WRAP_AVAILABLE),
+        "x" => Some(ICON_X_AVAILABLE),
+        "x-circle" => Some(ICON_X_CIRCLE_AVAILABLE),
+        "zap" => Some(ICON_ZAP_AVAILABLE),
+        "zoom-in" => Some(ICON_ZOOM_IN_AVAILABLE),
+        "zoom-out" => Some(ICON_ZOOM_OUT_AVAILABLE),
+        _ => None,
+    }
+}
+
+pub(crate) fn brand_color(name: &str) -> Option<[u8; 4]> {
+    ICON_COLORS
+        .binary_search_by(|(entry, _)| (*entry).cmp(name))
+        .ok()
+        .map(|index| ICON_COLORS[index].1)
+}
+
+pub(crate) fn aria_label(name: &str) -> Option<&'static str> {
+    ICON_NAMES
+        .binary_search(&name)
+        .ok()
+        .map(|index| ICON_LABELS[index])
+}
+
+pub(crate) fn icon_by_id(id: u32) -> Option<&'static str> {
+    ICON_IDS
+        .binary_search_by_key(&id, |(entry, _)| *entry)
+        .ok()
+        .map(|index| ICON_IDS[index].1)
+}
+
+pub(crate) fn rtl_mirror(name: &str) -> bool {
+    ICON_RTL_MIRROR.binary_search(&name).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_lookup_agrees_with_scan_tables() {
+        for entry in ICON_CODEPOINTS {
+            for &(key, codepoint) in entry.codepoints {
+                assert_eq!(icon_codepoint(entry.name, key), Some(codepoint));
+            }
+        }
+        for entry in ICON_AVAILABILITY {
+            assert_eq!(icon_available(entry.name), Some(entry.available));
+        }
+        assert_eq!(icon_available(""), None);
+        assert_eq!(icon_codepoint("", VARIANTS[0].key), None);
+    }
 }
@@ -0,0 +1,29 @@
+//! Font loading for iced applications.
+
+use crate::font_source::{AsyncFontSource, load_fonts_with};
+use iced_runtime::{Task, font};
+
+/// [`AsyncFontSource`] backed by `iced::font::load`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IcedFonts;
+
+impl AsyncFontSource for IcedFonts {
+    type Task = Task<()>;
+
+    fn load_font(&self, _family: &'static str, bytes: &'static [u8]) -> Self::Task {
+        font::load(bytes).map(|_| ())
+    }
+
+    fn join(&self, tasks: Vec<Self::Task>) -> Self::Task {
+        Task::batch(tasks).collect().map(|_| ())
+    }
+}
+
+/// Loads every embedded font, producing a single `()` once all loads have finished.
+///
+/// Map it into your message type from `new`:
+/// `iconflow::iced::load_all().map(|()| Message::FontsLoaded)`. Fonts iced fails to parse
+/// are skipped.
+pub fn load_all() -> Task<()> {
+    load_fonts_with(&IcedFonts)
+}
@@ -1,8 +1,34 @@
 #[doc(hidden)]
 pub mod core;
+#[cfg(feature = "egui")]
+pub mod egui;
+mod font_source;
 #[doc(hidden)]
 pub mod generated;
+#[cfg(feature = "iced")]
+pub mod iced;
+#[cfg(feature = "raster")]
+pub mod metrics;
 pub mod packs;
+#[cfg(feature = "raster")]
+pub mod raster;
+#[cfg(feature = "slint")]
+pub mod slint;
+#[cfg(feature = "tiny-skia")]
+pub mod tiny_skia;
 
-pub use crate::core::{FontAsset, IconError, IconRef, Size, Style, fonts, list, try_icon};
+pub use crate::core::{
+    AsGlyph, FontAsset, IconError, IconLookupError, IconRef, IconRequest, OwnedIconRef, PackKind,
+    PackResolver, ParseVariantError, Resolution, Size, Style, VariantKey, aria_label, autocomplete,
+    brand_color, codepoint_range, common_icons, content_hash, display_name, embedded_font_bytes,
+    fonts, group_by_initial, icon_by_id, icon_or_placeholder, id_of, iter_icons, list,
+    list_by_codepoint, list_in_variant, list_iter, list_many, list_page, list_resolved,
+    name_for_codepoint, next_size, pack_kind, packs_of_kind, packs_with_icon, prev_size,
+    resolve_with_fallback, rtl_mirror, search_all, search_glob, sizes, sizes_static, styles,
+    styles_static, total_embedded_font_bytes, total_icon_count, try_icon, try_icon_ci,
+    try_icon_ref, unique_fonts, variant_fonts,
+};
+pub use crate::font_source::{AsyncFontSource, async_fonts, load_fonts_with};
+#[cfg(feature = "index")]
+pub use crate::generated::ALL_ICONS;
 pub use crate::generated::Pack;
@@ -0,0 +1,99 @@
+//! Glyph metrics for laying out icons, read from the embedded fonts with `ttf-parser`.
+
+/// Horizontal and bounding-box metrics of one glyph, in pixels at the requested size.
+///
+/// `bearing_x` is the offset from the pen position to the left edge of the glyph and
+/// `bearing_y` the distance from the baseline up to its top edge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GlyphMetrics {
+    /// Horizontal advance to the next pen position.
+    pub advance: f32,
+    /// Left edge of the glyph relative to the pen position.
+    pub bearing_x: f32,
+    /// Top edge of the glyph above the baseline.
+    pub bearing_y: f32,
+    /// Width of the glyph bounding box.
+    pub width: f32,
+    /// Height of the glyph bounding box.
+    pub height: f32,
+}
+
+/// Metrics of `codepoint` in `font_bytes` scaled to a `px` em size.
+///
+/// Returns `None` when the font cannot be parsed or has no glyph for `codepoint`. Glyphs
+/// without an outline (spaces) report a zero-sized box.
+pub fn glyph_metrics(font_bytes: &[u8], codepoint: u32, px: f32) -> Option<GlyphMetrics> {
+    let face = ttf_parser::Face::parse(font_bytes, 0).ok()?;
+    let glyph = face.glyph_index(char::from_u32(codepoint)?)?;
+    let scale = px / f32::from(face.units_per_em());
+    let advance = f32::from(face.glyph_hor_advance(glyph).unwrap_or(0)) * scale;
+    let Some(bbox) = face.glyph_bounding_box(glyph) else {
+        return Some(GlyphMetrics {
+            advance,
+            bearing_x: 0.0,
+            bearing_y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        });
+    };
+    Some(GlyphMetrics {
+        advance,
+        bearing_x: f32::from(bbox.x_min) * scale,
+        bearing_y: f32::from(bbox.y_max) * scale,
+        width: f32::from(bbox.width()) * scale,
+        height: f32::from(bbox.height()) * scale,
+    })
+}
+
+/// Whether the cmap of `font_bytes` maps `codepoint` to a glyph, without resolving an icon.
+///
+/// Useful for fonts loaded at runtime; returns `false` when the font cannot be parsed.
+pub fn glyph_exists(font_bytes: &[u8], codepoint: u32) -> bool {
+    let Some(ch) = char::from_u32(codepoint) else {
+        return false;
+    };
+    ttf_parser::Face::parse(font_bytes, 0)
+        .ok()
+        .and_then(|face| face.glyph_index(ch))
+        .is_some()
+}
+
+#[cfg(all(test, feature = "pack-bootstrap"))]
+mod tests {
+    use super::{glyph_exists, glyph_metrics};
+    use crate::generated::bootstrap;
+    use crate::{Pack, Size, Style, try_icon};
+
+    #[test]
+    fn known_glyph_has_positive_metrics() {
+        let icon = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        let font = bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR;
+        assert_eq!(font.family, icon.family);
+
+        let metrics = glyph_metrics(font.bytes, icon.codepoint, 32.0).unwrap();
+        assert!(metrics.advance > 0.0);
+        assert!(metrics.width > 0.0);
+        assert!(metrics.height > 0.0);
+        assert!(metrics.width <= metrics.advance * 2.0);
+
+        let doubled = glyph_metrics(font.bytes, icon.codepoint, 64.0).unwrap();
+        assert!((doubled.advance - metrics.advance * 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn unmapped_codepoint_or_bad_font_is_none() {
+        let font = bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR;
+        assert_eq!(glyph_metrics(font.bytes, u32::from('A'), 16.0), None);
+        assert_eq!(glyph_metrics(b"not a font", 0xF101, 16.0), None);
+    }
+
+    #[test]
+    fn glyph_exists_checks_cmap() {
+        let icon = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        let font = bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR;
+        assert!(glyph_exists(font.bytes, icon.codepoint));
+        assert!(!glyph_exists(font.bytes, u32::from('A')));
+        assert!(!glyph_exists(font.bytes, 0xD800));
+        assert!(!glyph_exists(b"not a font", icon.codepoint));
+    }
+}
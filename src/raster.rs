@@ -0,0 +1,218 @@
+//! Software rasterization of icon glyphs with `fontdue`, plus a small LRU cache for
+//! renderers that draw the same icons every frame.
+
+use crate::AsGlyph;
+use image::RgbaImage;
+use std::collections::HashMap;
+
+/// Rasterizes `codepoint` from `font_bytes` at a `px` em size, tinted with `color` (RGBA).
+///
+/// Glyph coverage scales the alpha channel of `color`; the image is cropped to the glyph
+/// bounding box. Returns `None` when the font cannot be parsed or has no glyph for
+/// `codepoint`.
+pub fn render_glyph(
+    font_bytes: &[u8],
+    codepoint: u32,
+    px: f32,
+    color: [u8; 4],
+) -> Option<RgbaImage> {
+    let font = fontdue::Font::from_bytes(font_bytes, fontdue::FontSettings::default()).ok()?;
+    let ch = char::from_u32(codepoint)?;
+    if font.lookup_glyph_index(ch) == 0 {
+        return None;
+    }
+    let (metrics, coverage) = font.rasterize(ch, px);
+    let [r, g, b, a] = color;
+    let pixels = coverage
+        .iter()
+        .flat_map(|&cov| [r, g, b, ((u16::from(a) * u16::from(cov) + 127) / 255) as u8])
+        .collect();
+    RgbaImage::from_raw(metrics.width as u32, metrics.height as u32, pixels)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+struct GlyphKey {
+    codepoint: u32,
+    px_bits: u32,
+    color: [u8; 4],
+}
+
+/// LRU cache of rendered glyphs keyed by `(family, codepoint, px, color)`.
+///
+/// Once `capacity` images are stored, inserting a new one evicts the least recently
+/// used entry.
+#[derive(Debug)]
+pub struct GlyphCache {
+    capacity: usize,
+    tick: u64,
+    renders: u64,
+    len: usize,
+    /// Keyed by family first, so lookups borrow the family name instead of allocating.
+    entries: HashMap<String, HashMap<GlyphKey, (u64, RgbaImage)>>,
+}
+
+impl GlyphCache {
+    /// Creates an empty cache holding at most `capacity` images (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            tick: 0,
+            renders: 0,
+            len: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Maximum number of cached images.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached images.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` when nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of times a glyph was rasterized rather than served from the cache.
+    pub fn renders(&self) -> u64 {
+        self.renders
+    }
+
+    /// Drops every cached image.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.len = 0;
+    }
+
+    /// Returns the cached image for `icon`, rendering it from `font_bytes` with
+    /// [`render_glyph`] on a miss.
+    ///
+    /// Takes an [`IconRef`](crate::IconRef) or an [`OwnedIconRef`](crate::OwnedIconRef);
+    /// entries are shared by every icon with the same family name. Returns `None` when the
+    /// glyph cannot be rendered; failures are not cached.
+    pub fn get_or_render(
+        &mut self,
+        icon: impl AsGlyph,
+        font_bytes: &[u8],
+        px: f32,
+        color: [u8; 4],
+    ) -> Option<&RgbaImage> {
+        let key = GlyphKey {
+            codepoint: icon.codepoint(),
+            px_bits: px.to_bits(),
+            color,
+        };
+        self.tick += 1;
+        let cached = self
+            .entries
+            .get(icon.family())
+            .is_some_and(|glyphs| glyphs.contains_key(&key));
+        if !cached {
+            let image = render_glyph(font_bytes, key.codepoint, px, color)?;
+            self.renders += 1;
+            if self.len >= self.capacity {
+                self.evict_oldest();
+            }
+            self.entries
+                .entry(icon.family().to_string())
+                .or_default()
+                .insert(key, (self.tick, image));
+            self.len += 1;
+        }
+        let entry = self.entries.get_mut(icon.family())?.get_mut(&key)?;
+        entry.0 = self.tick;
+        Some(&entry.1)
+    }
+
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .flat_map(|(family, glyphs)| {
+                glyphs
+                    .iter()
+                    .map(move |(key, (last_used, _))| (*last_used, family, *key))
+            })
+            .min_by_key(|(last_used, _, _)| *last_used)
+            .map(|(_, family, key)| (family.clone(), key));
+        let Some((family, key)) = oldest else {
+            return;
+        };
+        if let Some(glyphs) = self.entries.get_mut(&family) {
+            glyphs.remove(&key);
+            self.len -= 1;
+            if glyphs.is_empty() {
+                self.entries.remove(&family);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "pack-bootstrap"))]
+mod tests {
+    use super::{GlyphCache, render_glyph};
+    use crate::generated::bootstrap;
+    use crate::{OwnedIconRef, Pack, Size, Style, try_icon};
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+    #[test]
+    fn renders_known_glyph_with_coverage() {
+        let icon = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        let font = bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR;
+        let image = render_glyph(font.bytes, icon.codepoint, 24.0, WHITE).unwrap();
+        assert!(image.width() > 0 && image.height() > 0);
+        assert!(image.pixels().any(|pixel| pixel.0[3] > 0));
+        assert_eq!(render_glyph(font.bytes, u32::from('A'), 24.0, WHITE), None);
+    }
+
+    #[test]
+    fn cache_hit_skips_rendering_and_evicts_least_recent() {
+        let font = bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR;
+        let alarm = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        let bell = try_icon(Pack::Bootstrap, "bell", Style::Regular, Size::Regular).unwrap();
+        let mut cache = GlyphCache::new(2);
+
+        let first = cache
+            .get_or_render(alarm, font.bytes, 24.0, WHITE)
+            .unwrap()
+            .clone();
+        let second = cache.get_or_render(alarm, font.bytes, 24.0, WHITE).unwrap();
+        assert_eq!(&first, second);
+        assert_eq!(cache.renders(), 1);
+
+        cache.get_or_render(bell, font.bytes, 24.0, WHITE);
+        cache.get_or_render(alarm, font.bytes, 24.0, WHITE);
+        cache.get_or_render(alarm, font.bytes, 32.0, WHITE);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.renders(), 3);
+
+        cache.get_or_render(alarm, font.bytes, 24.0, WHITE);
+        assert_eq!(cache.renders(), 3);
+        cache.get_or_render(bell, font.bytes, 24.0, WHITE);
+        assert_eq!(cache.renders(), 4);
+    }
+
+    #[test]
+    fn owned_families_share_cache_entries() {
+        let font = bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR;
+        let alarm = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        let mut cache = GlyphCache::new(4);
+
+        let static_image = cache
+            .get_or_render(alarm, font.bytes, 24.0, WHITE)
+            .unwrap()
+            .clone();
+        let owned = OwnedIconRef::from(alarm);
+        let owned_image = cache
+            .get_or_render(&owned, font.bytes, 24.0, WHITE)
+            .unwrap();
+        assert_eq!(&static_image, owned_image);
+        assert_eq!(cache.renders(), 1);
+    }
+}
@@ -0,0 +1,58 @@
+//! Font registration and glyph strings for Slint applications.
+//!
+//! Fonts go through Slint's `unstable-fontique-011` collection, so keep the app's `slint`
+//! dependency on the same minor release as iconflow's.
+
+use crate::core::{AsGlyph, unique_fonts};
+use ::slint::SharedString;
+use ::slint::fontique_011::{fontique, shared_collection};
+use std::sync::Arc;
+
+/// Registers every embedded font with Slint's process-wide font collection.
+///
+/// Call it once before the first window shows an icon. Fonts come from
+/// [`unique_fonts`](crate::unique_fonts), so a family shared by two packs is registered once.
+///
+/// # Panics
+///
+/// If Slint cannot create its platform backend; the app's `slint` dependency provides one.
+pub fn register_fonts() {
+    let mut collection = shared_collection();
+    for font in unique_fonts() {
+        collection.register_fonts(fontique::Blob::new(Arc::new(font.bytes)), None);
+    }
+}
+
+/// The `text` of a Slint `Text` drawing `icon`; set its `font-family` to the icon's family.
+///
+/// Falls back to U+FFFD, like [`IconRef::glyph_str`](crate::IconRef::glyph_str), if the
+/// codepoint is not a valid `char`.
+pub fn glyph_string(icon: impl AsGlyph) -> SharedString {
+    let glyph = char::from_u32(icon.codepoint()).unwrap_or(char::REPLACEMENT_CHARACTER);
+    SharedString::from(&*glyph.encode_utf8(&mut [0; 4]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glyph_string;
+    use crate::{IconRef, OwnedIconRef};
+
+    #[test]
+    fn glyph_string_is_the_codepoint_char() {
+        let icon = IconRef {
+            family: "Demo",
+            codepoint: 0xF101,
+        };
+        assert_eq!(glyph_string(icon), "\u{F101}");
+        assert_eq!(
+            glyph_string(OwnedIconRef::from(icon)),
+            icon.glyph_str().as_str()
+        );
+
+        let invalid = IconRef {
+            codepoint: 0xD800,
+            ..icon
+        };
+        assert_eq!(glyph_string(invalid), "\u{FFFD}");
+    }
+}
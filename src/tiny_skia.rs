@@ -0,0 +1,100 @@
+//! Software rendering of icon glyphs into `tiny-skia` pixmaps, without the `image` crate.
+
+use crate::AsGlyph;
+use ::tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, Transform};
+
+struct PathSink(PathBuilder);
+
+impl ttf_parser::OutlineBuilder for PathSink {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.0.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.0.cubic_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.0.close();
+    }
+}
+
+/// Pixel length of the font-unit span `min..max`, widened first so extreme bboxes cannot overflow.
+fn scaled_extent(min: i16, max: i16, scale: f32) -> u32 {
+    ((i32::from(max) - i32::from(min)) as f32 * scale).ceil() as u32
+}
+
+/// Fills the outline of `icon` from `font_bytes` at a `px` em size with `color` (RGBA).
+///
+/// `icon` is an [`IconRef`](crate::IconRef) or an [`OwnedIconRef`](crate::OwnedIconRef); only
+/// its codepoint is read. The pixmap is cropped to the glyph bounding box, like
+/// `raster::render_glyph`. Returns `None` when the font cannot be parsed or has no outline for
+/// the codepoint.
+pub fn draw(icon: impl AsGlyph, font_bytes: &[u8], px: f32, color: [u8; 4]) -> Option<Pixmap> {
+    let face = ttf_parser::Face::parse(font_bytes, 0).ok()?;
+    let glyph = face.glyph_index(char::from_u32(icon.codepoint())?)?;
+    let mut sink = PathSink(PathBuilder::new());
+    let bbox = face.outline_glyph(glyph, &mut sink)?;
+    let path = sink.0.finish()?;
+
+    let scale = px / f32::from(face.units_per_em());
+    let width = scaled_extent(bbox.x_min, bbox.x_max, scale);
+    let height = scaled_extent(bbox.y_min, bbox.y_max, scale);
+    let mut pixmap = Pixmap::new(width.max(1), height.max(1))?;
+
+    let mut paint = Paint::default();
+    let [r, g, b, a] = color;
+    paint.set_color_rgba8(r, g, b, a);
+    paint.anti_alias = true;
+    let transform = Transform::from_row(
+        scale,
+        0.0,
+        0.0,
+        -scale,
+        -f32::from(bbox.x_min) * scale,
+        f32::from(bbox.y_max) * scale,
+    );
+    pixmap.fill_path(&path, &paint, FillRule::Winding, transform, None);
+    Some(pixmap)
+}
+
+#[cfg(all(test, feature = "pack-bootstrap"))]
+mod tests {
+    use super::{draw, scaled_extent};
+    use crate::generated::bootstrap;
+    use crate::{IconRef, OwnedIconRef, Pack, Size, Style, try_icon};
+
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+    #[test]
+    fn draws_known_glyph_with_coverage() {
+        let icon = try_icon(Pack::Bootstrap, "alarm", Style::Regular, Size::Regular).unwrap();
+        let font = bootstrap::FONT_ASSET_BOOTSTRAP_BOOTSTRAP_REGULAR;
+        let pixmap = draw(icon, font.bytes, 32.0, BLACK).unwrap();
+        assert!(pixmap.width() > 0 && pixmap.height() <= 40);
+        assert!(pixmap.pixels().iter().any(|pixel| pixel.alpha() > 0));
+
+        let missing = IconRef {
+            codepoint: u32::from('A'),
+            ..icon
+        };
+        assert!(draw(missing, font.bytes, 32.0, BLACK).is_none());
+
+        let owned = OwnedIconRef::from(icon);
+        assert_eq!(draw(&owned, font.bytes, 32.0, BLACK), Some(pixmap));
+    }
+
+    #[test]
+    fn scaled_extent_spans_the_full_i16_range() {
+        assert_eq!(scaled_extent(i16::MIN, i16::MAX, 1.0), 65_535);
+        assert_eq!(scaled_extent(-100, 901, 0.5), 501);
+    }
+}
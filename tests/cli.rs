@@ -0,0 +1,70 @@
+use assert_cmd::Command;
+
+fn iconflow(args: &[&str]) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("iconflow")
+        .expect("iconflow binary should be built")
+        .args(args)
+        .assert()
+}
+
+fn stdout(assert: &assert_cmd::assert::Assert) -> String {
+    String::from_utf8(assert.get_output().stdout.clone()).expect("stdout should be UTF-8")
+}
+
+fn stderr(assert: &assert_cmd::assert::Assert) -> String {
+    String::from_utf8(assert.get_output().stderr.clone()).expect("stderr should be UTF-8")
+}
+
+#[test]
+fn list_prints_pack_names() {
+    let assert = iconflow(&["list", "bootstrap"]).success();
+    assert!(stdout(&assert).lines().any(|line| line == "alarm"));
+}
+
+#[test]
+fn search_prints_qualified_matches() {
+    let assert = iconflow(&["search", "alarm"]).success();
+    assert!(
+        stdout(&assert)
+            .lines()
+            .any(|line| line == "bootstrap:alarm")
+    );
+}
+
+#[test]
+fn show_prints_codepoint_family_and_glyph() {
+    let assert = iconflow(&["show", "bootstrap:alarm"]).success();
+    let line = stdout(&assert)
+        .lines()
+        .find(|line| line.starts_with("Regular/Regular\t"))
+        .map(str::to_string)
+        .expect("Regular variant should be listed");
+    assert!(line.contains("\tU+"));
+    assert!(line.contains("\tBootstrap Regular\t"));
+}
+
+#[cfg(feature = "pack-fluentui")]
+#[test]
+fn show_lists_custom_sizes() {
+    let assert = iconflow(&["show", "fluentui:add"]).success();
+    assert!(
+        stdout(&assert)
+            .lines()
+            .any(|line| line.starts_with("Regular/16px\t"))
+    );
+}
+
+#[test]
+fn unknown_pack_fails() {
+    let assert = iconflow(&["list", "missing"]).failure();
+    assert!(stderr(&assert).starts_with("error: unknown or disabled pack 'missing'"));
+}
+
+#[test]
+fn unknown_icon_reports_a_readable_error() {
+    let assert = iconflow(&["show", "bootstrap:no-such-icon"]).failure();
+    assert_eq!(
+        stderr(&assert),
+        "error: no icon 'no-such-icon' in pack 'bootstrap'\n"
+    );
+}
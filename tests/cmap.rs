@@ -0,0 +1,24 @@
+//! Resolves every icon of every enabled variant with `try_icon` and checks that the variant's
+//! bundled font has a glyph at that codepoint, so generated tables (including `pua_base`
+//! relocations) never point past the font's cmap.
+
+use iconflow::metrics::glyph_exists;
+use iconflow::{Pack, list_in_variant, try_icon, variant_fonts};
+
+#[test]
+fn every_resolved_icon_has_a_glyph_in_its_font() {
+    let mut missing = Vec::new();
+    for &pack in Pack::ALL {
+        for (key, font) in variant_fonts(pack) {
+            for name in list_in_variant(pack, key.style, key.size) {
+                let icon = try_icon(pack, name, key.style, key.size)
+                    .unwrap_or_else(|err| panic!("{}/{name}: {err:?}", pack.id()));
+                assert_eq!(icon.family, font.family, "{}/{name}", pack.id());
+                if !glyph_exists(font.bytes, icon.codepoint) {
+                    missing.push(format!("{}/{name} U+{:04X}", pack.id(), icon.codepoint));
+                }
+            }
+        }
+    }
+    assert!(missing.is_empty(), "no glyph for {missing:?}");
+}
@@ -0,0 +1,85 @@
+//! Renders a few icons of every enabled pack and compares them with committed goldens, so a
+//! codegen change that silently remaps codepoints shows up as a visual diff.
+//!
+//! Run with `BLESS=1` to (re)write `tests/golden/<pack>/<icon>.png`.
+
+use iconflow::raster::render_glyph;
+use iconflow::{Pack, Size, list, resolve_with_fallback, styles_static, variant_fonts};
+use image::RgbaImage;
+use std::path::{Path, PathBuf};
+
+const ICONS_PER_PACK: usize = 3;
+const PX: f32 = 16.0;
+const WHITE: [u8; 4] = [255, 255, 255, 255];
+/// Alpha difference above which a pixel counts as changed.
+const ALPHA_TOLERANCE: u8 = 16;
+/// Share of changed pixels a render may have before it no longer matches its golden.
+const MAX_CHANGED_RATIO: f64 = 0.02;
+
+fn golden_path(pack: Pack, name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(pack.id())
+        .join(format!("{name}.png"))
+}
+
+fn render(pack: Pack, name: &str) -> RgbaImage {
+    let style = styles_static(pack)[0];
+    let icon = resolve_with_fallback(pack, name, style, Size::Regular)
+        .unwrap_or_else(|err| panic!("{}/{name}: {err:?}", pack.id()))
+        .icon;
+    let (_, font) = variant_fonts(pack)
+        .iter()
+        .find(|(_, font)| font.family == icon.family)
+        .unwrap_or_else(|| panic!("{}/{name}: no font for {}", pack.id(), icon.family));
+    render_glyph(font.bytes, icon.codepoint, PX, WHITE)
+        .unwrap_or_else(|| panic!("{}/{name}: U+{:04X} has no glyph", pack.id(), icon.codepoint))
+}
+
+fn changed_ratio(expected: &RgbaImage, actual: &RgbaImage) -> f64 {
+    if expected.dimensions() != actual.dimensions() {
+        return 1.0;
+    }
+    let changed = expected
+        .pixels()
+        .zip(actual.pixels())
+        .filter(|(old, new)| old.0[3].abs_diff(new.0[3]) > ALPHA_TOLERANCE)
+        .count();
+    changed as f64 / expected.pixels().len().max(1) as f64
+}
+
+#[test]
+fn rendered_icons_match_goldens() {
+    let bless = std::env::var_os("BLESS").is_some_and(|value| value == "1");
+    let mut failures = Vec::new();
+    for &pack in Pack::ALL {
+        for name in list(pack).iter().take(ICONS_PER_PACK) {
+            let actual = render(pack, name);
+            let path = golden_path(pack, name);
+            if bless {
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                actual.save(&path).unwrap();
+                continue;
+            }
+            match image::open(&path) {
+                Ok(expected) => {
+                    let ratio = changed_ratio(&expected.to_rgba8(), &actual);
+                    if ratio > MAX_CHANGED_RATIO {
+                        failures.push(format!(
+                            "{}: {:.1}% of pixels changed",
+                            path.display(),
+                            ratio * 100.0
+                        ));
+                    }
+                }
+                Err(err) => failures.push(format!("{}: {err}", path.display())),
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "glyphs differ from their goldens (rerun with BLESS=1 if intended):\n{}",
+        failures.join("\n")
+    );
+}
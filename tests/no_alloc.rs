@@ -0,0 +1,74 @@
+use iconflow::{IconLookupError, Pack, Size, Style, try_icon_ref};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    (value, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn try_icon_ref_miss_does_not_allocate() {
+    let (result, allocations) = allocations_during(|| {
+        try_icon_ref(
+            Pack::Bootstrap,
+            "no-such-icon",
+            Style::Regular,
+            Size::Regular,
+        )
+    });
+    assert_eq!(
+        result,
+        Err(IconLookupError::IconNotFound {
+            pack: "bootstrap",
+            name: "no-such-icon",
+        })
+    );
+    assert_eq!(allocations, 0);
+
+    let (result, allocations) =
+        allocations_during(|| try_icon_ref(Pack::Bootstrap, "alarm", Style::Outline, Size::Mini));
+    assert!(matches!(
+        result,
+        Err(IconLookupError::VariantUnavailable { name: "alarm", .. })
+    ));
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn try_icon_ref_reports_the_trimmed_input_name() {
+    assert_eq!(
+        try_icon_ref(
+            Pack::Bootstrap,
+            " no_such_icon ",
+            Style::Regular,
+            Size::Regular
+        ),
+        Err(IconLookupError::IconNotFound {
+            pack: "bootstrap",
+            name: "no_such_icon",
+        })
+    );
+    assert!(try_icon_ref(Pack::Bootstrap, "arrow_left", Style::Regular, Size::Regular).is_ok());
+}
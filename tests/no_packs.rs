@@ -0,0 +1,49 @@
+//! Builds against no `pack-*` feature, where `Pack` has no variants, and checks that every
+//! cross-pack entry point reports an empty catalogue instead of failing to compile or panicking.
+//!
+//! Run with `cargo test --no-default-features`. Per-pack functions need a `Pack` value, which
+//! cannot exist in this build, so they are covered by the type system rather than at runtime.
+
+#![cfg(not(any(
+    feature = "pack-bootstrap",
+    feature = "pack-carbon",
+    feature = "pack-devicon",
+    feature = "pack-feather",
+    feature = "pack-fluentui",
+    feature = "pack-heroicons",
+    feature = "pack-iconoir",
+    feature = "pack-ionicons",
+    feature = "pack-lobe",
+    feature = "pack-lucide",
+    feature = "pack-octicons",
+    feature = "pack-phosphor",
+    feature = "pack-remixicon",
+    feature = "pack-tabler",
+)))]
+
+use iconflow::{
+    Pack, PackKind, async_fonts, common_icons, fonts, list_many, packs_of_kind, packs_with_icon,
+    search_all, total_embedded_font_bytes, total_icon_count, unique_fonts,
+};
+
+#[test]
+fn catalogue_is_empty_without_packs() {
+    assert!(Pack::ALL.is_empty());
+    assert!(fonts().is_empty());
+    assert!(unique_fonts().is_empty());
+    assert_eq!(async_fonts().len(), 0);
+    assert_eq!(total_icon_count(), 0);
+    assert_eq!(total_embedded_font_bytes(), 0);
+}
+
+#[test]
+fn cross_pack_queries_find_nothing_without_packs() {
+    assert!(list_many(Pack::ALL).is_empty());
+    assert!(search_all(Pack::ALL, "arrow").is_empty());
+    assert!(common_icons(Pack::ALL).is_empty());
+    assert!(packs_with_icon("heart").is_empty());
+    assert!(packs_of_kind(PackKind::Ui).is_empty());
+    assert!(packs_of_kind(PackKind::Brand).is_empty());
+    #[cfg(feature = "index")]
+    assert!(iconflow::ALL_ICONS.is_empty());
+}
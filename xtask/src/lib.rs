@@ -0,0 +1,5456 @@
+//! Code generator behind `cargo xtask`: turns `assets/maps/*.json` into `src/generated/**`.
+//!
+//! The binary wraps [`run_gen`], [`run_svg_sprite`], [`run_diff`] and [`run_coverage`];
+//! [`generate`] and [`generate_into`] expose the same pipeline to `build.rs` scripts.
+
+use anyhow::{Context, Result, bail};
+use iconflow::{PackKind, Size, Style};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Rust spelling of core `Size` values in generated code.
+trait SizeExt {
+    fn rust_expr(self) -> String;
+    fn label(self) -> String;
+}
+
+impl SizeExt for Size {
+    fn rust_expr(self) -> String {
+        match self {
+            Size::Custom(value) => format!("Size::Custom({value})"),
+            named => format!("Size::{}", named.label()),
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Size::Tiny => "Tiny".to_string(),
+            Size::Mini => "Mini".to_string(),
+            Size::Regular => "Regular".to_string(),
+            Size::Large => "Large".to_string(),
+            Size::Custom(value) => value.to_string(),
+        }
+    }
+}
+
+fn deserialize_style<'de, D>(deserializer: D) -> Result<Style, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+    name.parse().map_err(de::Error::custom)
+}
+
+fn deserialize_styles<'de, D>(deserializer: D) -> Result<Vec<Style>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let names = Vec::<String>::deserialize(deserializer)?;
+    names
+        .iter()
+        .map(|name| name.parse().map_err(de::Error::custom))
+        .collect()
+}
+
+/// A named size string (Tiny/Mini/Regular/Large) or a positive integer for `Size::Custom`.
+fn deserialize_size<'de, D>(deserializer: D) -> Result<Size, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct SizeVisitor;
+
+    impl<'de> Visitor<'de> for SizeVisitor {
+        type Value = Size;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a size string (Tiny/Mini/Regular/Large) or a positive integer")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Size, E>
+        where
+            E: de::Error,
+        {
+            match value.parse() {
+                Ok(Size::Custom(_)) | Err(_) => Err(E::unknown_variant(
+                    value,
+                    &["Tiny", "Mini", "Regular", "Large"],
+                )),
+                Ok(size) => Ok(size),
+            }
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Size, E>
+        where
+            E: de::Error,
+        {
+            if value == 0 || value > u16::MAX as u64 {
+                return Err(E::custom("custom size must be between 1 and 65535"));
+            }
+            Ok(Size::Custom(value as u16))
+        }
+    }
+
+    deserializer.deserialize_any(SizeVisitor)
+}
+
+fn deserialize_kind<'de, D>(deserializer: D) -> Result<PackKind, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+    name.parse().map_err(de::Error::custom)
+}
+
+fn serialize_kind<S>(kind: &PackKind, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(kind.name())
+}
+
+fn serialize_style<S>(style: &Style, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(style.name())
+}
+
+fn serialize_styles<S>(styles: &[Style], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_seq(styles.iter().map(|style| style.name()))
+}
+
+/// Writes sizes the way maps spell them: a name, or the pixel size for `Size::Custom`.
+fn serialize_size<S>(size: &Size, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match size {
+        Size::Custom(value) => serializer.serialize_u16(*value),
+        named => serializer.serialize_str(&named.label()),
+    }
+}
+
+/// Codepoint as written in a map: an integer, `"U+E001"`, `"0xe001"`, `"\\ue001"` or the
+/// character itself (`"\ue001"` in JSON).
+///
+/// Only non-ASCII characters are taken literally, so a typo such as `"E"` or `"7"` is rejected
+/// rather than read as U+0045 or U+0037.
+struct CodepointValue(u32);
+
+impl<'de> Deserialize<'de> for CodepointValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CodepointVisitor;
+
+        impl<'de> Visitor<'de> for CodepointVisitor {
+            type Value = CodepointValue;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str(
+                    "a codepoint as an integer, \"U+XXXX\", \"0xXXXX\", \"\\\\uXXXX\" or a single non-ASCII character",
+                )
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<CodepointValue, E>
+            where
+                E: de::Error,
+            {
+                let value = u32::try_from(value)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| {
+                        E::custom(format!("codepoint {value} is not a Unicode scalar value"))
+                    })?;
+                Ok(CodepointValue(value as u32))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<CodepointValue, E>
+            where
+                E: de::Error,
+            {
+                let mut chars = value.chars();
+                if let (Some(ch), None) = (chars.next(), chars.next())
+                    && !ch.is_ascii()
+                {
+                    return Ok(CodepointValue(ch as u32));
+                }
+
+                let hex = value
+                    .strip_prefix("U+")
+                    .or_else(|| value.strip_prefix("u+"))
+                    .or_else(|| value.strip_prefix("0x"))
+                    .or_else(|| value.strip_prefix("0X"))
+                    .or_else(|| {
+                        value
+                            .strip_prefix("\\u{")
+                            .and_then(|rest| rest.strip_suffix('}'))
+                    })
+                    .or_else(|| value.strip_prefix("\\u"))
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))?;
+                if hex.is_empty() || hex.len() > 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(E::invalid_value(de::Unexpected::Str(value), &self));
+                }
+                let parsed = u32::from_str_radix(hex, 16)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))?;
+                self.visit_u64(u64::from(parsed))
+            }
+        }
+
+        deserializer.deserialize_any(CodepointVisitor)
+    }
+}
+
+fn deserialize_optional_codepoint<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: Option<CodepointValue> = Deserialize::deserialize(deserializer)?;
+    Ok(value.map(|value| value.0))
+}
+
+fn deserialize_codepoint_map<'de, D>(deserializer: D) -> Result<BTreeMap<String, u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let map: BTreeMap<String, CodepointValue> = Deserialize::deserialize(deserializer)?;
+    Ok(map.into_iter().map(|(key, value)| (key, value.0)).collect())
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+struct VariantKey {
+    #[serde(
+        serialize_with = "serialize_style",
+        deserialize_with = "deserialize_style"
+    )]
+    style: Style,
+    #[serde(
+        serialize_with = "serialize_size",
+        deserialize_with = "deserialize_size"
+    )]
+    size: Size,
+}
+
+/// Where an icon exists: a list of variant ids or `"Style/Size"` strings, `{ "style", "size" }`
+/// pairs, or a map from style to sizes. All but ids are resolved to variant ids during
+/// normalization.
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Availability {
+    Variants(Vec<String>),
+    Keys(Vec<VariantKey>),
+}
+
+impl<'de> Deserialize<'de> for Availability {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct SizeValue(#[serde(deserialize_with = "deserialize_size")] Size);
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Variants(Vec<String>),
+            Keys(Vec<VariantKey>),
+            ByStyle(BTreeMap<String, Vec<SizeValue>>),
+        }
+
+        match Repr::deserialize(deserializer).map_err(|_| {
+            de::Error::custom(
+                "expected availability as variant ids, {style, size} pairs or a style -> sizes map",
+            )
+        })? {
+            Repr::Variants(ids) => Ok(Availability::Variants(ids)),
+            Repr::Keys(keys) => Ok(Availability::Keys(keys)),
+            Repr::ByStyle(by_style) => {
+                let mut keys = Vec::new();
+                for (style, sizes) in by_style {
+                    let style: Style = style.parse().map_err(de::Error::custom)?;
+                    keys.extend(
+                        sizes
+                            .into_iter()
+                            .map(|SizeValue(size)| VariantKey { style, size }),
+                    );
+                }
+                Ok(Availability::Keys(keys))
+            }
+        }
+    }
+}
+
+/// Reads an availability entry written as `"Outline/Regular"` or `"Regular/24"`; `None` for
+/// plain variant ids.
+fn parse_style_size(value: &str) -> Option<Result<VariantKey>> {
+    let (style, size) = value.split_once('/')?;
+    Some(match (style.parse(), size.parse()) {
+        (Ok(style), Ok(size)) => Ok(VariantKey { style, size }),
+        (Err(err), _) | (_, Err(err)) => Err(err.into()),
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct PackMap {
+    pack_id: String,
+    variants: Vec<Variant>,
+    icons: Vec<Icon>,
+    /// Accept codepoint `0`, which otherwise almost always means a missing mapping.
+    #[serde(default)]
+    allow_zero_codepoint: bool,
+    /// Human-readable pack name for UIs; defaults to the title-cased `pack_id`.
+    #[serde(default)]
+    display_name: Option<String>,
+    /// Preferred presentation order of the pack's styles; unlisted styles follow, sorted.
+    #[serde(default, deserialize_with = "deserialize_styles")]
+    style_order: Vec<Style>,
+    /// `ui` (the default) or `brand`, so pickers can keep logos apart from UI icons.
+    #[serde(default, deserialize_with = "deserialize_kind")]
+    kind: PackKind,
+    #[serde(skip)]
+    source_path: PathBuf,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct Variant {
+    id: String,
+    #[serde(deserialize_with = "deserialize_style")]
+    style: Style,
+    #[serde(deserialize_with = "deserialize_size")]
+    size: Size,
+    family: String,
+    ttf_asset_path: String,
+    #[serde(default)]
+    feature: Option<String>,
+    /// Variant id whose resolved codepoint this variant reuses when an icon has no override.
+    #[serde(default)]
+    inherit_from: Option<String>,
+    /// Added to the base codepoint of icons that do not override this variant.
+    #[serde(default)]
+    codepoint_offset: Option<i32>,
+    /// Moves this variant's resolved codepoints so the lowest one lands here. The TTF's cmap
+    /// must be re-encoded to the same range; `gen` does not rewrite fonts, but fails when a
+    /// relocated codepoint has no glyph.
+    #[serde(default, deserialize_with = "deserialize_optional_codepoint")]
+    pua_base: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Icon {
+    name: String,
+    #[serde(default, deserialize_with = "deserialize_optional_codepoint")]
+    codepoint: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_codepoint_map")]
+    overrides: BTreeMap<String, u32>,
+    #[serde(default)]
+    availability: Option<Availability>,
+    /// Variants the icon lacks; every other variant is available. Excludes `availability`.
+    #[serde(default)]
+    unavailable: Vec<String>,
+    #[serde(default)]
+    color: Option<String>,
+    #[serde(default)]
+    rtl_mirror: bool,
+    #[serde(default)]
+    label: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct VariantInfo {
+    id: String,
+    key: VariantKey,
+    family: String,
+    ttf_asset_path: String,
+    feature: Option<String>,
+    /// Delta `pua_base` added to every codepoint of this variant.
+    pua_shift: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct NormalizedIcon {
+    name: String,
+    ident: String,
+    codepoints: Vec<(VariantKey, u32)>,
+    color: Option<[u8; 4]>,
+    rtl_mirror: bool,
+    label: String,
+}
+
+#[derive(Debug, Serialize)]
+struct NormalizedPack {
+    pack_id: String,
+    display_name: String,
+    #[serde(serialize_with = "serialize_kind")]
+    kind: PackKind,
+    #[serde(serialize_with = "serialize_styles")]
+    style_order: Vec<Style>,
+    variants: Vec<VariantInfo>,
+    icons: Vec<NormalizedIcon>,
+}
+
+#[derive(Debug)]
+struct FontAssetInfo {
+    const_ident: String,
+    family: String,
+    ttf_asset_path: String,
+    feature: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizeOptions {
+    /// Suffix colliding icon idents (`ArrowLeft2`) instead of failing.
+    pub resolve_collisions: bool,
+    /// Turn normalization warnings (such as variants no icon uses) into errors.
+    pub strict: bool,
+    /// Warn when an icon with a default codepoint lists only some variants in `availability`.
+    pub strict_availability: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderOptions {
+    /// Gate icon tables behind per-initial `<pack>-icons-<group>` features.
+    pub granular_features: bool,
+}
+
+type FontAssetCollection = (
+    Vec<FontAssetInfo>,
+    BTreeMap<String, String>,
+    BTreeMap<VariantKey, Option<String>>,
+);
+
+/// Runs `cargo xtask gen` against the repository's maps and `src/generated`.
+pub fn run_gen(
+    check: bool,
+    verify_family: bool,
+    options: NormalizeOptions,
+    render_options: RenderOptions,
+    emit_json: Option<PathBuf>,
+) -> Result<()> {
+    let repo_root = repo_root()?;
+    let maps_dir = repo_root.join("assets").join("maps");
+    let generated_dir = repo_root.join("src").join("generated");
+
+    let normalized = load_packs(&maps_dir, &repo_root, options)?;
+
+    if verify_family {
+        for pack in &normalized {
+            verify_pack_families(pack, &repo_root)?;
+        }
+    }
+
+    if let Some(out_dir) = emit_json {
+        return write_pack_json(&normalized, &out_dir);
+    }
+
+    let mut outputs: Vec<(PathBuf, String)> = render_sources(&normalized, render_options)?
+        .into_iter()
+        .map(|(file_name, content)| (generated_dir.join(file_name), content))
+        .collect();
+
+    if render_options.granular_features {
+        let manifest_path = repo_root.join("Cargo.toml");
+        let manifest = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Reading {manifest_path:?}"))?;
+        let features = render_granular_features(&normalized);
+        outputs.push((
+            manifest_path,
+            replace_granular_features(&manifest, &features)?,
+        ));
+    }
+
+    for (path, content) in &outputs {
+        write_output(path, content, check)?;
+    }
+
+    Ok(())
+}
+
+/// Generates the `generated` module for every `*.json` map in `maps_dir`.
+///
+/// Returns `(file name, source)` pairs: `mod.rs` plus one `<pack_id>.rs` per map, formatted
+/// exactly as `cargo xtask gen` writes them. Requires `rustfmt` on `PATH`.
+///
+/// Fonts of `pua_base` variants are read relative to `CARGO_MANIFEST_DIR` (the current directory
+/// when unset), the same root the generated `include_bytes!` paths use.
+pub fn generate(
+    maps_dir: &Path,
+    options: NormalizeOptions,
+    render_options: RenderOptions,
+) -> Result<Vec<(String, String)>> {
+    let font_root = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    render_sources(&load_packs(maps_dir, &font_root, options)?, render_options)
+}
+
+/// [`generate`] into `out_dir`, e.g. `OUT_DIR` from a `build.rs` bundling custom maps.
+///
+/// The output still uses `crate::core` paths and `include_bytes!` relative to
+/// `CARGO_MANIFEST_DIR`, so it must be included as a module of a crate laid out like iconflow.
+pub fn generate_into(
+    maps_dir: &Path,
+    out_dir: &Path,
+    options: NormalizeOptions,
+    render_options: RenderOptions,
+) -> Result<()> {
+    fs::create_dir_all(out_dir).with_context(|| format!("Creating {}", out_dir.display()))?;
+    for (file_name, content) in generate(maps_dir, options, render_options)? {
+        let path = out_dir.join(file_name);
+        fs::write(&path, content).with_context(|| format!("Writing {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn load_packs(
+    maps_dir: &Path,
+    font_root: &Path,
+    options: NormalizeOptions,
+) -> Result<Vec<NormalizedPack>> {
+    let mut map_paths: Vec<PathBuf> = fs::read_dir(maps_dir)
+        .with_context(|| format!("Reading maps directory {maps_dir:?}"))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+        .collect();
+    map_paths.sort();
+
+    if map_paths.is_empty() {
+        bail!("No map files found in {maps_dir:?}");
+    }
+
+    let mut normalized = Vec::new();
+    for path in map_paths {
+        let pack = normalize_pack(load_pack_map(&path)?, options)?;
+        check_pua_glyphs(&pack, font_root)?;
+        normalized.push(pack);
+    }
+    normalized.sort_by(|a, b| a.pack_id.cmp(&b.pack_id));
+    check_shared_families(&normalized)?;
+    Ok(normalized)
+}
+
+fn render_sources(
+    packs: &[NormalizedPack],
+    render_options: RenderOptions,
+) -> Result<Vec<(String, String)>> {
+    let mut sources = vec![("mod.rs".to_string(), rustfmt(&render_mod(packs)?)?)];
+    for pack in packs {
+        sources.push((
+            format!("{}.rs", pack.pack_id),
+            rustfmt(&render_pack(pack, render_options)?)?,
+        ));
+    }
+    Ok(sources)
+}
+
+/// Writes each normalized pack to `<out_dir>/<pack_id>.json` instead of generating Rust.
+fn write_pack_json(packs: &[NormalizedPack], out_dir: &Path) -> Result<()> {
+    fs::create_dir_all(out_dir).with_context(|| format!("Creating {}", out_dir.display()))?;
+    for pack in packs {
+        let path = out_dir.join(format!("{}.json", pack.pack_id));
+        fs::write(&path, serde_json::to_string_pretty(pack)? + "\n")
+            .with_context(|| format!("Writing {}", path.display()))?;
+    }
+    println!("Wrote {} packs to {}", packs.len(), out_dir.display());
+    Ok(())
+}
+
+/// Runs `cargo xtask svg-sprite <pack>`.
+pub fn run_svg_sprite(pack_id: &str, out_dir: Option<PathBuf>) -> Result<()> {
+    let repo_root = repo_root()?;
+    let map_path = repo_root
+        .join("assets")
+        .join("maps")
+        .join(format!("{pack_id}.json"));
+    let pack = normalize_pack(load_pack_map(&map_path)?, NormalizeOptions::default())?;
+    let (sprite, manifest) = render_svg_sprite(&pack, &repo_root)?;
+
+    let out_dir = out_dir.unwrap_or_else(|| repo_root.join("target").join("svg-sprite"));
+    fs::create_dir_all(&out_dir).with_context(|| format!("Creating {}", out_dir.display()))?;
+    let sprite_path = out_dir.join(format!("{pack_id}.svg"));
+    let manifest_path = out_dir.join(format!("{pack_id}.json"));
+    fs::write(&sprite_path, sprite)
+        .with_context(|| format!("Writing {}", sprite_path.display()))?;
+    fs::write(
+        &manifest_path,
+        serde_json::to_string_pretty(&manifest)? + "\n",
+    )
+    .with_context(|| format!("Writing {}", manifest_path.display()))?;
+    println!(
+        "Wrote {} symbols to {}",
+        manifest.symbols.len(),
+        sprite_path.display()
+    );
+    Ok(())
+}
+
+/// Runs `cargo xtask diff <old.json> <new.json>`.
+pub fn run_diff(old_path: &Path, new_path: &Path) -> Result<()> {
+    let old = normalize_pack(load_pack_map(old_path)?, NormalizeOptions::default())?;
+    let new = normalize_pack(load_pack_map(new_path)?, NormalizeOptions::default())?;
+    let diff = diff_packs(&old, &new);
+    if diff.is_empty() {
+        println!("No icon changes");
+        return Ok(());
+    }
+    for name in &diff.added {
+        println!("+ {name}");
+    }
+    for name in &diff.removed {
+        println!("- {name}");
+    }
+    for change in &diff.changed {
+        let format = |codepoint: Option<u32>| {
+            codepoint.map_or_else(|| "none".to_string(), |cp| format!("U+{cp:04X}"))
+        };
+        println!(
+            "~ {} [{}]: {} -> {}",
+            change.name,
+            variant_label(change.key),
+            format(change.old),
+            format(change.new)
+        );
+    }
+    println!(
+        "{} added, {} removed, {} codepoint changes",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    );
+    Ok(())
+}
+
+/// Runs `cargo xtask coverage`: per pack and variant, how many icons the variant resolves.
+pub fn run_coverage() -> Result<()> {
+    let repo_root = repo_root()?;
+    let maps_dir = repo_root.join("assets").join("maps");
+    for pack in load_packs(&maps_dir, &repo_root, NormalizeOptions::default())? {
+        println!("{}", pack.pack_id);
+        for row in variant_coverage(&pack) {
+            println!(
+                "  {:<24} {:<16} {:>6}/{} ({:.1}%)",
+                row.variant_id,
+                variant_label(row.key),
+                row.icons,
+                row.total,
+                row.percent()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Family names recorded in a font's `name` table (legacy and typographic family ids).
+fn font_family_names(bytes: &[u8]) -> Result<BTreeSet<String>> {
+    let face = ttf_parser::Face::parse(bytes, 0).context("Parsing font")?;
+    Ok(face
+        .names()
+        .into_iter()
+        .filter(|name| {
+            name.name_id == ttf_parser::name_id::FAMILY
+                || name.name_id == ttf_parser::name_id::TYPOGRAPHIC_FAMILY
+        })
+        .filter_map(|name| name.to_string())
+        .collect())
+}
+
+/// Fails when a variant's declared `family` is not one of the family names in its TTF, which
+/// would make GUI font registration silently miss the glyphs.
+fn verify_pack_families(pack: &NormalizedPack, repo_root: &Path) -> Result<()> {
+    for variant in &pack.variants {
+        let path = repo_root.join(&variant.ttf_asset_path);
+        let bytes = fs::read(&path).with_context(|| format!("Reading {}", path.display()))?;
+        let names = font_family_names(&bytes).with_context(|| format!("{}", path.display()))?;
+        if !names.contains(&variant.family) {
+            let found = names.into_iter().collect::<Vec<_>>().join("', '");
+            bail!(
+                "{}: variant '{}' declares family '{}' but {} names '{}'",
+                pack.pack_id,
+                variant.id,
+                variant.family,
+                variant.ttf_asset_path,
+                found
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Fails when the font of a `pua_base` variant has no glyph at one of its relocated codepoints,
+/// i.e. its cmap was not re-encoded to the range the generated tables point at.
+fn check_pua_glyphs(pack: &NormalizedPack, font_root: &Path) -> Result<()> {
+    for variant in pack.variants.iter().filter(|v| v.pua_shift.is_some()) {
+        let path = font_root.join(&variant.ttf_asset_path);
+        let bytes = fs::read(&path).with_context(|| format!("Reading {}", path.display()))?;
+        let face = ttf_parser::Face::parse(&bytes, 0)
+            .with_context(|| format!("Parsing font {}", path.display()))?;
+        for icon in &pack.icons {
+            for &(key, codepoint) in &icon.codepoints {
+                if key != variant.key
+                    || char::from_u32(codepoint)
+                        .and_then(|ch| face.glyph_index(ch))
+                        .is_some()
+                {
+                    continue;
+                }
+                bail!(
+                    "{}: variant '{}' moves icon '{}' to U+{:04X} via pua_base but {} has no glyph \
+                     there; re-encode its cmap to the relocated range",
+                    pack.pack_id,
+                    variant.id,
+                    icon.name,
+                    codepoint,
+                    variant.ttf_asset_path
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn repo_root() -> Result<PathBuf> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir
+        .parent()
+        .context("xtask is expected to live one level below repo root")
+        .map(Path::to_path_buf)
+}
+
+fn load_pack_map(path: &Path) -> Result<PackMap> {
+    let raw = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+    let mut map: PackMap =
+        serde_json::from_str(&raw).with_context(|| format!("Parsing JSON in {path:?}"))?;
+    map.source_path = path.to_path_buf();
+    Ok(map)
+}
+
+fn normalize_pack(pack: PackMap, options: NormalizeOptions) -> Result<NormalizedPack> {
+    let mut variants = pack.variants.clone();
+    variants.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut seen_variant_ids = BTreeSet::new();
+    let mut variant_id_by_key = BTreeMap::new();
+    let mut variants_info = Vec::new();
+    let mut variant_key_by_id = BTreeMap::new();
+
+    for variant in variants {
+        if !seen_variant_ids.insert(variant.id.clone()) {
+            bail!(
+                "{}: duplicate variant.id '{}'",
+                pack.source_path.display(),
+                variant.id
+            );
+        }
+
+        let key = VariantKey {
+            style: variant.style,
+            size: variant.size,
+        };
+        // Checked on the final variant list so no two ids can shadow each other in `variant_info`.
+        if let Some(existing) = variant_id_by_key.insert(key, variant.id.clone()) {
+            bail!(
+                "{}: variants '{}' and '{}' share style/size {:?}/{:?}",
+                pack.source_path.display(),
+                existing,
+                variant.id,
+                variant.style,
+                variant.size
+            );
+        }
+
+        if let Some(feature) = &variant.feature
+            && feature.trim().is_empty()
+        {
+            bail!(
+                "{}: variant '{}' has empty feature name",
+                pack.source_path.display(),
+                variant.id
+            );
+        }
+        variant_key_by_id.insert(variant.id.clone(), key);
+        variants_info.push(VariantInfo {
+            id: variant.id,
+            key,
+            family: variant.family,
+            ttf_asset_path: variant.ttf_asset_path,
+            feature: variant.feature,
+            pua_shift: None,
+        });
+    }
+
+    let variant_ids: Vec<String> = variants_info.iter().map(|v| v.id.clone()).collect();
+    let variant_id_set: BTreeSet<&str> = variants_info.iter().map(|v| v.id.as_str()).collect();
+
+    let offset_by_id: BTreeMap<&str, i32> = pack
+        .variants
+        .iter()
+        .filter_map(|variant| Some((variant.id.as_str(), variant.codepoint_offset?)))
+        .collect();
+    let mut inherit_from_by_id = BTreeMap::new();
+    for variant in &pack.variants {
+        let Some(base) = &variant.inherit_from else {
+            continue;
+        };
+        if !variant_id_set.contains(base.as_str()) {
+            bail!(
+                "{}: variant '{}' inherits from unknown variant '{}'",
+                pack.source_path.display(),
+                variant.id,
+                base
+            );
+        }
+        inherit_from_by_id.insert(variant.id.as_str(), base.as_str());
+    }
+    for id in inherit_from_by_id.keys() {
+        if inherit_chain(id, &inherit_from_by_id).count() > inherit_from_by_id.len() + 1 {
+            bail!(
+                "{}: variant '{}' has a cyclic inherit_from chain",
+                pack.source_path.display(),
+                id
+            );
+        }
+    }
+
+    let icon_idents = assign_icon_idents(&pack, options)?;
+    let mut icons_info = Vec::new();
+
+    for icon in &pack.icons {
+        let ident = icon_idents[&icon.name].clone();
+
+        for variant_id in icon.overrides.keys() {
+            if !variant_id_set.contains(variant_id.as_str()) {
+                bail!(
+                    "{}: icon '{}' overrides unknown variant '{}'",
+                    pack.source_path.display(),
+                    icon.name,
+                    variant_id
+                );
+            }
+        }
+
+        let variant_for_key = |key: VariantKey| -> Result<String> {
+            match pack.variants.iter().find(|variant| {
+                (VariantKey {
+                    style: variant.style,
+                    size: variant.size,
+                }) == key
+            }) {
+                Some(variant) => Ok(variant.id.clone()),
+                None => bail!(
+                    "{}: icon '{}' availability has no variant for {} {}",
+                    pack.source_path.display(),
+                    icon.name,
+                    key.style.name(),
+                    key.size.label()
+                ),
+            }
+        };
+        let variant_for_id = |field: &str, id: &String| -> Result<String> {
+            match parse_style_size(id) {
+                Some(key) => variant_for_key(key.with_context(|| {
+                    format!(
+                        "{}: icon '{}' {field} '{}'",
+                        pack.source_path.display(),
+                        icon.name,
+                        id
+                    )
+                })?),
+                None => Ok(id.clone()),
+            }
+        };
+        if icon.availability.is_some() && !icon.unavailable.is_empty() {
+            bail!(
+                "{}: icon '{}' sets both availability and unavailable",
+                pack.source_path.display(),
+                icon.name
+            );
+        }
+        let listed_availability = match &icon.availability {
+            Some(Availability::Variants(ids)) => Some(
+                ids.iter()
+                    .map(|id| variant_for_id("availability", id))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            Some(Availability::Keys(keys)) => Some(
+                keys.iter()
+                    .map(|&key| variant_for_key(key))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            None if !icon.unavailable.is_empty() => {
+                let mut excluded = BTreeSet::new();
+                for id in &icon.unavailable {
+                    let variant_id = variant_for_id("unavailable", id)?;
+                    if !variant_id_set.contains(variant_id.as_str()) {
+                        bail!(
+                            "{}: icon '{}' unavailable unknown variant '{}'",
+                            pack.source_path.display(),
+                            icon.name,
+                            variant_id
+                        );
+                    }
+                    excluded.insert(variant_id);
+                }
+                Some(
+                    variant_ids
+                        .iter()
+                        .filter(|id| !excluded.contains(*id))
+                        .cloned()
+                        .collect(),
+                )
+            }
+            None => None,
+        };
+
+        if let Some(availability) = &listed_availability {
+            for variant_id in availability {
+                if !variant_id_set.contains(variant_id.as_str()) {
+                    bail!(
+                        "{}: icon '{}' availability unknown variant '{}'",
+                        pack.source_path.display(),
+                        icon.name,
+                        variant_id
+                    );
+                }
+            }
+            if !icon.overrides.is_empty() {
+                for variant_id in icon.overrides.keys() {
+                    if !availability.iter().any(|id| id == variant_id) {
+                        bail!(
+                            "{}: icon '{}' overrides not listed in availability: '{}'",
+                            pack.source_path.display(),
+                            icon.name,
+                            variant_id
+                        );
+                    }
+                }
+            }
+        }
+
+        let availability = match &listed_availability {
+            Some(list) => {
+                if list.is_empty() {
+                    bail!(
+                        "{}: icon '{}' availability is empty",
+                        pack.source_path.display(),
+                        icon.name
+                    );
+                }
+                let mut dedup = BTreeSet::new();
+                for item in list {
+                    if !dedup.insert(item.as_str()) {
+                        bail!(
+                            "{}: icon '{}' availability has duplicates: '{}'",
+                            pack.source_path.display(),
+                            icon.name,
+                            item
+                        );
+                    }
+                }
+                // `unavailable` omits variants on purpose.
+                if options.strict_availability
+                    && icon.unavailable.is_empty()
+                    && icon.codepoint.is_some()
+                    && list.len() < variant_ids.len()
+                {
+                    let omitted: Vec<&str> = variant_ids
+                        .iter()
+                        .filter(|id| !list.contains(id))
+                        .map(String::as_str)
+                        .collect();
+                    let message = format!(
+                        "{}: icon '{}' has a default codepoint but its availability omits '{}'",
+                        pack.source_path.display(),
+                        icon.name,
+                        omitted.join("', '")
+                    );
+                    if options.strict {
+                        bail!(message);
+                    }
+                    eprintln!("warning: {message}");
+                }
+                list.clone()
+            }
+            None => {
+                if icon.codepoint.is_some() {
+                    variant_ids.clone()
+                } else if !icon.overrides.is_empty() {
+                    variant_ids
+                        .iter()
+                        .filter(|id| {
+                            inherit_chain(id, &inherit_from_by_id)
+                                .any(|id| icon.overrides.contains_key(id))
+                        })
+                        .cloned()
+                        .collect()
+                } else {
+                    bail!(
+                        "{}: icon '{}' has no codepoint or overrides",
+                        pack.source_path.display(),
+                        icon.name
+                    );
+                }
+            }
+        };
+
+        let availability_set: BTreeSet<&str> = availability.iter().map(|id| id.as_str()).collect();
+        let mut codepoints = Vec::new();
+
+        for variant_id in &variant_ids {
+            if !availability_set.contains(variant_id.as_str()) {
+                continue;
+            }
+
+            // Walk the inheritance chain, adding each derived variant's offset until an override
+            // (or the icon's default codepoint) anchors it.
+            let mut offset = 0i64;
+            let mut base = None;
+            for id in inherit_chain(variant_id, &inherit_from_by_id) {
+                if let Some(value) = icon.overrides.get(id) {
+                    base = Some(*value);
+                    break;
+                }
+                offset += i64::from(offset_by_id.get(id).copied().unwrap_or(0));
+            }
+            let base = match base.or(icon.codepoint) {
+                Some(value) => value,
+                None => bail!(
+                    "{}: icon '{}' missing codepoint for variant '{}'",
+                    pack.source_path.display(),
+                    icon.name,
+                    variant_id
+                ),
+            };
+            let codepoint = u32::try_from(i64::from(base) + offset)
+                .ok()
+                .filter(|value| char::from_u32(*value).is_some())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{}: icon '{}' codepoint {:#x} with offset {} for variant '{}' is not a Unicode scalar value",
+                        pack.source_path.display(),
+                        icon.name,
+                        base,
+                        offset,
+                        variant_id
+                    )
+                })?;
+
+            let key = *variant_key_by_id.get(variant_id).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{}: icon '{}' references unknown variant '{}'",
+                    pack.source_path.display(),
+                    icon.name,
+                    variant_id
+                )
+            })?;
+
+            if codepoint == 0 && !pack.allow_zero_codepoint {
+                bail!(
+                    "{}: icon '{}' has codepoint 0 for variant '{}' (set allow_zero_codepoint to permit it)",
+                    pack.source_path.display(),
+                    icon.name,
+                    variant_id
+                );
+            }
+
+            codepoints.push((key, codepoint));
+        }
+
+        if codepoints.is_empty() {
+            bail!(
+                "{}: icon '{}' has no available variants",
+                pack.source_path.display(),
+                icon.name
+            );
+        }
+
+        let color = match &icon.color {
+            Some(hex) => Some(parse_hex_color(hex).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{}: icon '{}' has invalid color '{}' (expected #RRGGBB or #RRGGBBAA)",
+                    pack.source_path.display(),
+                    icon.name,
+                    hex
+                )
+            })?),
+            None => None,
+        };
+
+        let label = match &icon.label {
+            Some(label) if label.trim().is_empty() => bail!(
+                "{}: icon '{}' has an empty label",
+                pack.source_path.display(),
+                icon.name
+            ),
+            Some(label) => label.clone(),
+            None => title_case_label(&icon.name),
+        };
+
+        icons_info.push(NormalizedIcon {
+            name: icon.name.clone(),
+            ident,
+            codepoints,
+            color,
+            rtl_mirror: icon.rtl_mirror,
+            label,
+        });
+    }
+
+    for variant in &pack.variants {
+        if let Some(pua_base) = variant.pua_base {
+            let info = variants_info
+                .iter_mut()
+                .find(|info| info.id == variant.id)
+                .expect("every variant has an info entry");
+            info.pua_shift =
+                relocate_to_pua(&mut icons_info, info.key, pua_base).map_err(|err| {
+                    anyhow::anyhow!(
+                        "{}: variant '{}' {err}",
+                        pack.source_path.display(),
+                        variant.id
+                    )
+                })?;
+        }
+    }
+
+    icons_info.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let referenced: BTreeSet<VariantKey> = icons_info
+        .iter()
+        .flat_map(|icon| icon.codepoints.iter().map(|(key, _)| *key))
+        .collect();
+    let orphans: Vec<&str> = variants_info
+        .iter()
+        .filter(|variant| !referenced.contains(&variant.key))
+        .map(|variant| variant.id.as_str())
+        .collect();
+    if !orphans.is_empty() {
+        let message = format!(
+            "{}: variant(s) used by no icon: '{}'",
+            pack.source_path.display(),
+            orphans.join("', '")
+        );
+        if options.strict {
+            bail!(message);
+        }
+        eprintln!("warning: {message}");
+    }
+
+    let mut name_by_id = BTreeMap::new();
+    for icon in &icons_info {
+        if let Some(existing) = name_by_id.insert(icon_id(&icon.name), icon.name.as_str()) {
+            bail!(
+                "{}: icons '{}' and '{}' hash to the same id {:#010x}",
+                pack.source_path.display(),
+                existing,
+                icon.name,
+                icon_id(&icon.name)
+            );
+        }
+    }
+
+    let display_name = match &pack.display_name {
+        Some(name) if name.trim().is_empty() => {
+            bail!("{}: display_name is empty", pack.source_path.display())
+        }
+        Some(name) => name.clone(),
+        None => title_case_label(&pack.pack_id),
+    };
+
+    let mut seen_styles = BTreeSet::new();
+    for style in &pack.style_order {
+        if !seen_styles.insert(*style) {
+            bail!(
+                "{}: style_order lists '{}' more than once",
+                pack.source_path.display(),
+                style.name()
+            );
+        }
+        if !variants_info
+            .iter()
+            .any(|variant| variant.key.style == *style)
+        {
+            bail!(
+                "{}: style_order lists '{}', which no variant uses",
+                pack.source_path.display(),
+                style.name()
+            );
+        }
+    }
+
+    Ok(NormalizedPack {
+        pack_id: pack.pack_id,
+        display_name,
+        kind: pack.kind,
+        style_order: pack.style_order,
+        variants: variants_info,
+        icons: icons_info,
+    })
+}
+
+/// Shifts every codepoint of variant `key` so the lowest one becomes `pua_base`, returning the
+/// delta applied (`None` when no icon uses the variant).
+///
+/// Inheriting variants resolved before the shift keep the source codepoints.
+fn relocate_to_pua(
+    icons: &mut [NormalizedIcon],
+    key: VariantKey,
+    pua_base: u32,
+) -> Result<Option<i64>> {
+    let Some(min) = icons
+        .iter()
+        .flat_map(|icon| &icon.codepoints)
+        .filter(|(variant, _)| *variant == key)
+        .map(|&(_, codepoint)| codepoint)
+        .min()
+    else {
+        return Ok(None);
+    };
+    let shift = i64::from(pua_base) - i64::from(min);
+    for icon in icons {
+        for (variant, codepoint) in &mut icon.codepoints {
+            if *variant != key {
+                continue;
+            }
+            *codepoint = u32::try_from(i64::from(*codepoint) + shift)
+                .ok()
+                .filter(|&shifted| is_private_use(shifted))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "pua_base {:#X} moves icon '{}' (U+{:04X}) outside the Private Use Area",
+                        pua_base,
+                        icon.name,
+                        codepoint
+                    )
+                })?;
+        }
+    }
+    Ok(Some(shift))
+}
+
+fn is_private_use(codepoint: u32) -> bool {
+    matches!(codepoint, 0xE000..=0xF8FF | 0xF_0000..=0xF_FFFD | 0x10_0000..=0x10_FFFD)
+}
+
+/// Fails when two packs declare the same font family and map the same codepoint in it.
+///
+/// Apps that enable both packs would register two fonts under one family name, and whichever
+/// loads last draws both icons.
+fn check_shared_families(packs: &[NormalizedPack]) -> Result<()> {
+    let mut owners: BTreeMap<(&str, u32), (&str, &str)> = BTreeMap::new();
+    for pack in packs {
+        for variant in &pack.variants {
+            for icon in &pack.icons {
+                for &(key, codepoint) in &icon.codepoints {
+                    if key != variant.key {
+                        continue;
+                    }
+                    let owner = (pack.pack_id.as_str(), icon.name.as_str());
+                    let existing = *owners
+                        .entry((variant.family.as_str(), codepoint))
+                        .or_insert(owner);
+                    if existing.0 != owner.0 {
+                        bail!(
+                            "packs '{}' and '{}' share font family '{}' and both map U+{:04X} ('{}' and '{}')",
+                            existing.0,
+                            owner.0,
+                            variant.family,
+                            codepoint,
+                            existing.1,
+                            owner.1
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `id` followed by the variants it inherits codepoints from, nearest first.
+///
+/// Stops after `inherit_from.len() + 1` steps so a cyclic chain cannot loop forever.
+fn inherit_chain<'a>(
+    id: &'a str,
+    inherit_from: &'a BTreeMap<&'a str, &'a str>,
+) -> impl Iterator<Item = &'a str> + 'a {
+    std::iter::successors(Some(id), |id| inherit_from.get(id).copied()).take(inherit_from.len() + 2)
+}
+
+/// Stable icon id: 32-bit FNV-1a of the UTF-8 name. Must match `icon_id` in `src/core/api.rs`.
+fn icon_id(name: &str) -> u32 {
+    name.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// Open-addressing slots for `names`: a power-of-two table at most half full, each name placed
+/// by linear probing from its [`icon_id`]. Slots hold indices into `names`.
+fn fast_lookup_slots(names: &[&str]) -> Vec<Option<usize>> {
+    let mut slots = vec![None; (names.len() * 2).next_power_of_two()];
+    let mask = slots.len() - 1;
+    for (index, name) in names.iter().enumerate() {
+        let mut slot = icon_id(name) as usize & mask;
+        while slots[slot].is_some() {
+            slot = (slot + 1) & mask;
+        }
+        slots[slot] = Some(index);
+    }
+    slots
+}
+
+/// 64-bit FNV-1a over the pack's families, icon names and codepoints, in map order.
+///
+/// Fields are separated by `0xFF`, which never occurs in UTF-8, so moving bytes between
+/// neighbouring names changes the hash.
+fn content_hash(pack: &NormalizedPack) -> u64 {
+    let mut bytes = Vec::new();
+    for variant in &pack.variants {
+        bytes.extend_from_slice(variant.family.as_bytes());
+        bytes.push(0xFF);
+    }
+    for icon in &pack.icons {
+        bytes.extend_from_slice(icon.name.as_bytes());
+        bytes.push(0xFF);
+        for (key, codepoint) in &icon.codepoints {
+            bytes.extend_from_slice(key.style.name().as_bytes());
+            bytes.extend_from_slice(key.size.label().as_bytes());
+            bytes.extend_from_slice(&codepoint.to_le_bytes());
+            bytes.push(0xFF);
+        }
+    }
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Default accessible label: `arrow-left` becomes `Arrow Left`.
+fn title_case_label(name: &str) -> String {
+    name.split(['-', '_'])
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let mut chars = segment.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Parses `#RRGGBB` (opaque) or `#RRGGBBAA` into RGBA bytes.
+fn parse_hex_color(hex: &str) -> Option<[u8; 4]> {
+    let digits = hex.strip_prefix('#')?;
+    if !matches!(digits.len(), 6 | 8) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut rgba = [0, 0, 0, 255];
+    for (channel, chunk) in rgba.iter_mut().zip(digits.as_bytes().chunks(2)) {
+        *channel = u8::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+    }
+    Some(rgba)
+}
+
+/// Maps every icon name to its `Icon` variant ident.
+///
+/// Names are processed in sorted order so collision suffixes are deterministic: the first name
+/// keeps the base ident and later ones get `2`, `3`, ... skipping idents already in use.
+fn assign_icon_idents(
+    pack: &PackMap,
+    options: NormalizeOptions,
+) -> Result<BTreeMap<String, String>> {
+    let mut base_idents = BTreeMap::new();
+    for icon in &pack.icons {
+        let ident = normalize_icon_name(&icon.name)?;
+        if base_idents.insert(icon.name.clone(), ident).is_some() {
+            bail!(
+                "{}: duplicate icon.name '{}'",
+                pack.source_path.display(),
+                icon.name
+            );
+        }
+    }
+
+    let reserved: BTreeSet<&str> = base_idents.values().map(String::as_str).collect();
+    let mut owners: BTreeMap<String, &str> = BTreeMap::new();
+    let mut idents = BTreeMap::new();
+    let mut remapped = Vec::new();
+
+    for (name, base) in &base_idents {
+        let ident = match owners.get(base) {
+            None => base.clone(),
+            Some(prev) => {
+                if !options.resolve_collisions {
+                    bail!(
+                        "{}: icon name collision: '{}' and '{}' both map to '{}'",
+                        pack.source_path.display(),
+                        prev,
+                        name,
+                        base
+                    );
+                }
+                let mut suffix = 2;
+                loop {
+                    let candidate = format!("{base}{suffix}");
+                    if !reserved.contains(candidate.as_str()) && !owners.contains_key(&candidate) {
+                        break candidate;
+                    }
+                    suffix += 1;
+                }
+            }
+        };
+        if ident != *base {
+            remapped.push((name.as_str(), ident.clone()));
+        }
+        owners.insert(ident.clone(), name);
+        idents.insert(name.clone(), ident);
+    }
+
+    if !remapped.is_empty() {
+        eprintln!(
+            "warning: {}: resolved {} icon ident collision(s):",
+            pack.source_path.display(),
+            remapped.len()
+        );
+        for (name, ident) in &remapped {
+            eprintln!("  '{name}' -> {ident}");
+        }
+    }
+
+    Ok(idents)
+}
+
+fn collect_font_assets(pack: &NormalizedPack) -> Result<FontAssetCollection> {
+    let mut asset_feature_sets: BTreeMap<String, BTreeSet<Option<String>>> = BTreeMap::new();
+    let mut asset_families: BTreeMap<String, String> = BTreeMap::new();
+    let mut variant_feature_by_key = BTreeMap::new();
+
+    for variant in &pack.variants {
+        let path = variant.ttf_asset_path.replace('\\', "/");
+        variant_feature_by_key.insert(variant.key, variant.feature.clone());
+        asset_feature_sets
+            .entry(path.clone())
+            .or_default()
+            .insert(variant.feature.clone());
+        if let Some(existing) = asset_families.get(&path) {
+            if existing != &variant.family {
+                bail!(
+                    "Pack {} has conflicting family names for {}: '{}' vs '{}'",
+                    pack.pack_id,
+                    path,
+                    existing,
+                    variant.family
+                );
+            }
+        } else {
+            asset_families.insert(path.clone(), variant.family.clone());
+        }
+    }
+
+    let mut assets = Vec::new();
+    let mut asset_const_by_path = BTreeMap::new();
+    for (path, family) in asset_families {
+        let const_ident = font_asset_const_ident_from_path(&pack.pack_id, &path)?;
+        let feature_set = asset_feature_sets.get(&path).cloned().unwrap_or_default();
+        let feature = if feature_set.len() == 1 {
+            feature_set.into_iter().next().unwrap_or(None)
+        } else {
+            None
+        };
+        asset_const_by_path.insert(path.clone(), const_ident.clone());
+        assets.push(FontAssetInfo {
+            const_ident,
+            family,
+            ttf_asset_path: path,
+            feature,
+        });
+    }
+    // Fonts are registered in this order, so keep it independent of file paths.
+    assets.sort_by(|a, b| (&a.family, &a.const_ident).cmp(&(&b.family, &b.const_ident)));
+
+    Ok((assets, asset_const_by_path, variant_feature_by_key))
+}
+
+fn render_mod(packs: &[NormalizedPack]) -> Result<String> {
+    let mut out = String::new();
+    push_line(&mut out, "// @generated by xtask gen. DO NOT EDIT.");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "use crate::core::{FontAsset, IconLookupError, IconRef, PackKind, Size, Style, VariantKey};",
+    );
+    push_line(&mut out, "");
+
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        push_line(&mut out, &format!("#[cfg(feature = \"pack-{pack_id}\")]"));
+        push_line(&mut out, &format!("pub mod {pack_id};"));
+        push_line(&mut out, "");
+    }
+
+    push_line(&mut out, "/// Icon packs enabled by `pack-*` features.");
+    for line in [
+        "///",
+        "/// New packs may be added in minor releases, so matches outside this crate need a wildcard",
+        "/// arm. Even with no packs enabled, an empty match does not compile:",
+        "///",
+        "/// ```compile_fail",
+        "/// fn id(pack: iconflow::Pack) -> &'static str {",
+        "///     match pack {}",
+        "/// }",
+        "/// ```",
+    ] {
+        push_line(&mut out, line);
+    }
+    push_line(
+        &mut out,
+        "#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]",
+    );
+    push_line(&mut out, "#[non_exhaustive]");
+    push_line(&mut out, "pub enum Pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("    #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(&mut out, &format!("    {ident},"));
+    }
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, "impl Pack {");
+    push_line(&mut out, "    /// Every pack enabled in this build.");
+    push_line(&mut out, "    pub const ALL: &[Pack] = &[");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(&mut out, &format!("        Pack::{ident},"));
+    }
+    push_line(&mut out, "    ];");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    /// Canonical pack id, matching the `pack-<id>` feature suffix.",
+    );
+    push_line(&mut out, "    ///");
+    push_line(
+        &mut out,
+        "    /// This is exactly the pack module's `PACK_ID`, e.g. `bootstrap::PACK_ID`.",
+    );
+    push_line(&mut out, "    pub fn id(self) -> &'static str {");
+    push_line(&mut out, "        match self {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("            #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("            Pack::{ident} => {pack_id}::PACK_ID,"),
+        );
+    }
+    push_line(&mut out, "        }");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    /// Human-readable pack name for UIs, e.g. `\"Fluent UI\"`.",
+    );
+    push_line(&mut out, "    pub fn display_name(self) -> &'static str {");
+    push_line(&mut out, "        match self {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("            #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("            Pack::{ident} => {pack_id}::DISPLAY_NAME,"),
+        );
+    }
+    push_line(&mut out, "        }");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    /// Whether the pack holds UI icons or brand logos, from the map's `kind`.",
+    );
+    push_line(&mut out, "    pub fn kind(self) -> PackKind {");
+    push_line(&mut out, "        match self {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("            #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("            Pack::{ident} => {pack_id}::KIND,"),
+        );
+    }
+    push_line(&mut out, "        }");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    /// Captures the pack's lookup functions once, for resolving many icons of it.",
+    );
+    push_line(
+        &mut out,
+        "    pub fn resolver(self) -> crate::core::PackResolver {",
+    );
+    push_line(&mut out, "        match self {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("            #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("            Pack::{ident} => crate::core::PackResolver {{"),
+        );
+        push_line(&mut out, "                pack: self,");
+        push_line(
+            &mut out,
+            &format!("                pack_id: {pack_id}::PACK_ID,"),
+        );
+        push_line(
+            &mut out,
+            &format!("                icon_available: {pack_id}::icon_available,"),
+        );
+        push_line(
+            &mut out,
+            &format!(
+                "                variant_family: |style, size| {pack_id}::variant_info(style, size).map(|info| info.family),"
+            ),
+        );
+        push_line(
+            &mut out,
+            &format!("                icon_codepoint: {pack_id}::icon_codepoint,"),
+        );
+        push_line(&mut out, "            },");
+    }
+    push_line(&mut out, "        }");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    let mut packs_by_id: Vec<&NormalizedPack> = packs.iter().collect();
+    packs_by_id.sort_by(|a, b| a.pack_id.cmp(&b.pack_id));
+    push_line(&mut out, "pub fn fonts() -> &'static [FontAsset] {");
+    push_line(&mut out, "    &[");
+    for pack in packs_by_id {
+        let pack_id = &pack.pack_id;
+        let (assets, _, _) = collect_font_assets(pack)?;
+        for asset in assets {
+            push_line(
+                &mut out,
+                &cfg_pack_feature_line(pack_id, asset.feature.as_deref(), 8),
+            );
+            push_line(
+                &mut out,
+                &format!("        {pack_id}::{},", asset.const_ident),
+            );
+        }
+    }
+    push_line(&mut out, "    ]");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    let pack_feature_list: Vec<String> = packs
+        .iter()
+        .map(|pack| format!("feature = \"pack-{}\"", pack.pack_id))
+        .collect();
+    let any_packs_cfg = pack_feature_list.join(", ");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn list(pack: Pack) -> &'static [&'static str] {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::ICON_NAMES,"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn list(_pack: Pack) -> &'static [&'static str] {",
+    );
+    push_line(&mut out, "    &[]");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn pack_fonts(pack: Pack) -> &'static [FontAsset] {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::FONT_ASSETS,"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn pack_fonts(_pack: Pack) -> &'static [FontAsset] {",
+    );
+    push_line(&mut out, "    &[]");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn variant_fonts(pack: Pack) -> &'static [(VariantKey, FontAsset)] {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::VARIANT_ASSETS,"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn variant_fonts(_pack: Pack) -> &'static [(VariantKey, FontAsset)] {",
+    );
+    push_line(&mut out, "    &[]");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn variant_codepoint_ranges(pack: Pack) -> &'static [(VariantKey, u32, u32)] {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::VARIANT_CODEPOINT_RANGES,"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn variant_codepoint_ranges(_pack: Pack) -> &'static [(VariantKey, u32, u32)] {",
+    );
+    push_line(&mut out, "    &[]");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    for (function, ty, constant) in [
+        ("styles", "Style", "STYLES"),
+        ("sizes", "Size", "SIZES"),
+        (
+            "icon_by_codepoint",
+            "(VariantKey, u32, &'static str)",
+            "ICON_BY_CODEPOINT",
+        ),
+    ] {
+        push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+        push_line(
+            &mut out,
+            &format!("pub fn {function}(pack: Pack) -> &'static [{ty}] {{"),
+        );
+        push_line(&mut out, "    match pack {");
+        for pack in packs {
+            let pack_id = &pack.pack_id;
+            let ident = pack_enum_ident(pack_id)?;
+            push_line(
+                &mut out,
+                &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+            );
+            push_line(
+                &mut out,
+                &format!("        Pack::{ident} => {pack_id}::{constant},"),
+            );
+        }
+        push_line(&mut out, "    }");
+        push_line(&mut out, "}");
+        push_line(&mut out, "");
+
+        push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+        push_line(
+            &mut out,
+            &format!("pub fn {function}(_pack: Pack) -> &'static [{ty}] {{"),
+        );
+        push_line(&mut out, "    &[]");
+        push_line(&mut out, "}");
+        push_line(&mut out, "");
+    }
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn style_order(pack: Pack) -> &'static [Style] {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::STYLE_ORDER,"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn style_order(_pack: Pack) -> &'static [Style] {",
+    );
+    push_line(&mut out, "    &[]");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, "pub fn content_hash(pack: Pack) -> u64 {");
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::CONTENT_HASH,"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        &format!("#[cfg(all(feature = \"index\", any({any_packs_cfg})))]"),
+    );
+    push_line(
+        &mut out,
+        "const fn append_icons(out: &mut [(Pack, &'static str)], mut at: usize, pack: Pack, names: &[&'static str]) -> usize {",
+    );
+    push_line(&mut out, "    let mut index = 0;");
+    push_line(&mut out, "    while index < names.len() {");
+    push_line(&mut out, "        out[at] = (pack, names[index]);");
+    push_line(&mut out, "        at += 1;");
+    push_line(&mut out, "        index += 1;");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "    at");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        &format!("#[cfg(all(feature = \"index\", any({any_packs_cfg})))]"),
+    );
+    push_line(&mut out, "const ALL_ICONS_LEN: usize = {");
+    push_line(&mut out, "    let mut len = 0;");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        push_line(
+            &mut out,
+            &format!("    #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("    {{ len += {pack_id}::ICON_NAMES.len(); }}"),
+        );
+    }
+    push_line(&mut out, "    len");
+    push_line(&mut out, "};");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// Every `(pack, name)` pair across the enabled packs, in `Pack::ALL` order.",
+    );
+    push_line(
+        &mut out,
+        &format!("#[cfg(all(feature = \"index\", any({any_packs_cfg})))]"),
+    );
+    push_line(&mut out, "pub const ALL_ICONS: &[(Pack, &str)] = &{");
+    push_line(
+        &mut out,
+        "    let mut out = [(Pack::ALL[0], \"\"); ALL_ICONS_LEN];",
+    );
+    push_line(&mut out, "    let mut at = 0;");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("    #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!(
+                "    {{ at = append_icons(&mut out, at, Pack::{ident}, {pack_id}::ICON_NAMES); }}"
+            ),
+        );
+    }
+    push_line(&mut out, "    let _ = at;");
+    push_line(&mut out, "    out");
+    push_line(&mut out, "};");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// Every `(pack, name)` pair across the enabled packs, in `Pack::ALL` order.",
+    );
+    push_line(
+        &mut out,
+        &format!("#[cfg(all(feature = \"index\", not(any({any_packs_cfg}))))]"),
+    );
+    push_line(&mut out, "pub const ALL_ICONS: &[(Pack, &str)] = &[];");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn canonical_name_ci(pack: Pack, lowercase: &str) -> Option<&'static str> {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::canonical_name_ci(lowercase),"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn canonical_name_ci(_pack: Pack, _lowercase: &str) -> Option<&'static str> {",
+    );
+    push_line(&mut out, "    None");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn brand_color(pack: Pack, name: &str) -> Option<[u8; 4]> {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::brand_color(name),"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn brand_color(_pack: Pack, _name: &str) -> Option<[u8; 4]> {",
+    );
+    push_line(&mut out, "    None");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn icon_by_id(pack: Pack, id: u32) -> Option<&'static str> {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::icon_by_id(id),"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn icon_by_id(_pack: Pack, _id: u32) -> Option<&'static str> {",
+    );
+    push_line(&mut out, "    None");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn aria_label(pack: Pack, name: &str) -> Option<&'static str> {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::aria_label(name),"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn aria_label(_pack: Pack, _name: &str) -> Option<&'static str> {",
+    );
+    push_line(&mut out, "    None");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn rtl_mirror(pack: Pack, name: &str) -> bool {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::rtl_mirror(name),"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn rtl_mirror(_pack: Pack, _name: &str) -> bool {",
+    );
+    push_line(&mut out, "    false");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn icon_available(pack: Pack, name: &str) -> Option<&'static [(Style, Size)]> {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => {pack_id}::icon_available(name),"),
+        );
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn icon_available(_pack: Pack, _name: &str) -> Option<&'static [(Style, Size)]> {",
+    );
+    push_line(&mut out, "    None");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(any({any_packs_cfg}))]"));
+    push_line(
+        &mut out,
+        "pub fn try_icon_ref(pack: Pack, name: &str, style: Style, size: Size) -> Result<IconRef, IconLookupError<'_>> {",
+    );
+    push_line(&mut out, "    match pack {");
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let ident = pack_enum_ident(pack_id)?;
+        push_line(
+            &mut out,
+            &format!("        #[cfg(feature = \"pack-{pack_id}\")]"),
+        );
+        push_line(
+            &mut out,
+            &format!("        Pack::{ident} => crate::core::resolve_icon("),
+        );
+        push_line(&mut out, &format!("            {pack_id}::PACK_ID,"));
+        push_line(&mut out, "            name,");
+        push_line(&mut out, "            style,");
+        push_line(&mut out, "            size,");
+        push_line(
+            &mut out,
+            &format!("            {pack_id}::icon_available(name),"),
+        );
+        push_line(
+            &mut out,
+            &format!("            {pack_id}::variant_info(style, size).map(|info| info.family),"),
+        );
+        push_line(
+            &mut out,
+            &format!(
+                "            {pack_id}::icon_codepoint(name, crate::core::VariantKey {{ style, size }}),"
+            ),
+        );
+        push_line(&mut out, "        ),");
+    }
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, &format!("#[cfg(not(any({any_packs_cfg})))]"));
+    push_line(
+        &mut out,
+        "pub fn try_icon_ref(_pack: Pack, _name: &str, _style: Style, _size: Size) -> Result<IconRef, IconLookupError<'_>> {",
+    );
+    push_line(
+        &mut out,
+        "    Err(IconLookupError::PackDisabled { pack: \"none\" })",
+    );
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    Ok(out)
+}
+
+fn render_pack(pack: &NormalizedPack, options: RenderOptions) -> Result<String> {
+    let pack_id = pack.pack_id.as_str();
+
+    let mut out = String::new();
+    push_line(&mut out, "// @generated by xtask gen. DO NOT EDIT.");
+    push_line(
+        &mut out,
+        "use crate::core::{FontAsset, IconRef, PackKind, Size, Style, VariantKey};",
+    );
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        &format!(
+            "/// Canonical pack id, also returned by `Pack::{}.id()`.",
+            pack_enum_ident(&pack.pack_id)?
+        ),
+    );
+    push_line(
+        &mut out,
+        &format!("pub const PACK_ID: &str = \"{}\";", pack.pack_id),
+    );
+    push_line(
+        &mut out,
+        &format!("pub const DISPLAY_NAME: &str = {:?};", pack.display_name),
+    );
+    push_line(
+        &mut out,
+        &format!("pub const KIND: PackKind = PackKind::{:?};", pack.kind),
+    );
+    push_line(
+        &mut out,
+        "/// Fingerprint of the pack's families, names and codepoints; changes when the map does.",
+    );
+    push_line(
+        &mut out,
+        &format!(
+            "pub const CONTENT_HASH: u64 = {:#018x};",
+            content_hash(pack)
+        ),
+    );
+    let style_order = pack
+        .style_order
+        .iter()
+        .map(|style| format!("Style::{}", style.name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    push_line(
+        &mut out,
+        &format!("pub const STYLE_ORDER: &[Style] = &[{style_order}];"),
+    );
+    push_line(&mut out, "");
+
+    let (assets, asset_const_by_path, variant_feature_by_key) = collect_font_assets(pack)?;
+
+    // Icons mapped only in feature-gated variants exist only while one of those features is on.
+    let variant_gates: BTreeMap<&str, String> = pack
+        .icons
+        .iter()
+        .filter(|icon| !icon.codepoints.is_empty())
+        .filter_map(|icon| {
+            let features: BTreeSet<Option<&str>> = icon
+                .codepoints
+                .iter()
+                .map(|(key, _)| variant_feature_by_key.get(key).and_then(|f| f.as_deref()))
+                .collect();
+            any_feature_cfg(&features, 0).map(|cfg| (icon.name.as_str(), cfg))
+        })
+        .collect();
+    let icon_cfg = |name: &str, indent: usize| -> Option<String> {
+        let group = options
+            .granular_features
+            .then(|| icon_group_cfg_line(pack_id, name, indent));
+        let variant = variant_gates
+            .get(name)
+            .map(|cfg| format!("{:indent$}{cfg}", "", indent = indent));
+        match (group, variant) {
+            (Some(group), Some(variant)) => Some(format!("{group}\n{variant}")),
+            (group, variant) => group.or(variant),
+        }
+    };
+
+    for asset in &assets {
+        if let Some(feature) = &asset.feature {
+            push_line(&mut out, &cfg_attr_line(feature, 0));
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "pub(crate) const {}: FontAsset = FontAsset {{ family: \"{}\", bytes: include_bytes!(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/{}\")) }};",
+                asset.const_ident, asset.family, asset.ttf_asset_path
+            ),
+        );
+    }
+
+    push_line(&mut out, "");
+    push_line(&mut out, "pub const FONT_ASSETS: &[FontAsset] = &[");
+    for asset in &assets {
+        if let Some(feature) = &asset.feature {
+            push_line(&mut out, &cfg_attr_line(feature, 4));
+        }
+        push_line(&mut out, &format!("    {},", asset.const_ident));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "pub const VARIANT_ASSETS: &[(VariantKey, FontAsset)] = &[",
+    );
+    for variant in &pack.variants {
+        if let Some(feature) = &variant.feature {
+            push_line(&mut out, &cfg_attr_line(feature, 4));
+        }
+        let const_ident = asset_const_by_path
+            .get(&variant.ttf_asset_path.replace('\\', "/"))
+            .ok_or_else(|| anyhow::anyhow!("Missing asset const for {}", variant.ttf_asset_path))?;
+        if let Some(shift) = variant.pua_shift {
+            push_line(
+                &mut out,
+                &format!(
+                    "    // Codepoints moved by {shift:+#X} via `pua_base`; the font's cmap must match."
+                ),
+            );
+        }
+        push_line(
+            &mut out,
+            &format!("    ({}, {}),", variant_key_expr(variant.key), const_ident),
+        );
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    // A style or size is available when any variant using it is enabled.
+    let mut style_features: BTreeMap<Style, BTreeSet<Option<&str>>> = BTreeMap::new();
+    let mut size_features: BTreeMap<Size, BTreeSet<Option<&str>>> = BTreeMap::new();
+    for variant in &pack.variants {
+        let feature = variant.feature.as_deref();
+        style_features
+            .entry(variant.key.style)
+            .or_default()
+            .insert(feature);
+        size_features
+            .entry(variant.key.size)
+            .or_default()
+            .insert(feature);
+    }
+    let mut styles: Vec<_> = style_features.into_iter().collect();
+    styles.sort_by_key(|(style, _)| {
+        let position = pack
+            .style_order
+            .iter()
+            .position(|preferred| preferred == style);
+        (position.unwrap_or(pack.style_order.len()), *style)
+    });
+    push_line(
+        &mut out,
+        "/// Styles of the enabled variants, in presentation order (`STYLE_ORDER` first).",
+    );
+    push_line(&mut out, "pub const STYLES: &[Style] = &[");
+    for (style, features) in &styles {
+        if let Some(cfg) = any_feature_cfg(features, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    Style::{},", style.name()));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+    push_line(&mut out, "/// Sizes of the enabled variants, sorted.");
+    push_line(&mut out, "pub const SIZES: &[Size] = &[");
+    for (size, features) in &size_features {
+        if let Some(cfg) = any_feature_cfg(features, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    {},", size.rust_expr()));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// Lowest and highest codepoint each variant maps, for variants that map any.",
+    );
+    push_line(
+        &mut out,
+        "pub(crate) const VARIANT_CODEPOINT_RANGES: &[(VariantKey, u32, u32)] = &[",
+    );
+    for variant in &pack.variants {
+        let codepoints = pack.icons.iter().flat_map(|icon| {
+            icon.codepoints
+                .iter()
+                .filter(|(key, _)| *key == variant.key)
+                .map(|&(_, codepoint)| codepoint)
+        });
+        let Some((min, max)) = codepoints.fold(None, |range, codepoint| match range {
+            None => Some((codepoint, codepoint)),
+            Some((min, max)) => Some((u32::min(min, codepoint), u32::max(max, codepoint))),
+        }) else {
+            continue;
+        };
+        if let Some(feature) = &variant.feature {
+            push_line(&mut out, &cfg_attr_line(feature, 4));
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "    ({}, {:#06X}, {:#06X}),",
+                variant_key_expr(variant.key),
+                min,
+                max
+            ),
+        );
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]",
+    );
+    push_line(&mut out, "pub enum Icon {");
+    for icon in &pack.icons {
+        push_line(&mut out, &format!("    /// {}", icon_doc(icon)));
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    {},", icon.ident));
+    }
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// An icon's available `(style, size)` pairs and its codepoint in each variant.",
+    );
+    push_line(
+        &mut out,
+        "type IconVariantTables = (&'static [(Style, Size)], &'static [(VariantKey, u32)]);",
+    );
+    push_line(&mut out, "");
+    push_line(&mut out, "impl Icon {");
+    push_line(&mut out, "    pub const ALL: &[Icon] = &[");
+    for icon in &pack.icons {
+        if let Some(cfg) = icon_cfg(&icon.name, 8) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("        Icon::{},", icon.ident));
+    }
+    push_line(&mut out, "    ];");
+    push_line(&mut out, "");
+    push_line(&mut out, "    pub fn name(self) -> &'static str {");
+    push_line(&mut out, "        match self {");
+    for icon in &pack.icons {
+        if let Some(cfg) = icon_cfg(&icon.name, 12) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!("            Icon::{} => \"{}\",", icon.ident, icon.name),
+        );
+    }
+    push_line(&mut out, "        }");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    /// Resolves this icon at a `(style, size)` known to exist, e.g. one listed in its docs.",
+    );
+    push_line(&mut out, "    ///");
+    push_line(&mut out, "    /// # Panics");
+    push_line(&mut out, "    ///");
+    push_line(
+        &mut out,
+        "    /// Panics when the variant is unavailable; use [`Icon::try_icon`] to handle that case.",
+    );
+    push_line(
+        &mut out,
+        "    pub fn icon(self, style: Style, size: Size) -> IconRef {",
+    );
+    push_line(&mut out, "        match self.try_icon(style, size) {");
+    push_line(&mut out, "            Ok(icon) => icon,");
+    push_line(
+        &mut out,
+        "            Err(err) => panic!(\"Icon '{}' is not available in {:?}/{:?}: {:?}\", self.name(), style, size, err),",
+    );
+    push_line(&mut out, "        }");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    /// Resolves this icon at `(style, size)` without a name lookup, returning the same errors",
+    );
+    push_line(&mut out, "    /// as [`crate::try_icon`].");
+    push_line(
+        &mut out,
+        "    pub fn try_icon(self, style: Style, size: Size) -> Result<IconRef, crate::core::IconError> {",
+    );
+    push_line(
+        &mut out,
+        "        let (available, codepoints) = self.variant_tables();",
+    );
+    push_line(&mut out, "        let key = VariantKey { style, size };");
+    push_line(&mut out, "        crate::core::resolve_icon(");
+    push_line(&mut out, "            PACK_ID,");
+    push_line(&mut out, "            self.name(),");
+    push_line(&mut out, "            style,");
+    push_line(&mut out, "            size,");
+    push_line(&mut out, "            Some(available),");
+    push_line(
+        &mut out,
+        "            variant_info(style, size).map(|info| info.family),",
+    );
+    push_line(
+        &mut out,
+        "            codepoints.iter().find(|(k, _)| *k == key).map(|&(_, cp)| cp),",
+    );
+    push_line(&mut out, "        )");
+    push_line(&mut out, "        .map_err(crate::core::IconError::from)");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    fn variant_tables(self) -> IconVariantTables {",
+    );
+    push_line(&mut out, "        match self {");
+    for icon in &pack.icons {
+        if let Some(cfg) = icon_cfg(&icon.name, 12) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "            Icon::{} => ({}, {}),",
+                icon.ident,
+                icon_available_const_ident(&icon.ident)?,
+                icon_codepoints_const_ident(&icon.ident)?
+            ),
+        );
+    }
+    push_line(&mut out, "        }");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// Looks an icon up by its exact map name; unknown names yield `IconError::IconNotFound`.",
+    );
+    push_line(&mut out, "///");
+    push_line(
+        &mut out,
+        "/// Binary-searches `ICON_NAMES`, which is sorted and index-aligned with `Icon::ALL`.",
+    );
+    push_line(&mut out, "impl TryFrom<&str> for Icon {");
+    push_line(&mut out, "    type Error = crate::core::IconError;");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "    fn try_from(name: &str) -> Result<Self, crate::core::IconError> {",
+    );
+    push_line(
+        &mut out,
+        "        ICON_NAMES.binary_search(&name).map(|index| Icon::ALL[index]).map_err(|_| crate::core::IconError::IconNotFound { pack: PACK_ID, name: name.to_string() })",
+    );
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// Serializes as the icon's map name, e.g. `\"alarm\"`.",
+    );
+    push_line(&mut out, "#[cfg(feature = \"serde\")]");
+    push_line(&mut out, "impl serde::Serialize for Icon {");
+    push_line(
+        &mut out,
+        "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {",
+    );
+    push_line(&mut out, "        serializer.serialize_str(self.name())");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "/// Deserializes from the icon's map name through `TryFrom<&str>`.",
+    );
+    push_line(&mut out, "#[cfg(feature = \"serde\")]");
+    push_line(&mut out, "impl<'de> serde::Deserialize<'de> for Icon {");
+    push_line(
+        &mut out,
+        "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {",
+    );
+    push_line(
+        &mut out,
+        "        let name = <String as serde::Deserialize>::deserialize(deserializer)?;",
+    );
+    push_line(
+        &mut out,
+        "        Icon::try_from(name.as_str()).map_err(|_| <D::Error as serde::de::Error>::custom(format_args!(\"unknown {PACK_ID} icon '{name}'\")))",
+    );
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, "impl From<Icon> for &'static str {");
+    push_line(&mut out, "    fn from(icon: Icon) -> Self {");
+    push_line(&mut out, "        icon.name()");
+    push_line(&mut out, "    }");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, "pub fn icons() -> impl Iterator<Item = Icon> {");
+    push_line(&mut out, "    Icon::ALL.iter().copied()");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    push_line(&mut out, "pub const ICON_NAMES: &[&str] = &[");
+    for icon in &pack.icons {
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    \"{}\",", icon.name));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// Icon names as constants, e.g. for `try_icon(pack, names::ALARM, ..)`.",
+    );
+    push_line(&mut out, "pub mod names {");
+    for icon in &pack.icons {
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "    pub const {}: &str = \"{}\";",
+                to_upper_snake(&icon.ident)?,
+                icon.name
+            ),
+        );
+    }
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+
+    let mut lowercase_index: Vec<(String, &str)> = pack
+        .icons
+        .iter()
+        .map(|icon| (icon.name.to_lowercase(), icon.name.as_str()))
+        .collect();
+    lowercase_index.sort();
+    push_line(
+        &mut out,
+        "pub(crate) const ICON_NAMES_LOWERCASE: &[(&str, &str)] = &[",
+    );
+    for (lower, name) in &lowercase_index {
+        if let Some(cfg) = icon_cfg(name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    (\"{lower}\", \"{name}\"),"));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    let mut by_codepoint: Vec<(VariantKey, u32, &str)> = pack
+        .icons
+        .iter()
+        .flat_map(|icon| {
+            icon.codepoints
+                .iter()
+                .map(|&(key, codepoint)| (key, codepoint, icon.name.as_str()))
+        })
+        .collect();
+    by_codepoint.sort();
+    push_line(
+        &mut out,
+        "/// `(variant, codepoint, name)` for every mapped glyph, sorted for `binary_search`.",
+    );
+    push_line(
+        &mut out,
+        "pub const ICON_BY_CODEPOINT: &[(VariantKey, u32, &str)] = &[",
+    );
+    for (key, codepoint, name) in &by_codepoint {
+        if let Some(feature) = variant_feature_by_key.get(key).and_then(|f| f.as_deref()) {
+            push_line(&mut out, &cfg_attr_line(feature, 4));
+        }
+        if let Some(cfg) = icon_cfg(name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "    ({}, {codepoint:#06X}, \"{name}\"),",
+                variant_key_expr(*key)
+            ),
+        );
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(&mut out, "#[derive(Clone, Copy, Debug)]");
+    push_line(&mut out, "pub(crate) struct VariantInfo {");
+    push_line(&mut out, "    pub key: VariantKey,");
+    push_line(&mut out, "    pub family: &'static str,");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(&mut out, "pub(crate) const VARIANTS: &[VariantInfo] = &[");
+    for variant in &pack.variants {
+        if let Some(feature) = &variant.feature {
+            push_line(&mut out, &cfg_attr_line(feature, 4));
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "    VariantInfo {{ key: {}, family: \"{}\" }},",
+                variant_key_expr(variant.key),
+                variant.family
+            ),
+        );
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    for icon in &pack.icons {
+        let const_name = icon_codepoints_const_ident(&icon.ident)?;
+        push_line(&mut out, &format!("/// {}", icon_doc(icon)));
+        if let Some(cfg) = icon_cfg(&icon.name, 0) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!("const {const_name}: &[(VariantKey, u32)] = &["),
+        );
+        for (key, codepoint) in &icon.codepoints {
+            if let Some(feature) = variant_feature_by_key.get(key).and_then(|f| f.as_deref()) {
+                push_line(&mut out, &cfg_attr_line(feature, 4));
+            }
+            push_line(
+                &mut out,
+                &format!("    ({}, {codepoint}),", variant_key_expr(*key)),
+            );
+        }
+        push_line(&mut out, "];");
+        push_line(&mut out, "");
+    }
+
+    for icon in &pack.icons {
+        let const_name = icon_available_const_ident(&icon.ident)?;
+        if let Some(cfg) = icon_cfg(&icon.name, 0) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!("const {const_name}: &[(Style, Size)] = &["),
+        );
+        for (key, _) in &icon.codepoints {
+            if let Some(feature) = variant_feature_by_key.get(key).and_then(|f| f.as_deref()) {
+                push_line(&mut out, &cfg_attr_line(feature, 4));
+            }
+            push_line(
+                &mut out,
+                &format!(
+                    "    (Style::{}, {}),",
+                    key.style.name(),
+                    key.size.rust_expr()
+                ),
+            );
+        }
+        push_line(&mut out, "];");
+        push_line(&mut out, "");
+    }
+
+    // Small packs look icons up through `match` arms; the scan tables stay for tests only.
+    let match_lookup = pack.icons.len() < MATCH_LOOKUP_MAX_ICONS;
+    // Slots index the scan tables, so packs whose entries may be cfg'd out skip them.
+    let fast_lookup = !match_lookup && !options.granular_features && variant_gates.is_empty();
+    // With `fast-lookup` the slots carry the names; the tables keep theirs for the scan path.
+    let push_name_field = |out: &mut String| {
+        if fast_lookup {
+            push_line(
+                out,
+                "    #[cfg_attr(feature = \"fast-lookup\", allow(dead_code))]",
+            );
+        }
+        push_line(out, "    pub name: &'static str,");
+    };
+    let push_table_cfg = |out: &mut String| {
+        if match_lookup {
+            push_line(out, "#[cfg(test)]");
+        }
+    };
+
+    push_table_cfg(&mut out);
+    push_line(&mut out, "#[derive(Clone, Copy, Debug)]");
+    push_line(&mut out, "pub(crate) struct IconCodepoints {");
+    push_name_field(&mut out);
+    push_line(
+        &mut out,
+        "    pub codepoints: &'static [(VariantKey, u32)],",
+    );
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_table_cfg(&mut out);
+    push_line(
+        &mut out,
+        "pub(crate) const ICON_CODEPOINTS: &[IconCodepoints] = &[",
+    );
+    for icon in &pack.icons {
+        let const_name = icon_codepoints_const_ident(&icon.ident)?;
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "    IconCodepoints {{ name: \"{}\", codepoints: {} }},",
+                icon.name, const_name
+            ),
+        );
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_table_cfg(&mut out);
+    push_line(&mut out, "#[derive(Clone, Copy, Debug)]");
+    push_line(&mut out, "pub(crate) struct IconAvailability {");
+    push_name_field(&mut out);
+    push_line(&mut out, "    pub available: &'static [(Style, Size)],");
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_table_cfg(&mut out);
+    push_line(
+        &mut out,
+        "pub(crate) const ICON_AVAILABILITY: &[IconAvailability] = &[",
+    );
+    for icon in &pack.icons {
+        let const_name = icon_available_const_ident(&icon.ident)?;
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!(
+                "    IconAvailability {{ name: \"{}\", available: {} }},",
+                icon.name, const_name
+            ),
+        );
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    if fast_lookup {
+        let names: Vec<&str> = pack.icons.iter().map(|icon| icon.name.as_str()).collect();
+        push_line(
+            &mut out,
+            "/// Open-addressing index into `ICON_CODEPOINTS` and `ICON_AVAILABILITY`, probed by `icon_slot`.",
+        );
+        push_line(&mut out, "#[cfg(feature = \"fast-lookup\")]");
+        push_line(&mut out, "const ICON_SLOTS: &[Option<(&str, usize)>] = &[");
+        for slot in fast_lookup_slots(&names) {
+            match slot {
+                Some(index) => push_line(
+                    &mut out,
+                    &format!("    Some((\"{}\", {index})),", names[index]),
+                ),
+                None => push_line(&mut out, "    None,"),
+            }
+        }
+        push_line(&mut out, "];");
+        push_line(&mut out, "");
+        push_line(
+            &mut out,
+            "/// Linear probe from the name's FNV-1a hash; the table is at most half full, so it stops.",
+        );
+        push_line(&mut out, "#[cfg(feature = \"fast-lookup\")]");
+        push_line(&mut out, "fn icon_slot(name: &str) -> Option<usize> {");
+        push_line(
+            &mut out,
+            "    let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {",
+        );
+        push_line(
+            &mut out,
+            "        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)",
+        );
+        push_line(&mut out, "    });");
+        push_line(&mut out, "    let mask = ICON_SLOTS.len() - 1;");
+        push_line(&mut out, "    let mut slot = hash as usize & mask;");
+        push_line(
+            &mut out,
+            "    while let Some((entry, index)) = ICON_SLOTS[slot] {",
+        );
+        push_line(&mut out, "        if entry == name {");
+        push_line(&mut out, "            return Some(index);");
+        push_line(&mut out, "        }");
+        push_line(&mut out, "        slot = (slot + 1) & mask;");
+        push_line(&mut out, "    }");
+        push_line(&mut out, "    None");
+        push_line(&mut out, "}");
+        push_line(&mut out, "");
+    }
+
+    push_line(
+        &mut out,
+        "pub(crate) const ICON_COLORS: &[(&str, [u8; 4])] = &[",
+    );
+    for icon in &pack.icons {
+        let Some([r, g, b, a]) = icon.color else {
+            continue;
+        };
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(
+            &mut out,
+            &format!("    (\"{}\", [{r}, {g}, {b}, {a}]),", icon.name),
+        );
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "/// Accessible labels, index-aligned with `ICON_NAMES`.",
+    );
+    push_line(&mut out, "pub(crate) const ICON_LABELS: &[&str] = &[");
+    for icon in &pack.icons {
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    {:?},", icon.label));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    let mut ids: Vec<(u32, &str)> = pack
+        .icons
+        .iter()
+        .map(|icon| (icon_id(&icon.name), icon.name.as_str()))
+        .collect();
+    ids.sort();
+    push_line(&mut out, "/// Stable icon ids, sorted by id.");
+    push_line(&mut out, "pub(crate) const ICON_IDS: &[(u32, &str)] = &[");
+    for (id, name) in ids {
+        if let Some(cfg) = icon_cfg(name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    ({id:#010x}, \"{name}\"),"));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(&mut out, "pub(crate) const ICON_RTL_MIRROR: &[&str] = &[");
+    for icon in pack.icons.iter().filter(|icon| icon.rtl_mirror) {
+        if let Some(cfg) = icon_cfg(&icon.name, 4) {
+            push_line(&mut out, &cfg);
+        }
+        push_line(&mut out, &format!("    \"{}\",", icon.name));
+    }
+    push_line(&mut out, "];");
+    push_line(&mut out, "");
+
+    push_line(
+        &mut out,
+        "pub(crate) fn variant_info(style: Style, size: Size) -> Option<&'static VariantInfo> {",
+    );
+    push_line(
+        &mut out,
+        "    VARIANTS.iter().find(|variant| variant.key == VariantKey { style, size })",
+    );
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "pub(crate) fn icon_codepoint(name: &str, key: VariantKey) -> Option<u32> {",
+    );
+    if match_lookup {
+        push_line(
+            &mut out,
+            "    let codepoints: &[(VariantKey, u32)] = match name {",
+        );
+        for icon in &pack.icons {
+            if let Some(cfg) = icon_cfg(&icon.name, 8) {
+                push_line(&mut out, &cfg);
+            }
+            push_line(
+                &mut out,
+                &format!(
+                    "        \"{}\" => {},",
+                    icon.name,
+                    icon_codepoints_const_ident(&icon.ident)?
+                ),
+            );
+        }
+        push_line(&mut out, "        _ => return None,");
+        push_line(&mut out, "    };");
+        push_line(
+            &mut out,
+            "    codepoints.iter().find(|(k, _)| *k == key).map(|(_, cp)| *cp)",
+        );
+    } else {
+        let mut entry_expr = "ICON_CODEPOINTS.iter().find(|entry| entry.name == name)";
+        if fast_lookup {
+            push_line(&mut out, "    #[cfg(feature = \"fast-lookup\")]");
+            push_line(
+                &mut out,
+                "    let entry = icon_slot(name).map(|index| &ICON_CODEPOINTS[index]);",
+            );
+            push_line(&mut out, "    #[cfg(not(feature = \"fast-lookup\"))]");
+            push_line(&mut out, &format!("    let entry = {entry_expr};"));
+            entry_expr = "entry";
+        }
+        push_line(&mut out, &format!("    {entry_expr}.and_then(|entry| {{"));
+        push_line(
+            &mut out,
+            "        entry.codepoints.iter().find(|(k, _)| *k == key).map(|(_, cp)| *cp)",
+        );
+        push_line(&mut out, "    })");
+    }
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "pub(crate) fn canonical_name_ci(lowercase: &str) -> Option<&'static str> {",
+    );
+    push_line(
+        &mut out,
+        "    let index = ICON_NAMES_LOWERCASE.partition_point(|(lower, _)| *lower < lowercase);",
+    );
+    push_line(
+        &mut out,
+        "    ICON_NAMES_LOWERCASE.get(index).filter(|(lower, _)| *lower == lowercase).map(|(_, name)| *name)",
+    );
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "pub(crate) fn icon_available(name: &str) -> Option<&'static [(Style, Size)]> {",
+    );
+    if match_lookup {
+        push_line(&mut out, "    match name {");
+        for icon in &pack.icons {
+            if let Some(cfg) = icon_cfg(&icon.name, 8) {
+                push_line(&mut out, &cfg);
+            }
+            push_line(
+                &mut out,
+                &format!(
+                    "        \"{}\" => Some({}),",
+                    icon.name,
+                    icon_available_const_ident(&icon.ident)?
+                ),
+            );
+        }
+        push_line(&mut out, "        _ => None,");
+        push_line(&mut out, "    }");
+    } else if fast_lookup {
+        push_line(&mut out, "    #[cfg(feature = \"fast-lookup\")]");
+        push_line(
+            &mut out,
+            "    let entry = icon_slot(name).map(|index| &ICON_AVAILABILITY[index]);",
+        );
+        push_line(&mut out, "    #[cfg(not(feature = \"fast-lookup\"))]");
+        push_line(
+            &mut out,
+            "    let entry = ICON_AVAILABILITY.iter().find(|entry| entry.name == name);",
+        );
+        push_line(&mut out, "    entry.map(|entry| entry.available)");
+    } else {
+        push_line(
+            &mut out,
+            "    ICON_AVAILABILITY.iter().find(|entry| entry.name == name).map(|entry| entry.available)",
+        );
+    }
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "pub(crate) fn brand_color(name: &str) -> Option<[u8; 4]> {",
+    );
+    push_line(
+        &mut out,
+        "    ICON_COLORS.binary_search_by(|(entry, _)| (*entry).cmp(name)).ok().map(|index| ICON_COLORS[index].1)",
+    );
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "pub(crate) fn aria_label(name: &str) -> Option<&'static str> {",
+    );
+    push_line(
+        &mut out,
+        "    ICON_NAMES.binary_search(&name).ok().map(|index| ICON_LABELS[index])",
+    );
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(
+        &mut out,
+        "pub(crate) fn icon_by_id(id: u32) -> Option<&'static str> {",
+    );
+    push_line(
+        &mut out,
+        "    ICON_IDS.binary_search_by_key(&id, |(entry, _)| *entry).ok().map(|index| ICON_IDS[index].1)",
+    );
+    push_line(&mut out, "}");
+    push_line(&mut out, "");
+    push_line(&mut out, "pub(crate) fn rtl_mirror(name: &str) -> bool {");
+    push_line(&mut out, "    ICON_RTL_MIRROR.binary_search(&name).is_ok()");
+    push_line(&mut out, "}");
+
+    if match_lookup {
+        push_line(&mut out, "");
+        push_line(&mut out, "#[cfg(test)]");
+        push_line(&mut out, "mod tests {");
+        push_line(&mut out, "    use super::*;");
+        push_line(&mut out, "");
+        push_line(&mut out, "    #[test]");
+        push_line(&mut out, "    fn match_lookup_agrees_with_scan_tables() {");
+        push_line(&mut out, "        for entry in ICON_CODEPOINTS {");
+        push_line(
+            &mut out,
+            "            for &(key, codepoint) in entry.codepoints {",
+        );
+        push_line(
+            &mut out,
+            "                assert_eq!(icon_codepoint(entry.name, key), Some(codepoint));",
+        );
+        push_line(&mut out, "            }");
+        push_line(&mut out, "        }");
+        push_line(&mut out, "        for entry in ICON_AVAILABILITY {");
+        push_line(
+            &mut out,
+            "            assert_eq!(icon_available(entry.name), Some(entry.available));",
+        );
+        push_line(&mut out, "        }");
+        push_line(&mut out, "        assert_eq!(icon_available(\"\"), None);");
+        push_line(
+            &mut out,
+            "        assert_eq!(icon_codepoint(\"\", VARIANTS[0].key), None);",
+        );
+        push_line(&mut out, "    }");
+        push_line(&mut out, "}");
+    }
+
+    if fast_lookup {
+        push_line(&mut out, "");
+        push_line(&mut out, "#[cfg(all(test, feature = \"fast-lookup\"))]");
+        push_line(&mut out, "mod tests {");
+        push_line(&mut out, "    use super::*;");
+        push_line(&mut out, "");
+        push_line(&mut out, "    #[test]");
+        push_line(&mut out, "    fn fast_lookup_agrees_with_scan_tables() {");
+        push_line(
+            &mut out,
+            "        for (index, entry) in ICON_CODEPOINTS.iter().enumerate() {",
+        );
+        push_line(
+            &mut out,
+            "            assert_eq!(icon_slot(entry.name), Some(index));",
+        );
+        push_line(
+            &mut out,
+            "            for &(key, codepoint) in entry.codepoints {",
+        );
+        push_line(
+            &mut out,
+            "                assert_eq!(icon_codepoint(entry.name, key), Some(codepoint));",
+        );
+        push_line(&mut out, "            }");
+        push_line(
+            &mut out,
+            "            assert_eq!(icon_available(entry.name), Some(ICON_AVAILABILITY[index].available));",
+        );
+        push_line(&mut out, "        }");
+        push_line(
+            &mut out,
+            "        assert_eq!(ICON_SLOTS.iter().flatten().count(), ICON_CODEPOINTS.len());",
+        );
+        push_line(&mut out, "        assert_eq!(icon_slot(\"\"), None);");
+        push_line(&mut out, "        assert_eq!(icon_available(\"\"), None);");
+        push_line(&mut out, "    }");
+        push_line(&mut out, "}");
+    }
+
+    Ok(out)
+}
+
+/// One-line summary used as the doc comment of an icon, e.g.
+/// `` `alarm` — U+F101 (available in Regular, Filled) ``.
+fn icon_doc(icon: &NormalizedIcon) -> String {
+    let first = icon.codepoints.first().map(|(_, cp)| *cp);
+    if icon.codepoints.iter().all(|(_, cp)| Some(*cp) == first) {
+        let variants: Vec<String> = icon
+            .codepoints
+            .iter()
+            .map(|(key, _)| variant_label(*key))
+            .collect();
+        format!(
+            "`{}` — U+{:04X} (available in {})",
+            icon.name,
+            first.unwrap_or_default(),
+            variants.join(", ")
+        )
+    } else {
+        let variants: Vec<String> = icon
+            .codepoints
+            .iter()
+            .map(|(key, cp)| format!("{} U+{cp:04X}", variant_label(*key)))
+            .collect();
+        format!("`{}` — available in {}", icon.name, variants.join(", "))
+    }
+}
+
+fn variant_label(key: VariantKey) -> String {
+    match key.size {
+        Size::Regular => key.style.name().to_string(),
+        size => format!("{}/{}", key.style.name(), size.label()),
+    }
+}
+
+fn variant_key_expr(key: VariantKey) -> String {
+    format!(
+        "VariantKey {{ style: Style::{}, size: {} }}",
+        key.style.name(),
+        key.size.rust_expr()
+    )
+}
+
+fn cfg_attr_line(feature: &str, indent: usize) -> String {
+    format!(
+        "{:indent$}#[cfg(feature = \"{feature}\")]",
+        "",
+        indent = indent
+    )
+}
+
+/// `cfg` for an item needed by several variants: `None` when any of them is ungated.
+fn any_feature_cfg(features: &BTreeSet<Option<&str>>, indent: usize) -> Option<String> {
+    let features = features.iter().copied().collect::<Option<Vec<_>>>()?;
+    match features.as_slice() {
+        [feature] => Some(cfg_attr_line(feature, indent)),
+        _ => Some(format!(
+            "{:indent$}#[cfg(any({}))]",
+            "",
+            features
+                .iter()
+                .map(|feature| format!("feature = \"{feature}\""))
+                .collect::<Vec<_>>()
+                .join(", "),
+            indent = indent
+        )),
+    }
+}
+
+fn cfg_pack_feature_line(pack_id: &str, feature: Option<&str>, indent: usize) -> String {
+    match feature {
+        Some(feature) => format!(
+            "{:indent$}#[cfg(all(feature = \"pack-{pack_id}\", feature = \"{feature}\"))]",
+            "",
+            indent = indent
+        ),
+        None => format!(
+            "{:indent$}#[cfg(feature = \"pack-{pack_id}\")]",
+            "",
+            indent = indent
+        ),
+    }
+}
+
+/// Granular feature group of an icon: its ASCII initial, `0-9` for digits, `other` otherwise.
+fn icon_group(name: &str) -> String {
+    match name.chars().next() {
+        Some(ch) if ch.is_ascii_digit() => "0-9".to_string(),
+        Some(ch) if ch.is_ascii_alphabetic() => ch.to_ascii_lowercase().to_string(),
+        _ => "other".to_string(),
+    }
+}
+
+fn icon_group_feature(pack_id: &str, name: &str) -> String {
+    format!("{pack_id}-icons-{}", icon_group(name))
+}
+
+/// Keeps an entry unless `<pack>-granular` is on and its group feature is off.
+fn icon_group_cfg_line(pack_id: &str, name: &str, indent: usize) -> String {
+    format!(
+        "{:indent$}#[cfg(any(not(feature = \"{pack_id}-granular\"), feature = \"{}\"))]",
+        "",
+        icon_group_feature(pack_id, name),
+        indent = indent
+    )
+}
+
+/// Packs with fewer icons get `match`-based `icon_codepoint`/`icon_available`, which compile
+/// to a jump table instead of a linear scan over the name tables.
+const MATCH_LOOKUP_MAX_ICONS: usize = 400;
+
+const GRANULAR_FEATURES_BEGIN: &str = "# @generated granular features: begin";
+const GRANULAR_FEATURES_END: &str = "# @generated granular features: end";
+
+/// Cargo feature lines for `--granular-features`: one switch per pack plus one feature
+/// per icon group, each of which turns the switch on.
+fn render_granular_features(packs: &[NormalizedPack]) -> String {
+    let mut out = String::new();
+    for pack in packs {
+        let pack_id = &pack.pack_id;
+        let groups: BTreeSet<String> = pack
+            .icons
+            .iter()
+            .map(|icon| icon_group(&icon.name))
+            .collect();
+        push_line(
+            &mut out,
+            &format!("{pack_id}-granular = [\"pack-{pack_id}\"]"),
+        );
+        for group in groups {
+            push_line(
+                &mut out,
+                &format!("{pack_id}-icons-{group} = [\"{pack_id}-granular\"]"),
+            );
+        }
+    }
+    out
+}
+
+/// Replaces the marked granular block in `manifest`, appending it to `[features]` on first use.
+fn replace_granular_features(manifest: &str, features: &str) -> Result<String> {
+    let block = format!("{GRANULAR_FEATURES_BEGIN}\n{features}{GRANULAR_FEATURES_END}\n");
+    if let Some(begin) = manifest.find(GRANULAR_FEATURES_BEGIN) {
+        let end = manifest[begin..]
+            .find(GRANULAR_FEATURES_END)
+            .map(|offset| begin + offset + GRANULAR_FEATURES_END.len())
+            .context("Unterminated granular features block in Cargo.toml")?;
+        let end = manifest[end..]
+            .find('\n')
+            .map_or(manifest.len(), |nl| end + nl + 1);
+        return Ok(format!("{}{block}{}", &manifest[..begin], &manifest[end..]));
+    }
+
+    let features_start = manifest
+        .find("[features]\n")
+        .context("Cargo.toml has no [features] table")?;
+    let body_start = features_start + "[features]\n".len();
+    let insert_at = manifest[body_start..]
+        .find("\n[")
+        .map_or(manifest.len(), |offset| body_start + offset + 1);
+    let (head, tail) = manifest.split_at(insert_at);
+    let separator = if tail.is_empty() { "" } else { "\n" };
+    let head = head.trim_end_matches('\n');
+    Ok(format!("{head}\n{block}{separator}{tail}"))
+}
+
+fn font_asset_const_ident_from_path(pack_id: &str, ttf_asset_path: &str) -> Result<String> {
+    let path = Path::new(ttf_asset_path);
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| anyhow::anyhow!("Invalid ttf asset path: {ttf_asset_path}"))?;
+    let normalized = stem.replace('-', "_");
+    let stem_ident = to_upper_snake(&normalized)?;
+    let pack_ident = to_upper_snake(pack_id)?;
+    Ok(format!("FONT_ASSET_{pack_ident}_{stem_ident}"))
+}
+
+/// Derives the `Icon` variant ident for a kebab-case icon name.
+///
+/// Rules, applied in order:
+/// - segments are PascalCased (`arrow-left` -> `ArrowLeft`);
+/// - non-ASCII letters are transliterated (`café` -> `Cafe`), combining marks are dropped, and any
+///   other alphanumeric character is spelled as its codepoint (`中` -> `U4E2D`);
+/// - a leading digit gets an `Icon` prefix (`0-circle` -> `Icon0Circle`);
+/// - digit runs stay grouped (`arrow-90-degree` -> `Arrow90Degree`, `ICON_ARROW_90_DEGREE_*`);
+/// - an ident whose lowercase form is a Rust keyword gets a trailing `_` (`type` -> `Type_`).
+///
+/// Raw identifiers are not used: `Self` cannot be raw, and the suffix keeps every keyword case
+/// uniform. Const idents are derived from the mangled ident, so they keep the suffix as well
+/// (`Type_` -> `ICON_TYPE__CODEPOINTS`).
+fn normalize_icon_name(name: &str) -> Result<String> {
+    if name.is_empty() {
+        bail!("Icon name is empty");
+    }
+
+    let mut ident = to_pascal_case(&transliterate_name(name)?)?;
+    if ident
+        .chars()
+        .next()
+        .map(|ch| ch.is_ascii_digit())
+        .unwrap_or(false)
+    {
+        ident = format!("Icon{ident}");
+    }
+
+    if is_rust_keyword(&ident) {
+        ident.push('_');
+    }
+
+    Ok(ident)
+}
+
+fn transliterate_name(name: &str) -> Result<String> {
+    let mut out = String::with_capacity(name.len());
+    for ch in name.chars() {
+        if ch.is_ascii() {
+            out.push(ch);
+        } else if ('\u{0300}'..='\u{036F}').contains(&ch) {
+            // Combining diacritical marks carry no letter of their own.
+        } else if let Some(ascii) = transliterate_char(ch) {
+            out.push_str(ascii);
+        } else if ch.is_alphanumeric() {
+            out.push_str(&format!("U{:04X}", ch as u32));
+        } else {
+            bail!("Icon name '{name}' contains unsupported character '{ch}'");
+        }
+    }
+    Ok(out)
+}
+
+fn transliterate_char(ch: char) -> Option<&'static str> {
+    let ascii = match ch.to_lowercase().next()? {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+fn to_pascal_case(name: &str) -> Result<String> {
+    let mut out = String::new();
+    for part in name.split('-') {
+        if part.is_empty() {
+            bail!("Icon name contains empty segment: '{name}'");
+        }
+        let mut chars = part.chars();
+        let Some(first) = chars.next() else {
+            continue;
+        };
+        if first.is_ascii_alphabetic() {
+            out.push(first.to_ascii_uppercase());
+        } else {
+            out.push(first);
+        }
+        out.extend(chars);
+    }
+    Ok(out)
+}
+
+fn is_rust_keyword(ident: &str) -> bool {
+    matches!(
+        ident.to_ascii_lowercase().as_str(),
+        "as" | "break"
+            | "const"
+            | "continue"
+            | "crate"
+            | "dyn"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "self"
+            | "static"
+            | "struct"
+            | "super"
+            | "trait"
+            | "true"
+            | "type"
+            | "union"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+            | "async"
+            | "await"
+            | "try"
+            | "yield"
+    )
+}
+
+fn icon_codepoints_const_ident(ident: &str) -> Result<String> {
+    let upper = to_upper_snake(ident)?;
+    Ok(format!("ICON_{upper}_CODEPOINTS"))
+}
+
+fn icon_available_const_ident(ident: &str) -> Result<String> {
+    let upper = to_upper_snake(ident)?;
+    Ok(format!("ICON_{upper}_AVAILABLE"))
+}
+
+fn to_upper_snake(ident: &str) -> Result<String> {
+    if ident.is_empty() {
+        bail!("Identifier is empty");
+    }
+    let mut out = String::new();
+    let mut prev_digit = false;
+    for (idx, ch) in ident.chars().enumerate() {
+        let is_digit = ch.is_ascii_digit();
+        if ch.is_ascii_uppercase() {
+            if idx != 0 {
+                out.push('_');
+            }
+            out.push(ch);
+        } else if ch.is_ascii_lowercase() {
+            out.push(ch.to_ascii_uppercase());
+        } else if is_digit {
+            // A digit run is one word: `Arrow90Degree` -> `ARROW_90_DEGREE`.
+            if idx != 0 && !prev_digit && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(ch);
+        } else if ch == '_' {
+            if !out.ends_with('_') {
+                out.push('_');
+            }
+        } else {
+            bail!("Identifier contains unsupported character '{ch}'");
+        }
+        prev_digit = is_digit;
+    }
+    Ok(out)
+}
+
+fn pack_enum_ident(pack_id: &str) -> Result<String> {
+    let mut ident = to_pascal_case(pack_id)?;
+    if is_rust_keyword(&ident) {
+        ident.push('_');
+    }
+    Ok(ident)
+}
+
+fn rustfmt(code: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--emit", "stdout", "--edition", "2024"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Spawning rustfmt")?;
+
+    {
+        let stdin = child.stdin.as_mut().context("Opening rustfmt stdin")?;
+        stdin
+            .write_all(code.as_bytes())
+            .context("Writing to rustfmt stdin")?;
+    }
+
+    let output = child.wait_with_output().context("Waiting on rustfmt")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("rustfmt failed: {stderr}");
+    }
+
+    String::from_utf8(output.stdout).context("Decoding rustfmt output")
+}
+
+fn write_output(path: &Path, content: &str, check: bool) -> Result<()> {
+    match fs::read_to_string(path) {
+        Ok(existing) => {
+            if existing != content {
+                if check {
+                    eprint!("{}", output_diff(path, &existing, content));
+                    bail!("Generated file differs: {}", path.display());
+                }
+                fs::write(path, content).with_context(|| format!("Writing {}", path.display()))?;
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            if check {
+                bail!("Generated file missing: {}", path.display());
+            }
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Creating {}", parent.display()))?;
+            }
+            fs::write(path, content).with_context(|| format!("Writing {}", path.display()))?;
+        }
+        Err(err) => return Err(err.into()),
+    }
+    Ok(())
+}
+
+/// Unified diff from the file on disk to the freshly generated content, for `gen --check`.
+fn output_diff(path: &Path, existing: &str, content: &str) -> String {
+    let path = path.display();
+    similar::TextDiff::from_lines(existing, content)
+        .unified_diff()
+        .header(&format!("{path} (on disk)"), &format!("{path} (generated)"))
+        .to_string()
+}
+
+/// Icon-level differences between two normalized versions of a pack.
+#[derive(Debug, Default, PartialEq)]
+struct PackDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<CodepointChange>,
+}
+
+impl PackDiff {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A variant of an icon present in both versions whose codepoint was added, dropped or moved.
+#[derive(Debug, PartialEq)]
+struct CodepointChange {
+    name: String,
+    key: VariantKey,
+    old: Option<u32>,
+    new: Option<u32>,
+}
+
+fn diff_packs(old: &NormalizedPack, new: &NormalizedPack) -> PackDiff {
+    let old_icons: BTreeMap<&str, &NormalizedIcon> = old
+        .icons
+        .iter()
+        .map(|icon| (icon.name.as_str(), icon))
+        .collect();
+    let new_icons: BTreeMap<&str, &NormalizedIcon> = new
+        .icons
+        .iter()
+        .map(|icon| (icon.name.as_str(), icon))
+        .collect();
+
+    let mut diff = PackDiff::default();
+    for (name, new_icon) in &new_icons {
+        let Some(old_icon) = old_icons.get(name) else {
+            diff.added.push(name.to_string());
+            continue;
+        };
+        let old_codepoints: BTreeMap<VariantKey, u32> =
+            old_icon.codepoints.iter().copied().collect();
+        let new_codepoints: BTreeMap<VariantKey, u32> =
+            new_icon.codepoints.iter().copied().collect();
+        let keys: BTreeSet<VariantKey> = old_codepoints
+            .keys()
+            .chain(new_codepoints.keys())
+            .copied()
+            .collect();
+        for key in keys {
+            let (old, new) = (old_codepoints.get(&key), new_codepoints.get(&key));
+            if old != new {
+                diff.changed.push(CodepointChange {
+                    name: name.to_string(),
+                    key,
+                    old: old.copied(),
+                    new: new.copied(),
+                });
+            }
+        }
+    }
+    diff.removed = old_icons
+        .keys()
+        .filter(|name| !new_icons.contains_key(*name))
+        .map(|name| name.to_string())
+        .collect();
+    diff
+}
+
+/// How many of a pack's icons one variant resolves, from the normalized codepoints.
+#[derive(Debug, PartialEq)]
+struct VariantCoverage {
+    variant_id: String,
+    key: VariantKey,
+    icons: usize,
+    total: usize,
+}
+
+impl VariantCoverage {
+    fn percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.icons as f64 * 100.0 / self.total as f64
+    }
+}
+
+fn variant_coverage(pack: &NormalizedPack) -> Vec<VariantCoverage> {
+    pack.variants
+        .iter()
+        .map(|variant| VariantCoverage {
+            variant_id: variant.id.clone(),
+            key: variant.key,
+            icons: pack
+                .icons
+                .iter()
+                .filter(|icon| icon.codepoints.iter().any(|(key, _)| *key == variant.key))
+                .count(),
+            total: pack.icons.len(),
+        })
+        .collect()
+}
+
+/// Symbol ids written next to an SVG sprite so front-ends can look icons up by name.
+#[derive(Debug, Serialize)]
+struct SpriteManifest {
+    pack_id: String,
+    symbols: Vec<SpriteSymbol>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpriteSymbol {
+    id: String,
+    name: String,
+}
+
+/// SVG path of one glyph, in font units with the y axis pointing down.
+#[derive(Debug)]
+struct GlyphPath {
+    view_box: [i32; 4],
+    data: String,
+}
+
+struct SvgPathBuilder {
+    data: String,
+    ascender: f32,
+}
+
+impl ttf_parser::OutlineBuilder for SvgPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let y = self.ascender - y;
+        self.data.push_str(&format!("M{x} {y}"));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let y = self.ascender - y;
+        self.data.push_str(&format!("L{x} {y}"));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (y1, y) = (self.ascender - y1, self.ascender - y);
+        self.data.push_str(&format!("Q{x1} {y1} {x} {y}"));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (y1, y2, y) = (self.ascender - y1, self.ascender - y2, self.ascender - y);
+        self.data.push_str(&format!("C{x1} {y1} {x2} {y2} {x} {y}"));
+    }
+
+    fn close(&mut self) {
+        self.data.push('Z');
+    }
+}
+
+/// Outline of `codepoint` as SVG path data, or `None` when the font has no such glyph.
+///
+/// The view box spans the glyph advance horizontally and ascender to descender vertically;
+/// glyphs without contours yield empty path data.
+fn to_path_data(face: &ttf_parser::Face, codepoint: u32) -> Option<GlyphPath> {
+    let glyph = face.glyph_index(char::from_u32(codepoint)?)?;
+    let ascender = face.ascender();
+    let height = i32::from(ascender) - i32::from(face.descender());
+    let advance = face
+        .glyph_hor_advance(glyph)
+        .unwrap_or_else(|| face.units_per_em());
+    let mut builder = SvgPathBuilder {
+        data: String::new(),
+        ascender: f32::from(ascender),
+    };
+    face.outline_glyph(glyph, &mut builder);
+    Some(GlyphPath {
+        view_box: [0, 0, i32::from(advance), height],
+        data: builder.data,
+    })
+}
+
+/// The variant a sprite draws for `icon`: Regular/Regular when available, else the first.
+fn default_variant(icon: &NormalizedIcon) -> Option<(VariantKey, u32)> {
+    let regular = VariantKey {
+        style: Style::Regular,
+        size: Size::Regular,
+    };
+    icon.codepoints
+        .iter()
+        .find(|(key, _)| *key == regular)
+        .or_else(|| icon.codepoints.first())
+        .copied()
+}
+
+fn render_svg_sprite(pack: &NormalizedPack, repo_root: &Path) -> Result<(String, SpriteManifest)> {
+    let mut fonts = BTreeMap::new();
+    for variant in &pack.variants {
+        let path = repo_root.join(&variant.ttf_asset_path);
+        let bytes = fs::read(&path).with_context(|| format!("Reading {}", path.display()))?;
+        fonts.insert(variant.key, bytes);
+    }
+    let mut faces = BTreeMap::new();
+    for (key, bytes) in &fonts {
+        let face = ttf_parser::Face::parse(bytes, 0).with_context(|| {
+            format!("{}: parsing font for {}", pack.pack_id, variant_label(*key))
+        })?;
+        faces.insert(*key, face);
+    }
+
+    let mut out = String::new();
+    push_line(
+        &mut out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" style="display: none">"#,
+    );
+    let mut symbols = Vec::new();
+    for icon in &pack.icons {
+        let Some((key, codepoint)) = default_variant(icon) else {
+            continue;
+        };
+        let face = faces
+            .get(&key)
+            .with_context(|| format!("{}: no font for {}", pack.pack_id, variant_label(key)))?;
+        let path = to_path_data(face, codepoint).with_context(|| {
+            format!(
+                "{}: icon '{}' has no glyph at U+{codepoint:04X}",
+                pack.pack_id, icon.name
+            )
+        })?;
+        let id = format!("{}-{}", pack.pack_id, icon.name);
+        let [x, y, width, height] = path.view_box;
+        push_line(
+            &mut out,
+            &format!(
+                r#"  <symbol id="{id}" viewBox="{x} {y} {width} {height}"><path d="{}"/></symbol>"#,
+                path.data
+            ),
+        );
+        symbols.push(SpriteSymbol {
+            id,
+            name: icon.name.clone(),
+        });
+    }
+    push_line(&mut out, "</svg>");
+
+    let manifest = SpriteManifest {
+        pack_id: pack.pack_id.clone(),
+        symbols,
+    };
+    Ok((out, manifest))
+}
+
+fn push_line(out: &mut String, line: &str) {
+    out.push_str(line);
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_icon_names() {
+        assert_eq!(normalize_icon_name("arrow-left").unwrap(), "ArrowLeft");
+        assert_eq!(normalize_icon_name("0-circle").unwrap(), "Icon0Circle");
+        assert_eq!(normalize_icon_name("type").unwrap(), "Type_");
+    }
+
+    #[test]
+    fn numeric_segments_keep_their_grouping() {
+        for (name, ident, upper) in [
+            ("h-1", "H1", "H_1"),
+            ("arrow-90-degree", "Arrow90Degree", "ARROW_90_DEGREE"),
+            ("v2", "V2", "V_2"),
+            ("v2-icon", "V2Icon", "V_2_ICON"),
+            ("0-circle", "Icon0Circle", "ICON_0_CIRCLE"),
+            ("123", "Icon123", "ICON_123"),
+        ] {
+            let normalized = normalize_icon_name(name).unwrap();
+            assert_eq!(normalized, ident);
+            assert_eq!(
+                icon_codepoints_const_ident(&normalized).unwrap(),
+                format!("ICON_{upper}_CODEPOINTS")
+            );
+            assert_eq!(
+                icon_available_const_ident(&normalized).unwrap(),
+                format!("ICON_{upper}_AVAILABLE")
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_icon_names_transliterates_unicode() {
+        assert_eq!(normalize_icon_name("café-icon").unwrap(), "CafeIcon");
+        assert_eq!(normalize_icon_name("Über-straße").unwrap(), "UberStrasse");
+        assert_eq!(normalize_icon_name("cafe\u{301}").unwrap(), "Cafe");
+        assert_eq!(normalize_icon_name("中文-icon").unwrap(), "U4E2DU6587Icon");
+        assert!(icon_codepoints_const_ident("U4E2DU6587Icon").is_ok());
+        assert!(normalize_icon_name("star-★").is_err());
+    }
+
+    #[test]
+    fn normalize_icon_names_suffixes_keywords() {
+        for (name, ident) in [("self", "Self_"), ("crate", "Crate_"), ("type", "Type_")] {
+            let normalized = normalize_icon_name(name).unwrap();
+            assert_eq!(normalized, ident);
+            let upper = ident.trim_end_matches('_').to_ascii_uppercase();
+            assert_eq!(
+                icon_codepoints_const_ident(&normalized).unwrap(),
+                format!("ICON_{upper}__CODEPOINTS")
+            );
+            assert_eq!(
+                icon_available_const_ident(&normalized).unwrap(),
+                format!("ICON_{upper}__AVAILABLE")
+            );
+        }
+        assert_eq!(
+            normalize_icon_name("self-portrait").unwrap(),
+            "SelfPortrait"
+        );
+    }
+
+    #[test]
+    fn normalize_pack_requires_codepoints() {
+        let pack = PackMap {
+            pack_id: "demo".to_string(),
+            source_path: PathBuf::from("demo.json"),
+            allow_zero_codepoint: false,
+            display_name: None,
+            style_order: Vec::new(),
+            kind: PackKind::Ui,
+            variants: vec![Variant {
+                id: "regular".to_string(),
+                style: Style::Regular,
+                size: Size::Regular,
+                family: "Demo Regular".to_string(),
+                ttf_asset_path: "assets/fonts/demo.ttf".to_string(),
+                feature: None,
+                inherit_from: None,
+                codepoint_offset: None,
+                pua_base: None,
+            }],
+            icons: vec![Icon {
+                name: "missing".to_string(),
+                codepoint: None,
+                overrides: BTreeMap::new(),
+                availability: None,
+                unavailable: Vec::new(),
+                color: None,
+                rtl_mirror: false,
+                label: None,
+            }],
+        };
+
+        let err = normalize_pack(pack, NormalizeOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("has no codepoint or overrides"));
+    }
+
+    #[test]
+    fn normalize_pack_uses_overrides_when_no_default() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert("regular".to_string(), 42);
+
+        let pack = PackMap {
+            pack_id: "demo".to_string(),
+            source_path: PathBuf::from("demo.json"),
+            allow_zero_codepoint: false,
+            display_name: None,
+            style_order: Vec::new(),
+            kind: PackKind::Ui,
+            variants: vec![Variant {
+                id: "regular".to_string(),
+                style: Style::Regular,
+                size: Size::Regular,
+                family: "Demo Regular".to_string(),
+                ttf_asset_path: "assets/fonts/demo.ttf".to_string(),
+                feature: None,
+                inherit_from: None,
+                codepoint_offset: None,
+                pua_base: None,
+            }],
+            icons: vec![Icon {
+                name: "icon".to_string(),
+                codepoint: None,
+                overrides,
+                availability: None,
+                unavailable: Vec::new(),
+                color: None,
+                rtl_mirror: false,
+                label: None,
+            }],
+        };
+
+        let normalized = normalize_pack(pack, NormalizeOptions::default()).unwrap();
+        assert_eq!(normalized.icons.len(), 1);
+        assert_eq!(normalized.icons[0].codepoints.len(), 1);
+        assert_eq!(normalized.icons[0].codepoints[0].1, 42);
+    }
+
+    fn collision_pack() -> PackMap {
+        let icon = |name: &str, codepoint| Icon {
+            name: name.to_string(),
+            codepoint: Some(codepoint),
+            overrides: BTreeMap::new(),
+            availability: None,
+            unavailable: Vec::new(),
+            color: None,
+            rtl_mirror: false,
+            label: None,
+        };
+        PackMap {
+            pack_id: "demo".to_string(),
+            source_path: PathBuf::from("demo.json"),
+            allow_zero_codepoint: false,
+            display_name: None,
+            style_order: Vec::new(),
+            kind: PackKind::Ui,
+            variants: vec![Variant {
+                id: "regular".to_string(),
+                style: Style::Regular,
+                size: Size::Regular,
+                family: "Demo Regular".to_string(),
+                ttf_asset_path: "assets/fonts/demo.ttf".to_string(),
+                feature: None,
+                inherit_from: None,
+                codepoint_offset: None,
+                pua_base: None,
+            }],
+            icons: vec![
+                icon("h1", 1),
+                icon("h-1", 2),
+                icon("h-12", 3),
+                icon("h-1-2", 4),
+            ],
+        }
+    }
+
+    #[test]
+    fn normalize_pack_fails_on_ident_collision_by_default() {
+        let err = normalize_pack(collision_pack(), NormalizeOptions::default()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("'h-1-2' and 'h-12' both map to 'H12'")
+        );
+    }
+
+    #[test]
+    fn normalize_pack_suffixes_colliding_idents() {
+        let options = NormalizeOptions {
+            resolve_collisions: true,
+            ..NormalizeOptions::default()
+        };
+        let normalized = normalize_pack(collision_pack(), options).unwrap();
+        let idents: Vec<(&str, &str)> = normalized
+            .icons
+            .iter()
+            .map(|icon| (icon.name.as_str(), icon.ident.as_str()))
+            .collect();
+        assert_eq!(
+            idents,
+            [
+                ("h-1", "H1"),
+                ("h-1-2", "H12"),
+                ("h-12", "H122"),
+                ("h1", "H13")
+            ]
+        );
+    }
+
+    #[test]
+    fn size_deserializes_custom_number() {
+        let raw = r#"
+        {
+          "pack_id": "demo",
+          "variants": [
+            {
+              "id": "regular-20",
+              "style": "Regular",
+              "size": 20,
+              "family": "Demo Regular",
+              "ttf_asset_path": "assets/fonts/demo/demo.ttf"
+            }
+          ],
+          "icons": [
+            { "name": "demo", "codepoint": 1 }
+          ]
+        }"#;
+        let map: PackMap = serde_json::from_str(raw).unwrap();
+        assert_eq!(map.variants.len(), 1);
+        assert_eq!(map.variants[0].size, Size::Custom(20));
+    }
+
+    fn parse_icon(raw: &str) -> Result<Icon, serde_json::Error> {
+        serde_json::from_str(raw)
+    }
+
+    #[test]
+    fn codepoints_accept_integer_and_string_forms() {
+        for raw in [
+            r#"{ "name": "demo", "codepoint": 57345 }"#,
+            r#"{ "name": "demo", "codepoint": "U+E001" }"#,
+            r#"{ "name": "demo", "codepoint": "0xe001" }"#,
+            r#"{ "name": "demo", "codepoint": "\\ue001" }"#,
+            r#"{ "name": "demo", "codepoint": "\\u{e001}" }"#,
+            r#"{ "name": "demo", "codepoint": "\ue001" }"#,
+        ] {
+            assert_eq!(parse_icon(raw).unwrap().codepoint, Some(0xE001), "{raw}");
+        }
+
+        let icon =
+            parse_icon(r#"{ "name": "demo", "overrides": { "filled": "U+F000" } }"#).unwrap();
+        assert_eq!(icon.overrides.get("filled"), Some(&0xF000));
+    }
+
+    #[test]
+    fn codepoints_reject_out_of_range_values() {
+        for raw in [
+            r#"{ "name": "demo", "codepoint": 1114112 }"#,
+            r#"{ "name": "demo", "codepoint": "U+110000" }"#,
+            r#"{ "name": "demo", "codepoint": "0xD800" }"#,
+            r#"{ "name": "demo", "codepoint": "E001" }"#,
+            r#"{ "name": "demo", "codepoint": "E" }"#,
+            r#"{ "name": "demo", "codepoint": "7" }"#,
+            r#"{ "name": "demo", "overrides": { "filled": "0x" } }"#,
+        ] {
+            assert!(parse_icon(raw).is_err(), "{raw}");
+        }
+    }
+
+    #[test]
+    fn render_pack_documents_icon_codepoints() {
+        let regular = VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        };
+        let filled = VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        };
+        let mini = VariantKey {
+            style: Style::Filled,
+            size: Size::Mini,
+        };
+        let variant = |id: &str, key: VariantKey| VariantInfo {
+            id: id.to_string(),
+            key,
+            family: "Demo".to_string(),
+            ttf_asset_path: format!("assets/fonts/demo/demo-{id}.ttf"),
+            feature: None,
+            pua_shift: None,
+        };
+        let pack = NormalizedPack {
+            pack_id: "demo".to_string(),
+            display_name: "Demo".to_string(),
+            kind: PackKind::Ui,
+            style_order: Vec::new(),
+            variants: vec![
+                variant("filled", filled),
+                variant("mini", mini),
+                variant("regular", regular),
+            ],
+            icons: vec![
+                NormalizedIcon {
+                    name: "alarm".to_string(),
+                    ident: "Alarm".to_string(),
+                    codepoints: vec![(filled, 0xE001), (regular, 0xE001)],
+                    color: None,
+                    rtl_mirror: false,
+                    label: "Alarm".to_string(),
+                },
+                NormalizedIcon {
+                    name: "bell".to_string(),
+                    ident: "Bell".to_string(),
+                    codepoints: vec![(mini, 0xF002), (regular, 0xE002)],
+                    color: None,
+                    rtl_mirror: false,
+                    label: "Bell".to_string(),
+                },
+            ],
+        };
+
+        let out = render_pack(&pack, RenderOptions::default()).unwrap();
+        let alarm_doc = "/// `alarm` — U+E001 (available in Filled, Regular)";
+        assert!(out.contains(&format!("    {alarm_doc}\n    Alarm,")));
+        assert!(out.contains(&format!("{alarm_doc}\nconst ICON_ALARM_CODEPOINTS")));
+        assert!(out.contains("/// `bell` — available in Filled/Mini U+F002, Regular U+E002"));
+    }
+
+    #[test]
+    fn collect_font_assets_deduplicates_by_path() {
+        let pack = NormalizedPack {
+            pack_id: "demo".to_string(),
+            display_name: "Demo".to_string(),
+            kind: PackKind::Ui,
+            style_order: Vec::new(),
+            variants: vec![
+                VariantInfo {
+                    id: "regular".to_string(),
+                    key: VariantKey {
+                        style: Style::Regular,
+                        size: Size::Regular,
+                    },
+                    family: "Demo Regular".to_string(),
+                    ttf_asset_path: "assets/fonts/demo/demo.ttf".to_string(),
+                    feature: None,
+                    pua_shift: None,
+                },
+                VariantInfo {
+                    id: "filled".to_string(),
+                    key: VariantKey {
+                        style: Style::Filled,
+                        size: Size::Regular,
+                    },
+                    family: "Demo Regular".to_string(),
+                    ttf_asset_path: "assets/fonts/demo/demo.ttf".to_string(),
+                    feature: None,
+                    pua_shift: None,
+                },
+            ],
+            icons: Vec::new(),
+        };
+
+        let (assets, _, _) = collect_font_assets(&pack).unwrap();
+        assert_eq!(assets.len(), 1);
+    }
+
+    #[test]
+    fn collect_font_assets_preserves_feature_when_uniform() {
+        let pack = NormalizedPack {
+            pack_id: "demo".to_string(),
+            display_name: "Demo".to_string(),
+            kind: PackKind::Ui,
+            style_order: Vec::new(),
+            variants: vec![
+                VariantInfo {
+                    id: "tiny".to_string(),
+                    key: VariantKey {
+                        style: Style::Regular,
+                        size: Size::Tiny,
+                    },
+                    family: "Demo Tiny".to_string(),
+                    ttf_asset_path: "assets/fonts/demo/demo-tiny.ttf".to_string(),
+                    feature: Some("demo-tiny".to_string()),
+                    pua_shift: None,
+                },
+                VariantInfo {
+                    id: "tiny-filled".to_string(),
+                    key: VariantKey {
+                        style: Style::Filled,
+                        size: Size::Tiny,
+                    },
+                    family: "Demo Tiny".to_string(),
+                    ttf_asset_path: "assets/fonts/demo/demo-tiny.ttf".to_string(),
+                    feature: Some("demo-tiny".to_string()),
+                    pua_shift: None,
+                },
+            ],
+            icons: Vec::new(),
+        };
+
+        let (assets, _, _) = collect_font_assets(&pack).unwrap();
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].feature.as_deref(), Some("demo-tiny"));
+    }
+
+    fn granular_demo_pack() -> NormalizedPack {
+        let regular = VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        };
+        let icon = |name: &str, ident: &str, codepoint: u32| NormalizedIcon {
+            name: name.to_string(),
+            ident: ident.to_string(),
+            codepoints: vec![(regular, codepoint)],
+            color: None,
+            rtl_mirror: false,
+            label: title_case_label(name),
+        };
+        NormalizedPack {
+            pack_id: "demo".to_string(),
+            display_name: "Demo".to_string(),
+            kind: PackKind::Ui,
+            style_order: Vec::new(),
+            variants: vec![VariantInfo {
+                id: "regular".to_string(),
+                key: regular,
+                family: "Demo".to_string(),
+                ttf_asset_path: "assets/fonts/demo/demo-regular.ttf".to_string(),
+                feature: None,
+                pua_shift: None,
+            }],
+            icons: vec![
+                icon("0-circle", "Icon0Circle", 0xE000),
+                icon("alarm", "Alarm", 0xE001),
+                icon("bell", "Bell", 0xE002),
+            ],
+        }
+    }
+
+    #[test]
+    fn content_hash_is_stable_and_tracks_map_changes() {
+        let pack = granular_demo_pack();
+        let hash = content_hash(&pack);
+        assert_ne!(hash, 0);
+        assert_eq!(content_hash(&granular_demo_pack()), hash);
+        let out = render_pack(&pack, RenderOptions::default()).unwrap();
+        assert!(out.contains(&format!("pub const CONTENT_HASH: u64 = {hash:#018x};")));
+
+        let mut remapped = granular_demo_pack();
+        remapped.icons[1].codepoints[0].1 = 0xE00A;
+        assert_ne!(content_hash(&remapped), hash);
+        let mut renamed = granular_demo_pack();
+        renamed.icons[1].name = "alarms".to_string();
+        assert_ne!(content_hash(&renamed), hash);
+        let mut refamilied = granular_demo_pack();
+        refamilied.variants[0].family = "Demo 2".to_string();
+        assert_ne!(content_hash(&refamilied), hash);
+    }
+
+    #[test]
+    fn icon_by_codepoint_is_sorted_and_gated_per_icon() {
+        let mut pack = granular_demo_pack();
+        pack.icons[1].codepoints[0].1 = 0xE002;
+        pack.icons[2].codepoints[0].1 = 0xE001;
+        let out = render_pack(
+            &pack,
+            RenderOptions {
+                granular_features: true,
+            },
+        )
+        .unwrap();
+        let table = &out[out.find("pub const ICON_BY_CODEPOINT").unwrap()..];
+        let position = |needle: &str| table.find(needle).unwrap();
+        assert!(position("0xE000, \"0-circle\")") < position("0xE001, \"bell\")"));
+        assert!(position("0xE001, \"bell\")") < position("0xE002, \"alarm\")"));
+        assert!(table.contains(
+            "    #[cfg(any(not(feature = \"demo-granular\"), feature = \"demo-icons-b\"))]\n    (VariantKey"
+        ));
+    }
+
+    #[test]
+    fn fast_lookup_slots_probe_past_collisions() {
+        let names: Vec<String> = (0..64).map(|n| format!("icon-{n}")).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let slots = fast_lookup_slots(&names);
+        assert_eq!(slots.len(), 128);
+        assert_eq!(slots.iter().flatten().count(), names.len());
+
+        let mask = slots.len() - 1;
+        let home = |name: &str| icon_id(name) as usize & mask;
+        let mut displaced = 0;
+        for (index, name) in names.iter().enumerate() {
+            let mut slot = home(name);
+            while slots[slot] != Some(index) {
+                assert!(slots[slot].is_some(), "probe for {name} hit an empty slot");
+                slot = (slot + 1) & mask;
+            }
+            if slot != home(name) {
+                displaced += 1;
+            }
+        }
+        assert!(displaced > 0, "expected at least one colliding name");
+
+        assert_eq!(fast_lookup_slots(&[]), vec![None]);
+    }
+
+    #[test]
+    fn render_pack_gates_icons_by_group_when_granular() {
+        let pack = granular_demo_pack();
+        let gate = |group: &str| {
+            format!(
+                "#[cfg(any(not(feature = \"demo-granular\"), feature = \"demo-icons-{group}\"))]"
+            )
+        };
+
+        let plain = render_pack(&pack, RenderOptions::default()).unwrap();
+        assert!(!plain.contains("demo-granular"));
+        assert!(plain.contains("        \"alarm\" => ICON_ALARM_CODEPOINTS,"));
+        assert!(plain.contains("#[cfg(test)]\npub(crate) const ICON_CODEPOINTS"));
+
+        let out = render_pack(
+            &pack,
+            RenderOptions {
+                granular_features: true,
+            },
+        )
+        .unwrap();
+        assert!(out.contains(&format!("    {}\n    Alarm,", gate("a"))));
+        assert!(out.contains(&format!("        {}\n        Icon::Bell,", gate("b"))));
+        assert!(out.contains(&format!("    {}\n    \"0-circle\",", gate("0-9"))));
+        assert!(out.contains(&format!("{}\nconst ICON_ALARM_CODEPOINTS", gate("a"))));
+        assert!(out.contains(&format!("{}\nconst ICON_BELL_AVAILABLE", gate("b"))));
+        assert!(out.contains(&format!(
+            "    {}\n    IconCodepoints {{ name: \"alarm\"",
+            gate("a")
+        )));
+    }
+
+    #[test]
+    fn granular_features_block_is_inserted_once_and_replaced() {
+        let features = render_granular_features(&[granular_demo_pack()]);
+        assert_eq!(
+            features,
+            "demo-granular = [\"pack-demo\"]\n\
+             demo-icons-0-9 = [\"demo-granular\"]\n\
+             demo-icons-a = [\"demo-granular\"]\n\
+             demo-icons-b = [\"demo-granular\"]\n"
+        );
+
+        let manifest = "[package]\nname = \"demo\"\n\n[features]\ndefault = []\n\n[workspace]\n";
+        let once = replace_granular_features(manifest, &features).unwrap();
+        assert_eq!(
+            once,
+            format!(
+                "[package]\nname = \"demo\"\n\n[features]\ndefault = []\n\
+                 {GRANULAR_FEATURES_BEGIN}\n{features}{GRANULAR_FEATURES_END}\n\n[workspace]\n"
+            )
+        );
+        assert_eq!(replace_granular_features(&once, &features).unwrap(), once);
+
+        let shrunk = replace_granular_features(&once, "demo-granular = []\n").unwrap();
+        assert!(shrunk.contains("demo-granular = []\n# @generated"));
+        assert!(!shrunk.contains("demo-icons-a"));
+    }
+
+    #[test]
+    fn parse_hex_color_accepts_rgb_and_rgba() {
+        assert_eq!(parse_hex_color("#1DA1F2"), Some([0x1D, 0xA1, 0xF2, 0xFF]));
+        assert_eq!(parse_hex_color("#1da1f280"), Some([0x1D, 0xA1, 0xF2, 0x80]));
+        for invalid in [
+            "1DA1F2",
+            "#1DA1F",
+            "#1DA1F2F",
+            "#GGGGGG",
+            "#",
+            "#１２３４５６",
+        ] {
+            assert_eq!(parse_hex_color(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn normalize_pack_validates_brand_colors() {
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.icons[0].color = Some("#1DA1F2".to_string());
+        let normalized = normalize_pack(pack, NormalizeOptions::default()).unwrap();
+        assert_eq!(normalized.icons[0].color, Some([0x1D, 0xA1, 0xF2, 0xFF]));
+        let name = normalized.icons[0].name.clone();
+        let out = render_pack(&normalized, RenderOptions::default()).unwrap();
+        assert!(out.contains(&format!("    (\"{name}\", [29, 161, 242, 255]),")));
+
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.icons[0].color = Some("blue".to_string());
+        let err = normalize_pack(pack, NormalizeOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("invalid color 'blue'"));
+    }
+
+    #[test]
+    fn labels_default_to_title_cased_names() {
+        assert_eq!(title_case_label("arrow-left"), "Arrow Left");
+        assert_eq!(title_case_label("0-circle"), "0 Circle");
+        assert_eq!(title_case_label("über-straße"), "Über Straße");
+
+        let mut pack = collision_pack();
+        pack.icons
+            .retain(|icon| icon.name == "h1" || icon.name == "h-12");
+        pack.icons[0].label = Some("Heading one".to_string());
+        let normalized = normalize_pack(pack, NormalizeOptions::default()).unwrap();
+        let labels: Vec<&str> = normalized
+            .icons
+            .iter()
+            .map(|icon| icon.label.as_str())
+            .collect();
+        assert_eq!(labels, ["H 12", "Heading one"]);
+
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.icons[0].label = Some("  ".to_string());
+        let err = normalize_pack(pack, NormalizeOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("has an empty label"));
+    }
+
+    #[test]
+    fn custom_size_variants_render_end_to_end() {
+        let mut pack: PackMap = serde_json::from_str(
+            r#"{
+                "pack_id": "demo",
+                "variants": [
+                    { "id": "16", "style": "Regular", "size": 16, "family": "Demo 16",
+                      "ttf_asset_path": "assets/fonts/demo/demo-16.ttf" },
+                    { "id": "24", "style": "Regular", "size": 24, "family": "Demo 24",
+                      "ttf_asset_path": "assets/fonts/demo/demo-24.ttf" }
+                ],
+                "icons": [
+                    { "name": "alarm", "codepoint": 57345, "overrides": { "24": 57346 } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        pack.source_path = PathBuf::from("demo.json");
+
+        let normalized = normalize_pack(pack, NormalizeOptions::default()).unwrap();
+        let sizes: Vec<Size> = normalized.variants.iter().map(|v| v.key.size).collect();
+        assert_eq!(sizes, [Size::Custom(16), Size::Custom(24)]);
+
+        let out = render_pack(&normalized, RenderOptions::default()).unwrap();
+        assert!(out.contains(
+            "    (VariantKey { style: Style::Regular, size: Size::Custom(24) }, 57346),"
+        ));
+        assert!(out.contains("    (Style::Regular, Size::Custom(16)),"));
+        assert!(out.contains("/// `alarm` — available in Regular/16 U+E001, Regular/24 U+E002"));
+    }
+
+    #[test]
+    fn normalize_pack_rejects_variant_ids_sharing_a_key() {
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        let mut twin = pack.variants[0].clone();
+        twin.id = "regular-copy".to_string();
+        twin.ttf_asset_path = "assets/fonts/demo-copy.ttf".to_string();
+        pack.variants.push(twin);
+
+        let err = normalize_pack(pack, NormalizeOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "demo.json: variants 'regular' and 'regular-copy' share style/size Regular/Regular"
+        );
+    }
+
+    #[test]
+    fn icon_ids_are_fnv1a_of_the_name() {
+        assert_eq!(icon_id(""), 0x811c_9dc5);
+        assert_eq!(icon_id("a"), 0xe40c_292c);
+        assert_eq!(icon_id("foobar"), 0xbf9c_f968);
+
+        let out = render_pack(&granular_demo_pack(), RenderOptions::default()).unwrap();
+        assert!(out.contains(&format!("    ({:#010x}, \"alarm\"),", icon_id("alarm"))));
+    }
+
+    #[test]
+    fn svg_sprite_has_one_symbol_per_icon() {
+        let repo_root = repo_root().unwrap();
+        let map = load_pack_map(&repo_root.join("assets/maps/feather.json")).unwrap();
+        let pack = normalize_pack(map, NormalizeOptions::default()).unwrap();
+        let (sprite, manifest) = render_svg_sprite(&pack, &repo_root).unwrap();
+
+        assert_eq!(manifest.symbols.len(), pack.icons.len());
+        assert_eq!(sprite.matches("<symbol ").count(), pack.icons.len());
+        assert!(sprite.contains(r#"<symbol id="feather-activity" viewBox="0 0 "#));
+        assert!(sprite.starts_with("<svg ") && sprite.ends_with("</svg>\n"));
+        assert_eq!(manifest.symbols[0].id, "feather-activity");
+        assert_eq!(manifest.symbols[0].name, "activity");
+    }
+
+    #[test]
+    fn variant_coverage_counts_icons_per_variant() {
+        let mut pack = granular_demo_pack();
+        let filled = VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        };
+        pack.variants.push(VariantInfo {
+            id: "filled".to_string(),
+            key: filled,
+            family: "Demo Filled".to_string(),
+            ttf_asset_path: "assets/fonts/demo/demo-filled.ttf".to_string(),
+            feature: None,
+            pua_shift: None,
+        });
+        pack.icons[1].codepoints.push((filled, 0xF001));
+
+        let coverage = variant_coverage(&pack);
+        assert_eq!(coverage.len(), 2);
+        assert_eq!(coverage[0].variant_id, "regular");
+        assert_eq!((coverage[0].icons, coverage[0].total), (3, 3));
+        assert_eq!(coverage[0].percent(), 100.0);
+        assert_eq!(coverage[1].variant_id, "filled");
+        assert_eq!((coverage[1].icons, coverage[1].total), (1, 3));
+        assert!((coverage[1].percent() - 100.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn diff_packs_reports_added_removed_and_moved_codepoints() {
+        let pack = |icons: &str| {
+            let raw = format!(
+                r#"{{
+                    "pack_id": "demo",
+                    "variants": [
+                        {{"id": "regular", "style": "Regular", "size": "Regular",
+                          "family": "Demo", "ttf_asset_path": "demo.ttf"}},
+                        {{"id": "filled", "style": "Filled", "size": "Regular",
+                          "family": "Demo Filled", "ttf_asset_path": "demo-filled.ttf"}}
+                    ],
+                    "icons": [{icons}]
+                }}"#
+            );
+            let map: PackMap = serde_json::from_str(&raw).unwrap();
+            normalize_pack(map, NormalizeOptions::default()).unwrap()
+        };
+        let old = pack(
+            r#"{"name": "alarm", "codepoint": 1},
+               {"name": "bell", "codepoint": 2, "overrides": {"filled": 3}},
+               {"name": "gone", "codepoint": 4}"#,
+        );
+        let new = pack(
+            r#"{"name": "alarm", "codepoint": 1},
+               {"name": "bell", "codepoint": 2, "overrides": {"filled": 5}},
+               {"name": "star", "codepoint": 6}"#,
+        );
+
+        let diff = diff_packs(&old, &new);
+        assert_eq!(diff.added, ["star"]);
+        assert_eq!(diff.removed, ["gone"]);
+        assert_eq!(
+            diff.changed,
+            [CodepointChange {
+                name: "bell".to_string(),
+                key: VariantKey {
+                    style: Style::Filled,
+                    size: Size::Regular,
+                },
+                old: Some(3),
+                new: Some(5),
+            }]
+        );
+
+        let reverse = diff_packs(&new, &old);
+        assert_eq!(reverse.added, ["gone"]);
+        assert_eq!(reverse.removed, ["star"]);
+        assert!(diff_packs(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn verify_family_reports_declared_and_font_names() {
+        let repo_root = repo_root().unwrap();
+        let map = load_pack_map(&repo_root.join("assets/maps/feather.json")).unwrap();
+        let mut pack = normalize_pack(map, NormalizeOptions::default()).unwrap();
+        verify_pack_families(&pack, &repo_root).unwrap();
+
+        pack.variants[0].family = "Feather Wrong".to_string();
+        let err = verify_pack_families(&pack, &repo_root)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("declares family 'Feather Wrong'"), "{err}");
+        assert!(err.contains("names 'Feather Regular'"), "{err}");
+    }
+
+    #[test]
+    fn orphan_variants_warn_by_default_and_fail_when_strict() {
+        let orphan_pack = || {
+            let mut pack = collision_pack();
+            pack.icons.truncate(1);
+            pack.icons[0].availability = Some(Availability::Variants(vec!["regular".to_string()]));
+            pack.variants.push(Variant {
+                id: "filled".to_string(),
+                style: Style::Filled,
+                size: Size::Regular,
+                family: "Demo Filled".to_string(),
+                ttf_asset_path: "assets/fonts/demo-filled.ttf".to_string(),
+                feature: None,
+                inherit_from: None,
+                codepoint_offset: None,
+                pua_base: None,
+            });
+            pack
+        };
+
+        let normalized = normalize_pack(orphan_pack(), NormalizeOptions::default()).unwrap();
+        assert_eq!(normalized.variants.len(), 2);
+
+        let options = NormalizeOptions {
+            strict: true,
+            ..NormalizeOptions::default()
+        };
+        let err = normalize_pack(orphan_pack(), options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("variant(s) used by no icon: 'filled'")
+        );
+    }
+
+    #[test]
+    fn zero_codepoints_are_rejected_unless_allowed() {
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.icons[0].codepoint = Some(0);
+        let err = normalize_pack(pack, NormalizeOptions::default()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("icon 'h1' has codepoint 0 for variant 'regular'")
+        );
+
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.icons[0].codepoint = Some(5);
+        pack.icons[0].overrides.insert("regular".to_string(), 0);
+        assert!(normalize_pack(pack, NormalizeOptions::default()).is_err());
+
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.icons[0].codepoint = Some(0);
+        pack.allow_zero_codepoint = true;
+        let normalized = normalize_pack(pack, NormalizeOptions::default()).unwrap();
+        assert_eq!(normalized.icons[0].codepoints[0].1, 0);
+    }
+
+    #[test]
+    fn inherit_from_reuses_the_base_variant_codepoint() {
+        let raw = r#"{
+            "pack_id": "demo",
+            "variants": [
+                {"id": "regular", "style": "Regular", "size": "Regular",
+                 "family": "Demo", "ttf_asset_path": "demo.ttf"},
+                {"id": "filled", "style": "Filled", "size": "Regular",
+                 "family": "Demo Filled", "ttf_asset_path": "demo-filled.ttf",
+                 "inherit_from": "regular"}
+            ],
+            "icons": [
+                {"name": "alarm", "overrides": {"regular": 7}},
+                {"name": "bell", "codepoint": 2, "overrides": {"regular": 3}},
+                {"name": "star", "codepoint": 1, "overrides": {"filled": 9}}
+            ]
+        }"#;
+        let map: PackMap = serde_json::from_str(raw).unwrap();
+        let pack = normalize_pack(map, NormalizeOptions::default()).unwrap();
+        let filled = VariantKey {
+            style: Style::Filled,
+            size: Size::Regular,
+        };
+        let filled_codepoint = |name: &str| {
+            let icon = pack.icons.iter().find(|icon| icon.name == name).unwrap();
+            icon.codepoints
+                .iter()
+                .find(|(key, _)| *key == filled)
+                .map(|(_, codepoint)| *codepoint)
+        };
+        assert_eq!(filled_codepoint("alarm"), Some(7));
+        assert_eq!(filled_codepoint("bell"), Some(3));
+        assert_eq!(filled_codepoint("star"), Some(9));
+
+        let cyclic = raw.replace(
+            r#""ttf_asset_path": "demo.ttf"}"#,
+            r#""ttf_asset_path": "demo.ttf", "inherit_from": "filled"}"#,
+        );
+        let map: PackMap = serde_json::from_str(&cyclic).unwrap();
+        let err = normalize_pack(map, NormalizeOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("cyclic inherit_from chain"));
+    }
+
+    #[test]
+    fn codepoint_offset_derives_variant_codepoints() {
+        let pack = |offset: i32| {
+            let raw = format!(
+                r#"{{
+                    "pack_id": "demo",
+                    "variants": [
+                        {{"id": "regular", "style": "Regular", "size": "Regular",
+                          "family": "Demo", "ttf_asset_path": "demo.ttf"}},
+                        {{"id": "filled", "style": "Filled", "size": "Regular",
+                          "family": "Demo Filled", "ttf_asset_path": "demo-filled.ttf",
+                          "codepoint_offset": {offset}}}
+                    ],
+                    "icons": [
+                        {{"name": "alarm", "codepoint": "U+E001"}},
+                        {{"name": "bell", "codepoint": "U+E002", "overrides": {{"filled": 42}}}}
+                    ]
+                }}"#
+            );
+            let map: PackMap = serde_json::from_str(&raw).unwrap();
+            normalize_pack(map, NormalizeOptions::default())
+        };
+
+        let normalized = pack(0x1000).unwrap();
+        let codepoints: Vec<(&str, Style, u32)> = normalized
+            .icons
+            .iter()
+            .flat_map(|icon| {
+                icon.codepoints
+                    .iter()
+                    .map(|(key, codepoint)| (icon.name.as_str(), key.style, *codepoint))
+            })
+            .collect();
+        assert_eq!(
+            codepoints,
+            [
+                ("alarm", Style::Filled, 0xF001),
+                ("alarm", Style::Regular, 0xE001),
+                ("bell", Style::Filled, 42),
+                ("bell", Style::Regular, 0xE002),
+            ]
+        );
+
+        let err = pack(-0xF000).unwrap_err();
+        assert!(err.to_string().contains("is not a Unicode scalar value"));
+    }
+
+    #[test]
+    fn display_name_defaults_to_title_cased_pack_id() {
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.pack_id = "remix-icon".to_string();
+        let normalized = normalize_pack(pack, NormalizeOptions::default()).unwrap();
+        assert_eq!(normalized.display_name, "Remix Icon");
+
+        let mut pack = collision_pack();
+        pack.icons.truncate(1);
+        pack.display_name = Some("Fluent UI".to_string());
+        let normalized = normalize_pack(pack, NormalizeOptions::default()).unwrap();
+        let out = render_pack(&normalized, RenderOptions::default()).unwrap();
+        assert!(out.contains("pub const DISPLAY_NAME: &str = \"Fluent UI\";"));
+        let out = render_mod(&[normalized]).unwrap();
+        assert!(out.contains("Pack::Demo => demo::DISPLAY_NAME,"));
+    }
+
+    #[test]
+    fn pack_kind_defaults_to_ui_and_reaches_generated_code() {
+        let raw = r#"{
+          "pack_id": "demo",
+          "kind": "brand",
+          "variants": [
+            {
+              "id": "regular",
+              "style": "Regular",
+              "size": "Regular",
+              "family": "Demo Regular",
+              "ttf_asset_path": "assets/fonts/demo/demo.ttf"
+            }
+          ],
+          "icons": [
+            { "name": "demo", "codepoint": 57344 }
+          ]
+        }"#;
+        let map: PackMap = serde_json::from_str(raw).unwrap();
+        assert_eq!(map.kind, PackKind::Brand);
+        let normalized = normalize_pack(map, NormalizeOptions::default()).unwrap();
+        let out = render_pack(&normalized, RenderOptions::default()).unwrap();
+        assert!(out.contains("pub const KIND: PackKind = PackKind::Brand;"));
+        let out = render_mod(&[normalized]).unwrap();
+        assert!(out.contains("Pack::Demo => demo::KIND,"));
+
+        let unset: PackMap = serde_json::from_str(&raw.replace(r#""kind": "brand","#, "")).unwrap();
+        assert_eq!(unset.kind, PackKind::Ui);
+        assert!(serde_json::from_str::<PackMap>(&raw.replace("brand", "logo")).is_err());
+    }
+
+    #[test]
+    fn variant_codepoint_ranges_cover_each_variant() {
+        let out = render_pack(&granular_demo_pack(), RenderOptions::default()).unwrap();
+        assert!(out.contains(
+            "    (VariantKey { style: Style::Regular, size: Size::Regular }, 0xE000, 0xE002),"
+        ));
+        let out = render_mod(&[granular_demo_pack()]).unwrap();
+        assert!(out.contains("Pack::Demo => demo::VARIANT_CODEPOINT_RANGES,"));
+    }
+
+    #[test]
+    fn shared_family_with_overlapping_codepoint_is_rejected() {
+        let first = granular_demo_pack();
+        let mut second = granular_demo_pack();
+        second.pack_id = "other".to_string();
+        second.icons.truncate(1);
+        second.icons[0].name = "zero".to_string();
+
+        let err = check_shared_families(&[first, second]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "packs 'demo' and 'other' share font family 'Demo' and both map U+E000 ('0-circle' and 'zero')"
+        );
+
+        let mut second = granular_demo_pack();
+        second.pack_id = "other".to_string();
+        second.variants[0].family = "Other".to_string();
+        assert!(check_shared_families(&[granular_demo_pack(), second]).is_ok());
+    }
+
+    #[test]
+    fn every_core_style_and_named_size_deserializes() {
+        #[derive(Deserialize)]
+        struct Key {
+            #[serde(deserialize_with = "deserialize_style")]
+            style: Style,
+            #[serde(deserialize_with = "deserialize_size")]
+            size: Size,
+        }
+
+        for &style in Style::ALL {
+            for &size in Size::NAMED {
+                let json = format!(
+                    r#"{{"style": "{}", "size": "{}"}}"#,
+                    style.name(),
+                    size.label()
+                );
+                let key: Key = serde_json::from_str(&json).unwrap();
+                assert_eq!((key.style, key.size), (style, size));
+                assert_eq!(size.rust_expr(), format!("Size::{}", size.label()));
+            }
+        }
+        let key: Key = serde_json::from_str(r#"{"style": "Bold", "size": 24}"#).unwrap();
+        assert_eq!(key.size, Size::Custom(24));
+        assert!(serde_json::from_str::<Key>(r#"{"style": "bold", "size": 24}"#).is_err());
+        assert!(serde_json::from_str::<Key>(r#"{"style": "Bold", "size": "24"}"#).is_err());
+    }
+
+    #[test]
+    fn style_order_is_validated_and_emitted() {
+        let pack = |order: Vec<Style>| {
+            let mut pack = collision_pack();
+            pack.icons.truncate(1);
+            let mut filled = pack.variants[0].clone();
+            filled.id = "filled".to_string();
+            filled.style = Style::Filled;
+            filled.family = "Demo Filled".to_string();
+            filled.ttf_asset_path = "assets/fonts/demo-filled.ttf".to_string();
+            pack.variants.push(filled);
+            pack.style_order = order;
+            normalize_pack(pack, NormalizeOptions::default())
+        };
+
+        let normalized = pack(vec![Style::Filled, Style::Regular]).unwrap();
+        let out = render_pack(&normalized, RenderOptions::default()).unwrap();
+        assert!(
+            out.contains("pub const STYLE_ORDER: &[Style] = &[Style::Filled, Style::Regular];")
+        );
+        let out = render_mod(&[normalized]).unwrap();
+        assert!(out.contains("Pack::Demo => demo::STYLE_ORDER,"));
+
+        let err = pack(vec![Style::Filled, Style::Filled]).unwrap_err();
+        assert!(err.to_string().contains("lists 'Filled' more than once"));
+
+        let err = pack(vec![Style::Duotone]).unwrap_err();
+        assert!(err.to_string().contains("'Duotone', which no variant uses"));
+    }
+
+    #[test]
+    fn emitted_json_keeps_normalized_fields() {
+        let mut pack = granular_demo_pack();
+        pack.icons[1].codepoints.push((
+            VariantKey {
+                style: Style::Bold,
+                size: Size::Custom(24),
+            },
+            0xF001,
+        ));
+        let out_dir = env::temp_dir().join(format!("iconflow-emit-json-{}", std::process::id()));
+        write_pack_json(std::slice::from_ref(&pack), &out_dir).unwrap();
+        let json = fs::read_to_string(out_dir.join("demo.json")).unwrap();
+        fs::remove_dir_all(&out_dir).unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["pack_id"], "demo");
+        assert_eq!(value["display_name"], "Demo");
+        assert_eq!(value["variants"][0]["family"], "Demo");
+        assert_eq!(
+            value["variants"][0]["key"],
+            serde_json::json!({"style": "Regular", "size": "Regular"})
+        );
+        let icons = value["icons"].as_array().unwrap();
+        assert_eq!(icons.len(), pack.icons.len());
+        assert_eq!(icons[0]["name"], "0-circle");
+        assert_eq!(icons[0]["ident"], "Icon0Circle");
+        assert_eq!(
+            icons[1]["codepoints"],
+            serde_json::json!([
+                [{"style": "Regular", "size": "Regular"}, 0xE001],
+                [{"style": "Bold", "size": 24}, 0xF001],
+            ])
+        );
+    }
+
+    #[test]
+    fn check_diff_shows_changed_lines() {
+        let existing = "pub const A: u32 = 1;\npub const B: u32 = 2;\npub const C: u32 = 3;\n";
+        let content = "pub const A: u32 = 1;\npub const B: u32 = 20;\npub const C: u32 = 3;\n";
+        let diff = output_diff(Path::new("src/generated/demo.rs"), existing, content);
+        assert!(diff.contains("--- src/generated/demo.rs (on disk)"));
+        assert!(diff.contains("+++ src/generated/demo.rs (generated)"));
+        assert!(diff.contains("\n-pub const B: u32 = 2;\n"));
+        assert!(diff.contains("\n+pub const B: u32 = 20;\n"));
+        assert!(diff.contains("\n pub const A: u32 = 1;\n"));
+    }
+
+    #[test]
+    fn render_mod_orders_fonts_by_pack_then_family() {
+        let pack = |pack_id: &str, families: &[&str]| {
+            let mut pack = granular_demo_pack();
+            pack.pack_id = pack_id.to_string();
+            pack.variants = families
+                .iter()
+                .enumerate()
+                .map(|(index, family)| VariantInfo {
+                    id: format!("v{index}"),
+                    key: VariantKey {
+                        style: Style::ALL[index],
+                        size: Size::Regular,
+                    },
+                    family: family.to_string(),
+                    ttf_asset_path: format!("assets/fonts/{pack_id}/{pack_id}-{index}.ttf"),
+                    feature: None,
+                    pua_shift: None,
+                })
+                .collect();
+            pack
+        };
+        let out = render_mod(&[
+            pack("zeta", &["Zeta A"]),
+            pack("alpha", &["Alpha Z", "Alpha B"]),
+        ])
+        .unwrap();
+        let fonts = &out[out.find("pub fn fonts()").unwrap()..];
+        let position = |asset: &str| fonts.find(asset).unwrap();
+        assert!(
+            position("alpha::FONT_ASSET_ALPHA_ALPHA_1,")
+                < position("alpha::FONT_ASSET_ALPHA_ALPHA_0,")
+        );
+        assert!(
+            position("alpha::FONT_ASSET_ALPHA_ALPHA_0,")
+                < position("zeta::FONT_ASSET_ZETA_ZETA_0,")
+        );
+    }
+
+    #[test]
+    fn strict_availability_flags_default_codepoints_with_partial_availability() {
+        let pack = |availability: &str, options| {
+            let raw = format!(
+                r#"{{
+                    "pack_id": "demo",
+                    "variants": [
+                        {{"id": "regular", "style": "Regular", "size": "Regular",
+                         "family": "Demo", "ttf_asset_path": "demo.ttf"}},
+                        {{"id": "filled", "style": "Filled", "size": "Regular",
+                         "family": "Demo Filled", "ttf_asset_path": "demo-filled.ttf"}},
+                        {{"id": "light", "style": "Light", "size": "Regular",
+                         "family": "Demo Light", "ttf_asset_path": "demo-light.ttf"}}
+                    ],
+                    "icons": [
+                        {{"name": "alarm", "codepoint": 1, "availability": {availability}}},
+                        {{"name": "bell", "codepoint": 2}}
+                    ]
+                }}"#
+            );
+            let map: PackMap = serde_json::from_str(&raw).unwrap();
+            normalize_pack(map, options)
+        };
+        let strict = |strict_availability| NormalizeOptions {
+            strict: true,
+            strict_availability,
+            ..NormalizeOptions::default()
+        };
+
+        assert!(pack(r#"["regular"]"#, strict(false)).is_ok());
+        let err = pack(r#"["regular"]"#, strict(true)).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "icon 'alarm' has a default codepoint but its availability omits 'filled', 'light'"
+            ),
+            "{err}"
+        );
+        assert!(pack(r#"["regular", "filled", "light"]"#, strict(true)).is_ok());
+
+        let warn_only = NormalizeOptions {
+            strict_availability: true,
+            ..NormalizeOptions::default()
+        };
+        let normalized = pack(r#"["regular"]"#, warn_only).unwrap();
+        assert_eq!(normalized.icons[0].codepoints.len(), 1);
+    }
+
+    #[test]
+    fn unavailable_lists_the_variants_an_icon_lacks() {
+        let pack = |fields: &str| {
+            let raw = format!(
+                r#"{{
+                    "pack_id": "demo",
+                    "variants": [
+                        {{"id": "outline", "style": "Outline", "size": "Regular",
+                         "family": "Demo", "ttf_asset_path": "demo.ttf"}},
+                        {{"id": "filled", "style": "Filled", "size": "Regular",
+                         "family": "Demo Filled", "ttf_asset_path": "demo-filled.ttf"}},
+                        {{"id": "light", "style": "Light", "size": "Regular",
+                         "family": "Demo Light", "ttf_asset_path": "demo-light.ttf"}}
+                    ],
+                    "icons": [
+                        {{"name": "alarm", "codepoint": 1, {fields}}},
+                        {{"name": "bell", "codepoint": 2}}
+                    ]
+                }}"#
+            );
+            let map: PackMap = serde_json::from_str(&raw)?;
+            normalize_pack(
+                map,
+                NormalizeOptions {
+                    strict: true,
+                    strict_availability: true,
+                    ..NormalizeOptions::default()
+                },
+            )
+        };
+        let styles = |fields: &str| -> Vec<Style> {
+            pack(fields).unwrap().icons[0]
+                .codepoints
+                .iter()
+                .map(|(key, _)| key.style)
+                .collect()
+        };
+
+        assert_eq!(
+            styles(r#""unavailable": ["filled"]"#),
+            [Style::Light, Style::Outline]
+        );
+        assert_eq!(
+            styles(r#""unavailable": ["Filled/Regular"]"#),
+            [Style::Light, Style::Outline]
+        );
+        assert_eq!(styles(r#""unavailable": []"#).len(), 3);
+
+        let err = pack(r#""availability": ["outline"], "unavailable": ["filled"]"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("sets both availability and unavailable")
+        );
+        let err = pack(r#""unavailable": ["bold"]"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unavailable unknown variant 'bold'")
+        );
+        assert!(pack(r#""unavailable": ["outline", "filled", "light"]"#).is_err());
+    }
+
+    #[test]
+    fn availability_accepts_pairs_and_style_maps() {
+        let pack = |availability: &str| {
+            let raw = format!(
+                r#"{{
+                    "pack_id": "demo",
+                    "variants": [
+                        {{"id": "outline", "style": "Outline", "size": "Regular",
+                         "family": "Demo", "ttf_asset_path": "demo.ttf"}},
+                        {{"id": "outline-large", "style": "Outline", "size": "Large",
+                         "family": "Demo Large", "ttf_asset_path": "demo-large.ttf"}},
+                        {{"id": "filled", "style": "Filled", "size": "Regular",
+                         "family": "Demo Filled", "ttf_asset_path": "demo-filled.ttf"}},
+                        {{"id": "filled-large", "style": "Filled", "size": "Large",
+                         "family": "Demo Filled Large", "ttf_asset_path": "demo-filled-large.ttf"}}
+                    ],
+                    "icons": [
+                        {{"name": "alarm", "codepoint": 1, "overrides": {{"filled": 2}},
+                         "availability": {availability}}},
+                        {{"name": "bell", "codepoint": 3}}
+                    ]
+                }}"#
+            );
+            let map: PackMap = serde_json::from_str(&raw)?;
+            normalize_pack(map, NormalizeOptions::default())
+        };
+        let codepoints =
+            |availability: &str| pack(availability).unwrap().icons.remove(0).codepoints;
+
+        let by_id = codepoints(r#"["outline", "outline-large", "filled"]"#);
+        assert_eq!(by_id.len(), 3);
+        assert!(by_id.contains(&(
+            VariantKey {
+                style: Style::Filled,
+                size: Size::Regular
+            },
+            2
+        )));
+        assert_eq!(
+            codepoints(
+                r#"[{"style": "Outline", "size": "Regular"},
+                    {"style": "Outline", "size": "Large"},
+                    {"style": "Filled", "size": "Regular"}]"#
+            ),
+            by_id
+        );
+        assert_eq!(
+            codepoints(r#"{"Outline": ["Regular", "Large"], "Filled": ["Regular"]}"#),
+            by_id
+        );
+
+        assert_eq!(
+            codepoints(r#"["Outline/Regular", "outline-large", "Filled/Regular"]"#),
+            by_id
+        );
+        assert_eq!(
+            codepoints(r#"["Outline/Large", "Filled/Regular"]"#),
+            [
+                (
+                    VariantKey {
+                        style: Style::Filled,
+                        size: Size::Regular
+                    },
+                    2
+                ),
+                (
+                    VariantKey {
+                        style: Style::Outline,
+                        size: Size::Large
+                    },
+                    1
+                )
+            ]
+        );
+
+        let err = pack(r#"["Filled/Tiny"]"#).unwrap_err();
+        assert!(err.to_string().contains("no variant for Filled Tiny"));
+        let err = pack(r#"["Outlined/Regular"]"#).unwrap_err();
+        assert!(format!("{err:#}").contains("unknown style 'Outlined'"));
+        let err = pack(r#"{"Filled": ["Tiny"]}"#).unwrap_err();
+        assert!(err.to_string().contains("no variant for Filled Tiny"));
+        let err = pack(r#"{"Filled": ["Regular", "Regular"]}"#).unwrap_err();
+        assert!(err.to_string().contains("duplicates: 'filled'"));
+        assert!(pack(r#"{"filled": ["Regular"]}"#).is_err());
+    }
+
+    #[test]
+    fn render_pack_gates_styles_and_sizes_by_variant_features() {
+        let mut pack = granular_demo_pack();
+        for (id, style, size, feature) in [
+            ("filled-large", Style::Filled, Size::Large, "demo-large"),
+            ("filled-tiny", Style::Filled, Size::Tiny, "demo-tiny"),
+        ] {
+            pack.variants.push(VariantInfo {
+                id: id.to_string(),
+                key: VariantKey { style, size },
+                family: format!("Demo {id}"),
+                ttf_asset_path: format!("assets/fonts/demo/demo-{id}.ttf"),
+                feature: Some(feature.to_string()),
+                pua_shift: None,
+            });
+        }
+        let out = render_pack(&pack, RenderOptions::default()).unwrap();
+        assert!(out.contains(
+            "pub const STYLES: &[Style] = &[\n    Style::Regular,\n    #[cfg(any(feature = \"demo-large\", feature = \"demo-tiny\"))]\n    Style::Filled,\n];"
+        ));
+        assert!(out.contains(
+            "pub const SIZES: &[Size] = &[\n    #[cfg(feature = \"demo-tiny\")]\n    Size::Tiny,\n    Size::Regular,\n    #[cfg(feature = \"demo-large\")]\n    Size::Large,\n];"
+        ));
+    }
+
+    #[test]
+    fn render_pack_gates_icons_only_in_feature_gated_variants() {
+        let mut pack = granular_demo_pack();
+        let tiny = VariantKey {
+            style: Style::Regular,
+            size: Size::Tiny,
+        };
+        pack.variants.push(VariantInfo {
+            id: "tiny".to_string(),
+            key: tiny,
+            family: "Demo Tiny".to_string(),
+            ttf_asset_path: "assets/fonts/demo/demo-tiny.ttf".to_string(),
+            feature: Some("demo-tiny".to_string()),
+            pua_shift: None,
+        });
+        pack.icons[1].codepoints.push((tiny, 0xF001));
+        pack.icons[2].codepoints = vec![(tiny, 0xF002)];
+        let gate = "#[cfg(feature = \"demo-tiny\")]";
+
+        let out = render_pack(&pack, RenderOptions::default()).unwrap();
+        assert!(out.contains(&format!("    {gate}\n    Bell,")));
+        assert!(out.contains(&format!("{gate}\nconst ICON_BELL_CODEPOINTS")));
+        assert!(out.contains(&format!("{gate}\nconst ICON_BELL_AVAILABLE")));
+        assert!(out.contains(&format!("    {gate}\n    \"bell\",")));
+        assert!(out.contains("    \"alarm\",\n"));
+        assert!(!out.contains(&format!("{gate}\n    Alarm,")));
+
+        let granular = render_pack(
+            &pack,
+            RenderOptions {
+                granular_features: true,
+            },
+        )
+        .unwrap();
+        assert!(granular.contains(&format!(
+            "    #[cfg(any(not(feature = \"demo-granular\"), feature = \"demo-icons-b\"))]\n    {gate}\n    Bell,"
+        )));
+    }
+
+    #[test]
+    fn pua_base_relocates_variant_codepoints() {
+        let pack = |pua_base: &str| {
+            let raw = format!(
+                r#"{{
+                    "pack_id": "demo",
+                    "variants": [
+                        {{"id": "regular", "style": "Regular", "size": "Regular",
+                         "family": "Demo", "ttf_asset_path": "demo.ttf"}},
+                        {{"id": "filled", "style": "Filled", "size": "Regular",
+                         "family": "Demo Filled", "ttf_asset_path": "demo-filled.ttf",
+                         "pua_base": {pua_base}}}
+                    ],
+                    "icons": [
+                        {{"name": "alarm", "codepoint": "U+E001"}},
+                        {{"name": "bell", "codepoint": "U+E005"}}
+                    ]
+                }}"#
+            );
+            let map: PackMap = serde_json::from_str(&raw)?;
+            normalize_pack(map, NormalizeOptions::default())
+        };
+        let regular = VariantKey {
+            style: Style::Regular,
+            size: Size::Regular,
+        };
+        let filled = VariantKey {
+            style: Style::Filled,
+            ..regular
+        };
+
+        let normalized = pack(r#""U+F0000""#).unwrap();
+        let codepoint = |name: &str, key: VariantKey| {
+            let icon = normalized
+                .icons
+                .iter()
+                .find(|icon| icon.name == name)
+                .unwrap();
+            icon.codepoints.iter().find(|(k, _)| *k == key).unwrap().1
+        };
+        assert_eq!(codepoint("alarm", filled), 0xF_0000);
+        assert_eq!(codepoint("bell", filled), 0xF_0004);
+        assert_eq!(codepoint("bell", regular), 0xE005);
+        let shifted = normalized
+            .variants
+            .iter()
+            .find(|v| v.id == "filled")
+            .unwrap();
+        assert_eq!(shifted.pua_shift, Some(0xF_0000 - 0xE001));
+
+        let out = render_pack(&normalized, RenderOptions::default()).unwrap();
+        assert!(out.contains("// Codepoints moved by +0xE1FFF via `pua_base`"));
+        assert!(out.contains("0xF0004"));
+
+        let err = pack(r#""U+10FFFC""#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("moves icon 'bell' (U+E005) outside the Private Use Area")
+        );
+    }
+
+    #[test]
+    fn pua_base_requires_glyphs_at_relocated_codepoints() {
+        let repo_root = repo_root().unwrap();
+        // Codepoints as a stale map would list them before the font was re-encoded to U+E000.
+        let pack = |pua_base: &str| {
+            let raw = format!(
+                r#"{{
+                    "pack_id": "demo",
+                    "variants": [
+                        {{"id": "regular", "style": "Regular", "size": "Regular",
+                         "family": "Bootstrap Regular",
+                         "ttf_asset_path": "assets/fonts/bootstrap/bootstrap-regular.ttf",
+                         "pua_base": {pua_base}}}
+                    ],
+                    "icons": [
+                        {{"name": "zero-circle", "codepoint": "U+F0100"}},
+                        {{"name": "zero-square", "codepoint": "U+F0101"}}
+                    ]
+                }}"#
+            );
+            let map: PackMap = serde_json::from_str(&raw).unwrap();
+            normalize_pack(map, NormalizeOptions::default()).unwrap()
+        };
+
+        let relocated = pack(r#""U+E000""#);
+        check_pua_glyphs(&relocated, &repo_root).unwrap();
+        let bytes =
+            fs::read(repo_root.join("assets/fonts/bootstrap/bootstrap-regular.ttf")).unwrap();
+        let face = ttf_parser::Face::parse(&bytes, 0).unwrap();
+        for icon in &relocated.icons {
+            let (_, codepoint) = icon.codepoints[0];
+            let ch = char::from_u32(codepoint).unwrap();
+            assert!(
+                face.glyph_index(ch).is_some(),
+                "{} at U+{codepoint:04X}",
+                icon.name
+            );
+        }
+
+        let err = check_pua_glyphs(&pack(r#""U+F0000""#), &repo_root).unwrap_err();
+        assert!(err.to_string().contains(
+            "variant 'regular' moves icon 'zero-circle' to U+F0000 via pua_base but \
+             assets/fonts/bootstrap/bootstrap-regular.ttf has no glyph there"
+        ));
+    }
+
+    #[test]
+    fn generate_matches_committed_output() {
+        let repo_root = repo_root().unwrap();
+        let sources = generate(
+            &repo_root.join("assets").join("maps"),
+            NormalizeOptions::default(),
+            RenderOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(sources[0].0, "mod.rs");
+        for (file_name, content) in &sources {
+            let path = repo_root.join("src").join("generated").join(file_name);
+            let committed = fs::read_to_string(&path).unwrap();
+            assert!(
+                committed == *content,
+                "{file_name} differs from `cargo xtask gen`:\n{}",
+                output_diff(&path, &committed, content)
+            );
+        }
+        assert_eq!(
+            sources.len(),
+            1 + fs::read_dir(repo_root.join("assets").join("maps"))
+                .unwrap()
+                .count()
+        );
+    }
+}